@@ -15,6 +15,10 @@ use bevy::state::app::StatesPlugin;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use rendering::building_meshes::{BuildingModelCache, InstancedModels};
+use simulation::buildings::Building;
+use simulation::config::{GRID_HEIGHT, GRID_WIDTH};
+use simulation::grid::ZoneType;
 use simulation::tutorial::TutorialState;
 
 /// Build a headless app with both SimulationPlugin and RenderingPlugin.
@@ -56,6 +60,9 @@ fn build_headless_rendering_app() -> App {
         ..Default::default()
     });
 
+    // Keep the player's real profile out of the benchmark.
+    app.insert_resource(simulation::profile::ProfileStore::Memory);
+
     // Start in Playing state so simulation systems run during the benchmark.
     app.insert_state(simulation::AppState::Playing);
 
@@ -129,5 +136,86 @@ fn bench_sim_frame(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_update_schedule, bench_sim_frame);
+/// Number of buildings in the instancing benchmark.
+const INSTANCING_BUILDINGS: usize = 50_000;
+
+/// Build a headless app showing `count` zone buildings that all use one
+/// two-mesh model, spawned instanced or as one `SceneRoot` each.
+fn build_city_of_buildings(count: usize, instanced: bool) -> App {
+    let mut app = build_headless_rendering_app();
+    // Components copied into scene instances must be registered for reflection.
+    app.register_type::<Mesh3d>()
+        .register_type::<MeshMaterial3d<StandardMaterial>>();
+
+    let world = app.world_mut();
+    let mesh = world.resource_mut::<Assets<Mesh>>().add(Cuboid::default());
+    let material = world
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    let mut scene_world = World::new();
+    scene_world
+        .spawn(Transform::default())
+        .with_children(|node| {
+            node.spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(0.0, 0.5, 0.0),
+            ));
+            node.spawn((
+                Mesh3d(mesh),
+                MeshMaterial3d(material),
+                Transform::from_xyz(0.0, 1.5, 0.0).with_scale(Vec3::splat(0.6)),
+            ));
+        });
+    let scene = world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(scene_world));
+
+    let mut cache = world.resource_mut::<BuildingModelCache>();
+    cache.residential = vec![scene.clone()];
+    cache.commercial = vec![scene.clone()];
+    cache.skyscrapers = vec![scene.clone()];
+    cache.industrial = vec![scene];
+    world.resource_mut::<InstancedModels>().enabled = instanced;
+
+    for i in 0..count {
+        world.spawn(Building {
+            zone_type: ZoneType::ResidentialLow,
+            level: 1,
+            grid_x: i % GRID_WIDTH,
+            grid_y: (i / GRID_WIDTH) % GRID_HEIGHT,
+            capacity: 10,
+            occupants: 5,
+        });
+    }
+
+    // Spawn the building meshes and let scene instances settle.
+    for _ in 0..5 {
+        app.update();
+    }
+    app
+}
+
+/// Compare a full frame with 50K buildings spawned as one `SceneRoot` each
+/// against the same buildings spawned instanced from flattened models.
+fn bench_building_instancing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("instancing");
+    group.sample_size(10);
+
+    for (name, instanced) in [("scene_roots_50k", false), ("instanced_50k", true)] {
+        let mut app = build_city_of_buildings(INSTANCING_BUILDINGS, instanced);
+        group.bench_function(name, |b| {
+            b.iter(|| app.update());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_update_schedule,
+    bench_sim_frame,
+    bench_building_instancing
+);
 criterion_main!(benches);
@@ -0,0 +1,453 @@
+//! Executing agent protocol commands against the world.
+//!
+//! Shared by the stdin/stdout `--agent` loop and the `--serve` WebSocket
+//! server: both parse a line of JSON into an [`AgentCommand`], run it here and
+//! send back the [`AgentResponse`]. See [`simulation::agent_protocol`] for the
+//! schema.
+//!
+//! [`AgentCommand`]: simulation::agent_protocol::AgentCommand
+//! [`AgentResponse`]: simulation::agent_protocol::AgentResponse
+
+/// How the world advances between commands.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Driver {
+    /// Nothing runs between commands: the `--agent` loop owns the app and
+    /// only steps it when told to.
+    Stepped,
+    /// The app's own loop keeps running (`--serve`), so Update and
+    /// FixedUpdate run every frame regardless of commands.
+    Live,
+}
+
+/// Most ticks one `step` command runs, to prevent accidental infinite loops.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const MAX_STEP_TICKS: u64 = 10_000;
+
+/// Parse one line of JSON into a command, or the response reporting why it
+/// couldn't be parsed.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn parse_command(
+    line: &str,
+) -> Result<simulation::agent_protocol::AgentCommand, simulation::agent_protocol::AgentResponse> {
+    use simulation::agent_protocol::{make_response, ResponsePayload};
+
+    serde_json::from_str(line).map_err(|e| {
+        // For act/batch_act commands, return the parse error as an
+        // ActionResult so the LLM gets structured feedback about
+        // invalid parameters (e.g. unknown service_type/utility_type).
+        try_act_parse_error_response(line, &e).unwrap_or_else(|| {
+            make_response(ResponsePayload::Error {
+                message: format!("Parse error: {e}"),
+            })
+        })
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Parse-error recovery for act/batch_act commands
+// ---------------------------------------------------------------------------
+
+/// When serde fails to parse an `act` or `batch_act` command (e.g. because of
+/// an unknown `service_type` or `utility_type` variant), return the error as a
+/// structured `ActionResult` instead of a generic protocol error. This gives
+/// the LLM agent useful feedback about which parameter was wrong and what the
+/// valid values are.
+#[cfg(not(target_arch = "wasm32"))]
+fn try_act_parse_error_response(
+    raw_line: &str,
+    err: &serde_json::Error,
+) -> Option<simulation::agent_protocol::AgentResponse> {
+    use simulation::agent_protocol::{make_response, ResponsePayload};
+    use simulation::game_actions::{ActionError, ActionResult};
+
+    // Quick check: only attempt recovery for act/batch_act commands.
+    let value: serde_json::Value = serde_json::from_str(raw_line).ok()?;
+    let cmd = value.get("cmd")?.as_str()?;
+
+    match cmd {
+        "act" => {
+            let message = format!("{err}");
+            let result = ActionResult::Error(ActionError::InvalidParameter(message));
+            Some(make_response(ResponsePayload::ActionResult { result }))
+        }
+        "batch_act" => {
+            let message = format!("{err}");
+            let result = ActionResult::Error(ActionError::InvalidParameter(message));
+            // Return a single error result for the whole batch since we
+            // cannot determine which action within the batch caused the
+            // parse failure.
+            Some(make_response(ResponsePayload::BatchResult {
+                results: vec![result],
+            }))
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Command dispatch
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn process_command(
+    cmd: simulation::agent_protocol::AgentCommand,
+    world: &mut bevy::ecs::world::World,
+    driver: Driver,
+) -> simulation::agent_protocol::AgentResponse {
+    use bevy::prelude::*;
+    use simulation::agent_protocol::{make_response, AgentCommand, ResponsePayload};
+    use simulation::game_actions::{ActionQueue, ActionSource};
+    use simulation::game_actions::{ActionResult, ActionResultLog};
+    use simulation::observation_builder::CurrentObservation;
+    use simulation::replay::{
+        read_replay, write_replay, LoadedReplay, ReplayCheckpoints, ReplayEncoding, ReplayPlayer,
+        ReplayRecorder,
+    };
+    use simulation::TickCounter;
+
+    match cmd {
+        AgentCommand::Observe => {
+            // A running app refreshes the observation every tick by itself.
+            if driver == Driver::Stepped {
+                // Force the SlowTickTimer to a cadence boundary so that
+                // coverage-metrics and other slow systems run on this Update.
+                {
+                    let mut timer = world.resource_mut::<simulation::SlowTickTimer>();
+                    let interval = simulation::SlowTickTimer::INTERVAL;
+                    timer.counter = (timer.counter / interval) * interval;
+                }
+                // Run Update schedule so coverage metrics and other Update-only
+                // systems refresh before we snapshot the observation.
+                world.run_schedule(Update);
+                // Run one more FixedUpdate so the observation builder (PostSim)
+                // captures the freshest state.
+                world.run_schedule(FixedUpdate);
+            }
+
+            let obs = world
+                .get_resource::<CurrentObservation>()
+                .map(|co| co.observation.clone())
+                .unwrap_or_default();
+            make_response(ResponsePayload::Observation {
+                observation: Box::new(obs),
+            })
+        }
+
+        AgentCommand::Act { action } => {
+            let tick = world
+                .get_resource::<TickCounter>()
+                .map(|t| t.0)
+                .unwrap_or(0);
+
+            world
+                .resource_mut::<ActionQueue>()
+                .push(tick, ActionSource::Agent, action);
+
+            // Run one FixedUpdate tick so the executor processes the action.
+            // We call run_schedule(FixedUpdate) directly (like the test harness)
+            // because app.update() in headless mode doesn't advance virtual time
+            // enough for FixedUpdate to fire.
+            world.run_schedule(FixedUpdate);
+
+            let result = world
+                .get_resource::<ActionResultLog>()
+                .and_then(|log| log.last_n(1).first().map(|(_, r)| r.clone()))
+                .unwrap_or(ActionResult::Success);
+
+            make_response(ResponsePayload::ActionResult { result })
+        }
+
+        AgentCommand::BatchAct { actions } => {
+            let mut results = Vec::with_capacity(actions.len());
+            for action in actions {
+                let tick = world
+                    .get_resource::<TickCounter>()
+                    .map(|t| t.0)
+                    .unwrap_or(0);
+
+                world
+                    .resource_mut::<ActionQueue>()
+                    .push(tick, ActionSource::Agent, action);
+
+                world.run_schedule(FixedUpdate);
+
+                let result = world
+                    .get_resource::<ActionResultLog>()
+                    .and_then(|log| log.last_n(1).first().map(|(_, r)| r.clone()))
+                    .unwrap_or(ActionResult::Success);
+
+                results.push(result);
+            }
+            make_response(ResponsePayload::BatchResult { results })
+        }
+
+        AgentCommand::Step { ticks } => {
+            let n = ticks.min(MAX_STEP_TICKS);
+            // Run FixedUpdate directly (same approach as the test harness).
+            // Bevy's virtual time system doesn't advance properly with
+            // MinimalPlugins in headless mode, so we bypass it entirely.
+            for _ in 0..n {
+                world.run_schedule(FixedUpdate);
+            }
+            let tick = world
+                .get_resource::<TickCounter>()
+                .map(|t| t.0)
+                .unwrap_or(0);
+            make_response(ResponsePayload::StepComplete { tick })
+        }
+
+        AgentCommand::NewGame { seed } => {
+            // Reset the WorldGrid: clear all cells to default (Grass, no zone/road/building)
+            if let Some(mut grid) = world.get_resource_mut::<simulation::grid::WorldGrid>() {
+                for cell in grid.cells.iter_mut() {
+                    cell.cell_type = simulation::grid::CellType::Grass;
+                    cell.zone = simulation::grid::ZoneType::None;
+                    cell.road_type = simulation::grid::RoadType::Local;
+                    cell.building_id = None;
+                    cell.elevation = 0.0;
+                    cell.has_power = false;
+                    cell.has_water = false;
+                }
+
+                // Apply procedural terrain (coastline water bodies from seed)
+                simulation::procedural_terrain::generate_terrain(&mut grid, seed);
+            }
+
+            // Reset TickCounter to 0
+            if let Some(mut tick) = world.get_resource_mut::<TickCounter>() {
+                tick.0 = 0;
+            }
+
+            // Reset CityBudget to default (treasury = 50_000.0)
+            world.insert_resource(simulation::economy::CityBudget::default());
+
+            // Restart the ReplayRecorder with the new seed
+            if let Some(mut recorder) = world.get_resource_mut::<ReplayRecorder>() {
+                recorder.start(seed, "agent_session".to_string(), 0);
+            }
+
+            // Run one tick so systems settle after the reset.
+            world.run_schedule(FixedUpdate);
+
+            make_response(ResponsePayload::Ok)
+        }
+
+        AgentCommand::SaveReplay { path } => {
+            let tick = world
+                .get_resource::<TickCounter>()
+                .map(|t| t.0)
+                .unwrap_or(0);
+
+            let replay_file = world
+                .get_resource_mut::<ReplayRecorder>()
+                .map(|mut recorder| LoadedReplay {
+                    replay: recorder.stop(tick, 0),
+                    checkpoints: recorder.take_checkpoints(),
+                });
+
+            match replay_file {
+                Some(loaded) => {
+                    let bytes = write_replay(&loaded, ReplayEncoding::for_path(&path));
+                    if let Err(e) = std::fs::write(&path, bytes) {
+                        make_response(ResponsePayload::Error {
+                            message: format!("Failed to write replay to {path}: {e}"),
+                        })
+                    } else {
+                        make_response(ResponsePayload::Ok)
+                    }
+                }
+                None => make_response(ResponsePayload::Error {
+                    message: "ReplayRecorder resource not found".to_string(),
+                }),
+            }
+        }
+
+        AgentCommand::LoadReplay { path } => {
+            let bytes = match std::fs::read(&path) {
+                Ok(b) => b,
+                Err(e) => {
+                    return make_response(ResponsePayload::Error {
+                        message: format!("Failed to read replay from {path}: {e}"),
+                    });
+                }
+            };
+
+            let loaded = match read_replay(&bytes) {
+                Ok(r) => r,
+                Err(e) => {
+                    return make_response(ResponsePayload::Error {
+                        message: format!("Failed to parse replay: {e}"),
+                    });
+                }
+            };
+
+            if !world.contains_resource::<ReplayPlayer>() {
+                return make_response(ResponsePayload::Error {
+                    message: "ReplayPlayer resource not found".to_string(),
+                });
+            }
+            world.resource_scope(|world, mut player: Mut<ReplayPlayer>| {
+                player.load(loaded.replay);
+                world
+                    .resource_mut::<ReplayCheckpoints>()
+                    .adopt(&player, loaded.checkpoints);
+            });
+            make_response(ResponsePayload::Ok)
+        }
+
+        AgentCommand::Query { layers } => handle_query(layers, world),
+
+        AgentCommand::QueryGrid {
+            layers,
+            region,
+            step,
+        } => match simulation::agent_queries::read_grids(world, &layers, region, step) {
+            Ok(grid) => make_response(ResponsePayload::GridResult { grid }),
+            Err(message) => make_response(ResponsePayload::Error { message }),
+        },
+
+        AgentCommand::FindPath { from, to } => {
+            match simulation::agent_queries::find_route(world, from, to) {
+                Ok(path) => make_response(ResponsePayload::PathResult { path }),
+                Err(message) => make_response(ResponsePayload::Error { message }),
+            }
+        }
+
+        AgentCommand::DistrictStats => make_response(ResponsePayload::DistrictStats {
+            districts: simulation::agent_queries::district_stats(world),
+        }),
+
+        AgentCommand::History {
+            series,
+            district,
+            last,
+        } => match simulation::agent_queries::read_history(world, &series, district, last) {
+            Ok(history) => make_response(ResponsePayload::History { history }),
+            Err(message) => make_response(ResponsePayload::Error { message }),
+        },
+
+        AgentCommand::Transaction {
+            commands,
+            stop_on_error,
+        } => {
+            let mut results = Vec::with_capacity(commands.len());
+            let mut completed = true;
+            for cmd in commands {
+                let payload = match cmd {
+                    AgentCommand::Transaction { .. }
+                    | AgentCommand::Subscribe { .. }
+                    | AgentCommand::Unsubscribe { .. }
+                    | AgentCommand::Quit => ResponsePayload::Error {
+                        message: "Not allowed inside a transaction".to_string(),
+                    },
+                    cmd => process_command(cmd, world, driver).payload,
+                };
+                let failed = payload.is_failure();
+                results.push(payload);
+                if failed && stop_on_error {
+                    completed = false;
+                    break;
+                }
+            }
+            make_response(ResponsePayload::TransactionResult { results, completed })
+        }
+
+        AgentCommand::Subscribe { .. } | AgentCommand::Unsubscribe { .. } => {
+            make_response(ResponsePayload::Error {
+                message: "Subscriptions need a WebSocket connection (--serve)".to_string(),
+            })
+        }
+
+        AgentCommand::Quit => make_response(ResponsePayload::Goodbye),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Query command handler
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+fn handle_query(
+    layers: Vec<String>,
+    world: &mut bevy::ecs::world::World,
+) -> simulation::agent_protocol::AgentResponse {
+    use simulation::agent_protocol::{make_response, ResponsePayload};
+
+    let mut result = serde_json::Map::new();
+
+    // Build the world snapshot once if any snapshot-based layers are requested
+    let needs_snapshot = layers.iter().any(|l| {
+        matches!(
+            l.as_str(),
+            "buildings" | "services" | "utilities" | "roads" | "zones" | "terrain"
+        )
+    });
+
+    let snapshot = if needs_snapshot {
+        Some(simulation::world_snapshot::build_world_snapshot(world))
+    } else {
+        None
+    };
+
+    for layer in &layers {
+        match layer.as_str() {
+            "map" => {
+                let grid = world.resource::<simulation::grid::WorldGrid>();
+                let detail = simulation::ascii_map::build_detail_map(grid, 10);
+                result.insert("map".to_string(), serde_json::Value::String(detail));
+            }
+            "overview" => {
+                let grid = world.resource::<simulation::grid::WorldGrid>();
+                let overview = simulation::ascii_map::build_overview_map(grid);
+                result.insert("overview".to_string(), serde_json::Value::String(overview));
+            }
+            "buildings" => {
+                let text = simulation::world_snapshot_format::format_buildings(
+                    &snapshot.as_ref().unwrap().buildings,
+                );
+                result.insert("buildings".to_string(), serde_json::Value::String(text));
+            }
+            "services" => {
+                let text = simulation::world_snapshot_format::format_services(
+                    &snapshot.as_ref().unwrap().services,
+                );
+                result.insert("services".to_string(), serde_json::Value::String(text));
+            }
+            "utilities" => {
+                let text = simulation::world_snapshot_format::format_utilities(
+                    &snapshot.as_ref().unwrap().utilities,
+                );
+                result.insert("utilities".to_string(), serde_json::Value::String(text));
+            }
+            "roads" => {
+                let text = simulation::world_snapshot_format::format_roads_summary(
+                    &snapshot.as_ref().unwrap().road_cells,
+                );
+                result.insert("roads".to_string(), serde_json::Value::String(text));
+            }
+            "zones" => {
+                let text = simulation::world_snapshot_format::format_zones_summary(
+                    &snapshot.as_ref().unwrap().zone_regions,
+                );
+                result.insert("zones".to_string(), serde_json::Value::String(text));
+            }
+            "terrain" => {
+                let text = simulation::world_snapshot_format::format_terrain(
+                    &snapshot.as_ref().unwrap().water_regions,
+                );
+                result.insert("terrain".to_string(), serde_json::Value::String(text));
+            }
+            unknown => {
+                result.insert(
+                    unknown.to_string(),
+                    serde_json::Value::String(format!("Unknown layer: {}", unknown)),
+                );
+            }
+        }
+    }
+
+    make_response(ResponsePayload::QueryResult {
+        layers: serde_json::Value::Object(result),
+    })
+}
@@ -3,6 +3,9 @@
 //!
 //! When the `--agent` CLI flag is passed, the game skips all rendering and UI
 //! plugins and enters this loop instead of the normal Bevy `app.run()`.
+//! With `--serve <port>` as well, the headless app runs freely at 60 Hz and
+//! takes the same commands over WebSocket instead (see
+//! [`crate::remote_server`]).
 //!
 //! ## Protocol
 //!
@@ -10,25 +13,33 @@
 //! Each line of stdout is a JSON response with `"protocol_version"` and
 //! `"type"` fields. See [`simulation::agent_protocol`] for the full schema.
 
+/// Build a minimal Bevy App with simulation + save, no rendering/UI, let
+/// `configure` add to it, and run its first update.
 #[cfg(not(target_arch = "wasm32"))]
-pub fn run_agent_mode(seed: Option<u64>) {
-    use std::io::{BufRead, Write};
+pub(crate) fn build_headless_app(
+    seed: Option<u64>,
+    configure: impl FnOnce(&mut bevy::prelude::App),
+) -> bevy::prelude::App {
+    use std::time::Duration;
 
+    use bevy::app::ScheduleRunnerPlugin;
     use bevy::prelude::*;
     use bevy::state::app::StatesPlugin;
 
-    use simulation::agent_protocol::{
-        make_response, AgentCommand, ResponsePayload, PROTOCOL_VERSION,
-    };
     use simulation::app_state::AppState;
     use simulation::replay::ReplayRecorder;
     use simulation::time_of_day::GameClock;
     use simulation::tutorial::TutorialState;
     use simulation::TickCounter;
 
-    // -- Build a minimal Bevy App with simulation + save, no rendering/UI ---
     let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    // The runner only matters for `--serve`, where `app.run()` drives the
+    // loop; the stdin loop calls `app.update()` itself.
+    app.add_plugins(
+        MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+            1.0 / 60.0,
+        ))),
+    );
     app.add_plugins(StatesPlugin);
 
     // Skip the tutorial so it does not pause the GameClock.
@@ -38,6 +49,9 @@ pub fn run_agent_mode(seed: Option<u64>) {
         ..Default::default()
     });
 
+    // Headless sessions must not read or write the player's profile.
+    app.insert_resource(simulation::profile::ProfileStore::Memory);
+
     // Start directly in Playing state so simulation systems run.
     app.insert_state(AppState::Playing);
 
@@ -47,6 +61,8 @@ pub fn run_agent_mode(seed: Option<u64>) {
     // Save plugin (for future save/load support via agent commands).
     app.add_plugins(save::SavePlugin);
 
+    configure(&mut app);
+
     // Initial update so Startup systems execute and resources initialize.
     app.update();
 
@@ -73,6 +89,23 @@ pub fn run_agent_mode(seed: Option<u64>) {
         }
     }
 
+    app
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_agent_mode(seed: Option<u64>, metrics_port: Option<u16>) {
+    use std::io::{BufRead, Write};
+
+    use simulation::agent_protocol::{make_response, ResponsePayload, PROTOCOL_VERSION};
+
+    use crate::agent_commands::{parse_command, process_command, Driver};
+
+    let mut app = build_headless_app(seed, |app| {
+        if let Some(port) = metrics_port {
+            app.add_plugins(crate::metrics_server::MetricsExporterPlugin { port });
+        }
+    });
+
     // -- I/O setup -----------------------------------------------------------
     let stdin = std::io::stdin();
     let stdout = std::io::stdout();
@@ -103,25 +136,10 @@ pub fn run_agent_mode(seed: Option<u64>) {
             continue;
         }
 
-        let cmd: AgentCommand = match serde_json::from_str(&line) {
-            Ok(c) => c,
-            Err(e) => {
-                // For act/batch_act commands, return the parse error as an
-                // ActionResult so the LLM gets structured feedback about
-                // invalid parameters (e.g. unknown service_type/utility_type).
-                let resp = match try_act_parse_error_response(&line, &e) {
-                    Some(r) => r,
-                    None => make_response(ResponsePayload::Error {
-                        message: format!("Parse error: {e}"),
-                    }),
-                };
-                let _ = writeln!(stdout, "{}", serde_json::to_string(&resp).unwrap());
-                let _ = stdout.flush();
-                continue;
-            }
+        let response = match parse_command(&line) {
+            Ok(cmd) => process_command(cmd, app.world_mut(), Driver::Stepped),
+            Err(resp) => resp,
         };
-
-        let response = process_command(cmd, &mut app);
         let is_goodbye = matches!(response.payload, ResponsePayload::Goodbye);
 
         let _ = writeln!(stdout, "{}", serde_json::to_string(&response).unwrap());
@@ -135,353 +153,16 @@ pub fn run_agent_mode(seed: Option<u64>) {
     eprintln!("megacity agent mode shutting down");
 }
 
-// ---------------------------------------------------------------------------
-// Parse-error recovery for act/batch_act commands
-// ---------------------------------------------------------------------------
-
-/// When serde fails to parse an `act` or `batch_act` command (e.g. because of
-/// an unknown `service_type` or `utility_type` variant), return the error as a
-/// structured `ActionResult` instead of a generic protocol error. This gives
-/// the LLM agent useful feedback about which parameter was wrong and what the
-/// valid values are.
-#[cfg(not(target_arch = "wasm32"))]
-fn try_act_parse_error_response(
-    raw_line: &str,
-    err: &serde_json::Error,
-) -> Option<simulation::agent_protocol::AgentResponse> {
-    use simulation::agent_protocol::{make_response, ResponsePayload};
-    use simulation::game_actions::{ActionError, ActionResult};
-
-    // Quick check: only attempt recovery for act/batch_act commands.
-    let value: serde_json::Value = serde_json::from_str(raw_line).ok()?;
-    let cmd = value.get("cmd")?.as_str()?;
-
-    match cmd {
-        "act" => {
-            let message = format!("{err}");
-            let result = ActionResult::Error(ActionError::InvalidParameter(message));
-            Some(make_response(ResponsePayload::ActionResult { result }))
-        }
-        "batch_act" => {
-            let message = format!("{err}");
-            let result = ActionResult::Error(ActionError::InvalidParameter(message));
-            // Return a single error result for the whole batch since we
-            // cannot determine which action within the batch caused the
-            // parse failure.
-            Some(make_response(ResponsePayload::BatchResult {
-                results: vec![result],
-            }))
-        }
-        _ => None,
-    }
-}
-
-// ---------------------------------------------------------------------------
-// Command dispatch
-// ---------------------------------------------------------------------------
-
+/// Headless `--agent --serve <port>`: run the simulation in real time and
+/// take commands from WebSocket clients until the process is killed.
 #[cfg(not(target_arch = "wasm32"))]
-fn process_command(
-    cmd: simulation::agent_protocol::AgentCommand,
-    app: &mut bevy::app::App,
-) -> simulation::agent_protocol::AgentResponse {
-    use bevy::prelude::*;
-    use simulation::agent_protocol::{make_response, AgentCommand, ResponsePayload};
-    use simulation::game_actions::{ActionQueue, ActionSource};
-    use simulation::game_actions::{ActionResult, ActionResultLog};
-    use simulation::observation_builder::CurrentObservation;
-    use simulation::replay::{ReplayFile, ReplayPlayer, ReplayRecorder};
-    use simulation::TickCounter;
-
-    match cmd {
-        AgentCommand::Observe => {
-            // Force the SlowTickTimer to a cadence boundary so that
-            // coverage-metrics and other slow systems run on this Update.
-            {
-                let mut timer = app.world_mut().resource_mut::<simulation::SlowTickTimer>();
-                let interval = simulation::SlowTickTimer::INTERVAL;
-                timer.counter = (timer.counter / interval) * interval;
-            }
-            // Run Update schedule so coverage metrics and other Update-only
-            // systems refresh before we snapshot the observation.
-            app.world_mut().run_schedule(Update);
-            // Run one more FixedUpdate so the observation builder (PostSim)
-            // captures the freshest state.
-            app.world_mut().run_schedule(FixedUpdate);
-
-            let obs = app
-                .world()
-                .get_resource::<CurrentObservation>()
-                .map(|co| co.observation.clone())
-                .unwrap_or_default();
-            make_response(ResponsePayload::Observation {
-                observation: Box::new(obs),
-            })
-        }
-
-        AgentCommand::Act { action } => {
-            let tick = app
-                .world()
-                .get_resource::<TickCounter>()
-                .map(|t| t.0)
-                .unwrap_or(0);
-
-            app.world_mut()
-                .resource_mut::<ActionQueue>()
-                .push(tick, ActionSource::Agent, action);
-
-            // Run one FixedUpdate tick so the executor processes the action.
-            // We call run_schedule(FixedUpdate) directly (like the test harness)
-            // because app.update() in headless mode doesn't advance virtual time
-            // enough for FixedUpdate to fire.
-            app.world_mut().run_schedule(FixedUpdate);
-
-            let result = app
-                .world()
-                .get_resource::<ActionResultLog>()
-                .and_then(|log| log.last_n(1).first().map(|(_, r)| r.clone()))
-                .unwrap_or(ActionResult::Success);
-
-            make_response(ResponsePayload::ActionResult { result })
-        }
-
-        AgentCommand::BatchAct { actions } => {
-            let mut results = Vec::with_capacity(actions.len());
-            for action in actions {
-                let tick = app
-                    .world()
-                    .get_resource::<TickCounter>()
-                    .map(|t| t.0)
-                    .unwrap_or(0);
-
-                app.world_mut().resource_mut::<ActionQueue>().push(
-                    tick,
-                    ActionSource::Agent,
-                    action,
-                );
-
-                app.world_mut().run_schedule(FixedUpdate);
-
-                let result = app
-                    .world()
-                    .get_resource::<ActionResultLog>()
-                    .and_then(|log| log.last_n(1).first().map(|(_, r)| r.clone()))
-                    .unwrap_or(ActionResult::Success);
-
-                results.push(result);
-            }
-            make_response(ResponsePayload::BatchResult { results })
-        }
-
-        AgentCommand::Step { ticks } => {
-            // Cap at 10 000 ticks to prevent accidental infinite loops.
-            let n = ticks.min(10_000);
-            // Run FixedUpdate directly (same approach as the test harness).
-            // Bevy's virtual time system doesn't advance properly with
-            // MinimalPlugins in headless mode, so we bypass it entirely.
-            for _ in 0..n {
-                app.world_mut().run_schedule(FixedUpdate);
-            }
-            let tick = app
-                .world()
-                .get_resource::<TickCounter>()
-                .map(|t| t.0)
-                .unwrap_or(0);
-            make_response(ResponsePayload::StepComplete { tick })
-        }
-
-        AgentCommand::NewGame { seed } => {
-            // Reset the WorldGrid: clear all cells to default (Grass, no zone/road/building)
-            if let Some(mut grid) = app
-                .world_mut()
-                .get_resource_mut::<simulation::grid::WorldGrid>()
-            {
-                for cell in grid.cells.iter_mut() {
-                    cell.cell_type = simulation::grid::CellType::Grass;
-                    cell.zone = simulation::grid::ZoneType::None;
-                    cell.road_type = simulation::grid::RoadType::Local;
-                    cell.building_id = None;
-                    cell.elevation = 0.0;
-                    cell.has_power = false;
-                    cell.has_water = false;
-                }
-
-                // Apply procedural terrain (coastline water bodies from seed)
-                simulation::procedural_terrain::generate_terrain(&mut grid, seed);
-            }
-
-            // Reset TickCounter to 0
-            if let Some(mut tick) = app.world_mut().get_resource_mut::<TickCounter>() {
-                tick.0 = 0;
-            }
-
-            // Reset CityBudget to default (treasury = 50_000.0)
-            app.world_mut()
-                .insert_resource(simulation::economy::CityBudget::default());
-
-            // Restart the ReplayRecorder with the new seed
-            if let Some(mut recorder) = app.world_mut().get_resource_mut::<ReplayRecorder>() {
-                recorder.start(seed, "agent_session".to_string(), 0);
-            }
-
-            // Run one tick so systems settle after the reset.
-            app.world_mut().run_schedule(FixedUpdate);
-
-            make_response(ResponsePayload::Ok)
-        }
-
-        AgentCommand::SaveReplay { path } => {
-            let tick = app
-                .world()
-                .get_resource::<TickCounter>()
-                .map(|t| t.0)
-                .unwrap_or(0);
-
-            let replay_file = app
-                .world_mut()
-                .get_resource_mut::<ReplayRecorder>()
-                .map(|mut recorder| recorder.stop(tick, 0));
-
-            match replay_file {
-                Some(replay) => {
-                    let json = replay.to_json();
-                    if let Err(e) = std::fs::write(&path, json) {
-                        make_response(ResponsePayload::Error {
-                            message: format!("Failed to write replay to {path}: {e}"),
-                        })
-                    } else {
-                        make_response(ResponsePayload::Ok)
-                    }
-                }
-                None => make_response(ResponsePayload::Error {
-                    message: "ReplayRecorder resource not found".to_string(),
-                }),
-            }
-        }
-
-        AgentCommand::LoadReplay { path } => {
-            let contents = match std::fs::read_to_string(&path) {
-                Ok(c) => c,
-                Err(e) => {
-                    return make_response(ResponsePayload::Error {
-                        message: format!("Failed to read replay from {path}: {e}"),
-                    });
-                }
-            };
-
-            let replay = match ReplayFile::from_json(&contents) {
-                Ok(r) => r,
-                Err(e) => {
-                    return make_response(ResponsePayload::Error {
-                        message: format!("Failed to parse replay: {e}"),
-                    });
-                }
-            };
-
-            match app.world_mut().get_resource_mut::<ReplayPlayer>() {
-                Some(mut player) => {
-                    player.load(replay);
-                    make_response(ResponsePayload::Ok)
-                }
-                None => make_response(ResponsePayload::Error {
-                    message: "ReplayPlayer resource not found".to_string(),
-                }),
-            }
+pub fn run_agent_server(seed: Option<u64>, port: u16, metrics_port: Option<u16>) {
+    let mut app = build_headless_app(seed, |app| {
+        app.add_plugins(crate::remote_server::RemoteControlPlugin { port });
+        if let Some(port) = metrics_port {
+            app.add_plugins(crate::metrics_server::MetricsExporterPlugin { port });
         }
-
-        AgentCommand::Query { layers } => handle_query(layers, app),
-
-        AgentCommand::Quit => make_response(ResponsePayload::Goodbye),
-    }
-}
-
-// ---------------------------------------------------------------------------
-// Query command handler
-// ---------------------------------------------------------------------------
-
-#[cfg(not(target_arch = "wasm32"))]
-fn handle_query(
-    layers: Vec<String>,
-    app: &mut bevy::app::App,
-) -> simulation::agent_protocol::AgentResponse {
-    use simulation::agent_protocol::{make_response, ResponsePayload};
-
-    let mut result = serde_json::Map::new();
-
-    // Build the world snapshot once if any snapshot-based layers are requested
-    let needs_snapshot = layers.iter().any(|l| {
-        matches!(
-            l.as_str(),
-            "buildings" | "services" | "utilities" | "roads" | "zones" | "terrain"
-        )
     });
-
-    let snapshot = if needs_snapshot {
-        Some(simulation::world_snapshot::build_world_snapshot(
-            app.world_mut(),
-        ))
-    } else {
-        None
-    };
-
-    for layer in &layers {
-        match layer.as_str() {
-            "map" => {
-                let grid = app.world().resource::<simulation::grid::WorldGrid>();
-                let detail = simulation::ascii_map::build_detail_map(grid, 10);
-                result.insert("map".to_string(), serde_json::Value::String(detail));
-            }
-            "overview" => {
-                let grid = app.world().resource::<simulation::grid::WorldGrid>();
-                let overview = simulation::ascii_map::build_overview_map(grid);
-                result.insert("overview".to_string(), serde_json::Value::String(overview));
-            }
-            "buildings" => {
-                let text = simulation::world_snapshot_format::format_buildings(
-                    &snapshot.as_ref().unwrap().buildings,
-                );
-                result.insert("buildings".to_string(), serde_json::Value::String(text));
-            }
-            "services" => {
-                let text = simulation::world_snapshot_format::format_services(
-                    &snapshot.as_ref().unwrap().services,
-                );
-                result.insert("services".to_string(), serde_json::Value::String(text));
-            }
-            "utilities" => {
-                let text = simulation::world_snapshot_format::format_utilities(
-                    &snapshot.as_ref().unwrap().utilities,
-                );
-                result.insert("utilities".to_string(), serde_json::Value::String(text));
-            }
-            "roads" => {
-                let text = simulation::world_snapshot_format::format_roads_summary(
-                    &snapshot.as_ref().unwrap().road_cells,
-                );
-                result.insert("roads".to_string(), serde_json::Value::String(text));
-            }
-            "zones" => {
-                let text = simulation::world_snapshot_format::format_zones_summary(
-                    &snapshot.as_ref().unwrap().zone_regions,
-                );
-                result.insert("zones".to_string(), serde_json::Value::String(text));
-            }
-            "terrain" => {
-                let text = simulation::world_snapshot_format::format_terrain(
-                    &snapshot.as_ref().unwrap().water_regions,
-                );
-                result.insert("terrain".to_string(), serde_json::Value::String(text));
-            }
-            unknown => {
-                result.insert(
-                    unknown.to_string(),
-                    serde_json::Value::String(format!("Unknown layer: {}", unknown)),
-                );
-            }
-        }
-    }
-
-    make_response(ResponsePayload::QueryResult {
-        layers: serde_json::Value::Object(result),
-    })
+    eprintln!("megacity agent server running headless");
+    app.run();
 }
@@ -0,0 +1,159 @@
+//! The `--batch` config file and the runs its sweep expands to.
+
+use serde::Deserialize;
+
+use simulation::city_observation::CityObservation;
+use simulation::policies::Policy;
+
+use crate::bench_mode::Scenario;
+
+/// Where the CSV goes unless the config says otherwise.
+const DEFAULT_OUTPUT: &str = "batch_results.csv";
+
+/// A batch of headless runs, as read from TOML.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BatchConfig {
+    /// Starting city: `empty` or `tel_aviv`.
+    #[serde(default = "default_city")]
+    pub city: String,
+    /// Ticks each run simulates.
+    pub ticks: u64,
+    #[serde(default = "default_output")]
+    pub output: String,
+    /// Runs simulated at the same time.
+    #[serde(default = "default_jobs")]
+    pub jobs: usize,
+    /// Whether random disasters can strike, as in the game's settings.
+    #[serde(default = "default_disasters")]
+    pub disasters: bool,
+    /// Dotted paths into the agent observation, e.g. `population.total`.
+    pub metrics: Vec<String>,
+    #[serde(default)]
+    pub sweep: Sweep,
+}
+
+/// Parameter values to sweep; every combination becomes one run.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Sweep {
+    /// Seeds for the simulation RNG and the disaster sequence.
+    #[serde(default)]
+    pub seeds: Vec<u64>,
+    /// Tax rate applied to every zone. Empty keeps the game's defaults.
+    #[serde(default)]
+    pub tax_rates: Vec<f32>,
+    /// Sets of policies enacted at the start of a run.
+    #[serde(default)]
+    pub policies: Vec<Vec<Policy>>,
+}
+
+/// One point of the sweep.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RunParams {
+    pub seed: u64,
+    pub tax_rate: Option<f32>,
+    pub policies: Vec<Policy>,
+}
+
+fn default_city() -> String {
+    Scenario::Empty.name().to_string()
+}
+
+fn default_output() -> String {
+    DEFAULT_OUTPUT.to_string()
+}
+
+fn default_jobs() -> usize {
+    1
+}
+
+fn default_disasters() -> bool {
+    true
+}
+
+impl BatchConfig {
+    /// Parse and validate a config file's contents.
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(text).map_err(|e| format!("invalid config: {e}"))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn scenario(&self) -> Scenario {
+        Scenario::from_name(&self.city).expect("validated")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        Scenario::from_name(&self.city).ok_or_else(|| Scenario::unknown(&self.city))?;
+        if self.ticks == 0 {
+            return Err("ticks must be at least 1".to_string());
+        }
+        if self.jobs == 0 {
+            return Err("jobs must be at least 1".to_string());
+        }
+        if self.metrics.is_empty() {
+            return Err("list at least one metric".to_string());
+        }
+        let observation = serde_json::to_value(CityObservation::default()).unwrap();
+        for metric in &self.metrics {
+            metric_value(&observation, metric)?;
+        }
+        if let Some(rate) = self
+            .sweep
+            .tax_rates
+            .iter()
+            .find(|r| !(0.0..=1.0).contains(*r))
+        {
+            return Err(format!("tax rate {rate} is outside 0..1"));
+        }
+        Ok(())
+    }
+
+    /// Every combination of the swept values, seeds varying fastest.
+    pub fn runs(&self) -> Vec<RunParams> {
+        let seeds = non_empty(&self.sweep.seeds, 0);
+        let tax_rates: Vec<Option<f32>> = if self.sweep.tax_rates.is_empty() {
+            vec![None]
+        } else {
+            self.sweep.tax_rates.iter().copied().map(Some).collect()
+        };
+        let policy_sets = non_empty(&self.sweep.policies, Vec::new());
+
+        let mut runs = Vec::new();
+        for policies in &policy_sets {
+            for &tax_rate in &tax_rates {
+                for &seed in &seeds {
+                    runs.push(RunParams {
+                        seed,
+                        tax_rate,
+                        policies: policies.clone(),
+                    });
+                }
+            }
+        }
+        runs
+    }
+}
+
+fn non_empty<T: Clone>(values: &[T], default: T) -> Vec<T> {
+    if values.is_empty() {
+        vec![default]
+    } else {
+        values.to_vec()
+    }
+}
+
+/// Read a numeric metric from a serialized observation. Booleans count as
+/// 0 or 1.
+pub(crate) fn metric_value(observation: &serde_json::Value, metric: &str) -> Result<f64, String> {
+    let pointer = format!("/{}", metric.replace('.', "/"));
+    let value = observation
+        .pointer(&pointer)
+        .ok_or_else(|| format!("unknown metric '{metric}'"))?;
+    match value {
+        serde_json::Value::Number(n) => Ok(n.as_f64().unwrap_or(0.0)),
+        serde_json::Value::Bool(b) => Ok(if *b { 1.0 } else { 0.0 }),
+        _ => Err(format!("metric '{metric}' is not a number")),
+    }
+}
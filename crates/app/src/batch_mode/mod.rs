@@ -0,0 +1,186 @@
+//! Headless `--batch <config.toml>` mode: run one simulation per point of a
+//! parameter sweep and collect chosen metrics from each into a CSV, for
+//! balancing passes and research.
+//!
+//! ```toml
+//! city = "tel_aviv"                 # or "empty" (the default)
+//! ticks = 5000
+//! output = "tax_sweep.csv"          # default "batch_results.csv"
+//! jobs = 4                          # runs simulated at once (default 1)
+//! disasters = true                  # random disasters on (the default)
+//! metrics = ["population.total", "treasury", "happiness.overall"]
+//!
+//! [sweep]
+//! seeds = [1, 2, 3]
+//! tax_rates = [0.08, 0.10, 0.12]
+//! policies = [[], ["RecyclingProgram"], ["EducationPush", "MinimumWage"]]
+//! ```
+//!
+//! Every combination of the swept values is one run (27 above). A run
+//! builds a fresh headless city, seeds the simulation RNG and the disaster
+//! sequence with its seed, sets every zone's tax rate, enacts its policies
+//! and simulates `ticks` ticks back to back. Metrics are dotted paths into
+//! the agent observation (`simulation::city_observation::CityObservation`),
+//! read once at the end. Each CSV row holds the run's index, its parameters
+//! and its metrics.
+
+mod config;
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+
+use simulation::disasters::DisasterSeed;
+use simulation::game_actions::{ActionQueue, ActionSource, GameAction};
+use simulation::observation_builder::CurrentObservation;
+use simulation::policies::{Policies, Policy};
+use simulation::sim_rng::SimRng;
+use simulation::weather::Weather;
+use simulation::{SlowTickTimer, TickCounter};
+
+use crate::agent_mode::build_headless_app;
+use config::{metric_value, BatchConfig, RunParams};
+
+/// Run every simulation the config at `config_path` describes and write
+/// the results CSV.
+pub fn run_batch_mode(config_path: &str) -> Result<(), String> {
+    let text = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("failed to read {config_path}: {e}"))?;
+    let config = BatchConfig::from_toml(&text)?;
+    let runs = config.runs();
+    eprintln!(
+        "Running {} simulations of {} ticks on '{}' ({} at a time)",
+        runs.len(),
+        config.ticks,
+        config.city,
+        config.jobs
+    );
+
+    let results: Mutex<Vec<Option<Vec<f64>>>> = Mutex::new(vec![None; runs.len()]);
+    let next = AtomicUsize::new(0);
+    let failure: Mutex<Option<String>> = Mutex::new(None);
+    std::thread::scope(|scope| {
+        for _ in 0..config.jobs.min(runs.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= runs.len() || failure.lock().unwrap().is_some() {
+                    return;
+                }
+                match run_one(&config, &runs[index]) {
+                    Ok(metrics) => {
+                        eprintln!("Run {}/{} done", index + 1, runs.len());
+                        results.lock().unwrap()[index] = Some(metrics);
+                    }
+                    Err(e) => {
+                        *failure.lock().unwrap() = Some(format!("run {}: {e}", index + 1));
+                    }
+                }
+            });
+        }
+    });
+    if let Some(e) = failure.into_inner().unwrap() {
+        return Err(e);
+    }
+
+    let results: Vec<Vec<f64>> = results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect();
+    let csv = to_csv(&config.metrics, &runs, &results);
+    std::fs::write(&config.output, csv)
+        .map_err(|e| format!("failed to write {}: {e}", config.output))?;
+    eprintln!("Wrote {}", config.output);
+    Ok(())
+}
+
+/// Simulate one run and read its metrics.
+fn run_one(config: &BatchConfig, run: &RunParams) -> Result<Vec<f64>, String> {
+    let scenario = config.scenario();
+    let mut app = build_headless_app(Some(run.seed), |app| scenario.configure(app));
+    let world = app.world_mut();
+
+    world.insert_resource(SimRng::from_seed_u64(run.seed));
+    world.insert_resource(DisasterSeed(run.seed));
+    world.resource_mut::<Weather>().disasters_enabled = config.disasters;
+    if let Some(rate) = run.tax_rate {
+        let tick = world.resource::<TickCounter>().0;
+        world.resource_mut::<ActionQueue>().push(
+            tick,
+            ActionSource::Agent,
+            GameAction::SetTaxRates {
+                residential: rate,
+                commercial: rate,
+                industrial: rate,
+                office: rate,
+            },
+        );
+    }
+    {
+        let mut policies = world.resource_mut::<Policies>();
+        for &policy in &run.policies {
+            if !policies.is_active(policy) {
+                policies.toggle(policy);
+            }
+        }
+    }
+
+    for _ in 0..config.ticks {
+        world.run_schedule(FixedUpdate);
+    }
+
+    // Refresh the slow and Update-only systems before reading the
+    // observation, as the agent `observe` command does.
+    {
+        let mut timer = world.resource_mut::<SlowTickTimer>();
+        timer.counter = (timer.counter / SlowTickTimer::INTERVAL) * SlowTickTimer::INTERVAL;
+    }
+    world.run_schedule(Update);
+    world.run_schedule(FixedUpdate);
+
+    let observation = serde_json::to_value(&world.resource::<CurrentObservation>().observation)
+        .map_err(|e| e.to_string())?;
+    config
+        .metrics
+        .iter()
+        .map(|metric| metric_value(&observation, metric))
+        .collect()
+}
+
+/// One header row, then one row per run.
+fn to_csv(metrics: &[String], runs: &[RunParams], results: &[Vec<f64>]) -> String {
+    let mut csv = String::from("run,seed,tax_rate,policies");
+    for metric in metrics {
+        csv.push(',');
+        csv.push_str(metric);
+    }
+    csv.push('\n');
+    for (index, (run, values)) in runs.iter().zip(results).enumerate() {
+        let tax_rate = run.tax_rate.map(|r| r.to_string()).unwrap_or_default();
+        let _ = write!(
+            csv,
+            "{},{},{},{}",
+            index + 1,
+            run.seed,
+            tax_rate,
+            policy_list(&run.policies)
+        );
+        for value in values {
+            let _ = write!(csv, ",{value}");
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Policies joined with `+`, so the list stays one CSV field.
+fn policy_list(policies: &[Policy]) -> String {
+    policies
+        .iter()
+        .map(|p| format!("{p:?}"))
+        .collect::<Vec<_>>()
+        .join("+")
+}
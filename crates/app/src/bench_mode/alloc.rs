@@ -0,0 +1,98 @@
+//! A global allocator that counts allocations while a benchmark runs.
+//!
+//! Counting is off unless [`start`] has been called, so the normal game only
+//! pays one relaxed atomic load per allocation.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed};
+
+/// The system allocator, plus counters.
+pub(crate) struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES_ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes allocated minus bytes freed since counting started; negative when
+/// more older memory was freed than new memory allocated.
+static NET_BYTES: AtomicI64 = AtomicI64::new(0);
+static PEAK_NET_BYTES: AtomicI64 = AtomicI64::new(0);
+
+/// Allocation counts over a measured run.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AllocStats {
+    /// Allocations, counting each `realloc` as one.
+    pub allocations: u64,
+    pub bytes_allocated: u64,
+    /// Growth in live heap memory over the run.
+    pub net_bytes: i64,
+    /// Highest live heap growth reached during the run.
+    pub peak_net_bytes: i64,
+}
+
+/// Reset the counters and start counting.
+pub(crate) fn start() {
+    ALLOCATIONS.store(0, Relaxed);
+    BYTES_ALLOCATED.store(0, Relaxed);
+    NET_BYTES.store(0, Relaxed);
+    PEAK_NET_BYTES.store(0, Relaxed);
+    ENABLED.store(true, Relaxed);
+}
+
+/// Stop counting and return the counts since [`start`].
+pub(crate) fn stop() -> AllocStats {
+    ENABLED.store(false, Relaxed);
+    AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed),
+        bytes_allocated: BYTES_ALLOCATED.load(Relaxed),
+        net_bytes: NET_BYTES.load(Relaxed),
+        peak_net_bytes: PEAK_NET_BYTES.load(Relaxed),
+    }
+}
+
+fn record_alloc(size: usize) {
+    if !ENABLED.load(Relaxed) {
+        return;
+    }
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES_ALLOCATED.fetch_add(size as u64, Relaxed);
+    let net = NET_BYTES.fetch_add(size as i64, Relaxed) + size as i64;
+    PEAK_NET_BYTES.fetch_max(net, Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    if ENABLED.load(Relaxed) {
+        NET_BYTES.fetch_sub(size as i64, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
@@ -0,0 +1,113 @@
+//! Headless `--bench <scenario> --ticks N` mode: run a fixed scenario for a
+//! fixed number of ticks and report how long they took.
+//!
+//! The report covers tick times (mean and percentiles), the mean time of
+//! each `FixedUpdate` phase, per-system times from the spans of
+//! `--features trace` builds, allocation counts, and the city's
+//! state at the end. It is printed as markdown and written as JSON to
+//! `--report <path>` (default `bench_report.json`), so CI can diff runs and
+//! catch regressions in pathfinding, traffic or LOD numerically.
+//!
+//! Ticks run back to back through `FixedUpdate`, as in the stdin agent mode,
+//! after a short warm-up that is left out of the report.
+
+pub(crate) mod alloc;
+mod report;
+
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use simulation::sim_metrics::SimMetrics;
+
+use crate::agent_mode::build_headless_app;
+use crate::span_timer;
+use report::BenchReport;
+
+/// Ticks run before measuring, so startup work isn't counted.
+const WARMUP_TICKS: u64 = 10;
+
+/// Where the JSON report goes unless `--report` says otherwise.
+pub const DEFAULT_REPORT_PATH: &str = "bench_report.json";
+
+/// The fixed cities a benchmark (or a `--batch` run) can start from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Scenario {
+    /// A blank map: the fixed cost of a tick.
+    Empty,
+    /// The prebuilt Tel Aviv map with its ~10K citizens.
+    TelAviv,
+}
+
+impl Scenario {
+    const ALL: [Scenario; 2] = [Scenario::Empty, Scenario::TelAviv];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Scenario::Empty => "empty",
+            Scenario::TelAviv => "tel_aviv",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.name() == name)
+    }
+
+    /// Error for an unknown scenario name, listing the available ones.
+    pub(crate) fn unknown(name: &str) -> String {
+        let names: Vec<_> = Self::ALL.iter().map(|s| s.name()).collect();
+        format!(
+            "unknown scenario '{name}' (available: {})",
+            names.join(", ")
+        )
+    }
+
+    /// Add what the scenario needs to a headless app before its first update.
+    pub(crate) fn configure(self, app: &mut App) {
+        if self == Scenario::TelAviv {
+            app.add_systems(Startup, simulation::world_init::init_world);
+        }
+    }
+}
+
+/// Run `scenario` for `ticks` ticks, print the markdown report and write the
+/// JSON one to `report_path`.
+pub fn run_bench_mode(scenario: &str, ticks: u64, report_path: &str) -> Result<(), String> {
+    let scenario = Scenario::from_name(scenario).ok_or_else(|| Scenario::unknown(scenario))?;
+    let spans = span_timer::install()?;
+
+    eprintln!("Building the '{}' scenario", scenario.name());
+    let mut app = build_headless_app(Some(0), |app| {
+        app.init_resource::<SimMetrics>();
+        scenario.configure(app);
+    });
+    let world = app.world_mut();
+    for _ in 0..WARMUP_TICKS {
+        world.run_schedule(FixedUpdate);
+    }
+
+    eprintln!("Running {ticks} ticks");
+    world.insert_resource(SimMetrics::default());
+    spans.reset();
+    alloc::start();
+    let mut tick_times = Vec::with_capacity(ticks as usize);
+    for _ in 0..ticks {
+        let start = Instant::now();
+        world.run_schedule(FixedUpdate);
+        tick_times.push(start.elapsed());
+    }
+    let alloc = alloc::stop();
+
+    let report = BenchReport {
+        scenario: scenario.name(),
+        warmup_ticks: WARMUP_TICKS,
+        tick_times,
+        metrics: world.resource::<SimMetrics>().clone(),
+        systems: spans.sorted(),
+        alloc,
+    };
+    let json = serde_json::to_string_pretty(&report.to_json()).unwrap();
+    std::fs::write(report_path, json).map_err(|e| format!("failed to write {report_path}: {e}"))?;
+    println!("{}", report.to_markdown());
+    eprintln!("Wrote {report_path}");
+    Ok(())
+}
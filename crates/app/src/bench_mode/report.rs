@@ -0,0 +1,220 @@
+//! The benchmark report and its JSON and markdown forms.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde_json::json;
+
+use simulation::sim_metrics::{SimMetrics, PHASES};
+
+use super::alloc::AllocStats;
+use crate::span_timer::SpanStat;
+
+/// Systems listed in the markdown report; the JSON has all of them.
+const MARKDOWN_SYSTEMS: usize = 20;
+
+/// Everything measured by one benchmark run.
+pub(crate) struct BenchReport {
+    pub scenario: &'static str,
+    pub warmup_ticks: u64,
+    /// Wall-clock time of each measured tick, in order.
+    pub tick_times: Vec<Duration>,
+    /// State after the last tick, with phase totals over the measured ticks.
+    pub metrics: SimMetrics,
+    pub systems: Vec<(String, SpanStat)>,
+    pub alloc: AllocStats,
+}
+
+/// Summary statistics of the tick times, in milliseconds.
+struct TickSummary {
+    mean: f64,
+    p50: f64,
+    p95: f64,
+    p99: f64,
+    max: f64,
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl BenchReport {
+    fn wall(&self) -> Duration {
+        self.tick_times.iter().sum()
+    }
+
+    fn ticks(&self) -> u64 {
+        self.tick_times.len() as u64
+    }
+
+    fn summary(&self) -> TickSummary {
+        let mut sorted = self.tick_times.clone();
+        sorted.sort();
+        let percentile = |p: f64| {
+            let i = ((sorted.len() as f64 * p).ceil() as usize).clamp(1, sorted.len().max(1));
+            sorted.get(i - 1).copied().map_or(0.0, ms)
+        };
+        TickSummary {
+            mean: ms(self.wall()) / self.ticks().max(1) as f64,
+            p50: percentile(0.50),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+            max: sorted.last().copied().map_or(0.0, ms),
+        }
+    }
+
+    fn ticks_per_second(&self) -> f64 {
+        let wall = self.wall().as_secs_f64();
+        if wall > 0.0 {
+            self.ticks() as f64 / wall
+        } else {
+            0.0
+        }
+    }
+
+    /// Mean milliseconds per tick spent in each `FixedUpdate` phase.
+    fn phase_means(&self) -> [f64; 3] {
+        let ticks = self.metrics.ticks_measured.max(1) as f64;
+        self.metrics
+            .phase_seconds_total
+            .map(|seconds| seconds * 1000.0 / ticks)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let summary = self.summary();
+        let phases: serde_json::Map<String, serde_json::Value> = PHASES
+            .iter()
+            .zip(self.phase_means())
+            .map(|(phase, mean)| (phase.to_string(), json!(mean)))
+            .collect();
+        let systems: Vec<serde_json::Value> = self
+            .systems
+            .iter()
+            .map(|(name, stat)| {
+                json!({
+                    "name": name,
+                    "calls": stat.calls,
+                    "total_ms": ms(stat.total),
+                    "mean_ms": ms(stat.total) / stat.calls.max(1) as f64,
+                    "max_ms": ms(stat.max),
+                })
+            })
+            .collect();
+        json!({
+            "scenario": self.scenario,
+            "warmup_ticks": self.warmup_ticks,
+            "ticks": self.ticks(),
+            "wall_seconds": self.wall().as_secs_f64(),
+            "ticks_per_second": self.ticks_per_second(),
+            "tick_ms": {
+                "mean": summary.mean,
+                "p50": summary.p50,
+                "p95": summary.p95,
+                "p99": summary.p99,
+                "max": summary.max,
+            },
+            "phase_mean_ms": phases,
+            "systems": systems,
+            "allocations": {
+                "count": self.alloc.allocations,
+                "bytes": self.alloc.bytes_allocated,
+                "per_tick": self.alloc.allocations as f64 / self.ticks().max(1) as f64,
+                "net_bytes": self.alloc.net_bytes,
+                "peak_net_bytes": self.alloc.peak_net_bytes,
+            },
+            "final": {
+                "tick": self.metrics.tick,
+                "entities": self.metrics.entities,
+                "citizen_entities": self.metrics.citizen_entities,
+                "population": self.metrics.population,
+                "treasury": self.metrics.treasury,
+                "path_requests_queued": self.metrics.path_requests_queued,
+                "paths_computing": self.metrics.paths_computing,
+            },
+        })
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let summary = self.summary();
+        let mut out = String::new();
+        let _ = writeln!(out, "# Benchmark: `{}`\n", self.scenario);
+        let _ = writeln!(
+            out,
+            "{} ticks after {} warm-up ticks in {:.2} s ({:.1} ticks/s)\n",
+            self.ticks(),
+            self.warmup_ticks,
+            self.wall().as_secs_f64(),
+            self.ticks_per_second()
+        );
+
+        let _ = writeln!(out, "## Tick time (ms)\n");
+        let _ = writeln!(out, "| mean | p50 | p95 | p99 | max |");
+        let _ = writeln!(out, "|---:|---:|---:|---:|---:|");
+        let _ = writeln!(
+            out,
+            "| {:.3} | {:.3} | {:.3} | {:.3} | {:.3} |\n",
+            summary.mean, summary.p50, summary.p95, summary.p99, summary.max
+        );
+
+        let _ = writeln!(out, "## Phases (mean ms per tick)\n");
+        let _ = writeln!(out, "| phase | ms |");
+        let _ = writeln!(out, "|---|---:|");
+        for (phase, mean) in PHASES.iter().zip(self.phase_means()) {
+            let _ = writeln!(out, "| {phase} | {mean:.3} |");
+        }
+
+        let _ = writeln!(out, "\n## Systems\n");
+        if self.systems.is_empty() {
+            let _ = writeln!(
+                out,
+                "No per-system timings: build with `--features trace` to record them.\n"
+            );
+        } else {
+            let _ = writeln!(out, "| system | calls | total ms | mean ms | max ms |");
+            let _ = writeln!(out, "|---|---:|---:|---:|---:|");
+            for (name, stat) in self.systems.iter().take(MARKDOWN_SYSTEMS) {
+                let _ = writeln!(
+                    out,
+                    "| {name} | {} | {:.3} | {:.3} | {:.3} |",
+                    stat.calls,
+                    ms(stat.total),
+                    ms(stat.total) / stat.calls.max(1) as f64,
+                    ms(stat.max)
+                );
+            }
+            let _ = writeln!(out);
+        }
+
+        let _ = writeln!(out, "## Allocations\n");
+        let _ = writeln!(
+            out,
+            "| count | per tick | MiB allocated | net MiB | peak net MiB |"
+        );
+        let _ = writeln!(out, "|---:|---:|---:|---:|---:|");
+        let mib = |bytes: f64| bytes / (1024.0 * 1024.0);
+        let _ = writeln!(
+            out,
+            "| {} | {:.0} | {:.1} | {:.1} | {:.1} |\n",
+            self.alloc.allocations,
+            self.alloc.allocations as f64 / self.ticks().max(1) as f64,
+            mib(self.alloc.bytes_allocated as f64),
+            mib(self.alloc.net_bytes as f64),
+            mib(self.alloc.peak_net_bytes as f64)
+        );
+
+        let _ = writeln!(out, "## Final state\n");
+        let _ = writeln!(
+            out,
+            "tick {}, {} entities ({} citizens), population {}, treasury {:.0}, \
+             pathfinding {} queued / {} computing",
+            self.metrics.tick,
+            self.metrics.entities,
+            self.metrics.citizen_entities,
+            self.metrics.population,
+            self.metrics.treasury,
+            self.metrics.path_requests_queued,
+            self.metrics.paths_computing
+        );
+        out
+    }
+}
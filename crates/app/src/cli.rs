@@ -0,0 +1,115 @@
+//! Command-line modes that run in place of the game window.
+//!
+//! `main` hands the arguments to [`run_command`] first; when one of these
+//! flags is present it does its work and the app exits without opening a
+//! window:
+//!
+//! - `--export-save <in> <out.json>` / `--import-save <in.json> <out>`
+//! - `--convert-replay <in> <out>`
+//! - `--bench <scenario> [--ticks N] [--report <path>]`
+//! - `--batch <config.toml>`
+//! - `--agent [--seed N] [--serve <port>] [--metrics <port>]`
+
+use crate::{agent_mode, batch_mode, bench_mode};
+
+/// The value following `flag`, if present.
+pub(crate) fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.windows(2)
+        .find(|w| w[0] == flag)
+        .map(|w| w[1].as_str())
+}
+
+/// The port following `flag` (`--serve`, `--metrics`), if present and valid.
+pub(crate) fn port_arg(args: &[String], flag: &str) -> Option<u16> {
+    flag_value(args, flag).and_then(|v| v.parse().ok())
+}
+
+/// Run the command-line mode `args` asks for. Returns `true` when one ran
+/// and the app should exit; exits the process with status 1 if it failed.
+pub(crate) fn run_command(args: &[String]) -> bool {
+    // -- Save conversion: `--export-save <in> <out.json>` and
+    // `--import-save <in.json> <out>` convert and exit ----------------------
+    if let Some(w) = args
+        .windows(3)
+        .find(|w| w[0] == "--export-save" || w[0] == "--import-save")
+    {
+        let result = if w[0] == "--export-save" {
+            save::save_json::export_save_file(&w[1], &w[2])
+        } else {
+            save::save_json::import_save_file(&w[1], &w[2])
+        };
+        match result {
+            Ok(()) => println!("Wrote {}", w[2]),
+            Err(e) => fail(&w[0], e),
+        }
+        return true;
+    }
+
+    // -- Replay conversion: `--convert-replay <in> <out>` writes JSON when
+    // `out` ends in `.json` and the binary container otherwise --------------
+    if let Some(w) = args.windows(3).find(|w| w[0] == "--convert-replay") {
+        if let Err(e) = convert_replay_file(&w[1], &w[2]) {
+            fail("--convert-replay", e);
+        }
+        println!("Wrote {}", w[2]);
+        return true;
+    }
+
+    // -- Benchmark mode: `--bench <scenario> [--ticks N] [--report <path>]`
+    // runs a fixed scenario headless and reports timings --------------------
+    if let Some(scenario) = flag_value(args, "--bench") {
+        let ticks: u64 = flag_value(args, "--ticks")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(1000);
+        let report_path = flag_value(args, "--report").unwrap_or(bench_mode::DEFAULT_REPORT_PATH);
+        if let Err(e) = bench_mode::run_bench_mode(scenario, ticks, report_path) {
+            fail("--bench", e);
+        }
+        return true;
+    }
+
+    // -- Batch mode: `--batch <config.toml>` runs a parameter sweep headless
+    // and writes one CSV row of metrics per run -----------------------------
+    if let Some(config) = flag_value(args, "--batch") {
+        if let Err(e) = batch_mode::run_batch_mode(config) {
+            fail("--batch", e);
+        }
+        return true;
+    }
+
+    // -- Agent mode: headless JSON protocol over stdin/stdout, or over
+    // WebSocket with `--serve <port>` ---------------------------------------
+    if args.iter().any(|a| a == "--agent") {
+        let seed: Option<u64> = flag_value(args, "--seed").and_then(|v| v.parse().ok());
+        let metrics_port = port_arg(args, "--metrics");
+        match port_arg(args, "--serve") {
+            Some(port) => agent_mode::run_agent_server(seed, port, metrics_port),
+            None => agent_mode::run_agent_mode(seed, metrics_port),
+        }
+        return true;
+    }
+
+    false
+}
+
+fn fail(flag: &str, error: impl std::fmt::Display) -> ! {
+    eprintln!("{flag} failed: {error}");
+    std::process::exit(1);
+}
+
+/// Convert a replay between the JSON and binary encodings.
+fn convert_replay_file(input: &str, output: &str) -> Result<(), String> {
+    use simulation::replay::{read_replay, write_replay, ReplayEncoding};
+
+    let bytes = std::fs::read(input).map_err(|e| format!("failed to read {input}: {e}"))?;
+    let loaded = read_replay(&bytes)?;
+    let encoding = ReplayEncoding::for_path(output);
+    if encoding == ReplayEncoding::Json && !loaded.checkpoints.is_empty() {
+        println!(
+            "Note: JSON replays cannot hold checkpoints; dropping {}",
+            loaded.checkpoints.len()
+        );
+    }
+    std::fs::write(output, write_replay(&loaded, encoding))
+        .map_err(|e| format!("failed to write {output}: {e}"))
+}
@@ -15,52 +15,67 @@ use bevy::render::view::screenshot::{save_to_disk, Screenshot};
 #[cfg(not(target_arch = "wasm32"))]
 use rendering::camera::OrbitCamera;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod agent_commands;
 #[cfg(not(target_arch = "wasm32"))]
 mod agent_mode;
 #[cfg(not(target_arch = "wasm32"))]
+mod batch_mode;
+#[cfg(not(target_arch = "wasm32"))]
+mod bench_mode;
+#[cfg(not(target_arch = "wasm32"))]
+mod cli;
+#[cfg(not(target_arch = "wasm32"))]
+mod metrics_server;
+#[cfg(not(target_arch = "wasm32"))]
 mod record_replay;
+#[cfg(not(target_arch = "wasm32"))]
+mod remote_server;
+#[cfg(not(target_arch = "wasm32"))]
+mod span_timer;
 #[cfg(target_arch = "wasm32")]
 mod web_replay;
 
+/// Counts allocations for `--bench` reports; a plain pass-through otherwise.
+#[cfg(not(target_arch = "wasm32"))]
+#[global_allocator]
+static ALLOCATOR: bench_mode::alloc::CountingAllocator = bench_mode::alloc::CountingAllocator;
+
 fn main() {
     // -- CLI argument parsing -------------------------------------------------
     let args: Vec<String> = std::env::args().collect();
-    let is_agent = args.iter().any(|a| a == "--agent");
 
-    // -- Agent mode: headless JSON protocol over stdin/stdout ----------------
+    // Conversions, `--bench`, `--batch` and `--agent` run without a window
+    // and exit (native only).
     #[cfg(not(target_arch = "wasm32"))]
-    if is_agent {
-        // Parse optional --seed <N> for agent mode (native only).
-        let seed: Option<u64> = args
-            .windows(2)
-            .find(|w| w[0] == "--seed")
-            .and_then(|w| w[1].parse().ok());
-        agent_mode::run_agent_mode(seed);
+    if cli::run_command(&args) {
         return;
     }
     #[cfg(target_arch = "wasm32")]
-    if is_agent {
+    if args.iter().any(|a| a == "--agent") {
         panic!("Agent mode is not supported on WASM");
     }
 
+    // `--serve <port>`: accept agent protocol commands over WebSocket.
+    #[cfg(not(target_arch = "wasm32"))]
+    let serve_port = cli::port_arg(&args, "--serve");
+
+    // `--metrics <port>`: serve Prometheus metrics over HTTP.
+    #[cfg(not(target_arch = "wasm32"))]
+    let metrics_port = cli::port_arg(&args, "--metrics");
+
     // Parse replay source for graphical playback.
     // Native: `--replay <path>`
     // WASM:   `?replay=<url>`
     #[cfg(not(target_arch = "wasm32"))]
-    let replay_source: Option<String> = args
-        .windows(2)
-        .find(|w| w[0] == "--replay")
-        .map(|w| w[1].clone());
+    let replay_source: Option<String> = cli::flag_value(&args, "--replay").map(str::to_string);
     #[cfg(target_arch = "wasm32")]
     let replay_source: Option<String> = web_replay::query_replay_url();
     let replay_mode = replay_source.is_some();
 
     // Parse optional --record <output_dir> for replay frame capture (native only).
     #[cfg(not(target_arch = "wasm32"))]
-    let record_dir: Option<String> = args
-        .windows(2)
-        .find(|w| w[0] == "--record")
-        .map(|w| w[1].clone());
+    let record_dir: Option<String> = cli::flag_value(&args, "--record").map(str::to_string);
     #[cfg(not(target_arch = "wasm32"))]
     let record_mode = replay_mode && record_dir.is_some();
     #[cfg(target_arch = "wasm32")]
@@ -177,7 +192,7 @@ fn main() {
         app.insert_state(simulation::AppState::MainMenu);
     }
 
-    // Replay viewer mode is watch-only (camera + playback controls only).
+    // Replay viewer mode is watch-only until the player takes over the replay.
     if replay_mode {
         app.insert_resource(simulation::replay::ReplayViewerMode);
     }
@@ -198,6 +213,16 @@ fn main() {
         ));
     }
 
+    // Remote control of the running game (native only).
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(port) = serve_port {
+        app.add_plugins(remote_server::RemoteControlPlugin { port });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(port) = metrics_port {
+        app.add_plugins(metrics_server::MetricsExporterPlugin { port });
+    }
+
     // Replay mode: register startup system to load the replay file (native only)
     #[cfg(not(target_arch = "wasm32"))]
     if let Some(path) = replay_source {
@@ -320,36 +345,41 @@ fn main() {
 #[derive(Resource)]
 struct ReplayFilePath(String);
 
-/// Startup system that reads a replay file from disk and loads it into the
-/// `ReplayPlayer` for automatic playback. The `feed_replay_actions` system
-/// (registered by `ReplayPlugin`) handles injecting actions each tick.
+/// Startup system that reads a replay file (JSON or binary) from disk and
+/// loads it into the `ReplayPlayer` for automatic playback, along with any
+/// embedded checkpoints. The `feed_replay_actions` system (registered by
+/// `ReplayPlugin`) handles injecting actions each tick.
 #[cfg(not(target_arch = "wasm32"))]
 fn load_replay_file(
     replay_path: Res<ReplayFilePath>,
     mut player: ResMut<simulation::replay::ReplayPlayer>,
+    mut checkpoints: ResMut<simulation::replay::ReplayCheckpoints>,
     mut commands: Commands,
 ) {
     info!("Loading replay from: {}", replay_path.0);
-    let contents = match std::fs::read_to_string(&replay_path.0) {
-        Ok(c) => c,
+    let bytes = match std::fs::read(&replay_path.0) {
+        Ok(b) => b,
         Err(e) => {
             error!("Failed to read replay file '{}': {e}", replay_path.0);
             return;
         }
     };
-    let replay = match simulation::replay::ReplayFile::from_json(&contents) {
+    let loaded = match simulation::replay::read_replay(&bytes) {
         Ok(r) => r,
         Err(e) => {
             error!("Failed to parse replay file '{}': {e}", replay_path.0);
             return;
         }
     };
+    let replay = loaded.replay;
     if let Err(e) = replay.validate() {
         warn!("Replay validation warning: {}", e);
     }
     info!(
-        "Replay loaded: {} entries, ticks {}..{}",
+        "Replay loaded: {} entries, {} checksums, {} checkpoints, ticks {}..{}",
         replay.entries.len(),
+        replay.checksums.len(),
+        loaded.checkpoints.len(),
         replay.header.start_tick,
         replay.footer.end_tick,
     );
@@ -360,6 +390,7 @@ fn load_replay_file(
         entry_count: replay.entries.len() as u64,
     });
     player.load(replay);
+    checkpoints.adopt(&player, loaded.checkpoints);
 }
 
 // ---------------------------------------------------------------------------
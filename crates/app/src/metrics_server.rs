@@ -0,0 +1,123 @@
+//! `--metrics <port>`: a Prometheus scrape endpoint.
+//!
+//! Serves [`SimMetrics::to_prometheus`] at `http://127.0.0.1:<port>/metrics`
+//! so long headless runs and CI perf jobs can be graphed externally. Adding
+//! the plugin is what turns metrics collection on (see
+//! [`simulation::sim_metrics`]).
+//!
+//! The latest text is rendered after every tick into a shared buffer (ticks
+//! also run outside the main loop in the stdin `--agent` mode), and a
+//! single thread answers scrapes from it, so a scrape never waits on the
+//! simulation.
+//!
+//! The plugin also installs the span timer as the `tracing` subscriber and
+//! exports what it records as per-system duration histograms. Spans only
+//! exist in `--features trace` builds, and the timer can't be installed next
+//! to another subscriber such as `LogPlugin`'s, so the histograms are only
+//! there for headless trace builds.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use simulation::sim_metrics::{record_tick_metrics, SimMetrics};
+
+use crate::span_timer::{self, SpanTimes};
+
+/// Serves the simulation metrics on `127.0.0.1:port`.
+pub struct MetricsExporterPlugin {
+    pub port: u16,
+}
+
+/// The text the endpoint currently serves.
+#[derive(Resource, Clone, Default)]
+struct MetricsText(Arc<Mutex<String>>);
+
+/// Span times recorded by the installed span timer.
+#[derive(Resource)]
+struct SystemTimes(SpanTimes);
+
+impl Plugin for MetricsExporterPlugin {
+    fn build(&self, app: &mut App) {
+        let listener = match TcpListener::bind(("127.0.0.1", self.port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Metrics: cannot listen on port {}: {e}", self.port);
+                return;
+            }
+        };
+        info!(
+            "Metrics available at http://127.0.0.1:{}/metrics",
+            self.port
+        );
+
+        let text = MetricsText::default();
+        let served = text.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Err(e) = answer_scrape(stream, &served) {
+                    warn!("Metrics: failed to answer a request: {e}");
+                }
+            }
+        });
+
+        match span_timer::install() {
+            Ok(times) => {
+                app.insert_resource(SystemTimes(times));
+            }
+            Err(e) => warn!("Metrics: no per-system timings: {e}"),
+        }
+
+        app.init_resource::<SimMetrics>()
+            .insert_resource(text)
+            .add_systems(FixedUpdate, publish_metrics.after(record_tick_metrics));
+    }
+}
+
+fn publish_metrics(
+    mut metrics: ResMut<SimMetrics>,
+    system_times: Option<Res<SystemTimes>>,
+    text: Res<MetricsText>,
+) {
+    if let Some(times) = system_times {
+        metrics.system_seconds = times
+            .0
+            .sorted()
+            .into_iter()
+            .map(|(name, stat)| (name, stat.histogram))
+            .collect();
+    }
+    *text.0.lock().unwrap() = metrics.to_prometheus();
+}
+
+/// Answer one HTTP request: the metrics for `GET /metrics`, 404 otherwise.
+fn answer_scrape(stream: TcpStream, text: &MetricsText) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers so closing the socket doesn't reset the connection.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", text.0.lock().unwrap().clone()),
+        _ => ("404 Not Found", "Not found; try /metrics\n".to_string()),
+    };
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
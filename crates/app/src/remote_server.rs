@@ -0,0 +1,446 @@
+//! `--serve <port>`: the agent protocol over WebSocket.
+//!
+//! External tools and RL agents connect to `ws://127.0.0.1:<port>` and send
+//! the same JSON commands as the stdin `--agent` loop, one per text message,
+//! getting one response message back for each. Unlike the stdin loop the game
+//! keeps running between commands, so this works against a graphical game as
+//! well as the headless `--agent --serve` app.
+//!
+//! Clients can also `subscribe` to the `ticks` and `stats` streams, which
+//! push a message every N simulation ticks until they `unsubscribe`.
+//!
+//! Each connection is served by its own thread, which only moves text
+//! between the socket and channels: commands reach the world through
+//! `handle_remote_commands`, an exclusive system in `Last`, so they never
+//! race the simulation. A long `step` is spread over several frames, and the
+//! client's later commands wait until it completes.
+//!
+//! Handshakes carrying an `Origin` header are refused: browsers always send
+//! one, so a web page the player happens to open can't drive the game.
+
+use std::collections::VecDeque;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::Message;
+
+use simulation::agent_protocol::{
+    make_response, AgentCommand, AgentResponse, ResponsePayload, StatsUpdate, StreamKind,
+};
+use simulation::observation_builder::CurrentObservation;
+use simulation::{SimulationSet, TickCounter};
+
+use crate::agent_commands::{parse_command, process_command, Driver, MAX_STEP_TICKS};
+
+/// How long a connection thread waits for a client message before checking
+/// for outgoing ones.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Most `step` ticks run in one frame, across all clients. Longer steps
+/// carry on next frame so rendering isn't frozen while they run.
+const STEP_TICKS_PER_FRAME: u64 = 100;
+
+/// Accepts WebSocket clients on `127.0.0.1:port` and runs their commands.
+pub struct RemoteControlPlugin {
+    pub port: u16,
+}
+
+/// What the connection threads report to the app.
+enum RemoteEvent {
+    Connected(u64, Sender<String>),
+    Message(u64, String),
+    Disconnected(u64),
+}
+
+#[derive(Resource)]
+struct RemoteInbox(Mutex<Receiver<RemoteEvent>>);
+
+struct RemoteClient {
+    outbox: Sender<String>,
+    /// Subscribed streams and the number of ticks between their messages.
+    subscriptions: HashMap<StreamKind, u64>,
+    /// Ticks left of the `step` in progress, if any.
+    step_left: Option<u64>,
+    /// Commands received and not yet run.
+    queued: VecDeque<String>,
+}
+
+impl RemoteClient {
+    fn send(&self, response: &AgentResponse) {
+        // A closed outbox means the client is gone; `Disconnected` follows.
+        let _ = self.outbox.send(serde_json::to_string(response).unwrap());
+    }
+}
+
+/// Connected clients by connection id.
+#[derive(Resource, Default)]
+struct RemoteClients(HashMap<u64, RemoteClient>);
+
+impl Plugin for RemoteControlPlugin {
+    fn build(&self, app: &mut App) {
+        let listener = match TcpListener::bind(("127.0.0.1", self.port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Remote control: cannot listen on port {}: {e}", self.port);
+                return;
+            }
+        };
+        info!("Remote control listening on ws://127.0.0.1:{}", self.port);
+
+        let (events, inbox) = channel();
+        std::thread::spawn(move || accept_clients(listener, events));
+
+        app.insert_resource(RemoteInbox(Mutex::new(inbox)))
+            .init_resource::<RemoteClients>()
+            .add_systems(
+                FixedUpdate,
+                send_stream_messages.after(SimulationSet::PostSim),
+            )
+            .add_systems(Last, handle_remote_commands);
+    }
+}
+
+fn accept_clients(listener: TcpListener, events: Sender<RemoteEvent>) {
+    for (id, stream) in (1..).zip(listener.incoming()) {
+        match stream {
+            Ok(stream) => {
+                let events = events.clone();
+                std::thread::spawn(move || serve_client(id, stream, events));
+            }
+            Err(e) => warn!("Remote control: failed to accept a connection: {e}"),
+        }
+    }
+}
+
+/// Shuttle messages between one client and the app until either side hangs
+/// up.
+fn serve_client(id: u64, stream: TcpStream, events: Sender<RemoteEvent>) {
+    let mut socket = match tungstenite::accept_hdr(stream, reject_browser_origin) {
+        Ok(socket) => socket,
+        Err(e) => {
+            warn!("Remote control: WebSocket handshake failed: {e}");
+            return;
+        }
+    };
+    // Reads time out so replies and stream messages aren't held up waiting
+    // for the client to say something.
+    let _ = socket.get_ref().set_read_timeout(Some(POLL_INTERVAL));
+
+    let ready = serde_json::to_string(&make_response(ResponsePayload::Ready)).unwrap();
+    let (outbox, outgoing) = channel();
+    if socket.send(Message::text(ready)).is_err()
+        || events.send(RemoteEvent::Connected(id, outbox)).is_err()
+    {
+        return;
+    }
+
+    loop {
+        match outgoing.try_recv() {
+            Ok(text) => {
+                if socket.send(Message::text(text)).is_err() {
+                    break;
+                }
+                continue;
+            }
+            // The app dropped the client (it sent `quit`).
+            Err(TryRecvError::Disconnected) => {
+                let _ = socket.close(None);
+                let _ = socket.flush();
+                break;
+            }
+            Err(TryRecvError::Empty) => {}
+        }
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                if !text.trim().is_empty() && events.send(RemoteEvent::Message(id, text)).is_err() {
+                    break;
+                }
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e))
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) => {}
+            Err(_) => break,
+        }
+    }
+    let _ = events.send(RemoteEvent::Disconnected(id));
+}
+
+/// Handshake callback: refuse browsers, which always send `Origin`.
+fn reject_browser_origin(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    if !request.headers().contains_key("origin") {
+        return Ok(response);
+    }
+    let mut refusal = ErrorResponse::new(Some("browser clients are not accepted".to_string()));
+    *refusal.status_mut() = StatusCode::FORBIDDEN;
+    Err(refusal)
+}
+
+/// Exclusive system: register and drop clients and run their commands.
+fn handle_remote_commands(world: &mut World) {
+    let events: Vec<RemoteEvent> = world
+        .resource::<RemoteInbox>()
+        .0
+        .lock()
+        .unwrap()
+        .try_iter()
+        .collect();
+
+    for event in events {
+        match event {
+            RemoteEvent::Connected(id, outbox) => {
+                let client = RemoteClient {
+                    outbox,
+                    subscriptions: HashMap::default(),
+                    step_left: None,
+                    queued: VecDeque::new(),
+                };
+                world.resource_mut::<RemoteClients>().0.insert(id, client);
+            }
+            RemoteEvent::Disconnected(id) => {
+                world.resource_mut::<RemoteClients>().0.remove(&id);
+            }
+            RemoteEvent::Message(id, text) => {
+                if let Some(client) = world.resource_mut::<RemoteClients>().0.get_mut(&id) {
+                    client.queued.push_back(text);
+                }
+            }
+        }
+    }
+
+    let mut ids: Vec<u64> = world
+        .resource::<RemoteClients>()
+        .0
+        .keys()
+        .copied()
+        .collect();
+    ids.sort_unstable();
+    let mut budget = STEP_TICKS_PER_FRAME;
+    for id in ids {
+        run_queued_commands(world, id, &mut budget);
+    }
+}
+
+/// Run one client's commands in order until they run out or a `step` uses
+/// up the frame's tick `budget`.
+fn run_queued_commands(world: &mut World, id: u64, budget: &mut u64) {
+    loop {
+        let mut clients = world.resource_mut::<RemoteClients>();
+        let Some(client) = clients.0.get_mut(&id) else {
+            return;
+        };
+        if let Some(left) = client.step_left {
+            let ticks = left.min(*budget);
+            *budget -= ticks;
+            client.step_left = (ticks < left).then_some(left - ticks);
+            let finished = client.step_left.is_none();
+            for _ in 0..ticks {
+                world.run_schedule(FixedUpdate);
+            }
+            if !finished {
+                return;
+            }
+            let tick = world.resource::<TickCounter>().0;
+            reply(
+                world,
+                id,
+                make_response(ResponsePayload::StepComplete { tick }),
+            );
+            continue;
+        }
+        let Some(text) = client.queued.pop_front() else {
+            return;
+        };
+        let response = match parse_command(&text) {
+            // Run by the loop above, a frame's budget at a time.
+            Ok(AgentCommand::Step { ticks }) => {
+                client.step_left = Some(ticks.min(MAX_STEP_TICKS));
+                continue;
+            }
+            Ok(cmd) => run_remote_command(world, id, cmd),
+            Err(response) => response,
+        };
+        reply(world, id, response);
+    }
+}
+
+fn reply(world: &mut World, id: u64, response: AgentResponse) {
+    let mut clients = world.resource_mut::<RemoteClients>();
+    if let Some(client) = clients.0.get(&id) {
+        client.send(&response);
+    }
+    if matches!(response.payload, ResponsePayload::Goodbye) {
+        clients.0.remove(&id);
+    }
+}
+
+fn run_remote_command(world: &mut World, id: u64, cmd: AgentCommand) -> AgentResponse {
+    match cmd {
+        AgentCommand::Subscribe { stream, every } => {
+            let every = every.unwrap_or_else(|| stream.default_every()).max(1);
+            if let Some(client) = world.resource_mut::<RemoteClients>().0.get_mut(&id) {
+                client.subscriptions.insert(stream, every);
+            }
+            make_response(ResponsePayload::Subscribed { stream, every })
+        }
+        AgentCommand::Unsubscribe { stream } => {
+            if let Some(client) = world.resource_mut::<RemoteClients>().0.get_mut(&id) {
+                client.subscriptions.remove(&stream);
+            }
+            make_response(ResponsePayload::Unsubscribed { stream })
+        }
+        cmd => process_command(cmd, world, Driver::Live),
+    }
+}
+
+/// System: push `tick` and `stats` messages to the clients due one this tick.
+fn send_stream_messages(
+    clients: Res<RemoteClients>,
+    tick: Res<TickCounter>,
+    observation: Res<CurrentObservation>,
+) {
+    let mut stats = None;
+    for client in clients.0.values() {
+        for (&stream, &every) in &client.subscriptions {
+            if tick.0 % every != 0 {
+                continue;
+            }
+            let payload = match stream {
+                StreamKind::Ticks => ResponsePayload::Tick { tick: tick.0 },
+                StreamKind::Stats => ResponsePayload::Stats {
+                    stats: stats
+                        .get_or_insert_with(|| StatsUpdate::from(&observation.observation))
+                        .clone(),
+                },
+            };
+            client.send(&make_response(payload));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tungstenite::client::IntoClientRequest;
+    use tungstenite::WebSocket;
+
+    /// A port nothing is listening on.
+    fn free_port() -> u16 {
+        TcpListener::bind(("127.0.0.1", 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    fn remote_app(port: u16) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<TickCounter>()
+            .init_resource::<CurrentObservation>()
+            .add_plugins(RemoteControlPlugin { port });
+        app
+    }
+
+    fn connect(port: u16) -> WebSocket<TcpStream> {
+        let stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        let (socket, _) = tungstenite::client(format!("ws://127.0.0.1:{port}"), stream).unwrap();
+        socket
+            .get_ref()
+            .set_read_timeout(Some(Duration::from_millis(10)))
+            .unwrap();
+        socket
+    }
+
+    /// Update the app until the next message arrives.
+    fn next_response(app: &mut App, socket: &mut WebSocket<TcpStream>) -> serde_json::Value {
+        for _ in 0..500 {
+            app.update();
+            match socket.read() {
+                Ok(Message::Text(text)) => return serde_json::from_str(&text).unwrap(),
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) => {}
+                Err(e) => panic!("connection failed: {e}"),
+            }
+        }
+        panic!("no response");
+    }
+
+    fn step_left(app: &App) -> Option<u64> {
+        app.world()
+            .resource::<RemoteClients>()
+            .0
+            .values()
+            .find_map(|client| client.step_left)
+    }
+
+    #[test]
+    fn test_round_trip_over_socket() {
+        let port = free_port();
+        let mut app = remote_app(port);
+        let mut socket = connect(port);
+        assert_eq!(next_response(&mut app, &mut socket)["type"], "ready");
+
+        socket
+            .send(Message::text(
+                r#"{"cmd":"subscribe","stream":"ticks","every":5}"#,
+            ))
+            .unwrap();
+        let response = next_response(&mut app, &mut socket);
+        assert_eq!(response["type"], "subscribed");
+        assert_eq!(response["every"], 5);
+    }
+
+    #[test]
+    fn test_long_step_is_spread_over_frames() {
+        let port = free_port();
+        let mut app = remote_app(port);
+        let mut socket = connect(port);
+        next_response(&mut app, &mut socket);
+
+        let ticks = STEP_TICKS_PER_FRAME * 3;
+        socket
+            .send(Message::text(format!(
+                r#"{{"cmd":"step","ticks":{ticks}}}"#
+            )))
+            .unwrap();
+        while step_left(&app).is_none() {
+            app.update();
+        }
+        assert_eq!(step_left(&app), Some(ticks - STEP_TICKS_PER_FRAME));
+        app.update();
+        assert_eq!(step_left(&app), Some(ticks - 2 * STEP_TICKS_PER_FRAME));
+        assert_eq!(
+            next_response(&mut app, &mut socket)["type"],
+            "step_complete"
+        );
+        assert_eq!(step_left(&app), None);
+    }
+
+    #[test]
+    fn test_browser_handshake_is_refused() {
+        let port = free_port();
+        let _app = remote_app(port);
+        let mut request = format!("ws://127.0.0.1:{port}")
+            .into_client_request()
+            .unwrap();
+        request
+            .headers_mut()
+            .insert("Origin", "https://example.com".parse().unwrap());
+        let stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        assert!(tungstenite::client(request, stream).is_err());
+    }
+}
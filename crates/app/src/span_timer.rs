@@ -0,0 +1,139 @@
+//! A `tracing` layer that adds up the time spent inside each named span.
+//!
+//! The simulation's hot systems open an `info_span!` named after themselves
+//! when built with `--features trace` (see `simulation::diagnostics`), and
+//! Bevy's own `trace` feature opens a `system` span with the system's name
+//! around every system. Both end up here keyed by that name.
+//!
+//! Used by the `--bench` report and by the `--metrics` exporter's per-system
+//! histograms.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bevy::log::tracing_subscriber::layer::{Context, SubscriberExt};
+use bevy::log::tracing_subscriber::registry::LookupSpan;
+use bevy::log::tracing_subscriber::{Layer, Registry};
+use bevy::utils::tracing::field::{Field, Visit};
+use bevy::utils::tracing::{span, Subscriber};
+use bevy::utils::{HashMap, Instant};
+use simulation::sim_metrics::DurationHistogram;
+
+/// Time spent in one span name.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SpanStat {
+    pub calls: u64,
+    pub total: Duration,
+    pub max: Duration,
+    pub histogram: DurationHistogram,
+}
+
+/// Handle to the times collected by the installed layer.
+#[derive(Clone, Default)]
+pub(crate) struct SpanTimes(Arc<Mutex<HashMap<String, SpanStat>>>);
+
+impl SpanTimes {
+    /// Forget everything recorded so far.
+    pub fn reset(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    /// Recorded spans, most total time first.
+    pub fn sorted(&self) -> Vec<(String, SpanStat)> {
+        let mut spans: Vec<_> = self
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, stat)| (name.clone(), *stat))
+            .collect();
+        spans.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(&b.0)));
+        spans
+    }
+}
+
+/// Install the layer as the global `tracing` subscriber. Fails if another
+/// subscriber (e.g. Bevy's `LogPlugin`) got there first.
+pub(crate) fn install() -> Result<SpanTimes, String> {
+    let times = SpanTimes::default();
+    let subscriber = Registry::default().with(SpanTimer {
+        times: times.clone(),
+    });
+    bevy::utils::tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| format!("cannot install the span timer: {e}"))?;
+    Ok(times)
+}
+
+struct SpanTimer {
+    times: SpanTimes,
+}
+
+/// Per-span state, kept in the span's extensions.
+struct SpanState {
+    name: String,
+    entered: Option<Instant>,
+}
+
+/// Picks the `name` field out of Bevy's `system` spans.
+struct NameField(Option<String>);
+
+impl Visit for NameField {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "name" {
+            self.0 = Some(format!("{value:?}").trim_matches('"').to_string());
+        }
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanTimer {
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut field = NameField(None);
+        attrs.record(&mut field);
+        let name = match field.0 {
+            Some(name) if attrs.metadata().name() == "system" => name,
+            _ => attrs.metadata().name().to_string(),
+        };
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanState {
+                name,
+                entered: None,
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(state) = span.extensions_mut().get_mut::<SpanState>() {
+                state.entered = Some(Instant::now());
+            }
+        }
+    }
+
+    // System spans live as long as the system and are entered once per run,
+    // so time is recorded on every exit rather than when the span closes.
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(state) = extensions.get_mut::<SpanState>() else {
+            return;
+        };
+        let Some(entered) = state.entered.take() else {
+            return;
+        };
+        let elapsed = entered.elapsed();
+        let mut times = self.times.0.lock().unwrap();
+        let stat = times.entry(state.name.clone()).or_default();
+        stat.calls += 1;
+        stat.total += elapsed;
+        stat.max = stat.max.max(elapsed);
+        stat.histogram.observe(elapsed.as_secs_f64());
+    }
+}
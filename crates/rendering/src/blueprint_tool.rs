@@ -0,0 +1,255 @@
+//! Blueprint stamp tool.
+//!
+//! With the blueprint tool active and a blueprint chosen in the blueprint
+//! panel, a ghost of the blueprint follows the cursor: planned roads, zones
+//! and service footprints, with cells that block the stamp in red. Left click
+//! stamps it, the rotate key turns it a quarter, right click puts it away.
+
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use simulation::app_state::AppState;
+use simulation::blueprints::{
+    BlueprintCaptured, BlueprintLibrary, BlueprintPlaceRejected, BlueprintPlaced, BlueprintPlan,
+    BlueprintRotation, PlaceBlueprint,
+};
+use simulation::config::CELL_SIZE;
+use simulation::economy::CityBudget;
+use simulation::grid::WorldGrid;
+use simulation::heightmap_import::BuildabilityGrid;
+use simulation::keybindings::KeyBindings;
+use simulation::services::ServiceBuilding;
+
+use crate::egui_input_guard::egui_wants_pointer;
+use crate::input::{ActiveTool, CursorGridPos, StatusMessage};
+use crate::terrain_render::{mark_chunk_dirty_at, ChunkDirty, TerrainChunk};
+use crate::zone_brush_preview::zone_color;
+
+/// Height of the ghost above the ground plane.
+const GHOST_Y: f32 = 0.6;
+
+/// Line pieces each ghost road curve is drawn with.
+const ROAD_STEPS: usize = 16;
+
+const OUTLINE_OK_COLOR: Color = Color::srgba(0.2, 0.8, 1.0, 0.9);
+const OUTLINE_BLOCKED_COLOR: Color = Color::srgba(0.9, 0.2, 0.2, 0.9);
+const ROAD_COLOR: Color = Color::srgba(0.9, 0.9, 0.9, 0.8);
+const SERVICE_COLOR: Color = Color::srgba(0.3, 0.9, 0.4, 0.9);
+const CONFLICT_COLOR: Color = Color::srgba(1.0, 0.15, 0.15, 0.8);
+
+/// Which blueprint the tool stamps and how it is turned.
+#[derive(Resource, Default)]
+pub struct BlueprintToolState {
+    /// Index into the `BlueprintLibrary`.
+    pub selected: Option<usize>,
+    pub rotation: BlueprintRotation,
+}
+
+/// Plan of the blueprint at the cursor, shared with the blueprint panel.
+#[derive(Resource, Default)]
+pub struct BlueprintGhost {
+    pub plan: Option<BlueprintPlan>,
+}
+
+/// Re-plans the selected blueprint at the cursor, centred on it.
+pub fn update_blueprint_ghost(
+    tool: Res<ActiveTool>,
+    cursor: Res<CursorGridPos>,
+    tool_state: Res<BlueprintToolState>,
+    library: Res<BlueprintLibrary>,
+    grid: Res<WorldGrid>,
+    buildable: Res<BuildabilityGrid>,
+    mut ghost: ResMut<BlueprintGhost>,
+) {
+    let blueprint = tool_state.selected.and_then(|i| library.get(i));
+    let (Some(blueprint), true, true) = (blueprint, *tool == ActiveTool::Blueprint, cursor.valid)
+    else {
+        ghost.plan = None;
+        return;
+    };
+
+    let (w, h) = tool_state
+        .rotation
+        .rotated_size(blueprint.width, blueprint.height);
+    let origin_x = (cursor.grid_x.max(0) as usize).saturating_sub(w as usize / 2);
+    let origin_y = (cursor.grid_y.max(0) as usize).saturating_sub(h as usize / 2);
+    ghost.plan = Some(blueprint.plan(&grid, &buildable, origin_x, origin_y, tool_state.rotation));
+}
+
+/// Rotate, stamp and cancel input for the blueprint tool.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_blueprint_tool(
+    mut contexts: EguiContexts,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    tool: Res<ActiveTool>,
+    ghost: Res<BlueprintGhost>,
+    mut tool_state: ResMut<BlueprintToolState>,
+    mut place_events: EventWriter<PlaceBlueprint>,
+    mut status: ResMut<StatusMessage>,
+    drag: Res<crate::camera::LeftClickDrag>,
+) {
+    if *tool != ActiveTool::Blueprint || tool_state.selected.is_none() {
+        return;
+    }
+
+    if bindings.rotate_blueprint.just_pressed(&keys) {
+        tool_state.rotation = tool_state.rotation.next();
+        status.set(
+            format!("Blueprint rotated to {}°", tool_state.rotation.degrees()),
+            false,
+        );
+    }
+
+    // Prevent click-through: skip world actions when egui is handling pointer input.
+    if egui_wants_pointer(&mut contexts) || drag.is_dragging {
+        return;
+    }
+
+    if buttons.just_pressed(MouseButton::Right) {
+        tool_state.selected = None;
+        return;
+    }
+
+    // Shift+drag is box selection, used to capture new blueprints.
+    let shift_held = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    if !buttons.just_pressed(MouseButton::Left) || shift_held {
+        return;
+    }
+
+    let (Some(index), Some(plan)) = (tool_state.selected, &ghost.plan) else {
+        return;
+    };
+    place_events.send(PlaceBlueprint {
+        blueprint_index: index,
+        target_x: plan.origin.0,
+        target_y: plan.origin.1,
+        rotation: tool_state.rotation,
+    });
+}
+
+fn cell_square(gizmos: &mut Gizmos, x: f32, y: f32, inset: f32, color: Color) {
+    let (x0, y0) = (x * CELL_SIZE + inset, y * CELL_SIZE + inset);
+    let (x1, y1) = ((x + 1.0) * CELL_SIZE - inset, (y + 1.0) * CELL_SIZE - inset);
+    rect(gizmos, Vec2::new(x0, y0), Vec2::new(x1, y1), color);
+}
+
+fn rect(gizmos: &mut Gizmos, min: Vec2, max: Vec2, color: Color) {
+    let c0 = Vec3::new(min.x, GHOST_Y, min.y);
+    let c1 = Vec3::new(max.x, GHOST_Y, min.y);
+    let c2 = Vec3::new(max.x, GHOST_Y, max.y);
+    let c3 = Vec3::new(min.x, GHOST_Y, max.y);
+    gizmos.line(c0, c1, color);
+    gizmos.line(c1, c2, color);
+    gizmos.line(c2, c3, color);
+    gizmos.line(c3, c0, color);
+}
+
+/// Draws the ghost: outline, roads, zone cells, service footprints and
+/// blocking cells.
+pub fn draw_blueprint_ghost(
+    ghost: Res<BlueprintGhost>,
+    budget: Res<CityBudget>,
+    mut gizmos: Gizmos,
+) {
+    let Some(plan) = &ghost.plan else {
+        return;
+    };
+
+    let ok = plan.is_buildable() && budget.treasury >= plan.cost;
+    let outline = if ok {
+        OUTLINE_OK_COLOR
+    } else {
+        OUTLINE_BLOCKED_COLOR
+    };
+    let min = Vec2::new(plan.origin.0 as f32, plan.origin.1 as f32) * CELL_SIZE;
+    let max = min + Vec2::new(plan.size.0 as f32, plan.size.1 as f32) * CELL_SIZE;
+    rect(&mut gizmos, min, max, outline);
+
+    for seg in &plan.segments {
+        let [p0, p1, p2, p3] = seg.points;
+        let mut prev = p0;
+        for i in 1..=ROAD_STEPS {
+            let t = i as f32 / ROAD_STEPS as f32;
+            let u = 1.0 - t;
+            let pt = p0 * (u * u * u)
+                + p1 * (3.0 * u * u * t)
+                + p2 * (3.0 * u * t * t)
+                + p3 * (t * t * t);
+            gizmos.line(
+                Vec3::new(prev.x, GHOST_Y, prev.y),
+                Vec3::new(pt.x, GHOST_Y, pt.y),
+                ROAD_COLOR,
+            );
+            prev = pt;
+        }
+    }
+
+    for &(x, y, zone) in &plan.zone_cells {
+        cell_square(&mut gizmos, x as f32, y as f32, 2.0, zone_color(zone));
+    }
+
+    for &(x, y, service_type) in &plan.services {
+        let (fw, fh) = ServiceBuilding::footprint(service_type);
+        let min = Vec2::new(x as f32, y as f32) * CELL_SIZE + Vec2::splat(1.0);
+        let max = Vec2::new((x + fw) as f32, (y + fh) as f32) * CELL_SIZE - Vec2::splat(1.0);
+        rect(&mut gizmos, min, max, SERVICE_COLOR);
+    }
+
+    for &(x, y, _) in &plan.conflicts {
+        cell_square(&mut gizmos, x as f32, y as f32, 1.0, CONFLICT_COLOR);
+    }
+}
+
+/// Reports capture and stamp results, and redraws terrain under a stamp.
+pub fn report_blueprint_results(
+    mut captured: EventReader<BlueprintCaptured>,
+    mut placed: EventReader<BlueprintPlaced>,
+    mut rejected: EventReader<BlueprintPlaceRejected>,
+    mut status: ResMut<StatusMessage>,
+    chunks: Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
+    mut commands: Commands,
+) {
+    for ev in captured.read() {
+        status.set(format!("Saved blueprint '{}'", ev.name), false);
+    }
+    for ev in placed.read() {
+        let (ox, oy) = ev.origin;
+        for y in oy..oy + ev.size.1 as usize {
+            for x in ox..ox + ev.size.0 as usize {
+                mark_chunk_dirty_at(x, y, &chunks, &mut commands);
+            }
+        }
+        status.set(
+            format!(
+                "Placed '{}': {} roads, {} zone cells, {} services (${:.0})",
+                ev.name, ev.segments_placed, ev.zones_placed, ev.services_placed, ev.cost
+            ),
+            false,
+        );
+    }
+    for ev in rejected.read() {
+        status.set(format!("Can't place '{}': {}", ev.name, ev.reason), true);
+    }
+}
+
+pub struct BlueprintToolPlugin;
+
+impl Plugin for BlueprintToolPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BlueprintToolState>()
+            .init_resource::<BlueprintGhost>()
+            .add_systems(
+                Update,
+                (
+                    handle_blueprint_tool,
+                    update_blueprint_ghost,
+                    draw_blueprint_ghost,
+                    report_blueprint_results,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -3,7 +3,8 @@
 //! When the player holds Shift and drags with the left mouse button, a
 //! translucent rectangle is drawn on the ground plane. On release, all
 //! buildings and road cells within the rectangle are added to the
-//! `MultiSelectState` from the multi-select system.
+//! `MultiSelectState` from the multi-select system. The selected grid area is
+//! also kept so tools such as blueprint capture can use it.
 
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
@@ -42,6 +43,8 @@ pub struct BoxSelectionState {
     pub start_world_pos: Vec2,
     /// Current world position (XZ plane) of the cursor during drag.
     pub current_world_pos: Vec2,
+    /// Grid area of the last completed selection: top-left cell, width, height.
+    pub last_area: Option<(usize, usize, usize, usize)>,
 }
 
 impl BoxSelectionState {
@@ -160,6 +163,12 @@ pub fn box_selection_release(
         let gy_start = grid_min_y.max(0) as usize;
         let gx_end = (grid_max_x as usize).min(grid.width.saturating_sub(1));
         let gy_end = (grid_max_y as usize).min(grid.height.saturating_sub(1));
+        box_state.last_area = Some((
+            gx_start,
+            gy_start,
+            (gx_end + 1).saturating_sub(gx_start),
+            (gy_end + 1).saturating_sub(gy_start),
+        ));
 
         // If Shift is still held, add to existing selection; otherwise replace
         let shift_held = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
@@ -317,6 +326,7 @@ mod tests {
             active: true,
             start_world_pos: Vec2::new(100.0, 200.0),
             current_world_pos: Vec2::new(50.0, 300.0),
+            last_area: None,
         };
         let (min, max) = state.bounds();
         assert_eq!(min, Vec2::new(50.0, 200.0));
@@ -329,6 +339,7 @@ mod tests {
             active: true,
             start_world_pos: Vec2::new(0.0, 0.0),
             current_world_pos: Vec2::new(160.0, 320.0),
+            last_area: None,
         };
         let size = state.size();
         assert_eq!(size, Vec2::new(160.0, 320.0));
@@ -340,5 +351,6 @@ mod tests {
         assert!(!state.active);
         assert_eq!(state.start_world_pos, Vec2::ZERO);
         assert_eq!(state.current_world_pos, Vec2::ZERO);
+        assert!(state.last_area.is_none());
     }
 }
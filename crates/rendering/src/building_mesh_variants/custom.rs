@@ -0,0 +1,40 @@
+//! User-provided models from the mod manifest as variant candidates.
+
+use bevy::prelude::*;
+
+use simulation::district_styles::ArchitectureStyle;
+use simulation::grid::ZoneType;
+
+use super::styles::style_pool;
+use super::{zone_pool, VARIANTS_PER_LEVEL};
+use crate::building_meshes::{BuildingModelCache, CustomBuildingModel};
+
+/// Pick one of the user-provided models for a building, if one is chosen.
+///
+/// Custom models that match the zone, level and style are extra candidates
+/// alongside the built-in variants of the level's slice, so each gets about
+/// the same share of lots as a built-in variant.  With no built-in models
+/// for the zone, a matching custom model is always chosen.
+pub(super) fn select_custom_model(
+    cache: &BuildingModelCache,
+    zone: ZoneType,
+    level: u8,
+    style: ArchitectureStyle,
+    hash: usize,
+) -> Option<&CustomBuildingModel> {
+    let matching = || {
+        cache
+            .custom
+            .iter()
+            .filter(move |m| m.entry.matches(zone, level, style))
+    };
+    let count = matching().count();
+    if count == 0 {
+        return None;
+    }
+    let pool =
+        style_pool(cache, style, zone, level).unwrap_or_else(|| zone_pool(cache, zone, level));
+    let builtin = VARIANTS_PER_LEVEL.min(pool.len());
+    let slot = hash % (builtin + count);
+    slot.checked_sub(builtin).and_then(|i| matching().nth(i))
+}
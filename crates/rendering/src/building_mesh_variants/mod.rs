@@ -10,17 +10,41 @@
 //! model appear different -- one might be tall and narrow, another wide and
 //! squat.  Combined with the per-level model partitioning from the model pool,
 //! this produces 2-3 visually distinct building shapes per zone/level combo.
+//!
+//! Districts can also carry an **architecture style** (`DistrictStyles`).
+//! A themed district draws from the model pool that suits its style, uses
+//! its own slice of that pool, and stretches the proportions towards the
+//! style's silhouette, so themed neighbourhoods stand apart from the mix.
+//!
+//! User-provided models from the mod manifest (`building_meshes::custom_models`)
+//! join the built-in variants of each zone/level/style they are listed for.
+//!
+//! Split into sub-modules:
+//! - `styles`: Model pools and proportions of district architecture styles
+//! - `custom`: Selection of user-provided models from the mod manifest
 
 use bevy::prelude::*;
 
 use simulation::buildings::Building;
-use simulation::SaveLoadState;
+use simulation::district_styles::{ArchitectureStyle, DistrictStyles};
+use simulation::districts::DistrictMap;
 use simulation::grid::ZoneType;
+use simulation::SaveLoadState;
 
-use crate::building_meshes::{building_scale, BuildingModelCache};
+use crate::building_meshes::{
+    building_scale, BuildingModelCache, InstancedModel, InstancedModels, InstancedPart,
+};
 use crate::building_render::{BuildingMesh3d, ZoneBuilding};
 use crate::building_variant_proportions;
 
+mod custom;
+mod styles;
+#[cfg(test)]
+mod tests;
+
+use custom::select_custom_model;
+use styles::{style_pool, style_proportions, STYLE_POOL_STRIDE};
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -40,8 +64,11 @@ const VARIANTS_PER_LEVEL: usize = 3;
 pub struct BuildingVariant {
     /// The building level at the time the variant was selected.
     pub level: u8,
-    /// Index within the level-specific variant slice (0..VARIANTS_PER_LEVEL).
+    /// Index within the level-specific variant slice (0..VARIANTS_PER_LEVEL);
+    /// 0 for custom models.
     pub variant_index: usize,
+    /// Architecture style of the building's district at selection time.
+    pub style: ArchitectureStyle,
 }
 
 // ---------------------------------------------------------------------------
@@ -91,62 +118,68 @@ fn variant_hash(grid_x: usize, grid_y: usize, level: u8, zone: ZoneType) -> usiz
 /// `VARIANTS_PER_LEVEL` entries.  Each level gets a distinct starting offset
 /// into the pool so that level-1 and level-2 buildings of the same zone
 /// always look different (assuming the pool has enough models).
+///
+/// A district style may swap in a different pool and shifts the slices
+/// along it; `Mixed` keeps the zone's usual pool and slices.
 fn select_variant_scene(
     cache: &BuildingModelCache,
     zone: ZoneType,
     level: u8,
+    style: ArchitectureStyle,
     hash: usize,
 ) -> (Handle<Scene>, usize) {
+    let pool =
+        style_pool(cache, style, zone, level).unwrap_or_else(|| zone_pool(cache, zone, level));
+    select_from_pool_offset(pool, level, hash, style.index() * STYLE_POOL_STRIDE)
+}
+
+/// The model pool a zone type draws from at a given level.
+fn zone_pool(cache: &BuildingModelCache, zone: ZoneType, level: u8) -> &[Handle<Scene>] {
     match zone {
-        ZoneType::ResidentialLow => select_from_pool(&cache.residential, level, hash),
-        ZoneType::ResidentialMedium => {
-            select_from_pool(&cache.commercial, level, hash)
-        }
+        ZoneType::ResidentialLow => &cache.residential,
+        ZoneType::ResidentialMedium => &cache.commercial,
         ZoneType::ResidentialHigh => {
             if level >= 3 && !cache.skyscrapers.is_empty() {
-                select_from_pool(&cache.skyscrapers, level, hash)
+                &cache.skyscrapers
             } else if !cache.commercial.is_empty() {
-                select_from_pool(&cache.commercial, level, hash)
-            } else {
-                select_from_pool(&cache.residential, level, hash)
-            }
-        }
-        ZoneType::CommercialLow => {
-            if level >= 4 && !cache.skyscrapers.is_empty() {
-                select_from_pool(&cache.skyscrapers, level, hash)
+                &cache.commercial
             } else {
-                select_from_pool(&cache.commercial, level, hash)
+                &cache.residential
             }
         }
-        ZoneType::CommercialHigh => {
+        ZoneType::CommercialLow | ZoneType::CommercialHigh => {
             if level >= 4 && !cache.skyscrapers.is_empty() {
-                select_from_pool(&cache.skyscrapers, level, hash)
+                &cache.skyscrapers
             } else {
-                select_from_pool(&cache.commercial, level, hash)
+                &cache.commercial
             }
         }
-        ZoneType::Industrial => select_from_pool(&cache.industrial, level, hash),
-        ZoneType::Office => {
+        ZoneType::Industrial => &cache.industrial,
+        ZoneType::Office | ZoneType::MixedUse => {
             if level >= 3 && !cache.skyscrapers.is_empty() {
-                select_from_pool(&cache.skyscrapers, level, hash)
+                &cache.skyscrapers
             } else {
-                select_from_pool(&cache.commercial, level, hash)
+                &cache.commercial
             }
         }
-        ZoneType::MixedUse => {
-            if level >= 3 && !cache.skyscrapers.is_empty() {
-                select_from_pool(&cache.skyscrapers, level, hash)
-            } else {
-                select_from_pool(&cache.commercial, level, hash)
-            }
-        }
-        ZoneType::None => select_from_pool(&cache.residential, level, hash),
+        ZoneType::None => &cache.residential,
     }
 }
 
 /// Given a model pool, partition it into per-level slices and select a
 /// variant from the slice for the given level.
 fn select_from_pool(pool: &[Handle<Scene>], level: u8, hash: usize) -> (Handle<Scene>, usize) {
+    select_from_pool_offset(pool, level, hash, 0)
+}
+
+/// Like `select_from_pool`, with the per-level slices shifted `offset`
+/// models along the pool.
+fn select_from_pool_offset(
+    pool: &[Handle<Scene>],
+    level: u8,
+    hash: usize,
+    offset: usize,
+) -> (Handle<Scene>, usize) {
     if pool.is_empty() {
         return (Handle::default(), 0);
     }
@@ -155,7 +188,7 @@ fn select_from_pool(pool: &[Handle<Scene>], level: u8, hash: usize) -> (Handle<S
     let level_offset = ((level as usize).wrapping_sub(1)) * VARIANTS_PER_LEVEL;
     let available = VARIANTS_PER_LEVEL.min(pool_len);
     let variant_index = hash % available;
-    let pool_index = (level_offset + variant_index) % pool_len;
+    let pool_index = (level_offset + offset + variant_index) % pool_len;
 
     (pool[pool_index].clone(), variant_index)
 }
@@ -168,28 +201,43 @@ fn select_from_pool(pool: &[Handle<Scene>], level: u8, hash: usize) -> (Handle<S
 ///
 /// For each zone building mesh entity:
 /// 1. If it has no `BuildingVariant` yet, compute one and replace the scene.
-/// 2. If it already has a `BuildingVariant` but the building's level has
-///    changed, recompute and replace.
+/// 2. If it already has a `BuildingVariant` but the building's level or its
+///    district's architecture style has changed, recompute and replace.
 ///
 /// Also applies **per-variant proportions** from the proportion tables: each
 /// variant gets a different width/height/depth scale multiplier so that
 /// buildings of the same zone and level are visually distinct even when they
 /// share the same GLB model.
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn assign_building_variants(
     mut commands: Commands,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
+    district_map: Res<DistrictMap>,
+    district_styles: Res<DistrictStyles>,
     buildings: Query<&Building>,
-    mut mesh_query: Query<(Entity, &BuildingMesh3d, &mut Transform, Option<&BuildingVariant>), With<ZoneBuilding>>,
+    mut mesh_query: Query<
+        (
+            Entity,
+            &BuildingMesh3d,
+            &mut Transform,
+            Option<&BuildingVariant>,
+            Option<&InstancedModel>,
+            Option<&Children>,
+        ),
+        With<ZoneBuilding>,
+    >,
+    instanced_parts: Query<(), With<InstancedPart>>,
 ) {
-    for (mesh_entity, bm, mut transform, maybe_variant) in &mut mesh_query {
+    for (mesh_entity, bm, mut transform, maybe_variant, instanced, children) in &mut mesh_query {
         let Ok(building) = buildings.get(bm.tracked_entity) else {
             continue;
         };
 
+        let style = district_styles.style_at(building.grid_x, building.grid_y, &district_map);
         let needs_assign = match maybe_variant {
             None => true,
-            Some(v) => v.level != building.level,
+            Some(v) => v.level != building.level || v.style != style,
         };
 
         if !needs_assign {
@@ -203,37 +251,64 @@ pub fn assign_building_variants(
             building.zone_type,
         );
 
-        let (scene_handle, variant_index) =
-            select_variant_scene(&model_cache, building.zone_type, building.level, hash);
-
-        // Look up per-variant proportions for this zone/level/variant
-        let proportions =
-            &building_variant_proportions::proportions_for(building.zone_type, building.level)
-                [variant_index];
-
-        // Compute base scale and the small per-building variation
-        let base_scale = building_scale(building.zone_type, building.level);
+        // Small per-building scale variation
         let pos_hash = building
             .grid_x
             .wrapping_mul(7)
             .wrapping_add(building.grid_y.wrapping_mul(13));
         let scale_var = 0.98 + (pos_hash % 5) as f32 / 100.0;
-        let s = base_scale * scale_var;
 
-        // Apply variant proportions to the transform scale
-        transform.scale = Vec3::new(
-            s * proportions.x,
-            s * proportions.y,
-            s * proportions.z,
+        let custom = select_custom_model(
+            &model_cache,
+            building.zone_type,
+            building.level,
+            style,
+            hash,
         );
+        let (scene_handle, variant_index) = if let Some(custom) = custom {
+            // Custom models bring their own proportions; fit them to the lot
+            transform.scale = Vec3::splat(custom.entry.lot_scale() * scale_var);
+            (custom.scene.clone(), 0)
+        } else {
+            let (scene_handle, variant_index) = select_variant_scene(
+                &model_cache,
+                building.zone_type,
+                building.level,
+                style,
+                hash,
+            );
+
+            // Look up per-variant proportions for this zone/level/variant
+            let proportions =
+                &building_variant_proportions::proportions_for(building.zone_type, building.level)
+                    [variant_index];
+
+            let s = building_scale(building.zone_type, building.level) * scale_var;
+            let styled = style_proportions(style);
+
+            // Apply variant and style proportions to the transform scale
+            transform.scale = Vec3::new(
+                s * proportions.x * styled.x,
+                s * proportions.y * styled.y,
+                s * proportions.z * styled.z,
+            );
+            (scene_handle, variant_index)
+        };
 
-        commands.entity(mesh_entity).insert((
-            SceneRoot(scene_handle),
-            BuildingVariant {
-                level: building.level,
-                variant_index,
-            },
-        ));
+        let old_parts = instanced.map(|_| {
+            children.map_or_else(Vec::new, |c| {
+                c.iter()
+                    .copied()
+                    .filter(|&part| instanced_parts.contains(part))
+                    .collect()
+            })
+        });
+        models.replace(&mut commands, mesh_entity, old_parts, scene_handle);
+        commands.entity(mesh_entity).insert(BuildingVariant {
+            level: building.level,
+            variant_index,
+            style,
+        });
     }
 }
 
@@ -254,127 +329,3 @@ impl Plugin for BuildingMeshVariantsPlugin {
         );
     }
 }
-
-// ---------------------------------------------------------------------------
-// Tests
-// ---------------------------------------------------------------------------
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn variant_hash_differs_by_level() {
-        let h1 = variant_hash(10, 20, 1, ZoneType::ResidentialLow);
-        let h2 = variant_hash(10, 20, 2, ZoneType::ResidentialLow);
-        let h3 = variant_hash(10, 20, 3, ZoneType::ResidentialLow);
-        assert_ne!(h1, h2);
-        assert_ne!(h2, h3);
-        assert_ne!(h1, h3);
-    }
-
-    #[test]
-    fn variant_hash_differs_by_position() {
-        let h1 = variant_hash(5, 5, 1, ZoneType::CommercialLow);
-        let h2 = variant_hash(6, 5, 1, ZoneType::CommercialLow);
-        let h3 = variant_hash(5, 6, 1, ZoneType::CommercialLow);
-        assert_ne!(h1, h2);
-        assert_ne!(h1, h3);
-    }
-
-    #[test]
-    fn variant_hash_differs_by_zone() {
-        let h1 = variant_hash(10, 10, 1, ZoneType::ResidentialLow);
-        let h2 = variant_hash(10, 10, 1, ZoneType::CommercialLow);
-        let h3 = variant_hash(10, 10, 1, ZoneType::Industrial);
-        assert_ne!(h1, h2);
-        assert_ne!(h1, h3);
-    }
-
-    #[test]
-    fn variant_hash_is_deterministic() {
-        let h1 = variant_hash(42, 99, 2, ZoneType::Industrial);
-        let h2 = variant_hash(42, 99, 2, ZoneType::Industrial);
-        assert_eq!(h1, h2);
-    }
-
-    #[test]
-    fn select_from_pool_empty() {
-        let pool: Vec<Handle<Scene>> = vec![];
-        let (handle, idx) = select_from_pool(&pool, 1, 12345);
-        assert_eq!(idx, 0);
-        assert_eq!(handle, Handle::default());
-    }
-
-    #[test]
-    fn select_from_pool_single_model() {
-        let pool = vec![Handle::default()];
-        let (_, idx) = select_from_pool(&pool, 1, 999);
-        assert_eq!(idx, 0);
-    }
-
-    #[test]
-    fn select_from_pool_level_offset() {
-        let pool: Vec<Handle<Scene>> = (0..9).map(|_| Handle::default()).collect();
-        let (_, v1) = select_from_pool(&pool, 1, 0);
-        let (_, v2) = select_from_pool(&pool, 2, 0);
-        let (_, v3) = select_from_pool(&pool, 3, 0);
-        assert_eq!(v1, 0);
-        assert_eq!(v2, 0);
-        assert_eq!(v3, 0);
-    }
-
-    #[test]
-    fn select_from_pool_wraps_around() {
-        let pool: Vec<Handle<Scene>> = (0..4).map(|_| Handle::default()).collect();
-        let _ = select_from_pool(&pool, 1, 0);
-        let _ = select_from_pool(&pool, 2, 0);
-        let _ = select_from_pool(&pool, 3, 0);
-        let _ = select_from_pool(&pool, 5, 123456);
-    }
-
-    #[test]
-    fn variant_selection_gives_at_least_2_variants() {
-        let pool: Vec<Handle<Scene>> = (0..6).map(|_| Handle::default()).collect();
-        let mut seen = std::collections::HashSet::new();
-        for h in 0..100 {
-            let (_, idx) = select_from_pool(&pool, 1, h);
-            seen.insert(idx);
-        }
-        assert!(
-            seen.len() >= 2,
-            "Expected at least 2 variants, got {}",
-            seen.len()
-        );
-    }
-
-    #[test]
-    fn residential_low_all_levels_covered() {
-        let pos = (15, 25);
-        let hashes: Vec<usize> = (1..=3)
-            .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::ResidentialLow))
-            .collect();
-        let unique: std::collections::HashSet<_> = hashes.iter().collect();
-        assert_eq!(unique.len(), 3);
-    }
-
-    #[test]
-    fn commercial_low_all_levels_covered() {
-        let pos = (30, 40);
-        let hashes: Vec<usize> = (1..=3)
-            .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::CommercialLow))
-            .collect();
-        let unique: std::collections::HashSet<_> = hashes.iter().collect();
-        assert_eq!(unique.len(), 3);
-    }
-
-    #[test]
-    fn industrial_all_levels_covered() {
-        let pos = (50, 60);
-        let hashes: Vec<usize> = (1..=3)
-            .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::Industrial))
-            .collect();
-        let unique: std::collections::HashSet<_> = hashes.iter().collect();
-        assert_eq!(unique.len(), 3);
-    }
-}
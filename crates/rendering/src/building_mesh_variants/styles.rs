@@ -0,0 +1,54 @@
+//! District architecture styles: the model pool each style builds a zone
+//! from, and the proportions that push buildings towards its silhouette.
+
+use bevy::prelude::*;
+
+use simulation::district_styles::ArchitectureStyle;
+use simulation::grid::ZoneType;
+
+use crate::building_meshes::BuildingModelCache;
+
+/// Models each style shifts its per-level slices along the pool.  Not a
+/// multiple of `VARIANTS_PER_LEVEL`, so a themed level-1 slice does not line
+/// up with the mixed level-2 slice.
+pub(super) const STYLE_POOL_STRIDE: usize = 5;
+
+/// The model pool a style builds a zone from, or `None` to keep the zone's
+/// usual pool.  Industry keeps its own pool in every style.
+pub(super) fn style_pool(
+    cache: &BuildingModelCache,
+    style: ArchitectureStyle,
+    zone: ZoneType,
+    level: u8,
+) -> Option<&[Handle<Scene>]> {
+    if zone == ZoneType::Industrial || zone == ZoneType::None {
+        return None;
+    }
+    let pool = match style {
+        ArchitectureStyle::Mixed => return None,
+        // Flat-roofed blocks, never towers
+        ArchitectureStyle::WhiteCity => &cache.commercial,
+        // Pitched-roof houses for the low-rise zones
+        ArchitectureStyle::OttomanStone => match zone {
+            ZoneType::ResidentialLow | ZoneType::ResidentialMedium | ZoneType::CommercialLow => {
+                &cache.residential
+            }
+            _ => return None,
+        },
+        // Towers from level 2 up
+        ArchitectureStyle::ModernGlass if level >= 2 => &cache.skyscrapers,
+        ArchitectureStyle::ModernGlass => &cache.commercial,
+    };
+    (!pool.is_empty()).then_some(pool.as_slice())
+}
+
+/// Scale applied on top of the variant proportions to push buildings
+/// towards the style's silhouette.
+pub(super) fn style_proportions(style: ArchitectureStyle) -> Vec3 {
+    match style {
+        ArchitectureStyle::Mixed => Vec3::ONE,
+        ArchitectureStyle::WhiteCity => Vec3::new(1.12, 0.85, 1.12),
+        ArchitectureStyle::OttomanStone => Vec3::new(1.05, 0.75, 1.05),
+        ArchitectureStyle::ModernGlass => Vec3::new(0.88, 1.3, 0.88),
+    }
+}
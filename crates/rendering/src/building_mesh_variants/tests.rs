@@ -0,0 +1,218 @@
+use super::*;
+use crate::building_meshes::{CustomBuildingModel, CustomModelEntry};
+
+#[test]
+fn variant_hash_differs_by_level() {
+    let h1 = variant_hash(10, 20, 1, ZoneType::ResidentialLow);
+    let h2 = variant_hash(10, 20, 2, ZoneType::ResidentialLow);
+    let h3 = variant_hash(10, 20, 3, ZoneType::ResidentialLow);
+    assert_ne!(h1, h2);
+    assert_ne!(h2, h3);
+    assert_ne!(h1, h3);
+}
+
+#[test]
+fn variant_hash_differs_by_position() {
+    let h1 = variant_hash(5, 5, 1, ZoneType::CommercialLow);
+    let h2 = variant_hash(6, 5, 1, ZoneType::CommercialLow);
+    let h3 = variant_hash(5, 6, 1, ZoneType::CommercialLow);
+    assert_ne!(h1, h2);
+    assert_ne!(h1, h3);
+}
+
+#[test]
+fn variant_hash_differs_by_zone() {
+    let h1 = variant_hash(10, 10, 1, ZoneType::ResidentialLow);
+    let h2 = variant_hash(10, 10, 1, ZoneType::CommercialLow);
+    let h3 = variant_hash(10, 10, 1, ZoneType::Industrial);
+    assert_ne!(h1, h2);
+    assert_ne!(h1, h3);
+}
+
+#[test]
+fn variant_hash_is_deterministic() {
+    let h1 = variant_hash(42, 99, 2, ZoneType::Industrial);
+    let h2 = variant_hash(42, 99, 2, ZoneType::Industrial);
+    assert_eq!(h1, h2);
+}
+
+#[test]
+fn select_from_pool_empty() {
+    let pool: Vec<Handle<Scene>> = vec![];
+    let (handle, idx) = select_from_pool(&pool, 1, 12345);
+    assert_eq!(idx, 0);
+    assert_eq!(handle, Handle::default());
+}
+
+#[test]
+fn select_from_pool_single_model() {
+    let pool = vec![Handle::default()];
+    let (_, idx) = select_from_pool(&pool, 1, 999);
+    assert_eq!(idx, 0);
+}
+
+#[test]
+fn select_from_pool_level_offset() {
+    let pool: Vec<Handle<Scene>> = (0..9).map(|_| Handle::default()).collect();
+    let (_, v1) = select_from_pool(&pool, 1, 0);
+    let (_, v2) = select_from_pool(&pool, 2, 0);
+    let (_, v3) = select_from_pool(&pool, 3, 0);
+    assert_eq!(v1, 0);
+    assert_eq!(v2, 0);
+    assert_eq!(v3, 0);
+}
+
+#[test]
+fn select_from_pool_wraps_around() {
+    let pool: Vec<Handle<Scene>> = (0..4).map(|_| Handle::default()).collect();
+    let _ = select_from_pool(&pool, 1, 0);
+    let _ = select_from_pool(&pool, 2, 0);
+    let _ = select_from_pool(&pool, 3, 0);
+    let _ = select_from_pool(&pool, 5, 123456);
+}
+
+#[test]
+fn variant_selection_gives_at_least_2_variants() {
+    let pool: Vec<Handle<Scene>> = (0..6).map(|_| Handle::default()).collect();
+    let mut seen = std::collections::HashSet::new();
+    for h in 0..100 {
+        let (_, idx) = select_from_pool(&pool, 1, h);
+        seen.insert(idx);
+    }
+    assert!(
+        seen.len() >= 2,
+        "Expected at least 2 variants, got {}",
+        seen.len()
+    );
+}
+
+#[test]
+fn select_from_pool_offset_shifts_the_slice() {
+    let mut assets = Assets::<Scene>::default();
+    let pool: Vec<Handle<Scene>> = (0..12)
+        .map(|_| assets.add(Scene::new(World::new())))
+        .collect();
+    let (plain, v1) = select_from_pool(&pool, 1, 0);
+    let (shifted, v2) = select_from_pool_offset(&pool, 1, 0, STYLE_POOL_STRIDE);
+    assert_eq!(v1, v2, "variant index (and so proportions) is unchanged");
+    assert_ne!(plain, shifted);
+    assert_eq!(shifted, pool[STYLE_POOL_STRIDE]);
+}
+
+#[test]
+fn mixed_style_keeps_plain_selection() {
+    let mut assets = Assets::<Scene>::default();
+    let pool: Vec<Handle<Scene>> = (0..7)
+        .map(|_| assets.add(Scene::new(World::new())))
+        .collect();
+    let offset = ArchitectureStyle::Mixed.index() * STYLE_POOL_STRIDE;
+    for h in 0..20 {
+        assert_eq!(
+            select_from_pool(&pool, 2, h),
+            select_from_pool_offset(&pool, 2, h, offset)
+        );
+    }
+}
+
+#[test]
+fn style_proportions_match_silhouettes() {
+    assert_eq!(style_proportions(ArchitectureStyle::Mixed), Vec3::ONE);
+    let glass = style_proportions(ArchitectureStyle::ModernGlass);
+    assert!(
+        glass.y > 1.0 && glass.x < 1.0,
+        "glass towers are tall and slender"
+    );
+    let bauhaus = style_proportions(ArchitectureStyle::WhiteCity);
+    assert!(
+        bauhaus.y < 1.0 && bauhaus.x > 1.0,
+        "white city blocks are low and wide"
+    );
+    let ottoman = style_proportions(ArchitectureStyle::OttomanStone);
+    assert!(ottoman.y < bauhaus.y, "ottoman houses are the squattest");
+}
+
+fn cache_with_custom(builtin: usize, styles: Vec<ArchitectureStyle>) -> BuildingModelCache {
+    let entry = CustomModelEntry {
+        file: "custom.glb".to_string(),
+        zones: vec![ZoneType::Office],
+        min_level: 1,
+        max_level: 5,
+        footprint: [1, 1],
+        scale: 1.0,
+        styles,
+    };
+    BuildingModelCache {
+        residential: Vec::new(),
+        commercial: (0..builtin).map(|_| Handle::default()).collect(),
+        skyscrapers: Vec::new(),
+        industrial: Vec::new(),
+        custom: vec![CustomBuildingModel {
+            entry,
+            scene: Handle::default(),
+        }],
+        vehicles: Vec::new(),
+        characters: Vec::new(),
+        trees: Vec::new(),
+        props: Vec::new(),
+        service_scenes: Default::default(),
+        utility_scenes: Default::default(),
+        service_meshes: Default::default(),
+        utility_meshes: Default::default(),
+        fallback_material: Handle::default(),
+    }
+}
+
+#[test]
+fn custom_models_join_the_builtin_variants() {
+    let cache = cache_with_custom(10, Vec::new());
+    let picked = (0..400)
+        .filter(|&h| {
+            select_custom_model(&cache, ZoneType::Office, 1, ArchitectureStyle::Mixed, h).is_some()
+        })
+        .count();
+    // One custom model alongside three built-in variants
+    assert_eq!(picked, 100);
+    assert!(
+        select_custom_model(&cache, ZoneType::Industrial, 1, ArchitectureStyle::Mixed, 3).is_none()
+    );
+}
+
+#[test]
+fn custom_models_fill_empty_pools_and_respect_styles() {
+    let cache = cache_with_custom(0, vec![ArchitectureStyle::ModernGlass]);
+    let glass = ArchitectureStyle::ModernGlass;
+    assert!((0..10).all(|h| select_custom_model(&cache, ZoneType::Office, 1, glass, h).is_some()));
+    assert!(
+        select_custom_model(&cache, ZoneType::Office, 1, ArchitectureStyle::Mixed, 0).is_none()
+    );
+}
+
+#[test]
+fn residential_low_all_levels_covered() {
+    let pos = (15, 25);
+    let hashes: Vec<usize> = (1..=3)
+        .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::ResidentialLow))
+        .collect();
+    let unique: std::collections::HashSet<_> = hashes.iter().collect();
+    assert_eq!(unique.len(), 3);
+}
+
+#[test]
+fn commercial_low_all_levels_covered() {
+    let pos = (30, 40);
+    let hashes: Vec<usize> = (1..=3)
+        .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::CommercialLow))
+        .collect();
+    let unique: std::collections::HashSet<_> = hashes.iter().collect();
+    assert_eq!(unique.len(), 3);
+}
+
+#[test]
+fn industrial_all_levels_covered() {
+    let pos = (50, 60);
+    let hashes: Vec<usize> = (1..=3)
+        .map(|lvl| variant_hash(pos.0, pos.1, lvl, ZoneType::Industrial))
+        .collect();
+    let unique: std::collections::HashSet<_> = hashes.iter().collect();
+    assert_eq!(unique.len(), 3);
+}
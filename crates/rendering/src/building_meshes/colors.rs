@@ -74,6 +74,9 @@ pub fn service_base_color(service_type: ServiceType) -> Color {
         ServiceType::SubstanceAbuseTreatmentCenter => Color::srgb(0.50, 0.60, 0.65),
         ServiceType::SeniorCenter => Color::srgb(0.65, 0.75, 0.60),
         ServiceType::YouthCenter => Color::srgb(0.60, 0.65, 0.85),
+        ServiceType::HazmatResponse => Color::srgb(0.95, 0.80, 0.20),
+        ServiceType::Hotel => Color::srgb(0.80, 0.62, 0.45),
+        ServiceType::ConventionCenter => Color::srgb(0.55, 0.65, 0.78),
     }
 }
 
@@ -0,0 +1,316 @@
+//! User-provided building models, the first step toward asset modding.
+//!
+//! glTF models dropped into `assets/mods/buildings/` are listed in a
+//! `manifest.json` beside them that says which zones, levels and district
+//! architecture styles each model suits:
+//!
+//! ```json
+//! { "models": [
+//!     { "file": "bauhaus-block.glb", "zones": ["ResidentialMedium"],
+//!       "min_level": 2, "max_level": 4, "footprint": [1, 1],
+//!       "styles": ["WhiteCity"] }
+//! ] }
+//! ```
+//!
+//! The manifest is read and validated once at startup. Invalid entries are
+//! logged and skipped; valid ones become extra mesh variants for the zones,
+//! levels and styles they list. Models are authored at one unit per cell:
+//! `footprint` is the model's ground size in those units, and the model is
+//! scaled to fit the building's lot.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::path::{Component, Path};
+
+use simulation::config::CELL_SIZE;
+use simulation::district_styles::ArchitectureStyle;
+use simulation::grid::ZoneType;
+
+/// Folder of custom building models, relative to the assets folder.
+pub const CUSTOM_MODELS_DIR: &str = "mods/buildings";
+/// Manifest listing the models in `CUSTOM_MODELS_DIR`.
+pub const MANIFEST_FILE: &str = "manifest.json";
+/// Largest model footprint, in cells, on either side.
+pub const MAX_FOOTPRINT: u8 = 4;
+
+/// Share of the lot a custom model's footprint is scaled to cover.
+const LOT_FILL: f32 = 0.9;
+
+/// One model in the manifest.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomModelEntry {
+    /// `.glb` or `.gltf` file, relative to `CUSTOM_MODELS_DIR`.
+    pub file: String,
+    /// Zones the model is built in.
+    pub zones: Vec<ZoneType>,
+    #[serde(default = "default_min_level")]
+    pub min_level: u8,
+    #[serde(default = "default_max_level")]
+    pub max_level: u8,
+    /// Ground size of the model in cells (model units), width by depth.
+    #[serde(default = "default_footprint")]
+    pub footprint: [u8; 2],
+    /// Extra uniform scale on top of fitting the footprint to the lot.
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    /// District styles the model is used in; empty for every style.
+    #[serde(default)]
+    pub styles: Vec<ArchitectureStyle>,
+}
+
+fn default_min_level() -> u8 {
+    1
+}
+
+fn default_max_level() -> u8 {
+    5
+}
+
+fn default_footprint() -> [u8; 2] {
+    [1, 1]
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+impl CustomModelEntry {
+    /// Check the entry. `file_exists` is asked about `file` once the name
+    /// itself is valid.
+    pub fn validate(&self, file_exists: impl Fn(&str) -> bool) -> Result<(), String> {
+        let file = &self.file;
+        if file.trim().is_empty() {
+            return Err("model entry has no file".to_string());
+        }
+        let lower = file.to_ascii_lowercase();
+        if !lower.ends_with(".glb") && !lower.ends_with(".gltf") {
+            return Err(format!("'{file}' is not a .glb or .gltf file"));
+        }
+        if !Path::new(file)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(format!("'{file}' must stay inside {CUSTOM_MODELS_DIR}"));
+        }
+        if !file_exists(file) {
+            return Err(format!("'{file}' not found in {CUSTOM_MODELS_DIR}"));
+        }
+        if self.zones.is_empty() || self.zones.contains(&ZoneType::None) {
+            return Err(format!("'{file}' must list one or more building zones"));
+        }
+        let top_level = self.zones.iter().map(|z| z.max_level()).max().unwrap_or(0);
+        if self.min_level == 0 || self.min_level > self.max_level || self.min_level > top_level {
+            return Err(format!(
+                "'{file}' has levels {}..={} but its zones go up to level {top_level}",
+                self.min_level, self.max_level
+            ));
+        }
+        if !self
+            .footprint
+            .iter()
+            .all(|side| (1..=MAX_FOOTPRINT).contains(side))
+        {
+            return Err(format!(
+                "'{file}' footprint {:?} must be 1 to {MAX_FOOTPRINT} cells a side",
+                self.footprint
+            ));
+        }
+        if !self.scale.is_finite() || self.scale <= 0.0 {
+            return Err(format!("'{file}' scale must be positive"));
+        }
+        Ok(())
+    }
+
+    /// Whether the model may be used for a building of `zone` and `level`
+    /// in a district of `style`.
+    pub fn matches(&self, zone: ZoneType, level: u8, style: ArchitectureStyle) -> bool {
+        self.zones.contains(&zone)
+            && (self.min_level..=self.max_level).contains(&level)
+            && (self.styles.is_empty() || self.styles.contains(&style))
+    }
+
+    /// Uniform scale that fits the model's footprint to a lot.
+    pub fn lot_scale(&self) -> f32 {
+        let cells = self.footprint[0].max(self.footprint[1]).max(1) as f32;
+        CELL_SIZE * LOT_FILL / cells * self.scale
+    }
+
+    /// Path of the model for the asset server.
+    pub fn asset_path(&self) -> String {
+        format!("{CUSTOM_MODELS_DIR}/{}", self.file)
+    }
+}
+
+/// The manifest file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomModelManifest {
+    pub models: Vec<CustomModelEntry>,
+}
+
+impl CustomModelManifest {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))
+    }
+
+    /// Split the entries into valid ones and an error for each invalid one.
+    pub fn validated(
+        self,
+        file_exists: impl Fn(&str) -> bool,
+    ) -> (Vec<CustomModelEntry>, Vec<String>) {
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+        for entry in self.models {
+            match entry.validate(&file_exists) {
+                Ok(()) => valid.push(entry),
+                Err(e) => errors.push(e),
+            }
+        }
+        (valid, errors)
+    }
+}
+
+/// A validated custom model and its scene.
+#[derive(Debug, Clone)]
+pub struct CustomBuildingModel {
+    pub entry: CustomModelEntry,
+    pub scene: Handle<Scene>,
+}
+
+/// Read and validate the manifest and start loading its models. A missing
+/// manifest means no custom models; an unreadable one is logged and ignored.
+pub(crate) fn load_custom_models(
+    load_scene: &dyn Fn(String) -> Handle<Scene>,
+) -> Vec<CustomBuildingModel> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use bevy::asset::io::file::FileAssetReader;
+
+        // `AssetPlugin` reads from the default "assets" folder.
+        let dir = FileAssetReader::get_base_path()
+            .join("assets")
+            .join(CUSTOM_MODELS_DIR);
+        let path = dir.join(MANIFEST_FILE);
+        let manifest = match std::fs::read_to_string(&path) {
+            Ok(json) => match CustomModelManifest::from_json(&json) {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    warn!("Ignoring custom building models in {}: {e}", path.display());
+                    return Vec::new();
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(e) => {
+                warn!("Failed to read {}: {e}", path.display());
+                return Vec::new();
+            }
+        };
+
+        let (valid, errors) = manifest.validated(|file| dir.join(file).is_file());
+        for e in &errors {
+            warn!("Skipping custom building model: {e}");
+        }
+        info!("Loaded {} custom building model(s)", valid.len());
+        valid
+            .into_iter()
+            .map(|entry| CustomBuildingModel {
+                scene: load_scene(entry.asset_path()),
+                entry,
+            })
+            .collect()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = load_scene;
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(json: &str) -> CustomModelEntry {
+        serde_json::from_str(json).expect("valid entry")
+    }
+
+    #[test]
+    fn test_manifest_defaults() {
+        let manifest = CustomModelManifest::from_json(
+            r#"{ "models": [{ "file": "a.glb", "zones": ["Office"] }] }"#,
+        )
+        .expect("valid manifest");
+        let model = &manifest.models[0];
+        assert_eq!((model.min_level, model.max_level), (1, 5));
+        assert_eq!(model.footprint, [1, 1]);
+        assert!(model.styles.is_empty());
+        assert_eq!(model.asset_path(), "mods/buildings/a.glb");
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let err = CustomModelManifest::from_json(
+            r#"{ "models": [{ "file": "a.glb", "zones": ["Office"], "levle": 2 }] }"#,
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_entries() {
+        let exists = |_: &str| true;
+        let ok = entry(r#"{ "file": "tower.gltf", "zones": ["CommercialHigh"] }"#);
+        assert!(ok.validate(exists).is_ok());
+
+        for bad in [
+            r#"{ "file": "tower.obj", "zones": ["Office"] }"#,
+            r#"{ "file": "../tower.glb", "zones": ["Office"] }"#,
+            r#"{ "file": "tower.glb", "zones": [] }"#,
+            r#"{ "file": "tower.glb", "zones": ["None"] }"#,
+            r#"{ "file": "tower.glb", "zones": ["Office"], "min_level": 0 }"#,
+            r#"{ "file": "house.glb", "zones": ["ResidentialLow"], "min_level": 4 }"#,
+            r#"{ "file": "tower.glb", "zones": ["Office"], "footprint": [5, 1] }"#,
+            r#"{ "file": "tower.glb", "zones": ["Office"], "scale": 0.0 }"#,
+        ] {
+            assert!(entry(bad).validate(exists).is_err(), "{bad} should fail");
+        }
+        assert!(ok.validate(|_| false).is_err(), "missing files fail");
+    }
+
+    #[test]
+    fn test_validated_keeps_good_entries() {
+        let manifest = CustomModelManifest::from_json(
+            r#"{ "models": [
+                { "file": "good.glb", "zones": ["Industrial"] },
+                { "file": "missing.glb", "zones": ["Industrial"] }
+            ] }"#,
+        )
+        .expect("valid manifest");
+        let (valid, errors) = manifest.validated(|file| file == "good.glb");
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].file, "good.glb");
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_matches_zone_level_and_style() {
+        let model = entry(
+            r#"{ "file": "a.glb", "zones": ["ResidentialMedium"], "min_level": 2,
+                 "max_level": 3, "styles": ["WhiteCity"] }"#,
+        );
+        let white = ArchitectureStyle::WhiteCity;
+        assert!(model.matches(ZoneType::ResidentialMedium, 2, white));
+        assert!(!model.matches(ZoneType::ResidentialMedium, 1, white));
+        assert!(!model.matches(ZoneType::ResidentialHigh, 2, white));
+        assert!(!model.matches(ZoneType::ResidentialMedium, 2, ArchitectureStyle::Mixed));
+    }
+
+    #[test]
+    fn test_lot_scale_fits_footprint() {
+        let single = entry(r#"{ "file": "a.glb", "zones": ["Office"] }"#);
+        let double = entry(r#"{ "file": "a.glb", "zones": ["Office"], "footprint": [2, 1] }"#);
+        assert!((single.lot_scale() - 2.0 * double.lot_scale()).abs() < 1e-5);
+        assert!(single.lot_scale() < CELL_SIZE);
+    }
+}
@@ -0,0 +1,243 @@
+//! Instanced model spawning: GLB scenes flattened into shared mesh parts.
+//!
+//! Spawning a `SceneRoot` per building copies the glTF node hierarchy into
+//! every instance through the scene spawner. Each loaded scene is instead
+//! flattened once into its mesh primitives, and buildings, trees, street
+//! lamps and parked cars spawn those as plain `Mesh3d` children sharing the
+//! scene's mesh and material handles. Bevy batches every copy of a variant
+//! into one instanced draw per primitive, and the scene spawner and the
+//! intermediate glTF node entities drop out of the frame entirely.
+
+use std::collections::HashMap;
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::render::mesh::skinning::SkinnedMesh;
+
+/// One mesh primitive of a flattened scene, positioned relative to the
+/// scene root.
+#[derive(Debug, Clone)]
+pub struct ModelPart {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+    pub transform: Transform,
+}
+
+/// Marker for entities spawned from flattened parts rather than a
+/// `SceneRoot`, holding the scene they were built from.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstancedModel(pub AssetId<Scene>);
+
+/// Marker for the mesh children spawned from a `ModelPart`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InstancedPart;
+
+/// Mesh parts of every loaded scene that can be instanced.
+#[derive(Resource)]
+pub struct InstancedModels {
+    /// When false every model spawns as a `SceneRoot`; used to benchmark
+    /// the two paths against each other.
+    pub enabled: bool,
+    parts: HashMap<AssetId<Scene>, Vec<ModelPart>>,
+}
+
+impl Default for InstancedModels {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            parts: HashMap::new(),
+        }
+    }
+}
+
+impl InstancedModels {
+    /// Record the parts of `scene`.
+    pub fn insert(&mut self, scene: AssetId<Scene>, parts: Vec<ModelPart>) {
+        self.parts.insert(scene, parts);
+    }
+
+    /// Parts of `scene`, if it has been flattened and instancing is on.
+    pub fn parts(&self, scene: &Handle<Scene>) -> Option<&[ModelPart]> {
+        if !self.enabled {
+            return None;
+        }
+        self.parts.get(&scene.id()).map(Vec::as_slice)
+    }
+
+    /// Number of flattened scenes.
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Whether every scene in `scenes` has finished loading one way or
+    /// another, so props spawned now get their final form.
+    pub fn settled(&self, scenes: &[Handle<Scene>], asset_server: &AssetServer) -> bool {
+        scenes.iter().all(|scene| {
+            self.parts.contains_key(&scene.id())
+                || !matches!(
+                    asset_server.get_load_state(scene.id()),
+                    Some(LoadState::NotLoaded | LoadState::Loading)
+                )
+        })
+    }
+
+    /// Spawn `bundle` showing `scene`: as shared-handle mesh children when
+    /// the scene has been flattened, otherwise as a `SceneRoot`.
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        scene: Handle<Scene>,
+        bundle: impl Bundle,
+    ) -> Entity {
+        let Some(parts) = self.parts(&scene) else {
+            return commands.spawn((bundle, SceneRoot(scene))).id();
+        };
+        commands
+            .spawn((bundle, InstancedModel(scene.id())))
+            .with_children(|children| spawn_parts(children, parts))
+            .id()
+    }
+
+    /// Show `scene` on `entity` in place of its current model. `old_parts`
+    /// are the entity's `InstancedPart` children when it was spawned
+    /// instanced; entities showing a `SceneRoot` keep using one.
+    pub fn replace(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        old_parts: Option<Vec<Entity>>,
+        scene: Handle<Scene>,
+    ) {
+        let Some(old_parts) = old_parts else {
+            commands.entity(entity).insert(SceneRoot(scene));
+            return;
+        };
+        for part in old_parts {
+            commands.entity(part).despawn_recursive();
+        }
+        match self.parts(&scene) {
+            Some(parts) => {
+                commands
+                    .entity(entity)
+                    .insert(InstancedModel(scene.id()))
+                    .with_children(|children| spawn_parts(children, parts));
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .remove::<InstancedModel>()
+                    .insert(SceneRoot(scene));
+            }
+        }
+    }
+}
+
+fn spawn_parts(children: &mut ChildBuilder, parts: &[ModelPart]) {
+    for part in parts {
+        children.spawn((
+            InstancedPart,
+            Mesh3d(part.mesh.clone()),
+            MeshMaterial3d(part.material.clone()),
+            part.transform,
+        ));
+    }
+}
+
+/// Flatten `scene` into its mesh primitives. Returns `None` for scenes with
+/// skinned meshes, which need their joint hierarchy and stay scenes.
+pub fn flatten_scene(scene: &Scene) -> Option<Vec<ModelPart>> {
+    let world = &scene.world;
+    let mut parts = Vec::new();
+    for entity in world.iter_entities() {
+        if entity.contains::<SkinnedMesh>() {
+            return None;
+        }
+        let (Some(mesh), Some(material)) = (
+            entity.get::<Mesh3d>(),
+            entity.get::<MeshMaterial3d<StandardMaterial>>(),
+        ) else {
+            continue;
+        };
+        parts.push(ModelPart {
+            mesh: mesh.0.clone(),
+            material: material.0.clone(),
+            transform: transform_from_root(world, entity.id()),
+        });
+    }
+    Some(parts)
+}
+
+/// Transform of `entity` relative to the root of its scene.
+fn transform_from_root(world: &World, entity: Entity) -> Transform {
+    let mut global = GlobalTransform::IDENTITY;
+    let mut current = Some(entity);
+    while let Some(e) = current {
+        let local = world.get::<Transform>(e).copied().unwrap_or_default();
+        global = GlobalTransform::from(local) * global;
+        current = world.get::<Parent>(e).map(Parent::get);
+    }
+    global.compute_transform()
+}
+
+/// Flatten scenes as they finish loading.
+pub fn flatten_loaded_scenes(
+    scenes: Option<Res<Assets<Scene>>>,
+    mut models: ResMut<InstancedModels>,
+) {
+    let Some(scenes) = scenes else {
+        return;
+    };
+    if !scenes.is_changed() {
+        return;
+    }
+    for (id, scene) in scenes.iter() {
+        if models.parts.contains_key(&id) {
+            continue;
+        }
+        if let Some(parts) = flatten_scene(scene) {
+            models.insert(id, parts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene_with_nested_mesh() -> Scene {
+        let mut world = World::new();
+        world
+            .spawn(Transform::from_xyz(0.0, 2.0, 0.0).with_scale(Vec3::splat(2.0)))
+            .with_children(|root| {
+                root.spawn((
+                    Mesh3d(Handle::default()),
+                    MeshMaterial3d::<StandardMaterial>(Handle::default()),
+                    Transform::from_xyz(1.0, 0.0, 0.0),
+                ));
+            });
+        Scene::new(world)
+    }
+
+    #[test]
+    fn test_flattened_parts_are_placed_relative_to_the_scene_root() {
+        let parts = flatten_scene(&scene_with_nested_mesh()).expect("static scene");
+        assert_eq!(parts.len(), 1);
+        let t = parts[0].transform;
+        assert!((t.translation - Vec3::new(2.0, 2.0, 0.0)).length() < 1e-5);
+        assert!((t.scale - Vec3::splat(2.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_disabled_instancing_hides_parts() {
+        let mut models = InstancedModels::default();
+        let scene = Handle::<Scene>::default();
+        models.insert(scene.id(), vec![]);
+        assert!(models.parts(&scene).is_some());
+        models.enabled = false;
+        assert!(models.parts(&scene).is_none());
+    }
+}
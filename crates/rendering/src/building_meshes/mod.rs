@@ -3,6 +3,8 @@
 //! Split into sub-modules by domain:
 //! - `model_cache`: The `BuildingModelCache` resource and scale helpers
 //! - `model_loading`: Startup system that loads all GLB models
+//! - `custom_models`: User-provided glTF models listed in a mod manifest
+//! - `instancing`: GLB scenes flattened into shared mesh parts for instancing
 //! - `mesh_data`: The `MeshData` helper for procedural mesh construction
 //! - `service_scene_map`: ServiceType → GLB asset path mapping
 //! - `utility_scene_map`: UtilityType → GLB asset path mapping
@@ -17,6 +19,8 @@
 //! - `colors`: Color query functions for UI/minimap
 
 mod colors;
+mod custom_models;
+mod instancing;
 mod mesh_data;
 mod model_cache;
 mod model_loading;
@@ -33,6 +37,13 @@ mod utility_scene_map;
 
 // Re-export everything so callers see the same public API as before.
 pub use colors::{service_base_color, utility_base_color, zone_base_color};
+pub use custom_models::{
+    CustomBuildingModel, CustomModelEntry, CustomModelManifest, CUSTOM_MODELS_DIR, MANIFEST_FILE,
+    MAX_FOOTPRINT,
+};
+pub use instancing::{
+    flatten_loaded_scenes, flatten_scene, InstancedModel, InstancedModels, InstancedPart, ModelPart,
+};
 pub use mesh_data::MeshData;
 pub use model_cache::{building_scale, service_building_scale, BuildingModelCache};
 pub use model_loading::load_building_models;
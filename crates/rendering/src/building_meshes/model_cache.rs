@@ -9,6 +9,7 @@ use simulation::grid::ZoneType;
 use simulation::services::ServiceType;
 use simulation::utilities::UtilityType;
 
+use super::custom_models::CustomBuildingModel;
 use super::service_civic::generate_civic_mesh;
 use super::service_education::generate_education_mesh;
 use super::service_emergency::generate_emergency_mesh;
@@ -133,6 +134,8 @@ pub struct BuildingModelCache {
     pub skyscrapers: Vec<Handle<Scene>>,
     /// Industrial building scenes
     pub industrial: Vec<Handle<Scene>>,
+    /// User-provided zone building models from the mod manifest
+    pub custom: Vec<CustomBuildingModel>,
     /// Vehicle scenes (sedan, SUV, van, truck, etc.)
     pub vehicles: Vec<Handle<Scene>>,
     /// Character scenes (male/female variants)
@@ -6,11 +6,13 @@ use std::collections::HashMap;
 use simulation::services::ServiceType;
 use simulation::utilities::UtilityType;
 
+use super::custom_models::load_custom_models;
 use super::model_cache::BuildingModelCache;
 use super::service_scene_map::service_scene_path;
 use super::utility_scene_map::utility_scene_path;
 
-/// Startup system: load all GLB models from assets/models/ directory
+/// Startup system: load all GLB models from assets/models/ directory, plus
+/// any custom building models listed in the mod manifest
 pub fn load_building_models(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -23,6 +25,7 @@ pub fn load_building_models(
     let residential = load_residential(&load_scene);
     let (commercial, skyscrapers) = load_commercial(&load_scene);
     let industrial = load_industrial(&load_scene);
+    let custom = load_custom_models(&load_scene);
     let vehicles = load_vehicles(&load_scene);
     let characters = load_characters(&load_scene);
     let trees = load_trees(&load_scene);
@@ -41,6 +44,7 @@ pub fn load_building_models(
         commercial,
         skyscrapers,
         industrial,
+        custom,
         vehicles,
         characters,
         trees,
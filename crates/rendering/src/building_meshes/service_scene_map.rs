@@ -101,7 +101,10 @@ pub fn service_scene_path(service_type: ServiceType) -> Option<&'static str> {
         | ServiceType::FerryPier
         | ServiceType::SmallAirstrip
         | ServiceType::RegionalAirport
-        | ServiceType::InternationalAirport => return None,
+        | ServiceType::InternationalAirport
+        | ServiceType::HazmatResponse
+        | ServiceType::Hotel
+        | ServiceType::ConventionCenter => return None,
     };
     Some(path)
 }
@@ -8,7 +8,9 @@ use simulation::trees::PlantedTree;
 use simulation::utilities::UtilitySource;
 
 use crate::building_mesh_variants::BuildingVariant;
-use crate::building_meshes::{building_scale, service_building_scale, BuildingModelCache};
+use crate::building_meshes::{
+    building_scale, service_building_scale, BuildingModelCache, InstancedModels,
+};
 use crate::building_variant_proportions;
 
 /// Marker for 3D building entities (both GLB scenes and procedural meshes)
@@ -17,11 +19,11 @@ pub struct BuildingMesh3d {
     pub tracked_entity: Entity,
 }
 
-/// Marker to distinguish zone buildings (GLB SceneRoot) from procedural service/utility meshes
+/// Marker to distinguish zone buildings (GLB models) from procedural service/utility meshes
 #[derive(Component)]
 pub struct ZoneBuilding;
 
-/// Marker for service/utility buildings spawned as GLB SceneRoot
+/// Marker for service/utility buildings spawned from GLB models
 #[derive(Component)]
 pub struct ServiceUtilitySceneBuilding;
 
@@ -58,9 +60,7 @@ fn building_facing_road(grid: &WorldGrid, gx: usize, gy: usize, hash: usize) ->
 
 /// Compute the position hash used for minor per-building variation.
 fn position_hash(grid_x: usize, grid_y: usize) -> usize {
-    grid_x
-        .wrapping_mul(7)
-        .wrapping_add(grid_y.wrapping_mul(13))
+    grid_x.wrapping_mul(7).wrapping_add(grid_y.wrapping_mul(13))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -73,6 +73,7 @@ pub fn spawn_building_meshes(
     existing: Query<&BuildingMesh3d>,
     grid: Res<WorldGrid>,
     mut model_cache: ResMut<BuildingModelCache>,
+    models: Res<InstancedModels>,
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
     if buildings.is_empty() && services.is_empty() && utilities.is_empty() {
@@ -82,7 +83,7 @@ pub fn spawn_building_meshes(
     let tracked: std::collections::HashSet<Entity> =
         existing.iter().map(|b| b.tracked_entity).collect();
 
-    // Zone buildings -> spawn as GLTF SceneRoot
+    // Zone buildings -> spawn the GLTF model, instanced once its scene is flattened
     for (entity, building) in &buildings {
         if tracked.contains(&entity) {
             continue;
@@ -113,17 +114,20 @@ pub fn spawn_building_meshes(
             Vec3::splat(base_scale)
         };
 
-        commands.spawn((
-            BuildingMesh3d {
-                tracked_entity: entity,
-            },
-            ZoneBuilding,
-            SceneRoot(scene_handle),
-            Transform::from_xyz(wx, wy, wz)
-                .with_rotation(Quat::from_rotation_y(yaw))
-                .with_scale(build_scale),
-            Visibility::default(),
-        ));
+        models.spawn(
+            &mut commands,
+            scene_handle,
+            (
+                BuildingMesh3d {
+                    tracked_entity: entity,
+                },
+                ZoneBuilding,
+                Transform::from_xyz(wx, wy, wz)
+                    .with_rotation(Quat::from_rotation_y(yaw))
+                    .with_scale(build_scale),
+                Visibility::default(),
+            ),
+        );
     }
 
     // Service buildings -> prefer GLB scene, fall back to procedural mesh
@@ -143,17 +147,20 @@ pub fn spawn_building_meshes(
         if let Some(scene_handle) = model_cache.get_service_scene(service.service_type) {
             let scale = service_building_scale(fw, fh);
             let yaw = building_facing_road(&grid, service.grid_x, service.grid_y, hash);
-            commands.spawn((
-                BuildingMesh3d {
-                    tracked_entity: entity,
-                },
-                ServiceUtilitySceneBuilding,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx + offset_x, wy, wz + offset_z)
-                    .with_rotation(Quat::from_rotation_y(yaw))
-                    .with_scale(Vec3::splat(scale)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    BuildingMesh3d {
+                        tracked_entity: entity,
+                    },
+                    ServiceUtilitySceneBuilding,
+                    Transform::from_xyz(wx + offset_x, wy, wz + offset_z)
+                        .with_rotation(Quat::from_rotation_y(yaw))
+                        .with_scale(Vec3::splat(scale)),
+                    Visibility::default(),
+                ),
+            );
         } else {
             // Fallback: procedural mesh
             let mesh_handle =
@@ -186,17 +193,20 @@ pub fn spawn_building_meshes(
         if let Some(scene_handle) = model_cache.get_utility_scene(utility.utility_type) {
             let scale = service_building_scale(1, 1);
             let yaw = building_facing_road(&grid, utility.grid_x, utility.grid_y, hash);
-            commands.spawn((
-                BuildingMesh3d {
-                    tracked_entity: entity,
-                },
-                ServiceUtilitySceneBuilding,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx, wy, wz)
-                    .with_rotation(Quat::from_rotation_y(yaw))
-                    .with_scale(Vec3::splat(scale)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    BuildingMesh3d {
+                        tracked_entity: entity,
+                    },
+                    ServiceUtilitySceneBuilding,
+                    Transform::from_xyz(wx, wy, wz)
+                        .with_rotation(Quat::from_rotation_y(yaw))
+                        .with_scale(Vec3::splat(scale)),
+                    Visibility::default(),
+                ),
+            );
         } else {
             // Fallback: procedural mesh
             let mesh_handle =
@@ -222,6 +232,7 @@ pub fn update_building_meshes(
     mesh_sprites: Query<(Entity, &BuildingMesh3d, Option<&ZoneBuilding>)>,
     grid: Res<WorldGrid>,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
 ) {
     if buildings.is_empty() {
         return;
@@ -246,23 +257,44 @@ pub fn update_building_meshes(
                 let yaw = building_facing_road(&grid, building.grid_x, building.grid_y, hash);
                 let scale_var = 0.98 + (hash % 5) as f32 / 100.0;
 
-                commands.entity(sprite_entity).despawn();
-                commands.spawn((
-                    BuildingMesh3d {
-                        tracked_entity: entity,
-                    },
-                    ZoneBuilding,
-                    SceneRoot(scene_handle),
-                    Transform::from_xyz(wx, wy, wz)
-                        .with_rotation(Quat::from_rotation_y(yaw))
-                        .with_scale(Vec3::splat(scale * scale_var)),
-                    Visibility::default(),
-                ));
+                commands.entity(sprite_entity).despawn_recursive();
+                models.spawn(
+                    &mut commands,
+                    scene_handle,
+                    (
+                        BuildingMesh3d {
+                            tracked_entity: entity,
+                        },
+                        ZoneBuilding,
+                        Transform::from_xyz(wx, wy, wz)
+                            .with_rotation(Quat::from_rotation_y(yaw))
+                            .with_scale(Vec3::splat(scale * scale_var)),
+                        Visibility::default(),
+                    ),
+                );
             }
         }
     }
 }
 
+/// Despawns the mesh of a service building whose `ServiceBuilding` changed
+/// (e.g. upgraded to the next tier) so `spawn_building_meshes` rebuilds it
+/// with the new model and footprint.
+pub fn refresh_changed_service_meshes(
+    mut commands: Commands,
+    services: Query<Entity, Changed<ServiceBuilding>>,
+    meshes: Query<(Entity, &BuildingMesh3d)>,
+) {
+    if services.is_empty() {
+        return;
+    }
+    for (mesh_entity, bm) in &meshes {
+        if services.contains(bm.tracked_entity) {
+            commands.entity(mesh_entity).despawn_recursive();
+        }
+    }
+}
+
 /// Gradually increases the y-scale of buildings under construction as they
 /// progress toward completion. When construction finishes (UnderConstruction
 /// removed), snaps scale to the full target.
@@ -294,10 +326,8 @@ pub fn update_construction_visuals(
                 z: 1.0,
             },
             |v| {
-                building_variant_proportions::proportions_for(
-                    building.zone_type,
-                    building.level,
-                )[v.variant_index]
+                building_variant_proportions::proportions_for(building.zone_type, building.level)
+                    [v.variant_index]
             },
         );
 
@@ -331,7 +361,7 @@ pub fn cleanup_orphan_building_meshes(
             || utilities.get(bm.tracked_entity).is_ok();
 
         if !exists {
-            commands.entity(sprite_entity).despawn();
+            commands.entity(sprite_entity).despawn_recursive();
         }
     }
 }
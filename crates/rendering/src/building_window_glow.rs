@@ -0,0 +1,277 @@
+//! Night-time window lighting for zone buildings.
+//!
+//! Every zone building mesh carries a window panel whose emissive material
+//! follows darkness outside, the zone's daily schedule (homes in the
+//! evening, offices during work hours), the building's occupancy and whether
+//! its cell has power.
+
+use bevy::prelude::*;
+
+use simulation::buildings::Building;
+use simulation::day_night_controls::DayNightControls;
+use simulation::grid::{WorldGrid, ZoneType};
+use simulation::time_of_day::GameClock;
+
+use crate::building_render::{BuildingMesh3d, ZoneBuilding};
+use crate::day_night::daylight_blend;
+
+/// Brightness steps a window panel can show, above fully dark.
+const WINDOW_LIGHT_LEVELS: u8 = 4;
+
+/// Emissive strength of a fully lit window panel.
+const WINDOW_EMISSIVE_STRENGTH: f32 = 4.0;
+
+/// Window panel in model space. Zone models are roughly one unit across, so
+/// the panel stands just proud of the front and back facades.
+const WINDOW_PANEL_SIZE: Vec3 = Vec3::new(0.8, 0.5, 1.01);
+const WINDOW_PANEL_CENTER: Vec3 = Vec3::new(0.0, 0.45, 0.0);
+
+/// Emissive window panel parented to a zone building's mesh.
+#[derive(Component)]
+pub struct WindowGlow {
+    pub tracked_entity: Entity,
+    /// Brightness step currently shown, 0 when dark.
+    pub level: u8,
+}
+
+/// Marker for zone building meshes that already carry a `WindowGlow`.
+#[derive(Component)]
+pub struct HasWindowGlow;
+
+/// Colour of a building's lit windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowTint {
+    /// Warm incandescent light of homes.
+    Warm,
+    /// Cool fluorescent light of offices and shops.
+    Cool,
+    /// Orange sodium light of industrial sites.
+    Sodium,
+}
+
+impl WindowTint {
+    const ALL: [WindowTint; 3] = [WindowTint::Warm, WindowTint::Cool, WindowTint::Sodium];
+
+    fn for_zone(zone: ZoneType) -> Self {
+        match zone {
+            ZoneType::Office | ZoneType::CommercialLow | ZoneType::CommercialHigh => Self::Cool,
+            ZoneType::Industrial => Self::Sodium,
+            _ => Self::Warm,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Warm => Color::srgb(1.0, 0.8, 0.5),
+            Self::Cool => Color::srgb(0.75, 0.85, 1.0),
+            Self::Sodium => Color::srgb(1.0, 0.6, 0.25),
+        }
+    }
+}
+
+/// Shared window panel mesh and one material per tint and brightness step,
+/// so lighting changes swap handles rather than creating materials.
+#[derive(Resource, Clone)]
+pub struct WindowGlowAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: Vec<Handle<StandardMaterial>>,
+}
+
+impl WindowGlowAssets {
+    fn material(&self, tint: WindowTint, level: u8) -> Handle<StandardMaterial> {
+        let tint_idx = WindowTint::ALL.iter().position(|&t| t == tint).unwrap_or(0);
+        let step = (level.clamp(1, WINDOW_LIGHT_LEVELS) - 1) as usize;
+        self.materials[tint_idx * WINDOW_LIGHT_LEVELS as usize + step].clone()
+    }
+}
+
+/// Share of a zone's windows in use at `hour`, ignoring daylight: homes
+/// light up in the evening and early morning, offices through the working
+/// day, shops until late, and industry runs night shifts.
+fn window_activity(zone: ZoneType, hour: f32) -> f32 {
+    let hour = hour.rem_euclid(24.0);
+    let residential = match hour {
+        h if (17.0..23.0).contains(&h) => 1.0,
+        h if h >= 23.0 || h < 1.0 => 0.5,
+        h if h < 5.0 => 0.1,
+        h if h < 8.0 => 0.6,
+        _ => 0.2,
+    };
+    let office = match hour {
+        h if (7.0..19.0).contains(&h) => 1.0,
+        h if (19.0..22.0).contains(&h) => 0.4,
+        _ => 0.05,
+    };
+    let shops = if (8.0..22.0).contains(&hour) {
+        1.0
+    } else {
+        0.15
+    };
+    match zone {
+        ZoneType::None => 0.0,
+        ZoneType::ResidentialLow | ZoneType::ResidentialMedium | ZoneType::ResidentialHigh => {
+            residential
+        }
+        ZoneType::Office => office,
+        ZoneType::CommercialLow | ZoneType::CommercialHigh => shops,
+        ZoneType::MixedUse => f32::max(residential, shops),
+        ZoneType::Industrial => 0.6,
+    }
+}
+
+/// Brightness step of a building's windows: how dark it is outside, how
+/// busy the building is at this hour and how full it is. Buildings without
+/// power stay dark.
+fn window_light_level(building: &Building, hour: f32, darkness: f32, powered: bool) -> u8 {
+    if !powered || building.capacity == 0 {
+        return 0;
+    }
+    let occupancy = (building.occupants as f32 / building.capacity as f32).clamp(0.0, 1.0);
+    let brightness =
+        darkness.clamp(0.0, 1.0) * window_activity(building.zone_type, hour) * occupancy.sqrt();
+    (brightness * WINDOW_LIGHT_LEVELS as f32).round() as u8
+}
+
+/// Give each zone building mesh a dark window panel for
+/// `update_window_lighting` to light.
+pub fn spawn_window_glow(
+    mut commands: Commands,
+    new_meshes: Query<(Entity, &BuildingMesh3d), (With<ZoneBuilding>, Without<HasWindowGlow>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    glow_assets: Option<Res<WindowGlowAssets>>,
+) {
+    if new_meshes.is_empty() {
+        return;
+    }
+
+    let assets = if let Some(a) = glow_assets {
+        a.clone()
+    } else {
+        let mesh = meshes.add(Cuboid::from_size(WINDOW_PANEL_SIZE));
+        let mut handles = Vec::new();
+        for tint in WindowTint::ALL {
+            for level in 1..=WINDOW_LIGHT_LEVELS {
+                let share = level as f32 / WINDOW_LIGHT_LEVELS as f32;
+                handles.push(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.08, 0.08, 0.1),
+                    emissive: tint.color().to_linear() * share * WINDOW_EMISSIVE_STRENGTH,
+                    perceptual_roughness: 0.3,
+                    ..default()
+                }));
+            }
+        }
+        let a = WindowGlowAssets {
+            mesh,
+            materials: handles,
+        };
+        commands.insert_resource(a.clone());
+        a
+    };
+
+    for (mesh_entity, bm) in &new_meshes {
+        commands.entity(mesh_entity).insert(HasWindowGlow);
+        let glow = commands
+            .spawn((
+                WindowGlow {
+                    tracked_entity: bm.tracked_entity,
+                    level: 0,
+                },
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material(WindowTint::Warm, 1)),
+                Transform::from_translation(WINDOW_PANEL_CENTER),
+                Visibility::Hidden,
+            ))
+            .id();
+        commands.entity(mesh_entity).add_child(glow);
+    }
+}
+
+/// Light building windows after dusk from the time of day, each building's
+/// occupancy and whether its cell has power.
+pub fn update_window_lighting(
+    controls: Res<DayNightControls>,
+    clock: Res<GameClock>,
+    grid: Res<WorldGrid>,
+    glow_assets: Option<Res<WindowGlowAssets>>,
+    buildings: Query<&Building>,
+    mut glows: Query<(
+        &mut WindowGlow,
+        &mut MeshMaterial3d<StandardMaterial>,
+        &mut Visibility,
+    )>,
+) {
+    let Some(assets) = glow_assets else {
+        return;
+    };
+    let hour = controls.effective_hour();
+    let darkness = 1.0 - daylight_blend(hour, clock.sunrise(), clock.sunset());
+
+    for (mut glow, mut material, mut visibility) in &mut glows {
+        let Ok(building) = buildings.get(glow.tracked_entity) else {
+            continue;
+        };
+        let powered = grid.get(building.grid_x, building.grid_y).has_power;
+        let level = window_light_level(building, hour, darkness, powered);
+        if level == glow.level {
+            continue;
+        }
+        glow.level = level;
+        if level == 0 {
+            *visibility = Visibility::Hidden;
+        } else {
+            material.0 = assets.material(WindowTint::for_zone(building.zone_type), level);
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn building(zone_type: ZoneType, occupants: u32) -> Building {
+        Building {
+            zone_type,
+            level: 1,
+            grid_x: 0,
+            grid_y: 0,
+            capacity: 20,
+            occupants,
+        }
+    }
+
+    #[test]
+    fn test_homes_light_up_in_the_evening_and_offices_by_day() {
+        let home = building(ZoneType::ResidentialLow, 20);
+        let office = building(ZoneType::Office, 20);
+        assert_eq!(
+            window_light_level(&home, 20.0, 1.0, true),
+            WINDOW_LIGHT_LEVELS
+        );
+        assert!(window_light_level(&office, 20.0, 1.0, true) < WINDOW_LIGHT_LEVELS);
+        assert_eq!(
+            window_light_level(&office, 17.5, 1.0, true),
+            WINDOW_LIGHT_LEVELS
+        );
+        assert_eq!(window_light_level(&home, 3.0, 1.0, true), 0);
+    }
+
+    #[test]
+    fn test_windows_stay_dark_in_daylight_without_power_or_people() {
+        let home = building(ZoneType::ResidentialHigh, 20);
+        assert_eq!(window_light_level(&home, 20.0, 0.0, true), 0);
+        assert_eq!(window_light_level(&home, 20.0, 1.0, false), 0);
+        assert_eq!(
+            window_light_level(&building(ZoneType::ResidentialHigh, 0), 20.0, 1.0, true),
+            0
+        );
+    }
+
+    #[test]
+    fn test_fuller_buildings_are_brighter() {
+        let half = window_light_level(&building(ZoneType::CommercialHigh, 3), 21.0, 1.0, true);
+        let full = window_light_level(&building(ZoneType::CommercialHigh, 20), 21.0, 1.0, true);
+        assert!(half < full);
+    }
+}
@@ -0,0 +1,194 @@
+use super::*;
+
+/// Helper: extract sRGB components from a Color.
+fn rgb(c: Color) -> (f32, f32, f32) {
+    let s = c.to_srgba();
+    (s.red, s.green, s.blue)
+}
+
+#[test]
+fn viridis_endpoints() {
+    let (r0, g0, b0) = rgb(VIRIDIS.sample(0.0));
+    // Should be dark purple
+    assert!(
+        r0 < 0.30 && g0 < 0.05 && b0 > 0.30,
+        "viridis(0) should be dark purple"
+    );
+
+    let (r1, g1, b1) = rgb(VIRIDIS.sample(1.0));
+    // Should be bright yellow
+    assert!(
+        r1 > 0.90 && g1 > 0.85 && b1 < 0.20,
+        "viridis(1) should be bright yellow"
+    );
+}
+
+#[test]
+fn inferno_endpoints() {
+    let (r0, g0, b0) = rgb(INFERNO.sample(0.0));
+    // Should be near-black
+    assert!(
+        r0 < 0.05 && g0 < 0.05 && b0 < 0.05,
+        "inferno(0) should be near-black"
+    );
+
+    let (r1, g1, _b1) = rgb(INFERNO.sample(1.0));
+    // Should be pale yellow
+    assert!(r1 > 0.90 && g1 > 0.90, "inferno(1) should be pale yellow");
+}
+
+#[test]
+fn cividis_endpoints() {
+    let (r0, _g0, b0) = rgb(CIVIDIS.sample(0.0));
+    // Should be dark navy
+    assert!(r0 < 0.05 && b0 > 0.25, "cividis(0) should be dark navy");
+
+    let (r1, g1, _b1) = rgb(CIVIDIS.sample(1.0));
+    // Should be warm yellow
+    assert!(r1 > 0.85 && g1 > 0.70, "cividis(1) should be warm yellow");
+}
+
+#[test]
+fn ramp_clamps_out_of_range() {
+    let below = rgb(VIRIDIS.sample(-0.5));
+    let at_zero = rgb(VIRIDIS.sample(0.0));
+    assert_eq!(below, at_zero, "t < 0 should clamp to t = 0");
+
+    let above = rgb(VIRIDIS.sample(1.5));
+    let at_one = rgb(VIRIDIS.sample(1.0));
+    assert_eq!(above, at_one, "t > 1 should clamp to t = 1");
+}
+
+#[test]
+fn ramp_midpoint_interpolation() {
+    // At t=0.5 the color should be between endpoints (not equal to either).
+    let (r0, g0, b0) = rgb(VIRIDIS.sample(0.0));
+    let (r1, g1, b1) = rgb(VIRIDIS.sample(1.0));
+    let (rm, gm, bm) = rgb(VIRIDIS.sample(0.5));
+
+    // Midpoint should differ from both endpoints
+    let diff_lo = (rm - r0).abs() + (gm - g0).abs() + (bm - b0).abs();
+    let diff_hi = (rm - r1).abs() + (gm - g1).abs() + (bm - b1).abs();
+    assert!(diff_lo > 0.1, "midpoint should differ from start");
+    assert!(diff_hi > 0.1, "midpoint should differ from end");
+}
+
+#[test]
+fn ramp_monotonic_luminance_viridis() {
+    // Viridis should have roughly monotonically increasing luminance.
+    // We use a simple relative luminance approximation: 0.2126R + 0.7152G + 0.0722B
+    let steps = 16;
+    let mut prev_lum = 0.0_f32;
+    for i in 0..=steps {
+        let t = i as f32 / steps as f32;
+        let (r, g, b) = rgb(VIRIDIS.sample(t));
+        let lum = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        // Allow small tolerance for perceptual uniformity approximation
+        assert!(
+            lum >= prev_lum - 0.02,
+            "viridis luminance should be roughly monotonic at t={t}: {lum} < {prev_lum}"
+        );
+        prev_lum = lum;
+    }
+}
+
+#[test]
+fn sample_rgba_returns_full_alpha() {
+    let c = VIRIDIS.sample_rgba(0.5);
+    assert_eq!(c[3], 1.0, "sample_rgba alpha should be 1.0");
+}
+
+#[test]
+fn blend_tint_identity() {
+    let base = Color::srgb(0.5, 0.5, 0.5);
+    let tint = Color::srgba(1.0, 0.0, 0.0, 0.0);
+    let result = rgb(blend_tint(base, tint));
+    let expected = rgb(base);
+    assert!(
+        (result.0 - expected.0).abs() < 1e-5
+            && (result.1 - expected.1).abs() < 1e-5
+            && (result.2 - expected.2).abs() < 1e-5,
+        "blend with alpha=0 should return base unchanged"
+    );
+}
+
+#[test]
+fn darken_halves_rgb() {
+    let base = Color::srgb(0.8, 0.6, 0.4);
+    let (r, g, b) = rgb(darken(base, 0.5));
+    assert!((r - 0.4).abs() < 1e-5);
+    assert!((g - 0.3).abs() < 1e-5);
+    assert!((b - 0.2).abs() < 1e-5);
+}
+
+#[test]
+fn groundwater_level_endpoints() {
+    let (r0, _g0, _b0) = rgb(GROUNDWATER_LEVEL.sample(0.0));
+    // Should be red/warm (dry)
+    assert!(r0 > 0.60, "groundwater_level(0) should be reddish (dry)");
+
+    let (r1, _g1, b1) = rgb(GROUNDWATER_LEVEL.sample(1.0));
+    // Should be deep blue (saturated)
+    assert!(
+        b1 > r1,
+        "groundwater_level(1) should be blue (saturated), got r={r1} b={b1}"
+    );
+}
+
+#[test]
+fn groundwater_quality_endpoints() {
+    let (r0, g0, _b0) = rgb(GROUNDWATER_QUALITY.sample(0.0));
+    // Should be brown (contaminated)
+    assert!(
+        r0 > g0,
+        "groundwater_quality(0) should be brownish, got r={r0} g={g0}"
+    );
+
+    let (_r1, g1, _b1) = rgb(GROUNDWATER_QUALITY.sample(1.0));
+    // Should be green (clean)
+    assert!(
+        g1 > 0.70,
+        "groundwater_quality(1) should be green, got g={g1}"
+    );
+}
+
+#[test]
+fn groundwater_ramps_different_from_each_other() {
+    let level_mid = rgb(GROUNDWATER_LEVEL.sample(0.5));
+    let quality_mid = rgb(GROUNDWATER_QUALITY.sample(0.5));
+    let diff = (level_mid.0 - quality_mid.0).abs()
+        + (level_mid.1 - quality_mid.1).abs()
+        + (level_mid.2 - quality_mid.2).abs();
+    assert!(
+        diff > 0.1,
+        "groundwater level and quality ramps should differ at midpoint"
+    );
+}
+
+#[test]
+fn beach_sand_endpoints() {
+    let (r0, g0, _b0) = rgb(BEACH_SAND.sample(0.0));
+    // Should be red (washed away)
+    assert!(
+        r0 > 0.60 && g0 < 0.25,
+        "beach_sand(0) should be red, got r={r0} g={g0}"
+    );
+
+    let (r1, g1, b1) = rgb(BEACH_SAND.sample(1.0));
+    // Should be pale sand (full beach)
+    assert!(
+        r1 > 0.90 && g1 > 0.85 && b1 > 0.65,
+        "beach_sand(1) should be pale sand, got r={r1} g={g1} b={b1}"
+    );
+}
+
+#[test]
+fn binary_palette_on_off() {
+    let base = Color::srgb(0.5, 0.5, 0.5);
+    let on_color = overlay_binary(base, &POWER_PALETTE, true);
+    let off_color = overlay_binary(base, &POWER_PALETTE, false);
+    let (on_r, _, _) = rgb(on_color);
+    let (off_r, _, _) = rgb(off_color);
+    // "On" should be more yellow (higher R+G), "Off" more red (higher R relative to G)
+    assert!(on_r != off_r, "on and off colors should differ");
+}
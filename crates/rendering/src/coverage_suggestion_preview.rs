@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use simulation::app_state::AppState;
+use simulation::budget::ExtendedBudget;
+use simulation::config::CELL_SIZE;
+use simulation::coverage_gaps::CoverageGapAnalysis;
+use simulation::grid::WorldGrid;
+use simulation::services::ServiceBuilding;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Outline color for suggested sites.
+const SITE_COLOR: Color = Color::srgba(0.2, 0.9, 1.0, 0.8);
+
+/// Outline color for the previewed site and its coverage disc.
+const PREVIEW_COLOR: Color = Color::srgba(1.0, 0.85, 0.1, 0.9);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Outline each suggested site's footprint, and draw the coverage disc of the
+/// site being previewed in the suggester window.
+pub fn draw_coverage_suggestions(
+    analysis: Res<CoverageGapAnalysis>,
+    ext_budget: Res<ExtendedBudget>,
+    mut gizmos: Gizmos,
+) {
+    let Some(service_type) = analysis.service_type else {
+        return;
+    };
+    let (fw, fh) = ServiceBuilding::footprint(service_type);
+    let y = 0.7; // just above the zone brush preview
+
+    for (i, site) in analysis.suggestions.iter().enumerate() {
+        let previewing = analysis.preview == Some(i);
+        let color = if previewing {
+            PREVIEW_COLOR
+        } else {
+            SITE_COLOR
+        };
+
+        let x0 = site.grid_x as f32 * CELL_SIZE;
+        let z0 = site.grid_y as f32 * CELL_SIZE;
+        let x1 = x0 + fw as f32 * CELL_SIZE;
+        let z1 = z0 + fh as f32 * CELL_SIZE;
+        let corners = [
+            Vec3::new(x0, y, z0),
+            Vec3::new(x1, y, z0),
+            Vec3::new(x1, y, z1),
+            Vec3::new(x0, y, z1),
+        ];
+        for k in 0..4 {
+            gizmos.line(corners[k], corners[(k + 1) % 4], color);
+        }
+
+        if previewing {
+            // Coverage is measured from the anchor cell, as in
+            // `update_service_coverage`.
+            let (wx, wz) = WorldGrid::grid_to_world(site.grid_x, site.grid_y);
+            let radius = ServiceBuilding::coverage_radius(service_type)
+                * ext_budget.service_budgets.for_service(service_type);
+            gizmos.circle(
+                Isometry3d::new(
+                    Vec3::new(wx, y, wz),
+                    Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+                ),
+                radius,
+                color,
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+pub struct CoverageSuggestionPreviewPlugin;
+
+impl Plugin for CoverageSuggestionPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            draw_coverage_suggestions.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -2,23 +2,36 @@ use bevy::pbr::{DistanceFog, FogFalloff};
 use bevy::prelude::*;
 use simulation::day_night_controls::DayNightControls;
 use simulation::fog::FogState;
+use simulation::time_of_day::{GameClock, MAX_DAYLIGHT_HOURS, MIN_DAYLIGHT_HOURS};
 use std::f32::consts::PI;
 
+use crate::weather_vfx::{screen_haze, WeatherVfx};
+
+/// Noon sun altitude on the longest day of the year (70 degrees).
+const SUMMER_NOON_ALTITUDE: f32 = 70.0 * PI / 180.0;
+
+/// Noon sun altitude on the shortest day of the year (25 degrees).
+const WINTER_NOON_ALTITUDE: f32 = 25.0 * PI / 180.0;
+
 /// Updates the directional light (sun), its transform, and the ambient light
 /// based on the current game hour to create a day/night cycle.
 ///
 /// Uses `DayNightControls::effective_hour()` so that time-lock and cycle-speed
-/// settings are respected.
+/// settings are respected, and the clock's sunrise and sunset so that summer
+/// days are long and winter days short. The sun also climbs higher at noon in
+/// summer than in winter, so shadows are short in summer and long in winter.
 pub fn update_day_night_cycle(
     controls: Res<DayNightControls>,
+    clock: Res<GameClock>,
     mut sun_query: Query<&mut DirectionalLight>,
     mut sun_transform_query: Query<&mut Transform, With<DirectionalLight>>,
     mut ambient: ResMut<AmbientLight>,
 ) {
     let hour = controls.effective_hour();
+    let (sunrise, sunset) = (clock.sunrise(), clock.sunset());
 
     // --- Sun illuminance and color ---
-    let (sun_illuminance, sun_color) = sun_light_for_hour(hour);
+    let (sun_illuminance, sun_color) = sun_light_for_hour(hour, sunrise, sunset);
 
     for mut sun in sun_query.iter_mut() {
         sun.illuminance = sun_illuminance;
@@ -27,27 +40,22 @@ pub fn update_day_night_cycle(
 
     // --- Sun rotation (elevation + azimuth from hour) ---
     //
-    // Map the 24-hour cycle so that:
-    //   - At hour 6 (sunrise), the sun is at the horizon (elevation ~0)
-    //   - At hour 12 (noon), the sun is at maximum elevation
-    //   - At hour 18 (sunset), the sun is back at the horizon
-    //   - Between 18 and 6, the sun is below the horizon
+    // The sun phase runs from 0 at sunrise to PI at sunset and on to 2*PI at
+    // the next sunrise, so that:
+    //   - At sunrise, the sun is at the horizon (elevation ~0)
+    //   - At noon, the sun is at maximum elevation
+    //   - At sunset, the sun is back at the horizon
+    //   - Between sunset and sunrise, the sun is below the horizon
     //
-    // Sun angle: hour * (PI / 12) gives a full PI rotation over 12 hours.
-    // We offset so that hour=6 maps to elevation=0 and hour=12 maps to elevation=PI/2.
-    //
-    // Elevation: use sin to get a smooth arc.
-    // At hour 6: sin((6 - 6) * PI/12) = sin(0) = 0 (horizon)
-    // At hour 12: sin((12 - 6) * PI/12) = sin(PI/2) = 1 (zenith)
-    // At hour 18: sin((18 - 6) * PI/12) = sin(PI) = 0 (horizon)
-    // At hour 0/24: sin((24 - 6) * PI/12) = sin(3PI/2) = -1 (nadir)
-    let elevation = ((hour - 6.0) * PI / 12.0).sin();
-    // Clamp elevation angle: map [-1, 1] to [-PI/2, PI/2]
-    let elevation_angle = elevation * (PI / 2.0);
+    // Elevation: sin(phase) gives a smooth arc, 1 at noon and -1 at midnight,
+    // scaled by how high the sun gets at noon this time of year.
+    let phase = sun_phase(hour, sunrise, sunset);
+    let elevation = phase.sin();
+    let elevation_angle = elevation * noon_sun_altitude(clock.daylight_hours());
 
     // Azimuth: the sun moves east to west. Use a simple linear sweep.
-    // At hour 6: east (PI/3), at 12: south (0), at 18: west (-PI/3)
-    let azimuth = PI / 3.0 - (hour - 6.0) * (PI / 18.0);
+    // At sunrise: east (PI/3), at noon: south (0), at sunset: west (-PI/3)
+    let azimuth = PI / 3.0 - phase * (2.0 / 3.0);
 
     for mut transform in sun_transform_query.iter_mut() {
         // Build rotation: first pitch down by elevation, then rotate around Y for azimuth.
@@ -62,16 +70,51 @@ pub fn update_day_night_cycle(
     }
 
     // --- Ambient light ---
-    let (ambient_brightness, ambient_color) = ambient_light_for_hour(hour);
+    let (ambient_brightness, ambient_color) = ambient_light_for_hour(hour, sunrise, sunset);
     ambient.brightness = ambient_brightness;
     ambient.color = ambient_color;
 }
 
+/// Angle of the sun through the day: 0 at sunrise, PI at sunset, 2*PI at the
+/// next sunrise.
+fn sun_phase(hour: f32, sunrise: f32, sunset: f32) -> f32 {
+    let day_length = sunset - sunrise;
+    let since_sunrise = (hour - sunrise).rem_euclid(24.0);
+    if since_sunrise < day_length {
+        since_sunrise / day_length * PI
+    } else {
+        PI + (since_sunrise - day_length) / (24.0 - day_length) * PI
+    }
+}
+
+/// Sun altitude at noon, in radians, on a day with `daylight_hours` of light:
+/// `SUMMER_NOON_ALTITUDE` on the longest day down to `WINTER_NOON_ALTITUDE`
+/// on the shortest.
+fn noon_sun_altitude(daylight_hours: f32) -> f32 {
+    let t = (daylight_hours - MIN_DAYLIGHT_HOURS) / (MAX_DAYLIGHT_HOURS - MIN_DAYLIGHT_HOURS);
+    lerp(
+        WINTER_NOON_ALTITUDE,
+        SUMMER_NOON_ALTITUDE,
+        t.clamp(0.0, 1.0),
+    )
+}
+
+/// Where `hour` falls in the twilight around sunrise and sunset: 0.0 is full
+/// night, 1.0 full day, with an hour of dawn and dusk either side.
+pub(crate) fn daylight_blend(hour: f32, sunrise: f32, sunset: f32) -> f32 {
+    let dawn = (hour - (sunrise - 1.0)) / 2.0;
+    let dusk = ((sunset + 1.0) - hour) / 2.0;
+    dawn.min(dusk).clamp(0.0, 1.0)
+}
+
 /// Compute sun illuminance and color for a given hour.
-fn sun_light_for_hour(hour: f32) -> (f32, Color) {
-    if (5.0..7.0).contains(&hour) {
-        // Dawn: 5:00 - 7:00
-        let t = (hour - 5.0) / 2.0; // 0.0 at 5:00, 1.0 at 7:00
+fn sun_light_for_hour(hour: f32, sunrise: f32, sunset: f32) -> (f32, Color) {
+    let t = daylight_blend(hour, sunrise, sunset);
+    if t <= 0.0 {
+        // Night
+        (500.0, Color::srgb(0.5, 0.55, 0.8)) // blue-ish moonlight
+    } else {
+        // Dawn and dusk ramp from warm orange to the warm white of day
         let illuminance = lerp(1000.0, 10000.0, t);
         let color = color_lerp(
             Color::srgb(1.0, 0.6, 0.3),  // warm orange
@@ -79,54 +122,19 @@ fn sun_light_for_hour(hour: f32) -> (f32, Color) {
             t,
         );
         (illuminance, color)
-    } else if (7.0..17.0).contains(&hour) {
-        // Day: 7:00 - 17:00
-        (10000.0, Color::srgb(1.0, 0.95, 0.9))
-    } else if (17.0..19.0).contains(&hour) {
-        // Dusk: 17:00 - 19:00
-        let t = (hour - 17.0) / 2.0; // 0.0 at 17:00, 1.0 at 19:00
-        let illuminance = lerp(10000.0, 1000.0, t);
-        let color = color_lerp(
-            Color::srgb(1.0, 0.95, 0.9), // warm white
-            Color::srgb(1.0, 0.6, 0.3),  // warm orange
-            t,
-        );
-        (illuminance, color)
-    } else {
-        // Night: 19:00 - 5:00
-        (500.0, Color::srgb(0.5, 0.55, 0.8)) // blue-ish moonlight
     }
 }
 
 /// Compute ambient light brightness and color for a given hour.
-fn ambient_light_for_hour(hour: f32) -> (f32, Color) {
-    if (5.0..7.0).contains(&hour) {
-        // Dawn transition
-        let t = (hour - 5.0) / 2.0;
-        let brightness = lerp(50.0, 300.0, t);
-        let color = color_lerp(
-            Color::srgb(0.4, 0.45, 0.7), // night blue
-            Color::srgb(0.9, 0.9, 1.0),  // warm white
-            t,
-        );
-        (brightness, color)
-    } else if (7.0..17.0).contains(&hour) {
-        // Day
-        (300.0, Color::srgb(0.9, 0.9, 1.0))
-    } else if (17.0..19.0).contains(&hour) {
-        // Dusk transition
-        let t = (hour - 17.0) / 2.0;
-        let brightness = lerp(300.0, 50.0, t);
-        let color = color_lerp(
-            Color::srgb(0.9, 0.9, 1.0),  // warm white
-            Color::srgb(0.4, 0.45, 0.7), // night blue
-            t,
-        );
-        (brightness, color)
-    } else {
-        // Night
-        (50.0, Color::srgb(0.4, 0.45, 0.7))
-    }
+fn ambient_light_for_hour(hour: f32, sunrise: f32, sunset: f32) -> (f32, Color) {
+    let t = daylight_blend(hour, sunrise, sunset);
+    let brightness = lerp(50.0, 300.0, t);
+    let color = color_lerp(
+        Color::srgb(0.4, 0.45, 0.7), // night blue
+        Color::srgb(0.9, 0.9, 1.0),  // warm white
+        t,
+    );
+    (brightness, color)
 }
 
 /// Linear interpolation between two f32 values.
@@ -145,44 +153,79 @@ fn color_lerp(a: Color, b: Color, t: f32) -> Color {
     )
 }
 
-/// Updates distance fog on the camera based on the current fog state.
+/// Updates distance fog on the camera based on the current fog state and
+/// any haze from the weather effects (sandstorms, heavy rain and snow).
 ///
-/// When fog is active, adds a `DistanceFog` component to the camera with
-/// exponential falloff tuned to the visibility distance. When fog clears,
+/// When fog or haze is present, adds a `DistanceFog` component to the camera
+/// with exponential falloff, taking whichever is denser. When both clear,
 /// removes the component.
 pub fn update_fog_rendering(
     fog: Res<FogState>,
+    vfx: Res<WeatherVfx>,
     mut commands: Commands,
     cameras: Query<(Entity, Option<&DistanceFog>), With<Camera3d>>,
 ) {
+    // Fog color: grey-white during day, blue-grey at night
+    //
+    // Exponential fog density inversely proportional to visibility
+    // density = 3.0 / visibility_m gives good visual results:
+    //   Dense (100m vis) -> density 0.03 (thick)
+    //   Moderate (500m vis) -> density 0.006
+    //   Mist (3000m vis) -> density 0.001
+    let fog_layer = fog.active.then(|| {
+        (
+            Color::srgba(0.85, 0.87, 0.90, 1.0),
+            (3.0 / fog.visibility_m).clamp(0.0005, 0.05),
+        )
+    });
+    let layer = match (fog_layer, screen_haze(&vfx)) {
+        (Some(f), Some(h)) => Some(if h.1 > f.1 { h } else { f }),
+        (f, h) => f.or(h),
+    };
+
     for (entity, existing_fog) in &cameras {
-        if fog.active {
-            // Fog color: grey-white during day, blue-grey at night
-            let fog_color = Color::srgba(0.85, 0.87, 0.90, 1.0);
-
-            // Exponential fog density inversely proportional to visibility
-            // density = 3.0 / visibility_m gives good visual results:
-            //   Dense (100m vis) -> density 0.03 (thick)
-            //   Moderate (500m vis) -> density 0.006
-            //   Mist (3000m vis) -> density 0.001
-            let density = (3.0 / fog.visibility_m).clamp(0.0005, 0.05);
-
-            let distance_fog = DistanceFog {
-                color: fog_color,
+        if let Some((color, density)) = layer {
+            commands.entity(entity).insert(DistanceFog {
+                color,
                 falloff: FogFalloff::Exponential { density },
                 ..default()
-            };
-
-            if existing_fog.is_some() {
-                // Update existing fog
-                commands.entity(entity).insert(distance_fog);
-            } else {
-                // Add fog component
-                commands.entity(entity).insert(distance_fog);
-            }
+            });
         } else if existing_fog.is_some() {
             // Fog cleared: remove the component
             commands.entity(entity).remove::<DistanceFog>();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sun_phase_spans_the_day() {
+        assert_eq!(sun_phase(6.0, 6.0, 18.0), 0.0);
+        assert!((sun_phase(12.0, 6.0, 18.0) - PI / 2.0).abs() < 1e-5);
+        assert!((sun_phase(18.0, 6.0, 18.0) - PI).abs() < 1e-5);
+        assert!((sun_phase(0.0, 6.0, 18.0) - 1.5 * PI).abs() < 1e-5);
+        // A long summer day is still at its height at noon.
+        assert!((sun_phase(12.0, 5.0, 19.0) - PI / 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_winter_evenings_are_dark_earlier() {
+        let (summer, _) = sun_light_for_hour(18.0, 5.0, 19.0);
+        let (winter, _) = sun_light_for_hour(18.0, 7.0, 17.0);
+        assert_eq!(summer, 10000.0);
+        assert_eq!(winter, 500.0);
+    }
+
+    #[test]
+    fn test_summer_sun_climbs_higher_than_winter() {
+        let summer = noon_sun_altitude(MAX_DAYLIGHT_HOURS);
+        let winter = noon_sun_altitude(MIN_DAYLIGHT_HOURS);
+        assert!((summer - SUMMER_NOON_ALTITUDE).abs() < 1e-5);
+        assert!((winter - WINTER_NOON_ALTITUDE).abs() < 1e-5);
+        let spring = noon_sun_altitude((MAX_DAYLIGHT_HOURS + MIN_DAYLIGHT_HOURS) / 2.0);
+        assert!(winter < spring && spring < summer);
+    }
+}
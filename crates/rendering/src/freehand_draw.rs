@@ -15,6 +15,7 @@ use simulation::freehand_road::{
     FREEHAND_SIMPLIFY_TOLERANCE,
 };
 use simulation::grid::RoadType;
+use simulation::keybindings::KeyBindings;
 use simulation::road_segments::RoadSegmentStore;
 use simulation::roads::RoadNetwork;
 
@@ -32,19 +33,21 @@ impl Plugin for FreehandDrawPlugin {
             (toggle_freehand_mode, handle_freehand_draw)
                 .chain()
                 .before(crate::input::handle_tool_input)
-                .run_if(in_state(AppState::Playing)),
+                .run_if(in_state(AppState::Playing))
+                .run_if(crate::photo_mode::photo_mode_inactive),
         );
     }
 }
 
-/// Toggle freehand drawing mode with H key.
+/// Toggle freehand drawing mode with the configured key (H by default).
 pub fn toggle_freehand_mode(
     keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     mut freehand: ResMut<FreehandDrawState>,
     mut status: ResMut<StatusMessage>,
     tool: Res<ActiveTool>,
 ) {
-    if !keys.just_pressed(KeyCode::KeyH) {
+    if !bindings.toggle_freehand_draw.just_pressed(&keys) {
         return;
     }
 
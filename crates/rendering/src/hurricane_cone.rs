@@ -0,0 +1,73 @@
+//! Hurricane forecast cone and wind field.
+//!
+//! While a hurricane is forecast, its projected track is drawn as a chain of
+//! rings that widen with lead time, so the player can see where it is likely
+//! to come ashore. Once the storm is crossing the map the remaining track is
+//! still drawn, along with rings marking its current wind field and eye.
+
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::hurricane::{day_fraction, HurricaneState, EYE_RADIUS, MAX_WIND_RADIUS_FRACTION};
+use simulation::time_of_day::GameClock;
+
+/// Height above ground at which the cone is drawn.
+const CONE_Y: f32 = 2.0;
+
+/// Game days between rings along the forecast track.
+const CONE_STEP_DAYS: f32 = 0.125;
+
+fn ring(gizmos: &mut Gizmos, (x, y): (f32, f32), radius_cells: f32, color: Color) {
+    gizmos.circle(
+        Isometry3d::new(
+            Vec3::new(x * CELL_SIZE, CONE_Y, y * CELL_SIZE),
+            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+        ),
+        radius_cells * CELL_SIZE,
+        color,
+    );
+}
+
+/// System: draw the forecast cone and the active storm's wind field.
+fn draw_hurricane_cone(state: Res<HurricaneState>, clock: Res<GameClock>, mut gizmos: Gizmos) {
+    let Some(track) = state.storm.as_ref() else {
+        return;
+    };
+    let now = day_fraction(clock.day, clock.hour);
+
+    let cone_color = Color::srgba(1.0, 0.75, 0.2, 0.5);
+    let cone = track.cone(now, CONE_STEP_DAYS);
+    for pair in cone.windows(2) {
+        let ((x0, y0), _) = pair[0];
+        let ((x1, y1), _) = pair[1];
+        gizmos.line(
+            Vec3::new(x0 * CELL_SIZE, CONE_Y, y0 * CELL_SIZE),
+            Vec3::new(x1 * CELL_SIZE, CONE_Y, y1 * CELL_SIZE),
+            cone_color,
+        );
+    }
+    for &(center, half_width) in &cone {
+        ring(&mut gizmos, center, half_width, cone_color);
+    }
+
+    if state.active(now).is_some() {
+        let center = track.center_at(now);
+        let storm_color = Color::srgba(0.95, 0.2, 0.15, 0.8);
+        ring(&mut gizmos, center, track.radius, storm_color);
+        ring(
+            &mut gizmos,
+            center,
+            track.radius * MAX_WIND_RADIUS_FRACTION,
+            storm_color,
+        );
+        ring(&mut gizmos, center, EYE_RADIUS, Color::WHITE);
+    }
+}
+
+pub struct HurricaneConePlugin;
+
+impl Plugin for HurricaneConePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_hurricane_cone);
+    }
+}
@@ -0,0 +1,103 @@
+//! Bulldoze tool: removes the building, zone or road under the cursor and
+//! refunds part of its cost.
+
+use bevy::prelude::*;
+
+use simulation::bulldoze_refund;
+use simulation::economy::CityBudget;
+use simulation::grid::{CellType, WorldGrid, ZoneType};
+use simulation::roads::RoadNetwork;
+use simulation::services::ServiceBuilding;
+use simulation::undo_redo::CityAction;
+use simulation::utilities::UtilitySource;
+
+use crate::terrain_render::{mark_chunk_dirty_at, ChunkDirty, TerrainChunk};
+
+/// Bulldozes whatever is at `(gx, gy)`: a building (every cell of a service
+/// footprint), otherwise a zone, otherwise a road. Returns `true` if
+/// anything was removed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn bulldoze_cell(
+    gx: usize,
+    gy: usize,
+    grid: &mut WorldGrid,
+    roads: &mut RoadNetwork,
+    budget: &mut CityBudget,
+    service_q: &Query<&ServiceBuilding>,
+    utility_q: &Query<&UtilitySource>,
+    chunks: &Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
+    commands: &mut Commands,
+    action_writer: &mut EventWriter<CityAction>,
+) -> bool {
+    let cell = grid.get(gx, gy);
+    if let Some(entity) = cell.building_id {
+        let refund = if let Ok(service) = service_q.get(entity) {
+            let (fw, fh) = ServiceBuilding::footprint(service.service_type);
+            let sx = service.grid_x;
+            let sy = service.grid_y;
+            for fy in sy..sy + fh {
+                for fx in sx..sx + fw {
+                    if grid.in_bounds(fx, fy) {
+                        grid.get_mut(fx, fy).building_id = None;
+                        grid.get_mut(fx, fy).zone = ZoneType::None;
+                        mark_chunk_dirty_at(fx, fy, chunks, commands);
+                    }
+                }
+            }
+            let stype = service.service_type;
+            let r = bulldoze_refund::refund_for_service(stype);
+            action_writer.send(CityAction::BulldozeService {
+                service_type: stype,
+                grid_x: service.grid_x,
+                grid_y: service.grid_y,
+                refund: r,
+            });
+            r
+        } else if let Ok(utility) = utility_q.get(entity) {
+            grid.get_mut(gx, gy).building_id = None;
+            grid.get_mut(gx, gy).zone = ZoneType::None;
+            let utype = utility.utility_type;
+            let r = bulldoze_refund::refund_for_utility(utype);
+            action_writer.send(CityAction::BulldozeUtility {
+                utility_type: utype,
+                grid_x: utility.grid_x,
+                grid_y: utility.grid_y,
+                refund: r,
+            });
+            r
+        } else {
+            grid.get_mut(gx, gy).building_id = None;
+            grid.get_mut(gx, gy).zone = ZoneType::None;
+            0.0
+        };
+        budget.treasury += refund;
+        commands.entity(entity).despawn();
+        true
+    } else if cell.zone != ZoneType::None {
+        let old_zone = cell.zone;
+        grid.get_mut(gx, gy).zone = ZoneType::None;
+        action_writer.send(CityAction::BulldozeZone {
+            x: gx,
+            y: gy,
+            zone: old_zone,
+        });
+        true
+    } else if cell.cell_type == CellType::Road {
+        let road_type = cell.road_type;
+        if roads.remove_road(grid, gx, gy) {
+            let refund = bulldoze_refund::refund_for_road(road_type);
+            budget.treasury += refund;
+            action_writer.send(CityAction::BulldozeRoad {
+                x: gx,
+                y: gy,
+                road_type,
+                refund,
+            });
+            true
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
@@ -0,0 +1,150 @@
+//! Single-cell placement tools: breakwaters, urban farms and street lamps.
+//!
+//! Each tool is a row of `CellTool`, looked up from the `ActiveTool` that
+//! selects it, with the check for where it may go and what placing it does.
+//! `handle_cell_tool` does the click handling and charging they share, so a
+//! new single-cell tool only adds a row instead of its own system.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use simulation::agriculture::{can_place_urban_farm, UrbanFarmKind, UrbanFarms};
+use simulation::coastal_erosion::{can_place_breakwater, Breakwater, CoastState};
+use simulation::economy::CityBudget;
+use simulation::grid::{CellType, WorldGrid};
+use simulation::street_lighting::{LampOrigin, StreetLighting};
+
+use crate::egui_input_guard::egui_wants_pointer;
+
+use super::types::{ActiveTool, CursorGridPos, StatusMessage};
+
+/// A tool that places one thing on the clicked cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellTool {
+    Breakwater,
+    UrbanFarm(UrbanFarmKind),
+    /// Clicking a lamp that is already there removes it.
+    StreetLamp,
+}
+
+impl CellTool {
+    /// The cell tool an `ActiveTool` selects, if any.
+    pub fn for_tool(tool: ActiveTool) -> Option<Self> {
+        match tool {
+            ActiveTool::PlaceBreakwater => Some(CellTool::Breakwater),
+            ActiveTool::PlaceRooftopFarm => Some(CellTool::UrbanFarm(UrbanFarmKind::Rooftop)),
+            ActiveTool::PlaceVerticalFarm => Some(CellTool::UrbanFarm(UrbanFarmKind::Vertical)),
+            ActiveTool::PlaceStreetLamp => Some(CellTool::StreetLamp),
+            _ => None,
+        }
+    }
+}
+
+/// The city state the cell tools place into.
+#[derive(SystemParam)]
+pub struct CellToolTargets<'w> {
+    coast: ResMut<'w, CoastState>,
+    farms: ResMut<'w, UrbanFarms>,
+    lighting: ResMut<'w, StreetLighting>,
+}
+
+impl CellToolTargets<'_> {
+    /// Why `tool` can't go on the cell, if it can't.
+    fn check(
+        &self,
+        tool: CellTool,
+        grid: &WorldGrid,
+        gx: usize,
+        gy: usize,
+    ) -> Result<(), &'static str> {
+        match tool {
+            CellTool::Breakwater if self.coast.has_breakwater_at(gx, gy) => {
+                Err("Breakwater already here")
+            }
+            CellTool::Breakwater if !can_place_breakwater(grid, gx, gy) => {
+                Err("Breakwaters must be built in the water off the shore")
+            }
+            CellTool::UrbanFarm(_) if self.farms.farm_at(gx, gy).is_some() => {
+                Err("This building already has a farm")
+            }
+            CellTool::UrbanFarm(_) if !can_place_urban_farm(grid, gx, gy) => {
+                Err("Urban farms go on buildings in high-density, office or mixed-use zones")
+            }
+            CellTool::StreetLamp if grid.get(gx, gy).cell_type != CellType::Road => {
+                Err("Street lamps must be placed on a road")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Place `tool` on the cell, once checked and paid for.
+    fn place(&mut self, tool: CellTool, gx: usize, gy: usize, cost: f64) -> String {
+        match tool {
+            CellTool::Breakwater => {
+                self.coast.total_spent += cost;
+                self.coast.breakwaters.push(Breakwater::new(gx, gy));
+                "Breakwater built".to_string()
+            }
+            CellTool::UrbanFarm(kind) => {
+                self.farms.build(gx, gy, kind);
+                format!("{} built", kind.name())
+            }
+            CellTool::StreetLamp => {
+                self.lighting.place(gx, gy, LampOrigin::Manual);
+                "Street lamp placed".to_string()
+            }
+        }
+    }
+}
+
+/// Place the active cell tool on the clicked cell.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_cell_tool(
+    mut contexts: EguiContexts,
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CursorGridPos>,
+    tool: Res<ActiveTool>,
+    grid: Res<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut status: ResMut<StatusMessage>,
+    mut targets: CellToolTargets,
+    left_drag: Res<crate::camera::LeftClickDrag>,
+) {
+    // Prevent click-through: skip world actions when egui is handling pointer input.
+    if egui_wants_pointer(&mut contexts) {
+        return;
+    }
+    let Some(cell_tool) = CellTool::for_tool(*tool) else {
+        return;
+    };
+    if left_drag.is_dragging || !buttons.just_pressed(MouseButton::Left) || !cursor.valid {
+        return;
+    }
+
+    let gx = cursor.grid_x as usize;
+    let gy = cursor.grid_y as usize;
+
+    if cell_tool == CellTool::StreetLamp && targets.lighting.remove(gx, gy) {
+        status.set("Street lamp removed", false);
+        return;
+    }
+    if let Err(reason) = targets.check(cell_tool, &grid, gx, gy) {
+        status.set(reason, true);
+        return;
+    }
+    let cost = tool.cost().unwrap_or(0.0);
+    if budget.treasury < cost {
+        status.set(
+            format!(
+                "Not enough funds (need ${:.0}, have ${:.0})",
+                cost, budget.treasury
+            ),
+            true,
+        );
+        return;
+    }
+    budget.treasury -= cost;
+    let placed = targets.place(cell_tool, gx, gy, cost);
+    status.set(placed, false);
+}
@@ -5,11 +5,16 @@ use simulation::bulldoze_refund;
 use simulation::config::CELL_SIZE;
 use simulation::curve_road_drawing::CurveDrawMode;
 use simulation::economy::CityBudget;
-use simulation::grid::{WorldGrid, ZoneType};
+use simulation::flood_protection::{
+    can_place_seawall, FloodProtectionState, ProtectionStructure, ProtectionType, SEAWALL_COST,
+};
+use simulation::grid::{CellType, WorldGrid, ZoneType};
+use simulation::park_districts::{ParkDistrictState, ParkType};
 use simulation::road_segments::RoadSegmentStore;
 use simulation::roads::RoadNetwork;
 use simulation::services::ServiceBuilding;
 use simulation::utilities::UtilitySource;
+use simulation::wildlife::NATURE_RESERVE_COST;
 
 use crate::egui_input_guard::egui_wants_pointer;
 use crate::terrain_render::{mark_chunk_dirty_at, ChunkDirty, TerrainChunk};
@@ -169,6 +174,7 @@ pub fn handle_tree_tool(
     mut budget: ResMut<CityBudget>,
     mut status: ResMut<StatusMessage>,
     mut tree_grid: ResMut<simulation::trees::TreeGrid>,
+    mut forestry: ResMut<simulation::urban_forestry::UrbanForestry>,
     planted_trees: Query<(Entity, &simulation::trees::PlantedTree)>,
     mut commands: Commands,
     left_drag: Res<crate::camera::LeftClickDrag>,
@@ -212,6 +218,7 @@ pub fn handle_tree_tool(
             } else {
                 budget.treasury -= simulation::trees::TREE_PLANT_COST;
                 tree_grid.set(gx, gy, true);
+                forestry.plant(gx, gy);
                 commands.spawn(simulation::trees::PlantedTree {
                     grid_x: gx,
                     grid_y: gy,
@@ -242,6 +249,107 @@ pub fn handle_tree_tool(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Seawall tool system (separate from handle_tool_input to stay within param limit)
+// ---------------------------------------------------------------------------
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_seawall_tool(
+    mut contexts: EguiContexts,
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CursorGridPos>,
+    tool: Res<ActiveTool>,
+    grid: Res<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut status: ResMut<StatusMessage>,
+    mut protection: ResMut<FloodProtectionState>,
+    left_drag: Res<crate::camera::LeftClickDrag>,
+) {
+    // Prevent click-through: skip world actions when egui is handling pointer input.
+    if egui_wants_pointer(&mut contexts) {
+        return;
+    }
+    if left_drag.is_dragging || *tool != ActiveTool::PlaceSeawall {
+        return;
+    }
+    if !buttons.just_pressed(MouseButton::Left) || !cursor.valid {
+        return;
+    }
+
+    let gx = cursor.grid_x as usize;
+    let gy = cursor.grid_y as usize;
+
+    if protection.has_structure_at(gx, gy) {
+        status.set("Flood protection already here", true);
+    } else if !can_place_seawall(&grid, gx, gy) {
+        status.set("Seawalls must be built on the shore", true);
+    } else if budget.treasury < SEAWALL_COST {
+        status.set(
+            format!(
+                "Not enough funds (need ${:.0}, have ${:.0})",
+                SEAWALL_COST, budget.treasury
+            ),
+            true,
+        );
+    } else {
+        budget.treasury -= SEAWALL_COST;
+        protection
+            .structures
+            .push(ProtectionStructure::new(gx, gy, ProtectionType::Seawall));
+        status.set("Seawall built", false);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Nature reserve tool system (separate from handle_tool_input to stay within param limit)
+// ---------------------------------------------------------------------------
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_nature_reserve_tool(
+    mut contexts: EguiContexts,
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CursorGridPos>,
+    tool: Res<ActiveTool>,
+    grid: Res<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut status: ResMut<StatusMessage>,
+    mut parks: ResMut<ParkDistrictState>,
+    left_drag: Res<crate::camera::LeftClickDrag>,
+) {
+    // Prevent click-through: skip world actions when egui is handling pointer input.
+    if egui_wants_pointer(&mut contexts) {
+        return;
+    }
+    if left_drag.is_dragging || *tool != ActiveTool::DesignateNatureReserve {
+        return;
+    }
+    if !buttons.just_pressed(MouseButton::Left) || !cursor.valid {
+        return;
+    }
+
+    let gx = cursor.grid_x as usize;
+    let gy = cursor.grid_y as usize;
+    let cell = grid.get(gx, gy);
+
+    if parks.nature_reserve_at(gx, gy) {
+        status.set("Already inside a nature reserve", true);
+    } else if cell.cell_type == CellType::Road || cell.building_id.is_some() {
+        status.set("Nature reserves must be centered on open land", true);
+    } else if budget.treasury < NATURE_RESERVE_COST {
+        status.set(
+            format!(
+                "Not enough funds (need ${:.0}, have ${:.0})",
+                NATURE_RESERVE_COST, budget.treasury
+            ),
+            true,
+        );
+    } else {
+        budget.treasury -= NATURE_RESERVE_COST;
+        parks.create_district(ParkType::NatureReserve, gx, gy);
+        status.set("Nature reserve designated", false);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Road upgrade tool system (separate from handle_tool_input for param limit)
 // ---------------------------------------------------------------------------
@@ -7,9 +7,14 @@
 //! - `road_drawing`: Freeform Bezier road drawing (straight and curved segments)
 //! - `terrain_tools`: Terrain modification helpers (raise, lower, level, water)
 //! - `tool_handler`: Main tool input dispatch system
-//! - `keyboard`: Keyboard shortcuts, escape key, tree tool, road upgrade, building delete
+//! - `keyboard`: Keyboard shortcuts, escape key, tree, seawall, nature reserve,
+//!   road upgrade, building delete
+//! - `cell_tools`: Single-cell placement tools (breakwater, urban farms, street lamps)
+//! - `bulldoze`: Bulldoze tool (buildings, zones, roads) with refunds
 //! - `unlock_guard`: Placement-side unlock enforcement (safety net)
 
+mod bulldoze;
+mod cell_tools;
 mod cursor;
 mod keyboard;
 mod placement;
@@ -36,8 +41,12 @@ pub use cursor::{tick_status_message, update_cursor_grid_pos, update_intersectio
 // Tool handler system
 pub use tool_handler::handle_tool_input;
 
+// Single-cell placement tools
+pub use cell_tools::{handle_cell_tool, CellTool};
+
 // Keyboard shortcut systems
 pub use keyboard::{
-    delete_selected_building, handle_escape_key, handle_road_upgrade_tool, handle_tree_tool,
-    keyboard_tool_switch, toggle_curve_draw_mode, toggle_grid_snap,
+    delete_selected_building, handle_escape_key, handle_nature_reserve_tool,
+    handle_road_upgrade_tool, handle_seawall_tool, handle_tree_tool, keyboard_tool_switch,
+    toggle_curve_draw_mode, toggle_grid_snap,
 };
@@ -2,9 +2,12 @@ use bevy::prelude::*;
 
 use simulation::economy::CityBudget;
 use simulation::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use simulation::heightmap_import::BuildabilityGrid;
 use simulation::roads::RoadNetwork;
 use simulation::services::{self, ServiceType};
 use simulation::undo_redo::CityAction;
+use simulation::land_ownership::LandOwnership;
+use simulation::map_tiles::MapTiles;
 use simulation::urban_growth_boundary::UrbanGrowthBoundary;
 use simulation::utilities::UtilityType;
 
@@ -88,17 +91,24 @@ pub(crate) enum ZoneResult {
     Success,
     NotAdjacentToRoad,
     OutsideUgb,
+    DeveloperOwned,
     InvalidCell,
     AlreadyZoned,
     OccupiedByBuilding,
+    TooSteep,
+    TileNotOwned,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn try_zone(
     grid: &WorldGrid,
     x: usize,
     y: usize,
     zone: ZoneType,
     ugb: &UrbanGrowthBoundary,
+    land: &LandOwnership,
+    buildable: &BuildabilityGrid,
+    tiles: &MapTiles,
 ) -> ZoneResult {
     let cell = grid.get(x, y);
     if cell.building_id.is_some() {
@@ -110,10 +120,20 @@ pub(crate) fn try_zone(
     if cell.zone == zone {
         return ZoneResult::AlreadyZoned;
     }
+    if !buildable.is_buildable(x, y) {
+        return ZoneResult::TooSteep;
+    }
+    if !tiles.contains(x, y) {
+        return ZoneResult::TileNotOwned;
+    }
     // Urban Growth Boundary: block zoning outside the boundary (ZONE-009).
     if !ugb.allows_zoning(x, y) {
         return ZoneResult::OutsideUgb;
     }
+    // Parcels auctioned to developers are out of the city's control.
+    if !land.allows_rezoning(x, y) {
+        return ZoneResult::DeveloperOwned;
+    }
     let (n4, n4c) = grid.neighbors4(x, y);
     let has_road = n4[..n4c]
         .iter()
@@ -132,6 +152,9 @@ fn zone_failure_message(result: &ZoneResult) -> Option<&'static str> {
         ZoneResult::OutsideUgb => {
             Some("Cannot zone outside urban growth boundary")
         }
+        ZoneResult::DeveloperOwned => {
+            Some("Cannot rezone — parcel was sold to a developer")
+        }
         ZoneResult::InvalidCell => {
             Some("Cannot zone here — invalid terrain")
         }
@@ -141,6 +164,12 @@ fn zone_failure_message(result: &ZoneResult) -> Option<&'static str> {
         ZoneResult::OccupiedByBuilding => {
             Some("Cannot zone — cell occupied by a building")
         }
+        ZoneResult::TooSteep => {
+            Some("Cannot zone — the slope is too steep to build on")
+        }
+        ZoneResult::TileNotOwned => {
+            Some("Cannot zone — buy this map tile first")
+        }
         ZoneResult::Success => None,
     }
 }
@@ -155,6 +184,9 @@ pub(crate) fn apply_zone_brush(
     cy: i32,
     zone: ZoneType,
     ugb: &UrbanGrowthBoundary,
+    land: &LandOwnership,
+    buildable: &BuildabilityGrid,
+    tiles: &MapTiles,
     brush: &crate::zone_brush_preview::ZoneBrushSize,
 ) -> Vec<(usize, usize)> {
     let half = brush.half_extent;
@@ -170,7 +202,7 @@ pub(crate) fn apply_zone_brush(
                 let ux = gx as usize;
                 let uy = gy as usize;
                 if grid.in_bounds(ux, uy) {
-                    let result = try_zone(grid, ux, uy, zone, ugb);
+                    let result = try_zone(grid, ux, uy, zone, ugb, land, buildable, tiles);
                     if matches!(result, ZoneResult::Success) {
                         valid_cells.push((ux, uy));
                     }
@@ -185,7 +217,7 @@ pub(crate) fn apply_zone_brush(
             let ux = cx as usize;
             let uy = cy as usize;
             if grid.in_bounds(ux, uy) {
-                let result = try_zone(grid, ux, uy, zone, ugb);
+                let result = try_zone(grid, ux, uy, zone, ugb, land, buildable, tiles);
                 if let Some(msg) = zone_failure_message(&result) {
                     status.set(msg, true);
                 }
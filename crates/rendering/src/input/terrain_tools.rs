@@ -1,109 +1,121 @@
 use bevy::prelude::*;
 
+use simulation::earthworks::{apply_terraform, plan_terraform, Earthworks, TerraformOp};
+use simulation::economy::CityBudget;
+use simulation::game_settings::GameSettings;
 use simulation::grid::WorldGrid;
+use simulation::natural_resources::{
+    paint_deposits, DepositBrush, ResourceGrid, DEPOSIT_BRUSH_RADIUS,
+};
+use simulation::waterways::{build_network, carve_waterway, Waterways};
 
 use crate::terrain_render::{mark_chunk_dirty_at, ChunkDirty, TerrainChunk};
 
-pub(crate) fn apply_terrain_raise(
+use super::types::StatusMessage;
+
+/// Apply one stroke of a raise, lower or level brush, paying for the soil
+/// it moves. Returns whether the terrain changed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_terraform_brush(
+    op: TerraformOp,
     gx: usize,
     gy: usize,
     grid: &mut WorldGrid,
+    earthworks: &mut Earthworks,
+    budget: &mut CityBudget,
+    status: &mut StatusMessage,
     chunks: &Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
     commands: &mut Commands,
-) {
-    let radius = 3i32;
-    for dy in -radius..=radius {
-        for dx in -radius..=radius {
-            let nx = gx as i32 + dx;
-            let ny = gy as i32 + dy;
-            if nx >= 0 && ny >= 0 && (nx as usize) < grid.width && (ny as usize) < grid.height {
-                let dist = ((dx * dx + dy * dy) as f32).sqrt();
-                if dist <= radius as f32 {
-                    let strength = 0.01 * (1.0 - dist / radius as f32);
-                    let cell = grid.get_mut(nx as usize, ny as usize);
-                    cell.elevation = (cell.elevation + strength).min(1.0);
-                    if cell.elevation > 0.35 && cell.cell_type == simulation::grid::CellType::Water
-                    {
-                        cell.cell_type = simulation::grid::CellType::Grass;
-                    }
-                    mark_chunk_dirty_at(nx as usize, ny as usize, chunks, commands);
-                }
-            }
+) -> bool {
+    let plan = match plan_terraform(grid, gx, gy, op) {
+        Ok(plan) => plan,
+        Err(reason) => {
+            status.set(reason, true);
+            return false;
         }
+    };
+    if plan.changes.is_empty() {
+        return false;
     }
-}
-
-pub(crate) fn apply_terrain_lower(
-    gx: usize,
-    gy: usize,
-    grid: &mut WorldGrid,
-    chunks: &Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
-    commands: &mut Commands,
-) {
-    let radius = 3i32;
-    for dy in -radius..=radius {
-        for dx in -radius..=radius {
-            let nx = gx as i32 + dx;
-            let ny = gy as i32 + dy;
-            if nx >= 0 && ny >= 0 && (nx as usize) < grid.width && (ny as usize) < grid.height {
-                let dist = ((dx * dx + dy * dy) as f32).sqrt();
-                if dist <= radius as f32 {
-                    let strength = 0.01 * (1.0 - dist / radius as f32);
-                    let cell = grid.get_mut(nx as usize, ny as usize);
-                    cell.elevation = (cell.elevation - strength).max(0.0);
-                    mark_chunk_dirty_at(nx as usize, ny as usize, chunks, commands);
-                }
-            }
+    let bill = match apply_terraform(&plan, grid, earthworks, budget) {
+        Ok(bill) => bill,
+        Err(reason) => {
+            status.set(reason, true);
+            return false;
         }
+    };
+    for &(x, y, _) in &plan.changes {
+        mark_chunk_dirty_at(x, y, chunks, commands);
     }
+    status.set(
+        format!(
+            "Earthworks: cut {:.0} m3, fill {:.0} m3 for ${:.0} (stockpile {:.0} m3)",
+            plan.cut_m3, plan.fill_m3, bill.cost, earthworks.stockpile_m3
+        ),
+        false,
+    );
+    true
 }
 
-pub(crate) fn apply_terrain_level(
+/// Carve one stroke of the water brush, reporting whether the waterway it
+/// extends joins existing water.
+pub(crate) fn apply_terrain_water(
     gx: usize,
     gy: usize,
     grid: &mut WorldGrid,
+    waterways: &mut Waterways,
+    status: &mut StatusMessage,
     chunks: &Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
     commands: &mut Commands,
-) {
-    let target_elev = grid.get(gx, gy).elevation;
-    let radius = 3i32;
-    for dy in -radius..=radius {
-        for dx in -radius..=radius {
-            let nx = gx as i32 + dx;
-            let ny = gy as i32 + dy;
-            if nx >= 0 && ny >= 0 && (nx as usize) < grid.width && (ny as usize) < grid.height {
-                let dist = ((dx * dx + dy * dy) as f32).sqrt();
-                if dist <= radius as f32 {
-                    let cell = grid.get_mut(nx as usize, ny as usize);
-                    cell.elevation += (target_elev - cell.elevation) * 0.3;
-                    mark_chunk_dirty_at(nx as usize, ny as usize, chunks, commands);
-                }
-            }
-        }
+) -> bool {
+    let changed = carve_waterway(grid, waterways, gx, gy);
+    if changed.is_empty() {
+        return false;
     }
+    for &(x, y) in &changed {
+        mark_chunk_dirty_at(x, y, chunks, commands);
+    }
+    let network = build_network(grid, waterways, 0.0);
+    let text = match network.body_at(gx, gy) {
+        Some(body) if body.is_connected() => format!(
+            "{} joins existing water: it flows, and ferries can use it",
+            body.kind.name()
+        ),
+        Some(body) => format!(
+            "{} is not connected: carve it to existing water to make it flow",
+            body.kind.name()
+        ),
+        None => return true,
+    };
+    status.set(text, false);
+    true
 }
 
-pub(crate) fn apply_terrain_water(
+/// Paint or erase natural resource deposits under the brush. The brush is
+/// an editor tool, so it only works with a sandbox option switched on.
+pub(crate) fn apply_deposit_brush(
+    brush: DepositBrush,
     gx: usize,
     gy: usize,
-    grid: &mut WorldGrid,
-    chunks: &Query<(Entity, &TerrainChunk), Without<ChunkDirty>>,
-    commands: &mut Commands,
+    resources: &mut ResourceGrid,
+    grid: &WorldGrid,
+    settings: &GameSettings,
+    status: &mut StatusMessage,
 ) {
-    let radius = 2i32;
-    for dy in -radius..=radius {
-        for dx in -radius..=radius {
-            let nx = gx as i32 + dx;
-            let ny = gy as i32 + dy;
-            if nx >= 0 && ny >= 0 && (nx as usize) < grid.width && (ny as usize) < grid.height {
-                let dist = ((dx * dx + dy * dy) as f32).sqrt();
-                if dist <= radius as f32 {
-                    let cell = grid.get_mut(nx as usize, ny as usize);
-                    cell.cell_type = simulation::grid::CellType::Water;
-                    cell.elevation = 0.3;
-                    mark_chunk_dirty_at(nx as usize, ny as usize, chunks, commands);
-                }
-            }
-        }
+    if !settings.sandbox.any() {
+        status.set("The resource brush needs a sandbox option enabled", true);
+        return;
+    }
+    if paint_deposits(resources, grid, gx, gy, DEPOSIT_BRUSH_RADIUS, brush) == 0 {
+        return;
     }
+    let text = match brush {
+        DepositBrush::Paint(resource, amount) => format!(
+            "{} deposit: {amount} units per cell, {} on the map",
+            resource.name(),
+            resources.total_remaining(resource)
+        ),
+        DepositBrush::Erase => "Deposits erased".to_string(),
+    };
+    status.set(text, false);
 }
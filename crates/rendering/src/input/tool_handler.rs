@@ -3,22 +3,28 @@
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
 
-use simulation::bulldoze_refund;
+use simulation::city_council::CityCouncil;
 use simulation::curve_road_drawing::CurveDrawMode;
+use simulation::earthworks::{Earthworks, TerraformOp};
 use simulation::economy::CityBudget;
+use simulation::game_settings::GameSettings;
 use simulation::grid::{RoadType, WorldGrid, ZoneType};
+use simulation::map_tiles::MapTiles;
+use simulation::natural_resources::{DepositBrush, ResourceGrid};
 use simulation::road_segments::RoadSegmentStore;
 use simulation::roads::RoadNetwork;
-use simulation::services::ServiceBuilding;
+use simulation::services::{ServiceBuilding, ServiceType};
 use simulation::undo_redo::CityAction;
 use simulation::unlocks::UnlockState;
 use simulation::urban_growth_boundary::UrbanGrowthBoundary;
 use simulation::utilities::{UtilitySource, UtilityType};
+use simulation::waterways::Waterways;
 
 use crate::angle_snap::AngleSnapState;
 use crate::egui_input_guard::egui_wants_pointer;
 use crate::terrain_render::{mark_chunk_dirty_at, ChunkDirty, TerrainChunk};
 
+use super::bulldoze::bulldoze_cell;
 use super::placement::{
     apply_zone_brush, place_road_if_affordable, place_service_if_affordable,
     place_utility_if_affordable,
@@ -29,7 +35,7 @@ use super::types::{
     ActiveTool, CursorGridPos, DrawPhase, IntersectionSnap, RoadDrawState, SelectedBuilding,
     StatusMessage,
 };
-use super::unlock_guard::is_tool_locked;
+use super::unlock_guard::tool_blocked_reason;
 
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
@@ -41,6 +47,7 @@ pub fn handle_tool_input(
         Res<CurveDrawMode>,
         Res<UnlockState>,
         EguiContexts,
+        ResMut<CityCouncil>,
     ),
     cursor: Res<CursorGridPos>,
     tool: Res<ActiveTool>,
@@ -62,17 +69,40 @@ pub fn handle_tool_input(
         Res<crate::zone_brush_preview::ZoneBrushSize>,
         Res<simulation::freehand_road::FreehandDrawState>,
         EventWriter<CityAction>,
+        Res<simulation::land_ownership::LandOwnership>,
+        Res<simulation::scenario::ScenarioState>,
+        Res<simulation::heightmap_import::BuildabilityGrid>,
+        Res<MapTiles>,
+        ResMut<Earthworks>,
+        ResMut<Waterways>,
+        ResMut<ResourceGrid>,
+        Res<GameSettings>,
     ),
     mut district_map: ResMut<simulation::districts::DistrictMap>,
 ) {
-    let (buttons, keys, angle_snap, curve_mode, unlocks, mut contexts) = input;
+    let (buttons, keys, angle_snap, curve_mode, unlocks, mut contexts, mut council) = input;
 
     // Prevent click-through: skip world actions when egui is handling pointer input.
     if egui_wants_pointer(&mut contexts) {
         return;
     }
 
-    let (left_drag, ugb, snap, brush_size, freehand, mut action_writer) = misc;
+    let (
+        left_drag,
+        ugb,
+        snap,
+        brush_size,
+        freehand,
+        mut action_writer,
+        land,
+        scenario,
+        buildable,
+        tiles,
+        mut earthworks,
+        mut waterways,
+        mut resources,
+        settings,
+    ) = misc;
 
     if left_drag.is_dragging {
         return;
@@ -86,17 +116,17 @@ pub fn handle_tool_input(
         return;
     }
 
-    // --- Unlock safety check: reject locked tools ---
-    if is_tool_locked(&tool, &unlocks) {
+    let gx = cursor.grid_x as usize;
+    let gy = cursor.grid_y as usize;
+
+    // --- Unlock, scenario and map tile safety checks ---
+    if let Some(reason) = tool_blocked_reason(&tool, &unlocks, &scenario, &tiles, gx, gy) {
         if buttons.just_pressed(MouseButton::Left) {
-            status.set("Building not yet unlocked", true);
+            status.set(reason, true);
         }
         return;
     }
 
-    let gx = cursor.grid_x as usize;
-    let gy = cursor.grid_y as usize;
-
     if buttons.just_pressed(MouseButton::Left) && *tool != ActiveTool::Inspect {
         selected.0 = grid.get(gx, gy).building_id;
     }
@@ -204,79 +234,18 @@ pub fn handle_tool_input(
             &mut action_writer,
         ),
 
-        ActiveTool::Bulldoze => {
-            let cell = grid.get(gx, gy);
-            if let Some(entity) = cell.building_id {
-                let refund = if let Ok(service) = service_q.get(entity) {
-                    let (fw, fh) = ServiceBuilding::footprint(service.service_type);
-                    let sx = service.grid_x;
-                    let sy = service.grid_y;
-                    for fy in sy..sy + fh {
-                        for fx in sx..sx + fw {
-                            if grid.in_bounds(fx, fy) {
-                                grid.get_mut(fx, fy).building_id = None;
-                                grid.get_mut(fx, fy).zone = ZoneType::None;
-                                mark_chunk_dirty_at(fx, fy, &chunks, &mut commands);
-                            }
-                        }
-                    }
-                    let stype = service.service_type;
-                    let r = bulldoze_refund::refund_for_service(stype);
-                    action_writer.send(CityAction::BulldozeService {
-                        service_type: stype,
-                        grid_x: service.grid_x,
-                        grid_y: service.grid_y,
-                        refund: r,
-                    });
-                    r
-                } else if let Ok(utility) = utility_q.get(entity) {
-                    grid.get_mut(gx, gy).building_id = None;
-                    grid.get_mut(gx, gy).zone = ZoneType::None;
-                    let utype = utility.utility_type;
-                    let r = bulldoze_refund::refund_for_utility(utype);
-                    action_writer.send(CityAction::BulldozeUtility {
-                        utility_type: utype,
-                        grid_x: utility.grid_x,
-                        grid_y: utility.grid_y,
-                        refund: r,
-                    });
-                    r
-                } else {
-                    grid.get_mut(gx, gy).building_id = None;
-                    grid.get_mut(gx, gy).zone = ZoneType::None;
-                    0.0
-                };
-                budget.treasury += refund;
-                commands.entity(entity).despawn();
-                true
-            } else if cell.zone != ZoneType::None {
-                let old_zone = cell.zone;
-                grid.get_mut(gx, gy).zone = ZoneType::None;
-                action_writer.send(CityAction::BulldozeZone {
-                    x: gx,
-                    y: gy,
-                    zone: old_zone,
-                });
-                true
-            } else if cell.cell_type == simulation::grid::CellType::Road {
-                let road_type = cell.road_type;
-                if roads.remove_road(&mut grid, gx, gy) {
-                    let refund = bulldoze_refund::refund_for_road(road_type);
-                    budget.treasury += refund;
-                    action_writer.send(CityAction::BulldozeRoad {
-                        x: gx,
-                        y: gy,
-                        road_type,
-                        refund,
-                    });
-                    true
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        }
+        ActiveTool::Bulldoze => bulldoze_cell(
+            gx,
+            gy,
+            &mut grid,
+            &mut roads,
+            &mut budget,
+            &service_q,
+            &utility_q,
+            &chunks,
+            &mut commands,
+            &mut action_writer,
+        ),
 
         ActiveTool::Inspect => false,
 
@@ -289,7 +258,9 @@ pub fn handle_tool_input(
         | ActiveTool::ZoneIndustrial
         | ActiveTool::ZoneOffice
         | ActiveTool::ZoneMixedUse => {
-            let Some(zone) = tool.zone_type() else { return; };
+            let Some(zone) = tool.zone_type() else {
+                return;
+            };
             let zoned_cells = apply_zone_brush(
                 &mut grid,
                 &mut status,
@@ -299,6 +270,9 @@ pub fn handle_tool_input(
                 gy as i32,
                 zone,
                 &ugb,
+                &land,
+                &buildable,
+                &tiles,
                 &brush_size,
             );
             if !zoned_cells.is_empty() {
@@ -419,28 +393,64 @@ pub fn handle_tool_input(
         ),
 
         // --- Terrain tools ---
-        ActiveTool::TerrainRaise => {
-            terrain_tools::apply_terrain_raise(gx, gy, &mut grid, &chunks, &mut commands);
-            true
-        }
-        ActiveTool::TerrainLower => {
-            terrain_tools::apply_terrain_lower(gx, gy, &mut grid, &chunks, &mut commands);
-            true
-        }
-        ActiveTool::TerrainLevel => {
-            terrain_tools::apply_terrain_level(gx, gy, &mut grid, &chunks, &mut commands);
-            true
+        ActiveTool::TerrainRaise | ActiveTool::TerrainLower | ActiveTool::TerrainLevel => {
+            let op = match *tool {
+                ActiveTool::TerrainRaise => TerraformOp::Raise,
+                ActiveTool::TerrainLower => TerraformOp::Lower,
+                _ => TerraformOp::Level,
+            };
+            terrain_tools::apply_terraform_brush(
+                op,
+                gx,
+                gy,
+                &mut grid,
+                &mut earthworks,
+                &mut budget,
+                &mut status,
+                &chunks,
+                &mut commands,
+            )
         }
-        ActiveTool::TerrainWater => {
-            terrain_tools::apply_terrain_water(gx, gy, &mut grid, &chunks, &mut commands);
-            true
+        ActiveTool::TerrainWater => terrain_tools::apply_terrain_water(
+            gx,
+            gy,
+            &mut grid,
+            &mut waterways,
+            &mut status,
+            &chunks,
+            &mut commands,
+        ),
+
+        // --- Resource deposit brush (no terrain change) ---
+        ActiveTool::PaintResource(_) | ActiveTool::EraseResource => {
+            let brush = match *tool {
+                ActiveTool::PaintResource(r) => DepositBrush::Paint(r, r.default_amount()),
+                _ => DepositBrush::Erase,
+            };
+            terrain_tools::apply_deposit_brush(
+                brush,
+                gx,
+                gy,
+                &mut resources,
+                &grid,
+                &settings,
+                &mut status,
+            );
+            false
         }
 
-        // --- Trees/RoadUpgrade/AutoGrid (handled by separate systems) ---
+        // --- Environment, lamps, road upgrade, auto-grid, blueprints (separate systems) ---
         ActiveTool::TreePlant
         | ActiveTool::TreeRemove
+        | ActiveTool::PlaceSeawall
+        | ActiveTool::DesignateNatureReserve
+        | ActiveTool::PlaceBreakwater
+        | ActiveTool::PlaceRooftopFarm
+        | ActiveTool::PlaceVerticalFarm
+        | ActiveTool::PlaceStreetLamp
         | ActiveTool::RoadUpgrade
-        | ActiveTool::AutoGrid => false,
+        | ActiveTool::AutoGrid
+        | ActiveTool::Blueprint => false,
 
         // --- Districts ---
         ActiveTool::DistrictPaint(di) => {
@@ -452,10 +462,18 @@ pub fn handle_tool_input(
             false
         }
 
+        // --- Stadiums need a council permit ---
+        ActiveTool::PlaceStadium if council.stadium_permits == 0 => {
+            if buttons.just_pressed(MouseButton::Left) {
+                status.set("Stadium construction needs city council approval", true);
+            }
+            false
+        }
+
         // --- Services (use service_type() helper) ---
         _ => {
             if let Some(st) = tool.service_type() {
-                place_service_if_affordable(
+                let placed = place_service_if_affordable(
                     &mut commands,
                     &mut grid,
                     &mut budget,
@@ -465,7 +483,11 @@ pub fn handle_tool_input(
                     gx,
                     gy,
                     &mut action_writer,
-                )
+                );
+                if placed && st == ServiceType::Stadium {
+                    council.stadium_permits -= 1;
+                }
+                placed
             } else {
                 false
             }
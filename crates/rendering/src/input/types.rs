@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use simulation::config::CELL_SIZE;
 use simulation::grid::{RoadType, ZoneType};
+use simulation::natural_resources::ResourceType;
 use simulation::services::{self, ServiceType};
 use simulation::utilities::UtilityType;
 
@@ -36,6 +37,7 @@ pub enum ActiveTool {
     PlaceFireStation,
     PlaceFireHouse,
     PlaceFireHQ,
+    PlaceHazmatResponse,
     PlacePoliceStation,
     PlacePoliceKiosk,
     PlacePoliceHQ,
@@ -64,6 +66,8 @@ pub enum ActiveTool {
     PlaceMuseum,
     PlaceCathedral,
     PlaceTVStation,
+    PlaceHotel,
+    PlaceConventionCenter,
     PlaceBusDepot,
     PlaceTrainStation,
     PlaceSubwayStation,
@@ -79,16 +83,28 @@ pub enum ActiveTool {
     TerrainLower,
     TerrainLevel,
     TerrainWater,
+    // Resource deposit brush (sandbox only)
+    PaintResource(ResourceType),
+    EraseResource,
     // District tools
     DistrictPaint(usize),
     DistrictErase,
     // Environment tools
     TreePlant,
     TreeRemove,
+    PlaceSeawall,
+    DesignateNatureReserve,
+    PlaceBreakwater,
+    PlaceRooftopFarm,
+    PlaceVerticalFarm,
+    // Street lamp placement tool
+    PlaceStreetLamp,
     // Road upgrade tool
     RoadUpgrade,
     // Auto-grid road placement tool
     AutoGrid,
+    // Blueprint capture and stamp tool
+    Blueprint,
 }
 
 impl ActiveTool {
@@ -107,12 +123,21 @@ impl ActiveTool {
             | ActiveTool::TerrainLower
             | ActiveTool::TerrainLevel
             | ActiveTool::TerrainWater
+            | ActiveTool::PaintResource(_)
+            | ActiveTool::EraseResource
             | ActiveTool::DistrictPaint(_)
             | ActiveTool::DistrictErase
             | ActiveTool::TreeRemove
             | ActiveTool::RoadUpgrade
-            | ActiveTool::AutoGrid => None,
+            | ActiveTool::AutoGrid
+            | ActiveTool::Blueprint => None,
             ActiveTool::TreePlant => Some(simulation::trees::TREE_PLANT_COST),
+            ActiveTool::PlaceSeawall => Some(simulation::flood_protection::SEAWALL_COST),
+            ActiveTool::DesignateNatureReserve => Some(simulation::wildlife::NATURE_RESERVE_COST),
+            ActiveTool::PlaceBreakwater => Some(simulation::coastal_erosion::BREAKWATER_COST),
+            ActiveTool::PlaceRooftopFarm => Some(simulation::agriculture::ROOFTOP_FARM_COST),
+            ActiveTool::PlaceVerticalFarm => Some(simulation::agriculture::VERTICAL_FARM_COST),
+            ActiveTool::PlaceStreetLamp => Some(simulation::street_lighting::STREET_LAMP_COST),
             ActiveTool::ZoneResidentialLow
             | ActiveTool::ZoneResidentialMedium
             | ActiveTool::ZoneResidentialHigh
@@ -148,6 +173,7 @@ impl ActiveTool {
             ActiveTool::PlaceFireStation => Some(ServiceType::FireStation),
             ActiveTool::PlaceFireHouse => Some(ServiceType::FireHouse),
             ActiveTool::PlaceFireHQ => Some(ServiceType::FireHQ),
+            ActiveTool::PlaceHazmatResponse => Some(ServiceType::HazmatResponse),
             ActiveTool::PlacePoliceStation => Some(ServiceType::PoliceStation),
             ActiveTool::PlacePoliceKiosk => Some(ServiceType::PoliceKiosk),
             ActiveTool::PlacePoliceHQ => Some(ServiceType::PoliceHQ),
@@ -176,6 +202,8 @@ impl ActiveTool {
             ActiveTool::PlaceMuseum => Some(ServiceType::Museum),
             ActiveTool::PlaceCathedral => Some(ServiceType::Cathedral),
             ActiveTool::PlaceTVStation => Some(ServiceType::TVStation),
+            ActiveTool::PlaceHotel => Some(ServiceType::Hotel),
+            ActiveTool::PlaceConventionCenter => Some(ServiceType::ConventionCenter),
             ActiveTool::PlaceBusDepot => Some(ServiceType::BusDepot),
             ActiveTool::PlaceTrainStation => Some(ServiceType::TrainStation),
             ActiveTool::PlaceSubwayStation => Some(ServiceType::SubwayStation),
@@ -220,6 +248,7 @@ impl ActiveTool {
             ActiveTool::PlaceFireStation => "Fire Station",
             ActiveTool::PlaceFireHouse => "Fire House",
             ActiveTool::PlaceFireHQ => "Fire HQ",
+            ActiveTool::PlaceHazmatResponse => "Hazmat Response Center",
             ActiveTool::PlacePoliceStation => "Police Station",
             ActiveTool::PlacePoliceKiosk => "Police Kiosk",
             ActiveTool::PlacePoliceHQ => "Police HQ",
@@ -248,6 +277,8 @@ impl ActiveTool {
             ActiveTool::PlaceMuseum => "Museum",
             ActiveTool::PlaceCathedral => "Cathedral",
             ActiveTool::PlaceTVStation => "TV Station",
+            ActiveTool::PlaceHotel => "Hotel",
+            ActiveTool::PlaceConventionCenter => "Convention Center",
             ActiveTool::PlaceBusDepot => "Bus Depot",
             ActiveTool::PlaceTrainStation => "Train Station",
             ActiveTool::PlaceSubwayStation => "Subway Station",
@@ -262,12 +293,24 @@ impl ActiveTool {
             ActiveTool::TerrainLower => "Lower Terrain",
             ActiveTool::TerrainLevel => "Level Terrain",
             ActiveTool::TerrainWater => "Place Water",
+            ActiveTool::PaintResource(ResourceType::FertileLand) => "Paint Fertile Land",
+            ActiveTool::PaintResource(ResourceType::Forest) => "Paint Forest",
+            ActiveTool::PaintResource(ResourceType::Ore) => "Paint Ore",
+            ActiveTool::PaintResource(ResourceType::Oil) => "Paint Oil",
+            ActiveTool::EraseResource => "Erase Deposits",
             ActiveTool::DistrictPaint(_) => "Paint District",
             ActiveTool::DistrictErase => "Erase District",
             ActiveTool::TreePlant => "Plant Tree",
             ActiveTool::TreeRemove => "Remove Tree",
+            ActiveTool::PlaceSeawall => "Seawall",
+            ActiveTool::DesignateNatureReserve => "Nature Reserve",
+            ActiveTool::PlaceBreakwater => "Breakwater",
+            ActiveTool::PlaceRooftopFarm => "Rooftop Farm",
+            ActiveTool::PlaceVerticalFarm => "Vertical Farm",
+            ActiveTool::PlaceStreetLamp => "Street Lamp",
             ActiveTool::RoadUpgrade => "Upgrade Road",
             ActiveTool::AutoGrid => "Auto-Grid",
+            ActiveTool::Blueprint => "Blueprint",
         }
     }
 
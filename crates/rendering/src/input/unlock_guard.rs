@@ -2,15 +2,45 @@
 //! the world. This is a safety net in case the UI-side graying-out is
 //! bypassed (e.g. via keyboard shortcuts or future hotkey bindings).
 
+use simulation::map_tiles::MapTiles;
+use simulation::scenario::ScenarioState;
+use simulation::services::ServiceBuilding;
 use simulation::unlocks::{UnlockNode, UnlockState};
 use simulation::utilities::UtilityType;
 
 use super::types::ActiveTool;
 
+/// Why the active tool can't be used at `(gx, gy)`: not unlocked yet, taken
+/// away by the scenario, or building on a map tile the city doesn't own.
+pub(crate) fn tool_blocked_reason(
+    tool: &ActiveTool,
+    unlocks: &UnlockState,
+    scenario: &ScenarioState,
+    tiles: &MapTiles,
+    gx: usize,
+    gy: usize,
+) -> Option<&'static str> {
+    if is_tool_locked(tool, unlocks) {
+        return Some("Building not yet unlocked");
+    }
+    if is_tool_locked_by_scenario(tool, scenario) {
+        return Some("Not available in this scenario");
+    }
+    if needs_owned_tile(tool) {
+        let (fw, fh) = tool
+            .service_type()
+            .map_or((1, 1), ServiceBuilding::footprint);
+        if !tiles.contains_footprint(gx, gy, fw, fh) {
+            return Some("Buy this map tile before building here");
+        }
+    }
+    None
+}
+
 /// Returns `true` if the active tool is gated by an unlock that has not yet
 /// been purchased. Always-available tools (roads, basic zones, terrain,
 /// districts, bulldoze, inspect, etc.) return `false`.
-pub(crate) fn is_tool_locked(tool: &ActiveTool, unlocks: &UnlockState) -> bool {
+fn is_tool_locked(tool: &ActiveTool, unlocks: &UnlockState) -> bool {
     // Utility placement tools
     if let Some(ut) = tool_to_utility_type(tool) {
         return !unlocks.is_utility_unlocked(ut);
@@ -21,17 +51,40 @@ pub(crate) fn is_tool_locked(tool: &ActiveTool, unlocks: &UnlockState) -> bool {
     }
     // Zone tools with unlock requirements
     match tool {
-        ActiveTool::ZoneResidentialHigh => {
-            !unlocks.is_unlocked(UnlockNode::HighDensityResidential)
-        }
-        ActiveTool::ZoneCommercialHigh => {
-            !unlocks.is_unlocked(UnlockNode::HighDensityCommercial)
-        }
+        ActiveTool::ZoneResidentialHigh => !unlocks.has_access(UnlockNode::HighDensityResidential),
+        ActiveTool::ZoneCommercialHigh => !unlocks.has_access(UnlockNode::HighDensityCommercial),
         ActiveTool::ZoneOffice => !unlocks.is_unlocked(UnlockNode::OfficeZoning),
         _ => false,
     }
 }
 
+/// Returns `true` if the scenario being played takes this tool away.
+fn is_tool_locked_by_scenario(tool: &ActiveTool, scenario: &ScenarioState) -> bool {
+    if let Some(ut) = tool_to_utility_type(tool) {
+        return scenario.locks_utility(ut);
+    }
+    if let Some(st) = tool.service_type() {
+        return scenario.locks_service(st);
+    }
+    tool.zone_type()
+        .is_some_and(|zone| scenario.locks_zone(zone))
+}
+
+/// Returns `true` if the tool builds or reshapes the land under the cursor,
+/// which is only allowed on owned map tiles. Zone brushes check each cell
+/// themselves; inspecting, bulldozing and painting districts work anywhere.
+fn needs_owned_tile(tool: &ActiveTool) -> bool {
+    !matches!(
+        tool,
+        ActiveTool::Inspect
+            | ActiveTool::Bulldoze
+            | ActiveTool::DistrictPaint(_)
+            | ActiveTool::DistrictErase
+            | ActiveTool::PaintResource(_)
+            | ActiveTool::EraseResource
+    ) && tool.zone_type().is_none()
+}
+
 /// Maps an ActiveTool to its UtilityType for unlock checking.
 fn tool_to_utility_type(tool: &ActiveTool) -> Option<UtilityType> {
     match tool {
@@ -0,0 +1,192 @@
+//! Bus-lane paint.
+//!
+//! Avenues, boulevards and one-way roads that serve a stop on an active bus
+//! route get their curb lanes painted red, trimmed clear of the junction
+//! decals at either end.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy::render::mesh::Indices;
+use bevy::render::render_asset::RenderAssetUsages;
+
+use simulation::bus_transit::BusTransitState;
+use simulation::grid::{RoadType, WorldGrid};
+use simulation::road_segments::{RoadSegment, RoadSegmentStore, SegmentId};
+
+use super::decals::{decal_material, node_setback, CURB_MARGIN};
+use super::line_primitives::{emit_solid_line, road_half_width};
+use super::Y_MARKING;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Bus-lane paint along one road segment.
+#[derive(Component)]
+pub struct BusLaneDecalMesh {
+    pub segment_id: SegmentId,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Bus lane paint colour.
+const BUS_LANE_RED: [f32; 4] = [0.72, 0.14, 0.10, 0.45];
+
+/// Half-width of a painted bus lane.
+const BUS_LANE_HW: f32 = 1.3;
+
+/// Bus lanes sit just under the lane lines so the lines stay visible.
+const Y_BUS_LANE: f32 = Y_MARKING - 0.005;
+
+// ---------------------------------------------------------------------------
+// Mesh construction
+// ---------------------------------------------------------------------------
+
+/// Whether a road segment gets a bus lane, given the cells of the stops on
+/// active bus routes.
+pub(crate) fn has_bus_lane(segment: &RoadSegment, stop_cells: &HashSet<(usize, usize)>) -> bool {
+    matches!(
+        segment.road_type,
+        RoadType::Avenue | RoadType::Boulevard | RoadType::OneWay
+    ) && segment
+        .rasterized_cells
+        .iter()
+        .any(|cell| stop_cells.contains(cell))
+}
+
+/// Cells of the stops served by active bus routes, sorted and deduplicated.
+fn active_stop_cells(transit: &BusTransitState) -> Vec<(usize, usize)> {
+    let mut cells: Vec<(usize, usize)> = transit
+        .routes
+        .iter()
+        .filter(|route| route.active)
+        .flat_map(|route| route.stop_ids.iter())
+        .filter_map(|&id| transit.stop_by_id(id))
+        .map(|stop| (stop.grid_x, stop.grid_y))
+        .collect();
+    cells.sort_unstable();
+    cells.dedup();
+    cells
+}
+
+/// Build the red curb-lane paint of one segment.  One-way roads run from
+/// `p0` to `p3` and get a single lane on their right.
+pub(crate) fn build_bus_lane_mesh(
+    segment: &RoadSegment,
+    trim_start: f32,
+    trim_end: f32,
+    grid: &WorldGrid,
+) -> Mesh {
+    let hw = road_half_width(segment.road_type);
+    let offset = hw - CURB_MARGIN - BUS_LANE_HW;
+    let sides: &[f32] = if segment.road_type == RoadType::OneWay {
+        &[1.0]
+    } else {
+        &[1.0, -1.0]
+    };
+    let total_steps = (segment.arc_length / 0.5).ceil() as usize;
+
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut colors: Vec<[f32; 4]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    for side in sides {
+        emit_solid_line(
+            &mut positions,
+            &mut normals,
+            &mut colors,
+            &mut uvs,
+            &mut indices,
+            segment.p0,
+            segment.p1,
+            segment.p2,
+            segment.p3,
+            side * offset,
+            BUS_LANE_HW,
+            Y_BUS_LANE,
+            segment.arc_length,
+            total_steps,
+            BUS_LANE_RED,
+            trim_start,
+            trim_end,
+            grid,
+        );
+    }
+
+    Mesh::new(
+        bevy::render::mesh::PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Paints bus lanes on the roads served by active bus routes.
+///
+/// Buses move every tick, so the transit state changes constantly; meshes
+/// are only rebuilt when the roads or the set of served stops change.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_bus_lane_decals(
+    store: Res<RoadSegmentStore>,
+    transit: Res<BusTransitState>,
+    grid: Res<WorldGrid>,
+    existing: Query<(Entity, &BusLaneDecalMesh)>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+    mut served_cells: Local<Vec<(usize, usize)>>,
+) {
+    if !store.is_changed() && !transit.is_changed() {
+        return;
+    }
+    let cells = active_stop_cells(&transit);
+    if !store.is_changed() && cells == *served_cells {
+        return;
+    }
+    *served_cells = cells;
+
+    // Bus lanes are few and their trims depend on neighbouring junctions,
+    // so rebuild them all.
+    for (entity, _) in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    let stop_cells: HashSet<(usize, usize)> = served_cells.iter().copied().collect();
+    if stop_cells.is_empty() {
+        return;
+    }
+    let material = decal_material(&mut material, &mut materials);
+    for segment in store
+        .segments
+        .iter()
+        .filter(|segment| has_bus_lane(segment, &stop_cells))
+    {
+        let hw = road_half_width(segment.road_type);
+        let trim_start = node_setback(&store, segment.start_node, hw);
+        let trim_end = node_setback(&store, segment.end_node, hw);
+        if trim_start + trim_end >= segment.arc_length {
+            continue;
+        }
+        commands.spawn((
+            BusLaneDecalMesh {
+                segment_id: segment.id,
+            },
+            Mesh3d(meshes.add(build_bus_lane_mesh(segment, trim_start, trim_end, &grid))),
+            MeshMaterial3d(material.clone()),
+            Transform::IDENTITY,
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+//! Flat painted shapes for road decals: quads, triangles and turn arrows.
+//!
+//! [`DecalMeshBuilder`] collects triangles lying on the terrain just above
+//! the road surface.  Every triangle is wound to face up regardless of the
+//! order its corners are given in, so callers can build shapes from any
+//! pair of axes.
+
+use bevy::prelude::*;
+use bevy::render::mesh::Indices;
+use bevy::render::render_asset::RenderAssetUsages;
+
+use simulation::grid::WorldGrid;
+
+use super::line_primitives::sample_terrain_y;
+use super::Y_DECAL;
+
+/// Half-width of a turn arrow's shaft.
+const ARROW_SHAFT_HW: f32 = 0.2;
+
+/// Length of a turn arrow's shaft before it branches.
+const ARROW_SHAFT_LEN: f32 = 3.0;
+
+/// Length of an arrow head.
+const ARROW_HEAD_LEN: f32 = 1.2;
+
+/// Half-width of an arrow head at its base.
+const ARROW_HEAD_HW: f32 = 0.55;
+
+/// Sideways reach of a left or right branch.
+const ARROW_BRANCH_LEN: f32 = 1.1;
+
+/// Full length of a turn arrow, tail to the tip of a straight head.
+pub(crate) const ARROW_LEN: f32 = ARROW_SHAFT_LEN + ARROW_HEAD_LEN;
+
+/// Which ways traffic may leave a junction from one approach lane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnMovements {
+    pub left: bool,
+    pub straight: bool,
+    pub right: bool,
+}
+
+impl TurnMovements {
+    pub fn is_empty(self) -> bool {
+        !(self.left || self.straight || self.right)
+    }
+}
+
+/// Triangle-list buffers for decal geometry.
+#[derive(Default)]
+pub(crate) struct DecalMeshBuilder {
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+}
+
+impl DecalMeshBuilder {
+    pub(crate) fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Add one up-facing triangle, raised `Y_DECAL` above the terrain at
+    /// its centroid.
+    pub(crate) fn triangle(
+        &mut self,
+        a: Vec2,
+        b: Vec2,
+        c: Vec2,
+        color: [f32; 4],
+        grid: &WorldGrid,
+    ) {
+        let centroid = (a + b + c) / 3.0;
+        let y = sample_terrain_y(grid, centroid.x, centroid.y) + Y_DECAL;
+        // Mapping world XZ onto Vec2, a triangle faces +Y (and survives
+        // back-face culling) when this perp-dot product is negative.
+        let (b, c) = if (b - a).perp_dot(c - a) > 0.0 {
+            (c, b)
+        } else {
+            (b, c)
+        };
+        let vi = self.positions.len() as u32;
+        for p in [a, b, c] {
+            self.positions.push([p.x, y, p.y]);
+        }
+        self.colors.extend_from_slice(&[color; 3]);
+        self.indices.extend_from_slice(&[vi, vi + 1, vi + 2]);
+    }
+
+    /// Add a rectangle centred on `center`, extending `half_along` along
+    /// the unit vector `along` and `half_across` at right angles to it.
+    pub(crate) fn quad(
+        &mut self,
+        center: Vec2,
+        along: Vec2,
+        half_along: f32,
+        half_across: f32,
+        color: [f32; 4],
+        grid: &WorldGrid,
+    ) {
+        let across = along.perp();
+        let a = along * half_along;
+        let c = across * half_across;
+        let corners = [
+            center - a - c,
+            center + a - c,
+            center + a + c,
+            center - a + c,
+        ];
+        self.triangle(corners[0], corners[1], corners[2], color, grid);
+        self.triangle(corners[0], corners[2], corners[3], color, grid);
+    }
+
+    /// Add a turn arrow whose tail is at `base` and which points along the
+    /// unit vector `forward`, with one head per allowed movement.  Right
+    /// is `forward` rotated a quarter turn clockwise seen from above.
+    pub(crate) fn turn_arrow(
+        &mut self,
+        base: Vec2,
+        forward: Vec2,
+        movements: TurnMovements,
+        color: [f32; 4],
+        grid: &WorldGrid,
+    ) {
+        if movements.is_empty() {
+            return;
+        }
+        let right = forward.perp();
+        let shaft_len = if movements.straight {
+            ARROW_SHAFT_LEN
+        } else {
+            ARROW_SHAFT_LEN * 0.7
+        };
+        let shaft_end = base + forward * shaft_len;
+        self.quad(
+            base + forward * (shaft_len * 0.5),
+            forward,
+            shaft_len * 0.5,
+            ARROW_SHAFT_HW,
+            color,
+            grid,
+        );
+        if movements.straight {
+            self.arrow_head(shaft_end, forward, color, grid);
+        }
+        // Branches leave the shaft near its end and turn sideways.
+        let branch_root = base + forward * (shaft_len - ARROW_SHAFT_HW);
+        for (allowed, side) in [(movements.left, -right), (movements.right, right)] {
+            if !allowed {
+                continue;
+            }
+            let tip = branch_root + side * ARROW_BRANCH_LEN;
+            self.quad(
+                branch_root + side * (ARROW_BRANCH_LEN * 0.5),
+                side,
+                ARROW_BRANCH_LEN * 0.5,
+                ARROW_SHAFT_HW,
+                color,
+                grid,
+            );
+            self.arrow_head(tip, side, color, grid);
+        }
+    }
+
+    fn arrow_head(&mut self, base: Vec2, dir: Vec2, color: [f32; 4], grid: &WorldGrid) {
+        let side = dir.perp() * ARROW_HEAD_HW;
+        self.triangle(
+            base - side,
+            base + side,
+            base + dir * ARROW_HEAD_LEN,
+            color,
+            grid,
+        );
+    }
+
+    pub(crate) fn build(self) -> Mesh {
+        let count = self.positions.len();
+        Mesh::new(
+            bevy::render::mesh::PrimitiveTopology::TriangleList,
+            RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, self.positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 1.0, 0.0]; count])
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, self.colors)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; count])
+        .with_inserted_indices(Indices::U32(self.indices))
+    }
+}
@@ -0,0 +1,411 @@
+//! Road decals generated from the segment/node topology.
+//!
+//! Junctions where three or more roads meet get a zebra crosswalk across
+//! each arm, a stop line behind it on the inbound side and a turn arrow per
+//! inbound lane showing the movements the junction allows.  Traffic keeps
+//! to the right.  Each junction mesh records a signature of the roads
+//! meeting there, so adding, removing or upgrading a road rebuilds only the
+//! junctions it touches.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use bevy::prelude::*;
+
+use simulation::grid::{RoadType, WorldGrid};
+use simulation::road_segments::{RoadSegment, RoadSegmentStore, SegmentNode, SegmentNodeId};
+
+use super::decal_geometry::{DecalMeshBuilder, TurnMovements, ARROW_LEN};
+use super::line_primitives::{bezier_tangent, eval_bezier, road_half_width};
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Crosswalks, stop lines and turn arrows of one junction.
+#[derive(Component)]
+pub struct JunctionDecalMesh {
+    pub node_id: SegmentNodeId,
+    /// [`junction_signature`] of the roads the mesh was built for.
+    pub signature: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Crosswalk stripe colour.
+const CROSSWALK_WHITE: [f32; 4] = [1.0, 1.0, 1.0, 0.6];
+
+/// Stop line colour (slightly more opaque).
+const STOP_LINE_WHITE: [f32; 4] = [1.0, 1.0, 1.0, 0.7];
+
+/// Turn arrow colour.
+const ARROW_WHITE: [f32; 4] = [1.0, 1.0, 1.0, 0.6];
+
+/// Fewest arms a node needs to get junction decals; two arms are a bend.
+const MIN_JUNCTION_ARMS: usize = 3;
+
+/// Width of each crosswalk stripe.
+const BAR_WIDTH: f32 = 0.6;
+
+/// Gap between crosswalk stripes.
+const BAR_GAP: f32 = 0.5;
+
+/// Gap between the junction area and the crosswalk.
+const CROSSWALK_MARGIN: f32 = 0.5;
+
+/// Length of the crosswalk along the road.
+const CROSSWALK_DEPTH: f32 = 3.0;
+
+/// Gap between the crosswalk and the stop line.
+const STOP_LINE_GAP: f32 = 0.6;
+
+/// Stop line thickness (along road direction).
+const STOP_LINE_THICKNESS: f32 = 0.35;
+
+/// Gap between the stop line and the tips of the turn arrows.
+const ARROW_SETBACK: f32 = 1.5;
+
+/// Distance painted decals keep from the road edge.
+pub(super) const CURB_MARGIN: f32 = 0.4;
+
+/// Largest angle between approach and exit still counted as straight on.
+const STRAIGHT_MAX_ANGLE: f32 = 35.0 * std::f32::consts::PI / 180.0;
+
+/// Smallest angle counted as a U-turn, which gets no arrow.
+const U_TURN_MIN_ANGLE: f32 = 160.0 * std::f32::consts::PI / 180.0;
+
+// ---------------------------------------------------------------------------
+// Junction topology
+// ---------------------------------------------------------------------------
+
+/// One road meeting a junction, with its curve reordered to start there.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct JunctionArm {
+    pub curve: [Vec2; 4],
+    pub length: f32,
+    pub road_type: RoadType,
+    /// Traffic on this road may drive into the junction.
+    pub inbound: bool,
+    /// Traffic may leave the junction along this road.
+    pub outbound: bool,
+}
+
+impl JunctionArm {
+    pub(crate) fn new(segment: &RoadSegment, node_id: SegmentNodeId) -> Self {
+        let at_start = segment.start_node == node_id;
+        let curve = if at_start {
+            [segment.p0, segment.p1, segment.p2, segment.p3]
+        } else {
+            [segment.p3, segment.p2, segment.p1, segment.p0]
+        };
+        // One-way roads run from their start node to their end node.
+        let one_way = segment.road_type == RoadType::OneWay;
+        Self {
+            curve,
+            length: segment.arc_length,
+            road_type: segment.road_type,
+            inbound: !one_way || !at_start,
+            outbound: !one_way || at_start,
+        }
+    }
+
+    fn half_width(&self) -> f32 {
+        road_half_width(self.road_type)
+    }
+
+    fn one_way(&self) -> bool {
+        self.road_type == RoadType::OneWay
+    }
+
+    /// Point `distance` along the arm and the unit direction away from the
+    /// junction there.
+    pub(crate) fn point_at(&self, distance: f32) -> (Vec2, Vec2) {
+        let t = if self.length > 0.0 {
+            (distance / self.length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let [p0, p1, p2, p3] = self.curve;
+        let dir = bezier_tangent(p0, p1, p2, p3, t).normalize_or_zero();
+        (eval_bezier(p0, p1, p2, p3, t), dir)
+    }
+
+    /// Direction away from the junction where the arm leaves it.
+    pub(crate) fn direction(&self) -> Vec2 {
+        self.point_at(0.0).1
+    }
+}
+
+/// Whether a road type gets crosswalks and stop lines.  Paths are too
+/// narrow for them and highways have no at-grade crossings.
+fn has_junction_decals(road_type: RoadType) -> bool {
+    !matches!(road_type, RoadType::Path | RoadType::Highway)
+}
+
+/// Arms of `node` that get junction decals.
+pub(crate) fn junction_arms(store: &RoadSegmentStore, node: &SegmentNode) -> Vec<JunctionArm> {
+    node.connected_segments
+        .iter()
+        .filter_map(|&id| store.get_segment(id))
+        .filter(|segment| has_junction_decals(segment.road_type))
+        .map(|segment| JunctionArm::new(segment, node.id))
+        .collect()
+}
+
+/// Hash of everything junction decals depend on: the node position and the
+/// id, type and shape of each connected road.
+pub(crate) fn junction_signature(store: &RoadSegmentStore, node: &SegmentNode) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    node.position.x.to_bits().hash(&mut hasher);
+    node.position.y.to_bits().hash(&mut hasher);
+    for segment in node
+        .connected_segments
+        .iter()
+        .filter_map(|&id| store.get_segment(id))
+    {
+        segment.id.hash(&mut hasher);
+        segment.road_type.hash(&mut hasher);
+        for p in [segment.p0, segment.p1, segment.p2, segment.p3] {
+            p.x.to_bits().hash(&mut hasher);
+            p.y.to_bits().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Movements out of the junction for traffic arriving along `arms[from]`.
+pub(crate) fn turn_movements(arms: &[JunctionArm], from: usize) -> TurnMovements {
+    let travel = -arms[from].direction();
+    let mut movements = TurnMovements::default();
+    for (i, arm) in arms.iter().enumerate() {
+        if i == from || !arm.outbound {
+            continue;
+        }
+        // Positive angles turn right when XZ is mapped onto Vec2.
+        let angle = travel.angle_to(arm.direction());
+        if angle.abs() <= STRAIGHT_MAX_ANGLE {
+            movements.straight = true;
+        } else if angle.abs() >= U_TURN_MIN_ANGLE {
+            continue;
+        } else if angle > 0.0 {
+            movements.right = true;
+        } else {
+            movements.left = true;
+        }
+    }
+    movements
+}
+
+/// Number of inbound lanes on an arm.
+fn inbound_lanes(road_type: RoadType) -> usize {
+    match road_type {
+        RoadType::Path | RoadType::Local | RoadType::OneWay => 1,
+        RoadType::Avenue | RoadType::Boulevard | RoadType::Highway => 2,
+    }
+}
+
+/// Movements painted on inbound lane `lane` of `lanes`, numbered from the
+/// left.  The left lane takes left turns and the right lane right turns;
+/// straight on is shared, and a lane left with nothing takes everything.
+pub(crate) fn lane_movements(all: TurnMovements, lane: usize, lanes: usize) -> TurnMovements {
+    if lanes < 2 {
+        return all;
+    }
+    let lane_moves = TurnMovements {
+        left: all.left && lane == 0,
+        straight: all.straight,
+        right: all.right && lane == lanes - 1,
+    };
+    if lane_moves.is_empty() {
+        all
+    } else {
+        lane_moves
+    }
+}
+
+/// Distance from the node at which an arm's crosswalk starts.
+fn crosswalk_start(junction_radius: f32) -> f32 {
+    junction_radius + CROSSWALK_MARGIN
+}
+
+/// Distance from the node to the centre of an arm's stop line.
+fn stop_line_distance(junction_radius: f32) -> f32 {
+    crosswalk_start(junction_radius) + CROSSWALK_DEPTH + STOP_LINE_GAP + STOP_LINE_THICKNESS * 0.5
+}
+
+/// Extent of the junction area: the half-width of its widest arm.
+fn junction_radius(arms: &[JunctionArm]) -> f32 {
+    arms.iter().map(JunctionArm::half_width).fold(0.0, f32::max)
+}
+
+// ---------------------------------------------------------------------------
+// Mesh construction
+// ---------------------------------------------------------------------------
+
+/// Build crosswalks, stop lines and turn arrows for all arms of a junction.
+pub(crate) fn build_junction_decal_mesh(arms: &[JunctionArm], grid: &WorldGrid) -> Mesh {
+    let mut builder = DecalMeshBuilder::default();
+    let radius = junction_radius(arms);
+
+    for (i, arm) in arms.iter().enumerate() {
+        let hw = arm.half_width();
+        let usable_hw = hw - CURB_MARGIN;
+
+        // Zebra crosswalk: stripes parallel to the road, spread across it.
+        let (mid, dir) = arm.point_at(crosswalk_start(radius) + CROSSWALK_DEPTH * 0.5);
+        let across = dir.perp();
+        let pitch = BAR_WIDTH + BAR_GAP;
+        let bar_count = ((2.0 * usable_hw + BAR_GAP) / pitch).floor().max(1.0) as usize;
+        let first = -(bar_count as f32 - 1.0) * pitch * 0.5;
+        for b in 0..bar_count {
+            builder.quad(
+                mid + across * (first + b as f32 * pitch),
+                dir,
+                CROSSWALK_DEPTH * 0.5,
+                BAR_WIDTH * 0.5,
+                CROSSWALK_WHITE,
+                grid,
+            );
+        }
+
+        if !arm.inbound {
+            continue;
+        }
+
+        // Stop line behind the crosswalk, across the inbound carriageway.
+        let stop_distance = stop_line_distance(radius);
+        let (stop_center, dir) = arm.point_at(stop_distance);
+        let travel = -dir;
+        let right = travel.perp();
+        let (inner, outer) = if arm.one_way() {
+            (-usable_hw, usable_hw)
+        } else {
+            (0.2, usable_hw)
+        };
+        builder.quad(
+            stop_center + right * ((inner + outer) * 0.5),
+            right,
+            (outer - inner) * 0.5,
+            STOP_LINE_THICKNESS * 0.5,
+            STOP_LINE_WHITE,
+            grid,
+        );
+
+        // One arrow per inbound lane, pointing into the junction.  Short
+        // arms have no room for them.
+        let movements = turn_movements(arms, i);
+        let tail_distance = stop_distance + STOP_LINE_THICKNESS * 0.5 + ARROW_SETBACK + ARROW_LEN;
+        if movements.is_empty() || tail_distance > arm.length * 0.5 {
+            continue;
+        }
+        let (tail, dir) = arm.point_at(tail_distance);
+        let travel = -dir;
+        let right = travel.perp();
+        let lanes = inbound_lanes(arm.road_type);
+        let (inner, outer) = if arm.one_way() { (-hw, hw) } else { (0.0, hw) };
+        let lane_width = (outer - inner) / lanes as f32;
+        for lane in 0..lanes {
+            let lateral = inner + lane_width * (lane as f32 + 0.5);
+            builder.turn_arrow(
+                tail + right * lateral,
+                travel,
+                lane_movements(movements, lane, lanes),
+                ARROW_WHITE,
+                grid,
+            );
+        }
+    }
+
+    builder.build()
+}
+
+/// Distance from a node that decals along a road keep clear of.
+pub(super) fn node_setback(
+    store: &RoadSegmentStore,
+    node_id: SegmentNodeId,
+    half_width: f32,
+) -> f32 {
+    let Some(node) = store.get_node(node_id) else {
+        return 0.0;
+    };
+    let arms = junction_arms(store, node);
+    if arms.len() >= MIN_JUNCTION_ARMS {
+        stop_line_distance(junction_radius(&arms)) + STOP_LINE_THICKNESS
+    } else if node.connected_segments.len() >= 2 {
+        half_width * 1.2
+    } else {
+        0.0
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Shared unlit, vertex-coloured material for all decals.
+pub(super) fn decal_material(
+    cached: &mut Option<Handle<StandardMaterial>>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Handle<StandardMaterial> {
+    cached
+        .get_or_insert_with(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                perceptual_roughness: 0.5,
+                unlit: true,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            })
+        })
+        .clone()
+}
+
+/// Keeps one [`JunctionDecalMesh`] per junction in step with the
+/// [`RoadSegmentStore`], rebuilding those whose roads changed.
+pub fn sync_junction_decals(
+    store: Res<RoadSegmentStore>,
+    grid: Res<WorldGrid>,
+    existing: Query<(Entity, &JunctionDecalMesh)>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    if !store.is_changed() {
+        return;
+    }
+
+    let mut junctions: HashMap<SegmentNodeId, (u64, Vec<JunctionArm>)> = HashMap::new();
+    for node in &store.nodes {
+        let arms = junction_arms(&store, node);
+        if arms.len() >= MIN_JUNCTION_ARMS {
+            junctions.insert(node.id, (junction_signature(&store, node), arms));
+        }
+    }
+
+    // Keep meshes whose junction is unchanged; despawn the rest.
+    for (entity, decal) in &existing {
+        match junctions.get(&decal.node_id) {
+            Some((signature, _)) if *signature == decal.signature => {
+                junctions.remove(&decal.node_id);
+            }
+            _ => commands.entity(entity).despawn(),
+        }
+    }
+
+    if junctions.is_empty() {
+        return;
+    }
+    let material = decal_material(&mut material, &mut materials);
+    for (node_id, (signature, arms)) in junctions {
+        commands.spawn((
+            JunctionDecalMesh { node_id, signature },
+            Mesh3d(meshes.add(build_junction_decal_mesh(&arms, &grid))),
+            MeshMaterial3d(material.clone()),
+            Transform::IDENTITY,
+        ));
+    }
+}
@@ -44,7 +44,7 @@ pub(crate) fn road_half_width(road_type: simulation::grid::RoadType) -> f32 {
 }
 
 /// Sample terrain elevation at a world XZ position.
-fn sample_terrain_y(grid: &WorldGrid, world_x: f32, world_z: f32) -> f32 {
+pub(crate) fn sample_terrain_y(grid: &WorldGrid, world_x: f32, world_z: f32) -> f32 {
     let gx = (world_x / CELL_SIZE).floor() as i32;
     let gy = (world_z / CELL_SIZE).floor() as i32;
     let gx = (gx as usize).min(grid.width.saturating_sub(1));
@@ -11,12 +11,21 @@
 //! | Avenue     | double yellow  | none          | solid white|
 //! | Boulevard  | yellow center  | dashed white  | solid white|
 //! | Highway    | barrier        | dashed white  | solid white|
+//!
+//! Road decals (crosswalks, stop lines and turn arrows) are built from the
+//! junction topology in [`decals`]; bus-lane paint lives in
+//! [`bus_lane_decals`].
 
+mod bus_lane_decals;
+mod decal_geometry;
+mod decals;
 mod line_primitives;
 mod mesh_builder;
 
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_decals;
 
 use bevy::prelude::*;
 
@@ -27,6 +36,9 @@ use simulation::road_segments::{RoadSegmentStore, SegmentId, SegmentNodeId};
 use self::line_primitives::road_half_width;
 use self::mesh_builder::build_lane_marking_mesh;
 
+pub use self::bus_lane_decals::{sync_bus_lane_decals, BusLaneDecalMesh};
+pub use self::decals::{sync_junction_decals, JunctionDecalMesh};
+
 // ---------------------------------------------------------------------------
 // Components & resources
 // ---------------------------------------------------------------------------
@@ -50,6 +62,9 @@ pub struct LaneMarkingSyncState {
 /// Y height for marking geometry (above road surface at 0.04).
 const Y_MARKING: f32 = 0.06;
 
+/// Y height for crosswalks, stop lines and arrows, just above the lines.
+const Y_DECAL: f32 = 0.065;
+
 /// Half-width of a painted line.
 const LINE_HW: f32 = 0.12;
 
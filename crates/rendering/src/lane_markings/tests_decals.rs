@@ -0,0 +1,219 @@
+//! Unit tests for crosswalks, stop lines, turn arrows and bus-lane paint.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use simulation::config::{GRID_HEIGHT, GRID_WIDTH};
+use simulation::grid::{RoadType, WorldGrid};
+use simulation::road_segments::{RoadSegment, SegmentId, SegmentNodeId};
+
+use super::bus_lane_decals::has_bus_lane;
+use super::decal_geometry::{DecalMeshBuilder, TurnMovements};
+use super::decals::{build_junction_decal_mesh, lane_movements, turn_movements, JunctionArm};
+
+/// Create a flat grid for tests (all elevation = 0.5).
+fn test_grid() -> WorldGrid {
+    WorldGrid::new(GRID_WIDTH, GRID_HEIGHT)
+}
+
+const JUNCTION: SegmentNodeId = SegmentNodeId(0);
+
+/// Straight segment from the junction at (100, 100) to `far`, drawn from
+/// the junction when `from_junction` is set and towards it otherwise.
+fn arm_segment(id: u32, far: Vec2, road_type: RoadType, from_junction: bool) -> RoadSegment {
+    let (start, end) = if from_junction {
+        (Vec2::new(100.0, 100.0), far)
+    } else {
+        (far, Vec2::new(100.0, 100.0))
+    };
+    let far_node = SegmentNodeId(id + 1);
+    let (start_node, end_node) = if from_junction {
+        (JUNCTION, far_node)
+    } else {
+        (far_node, JUNCTION)
+    };
+    RoadSegment {
+        id: SegmentId(id),
+        start_node,
+        end_node,
+        p0: start,
+        p1: start + (end - start) / 3.0,
+        p2: start + (end - start) * 2.0 / 3.0,
+        p3: end,
+        road_type,
+        arc_length: (end - start).length(),
+        rasterized_cells: vec![],
+    }
+}
+
+/// A four-way junction of local roads; arms point east, north, west, south
+/// in Vec2 terms, where +y is world +Z.
+fn four_way() -> Vec<JunctionArm> {
+    [
+        Vec2::new(200.0, 100.0),
+        Vec2::new(100.0, 200.0),
+        Vec2::new(0.0, 100.0),
+        Vec2::new(100.0, 0.0),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, &far)| {
+        JunctionArm::new(
+            &arm_segment(i as u32, far, RoadType::Local, i % 2 == 0),
+            JUNCTION,
+        )
+    })
+    .collect()
+}
+
+fn position_count(mesh: &Mesh) -> usize {
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .map(|a| a.len())
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_arm_direction_points_away_from_junction() {
+    let outgoing = arm_segment(0, Vec2::new(200.0, 100.0), RoadType::Local, true);
+    let incoming = arm_segment(1, Vec2::new(200.0, 100.0), RoadType::Avenue, false);
+    for segment in [outgoing, incoming] {
+        let dir = JunctionArm::new(&segment, JUNCTION).direction();
+        assert!((dir.x - 1.0).abs() < 0.01, "should point along +x");
+        assert!(dir.y.abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_one_way_arms_flow_start_to_end() {
+    let leaving = arm_segment(0, Vec2::new(200.0, 100.0), RoadType::OneWay, true);
+    let entering = arm_segment(1, Vec2::new(0.0, 100.0), RoadType::OneWay, false);
+    let leaving = JunctionArm::new(&leaving, JUNCTION);
+    let entering = JunctionArm::new(&entering, JUNCTION);
+    assert!(!leaving.inbound && leaving.outbound);
+    assert!(entering.inbound && !entering.outbound);
+}
+
+#[test]
+fn test_four_way_allows_every_movement() {
+    let arms = four_way();
+    let all = TurnMovements {
+        left: true,
+        straight: true,
+        right: true,
+    };
+    for from in 0..arms.len() {
+        assert_eq!(turn_movements(&arms, from), all);
+    }
+}
+
+#[test]
+fn test_turns_follow_right_hand_geometry() {
+    // Drop the south arm: arriving from the west (travelling +x), north
+    // (+y) is a right turn and east is straight on.
+    let arms = &four_way()[..3];
+    let from_west = turn_movements(arms, 2);
+    assert_eq!(
+        from_west,
+        TurnMovements {
+            left: false,
+            straight: true,
+            right: true,
+        }
+    );
+    // The stem of the T can only turn.
+    let from_north = turn_movements(arms, 1);
+    assert!(from_north.left && from_north.right && !from_north.straight);
+}
+
+#[test]
+fn test_one_way_exit_is_not_a_movement() {
+    let mut arms = four_way();
+    // The north arm becomes a one-way road into the junction.
+    arms[1] = JunctionArm::new(
+        &arm_segment(1, Vec2::new(100.0, 200.0), RoadType::OneWay, false),
+        JUNCTION,
+    );
+    assert!(
+        !turn_movements(&arms, 2).right,
+        "cannot turn into a one-way"
+    );
+}
+
+#[test]
+fn test_lane_movements_split_turns_between_lanes() {
+    let all = TurnMovements {
+        left: true,
+        straight: true,
+        right: true,
+    };
+    assert_eq!(lane_movements(all, 0, 1), all);
+    let left_lane = lane_movements(all, 0, 2);
+    let right_lane = lane_movements(all, 1, 2);
+    assert!(left_lane.left && left_lane.straight && !left_lane.right);
+    assert!(!right_lane.left && right_lane.straight && right_lane.right);
+
+    let left_only = TurnMovements {
+        left: true,
+        ..Default::default()
+    };
+    assert_eq!(lane_movements(left_only, 1, 2), left_only);
+}
+
+#[test]
+fn test_junction_decals_face_up() {
+    let grid = test_grid();
+    let mesh = build_junction_decal_mesh(&four_way(), &grid);
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("mesh should have positions");
+    };
+    let Some(bevy::render::mesh::Indices::U32(indices)) = mesh.indices() else {
+        panic!("mesh should have u32 indices");
+    };
+    assert!(!indices.is_empty(), "four-way junction should have decals");
+    for tri in indices.chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(positions[tri[k] as usize]));
+        assert!((b - a).cross(c - a).y > 0.0, "triangle faces down");
+    }
+}
+
+#[test]
+fn test_empty_arms_produce_empty_decal_mesh() {
+    let mesh = build_junction_decal_mesh(&[], &test_grid());
+    assert_eq!(position_count(&mesh), 0);
+}
+
+#[test]
+fn test_turn_arrow_heads_match_movements() {
+    let grid = test_grid();
+    let count = |movements: TurnMovements| {
+        let mut builder = DecalMeshBuilder::default();
+        builder.turn_arrow(Vec2::splat(50.0), Vec2::X, movements, [1.0; 4], &grid);
+        builder.vertex_count()
+    };
+    let straight = TurnMovements {
+        straight: true,
+        ..Default::default()
+    };
+    let all = TurnMovements {
+        left: true,
+        straight: true,
+        right: true,
+    };
+    assert_eq!(count(TurnMovements::default()), 0);
+    assert!(count(all) > count(straight));
+}
+
+#[test]
+fn test_bus_lanes_need_a_served_stop_on_a_wide_road() {
+    let mut avenue = arm_segment(0, Vec2::new(200.0, 100.0), RoadType::Avenue, true);
+    avenue.rasterized_cells = vec![(6, 6), (7, 6)];
+    let mut local = avenue.clone();
+    local.road_type = RoadType::Local;
+    let stops: HashSet<(usize, usize)> = [(7, 6)].into_iter().collect();
+    assert!(has_bus_lane(&avenue, &stops));
+    assert!(!has_bus_lane(&local, &stops));
+    assert!(!has_bus_lane(&avenue, &HashSet::new()));
+}
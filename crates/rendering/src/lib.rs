@@ -8,6 +8,7 @@ automod_dir::dir!(pub "src" exclude "plugin_registration");
 mod plugin_registration;
 
 use angle_snap::AngleSnapState;
+use building_meshes::InstancedModels;
 use camera::{CameraDrag, LeftClickDrag, RightClickDrag};
 use camera_smoothing::{CameraSmoothingConfig, CameraTarget, LastSmoothedState};
 use input::{
@@ -15,6 +16,8 @@ use input::{
     StatusMessage,
 };
 use overlay::{DualOverlayState, OverlayState};
+use overlay_snapshot::OverlaySnapshots;
+use photo_mode::PhotoMode;
 use props::PropsSpawned;
 
 pub struct RenderingPlugin;
@@ -31,9 +34,6 @@ pub struct CameraRenderTarget(pub RenderTarget);
 impl Plugin for RenderingPlugin {
     fn build(&self, app: &mut App) {
         let headless = app.world().contains_resource::<HeadlessRecordMode>();
-        let replay_viewer = app
-            .world()
-            .contains_resource::<simulation::replay::ReplayViewerMode>();
 
         app.init_resource::<CameraDrag>()
             .init_resource::<LeftClickDrag>()
@@ -45,16 +45,19 @@ impl Plugin for RenderingPlugin {
             .init_resource::<ActiveTool>()
             .init_resource::<OverlayState>()
             .init_resource::<DualOverlayState>()
+            .init_resource::<OverlaySnapshots>()
             .init_resource::<StatusMessage>()
             .init_resource::<SelectedBuilding>()
             .init_resource::<PropsSpawned>()
+            .init_resource::<InstancedModels>()
+            .init_resource::<PhotoMode>()
             .init_resource::<RoadDrawState>()
             .init_resource::<GridSnap>()
             .init_resource::<AngleSnapState>()
             .init_resource::<IntersectionSnap>();
 
         // Register all rendering systems and plugins (extracted for conflict-free additions)
-        plugin_registration::register_rendering_systems(app, headless, replay_viewer);
+        plugin_registration::register_rendering_systems(app, headless);
     }
 }
 
@@ -65,7 +68,8 @@ fn setup_lighting(mut commands: Commands) {
         brightness: 300.0,
     });
 
-    // Directional light (sun) angled from above
+    // Directional light (sun) angled from above; `sun_shadows` turns its
+    // shadows on once the quality setting has been applied.
     commands.spawn((
         DirectionalLight {
             illuminance: 10000.0,
@@ -16,10 +16,15 @@ pub enum OverlayMode {
     GroundwaterLevel,
     GroundwaterQuality,
     Wind,
+    Biodiversity,
+    Erosion,
+    CommuteTime,
+    TransitRidership,
+    JobAccess,
 }
 
 /// Ordered list of all overlay modes for Tab/Shift+Tab cycling.
-const ALL_OVERLAYS: [OverlayMode; 13] = [
+const ALL_OVERLAYS: [OverlayMode; 18] = [
     OverlayMode::None,
     OverlayMode::Power,
     OverlayMode::Water,
@@ -33,10 +38,15 @@ const ALL_OVERLAYS: [OverlayMode; 13] = [
     OverlayMode::GroundwaterLevel,
     OverlayMode::GroundwaterQuality,
     OverlayMode::Wind,
+    OverlayMode::Biodiversity,
+    OverlayMode::Erosion,
+    OverlayMode::CommuteTime,
+    OverlayMode::TransitRidership,
+    OverlayMode::JobAccess,
 ];
 
 /// List of overlay modes excluding None, for UI dropdowns.
-pub const OVERLAY_CHOICES: [OverlayMode; 12] = [
+pub const OVERLAY_CHOICES: [OverlayMode; 17] = [
     OverlayMode::Power,
     OverlayMode::Water,
     OverlayMode::Traffic,
@@ -49,6 +59,11 @@ pub const OVERLAY_CHOICES: [OverlayMode; 12] = [
     OverlayMode::GroundwaterLevel,
     OverlayMode::GroundwaterQuality,
     OverlayMode::Wind,
+    OverlayMode::Biodiversity,
+    OverlayMode::Erosion,
+    OverlayMode::CommuteTime,
+    OverlayMode::TransitRidership,
+    OverlayMode::JobAccess,
 ];
 
 impl OverlayMode {
@@ -80,6 +95,11 @@ impl OverlayMode {
             Self::GroundwaterLevel => "Groundwater Level",
             Self::GroundwaterQuality => "Groundwater Quality",
             Self::Wind => "Wind",
+            Self::Biodiversity => "Biodiversity",
+            Self::Erosion => "Erosion",
+            Self::CommuteTime => "Commute Time",
+            Self::TransitRidership => "Transit Ridership",
+            Self::JobAccess => "Job Access",
         }
     }
 }
@@ -184,6 +204,11 @@ mod tests {
             OverlayMode::GroundwaterLevel,
             OverlayMode::GroundwaterQuality,
             OverlayMode::Wind,
+            OverlayMode::Biodiversity,
+            OverlayMode::Erosion,
+            OverlayMode::CommuteTime,
+            OverlayMode::TransitRidership,
+            OverlayMode::JobAccess,
             OverlayMode::None, // wraps back
         ];
         for &exp in &expected {
@@ -196,6 +221,11 @@ mod tests {
     fn prev_cycles_backward_through_all_overlays() {
         let mut mode = OverlayMode::None;
         let expected = [
+            OverlayMode::JobAccess,
+            OverlayMode::TransitRidership,
+            OverlayMode::CommuteTime,
+            OverlayMode::Erosion,
+            OverlayMode::Biodiversity,
             OverlayMode::Wind,
             OverlayMode::GroundwaterQuality,
             OverlayMode::GroundwaterLevel,
@@ -266,6 +296,6 @@ mod tests {
         for &mode in &OVERLAY_CHOICES {
             assert_ne!(mode, OverlayMode::None);
         }
-        assert_eq!(OVERLAY_CHOICES.len(), 12);
+        assert_eq!(OVERLAY_CHOICES.len(), 17);
     }
 }
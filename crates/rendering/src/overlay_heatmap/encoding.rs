@@ -0,0 +1,277 @@
+//! Packing grid data into the textures the heatmap shader samples.
+//!
+//! Each cell becomes one RGBA8 texel: R is the overlay value normalised to
+//! 0..=1, G the [`CellTreatment`] and B the map-tile shade.  Colours are
+//! not computed here; every overlay has a 256-entry ramp that the shader
+//! looks the (interpolated) value up in.
+
+use simulation::grid::{Cell, CellType, WorldGrid};
+
+use crate::aqi_colors;
+use crate::color_ramps::{
+    BEACH_SAND, CHANGE, CIVIDIS, GROUNDWATER_LEVEL, GROUNDWATER_QUALITY, INFERNO, VIRIDIS,
+};
+use crate::overlay::{DualOverlayState, OverlayMode};
+use crate::overlay_snapshot::{diff_cell, OverlaySnapshot};
+use crate::terrain_render::{DualOverlayInfo, OverlayGrids, UNOWNED_TILE_SHADE};
+
+use super::material::OverlayLayerUniform;
+
+/// Commute time at the top of the commute overlay's ramp, in minutes.
+pub const COMMUTE_OVERLAY_MINUTES: f32 = 60.0;
+
+/// Entries in an overlay ramp texture.
+pub const RAMP_SIZE: usize = 256;
+
+/// How the shader colours a cell.  The discriminants are the values stored
+/// in the G channel and must match `terrain_overlay.wgsl`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellTreatment {
+    /// Leave the terrain colour alone.
+    Base = 0,
+    /// Replace the terrain colour with the ramp colour for the value.
+    Ramp = 1,
+    /// Darken the terrain colour by the layer's factor.
+    Darken = 2,
+    /// Blend the terrain colour toward the layer's tint by the value.
+    Tint = 3,
+}
+
+/// Whether an overlay is drawn by the heatmap shader rather than baked
+/// into the terrain mesh.  Power and water colour cells by the network
+/// that supplies them, which stays on the CPU.
+pub fn is_heatmap(mode: OverlayMode) -> bool {
+    !matches!(
+        mode,
+        OverlayMode::None | OverlayMode::Power | OverlayMode::Water
+    )
+}
+
+/// The overlays still baked into terrain vertex colours: the primary
+/// overlay and dual-overlay settings to build chunk meshes with.
+pub fn baked_overlay(
+    primary: OverlayMode,
+    dual: &DualOverlayState,
+) -> (OverlayMode, DualOverlayInfo) {
+    let secondary = if dual.is_active(primary) {
+        dual.secondary
+    } else {
+        OverlayMode::None
+    };
+    let bake = |mode: OverlayMode| mode != OverlayMode::None && !is_heatmap(mode);
+    match (bake(primary), bake(secondary)) {
+        (true, true) => (
+            primary,
+            DualOverlayInfo {
+                secondary,
+                mode: dual.mode,
+                blend_factor: dual.blend_factor,
+            },
+        ),
+        (true, false) => (primary, DualOverlayInfo::default()),
+        (false, true) => (secondary, DualOverlayInfo::default()),
+        (false, false) => (OverlayMode::None, DualOverlayInfo::default()),
+    }
+}
+
+/// Darken factor, tint and pulse threshold of an overlay layer.
+pub fn layer_style(mode: OverlayMode) -> OverlayLayerUniform {
+    let mut style = OverlayLayerUniform {
+        enabled: is_heatmap(mode) as u32,
+        darken: 1.0,
+        ..Default::default()
+    };
+    match mode {
+        OverlayMode::Traffic => style.darken = 0.5,
+        OverlayMode::Wind => style.darken = 0.7,
+        OverlayMode::Biodiversity
+        | OverlayMode::Erosion
+        | OverlayMode::CommuteTime
+        | OverlayMode::TransitRidership => style.darken = 0.6,
+        OverlayMode::WaterPollution => {
+            // Land near polluted water gets a subtle brown tint.
+            style.darken = 0.7;
+            style.tint = bevy::math::Vec4::new(0.5, 0.35, 0.15, 0.4);
+        }
+        OverlayMode::GroundwaterLevel => {
+            // Cells below 30% pulse toward warning orange.
+            style.tint = bevy::math::Vec4::new(1.0, 0.6, 0.0, 0.3);
+            style.pulse_below = 76.0 / 255.0;
+        }
+        _ => {}
+    }
+    style
+}
+
+/// Overlay value (0..=255) and treatment of one cell.
+pub fn encode_cell(
+    mode: OverlayMode,
+    cell: &Cell,
+    grids: &OverlayGrids,
+    gx: usize,
+    gy: usize,
+) -> (u8, CellTreatment) {
+    use CellTreatment::{Base, Darken, Ramp, Tint};
+
+    let water = cell.cell_type == CellType::Water;
+    let scaled = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let land = |value: Option<u8>| match value {
+        _ if water => (0, Base),
+        Some(v) => (v, Ramp),
+        None => (0, Base),
+    };
+
+    match mode {
+        OverlayMode::None | OverlayMode::Power | OverlayMode::Water => (0, Base),
+        OverlayMode::Traffic => match grids.traffic {
+            Some(traffic) if cell.cell_type == CellType::Road => {
+                (scaled(traffic.congestion_level(gx, gy)), Ramp)
+            }
+            Some(_) => (0, Darken),
+            None => (0, Base),
+        },
+        OverlayMode::Pollution => land(grids.pollution.map(|g| g.get(gx, gy))),
+        OverlayMode::LandValue => land(grids.land_value.map(|g| g.get(gx, gy))),
+        OverlayMode::Education => land(grids.education.map(|g| scaled(g.get(gx, gy) as f32 / 3.0))),
+        OverlayMode::Garbage => land(grids.garbage.map(|g| scaled(g.get(gx, gy) as f32 / 30.0))),
+        OverlayMode::Noise => land(grids.noise.map(|g| scaled(g.get(gx, gy) as f32 / 100.0))),
+        OverlayMode::GroundwaterLevel => land(grids.groundwater.map(|g| g.get(gx, gy))),
+        OverlayMode::GroundwaterQuality => land(grids.water_quality.map(|g| g.get(gx, gy))),
+        OverlayMode::WaterPollution => match grids.water_pollution.map(|g| g.get(gx, gy)) {
+            Some(level) if water => (level, Ramp),
+            Some(0) => (0, Darken),
+            Some(level) => (level, Tint),
+            None => (0, Base),
+        },
+        OverlayMode::Wind => (0, Darken),
+        OverlayMode::Biodiversity => match grids.biodiversity.map(|g| g.get(gx, gy)) {
+            // Developed land and isolated fragments support no wildlife.
+            Some(0) => (0, Darken),
+            Some(level) => (level, Ramp),
+            None => (0, Base),
+        },
+        OverlayMode::Erosion => match grids.erosion.map(|g| g.get(gx, gy)) {
+            _ if water => (0, Base),
+            // Only beaches erode; dim everything inland.
+            Some(0) => (0, Darken),
+            Some(level) => (level, Ramp),
+            None => (0, Base),
+        },
+        OverlayMode::CommuteTime => match grids.commute_time.map(|g| g.average(gx, gy)) {
+            _ if water => (0, Base),
+            Some(Some(minutes)) => (scaled(minutes / COMMUTE_OVERLAY_MINUTES), Ramp),
+            // Nobody commutes from here.
+            Some(None) => (0, Darken),
+            None => (0, Base),
+        },
+        OverlayMode::TransitRidership => match grids.transit_ridership.map(|g| g.share(gx, gy)) {
+            Some(Some(share)) => (scaled(share), Ramp),
+            Some(None) => (0, Darken),
+            None => (0, Base),
+        },
+        OverlayMode::JobAccess => land(grids.job_access.map(|g| scaled(g.share(gx, gy)))),
+    }
+}
+
+/// Map-tile shade of a cell: unowned tiles are drawn darker.
+fn tile_shade(grids: &OverlayGrids, gx: usize, gy: usize) -> u8 {
+    if grids.map_tiles.is_some_and(|t| !t.contains(gx, gy)) {
+        (UNOWNED_TILE_SHADE * 255.0).round() as u8
+    } else {
+        u8::MAX
+    }
+}
+
+/// Pack every cell of `grid` into `texels` (RGBA8, row-major).
+pub fn pack_cells(mode: OverlayMode, grid: &WorldGrid, grids: &OverlayGrids, texels: &mut [u8]) {
+    for gy in 0..grid.height {
+        for gx in 0..grid.width {
+            let (value, treatment) = encode_cell(mode, grid.get(gx, gy), grids, gx, gy);
+            let shade = tile_shade(grids, gx, gy);
+            let i = (gy * grid.width + gx) * 4;
+            texels[i..i + 4].copy_from_slice(&[value, treatment as u8, shade, u8::MAX]);
+        }
+    }
+}
+
+/// Pack the change of every cell since `snapshot` into `texels`, to be
+/// coloured with [`diff_ramp_texels`].
+pub fn pack_diff(
+    snapshot: &OverlaySnapshot,
+    grid: &WorldGrid,
+    grids: &OverlayGrids,
+    texels: &mut [u8],
+) {
+    for gy in 0..grid.height {
+        for gx in 0..grid.width {
+            let idx = gy * grid.width + gx;
+            let after = encode_cell(snapshot.mode, grid.get(gx, gy), grids, gx, gy);
+            let before = snapshot.cells.get(idx).copied().unwrap_or(after);
+            let (value, treatment) = diff_cell(before, after);
+            let shade = tile_shade(grids, gx, gy);
+            texels[idx * 4..idx * 4 + 4].copy_from_slice(&[value, treatment as u8, shade, u8::MAX]);
+        }
+    }
+}
+
+/// Style of a layer drawn as a snapshot diff: unchanged cells are dimmed,
+/// nothing is tinted or pulses.
+pub fn diff_layer_style() -> OverlayLayerUniform {
+    OverlayLayerUniform {
+        enabled: 1,
+        darken: 0.6,
+        ..Default::default()
+    }
+}
+
+/// Colour of a ramp entry, as the sRGB components the terrain's vertex
+/// colours use.
+pub fn ramp_color(mode: OverlayMode, index: u8) -> [f32; 3] {
+    let t = index as f32 / 255.0;
+    let color = match mode {
+        OverlayMode::Traffic | OverlayMode::Garbage | OverlayMode::Noise => INFERNO.sample(t),
+        // EPA AQI 6-tier color scheme (POLL-020)
+        OverlayMode::Pollution => aqi_colors::aqi_overlay_color(index),
+        OverlayMode::LandValue => CIVIDIS.sample(t),
+        OverlayMode::Education | OverlayMode::Biodiversity | OverlayMode::TransitRidership => {
+            VIRIDIS.sample(t)
+        }
+        OverlayMode::CommuteTime => INFERNO.sample(t),
+        OverlayMode::JobAccess => CIVIDIS.sample(t),
+        // Reversed so clean water is bright and polluted water dark.
+        OverlayMode::WaterPollution => VIRIDIS.sample(1.0 - t),
+        OverlayMode::GroundwaterLevel => GROUNDWATER_LEVEL.sample(t),
+        OverlayMode::GroundwaterQuality => GROUNDWATER_QUALITY.sample(t),
+        // Red (washed away) -> pale sand (full beach); 0 is off the beach.
+        OverlayMode::Erosion => BEACH_SAND.sample(index.saturating_sub(1) as f32 / 254.0),
+        OverlayMode::None | OverlayMode::Power | OverlayMode::Water | OverlayMode::Wind => {
+            return [0.0; 3];
+        }
+    };
+    let srgba = color.to_srgba();
+    [srgba.red, srgba.green, srgba.blue]
+}
+
+/// Pack a ramp of `RAMP_SIZE` colours into an RGBA8 texture.
+fn ramp_image_texels(color: impl Fn(u8) -> [f32; 3]) -> Vec<u8> {
+    (0..RAMP_SIZE)
+        .flat_map(|i| {
+            let [r, g, b] = color(i as u8);
+            let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+            [byte(r), byte(g), byte(b), u8::MAX]
+        })
+        .collect()
+}
+
+/// The ramp texture of an overlay (RGBA8, `RAMP_SIZE` x 1).
+pub fn ramp_texels(mode: OverlayMode) -> Vec<u8> {
+    ramp_image_texels(|i| ramp_color(mode, i))
+}
+
+/// The ramp texture of a snapshot diff: decreases blue, increases orange.
+pub fn diff_ramp_texels() -> Vec<u8> {
+    ramp_image_texels(|i| {
+        let srgba = CHANGE.sample(i as f32 / 255.0).to_srgba();
+        [srgba.red, srgba.green, srgba.blue]
+    })
+}
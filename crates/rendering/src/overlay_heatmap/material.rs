@@ -0,0 +1,90 @@
+//! The terrain material: [`StandardMaterial`] extended with the heatmap
+//! fragment shader, its grid textures and the detail textures of the
+//! terrain splat layers.
+
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
+
+/// Material shared by all terrain chunks.
+pub type TerrainMaterial = ExtendedMaterial<StandardMaterial, TerrainOverlayExtension>;
+
+/// Asset path of the shader registered with `embedded_asset!`.
+const SHADER_PATH: &str = "embedded://rendering/overlay_heatmap/terrain_overlay.wgsl";
+
+/// How one overlay layer is drawn.  Field order must match `OverlayLayer`
+/// in `terrain_overlay.wgsl`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, ShaderType, Reflect)]
+pub struct OverlayLayerUniform {
+    /// Colour for tinted cells and pulse warnings; alpha is the strength.
+    pub tint: Vec4,
+    /// Non-zero when the layer is drawn.
+    pub enabled: u32,
+    /// Factor darkened cells are multiplied by.
+    pub darken: f32,
+    /// Ramp cells with values below this pulse toward `tint`.
+    pub pulse_below: f32,
+}
+
+/// Shader parameters.  Field order must match `TerrainOverlay` in
+/// `terrain_overlay.wgsl`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, ShaderType, Reflect)]
+pub struct TerrainOverlayUniform {
+    pub primary: OverlayLayerUniform,
+    pub secondary: OverlayLayerUniform,
+    /// Grid size in cells.
+    pub grid_size: Vec2,
+    pub cell_size: f32,
+    /// One of the `DUAL_*` constants.
+    pub dual_mode: u32,
+    /// Share of the secondary layer in blend mode.
+    pub blend_factor: f32,
+    /// World X where split mode switches to the secondary layer.
+    pub split_x: f32,
+    /// Seconds, for animated layers; zero when nothing animates.
+    pub time: f32,
+}
+
+/// Only the primary layer is drawn.
+pub const DUAL_NONE: u32 = 0;
+/// Both layers are blended by `blend_factor`.
+pub const DUAL_BLEND: u32 = 1;
+/// The primary layer is drawn west of `split_x`, the secondary east of it.
+pub const DUAL_SPLIT: u32 = 2;
+
+/// Heatmap extension of the terrain material.  Each layer has a grid
+/// texture of packed cell values and a ramp texture of colours.  The splat
+/// textures add surface detail to open ground (see `terrain_render::splat`)
+/// and share the sampler of the sand texture.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct TerrainOverlayExtension {
+    #[uniform(100)]
+    pub params: TerrainOverlayUniform,
+    #[texture(101)]
+    pub primary_cells: Handle<Image>,
+    #[texture(102)]
+    pub primary_ramp: Handle<Image>,
+    #[texture(103)]
+    pub secondary_cells: Handle<Image>,
+    #[texture(104)]
+    pub secondary_ramp: Handle<Image>,
+    #[texture(105)]
+    #[sampler(106)]
+    pub sand_detail: Handle<Image>,
+    #[texture(107)]
+    pub grass_detail: Handle<Image>,
+    #[texture(108)]
+    pub rock_detail: Handle<Image>,
+    #[texture(109)]
+    pub soil_detail: Handle<Image>,
+}
+
+impl MaterialExtension for TerrainOverlayExtension {
+    fn fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn deferred_fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+}
@@ -0,0 +1,49 @@
+//! GPU heatmaps for the data overlays.
+//!
+//! Terrain chunks share one [`TerrainMaterial`]: the standard material with
+//! a fragment shader that draws the continuous overlays (traffic,
+//! pollution, land value, noise and the rest) on top of the terrain colour.
+//! The shader samples a texture of raw per-cell values, interpolates them
+//! smoothly between cells and colours them through a per-overlay ramp, so
+//! switching overlays, blending or splitting two of them and following
+//! changing data only update textures and uniforms.  Depleted groundwater
+//! pulses to draw the eye.
+//!
+//! Power and water colour cells by the network that supplies them and stay
+//! baked into the chunk meshes (see [`baked_overlay`]).
+//!
+//! The primary layer can also show the change since an overlay snapshot
+//! (see [`crate::overlay_snapshot`]) through a diverging ramp.
+
+mod encoding;
+mod material;
+mod systems;
+
+#[cfg(test)]
+mod tests;
+
+use bevy::asset::embedded_asset;
+use bevy::prelude::*;
+
+pub use encoding::{
+    baked_overlay, diff_layer_style, diff_ramp_texels, encode_cell, is_heatmap, layer_style,
+    pack_diff, ramp_color, CellTreatment, RAMP_SIZE,
+};
+pub use material::{
+    OverlayLayerUniform, TerrainMaterial, TerrainOverlayExtension, TerrainOverlayUniform,
+    DUAL_BLEND, DUAL_NONE, DUAL_SPLIT,
+};
+pub use systems::{overlay_uniform, setup_overlay_heatmap, sync_overlay_heatmap, OverlayHeatmap};
+
+pub struct OverlayHeatmapPlugin;
+
+impl Plugin for OverlayHeatmapPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "terrain_overlay.wgsl");
+        app.add_plugins(MaterialPlugin::<TerrainMaterial>::default())
+            .add_systems(
+                Update,
+                sync_overlay_heatmap.run_if(resource_exists::<OverlayHeatmap>),
+            );
+    }
+}
@@ -0,0 +1,322 @@
+//! Creating the terrain material and keeping its heatmap textures and
+//! parameters in step with the overlay state and the simulation grids.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use simulation::coastal_erosion::ErosionGrid;
+use simulation::commute_grids::{CommuteTimeGrid, JobAccessGrid, TransitRidershipGrid};
+use simulation::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use simulation::education::EducationGrid;
+use simulation::garbage::GarbageGrid;
+use simulation::grid::WorldGrid;
+use simulation::groundwater::{GroundwaterGrid, WaterQualityGrid};
+use simulation::land_value::LandValueGrid;
+use simulation::map_tiles::MapTiles;
+use simulation::noise::NoisePollutionGrid;
+use simulation::pollution::PollutionGrid;
+use simulation::time_of_day::GameClock;
+use simulation::traffic::TrafficGrid;
+use simulation::water_pollution::WaterPollutionGrid;
+use simulation::wildlife::BiodiversityGrid;
+
+use crate::overlay::{DualOverlayMode, DualOverlayState, OverlayMode, OverlayState};
+use crate::overlay_snapshot::{capture_snapshot, OverlaySnapshot, OverlaySnapshots};
+use crate::terrain_render::{
+    splat_layer_image, OverlayGrids, LAYER_GRASS, LAYER_ROCK, LAYER_SAND, LAYER_SOIL,
+};
+
+use super::encoding::{
+    diff_layer_style, diff_ramp_texels, is_heatmap, layer_style, pack_cells, pack_diff,
+    ramp_texels, RAMP_SIZE,
+};
+use super::material::{
+    TerrainMaterial, TerrainOverlayExtension, TerrainOverlayUniform, DUAL_BLEND, DUAL_NONE,
+    DUAL_SPLIT,
+};
+
+/// Textures of one overlay layer.
+struct HeatmapLayer {
+    /// Overlay packed into `cells`; `None` before the first upload.
+    mode: OverlayMode,
+    /// Whether `cells` holds a diff against a snapshot of the overlay.
+    diff: bool,
+    cells: Handle<Image>,
+    ramp: Handle<Image>,
+}
+
+/// The shared terrain material and the textures it samples.
+#[derive(Resource)]
+pub struct OverlayHeatmap {
+    pub material: Handle<TerrainMaterial>,
+    primary: HeatmapLayer,
+    secondary: HeatmapLayer,
+}
+
+fn data_image(width: usize, height: usize) -> Image {
+    Image::new_fill(
+        Extent3d {
+            width: width as u32,
+            height: height as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, u8::MAX, u8::MAX],
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+}
+
+/// Create the terrain material with empty heatmap textures.
+pub fn setup_overlay_heatmap(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    let mut layer = || HeatmapLayer {
+        mode: OverlayMode::None,
+        diff: false,
+        cells: images.add(data_image(GRID_WIDTH, GRID_HEIGHT)),
+        ramp: images.add(data_image(RAMP_SIZE, 1)),
+    };
+    let primary = layer();
+    let secondary = layer();
+    let material = materials.add(TerrainMaterial {
+        base: StandardMaterial {
+            base_color: Color::WHITE,
+            perceptual_roughness: 0.9,
+            ..default()
+        },
+        extension: TerrainOverlayExtension {
+            params: overlay_uniform(OverlayMode::None, &DualOverlayState::default(), 0.0),
+            primary_cells: primary.cells.clone(),
+            primary_ramp: primary.ramp.clone(),
+            secondary_cells: secondary.cells.clone(),
+            secondary_ramp: secondary.ramp.clone(),
+            sand_detail: images.add(splat_layer_image(LAYER_SAND)),
+            grass_detail: images.add(splat_layer_image(LAYER_GRASS)),
+            rock_detail: images.add(splat_layer_image(LAYER_ROCK)),
+            soil_detail: images.add(splat_layer_image(LAYER_SOIL)),
+        },
+    });
+    commands.insert_resource(OverlayHeatmap {
+        material,
+        primary,
+        secondary,
+    });
+}
+
+/// Shader parameters for the overlay state.  `elapsed` is only passed on
+/// when a layer animates, so static overlays leave the material untouched.
+pub fn overlay_uniform(
+    primary: OverlayMode,
+    dual: &DualOverlayState,
+    elapsed: f32,
+) -> TerrainOverlayUniform {
+    let dual_active = dual.is_active(primary);
+    let secondary = layer_style(if dual_active {
+        dual.secondary
+    } else {
+        OverlayMode::None
+    });
+    let primary = layer_style(primary);
+    // A layer that is baked into the mesh is drawn as the terrain colour,
+    // so dual mode is needed whenever either layer is a heatmap.
+    let dual_mode = if !dual_active || (primary.enabled == 0 && secondary.enabled == 0) {
+        DUAL_NONE
+    } else {
+        match dual.mode {
+            DualOverlayMode::Blend => DUAL_BLEND,
+            DualOverlayMode::Split => DUAL_SPLIT,
+        }
+    };
+    let animated = [primary, secondary]
+        .iter()
+        .any(|layer| layer.enabled != 0 && layer.pulse_below > 0.0);
+    TerrainOverlayUniform {
+        primary,
+        secondary,
+        grid_size: Vec2::new(GRID_WIDTH as f32, GRID_HEIGHT as f32),
+        cell_size: CELL_SIZE,
+        dual_mode,
+        blend_factor: dual.blend_factor.clamp(0.0, 1.0),
+        split_x: (GRID_WIDTH / 2) as f32 * CELL_SIZE,
+        time: if animated { elapsed } else { 0.0 },
+    }
+}
+
+/// Repack a layer's textures if its overlay, its snapshot diff or the data
+/// behind it changed.  Returns whether anything was uploaded.
+fn refresh_layer(
+    layer: &mut HeatmapLayer,
+    mode: OverlayMode,
+    diff: Option<&OverlaySnapshot>,
+    data_changed: bool,
+    grid: &WorldGrid,
+    grids: &OverlayGrids,
+    images: &mut Assets<Image>,
+) -> bool {
+    let same_view = layer.mode == mode && layer.diff == diff.is_some();
+    if !is_heatmap(mode) || (same_view && !data_changed) {
+        return false;
+    }
+    if !same_view {
+        if let Some(ramp) = images.get_mut(&layer.ramp) {
+            ramp.data = if diff.is_some() {
+                diff_ramp_texels()
+            } else {
+                ramp_texels(mode)
+            };
+        }
+        layer.mode = mode;
+        layer.diff = diff.is_some();
+    }
+    if let Some(cells) = images.get_mut(&layer.cells) {
+        match diff {
+            Some(snapshot) => pack_diff(snapshot, grid, grids, &mut cells.data),
+            None => pack_cells(mode, grid, grids, &mut cells.data),
+        }
+    }
+    true
+}
+
+/// Upload the grids behind the visible heatmaps when they change and keep
+/// the shader parameters current.  Also captures requested overlay
+/// snapshots, and draws the primary overlay as a diff against its snapshot
+/// when the diff view is on.
+///
+/// Only raw per-cell values are packed on the CPU; colouring, smoothing,
+/// dual-overlay blending and animation happen in the fragment shader, so
+/// switching overlays or data updates never rebuild terrain meshes.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_overlay_heatmap(
+    overlay_params: (Res<OverlayState>, Res<DualOverlayState>),
+    grid: Res<WorldGrid>,
+    city_grids: (
+        Res<PollutionGrid>,
+        Res<LandValueGrid>,
+        Res<EducationGrid>,
+        Res<GarbageGrid>,
+        Res<TrafficGrid>,
+        Res<NoisePollutionGrid>,
+    ),
+    env_grids: (
+        Res<WaterPollutionGrid>,
+        Res<BiodiversityGrid>,
+        Res<ErosionGrid>,
+    ),
+    groundwater_grids: (Res<GroundwaterGrid>, Res<WaterQualityGrid>),
+    commute_grids: (
+        Res<CommuteTimeGrid>,
+        Res<TransitRidershipGrid>,
+        Res<JobAccessGrid>,
+    ),
+    map_tiles: Res<MapTiles>,
+    time: Res<Time>,
+    clock: Res<GameClock>,
+    mut snapshots: ResMut<OverlaySnapshots>,
+    mut heatmap: ResMut<OverlayHeatmap>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    let (overlay, dual) = overlay_params;
+    let (pollution, land_value, education, garbage, traffic, noise) = city_grids;
+    let (water_pollution, biodiversity, erosion) = env_grids;
+    let (groundwater, water_quality) = groundwater_grids;
+    let (commute_time, transit_ridership, job_access) = commute_grids;
+
+    let data_changed = |mode: OverlayMode| {
+        grid.is_changed()
+            || map_tiles.is_changed()
+            || match mode {
+                OverlayMode::Pollution => pollution.is_changed(),
+                OverlayMode::LandValue => land_value.is_changed(),
+                OverlayMode::Education => education.is_changed(),
+                OverlayMode::Garbage => garbage.is_changed(),
+                OverlayMode::Traffic => traffic.is_changed(),
+                OverlayMode::Noise => noise.is_changed(),
+                OverlayMode::WaterPollution => water_pollution.is_changed(),
+                OverlayMode::GroundwaterLevel => groundwater.is_changed(),
+                OverlayMode::GroundwaterQuality => water_quality.is_changed(),
+                OverlayMode::Biodiversity => biodiversity.is_changed(),
+                OverlayMode::Erosion => erosion.is_changed(),
+                OverlayMode::CommuteTime => commute_time.is_changed(),
+                OverlayMode::TransitRidership => transit_ridership.is_changed(),
+                OverlayMode::JobAccess => job_access.is_changed(),
+                OverlayMode::None | OverlayMode::Power | OverlayMode::Water | OverlayMode::Wind => {
+                    false
+                }
+            }
+    };
+    let grids = OverlayGrids {
+        pollution: Some(&pollution),
+        land_value: Some(&land_value),
+        education: Some(&education),
+        garbage: Some(&garbage),
+        traffic: Some(&traffic),
+        noise: Some(&noise),
+        water_pollution: Some(&water_pollution),
+        groundwater: Some(&groundwater),
+        water_quality: Some(&water_quality),
+        snow: None,
+        biodiversity: Some(&biodiversity),
+        erosion: Some(&erosion),
+        commute_time: Some(&commute_time),
+        transit_ridership: Some(&transit_ridership),
+        job_access: Some(&job_access),
+        map_tiles: Some(&map_tiles),
+        biomes: None,
+    };
+
+    if let Some(mode) = snapshots.pending_capture {
+        snapshots.pending_capture = None;
+        snapshots.insert(capture_snapshot(mode, clock.day, &grid, &grids));
+    }
+    // A snapshot from a later day or another map size belongs to a different
+    // city that has since been loaded or started.
+    let cell_count = grid.width * grid.height;
+    if snapshots
+        .snapshots
+        .iter()
+        .any(|s| s.day > clock.day || s.cells.len() != cell_count)
+    {
+        snapshots
+            .snapshots
+            .retain(|s| s.day <= clock.day && s.cells.len() == cell_count);
+    }
+
+    let primary = overlay.mode;
+    let secondary = if dual.is_active(primary) {
+        dual.secondary
+    } else {
+        OverlayMode::None
+    };
+    let diff = snapshots.diff_for(primary);
+    let heatmap = &mut *heatmap;
+    let mut uploaded = false;
+    for (layer, mode, diff) in [
+        (&mut heatmap.primary, primary, diff),
+        (&mut heatmap.secondary, secondary, None),
+    ] {
+        let changed = data_changed(mode) || (diff.is_some() && snapshots.is_changed());
+        uploaded |= refresh_layer(layer, mode, diff, changed, &grid, &grids, &mut images);
+    }
+
+    // Touching the material also rebinds textures that were re-uploaded.
+    let mut params = overlay_uniform(primary, &dual, time.elapsed_secs());
+    if heatmap.primary.diff && is_heatmap(primary) {
+        params.primary = diff_layer_style();
+        if params.secondary.enabled == 0 || params.secondary.pulse_below == 0.0 {
+            params.time = 0.0;
+        }
+    }
+    let stale = materials
+        .get(&heatmap.material)
+        .is_some_and(|material| material.extension.params != params);
+    if uploaded || stale {
+        if let Some(material) = materials.get_mut(&heatmap.material) {
+            material.extension.params = params;
+        }
+    }
+}
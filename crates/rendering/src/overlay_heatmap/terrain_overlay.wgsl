@@ -0,0 +1,198 @@
+// Terrain fragment shader: the standard PBR material with splat layer
+// detail on open ground and data overlays drawn on top.  Open ground
+// blends the sand, grass, rock and soil detail textures by the weights
+// packed into the vertex UVs (UV_0 = sand, rock; UV_1 = soil, strength).
+// Each overlay layer reads packed cell values from a grid
+// texture (R = value, G = treatment, B = map-tile shade), interpolates the
+// value between neighbouring cells of the same treatment and looks it up
+// in the layer's 256-entry colour ramp.
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::alpha_discard,
+}
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::{
+    prepass_io::{VertexOutput, FragmentOutput},
+    pbr_deferred_functions::deferred_output,
+}
+#else
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+#endif
+
+// Must match `OverlayLayerUniform`.
+struct OverlayLayer {
+    tint: vec4<f32>,
+    enabled: u32,
+    darken: f32,
+    pulse_below: f32,
+}
+
+// Must match `TerrainOverlayUniform`.
+struct TerrainOverlay {
+    primary: OverlayLayer,
+    secondary: OverlayLayer,
+    grid_size: vec2<f32>,
+    cell_size: f32,
+    dual_mode: u32,
+    blend_factor: f32,
+    split_x: f32,
+    time: f32,
+}
+
+// `CellTreatment` discriminants.
+const TREATMENT_BASE: u32 = 0u;
+const TREATMENT_RAMP: u32 = 1u;
+const TREATMENT_DARKEN: u32 = 2u;
+const TREATMENT_TINT: u32 = 3u;
+
+// `DUAL_*` constants.
+const DUAL_NONE: u32 = 0u;
+const DUAL_BLEND: u32 = 1u;
+
+@group(2) @binding(100) var<uniform> overlay: TerrainOverlay;
+@group(2) @binding(101) var primary_cells: texture_2d<f32>;
+@group(2) @binding(102) var primary_ramp: texture_2d<f32>;
+@group(2) @binding(103) var secondary_cells: texture_2d<f32>;
+@group(2) @binding(104) var secondary_ramp: texture_2d<f32>;
+@group(2) @binding(105) var sand_detail: texture_2d<f32>;
+@group(2) @binding(106) var splat_sampler: sampler;
+@group(2) @binding(107) var grass_detail: texture_2d<f32>;
+@group(2) @binding(108) var rock_detail: texture_2d<f32>;
+@group(2) @binding(109) var soil_detail: texture_2d<f32>;
+
+// Cells covered by one repeat of a detail texture.
+const SPLAT_TILE_CELLS: f32 = 2.0;
+
+fn load_cell(cells: texture_2d<f32>, cell: vec2<i32>) -> vec4<f32> {
+    let last = vec2<i32>(overlay.grid_size) - vec2<i32>(1);
+    return textureLoad(cells, clamp(cell, vec2<i32>(0), last), 0);
+}
+
+fn treatment(texel: vec4<f32>) -> u32 {
+    return u32(texel.g * 255.0 + 0.5);
+}
+
+// Value at `pos` (in cells), interpolated bilinearly between the four
+// nearest cells that share `kind` so borders between treatments stay crisp.
+fn smooth_value(cells: texture_2d<f32>, pos: vec2<f32>, kind: u32, fallback: f32) -> f32 {
+    let p = pos - vec2<f32>(0.5);
+    let origin = vec2<i32>(floor(p));
+    let f = p - floor(p);
+    var sum = 0.0;
+    var weight = 0.0;
+    for (var dy = 0; dy < 2; dy++) {
+        for (var dx = 0; dx < 2; dx++) {
+            let texel = load_cell(cells, origin + vec2<i32>(dx, dy));
+            let wx = select(1.0 - f.x, f.x, dx == 1);
+            let wy = select(1.0 - f.y, f.y, dy == 1);
+            if treatment(texel) == kind {
+                sum += texel.r * wx * wy;
+                weight += wx * wy;
+            }
+        }
+    }
+    return select(fallback, sum / weight, weight > 0.0);
+}
+
+fn ramp_color(ramp: texture_2d<f32>, value: f32) -> vec3<f32> {
+    let index = i32(clamp(value, 0.0, 1.0) * 255.0 + 0.5);
+    return textureLoad(ramp, vec2<i32>(index, 0), 0).rgb;
+}
+
+fn layer_color(
+    layer: OverlayLayer,
+    cells: texture_2d<f32>,
+    ramp: texture_2d<f32>,
+    pos: vec2<f32>,
+    base: vec3<f32>,
+) -> vec3<f32> {
+    let texel = load_cell(cells, vec2<i32>(floor(pos)));
+    let kind = treatment(texel);
+    let value = smooth_value(cells, pos, kind, texel.r);
+    var color = base;
+    switch kind {
+        case TREATMENT_RAMP: {
+            color = ramp_color(ramp, value);
+            if value < layer.pulse_below {
+                let pulse = 0.75 + 0.25 * sin(overlay.time * 4.0);
+                let warning = (1.0 - value / layer.pulse_below) * layer.tint.a * pulse;
+                color = mix(color, layer.tint.rgb, warning);
+            }
+            // The ramp replaces the terrain colour, so reapply the shade
+            // of map tiles the city does not own.
+            color *= texel.b;
+        }
+        case TREATMENT_DARKEN: {
+            color = base * layer.darken;
+        }
+        case TREATMENT_TINT: {
+            color = mix(base, layer.tint.rgb, value * layer.tint.a);
+        }
+        default: {}
+    }
+    return color;
+}
+
+// Factor the vertex colour is scaled by for the blended layer detail.
+// Detail texels store brightness with 0.5 standing for 1.0.
+fn splat_detail(in: VertexOutput) -> vec3<f32> {
+    var detail = vec3<f32>(1.0);
+#ifdef VERTEX_UVS_A
+#ifdef VERTEX_UVS_B
+    let sand = in.uv.x;
+    let rock = in.uv.y;
+    let soil = in.uv_b.x;
+    let grass = max(1.0 - sand - rock - soil, 0.0);
+    let uv = in.world_position.xz / (overlay.cell_size * SPLAT_TILE_CELLS);
+    let blended = sand * textureSample(sand_detail, splat_sampler, uv).rgb
+        + grass * textureSample(grass_detail, splat_sampler, uv).rgb
+        + rock * textureSample(rock_detail, splat_sampler, uv).rgb
+        + soil * textureSample(soil_detail, splat_sampler, uv).rgb;
+    detail = mix(detail, blended * 2.0, in.uv_b.y);
+#endif
+#endif
+    return detail;
+}
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+    let pos = in.world_position.xz / overlay.cell_size;
+    let base = pbr_input.material.base_color.rgb * splat_detail(in);
+    var color = base;
+    if overlay.primary.enabled != 0u {
+        color = layer_color(overlay.primary, primary_cells, primary_ramp, pos, base);
+    }
+    if overlay.dual_mode != DUAL_NONE {
+        var second = base;
+        if overlay.secondary.enabled != 0u {
+            second = layer_color(overlay.secondary, secondary_cells, secondary_ramp, pos, base);
+        }
+        if overlay.dual_mode == DUAL_BLEND {
+            color = mix(color, second, overlay.blend_factor);
+        } else if in.world_position.x >= overlay.split_x {
+            color = second;
+        }
+    }
+    let alpha = pbr_input.material.base_color.a;
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, vec4<f32>(color, alpha));
+
+#ifdef PREPASS_PIPELINE
+    let out = deferred_output(in, pbr_input);
+#else
+    var out: FragmentOutput;
+    out.color = apply_pbr_lighting(pbr_input);
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+#endif
+
+    return out;
+}
@@ -0,0 +1,250 @@
+//! Unit tests for heatmap cell encoding and shader parameters.
+
+use simulation::coastal_erosion::ErosionGrid;
+use simulation::commute_grids::{CommuteTimeGrid, TransitRidershipGrid};
+use simulation::grid::{Cell, CellType, WorldGrid};
+use simulation::land_value::LandValueGrid;
+use simulation::traffic::TrafficGrid;
+use simulation::water_pollution::WaterPollutionGrid;
+
+use crate::color_ramps::CIVIDIS;
+use crate::overlay::{DualOverlayMode, DualOverlayState, OverlayMode};
+use crate::overlay_snapshot::{capture_snapshot, DIFF_NEUTRAL};
+use crate::terrain_render::OverlayGrids;
+
+use super::{
+    baked_overlay, diff_ramp_texels, encode_cell, is_heatmap, overlay_uniform, pack_diff,
+    ramp_color, CellTreatment, DUAL_BLEND, DUAL_NONE, DUAL_SPLIT, RAMP_SIZE,
+};
+
+fn dual(secondary: OverlayMode, mode: DualOverlayMode) -> DualOverlayState {
+    DualOverlayState {
+        secondary,
+        mode,
+        ..Default::default()
+    }
+}
+
+fn cell(cell_type: CellType) -> Cell {
+    Cell {
+        cell_type,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_network_overlays_are_baked() {
+    assert!(!is_heatmap(OverlayMode::None));
+    assert!(!is_heatmap(OverlayMode::Power));
+    assert!(!is_heatmap(OverlayMode::Water));
+    assert!(is_heatmap(OverlayMode::Traffic));
+    assert!(is_heatmap(OverlayMode::LandValue));
+    assert!(is_heatmap(OverlayMode::GroundwaterLevel));
+}
+
+#[test]
+fn test_baked_overlay_skips_heatmaps() {
+    let off = DualOverlayState::default();
+    assert_eq!(
+        baked_overlay(OverlayMode::Traffic, &off).0,
+        OverlayMode::None
+    );
+    assert_eq!(
+        baked_overlay(OverlayMode::Power, &off).0,
+        OverlayMode::Power
+    );
+
+    // A heatmap primary leaves the baked secondary as the terrain colour.
+    let water = dual(OverlayMode::Water, DualOverlayMode::Split);
+    let (primary, info) = baked_overlay(OverlayMode::Noise, &water);
+    assert_eq!(primary, OverlayMode::Water);
+    assert_eq!(info.secondary, OverlayMode::None);
+
+    // Both layers baked keeps the full dual settings.
+    let (primary, info) = baked_overlay(OverlayMode::Power, &water);
+    assert_eq!(primary, OverlayMode::Power);
+    assert_eq!(info.secondary, OverlayMode::Water);
+    assert_eq!(info.mode, DualOverlayMode::Split);
+}
+
+#[test]
+fn test_ramp_matches_color_ramp() {
+    let expected = CIVIDIS.sample(1.0).to_srgba();
+    let [r, g, b] = ramp_color(OverlayMode::LandValue, 255);
+    assert!((r - expected.red).abs() < 1e-5);
+    assert!((g - expected.green).abs() < 1e-5);
+    assert!((b - expected.blue).abs() < 1e-5);
+}
+
+#[test]
+fn test_water_cells_keep_terrain_color() {
+    let grids = OverlayGrids::none();
+    let (_, treatment) = encode_cell(OverlayMode::Pollution, &cell(CellType::Water), &grids, 0, 0);
+    assert_eq!(treatment, CellTreatment::Base);
+}
+
+#[test]
+fn test_traffic_darkens_non_road_cells() {
+    let mut traffic = TrafficGrid::default();
+    traffic.set(3, 4, 20);
+    let mut grids = OverlayGrids::none();
+    grids.traffic = Some(&traffic);
+
+    let road = encode_cell(OverlayMode::Traffic, &cell(CellType::Road), &grids, 3, 4);
+    assert_eq!(road, (255, CellTreatment::Ramp));
+    let grass = encode_cell(OverlayMode::Traffic, &cell(CellType::Grass), &grids, 3, 4);
+    assert_eq!(grass.1, CellTreatment::Darken);
+}
+
+#[test]
+fn test_erosion_dims_inland_cells() {
+    let erosion = ErosionGrid::default();
+    let mut grids = OverlayGrids::none();
+    grids.erosion = Some(&erosion);
+    let (_, treatment) = encode_cell(OverlayMode::Erosion, &cell(CellType::Grass), &grids, 0, 0);
+    assert_eq!(treatment, CellTreatment::Darken);
+}
+
+#[test]
+fn test_commute_time_scales_to_an_hour() {
+    let mut commute = CommuteTimeGrid::default();
+    commute.record(2, 2, 30.0);
+    commute.record(3, 3, 90.0);
+    let mut grids = OverlayGrids::none();
+    grids.commute_time = Some(&commute);
+    let encode = |x, y| {
+        encode_cell(
+            OverlayMode::CommuteTime,
+            &cell(CellType::Grass),
+            &grids,
+            x,
+            y,
+        )
+    };
+
+    assert_eq!(encode(2, 2), (128, CellTreatment::Ramp));
+    assert_eq!(encode(3, 3), (255, CellTreatment::Ramp));
+    // Cells nobody commutes from are dimmed.
+    assert_eq!(encode(4, 4), (0, CellTreatment::Darken));
+}
+
+#[test]
+fn test_transit_ridership_only_near_stops() {
+    let ridership = TransitRidershipGrid::from_stops(&[((5, 5), 100), ((20, 20), 50)]);
+    let mut grids = OverlayGrids::none();
+    grids.transit_ridership = Some(&ridership);
+    let encode = |x, y| {
+        encode_cell(
+            OverlayMode::TransitRidership,
+            &cell(CellType::Road),
+            &grids,
+            x,
+            y,
+        )
+    };
+
+    assert_eq!(encode(5, 5), (255, CellTreatment::Ramp));
+    assert_eq!(encode(20, 20), (128, CellTreatment::Ramp));
+    assert_eq!(encode(10, 10), (0, CellTreatment::Darken));
+}
+
+#[test]
+fn test_polluted_water_tints_land() {
+    let mut pollution = WaterPollutionGrid::default();
+    pollution.set(1, 1, 120);
+    let mut grids = OverlayGrids::none();
+    grids.water_pollution = Some(&pollution);
+
+    let land = encode_cell(
+        OverlayMode::WaterPollution,
+        &cell(CellType::Grass),
+        &grids,
+        1,
+        1,
+    );
+    assert_eq!(land, (120, CellTreatment::Tint));
+    let water = encode_cell(
+        OverlayMode::WaterPollution,
+        &cell(CellType::Water),
+        &grids,
+        1,
+        1,
+    );
+    assert_eq!(water, (120, CellTreatment::Ramp));
+}
+
+#[test]
+fn test_dual_mode_only_with_a_heatmap_layer() {
+    let both_baked = dual(OverlayMode::Water, DualOverlayMode::Blend);
+    assert_eq!(
+        overlay_uniform(OverlayMode::Power, &both_baked, 0.0).dual_mode,
+        DUAL_NONE
+    );
+
+    let mixed = overlay_uniform(OverlayMode::Traffic, &both_baked, 0.0);
+    assert_eq!(mixed.dual_mode, DUAL_BLEND);
+    assert_eq!(mixed.primary.enabled, 1);
+    assert_eq!(mixed.secondary.enabled, 0);
+
+    let split = dual(OverlayMode::Noise, DualOverlayMode::Split);
+    assert_eq!(
+        overlay_uniform(OverlayMode::LandValue, &split, 0.0).dual_mode,
+        DUAL_SPLIT
+    );
+    assert_eq!(
+        overlay_uniform(OverlayMode::None, &split, 0.0).dual_mode,
+        DUAL_NONE
+    );
+}
+
+#[test]
+fn test_time_only_passed_to_animated_layers() {
+    let off = DualOverlayState::default();
+    assert_eq!(
+        overlay_uniform(OverlayMode::LandValue, &off, 12.0).time,
+        0.0
+    );
+    assert_eq!(
+        overlay_uniform(OverlayMode::GroundwaterLevel, &off, 12.0).time,
+        12.0
+    );
+}
+
+#[test]
+fn test_pack_diff_marks_changed_cells() {
+    let grid = WorldGrid::new(4, 4);
+    let mut land_value = LandValueGrid::default();
+    land_value.set(0, 0, 100);
+    land_value.set(1, 1, 100);
+    let before = OverlayGrids {
+        land_value: Some(&land_value),
+        ..OverlayGrids::none()
+    };
+    let snapshot = capture_snapshot(OverlayMode::LandValue, 3, &grid, &before);
+    assert_eq!(snapshot.cells.len(), 16);
+
+    land_value.set(1, 1, 200);
+    let after = OverlayGrids {
+        land_value: Some(&land_value),
+        ..OverlayGrids::none()
+    };
+    let mut texels = vec![0u8; 16 * 4];
+    pack_diff(&snapshot, &grid, &after, &mut texels);
+
+    let texel = |x: usize, y: usize| &texels[(y * 4 + x) * 4..(y * 4 + x) * 4 + 2];
+    assert_eq!(texel(0, 0), [DIFF_NEUTRAL, CellTreatment::Darken as u8]);
+    assert_eq!(texel(1, 1), [DIFF_NEUTRAL + 50, CellTreatment::Ramp as u8]);
+}
+
+#[test]
+fn test_diff_ramp_is_neutral_in_the_middle() {
+    let ramp = diff_ramp_texels();
+    assert_eq!(ramp.len(), RAMP_SIZE * 4);
+    let mid = &ramp[128 * 4..128 * 4 + 3];
+    // Near-grey: no channel dominates.
+    let spread = mid.iter().max().unwrap() - mid.iter().min().unwrap();
+    assert!(spread < 20, "neutral colour {:?}", mid);
+    // Decreases are blue, increases orange.
+    assert!(ramp[2] > ramp[0]);
+    assert!(ramp[255 * 4] > ramp[255 * 4 + 2]);
+}
@@ -0,0 +1,162 @@
+//! Before/after snapshots of heatmap overlays.
+//!
+//! The player can capture the current state of a heatmap overlay (traffic,
+//! land value, pollution and the rest), build a highway or change a policy,
+//! and then view the overlay as a diff against the snapshot: cells that went
+//! up are drawn orange, cells that went down blue, and unchanged cells are
+//! dimmed.  One snapshot is kept per overlay; capturing again replaces it.
+//!
+//! Capturing happens in the heatmap sync system, which already has every
+//! overlay grid at hand, so the UI only sets `pending_capture`.
+
+use bevy::prelude::*;
+
+use simulation::grid::WorldGrid;
+
+use crate::overlay::OverlayMode;
+use crate::overlay_heatmap::{encode_cell, is_heatmap, CellTreatment};
+use crate::terrain_render::OverlayGrids;
+
+/// Value the diff ramp is centred on: no change.
+pub const DIFF_NEUTRAL: u8 = 128;
+
+/// Changes smaller than this (on the 0..=255 overlay scale) count as no change.
+pub const DIFF_THRESHOLD: i16 = 3;
+
+/// An overlay's per-cell values at one point in time.
+#[derive(Debug, Clone)]
+pub struct OverlaySnapshot {
+    pub mode: OverlayMode,
+    /// Game day the snapshot was taken on.
+    pub day: u32,
+    /// Encoded value and treatment of every cell, row-major.
+    pub cells: Vec<(u8, CellTreatment)>,
+}
+
+/// Captured overlay snapshots and whether the diff view is on.
+#[derive(Resource, Default)]
+pub struct OverlaySnapshots {
+    pub snapshots: Vec<OverlaySnapshot>,
+    /// Draw the primary overlay as a diff against its snapshot, if it has one.
+    pub show_diff: bool,
+    /// Overlay to capture on the next heatmap sync.
+    pub pending_capture: Option<OverlayMode>,
+}
+
+impl OverlaySnapshots {
+    /// The snapshot of an overlay, if one was taken.
+    pub fn get(&self, mode: OverlayMode) -> Option<&OverlaySnapshot> {
+        self.snapshots.iter().find(|s| s.mode == mode)
+    }
+
+    /// Store a snapshot, replacing any earlier one of the same overlay.
+    pub fn insert(&mut self, snapshot: OverlaySnapshot) {
+        self.remove(snapshot.mode);
+        self.snapshots.push(snapshot);
+    }
+
+    /// Discard the snapshot of an overlay.
+    pub fn remove(&mut self, mode: OverlayMode) {
+        self.snapshots.retain(|s| s.mode != mode);
+    }
+
+    /// The snapshot to diff the primary overlay against, when the diff view
+    /// is on and the overlay has one.
+    pub fn diff_for(&self, primary: OverlayMode) -> Option<&OverlaySnapshot> {
+        self.get(primary).filter(|_| self.show_diff)
+    }
+}
+
+/// Whether an overlay can be snapshotted.  Power and water are baked into
+/// the terrain mesh and wind is drawn as streamlines, so they have no
+/// per-cell values to compare.
+pub fn can_snapshot(mode: OverlayMode) -> bool {
+    is_heatmap(mode) && mode != OverlayMode::Wind
+}
+
+/// Capture the current values of an overlay.
+pub fn capture_snapshot(
+    mode: OverlayMode,
+    day: u32,
+    grid: &WorldGrid,
+    grids: &OverlayGrids,
+) -> OverlaySnapshot {
+    let mut cells = Vec::with_capacity(grid.width * grid.height);
+    for gy in 0..grid.height {
+        for gx in 0..grid.width {
+            cells.push(encode_cell(mode, grid.get(gx, gy), grids, gx, gy));
+        }
+    }
+    OverlaySnapshot { mode, day, cells }
+}
+
+/// Diff of one cell: the value change mapped onto the diff ramp around
+/// `DIFF_NEUTRAL`, or a dimmed cell when nothing changed.  Cells the overlay
+/// leaves alone (water for land overlays) stay untouched.
+pub fn diff_cell(before: (u8, CellTreatment), after: (u8, CellTreatment)) -> (u8, CellTreatment) {
+    if before.1 == CellTreatment::Base && after.1 == CellTreatment::Base {
+        return (DIFF_NEUTRAL, CellTreatment::Base);
+    }
+    let delta = after.0 as i16 - before.0 as i16;
+    if delta.abs() < DIFF_THRESHOLD {
+        return (DIFF_NEUTRAL, CellTreatment::Darken);
+    }
+    let value = (DIFF_NEUTRAL as i16 + delta / 2).clamp(0, 255) as u8;
+    (value, CellTreatment::Ramp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(mode: OverlayMode, day: u32) -> OverlaySnapshot {
+        OverlaySnapshot {
+            mode,
+            day,
+            cells: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn insert_replaces_snapshot_of_same_overlay() {
+        let mut snapshots = OverlaySnapshots::default();
+        snapshots.insert(snapshot(OverlayMode::Traffic, 1));
+        snapshots.insert(snapshot(OverlayMode::LandValue, 2));
+        snapshots.insert(snapshot(OverlayMode::Traffic, 3));
+        assert_eq!(snapshots.snapshots.len(), 2);
+        assert_eq!(snapshots.get(OverlayMode::Traffic).unwrap().day, 3);
+        snapshots.remove(OverlayMode::Traffic);
+        assert!(snapshots.get(OverlayMode::Traffic).is_none());
+    }
+
+    #[test]
+    fn diff_only_when_enabled() {
+        let mut snapshots = OverlaySnapshots::default();
+        snapshots.insert(snapshot(OverlayMode::Pollution, 1));
+        assert!(snapshots.diff_for(OverlayMode::Pollution).is_none());
+        snapshots.show_diff = true;
+        assert!(snapshots.diff_for(OverlayMode::Pollution).is_some());
+        assert!(snapshots.diff_for(OverlayMode::Noise).is_none());
+    }
+
+    #[test]
+    fn diff_cell_maps_change_around_neutral() {
+        use CellTreatment::{Base, Darken, Ramp};
+        assert_eq!(diff_cell((100, Ramp), (101, Ramp)), (DIFF_NEUTRAL, Darken));
+        assert_eq!(diff_cell((100, Ramp), (200, Ramp)), (178, Ramp));
+        assert_eq!(diff_cell((200, Ramp), (100, Ramp)), (78, Ramp));
+        assert_eq!(diff_cell((0, Ramp), (255, Ramp)).0, 255);
+        assert_eq!(diff_cell((0, Base), (0, Base)), (DIFF_NEUTRAL, Base));
+        // A road that newly carries traffic counts as an increase.
+        assert_eq!(diff_cell((0, Darken), (80, Ramp)), (168, Ramp));
+    }
+
+    #[test]
+    fn snapshots_exclude_baked_and_streamline_overlays() {
+        assert!(can_snapshot(OverlayMode::Traffic));
+        assert!(can_snapshot(OverlayMode::LandValue));
+        assert!(!can_snapshot(OverlayMode::Power));
+        assert!(!can_snapshot(OverlayMode::Wind));
+        assert!(!can_snapshot(OverlayMode::None));
+    }
+}
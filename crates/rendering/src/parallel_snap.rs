@@ -14,6 +14,7 @@ use bevy::prelude::*;
 use simulation::app_state::AppState;
 use simulation::config::CELL_SIZE;
 use simulation::grid::RoadType;
+use simulation::keybindings::KeyBindings;
 use simulation::road_segments::{RoadSegmentStore, SegmentId};
 
 use crate::input::{ActiveTool, CursorGridPos, DrawPhase, RoadDrawState};
@@ -125,9 +126,13 @@ fn active_road_type(tool: &ActiveTool) -> Option<RoadType> {
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Toggle parallel snap with the P key.
-pub fn toggle_parallel_snap(keys: Res<ButtonInput<KeyCode>>, mut snap: ResMut<ParallelSnapState>) {
-    if keys.just_pressed(KeyCode::KeyP) {
+/// Toggle parallel snap with the configured key (P by default).
+pub fn toggle_parallel_snap(
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut snap: ResMut<ParallelSnapState>,
+) {
+    if bindings.toggle_parallel_snap.just_pressed(&keys) {
         snap.enabled = !snap.enabled;
         if !snap.enabled {
             snap.snapped_pos = None;
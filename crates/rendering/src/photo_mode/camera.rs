@@ -0,0 +1,158 @@
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use simulation::keybindings::{KeyBinding, KeyBindings};
+use simulation::time_of_day::GameClock;
+
+use crate::camera::OrbitCamera;
+use crate::egui_input_guard::egui_wants_pointer;
+use crate::input::CursorGridPos;
+
+use super::capture::PhotoCaptureCamera;
+use super::types::{FreeCamera, PhotoMode, MAX_FREE_PITCH};
+
+/// Free camera speed per unit of height above the ground, per second.
+const FLY_SPEED_PER_HEIGHT: f32 = 1.0;
+/// Slowest free camera speed, used near the ground.
+const MIN_FLY_SPEED: f32 = 20.0;
+const FAST_MULTIPLIER: f32 = 4.0;
+const LOOK_SENSITIVITY: f32 = 0.003;
+const ROLL_SPEED: f32 = 1.0;
+/// World units moved up or down per scroll line.
+const SCROLL_CLIMB: f32 = 10.0;
+
+/// Keys that work in and around photo mode: the photo mode binding toggles
+/// it, H hides the UI and the screenshot binding takes a photo.
+pub fn photo_mode_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut photo: ResMut<PhotoMode>,
+) {
+    if bindings.toggle_photo_mode.just_pressed(&keyboard) {
+        photo.active = !photo.active;
+        return;
+    }
+    if !photo.active {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        photo.hide_ui = !photo.hide_ui;
+    }
+    if bindings.screenshot.just_pressed(&keyboard) {
+        photo.capture_requested = true;
+    }
+}
+
+/// Enter or leave photo mode after `PhotoMode::active` changes. Entering
+/// pauses the clock, hides the tool cursor and starts the free camera where
+/// the orbit camera is; leaving restores the clock and the orbit camera.
+pub fn sync_photo_mode(
+    mut photo: ResMut<PhotoMode>,
+    mut clock: ResMut<GameClock>,
+    orbit: Option<ResMut<OrbitCamera>>,
+    cursor: Option<ResMut<CursorGridPos>>,
+    cameras: Query<&Transform, (With<Camera3d>, Without<PhotoCaptureCamera>)>,
+) {
+    if photo.active == photo.entered {
+        return;
+    }
+    if photo.active {
+        photo.clock_was_paused = clock.paused;
+        clock.paused = true;
+        if let Ok(transform) = cameras.get_single() {
+            photo.camera = FreeCamera::from_transform(transform);
+        }
+        // Cursor tracking is off in photo mode; an invalid cursor keeps the
+        // tool preview out of the shot.
+        if let Some(mut cursor) = cursor {
+            cursor.valid = false;
+        }
+    } else {
+        clock.paused = photo.clock_was_paused;
+        photo.hide_ui = false;
+        photo.capture_requested = false;
+        // Let `apply_orbit_camera` put the camera back where it was.
+        if let Some(mut orbit) = orbit {
+            orbit.set_changed();
+        }
+    }
+    photo.entered = photo.active;
+}
+
+/// Fly the photo mode camera: movement keys move along the view, right-drag
+/// looks around, the rotate keys roll and the scroll wheel climbs.
+#[allow(clippy::too_many_arguments)]
+pub fn fly_photo_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    motion: Res<AccumulatedMouseMotion>,
+    scroll: Res<AccumulatedMouseScroll>,
+    time: Res<Time>,
+    bindings: Res<KeyBindings>,
+    mut contexts: EguiContexts,
+    mut photo: ResMut<PhotoMode>,
+    mut cameras: Query<&mut Transform, (With<Camera3d>, Without<PhotoCaptureCamera>)>,
+) {
+    if !photo.active {
+        return;
+    }
+    let dt = time.delta_secs();
+    let pointer_free = !egui_wants_pointer(&mut contexts);
+    let mut cam = photo.camera;
+
+    if pointer_free && buttons.pressed(MouseButton::Right) {
+        cam.yaw -= motion.delta.x * LOOK_SENSITIVITY;
+        cam.pitch =
+            (cam.pitch - motion.delta.y * LOOK_SENSITIVITY).clamp(-MAX_FREE_PITCH, MAX_FREE_PITCH);
+    }
+
+    // Bindings are matched on their key alone so Shift can speed things up.
+    let held = |binding: KeyBinding| keys.pressed(binding.key);
+    let mut dir = Vec3::ZERO;
+    if held(bindings.camera_pan_up) || held(bindings.camera_pan_up_alt) {
+        dir.z -= 1.0;
+    }
+    if held(bindings.camera_pan_down) || held(bindings.camera_pan_down_alt) {
+        dir.z += 1.0;
+    }
+    if held(bindings.camera_pan_left) || held(bindings.camera_pan_left_alt) {
+        dir.x -= 1.0;
+    }
+    if held(bindings.camera_pan_right) || held(bindings.camera_pan_right_alt) {
+        dir.x += 1.0;
+    }
+    if held(bindings.camera_rotate_left) {
+        cam.roll += ROLL_SPEED * dt;
+    }
+    if held(bindings.camera_rotate_right) {
+        cam.roll -= ROLL_SPEED * dt;
+    }
+
+    let mut speed = (cam.position.y * FLY_SPEED_PER_HEIGHT).max(MIN_FLY_SPEED);
+    if keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight) {
+        speed *= FAST_MULTIPLIER;
+    }
+    if dir != Vec3::ZERO {
+        cam.position += cam.rotation() * dir.normalize() * speed * dt;
+    }
+
+    if pointer_free && scroll.delta.y != 0.0 {
+        let lines = match scroll.unit {
+            MouseScrollUnit::Line => scroll.delta.y,
+            MouseScrollUnit::Pixel => scroll.delta.y / 20.0,
+        };
+        cam.position.y += lines * SCROLL_CLIMB * speed / MIN_FLY_SPEED;
+    }
+    cam.position.y = cam.position.y.max(1.0);
+
+    if cam != photo.camera {
+        photo.camera = cam;
+    }
+    if let Ok(mut transform) = cameras.get_single_mut() {
+        let target = photo.camera.transform();
+        if *transform != target {
+            *transform = target;
+        }
+    }
+}
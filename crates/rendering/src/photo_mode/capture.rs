@@ -0,0 +1,261 @@
+//! Super-resolution photo capture.
+//!
+//! A photo is rendered by a second camera matching the main one, pointed at
+//! an offscreen image several times the window's size. Once it has rendered
+//! a couple of frames the image is captured with the screenshot plugin's
+//! `Screenshot` and written as a PNG carrying the city's metadata. Egui only
+//! draws to the window, so photos never include the UI.
+
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::{
+    core_pipeline::tonemapping::Tonemapping,
+    pbr::DistanceFog,
+    render::camera::RenderTarget,
+    render::render_asset::RenderAssetUsages,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+    render::view::screenshot::{Screenshot, ScreenshotCaptured},
+    window::PrimaryWindow,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use simulation::{
+    new_game_config::NewGameConfig, stats::CityStats, time_of_day::GameClock, weather::Weather,
+};
+
+use crate::input::StatusMessage;
+
+use super::types::PhotoMode;
+#[cfg(not(target_arch = "wasm32"))]
+use super::{effects::depth_of_field, metadata::PhotoMetadata, types::capture_size};
+
+/// Frames the capture camera renders before it is captured, so its
+/// render target and post-processing have settled.
+#[cfg(not(target_arch = "wasm32"))]
+const WARMUP_FRAMES: u32 = 2;
+
+/// Marker for the temporary camera rendering a photo.
+#[derive(Component)]
+pub struct PhotoCaptureCamera;
+
+/// A photo being rendered.
+#[cfg(not(target_arch = "wasm32"))]
+struct PendingCapture {
+    camera: Entity,
+    frames: u32,
+    screenshot_taken: bool,
+    path: String,
+    metadata: PhotoMetadata,
+}
+
+/// The photo currently being captured, if any.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource, Default)]
+pub struct PhotoCapture(Option<PendingCapture>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PhotoCapture {
+    pub fn in_progress(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn render_target_image(size: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::bevy_default(),
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::COPY_SRC
+        | TextureUsages::COPY_DST
+        | TextureUsages::RENDER_ATTACHMENT
+        | TextureUsages::TEXTURE_BINDING;
+    image
+}
+
+/// Spawn a capture camera matching the main camera when a photo is
+/// requested.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::too_many_arguments)]
+pub fn start_photo_capture(
+    mut commands: Commands,
+    mut photo: ResMut<PhotoMode>,
+    mut capture: ResMut<PhotoCapture>,
+    mut images: ResMut<Assets<Image>>,
+    mut status: ResMut<StatusMessage>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<
+        (&Transform, &Projection, &Tonemapping, Option<&DistanceFog>),
+        (With<Camera3d>, Without<PhotoCaptureCamera>),
+    >,
+    config: Res<NewGameConfig>,
+    clock: Res<GameClock>,
+    weather: Res<Weather>,
+    stats: Res<CityStats>,
+) {
+    if !photo.capture_requested || capture.in_progress() {
+        return;
+    }
+    photo.capture_requested = false;
+    let (Ok(window), Ok((transform, projection, tonemapping, fog))) =
+        (windows.get_single(), cameras.get_single())
+    else {
+        return;
+    };
+    let path = match crate::screenshot::capture_path("photo") {
+        Ok(path) => path,
+        Err(e) => {
+            status.set(format!("Failed to create screenshots directory: {e}"), true);
+            return;
+        }
+    };
+
+    let size = capture_size(window.physical_size(), photo.resolution_scale);
+    let image = images.add(render_target_image(size));
+    let mut camera = commands.spawn((
+        PhotoCaptureCamera,
+        Camera3d::default(),
+        Camera {
+            target: RenderTarget::Image(image),
+            order: -1,
+            ..default()
+        },
+        projection.clone(),
+        *tonemapping,
+        *transform,
+        photo.color_grading(),
+    ));
+    if let Some(fog) = fog {
+        camera.insert(fog.clone());
+    }
+    if let Some(dof) = depth_of_field(&photo) {
+        camera.insert(dof);
+    }
+
+    capture.0 = Some(PendingCapture {
+        camera: camera.id(),
+        frames: 0,
+        screenshot_taken: false,
+        path,
+        metadata: PhotoMetadata::new(
+            &config.city_name,
+            &clock,
+            &weather,
+            stats.population,
+            photo.filter,
+        ),
+    });
+    status.set(format!("Capturing {}x{} photo...", size.x, size.y), false);
+}
+
+/// Capture the photo once the capture camera has warmed up, then write it
+/// out and remove the camera.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn finish_photo_capture(
+    mut commands: Commands,
+    mut capture: ResMut<PhotoCapture>,
+    cameras: Query<&Camera, With<PhotoCaptureCamera>>,
+) {
+    let Some(pending) = capture.0.as_mut() else {
+        return;
+    };
+    if pending.screenshot_taken {
+        return;
+    }
+    pending.frames += 1;
+    if pending.frames < WARMUP_FRAMES {
+        return;
+    }
+    let Ok(camera) = cameras.get(pending.camera) else {
+        capture.0 = None;
+        return;
+    };
+    let RenderTarget::Image(image) = camera.target.clone() else {
+        return;
+    };
+    pending.screenshot_taken = true;
+    commands
+        .spawn(Screenshot::image(image))
+        .observe(save_photo);
+}
+
+/// Observer: encode the captured photo on the IO task pool and clean up the
+/// capture camera and its image.
+#[cfg(not(target_arch = "wasm32"))]
+fn save_photo(
+    trigger: Trigger<ScreenshotCaptured>,
+    mut commands: Commands,
+    mut capture: ResMut<PhotoCapture>,
+    mut images: ResMut<Assets<Image>>,
+    mut status: ResMut<StatusMessage>,
+    cameras: Query<&Camera, With<PhotoCaptureCamera>>,
+) {
+    let Some(pending) = capture.0.take() else {
+        return;
+    };
+    if let Ok(camera) = cameras.get(pending.camera) {
+        if let RenderTarget::Image(image) = &camera.target {
+            images.remove(image);
+        }
+    }
+    commands.entity(pending.camera).despawn_recursive();
+
+    // Drop the alpha channel, as `save_to_disk` does, since it holds
+    // brightness rather than coverage when HDR is on.
+    let image = match trigger.event().0.clone().try_into_dynamic() {
+        Ok(image) => image.to_rgb8(),
+        Err(e) => {
+            status.set(format!("Failed to capture photo: {e}"), true);
+            return;
+        }
+    };
+    let size = UVec2::new(image.width(), image.height());
+    let pixels = image.into_raw();
+    let path = pending.path;
+    let chunks = pending.metadata.text_chunks();
+    status.set(format!("Photo saved: {path}"), false);
+    bevy::tasks::IoTaskPool::get()
+        .spawn(async move {
+            if let Err(e) = write_png(&path, size, &pixels, &chunks) {
+                error!("Cannot save photo to {path}: {e}");
+            }
+        })
+        .detach();
+}
+
+/// Write 8-bit RGB `pixels` as a PNG with `chunks` as international text
+/// chunks, so city names outside Latin-1 survive.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_png(
+    path: &str,
+    size: UVec2,
+    pixels: &[u8],
+    chunks: &[(&'static str, String)],
+) -> Result<(), png::EncodingError> {
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(file, size.x, size.y);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    for (keyword, text) in chunks {
+        encoder.add_itxt_chunk(keyword.to_string(), text.clone())?;
+    }
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels)?;
+    writer.finish()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn start_photo_capture(mut photo: ResMut<PhotoMode>, mut status: ResMut<StatusMessage>) {
+    if photo.capture_requested {
+        photo.capture_requested = false;
+        status.set("Photos not supported in browser", true);
+    }
+}
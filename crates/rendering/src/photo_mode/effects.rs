@@ -0,0 +1,56 @@
+use bevy::core_pipeline::dof::DepthOfField;
+use bevy::prelude::*;
+use bevy::render::view::ColorGrading;
+use bevy_egui::EguiRenderOutput;
+
+use super::capture::PhotoCaptureCamera;
+use super::types::PhotoMode;
+
+/// Depth of field for the current lens settings, if it is switched on.
+pub fn depth_of_field(photo: &PhotoMode) -> Option<DepthOfField> {
+    if !photo.active || !photo.depth_of_field {
+        return None;
+    }
+    Some(DepthOfField {
+        focal_distance: photo.focal_distance,
+        aperture_f_stops: photo.aperture_f_stops,
+        ..default()
+    })
+}
+
+/// Put the filter, exposure and depth of field on the main camera while in
+/// photo mode, and take them off again afterwards.
+pub fn apply_photo_effects(
+    mut commands: Commands,
+    photo: Res<PhotoMode>,
+    cameras: Query<Entity, (With<Camera3d>, Without<PhotoCaptureCamera>)>,
+) {
+    if !photo.is_changed() {
+        return;
+    }
+    let grading = if photo.active {
+        photo.color_grading()
+    } else {
+        ColorGrading::default()
+    };
+    for camera in &cameras {
+        let mut entity = commands.entity(camera);
+        entity.insert(grading.clone());
+        match depth_of_field(&photo) {
+            Some(dof) => entity.insert(dof),
+            None => entity.remove::<DepthOfField>(),
+        };
+    }
+}
+
+/// Drop egui's output for the frame so nothing but the city is drawn while
+/// the UI is hidden. Runs after egui has processed its output so textures
+/// still upload and the panels come back intact.
+pub fn hide_egui_output(photo: Res<PhotoMode>, mut outputs: Query<&mut EguiRenderOutput>) {
+    if !photo.active || !photo.hide_ui {
+        return;
+    }
+    for mut output in &mut outputs {
+        output.paint_jobs = Default::default();
+    }
+}
@@ -0,0 +1,62 @@
+use simulation::time_of_day::GameClock;
+use simulation::weather::Weather;
+
+use super::types::PhotoFilter;
+
+/// Facts about the city at the moment a photo was taken, written into the
+/// PNG as text chunks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoMetadata {
+    pub city_name: String,
+    pub day: u32,
+    pub hour: f32,
+    pub season: &'static str,
+    pub temperature: f32,
+    pub population: u32,
+    pub filter: PhotoFilter,
+}
+
+impl PhotoMetadata {
+    pub fn new(
+        city_name: &str,
+        clock: &GameClock,
+        weather: &Weather,
+        population: u32,
+        filter: PhotoFilter,
+    ) -> Self {
+        Self {
+            city_name: city_name.to_string(),
+            day: clock.day,
+            hour: clock.hour,
+            season: clock.season().name(),
+            temperature: weather.temperature,
+            population,
+            filter,
+        }
+    }
+
+    /// Keyword/text pairs for the PNG, using the registered keywords where
+    /// one fits so image viewers show them.
+    pub fn text_chunks(&self) -> Vec<(&'static str, String)> {
+        let h = self.hour as u32;
+        let m = ((self.hour - h as f32) * 60.0) as u32;
+        let time = format!("Day {} {:02}:{:02}", self.day, h, m);
+        vec![
+            ("Title", self.city_name.clone()),
+            ("Software", "Megacity".to_string()),
+            (
+                "Description",
+                format!(
+                    "{}, {}, {}, population {}",
+                    self.city_name, time, self.season, self.population
+                ),
+            ),
+            ("City", self.city_name.clone()),
+            ("Game Time", time),
+            ("Season", self.season.to_string()),
+            ("Temperature", format!("{:.1} C", self.temperature)),
+            ("Population", self.population.to_string()),
+            ("Filter", self.filter.name().to_string()),
+        ]
+    }
+}
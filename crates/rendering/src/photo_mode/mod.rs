@@ -0,0 +1,60 @@
+//! Photo mode: pause the city and compose a shot.
+//!
+//! Toggled with its key binding (Shift+F12 by default). While active the
+//! simulation clock is paused, tools and the orbit camera are switched off
+//! and a free camera takes over: the movement keys fly along the view,
+//! right-drag looks around, the rotate keys roll and the scroll wheel
+//! climbs. Exposure, depth of field and colour filters apply to the view,
+//! H hides the UI, and the screenshot key captures a super-resolution PNG
+//! tagged with the city's name, date and population.
+
+mod camera;
+mod capture;
+mod effects;
+mod metadata;
+mod tests;
+mod types;
+
+use bevy::prelude::*;
+use bevy_egui::EguiPostUpdateSet;
+
+use simulation::app_state::AppState;
+
+pub use camera::{fly_photo_camera, photo_mode_keys, sync_photo_mode};
+#[cfg(not(target_arch = "wasm32"))]
+pub use capture::{finish_photo_capture, write_png, PhotoCapture};
+pub use capture::{start_photo_capture, PhotoCaptureCamera};
+pub use effects::{apply_photo_effects, depth_of_field, hide_egui_output};
+pub use metadata::PhotoMetadata;
+pub use types::{
+    capture_size, photo_mode_inactive, FreeCamera, PhotoFilter, PhotoMode, MAX_CAPTURE_SIDE,
+    MAX_RESOLUTION_SCALE,
+};
+
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoMode>()
+            .add_systems(
+                Update,
+                (
+                    photo_mode_keys,
+                    sync_photo_mode,
+                    fly_photo_camera,
+                    apply_photo_effects,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(Update, start_photo_capture.after(apply_photo_effects))
+            .add_systems(
+                PostUpdate,
+                hide_egui_output.after(EguiPostUpdateSet::ProcessOutput),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.init_resource::<PhotoCapture>()
+            .add_systems(Update, finish_photo_capture.after(start_photo_capture));
+    }
+}
@@ -0,0 +1,132 @@
+//! Tests for photo mode.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::render::view::ColorGrading;
+
+    use simulation::time_of_day::GameClock;
+    use simulation::weather::Weather;
+
+    use crate::photo_mode::{
+        capture_size, depth_of_field, sync_photo_mode, FreeCamera, PhotoFilter, PhotoMetadata,
+        PhotoMode, MAX_CAPTURE_SIDE,
+    };
+
+    #[test]
+    fn test_capture_size_scales_and_fits_texture_limit() {
+        assert_eq!(
+            capture_size(UVec2::new(1280, 720), 2),
+            UVec2::new(2560, 1440)
+        );
+        let big = capture_size(UVec2::new(2560, 1440), 4);
+        assert_eq!(big.x, MAX_CAPTURE_SIDE);
+        assert!((big.x as f32 / big.y as f32 - 16.0 / 9.0).abs() < 0.01);
+        assert_eq!(capture_size(UVec2::new(800, 600), 0), UVec2::new(800, 600));
+    }
+
+    #[test]
+    fn test_free_camera_starts_where_the_camera_looks() {
+        let transform = Transform::from_xyz(100.0, 500.0, 300.0).looking_at(Vec3::ZERO, Vec3::Y);
+        let free = FreeCamera::from_transform(&transform);
+        assert_eq!(free.roll, 0.0);
+        let rebuilt = free.transform();
+        assert!((rebuilt.translation - transform.translation).length() < 1e-4);
+        assert!(rebuilt.forward().dot(*transform.forward()) > 0.9999);
+    }
+
+    #[test]
+    fn test_no_filter_only_adds_exposure() {
+        let grading = PhotoFilter::None.color_grading(1.5);
+        let identity = ColorGrading::default();
+        assert_eq!(grading.global.exposure, 1.5);
+        assert_eq!(
+            grading.global.post_saturation,
+            identity.global.post_saturation
+        );
+        assert_eq!(grading.midtones.saturation, identity.midtones.saturation);
+        assert_eq!(
+            PhotoFilter::Noir.color_grading(0.0).global.post_saturation,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_depth_of_field_only_in_photo_mode() {
+        let mut photo = PhotoMode {
+            depth_of_field: true,
+            ..default()
+        };
+        assert!(depth_of_field(&photo).is_none());
+        photo.active = true;
+        let dof = depth_of_field(&photo).expect("enabled in photo mode");
+        assert_eq!(dof.focal_distance, photo.focal_distance);
+    }
+
+    #[test]
+    fn test_entering_photo_mode_pauses_and_leaving_restores() {
+        let mut world = World::new();
+        world.insert_resource(GameClock::default());
+        world.insert_resource(PhotoMode::default());
+        let mut system = IntoSystem::into_system(sync_photo_mode);
+        system.initialize(&mut world);
+
+        world.resource_mut::<PhotoMode>().active = true;
+        system.run((), &mut world);
+        assert!(world.resource::<GameClock>().paused);
+
+        world.resource_mut::<PhotoMode>().active = false;
+        system.run((), &mut world);
+        assert!(
+            !world.resource::<GameClock>().paused,
+            "clock was running before"
+        );
+    }
+
+    #[test]
+    fn test_metadata_names_the_city_and_population() {
+        let clock = GameClock {
+            day: 100,
+            hour: 14.5,
+            ..default()
+        };
+        let meta = PhotoMetadata::new(
+            "Tel Aviv",
+            &clock,
+            &Weather::default(),
+            12_345,
+            PhotoFilter::Vivid,
+        );
+        let chunks = meta.text_chunks();
+        let get = |key: &str| {
+            chunks
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.clone())
+        };
+        assert_eq!(get("Title").as_deref(), Some("Tel Aviv"));
+        assert_eq!(get("Population").as_deref(), Some("12345"));
+        assert_eq!(get("Game Time").as_deref(), Some("Day 100 14:30"));
+        assert_eq!(get("Filter").as_deref(), Some("Vivid"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_written_png_carries_metadata() {
+        let path = std::env::temp_dir().join("megacity_photo_mode_test.png");
+        let path = path.to_str().expect("utf-8 temp path").to_string();
+        let chunks = vec![("Title", "Zürich".to_string())];
+        crate::photo_mode::write_png(&path, UVec2::new(2, 1), &[255; 6], &chunks)
+            .expect("png written");
+
+        let decoder = png::Decoder::new(std::io::BufReader::new(
+            std::fs::File::open(&path).expect("png exists"),
+        ));
+        let reader = decoder.read_info().expect("valid png");
+        let text = &reader.info().utf8_text;
+        assert_eq!(text.len(), 1);
+        assert_eq!(text[0].keyword, "Title");
+        assert_eq!(text[0].get_text().expect("utf-8 text"), "Zürich");
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use bevy::render::view::{ColorGrading, ColorGradingGlobal, ColorGradingSection};
+
+/// Largest supersampling factor for captured photos.
+pub const MAX_RESOLUTION_SCALE: u32 = 4;
+
+/// Largest side, in pixels, of a captured photo; wgpu's default texture limit.
+pub const MAX_CAPTURE_SIDE: u32 = 8192;
+
+/// Free camera pitch limit, just short of straight up or down.
+pub(crate) const MAX_FREE_PITCH: f32 = 89.0 * std::f32::consts::PI / 180.0;
+
+/// Colour filter applied to the photo.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PhotoFilter {
+    #[default]
+    None,
+    Noir,
+    Warm,
+    Cool,
+    Vivid,
+    Faded,
+}
+
+impl PhotoFilter {
+    pub const ALL: [PhotoFilter; 6] = [
+        PhotoFilter::None,
+        PhotoFilter::Noir,
+        PhotoFilter::Warm,
+        PhotoFilter::Cool,
+        PhotoFilter::Vivid,
+        PhotoFilter::Faded,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PhotoFilter::None => "None",
+            PhotoFilter::Noir => "Noir",
+            PhotoFilter::Warm => "Warm",
+            PhotoFilter::Cool => "Cool",
+            PhotoFilter::Vivid => "Vivid",
+            PhotoFilter::Faded => "Faded",
+        }
+    }
+
+    /// Colour grading for this filter with `exposure` stops added.
+    pub fn color_grading(self, exposure: f32) -> ColorGrading {
+        let mut global = ColorGradingGlobal {
+            exposure,
+            ..default()
+        };
+        let mut section = ColorGradingSection::default();
+        match self {
+            PhotoFilter::None => {}
+            PhotoFilter::Noir => {
+                global.post_saturation = 0.0;
+                section.contrast = 1.3;
+            }
+            PhotoFilter::Warm => {
+                global.temperature = 0.3;
+                section.saturation = 0.8;
+            }
+            PhotoFilter::Cool => {
+                global.temperature = -0.25;
+                global.tint = -0.05;
+            }
+            PhotoFilter::Vivid => {
+                section.saturation = 1.4;
+                section.contrast = 1.1;
+            }
+            PhotoFilter::Faded => {
+                section.saturation = 0.7;
+                section.contrast = 0.85;
+                section.lift = 0.05;
+            }
+        }
+        ColorGrading::with_identical_sections(global, section)
+    }
+}
+
+/// Position and orientation of the photo mode camera. Unlike the orbit
+/// camera it flies freely and can roll.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FreeCamera {
+    pub position: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub roll: f32,
+}
+
+impl Default for FreeCamera {
+    fn default() -> Self {
+        Self::from_transform(&Transform::IDENTITY)
+    }
+}
+
+impl FreeCamera {
+    /// Free camera looking the way `transform` does, without roll.
+    pub fn from_transform(transform: &Transform) -> Self {
+        let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+        Self {
+            position: transform.translation,
+            yaw,
+            pitch: pitch.clamp(-MAX_FREE_PITCH, MAX_FREE_PITCH),
+            roll: 0.0,
+        }
+    }
+
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, self.roll)
+    }
+
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(self.position).with_rotation(self.rotation())
+    }
+}
+
+/// Photo mode: the simulation pauses while the player frames a shot with a
+/// free camera, lens and colour settings, then captures it at up to
+/// `MAX_RESOLUTION_SCALE` times the window resolution.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PhotoMode {
+    /// Set to enter or leave photo mode; the switch happens next frame.
+    pub active: bool,
+    /// Hide every egui panel, including the photo mode controls.
+    pub hide_ui: bool,
+    pub camera: FreeCamera,
+    /// Exposure adjustment in stops.
+    pub exposure: f32,
+    pub depth_of_field: bool,
+    /// Distance to the plane in focus, in world units.
+    pub focal_distance: f32,
+    /// Lower values blur more of the scene outside the focal plane.
+    pub aperture_f_stops: f32,
+    pub filter: PhotoFilter,
+    /// Captured photos are this many times the window resolution.
+    pub resolution_scale: u32,
+    /// Set to capture a photo; cleared once the capture starts.
+    pub capture_requested: bool,
+    /// Whether the enter/exit work for `active` has been done.
+    pub(crate) entered: bool,
+    /// Whether the clock was paused before photo mode paused it.
+    pub(crate) clock_was_paused: bool,
+}
+
+impl Default for PhotoMode {
+    fn default() -> Self {
+        Self {
+            active: false,
+            hide_ui: false,
+            camera: FreeCamera::default(),
+            exposure: 0.0,
+            depth_of_field: false,
+            focal_distance: 400.0,
+            aperture_f_stops: 1.4,
+            filter: PhotoFilter::None,
+            resolution_scale: 2,
+            capture_requested: false,
+            entered: false,
+            clock_was_paused: false,
+        }
+    }
+}
+
+impl PhotoMode {
+    /// Colour grading for the current filter and exposure.
+    pub fn color_grading(&self) -> ColorGrading {
+        self.filter.color_grading(self.exposure)
+    }
+}
+
+/// Run condition: photo mode is off, so the regular camera and tools run.
+pub fn photo_mode_inactive(photo: Res<PhotoMode>) -> bool {
+    !photo.active
+}
+
+/// Size of a photo captured at `scale` times a `window` sized view, shrunk
+/// to fit `MAX_CAPTURE_SIDE` while keeping the aspect ratio.
+pub fn capture_size(window: UVec2, scale: u32) -> UVec2 {
+    let size = window.max(UVec2::ONE) * scale.clamp(1, MAX_RESOLUTION_SCALE);
+    let largest = size.x.max(size.y);
+    if largest <= MAX_CAPTURE_SIDE {
+        return size;
+    }
+    let shrink = MAX_CAPTURE_SIDE as f32 / largest as f32;
+    (size.as_vec2() * shrink).floor().as_uvec2().max(UVec2::ONE)
+}
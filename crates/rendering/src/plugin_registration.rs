@@ -3,6 +3,7 @@ use bevy::time::common_conditions::on_timer;
 
 use crate::*;
 use simulation::app_state::AppState;
+use simulation::replay::ReplayViewerMode;
 use simulation::SaveLoadState;
 
 /// Register all rendering plugins and systems.
@@ -14,9 +15,12 @@ use simulation::SaveLoadState;
 /// Systems that query game entities (buildings, citizens, etc.) are gated behind
 /// `SaveLoadState::Idle` to prevent races with the exclusive load/new-game systems
 /// that despawn entities with direct world access (issue #1604).
-pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_viewer: bool) {
+pub(crate) fn register_rendering_systems(app: &mut App, headless: bool) {
     let idle = in_state(SaveLoadState::Idle);
     let playing = in_state(AppState::Playing);
+    // Replay viewer mode can end at runtime when the player takes over, so it
+    // gates input with a run condition rather than at registration time.
+    let not_viewing = not(resource_exists::<ReplayViewerMode>);
 
     app.add_systems(
         Startup,
@@ -24,7 +28,9 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
             camera::setup_camera,
             camera_smoothing::init_camera_target,
             super::setup_lighting,
+            overlay_heatmap::setup_overlay_heatmap,
             terrain_render::spawn_terrain_chunks,
+            street_lights::spawn_street_light_pool,
             building_preview_mesh::setup_building_preview_meshes,
             cursor_preview::spawn_cursor_preview,
             building_meshes::load_building_models,
@@ -40,7 +46,8 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
     //
     // Camera input is gated behind AppState::Playing so that the camera
     // cannot be moved from the main menu or while paused (issue #1733).
-    // The smoothing + apply systems always run so the camera stays valid.
+    // The smoothing + apply systems always run so the camera stays valid,
+    // except in photo mode where the free camera owns the Transform.
     if !headless {
         app.add_systems(
             Update,
@@ -54,7 +61,8 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
                 camera::camera_rotate_keyboard,
             )
                 .after(camera_smoothing::sync_target_from_external_changes)
-                .run_if(playing.clone()),
+                .run_if(playing.clone())
+                .run_if(photo_mode::photo_mode_inactive),
         );
     }
     app.add_systems(
@@ -69,13 +77,17 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
                 .after(camera::camera_zoom)
                 .after(camera::camera_zoom_keyboard)
                 .after(camera::camera_rotate_keyboard),
-            camera::apply_orbit_camera.after(camera_smoothing::smooth_camera_to_target),
+            camera::apply_orbit_camera
+                .after(camera_smoothing::smooth_camera_to_target)
+                .run_if(photo_mode::photo_mode_inactive),
         ),
     );
 
     // Input and tool handling — gated behind SaveLoadState::Idle (entity safety)
     // and AppState::Playing (no input on main menu or pause, issue #1733).
-    if !headless && !replay_viewer {
+    // Tools are off in photo mode so framing a shot cannot edit the city, and
+    // in replay viewer mode until the player takes over.
+    if !headless {
         app.add_systems(
             Update,
             (
@@ -94,15 +106,25 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
                     .before(input::handle_tool_input),
                 input::handle_tool_input,
                 input::handle_tree_tool,
+                input::handle_seawall_tool,
+                input::handle_nature_reserve_tool,
+                input::handle_cell_tool,
                 input::handle_road_upgrade_tool,
                 input::keyboard_tool_switch,
                 input::toggle_grid_snap,
                 input::toggle_curve_draw_mode,
                 input::handle_escape_key,
                 input::delete_selected_building,
-                input::tick_status_message,
                 overlay::toggle_overlay_keys,
             )
+                .run_if(idle.clone())
+                .run_if(playing.clone())
+                .run_if(photo_mode::photo_mode_inactive)
+                .run_if(not_viewing),
+        );
+        app.add_systems(
+            Update,
+            input::tick_status_message
                 .run_if(idle.clone())
                 .run_if(playing.clone()),
         );
@@ -120,7 +142,8 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
             cursor_preview::draw_intersection_snap_indicator,
             road_render::sync_road_segment_meshes,
             lane_markings::sync_lane_marking_meshes,
-            intersection_markings::sync_intersection_markings,
+            lane_markings::sync_junction_decals,
+            lane_markings::sync_bus_lane_decals,
             road_grade::draw_road_grade_indicators,
         )
             .run_if(idle.clone()),
@@ -128,7 +151,16 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
 
     // Day/night cycle — safe, no game entity queries
     app.add_systems(Update, day_night::update_day_night_cycle);
-    app.add_systems(Update, day_night::update_fog_rendering);
+    app.add_systems(
+        Update,
+        day_night::update_fog_rendering.after(weather_vfx::update_weather_vfx),
+    );
+
+    // Budgeted point lights on the powered street lamps nearest the camera
+    app.add_systems(Update, street_lights::update_street_lights);
+
+    // Flatten GLB scenes as they load so buildings and props spawn instanced
+    app.add_systems(Update, building_meshes::flatten_loaded_scenes);
 
     // Building rendering — queries Building, ServiceBuilding, UtilitySource entities
     app.add_systems(
@@ -136,13 +168,18 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
         (
             building_render::spawn_building_meshes,
             building_render::update_building_meshes,
+            building_render::refresh_changed_service_meshes,
             building_render::update_construction_visuals,
             building_render::cleanup_orphan_building_meshes
                 .run_if(on_timer(std::time::Duration::from_secs(1))),
+            building_window_glow::spawn_window_glow,
+            building_window_glow::update_window_lighting
+                .run_if(on_timer(std::time::Duration::from_millis(500))),
             props::spawn_tree_props,
             props::spawn_road_props,
             props::spawn_parked_cars,
         )
+            .after(building_meshes::flatten_loaded_scenes)
             .run_if(idle.clone()),
     );
 
@@ -207,12 +244,30 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
         oneway_arrows::draw_oneway_arrows.run_if(idle.clone()),
     );
 
+    // Flow direction of carved waterways
+    app.add_systems(
+        Update,
+        waterway_flow::draw_waterway_flow.run_if(idle.clone()),
+    );
+
+    // Natural resource deposits under the deposit brush
+    app.add_systems(
+        Update,
+        resource_deposits::draw_resource_deposits.run_if(idle.clone()),
+    );
+
     // Feature plugins
     app.add_plugins(traffic_los_render::TrafficLosRenderPlugin);
     app.add_plugins(traffic_arrows::TrafficArrowsPlugin);
     app.add_plugins(wind_streamlines::WindStreamlinesPlugin);
+    app.add_plugins(weather_vfx::WeatherVfxPlugin);
+    app.add_plugins(seasonal_palette::SeasonalPalettePlugin);
+    app.add_plugins(sun_shadows::SunShadowsPlugin);
+    app.add_plugins(hurricane_cone::HurricaneConePlugin);
+    app.add_plugins(tutorial_marker::TutorialMarkerPlugin);
     app.add_plugins(tree_props::TreePropsPlugin);
     app.add_plugins(network_viz::NetworkVizPlugin);
+    app.add_plugins(overlay_heatmap::OverlayHeatmapPlugin);
 
     // Screenshot plugin (F12 to capture)
     app.add_plugins(screenshot::ScreenshotPlugin);
@@ -223,9 +278,12 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
     // Satellite view at maximum zoom-out
     app.add_plugins(satellite_view::SatelliteViewPlugin);
 
-    // Interactive build/edit plugins are disabled in headless record mode
-    // and replay-viewer mode.
-    if !headless && !replay_viewer {
+    // Underground cross-section view
+    app.add_plugins(underground_view::UndergroundViewPlugin);
+
+    // Interactive build/edit plugins are disabled in headless record mode.
+    // In replay viewer mode they stay idle because tool input is gated above.
+    if !headless {
         // Parallel road snapping (UX-026)
         app.add_plugins(parallel_snap::ParallelSnapPlugin);
 
@@ -237,6 +295,7 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
 
         // Zone brush preview (UX-018)
         app.add_plugins(zone_brush_preview::ZoneBrushPreviewPlugin);
+        app.add_plugins(coverage_suggestion_preview::CoverageSuggestionPreviewPlugin);
 
         // Enhanced click-to-select with priority ordering (UX-009)
         app.add_plugins(enhanced_select::EnhancedSelectPlugin);
@@ -249,6 +308,12 @@ pub(crate) fn register_rendering_systems(app: &mut App, headless: bool, replay_v
 
         // Auto-grid road placement (TRAF-010)
         app.add_plugins(auto_grid_draw::AutoGridDrawPlugin);
+
+        // Blueprint stamp tool: ghost preview, rotation and placement
+        app.add_plugins(blueprint_tool::BlueprintToolPlugin);
+
+        // Photo mode: free camera, lens effects and super-resolution capture
+        app.add_plugins(photo_mode::PhotoModePlugin);
     }
 
     // Power grid overlay (POWER-020)
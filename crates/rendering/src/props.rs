@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use simulation::config::CELL_SIZE;
 use simulation::grid::{CellType, RoadType, WorldGrid, ZoneType};
 
-use crate::building_meshes::BuildingModelCache;
+use crate::building_meshes::{BuildingModelCache, InstancedModels};
 
 /// Marker component for prop entities (trees, lamps, benches, etc.)
 #[derive(Component)]
@@ -39,12 +39,18 @@ const LAMP_SCALE: f32 = 1.5;
 pub fn spawn_tree_props(
     mut commands: Commands,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
+    asset_server: Res<AssetServer>,
     grid: Res<WorldGrid>,
     mut props_spawned: ResMut<PropsSpawned>,
 ) {
     if props_spawned.trees_spawned || model_cache.trees.is_empty() {
         return;
     }
+    // Wait for the models to load so the props spawn instanced.
+    if !models.settled(&model_cache.trees, &asset_server) {
+        return;
+    }
     props_spawned.trees_spawned = true;
 
     let width = grid.width;
@@ -100,14 +106,21 @@ pub fn spawn_tree_props(
                         // Street trees are smaller and more uniform
                         let scale = TREE_SCALE * (0.6 + (tree_hash as f32 % 4.0) / 20.0);
 
-                        commands.spawn((
-                            PropEntity,
-                            TreeProp,
-                            SceneRoot(scene_handle),
-                            Transform::from_xyz(wx + off_x, grid.elevation_y(gx, gy), wz + off_z)
+                        models.spawn(
+                            &mut commands,
+                            scene_handle,
+                            (
+                                PropEntity,
+                                TreeProp,
+                                Transform::from_xyz(
+                                    wx + off_x,
+                                    grid.elevation_y(gx, gy),
+                                    wz + off_z,
+                                )
                                 .with_scale(Vec3::splat(scale)),
-                            Visibility::default(),
-                        ));
+                                Visibility::default(),
+                            ),
+                        );
                     }
                 }
                 continue;
@@ -143,15 +156,18 @@ pub fn spawn_tree_props(
             let scale_var = TREE_SCALE * (0.7 + (tree_hash as f32 % 7.0) / 10.0);
             let yaw = (tree_variant % 8) as f32 * std::f32::consts::FRAC_PI_4;
 
-            commands.spawn((
-                PropEntity,
-                TreeProp,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx, grid.elevation_y(gx, gy), wz)
-                    .with_rotation(Quat::from_rotation_y(yaw))
-                    .with_scale(Vec3::splat(scale_var)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    PropEntity,
+                    TreeProp,
+                    Transform::from_xyz(wx, grid.elevation_y(gx, gy), wz)
+                        .with_rotation(Quat::from_rotation_y(yaw))
+                        .with_scale(Vec3::splat(scale_var)),
+                    Visibility::default(),
+                ),
+            );
         }
     }
 }
@@ -160,12 +176,18 @@ pub fn spawn_tree_props(
 pub fn spawn_road_props(
     mut commands: Commands,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
+    asset_server: Res<AssetServer>,
     grid: Res<WorldGrid>,
     mut props_spawned: ResMut<PropsSpawned>,
 ) {
     if props_spawned.lamps_spawned || model_cache.props.is_empty() {
         return;
     }
+    // Wait for the models to load so the props spawn instanced.
+    if !models.settled(&model_cache.props, &asset_server) {
+        return;
+    }
     props_spawned.lamps_spawned = true;
 
     let width = grid.width;
@@ -232,14 +254,17 @@ pub fn spawn_road_props(
                 model_cache.get_prop(lamp_hash)
             };
 
-            commands.spawn((
-                PropEntity,
-                StreetLamp,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx + offset_x, grid.elevation_y(gx, gy), wz + offset_z)
-                    .with_scale(Vec3::splat(LAMP_SCALE)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    PropEntity,
+                    StreetLamp,
+                    Transform::from_xyz(wx + offset_x, grid.elevation_y(gx, gy), wz + offset_z)
+                        .with_scale(Vec3::splat(LAMP_SCALE)),
+                    Visibility::default(),
+                ),
+            );
         }
     }
 }
@@ -248,12 +273,18 @@ pub fn spawn_road_props(
 pub fn spawn_parked_cars(
     mut commands: Commands,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
+    asset_server: Res<AssetServer>,
     grid: Res<WorldGrid>,
     mut props_spawned: ResMut<PropsSpawned>,
 ) {
     if props_spawned.parked_cars_spawned || model_cache.vehicles.is_empty() {
         return;
     }
+    // Wait for the models to load so the props spawn instanced.
+    if !models.settled(&model_cache.vehicles, &asset_server) {
+        return;
+    }
     props_spawned.parked_cars_spawned = true;
 
     let width = grid.width;
@@ -327,15 +358,18 @@ pub fn spawn_parked_cars(
             let car_idx = car_hash % 8; // sedan, sedan-sports, hatchback, suv, suv-luxury, van, truck, taxi
             let scene_handle = model_cache.vehicles[car_idx % model_cache.vehicles.len()].clone();
 
-            commands.spawn((
-                PropEntity,
-                ParkedCar,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx + off_x, grid.elevation_y(gx, gy), wz + off_z)
-                    .with_rotation(Quat::from_rotation_y(yaw))
-                    .with_scale(Vec3::splat(1.0)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    PropEntity,
+                    ParkedCar,
+                    Transform::from_xyz(wx + off_x, grid.elevation_y(gx, gy), wz + off_z)
+                        .with_rotation(Quat::from_rotation_y(yaw))
+                        .with_scale(Vec3::splat(1.0)),
+                    Visibility::default(),
+                ),
+            );
         }
     }
 }
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::grid::WorldGrid;
+use simulation::natural_resources::{ResourceGrid, ResourceType};
+
+use crate::input::{ActiveTool, CursorGridPos};
+
+/// Cells around the cursor whose deposits are drawn.
+const MARKER_REACH: i32 = 24;
+
+/// Mark natural resource deposits near the cursor while a deposit brush is
+/// active. Each marker shrinks as its deposit is worked, and exhausted
+/// deposits show as a faint ring.
+pub fn draw_resource_deposits(
+    tool: Res<ActiveTool>,
+    cursor: Res<CursorGridPos>,
+    resources: Res<ResourceGrid>,
+    grid: Res<WorldGrid>,
+    mut gizmos: Gizmos,
+) {
+    if !matches!(
+        *tool,
+        ActiveTool::PaintResource(_) | ActiveTool::EraseResource
+    ) || !cursor.valid
+    {
+        return;
+    }
+
+    let x0 = (cursor.grid_x - MARKER_REACH).max(0) as usize;
+    let y0 = (cursor.grid_y - MARKER_REACH).max(0) as usize;
+    let x1 = ((cursor.grid_x + MARKER_REACH) as usize).min(resources.width - 1);
+    let y1 = ((cursor.grid_y + MARKER_REACH) as usize).min(resources.height - 1);
+    let flat = Quat::from_rotation_x(std::f32::consts::FRAC_PI_2);
+
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            let Some(deposit) = resources.get(x, y) else {
+                continue;
+            };
+            let share = if deposit.max_amount > 0 {
+                deposit.amount as f32 / deposit.max_amount as f32
+            } else {
+                0.0
+            };
+            let color = deposit_color(deposit.resource_type)
+                .with_alpha(if deposit.is_exhausted() { 0.25 } else { 0.85 });
+            let (wx, wz) = WorldGrid::grid_to_world(x, y);
+            let pos = Vec3::new(wx, grid.elevation_y(x, y) + 0.3, wz);
+            let radius = CELL_SIZE * (0.1 + 0.35 * share.clamp(0.0, 1.0));
+            gizmos.circle(Isometry3d::new(pos, flat), radius, color);
+        }
+    }
+}
+
+fn deposit_color(resource: ResourceType) -> Color {
+    match resource {
+        ResourceType::FertileLand => Color::srgb(0.55, 0.85, 0.3),
+        ResourceType::Forest => Color::srgb(0.1, 0.5, 0.15),
+        ResourceType::Ore => Color::srgb(0.75, 0.45, 0.3),
+        ResourceType::Oil => Color::srgb(0.15, 0.15, 0.2),
+    }
+}
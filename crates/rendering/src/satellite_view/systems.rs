@@ -16,6 +16,7 @@ use crate::citizen_render::CitizenSprite;
 use crate::lane_markings::LaneMarkingMesh;
 use crate::props::PropEntity;
 use crate::road_render::{RoadIntersectionMesh, RoadSegmentMesh};
+use crate::underground_view::UndergroundView;
 
 use super::image_gen::{create_blank_image, generate_satellite_image};
 use super::types::{SatelliteQuad, SatelliteView, SATELLITE_Y, TRANSITION_END, TRANSITION_START};
@@ -202,11 +203,13 @@ fn update_satellite_visibility(
     }
 }
 
-/// Fade out 3D objects as satellite view fades in. Uses `ParamSet` to avoid
-/// conflicting `Visibility` queries.
+/// Fade out 3D objects as satellite view fades in, and while the
+/// underground view is on. Uses `ParamSet` to avoid conflicting
+/// `Visibility` queries.
 #[allow(clippy::type_complexity)]
 fn fade_3d_objects(
     satellite: Res<SatelliteView>,
+    underground: Res<UndergroundView>,
     mut set: ParamSet<(
         Query<&mut Visibility, With<BuildingMesh3d>>,
         Query<&mut Visibility, With<RoadSegmentMesh>>,
@@ -217,7 +220,7 @@ fn fade_3d_objects(
     )>,
 ) {
     // Hide 3D objects when the satellite view is more than 70% blended in
-    let hide_3d = satellite.blend > 0.7;
+    let hide_3d = satellite.blend > 0.7 || underground.hides_surface();
     let target = if hide_3d {
         Visibility::Hidden
     } else {
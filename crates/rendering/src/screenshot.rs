@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy::render::view::screenshot::{save_to_disk, Screenshot};
 
 use crate::input::StatusMessage;
+use crate::photo_mode::PhotoMode;
 
 pub struct ScreenshotPlugin;
 
@@ -19,29 +20,20 @@ fn handle_screenshot_key(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut status: ResMut<StatusMessage>,
     bindings: Res<simulation::keybindings::KeyBindings>,
+    photo: Res<PhotoMode>,
 ) {
+    // In photo mode the same key takes a photo instead.
+    if photo.active {
+        return;
+    }
     if bindings.screenshot.just_pressed(&keyboard) {
-        // Create screenshots directory if it doesn't exist
-        let dir = "screenshots";
-        if std::fs::create_dir_all(dir).is_err() {
-            status.set("Failed to create screenshots directory", true);
-            return;
-        }
-
-        // Generate timestamp filename
-        let now = std::time::SystemTime::now();
-        let since_epoch = now
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default();
-        let secs = since_epoch.as_secs();
-
-        // Convert epoch seconds to a human-readable timestamp
-        // (manual conversion to avoid adding chrono dependency)
-        let (year, month, day, hour, minute, second) = epoch_to_datetime(secs);
-        let filename = format!(
-            "{}/screenshot_{:04}-{:02}-{:02}_{:02}-{:02}-{:02}.png",
-            dir, year, month, day, hour, minute, second
-        );
+        let filename = match capture_path("screenshot") {
+            Ok(filename) => filename,
+            Err(_) => {
+                status.set("Failed to create screenshots directory", true);
+                return;
+            }
+        };
 
         let display_name = filename.clone();
         commands
@@ -52,13 +44,38 @@ fn handle_screenshot_key(
     }
 }
 
+/// Path for a new capture in the screenshots directory, named after
+/// `prefix` and the current time. Creates the directory if it is missing.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn capture_path(prefix: &str) -> std::io::Result<String> {
+    // Create screenshots directory if it doesn't exist
+    let dir = "screenshots";
+    std::fs::create_dir_all(dir)?;
+
+    // Generate timestamp filename
+    let now = std::time::SystemTime::now();
+    let since_epoch = now
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = since_epoch.as_secs();
+
+    // Convert epoch seconds to a human-readable timestamp
+    // (manual conversion to avoid adding chrono dependency)
+    let (year, month, day, hour, minute, second) = epoch_to_datetime(secs);
+    Ok(format!(
+        "{}/{}_{:04}-{:02}-{:02}_{:02}-{:02}-{:02}.png",
+        dir, prefix, year, month, day, hour, minute, second
+    ))
+}
+
 #[cfg(target_arch = "wasm32")]
 fn handle_screenshot_key(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut status: ResMut<StatusMessage>,
     bindings: Res<simulation::keybindings::KeyBindings>,
+    photo: Res<PhotoMode>,
 ) {
-    if bindings.screenshot.just_pressed(&keyboard) {
+    if !photo.active && bindings.screenshot.just_pressed(&keyboard) {
         status.set("Screenshots not supported in browser", true);
     }
 }
@@ -0,0 +1,201 @@
+//! Seasonal colours for ground cover and foliage.
+//!
+//! Terrain chunks and tree props read their grass and leaf colours from
+//! `SeasonalPalette` rather than switching on the season directly. The
+//! palette follows the climate (Mediterranean and arid summers dry the grass
+//! to straw, tropical and Mediterranean winters stay green) and, over the
+//! last `BLEND_DAYS` of each season, eases day by day towards the next one so
+//! the city changes colour gradually instead of overnight.
+
+use bevy::prelude::*;
+
+use simulation::time_of_day::GameClock;
+use simulation::weather::{ClimateZone, Season};
+
+/// Days at the end of each season spent blending into the next.
+pub const BLEND_DAYS: u32 = 20;
+
+/// Days in each season of the 360-day year.
+const SEASON_DAYS: u32 = 90;
+
+/// Blossom on spring trees, fading to summer green as spring goes on.
+const SPRING_BLOOM: Color = Color::srgb(0.90, 0.74, 0.78);
+/// Lush summer leaves.
+const SUMMER_FOLIAGE: Color = Color::srgb(0.35, 0.70, 0.30);
+/// Dusty olive leaves of a dry summer.
+const DRY_SUMMER_FOLIAGE: Color = Color::srgb(0.48, 0.58, 0.33);
+/// Warm orange-gold autumn leaves.
+const AUTUMN_FOLIAGE: Color = Color::srgb(0.85, 0.55, 0.20);
+/// Grey-brown bare winter branches.
+const WINTER_FOLIAGE: Color = Color::srgb(0.55, 0.50, 0.40);
+/// Evergreen leaves of climates without a cold winter.
+const EVERGREEN_FOLIAGE: Color = Color::srgb(0.30, 0.55, 0.30);
+
+/// Straw-coloured summer grass of dry climates.
+const DRY_GRASS: [f32; 3] = [0.60, 0.52, 0.28];
+/// Green grass of a mild, wet winter.
+const WET_WINTER_GRASS: [f32; 3] = [0.30, 0.55, 0.18];
+/// Dusty scrub of an arid winter.
+const ARID_WINTER_GRASS: [f32; 3] = [0.50, 0.48, 0.30];
+
+/// Ground cover and foliage colours for the current day and climate.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SeasonalPalette {
+    /// Base grass colour before per-cell variation.
+    pub grass: [f32; 3],
+    /// Tint applied to tree prop materials.
+    pub foliage: Color,
+    /// Game day the palette was computed for.
+    pub day: u32,
+}
+
+impl Default for SeasonalPalette {
+    fn default() -> Self {
+        palette_for_day(1, ClimateZone::default())
+    }
+}
+
+/// Grass colour at the height of `season` in `climate`.
+pub fn grass_palette(season: Season, climate: ClimateZone) -> [f32; 3] {
+    match (season, climate) {
+        (Season::Summer, ClimateZone::Mediterranean | ClimateZone::Arid) => DRY_GRASS,
+        (Season::Autumn | Season::Winter, ClimateZone::Tropical) => Season::Summer.grass_color(),
+        (Season::Winter, ClimateZone::Mediterranean) => WET_WINTER_GRASS,
+        (Season::Winter, ClimateZone::Arid) => ARID_WINTER_GRASS,
+        _ => season.grass_color(),
+    }
+}
+
+/// Foliage tint at the height of `season` in `climate`.
+pub fn foliage_palette(season: Season, climate: ClimateZone) -> Color {
+    match (season, climate) {
+        (Season::Spring, _) => SPRING_BLOOM,
+        (Season::Summer, ClimateZone::Mediterranean | ClimateZone::Arid) => DRY_SUMMER_FOLIAGE,
+        (Season::Summer, _) | (_, ClimateZone::Tropical) => SUMMER_FOLIAGE,
+        (Season::Winter, ClimateZone::Mediterranean | ClimateZone::Arid) => EVERGREEN_FOLIAGE,
+        (Season::Autumn, _) => AUTUMN_FOLIAGE,
+        (Season::Winter, _) => WINTER_FOLIAGE,
+    }
+}
+
+/// The season following `season`.
+fn next_season(season: Season) -> Season {
+    match season {
+        Season::Spring => Season::Summer,
+        Season::Summer => Season::Autumn,
+        Season::Autumn => Season::Winter,
+        Season::Winter => Season::Spring,
+    }
+}
+
+/// How far `day` is into the blend towards the next season: 0.0 until the
+/// last `BLEND_DAYS` of the season, rising to 1.0 on its final day.
+pub fn blend_progress(day: u32) -> f32 {
+    let day_in_season = day.saturating_sub(1) % SEASON_DAYS + 1;
+    let blend_start = SEASON_DAYS - BLEND_DAYS;
+    if day_in_season <= blend_start {
+        0.0
+    } else {
+        (day_in_season - blend_start) as f32 / BLEND_DAYS as f32
+    }
+}
+
+/// The palette for `day` in `climate`.
+pub fn palette_for_day(day: u32, climate: ClimateZone) -> SeasonalPalette {
+    let season = Season::from_day(day);
+    let next = next_season(season);
+    let t = blend_progress(day);
+    let lerp = |a: [f32; 3], b: [f32; 3]| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+    let srgb = |c: Color| {
+        let c = c.to_srgba();
+        [c.red, c.green, c.blue]
+    };
+    let [r, g, b] = lerp(
+        srgb(foliage_palette(season, climate)),
+        srgb(foliage_palette(next, climate)),
+    );
+    SeasonalPalette {
+        grass: lerp(grass_palette(season, climate), grass_palette(next, climate)),
+        foliage: Color::srgb(r, g, b),
+        day,
+    }
+}
+
+/// Recompute the palette when the day or climate changes. Only changed
+/// palettes are written, so terrain and trees only refresh when their
+/// colours actually move.
+pub fn update_seasonal_palette(
+    clock: Res<GameClock>,
+    climate: Res<ClimateZone>,
+    mut palette: ResMut<SeasonalPalette>,
+) {
+    if palette.day == clock.day && !climate.is_changed() {
+        return;
+    }
+    let next = palette_for_day(clock.day, *climate);
+    let colours_moved = next.grass != palette.grass || next.foliage != palette.foliage;
+    if colours_moved {
+        *palette = next;
+    } else {
+        palette.bypass_change_detection().day = clock.day;
+    }
+}
+
+pub struct SeasonalPalettePlugin;
+
+impl Plugin for SeasonalPalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeasonalPalette>()
+            .add_systems(Update, update_seasonal_palette);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blend_only_in_the_last_days_of_a_season() {
+        assert_eq!(blend_progress(1), 0.0);
+        assert_eq!(blend_progress(SEASON_DAYS - BLEND_DAYS), 0.0);
+        assert!(blend_progress(SEASON_DAYS - BLEND_DAYS / 2) > 0.0);
+        assert_eq!(blend_progress(SEASON_DAYS), 1.0);
+        assert_eq!(blend_progress(SEASON_DAYS + 1), 0.0);
+    }
+
+    #[test]
+    fn test_palette_changes_gradually_between_seasons() {
+        let climate = ClimateZone::Temperate;
+        let late_spring = palette_for_day(SEASON_DAYS, climate);
+        let early_summer = palette_for_day(SEASON_DAYS + 1, climate);
+        assert_eq!(late_spring.grass, early_summer.grass);
+
+        let day_before = palette_for_day(SEASON_DAYS - 1, climate);
+        let step = (late_spring.grass[0] - day_before.grass[0]).abs();
+        assert!(
+            step > 0.0 && step < 0.01,
+            "one day moves the grass a little"
+        );
+    }
+
+    #[test]
+    fn test_mediterranean_summer_grass_is_dry() {
+        let summer_day = SEASON_DAYS + 10;
+        let med = palette_for_day(summer_day, ClimateZone::Mediterranean).grass;
+        let temperate = palette_for_day(summer_day, ClimateZone::Temperate).grass;
+        assert!(med[0] > med[1] * 0.9, "dry grass is straw, not green");
+        assert!(temperate[1] > temperate[0]);
+    }
+
+    #[test]
+    fn test_spring_opens_in_bloom() {
+        let spring = palette_for_day(5, ClimateZone::Temperate)
+            .foliage
+            .to_srgba();
+        assert!(spring.red > spring.green, "blossom is pink");
+        let summer = palette_for_day(SEASON_DAYS + 5, ClimateZone::Temperate)
+            .foliage
+            .to_srgba();
+        assert!(summer.green > summer.red);
+    }
+}
@@ -0,0 +1,142 @@
+//! Night-time street lighting.
+//!
+//! Every powered street lamp could cast light, but point lights are
+//! expensive, so a fixed pool of `MAX_STREET_LIGHTS` lights is spawned once
+//! and moved each frame onto the lit lamps nearest the camera focus. The
+//! lights fade in at dusk and out at dawn; during the day the pool is
+//! hidden. Lamps without power (outages, blackouts) never get a light.
+
+use bevy::prelude::*;
+
+use simulation::blackout::BlackoutState;
+use simulation::config::CELL_SIZE;
+use simulation::day_night_controls::DayNightControls;
+use simulation::grid::WorldGrid;
+use simulation::street_lighting::{lamp_powered, StreetLighting, LAMP_LIGHT_RADIUS};
+use simulation::time_of_day::GameClock;
+
+use crate::camera::OrbitCamera;
+use crate::day_night::daylight_blend;
+
+/// Point lights available to street lamps at any one time.
+const MAX_STREET_LIGHTS: usize = 32;
+
+/// Height of a lamp head above the road.
+const LAMP_HEIGHT: f32 = 7.0;
+
+/// Light output of a lamp at full darkness, in lumens.
+const LAMP_INTENSITY: f32 = 120_000.0;
+
+/// Warm sodium-vapour colour.
+const LAMP_COLOR: Color = Color::srgb(1.0, 0.78, 0.5);
+
+/// Darkness below which the pool stays hidden.
+const MIN_DARKNESS: f32 = 0.05;
+
+/// One light of the street light pool.
+#[derive(Component)]
+pub struct StreetLightSource;
+
+/// Spawn the pool of street lights, hidden until dusk.
+pub fn spawn_street_light_pool(mut commands: Commands) {
+    for _ in 0..MAX_STREET_LIGHTS {
+        commands.spawn((
+            StreetLightSource,
+            PointLight {
+                color: LAMP_COLOR,
+                intensity: 0.0,
+                range: (LAMP_LIGHT_RADIUS as f32 + 1.0) * CELL_SIZE,
+                shadows_enabled: false,
+                ..default()
+            },
+            Transform::default(),
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// The `budget` lamp positions nearest `focus` on the ground plane.
+pub fn nearest_lamps(lamps: &[Vec3], focus: Vec3, budget: usize) -> Vec<Vec3> {
+    let distance = |p: &Vec3| p.xz().distance_squared(focus.xz());
+    let mut nearest = lamps.to_vec();
+    if nearest.len() > budget {
+        nearest.select_nth_unstable_by(budget, |a, b| distance(a).total_cmp(&distance(b)));
+        nearest.truncate(budget);
+    }
+    nearest
+}
+
+/// Move the pool onto the powered lamps nearest the camera and fade it
+/// with the daylight.
+#[allow(clippy::too_many_arguments)]
+pub fn update_street_lights(
+    controls: Res<DayNightControls>,
+    clock: Res<GameClock>,
+    grid: Res<WorldGrid>,
+    lighting: Res<StreetLighting>,
+    blackout: Res<BlackoutState>,
+    orbit: Res<OrbitCamera>,
+    mut powered_lamps: Local<Vec<Vec3>>,
+    mut lights: Query<(&mut PointLight, &mut Transform, &mut Visibility), With<StreetLightSource>>,
+) {
+    // Kept current by day too, so change detection is never missed.
+    if lighting.is_changed() || blackout.is_changed() || grid.is_changed() {
+        *powered_lamps = lighting
+            .iter()
+            .filter(|&(x, y, _)| lamp_powered(&grid, &blackout, x, y))
+            .map(|(x, y, _)| {
+                let (wx, wz) = WorldGrid::grid_to_world(x, y);
+                Vec3::new(wx, grid.elevation_y(x, y) + LAMP_HEIGHT, wz)
+            })
+            .collect();
+    }
+
+    let darkness = 1.0 - daylight_blend(controls.effective_hour(), clock.sunrise(), clock.sunset());
+    if darkness < MIN_DARKNESS {
+        for (_, _, mut visibility) in &mut lights {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    }
+
+    let mut nearest = nearest_lamps(&powered_lamps, orbit.focus, MAX_STREET_LIGHTS).into_iter();
+    for (mut light, mut transform, mut visibility) in &mut lights {
+        match nearest.next() {
+            Some(position) => {
+                transform.translation = position;
+                light.intensity = LAMP_INTENSITY * darkness;
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_lamps_respects_budget() {
+        let lamps: Vec<Vec3> = (0..10)
+            .map(|i| Vec3::new(i as f32 * 10.0, 5.0, 0.0))
+            .collect();
+        let nearest = nearest_lamps(&lamps, Vec3::new(95.0, 0.0, 0.0), 3);
+        assert_eq!(nearest.len(), 3);
+        for lamp in nearest {
+            assert!(
+                lamp.x >= 70.0,
+                "lamp at {} is not among the nearest",
+                lamp.x
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest_lamps_keeps_all_under_budget() {
+        let lamps = vec![Vec3::ZERO, Vec3::X];
+        assert_eq!(nearest_lamps(&lamps, Vec3::ZERO, 8).len(), 2);
+    }
+}
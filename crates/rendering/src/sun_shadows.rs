@@ -0,0 +1,168 @@
+//! Shadows cast by the sun.
+//!
+//! `day_night` moves the sun with the hour and season; this module decides
+//! whether it casts shadows and at what quality. Shadows use cascaded shadow
+//! maps sized by `ShadowQuality`, and are switched off while the sun is below
+//! the horizon and once the satellite view has mostly replaced the 3D city,
+//! where they would cost frame time without being seen.
+
+use bevy::pbr::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLightShadowMap};
+use bevy::prelude::*;
+
+use simulation::day_night_controls::DayNightControls;
+use simulation::time_of_day::GameClock;
+
+use crate::satellite_view::SatelliteView;
+
+/// Satellite blend above which the 3D city is mostly hidden and shadows are
+/// switched off.
+const SATELLITE_SHADOW_CUTOFF: f32 = 0.5;
+
+/// How detailed sun shadows are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShadowQuality {
+    Off,
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl ShadowQuality {
+    pub const ALL: [ShadowQuality; 4] = [
+        ShadowQuality::Off,
+        ShadowQuality::Low,
+        ShadowQuality::Medium,
+        ShadowQuality::High,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ShadowQuality::Off => "Off",
+            ShadowQuality::Low => "Low",
+            ShadowQuality::Medium => "Medium",
+            ShadowQuality::High => "High",
+        }
+    }
+
+    /// Number of shadow cascades; more keep nearby shadows sharp while still
+    /// covering the far city.
+    pub fn num_cascades(self) -> usize {
+        match self {
+            ShadowQuality::Off | ShadowQuality::Low => 1,
+            ShadowQuality::Medium => 2,
+            ShadowQuality::High => 4,
+        }
+    }
+
+    /// Distance from the camera beyond which nothing casts a shadow.
+    pub fn maximum_distance(self) -> f32 {
+        match self {
+            ShadowQuality::Off | ShadowQuality::Low => 1500.0,
+            ShadowQuality::Medium => 3000.0,
+            ShadowQuality::High => 4500.0,
+        }
+    }
+
+    /// Resolution of each shadow map.
+    pub fn map_size(self) -> usize {
+        match self {
+            ShadowQuality::Off | ShadowQuality::Low => 1024,
+            ShadowQuality::Medium => 2048,
+            ShadowQuality::High => 4096,
+        }
+    }
+
+    fn cascade_config(self) -> CascadeShadowConfig {
+        let maximum_distance = self.maximum_distance();
+        CascadeShadowConfigBuilder {
+            num_cascades: self.num_cascades(),
+            maximum_distance,
+            first_cascade_far_bound: maximum_distance / 6.0,
+            ..default()
+        }
+        .build()
+    }
+}
+
+/// Player's choice of sun shadow quality.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ShadowSettings {
+    pub quality: ShadowQuality,
+}
+
+/// Whether the sun should cast shadows right now.
+pub fn shadows_wanted(quality: ShadowQuality, sun_up: bool, satellite_blend: f32) -> bool {
+    quality != ShadowQuality::Off && sun_up && satellite_blend < SATELLITE_SHADOW_CUTOFF
+}
+
+/// Switch sun shadows on and off and rebuild the cascades when the quality
+/// setting changes.
+pub fn update_sun_shadows(
+    settings: Res<ShadowSettings>,
+    satellite: Res<SatelliteView>,
+    controls: Res<DayNightControls>,
+    clock: Res<GameClock>,
+    shadow_map: Option<ResMut<DirectionalLightShadowMap>>,
+    mut suns: Query<(&mut DirectionalLight, &mut CascadeShadowConfig)>,
+) {
+    let sun_up = (clock.sunrise()..clock.sunset()).contains(&controls.effective_hour());
+    let enabled = shadows_wanted(settings.quality, sun_up, satellite.blend);
+
+    if settings.is_changed() {
+        if let Some(mut shadow_map) = shadow_map {
+            shadow_map.size = settings.quality.map_size();
+        }
+    }
+
+    for (mut sun, mut cascades) in &mut suns {
+        if sun.shadows_enabled != enabled {
+            sun.shadows_enabled = enabled;
+        }
+        if settings.is_changed() {
+            *cascades = settings.quality.cascade_config();
+        }
+    }
+}
+
+pub struct SunShadowsPlugin;
+
+impl Plugin for SunShadowsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShadowSettings>().add_systems(
+            Update,
+            update_sun_shadows.after(crate::day_night::update_day_night_cycle),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_quality_covers_more_with_more_cascades() {
+        for pair in ShadowQuality::ALL[1..].windows(2) {
+            let (lower, higher) = (pair[0], pair[1]);
+            assert!(higher.num_cascades() > lower.num_cascades());
+            assert!(higher.maximum_distance() > lower.maximum_distance());
+            assert!(higher.map_size() > lower.map_size());
+        }
+    }
+
+    #[test]
+    fn test_shadows_only_in_daylight_outside_satellite_view() {
+        let q = ShadowQuality::Medium;
+        assert!(shadows_wanted(q, true, 0.0));
+        assert!(
+            shadows_wanted(q, true, 0.3),
+            "early in the zoom-out still casts"
+        );
+        assert!(!shadows_wanted(q, false, 0.0), "no sun shadows at night");
+        assert!(
+            !shadows_wanted(q, true, 0.9),
+            "satellite view skips shadows"
+        );
+        assert!(!shadows_wanted(ShadowQuality::Off, true, 0.0));
+    }
+}
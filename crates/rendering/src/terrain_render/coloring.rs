@@ -4,26 +4,20 @@ use simulation::colorblind::ColorblindMode;
 use simulation::config::{GRID_HEIGHT, GRID_WIDTH};
 use simulation::grid::{CellType, WorldGrid, ZoneType};
 use simulation::network_viz::NetworkVizData;
-use simulation::weather::Season;
 
-use crate::aqi_colors;
-use crate::color_ramps::{self, CIVIDIS, GROUNDWATER_LEVEL, GROUNDWATER_QUALITY, INFERNO, VIRIDIS};
+use crate::color_ramps;
 use crate::colorblind_palette;
 use crate::overlay::{DualOverlayMode, OverlayMode};
 
-use super::types::OverlayGrids;
-
 pub fn terrain_color(
     cell: &simulation::grid::Cell,
     gx: usize,
     gy: usize,
-    season: Season,
+    grass: [f32; 3],
     snow_depth: f32,
     cb_mode: ColorblindMode,
 ) -> Color {
-    // Per-cell noise for variation (no two cells look identical)
-    let noise = ((gx.wrapping_mul(7919).wrapping_add(gy.wrapping_mul(6271))) % 100) as f32 / 100.0;
-    let v = (noise - 0.5) * 0.04; // +/- 2% color variation
+    let v = cell_variation(gx, gy);
 
     let base_color = if cell.zone != ZoneType::None && cell.cell_type != CellType::Road {
         // Urban ground: light concrete/pavement tones (must contrast with dark road asphalt)
@@ -68,8 +62,8 @@ pub fn terrain_color(
                 )
             }
             CellType::Grass => {
-                // Grass color varies by season with per-cell noise variation
-                let [sr, sg, sb] = season.grass_color();
+                // Grass color follows the seasonal palette with per-cell noise variation
+                let [sr, sg, sb] = grass;
                 let elev = cell.elevation;
                 let patch =
                     ((gx.wrapping_mul(31).wrapping_add(gy.wrapping_mul(47))) % 100) as f32 / 100.0;
@@ -81,10 +75,30 @@ pub fn terrain_color(
         }
     };
 
-    // Snow overlay: blend toward white based on snow depth.
-    // Water cells don't get snow overlay. Full white at 6+ inches.
-    if snow_depth > 0.0 && cell.cell_type != CellType::Water {
-        let snow_factor = (snow_depth / 6.0).min(1.0);
+    // Water cells don't get snow overlay.
+    if cell.cell_type != CellType::Water {
+        apply_snow(base_color, gx, gy, snow_depth)
+    } else {
+        base_color
+    }
+}
+
+/// Per-cell noise for variation (no two cells look identical), +/- 2%.
+pub(super) fn cell_variation(gx: usize, gy: usize) -> f32 {
+    let noise = ((gx.wrapping_mul(7919).wrapping_add(gy.wrapping_mul(6271))) % 100) as f32 / 100.0;
+    (noise - 0.5) * 0.04
+}
+
+/// Share of the ground snow covers; full white at 6+ inches.
+pub(super) fn snow_cover(snow_depth: f32) -> f32 {
+    (snow_depth / 6.0).clamp(0.0, 1.0)
+}
+
+/// Snow overlay: blend toward white based on snow depth.
+pub(super) fn apply_snow(base_color: Color, gx: usize, gy: usize, snow_depth: f32) -> Color {
+    if snow_depth > 0.0 {
+        let v = cell_variation(gx, gy);
+        let snow_factor = snow_cover(snow_depth);
         // Snow white with slight blue tint and per-cell noise for variation
         let snow_r = 0.92 + v * 0.3;
         let snow_g = 0.94 + v * 0.2;
@@ -131,20 +145,18 @@ pub(super) fn blend_dual_overlays(
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Colour a cell for the overlays baked into the terrain mesh.  The other
+/// overlays are drawn by the heatmap shader and leave `base` alone.
 pub(super) fn apply_overlay(
     base: Color,
     cell: &simulation::grid::Cell,
     gx: usize,
     gy: usize,
-    _grid: &WorldGrid,
     overlay: &OverlayMode,
-    grids: &OverlayGrids,
     cb_mode: ColorblindMode,
     network_viz: &NetworkVizData,
 ) -> Color {
     match overlay {
-        OverlayMode::None => base,
         OverlayMode::Power => {
             if cell.cell_type == CellType::Water {
                 return base;
@@ -175,133 +187,7 @@ pub(super) fn apply_overlay(
                 color_ramps::overlay_binary(base, &palette, false)
             }
         }
-        OverlayMode::Traffic => {
-            if cell.cell_type == CellType::Road {
-                if let Some(traffic) = grids.traffic {
-                    let congestion = traffic.congestion_level(gx, gy);
-                    // Inferno: black (no traffic) -> red/orange -> yellow (gridlock)
-                    color_ramps::overlay_continuous(&INFERNO, congestion)
-                } else {
-                    base
-                }
-            } else {
-                color_ramps::darken(base, 0.5)
-            }
-        }
-        OverlayMode::Pollution => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(pollution) = grids.pollution {
-                let concentration = pollution.get(gx, gy);
-                // EPA AQI 6-tier color scheme (POLL-020)
-                aqi_colors::aqi_overlay_color(concentration)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::LandValue => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(land_value) = grids.land_value {
-                let value = land_value.get(gx, gy) as f32 / 255.0;
-                // Cividis: dark navy (low) -> yellow (high) -- CVD safe
-                color_ramps::overlay_continuous(&CIVIDIS, value)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::Education => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(education) = grids.education {
-                let level = education.get(gx, gy) as f32 / 3.0;
-                // Viridis: purple (uneducated) -> teal -> yellow (highly educated)
-                color_ramps::overlay_continuous(&VIRIDIS, level)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::Garbage => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(garbage) = grids.garbage {
-                let level = (garbage.get(gx, gy) as f32 / 30.0).clamp(0.0, 1.0);
-                // Inferno: dark (clean) -> bright (lots of garbage)
-                color_ramps::overlay_continuous(&INFERNO, level)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::Noise => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(noise) = grids.noise {
-                let level = (noise.get(gx, gy) as f32 / 100.0).clamp(0.0, 1.0);
-                // Inferno: black (quiet) -> red/orange -> yellow (loud)
-                color_ramps::overlay_continuous(&INFERNO, level)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::WaterPollution => {
-            if let Some(wp) = grids.water_pollution {
-                let level = (wp.get(gx, gy) as f32 / 255.0).clamp(0.0, 1.0);
-                if cell.cell_type == CellType::Water {
-                    // Viridis reversed: yellow (clean) -> teal -> purple (polluted)
-                    // Reverse t so clean water = bright, polluted = dark
-                    color_ramps::overlay_continuous(&VIRIDIS, 1.0 - level)
-                } else if level > 0.0 {
-                    // Land cells near polluted water get a subtle brown tint
-                    color_ramps::blend_tint(base, Color::srgba(0.5, 0.35, 0.15, level * 0.4))
-                } else {
-                    color_ramps::darken(base, 0.7)
-                }
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::GroundwaterLevel => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(gw) = grids.groundwater {
-                let level = gw.get(gx, gy);
-                let t = level as f32 / 255.0;
-                let color = color_ramps::overlay_continuous(&GROUNDWATER_LEVEL, t);
-                // Depletion warning: cells with level < 30% (~76) get a pulsing highlight
-                if level < 76 {
-                    // Blend toward warning orange for depleted cells
-                    let warning_intensity = (1.0 - level as f32 / 76.0) * 0.3;
-                    color_ramps::blend_tint(color, Color::srgba(1.0, 0.6, 0.0, warning_intensity))
-                } else {
-                    color
-                }
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::GroundwaterQuality => {
-            if cell.cell_type == CellType::Water {
-                return base;
-            }
-            if let Some(wq) = grids.water_quality {
-                let quality = wq.get(gx, gy);
-                let t = quality as f32 / 255.0;
-                color_ramps::overlay_continuous(&GROUNDWATER_QUALITY, t)
-            } else {
-                color_ramps::darken(base, 0.8)
-            }
-        }
-        OverlayMode::Wind => {
-            // Wind overlay uses gizmo streamlines, no terrain recolor needed.
-            // Slightly darken the terrain for contrast with the streamline particles.
-            color_ramps::darken(base, 0.7)
-        }
+        _ => base,
     }
 }
 
@@ -361,5 +247,12 @@ pub(super) fn coast_tint(
 }
 
 pub fn cell_color(cell: &simulation::grid::Cell) -> Color {
-    terrain_color(cell, 0, 0, Season::Spring, 0.0, ColorblindMode::Normal)
+    terrain_color(
+        cell,
+        0,
+        0,
+        simulation::weather::Season::Spring.grass_color(),
+        0.0,
+        ColorblindMode::Normal,
+    )
 }
@@ -7,14 +7,19 @@ use simulation::grid::{CellType, WorldGrid};
 use simulation::network_viz::NetworkVizData;
 use simulation::road_segments::RoadSegmentStore;
 use simulation::roads::RoadNetwork;
-use simulation::weather::Season;
 
 use crate::overlay::OverlayMode;
 
-use super::coloring::{apply_overlay, blend_dual_overlays, coast_tint, terrain_color};
+use super::coloring::{
+    apply_overlay, apply_snow, blend_dual_overlays, coast_tint, snow_cover, terrain_color,
+};
 use super::road_markings::add_road_markings;
+use super::splat::{is_splat_cell, splat_color, splat_uvs, ChunkSplat};
 use super::types::{DualOverlayInfo, OverlayGrids};
 
+/// Brightness of the terrain on map tiles the city does not own yet.
+pub(crate) const UNOWNED_TILE_SHADE: f32 = 0.6;
+
 pub(super) fn chunk_world_pos(cx: usize, cy: usize) -> (f32, f32) {
     let wx = cx as f32 * CHUNK_SIZE as f32 * CELL_SIZE;
     let wz = cy as f32 * CHUNK_SIZE as f32 * CELL_SIZE;
@@ -110,7 +115,7 @@ pub fn build_chunk_mesh(
     cy: usize,
     overlay: &OverlayMode,
     overlay_grids: &OverlayGrids,
-    season: Season,
+    grass: [f32; 3],
     cb_mode: ColorblindMode,
     network_viz: &NetworkVizData,
     dual: &DualOverlayInfo,
@@ -120,6 +125,15 @@ pub fn build_chunk_mesh(
     let mut normals: Vec<[f32; 3]> = Vec::with_capacity(cells_in_chunk * 4);
     let mut colors: Vec<[f32; 4]> = Vec::with_capacity(cells_in_chunk * 4);
     let mut indices: Vec<u32> = Vec::with_capacity(cells_in_chunk * 6);
+    let mut splat_uv0: Vec<[f32; 2]> = Vec::with_capacity(cells_in_chunk * 4);
+    let mut splat_uv1: Vec<[f32; 2]> = Vec::with_capacity(cells_in_chunk * 4);
+    let splat = ChunkSplat::new(
+        grid,
+        overlay_grids.groundwater,
+        overlay_grids.biomes,
+        cx,
+        cy,
+    );
 
     let base_gx = cx * CHUNK_SIZE;
     let base_gy = cy * CHUNK_SIZE;
@@ -135,49 +149,70 @@ pub fn build_chunk_mesh(
 
             let cell = grid.get(gx, gy);
             let snow_depth = overlay_grids.snow.map(|sg| sg.get(gx, gy)).unwrap_or(0.0);
-            let base_color = terrain_color(cell, gx, gy, season, snow_depth, cb_mode);
-            let color = if dual.is_active(overlay) {
-                let primary_color = apply_overlay(
-                    base_color,
-                    cell,
-                    gx,
-                    gy,
-                    grid,
-                    overlay,
-                    overlay_grids,
-                    cb_mode,
-                    network_viz,
-                );
-                let secondary_color = apply_overlay(
-                    base_color,
-                    cell,
-                    gx,
-                    gy,
-                    grid,
-                    &dual.secondary,
-                    overlay_grids,
-                    cb_mode,
-                    network_viz,
-                );
-                blend_dual_overlays(
-                    primary_color,
-                    secondary_color,
-                    gx,
-                    &dual.mode,
-                    dual.blend_factor,
-                )
+            let splat_cell = is_splat_cell(cell);
+
+            // Open ground blends the splat layers per corner; everything
+            // else takes one flat colour.
+            let (corner_bases, corner_weights) = if splat_cell {
+                let seasonal = terrain_color(cell, gx, gy, grass, 0.0, cb_mode).to_srgba();
+                let seasonal = [seasonal.red, seasonal.green, seasonal.blue];
+                let weights = splat.corners(gx, gy);
+                let bases = weights.map(|w| {
+                    let [r, g, b] = splat_color(&w, seasonal);
+                    apply_snow(Color::srgb(r, g, b), gx, gy, snow_depth)
+                });
+                (bases, weights)
             } else {
-                apply_overlay(
-                    base_color,
-                    cell,
-                    gx,
-                    gy,
-                    grid,
-                    overlay,
-                    overlay_grids,
-                    cb_mode,
-                    network_viz,
-                )
+                let base = terrain_color(cell, gx, gy, grass, snow_depth, cb_mode);
+                ([base; 4], [[0.0; 4]; 4])
+            };
+
+            let finish = |base_color: Color| -> [f32; 4] {
+                let color = if dual.is_active(overlay) {
+                    let primary_color =
+                        apply_overlay(base_color, cell, gx, gy, overlay, cb_mode, network_viz);
+                    let secondary_color = apply_overlay(
+                        base_color,
+                        cell,
+                        gx,
+                        gy,
+                        &dual.secondary,
+                        cb_mode,
+                        network_viz,
+                    );
+                    blend_dual_overlays(
+                        primary_color,
+                        secondary_color,
+                        gx,
+                        &dual.mode,
+                        dual.blend_factor,
+                    )
+                } else {
+                    apply_overlay(base_color, cell, gx, gy, overlay, cb_mode, network_viz)
+                };
+
+                let c: [f32; 4] = color.to_srgba().to_f32_array();
+
+                // Cheap coastline blending: tint cells adjacent to water.
+                // Open ground gets its shore from the sand and soil layers.
+                let c = if cell.cell_type != CellType::Road && !splat_cell {
+                    coast_tint(grid, gx, gy, c, cell.cell_type)
+                } else {
+                    c
+                };
+
+                // Shade map tiles the city has not bought yet
+                if overlay_grids.map_tiles.is_some_and(|t| !t.contains(gx, gy)) {
+                    let s = UNOWNED_TILE_SHADE;
+                    [c[0] * s, c[1] * s, c[2] * s, c[3]]
+                } else {
+                    c
+                }
+            };
+            let corner_colors = if splat_cell {
+                corner_bases.map(finish)
+            } else {
+                [finish(corner_bases[0]); 4]
             };
 
             let x0 = lx as f32 * CELL_SIZE;
@@ -188,15 +223,6 @@ pub fn build_chunk_mesh(
             // Heightmap: compute per-corner Y from elevation grid
             let [y_tl, y_tr, y_br, y_bl] = cell_corner_heights(grid, gx, gy);
 
-            let c: [f32; 4] = color.to_srgba().to_f32_array();
-
-            // Cheap coastline blending: tint cells adjacent to water
-            let c = if cell.cell_type != CellType::Road {
-                coast_tint(grid, gx, gy, c, cell.cell_type)
-            } else {
-                c
-            };
-
             // 4 vertices: TL, TR, BR, BL
             let vi = positions.len() as u32;
             let p_tl = [x0, y_tl, z0];
@@ -221,7 +247,19 @@ pub fn build_chunk_mesh(
             normals.push(n_br);  // BR (shared by both tris)
             normals.push(n2);    // BL (only tri 1)
 
-            colors.extend_from_slice(&[c; 4]);
+            colors.extend_from_slice(&corner_colors);
+
+            // Snow hides the layer detail along with the layer colours.
+            let strength = if splat_cell {
+                1.0 - snow_cover(snow_depth)
+            } else {
+                0.0
+            };
+            for weights in &corner_weights {
+                let (uv0, uv1) = splat_uvs(weights, strength);
+                splat_uv0.push(uv0);
+                splat_uv1.push(uv1);
+            }
 
             // Two triangles: TL-BR-TR and TL-BL-BR
             indices.push(vi);
@@ -252,7 +290,9 @@ pub fn build_chunk_mesh(
         }
     }
 
-    let uvs: Vec<[f32; 2]> = vec![[0.0, 0.0]; positions.len()];
+    // Road surfaces and markings carry no splat detail.
+    splat_uv0.resize(positions.len(), [0.0, 0.0]);
+    splat_uv1.resize(positions.len(), [0.0, 0.0]);
     Mesh::new(
         bevy::render::mesh::PrimitiveTopology::TriangleList,
         bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD
@@ -261,7 +301,8 @@ pub fn build_chunk_mesh(
     .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
     .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
     .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
-    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, splat_uv0)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_1, splat_uv1)
     .with_inserted_indices(Indices::U32(indices))
 }
 
@@ -2,11 +2,19 @@ mod coloring;
 mod lane_markings;
 mod mesh;
 mod road_markings;
+mod splat;
+mod splat_textures;
 mod systems;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 pub use coloring::cell_color;
 pub use mesh::build_chunk_mesh;
+pub(crate) use mesh::UNOWNED_TILE_SHADE;
+pub use splat::{LAYER_GRASS, LAYER_ROCK, LAYER_SAND, LAYER_SOIL};
+pub use splat_textures::splat_layer_image;
 pub use systems::{
     dirty_chunks_on_overlay_change, mark_all_chunks_dirty, mark_chunk_dirty_at,
     rebuild_dirty_chunks, spawn_terrain_chunks,
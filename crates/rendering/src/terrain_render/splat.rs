@@ -0,0 +1,304 @@
+//! Splat weights of the natural terrain layers.
+//!
+//! Open ground is drawn from four tiling layers: sand, grass, rock and wet
+//! soil. Each cell gets a weight per layer from its elevation and slope,
+//! its biome, the groundwater under it and how close it is to water; sand
+//! lines the coast and rock covers steep slopes and mountains, while wet
+//! soil follows river banks and waterlogged ground. Grass fills the rest.
+//! Corner weights average the cells around each vertex so the layers blend
+//! smoothly across cell borders.
+
+use simulation::config::{
+    CELL_SIZE, CHUNK_SIZE, GRID_HEIGHT, GRID_WIDTH, TERRAIN_HEIGHT_SCALE, WATER_THRESHOLD,
+};
+use simulation::grid::{Cell, CellType, WorldGrid, ZoneType};
+use simulation::groundwater::GroundwaterGrid;
+use simulation::terrain_generation::{Biome, BiomeGrid};
+
+/// Weight of each layer, indexed by the `LAYER_*` constants. Sums to one.
+pub type SplatWeights = [f32; 4];
+
+pub const LAYER_SAND: usize = 0;
+pub const LAYER_GRASS: usize = 1;
+pub const LAYER_ROCK: usize = 2;
+pub const LAYER_SOIL: usize = 3;
+
+/// Cells from the water's edge that sand or wet soil reaches.
+pub const SHORE_REACH: usize = 3;
+
+/// Widest stretch of water, in cells, still treated as a river rather than
+/// open water.
+const RIVER_MAX_WIDTH: usize = 4;
+
+/// Rise over run where rock starts to show, and where it covers the ground.
+const ROCK_SLOPE_START: f32 = 0.25;
+const ROCK_SLOPE_FULL: f32 = 0.6;
+
+/// Elevations where bare rock takes over from grass regardless of slope.
+const ROCK_ELEVATION_START: f32 = 0.75;
+const ROCK_ELEVATION_FULL: f32 = 0.9;
+
+/// Height above the water line over which beaches give way to grass.
+const BEACH_HEIGHT: f32 = 0.12;
+
+/// Groundwater saturation where the ground turns to wet soil.
+const WET_GROUND_START: f32 = 0.7;
+const WET_GROUND_FULL: f32 = 0.95;
+
+/// Most of a cell waterlogged ground alone turns to soil.
+const WET_GROUND_SOIL: f32 = 0.6;
+
+/// Average colour of the sand, rock and soil layers (sRGB). Grass takes
+/// the seasonal palette.
+const SAND_COLOR: [f32; 3] = [0.74, 0.68, 0.50];
+const ROCK_COLOR: [f32; 3] = [0.46, 0.44, 0.41];
+const SOIL_COLOR: [f32; 3] = [0.31, 0.25, 0.18];
+
+/// Nearest water seen from a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shore {
+    /// Cells to the water, 1 for a cell on the water's edge.
+    pub distance: usize,
+    /// Whether the water is a river with a bank on the far side.
+    pub river: bool,
+}
+
+/// Everything the layer weights of one cell are chosen from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplatInputs {
+    pub elevation: f32,
+    /// Steepest rise to a neighbouring cell, as rise over run.
+    pub slope: f32,
+    /// Nearest water within `SHORE_REACH` cells.
+    pub shore: Option<Shore>,
+    /// Groundwater saturation, 0 (dry) to 1 (saturated).
+    pub moisture: f32,
+    pub biome: Option<Biome>,
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Whether a cell is drawn from the splat layers. Water, roads and zoned
+/// ground keep their flat colours.
+pub fn is_splat_cell(cell: &Cell) -> bool {
+    cell.cell_type == CellType::Grass && cell.zone == ZoneType::None
+}
+
+/// Layer weights for a cell. Rock wins over sand, sand over wet soil and
+/// grass takes whatever is left.
+pub fn splat_weights(inputs: &SplatInputs) -> SplatWeights {
+    let mut rock = smoothstep(ROCK_SLOPE_START, ROCK_SLOPE_FULL, inputs.slope).max(smoothstep(
+        ROCK_ELEVATION_START,
+        ROCK_ELEVATION_FULL,
+        inputs.elevation,
+    ));
+
+    let lowland = 1.0 - smoothstep(0.0, BEACH_HEIGHT, inputs.elevation - WATER_THRESHOLD);
+    let (mut sand, mut soil) = match inputs.shore {
+        Some(shore) => {
+            let near = 1.0 - (shore.distance - 1) as f32 / SHORE_REACH as f32;
+            if shore.river {
+                (0.0, near)
+            } else {
+                (near * lowland, 0.0)
+            }
+        }
+        None => (0.0, 0.0),
+    };
+    soil += smoothstep(WET_GROUND_START, WET_GROUND_FULL, inputs.moisture) * WET_GROUND_SOIL;
+
+    match inputs.biome {
+        Some(Biome::Beach) if !inputs.shore.is_some_and(|s| s.river) => sand = sand.max(0.8),
+        Some(Biome::Mountain) => rock = rock.max(0.7),
+        Some(Biome::Highland) => rock = rock.max(0.3),
+        Some(Biome::Forest) => soil += 0.15,
+        _ => {}
+    }
+
+    let rock = rock.clamp(0.0, 1.0);
+    let sand = sand.clamp(0.0, 1.0 - rock);
+    let soil = soil.clamp(0.0, 1.0 - rock - sand);
+    let grass = 1.0 - rock - sand - soil;
+    let mut weights = [0.0; 4];
+    weights[LAYER_SAND] = sand;
+    weights[LAYER_GRASS] = grass;
+    weights[LAYER_ROCK] = rock;
+    weights[LAYER_SOIL] = soil;
+    weights
+}
+
+/// Walk the four cardinal directions for the nearest water. Water with land
+/// again within `RIVER_MAX_WIDTH` cells is a river; water running on past
+/// that or off the map is open water.
+fn find_shore(grid: &WorldGrid, gx: usize, gy: usize) -> Option<Shore> {
+    const DIRS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    let cell_at = |step: usize, (dx, dy): (i32, i32)| {
+        let x = gx as i32 + dx * step as i32;
+        let y = gy as i32 + dy * step as i32;
+        if x < 0 || y < 0 || x >= GRID_WIDTH as i32 || y >= GRID_HEIGHT as i32 {
+            None
+        } else {
+            Some(grid.get(x as usize, y as usize).cell_type)
+        }
+    };
+    for distance in 1..=SHORE_REACH {
+        for dir in DIRS {
+            if cell_at(distance, dir) != Some(CellType::Water) {
+                continue;
+            }
+            let width = (distance..distance + RIVER_MAX_WIDTH + 1)
+                .take_while(|&step| cell_at(step, dir) == Some(CellType::Water))
+                .count();
+            let far_bank = cell_at(distance + width, dir);
+            let river = width <= RIVER_MAX_WIDTH && far_bank.is_some();
+            return Some(Shore { distance, river });
+        }
+    }
+    None
+}
+
+/// Gather the splat inputs of a cell.
+pub fn cell_splat_inputs(
+    grid: &WorldGrid,
+    groundwater: Option<&GroundwaterGrid>,
+    biomes: Option<&BiomeGrid>,
+    gx: usize,
+    gy: usize,
+) -> SplatInputs {
+    let elevation = grid.get(gx, gy).elevation;
+    // Water counts at its surface so banks are not mistaken for cliffs.
+    let surface = |x: usize, y: usize| grid.get(x, y).elevation.max(WATER_THRESHOLD);
+    let here = surface(gx, gy);
+    let mut rise: f32 = 0.0;
+    if gx > 0 {
+        rise = rise.max((surface(gx - 1, gy) - here).abs());
+    }
+    if gx + 1 < GRID_WIDTH {
+        rise = rise.max((surface(gx + 1, gy) - here).abs());
+    }
+    if gy > 0 {
+        rise = rise.max((surface(gx, gy - 1) - here).abs());
+    }
+    if gy + 1 < GRID_HEIGHT {
+        rise = rise.max((surface(gx, gy + 1) - here).abs());
+    }
+
+    SplatInputs {
+        elevation,
+        slope: rise * TERRAIN_HEIGHT_SCALE / CELL_SIZE,
+        shore: find_shore(grid, gx, gy),
+        moisture: groundwater.map_or(0.5, |g| g.get(gx, gy) as f32 / 255.0),
+        biome: biomes
+            .filter(|b| b.width == GRID_WIDTH && b.height == GRID_HEIGHT)
+            .map(|b| b.get(gx, gy)),
+    }
+}
+
+/// Side of the block of cell weights a chunk needs: the chunk plus a one
+/// cell border for the corners on its edge.
+const BLOCK: usize = CHUNK_SIZE + 2;
+
+/// Splat weights of a chunk's cells and the ring of cells around it.
+/// Cells that are not drawn from the layers, or lie off the map, are `None`.
+pub struct ChunkSplat {
+    base_gx: usize,
+    base_gy: usize,
+    cells: Vec<Option<SplatWeights>>,
+}
+
+impl ChunkSplat {
+    pub fn new(
+        grid: &WorldGrid,
+        groundwater: Option<&GroundwaterGrid>,
+        biomes: Option<&BiomeGrid>,
+        cx: usize,
+        cy: usize,
+    ) -> Self {
+        let base_gx = cx * CHUNK_SIZE;
+        let base_gy = cy * CHUNK_SIZE;
+        let mut cells = vec![None; BLOCK * BLOCK];
+        for by in 0..BLOCK {
+            for bx in 0..BLOCK {
+                let (Some(gx), Some(gy)) =
+                    ((base_gx + bx).checked_sub(1), (base_gy + by).checked_sub(1))
+                else {
+                    continue;
+                };
+                if gx >= GRID_WIDTH || gy >= GRID_HEIGHT || !is_splat_cell(grid.get(gx, gy)) {
+                    continue;
+                }
+                let inputs = cell_splat_inputs(grid, groundwater, biomes, gx, gy);
+                cells[by * BLOCK + bx] = Some(splat_weights(&inputs));
+            }
+        }
+        Self {
+            base_gx,
+            base_gy,
+            cells,
+        }
+    }
+
+    fn cell(&self, gx: i64, gy: i64) -> Option<SplatWeights> {
+        let bx = gx - self.base_gx as i64 + 1;
+        let by = gy - self.base_gy as i64 + 1;
+        if bx < 0 || by < 0 || bx >= BLOCK as i64 || by >= BLOCK as i64 {
+            return None;
+        }
+        self.cells[by as usize * BLOCK + bx as usize]
+    }
+
+    /// Weights at the corners of a splat cell, in `cell_corner_heights`
+    /// order (top-left, top-right, bottom-right, bottom-left). Each corner
+    /// averages the splat cells that share it.
+    pub fn corners(&self, gx: usize, gy: usize) -> [SplatWeights; 4] {
+        let (x, y) = (gx as i64, gy as i64);
+        let own = self.cell(x, y).unwrap_or([0.0, 1.0, 0.0, 0.0]);
+        let corner = |dx: i64, dy: i64| {
+            let mut sum = [0.0; 4];
+            let mut count = 0.0;
+            for (nx, ny) in [(x, y), (x + dx, y), (x, y + dy), (x + dx, y + dy)] {
+                if let Some(w) = self.cell(nx, ny) {
+                    for (s, v) in sum.iter_mut().zip(w) {
+                        *s += v;
+                    }
+                    count += 1.0;
+                }
+            }
+            if count == 0.0 {
+                own
+            } else {
+                sum.map(|s| s / count)
+            }
+        };
+        [corner(-1, -1), corner(1, -1), corner(1, 1), corner(-1, 1)]
+    }
+}
+
+/// Average colour of the blended layers, `grass` being the seasonal grass.
+pub fn splat_color(weights: &SplatWeights, grass: [f32; 3]) -> [f32; 3] {
+    let mut color = [0.0; 3];
+    for (layer, layer_color) in [
+        (LAYER_SAND, SAND_COLOR),
+        (LAYER_GRASS, grass),
+        (LAYER_ROCK, ROCK_COLOR),
+        (LAYER_SOIL, SOIL_COLOR),
+    ] {
+        for (c, l) in color.iter_mut().zip(layer_color) {
+            *c += weights[layer] * l;
+        }
+    }
+    color
+}
+
+/// Splat data packed into the two UV channels of a terrain vertex:
+/// `UV_0` carries the sand and rock weights, `UV_1` the soil weight and how
+/// strongly the layer textures show. Grass is what the three leave.
+pub fn splat_uvs(weights: &SplatWeights, strength: f32) -> ([f32; 2], [f32; 2]) {
+    (
+        [weights[LAYER_SAND], weights[LAYER_ROCK]],
+        [weights[LAYER_SOIL], strength],
+    )
+}
@@ -0,0 +1,118 @@
+//! Tiling detail textures of the splat layers.
+//!
+//! The layers' colours come from the terrain mesh; these textures carry
+//! only their surface: fine grain and ripples on sand, blades on grass,
+//! blocky cracked rock and clumpy soil. Texels are brightness factors with
+//! 0.5 standing for 1.0, so the shader scales the vertex colour by twice
+//! the sample. The textures are generated rather than loaded and wrap
+//! seamlessly.
+
+use bevy::image::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor};
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use super::splat::{LAYER_GRASS, LAYER_ROCK, LAYER_SAND, LAYER_SOIL};
+
+/// Side of each detail texture in texels.
+pub const SPLAT_TEXTURE_SIZE: usize = 128;
+
+/// Tileable value noise in 0..1 with `period` lattice cells across the
+/// texture.
+fn tile_noise(u: f32, v: f32, period: u32, seed: u32) -> f32 {
+    let hash = |x: u32, y: u32| {
+        let mut h = (x % period)
+            .wrapping_mul(374_761_393)
+            .wrapping_add((y % period).wrapping_mul(668_265_263))
+            .wrapping_add(seed.wrapping_mul(2_147_483_647));
+        h = (h ^ (h >> 13)).wrapping_mul(1_274_126_177);
+        (h ^ (h >> 16)) as f32 / u32::MAX as f32
+    };
+    let x = u * period as f32;
+    let y = v * period as f32;
+    let (x0, y0) = (x.floor() as u32, y.floor() as u32);
+    let fx = x.fract();
+    let fy = y.fract();
+    let sx = fx * fx * (3.0 - 2.0 * fx);
+    let sy = fy * fy * (3.0 - 2.0 * fy);
+    let top = hash(x0, y0) + (hash(x0 + 1, y0) - hash(x0, y0)) * sx;
+    let bottom = hash(x0, y0 + 1) + (hash(x0 + 1, y0 + 1) - hash(x0, y0 + 1)) * sx;
+    top + (bottom - top) * sy
+}
+
+/// Brightness factor and per-channel shift of a layer at (u, v).
+fn layer_texel(layer: usize, u: f32, v: f32) -> [f32; 3] {
+    let tau = std::f32::consts::TAU;
+    match layer {
+        LAYER_SAND => {
+            let grain = tile_noise(u, v, 64, 1) - 0.5;
+            let ripple = (tau * (u * 6.0 + tile_noise(u, v, 4, 2) * 0.8)).sin();
+            let b = 1.0 + grain * 0.16 + ripple * 0.05;
+            [b, b, b * 0.98]
+        }
+        LAYER_GRASS => {
+            let patch = tile_noise(u, v, 8, 3) - 0.5;
+            let blades = tile_noise(u, v, 64, 4) - 0.5;
+            let b = 1.0 + patch * 0.25 + blades * 0.2;
+            [b * 1.02, b, b * 0.96]
+        }
+        LAYER_ROCK => {
+            let blocks = tile_noise(u, v, 6, 5) - 0.5;
+            let grain = tile_noise(u, v, 48, 6) - 0.5;
+            let crack = tile_noise(u, v, 16, 7);
+            let crack = if (crack - 0.5).abs() < 0.03 {
+                -0.3
+            } else {
+                0.0
+            };
+            let b = 1.0 + blocks * 0.35 + grain * 0.15 + crack;
+            [b, b, b]
+        }
+        LAYER_SOIL => {
+            let clumps = tile_noise(u, v, 16, 8) - 0.5;
+            let specks = tile_noise(u, v, 64, 9);
+            let speck = if specks > 0.85 { -0.2 } else { 0.0 };
+            let b = 1.0 + clumps * 0.3 + speck;
+            [b, b * 0.97, b * 0.94]
+        }
+        _ => [1.0; 3],
+    }
+}
+
+/// RGBA8 texels of one layer's detail texture.
+pub fn splat_layer_texels(layer: usize) -> Vec<u8> {
+    let size = SPLAT_TEXTURE_SIZE;
+    let mut data = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let u = x as f32 / size as f32;
+            let v = y as f32 / size as f32;
+            for channel in layer_texel(layer, u, v) {
+                data.push(((channel * 0.5).clamp(0.0, 1.0) * 255.0).round() as u8);
+            }
+            data.push(u8::MAX);
+        }
+    }
+    data
+}
+
+/// Repeating detail texture of one layer.
+pub fn splat_layer_image(layer: usize) -> Image {
+    let mut image = Image::new(
+        Extent3d {
+            width: SPLAT_TEXTURE_SIZE as u32,
+            height: SPLAT_TEXTURE_SIZE as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        splat_layer_texels(layer),
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        address_mode_u: ImageAddressMode::Repeat,
+        address_mode_v: ImageAddressMode::Repeat,
+        ..ImageSamplerDescriptor::linear()
+    });
+    image
+}
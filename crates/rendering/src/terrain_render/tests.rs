@@ -0,0 +1,191 @@
+//! Unit tests for the terrain splat layers.
+
+use simulation::config::{CHUNK_SIZE, WATER_THRESHOLD};
+use simulation::grid::{CellType, WorldGrid, ZoneType};
+use simulation::groundwater::GroundwaterGrid;
+
+use super::splat::{
+    cell_splat_inputs, is_splat_cell, splat_weights, ChunkSplat, Shore, SplatInputs, LAYER_GRASS,
+    LAYER_ROCK, LAYER_SAND, LAYER_SOIL,
+};
+use super::splat_textures::{splat_layer_texels, SPLAT_TEXTURE_SIZE};
+
+fn flat_grid(elevation: f32) -> WorldGrid {
+    let mut grid = WorldGrid::default();
+    for cell in grid.cells.iter_mut() {
+        cell.elevation = elevation;
+    }
+    grid
+}
+
+fn inputs() -> SplatInputs {
+    SplatInputs {
+        elevation: 0.5,
+        slope: 0.0,
+        shore: None,
+        moisture: 0.5,
+        biome: None,
+    }
+}
+
+fn assert_sums_to_one(weights: &[f32; 4]) {
+    let sum: f32 = weights.iter().sum();
+    assert!((sum - 1.0).abs() < 1e-5, "weights {weights:?} sum to {sum}");
+    assert!(
+        weights.iter().all(|&w| w >= 0.0),
+        "negative weight in {weights:?}"
+    );
+}
+
+#[test]
+fn test_flat_dry_ground_is_grass() {
+    let weights = splat_weights(&inputs());
+    assert_eq!(weights[LAYER_GRASS], 1.0);
+}
+
+#[test]
+fn test_steep_slopes_are_rock() {
+    let weights = splat_weights(&SplatInputs {
+        slope: 1.0,
+        ..inputs()
+    });
+    assert_eq!(weights[LAYER_ROCK], 1.0);
+    assert_sums_to_one(&weights);
+}
+
+#[test]
+fn test_coast_is_sand_and_river_bank_is_soil() {
+    let low = WATER_THRESHOLD + 0.02;
+    let coast = splat_weights(&SplatInputs {
+        elevation: low,
+        shore: Some(Shore {
+            distance: 1,
+            river: false,
+        }),
+        ..inputs()
+    });
+    assert!(coast[LAYER_SAND] > 0.8);
+    assert_eq!(coast[LAYER_SOIL], 0.0);
+
+    let bank = splat_weights(&SplatInputs {
+        elevation: low,
+        shore: Some(Shore {
+            distance: 1,
+            river: true,
+        }),
+        ..inputs()
+    });
+    assert_eq!(bank[LAYER_SAND], 0.0);
+    assert!(bank[LAYER_SOIL] > 0.8);
+    assert_sums_to_one(&bank);
+}
+
+#[test]
+fn test_saturated_ground_turns_to_soil() {
+    let wet = splat_weights(&SplatInputs {
+        moisture: 1.0,
+        ..inputs()
+    });
+    assert!(wet[LAYER_SOIL] > 0.5);
+    assert_sums_to_one(&wet);
+}
+
+#[test]
+fn test_rock_takes_priority() {
+    let weights = splat_weights(&SplatInputs {
+        elevation: 0.95,
+        shore: Some(Shore {
+            distance: 1,
+            river: true,
+        }),
+        moisture: 1.0,
+        ..inputs()
+    });
+    assert_eq!(weights[LAYER_ROCK], 1.0);
+    assert_sums_to_one(&weights);
+}
+
+#[test]
+fn test_narrow_water_is_a_river() {
+    let mut grid = flat_grid(0.5);
+    for y in 0..grid.height {
+        grid.get_mut(50, y).cell_type = CellType::Water;
+    }
+    // The sea fills everything west of x = 10.
+    for y in 0..grid.height {
+        for x in 0..10 {
+            grid.get_mut(x, y).cell_type = CellType::Water;
+        }
+    }
+
+    let bank = cell_splat_inputs(&grid, None, None, 48, 20);
+    assert_eq!(
+        bank.shore,
+        Some(Shore {
+            distance: 2,
+            river: true
+        })
+    );
+    let beach = cell_splat_inputs(&grid, None, None, 10, 20);
+    assert_eq!(
+        beach.shore,
+        Some(Shore {
+            distance: 1,
+            river: false
+        })
+    );
+    assert_eq!(cell_splat_inputs(&grid, None, None, 30, 20).shore, None);
+}
+
+#[test]
+fn test_groundwater_sets_moisture() {
+    let grid = flat_grid(0.5);
+    let mut groundwater = GroundwaterGrid::default();
+    groundwater.set(5, 5, 255);
+    let inputs = cell_splat_inputs(&grid, Some(&groundwater), None, 5, 5);
+    assert_eq!(inputs.moisture, 1.0);
+}
+
+#[test]
+fn test_only_open_ground_is_splatted() {
+    let mut grid = flat_grid(0.5);
+    grid.get_mut(1, 1).zone = ZoneType::Industrial;
+    grid.get_mut(2, 1).cell_type = CellType::Road;
+    assert!(is_splat_cell(grid.get(0, 1)));
+    assert!(!is_splat_cell(grid.get(1, 1)));
+    assert!(!is_splat_cell(grid.get(2, 1)));
+}
+
+#[test]
+fn test_corners_blend_neighbouring_cells() {
+    let mut grid = flat_grid(0.5);
+    // A cliff between x = 3 and x = 4 makes both cells rock.
+    for y in 0..CHUNK_SIZE {
+        for x in 4..CHUNK_SIZE {
+            grid.get_mut(x, y).elevation = 0.7;
+        }
+    }
+    let splat = ChunkSplat::new(&grid, None, None, 0, 0);
+    let [tl, tr, _, _] = splat.corners(2, 2);
+    // The west corners of cell 2 only touch grass.
+    assert_eq!(tl[LAYER_GRASS], 1.0);
+    // The east corners are shared with the rocky cell 3.
+    assert!(tr[LAYER_ROCK] > 0.0 && tr[LAYER_ROCK] < 1.0);
+    assert_sums_to_one(&tr);
+}
+
+#[test]
+fn test_detail_textures_tile_seamlessly() {
+    let size = SPLAT_TEXTURE_SIZE;
+    for layer in [LAYER_SAND, LAYER_GRASS, LAYER_ROCK, LAYER_SOIL] {
+        let texels = splat_layer_texels(layer);
+        assert_eq!(texels.len(), size * size * 4);
+        // Opposite edges should differ no more than neighbouring texels.
+        let at = |x: usize, y: usize| texels[(y * size + x) * 4] as i32;
+        let seam = (0..size)
+            .map(|y| (at(size - 1, y) - at(0, y)).abs())
+            .max()
+            .unwrap();
+        assert!(seam < 64, "layer {layer} seam jumps by {seam}");
+    }
+}
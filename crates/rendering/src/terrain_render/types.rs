@@ -1,14 +1,19 @@
 use bevy::prelude::*;
 
+use simulation::coastal_erosion::ErosionGrid;
+use simulation::commute_grids::{CommuteTimeGrid, JobAccessGrid, TransitRidershipGrid};
 use simulation::education::EducationGrid;
 use simulation::garbage::GarbageGrid;
 use simulation::groundwater::{GroundwaterGrid, WaterQualityGrid};
 use simulation::land_value::LandValueGrid;
+use simulation::map_tiles::MapTiles;
 use simulation::noise::NoisePollutionGrid;
 use simulation::pollution::PollutionGrid;
 use simulation::snow::SnowGrid;
+use simulation::terrain_generation::BiomeGrid;
 use simulation::traffic::TrafficGrid;
 use simulation::water_pollution::WaterPollutionGrid;
+use simulation::wildlife::BiodiversityGrid;
 
 use crate::overlay::{DualOverlayMode, OverlayMode};
 
@@ -23,6 +28,15 @@ pub struct OverlayGrids<'a> {
     pub groundwater: Option<&'a GroundwaterGrid>,
     pub water_quality: Option<&'a WaterQualityGrid>,
     pub snow: Option<&'a SnowGrid>,
+    pub biodiversity: Option<&'a BiodiversityGrid>,
+    pub erosion: Option<&'a ErosionGrid>,
+    pub commute_time: Option<&'a CommuteTimeGrid>,
+    pub transit_ridership: Option<&'a TransitRidershipGrid>,
+    pub job_access: Option<&'a JobAccessGrid>,
+    /// Tiles the city owns; the rest of the map is shaded.
+    pub map_tiles: Option<&'a MapTiles>,
+    /// Biomes of the generated terrain, for the splat layers.
+    pub biomes: Option<&'a BiomeGrid>,
 }
 
 impl<'a> OverlayGrids<'a> {
@@ -38,11 +52,19 @@ impl<'a> OverlayGrids<'a> {
             groundwater: None,
             water_quality: None,
             snow: None,
+            biodiversity: None,
+            erosion: None,
+            commute_time: None,
+            transit_ridership: None,
+            job_access: None,
+            map_tiles: None,
+            biomes: None,
         }
     }
 }
 
 /// Parameters for dual-overlay blending/split passed into mesh building.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DualOverlayInfo {
     /// The secondary overlay mode (None = single overlay).
     pub secondary: OverlayMode,
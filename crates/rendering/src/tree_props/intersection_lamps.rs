@@ -6,7 +6,7 @@ use bevy::prelude::*;
 use simulation::config::CELL_SIZE;
 use simulation::grid::{CellType, WorldGrid};
 
-use crate::building_meshes::BuildingModelCache;
+use crate::building_meshes::{BuildingModelCache, InstancedModels};
 use crate::props::{PropEntity, PropsSpawned, StreetLamp};
 
 // =============================================================================
@@ -68,6 +68,7 @@ pub fn is_intersection(grid: &WorldGrid, gx: usize, gy: usize) -> bool {
 pub fn spawn_intersection_lamps(
     mut commands: Commands,
     model_cache: Res<BuildingModelCache>,
+    models: Res<InstancedModels>,
     grid: Res<WorldGrid>,
     mut spawned: ResMut<IntersectionLampsSpawned>,
     props_spawned: Res<PropsSpawned>,
@@ -120,15 +121,18 @@ pub fn spawn_intersection_lamps(
                 model_cache.get_prop(hash)
             };
 
-            commands.spawn((
-                PropEntity,
-                StreetLamp,
-                IntersectionLamp,
-                SceneRoot(scene_handle),
-                Transform::from_xyz(wx + off_x, grid.elevation_y(gx, gy), wz + off_z)
-                    .with_scale(Vec3::splat(INTERSECTION_LAMP_SCALE)),
-                Visibility::default(),
-            ));
+            models.spawn(
+                &mut commands,
+                scene_handle,
+                (
+                    PropEntity,
+                    StreetLamp,
+                    IntersectionLamp,
+                    Transform::from_xyz(wx + off_x, grid.elevation_y(gx, gy), wz + off_z)
+                        .with_scale(Vec3::splat(INTERSECTION_LAMP_SCALE)),
+                    Visibility::default(),
+                ),
+            );
         }
     }
 }
@@ -29,7 +29,7 @@ pub use intersection_lamps::{
 };
 pub use prop_lod::{should_show_prop, update_prop_lod};
 pub use seasonal_tint::{
-    blended_season_tint, season_tint, update_tree_seasonal_tint, LastTreeTintSeason,
+    blended_season_tint, season_tint, update_tree_seasonal_tint, LastTreeTint,
 };
 
 // =============================================================================
@@ -41,7 +41,7 @@ pub struct TreePropsPlugin;
 impl Plugin for TreePropsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<IntersectionLampsSpawned>()
-            .init_resource::<LastTreeTintSeason>()
+            .init_resource::<LastTreeTint>()
             .add_systems(
                 Update,
                 (
@@ -1,11 +1,13 @@
 //! Seasonal tree tinting -- modifies `StandardMaterial` base color of tree props
-//! to reflect the current season (spring, summer, autumn, winter).
+//! to follow the foliage colour of the `SeasonalPalette`, which blends between
+//! spring blossom, summer green, autumn and winter over the year.
 
 use bevy::prelude::*;
 
 use simulation::weather::Season;
 
 use crate::props::TreeProp;
+use crate::seasonal_palette::SeasonalPalette;
 
 // =============================================================================
 // Constants
@@ -25,10 +27,12 @@ const WINTER_TINT: Color = Color::srgb(0.55, 0.50, 0.40);
 // Resources
 // =============================================================================
 
-/// Tracks the last season for which tree tinting was applied,
-/// so we only update materials when the season changes.
+/// Number of trees tinted the last time tinting was applied, so newly
+/// planted trees pick up the current palette between palette changes.
 #[derive(Resource, Default)]
-pub struct LastTreeTintSeason(pub Option<u8>);
+pub struct LastTreeTint {
+    pub trees: Option<usize>,
+}
 
 // =============================================================================
 // Pure helper functions
@@ -74,32 +78,28 @@ pub fn blended_season_tint(season: Season, progress: f32) -> Color {
 
 /// Apply seasonal color tinting to all tree prop scene materials.
 ///
-/// When the season changes (tracked via `LastTreeTintSeason`), walks every
-/// `StandardMaterial` in the asset store and applies the seasonal tint to
-/// tree entities. Because tree meshes are shared GLB scenes whose materials
+/// Walks every tree entity's `StandardMaterial`s and applies the palette's
+/// foliage tint. Because tree meshes are shared GLB scenes whose materials
 /// are loaded from asset files, we tint via material base_color directly.
 ///
-/// This system is intentionally coarse-grained: it only runs when the season
-/// id changes, not every frame.
+/// This system is intentionally coarse-grained: it only runs when the
+/// palette changes (at most once a game day) or the number of trees does,
+/// not every frame.
 pub fn update_tree_seasonal_tint(
-    seasonal: Res<simulation::seasonal_rendering::SeasonalRenderingState>,
-    mut last_season: ResMut<LastTreeTintSeason>,
+    palette: Res<SeasonalPalette>,
+    mut last_tint: ResMut<LastTreeTint>,
     tree_query: Query<&Children, With<TreeProp>>,
     children_query: Query<&Children>,
     mesh_query: Query<&MeshMaterial3d<StandardMaterial>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let current_id = seasonal.current_season_id;
-
-    // Only update when season changes.
-    if last_season.0 == Some(current_id) {
+    let trees = tree_query.iter().count();
+    if !palette.is_changed() && last_tint.trees == Some(trees) {
         return;
     }
-    last_season.0 = Some(current_id);
+    last_tint.trees = Some(trees);
 
-    let season = seasonal.active_season();
-    let tint = season_tint(season);
-    let tint_srgba = tint.to_srgba();
+    let tint_srgba = palette.foliage.to_srgba();
 
     // Walk each tree entity -> children -> children (scenes have nested hierarchies)
     // and find all StandardMaterial handles to tint.
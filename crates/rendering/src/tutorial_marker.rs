@@ -0,0 +1,49 @@
+//! Map marker for tutorial steps that point at a grid cell.
+//!
+//! Draws a pulsing ring with a beacon line over the cell named by the
+//! current step's `cell` highlight, so the player can find it after the
+//! camera has moved there.
+
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::grid::WorldGrid;
+use simulation::tutorial_hints::TutorialUiHint;
+
+/// Height above ground at which the ring is drawn.
+const MARKER_Y: f32 = 2.0;
+
+/// Height of the beacon line above the ring.
+const BEACON_HEIGHT: f32 = 60.0;
+
+/// System: draw the marker over the highlighted cell, if any.
+fn draw_tutorial_marker(hint: Res<TutorialUiHint>, time: Res<Time>, mut gizmos: Gizmos) {
+    let Some((gx, gy)) = hint.cell() else {
+        return;
+    };
+    let (x, z) = WorldGrid::grid_to_world(gx, gy);
+    let pulse = (time.elapsed_secs() * 3.0).sin() * 0.5 + 0.5;
+    let color = Color::srgba(1.0, 0.78, 0.24, 0.55 + pulse * 0.45);
+
+    gizmos.circle(
+        Isometry3d::new(
+            Vec3::new(x, MARKER_Y, z),
+            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+        ),
+        CELL_SIZE * (0.8 + pulse * 0.4),
+        color,
+    );
+    gizmos.line(
+        Vec3::new(x, MARKER_Y, z),
+        Vec3::new(x, MARKER_Y + BEACON_HEIGHT, z),
+        color,
+    );
+}
+
+pub struct TutorialMarkerPlugin;
+
+impl Plugin for TutorialMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_tutorial_marker);
+    }
+}
@@ -0,0 +1,230 @@
+//! Underground cross-section view.
+//!
+//! Toggled with the underground view key binding (Shift+U by default). The
+//! terrain fades to a translucent shell, surface objects are hidden (see
+//! `satellite_view`), and the underground layer is drawn with gizmos at
+//! its real depth below each cell:
+//!
+//! - **Water and sewer mains** under roads as blue and brown pipes.
+//! - **Metro tunnels** in orange where cut-and-cover, purple where bored.
+//! - **Foundations** as grey piles, and **basement parking** as boxes.
+
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::grid::WorldGrid;
+use simulation::keybindings::KeyBindings;
+use simulation::underground::{
+    UndergroundKind, UndergroundLayer, BORED_TUNNEL_DEPTH_M, PIPE_DEPTH_M,
+};
+
+use crate::input::StatusMessage;
+use crate::overlay_heatmap::{OverlayHeatmap, TerrainMaterial};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// How far below the surface one metre of depth is drawn, in world units.
+/// Exaggerated so the shallow layers separate visually.
+const DEPTH_SCALE: f32 = 2.0;
+
+/// Terrain opacity with the view fully on.
+const FADED_TERRAIN_ALPHA: f32 = 0.25;
+
+/// Fraction of the fade covered per second when toggling.
+const FADE_SPEED: f32 = 3.0;
+
+/// Above this fade, surface objects are hidden.
+const HIDE_SURFACE_AT: f32 = 0.5;
+
+const WATER_MAIN_COLOR: Color = Color::srgb(0.2, 0.5, 1.0);
+const SEWER_MAIN_COLOR: Color = Color::srgb(0.55, 0.4, 0.2);
+const CUT_AND_COVER_COLOR: Color = Color::srgb(1.0, 0.55, 0.1);
+const BORED_TUNNEL_COLOR: Color = Color::srgb(0.7, 0.3, 1.0);
+const FOUNDATION_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+const PARKING_COLOR: Color = Color::srgb(0.85, 0.85, 0.5);
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// Whether the cross-section view is on, and how far it has faded in.
+#[derive(Resource, Default)]
+pub struct UndergroundView {
+    pub active: bool,
+    /// 0.0 = surface only, 1.0 = fully underground.
+    pub fade: f32,
+}
+
+impl UndergroundView {
+    /// Whether surface objects should be hidden.
+    pub fn hides_surface(&self) -> bool {
+        self.fade > HIDE_SURFACE_AT
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+pub struct UndergroundViewPlugin;
+
+impl Plugin for UndergroundViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UndergroundView>().add_systems(
+            Update,
+            (
+                toggle_underground_view,
+                update_fade,
+                fade_terrain,
+                draw_underground_layer,
+            )
+                .chain(),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+fn toggle_underground_view(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    bindings: Res<KeyBindings>,
+    mut view: ResMut<UndergroundView>,
+    mut status: ResMut<StatusMessage>,
+) {
+    let Some(keyboard) = keyboard else {
+        return;
+    };
+    if !bindings.toggle_underground_view.just_pressed(&keyboard) {
+        return;
+    }
+    view.active = !view.active;
+    let text = if view.active {
+        "Underground view on"
+    } else {
+        "Underground view off"
+    };
+    status.set(text, false);
+}
+
+fn update_fade(time: Res<Time>, mut view: ResMut<UndergroundView>) {
+    let target = if view.active { 1.0 } else { 0.0 };
+    if view.fade == target {
+        return;
+    }
+    let step = FADE_SPEED * time.delta_secs();
+    view.fade = if view.fade < target {
+        (view.fade + step).min(target)
+    } else {
+        (view.fade - step).max(target)
+    };
+}
+
+/// Make the terrain translucent while the view fades in, and opaque again
+/// once it has faded out.
+fn fade_terrain(
+    view: Res<UndergroundView>,
+    heatmap: Res<OverlayHeatmap>,
+    mut materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    if !view.is_changed() {
+        return;
+    }
+    // All terrain chunks share one material.
+    let Some(mat) = materials.get_mut(&heatmap.material) else {
+        return;
+    };
+    let alpha = 1.0 - (1.0 - FADED_TERRAIN_ALPHA) * view.fade;
+    mat.base.base_color.set_alpha(alpha);
+    mat.base.alpha_mode = if view.fade > 0.0 {
+        AlphaMode::Blend
+    } else {
+        AlphaMode::Opaque
+    };
+}
+
+/// World position `depth_m` below the centre of cell (`x`, `y`).
+fn below(grid: &WorldGrid, x: usize, y: usize, depth_m: f32) -> Vec3 {
+    let (wx, wz) = WorldGrid::grid_to_world(x, y);
+    Vec3::new(wx, grid.elevation_y(x, y) - depth_m * DEPTH_SCALE, wz)
+}
+
+/// Draw each feature of the underground layer at its depth. Linear
+/// features are joined to their east and south neighbours.
+fn draw_underground_layer(
+    view: Res<UndergroundView>,
+    layer: Res<UndergroundLayer>,
+    grid: Res<WorldGrid>,
+    mut gizmos: Gizmos,
+) {
+    if view.fade < 0.01 || layer.width != grid.width || layer.height != grid.height {
+        return;
+    }
+    let alpha = view.fade;
+
+    for y in 0..layer.height {
+        for x in 0..layer.width {
+            if !layer.is_occupied(x, y) {
+                continue;
+            }
+            let neighbours = [(x + 1, y), (x, y + 1)];
+
+            for (kind, depth, color) in [
+                (UndergroundKind::WaterMain, PIPE_DEPTH_M, WATER_MAIN_COLOR),
+                (
+                    UndergroundKind::SewerMain,
+                    PIPE_DEPTH_M + 1.0,
+                    SEWER_MAIN_COLOR,
+                ),
+            ] {
+                if !layer.has(x, y, kind) {
+                    continue;
+                }
+                for &(nx, ny) in &neighbours {
+                    if layer.has(nx, ny, kind) {
+                        gizmos.line(
+                            below(&grid, x, y, depth),
+                            below(&grid, nx, ny, depth),
+                            color.with_alpha(alpha),
+                        );
+                    }
+                }
+            }
+
+            if let Some(depth) = layer.tunnel_depth(x, y) {
+                let color = if depth >= BORED_TUNNEL_DEPTH_M {
+                    BORED_TUNNEL_COLOR
+                } else {
+                    CUT_AND_COVER_COLOR
+                };
+                for &(nx, ny) in &neighbours {
+                    if let Some(next) = layer.tunnel_depth(nx, ny) {
+                        gizmos.line(
+                            below(&grid, x, y, depth),
+                            below(&grid, nx, ny, next),
+                            color.with_alpha(alpha),
+                        );
+                    }
+                }
+            }
+
+            let foundation = layer.foundation_depth(x, y);
+            if foundation > 0.0 {
+                let top = below(&grid, x, y, 0.0);
+                let bottom = below(&grid, x, y, foundation);
+                if layer.has(x, y, UndergroundKind::Parking) {
+                    let size = Vec3::new(CELL_SIZE * 0.8, top.y - bottom.y, CELL_SIZE * 0.8);
+                    gizmos.cuboid(
+                        Transform::from_translation((top + bottom) * 0.5).with_scale(size),
+                        PARKING_COLOR.with_alpha(alpha),
+                    );
+                } else {
+                    gizmos.line(top, bottom, FOUNDATION_COLOR.with_alpha(alpha));
+                }
+            }
+        }
+    }
+}
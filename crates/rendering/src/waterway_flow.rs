@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::grid::WorldGrid;
+use simulation::waterways::WaterwayNetwork;
+
+use crate::input::ActiveTool;
+
+/// Draw the flow direction of carved waterways while the water tool is
+/// active. Arrows point towards the water each waterway drains into and
+/// turn red on waterways spilling over their banks.
+pub fn draw_waterway_flow(
+    tool: Res<ActiveTool>,
+    network: Res<WaterwayNetwork>,
+    grid: Res<WorldGrid>,
+    mut gizmos: Gizmos,
+) {
+    if *tool != ActiveTool::TerrainWater || network.bodies.is_empty() {
+        return;
+    }
+
+    let flowing = Color::srgba(0.3, 0.8, 1.0, 0.9);
+    let spilling = Color::srgba(1.0, 0.3, 0.2, 0.9);
+    let arrow_len = CELL_SIZE * 0.8;
+    let head_len = CELL_SIZE * 0.3;
+
+    for body in &network.bodies {
+        let color = if body.is_overflowing() {
+            spilling
+        } else {
+            flowing
+        };
+        for &idx in &body.cells {
+            let (x, y) = (idx % grid.width, idx / grid.width);
+            // Every third cell keeps wide waterways readable.
+            if (x + y) % 3 != 0 {
+                continue;
+            }
+            let Some((dx, dy)) = network.flow_at(x, y) else {
+                continue;
+            };
+            let dir = Vec2::new(dx as f32, dy as f32);
+            let (wx, wz) = WorldGrid::grid_to_world(x, y);
+            let center = Vec2::new(wx, wz);
+            let tip = center + dir * arrow_len * 0.5;
+            let base = center - dir * arrow_len * 0.5;
+            let head_base = tip - dir * head_len;
+            let perp = Vec2::new(-dir.y, dir.x) * head_len * 0.8;
+            let h = grid.elevation_y(x, y) + 0.3;
+            let at = |p: Vec2| Vec3::new(p.x, h, p.y);
+
+            gizmos.line(at(base), at(tip), color);
+            gizmos.line(at(head_base + perp), at(tip), color);
+            gizmos.line(at(head_base - perp), at(tip), color);
+        }
+    }
+}
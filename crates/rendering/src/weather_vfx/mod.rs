@@ -0,0 +1,46 @@
+//! Weather visual effects driven by `Weather`, `WindState` and the
+//! stormwater grid.
+//!
+//! - **Precipitation**: rain streaks, drifting snow and, in arid climates on
+//!   windy dry days, blowing sand, drawn with gizmos around the camera focus
+//! - **Haze**: sandstorms and heavy rain thicken the camera's distance fog
+//!   (see `day_night::update_fog_rendering`)
+//! - **Wet roads**: road materials darken and turn glossy while it rains and
+//!   dry out slowly afterwards
+//! - **Puddles**: dark decals on road and pavement cells where stormwater
+//!   runoff has pooled
+
+mod particles;
+mod puddles;
+mod state;
+#[cfg(test)]
+mod tests;
+mod wet_roads;
+
+pub use particles::draw_precipitation;
+pub use puddles::{sync_puddle_decals, PuddleDecal};
+pub use state::{precipitation_vfx, screen_haze, update_weather_vfx, PrecipitationVfx, WeatherVfx};
+pub use wet_roads::update_wet_roads;
+
+use bevy::prelude::*;
+
+pub struct WeatherVfxPlugin;
+
+impl Plugin for WeatherVfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeatherVfx>().add_systems(
+            Update,
+            (
+                update_weather_vfx,
+                (
+                    draw_precipitation,
+                    update_wet_roads.after(crate::traffic_los_render::update_road_los_colors),
+                    sync_puddle_decals.run_if(bevy::time::common_conditions::on_timer(
+                        std::time::Duration::from_secs(2),
+                    )),
+                ),
+            )
+                .chain(),
+        );
+    }
+}
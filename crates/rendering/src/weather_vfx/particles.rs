@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::camera::OrbitCamera;
+
+use super::state::{PrecipitationVfx, WeatherVfx};
+
+/// Particles drawn at full intensity.
+const MAX_PARTICLES: usize = 900;
+
+/// Camera distance beyond which particles are too small to be worth drawing.
+const MAX_DRAW_DISTANCE: f32 = 2000.0;
+
+/// Height of the column particles fall through, above the camera focus.
+const COLUMN_HEIGHT: f32 = 160.0;
+
+/// Fall speeds in world units per second.
+const RAIN_SPEED: f32 = 220.0;
+const SNOW_SPEED: f32 = 25.0;
+
+/// Speed blown sand travels at full wind, world units per second.
+const SAND_SPEED: f32 = 140.0;
+
+/// Deterministic 0..1 value for particle `i`, varied by `salt`.
+fn particle_hash(i: usize, salt: u64) -> f32 {
+    let mixed = (i as u64 ^ salt.wrapping_mul(0x6c62272e07bb0142)).wrapping_mul(0x9e3779b97f4a7c15);
+    let mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    let mixed = mixed ^ (mixed >> 27);
+    (mixed % 10_000) as f32 / 10_000.0
+}
+
+/// Draw rain, snow or blowing sand in a box around the camera focus. Each
+/// particle loops through the box on its own phase, so no particle state is
+/// kept between frames.
+pub fn draw_precipitation(
+    vfx: Res<WeatherVfx>,
+    camera: Res<OrbitCamera>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    if vfx.kind == PrecipitationVfx::Clear
+        || vfx.intensity <= 0.0
+        || camera.distance > MAX_DRAW_DISTANCE
+    {
+        return;
+    }
+
+    let count = (MAX_PARTICLES as f32 * vfx.intensity) as usize;
+    let radius = (camera.distance * 0.6).clamp(100.0, 600.0);
+    let t = time.elapsed_secs();
+    let ground = camera.focus.y;
+    let alpha = 0.25 + 0.45 * vfx.intensity;
+
+    for i in 0..count {
+        let ox = (particle_hash(i, 1) * 2.0 - 1.0) * radius;
+        let oz = (particle_hash(i, 2) * 2.0 - 1.0) * radius;
+        let phase = particle_hash(i, 3);
+        let base = Vec3::new(camera.focus.x + ox, ground, camera.focus.z + oz);
+
+        match vfx.kind {
+            PrecipitationVfx::Rain => {
+                let fall = (t * RAIN_SPEED / COLUMN_HEIGHT + phase).fract();
+                let top = base + Vec3::Y * COLUMN_HEIGHT * (1.0 - fall);
+                let slant = Vec3::new(vfx.wind.x, 0.0, vfx.wind.y) * 4.0;
+                let streak = Vec3::NEG_Y * (6.0 + 6.0 * vfx.intensity) + slant;
+                gizmos.line(top, top + streak, Color::srgba(0.7, 0.75, 0.85, alpha));
+            }
+            PrecipitationVfx::Snow => {
+                let fall = (t * SNOW_SPEED / COLUMN_HEIGHT + phase).fract();
+                let sway = (t * 1.3 + phase * 20.0).sin() * 3.0;
+                let drift = Vec3::new(vfx.wind.x, 0.0, vfx.wind.y) * 20.0 * fall;
+                let p = base
+                    + Vec3::Y * COLUMN_HEIGHT * (1.0 - fall)
+                    + Vec3::new(sway, 0.0, 0.0)
+                    + drift;
+                let color = Color::srgba(0.95, 0.96, 1.0, alpha + 0.2);
+                gizmos.line(p - Vec3::X * 0.8, p + Vec3::X * 0.8, color);
+                gizmos.line(p - Vec3::Z * 0.8, p + Vec3::Z * 0.8, color);
+            }
+            PrecipitationVfx::Sandstorm => {
+                let dir = Vec3::new(vfx.wind.x, 0.0, vfx.wind.y).normalize_or(Vec3::X);
+                let travel = (t * SAND_SPEED / (radius * 2.0) + phase).fract();
+                let along = (travel * 2.0 - 1.0) * radius;
+                let height = particle_hash(i, 4) * 40.0;
+                let p = base + dir * along + Vec3::Y * height;
+                let color = Color::srgba(0.8, 0.65, 0.42, alpha);
+                gizmos.line(p, p + dir * (4.0 + 8.0 * vfx.intensity), color);
+            }
+            PrecipitationVfx::Clear => {}
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use simulation::config::CELL_SIZE;
+use simulation::grid::{CellType, WorldGrid};
+use simulation::stormwater::StormwaterGrid;
+
+use super::state::WeatherVfx;
+
+/// Runoff (stormwater grid units) at which a puddle starts to form.
+pub(crate) const PUDDLE_MIN_RUNOFF: f32 = 100.0;
+
+/// Runoff at which a puddle reaches full size.
+const PUDDLE_FULL_RUNOFF: f32 = 1000.0;
+
+/// Most puddles shown at once, the wettest cells first.
+const MAX_PUDDLES: usize = 400;
+
+/// Height above the ground puddles float at, to avoid z-fighting.
+const PUDDLE_Y_OFFSET: f32 = 0.15;
+
+/// Dark reflective decal on a cell where stormwater has pooled.
+#[derive(Component)]
+pub struct PuddleDecal {
+    pub grid_x: usize,
+    pub grid_y: usize,
+}
+
+/// Puddle radius in world units for a cell's runoff, or `None` when the
+/// cell is too dry for one.
+pub(crate) fn puddle_radius(runoff: f32) -> Option<f32> {
+    if runoff < PUDDLE_MIN_RUNOFF {
+        return None;
+    }
+    let share =
+        ((runoff - PUDDLE_MIN_RUNOFF) / (PUDDLE_FULL_RUNOFF - PUDDLE_MIN_RUNOFF)).clamp(0.0, 1.0);
+    Some(CELL_SIZE * (0.15 + 0.3 * share))
+}
+
+/// Shared puddle mesh and material.
+#[derive(Resource, Clone)]
+pub struct PuddleAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Place puddles on road cells where stormwater runoff has pooled, sized
+/// by the runoff, and clear them once the streets dry out.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_puddle_decals(
+    mut commands: Commands,
+    vfx: Res<WeatherVfx>,
+    stormwater: Res<StormwaterGrid>,
+    grid: Res<WorldGrid>,
+    mut puddles: Query<(Entity, &PuddleDecal, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    assets: Option<Res<PuddleAssets>>,
+) {
+    // Runoff lingers in the grid after the streets look dry.
+    let mut wanted: Vec<(usize, usize, f32)> = Vec::new();
+    if vfx.wetness > 0.1 && stormwater.total_runoff > 0.0 {
+        for y in 0..grid.height.min(stormwater.height) {
+            for x in 0..grid.width.min(stormwater.width) {
+                if grid.get(x, y).cell_type != CellType::Road {
+                    continue;
+                }
+                if let Some(radius) = puddle_radius(stormwater.get(x, y)) {
+                    wanted.push((x, y, radius));
+                }
+            }
+        }
+        wanted.sort_by(|a, b| b.2.total_cmp(&a.2));
+        wanted.truncate(MAX_PUDDLES);
+    }
+
+    let mut wanted_at: HashMap<(usize, usize), f32> =
+        wanted.iter().map(|&(x, y, r)| ((x, y), r)).collect();
+
+    for (entity, puddle, mut transform) in &mut puddles {
+        match wanted_at.remove(&(puddle.grid_x, puddle.grid_y)) {
+            Some(radius) => transform.scale = Vec3::new(radius, radius, 1.0),
+            None => commands.entity(entity).despawn(),
+        }
+    }
+    if wanted_at.is_empty() {
+        return;
+    }
+
+    let assets = if let Some(a) = assets {
+        a.clone()
+    } else {
+        let a = PuddleAssets {
+            mesh: meshes.add(Circle::new(1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.12, 0.14, 0.18, 0.7),
+                perceptual_roughness: 0.05,
+                reflectance: 0.9,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            }),
+        };
+        commands.insert_resource(a.clone());
+        a
+    };
+
+    for ((x, y), radius) in wanted_at {
+        let (wx, wz) = WorldGrid::grid_to_world(x, y);
+        let wy = grid.elevation_y(x, y) + PUDDLE_Y_OFFSET;
+        commands.spawn((
+            PuddleDecal {
+                grid_x: x,
+                grid_y: y,
+            },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(wx, wy, wz)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::new(radius, radius, 1.0)),
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use simulation::weather::{ClimateZone, Weather, WeatherCondition};
+use simulation::wind::WindState;
+
+/// Wind speed (0..1) above which a dry day in an arid climate raises sand.
+pub(crate) const SANDSTORM_WIND: f32 = 0.6;
+
+/// Seconds for a running effect to fade fully in or out.
+const FADE_SECS: f32 = 3.0;
+
+/// Wetness gained per second of the heaviest rain.
+const SOAK_RATE: f32 = 0.2;
+
+/// Wetness lost per second once the rain stops.
+const DRY_RATE: f32 = 0.01;
+
+/// The precipitation effect on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrecipitationVfx {
+    #[default]
+    Clear,
+    Rain,
+    Snow,
+    Sandstorm,
+}
+
+/// What the weather effects currently show. Eased towards the simulated
+/// weather so effects fade rather than pop.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WeatherVfx {
+    pub kind: PrecipitationVfx,
+    /// Strength of `kind`, 0..1.
+    pub intensity: f32,
+    /// How soaked the streets are, 0 (dry) to 1.
+    pub wetness: f32,
+    /// Wind direction scaled by speed, on the ground plane.
+    pub wind: Vec2,
+}
+
+/// Which effect the weather calls for. The simulation has no sandstorm
+/// condition, so one is shown on windy days without rain in an arid climate.
+pub fn precipitation_vfx(
+    condition: WeatherCondition,
+    climate: ClimateZone,
+    wind_speed: f32,
+) -> PrecipitationVfx {
+    match condition {
+        WeatherCondition::Rain | WeatherCondition::HeavyRain | WeatherCondition::Storm => {
+            PrecipitationVfx::Rain
+        }
+        WeatherCondition::Snow => PrecipitationVfx::Snow,
+        _ if climate == ClimateZone::Arid && wind_speed >= SANDSTORM_WIND => {
+            PrecipitationVfx::Sandstorm
+        }
+        _ => PrecipitationVfx::Clear,
+    }
+}
+
+/// Strength an effect should reach, 0..1.
+pub(crate) fn target_intensity(kind: PrecipitationVfx, weather: &Weather, wind_speed: f32) -> f32 {
+    match kind {
+        PrecipitationVfx::Clear => 0.0,
+        PrecipitationVfx::Rain => (weather.precipitation_intensity / 2.0).clamp(0.2, 1.0),
+        PrecipitationVfx::Snow => weather.precipitation_intensity.clamp(0.2, 1.0),
+        PrecipitationVfx::Sandstorm => {
+            ((wind_speed - SANDSTORM_WIND) / (1.0 - SANDSTORM_WIND)).clamp(0.2, 1.0)
+        }
+    }
+}
+
+/// Haze the effect adds to the camera fog as (colour, exponential density),
+/// or `None` when it adds none.
+pub fn screen_haze(vfx: &WeatherVfx) -> Option<(Color, f32)> {
+    match vfx.kind {
+        PrecipitationVfx::Sandstorm if vfx.intensity > 0.0 => Some((
+            Color::srgba(0.78, 0.62, 0.42, 1.0),
+            0.0008 + 0.006 * vfx.intensity,
+        )),
+        PrecipitationVfx::Rain if vfx.intensity > 0.5 => {
+            Some((Color::srgba(0.55, 0.58, 0.62, 1.0), 0.0012 * vfx.intensity))
+        }
+        PrecipitationVfx::Snow if vfx.intensity > 0.3 => {
+            Some((Color::srgba(0.88, 0.9, 0.94, 1.0), 0.0015 * vfx.intensity))
+        }
+        _ => None,
+    }
+}
+
+/// Ease the effects towards the current weather and soak or dry the
+/// streets.
+pub fn update_weather_vfx(
+    time: Res<Time>,
+    weather: Res<Weather>,
+    climate: Res<ClimateZone>,
+    wind: Res<WindState>,
+    mut vfx: ResMut<WeatherVfx>,
+) {
+    let dt = time.delta_secs();
+    let wanted = precipitation_vfx(weather.current_event, *climate, wind.speed);
+    let (dx, dy) = wind.direction_vector();
+    vfx.wind = Vec2::new(dx, dy) * wind.speed;
+
+    // A different effect waits for the current one to fade out.
+    let step = dt / FADE_SECS;
+    if wanted != vfx.kind {
+        vfx.intensity = (vfx.intensity - step).max(0.0);
+        if vfx.intensity == 0.0 {
+            vfx.kind = wanted;
+        }
+    } else {
+        let target = target_intensity(wanted, &weather, wind.speed);
+        vfx.intensity += (target - vfx.intensity).clamp(-step, step);
+    }
+
+    vfx.wetness = if vfx.kind == PrecipitationVfx::Rain {
+        (vfx.wetness + SOAK_RATE * vfx.intensity * dt).min(1.0)
+    } else {
+        (vfx.wetness - DRY_RATE * dt).max(0.0)
+    };
+}
@@ -0,0 +1,78 @@
+use simulation::config::CELL_SIZE;
+use simulation::weather::{ClimateZone, WeatherCondition};
+
+use super::puddles::{puddle_radius, PUDDLE_MIN_RUNOFF};
+use super::state::SANDSTORM_WIND;
+use super::wet_roads::wet_road_look;
+use super::*;
+
+#[test]
+fn test_precipitation_follows_the_weather() {
+    use PrecipitationVfx::*;
+    let temperate = ClimateZone::Temperate;
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Storm, temperate, 0.9),
+        Rain
+    );
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Snow, temperate, 0.0),
+        Snow
+    );
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Sunny, temperate, 0.9),
+        Clear
+    );
+}
+
+#[test]
+fn test_sandstorms_need_an_arid_windy_dry_day() {
+    let arid = ClimateZone::Arid;
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Sunny, arid, SANDSTORM_WIND),
+        PrecipitationVfx::Sandstorm
+    );
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Sunny, arid, SANDSTORM_WIND - 0.1),
+        PrecipitationVfx::Clear
+    );
+    assert_eq!(
+        precipitation_vfx(WeatherCondition::Rain, arid, 1.0),
+        PrecipitationVfx::Rain
+    );
+}
+
+#[test]
+fn test_sandstorm_haze_is_thicker_than_rain_haze() {
+    let sand = WeatherVfx {
+        kind: PrecipitationVfx::Sandstorm,
+        intensity: 1.0,
+        ..Default::default()
+    };
+    let rain = WeatherVfx {
+        kind: PrecipitationVfx::Rain,
+        intensity: 1.0,
+        ..Default::default()
+    };
+    let (_, sand_density) = screen_haze(&sand).unwrap();
+    let (_, rain_density) = screen_haze(&rain).unwrap();
+    assert!(sand_density > rain_density);
+    assert!(screen_haze(&WeatherVfx::default()).is_none());
+}
+
+#[test]
+fn test_wet_roads_are_darker_and_glossier() {
+    let (dry_color, dry_rough) = wet_road_look(0.0);
+    let (wet_color, wet_rough) = wet_road_look(1.0);
+    assert_eq!(dry_color.to_srgba().red, 1.0);
+    assert!(wet_color.to_srgba().red < dry_color.to_srgba().red);
+    assert!(wet_rough < dry_rough);
+}
+
+#[test]
+fn test_puddles_grow_with_runoff() {
+    assert_eq!(puddle_radius(PUDDLE_MIN_RUNOFF - 1.0), None);
+    let small = puddle_radius(PUDDLE_MIN_RUNOFF).unwrap();
+    let large = puddle_radius(10_000.0).unwrap();
+    assert!(small < large);
+    assert!(large < CELL_SIZE * 0.5, "a puddle stays within its cell");
+}
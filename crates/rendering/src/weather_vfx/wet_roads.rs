@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use simulation::traffic_los::TrafficLosGrid;
+
+use crate::overlay::{OverlayMode, OverlayState};
+use crate::road_render::{RoadIntersectionMesh, RoadSegmentMesh};
+
+use super::state::WeatherVfx;
+
+/// Brightness of a soaked road, as a multiplier on its vertex colours.
+const WET_BRIGHTNESS: f32 = 0.55;
+
+/// Roughness of dry and soaked asphalt; wet roads turn glossy.
+const DRY_ROUGHNESS: f32 = 0.9;
+const WET_ROUGHNESS: f32 = 0.35;
+
+/// Wetness changes smaller than this are not worth touching every material.
+const WETNESS_STEP: f32 = 0.05;
+
+/// Road material tint and roughness for a wetness of 0..1.
+pub(crate) fn wet_road_look(wetness: f32) -> (Color, f32) {
+    let w = wetness.clamp(0.0, 1.0);
+    let brightness = 1.0 + (WET_BRIGHTNESS - 1.0) * w;
+    let roughness = DRY_ROUGHNESS + (WET_ROUGHNESS - DRY_ROUGHNESS) * w;
+    (Color::srgb(brightness, brightness, brightness), roughness)
+}
+
+/// Darken road materials and make them glossy as the streets get wet. The
+/// traffic overlay owns road colours while it is shown, so only roughness
+/// changes then; it also resets colours whenever it refreshes, after which
+/// the wet tint is reapplied.
+pub fn update_wet_roads(
+    vfx: Res<WeatherVfx>,
+    overlay: Res<OverlayState>,
+    los_grid: Res<TrafficLosGrid>,
+    roads: Query<
+        &MeshMaterial3d<StandardMaterial>,
+        Or<(With<RoadSegmentMesh>, With<RoadIntersectionMesh>)>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut applied: Local<Option<(f32, usize)>>,
+) {
+    let wetness = (vfx.wetness / WETNESS_STEP).round() * WETNESS_STEP;
+    let road_count = roads.iter().len();
+    if *applied == Some((wetness, road_count)) && !overlay.is_changed() && !los_grid.is_changed() {
+        return;
+    }
+    *applied = Some((wetness, road_count));
+
+    let (tint, roughness) = wet_road_look(wetness);
+    let tint_colors = overlay.mode != OverlayMode::Traffic;
+    for handle in &roads {
+        let Some(material) = materials.get_mut(&handle.0) else {
+            continue;
+        };
+        material.perceptual_roughness = roughness;
+        if tint_colors {
+            material.base_color = tint;
+        }
+    }
+}
@@ -3,6 +3,9 @@ use bevy::prelude::*;
 use simulation::app_state::AppState;
 use simulation::config::CELL_SIZE;
 use simulation::grid::{CellType, WorldGrid, ZoneType};
+use simulation::heightmap_import::BuildabilityGrid;
+use simulation::land_ownership::LandOwnership;
+use simulation::map_tiles::MapTiles;
 use simulation::urban_growth_boundary::UrbanGrowthBoundary;
 
 use crate::input::{ActiveTool, CursorGridPos};
@@ -148,6 +151,9 @@ pub fn draw_zone_brush_preview(
     brush: Res<ZoneBrushSize>,
     grid: Res<WorldGrid>,
     ugb: Res<UrbanGrowthBoundary>,
+    land: Res<LandOwnership>,
+    buildable: Res<BuildabilityGrid>,
+    tiles: Res<MapTiles>,
     mut gizmos: Gizmos,
 ) {
     let Some(zone) = tool.zone_type() else {
@@ -162,7 +168,10 @@ pub fn draw_zone_brush_preview(
     let y = 0.6; // slightly above ground
 
     for (gx, gy) in &cells {
-        let valid = is_cell_valid_for_zone(&grid, *gx, *gy, zone, &ugb);
+        let valid = is_cell_valid_for_zone(&grid, *gx, *gy, zone, &ugb)
+            && land.allows_rezoning(*gx, *gy)
+            && buildable.is_buildable(*gx, *gy)
+            && tiles.contains(*gx, *gy);
         let color = if valid { valid_color } else { INVALID_COLOR };
 
         let (wx, _) = WorldGrid::grid_to_world(*gx, *gy);
@@ -1,8 +1,10 @@
 //! Bridge between the simulation-side autosave timer and the save system.
 //!
 //! Reads `AutosavePending` (set by `simulation::autosave`) and, when true,
-//! triggers a `SaveGameEvent`. After the save completes (state returns to
-//! `Idle`), copies the save file to the current rotating autosave slot.
+//! triggers a `SaveGameEvent`. After the save completes (state is back to
+//! `Idle` and the background write has finished), copies the save file, and
+//! its delta when the save was written as one, to the current rotating
+//! autosave slot.
 //!
 //! On WASM, the slot rotation is skipped (IndexedDB saves use a single key).
 
@@ -22,6 +24,9 @@ use crate::SaveGameEvent;
 struct AutosaveInFlight {
     /// The slot filename to copy to after the save finishes.
     target_slot_filename: Option<String>,
+    /// Whether the save has been seen running, so rotation doesn't copy the
+    /// previous save in the frames before this one starts.
+    save_started: bool,
 }
 
 // =============================================================================
@@ -63,15 +68,26 @@ fn trigger_autosave(
 ///
 /// Only runs on native platforms (WASM uses IndexedDB with a single key).
 #[cfg(not(target_arch = "wasm32"))]
-fn rotate_autosave_slot(mut in_flight: ResMut<AutosaveInFlight>, state: Res<State<SaveLoadState>>) {
-    // Wait until the save operation completes.
-    if *state.get() != SaveLoadState::Idle {
+fn rotate_autosave_slot(
+    mut in_flight: ResMut<AutosaveInFlight>,
+    state: Res<State<SaveLoadState>>,
+    background: Res<crate::BackgroundSave>,
+) {
+    if in_flight.target_slot_filename.is_none() {
+        return;
+    }
+
+    // Wait until the save has started and then completed.
+    if *state.get() != SaveLoadState::Idle || background.is_saving() {
+        in_flight.save_started = true;
+        return;
+    }
+    if !std::mem::take(&mut in_flight.save_started) {
         return;
     }
 
-    let filename = match in_flight.target_slot_filename.take() {
-        Some(f) => f,
-        None => return,
+    let Some(filename) = in_flight.target_slot_filename.take() else {
+        return;
     };
 
     let source = crate::save_plugin::save_file_path();
@@ -80,8 +96,22 @@ fn rotate_autosave_slot(mut in_flight: ResMut<AutosaveInFlight>, state: Res<Stat
             "Autosave slot rotation failed: could not copy {} -> {}: {}",
             source, filename, e
         );
+        return;
+    }
+    info!("Autosave: copied {} -> {}", source, filename);
+
+    // Keep the slot's delta in step with its snapshot.
+    let source_delta = crate::save_delta::delta_file_path(&source);
+    let slot_delta = crate::save_delta::delta_file_path(&filename);
+    let result = if std::path::Path::new(&source_delta).exists() {
+        std::fs::copy(&source_delta, &slot_delta).map(drop)
     } else {
-        info!("Autosave: copied {} -> {}", source, filename);
+        std::fs::remove_file(&slot_delta)
+    };
+    if let Err(e) = result {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!("Autosave slot rotation failed for {}: {}", slot_delta, e);
+        }
     }
 }
 
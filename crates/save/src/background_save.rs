@@ -0,0 +1,194 @@
+// ---------------------------------------------------------------------------
+// background_save – Encoding and writing saves off the main thread
+// ---------------------------------------------------------------------------
+//
+// Collecting the world into a `SaveData` needs exclusive world access, but
+// encoding, compressing and writing it don't, and for a large city they are
+// most of the save's cost. On native builds `exclusive_save` hands the
+// collected `SaveData` to a task on the `AsyncComputeTaskPool` and returns,
+// so the frame isn't blocked while the file is written. `poll_background_save`
+// picks up the result.
+//
+// Only one save runs at a time: a save requested while another is still
+// being written is queued and started when it finishes. The delta base moves
+// into the task and comes back with its result, so a failed write simply
+// makes the next save a full snapshot.
+//
+// WASM saves already write to IndexedDB asynchronously and encode on the
+// main thread (the task pool has no worker threads there), so
+// `BackgroundSave` never holds a task on WASM.
+
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+#[cfg(not(target_arch = "wasm32"))]
+use simulation::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::save_delta::{BaseSnapshot, DeltaSaveBase, DeltaSaveSettings};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::save_error::SaveError;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::save_metadata::SaveMetadata;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::save_types::SaveData;
+
+/// The save being encoded and written in the background, if any.
+#[derive(Resource, Default)]
+pub struct BackgroundSave {
+    /// Resolves to the delta base for the next save.
+    #[cfg(not(target_arch = "wasm32"))]
+    task: Option<Task<Result<Option<BaseSnapshot>, SaveError>>>,
+    /// Whether another save was requested while this one was running.
+    #[cfg(not(target_arch = "wasm32"))]
+    queued: bool,
+}
+
+impl BackgroundSave {
+    /// Whether a save is still being encoded or written, or queued to
+    /// follow the one that is.
+    pub fn is_saving(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.task.is_some() || self.queued
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            false
+        }
+    }
+
+    /// If a save is still running, queue another one to follow it and
+    /// return `true`; the caller must not start a save now.
+    pub(crate) fn queue_if_busy(&mut self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if self.task.is_some() {
+                self.queued = true;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Mark the queued save, if any, as started.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn start_queued_save(world: &mut World) {
+    world.resource_mut::<BackgroundSave>().queued = false;
+}
+
+/// Encode `save` and write it to `path` on the task pool.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn_background_save(
+    world: &mut World,
+    path: String,
+    save: SaveData,
+    metadata: SaveMetadata,
+) {
+    let base = world.resource_mut::<DeltaSaveBase>().0.take();
+    let settings = world
+        .get_resource::<DeltaSaveSettings>()
+        .cloned()
+        .unwrap_or_default();
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        crate::save_delta::write_save(base, &settings, &path, save, &metadata)
+    });
+    world.resource_mut::<BackgroundSave>().task = Some(task);
+}
+
+/// Collect the result of a finished background save and start the queued
+/// save, if any.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn poll_background_save(
+    mut background: ResMut<BackgroundSave>,
+    mut base: ResMut<DeltaSaveBase>,
+    mut notifications: EventWriter<NotificationEvent>,
+    mut save_events: EventWriter<crate::SaveGameEvent>,
+) {
+    let Some(task) = background.task.as_mut() else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    background.task = None;
+
+    match result {
+        Ok(next_base) => base.0 = next_base,
+        Err(e) => {
+            let msg = format!("Save failed: {e}");
+            error!("{msg}");
+            notifications.send(NotificationEvent {
+                text: msg,
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::General,
+                location: None,
+            });
+        }
+    }
+
+    // `queued` stays set until `exclusive_save` starts the queued save, so
+    // `is_saving` doesn't flicker off in between.
+    if background.queued {
+        save_events.send(crate::SaveGameEvent);
+    }
+}
+
+/// Let a save still being written finish before the app exits, so quitting
+/// right after saving doesn't lose the save.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn finish_background_save_on_exit(
+    mut exit: EventReader<AppExit>,
+    mut background: ResMut<BackgroundSave>,
+) {
+    if exit.read().next().is_none() {
+        return;
+    }
+    if let Some(task) = background.task.take() {
+        info!("Waiting for the background save to finish before exiting");
+        if let Err(e) = block_on(task) {
+            error!("Save failed: {e}");
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use bevy::tasks::TaskPool;
+
+    use super::*;
+    use crate::save_types::CURRENT_SAVE_VERSION;
+    use crate::serialization::tests_save_error::minimal_save;
+
+    #[test]
+    fn test_background_save_writes_file_and_queues_while_busy() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<BackgroundSave>();
+        world.init_resource::<DeltaSaveBase>();
+        world.init_resource::<DeltaSaveSettings>();
+
+        let path = std::env::temp_dir()
+            .join(format!("megacity_bg_save_{}.bin", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let save = minimal_save(CURRENT_SAVE_VERSION);
+        spawn_background_save(&mut world, path.clone(), save, SaveMetadata::default());
+
+        let mut background = world.resource_mut::<BackgroundSave>();
+        assert!(background.is_saving());
+        assert!(background.queue_if_busy());
+
+        let base = block_on(background.task.take().unwrap()).unwrap();
+        assert_eq!(base.map(|b| b.path), Some(path.clone()));
+        assert!(std::fs::metadata(&path).unwrap().len() > 0);
+
+        // The queued save keeps the indicator on until it starts.
+        assert!(background.is_saving());
+        start_queued_save(&mut world);
+        assert!(!world.resource::<BackgroundSave>().is_saving());
+        let _ = std::fs::remove_file(&path);
+    }
+}
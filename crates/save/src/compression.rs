@@ -0,0 +1,65 @@
+// ---------------------------------------------------------------------------
+// compression – Zstd compression of the encoded SaveData payload
+// ---------------------------------------------------------------------------
+//
+// Native builds compress save payloads as a single zstd frame. The `zstd`
+// crate builds the C library, which WASM builds can't link, so it sits behind
+// the `zstd` feature on native targets only; without it saves fall back to
+// LZ4 (see `file_header::wrap_with_header_for_save`).
+//
+// Save files mark zstd payloads with the header's `FLAG_ZSTD`, and loading
+// decodes by that flag. A zstd frame also starts with its own magic number,
+// which `SaveData::decode` falls back on for input with no header.
+
+/// Magic number that opens every zstd frame (0xFD2FB528, little-endian).
+pub const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Zstd compression level: zstd's default, a good size/speed trade-off for
+/// saves that happen during play.
+pub const ZSTD_LEVEL: i32 = 3;
+
+/// Whether `bytes` starts like a zstd frame.
+pub fn is_zstd_frame(bytes: &[u8]) -> bool {
+    bytes.starts_with(&ZSTD_MAGIC)
+}
+
+/// Compress `data` into a single zstd frame.
+#[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+pub fn compress_zstd(data: &[u8]) -> Result<Vec<u8>, String> {
+    zstd::bulk::compress(data, ZSTD_LEVEL).map_err(|e| format!("zstd compression failed: {e}"))
+}
+
+/// Decompress a zstd frame.
+#[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+pub fn decompress_zstd(frame: &[u8]) -> Result<Vec<u8>, String> {
+    zstd::stream::decode_all(frame).map_err(|e| {
+        format!("Failed to decompress zstd payload: {e}. The save file may be corrupted.")
+    })
+}
+
+/// Decompress a zstd frame (unsupported in this build).
+#[cfg(not(all(feature = "zstd", not(target_arch = "wasm32"))))]
+pub fn decompress_zstd(_frame: &[u8]) -> Result<Vec<u8>, String> {
+    Err("Save is zstd-compressed, but this build was compiled without zstd support.".into())
+}
+
+#[cfg(all(test, feature = "zstd", not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zstd_roundtrip_and_magic() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 7) as u8).collect();
+        let frame = compress_zstd(&data).unwrap();
+        assert!(is_zstd_frame(&frame));
+        assert!(frame.len() < data.len() / 10);
+        assert_eq!(decompress_zstd(&frame).unwrap(), data);
+    }
+
+    #[test]
+    fn test_corrupt_frame_is_an_error() {
+        let mut frame = compress_zstd(b"megacity megacity megacity").unwrap();
+        frame.truncate(frame.len() - 4);
+        assert!(decompress_zstd(&frame).is_err());
+    }
+}
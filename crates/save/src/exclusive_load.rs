@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use simulation::app_state::AppState;
-use simulation::notifications::{NotificationEvent, NotificationPriority};
+use simulation::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use simulation::post_load_rebuild::PostLoadRebuildPending;
 use simulation::reset_commuting_on_load::PostLoadResetPending;
 use simulation::PreLoadAppState;
@@ -8,11 +8,12 @@ use simulation::SaveLoadState;
 use simulation::SaveableRegistry;
 
 use crate::despawn::despawn_all_game_entities;
-use crate::file_header::{decompress_payload, unwrap_header, UnwrapResult};
 use crate::restore_resources::restore_resources_from_save;
+use crate::save_decode::decode_save_file;
 use crate::save_error::SaveError;
 use crate::save_plugin::PendingLoadBytes;
-use crate::serialization::{migrate_save_with_report, SaveData};
+use crate::save_repair::repair_save;
+use crate::serialization::SaveData;
 use crate::spawn_entities::spawn_entities_from_save;
 
 /// Exclusive system that performs the entire load operation with full world
@@ -25,6 +26,7 @@ pub(crate) fn exclusive_load(world: &mut World) {
         world.send_event(NotificationEvent {
             text: msg,
             priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
             location: None,
         });
 
@@ -57,64 +59,16 @@ fn exclusive_load_inner(world: &mut World) -> Result<(), SaveError> {
     let bytes = world.resource_mut::<PendingLoadBytes>().0.take();
     let bytes = bytes.ok_or(SaveError::NoData)?;
 
-    // -- Stage 0: Validate file header and extract payload --
-    let (raw_payload, is_compressed) = match unwrap_header(&bytes) {
-        Ok(UnwrapResult::WithHeader {
-            header,
-            metadata,
-            payload,
-        }) => {
-            info!(
-                "Save file header: format v{}, flags {:#X}, timestamp {}, \
-                 data size {}, checksum {:#010X}, metadata size {}",
-                header.format_version,
-                header.flags,
-                header.timestamp,
-                header.uncompressed_size,
-                header.checksum,
-                header.metadata_size,
-            );
-            if let Some(ref meta) = metadata {
-                info!(
-                    "Save metadata: city='{}', pop={}, treasury={:.0}, day={}, hour={:.1}, play_time={:.0}s",
-                    meta.city_name,
-                    meta.population,
-                    meta.treasury,
-                    meta.day,
-                    meta.hour,
-                    meta.play_time_seconds,
-                );
-            }
-            (payload, header.is_compressed())
-        }
-        Ok(UnwrapResult::Legacy(payload)) => {
-            info!("Loading legacy save file (no header)");
-            (payload, false)
-        }
-        Err(e) => {
-            return Err(SaveError::Decode(format!("Invalid file header: {e}")));
-        }
-    };
-
-    // -- Stage 0b: Decompress if the compressed flag is set --
-    let decompressed;
-    let decode_input = if is_compressed {
-        decompressed =
-            decompress_payload(raw_payload).map_err(|e| SaveError::Decode(e.to_string()))?;
-        info!(
-            "Decompressed save: {} bytes -> {} bytes",
-            raw_payload.len(),
-            decompressed.len(),
-        );
-        decompressed.as_slice()
-    } else {
-        raw_payload
-    };
-
-    // -- Stage 1: Parse and migrate --
-    let mut save = SaveData::decode(decode_input)?;
-
-    let report = migrate_save_with_report(&mut save)?;
+    // -- Stages 0-1: Validate, decompress, decode, apply the delta saved
+    // beside this snapshot and migrate --
+    #[cfg(not(target_arch = "wasm32"))]
+    let delta = world
+        .resource_mut::<crate::save_delta::PendingDeltaBytes>()
+        .0
+        .take();
+    #[cfg(target_arch = "wasm32")]
+    let delta: Option<Vec<u8>> = None;
+    let (mut save, report) = decode_save_file(&bytes, delta.as_deref())?;
 
     if report.steps_applied > 0 {
         info!(
@@ -126,14 +80,50 @@ fn exclusive_load_inner(world: &mut World) -> Result<(), SaveError> {
         }
     }
 
+    // -- Stage 1b: Repair dangling references and grid/entity mismatches --
+    let repair = repair_save(&mut save);
+    if !repair.is_clean() {
+        let lines = repair.lines();
+        for line in &lines {
+            warn!("Save repair: {line}");
+        }
+        world.send_event(NotificationEvent {
+            text: format!("Save was repaired while loading: {}", lines.join(", ")),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+    world.insert_resource(repair);
+
+    apply_save_data(world, &save)?;
+
+    // Load succeeded — clear the pre-load state since no rollback is needed.
+    if let Some(mut pre_load) = world.get_resource_mut::<PreLoadAppState>() {
+        pre_load.0 = None;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    info!("Loaded save from {}", crate::save_plugin::save_file_path());
+    #[cfg(target_arch = "wasm32")]
+    web_sys::console::log_1(&"Loaded save from IndexedDB".into());
+
+    Ok(())
+}
+
+/// Replace the world's game state with `save`: despawn, restore resources,
+/// spawn entities, apply extensions and flag the post-load passes.
+///
+/// Shared by file loads and replay checkpoints.
+pub(crate) fn apply_save_data(world: &mut World, save: &SaveData) -> Result<(), SaveError> {
     // -- Stage 2: Despawn existing entities (immediate, not deferred) --
     despawn_all_game_entities(world);
 
     // -- Stage 3: Restore resources --
-    restore_resources_from_save(world, &save);
+    restore_resources_from_save(world, save);
 
     // -- Stage 4: Spawn entities --
-    spawn_entities_from_save(world, &save);
+    spawn_entities_from_save(world, save);
 
     // -- Stage 5: Apply extension map via SaveableRegistry --
     // IMPORTANT: Always call load_all(), even when save.extensions is empty.
@@ -153,15 +143,5 @@ fn exclusive_load_inner(world: &mut World) -> Result<(), SaveError> {
     // -- Stage 7: Signal post-load derived state rebuild (SAVE-026) --
     world.insert_resource(PostLoadRebuildPending);
 
-    // Load succeeded — clear the pre-load state since no rollback is needed.
-    if let Some(mut pre_load) = world.get_resource_mut::<PreLoadAppState>() {
-        pre_load.0 = None;
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    info!("Loaded save from {}", crate::save_plugin::save_file_path());
-    #[cfg(target_arch = "wasm32")]
-    web_sys::console::log_1(&"Loaded save from IndexedDB".into());
-
     Ok(())
 }
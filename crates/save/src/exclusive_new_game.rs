@@ -1,5 +1,13 @@
+use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
+use simulation::game_settings::GameSettings;
+use simulation::heightmap_import::{apply_heightmap, BuildabilityGrid, PendingHeightmap};
+use simulation::map_tiles::MapTiles;
 use simulation::new_game_config::NewGameConfig;
+use simulation::osm_import::{apply_osm_import, PendingOsmImport};
+use simulation::road_segments::RoadSegmentStore;
+use simulation::roads::RoadNetwork;
+use simulation::scenario::{begin_scenario, PendingScenario, StartingMap};
 use simulation::terrain_generation::{generate_procedural_terrain, TerrainConfig};
 use simulation::SaveLoadState;
 use simulation::SaveableRegistry;
@@ -19,8 +27,27 @@ pub(crate) fn exclusive_new_game(world: &mut World) {
         .get_resource::<NewGameConfig>()
         .cloned()
         .unwrap_or_default();
+    let settings = world
+        .get_resource::<GameSettings>()
+        .cloned()
+        .unwrap_or_default();
 
-    let seed = config.seed;
+    let scenario = world
+        .get_resource_mut::<PendingScenario>()
+        .and_then(|mut pending| pending.0.take());
+    let map = scenario.as_ref().map(|s| s.map.clone());
+    let heightmap = world
+        .get_resource_mut::<PendingHeightmap>()
+        .and_then(|mut pending| pending.0.take());
+    let streets = world
+        .get_resource_mut::<PendingOsmImport>()
+        .and_then(|mut pending| pending.0.take());
+
+    // A scenario can pin the map seed.
+    let seed = match map {
+        Some(StartingMap::Procedural { seed: Some(seed) }) => seed,
+        _ => config.seed,
+    };
     let city_name = config.city_name.clone();
 
     // -- Stage 1: Despawn existing entities (immediate) --
@@ -39,32 +66,90 @@ pub(crate) fn exclusive_new_game(world: &mut World) {
 
     // -- Stage 3b: Restore the player's chosen config (reset cleared it) --
     world.insert_resource(NewGameConfig { city_name, seed });
+    // Difficulty sets the opening treasury; a scenario may override it below.
+    world.resource_mut::<simulation::economy::CityBudget>().treasury =
+        settings.starting_treasury();
+    world.insert_resource(settings);
 
-    // -- Stage 4: Generate procedural terrain --
-    let biome_grid = {
-        let mut grid = world.resource_mut::<simulation::grid::WorldGrid>();
-        generate_procedural_terrain(&mut grid, seed, NEW_GAME_EROSION_ITERATIONS)
-    };
-    world.insert_resource(biome_grid);
-
-    // Store the terrain configuration so it persists through saves.
-    world.insert_resource(TerrainConfig {
-        seed,
-        erosion_iterations: NEW_GAME_EROSION_ITERATIONS,
-        generated: true,
-    });
-
-    // -- Stage 5: Activate tutorial for new games --
-    {
-        let mut tutorial = world.resource_mut::<simulation::tutorial::TutorialState>();
-        tutorial.active = true;
-        tutorial.current_step = simulation::tutorial::TutorialStep::Welcome;
-        tutorial.completed = false;
+    // -- Stage 4: Build the starting map --
+    if map == Some(StartingMap::TelAviv) {
+        if let Err(e) = world.run_system_once(simulation::world_init::init_world) {
+            error!("Failed to build the Tel Aviv map: {e}");
+        }
+    } else if let Some(heightmap) = heightmap {
+        let (biome_grid, buildability) = {
+            let mut grid = world.resource_mut::<simulation::grid::WorldGrid>();
+            apply_heightmap(&mut grid, &heightmap)
+        };
+        world.insert_resource(biome_grid);
+        world.insert_resource(buildability);
+    } else {
+        let biome_grid = {
+            let mut grid = world.resource_mut::<simulation::grid::WorldGrid>();
+            generate_procedural_terrain(&mut grid, seed, NEW_GAME_EROSION_ITERATIONS)
+        };
+        world.insert_resource(biome_grid);
+
+        // Store the terrain configuration so it persists through saves.
+        world.insert_resource(TerrainConfig {
+            seed,
+            erosion_iterations: NEW_GAME_EROSION_ITERATIONS,
+            generated: true,
+        });
+    }
+
+    // -- Stage 4b: Lay out imported streets on the new terrain --
+    if let Some((data, options)) = streets.filter(|_| map != Some(StartingMap::TelAviv)) {
+        let report = world.resource_scope(|world, mut grid: Mut<simulation::grid::WorldGrid>| {
+            world.resource_scope(|world, mut segments: Mut<RoadSegmentStore>| {
+                world.resource_scope(|world, mut roads: Mut<RoadNetwork>| {
+                    apply_osm_import(
+                        &data,
+                        options.zone_from_landuse,
+                        &mut grid,
+                        &mut roads,
+                        &mut segments,
+                        world.resource::<BuildabilityGrid>(),
+                    )
+                })
+            })
+        });
+        println!(
+            "Imported {} streets as {} road segments, zoned {} cells",
+            report.streets, report.segments, report.zoned_cells
+        );
+    }
+
+    // -- Stage 5: Starting tiles and tutorial for sandbox games, or start the scenario --
+    if let Some(scenario) = scenario {
+        begin_scenario(world, scenario);
+    } else {
+        // Sandbox cities start on the central map tiles and buy the rest.
+        let grid = world.resource::<simulation::grid::WorldGrid>();
+        let tiles = MapTiles::starting(grid.width, grid.height);
+        world.insert_resource(tiles);
+
+        // Players who finished or skipped the basics in another city aren't
+        // walked through them again; the help overlay can still start them.
+        let basics_done = world
+            .get_resource::<simulation::profile::PlayerProfile>()
+            .is_some_and(|p| p.has_completed_tutorial(simulation::tutorial::BASICS_TUTORIAL_ID));
+        if !basics_done {
+            let basics = world
+                .resource::<simulation::tutorial::TutorialLibrary>()
+                .get(simulation::tutorial::BASICS_TUTORIAL_ID)
+                .cloned()
+                .unwrap_or_else(simulation::tutorial::basics_tutorial);
+            world
+                .resource_mut::<simulation::tutorial::TutorialState>()
+                .start(basics);
+        }
     }
 
     let config = world.resource::<NewGameConfig>();
+    let treasury = world.resource::<simulation::economy::CityBudget>().treasury;
     println!(
-        "New game '{}' started — procedural terrain (seed {seed}) with $50,000 treasury",
+        "New game '{}' started — seed {seed} with ${treasury:.0} treasury",
         config.city_name
     );
 
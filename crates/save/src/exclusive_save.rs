@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use simulation::new_game_config::NewGameConfig;
-use simulation::notifications::{NotificationEvent, NotificationPriority};
+use simulation::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use simulation::SaveLoadState;
 use simulation::SaveableRegistry;
 
@@ -9,13 +9,12 @@ use simulation::citizen::Citizen;
 use simulation::play_time::PlayTime;
 
 use crate::save_error::SaveError;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::save_plugin::PendingSavePath;
 use crate::save_stages::{
     assemble_save_data, collect_disaster_stage, collect_economy_stage, collect_entity_stage,
     collect_environment_stage, collect_grid_stage, collect_policy_stage,
 };
-use crate::serialization::CitizenSaveInput;
+use crate::serialization::{CitizenSaveInput, SaveData, NO_WORKPLACE};
 
 use simulation::agriculture::AgricultureState;
 use simulation::budget::ExtendedBudget;
@@ -65,9 +64,10 @@ use simulation::weather::{ClimateZone, ConstructionModifiers, Weather};
 use simulation::wind_damage::WindDamageState;
 use simulation::zones::ZoneDemand;
 
-/// Exclusive system that performs the entire save operation with full world
-/// access.  Runs on `OnEnter(SaveLoadState::Saving)`, then transitions back
-/// to `Idle`.
+/// Exclusive system that collects the world into a `SaveData` with full
+/// world access.  On native the save is then encoded and written on a
+/// background task (see `background_save`).  Runs on
+/// `OnEnter(SaveLoadState::Saving)`, then transitions back to `Idle`.
 pub(crate) fn exclusive_save(world: &mut World) {
     if let Err(e) = exclusive_save_inner(world) {
         let msg = format!("Save failed: {e}");
@@ -75,6 +75,7 @@ pub(crate) fn exclusive_save(world: &mut World) {
         world.send_event(NotificationEvent {
             text: msg,
             priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
             location: None,
         });
     }
@@ -87,6 +88,59 @@ pub(crate) fn exclusive_save(world: &mut World) {
 
 /// Inner implementation that returns `Result` for proper error propagation.
 fn exclusive_save_inner(world: &mut World) -> Result<(), SaveError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    crate::background_save::start_queued_save(world);
+
+    let (save, metadata) = collect_save_data(world)?;
+
+    // -- Stage 3: Encode and write to disk (as a snapshot or delta, on a
+    // background task) or IndexedDB --
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        // Use the override path if set, otherwise fall back to default.
+        let path = world
+            .resource_mut::<PendingSavePath>()
+            .0
+            .take()
+            .unwrap_or_else(crate::save_plugin::save_file_path);
+        crate::background_save::spawn_background_save(world, path, save, metadata);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let encoded = save.encode();
+        let bytes = crate::file_header::wrap_with_header_for_save(&encoded, &metadata);
+        let len = bytes.len();
+        let key = crate::wasm_idb::storage_key(world.resource_mut::<PendingSavePath>().0.take());
+        let error_slot = world
+            .resource::<crate::save_plugin::WasmSaveErrorBuffer>()
+            .0
+            .clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::wasm_idb::idb_save(key.clone(), bytes).await {
+                Ok(()) => {
+                    web_sys::console::log_1(
+                        &format!("Saved {} bytes to IndexedDB key {}", len, key).into(),
+                    );
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    web_sys::console::error_1(&msg.clone().into());
+                    if let Ok(mut guard) = error_slot.lock() {
+                        *guard = Some(msg);
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Collect the whole game state into a `SaveData` plus its metadata.
+///
+/// Shared by file saves and replay checkpoints.
+pub(crate) fn collect_save_data(world: &mut World) -> Result<(SaveData, SaveMetadata), SaveError> {
     // -- Stage 1: Collect entity data via queries (needs &mut World) --
     let building_data: Vec<(Building, Option<MixedUseBuilding>)> = {
         let mut q = world.query::<(&Building, Option<&MixedUseBuilding>)>();
@@ -101,7 +155,7 @@ fn exclusive_save_inner(world: &mut World) -> Result<(), SaveError> {
             &CitizenDetails,
             &CitizenStateComp,
             &HomeLocation,
-            &WorkLocation,
+            Option<&WorkLocation>,
             &PathCache,
             &Velocity,
             &Position,
@@ -119,8 +173,8 @@ fn exclusive_save_inner(world: &mut World) -> Result<(), SaveError> {
                         state: state.0,
                         home_x: home.grid_x,
                         home_y: home.grid_y,
-                        work_x: work.grid_x,
-                        work_y: work.grid_y,
+                        work_x: work.map_or(NO_WORKPLACE, |w| w.grid_x),
+                        work_y: work.map_or(NO_WORKPLACE, |w| w.grid_y),
                         path: path.clone(),
                         velocity: vel.clone(),
                         position: pos.clone(),
@@ -174,46 +228,7 @@ fn exclusive_save_inner(world: &mut World) -> Result<(), SaveError> {
     save.extensions = registry.save_all(world);
     world.insert_resource(registry);
 
-    // -- Stage 3: Encode and write to disk/IndexedDB --
-    let encoded = save.encode();
-    let bytes = crate::file_header::wrap_with_header_compressed(&encoded, &metadata);
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        // Use the override path if set, otherwise fall back to default.
-        let path = world
-            .resource_mut::<PendingSavePath>()
-            .0
-            .take()
-            .unwrap_or_else(crate::save_plugin::save_file_path);
-        crate::atomic_write::atomic_write(&path, &bytes)?;
-        info!("Saved {} bytes to {}", bytes.len(), path);
-    }
-
-    #[cfg(target_arch = "wasm32")]
-    {
-        let len = bytes.len();
-        let error_slot = world
-            .resource::<crate::save_plugin::WasmSaveErrorBuffer>()
-            .0
-            .clone();
-        wasm_bindgen_futures::spawn_local(async move {
-            match crate::wasm_idb::idb_save(bytes).await {
-                Ok(()) => {
-                    web_sys::console::log_1(&format!("Saved {} bytes to IndexedDB", len).into());
-                }
-                Err(e) => {
-                    let msg = e.to_string();
-                    web_sys::console::error_1(&msg.clone().into());
-                    if let Ok(mut guard) = error_slot.lock() {
-                        *guard = Some(msg);
-                    }
-                }
-            }
-        });
-    }
-
-    Ok(())
+    Ok((save, metadata))
 }
 
 /// Build a `SaveMetadata` snapshot from the current game state.
@@ -5,7 +5,7 @@
 // Header format v2 (32 bytes, fixed-size, little-endian):
 //   [0..4]   Magic bytes: "MEGA" (0x4D454741)
 //   [4..8]   Format version (u32)
-//   [8..12]  Flags (u32: bit 0 = compressed, bit 1 = delta save)
+//   [8..12]  Flags (u32: bit 0 = LZ4, bit 1 = delta save, bit 2 = zstd)
 //   [12..20] Timestamp (Unix epoch, u64)
 //   [20..24] Uncompressed data size (u32)
 //   [24..28] xxHash32 checksum of the data payload (after header + metadata)
@@ -15,6 +15,8 @@
 //
 // On save: encode SaveData -> compress -> encode metadata -> prepend header
 // On load: parse header -> read metadata -> validate checksum -> decompress -> decode
+// Compression: zstd where the build supports it, LZ4 otherwise (WASM). Zstd
+// payloads are decompressed by `SaveData::decode_payload` itself.
 // Legacy: if first 4 bytes != "MEGA", treat as raw bitcode (headerless save)
 // V1 compat: if format_version == 1, header is 28 bytes with no metadata
 
@@ -30,12 +32,19 @@ pub const HEADER_SIZE_V1: usize = 28;
 /// Size of the V2 file header in bytes (with metadata_size field).
 pub const HEADER_SIZE: usize = 32;
 
-/// Current file header format version. Bumped to 2 for the metadata section.
-pub const HEADER_FORMAT_VERSION: u32 = 2;
+/// Current file header format version. Bumped to 2 for the metadata section,
+/// and to 3 for zstd payloads, which older builds would misread as raw data.
+pub const HEADER_FORMAT_VERSION: u32 = 3;
 
 /// Flag bit 0: payload is LZ4-compressed.
 pub const FLAG_COMPRESSED: u32 = 0x1;
 
+/// Flag bit 1: payload is a `SaveDelta` against a full snapshot.
+pub const FLAG_DELTA: u32 = 0x2;
+
+/// Flag bit 2: payload is a zstd frame.
+pub const FLAG_ZSTD: u32 = 0x4;
+
 /// Seed for xxHash32 checksum.
 const XXHASH_SEED: u32 = 0;
 
@@ -56,6 +65,45 @@ impl FileHeader {
     pub fn is_compressed(&self) -> bool {
         self.flags & FLAG_COMPRESSED != 0
     }
+
+    /// Returns `true` if the delta flag (bit 1) is set.
+    pub fn is_delta(&self) -> bool {
+        self.flags & FLAG_DELTA != 0
+    }
+
+    /// Returns `true` if the zstd flag (bit 2) is set.
+    pub fn is_zstd(&self) -> bool {
+        self.flags & FLAG_ZSTD != 0
+    }
+}
+
+/// Compress and wrap a save for writing: zstd where the build supports it,
+/// LZ4 otherwise.
+pub fn wrap_with_header_for_save(data: &[u8], metadata: &SaveMetadata) -> Vec<u8> {
+    wrap_with_header_for_save_flagged(data, 0, metadata)
+}
+
+/// Like `wrap_with_header_for_save`, with `extra_flags` (e.g. `FLAG_DELTA`)
+/// set in the header.
+pub fn wrap_with_header_for_save_flagged(
+    data: &[u8],
+    extra_flags: u32,
+    metadata: &SaveMetadata,
+) -> Vec<u8> {
+    #[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+    match crate::compression::compress_zstd(data) {
+        Ok(frame) => {
+            return wrap_payload(data.len(), FLAG_ZSTD | extra_flags, &frame, metadata);
+        }
+        Err(e) => bevy::log::warn!("{e}; falling back to LZ4"),
+    }
+    let compressed = lz4_flex::compress_prepend_size(data);
+    wrap_payload(
+        data.len(),
+        FLAG_COMPRESSED | extra_flags,
+        &compressed,
+        metadata,
+    )
 }
 
 /// Compress, wrap with header and metadata.
@@ -63,6 +111,16 @@ impl FileHeader {
 /// Layout: [Header 32B] [Metadata] [LZ4-compressed payload]
 pub fn wrap_with_header_compressed(data: &[u8], metadata: &SaveMetadata) -> Vec<u8> {
     let compressed = lz4_flex::compress_prepend_size(data);
+    wrap_payload(data.len(), FLAG_COMPRESSED, &compressed, metadata)
+}
+
+/// Wrap an already-compressed payload with header and metadata.
+fn wrap_payload(
+    uncompressed_size: usize,
+    flags: u32,
+    compressed: &[u8],
+    metadata: &SaveMetadata,
+) -> Vec<u8> {
     let metadata_bytes = metadata.encode();
 
     let timestamp = std::time::SystemTime::now()
@@ -72,17 +130,17 @@ pub fn wrap_with_header_compressed(data: &[u8], metadata: &SaveMetadata) -> Vec<
 
     let header = FileHeader {
         format_version: HEADER_FORMAT_VERSION,
-        flags: FLAG_COMPRESSED,
+        flags,
         timestamp,
-        uncompressed_size: data.len() as u32,
-        checksum: xxh32(&compressed, XXHASH_SEED),
+        uncompressed_size: uncompressed_size as u32,
+        checksum: xxh32(compressed, XXHASH_SEED),
         metadata_size: metadata_bytes.len() as u32,
     };
 
     let mut out = Vec::with_capacity(HEADER_SIZE + metadata_bytes.len() + compressed.len());
     write_header(&mut out, &header);
     out.extend_from_slice(&metadata_bytes);
-    out.extend_from_slice(&compressed);
+    out.extend_from_slice(compressed);
     out
 }
 
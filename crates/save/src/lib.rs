@@ -1,20 +1,27 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod atomic_write;
 mod autosave_bridge;
+mod background_save;
+pub mod compression;
 mod crash_recovery;
 mod despawn;
 mod exclusive_load;
 mod exclusive_new_game;
 mod exclusive_save;
 mod file_header;
+mod replay_checkpoint;
 mod reset_resources;
 mod restore_resources;
 mod save_codec;
+mod save_decode;
+pub mod save_delta;
 pub mod save_error;
+pub mod save_json;
 pub mod save_metadata;
 mod save_migrate;
 mod save_migrate_registry;
 mod save_plugin;
+pub mod save_repair;
 mod save_restore;
 pub mod save_stages;
 mod save_types;
@@ -25,16 +32,20 @@ mod spawn_entities;
 #[cfg(target_arch = "wasm32")]
 mod wasm_idb;
 
+#[cfg(test)]
+mod save_fixture_tests;
 #[cfg(test)]
 mod save_fuzz_mutation_tests;
 #[cfg(test)]
 mod save_fuzz_tests;
 
+pub use background_save::BackgroundSave;
 pub use crash_recovery::CrashRecoveryState;
 pub use file_header::read_metadata_only;
 pub use save_error::SaveError;
 pub use save_metadata::SaveMetadata;
 pub use save_plugin::{LoadGameEvent, NewGameEvent, PendingSavePath, SaveGameEvent, SavePlugin};
+pub use save_repair::LoadReport;
 pub use saveable_ext::SaveableAppExt;
 
 #[cfg(not(target_arch = "wasm32"))]
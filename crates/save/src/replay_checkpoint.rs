@@ -0,0 +1,133 @@
+//! Replay checkpoint codec: full game state snapshots for replay scrubbing.
+//!
+//! `simulation::replay` takes checkpoints while a replay plays but cannot
+//! see the save pipeline, so this crate installs a `CheckpointCodec` that
+//! captures the same `SaveData` a save file holds and restores it the way a
+//! load does. Checkpoints are zstd-compressed when the build supports it.
+//!
+//! Checkpoints are written into binary replay files, so a build with other
+//! features or a newer save version may restore them. Each is stored as a
+//! [`StoredCheckpoint`] recording its compression and save version, and is
+//! migrated on restore like a loaded save. Checkpoints taken before the
+//! wrapper existed are bare `SaveData` payloads and still restore.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use simulation::replay::CheckpointCodec;
+
+use crate::exclusive_load::apply_save_data;
+use crate::exclusive_save::collect_save_data;
+use crate::save_migrate::migrate_save;
+use crate::serialization::SaveData;
+
+/// The codec `SavePlugin` installs.
+pub(crate) const CODEC: CheckpointCodec = CheckpointCodec {
+    capture: capture_checkpoint,
+    restore: restore_checkpoint,
+};
+
+/// A checkpoint's bytes as kept in memory and in replay files.
+#[derive(Encode, Decode)]
+struct StoredCheckpoint {
+    /// `SaveData::version` of the build that took the checkpoint.
+    version: u32,
+    /// Whether `payload` is a zstd frame rather than plain bitcode.
+    zstd: bool,
+    payload: Vec<u8>,
+}
+
+fn capture_checkpoint(world: &mut World) -> Result<Vec<u8>, String> {
+    let (save, _metadata) = collect_save_data(world).map_err(|e| e.to_string())?;
+    encode_checkpoint(&save)
+}
+
+fn restore_checkpoint(world: &mut World, bytes: &[u8]) -> Result<(), String> {
+    let save = decode_checkpoint(bytes)?;
+    apply_save_data(world, &save).map_err(|e| e.to_string())
+}
+
+fn encode_checkpoint(save: &SaveData) -> Result<Vec<u8>, String> {
+    let payload = save.encode();
+    let zstd = cfg!(all(feature = "zstd", not(target_arch = "wasm32")));
+    #[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+    let payload = crate::compression::compress_zstd(&payload)?;
+    Ok(bitcode::encode(&StoredCheckpoint {
+        version: save.version,
+        zstd,
+        payload,
+    }))
+}
+
+/// Decode a checkpoint and migrate it to the current save version.
+fn decode_checkpoint(bytes: &[u8]) -> Result<SaveData, String> {
+    let mut save = match bitcode::decode::<StoredCheckpoint>(bytes) {
+        Ok(stored) => {
+            let save = SaveData::decode_payload(&stored.payload, stored.zstd)
+                .map_err(|e| e.to_string())?;
+            if save.version != stored.version {
+                return Err(format!(
+                    "checkpoint recorded as v{} holds v{}",
+                    stored.version, save.version
+                ));
+            }
+            save
+        }
+        // Taken before checkpoints recorded their format.
+        Err(_) => SaveData::decode(bytes).map_err(|e| e.to_string())?,
+    };
+    migrate_save(&mut save).map_err(|e| e.to_string())?;
+    Ok(save)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save_types::CURRENT_SAVE_VERSION;
+    use crate::serialization::tests_save_error::minimal_save;
+
+    #[test]
+    fn test_checkpoint_records_version_and_compression() {
+        let bytes = encode_checkpoint(&minimal_save(CURRENT_SAVE_VERSION)).unwrap();
+        let stored: StoredCheckpoint = bitcode::decode(&bytes).unwrap();
+        assert_eq!(stored.version, CURRENT_SAVE_VERSION);
+        assert_eq!(
+            stored.zstd,
+            cfg!(all(feature = "zstd", not(target_arch = "wasm32")))
+        );
+        assert_eq!(
+            decode_checkpoint(&bytes).unwrap().version,
+            CURRENT_SAVE_VERSION
+        );
+    }
+
+    #[test]
+    fn test_old_checkpoint_is_migrated() {
+        let old = minimal_save(CURRENT_SAVE_VERSION - 1);
+        let bytes = bitcode::encode(&StoredCheckpoint {
+            version: old.version,
+            zstd: false,
+            payload: old.encode(),
+        });
+        let save = decode_checkpoint(&bytes).unwrap();
+        assert_eq!(save.version, CURRENT_SAVE_VERSION);
+    }
+
+    #[test]
+    fn test_bare_checkpoint_still_restores() {
+        let bytes = minimal_save(CURRENT_SAVE_VERSION).encode();
+        assert_eq!(
+            decode_checkpoint(&bytes).unwrap().version,
+            CURRENT_SAVE_VERSION
+        );
+    }
+
+    #[test]
+    fn test_mismatched_version_is_rejected() {
+        let bytes = bitcode::encode(&StoredCheckpoint {
+            version: CURRENT_SAVE_VERSION - 1,
+            zstd: false,
+            payload: minimal_save(CURRENT_SAVE_VERSION).encode(),
+        });
+        assert!(decode_checkpoint(&bytes).is_err());
+    }
+}
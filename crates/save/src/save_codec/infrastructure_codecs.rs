@@ -43,6 +43,7 @@ pub fn policy_to_u8(p: Policy) -> u8 {
         Policy::TaxIncentiveZone => 26,
         Policy::PetBan => 27,
         Policy::ParksAndRec => 28,
+        Policy::SeismicRetrofit => 29,
     }
 }
 
@@ -78,6 +79,7 @@ pub fn u8_to_policy(v: u8) -> Option<Policy> {
         26 => Some(Policy::TaxIncentiveZone),
         27 => Some(Policy::PetBan),
         28 => Some(Policy::ParksAndRec),
+        29 => Some(Policy::SeismicRetrofit),
         _ => None,
     }
 }
@@ -122,6 +124,12 @@ pub fn unlock_node_to_u8(n: UnlockNode) -> u8 {
         UnlockNode::WaterInfrastructure => 35,
         UnlockNode::RegionalAirports => 36,
         UnlockNode::InternationalAirports => 37,
+        UnlockNode::DenseCore => 38,
+        UnlockNode::GardenSuburbs => 39,
+        UnlockNode::TransitFirst => 40,
+        UnlockNode::CarCentric => 41,
+        UnlockNode::RenewableMandate => 42,
+        UnlockNode::NuclearProgram => 43,
     }
 }
 
@@ -165,6 +173,12 @@ pub fn u8_to_unlock_node(v: u8) -> Option<UnlockNode> {
         35 => Some(UnlockNode::WaterInfrastructure),
         36 => Some(UnlockNode::RegionalAirports),
         37 => Some(UnlockNode::InternationalAirports),
+        38 => Some(UnlockNode::DenseCore),
+        39 => Some(UnlockNode::GardenSuburbs),
+        40 => Some(UnlockNode::TransitFirst),
+        41 => Some(UnlockNode::CarCentric),
+        42 => Some(UnlockNode::RenewableMandate),
+        43 => Some(UnlockNode::NuclearProgram),
         _ => None,
     }
 }
@@ -98,6 +98,9 @@ pub fn service_type_to_u8(s: ServiceType) -> u8 {
         ServiceType::SubstanceAbuseTreatmentCenter => 53,
         ServiceType::SeniorCenter => 54,
         ServiceType::YouthCenter => 55,
+        ServiceType::HazmatResponse => 56,
+        ServiceType::Hotel => 57,
+        ServiceType::ConventionCenter => 58,
     }
 }
 
@@ -159,6 +162,9 @@ pub fn u8_to_service_type(v: u8) -> Option<ServiceType> {
         53 => Some(ServiceType::SubstanceAbuseTreatmentCenter),
         54 => Some(ServiceType::SeniorCenter),
         55 => Some(ServiceType::YouthCenter),
+        56 => Some(ServiceType::HazmatResponse),
+        57 => Some(ServiceType::Hotel),
+        58 => Some(ServiceType::ConventionCenter),
         _ => None,
     }
 }
@@ -0,0 +1,96 @@
+// ---------------------------------------------------------------------------
+// save_decode – Save file bytes to a current-version SaveData
+// ---------------------------------------------------------------------------
+//
+// The one pipeline every reader of a save file goes through: validate the
+// header, decompress an LZ4 payload (zstd payloads are decompressed by
+// `SaveData::decode_payload`), decode, apply the delta saved beside the
+// snapshot and migrate. Used by the in-game load and by the JSON export, so
+// the export always sees exactly what a load would.
+
+use bevy::prelude::*;
+
+use crate::file_header::{decompress_payload, unwrap_header, UnwrapResult};
+use crate::save_error::SaveError;
+use crate::save_migrate_registry::MigrationReport;
+use crate::serialization::{migrate_save_with_report, SaveData};
+
+/// Decode the save file `bytes`, apply `delta` (the bytes of the delta file
+/// beside it, if any) and migrate the result to the current version.
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+pub(crate) fn decode_save_file(
+    bytes: &[u8],
+    delta: Option<&[u8]>,
+) -> Result<(SaveData, MigrationReport), SaveError> {
+    // -- Validate file header and extract payload --
+    let (raw_payload, is_compressed, zstd, checksum) = match unwrap_header(bytes) {
+        Ok(UnwrapResult::WithHeader {
+            header,
+            metadata,
+            payload,
+        }) => {
+            info!(
+                "Save file header: format v{}, flags {:#X}, timestamp {}, \
+                 data size {}, checksum {:#010X}, metadata size {}",
+                header.format_version,
+                header.flags,
+                header.timestamp,
+                header.uncompressed_size,
+                header.checksum,
+                header.metadata_size,
+            );
+            if let Some(ref meta) = metadata {
+                info!(
+                    "Save metadata: city='{}', pop={}, treasury={:.0}, day={}, hour={:.1}, play_time={:.0}s",
+                    meta.city_name,
+                    meta.population,
+                    meta.treasury,
+                    meta.day,
+                    meta.hour,
+                    meta.play_time_seconds,
+                );
+            }
+            (
+                payload,
+                header.is_compressed(),
+                Some(header.is_zstd()),
+                Some(header.checksum),
+            )
+        }
+        Ok(UnwrapResult::Legacy(payload)) => {
+            info!("Loading legacy save file (no header)");
+            (payload, false, None, None)
+        }
+        Err(e) => {
+            return Err(SaveError::Decode(format!("Invalid file header: {e}")));
+        }
+    };
+
+    // -- Decompress if the LZ4 flag is set --
+    let decompressed;
+    let decode_input = if is_compressed {
+        decompressed = decompress_payload(raw_payload).map_err(SaveError::Decode)?;
+        info!(
+            "Decompressed save: {} bytes -> {} bytes",
+            raw_payload.len(),
+            decompressed.len(),
+        );
+        decompressed.as_slice()
+    } else {
+        raw_payload
+    };
+
+    // -- Parse, apply the delta and migrate --
+    let mut save = match zstd {
+        Some(zstd) => SaveData::decode_payload(decode_input, zstd)?,
+        None => SaveData::decode(decode_input)?,
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(delta) = delta {
+        save = crate::save_delta::apply_delta_file(save, checksum, delta)?;
+    }
+
+    let report = migrate_save_with_report(&mut save)?;
+    Ok((save, report))
+}
@@ -0,0 +1,133 @@
+//! Computing and applying a `SaveDelta`.
+
+use bitcode::{Decode, Encode};
+
+use crate::save_error::SaveError;
+use crate::save_types::{SaveBuilding, SaveCell, SaveCitizen, SaveData};
+
+/// The difference between a base snapshot and a later save.
+///
+/// Grid cells, buildings and citizens are stored as the entries that differ
+/// from the base, by index, plus the new length; everything else is small
+/// and is stored in full in `rest`.
+#[derive(Encode, Decode)]
+pub struct SaveDelta {
+    /// Payload checksum from the base snapshot's file header.
+    pub base_checksum: u32,
+    pub cell_count: u32,
+    pub changed_cells: Vec<(u32, SaveCell)>,
+    pub building_count: u32,
+    pub changed_buildings: Vec<(u32, SaveBuilding)>,
+    pub citizen_count: u32,
+    pub changed_citizens: Vec<(u32, SaveCitizen)>,
+    /// The later save with its grid cells, buildings and citizens taken out.
+    pub rest: SaveData,
+}
+
+impl SaveDelta {
+    /// The delta that turns `base` into `current`.
+    pub fn diff(base: &SaveData, mut current: SaveData, base_checksum: u32) -> Self {
+        let (cell_count, changed_cells) =
+            diff_list(&base.grid.cells, std::mem::take(&mut current.grid.cells));
+        let (building_count, changed_buildings) =
+            diff_list(&base.buildings, std::mem::take(&mut current.buildings));
+        let (citizen_count, changed_citizens) =
+            diff_list(&base.citizens, std::mem::take(&mut current.citizens));
+        Self {
+            base_checksum,
+            cell_count,
+            changed_cells,
+            building_count,
+            changed_buildings,
+            citizen_count,
+            changed_citizens,
+            rest: current,
+        }
+    }
+
+    /// Rebuild the later save from `base`.
+    pub fn apply(self, mut base: SaveData) -> Result<SaveData, SaveError> {
+        let mut save = self.rest;
+        save.grid.cells = apply_list(
+            "grid cell",
+            std::mem::take(&mut base.grid.cells),
+            self.cell_count,
+            self.changed_cells,
+        )?;
+        save.buildings = apply_list(
+            "building",
+            std::mem::take(&mut base.buildings),
+            self.building_count,
+            self.changed_buildings,
+        )?;
+        save.citizens = apply_list(
+            "citizen",
+            std::mem::take(&mut base.citizens),
+            self.citizen_count,
+            self.changed_citizens,
+        )?;
+        Ok(save)
+    }
+
+    /// Number of changed cells, buildings and citizens.
+    pub fn changed_entries(&self) -> usize {
+        self.changed_cells.len() + self.changed_buildings.len() + self.changed_citizens.len()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bitcode::encode(self)
+    }
+
+    /// Decode a delta payload: a zstd frame when its header has `FLAG_ZSTD`
+    /// (`zstd`), bitcode otherwise.
+    pub fn decode(bytes: &[u8], zstd: bool) -> Result<Self, SaveError> {
+        if zstd {
+            let raw = crate::compression::decompress_zstd(bytes).map_err(SaveError::Decode)?;
+            return Ok(bitcode::decode(&raw)?);
+        }
+        Ok(bitcode::decode(bytes)?)
+    }
+}
+
+/// New length of `current`, and its entries that differ from `base`.
+fn diff_list<T: PartialEq>(base: &[T], current: Vec<T>) -> (u32, Vec<(u32, T)>) {
+    let len = current.len() as u32;
+    let changed = current
+        .into_iter()
+        .enumerate()
+        .filter(|(i, item)| base.get(*i) != Some(item))
+        .map(|(i, item)| (i as u32, item))
+        .collect();
+    (len, changed)
+}
+
+/// Resize `base` to `len` and overwrite the changed entries, which must
+/// cover every entry past the end of `base`.
+fn apply_list<T>(
+    what: &str,
+    mut base: Vec<T>,
+    len: u32,
+    changed: Vec<(u32, T)>,
+) -> Result<Vec<T>, SaveError> {
+    base.truncate(len as usize);
+    for (i, item) in changed {
+        let i = i as usize;
+        match i.cmp(&base.len()) {
+            std::cmp::Ordering::Less => base[i] = item,
+            std::cmp::Ordering::Equal => base.push(item),
+            std::cmp::Ordering::Greater => {
+                return Err(SaveError::Decode(format!(
+                    "Save delta skips {what} {}; the delta does not match its snapshot.",
+                    base.len()
+                )));
+            }
+        }
+    }
+    if base.len() != len as usize {
+        return Err(SaveError::Decode(format!(
+            "Save delta has {} of {len} {what} entries; the delta does not match its snapshot.",
+            base.len()
+        )));
+    }
+    Ok(base)
+}
@@ -0,0 +1,101 @@
+//! Writing saves as snapshots or deltas, and applying a delta on load.
+
+use bevy::prelude::*;
+
+use super::{delta_file_path, BaseSnapshot, DeltaSaveSettings, SaveDelta};
+use crate::file_header::{
+    decompress_payload, unwrap_header, wrap_with_header_for_save,
+    wrap_with_header_for_save_flagged, UnwrapResult, FLAG_DELTA,
+};
+use crate::save_error::SaveError;
+use crate::save_metadata::SaveMetadata;
+use crate::save_types::SaveData;
+
+/// Write `save` to `path`: as a delta against `base` when delta saves are on
+/// and `base` is a snapshot of `path` that isn't due a full snapshot,
+/// otherwise as a full snapshot that starts a new chain. Returns the base for
+/// the next save. Runs off the main thread, so it takes no world access.
+pub(crate) fn write_save(
+    base: Option<BaseSnapshot>,
+    settings: &DeltaSaveSettings,
+    path: &str,
+    save: SaveData,
+    metadata: &SaveMetadata,
+) -> Result<Option<BaseSnapshot>, SaveError> {
+    let delta_path = delta_file_path(path);
+
+    if let Some(mut snapshot) = base.filter(|snapshot| {
+        settings.enabled
+            && snapshot.path == path
+            && snapshot.deltas_written < settings.full_snapshot_interval
+    }) {
+        let delta = SaveDelta::diff(&snapshot.save, save, snapshot.checksum);
+        let bytes = wrap_with_header_for_save_flagged(&delta.encode(), FLAG_DELTA, metadata);
+        crate::atomic_write::atomic_write(&delta_path, &bytes)?;
+        snapshot.deltas_written += 1;
+        info!(
+            "Saved delta of {} entries ({} bytes) to {}",
+            delta.changed_entries(),
+            bytes.len(),
+            delta_path
+        );
+        return Ok(Some(snapshot));
+    }
+
+    let bytes = wrap_with_header_for_save(&save.encode(), metadata);
+    crate::atomic_write::atomic_write(path, &bytes)?;
+    info!("Saved {} bytes to {}", bytes.len(), path);
+
+    // A full snapshot supersedes any delta written against the previous one.
+    if let Err(e) = std::fs::remove_file(&delta_path) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!("Failed to remove stale save delta {}: {}", delta_path, e);
+        }
+    }
+    Ok(match unwrap_header(&bytes) {
+        Ok(UnwrapResult::WithHeader { header, .. }) if settings.enabled => Some(BaseSnapshot {
+            path: path.to_string(),
+            checksum: header.checksum,
+            save,
+            deltas_written: 0,
+        }),
+        _ => None,
+    })
+}
+
+/// Read the delta beside the save at `path`, if there is one.
+pub(crate) fn read_delta_file(path: &str) -> Option<Vec<u8>> {
+    std::fs::read(delta_file_path(path)).ok()
+}
+
+/// Rebuild a save from its snapshot `base`, whose header checksum is
+/// `base_checksum`, and the bytes of the delta file beside it. A delta taken
+/// against a different snapshot is ignored.
+pub(crate) fn apply_delta_file(
+    base: SaveData,
+    base_checksum: Option<u32>,
+    bytes: &[u8],
+) -> Result<SaveData, SaveError> {
+    let header_err = |e: String| SaveError::Decode(format!("Invalid save delta: {e}"));
+    let (header, payload) = match unwrap_header(bytes).map_err(header_err)? {
+        UnwrapResult::WithHeader {
+            header, payload, ..
+        } if header.is_delta() => (header, payload),
+        _ => return Err(header_err("missing delta header".to_string())),
+    };
+    let decompressed;
+    let payload = if header.is_compressed() {
+        decompressed = decompress_payload(payload).map_err(SaveError::Decode)?;
+        decompressed.as_slice()
+    } else {
+        payload
+    };
+
+    let delta = SaveDelta::decode(payload, header.is_zstd())?;
+    if Some(delta.base_checksum) != base_checksum {
+        warn!("Ignoring a save delta taken against a different snapshot");
+        return Ok(base);
+    }
+    info!("Applying save delta of {} entries", delta.changed_entries());
+    delta.apply(base)
+}
@@ -0,0 +1,80 @@
+// ---------------------------------------------------------------------------
+// save_delta – Differential saves: full snapshots plus per-save deltas
+// ---------------------------------------------------------------------------
+//
+// Serializing every cell and citizen on each save is most of the save cost in
+// a large city, yet little of it changes between saves. With delta saves on
+// (`DeltaSaveSettings`), a save to the same path as the last full snapshot
+// writes only a `SaveDelta` against that snapshot: the changed grid cells,
+// buildings and citizens, plus the remaining (small) sections in full.
+// Every `full_snapshot_interval` saves, or when the path changes, a full
+// snapshot is written again and the delta chain restarts.
+//
+// Files (native only; WASM saves are always full snapshots):
+//   <path>        the last full snapshot, loadable on its own
+//   <path>.delta  the latest delta, header flag FLAG_DELTA, keyed to the
+//                 snapshot by the snapshot's payload checksum
+//
+// Deltas are cumulative against the snapshot, so loading needs the snapshot
+// and the latest delta only. A delta whose checksum doesn't match the
+// snapshot beside it (e.g. a crash between writing a new snapshot and
+// removing the old delta) is stale and ignored.
+
+mod diff;
+#[cfg(not(target_arch = "wasm32"))]
+mod io;
+#[cfg(test)]
+mod tests;
+
+use bevy::prelude::*;
+
+pub use diff::SaveDelta;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use io::{apply_delta_file, read_delta_file, write_save};
+
+/// Saves between full snapshots by default.
+pub const DEFAULT_FULL_SNAPSHOT_INTERVAL: u32 = 10;
+
+/// Path of the delta written beside the snapshot at `path`.
+pub fn delta_file_path(path: &str) -> String {
+    format!("{path}.delta")
+}
+
+/// Controls differential saving.
+#[derive(Resource, Debug, Clone)]
+pub struct DeltaSaveSettings {
+    /// Whether saves may be written as deltas.
+    pub enabled: bool,
+    /// Deltas written against a snapshot before the next full snapshot.
+    pub full_snapshot_interval: u32,
+}
+
+impl Default for DeltaSaveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            full_snapshot_interval: DEFAULT_FULL_SNAPSHOT_INTERVAL,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// The last full snapshot written this session, which deltas are taken
+/// against. Loading a save does not set it, so the first save after a load
+/// is always a full snapshot.
+#[derive(Resource, Default)]
+pub(crate) struct DeltaSaveBase(pub(crate) Option<BaseSnapshot>);
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct BaseSnapshot {
+    pub(crate) path: String,
+    /// Payload checksum from the snapshot's file header.
+    pub(crate) checksum: u32,
+    pub(crate) save: crate::save_types::SaveData,
+    pub(crate) deltas_written: u32,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Delta file found beside the save being loaded, consumed by the load.
+#[derive(Resource, Default)]
+pub(crate) struct PendingDeltaBytes(pub(crate) Option<Vec<u8>>);
@@ -0,0 +1,132 @@
+use super::*;
+use crate::save_types::{SaveBuilding, SaveCell, SaveData, CURRENT_SAVE_VERSION};
+use crate::serialization::tests_save_error::minimal_save;
+
+fn cell(elevation: f32, zone: u8) -> SaveCell {
+    SaveCell {
+        elevation,
+        cell_type: 0,
+        zone,
+        road_type: 0,
+        has_power: false,
+        has_water: false,
+    }
+}
+
+fn building(grid_x: usize, occupants: u32) -> SaveBuilding {
+    SaveBuilding {
+        zone_type: 1,
+        level: 1,
+        grid_x,
+        grid_y: 0,
+        capacity: 10,
+        occupants,
+        commercial_capacity: 0,
+        commercial_occupants: 0,
+        residential_capacity: 0,
+        residential_occupants: 0,
+    }
+}
+
+fn city(zones: &[u8], occupants: &[u32], day: u32) -> SaveData {
+    let mut save = minimal_save(CURRENT_SAVE_VERSION);
+    save.grid.width = zones.len();
+    save.grid.cells = zones.iter().map(|&z| cell(1.0, z)).collect();
+    save.buildings = occupants
+        .iter()
+        .enumerate()
+        .map(|(x, &o)| building(x, o))
+        .collect();
+    save.clock.day = day;
+    save
+}
+
+fn assert_same(a: &SaveData, b: &SaveData) {
+    assert_eq!(a.encode(), b.encode());
+}
+
+#[test]
+fn test_delta_stores_only_changed_entries() {
+    let base = city(&[0, 0, 0, 0], &[1, 2, 3], 1);
+    let current = city(&[0, 5, 0, 0], &[1, 2, 9], 40);
+    let delta = SaveDelta::diff(&base, city(&[0, 5, 0, 0], &[1, 2, 9], 40), 7);
+
+    assert_eq!(delta.base_checksum, 7);
+    assert_eq!(delta.changed_cells.len(), 1);
+    assert_eq!(delta.changed_cells[0].0, 1);
+    assert_eq!(delta.changed_buildings.len(), 1);
+    assert_eq!(delta.changed_buildings[0].0, 2);
+    assert!(delta.changed_citizens.is_empty());
+    assert_eq!(delta.changed_entries(), 2);
+
+    let rebuilt = delta.apply(base).unwrap();
+    assert_eq!(rebuilt.clock.day, 40);
+    assert_same(&rebuilt, &current);
+}
+
+#[test]
+fn test_delta_handles_growing_and_shrinking_lists() {
+    let base = city(&[0, 0], &[1, 2, 3], 1);
+
+    let grown = city(&[0, 0, 4], &[1, 2, 3, 4, 5], 2);
+    let delta = SaveDelta::diff(&base, city(&[0, 0, 4], &[1, 2, 3, 4, 5], 2), 0);
+    assert_same(&delta.apply(city(&[0, 0], &[1, 2, 3], 1)).unwrap(), &grown);
+
+    let shrunk = city(&[0, 0], &[1], 3);
+    let delta = SaveDelta::diff(&base, city(&[0, 0], &[1], 3), 0);
+    assert!(delta.changed_buildings.is_empty());
+    assert_same(&delta.apply(base).unwrap(), &shrunk);
+}
+
+#[test]
+fn test_delta_against_wrong_snapshot_is_an_error() {
+    let base = city(&[0, 0], &[1], 1);
+    let delta = SaveDelta::diff(&base, city(&[0, 0], &[1, 2, 3], 2), 0);
+    // A snapshot with fewer buildings than the delta expects to extend.
+    let err = delta.apply(city(&[0, 0], &[], 1));
+    assert!(matches!(err, Err(crate::save_error::SaveError::Decode(_))));
+}
+
+#[test]
+fn test_delta_encode_roundtrip() {
+    let base = city(&[0, 0, 0], &[1, 2], 1);
+    let delta = SaveDelta::diff(&base, city(&[3, 0, 0], &[1, 2], 5), 42);
+    let decoded = SaveDelta::decode(&delta.encode(), false).unwrap();
+    assert_eq!(decoded.base_checksum, 42);
+    assert!(decoded.changed_cells == delta.changed_cells);
+    assert_same(&decoded.rest, &delta.rest);
+}
+
+#[test]
+fn test_delta_file_path() {
+    assert_eq!(
+        delta_file_path("saves/slot_1.bin"),
+        "saves/slot_1.bin.delta"
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_delta_file_applies_only_to_its_snapshot() {
+    use crate::file_header::{wrap_with_header_for_save, wrap_with_header_for_save_flagged};
+    use crate::save_metadata::SaveMetadata;
+
+    let base = city(&[0, 0], &[1], 1);
+    let delta = SaveDelta::diff(&base, city(&[2, 0], &[1], 9), 1234);
+    let bytes = wrap_with_header_for_save_flagged(
+        &delta.encode(),
+        crate::file_header::FLAG_DELTA,
+        &SaveMetadata::default(),
+    );
+
+    let rebuilt = apply_delta_file(city(&[0, 0], &[1], 1), Some(1234), &bytes).unwrap();
+    assert_eq!(rebuilt.clock.day, 9);
+
+    // A delta from another chain leaves the snapshot as it is.
+    let unchanged = apply_delta_file(city(&[0, 0], &[1], 1), Some(99), &bytes).unwrap();
+    assert_eq!(unchanged.clock.day, 1);
+
+    // A full save is not a delta.
+    let full = wrap_with_header_for_save(&base.encode(), &SaveMetadata::default());
+    assert!(apply_delta_file(base, Some(1234), &full).is_err());
+}
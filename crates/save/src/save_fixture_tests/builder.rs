@@ -0,0 +1,135 @@
+//! The city the fixture recorder saves: a small grid with buildings,
+//! services, citizens and the optional sections of the current save format.
+
+use crate::save_types::*;
+use crate::serialization::tests_save_error::minimal_save;
+
+/// Side of the fixture grid.
+const SIZE: usize = 8;
+
+/// The fixture city as saved by this build.
+pub(super) fn fixture_city() -> SaveData {
+    let version = CURRENT_SAVE_VERSION;
+    let mut save = minimal_save(version);
+    save.grid = SaveGrid {
+        cells: (0..SIZE * SIZE).map(cell).collect(),
+        width: SIZE,
+        height: SIZE,
+    };
+    save.roads.road_positions = (0..SIZE).map(|x| (x, 3)).collect();
+    save.clock = SaveClock {
+        day: 120 + version,
+        hour: 9.5,
+        speed: 1.0,
+    };
+    save.budget = SaveBudget {
+        treasury: 48_250.75 + f64::from(version),
+        tax_rate: 0.1,
+        last_collection_day: 119,
+    };
+    save.buildings = vec![
+        building(1, 1, 1, 12),
+        building(2, 1, 1, 9),
+        building(5, 5, 2, 20),
+    ];
+    save.service_buildings = vec![SaveServiceBuilding {
+        service_type: 0,
+        grid_x: 6,
+        grid_y: 1,
+        radius_cells: 10,
+    }];
+    save.utility_sources = vec![SaveUtilitySource {
+        utility_type: 0,
+        grid_x: 0,
+        grid_y: 7,
+        range: 30,
+    }];
+    save.citizens = vec![
+        citizen((1, 1), (5, 5), 34),
+        citizen((1, 1), (5, 5), 31),
+        citizen((2, 1), (6, 1), 58),
+    ];
+    save.citizens[0].family_partner = 1;
+    save.citizens[1].family_partner = 0;
+    save.policies = Some(SavePolicies { active: vec![0, 3] });
+    save.unlock_state = Some(SaveUnlockState {
+        development_points: 12,
+        spent_points: 4,
+        unlocked_nodes: vec![0, 1],
+        last_milestone_pop: 1_000,
+    });
+    save.virtual_population = Some(SaveVirtualPopulation {
+        total_virtual: 1_200,
+        virtual_employed: 900,
+        district_stats: Vec::new(),
+        max_real_citizens: 10_000,
+    });
+    save
+}
+
+fn cell(i: usize) -> SaveCell {
+    let (x, y) = (i % SIZE, i / SIZE);
+    SaveCell {
+        elevation: 0.25 + (x * y) as f32 * 0.01,
+        cell_type: match (x, y) {
+            (_, 3) => 2,
+            (7, _) => 1,
+            _ => 0,
+        },
+        zone: (x % 4) as u8,
+        road_type: u8::from(y == 3),
+        has_power: y < 6,
+        has_water: x < 6,
+    }
+}
+
+fn building(grid_x: usize, grid_y: usize, zone_type: u8, occupants: u32) -> SaveBuilding {
+    SaveBuilding {
+        zone_type,
+        level: 2,
+        grid_x,
+        grid_y,
+        capacity: 40,
+        occupants,
+        commercial_capacity: 0,
+        commercial_occupants: 0,
+        residential_capacity: 0,
+        residential_occupants: 0,
+    }
+}
+
+fn citizen(home: (usize, usize), work: (usize, usize), age: u8) -> SaveCitizen {
+    SaveCitizen {
+        age,
+        happiness: 64.0,
+        education: 2,
+        state: 0,
+        home_x: home.0,
+        home_y: home.1,
+        work_x: work.0,
+        work_y: work.1,
+        path_waypoints: vec![(1, 3), (4, 3)],
+        path_current_index: 0,
+        velocity_x: 0.0,
+        velocity_y: 0.0,
+        pos_x: 24.0,
+        pos_y: 24.0,
+        gender: age % 2,
+        health: 80.0,
+        salary: 3_200.0,
+        savings: 5_000.0,
+        ambition: 0.5,
+        sociability: 0.5,
+        materialism: 0.5,
+        resilience: 0.5,
+        need_hunger: 80.0,
+        need_energy: 80.0,
+        need_social: 70.0,
+        need_fun: 70.0,
+        need_comfort: 60.0,
+        activity_timer: 0,
+        family_partner: u32::MAX,
+        family_children: vec![],
+        family_parent: u32::MAX,
+    }
+}
@@ -0,0 +1,210 @@
+// ---------------------------------------------------------------------------
+// save_fixture_tests – Migration regression harness over versioned fixtures
+// ---------------------------------------------------------------------------
+//
+// `crates/save/fixtures/saves` holds binary saves written by past builds,
+// `v{N}.bin` for the build that shipped save version N, beside
+// `v{N}.expected`: the population, treasury and grid checksum that city must
+// still have once loaded and migrated to `CURRENT_SAVE_VERSION`. Fixtures are
+// never rewritten, so each keeps the exact bytes of the build that recorded
+// it. A fixture that stops decoding means `SaveData`'s binary layout changed
+// in a way old saves can't survive; a changed invariant means a migration
+// corrupted the city.
+//
+// Tests only ever read fixtures. A fixture is recorded by the build that
+// introduces its save version, through the ignored recorder:
+//
+//     cargo test -p save record_current_save_fixture -- --ignored
+//
+// which writes `builder::fixture_city` for `CURRENT_SAVE_VERSION` only and
+// refuses to overwrite an existing fixture. Commit the two files with the
+// version bump. Fixtures of versions older than the running build can only
+// come from the builds that wrote them.
+
+mod builder;
+
+use std::path::PathBuf;
+
+use xxhash_rust::xxh32::xxh32;
+
+use crate::file_header::{decompress_payload, unwrap_header, wrap_with_header, UnwrapResult};
+use crate::save_migrate::migrate_save;
+use crate::save_types::{SaveData, CURRENT_SAVE_VERSION};
+use builder::fixture_city;
+
+/// What must survive loading a fixture.
+#[derive(Debug, PartialEq)]
+struct Invariants {
+    population: u64,
+    treasury: f64,
+    grid_checksum: u32,
+}
+
+impl Invariants {
+    fn of(save: &SaveData) -> Self {
+        let mut cells = Vec::with_capacity(save.grid.cells.len() * 9);
+        for c in &save.grid.cells {
+            cells.extend_from_slice(&c.elevation.to_le_bytes());
+            cells.extend_from_slice(&[c.cell_type, c.zone, c.road_type]);
+            cells.extend_from_slice(&[u8::from(c.has_power), u8::from(c.has_water)]);
+        }
+        let virtual_population = save
+            .virtual_population
+            .as_ref()
+            .map_or(0, |v| v.total_virtual);
+        Self {
+            population: save.citizens.len() as u64 + u64::from(virtual_population),
+            treasury: save.budget.treasury,
+            grid_checksum: xxh32(&cells, 0),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        format!(
+            "population = {}\ntreasury = {}\ngrid_checksum = {:#010x}\n",
+            self.population, self.treasury, self.grid_checksum
+        )
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let value = |key: &str| {
+            text.lines()
+                .filter_map(|line| line.split_once('='))
+                .find(|(k, _)| k.trim() == key)
+                .map(|(_, v)| v.trim().to_string())
+        };
+        let checksum = value("grid_checksum")?;
+        Some(Self {
+            population: value("population")?.parse().ok()?,
+            treasury: value("treasury")?.parse().ok()?,
+            grid_checksum: u32::from_str_radix(checksum.trim_start_matches("0x"), 16).ok()?,
+        })
+    }
+}
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/saves")
+}
+
+fn fixture_path(version: u32, extension: &str) -> PathBuf {
+    fixtures_dir().join(format!("v{version}.{extension}"))
+}
+
+/// Save version of a fixture file name, `v{N}.bin`.
+fn fixture_version(file_name: &str) -> Option<u32> {
+    file_name
+        .strip_prefix('v')?
+        .strip_suffix(".bin")?
+        .parse()
+        .ok()
+}
+
+/// Save versions that have a recorded fixture, in ascending order.
+fn recorded_versions() -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir(fixtures_dir()) else {
+        return Vec::new();
+    };
+    let mut versions: Vec<u32> = entries
+        .filter_map(|entry| fixture_version(entry.ok()?.file_name().to_str()?))
+        .collect();
+    versions.sort_unstable();
+    versions
+}
+
+/// Load the fixture of `version` the way the game does and check it.
+fn check_fixture(version: u32) -> Result<(), String> {
+    let bytes = std::fs::read(fixture_path(version, "bin")).map_err(|e| e.to_string())?;
+    let decoded = match unwrap_header(&bytes)? {
+        UnwrapResult::WithHeader {
+            header, payload, ..
+        } if header.is_compressed() => decompress_payload(payload)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| SaveData::decode_payload(&raw, false).map_err(|e| e.to_string())),
+        UnwrapResult::WithHeader {
+            header, payload, ..
+        } => SaveData::decode_payload(payload, header.is_zstd()).map_err(|e| e.to_string()),
+        UnwrapResult::Legacy(payload) => SaveData::decode(payload).map_err(|e| e.to_string()),
+    };
+    let mut save = decoded.map_err(|e| format!("no longer decodes: {e}"))?;
+    if save.version != version {
+        return Err(format!("stored as v{}", save.version));
+    }
+    migrate_save(&mut save).map_err(|e| format!("migration failed: {e}"))?;
+    if save.version != CURRENT_SAVE_VERSION {
+        return Err(format!("migrated only to v{}", save.version));
+    }
+
+    let text = std::fs::read_to_string(fixture_path(version, "expected"))
+        .map_err(|e| format!("missing invariants: {e}"))?;
+    let expected = Invariants::parse(&text).ok_or_else(|| "unreadable invariants".to_string())?;
+    let actual = Invariants::of(&save);
+    if actual != expected {
+        return Err(format!("expected {expected:?}, got {actual:?}"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_every_recorded_save_fixture_migrates_intact() {
+    let versions = recorded_versions();
+    assert!(
+        versions.contains(&CURRENT_SAVE_VERSION),
+        "no fixture for v{CURRENT_SAVE_VERSION}; record it with \
+         `cargo test -p save record_current_save_fixture -- --ignored`"
+    );
+    let failures: Vec<String> = versions
+        .iter()
+        .filter_map(|&v| check_fixture(v).err().map(|e| format!("v{v}: {e}")))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(
+        versions.iter().all(|&v| v <= CURRENT_SAVE_VERSION),
+        "fixture from a newer build than v{CURRENT_SAVE_VERSION}: {versions:?}"
+    );
+}
+
+/// Records the fixture of `CURRENT_SAVE_VERSION` from this build. Run by
+/// hand when bumping the save version; never part of a normal test run.
+#[test]
+#[ignore = "writes into the source tree; run explicitly when bumping the save version"]
+fn record_current_save_fixture() {
+    let version = CURRENT_SAVE_VERSION;
+    assert!(
+        !fixture_path(version, "bin").exists(),
+        "v{version} already has a fixture; fixtures are never rewritten"
+    );
+    let save = fixture_city();
+    std::fs::create_dir_all(fixtures_dir()).unwrap();
+    std::fs::write(
+        fixture_path(version, "bin"),
+        wrap_with_header(&save.encode()),
+    )
+    .unwrap();
+    std::fs::write(
+        fixture_path(version, "expected"),
+        Invariants::of(&save).to_text(),
+    )
+    .unwrap();
+    check_fixture(version).unwrap();
+}
+
+#[test]
+fn test_fixture_version_reads_only_bin_fixtures() {
+    assert_eq!(fixture_version("v12.bin"), Some(12));
+    assert_eq!(fixture_version("v3.expected"), None);
+    assert_eq!(fixture_version("vx.bin"), None);
+    assert_eq!(fixture_version("notes.txt"), None);
+}
+
+#[test]
+fn test_fixture_city_needs_no_repair() {
+    let mut save = fixture_city();
+    let report = crate::save_repair::repair_save(&mut save);
+    assert!(report.is_clean(), "{report:?}");
+}
+
+#[test]
+fn test_invariants_text_roundtrip() {
+    let invariants = Invariants::of(&fixture_city());
+    assert_eq!(Invariants::parse(&invariants.to_text()), Some(invariants));
+}
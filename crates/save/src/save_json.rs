@@ -0,0 +1,151 @@
+// ---------------------------------------------------------------------------
+// save_json – Human-readable JSON export/import of SaveData
+// ---------------------------------------------------------------------------
+//
+// Tooling, modders and bug reports need to read and hand-edit city state, so
+// a `SaveData` can be written as pretty-printed JSON and read back. The JSON
+// mirrors the serde shape of `SaveData` field for field; extension entries
+// stay opaque bitcode byte arrays.
+//
+// Hand-edited JSON is untrusted, so `from_json` checks the structure beyond
+// what serde enforces (version range, grid size, coordinates inside the
+// grid, family references) before anything reaches the loader.
+//
+// On native builds `export_save_file` / `import_save_file` convert between
+// save files and JSON; the app exposes them as `--export-save <in> <out>`
+// and `--import-save <in.json> <out>`.
+
+use crate::save_error::SaveError;
+use crate::save_types::{SaveData, CURRENT_SAVE_VERSION, NO_WORKPLACE};
+
+/// Family reference meaning "no such relative".
+const NO_FAMILY_REF: u32 = u32::MAX;
+
+impl SaveData {
+    /// Pretty-printed JSON of this save. Fails on non-finite floats, which
+    /// JSON can't represent.
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        serde_json::to_string_pretty(self).map_err(|e| SaveError::Encode(e.to_string()))
+    }
+
+    /// Parse and validate a save from JSON written by `to_json` (possibly
+    /// hand-edited).
+    pub fn from_json(json: &str) -> Result<Self, SaveError> {
+        let save: SaveData = serde_json::from_str(json)
+            .map_err(|e| SaveError::Decode(format!("Invalid save JSON: {e}")))?;
+        save.validate_schema()?;
+        Ok(save)
+    }
+
+    /// Check the invariants the loader relies on but serde can't express.
+    pub fn validate_schema(&self) -> Result<(), SaveError> {
+        if self.version > CURRENT_SAVE_VERSION {
+            return Err(SaveError::VersionMismatch {
+                expected_max: CURRENT_SAVE_VERSION,
+                found: self.version,
+            });
+        }
+
+        let (width, height) = (self.grid.width, self.grid.height);
+        if width.checked_mul(height) != Some(self.grid.cells.len()) {
+            return Err(schema_error(format!(
+                "grid.cells has {} entries, expected width * height = {width} * {height}",
+                self.grid.cells.len()
+            )));
+        }
+        let in_grid = |x: usize, y: usize| x < width && y < height;
+
+        for (i, &(x, y)) in self.roads.road_positions.iter().enumerate() {
+            if !in_grid(x, y) {
+                return Err(schema_error(format!(
+                    "roads.road_positions[{i}] ({x}, {y}) is outside the grid"
+                )));
+            }
+        }
+        for (i, b) in self.buildings.iter().enumerate() {
+            if !in_grid(b.grid_x, b.grid_y) {
+                return Err(schema_error(format!(
+                    "buildings[{i}] at ({}, {}) is outside the grid",
+                    b.grid_x, b.grid_y
+                )));
+            }
+        }
+
+        let citizen_count = self.citizens.len();
+        let valid_ref = |r: u32| r == NO_FAMILY_REF || (r as usize) < citizen_count;
+        for (i, c) in self.citizens.iter().enumerate() {
+            let unemployed = (c.work_x, c.work_y) == (NO_WORKPLACE, NO_WORKPLACE);
+            if !in_grid(c.home_x, c.home_y) || !(unemployed || in_grid(c.work_x, c.work_y)) {
+                return Err(schema_error(format!(
+                    "citizens[{i}] lives or works outside the grid"
+                )));
+            }
+            let children_valid = c
+                .family_children
+                .iter()
+                .all(|&r| r != NO_FAMILY_REF && valid_ref(r));
+            if !valid_ref(c.family_partner) || !valid_ref(c.family_parent) || !children_valid {
+                return Err(schema_error(format!(
+                    "citizens[{i}] refers to a family member that doesn't exist"
+                )));
+            }
+        }
+
+        if !(0.0..=24.0).contains(&self.clock.hour) {
+            return Err(schema_error(format!(
+                "clock.hour {} is outside 0..=24",
+                self.clock.hour
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn schema_error(msg: String) -> SaveError {
+    SaveError::Decode(format!("Save JSON failed validation: {msg}"))
+}
+
+/// Read the save file at `path` (applying any delta beside it and migrating
+/// it to the current version) and write it to `json_path` as JSON.
+///
+/// Sections registered through `SaveableRegistry` are exported as they are
+/// stored: `extensions` maps each key to its opaque bitcode bytes, a JSON
+/// array of numbers, and those bytes are not meant to be hand-edited.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_save_file(path: &str, json_path: &str) -> Result<(), SaveError> {
+    let save = read_save_file(path)?;
+    crate::atomic_write::atomic_write(json_path, save.to_json()?.as_bytes())?;
+    Ok(())
+}
+
+/// Validate the JSON at `json_path` and write it to `path` as a full save.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn import_save_file(json_path: &str, path: &str) -> Result<(), SaveError> {
+    let mut save = SaveData::from_json(&std::fs::read_to_string(json_path)?)?;
+    crate::serialization::migrate_save(&mut save)?;
+
+    let metadata = crate::save_metadata::SaveMetadata {
+        population: save.citizens.len() as u32,
+        treasury: save.budget.treasury,
+        day: save.clock.day,
+        hour: save.clock.hour,
+        ..Default::default()
+    };
+    let bytes = crate::file_header::wrap_with_header_for_save(&save.encode(), &metadata);
+    crate::atomic_write::atomic_write(path, &bytes)?;
+
+    // The new snapshot invalidates any delta written against the old file.
+    match std::fs::remove_file(crate::save_delta::delta_file_path(path)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Decode the save file at `path` the way loading does, without a world.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_save_file(path: &str) -> Result<SaveData, SaveError> {
+    let bytes = std::fs::read(path)?;
+    let delta = crate::save_delta::read_delta_file(path);
+    let (save, _report) = crate::save_decode::decode_save_file(&bytes, delta.as_deref())?;
+    Ok(save)
+}
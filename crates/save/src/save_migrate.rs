@@ -245,11 +245,35 @@ pub(crate) fn build_migration_registry() -> MigrationRegistry {
                 // Family fields on SaveCitizen default correctly via serde.
             },
         },
+        // v32 -> v33: Linear unlocks became a branching tree.
+        MigrationStep {
+            from_version: 32,
+            description: "Refund development points spent on milestone unlocks",
+            migrate_fn: migrate_linear_unlocks,
+        },
     ];
 
     MigrationRegistry::new(steps, CURRENT_SAVE_VERSION)
 }
 
+/// Under the linear system, development points could be spent on nodes that
+/// milestones now hand out for free. Keep every node the player had, but only
+/// charge for those bought ahead of the milestone reached, so the rest can be
+/// spent on the branch points of the tree.
+fn migrate_linear_unlocks(save: &mut SaveData) {
+    let Some(unlocks) = save.unlock_state.as_mut() else {
+        return;
+    };
+    let bought_early: u32 = unlocks
+        .unlocked_nodes
+        .iter()
+        .filter_map(|&v| crate::save_codec::u8_to_unlock_node(v))
+        .filter(|node| node.required_population() > unlocks.last_milestone_pop)
+        .map(|node| node.cost())
+        .sum();
+    unlocks.spent_points = bought_early.min(unlocks.spent_points);
+}
+
 /// Migrate a `SaveData` from any older version up to `CURRENT_SAVE_VERSION`.
 ///
 /// Returns the original version so callers can log the migration.
@@ -431,6 +455,32 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_linear_unlocks_refunded() {
+        use simulation::unlocks::UnlockNode;
+        let mut save = minimal_save(32);
+        save.unlock_state = Some(SaveUnlockState {
+            development_points: 10,
+            spent_points: 6,
+            unlocked_nodes: [
+                UnlockNode::BasicRoads,
+                UnlockNode::HealthCare,
+                UnlockNode::HighSchoolEducation,
+            ]
+            .into_iter()
+            .map(crate::save_codec::unlock_node_to_u8)
+            .collect(),
+            last_milestone_pop: 1_200,
+        });
+        migrate_save(&mut save).unwrap();
+
+        // Health care came with the 240 milestone; the high school was
+        // bought ahead of its 2,600 milestone and stays paid for.
+        let unlocks = save.unlock_state.unwrap();
+        assert_eq!(unlocks.unlocked_nodes.len(), 3);
+        assert_eq!(unlocks.spent_points, UnlockNode::HighSchoolEducation.cost());
+    }
+
     #[test]
     fn test_partial_migration_step_count() {
         for start_version in 0..=CURRENT_SAVE_VERSION {
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use simulation::app_state::AppState;
-use simulation::notifications::{NotificationEvent, NotificationPriority};
+use simulation::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use simulation::PreLoadAppState;
 use simulation::SaveLoadState;
 use simulation::SaveableRegistry;
@@ -19,7 +19,8 @@ pub(crate) struct PendingLoadBytes(pub(crate) Option<Vec<u8>>);
 
 /// Optional override for the save/load file path.
 /// When set, the next save or load operation uses this path instead of the
-/// default `megacity_save.bin`. Consumed (reset to `None`) after use.
+/// default `megacity_save.bin`. Consumed (reset to `None`) after use. On WASM
+/// the path is the IndexedDB key.
 #[derive(Resource, Default)]
 pub struct PendingSavePath(pub Option<String>);
 
@@ -66,6 +67,9 @@ impl Plugin for SavePlugin {
             .init_resource::<SaveableRegistry>()
             .init_resource::<PendingLoadBytes>()
             .init_resource::<PendingSavePath>()
+            .init_resource::<crate::save_delta::DeltaSaveSettings>()
+            .init_resource::<crate::save_repair::LoadReport>()
+            .init_resource::<crate::background_save::BackgroundSave>()
             .init_resource::<PreLoadAppState>();
 
         // On WASM, register IndexedDB async load infrastructure.
@@ -81,7 +85,14 @@ impl Plugin for SavePlugin {
         // Native: synchronous load event detection (reads file, stores bytes,
         // transitions to Loading state).
         #[cfg(not(target_arch = "wasm32"))]
-        app.add_systems(Update, detect_load_event);
+        app.init_resource::<crate::save_delta::DeltaSaveBase>()
+            .init_resource::<crate::save_delta::PendingDeltaBytes>()
+            .add_systems(Update, detect_load_event);
+
+        // Native: saves are encoded and written on a background task.
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Update, crate::background_save::poll_background_save)
+            .add_systems(Last, crate::background_save::finish_background_save_on_exit);
 
         // WASM: async two-phase load detection.
         // 1) `start_wasm_load` kicks off async IndexedDB read
@@ -93,6 +104,7 @@ impl Plugin for SavePlugin {
                 start_wasm_load,
                 poll_wasm_load.after(start_wasm_load),
                 poll_wasm_save_error,
+                delete_wasm_slots,
             ),
         );
 
@@ -112,6 +124,9 @@ impl Plugin for SavePlugin {
             crate::exclusive_new_game::exclusive_new_game,
         );
 
+        // Replay checkpoints reuse the save pipeline to snapshot full state.
+        app.insert_resource(crate::replay_checkpoint::CODEC);
+
         // Autosave bridge: converts simulation-side timer triggers into save events.
         app.add_plugins(crate::autosave_bridge::AutosaveBridgePlugin);
 
@@ -124,15 +139,19 @@ impl Plugin for SavePlugin {
 // Event detection systems (lightweight, run in Update)
 // ---------------------------------------------------------------------------
 
-/// Detects `SaveGameEvent` and transitions to `Saving` state.
+/// Detects `SaveGameEvent` and transitions to `Saving` state, or queues the
+/// save while a background save is still being written.
 fn detect_save_event(
     mut events: EventReader<SaveGameEvent>,
     mut next_state: ResMut<NextState<SaveLoadState>>,
+    mut background: ResMut<crate::background_save::BackgroundSave>,
 ) {
     if events.read().next().is_some() {
         // Drain remaining events (only process one per frame).
         events.read().for_each(drop);
-        next_state.set(SaveLoadState::Saving);
+        if !background.queue_if_busy() {
+            next_state.set(SaveLoadState::Saving);
+        }
     }
 }
 
@@ -147,14 +166,16 @@ fn detect_new_game_event(
     }
 }
 
-/// Native: detects `LoadGameEvent`, reads save file, stores bytes, and
-/// transitions to `Loading` state.  File I/O errors are surfaced as
+/// Native: detects `LoadGameEvent`, reads the save file and any delta beside
+/// it, stores bytes, and transitions to `Loading` state.  File I/O errors are surfaced as
 /// notifications and trigger a rollback to the pre-load `AppState`.
 #[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::too_many_arguments)]
 fn detect_load_event(
     mut events: EventReader<LoadGameEvent>,
     mut next_state: ResMut<NextState<SaveLoadState>>,
     mut pending: ResMut<PendingLoadBytes>,
+    mut pending_delta: ResMut<crate::save_delta::PendingDeltaBytes>,
     mut notifications: EventWriter<NotificationEvent>,
     mut path_override: ResMut<PendingSavePath>,
     mut pre_load: ResMut<PreLoadAppState>,
@@ -166,6 +187,7 @@ fn detect_load_event(
         match std::fs::read(&path) {
             Ok(bytes) => {
                 pending.0 = Some(bytes);
+                pending_delta.0 = crate::save_delta::read_delta_file(&path);
                 next_state.set(SaveLoadState::Loading);
             }
             Err(e) => {
@@ -175,6 +197,7 @@ fn detect_load_event(
                 notifications.send(NotificationEvent {
                     text: msg,
                     priority: NotificationPriority::Warning,
+                    category: NotificationCategory::General,
                     location: None,
                 });
                 // Roll back AppState so the player isn't stranded.
@@ -186,13 +209,19 @@ fn detect_load_event(
     }
 }
 
-/// WASM phase 1: consumes `LoadGameEvent` and kicks off an async IndexedDB read.
+/// WASM phase 1: consumes `LoadGameEvent` and kicks off an async IndexedDB
+/// read of the key for `PendingSavePath`.
 #[cfg(target_arch = "wasm32")]
-fn start_wasm_load(mut events: EventReader<LoadGameEvent>, buffer: Res<WasmLoadBuffer>) {
+fn start_wasm_load(
+    mut events: EventReader<LoadGameEvent>,
+    buffer: Res<WasmLoadBuffer>,
+    mut path_override: ResMut<PendingSavePath>,
+) {
     for _ in events.read() {
         let slot = buffer.0.clone();
+        let key = wasm_idb::storage_key(path_override.0.take());
         wasm_bindgen_futures::spawn_local(async move {
-            let result = wasm_idb::idb_load().await;
+            let result = wasm_idb::idb_load(key).await;
             if let Ok(mut guard) = slot.lock() {
                 *guard = Some(result);
             }
@@ -227,6 +256,7 @@ fn poll_wasm_load(
                 notifications.send(NotificationEvent {
                     text: msg,
                     priority: NotificationPriority::Warning,
+                    category: NotificationCategory::General,
                     location: None,
                 });
                 // Roll back AppState so the player isn't stranded.
@@ -253,11 +283,26 @@ fn poll_wasm_save_error(
         notifications.send(NotificationEvent {
             text: error_msg,
             priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
             location: None,
         });
     }
 }
 
+/// Removes the IndexedDB entry of every deleted save slot; on native the
+/// simulation crate deletes the slot file itself.
+#[cfg(target_arch = "wasm32")]
+fn delete_wasm_slots(mut events: EventReader<simulation::save_slots::DeleteSlotEvent>) {
+    for event in events.read() {
+        let key = simulation::save_slots::slot_file_path(event.slot_index);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = wasm_idb::idb_delete(key).await {
+                web_sys::console::error_1(&format!("Failed to delete save slot: {e}").into());
+            }
+        });
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Utility
 // ---------------------------------------------------------------------------
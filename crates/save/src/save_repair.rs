@@ -0,0 +1,320 @@
+// ---------------------------------------------------------------------------
+// save_repair – Integrity check and repair pass run on every load
+// ---------------------------------------------------------------------------
+//
+// A decoded and migrated `SaveData` can still be inconsistent: saves edited by
+// hand (see `save_json`), written by buggy builds or damaged in ways the
+// checksum can't catch. Spawning such a save used to fail silently: a citizen
+// whose workplace was gone got `Entity::PLACEHOLDER` as its work building,
+// and out-of-grid coordinates panicked or were dropped without a trace.
+//
+// `repair_save` runs between migration and spawning. It fixes what it can,
+// drops what it can't, and records every change in a `LoadReport` that the
+// loader logs, surfaces as a notification and keeps as a resource. Citizens
+// whose workplace is missing are kept and saved unemployed (`NO_WORKPLACE`),
+// so the job market finds them new work. Citizens whose home cell holds no
+// building have nowhere to live and are dropped.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::save_types::{SaveCell, SaveCitizen, SaveData, NO_WORKPLACE};
+
+/// Family reference meaning "no such relative".
+const NO_FAMILY_REF: u32 = u32::MAX;
+
+/// What the repair pass changed while loading the last save.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LoadReport {
+    /// Grid cells added or removed to match the grid's width * height.
+    pub grid_cells_resized: usize,
+    /// Road positions outside the grid, dropped.
+    pub roads_dropped: usize,
+    /// Buildings outside the grid or on a cell another building holds, dropped.
+    pub buildings_dropped: usize,
+    /// Citizens whose home is outside the grid, dropped.
+    pub citizens_dropped: usize,
+    /// Citizens whose home cell holds no building, dropped.
+    pub citizens_homeless: usize,
+    /// Citizens whose workplace no longer exists, spawned unemployed.
+    pub citizens_unemployed: usize,
+    /// Family references to citizens that don't exist, cleared.
+    pub family_links_cleared: usize,
+}
+
+impl LoadReport {
+    /// Whether the save loaded without any repair.
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// One line per kind of repair, for logs and notifications.
+    pub fn lines(&self) -> Vec<String> {
+        [
+            (
+                self.grid_cells_resized,
+                "grid cells resized to fit the grid",
+            ),
+            (self.roads_dropped, "road tiles outside the grid dropped"),
+            (self.buildings_dropped, "misplaced buildings dropped"),
+            (self.citizens_dropped, "citizens with no valid home dropped"),
+            (
+                self.citizens_homeless,
+                "citizens whose home was gone dropped",
+            ),
+            (
+                self.citizens_unemployed,
+                "citizens lost their missing workplace",
+            ),
+            (self.family_links_cleared, "broken family links cleared"),
+        ]
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, what)| format!("{count} {what}"))
+        .collect()
+    }
+}
+
+/// Check `save` for dangling references and grid/entity mismatches, repair
+/// it in place and report what changed.
+pub fn repair_save(save: &mut SaveData) -> LoadReport {
+    let mut report = LoadReport::default();
+    let (width, height) = (save.grid.width, save.grid.height);
+    let in_grid = |x: usize, y: usize| x < width && y < height;
+
+    // Grid cells: `WorldGrid::new(width, height)` is filled cell by cell.
+    let expected_cells = width.saturating_mul(height);
+    if save.grid.cells.len() != expected_cells {
+        report.grid_cells_resized = save.grid.cells.len().abs_diff(expected_cells);
+        save.grid.cells.resize_with(expected_cells, || SaveCell {
+            elevation: 0.0,
+            cell_type: 0,
+            zone: 0,
+            road_type: 0,
+            has_power: false,
+            has_water: false,
+        });
+    }
+
+    let roads_before = save.roads.road_positions.len();
+    save.roads.road_positions.retain(|&(x, y)| in_grid(x, y));
+    report.roads_dropped = roads_before - save.roads.road_positions.len();
+
+    // Buildings: one per cell, inside the grid.
+    let mut occupied = HashSet::new();
+    let buildings_before = save.buildings.len();
+    save.buildings
+        .retain(|b| in_grid(b.grid_x, b.grid_y) && occupied.insert((b.grid_x, b.grid_y)));
+    report.buildings_dropped = buildings_before - save.buildings.len();
+    let homes = occupied.clone();
+
+    // Service buildings and water sources also own their cell and can employ.
+    occupied.extend(save.service_buildings.iter().map(|s| (s.grid_x, s.grid_y)));
+    if let Some(ref sources) = save.water_sources {
+        occupied.extend(sources.iter().map(|s| (s.grid_x, s.grid_y)));
+    }
+
+    // Citizens: drop those with an impossible or missing home, remapping
+    // family indices.
+    let old_citizens = std::mem::take(&mut save.citizens);
+    let mut new_index = vec![NO_FAMILY_REF; old_citizens.len()];
+    for (i, citizen) in old_citizens.into_iter().enumerate() {
+        if !in_grid(citizen.home_x, citizen.home_y) {
+            report.citizens_dropped += 1;
+        } else if !homes.contains(&(citizen.home_x, citizen.home_y)) {
+            report.citizens_homeless += 1;
+        } else {
+            new_index[i] = save.citizens.len() as u32;
+            save.citizens.push(citizen);
+        }
+    }
+    for citizen in &mut save.citizens {
+        report.family_links_cleared += remap_family(citizen, &new_index);
+        let unemployed = citizen.work_x == NO_WORKPLACE;
+        if !unemployed && !occupied.contains(&(citizen.work_x, citizen.work_y)) {
+            report.citizens_unemployed += 1;
+            citizen.work_x = NO_WORKPLACE;
+            citizen.work_y = NO_WORKPLACE;
+        }
+    }
+
+    report
+}
+
+/// Point `citizen`'s family references at the surviving citizens' new
+/// indices, clearing references to citizens that don't exist. Returns the
+/// number of references cleared.
+fn remap_family(citizen: &mut SaveCitizen, new_index: &[u32]) -> usize {
+    let remap = |r: u32| new_index.get(r as usize).copied().unwrap_or(NO_FAMILY_REF);
+    let mut cleared = 0;
+    for link in [&mut citizen.family_partner, &mut citizen.family_parent] {
+        if *link != NO_FAMILY_REF {
+            *link = remap(*link);
+            cleared += usize::from(*link == NO_FAMILY_REF);
+        }
+    }
+    let children_before = citizen.family_children.len();
+    citizen.family_children = citizen
+        .family_children
+        .iter()
+        .map(|&r| remap(r))
+        .filter(|&r| r != NO_FAMILY_REF)
+        .collect();
+    cleared + children_before - citizen.family_children.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save_types::{SaveBuilding, CURRENT_SAVE_VERSION};
+    use crate::serialization::tests_save_error::minimal_save;
+
+    fn building(grid_x: usize, grid_y: usize) -> SaveBuilding {
+        SaveBuilding {
+            zone_type: 1,
+            level: 1,
+            grid_x,
+            grid_y,
+            capacity: 10,
+            occupants: 1,
+            commercial_capacity: 0,
+            commercial_occupants: 0,
+            residential_capacity: 0,
+            residential_occupants: 0,
+        }
+    }
+
+    fn citizen(home: (usize, usize), work: (usize, usize)) -> SaveCitizen {
+        SaveCitizen {
+            age: 30,
+            happiness: 60.0,
+            education: 1,
+            state: 0,
+            home_x: home.0,
+            home_y: home.1,
+            work_x: work.0,
+            work_y: work.1,
+            path_waypoints: vec![],
+            path_current_index: 0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            pos_x: 0.0,
+            pos_y: 0.0,
+            gender: 0,
+            health: 80.0,
+            salary: 0.0,
+            savings: 0.0,
+            ambition: 0.5,
+            sociability: 0.5,
+            materialism: 0.5,
+            resilience: 0.5,
+            need_hunger: 80.0,
+            need_energy: 80.0,
+            need_social: 70.0,
+            need_fun: 70.0,
+            need_comfort: 60.0,
+            activity_timer: 0,
+            family_partner: NO_FAMILY_REF,
+            family_children: vec![],
+            family_parent: NO_FAMILY_REF,
+        }
+    }
+
+    /// A 4x4 city with homes at (0, 0) and (1, 0) and a workplace at (3, 3).
+    fn city() -> SaveData {
+        let mut save = minimal_save(CURRENT_SAVE_VERSION);
+        save.grid.width = 4;
+        save.grid.height = 4;
+        save.grid.cells = (0..16).map(|_| cell()).collect();
+        save.buildings = vec![building(0, 0), building(1, 0), building(3, 3)];
+        save.citizens = vec![citizen((0, 0), (3, 3)), citizen((1, 0), (3, 3))];
+        save
+    }
+
+    fn cell() -> SaveCell {
+        SaveCell {
+            elevation: 0.0,
+            cell_type: 0,
+            zone: 0,
+            road_type: 0,
+            has_power: false,
+            has_water: false,
+        }
+    }
+
+    #[test]
+    fn test_consistent_save_is_clean() {
+        let mut save = city();
+        let report = repair_save(&mut save);
+        assert!(report.is_clean(), "{report:?}");
+        assert!(report.lines().is_empty());
+        assert_eq!(save.buildings.len(), 3);
+        assert_eq!(save.citizens.len(), 2);
+    }
+
+    #[test]
+    fn test_grid_and_buildings_are_repaired() {
+        let mut save = city();
+        save.grid.cells.truncate(10);
+        save.roads.road_positions = vec![(2, 2), (9, 0)];
+        save.buildings.push(building(0, 0)); // duplicate cell
+        save.buildings.push(building(4, 1)); // outside the grid
+
+        let report = repair_save(&mut save);
+        assert_eq!(report.grid_cells_resized, 6);
+        assert_eq!(save.grid.cells.len(), 16);
+        assert_eq!(report.roads_dropped, 1);
+        assert_eq!(report.buildings_dropped, 2);
+        assert_eq!(save.buildings.len(), 3);
+        assert_eq!(report.lines().len(), 3);
+    }
+
+    #[test]
+    fn test_missing_workplace_is_reported() {
+        let mut save = city();
+        save.buildings.pop(); // the workplace at (3, 3)
+        let report = repair_save(&mut save);
+        assert_eq!(report.citizens_unemployed, 2);
+        assert_eq!(save.citizens.len(), 2);
+        assert!(save.citizens.iter().all(|c| c.work_x == NO_WORKPLACE));
+
+        // Already unemployed citizens are not reported again.
+        assert!(repair_save(&mut save).is_clean());
+    }
+
+    #[test]
+    fn test_citizen_without_home_building_is_dropped() {
+        let mut save = city();
+        save.buildings.remove(1); // the home at (1, 0)
+        let report = repair_save(&mut save);
+        assert_eq!(report.citizens_homeless, 1);
+        assert_eq!(report.citizens_dropped, 0);
+        assert_eq!(save.citizens.len(), 1);
+        assert_eq!((save.citizens[0].home_x, save.citizens[0].home_y), (0, 0));
+        assert_eq!(
+            report.lines(),
+            vec!["1 citizens whose home was gone dropped"]
+        );
+    }
+
+    #[test]
+    fn test_dropping_citizens_remaps_family() {
+        let mut save = city();
+        // Citizen 0 lives outside the grid. Citizens 1 and 2 are partners;
+        // citizen 2 also claims the dropped citizen 0 and a nonexistent
+        // citizen 9 as children.
+        save.citizens.insert(0, citizen((7, 7), (3, 3)));
+        save.citizens[1].family_partner = 2;
+        save.citizens[2].family_partner = 1;
+        save.citizens[2].family_children = vec![0, 9];
+
+        let report = repair_save(&mut save);
+        assert_eq!(report.citizens_dropped, 1);
+        assert_eq!(report.family_links_cleared, 2);
+        assert_eq!(save.citizens.len(), 2);
+        assert_eq!(save.citizens[0].family_partner, 1);
+        assert_eq!(save.citizens[1].family_partner, 0);
+        assert!(save.citizens[1].family_children.is_empty());
+    }
+}
@@ -17,7 +17,7 @@ pub struct SaveGrid {
     pub height: usize,
 }
 
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq)]
 pub struct SaveCell {
     pub elevation: f32,
     pub cell_type: u8,
@@ -93,7 +93,7 @@ pub struct SaveDemand {
     pub vacancy_office: f32,
 }
 
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq)]
 pub struct SaveBuilding {
     pub zone_type: u8,
     pub level: u8,
@@ -112,7 +112,10 @@ pub struct SaveBuilding {
     pub residential_occupants: u32,
 }
 
-#[derive(Serialize, Deserialize, Encode, Decode)]
+/// `SaveCitizen::work_x` and `work_y` of a citizen with no workplace.
+pub const NO_WORKPLACE: usize = usize::MAX;
+
+#[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq)]
 pub struct SaveCitizen {
     pub age: u8,
     pub happiness: f32,
@@ -120,6 +123,7 @@ pub struct SaveCitizen {
     pub state: u8,
     pub home_x: usize,
     pub home_y: usize,
+    /// `NO_WORKPLACE` when unemployed.
     pub work_x: usize,
     pub work_y: usize,
     // V3 fields: PathCache, Velocity, Position (backward-compatible via serde defaults)
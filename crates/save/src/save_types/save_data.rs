@@ -7,6 +7,8 @@ use std::collections::BTreeMap;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+use crate::save_error::SaveError;
+
 use super::core_types::*;
 use super::infrastructure_types::*;
 use super::policy_types::*;
@@ -108,7 +110,21 @@ impl SaveData {
         bitcode::encode(self)
     }
 
-    pub fn decode(bytes: &[u8]) -> Result<Self, bitcode::Error> {
-        bitcode::decode(bytes)
+    /// Decode the payload of a save file. `zstd` is the header's
+    /// `FLAG_ZSTD`: set, the payload is a zstd frame; clear, it is bitcode
+    /// (LZ4 payloads are decompressed by the caller first).
+    pub fn decode_payload(payload: &[u8], zstd: bool) -> Result<Self, SaveError> {
+        if zstd {
+            let raw = crate::compression::decompress_zstd(payload).map_err(SaveError::Decode)?;
+            return Ok(bitcode::decode(&raw)?);
+        }
+        Ok(bitcode::decode(payload)?)
+    }
+
+    /// Decode bytes with no file header to say how they are stored: legacy
+    /// headerless saves and raw bitcode. A zstd frame is recognised by its
+    /// magic number.
+    pub fn decode(bytes: &[u8]) -> Result<Self, SaveError> {
+        Self::decode_payload(bytes, crate::compression::is_zstd_frame(bytes))
     }
 }
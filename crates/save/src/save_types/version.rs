@@ -35,5 +35,6 @@
 /// v30 = snow_state (SnowGrid + SnowPlowingState serialization for snow accumulation and plowing)
 /// v31 = agriculture_state (AgricultureState serialization for growing season and crop yield)
 /// v32 = family graph (partner/children/parent Entity refs serialized as citizen indices)
+/// v33 = branching unlock tree (development points spent on the linear track are refunded)
 // v32 = family graph (partner/children/parent relationships across save/load)
-pub const CURRENT_SAVE_VERSION: u32 = 32; // v32: Family graph serialization
+pub const CURRENT_SAVE_VERSION: u32 = 33; // v33: Branching unlock tree
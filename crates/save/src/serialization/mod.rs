@@ -37,6 +37,8 @@ mod tests_extensions_vacancy;
 #[cfg(test)]
 mod tests_family;
 #[cfg(test)]
+mod tests_json;
+#[cfg(test)]
 mod tests_life_sim;
 #[cfg(test)]
 mod tests_lz4_compression;
@@ -45,7 +47,7 @@ mod tests_migration;
 #[cfg(test)]
 mod tests_migration_chain;
 #[cfg(test)]
-mod tests_save_error;
+pub(crate) mod tests_save_error;
 #[cfg(test)]
 mod tests_save_metadata;
 #[cfg(test)]
@@ -54,6 +56,8 @@ mod tests_savings;
 mod tests_stormwater_climate;
 #[cfg(test)]
 mod tests_water;
+#[cfg(all(test, feature = "zstd", not(target_arch = "wasm32")))]
+mod tests_zstd_compression;
 
 use simulation::agriculture::AgricultureState;
 use simulation::buildings::{Building, MixedUseBuilding};
@@ -0,0 +1,125 @@
+//! JSON export/import tests.
+
+use super::tests_save_error::minimal_save;
+use super::*;
+use crate::save_error::SaveError;
+
+fn city() -> SaveData {
+    let mut save = minimal_save(CURRENT_SAVE_VERSION);
+    save.grid = SaveGrid {
+        cells: (0..6)
+            .map(|i| SaveCell {
+                elevation: i as f32,
+                cell_type: 0,
+                zone: (i % 3) as u8,
+                road_type: 0,
+                has_power: true,
+                has_water: false,
+            })
+            .collect(),
+        width: 3,
+        height: 2,
+    };
+    save.roads.road_positions = vec![(0, 1), (1, 1)];
+    save.buildings = vec![SaveBuilding {
+        zone_type: 1,
+        level: 2,
+        grid_x: 2,
+        grid_y: 0,
+        capacity: 20,
+        occupants: 12,
+        commercial_capacity: 0,
+        commercial_occupants: 0,
+        residential_capacity: 0,
+        residential_occupants: 0,
+    }];
+    save.clock.day = 42;
+    save.clock.hour = 13.5;
+    save.budget.treasury = 125_000.0;
+    save.extensions
+        .insert("test_key".to_string(), vec![1, 2, 3]);
+    save
+}
+
+fn assert_schema_error(json: &str, needle: &str) {
+    match SaveData::from_json(json) {
+        Err(SaveError::Decode(msg)) => assert!(msg.contains(needle), "got: {msg}"),
+        Err(e) => panic!("expected a decode error, got: {e}"),
+        Ok(_) => panic!("expected {needle:?} to be rejected"),
+    }
+}
+
+#[test]
+fn test_json_roundtrip_preserves_save() {
+    let save = city();
+    let json = save.to_json().unwrap();
+    assert!(json.contains("\"treasury\": 125000.0"), "got: {json}");
+
+    let loaded = SaveData::from_json(&json).unwrap();
+    assert_eq!(loaded.encode(), save.encode());
+}
+
+#[test]
+fn test_json_accepts_hand_edits() {
+    let json = city()
+        .to_json()
+        .unwrap()
+        .replace("\"treasury\": 125000.0", "\"treasury\": 1.0");
+    let loaded = SaveData::from_json(&json).unwrap();
+    assert_eq!(loaded.budget.treasury, 1.0);
+}
+
+#[test]
+fn test_json_rejects_malformed_input() {
+    assert_schema_error("{\"version\": 33}", "Invalid save JSON");
+    assert_schema_error("not json", "Invalid save JSON");
+}
+
+#[test]
+fn test_json_rejects_wrong_grid_size() {
+    let mut save = city();
+    save.grid.width = 4;
+    assert_schema_error(&save.to_json().unwrap(), "grid.cells has 6 entries");
+}
+
+#[test]
+fn test_json_rejects_building_outside_grid() {
+    let mut save = city();
+    save.buildings[0].grid_y = 2;
+    assert_schema_error(&save.to_json().unwrap(), "buildings[0]");
+}
+
+#[test]
+fn test_json_rejects_future_version() {
+    let mut save = city();
+    save.version = CURRENT_SAVE_VERSION + 1;
+    let result = SaveData::from_json(&save.to_json().unwrap());
+    assert!(matches!(result, Err(SaveError::VersionMismatch { .. })));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_export_and_import_save_files() {
+    use crate::save_json::{export_save_file, import_save_file};
+
+    let dir = std::env::temp_dir().join(format!("megacity_json_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+
+    let bytes = crate::file_header::wrap_with_header_for_save(
+        &city().encode(),
+        &crate::save_metadata::SaveMetadata::default(),
+    );
+    std::fs::write(path("city.bin"), bytes).unwrap();
+
+    export_save_file(&path("city.bin"), &path("city.json")).unwrap();
+    import_save_file(&path("city.json"), &path("copy.bin")).unwrap();
+    export_save_file(&path("copy.bin"), &path("copy.json")).unwrap();
+
+    let original = std::fs::read_to_string(path("city.json")).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path("copy.json")).unwrap(),
+        original
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
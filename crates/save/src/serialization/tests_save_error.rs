@@ -8,7 +8,7 @@ use crate::save_types::*;
 use std::collections::BTreeMap;
 
 /// Helper to create a minimal SaveData for testing.
-fn minimal_save(version: u32) -> SaveData {
+pub(crate) fn minimal_save(version: u32) -> SaveData {
     SaveData {
         version,
         grid: SaveGrid {
@@ -92,12 +92,11 @@ fn test_decode_garbage_bytes_returns_error() {
     let result = SaveData::decode(&garbage);
     assert!(result.is_err(), "Decoding garbage should fail");
 
-    // Verify the bitcode::Error converts to SaveError::Decode
-    let bitcode_err = match result {
+    // Verify the bitcode::Error surfaces as SaveError::Decode
+    let save_err = match result {
         Err(e) => e,
         Ok(_) => panic!("Expected error"),
     };
-    let save_err: SaveError = bitcode_err.into();
     assert!(
         matches!(save_err, SaveError::Decode(_)),
         "Should be Decode variant, got: {save_err:?}"
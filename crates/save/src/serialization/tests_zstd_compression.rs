@@ -0,0 +1,88 @@
+// ---------------------------------------------------------------------------
+// tests_zstd_compression – Tests for zstd compression in save/load pipeline
+// ---------------------------------------------------------------------------
+
+use super::tests_save_error::minimal_save;
+use crate::compression::{compress_zstd, is_zstd_frame};
+use crate::file_header::{
+    decompress_payload, unwrap_header, wrap_with_header_compressed, wrap_with_header_for_save,
+    UnwrapResult, FLAG_COMPRESSED, FLAG_ZSTD, HEADER_FORMAT_VERSION,
+};
+use crate::save_metadata::SaveMetadata;
+use crate::save_types::{SaveData, CURRENT_SAVE_VERSION};
+
+#[test]
+fn test_save_is_written_as_zstd() {
+    let encoded = minimal_save(CURRENT_SAVE_VERSION).encode();
+    let wrapped = wrap_with_header_for_save(&encoded, &SaveMetadata::default());
+
+    match unwrap_header(&wrapped).expect("unwrap should succeed") {
+        UnwrapResult::WithHeader {
+            header, payload, ..
+        } => {
+            assert_eq!(header.format_version, HEADER_FORMAT_VERSION);
+            assert!(header.is_zstd());
+            assert!(!header.is_compressed());
+            assert_eq!(header.flags, FLAG_ZSTD);
+            assert_eq!(header.uncompressed_size, encoded.len() as u32);
+            assert!(is_zstd_frame(payload));
+
+            let decoded = SaveData::decode_payload(payload, header.is_zstd())
+                .expect("zstd payload should decode");
+            assert_eq!(decoded.version, CURRENT_SAVE_VERSION);
+        }
+        UnwrapResult::Legacy(_) => panic!("expected WithHeader, got Legacy"),
+    }
+}
+
+#[test]
+fn test_payload_decodes_by_header_flag() {
+    let encoded = minimal_save(CURRENT_SAVE_VERSION).encode();
+    let frame = compress_zstd(&encoded).unwrap();
+
+    assert!(SaveData::decode_payload(&frame, true).is_ok());
+    assert!(SaveData::decode_payload(&encoded, false).is_ok());
+    // The flag decides, not the bytes.
+    assert!(SaveData::decode_payload(&frame, false).is_err());
+    assert!(SaveData::decode_payload(&encoded, true).is_err());
+}
+
+#[test]
+fn test_headerless_decode_accepts_zstd_and_raw_payloads() {
+    let encoded = minimal_save(CURRENT_SAVE_VERSION).encode();
+    let frame = compress_zstd(&encoded).unwrap();
+
+    let from_zstd = SaveData::decode(&frame).expect("zstd frame should decode");
+    let from_raw = SaveData::decode(&encoded).expect("raw bitcode should decode");
+    assert_eq!(from_zstd.version, from_raw.version);
+}
+
+#[test]
+fn test_lz4_saves_still_load() {
+    let encoded = minimal_save(CURRENT_SAVE_VERSION).encode();
+    let wrapped = wrap_with_header_compressed(&encoded, &SaveMetadata::default());
+
+    match unwrap_header(&wrapped).expect("unwrap should succeed") {
+        UnwrapResult::WithHeader {
+            header, payload, ..
+        } => {
+            assert_eq!(header.flags, FLAG_COMPRESSED);
+            assert!(!header.is_zstd());
+            let raw = decompress_payload(payload).expect("LZ4 payload should decompress");
+            let decoded = SaveData::decode(&raw).expect("LZ4 payload should decode");
+            assert_eq!(decoded.version, CURRENT_SAVE_VERSION);
+        }
+        UnwrapResult::Legacy(_) => panic!("expected WithHeader, got Legacy"),
+    }
+}
+
+#[test]
+fn test_corrupt_zstd_payload_is_a_decode_error() {
+    let encoded = minimal_save(CURRENT_SAVE_VERSION).encode();
+    let mut frame = compress_zstd(&encoded).unwrap();
+    frame.truncate(frame.len() / 2);
+    assert!(matches!(
+        SaveData::decode(&frame),
+        Err(crate::save_error::SaveError::Decode(_))
+    ));
+}
@@ -121,6 +121,7 @@ pub(crate) fn spawn_entities_from_save(world: &mut World, save: &SaveData) {
                     _ => CitizenState::AtHome,
                 };
 
+                // `repair_save` drops citizens whose home cell has no building.
                 let home_building = if grid.in_bounds(sc.home_x, sc.home_y) {
                     grid.get(sc.home_x, sc.home_y)
                         .building_id
@@ -129,13 +130,17 @@ pub(crate) fn spawn_entities_from_save(world: &mut World, save: &SaveData) {
                     Entity::PLACEHOLDER
                 };
 
-                let work_building = if grid.in_bounds(sc.work_x, sc.work_y) {
-                    grid.get(sc.work_x, sc.work_y)
-                        .building_id
-                        .unwrap_or(Entity::PLACEHOLDER)
-                } else {
-                    Entity::PLACEHOLDER
-                };
+                // An unemployed citizen (`NO_WORKPLACE`, which `repair_save`
+                // also sets when the workplace is gone) spawns without one.
+                let work = grid
+                    .in_bounds(sc.work_x, sc.work_y)
+                    .then(|| grid.get(sc.work_x, sc.work_y).building_id)
+                    .flatten()
+                    .map(|building| WorkLocation {
+                        grid_x: sc.work_x,
+                        grid_y: sc.work_y,
+                        building,
+                    });
 
                 let (pos_x, pos_y) = if sc.pos_x != 0.0 || sc.pos_y != 0.0 {
                     (sc.pos_x, sc.pos_y)
@@ -187,7 +192,7 @@ pub(crate) fn spawn_entities_from_save(world: &mut World, save: &SaveData) {
                     sc.savings
                 };
 
-                (
+                let bundle = (
                     Citizen,
                     CitizenDetails {
                         age: sc.age,
@@ -204,11 +209,6 @@ pub(crate) fn spawn_entities_from_save(world: &mut World, save: &SaveData) {
                         grid_y: sc.home_y,
                         building: home_building,
                     },
-                    WorkLocation {
-                        grid_x: sc.work_x,
-                        grid_y: sc.work_y,
-                        building: work_building,
-                    },
                     Position { x: pos_x, y: pos_y },
                     velocity,
                     path_cache,
@@ -228,14 +228,18 @@ pub(crate) fn spawn_entities_from_save(world: &mut World, save: &SaveData) {
                     Family::default(),
                     ActivityTimer(sc.activity_timer),
                     LodTier::default(),
-                )
+                );
+                (bundle, work)
             })
             .collect()
     }; // grid borrow ends here
 
-    for data in citizen_spawn_data {
-        let entity = world.spawn(data).id();
-        citizen_entities.push(entity);
+    for (bundle, work) in citizen_spawn_data {
+        let mut entity = world.spawn(bundle);
+        if let Some(work) = work {
+            entity.insert(work);
+        }
+        citizen_entities.push(entity.id());
     }
 
     // Second pass: restore family relationships using saved citizen indices.
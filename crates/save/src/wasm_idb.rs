@@ -3,6 +3,10 @@
 //! Replaces the old localStorage + base64 approach with IndexedDB which
 //! supports storing large binary blobs (50 MB+) without the ~5 MB
 //! localStorage limit.
+//!
+//! Each save lives under its own key: the default save under `SAVE_KEY`, and
+//! every other save (named slots, quicksave) under the path the native build
+//! would write it to, so `PendingSavePath` means the same thing on both.
 
 use js_sys::Uint8Array;
 use wasm_bindgen::prelude::*;
@@ -54,6 +58,11 @@ fn is_quota_exceeded_error(err: &JsValue) -> bool {
     s.contains("QuotaExceededError") || s.contains("quota")
 }
 
+/// The IndexedDB key for a save path, `None` meaning the default save.
+pub fn storage_key(path: Option<String>) -> String {
+    path.unwrap_or_else(|| SAVE_KEY.to_string())
+}
+
 fn window() -> Result<Window, WasmStorageError> {
     web_sys::window().ok_or_else(|| WasmStorageError::Other("no window".to_string()))
 }
@@ -145,8 +154,8 @@ async fn idb_request_to_future(request: &IdbRequest) -> Result<JsValue, WasmStor
     result
 }
 
-/// Save compressed binary data to IndexedDB.
-pub async fn idb_save(bytes: Vec<u8>) -> Result<(), WasmStorageError> {
+/// Save compressed binary data to IndexedDB under `key`.
+pub async fn idb_save(key: String, bytes: Vec<u8>) -> Result<(), WasmStorageError> {
     use flate2::write::DeflateEncoder;
     use flate2::Compression;
     use std::io::Write;
@@ -171,7 +180,7 @@ pub async fn idb_save(bytes: Vec<u8>) -> Result<(), WasmStorageError> {
 
     // Store as Uint8Array (binary, no base64 overhead).
     let js_array = Uint8Array::from(&compressed[..]);
-    let put_result = store.put_with_key(&js_array, &JsValue::from_str(SAVE_KEY));
+    let put_result = store.put_with_key(&js_array, &JsValue::from_str(&key));
 
     let request = match put_result {
         Ok(req) => req,
@@ -187,9 +196,10 @@ pub async fn idb_save(bytes: Vec<u8>) -> Result<(), WasmStorageError> {
 
     web_sys::console::log_1(
         &format!(
-            "Saved {} bytes ({} compressed) to IndexedDB",
+            "Saved {} bytes ({} compressed) to IndexedDB key {}",
             bytes.len(),
-            compressed.len()
+            compressed.len(),
+            key
         )
         .into(),
     );
@@ -197,11 +207,11 @@ pub async fn idb_save(bytes: Vec<u8>) -> Result<(), WasmStorageError> {
     Ok(())
 }
 
-/// Load compressed binary data from IndexedDB.
-/// Falls back to localStorage for migration of old saves.
-pub async fn idb_load() -> Result<Vec<u8>, String> {
+/// Load compressed binary data stored under `key` from IndexedDB.
+/// The default save falls back to localStorage for migration of old saves.
+pub async fn idb_load(key: String) -> Result<Vec<u8>, String> {
     // First try IndexedDB.
-    match idb_load_from_db().await {
+    match idb_load_from_db(&key).await {
         Ok(Some(bytes)) => return Ok(bytes),
         Ok(None) => {
             // No save in IndexedDB; try migrating from localStorage.
@@ -220,6 +230,11 @@ pub async fn idb_load() -> Result<Vec<u8>, String> {
         }
     }
 
+    // Only the default save predates IndexedDB.
+    if key != SAVE_KEY {
+        return Err(format!("no save found at {}", key));
+    }
+
     // Try loading from legacy localStorage.
     match load_from_local_storage() {
         Ok(bytes) => {
@@ -228,7 +243,7 @@ pub async fn idb_load() -> Result<Vec<u8>, String> {
             // We re-compress inside idb_save, so pass the decompressed bytes.
             let bytes_clone = bytes.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                if let Err(e) = idb_save(bytes_clone).await {
+                if let Err(e) = idb_save(key, bytes_clone).await {
                     web_sys::console::log_1(
                         &format!("Migration save to IndexedDB failed: {}", e).into(),
                     );
@@ -243,8 +258,8 @@ pub async fn idb_load() -> Result<Vec<u8>, String> {
     }
 }
 
-/// Attempt to load from IndexedDB. Returns Ok(None) if no save exists.
-async fn idb_load_from_db() -> Result<Option<Vec<u8>>, String> {
+/// Attempt to load `key` from IndexedDB. Returns Ok(None) if no save exists.
+async fn idb_load_from_db(key: &str) -> Result<Option<Vec<u8>>, String> {
     use flate2::read::DeflateDecoder;
     use std::io::Read;
 
@@ -258,7 +273,7 @@ async fn idb_load_from_db() -> Result<Option<Vec<u8>>, String> {
         .map_err(|e| format!("object store error: {:?}", e))?;
 
     let request = store
-        .get(&JsValue::from_str(SAVE_KEY))
+        .get(&JsValue::from_str(key))
         .map_err(|e| format!("get error: {:?}", e))?;
 
     let result = idb_request_to_future(&request)
@@ -281,6 +296,27 @@ async fn idb_load_from_db() -> Result<Option<Vec<u8>>, String> {
     }
 }
 
+/// Delete the save stored under `key` from IndexedDB.
+pub async fn idb_delete(key: String) -> Result<(), String> {
+    let db = open_db().await.map_err(|e| e.to_string())?;
+
+    let transaction = db
+        .transaction_with_str_and_mode(STORE_NAME, IdbTransactionMode::Readwrite)
+        .map_err(|e| format!("transaction error: {:?}", e))?;
+    let store = transaction
+        .object_store(STORE_NAME)
+        .map_err(|e| format!("object store error: {:?}", e))?;
+
+    let request = store
+        .delete(&JsValue::from_str(&key))
+        .map_err(|e| format!("delete error: {:?}", e))?;
+
+    idb_request_to_future(&request)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 /// Load from legacy localStorage (base64-encoded, possibly compressed).
 fn load_from_local_storage() -> Result<Vec<u8>, String> {
     use flate2::read::DeflateDecoder;
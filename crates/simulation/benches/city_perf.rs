@@ -11,6 +11,7 @@ use rand::Rng;
 
 use simulation::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 use simulation::grid::{CellType, WorldGrid};
+use simulation::packed_grid::BitGrid;
 use simulation::pathfinding_sys::{find_path, nearest_road, nearest_road_grid};
 use simulation::road_graph_csr::{csr_find_path, CsrGraph};
 use simulation::roads::{RoadNetwork, RoadNode};
@@ -248,7 +249,7 @@ fn bench_spatial_grid(c: &mut Criterion) {
     let mut group = c.benchmark_group("spatial_grid");
 
     // Full rebuild with 50K entities (the per-tick cost)
-    for count in [10_000u32, 25_000, 50_000] {
+    for count in [10_000u32, 25_000, 50_000, 100_000] {
         let mut rng = rand::thread_rng();
         let positions: Vec<(f32, f32)> = (0..count)
             .map(|_| {
@@ -303,6 +304,58 @@ fn bench_spatial_grid(c: &mut Criterion) {
         });
     }
 
+    // Radius and k-nearest queries at 100K agents, against a linear scan
+    {
+        let mut rng = rand::thread_rng();
+        let mut grid = SpatialGrid::default();
+        let mut points = Vec::with_capacity(100_000);
+        for i in 0..100_000u32 {
+            let x = rng.gen_range(0.0..(GRID_WIDTH as f32 * CELL_SIZE));
+            let y = rng.gen_range(0.0..(GRID_HEIGHT as f32 * CELL_SIZE));
+            grid.insert(Entity::from_raw(i), x, y);
+            points.push((Entity::from_raw(i), x, y));
+        }
+        let queries: Vec<(f32, f32)> = (0..64)
+            .map(|_| {
+                (
+                    rng.gen_range(0.0..(GRID_WIDTH as f32 * CELL_SIZE)),
+                    rng.gen_range(0.0..(GRID_HEIGHT as f32 * CELL_SIZE)),
+                )
+            })
+            .collect();
+
+        group.bench_function("radius_100k", |b| {
+            b.iter(|| {
+                for &(x, y) in &queries {
+                    black_box(grid.query_radius(x, y, 200.0));
+                }
+            });
+        });
+
+        group.bench_function("k_nearest_100k", |b| {
+            b.iter(|| {
+                for &(x, y) in &queries {
+                    black_box(grid.k_nearest(x, y, 8, f32::INFINITY));
+                }
+            });
+        });
+
+        group.bench_function("k_nearest_linear_scan_100k", |b| {
+            b.iter(|| {
+                for &(x, y) in &queries {
+                    let mut dists: Vec<(f32, Entity)> = points
+                        .iter()
+                        .map(|&(e, px, py)| ((px - x).powi(2) + (py - y).powi(2), e))
+                        .collect();
+                    dists.select_nth_unstable_by(7, |a, b| a.0.total_cmp(&b.0));
+                    dists.truncate(8);
+                    dists.sort_by(|a, b| a.0.total_cmp(&b.0));
+                    black_box(dists);
+                }
+            });
+        });
+    }
+
     group.finish();
 }
 
@@ -518,6 +571,53 @@ fn bench_service_coverage(c: &mut Criterion) {
         });
     });
 
+    // The same stamp into a bit-packed layer
+    group.bench_function("stamp_50_services_r15_bitgrid", |b| {
+        let mut grid = BitGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        let mut rng = rand::thread_rng();
+        let services: Vec<(usize, usize, i32)> = (0..50)
+            .map(|_| {
+                (
+                    rng.gen_range(20..GRID_WIDTH - 20),
+                    rng.gen_range(20..GRID_HEIGHT - 20),
+                    15,
+                )
+            })
+            .collect();
+
+        b.iter(|| {
+            grid.fill(false);
+            for &(cx, cy, r) in &services {
+                let r2 = (r as f32 * CELL_SIZE).powi(2);
+                for dy in -r..=r {
+                    for dx in -r..=r {
+                        let dist_sq =
+                            (dx as f32 * CELL_SIZE).powi(2) + (dy as f32 * CELL_SIZE).powi(2);
+                        if dist_sq <= r2 {
+                            grid.set((cx as i32 + dx) as usize, (cy as i32 + dy) as usize, true);
+                        }
+                    }
+                }
+            }
+            black_box(&grid);
+        });
+    });
+
+    // Sweep a half-covered layer: count covered cells
+    let mut rng = rand::thread_rng();
+    let flags: Vec<bool> = (0..GRID_WIDTH * GRID_HEIGHT)
+        .map(|_| rng.gen_bool(0.5))
+        .collect();
+    let packed = BitGrid::from_bools(GRID_WIDTH, GRID_HEIGHT, &flags);
+
+    group.bench_function("count_covered_vec_bool", |b| {
+        b.iter(|| black_box(flags.iter().filter(|&&v| v).count()));
+    });
+
+    group.bench_function("count_covered_bitgrid", |b| {
+        b.iter(|| black_box(packed.count_ones()));
+    });
+
     group.finish();
 }
 
@@ -794,6 +894,129 @@ fn bench_ecs_tick(c: &mut Criterion) {
         });
     });
 
+    // A tick on which the slow grid systems (pollution, noise, land value,
+    // groundwater, crime ...) all run.
+    group.bench_function("tel_aviv_slow_tick", |b| {
+        b.iter(|| {
+            let world = city.world_mut();
+            {
+                let mut timer = world.resource_mut::<simulation::SlowTickTimer>();
+                let interval = simulation::SlowTickTimer::INTERVAL;
+                timer.counter = (timer.counter / interval + 1) * interval - 1;
+            }
+            world.run_schedule(FixedUpdate);
+        });
+    });
+
+    group.finish();
+}
+
+// ---------------------------------------------------------------------------
+// 12. CITIZEN MOTION: ARCHETYPE ITERATION VS SOA BUFFER
+// ---------------------------------------------------------------------------
+
+/// One movement step for 10K and 100K commuting citizens, once iterating
+/// `Position`/`Velocity`/`PathCache` components as the old `move_citizens`
+/// did and once stepping the same citizens in a `CitizenMotionBuffer`. Both
+/// run the same per-citizen `advance_along_path` on the compute task pool.
+fn bench_citizen_motion(c: &mut Criterion) {
+    use bevy::prelude::*;
+    use bevy::tasks::{ComputeTaskPool, TaskPool};
+    use simulation::citizen::{Citizen, PathCache, Position, Velocity};
+    use simulation::movement::{advance_along_path, lane_offset, CitizenMotionBuffer, Motion};
+    use simulation::traffic_congestion::TrafficCongestion;
+
+    type Moving = (
+        Entity,
+        &'static mut Position,
+        &'static mut Velocity,
+        &'static mut PathCache,
+    );
+
+    let mut group = c.benchmark_group("citizen_motion");
+    group.sample_size(20);
+    ComputeTaskPool::get_or_init(TaskPool::default);
+    let congestion = TrafficCongestion::default();
+    let speed = 1.0;
+
+    for count in [10_000usize, 100_000] {
+        // Back-and-forth paths long enough that nobody arrives during a run.
+        let mut rng = rand::thread_rng();
+        let paths: Vec<PathCache> = (0..count)
+            .map(|_| {
+                let y = rng.gen_range(0..GRID_HEIGHT);
+                let x0 = rng.gen_range(0..GRID_WIDTH - 40);
+                let leg: Vec<RoadNode> = (x0..x0 + 40).map(|x| RoadNode(x, y)).collect();
+                let back = leg.iter().rev().copied();
+                PathCache::new(leg.iter().copied().chain(back).cycle().take(800).collect())
+            })
+            .collect();
+        let start = |path: &PathCache| {
+            let node = path.waypoints[0];
+            WorldGrid::grid_to_world(node.0, node.1)
+        };
+
+        let mut world = World::new();
+        for path in &paths {
+            let (x, y) = start(path);
+            world.spawn((
+                Citizen,
+                Position { x, y },
+                Velocity { x: 0.0, y: 0.0 },
+                path.clone(),
+            ));
+        }
+        let mut query = world.query_filtered::<Moving, With<Citizen>>();
+        group.bench_with_input(BenchmarkId::new("archetype", count), &count, |b, _| {
+            b.iter(|| {
+                query
+                    .par_iter_mut(&mut world)
+                    .for_each(|(entity, mut pos, mut vel, mut path)| {
+                        let motion = Motion {
+                            x: pos.x,
+                            y: pos.y,
+                            vel_x: vel.x,
+                            vel_y: vel.y,
+                            index: path.current_index,
+                        };
+                        let next = advance_along_path(
+                            motion,
+                            &path.waypoints,
+                            speed,
+                            1.0,
+                            lane_offset(entity),
+                            &congestion,
+                        );
+                        if next != motion {
+                            pos.x = next.x;
+                            pos.y = next.y;
+                            vel.x = next.vel_x;
+                            vel.y = next.vel_y;
+                            path.current_index = next.index;
+                        }
+                    });
+            });
+        });
+
+        let mut buffer = CitizenMotionBuffer::default();
+        for (i, path) in paths.iter().enumerate() {
+            buffer.upsert(
+                Entity::from_raw(i as u32),
+                start(path),
+                path,
+                true,
+                false,
+                1.0,
+            );
+        }
+        group.bench_with_input(BenchmarkId::new("soa_buffer", count), &count, |b, _| {
+            b.iter(|| {
+                buffer.step(speed, &congestion);
+                black_box(buffer.len());
+            });
+        });
+    }
+
     group.finish();
 }
 
@@ -815,5 +1038,6 @@ criterion_group!(
     bench_memory_footprint,
     bench_full_tick_estimate,
     bench_ecs_tick,
+    bench_citizen_motion,
 );
 criterion_main!(benches);
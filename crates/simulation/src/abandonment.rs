@@ -1,8 +1,11 @@
+use std::collections::BTreeSet;
+
 use bevy::prelude::*;
 
+use crate::building_events::{BuildingOccupancyChanged, BuildingUtilitiesChanged};
 use crate::buildings::Building;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
-use crate::grid::WorldGrid;
+use crate::grid::{Cell, WorldGrid};
 use crate::land_value::LandValueGrid;
 use crate::TickCounter;
 use crate::TestSafetyNet;
@@ -15,7 +18,7 @@ pub struct Abandoned {
 }
 
 /// How often (in ticks) the abandonment check runs.
-const CHECK_INTERVAL: u64 = 50;
+pub const CHECK_INTERVAL: u64 = 50;
 
 /// Number of ticks an abandoned building survives before demolition.
 const DEMOLISH_THRESHOLD: u32 = 500;
@@ -26,38 +29,63 @@ const LAND_VALUE_PENALTY: i32 = 5;
 /// Radius (in cells, Chebyshev distance) for the neighbor land value penalty.
 const PENALTY_RADIUS: i32 = 2;
 
-/// Checks non-abandoned buildings and marks them as `Abandoned` when conditions are met.
+/// Buildings that may have started to meet an abandonment condition since
+/// the last check: those whose occupancy changed (or that are new), those
+/// that lost both utilities, and those that just recovered. Ordered so
+/// buildings are abandoned in the same order every run.
+#[derive(Resource, Default)]
+pub struct AbandonmentCandidates {
+    pending: BTreeSet<Entity>,
+}
+
+/// Whether a non-abandoned building on `cell` should be abandoned:
+/// - its cell has neither power nor water, OR
+/// - it has 0 occupants and its level is > 1 (upgraded building that emptied out).
+pub fn should_abandon(building: &Building, cell: &Cell) -> bool {
+    let no_utilities = !cell.has_power && !cell.has_water;
+    let empty_upgraded = building.occupants == 0 && building.level > 1;
+    no_utilities || empty_upgraded
+}
+
+/// Marks candidate buildings as `Abandoned` when [`should_abandon`] holds.
 ///
-/// Runs every `CHECK_INTERVAL` ticks. A building becomes abandoned when:
-/// - Its cell has neither power nor water, OR
-/// - It has 0 occupants and its level is > 1 (upgraded building that emptied out).
+/// Collects candidates from building change events every tick and evaluates
+/// them every `CHECK_INTERVAL` ticks. Both conditions only depend on
+/// occupancy, level and utilities, so a building that raised none of those
+/// events since it last failed the check still fails it.
 pub fn check_building_abandonment(
     mut commands: Commands,
     tick: Res<TickCounter>,
     grid: Res<WorldGrid>,
-    buildings: Query<(Entity, &Building), Without<Abandoned>>,
+    mut candidates: ResMut<AbandonmentCandidates>,
+    mut occupancy_events: EventReader<BuildingOccupancyChanged>,
+    mut utility_events: EventReader<BuildingUtilitiesChanged>,
+    buildings: Query<&Building, Without<Abandoned>>,
 ) {
+    candidates
+        .pending
+        .extend(occupancy_events.read().map(|event| event.entity));
+    candidates.pending.extend(
+        utility_events
+            .read()
+            .filter(|event| event.lost_all())
+            .map(|event| event.entity),
+    );
+
     if !tick.0.is_multiple_of(CHECK_INTERVAL) {
         return;
     }
 
-    for (entity, building) in &buildings {
-        let x = building.grid_x;
-        let y = building.grid_y;
-
+    for entity in std::mem::take(&mut candidates.pending) {
+        let Ok(building) = buildings.get(entity) else {
+            continue;
+        };
+        let (x, y) = (building.grid_x, building.grid_y);
         if !grid.in_bounds(x, y) {
             continue;
         }
 
-        let cell = grid.get(x, y);
-
-        // Condition 1: both power and water are missing
-        let no_utilities = !cell.has_power && !cell.has_water;
-
-        // Condition 2: upgraded building with zero occupants
-        let empty_upgraded = building.occupants == 0 && building.level > 1;
-
-        if no_utilities || empty_upgraded {
+        if should_abandon(building, grid.get(x, y)) {
             commands
                 .entity(entity)
                 .insert(Abandoned { ticks_abandoned: 0 });
@@ -69,7 +97,8 @@ pub fn check_building_abandonment(
 ///
 /// Each tick:
 /// - Increments `ticks_abandoned`.
-/// - If the cell has regained both power AND water, the building recovers (Abandoned removed).
+/// - If the cell has regained both power AND water, the building recovers (Abandoned removed)
+///   and goes back into the abandonment candidates.
 /// - If `ticks_abandoned` exceeds `DEMOLISH_THRESHOLD`, the building is demolished:
 ///   the entity is despawned and the grid cell's `building_id` and `zone` are cleared.
 /// - While abandoned, building occupants are forced to 0.
@@ -77,6 +106,7 @@ pub fn process_abandoned_buildings(
     mut commands: Commands,
     mut grid: ResMut<WorldGrid>,
     mut buildings: Query<(Entity, &mut Building, &mut Abandoned)>,
+    mut candidates: ResMut<AbandonmentCandidates>,
     safety_net: Option<Res<TestSafetyNet>>,
 ) {
     if safety_net.is_some() {
@@ -99,9 +129,11 @@ pub fn process_abandoned_buildings(
         let has_power = cell.has_power;
         let has_water = cell.has_water;
 
-        // Recovery: if both utilities are restored, remove Abandoned
+        // Recovery: if both utilities are restored, remove Abandoned. The
+        // building is re-checked, since it may still be an empty upgrade.
         if has_power && has_water {
             commands.entity(entity).remove::<Abandoned>();
+            candidates.pending.insert(entity);
             continue;
         }
 
@@ -156,7 +188,7 @@ pub struct AbandonmentPlugin;
 
 impl Plugin for AbandonmentPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<AbandonmentCandidates>().add_systems(
             FixedUpdate,
             (
                 check_building_abandonment,
@@ -165,6 +197,8 @@ impl Plugin for AbandonmentPlugin {
             )
                 .chain()
                 .after(crate::utilities::propagate_utilities)
+                .after(crate::building_events::detect_occupancy_changes)
+                .after(crate::building_events::detect_utility_changes)
                 .in_set(crate::SimulationSet::Simulation),
         )
         .add_systems(
@@ -0,0 +1,246 @@
+//! Keyboard and screen-reader accessibility mode.
+//!
+//! `AccessibilitySettings::enabled` switches the UI into a mode where every
+//! panel can be driven from the keyboard, color-coded bars are labelled with
+//! text, and critical notifications are copied into `AccessibleLog`: a plain
+//! text log the UI shows in a read-only window and, on desktop, appends to
+//! `accessibility_log.txt` so external screen readers can follow it.
+//!
+//! Like the UI scale, the mode is a player preference kept in the player
+//! profile (see [`crate::profile`]).
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::Write;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::{Notification, NotificationLog, NotificationPriority};
+
+/// Settings file written by builds before the player profile, relative to
+/// the working directory. Only read to import old settings into the profile.
+pub const ACCESSIBILITY_PATH: &str = "accessibility.json";
+
+/// Text log that critical notifications are appended to while the mode is on.
+pub const ACCESSIBLE_LOG_PATH: &str = "accessibility_log.txt";
+
+/// Lines kept in memory for the in-game log window.
+pub const MAX_ACCESSIBLE_LOG_LINES: usize = 200;
+
+/// Player-facing accessibility options.
+#[derive(Resource, Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Keyboard navigation, text equivalents and the accessible log.
+    pub enabled: bool,
+    /// Also append the log to [`ACCESSIBLE_LOG_PATH`] while enabled.
+    pub log_to_file: bool,
+}
+
+impl AccessibilitySettings {
+    /// Parse settings from JSON. Missing fields keep their defaults.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))
+    }
+
+    /// Pretty-printed JSON for the settings file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load settings from `path`. A missing file yields the defaults; an
+    /// unreadable or invalid one is reported as an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
+
+/// Whether a notification is important enough for the accessible log.
+pub fn is_critical(priority: NotificationPriority) -> bool {
+    matches!(
+        priority,
+        NotificationPriority::Emergency
+            | NotificationPriority::Warning
+            | NotificationPriority::Attention
+    )
+}
+
+/// One log line: game time, priority in words, then the message.
+pub fn format_log_line(notification: &Notification) -> String {
+    let hour = notification.hour as u32;
+    let minute = (notification.hour.fract() * 60.0) as u32;
+    format!(
+        "Day {} {:02}:{:02} [{}] {}",
+        notification.day,
+        hour,
+        minute,
+        notification.priority.label(),
+        notification.text
+    )
+}
+
+/// Plain-text copy of critical notifications, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct AccessibleLog {
+    pub lines: Vec<String>,
+    /// Highest notification ID already considered.
+    last_seen_id: u64,
+}
+
+impl AccessibleLog {
+    /// Append lines for critical notifications newer than the last call and
+    /// return them.
+    pub fn collect(&mut self, log: &NotificationLog) -> Vec<String> {
+        let mut added = Vec::new();
+        for notification in &log.active {
+            if notification.id <= self.last_seen_id {
+                continue;
+            }
+            self.last_seen_id = notification.id;
+            if is_critical(notification.priority) {
+                added.push(format_log_line(notification));
+            }
+        }
+        self.lines.extend(added.iter().cloned());
+        if self.lines.len() > MAX_ACCESSIBLE_LOG_LINES {
+            let excess = self.lines.len() - MAX_ACCESSIBLE_LOG_LINES;
+            self.lines.drain(0..excess);
+        }
+        added
+    }
+
+    /// The whole log as one string, one line per notification.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// System: copy new critical notifications into the accessible log.
+fn collect_accessible_log(
+    settings: Res<AccessibilitySettings>,
+    notifications: Res<NotificationLog>,
+    mut log: ResMut<AccessibleLog>,
+) {
+    if !notifications.is_changed() {
+        return;
+    }
+    let added = log.collect(&notifications);
+    if added.is_empty() || !settings.enabled || !settings.log_to_file {
+        return;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let result = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(ACCESSIBLE_LOG_PATH)
+            .and_then(|mut file| added.iter().try_for_each(|line| writeln!(file, "{line}")));
+        if let Err(e) = result {
+            warn!("Failed to write {ACCESSIBLE_LOG_PATH}: {e}");
+        }
+    }
+}
+
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AccessibilitySettings>()
+            .init_resource::<AccessibleLog>()
+            .add_systems(Update, collect_accessible_log);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::{NotificationCategory, NotificationEvent};
+    use crate::time_of_day::GameClock;
+
+    fn push(log: &mut NotificationLog, text: &str, priority: NotificationPriority) {
+        let event = NotificationEvent {
+            text: text.to_string(),
+            priority,
+            category: NotificationCategory::General,
+            location: None,
+        };
+        log.push(&event, &GameClock::default(), 0);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let settings = AccessibilitySettings {
+            enabled: true,
+            log_to_file: true,
+        };
+        let json = settings.to_json().unwrap();
+        assert_eq!(AccessibilitySettings::from_json(&json).unwrap(), settings);
+        assert_eq!(
+            AccessibilitySettings::from_json("{}").unwrap(),
+            AccessibilitySettings::default()
+        );
+        assert!(AccessibilitySettings::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_log_keeps_only_critical_notifications() {
+        let mut notifications = NotificationLog::default();
+        push(&mut notifications, "Fire!", NotificationPriority::Emergency);
+        push(&mut notifications, "New park", NotificationPriority::Info);
+        push(&mut notifications, "Deficit", NotificationPriority::Warning);
+
+        let mut log = AccessibleLog::default();
+        let added = log.collect(&notifications);
+        assert_eq!(added.len(), 2);
+        assert!(added[0].ends_with("[EMERGENCY] Fire!"));
+        assert!(added[1].ends_with("[WARNING] Deficit"));
+
+        // Nothing is logged twice.
+        assert!(log.collect(&notifications).is_empty());
+        assert_eq!(log.lines.len(), 2);
+    }
+
+    #[test]
+    fn test_log_is_bounded() {
+        let mut notifications = NotificationLog::default();
+        let mut log = AccessibleLog::default();
+        for i in 0..MAX_ACCESSIBLE_LOG_LINES + 10 {
+            push(
+                &mut notifications,
+                &format!("n{i}"),
+                NotificationPriority::Attention,
+            );
+            log.collect(&notifications);
+        }
+        assert_eq!(log.lines.len(), MAX_ACCESSIBLE_LOG_LINES);
+        assert!(log.lines[0].ends_with("n10"));
+    }
+
+    #[test]
+    fn test_format_log_line_includes_time() {
+        let mut notifications = NotificationLog::default();
+        let clock = GameClock {
+            day: 3,
+            hour: 8.5,
+            ..GameClock::default()
+        };
+        let event = NotificationEvent {
+            text: "Flood".to_string(),
+            priority: NotificationPriority::Emergency,
+            category: NotificationCategory::General,
+            location: None,
+        };
+        notifications.push(&event, &clock, 0);
+        assert_eq!(
+            format_log_line(&notifications.active[0]),
+            "Day 3 08:30 [EMERGENCY] Flood"
+        );
+    }
+}
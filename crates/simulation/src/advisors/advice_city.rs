@@ -1,5 +1,5 @@
 //! Advisor logic for Safety, Environment, Housing, Traffic, Zone Demand,
-//! and Fire Coverage domains.
+//! Fire Coverage and Structural Integrity domains.
 
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::WorldGrid;
@@ -343,3 +343,31 @@ pub(crate) fn fire_coverage_advice(
         });
     }
 }
+
+// ---------------------------------------------------------------------------
+// Structural integrity advice
+// ---------------------------------------------------------------------------
+
+pub(crate) fn dangerous_buildings_advice(
+    tick: u64,
+    extras: &AdvisorExtras,
+    msgs: &mut Vec<AdvisorMessage>,
+) {
+    let structural = &extras.structural;
+    if structural.unsafe_count == 0 && structural.condemned_count == 0 {
+        return;
+    }
+    msgs.push(AdvisorMessage {
+        advisor_type: AdvisorType::Safety,
+        tip_id: TipId::DangerousBuildings,
+        message: format!(
+            "Inspectors report {} unsafe and {} condemned buildings.",
+            structural.unsafe_count, structural.condemned_count
+        ),
+        priority: if structural.condemned_count > 0 { 4 } else { 3 },
+        suggestion: "Renovate damaged buildings before they are condemned, or demolish them."
+            .into(),
+        tick_created: tick,
+        location: structural.worst_building(),
+    });
+}
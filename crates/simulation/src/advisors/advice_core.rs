@@ -11,12 +11,12 @@ use super::types::{AdvisorExtras, AdvisorMessage, AdvisorType, TipId};
 // Location-finding helpers
 // ---------------------------------------------------------------------------
 
-/// Find the grid cell with the worst value in a flat grid array (highest value = worst).
-pub(crate) fn find_worst_cell(levels: &[u8], width: usize) -> Option<(usize, usize)> {
-    if levels.is_empty() {
-        return None;
-    }
-    let (max_idx, &max_val) = levels.iter().enumerate().max_by_key(|(_, &v)| v)?;
+/// Find the grid cell with the worst value in a row-major grid (highest value = worst).
+pub(crate) fn find_worst_cell<'a>(
+    levels: impl IntoIterator<Item = &'a u8>,
+    width: usize,
+) -> Option<(usize, usize)> {
+    let (max_idx, &max_val) = levels.into_iter().enumerate().max_by_key(|(_, &v)| v)?;
     if max_val == 0 {
         return None;
     }
@@ -349,7 +349,7 @@ pub(crate) fn health_advice(tick: u64, extras: &AdvisorExtras, msgs: &mut Vec<Ad
 pub(crate) fn education_advice(tick: u64, extras: &AdvisorExtras, msgs: &mut Vec<AdvisorMessage>) {
     // Compute average education level across the grid
     let total = extras.education_grid.levels.len() as f32;
-    let sum: f32 = extras.education_grid.levels.iter().map(|&v| v as f32).sum();
+    let sum = extras.education_grid.levels.sum() as f32;
     let avg_edu = if total > 0.0 { sum / total } else { 0.0 };
 
     if avg_edu < 0.5 {
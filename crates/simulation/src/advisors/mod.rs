@@ -23,8 +23,8 @@ use crate::stats::CityStats;
 use crate::TickCounter;
 
 use advice_city::{
-    environment_advice, fire_coverage_advice, housing_advice, safety_advice, traffic_advice,
-    zone_demand_advice,
+    dangerous_buildings_advice, environment_advice, fire_coverage_advice, housing_advice,
+    safety_advice, traffic_advice, zone_demand_advice,
 };
 use advice_core::{education_advice, finance_advice, health_advice, infrastructure_advice};
 use types::ADVISOR_INTERVAL;
@@ -85,6 +85,9 @@ pub fn update_advisors(
     // ------ Fire Coverage ------
     fire_coverage_advice(t, &grid, &extras, &mut new_msgs);
 
+    // ------ Structural Integrity ------
+    dangerous_buildings_advice(t, &extras, &mut new_msgs);
+
     // Filter out dismissed tips
     new_msgs.retain(|msg| !dismissed.is_dismissed(msg.tip_id));
 
@@ -11,6 +11,7 @@ use crate::homelessness::HomelessnessStats;
 use crate::loans::LoanBook;
 use crate::pollution::PollutionGrid;
 use crate::road_maintenance::RoadMaintenanceStats;
+use crate::structural_integrity::StructuralState;
 use crate::traffic::TrafficGrid;
 use crate::zones::ZoneDemand;
 
@@ -130,6 +131,9 @@ pub enum TipId {
 
     // Fire coverage
     FireCoverageGap,
+
+    // Structural integrity
+    DangerousBuildings,
 }
 
 impl TipId {
@@ -173,6 +177,7 @@ impl TipId {
             TipId::ZoneDemandCommercial => "Commercial Demand",
             TipId::ZoneDemandIndustrial => "Industrial Demand",
             TipId::FireCoverageGap => "Fire Coverage Gap",
+            TipId::DangerousBuildings => "Dangerous Buildings",
         }
     }
 }
@@ -278,4 +283,5 @@ pub struct AdvisorExtras<'w> {
     pub road_stats: Res<'w, RoadMaintenanceStats>,
     pub traffic: Res<'w, TrafficGrid>,
     pub zone_demand: Res<'w, ZoneDemand>,
+    pub structural: Res<'w, StructuralState>,
 }
@@ -0,0 +1,128 @@
+//! Commands an external agent sends to the simulation (stdin → simulation).
+
+use serde::{Deserialize, Serialize};
+
+use crate::agent_queries::{GridLayer, GridRegion};
+use crate::game_actions::GameAction;
+
+/// A single command sent by the external agent over stdin.
+///
+/// Each line of stdin is parsed as one `AgentCommand`. The `cmd` field acts as
+/// the discriminator tag.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "cmd")]
+pub enum AgentCommand {
+    /// Request the current city observation snapshot.
+    #[serde(rename = "observe")]
+    Observe,
+
+    /// Execute a single game action.
+    #[serde(rename = "act")]
+    Act { action: GameAction },
+
+    /// Execute multiple game actions in sequence.
+    #[serde(rename = "batch_act")]
+    BatchAct { actions: Vec<GameAction> },
+
+    /// Advance the simulation by `ticks` fixed-update ticks.
+    #[serde(rename = "step")]
+    Step { ticks: u64 },
+
+    /// Reset to a new game with the given seed.
+    #[serde(rename = "new_game")]
+    NewGame { seed: u64 },
+
+    /// Save the recorded replay: JSON for a `.json` path, the binary
+    /// container (with checkpoints) otherwise.
+    #[serde(rename = "save_replay")]
+    SaveReplay { path: String },
+
+    /// Load a replay file in either encoding.
+    #[serde(rename = "load_replay")]
+    LoadReplay { path: String },
+
+    /// Request one or more spatial data layers.
+    #[serde(rename = "query")]
+    Query { layers: Vec<String> },
+
+    /// Read grid layers as flat arrays over `region` (default: the whole
+    /// map), averaged over `step`×`step` blocks (default 1).
+    #[serde(rename = "query_grid")]
+    QueryGrid {
+        layers: Vec<GridLayer>,
+        #[serde(default)]
+        region: Option<GridRegion>,
+        #[serde(default)]
+        step: Option<usize>,
+    },
+
+    /// Find the road route between two `[x, y]` cells.
+    #[serde(rename = "find_path")]
+    FindPath { from: [usize; 2], to: [usize; 2] },
+
+    /// Current statistics of every player-defined district.
+    #[serde(rename = "district_stats")]
+    DistrictStats,
+
+    /// Recorded time series for the city, or for `district` if given; all
+    /// series if `series` is empty, and only the `last` samples if given.
+    #[serde(rename = "history")]
+    History {
+        #[serde(default)]
+        series: Vec<String>,
+        #[serde(default)]
+        district: Option<usize>,
+        #[serde(default)]
+        last: Option<usize>,
+    },
+
+    /// Run `commands` in order as one request. With `stop_on_error` (the
+    /// default) the first failing command ends the transaction.
+    #[serde(rename = "transaction")]
+    Transaction {
+        commands: Vec<AgentCommand>,
+        #[serde(default = "default_stop_on_error")]
+        stop_on_error: bool,
+    },
+
+    /// Receive `stream` messages every `every` ticks (default per stream)
+    /// until unsubscribed. WebSocket clients only.
+    #[serde(rename = "subscribe")]
+    Subscribe {
+        stream: StreamKind,
+        #[serde(default)]
+        every: Option<u64>,
+    },
+
+    /// Stop receiving `stream` messages.
+    #[serde(rename = "unsubscribe")]
+    Unsubscribe { stream: StreamKind },
+
+    /// Gracefully shut down the agent session.
+    #[serde(rename = "quit")]
+    Quit,
+}
+
+/// Streams a client can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamKind {
+    /// A `tick` message after each simulation tick.
+    Ticks,
+    /// A `stats` message with the city's headline numbers.
+    Stats,
+}
+
+impl StreamKind {
+    /// Ticks between messages when `subscribe` doesn't say.
+    pub fn default_every(self) -> u64 {
+        match self {
+            StreamKind::Ticks => 1,
+            StreamKind::Stats => 10,
+        }
+    }
+}
+
+fn default_stop_on_error() -> bool {
+    true
+}
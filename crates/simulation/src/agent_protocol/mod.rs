@@ -0,0 +1,31 @@
+//! Agent text protocol types for the `--agent` headless mode.
+//!
+//! Defines the JSON command/response envelope that external programs (LLMs,
+//! scripts, test harnesses) use to interact with the simulation over
+//! newline-delimited JSON on stdin/stdout, or as WebSocket text messages in
+//! `--serve` mode. Only WebSocket clients can subscribe to streams. Grid,
+//! path, district and history queries are answered by `agent_queries`, and a
+//! `transaction` runs several commands as one request.
+//!
+//! These types live in the `simulation` crate so they can be unit-tested
+//! without pulling in the full app binary. The actual I/O loops live in
+//! `crates/app/src/agent_mode.rs` and `crates/app/src/remote_server.rs`.
+
+mod commands;
+mod responses;
+#[cfg(test)]
+mod tests;
+
+pub use commands::{AgentCommand, StreamKind};
+pub use responses::{AgentResponse, ResponsePayload, StatsUpdate};
+
+/// Current protocol version. Bump when the command/response schema changes.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Convenience constructor that wraps a payload with the current protocol version.
+pub fn make_response(payload: ResponsePayload) -> AgentResponse {
+    AgentResponse {
+        protocol_version: PROTOCOL_VERSION,
+        payload,
+    }
+}
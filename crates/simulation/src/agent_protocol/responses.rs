@@ -0,0 +1,143 @@
+//! Responses and stream messages the simulation sends back (simulation →
+//! stdout).
+
+use serde::Serialize;
+
+use crate::agent_queries::{DistrictInfo, GridData, HistorySeries, PathInfo};
+use crate::city_observation::CityObservation;
+use crate::game_actions::ActionResult;
+
+use super::StreamKind;
+
+/// Every response includes the protocol version and a tagged payload.
+#[derive(Debug, Serialize)]
+pub struct AgentResponse {
+    /// Monotonically increasing protocol version (currently 1).
+    pub protocol_version: u32,
+    /// The response payload, flattened into this object.
+    #[serde(flatten)]
+    pub payload: ResponsePayload,
+}
+
+/// Tagged payload variants for agent responses.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ResponsePayload {
+    /// The simulation is ready to accept commands.
+    #[serde(rename = "ready")]
+    Ready,
+
+    /// A city observation snapshot.
+    #[serde(rename = "observation")]
+    Observation { observation: Box<CityObservation> },
+
+    /// Result of a single `act` command.
+    #[serde(rename = "action_result")]
+    ActionResult { result: ActionResult },
+
+    /// Results of a `batch_act` command.
+    #[serde(rename = "batch_result")]
+    BatchResult { results: Vec<ActionResult> },
+
+    /// The simulation has advanced; reports the current tick counter.
+    #[serde(rename = "step_complete")]
+    StepComplete { tick: u64 },
+
+    /// Results of a `query` command — a JSON object keyed by layer name.
+    #[serde(rename = "query_result")]
+    QueryResult { layers: serde_json::Value },
+
+    /// Generic success acknowledgement (used for stubs, new_game, etc.).
+    #[serde(rename = "ok")]
+    Ok,
+
+    /// An error occurred while processing the command.
+    #[serde(rename = "error")]
+    Error { message: String },
+
+    /// The session is ending (response to `quit`).
+    #[serde(rename = "goodbye")]
+    Goodbye,
+
+    /// A subscription was started or replaced.
+    #[serde(rename = "subscribed")]
+    Subscribed { stream: StreamKind, every: u64 },
+
+    /// A subscription was stopped.
+    #[serde(rename = "unsubscribed")]
+    Unsubscribed { stream: StreamKind },
+
+    /// `ticks` stream: the simulation finished a tick.
+    #[serde(rename = "tick")]
+    Tick { tick: u64 },
+
+    /// `stats` stream: the city's headline numbers.
+    #[serde(rename = "stats")]
+    Stats { stats: StatsUpdate },
+
+    /// Results of a `query_grid` command.
+    #[serde(rename = "grid_result")]
+    GridResult { grid: GridData },
+
+    /// Result of a `find_path` command.
+    #[serde(rename = "path_result")]
+    PathResult { path: PathInfo },
+
+    /// Results of a `district_stats` command.
+    #[serde(rename = "district_stats")]
+    DistrictStats { districts: Vec<DistrictInfo> },
+
+    /// Results of a `history` command.
+    #[serde(rename = "history")]
+    History { history: HistorySeries },
+
+    /// Results of a `transaction`, one per command run. `completed` is false
+    /// if a failing command stopped it early.
+    #[serde(rename = "transaction_result")]
+    TransactionResult {
+        results: Vec<ResponsePayload>,
+        completed: bool,
+    },
+}
+
+impl ResponsePayload {
+    /// Whether the command this answers failed: a protocol error or a
+    /// rejected game action.
+    pub fn is_failure(&self) -> bool {
+        match self {
+            ResponsePayload::Error { .. } => true,
+            ResponsePayload::ActionResult { result } => !result.is_success(),
+            ResponsePayload::BatchResult { results } => results.iter().any(|r| !r.is_success()),
+            ResponsePayload::TransactionResult { completed, .. } => !completed,
+            _ => false,
+        }
+    }
+}
+
+/// Headline numbers sent to `stats` subscribers.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StatsUpdate {
+    pub tick: u64,
+    pub day: u32,
+    pub hour: f32,
+    pub population: u32,
+    pub unemployed: u32,
+    pub treasury: f64,
+    pub net_income: f64,
+    pub happiness: f32,
+}
+
+impl From<&CityObservation> for StatsUpdate {
+    fn from(obs: &CityObservation) -> Self {
+        Self {
+            tick: obs.tick,
+            day: obs.day,
+            hour: obs.hour,
+            population: obs.population.total,
+            unemployed: obs.population.unemployed,
+            treasury: obs.treasury,
+            net_income: obs.net_income,
+            happiness: obs.happiness.overall,
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use super::*;
+use crate::agent_queries::GridLayer;
+use crate::city_observation::CityObservation;
+use crate::game_actions::ActionResult;
+
+#[test]
+fn deserialize_observe_command() {
+    let json = r#"{"cmd":"observe"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::Observe));
+}
+
+#[test]
+fn deserialize_act_command() {
+    let json = r#"{"cmd":"act","action":{"SetPaused":{"paused":true}}}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::Act { .. }));
+}
+
+#[test]
+fn deserialize_batch_act_command() {
+    let json = r#"{"cmd":"batch_act","actions":[{"SetSpeed":{"speed":2}},{"SetPaused":{"paused":false}}]}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::BatchAct { actions } = cmd {
+        assert_eq!(actions.len(), 2);
+    } else {
+        panic!("expected BatchAct");
+    }
+}
+
+#[test]
+fn deserialize_step_command() {
+    let json = r#"{"cmd":"step","ticks":100}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::Step { ticks } = cmd {
+        assert_eq!(ticks, 100);
+    } else {
+        panic!("expected Step");
+    }
+}
+
+#[test]
+fn deserialize_new_game_command() {
+    let json = r#"{"cmd":"new_game","seed":42}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::NewGame { seed } = cmd {
+        assert_eq!(seed, 42);
+    } else {
+        panic!("expected NewGame");
+    }
+}
+
+#[test]
+fn deserialize_save_replay_command() {
+    let json = r#"{"cmd":"save_replay","path":"/tmp/replay.bin"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::SaveReplay { .. }));
+}
+
+#[test]
+fn deserialize_load_replay_command() {
+    let json = r#"{"cmd":"load_replay","path":"/tmp/replay.bin"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::LoadReplay { .. }));
+}
+
+#[test]
+fn deserialize_query_command() {
+    let json = r#"{"cmd":"query","layers":["map","buildings"]}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::Query { layers } = cmd {
+        assert_eq!(layers, vec!["map", "buildings"]);
+    } else {
+        panic!("expected Query");
+    }
+}
+
+#[test]
+fn deserialize_query_command_empty_layers() {
+    let json = r#"{"cmd":"query","layers":[]}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::Query { layers } = cmd {
+        assert!(layers.is_empty());
+    } else {
+        panic!("expected Query");
+    }
+}
+
+#[test]
+fn deserialize_subscribe_commands() {
+    let json = r#"{"cmd":"subscribe","stream":"stats","every":5}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        cmd,
+        AgentCommand::Subscribe {
+            stream: StreamKind::Stats,
+            every: Some(5)
+        }
+    ));
+
+    let json = r#"{"cmd":"subscribe","stream":"ticks"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::Subscribe { every: None, .. }));
+
+    let json = r#"{"cmd":"unsubscribe","stream":"ticks"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        cmd,
+        AgentCommand::Unsubscribe {
+            stream: StreamKind::Ticks
+        }
+    ));
+}
+
+#[test]
+fn deserialize_data_query_commands() {
+    let json = r#"{"cmd":"query_grid","layers":["pollution","land_value"],"step":4}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::QueryGrid {
+        layers,
+        region,
+        step,
+    } = cmd
+    {
+        assert_eq!(layers, vec![GridLayer::Pollution, GridLayer::LandValue]);
+        assert_eq!(region, None);
+        assert_eq!(step, Some(4));
+    } else {
+        panic!("expected QueryGrid");
+    }
+
+    let json = r#"{"cmd":"query_grid","layers":["smog"]}"#;
+    assert!(serde_json::from_str::<AgentCommand>(json).is_err());
+
+    let json = r#"{"cmd":"find_path","from":[1,2],"to":[30,40]}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        cmd,
+        AgentCommand::FindPath {
+            from: [1, 2],
+            to: [30, 40]
+        }
+    ));
+
+    let json = r#"{"cmd":"history","district":2,"last":10}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        cmd,
+        AgentCommand::History {
+            district: Some(2),
+            last: Some(10),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn deserialize_transaction_command() {
+    let json = r#"{"cmd":"transaction","commands":[{"cmd":"step","ticks":5},{"cmd":"observe"}]}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    if let AgentCommand::Transaction {
+        commands,
+        stop_on_error,
+    } = cmd
+    {
+        assert_eq!(commands.len(), 2);
+        assert!(stop_on_error);
+    } else {
+        panic!("expected Transaction");
+    }
+}
+
+#[test]
+fn serialize_transaction_result() {
+    let resp = make_response(ResponsePayload::TransactionResult {
+        results: vec![
+            ResponsePayload::StepComplete { tick: 5 },
+            ResponsePayload::Error {
+                message: "bad".to_string(),
+            },
+        ],
+        completed: false,
+    });
+    assert!(resp.payload.is_failure());
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"transaction_result\""));
+    assert!(json.contains("{\"type\":\"step_complete\",\"tick\":5}"));
+    assert!(!ResponsePayload::StepComplete { tick: 5 }.is_failure());
+}
+
+#[test]
+fn serialize_stream_messages() {
+    let resp = make_response(ResponsePayload::Tick { tick: 7 });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"tick\""));
+    assert!(json.contains("\"tick\":7"));
+
+    let mut obs = CityObservation::default();
+    obs.population.total = 1234;
+    let resp = make_response(ResponsePayload::Stats {
+        stats: StatsUpdate::from(&obs),
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"stats\""));
+    assert!(json.contains("\"population\":1234"));
+}
+
+#[test]
+fn deserialize_quit_command() {
+    let json = r#"{"cmd":"quit"}"#;
+    let cmd: AgentCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, AgentCommand::Quit));
+}
+
+#[test]
+fn serialize_ready_response() {
+    let resp = make_response(ResponsePayload::Ready);
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"protocol_version\":1"));
+    assert!(json.contains("\"type\":\"ready\""));
+}
+
+#[test]
+fn serialize_observation_response() {
+    let obs = CityObservation::default();
+    let resp = make_response(ResponsePayload::Observation {
+        observation: Box::new(obs),
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"observation\""));
+    assert!(json.contains("\"tick\":0"));
+}
+
+#[test]
+fn serialize_action_result_response() {
+    let resp = make_response(ResponsePayload::ActionResult {
+        result: ActionResult::Success,
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"action_result\""));
+    assert!(json.contains("\"result\":\"Success\""));
+}
+
+#[test]
+fn serialize_batch_result_response() {
+    let resp = make_response(ResponsePayload::BatchResult {
+        results: vec![ActionResult::Success, ActionResult::Success],
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"batch_result\""));
+}
+
+#[test]
+fn serialize_step_complete_response() {
+    let resp = make_response(ResponsePayload::StepComplete { tick: 42 });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"step_complete\""));
+    assert!(json.contains("\"tick\":42"));
+}
+
+#[test]
+fn serialize_query_result_response() {
+    let mut map = serde_json::Map::new();
+    map.insert(
+        "overview".to_string(),
+        serde_json::Value::String("test map data".to_string()),
+    );
+    let resp = make_response(ResponsePayload::QueryResult {
+        layers: serde_json::Value::Object(map),
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"query_result\""));
+    assert!(json.contains("\"overview\""));
+    assert!(json.contains("test map data"));
+}
+
+#[test]
+fn serialize_error_response() {
+    let resp = make_response(ResponsePayload::Error {
+        message: "something went wrong".to_string(),
+    });
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"error\""));
+    assert!(json.contains("something went wrong"));
+}
+
+#[test]
+fn serialize_goodbye_response() {
+    let resp = make_response(ResponsePayload::Goodbye);
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"goodbye\""));
+}
+
+#[test]
+fn serialize_ok_response() {
+    let resp = make_response(ResponsePayload::Ok);
+    let json = serde_json::to_string(&resp).unwrap();
+    assert!(json.contains("\"type\":\"ok\""));
+}
+
+#[test]
+fn invalid_command_returns_parse_error() {
+    let json = r#"{"cmd":"nonexistent"}"#;
+    let result = serde_json::from_str::<AgentCommand>(json);
+    assert!(result.is_err());
+}
+
+#[test]
+fn malformed_json_returns_parse_error() {
+    let json = r#"{not valid json"#;
+    let result = serde_json::from_str::<AgentCommand>(json);
+    assert!(result.is_err());
+}
@@ -0,0 +1,63 @@
+//! `district_stats`: the player's districts and how they are doing.
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::buildings::Building;
+use crate::district_history::{building_jobs, snapshot_district};
+use crate::districts::DistrictMap;
+use crate::land_value::LandValueGrid;
+use crate::time_of_day::GameClock;
+
+/// One player-defined district's current statistics.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DistrictInfo {
+    /// Index used by `history` queries.
+    pub index: usize,
+    pub name: String,
+    pub cells: usize,
+    pub population: u32,
+    pub happiness: f32,
+    pub crime: f32,
+    pub jobs: u32,
+    pub land_value: f32,
+    pub tax_rate: Option<f32>,
+}
+
+/// Current statistics of every player-defined district.
+pub fn district_stats(world: &mut World) -> Vec<DistrictInfo> {
+    let district_count = world.resource::<DistrictMap>().districts.len();
+    let mut jobs = vec![0u32; district_count];
+    let mut buildings = world.query::<&Building>();
+    let district_map = world.resource::<DistrictMap>();
+    for building in buildings.iter(world) {
+        if let Some(di) = district_map.get_district_index_at(building.grid_x, building.grid_y) {
+            if let Some(j) = jobs.get_mut(di) {
+                *j += building_jobs(building);
+            }
+        }
+    }
+
+    let day = world.resource::<GameClock>().day;
+    let land_value = world.resource::<LandValueGrid>();
+    district_map
+        .districts
+        .iter()
+        .zip(jobs)
+        .enumerate()
+        .map(|(index, (district, jobs))| {
+            let snapshot = snapshot_district(day, district, jobs, land_value);
+            DistrictInfo {
+                index,
+                name: district.name.clone(),
+                cells: district.cells.len(),
+                population: snapshot.population,
+                happiness: snapshot.happiness,
+                crime: snapshot.crime,
+                jobs: snapshot.jobs,
+                land_value: snapshot.land_value,
+                tax_rate: district.policies.tax_rate,
+            }
+        })
+        .collect()
+}
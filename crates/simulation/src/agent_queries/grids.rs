@@ -0,0 +1,161 @@
+//! `query_grid`: simulation grids as compact arrays.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::crime::CrimeGrid;
+use crate::land_value::LandValueGrid;
+use crate::noise::NoisePollutionGrid;
+use crate::pollution::PollutionGrid;
+use crate::traffic::TrafficGrid;
+
+/// Largest `step` a grid query may use.
+pub const MAX_GRID_STEP: usize = 64;
+
+/// A grid layer agents can read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GridLayer {
+    /// Air pollution, 0-255.
+    Pollution,
+    /// Land value, 0-255.
+    LandValue,
+    /// Vehicles per cell.
+    Traffic,
+    /// Noise, 0-100.
+    Noise,
+    /// Crime, 0-255.
+    Crime,
+}
+
+impl GridLayer {
+    pub fn name(self) -> &'static str {
+        match self {
+            GridLayer::Pollution => "pollution",
+            GridLayer::LandValue => "land_value",
+            GridLayer::Traffic => "traffic",
+            GridLayer::Noise => "noise",
+            GridLayer::Crime => "crime",
+        }
+    }
+}
+
+/// The part of the map a grid query covers, in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GridRegion {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for GridRegion {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: GRID_WIDTH,
+            height: GRID_HEIGHT,
+        }
+    }
+}
+
+/// Grid layers over a region, one row-major array per layer. With a `step`
+/// above 1 each value is the mean of a `step`×`step` block of cells (smaller
+/// at the region's right and bottom edges).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GridData {
+    pub region: GridRegion,
+    pub step: usize,
+    /// Values per row.
+    pub columns: usize,
+    pub rows: usize,
+    pub layers: BTreeMap<&'static str, Vec<u16>>,
+}
+
+/// Read `layers` over `region` (default: the whole map), averaging blocks of
+/// `step` cells (default 1).
+pub fn read_grids(
+    world: &World,
+    layers: &[GridLayer],
+    region: Option<GridRegion>,
+    step: Option<usize>,
+) -> Result<GridData, String> {
+    let region = region.unwrap_or_default();
+    if region.width == 0
+        || region.height == 0
+        || region.x + region.width > GRID_WIDTH
+        || region.y + region.height > GRID_HEIGHT
+    {
+        return Err(format!(
+            "region must be non-empty and inside the {GRID_WIDTH}x{GRID_HEIGHT} map"
+        ));
+    }
+    let step = step.unwrap_or(1);
+    if !(1..=MAX_GRID_STEP).contains(&step) {
+        return Err(format!("step must be between 1 and {MAX_GRID_STEP}"));
+    }
+
+    let columns = region.width.div_ceil(step);
+    let rows = region.height.div_ceil(step);
+    let mut data = GridData {
+        region,
+        step,
+        columns,
+        rows,
+        layers: BTreeMap::new(),
+    };
+    for &layer in layers {
+        let values = match layer {
+            GridLayer::Pollution => sample(world, region, step, |g: &PollutionGrid, x, y| {
+                g.get(x, y) as u32
+            }),
+            GridLayer::LandValue => sample(world, region, step, |g: &LandValueGrid, x, y| {
+                g.get(x, y) as u32
+            }),
+            GridLayer::Traffic => sample(world, region, step, |g: &TrafficGrid, x, y| {
+                g.get(x, y) as u32
+            }),
+            GridLayer::Noise => sample(world, region, step, |g: &NoisePollutionGrid, x, y| {
+                g.get(x, y) as u32
+            }),
+            GridLayer::Crime => sample(world, region, step, |g: &CrimeGrid, x, y| {
+                g.get(x, y) as u32
+            }),
+        }
+        .ok_or_else(|| format!("grid '{}' is not available", layer.name()))?;
+        data.layers.insert(layer.name(), values);
+    }
+    Ok(data)
+}
+
+/// Block means of `value` over `region`, or `None` if the grid resource
+/// doesn't exist.
+fn sample<G: Resource>(
+    world: &World,
+    region: GridRegion,
+    step: usize,
+    value: impl Fn(&G, usize, usize) -> u32,
+) -> Option<Vec<u16>> {
+    let grid = world.get_resource::<G>()?;
+    let x_end = region.x + region.width;
+    let y_end = region.y + region.height;
+    let mut values = Vec::with_capacity(region.width.div_ceil(step) * region.height.div_ceil(step));
+    for by in (region.y..y_end).step_by(step) {
+        for bx in (region.x..x_end).step_by(step) {
+            let mut sum = 0u32;
+            let mut count = 0u32;
+            for y in by..(by + step).min(y_end) {
+                for x in bx..(bx + step).min(x_end) {
+                    sum += value(grid, x, y);
+                    count += 1;
+                }
+            }
+            values.push(((sum + count / 2) / count).min(u16::MAX as u32) as u16);
+        }
+    }
+    Some(values)
+}
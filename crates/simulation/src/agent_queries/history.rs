@@ -0,0 +1,147 @@
+//! `history`: recorded time series, city-wide or for one district.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::chart_data::{BudgetSnapshot, ChartHistory, PopulationSnapshot};
+use crate::district_history::{DistrictHistory, DistrictSnapshot, RECORD_INTERVAL_DAYS};
+use crate::districts::DistrictMap;
+
+/// Game days between city-wide history samples (see `chart_data`).
+pub const CITY_HISTORY_INTERVAL_DAYS: u32 = 10;
+
+/// City-wide series: population (from `PopulationSnapshot`) and budget.
+const CITY_SERIES: [&str; 6] = [
+    "population",
+    "residential_workers",
+    "commercial_workers",
+    "industrial_workers",
+    "income",
+    "expenses",
+];
+
+/// Per-district series.
+const DISTRICT_SERIES: [&str; 6] = [
+    "day",
+    "population",
+    "happiness",
+    "crime",
+    "jobs",
+    "land_value",
+];
+
+/// The requested series, oldest sample first.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistorySeries {
+    /// The district the series belong to, or `None` for the whole city.
+    pub district: Option<usize>,
+    /// Game days between samples.
+    pub interval_days: u32,
+    pub series: BTreeMap<String, Vec<f64>>,
+}
+
+fn city_value(name: &str, population: &PopulationSnapshot, budget: &BudgetSnapshot) -> f64 {
+    match name {
+        "population" => population.total as f64,
+        "residential_workers" => population.residential_workers as f64,
+        "commercial_workers" => population.commercial_workers as f64,
+        "industrial_workers" => population.industrial_workers as f64,
+        "income" => {
+            budget.residential_tax
+                + budget.commercial_tax
+                + budget.industrial_tax
+                + budget.office_tax
+                + budget.trade_income
+        }
+        _ => {
+            budget.road_maintenance
+                + budget.service_costs
+                + budget.policy_costs
+                + budget.loan_payments
+        }
+    }
+}
+
+fn district_value(name: &str, snapshot: &DistrictSnapshot) -> f64 {
+    match name {
+        "day" => snapshot.day as f64,
+        "population" => snapshot.population as f64,
+        "happiness" => snapshot.happiness as f64,
+        "crime" => snapshot.crime as f64,
+        "jobs" => snapshot.jobs as f64,
+        _ => snapshot.land_value as f64,
+    }
+}
+
+/// Read `series` (all of them if empty) for `district`, or for the city if
+/// `None`, keeping only the `last` samples if given.
+pub fn read_history(
+    world: &World,
+    series: &[String],
+    district: Option<usize>,
+    last: Option<usize>,
+) -> Result<HistorySeries, String> {
+    let available: &[&str] = if district.is_some() {
+        &DISTRICT_SERIES
+    } else {
+        &CITY_SERIES
+    };
+    let names: Vec<&str> = if series.is_empty() {
+        available.to_vec()
+    } else {
+        series.iter().map(String::as_str).collect()
+    };
+    if let Some(unknown) = names.iter().find(|name| !available.contains(name)) {
+        return Err(format!(
+            "unknown series '{unknown}' (available: {})",
+            available.join(", ")
+        ));
+    }
+
+    let (interval_days, columns) = match district {
+        None => {
+            let history = world.resource::<ChartHistory>();
+            let columns: Vec<Vec<f64>> = names
+                .iter()
+                .map(|name| {
+                    history
+                        .population
+                        .iter()
+                        .zip(&history.budget)
+                        .map(|(population, budget)| city_value(name, population, budget))
+                        .collect()
+                })
+                .collect();
+            (CITY_HISTORY_INTERVAL_DAYS, columns)
+        }
+        Some(index) => {
+            if index >= world.resource::<DistrictMap>().districts.len() {
+                return Err(format!("there is no district {index}"));
+            }
+            let snapshots = world.resource::<DistrictHistory>().series(index);
+            let columns: Vec<Vec<f64>> = names
+                .iter()
+                .map(|name| snapshots.iter().map(|s| district_value(name, s)).collect())
+                .collect();
+            (RECORD_INTERVAL_DAYS, columns)
+        }
+    };
+
+    let series = names
+        .into_iter()
+        .zip(columns)
+        .map(|(name, mut values)| {
+            if let Some(last) = last {
+                values.drain(..values.len().saturating_sub(last));
+            }
+            (name.to_string(), values)
+        })
+        .collect();
+    Ok(HistorySeries {
+        district,
+        interval_days,
+        series,
+    })
+}
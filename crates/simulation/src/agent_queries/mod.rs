@@ -0,0 +1,24 @@
+//! Read-only queries behind the agent protocol's data commands.
+//!
+//! - `query_grid` returns simulation grids (pollution, land value, traffic,
+//!   noise, crime) as flat row-major arrays, optionally cropped to a region
+//!   and averaged over blocks so a whole map fits in a small message.
+//! - `find_path` routes between two cells over the road network the way
+//!   citizens drive, with the current traffic cost.
+//! - `district_stats` summarizes every player-defined district.
+//! - `history` returns recorded time series for the city or one district.
+//!
+//! Each takes the world and returns a serializable result, so the agent
+//! command loop only has to wrap it in a response.
+
+pub mod districts;
+pub mod grids;
+pub mod history;
+pub mod paths;
+#[cfg(test)]
+mod tests;
+
+pub use districts::{district_stats, DistrictInfo};
+pub use grids::{read_grids, GridData, GridLayer, GridRegion, MAX_GRID_STEP};
+pub use history::{read_history, HistorySeries};
+pub use paths::{find_route, PathInfo};
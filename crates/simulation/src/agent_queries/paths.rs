@@ -0,0 +1,75 @@
+//! `find_path`: a road route between two cells.
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::grid::WorldGrid;
+use crate::pathfinding_sys::{find_path, nearest_road};
+use crate::roads::{RoadNetwork, RoadNode};
+use crate::traffic::TrafficGrid;
+
+/// The road route between two cells, as citizens would drive it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PathInfo {
+    pub found: bool,
+    /// Road cell the route starts from: the nearest one to `from`.
+    pub start: Option<[usize; 2]>,
+    /// Road cell the route ends at: the nearest one to `to`.
+    pub goal: Option<[usize; 2]>,
+    /// Every road cell on the route, `start` and `goal` included.
+    pub cells: Vec<[usize; 2]>,
+    /// Travel cost with the current traffic and road speeds, in the units
+    /// the simulation's pathfinding uses.
+    pub travel_cost: u32,
+    /// Why no route was found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Find the road route from the road nearest `from` to the road nearest `to`.
+pub fn find_route(world: &World, from: [usize; 2], to: [usize; 2]) -> Result<PathInfo, String> {
+    let grid = world.resource::<WorldGrid>();
+    for [x, y] in [from, to] {
+        if !grid.in_bounds(x, y) {
+            return Err(format!("cell ({x}, {y}) is outside the map"));
+        }
+    }
+    let network = world.resource::<RoadNetwork>();
+    let node = |[x, y]: [usize; 2]| nearest_road(network, x, y);
+    let cell = |node: RoadNode| [node.0, node.1];
+
+    let (Some(start), Some(goal)) = (node(from), node(to)) else {
+        return Ok(PathInfo {
+            start: node(from).map(cell),
+            goal: node(to).map(cell),
+            reason: Some("no road within 3 cells of an endpoint".to_string()),
+            ..default()
+        });
+    };
+    let Some(route) = find_path(network, start, goal) else {
+        return Ok(PathInfo {
+            start: Some(cell(start)),
+            goal: Some(cell(goal)),
+            reason: Some("the roads are not connected".to_string()),
+            ..default()
+        });
+    };
+
+    let traffic = world.get_resource::<TrafficGrid>();
+    let travel_cost = route
+        .iter()
+        .skip(1)
+        .map(|n| match traffic {
+            Some(traffic) => traffic.path_cost_with_road(n.0, n.1, grid.get(n.0, n.1).road_type),
+            None => 1,
+        })
+        .sum();
+    Ok(PathInfo {
+        found: true,
+        start: Some(cell(start)),
+        goal: Some(cell(goal)),
+        cells: route.into_iter().map(cell).collect(),
+        travel_cost,
+        reason: None,
+    })
+}
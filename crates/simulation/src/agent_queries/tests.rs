@@ -0,0 +1,112 @@
+use super::*;
+use crate::chart_data::{BudgetSnapshot, ChartHistory, PopulationSnapshot};
+use crate::grid::RoadType;
+use crate::pollution::PollutionGrid;
+use crate::test_harness::TestCity;
+use crate::traffic::TrafficGrid;
+
+#[test]
+fn test_grid_region_and_block_means() {
+    let mut city = TestCity::new();
+    let world = city.world_mut();
+    {
+        let mut pollution = world.resource_mut::<PollutionGrid>();
+        pollution.set(10, 10, 100);
+        pollution.set(11, 10, 50);
+    }
+    world.resource_mut::<TrafficGrid>().set(12, 11, 7);
+
+    let region = GridRegion {
+        x: 10,
+        y: 10,
+        width: 3,
+        height: 2,
+    };
+    let grid = read_grids(
+        world,
+        &[GridLayer::Pollution, GridLayer::Traffic],
+        Some(region),
+        None,
+    )
+    .unwrap();
+    assert_eq!((grid.columns, grid.rows), (3, 2));
+    assert_eq!(grid.layers["pollution"], vec![100, 50, 0, 0, 0, 0]);
+    assert_eq!(grid.layers["traffic"], vec![0, 0, 0, 0, 0, 7]);
+
+    let grid = read_grids(world, &[GridLayer::Pollution], Some(region), Some(2)).unwrap();
+    assert_eq!((grid.columns, grid.rows), (2, 1));
+    // (100 + 50 + 0 + 0) / 4, then the 1-wide edge block.
+    assert_eq!(grid.layers["pollution"], vec![38, 0]);
+
+    let whole = read_grids(world, &[GridLayer::LandValue], None, Some(64)).unwrap();
+    assert_eq!(whole.layers["land_value"].len(), whole.columns * whole.rows);
+
+    let outside = GridRegion { x: 250, ..region };
+    assert!(read_grids(world, &[GridLayer::Crime], Some(outside), None).is_err());
+    assert!(read_grids(world, &[GridLayer::Crime], None, Some(0)).is_err());
+}
+
+#[test]
+fn test_find_route_follows_roads() {
+    let mut city = TestCity::new()
+        .with_road(50, 50, 60, 50, RoadType::Local)
+        .with_road(60, 50, 60, 58, RoadType::Local);
+    let world = city.world_mut();
+
+    let path = find_route(world, [50, 51], [61, 58]).unwrap();
+    assert!(path.found, "{path:?}");
+    assert_eq!(path.start, Some([50, 50]));
+    assert_eq!(path.goal, Some([60, 58]));
+    assert_eq!(path.cells.first(), Some(&[50, 50]));
+    assert_eq!(path.cells.last(), Some(&[60, 58]));
+    assert!(path.travel_cost >= path.cells.len() as u32 - 1);
+
+    let nowhere = find_route(world, [50, 51], [200, 200]).unwrap();
+    assert!(!nowhere.found);
+    assert!(nowhere.reason.is_some());
+    assert!(find_route(world, [0, 0], [999, 0]).is_err());
+}
+
+#[test]
+fn test_district_stats_lists_every_district() {
+    let mut city = TestCity::new();
+    let stats = district_stats(city.world_mut());
+    let map = city.resource::<crate::districts::DistrictMap>();
+    assert_eq!(stats.len(), map.districts.len());
+    assert_eq!(stats[0].name, map.districts[0].name);
+    assert_eq!(stats[0].index, 0);
+}
+
+#[test]
+fn test_history_series() {
+    let mut city = TestCity::new();
+    let world = city.world_mut();
+    {
+        let mut history = world.resource_mut::<ChartHistory>();
+        for total in [100, 200, 300] {
+            history.population.push(PopulationSnapshot {
+                total,
+                ..Default::default()
+            });
+            history.budget.push(BudgetSnapshot {
+                residential_tax: 10.0,
+                trade_income: 5.0,
+                service_costs: 4.0,
+                ..Default::default()
+            });
+        }
+    }
+
+    let series = ["population".to_string(), "income".to_string()];
+    let history = read_history(world, &series, None, Some(2)).unwrap();
+    assert_eq!(history.series["population"], vec![200.0, 300.0]);
+    assert_eq!(history.series["income"], vec![15.0, 15.0]);
+
+    let all = read_history(world, &[], None, None).unwrap();
+    assert_eq!(all.series["expenses"], vec![4.0; 3]);
+    assert!(read_history(world, &["smog".to_string()], None, None).is_err());
+
+    let district = read_history(world, &[], Some(0), None).unwrap();
+    assert!(district.series.contains_key("land_value"));
+    assert!(read_history(world, &[], Some(999), None).is_err());
+}
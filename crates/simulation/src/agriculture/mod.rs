@@ -1,11 +1,20 @@
 mod helpers;
 mod systems;
 mod tests;
+mod tests_urban_farms;
 pub mod types;
+pub mod urban_farms;
 
 pub use helpers::{
     calculate_crop_yield, calculate_frost_risk, is_growing_season, rainfall_adequacy,
     temperature_suitability,
 };
-pub use systems::{update_agriculture, AgriculturePlugin};
+pub use systems::{
+    add_urban_farm_food, add_urban_farm_power, add_urban_farm_water, apply_urban_farm_cooling,
+    update_agriculture, update_urban_farms, AgriculturePlugin,
+};
 pub use types::{AgricultureState, FrostEvent};
+pub use urban_farms::{
+    can_place_urban_farm, is_dense_zone, UrbanFarm, UrbanFarmKind, UrbanFarms, FOOD_IMPORT_SHARE,
+    ROOFTOP_FARM_COST, VERTICAL_FARM_COST,
+};
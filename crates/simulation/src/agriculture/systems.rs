@@ -2,11 +2,15 @@ use bevy::prelude::*;
 
 use crate::buildings::Building;
 use crate::drought::DroughtState;
-use crate::grid::ZoneType;
+use crate::energy_demand::EnergyGrid;
+use crate::grid::{WorldGrid, ZoneType};
 use crate::natural_resources::{ResourceBalance, ResourceGrid, ResourceType};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::services::{ServiceBuilding, ServiceType};
+use crate::urban_heat_island::UhiGrid;
+use crate::water_demand::WaterSupply;
 use crate::weather::{Season, Weather};
-use crate::SlowTickTimer;
+use crate::{SlowTickTimer, TickCounter};
 
 use super::helpers::{
     calculate_crop_yield, calculate_frost_risk, is_growing_season, rainfall_adequacy,
@@ -16,6 +20,7 @@ use super::types::{
     AgricultureState, FrostEvent, BASE_SOIL_QUALITY, FROST_DAMAGE_FRACTION,
     IRRIGATION_FERTILIZER_BONUS, IRRIGATION_RADIUS,
 };
+use super::urban_farms::{can_place_urban_farm, UrbanFarm, UrbanFarms};
 
 // =============================================================================
 // System
@@ -187,6 +192,123 @@ pub fn update_agriculture(
     }
 }
 
+// =============================================================================
+// Urban farms
+// =============================================================================
+
+/// Power draw is added at the energy aggregation interval.
+const URBAN_FARM_POWER_INTERVAL: u64 = 4;
+
+/// Cooling is applied at the heat-island update interval.
+const URBAN_FARM_COOLING_INTERVAL: u64 = 30;
+
+/// System: Check every urban farm still has a dense building under it and
+/// tally what the running farms grow and draw.
+///
+/// Runs on the slow tick timer. A farm only runs when its cell has both
+/// power and water.
+pub fn update_urban_farms(
+    timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    balance: Res<ResourceBalance>,
+    mut farms: ResMut<UrbanFarms>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !timer.should_run() {
+        return;
+    }
+
+    let before = farms.farms.len();
+    let mut lost = None;
+    farms.farms.retain(|f| {
+        let keep = can_place_urban_farm(&grid, f.x as usize, f.y as usize);
+        if !keep {
+            lost = Some(*f);
+        }
+        keep
+    });
+    let lost_count = (before - farms.farms.len()) as u32;
+    farms.farms_lost += lost_count;
+    if let Some(farm) = lost {
+        notifications.send(NotificationEvent {
+            text: format!("{} lost with its building", farm.kind.name()),
+            priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
+            location: Some(WorldGrid::grid_to_world(farm.x as usize, farm.y as usize)),
+        });
+    }
+
+    let active: Vec<UrbanFarm> = farms
+        .farms
+        .iter()
+        .filter(|f| {
+            let cell = grid.get(f.x as usize, f.y as usize);
+            cell.has_power && cell.has_water
+        })
+        .copied()
+        .collect();
+    farms.tally(active.iter());
+    farms.update_self_sufficiency(balance.food_consumption);
+}
+
+/// System: Add the urban farms' harvest to the city's food production.
+///
+/// Food production is rebuilt every tick, so this runs every tick after the
+/// crop yield modifier has been applied; indoor farms are not affected by
+/// the weather.
+pub fn add_urban_farm_food(farms: Res<UrbanFarms>, mut balance: ResMut<ResourceBalance>) {
+    if farms.food_output > 0.0 {
+        balance.food_production += farms.food_output;
+    }
+}
+
+/// System: Add the farms' grow lights and pumps to the energy demand.
+pub fn add_urban_farm_power(
+    tick: Res<TickCounter>,
+    farms: Res<UrbanFarms>,
+    mut energy_grid: ResMut<EnergyGrid>,
+) {
+    if !tick.0.is_multiple_of(URBAN_FARM_POWER_INTERVAL) || farms.power_demand_mw <= 0.0 {
+        return;
+    }
+    energy_grid.total_demand_mwh += farms.power_demand_mw;
+    let supply = energy_grid.total_supply_mwh;
+    if supply > 0.0 {
+        energy_grid.reserve_margin = (supply - energy_grid.total_demand_mwh) / supply;
+    } else {
+        energy_grid.reserve_margin = -1.0;
+    }
+}
+
+/// System: Add the farms' irrigation to the water demand.
+pub fn add_urban_farm_water(
+    timer: Res<SlowTickTimer>,
+    farms: Res<UrbanFarms>,
+    mut water_supply: ResMut<WaterSupply>,
+) {
+    if !timer.should_run() || farms.water_demand_gpd <= 0.0 {
+        return;
+    }
+    water_supply.total_demand_gpd += farms.water_demand_gpd;
+    water_supply.supply_ratio = water_supply.total_supply_gpd / water_supply.total_demand_gpd;
+}
+
+/// System: Planted roofs and shaded farm floors cool their own cells.
+pub fn apply_urban_farm_cooling(
+    tick: Res<TickCounter>,
+    farms: Res<UrbanFarms>,
+    mut uhi: ResMut<UhiGrid>,
+) {
+    if !tick.0.is_multiple_of(URBAN_FARM_COOLING_INTERVAL) {
+        return;
+    }
+    for farm in &farms.farms {
+        let (x, y) = (farm.x as usize, farm.y as usize);
+        let current = uhi.get(x, y);
+        uhi.set(x, y, current - farm.kind.uhi_reduction());
+    }
+}
+
 // =============================================================================
 // Plugin
 // =============================================================================
@@ -196,12 +318,31 @@ pub struct AgriculturePlugin;
 impl Plugin for AgriculturePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AgricultureState>()
+            .init_resource::<UrbanFarms>()
             .add_event::<FrostEvent>()
             .add_systems(
                 FixedUpdate,
-                update_agriculture
-                    .after(crate::natural_resources::update_resource_production)
+                (
+                    update_agriculture.after(crate::natural_resources::update_resource_production),
+                    update_urban_farms.after(update_agriculture),
+                    add_urban_farm_food
+                        .after(update_urban_farms)
+                        .after(crate::outside_connections::update_outside_connections),
+                    add_urban_farm_power
+                        .after(crate::energy_demand::aggregate_energy_demand)
+                        .before(crate::demand_response::apply_demand_response)
+                        .before(crate::energy_dispatch::dispatch_energy),
+                    add_urban_farm_water
+                        .after(crate::water_demand::aggregate_water_supply)
+                        .before(crate::water_rights::apply_water_agreements),
+                    apply_urban_farm_cooling.after(crate::uhi_mitigation::apply_uhi_mitigation),
+                )
                     .in_set(crate::SimulationSet::Simulation),
             );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<UrbanFarms>();
     }
 }
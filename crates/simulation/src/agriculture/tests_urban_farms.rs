@@ -0,0 +1,81 @@
+//! Tests for rooftop and vertical urban farms.
+
+#[cfg(test)]
+mod tests {
+    use crate::agriculture::urban_farms::{
+        is_dense_zone, UrbanFarmKind, UrbanFarms, FOOD_IMPORT_SHARE,
+    };
+    use crate::grid::ZoneType;
+    use crate::Saveable;
+
+    #[test]
+    fn test_only_dense_zones_host_farms() {
+        assert!(is_dense_zone(ZoneType::ResidentialHigh));
+        assert!(is_dense_zone(ZoneType::CommercialHigh));
+        assert!(is_dense_zone(ZoneType::Office));
+        assert!(is_dense_zone(ZoneType::MixedUse));
+        assert!(!is_dense_zone(ZoneType::ResidentialLow));
+        assert!(!is_dense_zone(ZoneType::Industrial));
+        assert!(!is_dense_zone(ZoneType::None));
+    }
+
+    #[test]
+    fn test_vertical_farm_trades_power_for_yield() {
+        let rooftop = UrbanFarmKind::Rooftop;
+        let vertical = UrbanFarmKind::Vertical;
+        assert!(vertical.food_output() > rooftop.food_output());
+        assert!(vertical.power_mw() > rooftop.power_mw());
+        assert!(rooftop.uhi_reduction() > vertical.uhi_reduction());
+    }
+
+    #[test]
+    fn test_one_farm_per_cell() {
+        let mut farms = UrbanFarms::default();
+        assert!(farms.build(5, 5, UrbanFarmKind::Rooftop));
+        assert!(!farms.build(5, 5, UrbanFarmKind::Vertical));
+        assert_eq!(farms.farms.len(), 1);
+        assert_eq!(farms.total_spent, UrbanFarmKind::Rooftop.cost());
+        assert!(farms.uhi_reduction_at(5, 5) > 0.0);
+        assert_eq!(farms.uhi_reduction_at(6, 5), 0.0);
+    }
+
+    #[test]
+    fn test_tally_sums_active_farms() {
+        let mut farms = UrbanFarms::default();
+        farms.build(1, 1, UrbanFarmKind::Rooftop);
+        farms.build(2, 2, UrbanFarmKind::Vertical);
+        let active = farms.farms.clone();
+        farms.tally(active.iter());
+        assert_eq!(farms.active_count, 2);
+        let food = UrbanFarmKind::Rooftop.food_output() + UrbanFarmKind::Vertical.food_output();
+        assert!((farms.food_output - food).abs() < f32::EPSILON);
+
+        farms.tally(active[..1].iter());
+        assert_eq!(farms.active_count, 1);
+        assert!((farms.power_demand_mw - UrbanFarmKind::Rooftop.power_mw()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_local_food_cuts_import_bill() {
+        let mut farms = UrbanFarms::default();
+        assert_eq!(farms.import_cost_factor(), 1.0);
+
+        farms.food_output = 10.0;
+        farms.update_self_sufficiency(40.0);
+        assert!((farms.self_sufficiency - 0.25).abs() < f32::EPSILON);
+        assert!(farms.import_cost_factor() < 1.0);
+
+        farms.update_self_sufficiency(5.0);
+        assert_eq!(farms.self_sufficiency, 1.0);
+        assert!((farms.import_cost_factor() - (1.0 - FOOD_IMPORT_SHARE)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_urban_farms_saveable_roundtrip() {
+        assert!(UrbanFarms::default().save_to_bytes().is_none());
+        let mut farms = UrbanFarms::default();
+        farms.build(3, 4, UrbanFarmKind::Vertical);
+        let bytes = farms.save_to_bytes().expect("farms save");
+        assert_eq!(UrbanFarms::load_from_bytes(&bytes), farms);
+    }
+}
@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::grid::{WorldGrid, ZoneType};
+use crate::Saveable;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Cost of fitting a rooftop farm onto a building.
+pub const ROOFTOP_FARM_COST: f64 = 20_000.0;
+
+/// Cost of converting a building into a vertical farm.
+pub const VERTICAL_FARM_COST: f64 = 60_000.0;
+
+/// Share of the city's commercial imports that is food.
+pub const FOOD_IMPORT_SHARE: f64 = 0.3;
+
+// =============================================================================
+// Farm kinds
+// =============================================================================
+
+/// Food grown inside the city, on and in dense buildings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum UrbanFarmKind {
+    /// Beds and greenhouses on the roof: modest yield, cools the block.
+    #[default]
+    Rooftop,
+    /// Stacked hydroponic floors under grow lights: high yield, power hungry.
+    Vertical,
+}
+
+impl UrbanFarmKind {
+    pub const ALL: [UrbanFarmKind; 2] = [UrbanFarmKind::Rooftop, UrbanFarmKind::Vertical];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UrbanFarmKind::Rooftop => "Rooftop Farm",
+            UrbanFarmKind::Vertical => "Vertical Farm",
+        }
+    }
+
+    pub fn cost(self) -> f64 {
+        match self {
+            UrbanFarmKind::Rooftop => ROOFTOP_FARM_COST,
+            UrbanFarmKind::Vertical => VERTICAL_FARM_COST,
+        }
+    }
+
+    /// Food produced per tick, in the units of `ResourceBalance`.
+    pub fn food_output(self) -> f32 {
+        match self {
+            UrbanFarmKind::Rooftop => 3.0,
+            UrbanFarmKind::Vertical => 12.0,
+        }
+    }
+
+    /// Power drawn by lighting, pumps and climate control (MW).
+    pub fn power_mw(self) -> f32 {
+        match self {
+            UrbanFarmKind::Rooftop => 0.05,
+            UrbanFarmKind::Vertical => 0.6,
+        }
+    }
+
+    /// Irrigation and hydroponic water use (gallons per day).
+    pub fn water_gpd(self) -> f32 {
+        match self {
+            UrbanFarmKind::Rooftop => 4_000.0,
+            UrbanFarmKind::Vertical => 12_000.0,
+        }
+    }
+
+    /// Heat island reduction on the farm's cell (Fahrenheit).
+    pub fn uhi_reduction(self) -> f32 {
+        match self {
+            UrbanFarmKind::Rooftop => 2.5,
+            UrbanFarmKind::Vertical => 1.0,
+        }
+    }
+}
+
+/// Zones dense enough for urban farming.
+pub fn is_dense_zone(zone: ZoneType) -> bool {
+    matches!(
+        zone,
+        ZoneType::ResidentialHigh
+            | ZoneType::CommercialHigh
+            | ZoneType::Office
+            | ZoneType::MixedUse
+    )
+}
+
+/// Whether (`x`, `y`) holds a building in a dense zone that can host a farm.
+pub fn can_place_urban_farm(grid: &WorldGrid, x: usize, y: usize) -> bool {
+    if !grid.in_bounds(x, y) {
+        return false;
+    }
+    let cell = grid.get(x, y);
+    cell.building_id.is_some() && is_dense_zone(cell.zone)
+}
+
+// =============================================================================
+// Resource
+// =============================================================================
+
+/// A farm on or in the building at (`x`, `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct UrbanFarm {
+    pub x: u16,
+    pub y: u16,
+    pub kind: UrbanFarmKind,
+}
+
+impl UrbanFarm {
+    pub fn new(x: usize, y: usize, kind: UrbanFarmKind) -> Self {
+        Self {
+            x: x as u16,
+            y: y as u16,
+            kind,
+        }
+    }
+}
+
+/// The city's rooftop and vertical farms and what they currently produce
+/// and consume.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct UrbanFarms {
+    pub farms: Vec<UrbanFarm>,
+    /// Farms with both power and water this slow tick.
+    pub active_count: u32,
+    /// Food produced per tick by the active farms.
+    pub food_output: f32,
+    /// Power drawn by the active farms (MW).
+    pub power_demand_mw: f32,
+    /// Water drawn by the active farms (gallons per day).
+    pub water_demand_gpd: f32,
+    /// Share of the city's food consumption grown locally (0.0 to 1.0).
+    pub self_sufficiency: f32,
+    /// Farms lost because their building was demolished or rezoned.
+    pub farms_lost: u32,
+    /// Spent building farms.
+    pub total_spent: f64,
+}
+
+impl UrbanFarms {
+    pub fn farm_at(&self, x: usize, y: usize) -> Option<&UrbanFarm> {
+        self.farms
+            .iter()
+            .find(|f| f.x as usize == x && f.y as usize == y)
+    }
+
+    /// Record a new farm; returns false if the cell already has one.
+    pub fn build(&mut self, x: usize, y: usize, kind: UrbanFarmKind) -> bool {
+        if self.farm_at(x, y).is_some() {
+            return false;
+        }
+        self.farms.push(UrbanFarm::new(x, y, kind));
+        self.total_spent += kind.cost();
+        true
+    }
+
+    /// Recompute output and demand from the farms that are running.
+    pub fn tally<'a>(&mut self, active: impl Iterator<Item = &'a UrbanFarm>) {
+        self.active_count = 0;
+        self.food_output = 0.0;
+        self.power_demand_mw = 0.0;
+        self.water_demand_gpd = 0.0;
+        for farm in active {
+            self.active_count += 1;
+            self.food_output += farm.kind.food_output();
+            self.power_demand_mw += farm.kind.power_mw();
+            self.water_demand_gpd += farm.kind.water_gpd();
+        }
+    }
+
+    /// Update the locally grown share of `food_consumption`.
+    pub fn update_self_sufficiency(&mut self, food_consumption: f32) {
+        self.self_sufficiency = if food_consumption > 0.0 {
+            (self.food_output / food_consumption).min(1.0)
+        } else if self.food_output > 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    /// Multiplier on the city's import bill: locally grown food replaces
+    /// part of the food share of imports.
+    pub fn import_cost_factor(&self) -> f64 {
+        1.0 - FOOD_IMPORT_SHARE * self.self_sufficiency as f64
+    }
+
+    /// Heat island reduction on (`x`, `y`).
+    pub fn uhi_reduction_at(&self, x: usize, y: usize) -> f32 {
+        self.farm_at(x, y).map_or(0.0, |f| f.kind.uhi_reduction())
+    }
+}
+
+impl Saveable for UrbanFarms {
+    const SAVE_KEY: &'static str = "urban_farms";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
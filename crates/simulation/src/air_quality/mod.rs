@@ -0,0 +1,17 @@
+//! City-wide air quality and smog alerts.
+//!
+//! Every slow tick the pollution concentration at every citizen's home is
+//! averaged into a population-weighted city air quality on the pollution
+//! grid's 0-255 scale, classified with the same AQI tiers used for the
+//! per-cell health effects. When the city's air stays unhealthy for
+//! sensitive groups for several slow ticks a smog alert is issued; while it
+//! lasts every citizen's health suffers, and it is lifted once the air
+//! clears below a lower threshold.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{notify_smog_alerts, smog_health_effects, update_air_quality, AirQualityPlugin};
+pub use types::*;
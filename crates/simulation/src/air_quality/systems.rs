@@ -0,0 +1,108 @@
+//! City air quality measurement, smog alerts and their health toll.
+
+use bevy::prelude::*;
+
+use crate::citizen::{CitizenDetails, HomeLocation};
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::pollution::PollutionGrid;
+use crate::pollution_health::AqiTier;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Every slow tick, measure air quality where citizens live and issue or
+/// lift the smog alert.
+pub fn update_air_quality(
+    slow_timer: Res<SlowTickTimer>,
+    pollution: Res<PollutionGrid>,
+    citizens: Query<&HomeLocation>,
+    mut air: ResMut<AirQuality>,
+    mut alerts: EventWriter<SmogAlertEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let home_levels: Vec<u8> = citizens
+        .iter()
+        .map(|home| {
+            pollution.get(
+                home.grid_x.min(GRID_WIDTH - 1),
+                home.grid_y.min(GRID_HEIGHT - 1),
+            )
+        })
+        .collect();
+    air.peak_level = home_levels.iter().copied().max().unwrap_or(0);
+    air.exposed_residents = home_levels
+        .iter()
+        .filter(|&&level| level >= UNHEALTHY_LEVEL)
+        .count() as u32;
+    let level = population_weighted_level(home_levels.into_iter().map(|l| (l, 1)));
+    if let Some(event) = air.record(level) {
+        alerts.send(event);
+    }
+}
+
+/// Every slow tick of a smog alert, every citizen's health suffers.
+pub fn smog_health_effects(
+    slow_timer: Res<SlowTickTimer>,
+    air: Res<AirQuality>,
+    mut citizens: Query<&mut CitizenDetails>,
+) {
+    if !slow_timer.should_run() || !air.smog_alert {
+        return;
+    }
+    for mut details in &mut citizens {
+        details.health = (details.health - SMOG_HEALTH_PENALTY).clamp(0.0, 100.0);
+    }
+}
+
+/// Tell the player when a smog alert is issued or lifted.
+pub fn notify_smog_alerts(
+    mut alerts: EventReader<SmogAlertEvent>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for alert in alerts.read() {
+        let (text, priority) = match *alert {
+            SmogAlertEvent::Issued { level } => (
+                format!(
+                    "Smog alert: air quality where residents live is {} ({level})",
+                    AqiTier::from_concentration(level).label()
+                ),
+                NotificationPriority::Warning,
+            ),
+            SmogAlertEvent::Lifted { .. } => (
+                "Smog alert lifted: the air has cleared".to_string(),
+                NotificationPriority::Positive,
+            ),
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Citizens,
+            location: None,
+        });
+    }
+}
+
+pub struct AirQualityPlugin;
+
+impl Plugin for AirQualityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AirQuality>()
+            .add_event::<SmogAlertEvent>()
+            .add_systems(
+                FixedUpdate,
+                (update_air_quality, smog_health_effects, notify_smog_alerts)
+                    .chain()
+                    .after(crate::pollution_health::apply_pollution_health_effects)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<AirQuality>();
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+use crate::pollution_health::AqiTier;
+use crate::Saveable;
+
+#[test]
+fn test_population_weighted_level() {
+    assert_eq!(population_weighted_level(std::iter::empty()), 0);
+    assert_eq!(population_weighted_level([(100, 1)].into_iter()), 100);
+    // Nine residents in clean air outweigh one in smog.
+    assert_eq!(
+        population_weighted_level([(10, 9), (190, 1)].into_iter()),
+        28
+    );
+    assert_eq!(
+        population_weighted_level([(255, 0), (40, 5)].into_iter()),
+        40
+    );
+}
+
+#[test]
+fn test_alert_needs_sustained_bad_air() {
+    let mut air = AirQuality::default();
+    for _ in 1..SMOG_SUSTAINED_TICKS {
+        assert_eq!(air.record(SMOG_ALERT_LEVEL), None);
+    }
+    assert_eq!(
+        air.record(SMOG_ALERT_LEVEL),
+        Some(SmogAlertEvent::Issued {
+            level: SMOG_ALERT_LEVEL
+        })
+    );
+    assert!(air.smog_alert);
+    assert_eq!(air.smog_alerts_issued, 1);
+    assert_eq!(air.tier(), AqiTier::UnhealthyForSensitive);
+}
+
+#[test]
+fn test_clean_tick_resets_the_count() {
+    let mut air = AirQuality::default();
+    for _ in 1..SMOG_SUSTAINED_TICKS {
+        air.record(200);
+    }
+    assert_eq!(air.record(20), None);
+    assert_eq!(air.high_ticks, 0);
+    assert_eq!(air.record(200), None);
+    assert!(!air.smog_alert);
+}
+
+#[test]
+fn test_alert_lifts_below_clear_level() {
+    let mut air = AirQuality {
+        smog_alert: true,
+        ..Default::default()
+    };
+    assert_eq!(air.record(SMOG_CLEAR_LEVEL), None, "hysteresis holds");
+    assert!(air.smog_alert);
+    assert_eq!(
+        air.record(SMOG_CLEAR_LEVEL - 1),
+        Some(SmogAlertEvent::Lifted {
+            level: SMOG_CLEAR_LEVEL - 1
+        })
+    );
+    assert!(!air.smog_alert);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(AirQuality::default().save_to_bytes().is_none());
+    let air = AirQuality {
+        city_level: 120,
+        peak_level: 210,
+        exposed_residents: 4,
+        high_ticks: 1,
+        smog_alert: true,
+        smog_alerts_issued: 2,
+    };
+    let bytes = air.save_to_bytes().expect("non-default state saves");
+    assert_eq!(AirQuality::load_from_bytes(&bytes), air);
+}
@@ -0,0 +1,121 @@
+//! City-wide air quality, smog alerts and the saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::pollution_health::AqiTier;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// City air quality (pollution concentration where residents live) at which
+/// a smog alert is issued once it persists.
+pub const SMOG_ALERT_LEVEL: u8 = 101;
+
+/// City air quality below which a smog alert is lifted.
+pub const SMOG_CLEAR_LEVEL: u8 = 80;
+
+/// Consecutive slow ticks of bad air before a smog alert is issued.
+pub const SMOG_SUSTAINED_TICKS: u8 = 3;
+
+/// Extra health every citizen loses each slow tick of a smog alert, on top
+/// of the per-cell air pollution health effects.
+pub const SMOG_HEALTH_PENALTY: f32 = 0.05;
+
+/// Residents at least this polluted are counted as exposed to unhealthy air.
+pub const UNHEALTHY_LEVEL: u8 = 151;
+
+/// Air quality where residents live, weighted by how many live there.
+/// `samples` yields the pollution concentration at each home and its
+/// residents. Zero when nobody lives in the city.
+pub fn population_weighted_level(samples: impl Iterator<Item = (u8, u32)>) -> u8 {
+    let (mut weighted, mut residents) = (0u64, 0u64);
+    for (concentration, count) in samples {
+        weighted += concentration as u64 * count as u64;
+        residents += count as u64;
+    }
+    if residents == 0 {
+        return 0;
+    }
+    (weighted / residents) as u8
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// A city-wide smog alert was issued or lifted.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmogAlertEvent {
+    Issued { level: u8 },
+    Lifted { level: u8 },
+}
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// City-wide air quality metric and smog alert status.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct AirQuality {
+    /// Pollution concentration where residents live, weighted by residents,
+    /// on the same 0-255 scale as the pollution grid.
+    pub city_level: u8,
+    /// Worst concentration at any home.
+    pub peak_level: u8,
+    /// Residents living in unhealthy air.
+    pub exposed_residents: u32,
+    /// Consecutive slow ticks at or above the smog alert level.
+    pub high_ticks: u8,
+    pub smog_alert: bool,
+    pub smog_alerts_issued: u32,
+}
+
+impl AirQuality {
+    pub fn tier(&self) -> AqiTier {
+        AqiTier::from_concentration(self.city_level)
+    }
+
+    /// Record this slow tick's city air quality, issuing a smog alert once
+    /// bad air persists and lifting it once the air clears.
+    pub fn record(&mut self, level: u8) -> Option<SmogAlertEvent> {
+        self.city_level = level;
+        if self.smog_alert {
+            if level < SMOG_CLEAR_LEVEL {
+                self.smog_alert = false;
+                return Some(SmogAlertEvent::Lifted { level });
+            }
+            return None;
+        }
+        if level < SMOG_ALERT_LEVEL {
+            self.high_ticks = 0;
+            return None;
+        }
+        self.high_ticks = self.high_ticks.saturating_add(1);
+        if self.high_ticks < SMOG_SUSTAINED_TICKS {
+            return None;
+        }
+        self.high_ticks = 0;
+        self.smog_alert = true;
+        self.smog_alerts_issued += 1;
+        Some(SmogAlertEvent::Issued { level })
+    }
+}
+
+impl Saveable for AirQuality {
+    const SAVE_KEY: &'static str = "air_quality";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,314 @@
+//! Runways, gates and cargo terminals of each airport, and how they cap the
+//! flights it can handle.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::tier::AirportTier;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// First runway movement of the day (06:00), in minutes after midnight.
+pub const OPERATING_START_MINUTE: u16 = 6 * 60;
+
+/// Night curfew (24:00): no runway movements at or after this minute.
+pub const OPERATING_END_MINUTE: u16 = 24 * 60;
+
+/// Minutes between two movements (a landing or a takeoff) on one runway.
+pub const RUNWAY_SEPARATION_MINUTES: u16 = 2;
+
+/// Flights (a landing and the takeoff after it) one runway handles per day.
+pub const RUNWAY_FLIGHTS_PER_DAY: u32 =
+    ((OPERATING_END_MINUTE - OPERATING_START_MINUTE) / RUNWAY_SEPARATION_MINUTES / 2) as u32;
+
+/// Passenger turnarounds one gate handles per day.
+pub const GATE_TURNS_PER_DAY: u32 = 12;
+
+/// Freighter turnarounds one cargo terminal handles per day.
+pub const TERMINAL_FREIGHTERS_PER_DAY: u32 = 10;
+
+/// Aircraft stands at one cargo terminal.
+pub const CARGO_STANDS_PER_TERMINAL: u32 = 4;
+
+/// Monthly freighter flights per unit of city freight demand (industrial
+/// outbound plus commercial inbound).
+pub const FREIGHTER_FLIGHTS_PER_FREIGHT_DEMAND: f32 = 10.0;
+
+/// Truckloads of goods unloaded from one freighter.
+pub const TRUCKLOADS_PER_FREIGHTER: f32 = 4.0;
+
+/// Most truckloads of air freight left waiting at the cargo terminals.
+pub const MAX_AIR_CARGO_BACKLOG: f32 = 50.0;
+
+/// Days in an airport month.
+const DAYS_PER_MONTH: u32 = 30;
+
+// ---------------------------------------------------------------------------
+// Expansions
+// ---------------------------------------------------------------------------
+
+/// A facility an airport can be expanded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AirportExpansion {
+    Runway,
+    Gate,
+    CargoTerminal,
+}
+
+impl AirportExpansion {
+    pub const ALL: [AirportExpansion; 3] = [
+        AirportExpansion::Runway,
+        AirportExpansion::Gate,
+        AirportExpansion::CargoTerminal,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AirportExpansion::Runway => "Runway",
+            AirportExpansion::Gate => "Gate",
+            AirportExpansion::CargoTerminal => "Cargo Terminal",
+        }
+    }
+
+    /// One-off construction cost.
+    pub fn cost(self) -> f64 {
+        match self {
+            AirportExpansion::Runway => 5_000.0,
+            AirportExpansion::Gate => 800.0,
+            AirportExpansion::CargoTerminal => 3_000.0,
+        }
+    }
+
+    /// How many of this facility a new airport of `tier` opens with.
+    pub fn initial(self, tier: AirportTier) -> u32 {
+        match (self, tier) {
+            (AirportExpansion::Runway, AirportTier::InternationalAirport) => 2,
+            (AirportExpansion::Runway, _) => 1,
+            (AirportExpansion::Gate, AirportTier::SmallAirstrip) => 2,
+            (AirportExpansion::Gate, AirportTier::RegionalAirport) => 10,
+            (AirportExpansion::Gate, AirportTier::InternationalAirport) => 30,
+            (AirportExpansion::CargoTerminal, AirportTier::InternationalAirport) => 1,
+            (AirportExpansion::CargoTerminal, _) => 0,
+        }
+    }
+
+    /// Most of this facility an airport of `tier` can be expanded to.
+    pub fn max(self, tier: AirportTier) -> u32 {
+        match (self, tier) {
+            (AirportExpansion::Runway, AirportTier::SmallAirstrip) => 1,
+            (AirportExpansion::Runway, AirportTier::RegionalAirport) => 2,
+            (AirportExpansion::Runway, AirportTier::InternationalAirport) => 6,
+            (AirportExpansion::Gate, AirportTier::SmallAirstrip) => 4,
+            (AirportExpansion::Gate, AirportTier::RegionalAirport) => 20,
+            (AirportExpansion::Gate, AirportTier::InternationalAirport) => 150,
+            (AirportExpansion::CargoTerminal, AirportTier::SmallAirstrip) => 0,
+            (AirportExpansion::CargoTerminal, AirportTier::RegionalAirport) => 1,
+            (AirportExpansion::CargoTerminal, AirportTier::InternationalAirport) => 3,
+        }
+    }
+}
+
+/// Player request to build one more runway, gate or cargo terminal at the
+/// airport on `airport`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AirportExpansionRequest {
+    pub airport: (u16, u16),
+    pub kind: AirportExpansion,
+}
+
+// ---------------------------------------------------------------------------
+// Per-airport facilities
+// ---------------------------------------------------------------------------
+
+/// The runways, gates and cargo terminals of one airport and the flights
+/// allotted to it this month.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct AirportFacilities {
+    pub cell: (u16, u16),
+    pub tier: AirportTier,
+    pub runways: u32,
+    pub gates: u32,
+    pub cargo_terminals: u32,
+    /// Passenger flights per month handled here.
+    pub passenger_flights: u32,
+    /// Dedicated freighter flights per month handled at the cargo terminals.
+    pub freighter_flights: u32,
+}
+
+impl AirportFacilities {
+    /// A newly opened airport with its tier's starting facilities.
+    pub fn new(cell: (u16, u16), tier: AirportTier) -> Self {
+        Self {
+            cell,
+            tier,
+            runways: AirportExpansion::Runway.initial(tier),
+            gates: AirportExpansion::Gate.initial(tier),
+            cargo_terminals: AirportExpansion::CargoTerminal.initial(tier),
+            passenger_flights: 0,
+            freighter_flights: 0,
+        }
+    }
+
+    pub fn count(&self, kind: AirportExpansion) -> u32 {
+        match kind {
+            AirportExpansion::Runway => self.runways,
+            AirportExpansion::Gate => self.gates,
+            AirportExpansion::CargoTerminal => self.cargo_terminals,
+        }
+    }
+
+    pub fn can_expand(&self, kind: AirportExpansion) -> bool {
+        self.count(kind) < kind.max(self.tier)
+    }
+
+    fn expand(&mut self, kind: AirportExpansion) {
+        match kind {
+            AirportExpansion::Runway => self.runways += 1,
+            AirportExpansion::Gate => self.gates += 1,
+            AirportExpansion::CargoTerminal => self.cargo_terminals += 1,
+        }
+    }
+
+    /// Monthly flights the runways can take, counting a landing and a
+    /// takeoff per flight.
+    pub fn runway_capacity(&self) -> u32 {
+        self.runways * RUNWAY_FLIGHTS_PER_DAY * DAYS_PER_MONTH
+    }
+
+    /// Monthly passenger turnarounds the gates can take.
+    pub fn gate_capacity(&self) -> u32 {
+        self.gates * GATE_TURNS_PER_DAY * DAYS_PER_MONTH
+    }
+
+    /// Monthly passenger flights: the tier's terminal capacity, limited by
+    /// runways and gates.
+    pub fn passenger_capacity(&self) -> u32 {
+        self.tier
+            .capacity()
+            .min(self.runway_capacity())
+            .min(self.gate_capacity())
+    }
+
+    /// Monthly freighter flights: what the cargo terminals can turn around,
+    /// limited to the runway slots passenger flights leave free.
+    pub fn freighter_capacity(&self) -> u32 {
+        let terminals = self.cargo_terminals * TERMINAL_FREIGHTERS_PER_DAY * DAYS_PER_MONTH;
+        terminals.min(
+            self.runway_capacity()
+                .saturating_sub(self.passenger_capacity()),
+        )
+    }
+
+    /// Average daily landings and takeoffs per runway this month.
+    pub fn movements_per_runway_day(&self) -> f32 {
+        if self.runways == 0 {
+            return 0.0;
+        }
+        let flights = self.passenger_flights + self.freighter_flights;
+        (flights * 2) as f32 / DAYS_PER_MONTH as f32 / self.runways as f32
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Facilities of every airport in the city, kept in cell order.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct AirportOperations {
+    pub airports: Vec<AirportFacilities>,
+    /// Total spent on runway, gate and cargo terminal expansions.
+    pub expansion_spent: f64,
+}
+
+impl AirportOperations {
+    /// Match the tracked airports to the airport buildings that exist: new
+    /// airports open with their tier's facilities, demolished ones are
+    /// dropped, and an airport replaced by another tier starts over.
+    pub fn sync(&mut self, present: &[((u16, u16), AirportTier)]) {
+        self.airports.retain(|a| {
+            present
+                .iter()
+                .any(|&(cell, tier)| cell == a.cell && tier == a.tier)
+        });
+        for &(cell, tier) in present {
+            if self.get(cell).is_none() {
+                self.airports.push(AirportFacilities::new(cell, tier));
+            }
+        }
+        self.airports.sort_by_key(|a| a.cell);
+    }
+
+    pub fn get(&self, cell: (u16, u16)) -> Option<&AirportFacilities> {
+        self.airports.iter().find(|a| a.cell == cell)
+    }
+
+    /// Cost of expanding the airport on `cell` with `kind`, or `None` if
+    /// there is no airport there or it is already at its tier's maximum.
+    pub fn expansion_cost(&self, cell: (u16, u16), kind: AirportExpansion) -> Option<f64> {
+        self.get(cell)
+            .filter(|a| a.can_expand(kind))
+            .map(|_| kind.cost())
+    }
+
+    /// Build one more `kind` at the airport on `cell`, returning its cost.
+    pub fn expand(&mut self, cell: (u16, u16), kind: AirportExpansion) -> Option<f64> {
+        let cost = self.expansion_cost(cell, kind)?;
+        let airport = self.airports.iter_mut().find(|a| a.cell == cell)?;
+        airport.expand(kind);
+        self.expansion_spent += cost;
+        Some(cost)
+    }
+
+    pub fn passenger_capacity(&self) -> u32 {
+        self.airports.iter().map(|a| a.passenger_capacity()).sum()
+    }
+
+    pub fn freighter_capacity(&self) -> u32 {
+        self.airports.iter().map(|a| a.freighter_capacity()).sum()
+    }
+
+    /// Total runways, gates and cargo terminals across all airports.
+    pub fn totals(&self) -> (u32, u32, u32) {
+        self.airports.iter().fold((0, 0, 0), |(r, g, c), a| {
+            (r + a.runways, g + a.gates, c + a.cargo_terminals)
+        })
+    }
+
+    /// Share the city's passenger and freighter flights out between
+    /// airports, filling the highest tier first so international airports
+    /// carry the traffic before smaller fields.
+    pub fn allocate(&mut self, passenger_flights: u32, freighter_flights: u32) {
+        let mut order: Vec<usize> = (0..self.airports.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(self.airports[i].tier as u8));
+
+        let mut passengers = passenger_flights;
+        let mut freighters = freighter_flights;
+        for i in order {
+            let airport = &mut self.airports[i];
+            airport.passenger_flights = passengers.min(airport.passenger_capacity());
+            passengers -= airport.passenger_flights;
+            airport.freighter_flights = freighters.min(airport.freighter_capacity());
+            freighters -= airport.freighter_flights;
+        }
+    }
+}
+
+impl Saveable for AirportOperations {
+    const SAVE_KEY: &'static str = "airport_operations";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,287 @@
+//! The day's flight board: each airport's flights timetabled over the
+//! operating day, given a runway slot to land, a gate (or cargo stand) and a
+//! runway slot to take off again. Flights wait when runways or gates are
+//! busy, and are cancelled when they could not leave before the curfew.
+
+use bevy::prelude::*;
+
+use super::facilities::{
+    AirportFacilities, CARGO_STANDS_PER_TERMINAL, OPERATING_END_MINUTE, OPERATING_START_MINUTE,
+    RUNWAY_SEPARATION_MINUTES,
+};
+
+/// Minutes a passenger aircraft spends at the gate between landing and
+/// takeoff.
+pub const GATE_TURNAROUND_MINUTES: u16 = 50;
+
+/// Minutes a freighter spends at its cargo stand.
+pub const CARGO_TURNAROUND_MINUTES: u16 = 90;
+
+/// Boarding opens this many minutes before departure.
+pub const BOARDING_MINUTES: u16 = 30;
+
+/// Cities flown to and from, shown on the board.
+const CONNECTED_CITIES: [&str; 12] = [
+    "Port Adler",
+    "Riverton",
+    "New Halden",
+    "Clearwater",
+    "Ashford",
+    "Marisport",
+    "Kingsbridge",
+    "Valewood",
+    "Eastmere",
+    "Solano Bay",
+    "Northgate",
+    "Brightwater",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightKind {
+    Arrival,
+    Departure,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightStatus {
+    Scheduled,
+    Delayed,
+    Boarding,
+    Departed,
+    Landed,
+    Cancelled,
+}
+
+impl FlightStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            FlightStatus::Scheduled => "On time",
+            FlightStatus::Delayed => "Delayed",
+            FlightStatus::Boarding => "Boarding",
+            FlightStatus::Departed => "Departed",
+            FlightStatus::Landed => "Landed",
+            FlightStatus::Cancelled => "Cancelled",
+        }
+    }
+}
+
+/// One arrival or departure on the board.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledFlight {
+    pub number: u32,
+    pub airport: (u16, u16),
+    pub kind: FlightKind,
+    pub cargo: bool,
+    /// Where an arrival comes from or a departure flies to.
+    pub city: &'static str,
+    /// Timetabled minute after midnight.
+    pub scheduled: u16,
+    /// Minutes late after waiting for a runway slot or a free gate.
+    pub delay: u16,
+    /// Runway used, from 1.
+    pub runway: u8,
+    /// Gate (passenger flights) or cargo stand (freighters), from 1.
+    pub stand: u16,
+    pub cancelled: bool,
+}
+
+impl ScheduledFlight {
+    /// Expected minute of landing or takeoff, including the delay.
+    pub fn time(&self) -> u16 {
+        self.scheduled + self.delay
+    }
+
+    pub fn status(&self, minute: u16) -> FlightStatus {
+        if self.cancelled {
+            return FlightStatus::Cancelled;
+        }
+        let time = self.time();
+        match self.kind {
+            FlightKind::Arrival if minute >= time => FlightStatus::Landed,
+            FlightKind::Departure if minute >= time => FlightStatus::Departed,
+            FlightKind::Departure if minute + BOARDING_MINUTES >= time => FlightStatus::Boarding,
+            _ if self.delay > 0 => FlightStatus::Delayed,
+            _ => FlightStatus::Scheduled,
+        }
+    }
+}
+
+/// Today's arrivals and departures at every airport, in time order.
+/// Redrawn each game day from the airports' monthly flights.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FlightBoard {
+    /// Game day the board was drawn up for.
+    pub day: u32,
+    pub flights: Vec<ScheduledFlight>,
+}
+
+impl FlightBoard {
+    /// Draw up the board for `day`.
+    pub fn schedule(&mut self, day: u32, airports: &[AirportFacilities]) {
+        self.day = day;
+        self.flights.clear();
+        for airport in airports {
+            schedule_airport(&mut self.flights, day, airport);
+        }
+        self.flights
+            .sort_by_key(|f| (f.time(), f.airport, f.number, f.kind as u8));
+    }
+
+    /// Cancel every flight that has not landed or departed by `minute`.
+    pub fn cancel_from(&mut self, minute: u16) {
+        for flight in &mut self.flights {
+            if flight.time() > minute {
+                flight.cancelled = true;
+            }
+        }
+    }
+
+    pub fn delayed(&self) -> usize {
+        self.flights
+            .iter()
+            .filter(|f| !f.cancelled && f.delay > 0)
+            .count()
+    }
+
+    pub fn cancelled(&self) -> usize {
+        self.flights.iter().filter(|f| f.cancelled).count()
+    }
+}
+
+/// Flights flown on `day` out of `monthly`, spread so that any 30
+/// consecutive days add up to the month.
+pub fn flights_on_day(monthly: u32, day: u32) -> u32 {
+    let d = day % 30;
+    monthly * (d + 1) / 30 - monthly * d / 30
+}
+
+/// Runway movement slots for one day, one row per runway.
+struct RunwaySlots {
+    taken: Vec<Vec<bool>>,
+}
+
+impl RunwaySlots {
+    fn new(runways: u32) -> Self {
+        let slots = (OPERATING_END_MINUTE / RUNWAY_SEPARATION_MINUTES) as usize;
+        Self {
+            taken: vec![vec![false; slots]; runways as usize],
+        }
+    }
+
+    /// First free `(runway, slot)` at or after `earliest` on any runway.
+    fn find(&self, earliest: u16) -> Option<(usize, usize)> {
+        let first = earliest
+            .max(OPERATING_START_MINUTE)
+            .div_ceil(RUNWAY_SEPARATION_MINUTES) as usize;
+        let slots = self.taken.first()?.len();
+        (first..slots).find_map(|slot| {
+            self.taken
+                .iter()
+                .position(|runway| !runway[slot])
+                .map(|runway| (runway, slot))
+        })
+    }
+
+    fn take(&mut self, (runway, slot): (usize, usize)) -> (u8, u16) {
+        self.taken[runway][slot] = true;
+        (runway as u8 + 1, slot as u16 * RUNWAY_SEPARATION_MINUTES)
+    }
+}
+
+/// Runways and stand booked for one flight.
+struct Turnaround {
+    landing: (u8, u16),
+    stand: u16,
+    takeoff: (u8, u16),
+}
+
+/// Book a stand, a landing slot no earlier than `arrival` and a takeoff slot
+/// `turnaround` minutes later. `None` when the flight could not take off
+/// again before the curfew.
+fn book_turnaround(
+    runways: &mut RunwaySlots,
+    stands: &mut [u16],
+    arrival: u16,
+    turnaround: u16,
+) -> Option<Turnaround> {
+    let (stand, free_at) = stands
+        .iter()
+        .copied()
+        .enumerate()
+        .min_by_key(|&(i, free_at)| (free_at, i))?;
+    let landing_slot = runways.find(arrival.max(free_at))?;
+    let landing_minute = landing_slot.1 as u16 * RUNWAY_SEPARATION_MINUTES;
+    let takeoff_slot = runways.find(landing_minute + turnaround)?;
+
+    let landing = runways.take(landing_slot);
+    let takeoff = runways.take(takeoff_slot);
+    stands[stand] = takeoff.1;
+    Some(Turnaround {
+        landing,
+        stand: stand as u16 + 1,
+        takeoff,
+    })
+}
+
+/// Timetabled arrival minutes for `count` flights, evenly spread on runway
+/// slots so the last one can still turn around before the curfew.
+fn timetable(count: u32, turnaround: u16) -> impl Iterator<Item = u16> {
+    let window = (OPERATING_END_MINUTE - OPERATING_START_MINUTE - turnaround) as u32;
+    (0..count).map(move |i| {
+        let minute = OPERATING_START_MINUTE + (i * window / count) as u16;
+        minute - minute % RUNWAY_SEPARATION_MINUTES
+    })
+}
+
+fn schedule_airport(flights: &mut Vec<ScheduledFlight>, day: u32, airport: &AirportFacilities) {
+    let passenger = flights_on_day(airport.passenger_flights, day);
+    let freighter = flights_on_day(airport.freighter_flights, day);
+
+    let mut timetabled: Vec<(u16, bool)> = timetable(passenger, GATE_TURNAROUND_MINUTES)
+        .map(|t| (t, false))
+        .chain(timetable(freighter, CARGO_TURNAROUND_MINUTES).map(|t| (t, true)))
+        .collect();
+    timetabled.sort_unstable();
+
+    let mut runways = RunwaySlots::new(airport.runways);
+    let mut gates = vec![0u16; airport.gates as usize];
+    let mut cargo_stands =
+        vec![0u16; (airport.cargo_terminals * CARGO_STANDS_PER_TERMINAL) as usize];
+    let seed = airport.cell.0 as usize + airport.cell.1 as usize + day as usize;
+
+    for (i, (arrival, cargo)) in timetabled.into_iter().enumerate() {
+        let (turnaround, stands) = if cargo {
+            (CARGO_TURNAROUND_MINUTES, &mut cargo_stands)
+        } else {
+            (GATE_TURNAROUND_MINUTES, &mut gates)
+        };
+        let booked = book_turnaround(&mut runways, stands, arrival, turnaround);
+        let departure = arrival + turnaround;
+        let leg = |kind, scheduled| ScheduledFlight {
+            number: 100 + i as u32,
+            airport: airport.cell,
+            kind,
+            cargo,
+            city: CONNECTED_CITIES[(seed + i * 7) % CONNECTED_CITIES.len()],
+            scheduled,
+            delay: 0,
+            runway: 0,
+            stand: 0,
+            cancelled: true,
+        };
+        let mut inbound = leg(FlightKind::Arrival, arrival);
+        let mut outbound = leg(FlightKind::Departure, departure);
+        if let Some(booked) = booked {
+            inbound.runway = booked.landing.0;
+            inbound.delay = booked.landing.1 - arrival;
+            outbound.runway = booked.takeoff.0;
+            outbound.delay = booked.takeoff.1 - departure;
+            inbound.stand = booked.stand;
+            outbound.stand = booked.stand;
+            inbound.cancelled = false;
+            outbound.cancelled = false;
+        }
+        flights.push(inbound);
+        flights.push(outbound);
+    }
+}
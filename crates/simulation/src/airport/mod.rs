@@ -1,10 +1,19 @@
+mod facilities;
+mod flight_board;
+mod runway_noise;
 mod stats;
 mod systems;
 mod tier;
 
+#[cfg(test)]
+mod operations_tests;
 #[cfg(test)]
 mod tests;
 
+pub use facilities::{
+    AirportExpansion, AirportExpansionRequest, AirportFacilities, AirportOperations,
+};
+pub use flight_board::{FlightBoard, FlightKind, FlightStatus, ScheduledFlight};
 pub use stats::AirportStats;
 pub use systems::{update_airports, AirportPlugin};
 pub use tier::AirportTier;
@@ -0,0 +1,253 @@
+use super::facilities::*;
+use super::flight_board::*;
+use super::runway_noise::*;
+use super::tier::AirportTier;
+use crate::noise::NoisePollutionGrid;
+use crate::Saveable;
+
+fn airport(tier: AirportTier) -> AirportFacilities {
+    AirportFacilities::new((100, 100), tier)
+}
+
+#[test]
+fn test_new_airports_open_with_tier_facilities() {
+    let small = airport(AirportTier::SmallAirstrip);
+    assert_eq!(
+        (small.runways, small.gates, small.cargo_terminals),
+        (1, 2, 0)
+    );
+    let international = airport(AirportTier::InternationalAirport);
+    assert_eq!(
+        (
+            international.runways,
+            international.gates,
+            international.cargo_terminals
+        ),
+        (2, 30, 1)
+    );
+}
+
+#[test]
+fn test_passenger_capacity_is_limited_by_runways_and_gates() {
+    // A small airstrip's terminal is the limit, a regional airport's gates.
+    let small = airport(AirportTier::SmallAirstrip);
+    assert_eq!(
+        small.passenger_capacity(),
+        AirportTier::SmallAirstrip.capacity()
+    );
+    let regional = airport(AirportTier::RegionalAirport);
+    assert_eq!(regional.passenger_capacity(), regional.gate_capacity());
+    assert!(regional.gate_capacity() < AirportTier::RegionalAirport.capacity());
+
+    let mut one_runway = airport(AirportTier::InternationalAirport);
+    one_runway.runways = 1;
+    one_runway.gates = 150;
+    assert_eq!(
+        one_runway.passenger_capacity(),
+        one_runway.runway_capacity()
+    );
+}
+
+#[test]
+fn test_freighters_use_runway_slots_left_by_passengers() {
+    let mut international = airport(AirportTier::InternationalAirport);
+    let spare = international.runway_capacity() - international.passenger_capacity();
+    assert_eq!(
+        international.freighter_capacity(),
+        (TERMINAL_FREIGHTERS_PER_DAY * 30).min(spare)
+    );
+
+    international.runways = 1;
+    international.gates = 150;
+    assert_eq!(international.freighter_capacity(), 0, "runway is full");
+    assert_eq!(
+        airport(AirportTier::RegionalAirport).freighter_capacity(),
+        0
+    );
+}
+
+#[test]
+fn test_sync_tracks_airport_buildings() {
+    let mut operations = AirportOperations::default();
+    operations.sync(&[
+        ((50, 50), AirportTier::RegionalAirport),
+        ((10, 10), AirportTier::SmallAirstrip),
+    ]);
+    operations.expand((50, 50), AirportExpansion::Gate).unwrap();
+    let cells: Vec<_> = operations.airports.iter().map(|a| a.cell).collect();
+    assert_eq!(cells, vec![(10, 10), (50, 50)]);
+
+    // The expanded airport keeps its gates; the demolished one is dropped
+    // and a rebuilt tier starts over.
+    operations.sync(&[((50, 50), AirportTier::RegionalAirport)]);
+    assert_eq!(operations.airports.len(), 1);
+    assert_eq!(operations.airports[0].gates, 11);
+    operations.sync(&[((50, 50), AirportTier::InternationalAirport)]);
+    assert_eq!(operations.airports[0].gates, 30);
+}
+
+#[test]
+fn test_expansion_respects_tier_maximum() {
+    let mut operations = AirportOperations::default();
+    operations.sync(&[((5, 5), AirportTier::SmallAirstrip)]);
+    assert_eq!(
+        operations.expansion_cost((5, 5), AirportExpansion::Runway),
+        None
+    );
+    assert_eq!(
+        operations.expansion_cost((5, 5), AirportExpansion::CargoTerminal),
+        None
+    );
+    assert_eq!(
+        operations.expansion_cost((9, 9), AirportExpansion::Gate),
+        None
+    );
+
+    assert_eq!(
+        operations.expand((5, 5), AirportExpansion::Gate),
+        Some(AirportExpansion::Gate.cost())
+    );
+    operations.expand((5, 5), AirportExpansion::Gate);
+    assert_eq!(operations.expand((5, 5), AirportExpansion::Gate), None);
+    assert_eq!(operations.airports[0].gates, 4);
+    assert_eq!(
+        operations.expansion_spent,
+        AirportExpansion::Gate.cost() * 2.0
+    );
+}
+
+#[test]
+fn test_allocation_fills_largest_airports_first() {
+    let mut operations = AirportOperations::default();
+    operations.sync(&[
+        ((10, 10), AirportTier::SmallAirstrip),
+        ((90, 90), AirportTier::InternationalAirport),
+    ]);
+    let international = operations.airports[1].passenger_capacity();
+    operations.allocate(international + 100, 50);
+    assert_eq!(operations.airports[1].passenger_flights, international);
+    assert_eq!(operations.airports[0].passenger_flights, 100);
+    assert_eq!(operations.airports[1].freighter_flights, 50);
+    assert_eq!(operations.airports[0].freighter_flights, 0);
+}
+
+#[test]
+fn test_operations_saveable_roundtrip() {
+    assert!(AirportOperations::default().save_to_bytes().is_none());
+    let mut operations = AirportOperations::default();
+    operations.sync(&[((7, 3), AirportTier::RegionalAirport)]);
+    operations.expand((7, 3), AirportExpansion::CargoTerminal);
+    let bytes = operations
+        .save_to_bytes()
+        .expect("airports should be saved");
+    assert_eq!(AirportOperations::load_from_bytes(&bytes), operations);
+    assert_eq!(AirportOperations::SAVE_KEY, "airport_operations");
+}
+
+#[test]
+fn test_daily_flights_add_up_to_the_month() {
+    for monthly in [0, 1, 29, 500, 10_800] {
+        let total: u32 = (1..=30).map(|day| flights_on_day(monthly, day)).sum();
+        assert_eq!(total, monthly);
+    }
+}
+
+#[test]
+fn test_board_lists_an_arrival_and_departure_per_flight() {
+    let mut regional = airport(AirportTier::RegionalAirport);
+    regional.passenger_flights = 300;
+    let mut board = FlightBoard::default();
+    board.schedule(4, &[regional]);
+
+    assert_eq!(board.day, 4);
+    assert_eq!(board.flights.len(), 20);
+    assert_eq!(board.cancelled(), 0);
+    assert_eq!(board.delayed(), 0);
+    assert!(board.flights.windows(2).all(|w| w[0].time() <= w[1].time()));
+    for flight in &board.flights {
+        assert!(flight.time() >= OPERATING_START_MINUTE);
+        assert!(flight.time() < OPERATING_END_MINUTE);
+        assert!((1..=10).contains(&flight.stand));
+    }
+}
+
+#[test]
+fn test_too_few_gates_delay_and_cancel_flights() {
+    let mut small = airport(AirportTier::SmallAirstrip);
+    small.passenger_flights = 60 * 30;
+    let mut board = FlightBoard::default();
+    board.schedule(1, &[small.clone()]);
+    assert!(board.delayed() > 0);
+    assert!(board.cancelled() > 0);
+
+    small.gates = 60;
+    board.schedule(1, &[small]);
+    assert_eq!(board.cancelled(), 0);
+}
+
+#[test]
+fn test_freighters_use_cargo_stands() {
+    let mut international = airport(AirportTier::InternationalAirport);
+    international.freighter_flights = 300;
+    let mut board = FlightBoard::default();
+    board.schedule(1, &[international]);
+    assert_eq!(board.flights.len(), 20);
+    assert!(board.flights.iter().all(|f| f.cargo && !f.cancelled));
+    assert!(board
+        .flights
+        .iter()
+        .all(|f| f.stand as u32 <= CARGO_STANDS_PER_TERMINAL));
+}
+
+#[test]
+fn test_flight_status_follows_the_clock() {
+    let mut regional = airport(AirportTier::RegionalAirport);
+    regional.passenger_flights = 30;
+    let mut board = FlightBoard::default();
+    board.schedule(1, &[regional]);
+    let arrival = board.flights[0].clone();
+    let departure = board.flights[1].clone();
+    assert_eq!(arrival.kind, FlightKind::Arrival);
+    assert_eq!(departure.kind, FlightKind::Departure);
+
+    assert_eq!(arrival.status(0), FlightStatus::Scheduled);
+    assert_eq!(arrival.status(arrival.time()), FlightStatus::Landed);
+    let boarding = departure.time() - BOARDING_MINUTES;
+    assert_eq!(departure.status(boarding), FlightStatus::Boarding);
+    assert_eq!(departure.status(departure.time()), FlightStatus::Departed);
+
+    board.cancel_from(arrival.time());
+    assert!(!board.flights[0].cancelled, "already landed");
+    assert_eq!(board.flights[1].status(0), FlightStatus::Cancelled);
+}
+
+#[test]
+fn test_runway_source_level_grows_with_movements() {
+    let tier = AirportTier::RegionalAirport;
+    assert_eq!(runway_source_db(tier, 0.0), 0.0);
+    assert!(runway_source_db(tier, 400.0) > runway_source_db(tier, 100.0));
+    assert!((runway_source_db(tier, 1000.0) - runway_source_db(tier, 100.0) - 10.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_runway_noise_carries_along_the_approach_path() {
+    let source = 90.0;
+    let along = runway_db(source, 6, 100, 100, 100 + 6 + 20, 100);
+    let across = runway_db(source, 6, 100, 100, 100, 120);
+    assert!(along > across);
+    assert_eq!(runway_rows(100, 2), vec![98, 102]);
+}
+
+#[test]
+fn test_runway_noise_contours_only_with_flights() {
+    let mut noise = NoisePollutionGrid::default();
+    let mut international = airport(AirportTier::InternationalAirport);
+    assert_eq!(add_runway_noise(&mut noise, &international), [0, 0, 0]);
+    assert!(noise.levels.iter().all(|&l| l == 0));
+
+    international.passenger_flights = 3000;
+    let contours = add_runway_noise(&mut noise, &international);
+    assert!(contours[0] > contours[1] && contours[1] > contours[2] && contours[2] > 0);
+    assert!(noise.get(100, 100) > 0);
+    assert!(noise.get(100 + 40, 98) > noise.get(100, 98 - 40));
+}
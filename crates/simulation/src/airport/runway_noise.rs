@@ -0,0 +1,90 @@
+//! Noise contours around runways. Landings and takeoffs are heard along each
+//! runway and far out along its approach and departure paths, and a busier
+//! runway is louder. The noise is added to `NoisePollutionGrid`, where the
+//! noise effects lower nearby land value and health.
+
+use super::facilities::AirportFacilities;
+use super::tier::AirportTier;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::noise::{
+    airport_source_db, attenuated_db, db_to_grid_u8, max_radius, NoisePollutionGrid,
+};
+
+/// Daily movements per runway at which a runway is as loud as its tier's
+/// airport source level.
+const REFERENCE_MOVEMENTS_PER_DAY: f32 = 100.0;
+
+/// Cells between parallel runways.
+pub const RUNWAY_SPACING: i32 = 4;
+
+/// Noise reaches this many times further along the approach and departure
+/// paths than sideways from the runway.
+const APPROACH_REACH: f32 = 2.0;
+
+/// Contour bands reported in `AirportStats`, in dB.
+pub const CONTOUR_BANDS_DB: [f32; 3] = [55.0, 65.0, 75.0];
+
+/// Rows of an airport's parallel east-west runways, centred on `cy`.
+pub fn runway_rows(cy: i32, runways: u32) -> Vec<i32> {
+    let offset = (runways as i32 - 1) * RUNWAY_SPACING / 2;
+    (0..runways as i32)
+        .map(|k| cy + k * RUNWAY_SPACING - offset)
+        .collect()
+}
+
+/// Source level of one runway: the tier's airport level, 10 dB louder per
+/// tenfold increase in movements over the reference.
+pub fn runway_source_db(tier: AirportTier, movements_per_day: f32) -> f32 {
+    if movements_per_day <= 0.0 {
+        return 0.0;
+    }
+    let base = airport_source_db(tier.service_type());
+    (base + 10.0 * (movements_per_day / REFERENCE_MOVEMENTS_PER_DAY).log10()).max(0.0)
+}
+
+/// Noise in dB at `(x, y)` from a runway of `half_length` centred on
+/// `(cx, row)`.
+pub fn runway_db(source_db: f32, half_length: i32, cx: i32, row: i32, x: i32, y: i32) -> f32 {
+    let along = ((x - cx).abs() - half_length).max(0) as f32 / APPROACH_REACH;
+    let across = (y - row).abs() as f32;
+    attenuated_db(source_db, along.hypot(across))
+}
+
+/// Add the runway noise of `airport` to `noise`, returning how many cells lie
+/// inside each of the `CONTOUR_BANDS_DB` contours.
+pub fn add_runway_noise(noise: &mut NoisePollutionGrid, airport: &AirportFacilities) -> [u32; 3] {
+    let mut contours = [0u32; 3];
+    let source = runway_source_db(airport.tier, airport.movements_per_runway_day());
+    let rows = runway_rows(airport.cell.1 as i32, airport.runways);
+    let (Some(&first), Some(&last)) = (rows.first(), rows.last()) else {
+        return contours;
+    };
+    if source <= 0.0 {
+        return contours;
+    }
+
+    let radius = max_radius(source);
+    let half = airport.tier.runway_half_length();
+    let cx = airport.cell.0 as i32;
+    let reach_x = half + (radius as f32 * APPROACH_REACH) as i32;
+    for y in (first - radius).max(0)..=(last + radius).min(GRID_HEIGHT as i32 - 1) {
+        for x in (cx - reach_x).max(0)..=(cx + reach_x).min(GRID_WIDTH as i32 - 1) {
+            let db = rows
+                .iter()
+                .map(|&row| runway_db(source, half, cx, row, x, y))
+                .fold(0.0, f32::max);
+            if db <= 0.0 {
+                continue;
+            }
+            for (count, &band) in contours.iter_mut().zip(&CONTOUR_BANDS_DB) {
+                if db >= band {
+                    *count += 1;
+                }
+            }
+            let (x, y) = (x as usize, y as usize);
+            let level = noise.get(x, y).saturating_add(db_to_grid_u8(db)).min(100);
+            noise.set(x, y, level);
+        }
+    }
+    contours
+}
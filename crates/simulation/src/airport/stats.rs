@@ -18,4 +18,15 @@ pub struct AirportStats {
     pub revenue: f64,
     /// Total monthly operating costs.
     pub total_monthly_cost: f64,
+    /// Runways, gates and cargo terminals across all airports.
+    pub runways: u32,
+    pub gates: u32,
+    pub cargo_terminals: u32,
+    /// Passenger flights per month the airports can handle, limited by
+    /// runways and gates.
+    pub passenger_capacity: u32,
+    /// Dedicated freighter flights per month (included in cargo flights).
+    pub freighter_flights_per_month: u32,
+    /// Cells inside the 55, 65 and 75 dB runway noise contours.
+    pub noise_contour_cells: [u32; 3],
 }
@@ -1,28 +1,99 @@
 use bevy::prelude::*;
 
+use super::facilities::{
+    AirportExpansionRequest, AirportOperations, FREIGHTER_FLIGHTS_PER_FREIGHT_DEMAND,
+    MAX_AIR_CARGO_BACKLOG, TRUCKLOADS_PER_FREIGHTER,
+};
+use super::flight_board::FlightBoard;
+use super::runway_noise::add_runway_noise;
 use super::stats::AirportStats;
 use super::tier::AirportTier;
+use crate::economy::CityBudget;
 use crate::fog::FogState;
+use crate::freight_traffic::FreightTrafficState;
+use crate::noise::NoisePollutionGrid;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::outside_connections::OutsideConnections;
 use crate::services::ServiceBuilding;
 use crate::stats::CityStats;
+use crate::time_of_day::GameClock;
 use crate::tourism::Tourism;
 use crate::SlowTickTimer;
 
+/// Slow ticks in a 30-day airport month.
+const SLOW_TICKS_PER_MONTH: u32 = 30 * 24 * 60 / SlowTickTimer::INTERVAL;
+
+/// Track the runways, gates and cargo terminals of every airport building.
+pub fn sync_airport_facilities(
+    slow_timer: Res<SlowTickTimer>,
+    services: Query<&ServiceBuilding>,
+    mut operations: ResMut<AirportOperations>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let present: Vec<((u16, u16), AirportTier)> = services
+        .iter()
+        .filter_map(|s| {
+            AirportTier::from_service_type(s.service_type)
+                .map(|tier| ((s.grid_x as u16, s.grid_y as u16), tier))
+        })
+        .collect();
+    operations.sync(&present);
+}
+
+/// Build requested runways, gates and cargo terminals out of the treasury.
+pub fn handle_airport_expansions(
+    mut requests: EventReader<AirportExpansionRequest>,
+    mut budget: ResMut<CityBudget>,
+    mut operations: ResMut<AirportOperations>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for request in requests.read() {
+        let name = request.kind.name();
+        let Some(cost) = operations.expansion_cost(request.airport, request.kind) else {
+            continue;
+        };
+        if budget.treasury < cost {
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "A new airport {name} needs ${cost:.0}, more than the treasury holds"
+                ),
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::General,
+                location: None,
+            });
+            continue;
+        }
+        operations.expand(request.airport, request.kind);
+        budget.treasury -= cost;
+        notifications.send(NotificationEvent {
+            text: format!("New airport {name} opened for ${cost:.0}"),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+}
+
 /// Update airport statistics every slow tick (100 ticks).
 ///
-/// - Counts airport buildings from `ServiceBuilding` query
+/// - Counts airports from `AirportOperations`
 /// - Calculates tourism multiplier with diminishing returns per additional airport
+/// - Caps passenger flights by terminal, runway and gate capacity, and adds
+///   freighters for the city's freight demand up to the cargo terminals' capacity
 /// - Generates flight revenue based on population and airport tier
 /// - Updates the Tourism resource with the airport multiplier
+#[allow(clippy::too_many_arguments)]
 pub fn update_airports(
     slow_timer: Res<SlowTickTimer>,
     mut airport_stats: ResMut<AirportStats>,
-    services: Query<&ServiceBuilding>,
+    mut operations: ResMut<AirportOperations>,
     stats: Res<CityStats>,
     mut tourism: ResMut<Tourism>,
     outside: Res<OutsideConnections>,
     fog: Res<FogState>,
+    freight: Res<FreightTrafficState>,
 ) {
     if !slow_timer.should_run() {
         return;
@@ -32,12 +103,11 @@ pub fn update_airports(
     // 1. Count airports by tier
     // -------------------------------------------------------------------------
     let mut by_tier = [0u32; 3];
-    for service in &services {
-        match AirportTier::from_service_type(service.service_type) {
-            Some(AirportTier::SmallAirstrip) => by_tier[0] += 1,
-            Some(AirportTier::RegionalAirport) => by_tier[1] += 1,
-            Some(AirportTier::InternationalAirport) => by_tier[2] += 1,
-            None => {}
+    for airport in &operations.airports {
+        match airport.tier {
+            AirportTier::SmallAirstrip => by_tier[0] += 1,
+            AirportTier::RegionalAirport => by_tier[1] += 1,
+            AirportTier::InternationalAirport => by_tier[2] += 1,
         }
     }
 
@@ -65,40 +135,43 @@ pub fn update_airports(
     // -------------------------------------------------------------------------
     let pop = stats.population as f32;
 
-    let mut total_capacity: u32 = 0;
-    for (tier, count) in &tiers {
-        total_capacity += tier.capacity() * count;
-    }
+    let passenger_capacity = operations.passenger_capacity();
     let demand = (pop * 0.01) as u32;
     let passenger_flights = if fog.flights_suspended {
         0
     } else {
-        demand.min(total_capacity)
+        demand.min(passenger_capacity)
     };
 
     let has_airport_connection =
         outside.has_connection(crate::outside_connections::ConnectionType::Airport);
     let cargo_base = passenger_flights / 5;
-    let cargo_flights = if has_airport_connection {
+    let belly_cargo_flights = if has_airport_connection {
         (cargo_base as f32 * 1.5) as u32
     } else {
         cargo_base
     };
 
+    // Dedicated freighters carry the city's goods from the cargo terminals.
+    let freighter_demand = ((freight.industrial_demand + freight.commercial_demand)
+        * FREIGHTER_FLIGHTS_PER_FREIGHT_DEMAND) as u32;
+    let freighter_flights = if fog.flights_suspended {
+        0
+    } else {
+        freighter_demand.min(operations.freighter_capacity())
+    };
+    let cargo_flights = belly_cargo_flights + freighter_flights;
+
+    operations.allocate(passenger_flights, freighter_flights);
+
     // -------------------------------------------------------------------------
     // 4. Revenue calculation
     // -------------------------------------------------------------------------
-    let mut revenue = 0.0f64;
-    let mut remaining_passengers = passenger_flights;
-    for &(tier, count) in tiers.iter().rev() {
-        if count == 0 || remaining_passengers == 0 {
-            continue;
-        }
-        let tier_capacity = tier.capacity() * count;
-        let served = remaining_passengers.min(tier_capacity);
-        revenue += served as f64 * tier.revenue_per_flight();
-        remaining_passengers = remaining_passengers.saturating_sub(served);
-    }
+    let mut revenue: f64 = operations
+        .airports
+        .iter()
+        .map(|a| a.passenger_flights as f64 * a.tier.revenue_per_flight())
+        .sum();
     revenue += cargo_flights as f64 * 8.0;
 
     if has_airport_connection {
@@ -128,17 +201,119 @@ pub fn update_airports(
     airport_stats.tourism_multiplier = 1.0 + tourism_mult;
     airport_stats.revenue = revenue;
     airport_stats.total_monthly_cost = total_cost;
+    let (runways, gates, cargo_terminals) = operations.totals();
+    airport_stats.runways = runways;
+    airport_stats.gates = gates;
+    airport_stats.cargo_terminals = cargo_terminals;
+    airport_stats.passenger_capacity = passenger_capacity;
+    airport_stats.freighter_flights_per_month = freighter_flights;
+}
+
+/// Redraw the flight board each game day, and cancel the rest of the day's
+/// flights while fog grounds them.
+pub fn update_flight_board(
+    clock: Res<GameClock>,
+    fog: Res<FogState>,
+    operations: Res<AirportOperations>,
+    mut board: ResMut<FlightBoard>,
+) {
+    let has_flights = operations
+        .airports
+        .iter()
+        .any(|a| a.passenger_flights + a.freighter_flights > 0);
+    if board.day != clock.day || (board.flights.is_empty() && has_flights) {
+        board.schedule(clock.day, &operations.airports);
+    }
+    if fog.flights_suspended {
+        board.cancel_from((clock.hour * 60.0) as u16);
+    }
+}
+
+/// Add runway noise contours to the noise grid after it is recomputed, so
+/// the noise effects lower land value and health around busy runways.
+pub fn apply_runway_noise(
+    slow_timer: Res<SlowTickTimer>,
+    operations: Res<AirportOperations>,
+    mut noise: ResMut<NoisePollutionGrid>,
+    mut airport_stats: ResMut<AirportStats>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let mut contours = [0u32; 3];
+    for airport in &operations.airports {
+        let cells = add_runway_noise(&mut noise, airport);
+        for (total, cells) in contours.iter_mut().zip(cells) {
+            *total += cells;
+        }
+    }
+    airport_stats.noise_contour_cells = contours;
+}
+
+/// Hand the freight unloaded from freighters to the freight system, which
+/// trucks it from the cargo terminals to shops.
+pub fn feed_air_cargo(
+    slow_timer: Res<SlowTickTimer>,
+    operations: Res<AirportOperations>,
+    mut freight: ResMut<FreightTrafficState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    freight.air_cargo_terminals = operations
+        .airports
+        .iter()
+        .filter(|a| a.cargo_terminals > 0)
+        .map(|a| (a.cell.0 as usize, a.cell.1 as usize))
+        .collect();
+    if freight.air_cargo_terminals.is_empty() {
+        freight.air_cargo_backlog = 0.0;
+        return;
+    }
+    let freighters: u32 = operations
+        .airports
+        .iter()
+        .map(|a| a.freighter_flights)
+        .sum();
+    let truckloads = freighters as f32 * TRUCKLOADS_PER_FREIGHTER / SLOW_TICKS_PER_MONTH as f32;
+    freight.air_cargo_backlog = (freight.air_cargo_backlog + truckloads).min(MAX_AIR_CARGO_BACKLOG);
 }
 
 pub struct AirportPlugin;
 
 impl Plugin for AirportPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AirportStats>().add_systems(
-            FixedUpdate,
-            update_airports
-                .after(crate::tourism::update_tourism)
-                .in_set(crate::SimulationSet::Simulation),
-        );
+        app.init_resource::<AirportStats>()
+            .init_resource::<AirportOperations>()
+            .init_resource::<FlightBoard>()
+            .add_event::<AirportExpansionRequest>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    (
+                        sync_airport_facilities,
+                        handle_airport_expansions,
+                        update_airports,
+                    )
+                        .chain(),
+                    update_flight_board.after(update_airports),
+                    apply_runway_noise
+                        .after(update_airports)
+                        .after(crate::noise::update_noise_pollution)
+                        .before(crate::noise_effects::apply_noise_land_value_effects)
+                        .before(crate::noise_effects::apply_noise_health_effects)
+                        .before(crate::noise_effects::update_noise_effects_stats),
+                    feed_air_cargo
+                        .after(update_airports)
+                        .before(crate::freight_traffic::generate_freight_trips),
+                )
+                    .after(crate::tourism::update_tourism)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<AirportOperations>();
     }
 }
@@ -1,9 +1,10 @@
+use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::services::ServiceType;
 
 /// Tier classification for airport buildings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize)]
 pub enum AirportTier {
     SmallAirstrip,
     RegionalAirport,
@@ -38,6 +39,23 @@ impl AirportTier {
         }
     }
 
+    /// Half the length of each runway in grid cells.
+    pub fn runway_half_length(self) -> i32 {
+        match self {
+            AirportTier::SmallAirstrip => 3,
+            AirportTier::RegionalAirport => 6,
+            AirportTier::InternationalAirport => 9,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AirportTier::SmallAirstrip => "Small Airstrip",
+            AirportTier::RegionalAirport => "Regional Airport",
+            AirportTier::InternationalAirport => "International Airport",
+        }
+    }
+
     /// Monthly operating cost.
     pub fn monthly_cost(self) -> f64 {
         match self {
@@ -57,6 +75,15 @@ impl AirportTier {
         }
     }
 
+    /// The service building of this tier.
+    pub fn service_type(self) -> ServiceType {
+        match self {
+            AirportTier::SmallAirstrip => ServiceType::SmallAirstrip,
+            AirportTier::RegionalAirport => ServiceType::RegionalAirport,
+            AirportTier::InternationalAirport => ServiceType::InternationalAirport,
+        }
+    }
+
     /// Revenue per passenger flight.
     pub fn revenue_per_flight(self) -> f64 {
         match self {
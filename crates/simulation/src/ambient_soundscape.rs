@@ -169,7 +169,7 @@ fn update_ambient_soundscape(
 
     // -- Nature: inversely proportional to density, boosted by green space ----
     let total_cells = (crate::config::GRID_WIDTH * crate::config::GRID_HEIGHT) as f32;
-    let tree_count = trees.cells.iter().filter(|&&v| v).count() as f32;
+    let tree_count = trees.tree_count() as f32;
     let tree_fraction = tree_count / total_cells;
     let park_count = (parks.small_park_count + parks.large_park_count) as f32;
     // Park bonus: each park adds a bit (capped contribution)
@@ -1,15 +1,17 @@
 //! PLAY-007: Audio System Infrastructure.
 //!
 //! Provides the foundational audio settings resource (`AudioSettings`) with
-//! per-channel volume controls, mute toggle, and persistence via `Saveable`.
+//! per-channel volume controls and a mute toggle, persisted in the player
+//! profile (see [`crate::profile`]) rather than with each city.
 //! Also defines `SfxEvent` / `PlaySfxEvent` for triggering sound effects
 //! from any system. Actual audio playback is handled downstream (rendering
 //! or app crate); this module owns the data layer.
 
 use bevy::prelude::*;
 
+use serde::{Deserialize, Serialize};
+
 use crate::keybindings::KeyBindings;
-use crate::Saveable;
 
 // =============================================================================
 // Sound effect event types
@@ -78,7 +80,8 @@ impl PlaySfxEvent {
 /// All volume values are in the range `0.0` (silent) to `1.0` (full).
 /// The `muted` flag overrides all channels to zero without losing the
 /// stored volume levels, so un-muting restores previous settings.
-#[derive(Resource, Debug, Clone, bitcode::Encode, bitcode::Decode)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioSettings {
     /// Master volume multiplier applied to all channels.
     pub master_volume: f32,
@@ -155,22 +158,6 @@ impl AudioSettings {
     }
 }
 
-// =============================================================================
-// Saveable implementation
-// =============================================================================
-
-impl Saveable for AudioSettings {
-    const SAVE_KEY: &'static str = "audio_settings";
-
-    fn save_to_bytes(&self) -> Option<Vec<u8>> {
-        Some(bitcode::encode(self))
-    }
-
-    fn load_from_bytes(bytes: &[u8]) -> Self {
-        crate::decode_or_warn(Self::SAVE_KEY, bytes)
-    }
-}
-
 // =============================================================================
 // Mute toggle system
 // =============================================================================
@@ -209,12 +196,6 @@ impl Plugin for AudioSettingsPlugin {
                 Update,
                 mute_toggle_system.in_set(crate::SimulationUpdateSet::Input),
             );
-
-        // Register for save/load via the SaveableRegistry.
-        app.init_resource::<crate::SaveableRegistry>();
-        app.world_mut()
-            .resource_mut::<crate::SaveableRegistry>()
-            .register::<AudioSettings>();
     }
 }
 
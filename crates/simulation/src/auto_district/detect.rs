@@ -0,0 +1,289 @@
+//! Neighborhood detection: barrier-separated components split into regions
+//! by a multi-source flood fill that prefers to stay on one side of arterial
+//! roads and within one land use.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+
+/// Extra flood-fill cost for entering an avenue or boulevard cell, making
+/// major roads natural neighborhood edges.
+pub const ARTERIAL_COST: u32 = 24;
+
+/// Extra flood-fill cost for crossing between different land uses.
+pub const LAND_USE_CHANGE_COST: u32 = 3;
+
+/// Tuning for neighborhood detection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NeighborhoodSettings {
+    /// Approximate number of cells per neighborhood.
+    pub target_cells: usize,
+    /// Regions smaller than this are merged into a neighbor or dropped.
+    pub min_cells: usize,
+    /// Maximum number of neighborhoods produced.
+    pub max_districts: usize,
+}
+
+impl Default for NeighborhoodSettings {
+    fn default() -> Self {
+        Self {
+            target_cells: 700,
+            min_cells: 40,
+            max_districts: 8,
+        }
+    }
+}
+
+/// Coarse land use of a cell for neighborhood purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LandUse {
+    /// Water or highway: neighborhoods never cross these.
+    Barrier,
+    /// Undeveloped land: not part of any neighborhood.
+    Empty,
+    Road,
+    Residential,
+    Commercial,
+    Industrial,
+    Office,
+    /// Unzoned cells with a building (services, parks, utilities).
+    Civic,
+}
+
+impl LandUse {
+    pub fn of(grid: &WorldGrid, x: usize, y: usize) -> Self {
+        let cell = grid.get(x, y);
+        match cell.cell_type {
+            CellType::Water => return Self::Barrier,
+            CellType::Road if cell.road_type == RoadType::Highway => return Self::Barrier,
+            CellType::Road => return Self::Road,
+            CellType::Grass => {}
+        }
+        match cell.zone {
+            ZoneType::None if cell.building_id.is_some() => Self::Civic,
+            ZoneType::None => Self::Empty,
+            ZoneType::Industrial => Self::Industrial,
+            ZoneType::Office => Self::Office,
+            ZoneType::MixedUse => Self::Commercial,
+            z if z.is_residential() => Self::Residential,
+            z if z.is_commercial() => Self::Commercial,
+            _ => Self::Civic,
+        }
+    }
+
+    fn is_member(self) -> bool {
+        !matches!(self, Self::Barrier | Self::Empty)
+    }
+}
+
+/// Label every member cell with a region id. Returns per-cell labels
+/// (`None` for non-member cells) and the number of regions.
+pub fn label_regions(
+    grid: &WorldGrid,
+    settings: &NeighborhoodSettings,
+) -> (Vec<Option<u32>>, usize) {
+    let (w, h) = (grid.width, grid.height);
+    let uses: Vec<LandUse> = (0..w * h)
+        .map(|i| LandUse::of(grid, i % w, i / w))
+        .collect();
+    let mut labels: Vec<Option<u32>> = vec![None; w * h];
+    let mut next_label: u32 = 0;
+
+    // 1. Connected components of member cells; barriers separate them.
+    let mut component = vec![u32::MAX; w * h];
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    for start in 0..w * h {
+        if !uses[start].is_member() || component[start] != u32::MAX {
+            continue;
+        }
+        let id = components.len() as u32;
+        let mut cells = vec![start];
+        component[start] = id;
+        let mut i = 0;
+        while i < cells.len() {
+            let idx = cells[i];
+            i += 1;
+            let (n, count) = grid.neighbors4(idx % w, idx / w);
+            for &(nx, ny) in &n[..count] {
+                let ni = ny * w + nx;
+                if uses[ni].is_member() && component[ni] == u32::MAX {
+                    component[ni] = id;
+                    cells.push(ni);
+                }
+            }
+        }
+        components.push(cells);
+    }
+
+    // 2. Split each component into roughly target-sized regions.
+    for cells in &components {
+        if cells.len() < settings.min_cells {
+            continue;
+        }
+        let k = (cells.len() as f32 / settings.target_cells.max(1) as f32).round() as usize;
+        let seeds = pick_seeds(cells, k.max(1), w);
+        let base = next_label;
+        next_label += seeds.len() as u32;
+        grow_regions(grid, &uses, &mut labels, &seeds, base);
+    }
+
+    // 3. Merge small regions and enforce the district cap.
+    let count = merge_regions(grid, &mut labels, next_label as usize, settings);
+    (labels, count)
+}
+
+/// Farthest-point sampling, starting from the cell farthest from the
+/// centroid so seeds spread towards the edges of the component.
+fn pick_seeds(cells: &[usize], k: usize, w: usize) -> Vec<usize> {
+    let pos = |i: usize| ((i % w) as i64, (i / w) as i64);
+    let n = cells.len() as i64;
+    let (sx, sy) = cells.iter().fold((0i64, 0i64), |(ax, ay), &c| {
+        let (x, y) = pos(c);
+        (ax + x, ay + y)
+    });
+    let (cx, cy) = (sx / n, sy / n);
+    let dist = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).pow(2) + (a.1 - b.1).pow(2);
+
+    let first = *cells
+        .iter()
+        .max_by_key(|&&c| dist(pos(c), (cx, cy)))
+        .expect("component is non-empty");
+    let mut seeds = vec![first];
+    let mut nearest: Vec<i64> = cells.iter().map(|&c| dist(pos(c), pos(first))).collect();
+    while seeds.len() < k {
+        let Some((best, &d)) = nearest.iter().enumerate().max_by_key(|(_, &d)| d) else {
+            break;
+        };
+        if d == 0 {
+            break;
+        }
+        let seed = cells[best];
+        seeds.push(seed);
+        for (j, &c) in cells.iter().enumerate() {
+            nearest[j] = nearest[j].min(dist(pos(c), pos(seed)));
+        }
+    }
+    seeds
+}
+
+/// Multi-source Dijkstra flood fill from `seeds`, labelling cells
+/// `base..base + seeds.len()`.
+fn grow_regions(
+    grid: &WorldGrid,
+    uses: &[LandUse],
+    labels: &mut [Option<u32>],
+    seeds: &[usize],
+    base: u32,
+) {
+    let w = grid.width;
+    let mut cost = vec![u32::MAX; labels.len()];
+    let mut heap = BinaryHeap::new();
+    for (i, &s) in seeds.iter().enumerate() {
+        cost[s] = 0;
+        labels[s] = Some(base + i as u32);
+        heap.push(Reverse((0u32, s)));
+    }
+    while let Some(Reverse((c, idx))) = heap.pop() {
+        if c > cost[idx] {
+            continue;
+        }
+        let from = uses[idx];
+        let label = labels[idx];
+        let (n, count) = grid.neighbors4(idx % w, idx / w);
+        for &(nx, ny) in &n[..count] {
+            let ni = ny * w + nx;
+            let to = uses[ni];
+            if !to.is_member() {
+                continue;
+            }
+            let mut step = 1;
+            if to == LandUse::Road {
+                let rt = grid.get(nx, ny).road_type;
+                if matches!(rt, RoadType::Avenue | RoadType::Boulevard) {
+                    step += ARTERIAL_COST;
+                }
+            } else if from != LandUse::Road && from != to {
+                step += LAND_USE_CHANGE_COST;
+            }
+            let nc = c + step;
+            if nc < cost[ni] {
+                cost[ni] = nc;
+                labels[ni] = label;
+                heap.push(Reverse((nc, ni)));
+            }
+        }
+    }
+}
+
+/// Merge regions below `min_cells` (and then the smallest regions while over
+/// `max_districts`) into the neighbor sharing the longest border; isolated
+/// regions are dropped instead. Relabels regions to `0..count` by size.
+fn merge_regions(
+    grid: &WorldGrid,
+    labels: &mut [Option<u32>],
+    region_count: usize,
+    settings: &NeighborhoodSettings,
+) -> usize {
+    let w = grid.width;
+    let mut alive = vec![true; region_count];
+    loop {
+        let mut sizes = vec![0usize; region_count];
+        for l in labels.iter().flatten() {
+            sizes[*l as usize] += 1;
+        }
+        let live: Vec<usize> = (0..region_count)
+            .filter(|&r| alive[r] && sizes[r] > 0)
+            .collect();
+        let Some(&smallest) = live.iter().min_by_key(|&&r| sizes[r]) else {
+            break;
+        };
+        if sizes[smallest] >= settings.min_cells && live.len() <= settings.max_districts {
+            break;
+        }
+
+        // Longest shared border with another region.
+        let mut borders: HashMap<u32, usize> = HashMap::new();
+        for (idx, l) in labels.iter().enumerate() {
+            if *l != Some(smallest as u32) {
+                continue;
+            }
+            let (n, count) = grid.neighbors4(idx % w, idx / w);
+            for &(nx, ny) in &n[..count] {
+                if let Some(other) = labels[ny * w + nx] {
+                    if other != smallest as u32 {
+                        *borders.entry(other).or_default() += 1;
+                    }
+                }
+            }
+        }
+        let target = borders
+            .into_iter()
+            .max_by_key(|&(r, len)| (len, Reverse(r)))
+            .map(|(r, _)| r);
+        for l in labels.iter_mut() {
+            if *l == Some(smallest as u32) {
+                *l = target;
+            }
+        }
+        alive[smallest] = false;
+    }
+
+    // Relabel by descending size.
+    let mut sizes = vec![0usize; region_count];
+    for l in labels.iter().flatten() {
+        sizes[*l as usize] += 1;
+    }
+    let mut order: Vec<usize> = (0..region_count).filter(|&r| sizes[r] > 0).collect();
+    order.sort_by_key(|&r| (Reverse(sizes[r]), r));
+    let mut remap = vec![u32::MAX; region_count];
+    for (new, &old) in order.iter().enumerate() {
+        remap[old] = new as u32;
+    }
+    for l in labels.iter_mut() {
+        if let Some(old) = *l {
+            *l = Some(remap[old as usize]);
+        }
+    }
+    order.len()
+}
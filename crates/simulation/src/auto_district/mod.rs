@@ -0,0 +1,125 @@
+//! Automatic district generation by neighborhood detection.
+//!
+//! Clusters contiguous developed cells into neighborhoods and writes them to
+//! the player's `DistrictMap`, where they can be renamed and repainted like
+//! any hand-made district:
+//!
+//! 1. Water and highways are hard barriers; connected components of
+//!    developed cells (zoned land, roads, buildings) are found between them.
+//! 2. Each component is split into roughly `target_cells`-sized regions by
+//!    a multi-source flood fill that pays extra to cross avenues and
+//!    boulevards or to change land use, so edges follow the road network
+//!    and the zoning mix.
+//! 3. Slivers are merged into the neighbor sharing the longest border, and
+//!    the smallest regions are merged until at most `max_districts` remain.
+//! 4. Each neighborhood is named from its character (residential, market,
+//!    industrial, waterfront, ...) and its compass position in the city.
+//!
+//! Triggered by sending `AutoDistrictRequest`.
+
+pub mod detect;
+pub mod naming;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashSet;
+
+use crate::districts::{District, DistrictMap, DEFAULT_DISTRICTS};
+use crate::grid::WorldGrid;
+
+pub use detect::{label_regions, LandUse, NeighborhoodSettings};
+pub use naming::NeighborhoodCharacter;
+pub use systems::{handle_auto_district_requests, AutoDistrictPlugin, AutoDistrictRequest};
+
+/// A detected neighborhood ready to become a district.
+#[derive(Debug, Clone)]
+pub struct Neighborhood {
+    pub name: String,
+    pub color: [f32; 4],
+    pub character: NeighborhoodCharacter,
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// Detect neighborhoods in the current city, largest first.
+pub fn detect_neighborhoods(
+    grid: &WorldGrid,
+    settings: &NeighborhoodSettings,
+) -> Vec<Neighborhood> {
+    let (labels, count) = label_regions(grid, settings);
+    let mut regions: Vec<Vec<(usize, usize)>> = vec![Vec::new(); count];
+    for (idx, label) in labels.iter().enumerate() {
+        if let Some(l) = label {
+            regions[*l as usize].push((idx % grid.width, idx / grid.width));
+        }
+    }
+
+    // City center and extent for compass naming.
+    let total: usize = regions.iter().map(Vec::len).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+    let centroid = |cells: &[(usize, usize)]| {
+        let (sx, sy) = cells.iter().fold((0.0f32, 0.0f32), |(ax, ay), &(x, y)| {
+            (ax + x as f32, ay + y as f32)
+        });
+        (sx / cells.len() as f32, sy / cells.len() as f32)
+    };
+    let all: Vec<(usize, usize)> = regions.iter().flatten().copied().collect();
+    let center = centroid(&all);
+    let radius = all
+        .iter()
+        .map(|&(x, y)| ((x as f32 - center.0).powi(2) + (y as f32 - center.1).powi(2)).sqrt())
+        .fold(1.0f32, f32::max);
+
+    let mut taken = HashSet::new();
+    let mut shade_counts = [0u32; 6];
+    regions
+        .into_iter()
+        .map(|cells| {
+            let character = naming::characterize(grid, &cells);
+            let compass = naming::compass_label(centroid(&cells), center, radius);
+            let name = naming::unique_name(format!("{compass} {}", character.label()), &mut taken);
+            // Darken repeated characters slightly so neighbors stay distinguishable.
+            let shade = &mut shade_counts[character as usize];
+            let factor = 1.0 - 0.15 * (*shade % 3) as f32;
+            *shade += 1;
+            let base = character.color();
+            let color = [
+                base[0] * factor,
+                base[1] * factor,
+                base[2] * factor,
+                base[3],
+            ];
+            Neighborhood {
+                name,
+                color,
+                character,
+                cells,
+            }
+        })
+        .collect()
+}
+
+/// Replace all districts in `map` with `neighborhoods`. Unused default
+/// districts are appended (empty) so every district paint tool still has a
+/// target for manual edits.
+pub fn apply_neighborhoods(map: &mut DistrictMap, neighborhoods: &[Neighborhood]) {
+    map.districts.clear();
+    map.cell_map.iter_mut().for_each(|c| *c = None);
+    for n in neighborhoods {
+        map.districts.push(District::new(n.name.clone(), n.color));
+        let di = map.districts.len() - 1;
+        for &(x, y) in &n.cells {
+            map.assign_cell_to_district(x, y, di);
+        }
+    }
+    for &(name, color) in DEFAULT_DISTRICTS {
+        if map.districts.len() >= DEFAULT_DISTRICTS.len() {
+            break;
+        }
+        if map.districts.iter().all(|d| d.name != name) {
+            map.districts.push(District::new(name.to_string(), color));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Characterising detected regions and giving them readable names.
+
+use std::collections::HashSet;
+
+use crate::grid::{CellType, WorldGrid};
+
+use super::detect::LandUse;
+
+/// Share of cells next to water that makes a region a waterfront.
+const WATERFRONT_SHARE: f32 = 0.12;
+
+/// Share of a single land use needed to define a region's character.
+const DOMINANT_SHARE: f32 = 0.45;
+
+/// Fraction of the city's radius treated as "central".
+const CENTRAL_RADIUS: f32 = 0.25;
+
+/// Overall character of a neighborhood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeighborhoodCharacter {
+    Residential,
+    Commercial,
+    Industrial,
+    Business,
+    Waterfront,
+    Mixed,
+}
+
+impl NeighborhoodCharacter {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Residential => "Residential",
+            Self::Commercial => "Market",
+            Self::Industrial => "Industrial",
+            Self::Business => "Business Park",
+            Self::Waterfront => "Waterfront",
+            Self::Mixed => "Quarter",
+        }
+    }
+
+    /// Base color, matching the default district palette.
+    pub fn color(self) -> [f32; 4] {
+        match self {
+            Self::Residential => [0.2, 0.8, 0.3, 0.5],
+            Self::Commercial => [0.2, 0.5, 1.0, 0.5],
+            Self::Industrial => [0.9, 0.7, 0.1, 0.5],
+            Self::Business => [0.3, 0.9, 0.6, 0.5],
+            Self::Waterfront => [0.1, 0.8, 0.9, 0.5],
+            Self::Mixed => [0.9, 0.4, 0.7, 0.5],
+        }
+    }
+}
+
+/// Classify a region from its land-use mix and proximity to water.
+pub fn characterize(grid: &WorldGrid, cells: &[(usize, usize)]) -> NeighborhoodCharacter {
+    let mut counts = [0usize; 5];
+    let mut near_water = 0usize;
+    for &(x, y) in cells {
+        let slot = match LandUse::of(grid, x, y) {
+            LandUse::Residential => 0,
+            LandUse::Commercial => 1,
+            LandUse::Industrial => 2,
+            LandUse::Office => 3,
+            LandUse::Civic => 4,
+            _ => continue,
+        };
+        counts[slot] += 1;
+        let (n, count) = grid.neighbors4(x, y);
+        if n[..count]
+            .iter()
+            .any(|&(nx, ny)| grid.get(nx, ny).cell_type == CellType::Water)
+        {
+            near_water += 1;
+        }
+    }
+    let developed: usize = counts.iter().sum();
+    if developed == 0 {
+        return NeighborhoodCharacter::Mixed;
+    }
+    if near_water as f32 / developed as f32 >= WATERFRONT_SHARE {
+        return NeighborhoodCharacter::Waterfront;
+    }
+    let (slot, &max) = counts
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, c)| *c)
+        .expect("counts is non-empty");
+    if (max as f32 / developed as f32) < DOMINANT_SHARE {
+        return NeighborhoodCharacter::Mixed;
+    }
+    match slot {
+        0 => NeighborhoodCharacter::Residential,
+        1 => NeighborhoodCharacter::Commercial,
+        2 => NeighborhoodCharacter::Industrial,
+        3 => NeighborhoodCharacter::Business,
+        _ => NeighborhoodCharacter::Mixed,
+    }
+}
+
+/// Compass position of `point` relative to `center`, or "Central" when it
+/// lies within `CENTRAL_RADIUS` of `radius`.
+pub fn compass_label(point: (f32, f32), center: (f32, f32), radius: f32) -> &'static str {
+    let dx = point.0 - center.0;
+    // Grid y grows southward.
+    let dy = center.1 - point.1;
+    if (dx * dx + dy * dy).sqrt() <= radius * CENTRAL_RADIUS {
+        return "Central";
+    }
+    let angle = dy.atan2(dx).to_degrees();
+    match angle {
+        a if (-22.5..22.5).contains(&a) => "East",
+        a if (22.5..67.5).contains(&a) => "Northeast",
+        a if (67.5..112.5).contains(&a) => "North",
+        a if (112.5..157.5).contains(&a) => "Northwest",
+        a if (-67.5..-22.5).contains(&a) => "Southeast",
+        a if (-112.5..-67.5).contains(&a) => "South",
+        a if (-157.5..-112.5).contains(&a) => "Southwest",
+        _ => "West",
+    }
+}
+
+/// Make `name` unique among `taken` by appending a number.
+pub fn unique_name(name: String, taken: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut n = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{name} {n}");
+        n += 1;
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
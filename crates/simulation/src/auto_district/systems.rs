@@ -0,0 +1,43 @@
+//! Event handling and plugin registration for auto-districting.
+
+use bevy::prelude::*;
+
+use crate::districts::DistrictMap;
+use crate::grid::WorldGrid;
+
+use super::{apply_neighborhoods, detect_neighborhoods, NeighborhoodSettings};
+
+/// Request to replace the player's districts with auto-detected
+/// neighborhoods.
+#[derive(Event, Debug, Clone, Default)]
+pub struct AutoDistrictRequest {
+    pub settings: NeighborhoodSettings,
+}
+
+/// System that processes `AutoDistrictRequest` events. Runs in `Update` so
+/// the tool also works while the simulation is paused.
+pub fn handle_auto_district_requests(
+    mut events: EventReader<AutoDistrictRequest>,
+    grid: Res<WorldGrid>,
+    mut district_map: ResMut<DistrictMap>,
+) {
+    // Only the latest request matters; each one replaces all districts.
+    let Some(request) = events.read().last() else {
+        return;
+    };
+    let neighborhoods = detect_neighborhoods(&grid, &request.settings);
+    apply_neighborhoods(&mut district_map, &neighborhoods);
+    info!(
+        "Auto-districting created {} neighborhoods",
+        neighborhoods.len()
+    );
+}
+
+pub struct AutoDistrictPlugin;
+
+impl Plugin for AutoDistrictPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AutoDistrictRequest>()
+            .add_systems(Update, handle_auto_district_requests);
+    }
+}
@@ -0,0 +1,179 @@
+use super::naming::{compass_label, unique_name};
+use super::*;
+use crate::districts::{DistrictMap, DEFAULT_DISTRICTS};
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+
+fn zone_rect(grid: &mut WorldGrid, x0: usize, y0: usize, x1: usize, y1: usize, zone: ZoneType) {
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            grid.get_mut(x, y).zone = zone;
+        }
+    }
+}
+
+fn water_column(grid: &mut WorldGrid, x: usize) {
+    for y in 0..grid.height {
+        grid.get_mut(x, y).cell_type = CellType::Water;
+    }
+}
+
+fn small_settings() -> NeighborhoodSettings {
+    NeighborhoodSettings {
+        target_cells: 400,
+        min_cells: 20,
+        max_districts: 8,
+    }
+}
+
+#[test]
+fn test_empty_grid_has_no_neighborhoods() {
+    let grid = WorldGrid::new(64, 64);
+    assert!(detect_neighborhoods(&grid, &small_settings()).is_empty());
+}
+
+#[test]
+fn test_river_separates_neighborhoods() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 10, 10, 29, 29, ZoneType::ResidentialLow);
+    water_column(&mut grid, 30);
+    zone_rect(&mut grid, 31, 10, 50, 29, ZoneType::ResidentialLow);
+
+    let hoods = detect_neighborhoods(&grid, &small_settings());
+    assert_eq!(hoods.len(), 2);
+    for h in &hoods {
+        let left = h.cells.iter().all(|&(x, _)| x < 30);
+        let right = h.cells.iter().all(|&(x, _)| x > 30);
+        assert!(left || right, "a neighborhood crossed the river");
+    }
+}
+
+#[test]
+fn test_highway_is_a_barrier() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 10, 10, 40, 25, ZoneType::Industrial);
+    for x in 10..=40 {
+        let cell = grid.get_mut(x, 18);
+        cell.cell_type = CellType::Road;
+        cell.road_type = RoadType::Highway;
+        cell.zone = ZoneType::None;
+    }
+    let hoods = detect_neighborhoods(&grid, &small_settings());
+    assert_eq!(hoods.len(), 2);
+    for h in &hoods {
+        let north = h.cells.iter().all(|&(_, y)| y < 18);
+        let south = h.cells.iter().all(|&(_, y)| y > 18);
+        assert!(north || south, "a neighborhood crossed the highway");
+    }
+}
+
+#[test]
+fn test_slivers_are_dropped() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 10, 10, 29, 29, ZoneType::CommercialLow);
+    // A 3x3 isolated patch is below `min_cells`.
+    zone_rect(&mut grid, 50, 50, 52, 52, ZoneType::ResidentialLow);
+    let hoods = detect_neighborhoods(&grid, &small_settings());
+    assert_eq!(hoods.len(), 1);
+    assert!(hoods[0].cells.iter().all(|&(x, _)| x < 30));
+}
+
+#[test]
+fn test_large_area_is_split_and_capped() {
+    let mut grid = WorldGrid::new(128, 128);
+    zone_rect(&mut grid, 0, 0, 127, 127, ZoneType::ResidentialLow);
+    let settings = NeighborhoodSettings {
+        target_cells: 1000,
+        min_cells: 50,
+        max_districts: 6,
+    };
+    let hoods = detect_neighborhoods(&grid, &settings);
+    assert_eq!(hoods.len(), 6);
+    let covered: usize = hoods.iter().map(|h| h.cells.len()).sum();
+    assert_eq!(covered, 128 * 128, "merging should keep every cell");
+    // Largest first.
+    assert!(hoods
+        .windows(2)
+        .all(|w| w[0].cells.len() >= w[1].cells.len()));
+}
+
+#[test]
+fn test_arterial_roads_shape_boundaries() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 0, 10, 63, 29, ZoneType::ResidentialLow);
+    for y in 10..=29 {
+        let cell = grid.get_mut(32, y);
+        cell.cell_type = CellType::Road;
+        cell.road_type = RoadType::Boulevard;
+        cell.zone = ZoneType::None;
+    }
+    let settings = NeighborhoodSettings {
+        target_cells: 640,
+        min_cells: 20,
+        max_districts: 8,
+    };
+    let hoods = detect_neighborhoods(&grid, &settings);
+    assert_eq!(hoods.len(), 2);
+    for h in &hoods {
+        let crossing = h.cells.iter().any(|&(x, _)| x < 32) && h.cells.iter().any(|&(x, _)| x > 32);
+        assert!(!crossing, "neighborhood should stop at the boulevard");
+    }
+}
+
+#[test]
+fn test_character_and_names() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 5, 5, 24, 24, ZoneType::Industrial);
+    water_column(&mut grid, 30);
+    zone_rect(&mut grid, 40, 40, 59, 59, ZoneType::ResidentialMedium);
+    let hoods = detect_neighborhoods(&grid, &small_settings());
+    let chars: Vec<_> = hoods.iter().map(|h| h.character).collect();
+    assert!(chars.contains(&NeighborhoodCharacter::Industrial));
+    assert!(chars.contains(&NeighborhoodCharacter::Residential));
+    let industrial = hoods
+        .iter()
+        .find(|h| h.character == NeighborhoodCharacter::Industrial)
+        .unwrap();
+    assert_eq!(industrial.name, "Northwest Industrial");
+}
+
+#[test]
+fn test_compass_labels() {
+    let center = (50.0, 50.0);
+    assert_eq!(compass_label((52.0, 51.0), center, 40.0), "Central");
+    assert_eq!(compass_label((50.0, 10.0), center, 40.0), "North");
+    assert_eq!(compass_label((90.0, 50.0), center, 40.0), "East");
+    assert_eq!(compass_label((10.0, 90.0), center, 40.0), "Southwest");
+}
+
+#[test]
+fn test_unique_names() {
+    let mut taken = std::collections::HashSet::new();
+    assert_eq!(
+        unique_name("North Market".into(), &mut taken),
+        "North Market"
+    );
+    assert_eq!(
+        unique_name("North Market".into(), &mut taken),
+        "North Market 2"
+    );
+}
+
+#[test]
+fn test_apply_replaces_districts_and_pads_defaults() {
+    let mut grid = WorldGrid::new(64, 64);
+    zone_rect(&mut grid, 10, 10, 29, 29, ZoneType::ResidentialLow);
+    let hoods = detect_neighborhoods(&grid, &small_settings());
+
+    let mut map = DistrictMap::default();
+    map.assign_cell_to_district(60, 60, 0);
+    apply_neighborhoods(&mut map, &hoods);
+
+    assert_eq!(map.districts.len(), DEFAULT_DISTRICTS.len());
+    assert_eq!(map.districts[0].name, hoods[0].name);
+    assert_eq!(map.districts[0].cells.len(), hoods[0].cells.len());
+    assert_eq!(map.get_district_index_at(15, 15), Some(0));
+    assert!(
+        map.get_district_at(60, 60).is_none(),
+        "old assignments cleared"
+    );
+}
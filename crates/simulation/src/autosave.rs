@@ -11,7 +11,7 @@
 
 use bevy::prelude::*;
 
-use crate::notifications::{NotificationEvent, NotificationPriority};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::Saveable;
 use crate::SlowTickTimer;
 
@@ -221,6 +221,7 @@ pub fn autosave_notification_system(
         notifications.send(NotificationEvent {
             text: format!("Autosaving to slot {}...", config.current_slot + 1),
             priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
             location: None,
         });
     }
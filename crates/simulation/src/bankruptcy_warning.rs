@@ -16,7 +16,7 @@ use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::economy::CityBudget;
-use crate::notifications::{NotificationEvent, NotificationPriority};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::SlowTickTimer;
 
 // ---------------------------------------------------------------------------
@@ -122,6 +122,7 @@ pub fn check_bankruptcy(
                     budget.treasury
                 ),
                 priority: NotificationPriority::Attention,
+                category: NotificationCategory::Finance,
                 location: None,
             });
         }
@@ -132,6 +133,7 @@ pub fn check_bankruptcy(
                     budget.treasury
                 ),
                 priority: NotificationPriority::Warning,
+                category: NotificationCategory::Finance,
                 location: None,
             });
         }
@@ -139,6 +141,7 @@ pub fn check_bankruptcy(
             notifications.send(NotificationEvent {
                 text: "The city is bankrupt! Services will deteriorate without funds.".to_string(),
                 priority: NotificationPriority::Emergency,
+                category: NotificationCategory::Finance,
                 location: None,
             });
         }
@@ -148,6 +151,7 @@ pub fn check_bankruptcy(
                 notifications.send(NotificationEvent {
                     text: "Treasury has recovered to healthy levels.".to_string(),
                     priority: NotificationPriority::Positive,
+                    category: NotificationCategory::Finance,
                     location: None,
                 });
             }
@@ -19,6 +19,7 @@ use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::energy_demand::{EnergyConsumer, LoadPriority};
 use crate::energy_dispatch::EnergyDispatchState;
 use crate::grid::WorldGrid;
+use crate::packed_grid::BitGrid;
 use crate::services::{ServiceBuilding, ServiceType};
 use crate::time_of_day::GameClock;
 use crate::{decode_or_warn, Saveable, SimulationSet, TickCounter};
@@ -85,14 +86,13 @@ pub struct BlackoutState {
     pub shed_by_tier: [u32; 4],
     /// Number of hospital casualties from power loss this session.
     pub hospital_casualties: u32,
-    /// Per-cell blackout flag grid (true = blacked out).
+    /// Per-cell blackout flag grid (set = blacked out).
     /// Recomputed each tick from dispatch state; not persisted.
-    pub blackout_grid: Vec<bool>,
+    pub blackout_grid: BitGrid,
 }
 
 impl Default for BlackoutState {
     fn default() -> Self {
-        let grid_size = GRID_WIDTH * GRID_HEIGHT;
         Self {
             active: false,
             affected_cell_count: 0,
@@ -102,7 +102,7 @@ impl Default for BlackoutState {
             load_shed_fraction: 0.0,
             shed_by_tier: [0; 4],
             hospital_casualties: 0,
-            blackout_grid: vec![false; grid_size],
+            blackout_grid: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
         }
     }
 }
@@ -126,7 +126,6 @@ impl Saveable for BlackoutState {
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
         let data: BlackoutSaveData = decode_or_warn(Self::SAVE_KEY, bytes);
-        let grid_size = GRID_WIDTH * GRID_HEIGHT;
         Self {
             active: data.active,
             affected_cell_count: data.affected_cell_count,
@@ -136,7 +135,7 @@ impl Saveable for BlackoutState {
             load_shed_fraction: data.load_shed_fraction,
             shed_by_tier: data.shed_by_tier,
             hospital_casualties: data.hospital_casualties,
-            blackout_grid: vec![false; grid_size],
+            blackout_grid: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
         }
     }
 }
@@ -184,9 +183,7 @@ pub fn evaluate_blackout(
     }
 
     // Clear the per-cell blackout grid each evaluation.
-    for v in blackout.blackout_grid.iter_mut() {
-        *v = false;
-    }
+    blackout.blackout_grid.fill(false);
     blackout.shed_by_tier = [0; 4];
     blackout.affected_cell_count = 0;
 
@@ -275,14 +272,14 @@ pub fn evaluate_blackout(
             for i in 0..count {
                 let rotated_idx = (i + rotation) % tier_cells.len();
                 let grid_idx = tier_cells[rotated_idx];
-                blackout.blackout_grid[grid_idx] = true;
+                blackout.blackout_grid.set_index(grid_idx, true);
             }
             blackout.shed_by_tier[tier] = count as u32;
             remaining_to_shed -= count;
         } else {
             let count = tier_cells.len().min(remaining_to_shed);
             for &grid_idx in tier_cells.iter().take(count) {
-                blackout.blackout_grid[grid_idx] = true;
+                blackout.blackout_grid.set_index(grid_idx, true);
             }
             blackout.shed_by_tier[tier] = count as u32;
             remaining_to_shed -= count;
@@ -292,10 +289,8 @@ pub fn evaluate_blackout(
     // Apply blackout to the world grid: set has_power = false for affected cells.
     let total_affected = cells_to_shed - remaining_to_shed;
     blackout.affected_cell_count = total_affected as u32;
-    for (idx, &blacked_out) in blackout.blackout_grid.iter().enumerate() {
-        if blacked_out {
-            grid.cells[idx].has_power = false;
-        }
+    for idx in blackout.blackout_grid.iter_ones() {
+        grid.cells[idx].has_power = false;
     }
 
     // Advance rolling blackout rotation.
@@ -451,7 +446,7 @@ mod tests {
             load_shed_fraction: 0.35,
             shed_by_tier: [10, 20, 12, 0],
             hospital_casualties: 3,
-            blackout_grid: vec![],
+            blackout_grid: BitGrid::new(0, 0),
         };
 
         let bytes = state.save_to_bytes().unwrap();
@@ -1,8 +1,8 @@
 //! Core `Blueprint` struct with capture and placement logic.
 //!
 //! A `Blueprint` stores a position-independent snapshot of a rectangular map
-//! region (road segments + zone cells). It can later be stamped onto the map
-//! at a different location.
+//! region (road segments, zone cells and service buildings). It can later be
+//! stamped onto the map at a different location.
 
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
@@ -11,10 +11,11 @@ use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
 use crate::road_segments::RoadSegmentStore;
 use crate::roads::RoadNetwork;
+use crate::services::ServiceBuilding;
 
-use super::types::{BlueprintSegment, BlueprintZoneCell, PlaceResult};
+use super::types::{BlueprintSegment, BlueprintService, BlueprintZoneCell, PlaceResult};
 
-/// A single blueprint capturing a road+zone layout relative to an origin.
+/// A single blueprint capturing a road+zone+service layout relative to an origin.
 #[derive(Debug, Clone, Encode, Decode)]
 pub struct Blueprint {
     /// Human-readable name for the blueprint.
@@ -27,6 +28,8 @@ pub struct Blueprint {
     pub segments: Vec<BlueprintSegment>,
     /// Zone cells with offsets relative to the origin.
     pub zone_cells: Vec<BlueprintZoneCell>,
+    /// Service buildings with footprint offsets relative to the origin.
+    pub services: Vec<BlueprintService>,
 }
 
 impl Blueprint {
@@ -89,10 +92,39 @@ impl Blueprint {
             height: h as u32,
             segments,
             zone_cells,
+            services: Vec::new(),
         }
     }
 
-    /// Place this blueprint onto the map at the given grid origin.
+    /// Add the service buildings whose whole footprint lies inside the
+    /// captured region. `origin_x, origin_y` must match the `capture` call.
+    pub fn with_services<'a>(
+        mut self,
+        services: impl IntoIterator<Item = &'a ServiceBuilding>,
+        origin_x: usize,
+        origin_y: usize,
+    ) -> Self {
+        let (w, h) = (self.width as usize, self.height as usize);
+        for service in services {
+            let (fw, fh) = ServiceBuilding::footprint(service.service_type);
+            let inside = service.grid_x >= origin_x
+                && service.grid_y >= origin_y
+                && service.grid_x + fw <= origin_x + w
+                && service.grid_y + fh <= origin_y + h;
+            if inside {
+                self.services.push(BlueprintService {
+                    dx: (service.grid_x - origin_x) as i32,
+                    dy: (service.grid_y - origin_y) as i32,
+                    service_type: service.service_type,
+                });
+            }
+        }
+        self
+    }
+
+    /// Place this blueprint's roads and zones onto the map at the given grid
+    /// origin, skipping anything that does not fit. Services need `Commands`
+    /// to spawn; full stamps go through `plan` and `BlueprintPlan::apply`.
     ///
     /// Returns the number of road segments placed and zone cells set.
     pub fn place(
@@ -165,6 +197,7 @@ impl Blueprint {
         PlaceResult {
             segments_placed,
             zones_placed,
+            services_placed: 0,
         }
     }
 }
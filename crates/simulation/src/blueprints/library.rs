@@ -9,6 +9,7 @@ use bitcode::{Decode, Encode};
 use crate::Saveable;
 
 use super::blueprint::Blueprint;
+use super::types::{BlueprintSegment, BlueprintZoneCell};
 
 // =============================================================================
 // Serializable save wrapper
@@ -20,6 +21,21 @@ struct BlueprintLibrarySave {
     blueprints: Vec<Blueprint>,
 }
 
+/// Blueprint as saved before blueprints carried service buildings.
+#[derive(Decode)]
+struct LegacyBlueprint {
+    name: String,
+    width: u32,
+    height: u32,
+    segments: Vec<BlueprintSegment>,
+    zone_cells: Vec<BlueprintZoneCell>,
+}
+
+#[derive(Decode)]
+struct LegacyBlueprintLibrarySave {
+    blueprints: Vec<LegacyBlueprint>,
+}
+
 // =============================================================================
 // Resource
 // =============================================================================
@@ -81,6 +97,27 @@ impl Saveable for BlueprintLibrary {
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
+        if let Ok(save) = bitcode::decode::<BlueprintLibrarySave>(bytes) {
+            return BlueprintLibrary {
+                blueprints: save.blueprints,
+            };
+        }
+        // Libraries saved before blueprints carried services.
+        if let Ok(legacy) = bitcode::decode::<LegacyBlueprintLibrarySave>(bytes) {
+            let blueprints = legacy
+                .blueprints
+                .into_iter()
+                .map(|bp| Blueprint {
+                    name: bp.name,
+                    width: bp.width,
+                    height: bp.height,
+                    segments: bp.segments,
+                    zone_cells: bp.zone_cells,
+                    services: Vec::new(),
+                })
+                .collect();
+            return BlueprintLibrary { blueprints };
+        }
         let save: BlueprintLibrarySave = crate::decode_or_warn(Self::SAVE_KEY, bytes);
         BlueprintLibrary {
             blueprints: save.blueprints,
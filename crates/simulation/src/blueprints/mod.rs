@@ -1,22 +1,29 @@
 //! Blueprint / Template System (UX-041).
 //!
-//! Provides a `BlueprintLibrary` resource that stores reusable road+zone layouts.
-//! Players can capture a rectangular area of the map as a blueprint, then stamp
-//! copies of that blueprint at different locations.
+//! Provides a `BlueprintLibrary` resource that stores reusable road, zone and
+//! service layouts. Players can capture a rectangular area of the map as a
+//! blueprint, then stamp copies of that blueprint at different locations,
+//! rotated in quarter turns.
 //!
-//! Blueprints store road segments and zone cells relative to an origin, making
-//! them position-independent and reusable.
+//! Blueprints store road segments, zone cells and service buildings relative
+//! to an origin, making them position-independent and reusable. A stamp is
+//! planned first (`Blueprint::plan`) so the cost and any terrain conflicts are
+//! known before anything is built.
 
 pub mod blueprint;
 pub mod library;
+pub mod plan;
 pub mod plugin;
 pub mod types;
 
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_plan;
 
 // Re-export all public items so callers don't need to change their imports.
 pub use blueprint::*;
 pub use library::*;
+pub use plan::*;
 pub use plugin::*;
 pub use types::*;
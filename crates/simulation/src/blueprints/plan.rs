@@ -0,0 +1,285 @@
+//! Planning a blueprint stamp: rotation, cost and terrain checks.
+//!
+//! `Blueprint::plan` works out where every road, zone cell and service of a
+//! blueprint lands for a given origin and rotation, what building it costs,
+//! and which cells make the stamp impossible. The stamp tool draws the plan
+//! as a ghost, and `BlueprintPlan::apply` builds it.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::config::CELL_SIZE;
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::heightmap_import::BuildabilityGrid;
+use crate::road_segments::{RoadSegment, RoadSegmentStore, SegmentId, SegmentNodeId};
+use crate::roads::RoadNetwork;
+use crate::services::{place_service, ServiceBuilding, ServiceType};
+
+use super::blueprint::Blueprint;
+use super::types::{BlueprintRotation, PlaceResult};
+
+/// Cost of zoning one cell, the same as the zone brush charges.
+const ZONE_COST_PER_CELL: f64 = 5.0;
+
+/// Why part of a blueprint cannot be built where it is being stamped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlueprintConflict {
+    OutOfBounds,
+    Water,
+    TooSteep,
+    Occupied,
+}
+
+impl BlueprintConflict {
+    pub fn message(self) -> &'static str {
+        match self {
+            BlueprintConflict::OutOfBounds => "Blueprint does not fit inside the map",
+            BlueprintConflict::Water => "Blueprint crosses water",
+            BlueprintConflict::TooSteep => "Part of the blueprint is on a slope too steep to build",
+            BlueprintConflict::Occupied => "Blueprint overlaps existing buildings",
+        }
+    }
+}
+
+/// A road segment of a plan, in world coordinates.
+#[derive(Debug, Clone)]
+pub struct PlannedSegment {
+    pub points: [Vec2; 4],
+    pub road_type: RoadType,
+    /// Grid cells the road covers.
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// Everything a blueprint stamp would build, where, and at what cost.
+#[derive(Debug, Clone, Default)]
+pub struct BlueprintPlan {
+    /// Grid cell of the rotated blueprint's top-left corner.
+    pub origin: (usize, usize),
+    /// Size of the rotated blueprint in grid cells.
+    pub size: (u32, u32),
+    pub segments: Vec<PlannedSegment>,
+    /// Cells to zone; cells already built on or zoned the same are left out.
+    pub zone_cells: Vec<(usize, usize, ZoneType)>,
+    /// Footprint top-left cell of each service building.
+    pub services: Vec<(usize, usize, ServiceType)>,
+    /// Cells that block the stamp. Off-map cells keep their (negative or
+    /// too large) coordinates.
+    pub conflicts: Vec<(i32, i32, BlueprintConflict)>,
+    pub cost: f64,
+}
+
+/// The grid cell at (`x`, `y`), if it is on the map.
+fn on_map(grid: &WorldGrid, x: i32, y: i32) -> Option<(usize, usize)> {
+    if x < 0 || y < 0 || !grid.in_bounds(x as usize, y as usize) {
+        return None;
+    }
+    Some((x as usize, y as usize))
+}
+
+/// Points along a cubic Bezier, spaced like the road rasterizer samples them.
+fn road_samples(points: [Vec2; 4], road_type: RoadType) -> Vec<Vec2> {
+    let segment = RoadSegment {
+        id: SegmentId(0),
+        start_node: SegmentNodeId(0),
+        end_node: SegmentNodeId(0),
+        p0: points[0],
+        p1: points[1],
+        p2: points[2],
+        p3: points[3],
+        road_type,
+        arc_length: 0.0,
+        rasterized_cells: Vec::new(),
+    };
+    let arc_length = segment.compute_arc_length();
+    segment.sample_uniform(((arc_length / 8.0).ceil() as usize).max(4))
+}
+
+impl Blueprint {
+    /// Plan stamping this blueprint with its (rotated) top-left corner at
+    /// `target_x, target_y`.
+    pub fn plan(
+        &self,
+        grid: &WorldGrid,
+        buildable: &BuildabilityGrid,
+        target_x: usize,
+        target_y: usize,
+        rotation: BlueprintRotation,
+    ) -> BlueprintPlan {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let (world_w, world_h) = (
+            self.width as f32 * CELL_SIZE,
+            self.height as f32 * CELL_SIZE,
+        );
+        let origin_world = Vec2::new(target_x as f32 * CELL_SIZE, target_y as f32 * CELL_SIZE);
+        let (ox, oy) = (target_x as i32, target_y as i32);
+
+        let mut plan = BlueprintPlan {
+            origin: (target_x, target_y),
+            size: rotation.rotated_size(self.width, self.height),
+            ..Default::default()
+        };
+
+        // --- Roads ---
+        let mut road_cells: HashSet<(usize, usize)> = HashSet::new();
+        for seg in &self.segments {
+            let points = [seg.p0, seg.p1, seg.p2, seg.p3].map(|[x, y]| {
+                let (rx, ry) = rotation.rotate_point(x, y, world_w, world_h);
+                origin_world + Vec2::new(rx, ry)
+            });
+            let road_type: RoadType = seg.road_type.into();
+            let mut seen: Vec<(i32, i32)> = Vec::new();
+            let mut cells = Vec::new();
+            for pt in road_samples(points, road_type) {
+                let (gx, gy) = WorldGrid::world_to_grid(pt.x, pt.y);
+                if seen.contains(&(gx, gy)) {
+                    continue;
+                }
+                seen.push((gx, gy));
+                let Some((x, y)) = on_map(grid, gx, gy) else {
+                    plan.conflicts
+                        .push((gx, gy, BlueprintConflict::OutOfBounds));
+                    continue;
+                };
+                cells.push((x, y));
+                let cell = grid.get(x, y);
+                if cell.cell_type == CellType::Water {
+                    plan.conflicts.push((gx, gy, BlueprintConflict::Water));
+                } else if cell.building_id.is_some() {
+                    plan.conflicts.push((gx, gy, BlueprintConflict::Occupied));
+                } else if cell.cell_type != CellType::Road && road_cells.insert((x, y)) {
+                    plan.cost += road_type.cost();
+                }
+            }
+            plan.segments.push(PlannedSegment {
+                points,
+                road_type,
+                cells,
+            });
+        }
+
+        // --- Zones ---
+        for zc in &self.zone_cells {
+            let (rx, ry) = rotation.rotate_cell(zc.dx, zc.dy, w, h);
+            let (gx, gy) = (ox + rx, oy + ry);
+            let Some((x, y)) = on_map(grid, gx, gy) else {
+                plan.conflicts
+                    .push((gx, gy, BlueprintConflict::OutOfBounds));
+                continue;
+            };
+            let cell = grid.get(x, y);
+            let zone: ZoneType = zc.zone_type.into();
+            if cell.cell_type == CellType::Water {
+                plan.conflicts.push((gx, gy, BlueprintConflict::Water));
+            } else if !buildable.is_buildable(x, y) {
+                plan.conflicts.push((gx, gy, BlueprintConflict::TooSteep));
+            } else if cell.cell_type == CellType::Grass
+                && cell.building_id.is_none()
+                && cell.zone != zone
+                && !road_cells.contains(&(x, y))
+            {
+                plan.zone_cells.push((x, y, zone));
+                plan.cost += ZONE_COST_PER_CELL;
+            }
+        }
+
+        // --- Services ---
+        for svc in &self.services {
+            let (fw, fh) = ServiceBuilding::footprint(svc.service_type);
+            let (fw, fh) = (fw as i32, fh as i32);
+            // Buildings keep their orientation: centre the footprint on where
+            // the rotated footprint lands.
+            let (ax, ay) = rotation.rotate_cell(svc.dx, svc.dy, w, h);
+            let (bx, by) = rotation.rotate_cell(svc.dx + fw - 1, svc.dy + fh - 1, w, h);
+            let (rw, rh) = ((ax - bx).abs() + 1, (ay - by).abs() + 1);
+            let gx = ox + ax.min(bx) + (rw - fw) / 2;
+            let gy = oy + ay.min(by) + (rh - fh) / 2;
+
+            let mut fits = true;
+            for cy in gy..gy + fh {
+                for cx in gx..gx + fw {
+                    let conflict = match on_map(grid, cx, cy) {
+                        None => Some(BlueprintConflict::OutOfBounds),
+                        Some((x, y)) => {
+                            let cell = grid.get(x, y);
+                            if cell.cell_type == CellType::Water {
+                                Some(BlueprintConflict::Water)
+                            } else if !buildable.is_buildable(x, y) {
+                                Some(BlueprintConflict::TooSteep)
+                            } else if cell.cell_type != CellType::Grass
+                                || cell.building_id.is_some()
+                                || road_cells.contains(&(x, y))
+                            {
+                                Some(BlueprintConflict::Occupied)
+                            } else {
+                                None
+                            }
+                        }
+                    };
+                    if let Some(conflict) = conflict {
+                        plan.conflicts.push((cx, cy, conflict));
+                        fits = false;
+                    }
+                }
+            }
+            if fits {
+                plan.services
+                    .push((gx as usize, gy as usize, svc.service_type));
+                plan.cost += ServiceBuilding::cost(svc.service_type);
+            }
+        }
+
+        plan
+    }
+}
+
+impl BlueprintPlan {
+    /// Whether the whole blueprint fits where it is planned.
+    pub fn is_buildable(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// The first reason the stamp is blocked, if it is.
+    pub fn first_conflict(&self) -> Option<BlueprintConflict> {
+        self.conflicts.first().map(|&(_, _, conflict)| conflict)
+    }
+
+    /// Build the planned roads, zones and services.
+    pub fn apply(
+        &self,
+        commands: &mut Commands,
+        grid: &mut WorldGrid,
+        segment_store: &mut RoadSegmentStore,
+        roads: &mut RoadNetwork,
+    ) -> PlaceResult {
+        let mut result = PlaceResult {
+            segments_placed: 0,
+            zones_placed: 0,
+            services_placed: 0,
+        };
+
+        for seg in &self.segments {
+            let [p0, p1, p2, p3] = seg.points;
+            let start = segment_store.find_or_create_node(p0, 16.0);
+            let end = segment_store.find_or_create_node(p3, 16.0);
+            segment_store.add_segment(start, end, p0, p1, p2, p3, seg.road_type, grid, roads);
+            result.segments_placed += 1;
+        }
+
+        for &(x, y, zone) in &self.zone_cells {
+            let cell = grid.get(x, y);
+            if cell.cell_type == CellType::Grass && cell.building_id.is_none() {
+                grid.get_mut(x, y).zone = zone;
+                result.zones_placed += 1;
+            }
+        }
+
+        for &(x, y, service_type) in &self.services {
+            if place_service(commands, grid, service_type, x, y) {
+                result.services_placed += 1;
+            }
+        }
+
+        result
+    }
+}
@@ -2,11 +2,15 @@
 
 use bevy::prelude::*;
 
+use crate::economy::CityBudget;
 use crate::grid::WorldGrid;
+use crate::heightmap_import::BuildabilityGrid;
 use crate::road_segments::RoadSegmentStore;
 use crate::roads::RoadNetwork;
+use crate::services::ServiceBuilding;
 
 use super::library::BlueprintLibrary;
+use super::types::BlueprintRotation;
 
 // =============================================================================
 // Events
@@ -27,9 +31,10 @@ pub struct CaptureBlueprint {
 pub struct PlaceBlueprint {
     /// Index of the blueprint in the library.
     pub blueprint_index: usize,
-    /// Target grid origin for placement.
+    /// Target grid origin for placement (top-left of the rotated blueprint).
     pub target_x: usize,
     pub target_y: usize,
+    pub rotation: BlueprintRotation,
 }
 
 /// Event fired after a blueprint is successfully captured.
@@ -45,6 +50,18 @@ pub struct BlueprintPlaced {
     pub name: String,
     pub segments_placed: u32,
     pub zones_placed: u32,
+    pub services_placed: u32,
+    pub cost: f64,
+    /// Grid area covered: top-left cell and size in cells.
+    pub origin: (usize, usize),
+    pub size: (u32, u32),
+}
+
+/// Event fired when a blueprint cannot be placed where it was stamped.
+#[derive(Event)]
+pub struct BlueprintPlaceRejected {
+    pub name: String,
+    pub reason: String,
 }
 
 // =============================================================================
@@ -57,6 +74,7 @@ fn handle_capture_blueprint(
     mut library: ResMut<BlueprintLibrary>,
     grid: Res<WorldGrid>,
     segments: Res<RoadSegmentStore>,
+    services: Query<&ServiceBuilding>,
     mut captured_events: EventWriter<BlueprintCaptured>,
 ) {
     for ev in events.read() {
@@ -68,7 +86,8 @@ fn handle_capture_blueprint(
             ev.width,
             ev.height,
             ev.name.clone(),
-        );
+        )
+        .with_services(services.iter(), ev.origin_x, ev.origin_y);
         let index = library.add(blueprint);
         info!(
             "Blueprint '{}' captured (index {}) from ({},{}) size {}x{}",
@@ -81,14 +100,20 @@ fn handle_capture_blueprint(
     }
 }
 
-/// System that processes `PlaceBlueprint` events.
+/// System that processes `PlaceBlueprint` events. A stamp is all or
+/// nothing: it is rejected if any part does not fit or the city cannot pay.
+#[allow(clippy::too_many_arguments)]
 fn handle_place_blueprint(
     mut events: EventReader<PlaceBlueprint>,
     library: Res<BlueprintLibrary>,
+    buildable: Res<BuildabilityGrid>,
     mut grid: ResMut<WorldGrid>,
     mut segments: ResMut<RoadSegmentStore>,
     mut roads: ResMut<RoadNetwork>,
+    mut budget: ResMut<CityBudget>,
+    mut commands: Commands,
     mut placed_events: EventWriter<BlueprintPlaced>,
+    mut rejected_events: EventWriter<BlueprintPlaceRejected>,
 ) {
     for ev in events.read() {
         let Some(blueprint) = library.get(ev.blueprint_index) else {
@@ -100,21 +125,45 @@ fn handle_place_blueprint(
             continue;
         };
         let name = blueprint.name.clone();
-        let result = blueprint.place(
-            &mut grid,
-            &mut segments,
-            &mut roads,
+        let plan = blueprint.plan(&grid, &buildable, ev.target_x, ev.target_y, ev.rotation);
+        if let Some(conflict) = plan.first_conflict() {
+            rejected_events.send(BlueprintPlaceRejected {
+                name,
+                reason: conflict.message().to_string(),
+            });
+            continue;
+        }
+        if budget.treasury < plan.cost {
+            rejected_events.send(BlueprintPlaceRejected {
+                name,
+                reason: format!(
+                    "Not enough funds (need ${:.0}, have ${:.0})",
+                    plan.cost, budget.treasury
+                ),
+            });
+            continue;
+        }
+
+        let result = plan.apply(&mut commands, &mut grid, &mut segments, &mut roads);
+        budget.treasury -= plan.cost;
+        info!(
+            "Blueprint '{}' placed at ({},{}) rotated {}° — {} segments, {} zones, {} services",
+            name,
             ev.target_x,
             ev.target_y,
-        );
-        info!(
-            "Blueprint '{}' placed at ({},{}) — {} segments, {} zones",
-            name, ev.target_x, ev.target_y, result.segments_placed, result.zones_placed
+            ev.rotation.degrees(),
+            result.segments_placed,
+            result.zones_placed,
+            result.services_placed
         );
         placed_events.send(BlueprintPlaced {
             name,
             segments_placed: result.segments_placed,
             zones_placed: result.zones_placed,
+            services_placed: result.services_placed,
+            cost: plan.cost,
+            origin: plan.origin,
+            size: plan.size,
         });
     }
 }
@@ -132,6 +181,7 @@ impl Plugin for BlueprintPlugin {
             .add_event::<PlaceBlueprint>()
             .add_event::<BlueprintCaptured>()
             .add_event::<BlueprintPlaced>()
+            .add_event::<BlueprintPlaceRejected>()
             .add_systems(
                 FixedUpdate,
                 // Event-driven (user-triggered): handle_place_blueprint writes
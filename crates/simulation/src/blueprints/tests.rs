@@ -57,6 +57,7 @@ fn test_blueprint_library_add_and_get() {
         height: 10,
         segments: vec![],
         zone_cells: vec![],
+        services: vec![],
     };
     let idx = lib.add(bp);
     assert_eq!(idx, 0);
@@ -74,6 +75,7 @@ fn test_blueprint_library_remove() {
         height: 5,
         segments: vec![],
         zone_cells: vec![],
+        services: vec![],
     });
     lib.add(Blueprint {
         name: "B".to_string(),
@@ -81,6 +83,7 @@ fn test_blueprint_library_remove() {
         height: 10,
         segments: vec![],
         zone_cells: vec![],
+        services: vec![],
     });
     assert_eq!(lib.count(), 2);
 
@@ -124,6 +127,7 @@ fn test_saveable_roundtrip() {
                 zone_type: BlueprintZoneType::CommercialHigh,
             },
         ],
+        services: vec![],
     });
 
     let bytes = lib.save_to_bytes().expect("should produce bytes");
@@ -209,6 +213,7 @@ fn test_place_zones_on_grass() {
                 zone_type: BlueprintZoneType::CommercialLow,
             },
         ],
+        services: vec![],
     };
 
     let result = bp.place(&mut grid, &mut segments, &mut roads, 50, 50);
@@ -234,6 +239,7 @@ fn test_place_zones_skip_water() {
             dy: 0,
             zone_type: BlueprintZoneType::ResidentialLow,
         }],
+        services: vec![],
     };
 
     let result = bp.place(&mut grid, &mut segments, &mut roads, 50, 50);
@@ -257,6 +263,7 @@ fn test_place_out_of_bounds_skipped() {
             dy: 5,
             zone_type: BlueprintZoneType::Industrial,
         }],
+        services: vec![],
     };
 
     // Place near the edge so dx=5 goes out of bounds (255 + 5 = 260 > 255)
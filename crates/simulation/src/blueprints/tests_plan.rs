@@ -0,0 +1,140 @@
+//! Unit tests for blueprint rotation, placement plans and captured services.
+
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::heightmap_import::BuildabilityGrid;
+use crate::road_segments::RoadSegmentStore;
+use crate::services::{ServiceBuilding, ServiceType};
+
+#[test]
+fn test_rotation_cycles_and_sizes() {
+    let mut rotation = BlueprintRotation::default();
+    for _ in 0..4 {
+        rotation = rotation.next();
+    }
+    assert_eq!(rotation, BlueprintRotation::None);
+    assert_eq!(BlueprintRotation::Quarter.rotated_size(6, 4), (4, 6));
+    assert_eq!(BlueprintRotation::Half.rotated_size(6, 4), (6, 4));
+    // Corners of a 6x4 area stay inside the rotated area.
+    assert_eq!(BlueprintRotation::Quarter.rotate_cell(0, 0, 6, 4), (3, 0));
+    assert_eq!(BlueprintRotation::Quarter.rotate_cell(5, 3, 6, 4), (0, 5));
+    assert_eq!(BlueprintRotation::Half.rotate_cell(0, 0, 6, 4), (5, 3));
+    assert_eq!(
+        BlueprintRotation::ThreeQuarter.rotate_cell(0, 0, 6, 4),
+        (0, 5)
+    );
+}
+
+fn zone_blueprint() -> Blueprint {
+    Blueprint {
+        name: "Zones".to_string(),
+        width: 3,
+        height: 2,
+        segments: vec![],
+        zone_cells: vec![BlueprintZoneCell {
+            dx: 0,
+            dy: 0,
+            zone_type: BlueprintZoneType::ResidentialLow,
+        }],
+        services: vec![],
+    }
+}
+
+#[test]
+fn test_plan_rotates_zones_and_costs_them() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let buildable = BuildabilityGrid::default();
+    let plan = zone_blueprint().plan(&grid, &buildable, 50, 50, BlueprintRotation::Quarter);
+    assert!(plan.is_buildable());
+    assert_eq!(plan.size, (2, 3));
+    assert_eq!(plan.zone_cells, vec![(51, 50, ZoneType::ResidentialLow)]);
+    assert_eq!(plan.cost, 5.0);
+}
+
+#[test]
+fn test_plan_reports_water_and_steep_conflicts() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    grid.get_mut(50, 50).cell_type = CellType::Water;
+    let mut buildable = BuildabilityGrid::default();
+    let plan = zone_blueprint().plan(&grid, &buildable, 50, 50, BlueprintRotation::None);
+    assert_eq!(plan.first_conflict(), Some(BlueprintConflict::Water));
+
+    buildable.steep[60 * GRID_WIDTH + 60] = true;
+    let plan = zone_blueprint().plan(&grid, &buildable, 60, 60, BlueprintRotation::None);
+    assert_eq!(plan.first_conflict(), Some(BlueprintConflict::TooSteep));
+}
+
+#[test]
+fn test_plan_out_of_bounds_conflict() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let buildable = BuildabilityGrid::default();
+    let mut bp = zone_blueprint();
+    bp.zone_cells[0].dx = 2;
+    let plan = bp.plan(
+        &grid,
+        &buildable,
+        GRID_WIDTH - 1,
+        0,
+        BlueprintRotation::None,
+    );
+    assert_eq!(plan.first_conflict(), Some(BlueprintConflict::OutOfBounds));
+}
+
+#[test]
+fn test_plan_road_cost_counts_new_cells() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let buildable = BuildabilityGrid::default();
+    let bp = Blueprint {
+        name: "Road".to_string(),
+        width: 10,
+        height: 2,
+        segments: vec![BlueprintSegment {
+            p0: [8.0, 8.0],
+            p1: [50.0, 8.0],
+            p2: [100.0, 8.0],
+            p3: [150.0, 8.0],
+            road_type: BlueprintRoadType::Local,
+        }],
+        zone_cells: vec![],
+        services: vec![],
+    };
+    let plan = bp.plan(&grid, &buildable, 20, 20, BlueprintRotation::None);
+    assert!(plan.is_buildable());
+    let cells = plan.segments[0].cells.len();
+    assert!(cells > 0);
+    assert!(plan.segments[0].cells.iter().all(|&(_, y)| y == 20));
+    assert_eq!(plan.cost, cells as f64 * 10.0);
+}
+
+#[test]
+fn test_capture_services_and_plan_them() {
+    let inside = ServiceBuilding {
+        service_type: ServiceType::FireStation,
+        grid_x: 12,
+        grid_y: 11,
+        radius: 0.0,
+    };
+    let outside = ServiceBuilding {
+        service_type: ServiceType::FireStation,
+        grid_x: 30,
+        grid_y: 30,
+        radius: 0.0,
+    };
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let segments = RoadSegmentStore::default();
+    let bp = Blueprint::capture(&grid, &segments, 10, 10, 4, 3, "Fire".to_string()).with_services(
+        [&inside, &outside],
+        10,
+        10,
+    );
+    assert_eq!(bp.services.len(), 1);
+    assert_eq!((bp.services[0].dx, bp.services[0].dy), (2, 1));
+
+    let buildable = BuildabilityGrid::default();
+    let plan = bp.plan(&grid, &buildable, 50, 50, BlueprintRotation::Half);
+    assert!(plan.is_buildable());
+    // (2, 1) in a 4x3 area lands on (1, 1) after half a turn.
+    assert_eq!(plan.services, vec![(51, 51, ServiceType::FireStation)]);
+    assert_eq!(plan.cost, ServiceBuilding::cost(ServiceType::FireStation));
+}
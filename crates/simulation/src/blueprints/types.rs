@@ -2,11 +2,12 @@
 //!
 //! Contains the serializable mirror types (`BlueprintRoadType`, `BlueprintZoneType`)
 //! and their conversions to/from the grid types, plus the data structs for
-//! segments, zone cells, and placement results.
+//! segments, zone cells, services, rotation, and placement results.
 
 use bitcode::{Decode, Encode};
 
 use crate::grid::{RoadType, ZoneType};
+use crate::services::ServiceType;
 
 // =============================================================================
 // Segment & zone-cell data
@@ -32,11 +33,85 @@ pub struct BlueprintZoneCell {
     pub zone_type: BlueprintZoneType,
 }
 
+/// A service building stored relative to the blueprint origin.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct BlueprintService {
+    /// Offset of the footprint's top-left cell from the blueprint origin.
+    pub dx: i32,
+    pub dy: i32,
+    pub service_type: ServiceType,
+}
+
 /// Result of placing a blueprint on the map.
 #[derive(Debug, Clone, Copy)]
 pub struct PlaceResult {
     pub segments_placed: u32,
     pub zones_placed: u32,
+    pub services_placed: u32,
+}
+
+// =============================================================================
+// Rotation
+// =============================================================================
+
+/// Clockwise quarter turns applied to a blueprint when it is stamped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlueprintRotation {
+    #[default]
+    None,
+    Quarter,
+    Half,
+    ThreeQuarter,
+}
+
+impl BlueprintRotation {
+    /// The next clockwise quarter turn.
+    pub fn next(self) -> Self {
+        match self {
+            BlueprintRotation::None => BlueprintRotation::Quarter,
+            BlueprintRotation::Quarter => BlueprintRotation::Half,
+            BlueprintRotation::Half => BlueprintRotation::ThreeQuarter,
+            BlueprintRotation::ThreeQuarter => BlueprintRotation::None,
+        }
+    }
+
+    pub fn degrees(self) -> u32 {
+        match self {
+            BlueprintRotation::None => 0,
+            BlueprintRotation::Quarter => 90,
+            BlueprintRotation::Half => 180,
+            BlueprintRotation::ThreeQuarter => 270,
+        }
+    }
+
+    /// Size of a `w` x `h` area after rotating it.
+    pub fn rotated_size(self, w: u32, h: u32) -> (u32, u32) {
+        match self {
+            BlueprintRotation::None | BlueprintRotation::Half => (w, h),
+            BlueprintRotation::Quarter | BlueprintRotation::ThreeQuarter => (h, w),
+        }
+    }
+
+    /// Rotate a point inside a `w` x `h` area (any unit) so the rotated area
+    /// again starts at the origin.
+    pub fn rotate_point(self, x: f32, y: f32, w: f32, h: f32) -> (f32, f32) {
+        match self {
+            BlueprintRotation::None => (x, y),
+            BlueprintRotation::Quarter => (h - y, x),
+            BlueprintRotation::Half => (w - x, h - y),
+            BlueprintRotation::ThreeQuarter => (y, w - x),
+        }
+    }
+
+    /// Rotate the cell at (`dx`, `dy`) inside a `w` x `h` cell area.
+    pub fn rotate_cell(self, dx: i32, dy: i32, w: i32, h: i32) -> (i32, i32) {
+        match self {
+            BlueprintRotation::None => (dx, dy),
+            BlueprintRotation::Quarter => (h - 1 - dy, dx),
+            BlueprintRotation::Half => (w - 1 - dx, h - 1 - dy),
+            BlueprintRotation::ThreeQuarter => (dy, w - 1 - dx),
+        }
+    }
 }
 
 // =============================================================================
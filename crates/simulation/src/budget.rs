@@ -130,6 +130,12 @@ pub struct IncomeBreakdown {
     pub industrial_tax: f64,
     pub office_tax: f64,
     pub trade_income: f64,
+    #[serde(default)]
+    pub insurance_payouts: f64,
+    #[serde(default)]
+    pub disaster_aid: f64,
+    #[serde(default)]
+    pub carbon_revenue: f64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -140,6 +146,8 @@ pub struct ExpenseBreakdown {
     pub loan_payments: f64,
     #[serde(default)]
     pub fuel_costs: f64,
+    #[serde(default)]
+    pub insurance_premiums: f64,
 }
 
 impl ExtendedBudget {
@@ -0,0 +1,86 @@
+//! Change events for per-building state.
+//!
+//! Systems that react to building state used to scan every building on each
+//! interval to find the few whose state had moved. Instead, two detectors
+//! emit an event when something a building depends on actually changes:
+//!
+//! - [`BuildingOccupancyChanged`] when a building's occupants, capacity or
+//!   level differ from what was last reported, and once for every building
+//!   the first time it is seen (new, loaded or promoted buildings).
+//! - [`BuildingUtilitiesChanged`] when power or water at a building's cell
+//!   flips, whether from utility propagation, power-line coverage or a
+//!   blackout. Only chunks whose revision moved are swept.
+//!
+//! Consumers fold the events into a candidate set and evaluate only those
+//! buildings when their interval fires; see `abandonment` and
+//! `building_upgrade`.
+//!
+//! There is no coverage event, and `happiness` still polls. Happiness is
+//! per citizen and moves with traffic, pollution, weather and needs every
+//! interval, none of which is building state, and no per-building consumer
+//! reads service coverage; `ServiceCoverageGrid` already recomputes only the
+//! regions around services that changed.
+
+mod systems;
+#[cfg(test)]
+mod tests;
+
+pub use systems::{detect_occupancy_changes, detect_utility_changes};
+
+use bevy::prelude::*;
+
+/// A building's occupants, capacity or level changed, or the building was
+/// seen for the first time.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildingOccupancyChanged {
+    pub entity: Entity,
+    pub occupants: u32,
+    pub capacity: u32,
+    pub level: u8,
+}
+
+impl BuildingOccupancyChanged {
+    /// Occupants over capacity; 0 for buildings without capacity.
+    pub fn occupancy(&self) -> f32 {
+        if self.capacity > 0 {
+            self.occupants as f32 / self.capacity as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Power or water at a building's cell changed. Carries the new state.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildingUtilitiesChanged {
+    pub entity: Entity,
+    pub has_power: bool,
+    pub has_water: bool,
+}
+
+impl BuildingUtilitiesChanged {
+    /// Whether the building has lost both power and water.
+    pub fn lost_all(&self) -> bool {
+        !self.has_power && !self.has_water
+    }
+}
+
+pub struct BuildingEventsPlugin;
+
+impl Plugin for BuildingEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BuildingOccupancyChanged>()
+            .add_event::<BuildingUtilitiesChanged>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    detect_occupancy_changes.after(crate::lifecycle::emigration),
+                    detect_utility_changes
+                        .after(crate::utilities::propagate_utilities)
+                        .after(crate::power_lines::propagate_power_coverage)
+                        .after(crate::blackout::evaluate_blackout),
+                )
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+    }
+}
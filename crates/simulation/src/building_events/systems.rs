@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::grid::WorldGrid;
+use crate::packed_grid::BitGrid;
+
+use super::{BuildingOccupancyChanged, BuildingUtilitiesChanged};
+
+/// Emit `BuildingOccupancyChanged` for buildings whose occupants, capacity or
+/// level moved since they were last reported.
+///
+/// `Changed<Building>` also fires for writes that leave the values as they
+/// were (abandoned buildings have their occupants zeroed every tick), so the
+/// last reported values filter those out.
+pub fn detect_occupancy_changes(
+    buildings: Query<(Entity, &Building), Changed<Building>>,
+    mut removed: RemovedComponents<Building>,
+    mut reported: Local<HashMap<Entity, (u32, u32, u8)>>,
+    mut events: EventWriter<BuildingOccupancyChanged>,
+) {
+    for entity in removed.read() {
+        reported.remove(&entity);
+    }
+    for (entity, building) in &buildings {
+        let state = (building.occupants, building.capacity, building.level);
+        if reported.insert(entity, state) == Some(state) {
+            continue;
+        }
+        events.send(BuildingOccupancyChanged {
+            entity,
+            occupants: building.occupants,
+            capacity: building.capacity,
+            level: building.level,
+        });
+    }
+}
+
+/// Utility flags as last seen by `detect_utility_changes`.
+#[derive(Default)]
+pub struct UtilitySnapshot {
+    power: BitGrid,
+    water: BitGrid,
+    /// Chunk revisions the flags were read at; empty before the first sweep.
+    revisions: Vec<u32>,
+    /// `ChunkedGrid::instance` of the grid the revisions belong to.
+    grid_instance: Option<u64>,
+}
+
+/// Emit `BuildingUtilitiesChanged` for buildings whose cell gained or lost
+/// power or water.
+///
+/// Only chunks whose revision moved since the last sweep are compared, so a
+/// tick that touches no cells costs one revision check per chunk. A grid
+/// replaced in place (a load or a new map) starts its revisions over, so it
+/// is swept in full against a fresh snapshot.
+pub fn detect_utility_changes(
+    grid: Res<WorldGrid>,
+    mut seen: Local<UtilitySnapshot>,
+    mut events: EventWriter<BuildingUtilitiesChanged>,
+) {
+    if !grid.is_changed() {
+        return;
+    }
+    let seen = &mut *seen;
+    let chunk_count = grid.cells.chunk_count();
+    let grid_instance = Some(grid.cells.instance());
+    let first_sweep = seen.grid_instance != grid_instance || seen.revisions.len() != chunk_count;
+    if first_sweep {
+        seen.grid_instance = grid_instance;
+        seen.revisions = vec![0; chunk_count];
+        seen.power = BitGrid::new(grid.width, grid.height);
+        seen.water = BitGrid::new(grid.width, grid.height);
+    }
+
+    for ci in 0..chunk_count {
+        let revision = grid.cells.chunk_revision(ci);
+        if !first_sweep && seen.revisions[ci] == revision {
+            continue;
+        }
+        seen.revisions[ci] = revision;
+
+        for (x, y, cell) in grid.cells.chunk_cells(ci) {
+            let idx = y * grid.width + x;
+            if seen.power.get_index(idx) == cell.has_power
+                && seen.water.get_index(idx) == cell.has_water
+            {
+                continue;
+            }
+            seen.power.set_index(idx, cell.has_power);
+            seen.water.set_index(idx, cell.has_water);
+            if let Some(entity) = cell.building_id {
+                events.send(BuildingUtilitiesChanged {
+                    entity,
+                    has_power: cell.has_power,
+                    has_water: cell.has_water,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::*;
+use crate::abandonment::{
+    check_building_abandonment, process_abandoned_buildings, should_abandon, Abandoned,
+    AbandonmentCandidates, CHECK_INTERVAL,
+};
+use crate::building_upgrade::{level_ceiling, track_upgrade_candidates, UpgradeCandidates};
+use crate::buildings::Building;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::TickCounter;
+
+const SIZE: usize = 64;
+const BUILDINGS: usize = 60;
+const TICKS: u64 = 8 * CHECK_INTERVAL;
+const SEED: u64 = 0xB01D_0929;
+
+/// The abandonment check as it was before events: every building, every
+/// interval.
+fn polling_abandonment(
+    mut commands: Commands,
+    tick: Res<TickCounter>,
+    grid: Res<WorldGrid>,
+    buildings: Query<(Entity, &Building), Without<Abandoned>>,
+) {
+    if !tick.0.is_multiple_of(CHECK_INTERVAL) {
+        return;
+    }
+    for (entity, building) in &buildings {
+        if should_abandon(building, grid.get(building.grid_x, building.grid_y)) {
+            commands
+                .entity(entity)
+                .insert(Abandoned { ticks_abandoned: 0 });
+        }
+    }
+}
+
+fn base_app() -> App {
+    let mut app = App::new();
+    app.add_event::<BuildingOccupancyChanged>()
+        .add_event::<BuildingUtilitiesChanged>()
+        .insert_resource(WorldGrid::new(SIZE, SIZE))
+        .init_resource::<TickCounter>()
+        .init_resource::<AbandonmentCandidates>()
+        .init_resource::<UpgradeCandidates>();
+    app
+}
+
+fn event_driven_app() -> App {
+    let mut app = base_app();
+    app.add_systems(
+        Update,
+        (
+            (detect_occupancy_changes, detect_utility_changes),
+            check_building_abandonment,
+            apply_deferred,
+            process_abandoned_buildings,
+        )
+            .chain(),
+    )
+    .add_systems(
+        Update,
+        track_upgrade_candidates.after(detect_occupancy_changes),
+    );
+    app
+}
+
+fn polling_app() -> App {
+    let mut app = base_app();
+    app.add_systems(
+        Update,
+        (
+            polling_abandonment,
+            apply_deferred,
+            process_abandoned_buildings,
+        )
+            .chain(),
+    );
+    app
+}
+
+#[derive(Clone, Copy)]
+enum Change {
+    Occupants(usize, u32),
+    Level(usize, u8),
+    Power(usize, bool),
+    Water(usize, bool),
+}
+
+impl Change {
+    fn random(rng: &mut StdRng, utilities: bool) -> Self {
+        let i = rng.gen_range(0..BUILDINGS);
+        match rng.gen_range(0..if utilities { 4 } else { 2 }) {
+            0 => Change::Occupants(i, rng.gen_range(0..=4)),
+            1 => Change::Level(i, rng.gen_range(1..=3)),
+            2 => Change::Power(i, rng.gen_bool(0.5)),
+            _ => Change::Water(i, rng.gen_bool(0.5)),
+        }
+    }
+
+    fn building(self) -> usize {
+        match self {
+            Change::Occupants(i, _)
+            | Change::Level(i, _)
+            | Change::Power(i, _)
+            | Change::Water(i, _) => i,
+        }
+    }
+}
+
+fn spawn_buildings(world: &mut World, rng: &mut StdRng) -> Vec<Entity> {
+    let zones = [
+        ZoneType::ResidentialLow,
+        ZoneType::ResidentialHigh,
+        ZoneType::CommercialLow,
+        ZoneType::Industrial,
+        ZoneType::Office,
+    ];
+    let mut cells: Vec<(usize, usize)> = Vec::new();
+    while cells.len() < BUILDINGS {
+        let cell = (rng.gen_range(0..SIZE), rng.gen_range(0..SIZE));
+        if !cells.contains(&cell) {
+            cells.push(cell);
+        }
+    }
+    cells
+        .into_iter()
+        .map(|(x, y)| {
+            let zone = zones[rng.gen_range(0..zones.len())];
+            let level = rng.gen_range(1..=3);
+            let capacity = Building::capacity_for_level(zone, level);
+            let entity = world
+                .spawn(Building {
+                    zone_type: zone,
+                    level,
+                    grid_x: x,
+                    grid_y: y,
+                    capacity,
+                    occupants: rng.gen_range(0..=capacity),
+                })
+                .id();
+            let mut grid = world.resource_mut::<WorldGrid>();
+            let cell = grid.get_mut(x, y);
+            cell.building_id = Some(entity);
+            cell.zone = zone;
+            cell.has_power = rng.gen_bool(0.8);
+            cell.has_water = rng.gen_bool(0.8);
+            entity
+        })
+        .collect()
+}
+
+fn apply(world: &mut World, buildings: &[Entity], change: Change) {
+    let Some(mut building) = world.get_mut::<Building>(buildings[change.building()]) else {
+        return;
+    };
+    let (x, y) = (building.grid_x, building.grid_y);
+    match change {
+        Change::Occupants(_, fill) => {
+            building.occupants = building.capacity * fill / 4;
+        }
+        Change::Level(_, level) => {
+            building.level = level;
+            building.capacity = Building::capacity_for_level(building.zone_type, level);
+            building.occupants = building.occupants.min(building.capacity);
+        }
+        Change::Power(_, on) => {
+            world.resource_mut::<WorldGrid>().get_mut(x, y).has_power = on;
+        }
+        Change::Water(_, on) => {
+            world.resource_mut::<WorldGrid>().get_mut(x, y).has_water = on;
+        }
+    }
+}
+
+fn abandoned(world: &mut World, buildings: &[Entity]) -> Vec<usize> {
+    (0..buildings.len())
+        .filter(|&i| world.get::<Abandoned>(buildings[i]).is_some())
+        .collect()
+}
+
+/// Upgrade candidates the way the old upgrade scan would have found them.
+fn polled_upgrade_sets(world: &mut World) -> (BTreeSet<Entity>, BTreeSet<Entity>) {
+    let mut crowded = BTreeSet::new();
+    let mut upgraded = BTreeSet::new();
+    let mut query = world.query::<(Entity, &Building)>();
+    for (entity, building) in query.iter(world) {
+        let occupancy = if building.capacity > 0 {
+            building.occupants as f32 / building.capacity as f32
+        } else {
+            0.0
+        };
+        if occupancy >= 0.75 && building.level < level_ceiling(building.zone_type) {
+            crowded.insert(entity);
+        }
+        if building.level > 1 {
+            upgraded.insert(entity);
+        }
+    }
+    (crowded, upgraded)
+}
+
+#[test]
+fn test_event_driven_abandonment_matches_polling() {
+    let mut events = event_driven_app();
+    let mut polling = polling_app();
+    let event_buildings = spawn_buildings(events.world_mut(), &mut StdRng::seed_from_u64(SEED));
+    let polling_buildings = spawn_buildings(polling.world_mut(), &mut StdRng::seed_from_u64(SEED));
+
+    let mut rng = StdRng::seed_from_u64(SEED + 1);
+    let mut abandonments = 0;
+    for tick in 1..=TICKS {
+        for _ in 0..rng.gen_range(0..4) {
+            let change = Change::random(&mut rng, true);
+            apply(events.world_mut(), &event_buildings, change);
+            apply(polling.world_mut(), &polling_buildings, change);
+        }
+        for app in [&mut events, &mut polling] {
+            app.world_mut().resource_mut::<TickCounter>().0 = tick;
+            app.update();
+        }
+
+        let expected = abandoned(polling.world_mut(), &polling_buildings);
+        let actual = abandoned(events.world_mut(), &event_buildings);
+        assert_eq!(
+            actual, expected,
+            "abandoned buildings diverge at tick {tick}"
+        );
+        abandonments += expected.len();
+    }
+    assert!(abandonments > 0, "the scenario should abandon something");
+}
+
+#[test]
+fn test_upgrade_candidates_match_polling() {
+    let mut rng = StdRng::seed_from_u64(SEED + 2);
+    let mut app = event_driven_app();
+    let buildings = spawn_buildings(app.world_mut(), &mut rng);
+
+    for tick in 1..=TICKS {
+        for _ in 0..rng.gen_range(0..4) {
+            let change = Change::random(&mut rng, false);
+            apply(app.world_mut(), &buildings, change);
+        }
+        // Detection runs first in the tick, so it sees exactly this state.
+        let (crowded, upgraded) = polled_upgrade_sets(app.world_mut());
+        app.world_mut().resource_mut::<TickCounter>().0 = tick;
+        app.update();
+
+        let candidates = app.world().resource::<UpgradeCandidates>();
+        assert_eq!(
+            candidates.crowded, crowded,
+            "crowded set diverges at tick {tick}"
+        );
+        assert_eq!(
+            candidates.upgraded, upgraded,
+            "upgraded set diverges at tick {tick}"
+        );
+    }
+}
+
+#[test]
+fn test_utility_events_only_for_flipped_building_cells() {
+    let mut app = base_app();
+    app.add_systems(Update, detect_utility_changes);
+    let building = app.world_mut().spawn_empty().id();
+    {
+        let mut grid = app.world_mut().resource_mut::<WorldGrid>();
+        grid.get_mut(3, 3).building_id = Some(building);
+        grid.get_mut(3, 3).has_power = true;
+        grid.get_mut(40, 40).has_power = true;
+    }
+    app.update();
+    app.world_mut()
+        .resource_mut::<WorldGrid>()
+        .get_mut(3, 3)
+        .has_power = false;
+    app.update();
+
+    let events = app.world().resource::<Events<BuildingUtilitiesChanged>>();
+    let sent: Vec<_> = events.iter_current_update_events().copied().collect();
+    assert_eq!(
+        sent,
+        vec![BuildingUtilitiesChanged {
+            entity: building,
+            has_power: false,
+            has_water: false,
+        }]
+    );
+    assert!(sent[0].lost_all());
+}
+
+#[test]
+fn test_replaced_grid_is_swept_in_full() {
+    // Same edits in the same order, so both grids end on equal revisions.
+    fn grid_with(building: Entity, has_water: bool) -> WorldGrid {
+        let mut grid = WorldGrid::new(SIZE, SIZE);
+        grid.get_mut(3, 3).building_id = Some(building);
+        let cell = grid.get_mut(3, 3);
+        cell.has_power = true;
+        cell.has_water = has_water;
+        grid
+    }
+
+    let mut app = base_app();
+    app.add_systems(Update, detect_utility_changes);
+    let building = app.world_mut().spawn_empty().id();
+    *app.world_mut().resource_mut::<WorldGrid>() = grid_with(building, false);
+    app.update();
+    // Replaced in place, as loading a save does.
+    *app.world_mut().resource_mut::<WorldGrid>() = grid_with(building, true);
+    app.update();
+
+    let events = app.world().resource::<Events<BuildingUtilitiesChanged>>();
+    let sent: Vec<_> = events.iter_current_update_events().copied().collect();
+    assert_eq!(
+        sent,
+        vec![BuildingUtilitiesChanged {
+            entity: building,
+            has_power: true,
+            has_water: true,
+        }]
+    );
+}
@@ -1,7 +1,14 @@
+use std::collections::BTreeSet;
+
 use bevy::prelude::*;
 
+use crate::building_events::BuildingOccupancyChanged;
 use crate::buildings::{max_level_for_far, Building, MixedUseBuilding};
+use crate::district_policies::DistrictPolicyLookup;
+use crate::grid::ZoneType;
+use crate::parking::ParkingPolicyState;
 use crate::stats::CityStats;
+use crate::underground::UndergroundLayer;
 use crate::urban_growth_boundary::UrbanGrowthBoundary;
 
 const UPGRADE_INTERVAL: u32 = 30; // sim ticks between upgrade checks
@@ -12,12 +19,70 @@ pub struct UpgradeTimer {
     pub downgrade_tick: u32,
 }
 
+/// Occupancy at or above which a building wants to grow.
+const UPGRADE_OCCUPANCY: f32 = 0.75;
+
+/// Buildings the upgrade and downgrade checks consider, kept up to date from
+/// `BuildingOccupancyChanged` instead of scanning every building.
+///
+/// Sets are ordered so the per-check upgrade cap picks the same buildings
+/// every run.
+#[derive(Resource, Default)]
+pub struct UpgradeCandidates {
+    /// Buildings at `UPGRADE_OCCUPANCY` or above and below their zone's
+    /// level ceiling. Caps that vary by location or policy are checked when
+    /// the upgrade runs.
+    pub crowded: BTreeSet<Entity>,
+    /// Buildings above level 1, the only ones that can downgrade.
+    pub upgraded: BTreeSet<Entity>,
+}
+
+/// Highest level a zone can reach anywhere: the zone maximum or the FAR cap.
+pub fn level_ceiling(zone: ZoneType) -> u8 {
+    zone.max_level().min(max_level_for_far(zone) as u8)
+}
+
+/// Fold occupancy events into `UpgradeCandidates`.
+pub fn track_upgrade_candidates(
+    mut candidates: ResMut<UpgradeCandidates>,
+    mut events: EventReader<BuildingOccupancyChanged>,
+    mut removed: RemovedComponents<Building>,
+    buildings: Query<&Building>,
+) {
+    for entity in removed.read() {
+        candidates.crowded.remove(&entity);
+        candidates.upgraded.remove(&entity);
+    }
+    for event in events.read() {
+        let Ok(building) = buildings.get(event.entity) else {
+            continue;
+        };
+        let crowded = event.occupancy() >= UPGRADE_OCCUPANCY
+            && event.level < level_ceiling(building.zone_type);
+        if crowded {
+            candidates.crowded.insert(event.entity);
+        } else {
+            candidates.crowded.remove(&event.entity);
+        }
+        if event.level > 1 {
+            candidates.upgraded.insert(event.entity);
+        } else {
+            candidates.upgraded.remove(&event.entity);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn upgrade_buildings(
     stats: Res<CityStats>,
     mut timer: ResMut<UpgradeTimer>,
+    candidates: Res<UpgradeCandidates>,
     mut buildings: Query<(&mut Building, Option<&mut MixedUseBuilding>)>,
     policies: Res<crate::policies::Policies>,
     ugb: Res<UrbanGrowthBoundary>,
+    district_rules: Res<DistrictPolicyLookup>,
+    underground: Res<UndergroundLayer>,
+    parking: Res<ParkingPolicyState>,
 ) {
     timer.tick += 1;
     if timer.tick < UPGRADE_INTERVAL {
@@ -30,10 +95,13 @@ pub fn upgrade_buildings(
     let mut upgraded = 0u32;
     let max_upgrades_per_tick = 50;
 
-    for (mut building, mixed_use) in &mut buildings {
+    for &entity in &candidates.crowded {
         if upgraded >= max_upgrades_per_tick {
             break;
         }
+        let Ok((mut building, mixed_use)) = buildings.get_mut(entity) else {
+            continue;
+        };
 
         // Buildings outside the UGB cannot be upgraded (ZONE-009).
         if !ugb.allows_upgrade(building.grid_x, building.grid_y) {
@@ -41,7 +109,27 @@ pub fn upgrade_buildings(
         }
 
         let far_cap = max_level_for_far(building.zone_type) as u8;
-        let max_level = building.zone_type.max_level().min(policy_max).min(far_cap);
+        // District density caps and setbacks (existing taller buildings are
+        // grandfathered, they just stop growing).
+        let district_cap = district_rules
+            .level_cap_at(building.grid_x, building.grid_y)
+            .unwrap_or(u8::MAX);
+        // Foundations may not grow down into a shallow metro tunnel.
+        let tunnel_cap = underground
+            .level_cap_at(
+                building.grid_x,
+                building.grid_y,
+                building.zone_type,
+                parking.eliminate_minimums,
+            )
+            .unwrap_or(u8::MAX);
+        let max_level = building
+            .zone_type
+            .max_level()
+            .min(policy_max)
+            .min(far_cap)
+            .min(district_cap)
+            .min(tunnel_cap);
         if building.level >= max_level {
             continue;
         }
@@ -53,7 +141,7 @@ pub fn upgrade_buildings(
         };
 
         // Upgrade when occupancy is high and happiness is decent
-        let should_upgrade = occupancy >= 0.75 && stats.average_happiness >= 45.0;
+        let should_upgrade = occupancy >= UPGRADE_OCCUPANCY && stats.average_happiness >= 45.0;
 
         if should_upgrade {
             building.level += 1;
@@ -73,6 +161,7 @@ pub fn upgrade_buildings(
 pub fn downgrade_buildings(
     stats: Res<CityStats>,
     mut timer: ResMut<UpgradeTimer>,
+    candidates: Res<UpgradeCandidates>,
     mut buildings: Query<(&mut Building, Option<&mut MixedUseBuilding>)>,
 ) {
     timer.downgrade_tick += 1;
@@ -85,7 +174,10 @@ pub fn downgrade_buildings(
         return;
     }
 
-    for (mut building, mixed_use) in &mut buildings {
+    for &entity in &candidates.upgraded {
+        let Ok((mut building, mixed_use)) = buildings.get_mut(entity) else {
+            continue;
+        };
         if building.level <= 1 {
             continue;
         }
@@ -118,12 +210,19 @@ pub struct BuildingUpgradePlugin;
 
 impl Plugin for BuildingUpgradePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<UpgradeTimer>().add_systems(
-            FixedUpdate,
-            (upgrade_buildings, downgrade_buildings)
-                .chain()
-                .after(crate::lifecycle::emigration)
-                .in_set(crate::SimulationSet::Simulation),
-        );
+        app.init_resource::<UpgradeTimer>()
+            .init_resource::<UpgradeCandidates>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    track_upgrade_candidates,
+                    upgrade_buildings,
+                    downgrade_buildings,
+                )
+                    .chain()
+                    .after(crate::lifecycle::emigration)
+                    .after(crate::building_events::detect_occupancy_changes)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
     }
 }
@@ -12,19 +12,25 @@ use super::types::{Building, UnderConstruction};
 /// - 0.0 = halted (storm), no progress
 /// - 0.5 = half speed (rain), progress every other tick
 /// - 1.0+ = normal or faster
+///
+/// With the instant-construction sandbox toggle, buildings finish at once.
 pub fn progress_construction(
     mut commands: Commands,
     mut query: Query<(Entity, &mut Building, &mut UnderConstruction)>,
     modifiers: Res<ConstructionModifiers>,
     tick: Res<crate::TickCounter>,
+    settings: Res<crate::game_settings::GameSettings>,
 ) {
     let speed = modifiers.speed_factor;
+    let instant = settings.sandbox.instant_construction;
 
     for (entity, mut building, mut uc) in &mut query {
         // Ensure no occupants while under construction
         building.occupants = 0;
 
-        if uc.ticks_remaining > 0 {
+        if instant {
+            uc.ticks_remaining = 0;
+        } else if uc.ticks_remaining > 0 {
             // Determine whether to make progress this tick based on speed_factor.
             // speed >= 1.0: always progress (1 tick per tick)
             // 0 < speed < 1: progress on a fraction of ticks using modular arithmetic
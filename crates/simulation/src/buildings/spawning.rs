@@ -1,12 +1,15 @@
+use crate::sim_rng::SimRng;
 use bevy::prelude::*;
 use rand::seq::IteratorRandom;
 use rand::Rng;
-use crate::sim_rng::SimRng;
 
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::cumulative_zoning::{select_effective_zone, CumulativeZoningState};
+use crate::disaster_recovery::RecoveryState;
+use crate::district_policies::DistrictPolicyLookup;
 use crate::game_params::GameParams;
 use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::land_ownership::LandOwnership;
 use crate::zones::{is_adjacent_to_road, ZoneDemand};
 
 use super::types::{max_level_for_far, Building, MixedUseBuilding, UnderConstruction};
@@ -73,6 +76,9 @@ pub fn building_spawner(
     cumulative_zoning: Res<CumulativeZoningState>,
     game_params: Res<GameParams>,
     mut rng: ResMut<SimRng>,
+    district_rules: Res<DistrictPolicyLookup>,
+    land: Res<LandOwnership>,
+    recovery: Res<RecoveryState>,
 ) {
     #[cfg(feature = "trace")]
     let _span = bevy::log::info_span!("building_spawner").entered();
@@ -82,7 +88,6 @@ pub fn building_spawner(
     }
     timer.0 = 0;
 
-
     for (zone, cells) in &eligible.cells {
         if demand.demand_for(*zone) < 0.1 || cells.is_empty() {
             continue;
@@ -104,6 +109,23 @@ pub fn building_spawner(
                 continue;
             }
 
+            // Land the city holds back (not leased) stays undeveloped.
+            if !land.allows_private_development(x, y) {
+                continue;
+            }
+
+            // Disaster debris must be cleared before anything is rebuilt.
+            if recovery.has_debris(x, y) {
+                continue;
+            }
+
+            // District density cap of 0 (or a setback strip under a level-1
+            // cap) forbids new construction.
+            let district_cap = district_rules.level_cap_at(x, y).unwrap_or(u8::MAX);
+            if district_cap == 0 {
+                continue;
+            }
+
             if rng.0.gen::<f32>() > spawn_chance {
                 continue;
             }
@@ -120,7 +142,7 @@ pub fn building_spawner(
             // Cap initial level by FAR constraint (initial level is 1, but
             // max_level_for_far is guaranteed >= 1, so this is a safety check)
             let far_cap = max_level_for_far(effective_zone) as u8;
-            let initial_level = 1u8.min(far_cap);
+            let initial_level = 1u8.min(far_cap).min(district_cap);
             let capacity = Building::capacity_for_level(effective_zone, initial_level);
             let construction_ticks = game_params.building.construction_ticks;
             let entity = if effective_zone == ZoneType::MixedUse {
@@ -0,0 +1,21 @@
+//! Carbon accounting.
+//!
+//! Every slow tick the ledger adds up the CO2 emitted by fossil power
+//! plants, vehicles on the road, industrial workplaces and building heating,
+//! and what the city's trees absorbed. Every 30 days the month is closed
+//! into the ledger history.
+//!
+//! The first month with net emissions sets the baseline, and a straight
+//! line from it to zero over the chosen number of years is the city's
+//! neutrality trajectory. Emissions can be priced with a flat carbon tax or
+//! with cap-and-trade permits capped at the trajectory, whose price climbs
+//! as emissions push against the cap. Carbon revenue is collected with the
+//! monthly budget, and a carbon price dampens industrial demand.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{carbon_price_demand, close_carbon_month, sample_emissions, CarbonLedgerPlugin};
+pub use types::*;
@@ -0,0 +1,135 @@
+//! Emission sampling, monthly ledger closing and the demand response to
+//! carbon pricing.
+
+use bevy::prelude::*;
+
+use crate::biomass_power::BiomassPowerState;
+use crate::buildings::Building;
+use crate::coal_power::CoalPowerState;
+use crate::gas_power::GasPowerState;
+use crate::grid::ZoneType;
+use crate::heating_emissions::HeatingEmissionsStats;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::oil_power::OilPowerState;
+use crate::time_of_day::GameClock;
+use crate::traffic::TrafficGrid;
+use crate::tree_absorption::TreeMaturityGrid;
+use crate::zones::ZoneDemand;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Every slow tick, add what each source emitted to the current month.
+#[allow(clippy::too_many_arguments)]
+pub fn sample_emissions(
+    slow_timer: Res<SlowTickTimer>,
+    coal: Res<CoalPowerState>,
+    gas: Res<GasPowerState>,
+    oil: Res<OilPowerState>,
+    biomass: Res<BiomassPowerState>,
+    traffic: Res<TrafficGrid>,
+    heating: Res<HeatingEmissionsStats>,
+    trees: Res<TreeMaturityGrid>,
+    buildings: Query<&Building>,
+    mut ledger: ResMut<CarbonLedger>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let power =
+        coal.total_co2_tons + gas.total_co2_tons + oil.total_co2_tons + biomass.total_co2_tons;
+    let vehicles: u64 = traffic.density.iter().map(|&d| d as u64).sum();
+    let workers: u64 = buildings
+        .iter()
+        .filter(|b| b.zone_type == ZoneType::Industrial)
+        .map(|b| b.occupants as u64)
+        .sum();
+    let maturity: f64 = trees.values.iter().map(|&m| m as f64).sum();
+
+    ledger.current.add(&Emissions {
+        power: power as f64,
+        traffic: vehicles as f64 * TRAFFIC_CO2_PER_VEHICLE,
+        industry: workers as f64 * INDUSTRY_CO2_PER_WORKER,
+        heating: heating.total_emission_q as f64 * HEATING_CO2_PER_EMISSION,
+        sequestered: maturity * TREE_CO2_PER_MATURITY,
+    });
+}
+
+/// Close the ledger month, charge for its emissions and tell the player
+/// when the city drifts off or reaches its neutrality path.
+pub fn close_carbon_month(
+    clock: Res<GameClock>,
+    mut ledger: ResMut<CarbonLedger>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day < ledger.month_start_day + MONTH_DAYS {
+        return;
+    }
+    let was_on_track = ledger.last_month().is_none_or(|m| m.on_track());
+    let was_neutral = ledger
+        .last_month()
+        .is_some_and(|m| m.emissions.net() <= 0.0);
+    let month = ledger.close_month(clock.day);
+
+    if month.emissions.gross() > 0.0 && month.emissions.net() <= 0.0 && !was_neutral {
+        notifications.send(NotificationEvent {
+            text: "The city was carbon neutral this month".to_string(),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    } else if let (false, true, Some(target)) = (month.on_track(), was_on_track, month.target) {
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Emissions of {:.0} t are above the neutrality target of {:.0} t",
+                month.emissions.net(),
+                target
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+}
+
+/// Every slow tick, carbon pricing dampens industrial demand.
+pub fn carbon_price_demand(
+    slow_timer: Res<SlowTickTimer>,
+    ledger: Res<CarbonLedger>,
+    mut demand: ResMut<ZoneDemand>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let penalty = ledger.industry_demand_penalty();
+    if penalty > 0.0 {
+        demand.industrial = (demand.industrial - penalty).max(0.0);
+    }
+}
+
+pub struct CarbonLedgerPlugin;
+
+impl Plugin for CarbonLedgerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CarbonLedger>().add_systems(
+            FixedUpdate,
+            (
+                sample_emissions
+                    .after(crate::coal_power::aggregate_coal_power)
+                    .after(crate::gas_power::aggregate_gas_power)
+                    .after(crate::oil_power::aggregate_oil_power)
+                    .after(crate::biomass_power::aggregate_biomass_power),
+                close_carbon_month,
+                carbon_price_demand.after(crate::zones::update_zone_demand),
+            )
+                .chain()
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<CarbonLedger>();
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::Saveable;
+
+fn emissions(power: f64, sequestered: f64) -> Emissions {
+    Emissions {
+        power,
+        traffic: 10.0,
+        industry: 20.0,
+        heating: 5.0,
+        sequestered,
+    }
+}
+
+#[test]
+fn test_gross_and_net() {
+    let e = emissions(100.0, 15.0);
+    assert_eq!(e.gross(), 135.0);
+    assert_eq!(e.net(), 120.0);
+    assert!(emissions(0.0, 100.0).net() < 0.0);
+}
+
+#[test]
+fn test_first_emitting_month_sets_baseline() {
+    let mut ledger = CarbonLedger::default();
+    ledger.close_month(31);
+    assert_eq!(ledger.baseline, None, "an empty month sets no baseline");
+
+    ledger.current = emissions(1_000.0, 0.0);
+    let month = ledger.close_month(61);
+    assert_eq!(ledger.baseline, Some(1_035.0));
+    assert_eq!(ledger.baseline_month, 1);
+    assert_eq!(month.target, Some(1_035.0));
+    assert_eq!(ledger.current, Emissions::default());
+    assert_eq!(ledger.month_start_day, 61);
+    assert_eq!(ledger.history.len(), 2);
+}
+
+#[test]
+fn test_trajectory_reaches_zero_at_neutrality() {
+    let ledger = CarbonLedger {
+        baseline: Some(1_200.0),
+        baseline_month: 6,
+        neutrality_years: 10,
+        ..Default::default()
+    };
+    assert_eq!(ledger.target_for_month(6), Some(1_200.0));
+    assert_eq!(ledger.target_for_month(66), Some(600.0));
+    assert_eq!(ledger.neutrality_month(), Some(126));
+    assert_eq!(ledger.target_for_month(126), Some(0.0));
+    assert_eq!(ledger.target_for_month(500), Some(0.0));
+}
+
+#[test]
+fn test_no_pricing_charges_nothing() {
+    let ledger = CarbonLedger::default();
+    assert_eq!(
+        ledger.charge(&emissions(100.0, 0.0), Some(50.0)),
+        (0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_tax_charges_gross_emissions() {
+    let ledger = CarbonLedger {
+        pricing: CarbonPricing::Tax,
+        tax_rate: 0.5,
+        ..Default::default()
+    };
+    let (price, revenue) = ledger.charge(&emissions(100.0, 50.0), None);
+    assert_eq!(price, 0.5);
+    assert_eq!(revenue, 67.5);
+}
+
+#[test]
+fn test_permit_price_rises_against_cap() {
+    assert_eq!(permit_price(0.0, 100.0), 0.0);
+    assert_eq!(permit_price(100.0, 100.0), PERMIT_BASE_PRICE);
+    assert!(permit_price(200.0, 100.0) > permit_price(100.0, 100.0));
+    assert!(permit_price(50.0, 100.0) < PERMIT_BASE_PRICE);
+    assert_eq!(permit_price(1e9, 1.0), MAX_PERMIT_PRICE);
+    assert_eq!(permit_price(10.0, 0.0), MAX_PERMIT_PRICE);
+}
+
+#[test]
+fn test_closed_month_revenue_is_collected_once() {
+    let mut ledger = CarbonLedger {
+        pricing: CarbonPricing::Tax,
+        tax_rate: 1.0,
+        current: emissions(65.0, 0.0),
+        ..Default::default()
+    };
+    let month = ledger.close_month(31);
+    assert_eq!(month.revenue, 100.0);
+    assert_eq!(ledger.last_price, 1.0);
+    assert_eq!(ledger.collect(), 100.0);
+    assert_eq!(ledger.collect(), 0.0);
+    assert_eq!(ledger.total_revenue, 100.0);
+}
+
+#[test]
+fn test_on_track() {
+    let mut month = MonthlyEmissions {
+        emissions: emissions(100.0, 0.0),
+        ..Default::default()
+    };
+    assert!(month.on_track(), "no target yet");
+    month.target = Some(200.0);
+    assert!(month.on_track());
+    month.target = Some(100.0);
+    assert!(!month.on_track());
+}
+
+#[test]
+fn test_industry_penalty_capped() {
+    let mut ledger = CarbonLedger::default();
+    assert_eq!(ledger.industry_demand_penalty(), 0.0);
+    ledger.last_price = 0.1;
+    assert!(ledger.industry_demand_penalty() > 0.0);
+    ledger.last_price = MAX_CARBON_TAX * 10.0;
+    assert_eq!(
+        ledger.industry_demand_penalty(),
+        MAX_INDUSTRY_DEMAND_PENALTY
+    );
+}
+
+#[test]
+fn test_history_capped() {
+    let mut ledger = CarbonLedger::default();
+    for day in 0..MAX_LEDGER_MONTHS as u32 + 5 {
+        ledger.close_month(day);
+    }
+    assert_eq!(ledger.history.len(), MAX_LEDGER_MONTHS);
+    assert_eq!(ledger.history[0].month, 5);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(CarbonLedger::default().save_to_bytes().is_none());
+    let mut ledger = CarbonLedger {
+        pricing: CarbonPricing::CapAndTrade,
+        current: emissions(40.0, 1.0),
+        ..Default::default()
+    };
+    ledger.close_month(31);
+    ledger.current = emissions(12.0, 3.0);
+    let bytes = ledger.save_to_bytes().expect("non-default ledger saves");
+    assert_eq!(CarbonLedger::load_from_bytes(&bytes), ledger);
+}
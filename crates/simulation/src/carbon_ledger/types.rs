@@ -0,0 +1,300 @@
+//! Per-source emissions, the monthly carbon ledger and carbon pricing.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Length of a ledger month in game days.
+pub const MONTH_DAYS: u32 = 30;
+
+/// Closed months kept in the ledger history.
+pub const MAX_LEDGER_MONTHS: usize = 240;
+
+/// Tons of CO2 per vehicle on the road, per slow tick.
+pub const TRAFFIC_CO2_PER_VEHICLE: f64 = 0.01;
+
+/// Tons of CO2 per industrial worker, per slow tick.
+pub const INDUSTRY_CO2_PER_WORKER: f64 = 0.02;
+
+/// Tons of CO2 per unit of heating emission, per slow tick.
+pub const HEATING_CO2_PER_EMISSION: f64 = 0.02;
+
+/// Tons of CO2 a fully mature tree absorbs per slow tick.
+pub const TREE_CO2_PER_MATURITY: f64 = 0.005;
+
+/// Carbon tax rate a new city starts with ($ per ton).
+pub const DEFAULT_CARBON_TAX: f64 = 0.10;
+
+/// Highest carbon tax the council can set ($ per ton).
+pub const MAX_CARBON_TAX: f64 = 1.0;
+
+/// Permit price when emissions sit exactly at the cap ($ per ton).
+pub const PERMIT_BASE_PRICE: f64 = 0.05;
+
+/// Ceiling on the permit price ($ per ton).
+pub const MAX_PERMIT_PRICE: f64 = 0.5;
+
+/// Years from the baseline month to carbon neutrality, by default.
+pub const DEFAULT_NEUTRALITY_YEARS: u32 = 25;
+
+/// Industrial demand lost per $/ton of carbon price.
+pub const INDUSTRY_DEMAND_PENALTY_PER_DOLLAR: f32 = 0.3;
+
+/// Cap on the industrial demand lost to carbon pricing.
+pub const MAX_INDUSTRY_DEMAND_PENALTY: f32 = 0.2;
+
+// ---------------------------------------------------------------------------
+// Emissions
+// ---------------------------------------------------------------------------
+
+/// Tons of CO2 by source, plus what the city's trees absorbed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Emissions {
+    pub power: f64,
+    pub traffic: f64,
+    pub industry: f64,
+    pub heating: f64,
+    pub sequestered: f64,
+}
+
+impl Emissions {
+    /// Everything emitted, before sequestration.
+    pub fn gross(&self) -> f64 {
+        self.power + self.traffic + self.industry + self.heating
+    }
+
+    /// Emissions after sequestration; negative when trees absorb more than
+    /// the city emits.
+    pub fn net(&self) -> f64 {
+        self.gross() - self.sequestered
+    }
+
+    pub fn add(&mut self, other: &Emissions) {
+        self.power += other.power;
+        self.traffic += other.traffic;
+        self.industry += other.industry;
+        self.heating += other.heating;
+        self.sequestered += other.sequestered;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pricing
+// ---------------------------------------------------------------------------
+
+/// How the city prices the emissions of its power plants, industry,
+/// vehicles and boilers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum CarbonPricing {
+    #[default]
+    None,
+    /// A flat price per ton.
+    Tax,
+    /// Permits capped at the neutrality trajectory; the permit price rises
+    /// as emissions push against the cap.
+    CapAndTrade,
+}
+
+impl CarbonPricing {
+    pub const ALL: [CarbonPricing; 3] = [
+        CarbonPricing::None,
+        CarbonPricing::Tax,
+        CarbonPricing::CapAndTrade,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CarbonPricing::None => "None",
+            CarbonPricing::Tax => "Carbon Tax",
+            CarbonPricing::CapAndTrade => "Cap and Trade",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            CarbonPricing::None => "Emissions are free",
+            CarbonPricing::Tax => "Emitters pay a flat rate per ton",
+            CarbonPricing::CapAndTrade => {
+                "Permits capped at the neutrality trajectory, priced by demand"
+            }
+        }
+    }
+}
+
+/// Permit price for `emissions` against a monthly `cap`.
+pub fn permit_price(emissions: f64, cap: f64) -> f64 {
+    if emissions <= 0.0 {
+        return 0.0;
+    }
+    if cap <= 0.0 {
+        return MAX_PERMIT_PRICE;
+    }
+    (PERMIT_BASE_PRICE * emissions / cap).min(MAX_PERMIT_PRICE)
+}
+
+// ---------------------------------------------------------------------------
+// Ledger
+// ---------------------------------------------------------------------------
+
+/// One closed month of the ledger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct MonthlyEmissions {
+    /// Month number since the ledger started.
+    pub month: u32,
+    pub emissions: Emissions,
+    /// Net emissions allowed by the neutrality trajectory, once a baseline
+    /// is set.
+    pub target: Option<f64>,
+    /// Carbon price paid per ton.
+    pub price: f64,
+    pub revenue: f64,
+}
+
+impl MonthlyEmissions {
+    pub fn on_track(&self) -> bool {
+        self.target.is_none_or(|t| self.emissions.net() <= t)
+    }
+}
+
+/// City-wide carbon accounting: emissions by source this month, closed
+/// months, the path to neutrality and how emissions are priced.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct CarbonLedger {
+    /// Emissions so far this month.
+    pub current: Emissions,
+    pub month_start_day: u32,
+    pub months_closed: u32,
+    /// Closed months, oldest first.
+    pub history: Vec<MonthlyEmissions>,
+    /// Net emissions of the first month the city emitted anything; the
+    /// neutrality trajectory starts here.
+    pub baseline: Option<f64>,
+    pub baseline_month: u32,
+    /// Years from the baseline to net zero.
+    pub neutrality_years: u32,
+    pub pricing: CarbonPricing,
+    /// Carbon tax rate ($ per ton).
+    pub tax_rate: f64,
+    /// Price per ton charged last month.
+    pub last_price: f64,
+    /// Revenue not yet collected into the budget.
+    pub pending_revenue: f64,
+    pub total_revenue: f64,
+}
+
+impl Default for CarbonLedger {
+    fn default() -> Self {
+        Self {
+            current: Emissions::default(),
+            month_start_day: 1,
+            months_closed: 0,
+            history: Vec::new(),
+            baseline: None,
+            baseline_month: 0,
+            neutrality_years: DEFAULT_NEUTRALITY_YEARS,
+            pricing: CarbonPricing::None,
+            tax_rate: DEFAULT_CARBON_TAX,
+            last_price: 0.0,
+            pending_revenue: 0.0,
+            total_revenue: 0.0,
+        }
+    }
+}
+
+impl CarbonLedger {
+    /// Net emissions allowed in `month`: a straight line from the baseline
+    /// down to zero at the neutrality date.
+    pub fn target_for_month(&self, month: u32) -> Option<f64> {
+        let baseline = self.baseline?;
+        let span = (self.neutrality_years * 12).max(1) as f64;
+        let elapsed = month.saturating_sub(self.baseline_month) as f64;
+        Some(baseline.max(0.0) * (1.0 - elapsed / span).max(0.0))
+    }
+
+    /// Month in which the trajectory reaches net zero.
+    pub fn neutrality_month(&self) -> Option<u32> {
+        self.baseline
+            .map(|_| self.baseline_month + self.neutrality_years * 12)
+    }
+
+    /// Price per ton and revenue for a month's emissions under the current
+    /// pricing policy.
+    pub fn charge(&self, emissions: &Emissions, target: Option<f64>) -> (f64, f64) {
+        let gross = emissions.gross();
+        let price = match self.pricing {
+            CarbonPricing::None => 0.0,
+            CarbonPricing::Tax => self.tax_rate,
+            CarbonPricing::CapAndTrade => permit_price(gross, target.unwrap_or(gross)),
+        };
+        (price, gross * price)
+    }
+
+    /// Close the current month on `day`: record it, set the baseline if
+    /// this is the first month with emissions, and charge for the month's
+    /// emissions.
+    pub fn close_month(&mut self, day: u32) -> MonthlyEmissions {
+        let month = self.months_closed;
+        let emissions = std::mem::take(&mut self.current);
+        if self.baseline.is_none() && emissions.net() > 0.0 {
+            self.baseline = Some(emissions.net());
+            self.baseline_month = month;
+        }
+        let target = self.target_for_month(month);
+        let (price, revenue) = self.charge(&emissions, target);
+
+        let entry = MonthlyEmissions {
+            month,
+            emissions,
+            target,
+            price,
+            revenue,
+        };
+        self.history.push(entry);
+        if self.history.len() > MAX_LEDGER_MONTHS {
+            let excess = self.history.len() - MAX_LEDGER_MONTHS;
+            self.history.drain(..excess);
+        }
+        self.months_closed += 1;
+        self.month_start_day = day;
+        self.last_price = price;
+        self.pending_revenue += revenue;
+        self.total_revenue += revenue;
+        entry
+    }
+
+    /// Take the revenue raised since the last budget collection.
+    pub fn collect(&mut self) -> f64 {
+        std::mem::take(&mut self.pending_revenue)
+    }
+
+    pub fn last_month(&self) -> Option<&MonthlyEmissions> {
+        self.history.last()
+    }
+
+    /// Industrial demand lost to the carbon price.
+    pub fn industry_demand_penalty(&self) -> f32 {
+        (self.last_price as f32 * INDUSTRY_DEMAND_PENALTY_PER_DOLLAR)
+            .min(MAX_INDUSTRY_DEMAND_PENALTY)
+    }
+}
+
+impl Saveable for CarbonLedger {
+    const SAVE_KEY: &'static str = "carbon_ledger";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,496 @@
+//! Chunked storage for per-cell grid layers.
+//!
+//! Cells are stored in square chunks of `STORAGE_CHUNK_SIZE` rather than
+//! one flat row-major vector, so systems can skip whole regions at a time.
+//! Each chunk carries a revision counter that is bumped on every mutable
+//! access; consumers that remember the revisions they last saw can
+//! reprocess only dirty chunks.
+//!
+//! A `ChunkedGrid` can be any size, but the game map is not: the world grid
+//! and every layer are still created at the compile-time `GRID_WIDTH` x
+//! `GRID_HEIGHT`, and most systems index with those constants. Storage in
+//! chunks is the groundwork for larger maps; making the map size a runtime
+//! setting is tracked separately (FEAT-035).
+//!
+//! For existing code the grid still behaves like a row-major slice:
+//! `grid[y * width + x]`, `iter()`, `iter_mut()` and `len()` all use
+//! row-major order.
+
+use std::ops::{Index, IndexMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Side length of a storage chunk, in cells. A power of two so cell
+/// lookups compile to shifts and masks.
+pub const STORAGE_CHUNK_SIZE: usize = 32;
+
+const CHUNK_AREA: usize = STORAGE_CHUNK_SIZE * STORAGE_CHUNK_SIZE;
+
+/// Source of `ChunkedGrid::instance` ids.
+static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
+
+/// One square block of cells, row-major within the block. Chunks on the
+/// right and bottom edges of a grid whose size is not a multiple of the
+/// chunk size carry unused padding.
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    cells: Vec<T>,
+    revision: u32,
+}
+
+/// A `width` x `height` grid of `T` stored in square chunks.
+#[derive(Debug, Clone)]
+pub struct ChunkedGrid<T> {
+    chunks: Vec<Chunk<T>>,
+    width: usize,
+    height: usize,
+    chunks_x: usize,
+    chunks_y: usize,
+    instance: u64,
+}
+
+impl<T: Clone> ChunkedGrid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        let chunks_x = width.div_ceil(STORAGE_CHUNK_SIZE);
+        let chunks_y = height.div_ceil(STORAGE_CHUNK_SIZE);
+        let chunk = Chunk {
+            cells: vec![fill; CHUNK_AREA],
+            revision: 0,
+        };
+        Self {
+            chunks: vec![chunk; chunks_x * chunks_y],
+            width,
+            height,
+            chunks_x,
+            chunks_y,
+            instance: NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// A grid from row-major `cells`; missing cells are filled with
+    /// `T::default()` and extra cells are dropped.
+    pub fn from_row_major(width: usize, height: usize, cells: Vec<T>) -> Self
+    where
+        T: Default,
+    {
+        let mut grid = Self::new(width, height, T::default());
+        for (i, value) in cells.into_iter().take(width * height).enumerate() {
+            grid[i] = value;
+        }
+        grid
+    }
+
+    /// Set every cell in chunk `ci` to `value`.
+    pub fn fill_chunk(&mut self, ci: usize, value: T) {
+        let chunk = &mut self.chunks[ci];
+        chunk.cells.fill(value);
+        chunk.revision = chunk.revision.wrapping_add(1);
+    }
+
+    /// Set every cell to `value`.
+    pub fn fill(&mut self, value: T) {
+        for ci in 0..self.chunks.len() {
+            self.fill_chunk(ci, value.clone());
+        }
+    }
+
+    /// The cells in row-major order.
+    pub fn to_row_major(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    /// Overwrite every cell from row-major `values`, bumping the revision
+    /// of only the chunks whose contents change, so layers rewritten in
+    /// full every update still tell their consumers which regions moved.
+    pub fn assign_row_major(&mut self, values: &[T])
+    where
+        T: PartialEq,
+    {
+        debug_assert_eq!(values.len(), self.len());
+        let width = self.width;
+        for ci in 0..self.chunks.len() {
+            let (x0, y0, x1, y1) = self.chunk_bounds(ci);
+            let chunk = &mut self.chunks[ci];
+            let row = |y: usize| {
+                let start = (y - y0) * STORAGE_CHUNK_SIZE;
+                start..start + x1 - x0
+            };
+            let source = |y: usize| y * width + x0..y * width + x1;
+            if (y0..y1).all(|y| chunk.cells[row(y)] == values[source(y)]) {
+                continue;
+            }
+            for y in y0..y1 {
+                chunk.cells[row(y)].clone_from_slice(&values[source(y)]);
+            }
+            chunk.revision = chunk.revision.wrapping_add(1);
+        }
+    }
+}
+
+impl<T> ChunkedGrid<T> {
+    /// Identifies the constructed grid; clones share it. Revisions only
+    /// compare within one instance, since a grid that replaces another
+    /// starts its revisions over.
+    pub fn instance(&self) -> u64 {
+        self.instance
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells (excluding chunk padding).
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn locate(&self, x: usize, y: usize) -> (usize, usize) {
+        let ci = (y / STORAGE_CHUNK_SIZE) * self.chunks_x + x / STORAGE_CHUNK_SIZE;
+        let local = (y % STORAGE_CHUNK_SIZE) * STORAGE_CHUNK_SIZE + x % STORAGE_CHUNK_SIZE;
+        (ci, local)
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> &T {
+        debug_assert!(x < self.width && y < self.height);
+        let (ci, local) = self.locate(x, y);
+        &self.chunks[ci].cells[local]
+    }
+
+    /// Mutable access to a cell; marks its chunk dirty.
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut T {
+        debug_assert!(x < self.width && y < self.height);
+        let (ci, local) = self.locate(x, y);
+        let chunk = &mut self.chunks[ci];
+        chunk.revision = chunk.revision.wrapping_add(1);
+        &mut chunk.cells[local]
+    }
+
+    // -----------------------------------------------------------------------
+    // Chunks
+    // -----------------------------------------------------------------------
+
+    pub fn chunks_x(&self) -> usize {
+        self.chunks_x
+    }
+
+    pub fn chunks_y(&self) -> usize {
+        self.chunks_y
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Index of the chunk holding (`x`, `y`).
+    pub fn chunk_of(&self, x: usize, y: usize) -> usize {
+        self.locate(x, y).0
+    }
+
+    /// Cell bounds of chunk `ci`: `(x0, y0, x1, y1)`, end-exclusive.
+    pub fn chunk_bounds(&self, ci: usize) -> (usize, usize, usize, usize) {
+        let x0 = (ci % self.chunks_x) * STORAGE_CHUNK_SIZE;
+        let y0 = (ci / self.chunks_x) * STORAGE_CHUNK_SIZE;
+        (
+            x0,
+            y0,
+            (x0 + STORAGE_CHUNK_SIZE).min(self.width),
+            (y0 + STORAGE_CHUNK_SIZE).min(self.height),
+        )
+    }
+
+    /// Counter bumped on every mutable access to chunk `ci`. Compare with a
+    /// previously seen value to tell whether the chunk may have changed.
+    pub fn chunk_revision(&self, ci: usize) -> u32 {
+        self.chunks[ci].revision
+    }
+
+    /// The cells of chunk `ci` with their grid coordinates.
+    pub fn chunk_cells(&self, ci: usize) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        let (x0, y0, x1, y1) = self.chunk_bounds(ci);
+        let cells = &self.chunks[ci].cells;
+        (y0..y1).flat_map(move |y| {
+            (x0..x1).map(move |x| {
+                let local = (y - y0) * STORAGE_CHUNK_SIZE + (x - x0);
+                (x, y, &cells[local])
+            })
+        })
+    }
+
+    /// Whether any cell of chunk `ci` satisfies `pred`.
+    pub fn chunk_any(&self, ci: usize, mut pred: impl FnMut(&T) -> bool) -> bool {
+        self.chunk_cells(ci).any(|(_, _, v)| pred(v))
+    }
+
+    // -----------------------------------------------------------------------
+    // Row-major iteration
+    // -----------------------------------------------------------------------
+
+    /// The part of row `y` that lies in chunk column `cx`.
+    fn row_segment(&self, y: usize, cx: usize) -> &[T] {
+        let ci = (y / STORAGE_CHUNK_SIZE) * self.chunks_x + cx;
+        let start = (y % STORAGE_CHUNK_SIZE) * STORAGE_CHUNK_SIZE;
+        let len = (self.width - cx * STORAGE_CHUNK_SIZE).min(STORAGE_CHUNK_SIZE);
+        &self.chunks[ci].cells[start..start + len]
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            grid: self,
+            y: 0,
+            cx: 0,
+            row: [].iter(),
+        }
+    }
+
+    /// All cells in row-major order, mutably. Marks every chunk dirty.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (width, chunks_x) = (self.width, self.chunks_x);
+        let mut rows: Vec<(usize, usize, &mut [T])> = Vec::with_capacity(self.height * chunks_x);
+        for (ci, chunk) in self.chunks.iter_mut().enumerate() {
+            chunk.revision = chunk.revision.wrapping_add(1);
+            let (cx, cy) = (ci % chunks_x, ci / chunks_x);
+            let row_len = (width - cx * STORAGE_CHUNK_SIZE).min(STORAGE_CHUNK_SIZE);
+            let rows_here = (self.height - cy * STORAGE_CHUNK_SIZE).min(STORAGE_CHUNK_SIZE);
+            for (ly, row) in chunk
+                .cells
+                .chunks_mut(STORAGE_CHUNK_SIZE)
+                .take(rows_here)
+                .enumerate()
+            {
+                rows.push((cy * STORAGE_CHUNK_SIZE + ly, cx, &mut row[..row_len]));
+            }
+        }
+        rows.sort_unstable_by_key(|&(y, cx, _)| (y, cx));
+        rows.into_iter()
+            .map(|(_, _, row)| row)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Grids are equal when their dimensions and cells match; revisions are
+/// bookkeeping and do not take part.
+impl<T: PartialEq> PartialEq for ChunkedGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.iter().eq(other.iter())
+    }
+}
+
+/// Row-major iterator over a `ChunkedGrid`.
+pub struct Iter<'a, T> {
+    grid: &'a ChunkedGrid<T>,
+    y: usize,
+    cx: usize,
+    row: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(value) = self.row.next() {
+                return Some(value);
+            }
+            if self.y >= self.grid.height || self.grid.chunks_x == 0 {
+                return None;
+            }
+            self.row = self.grid.row_segment(self.y, self.cx).iter();
+            self.cx += 1;
+            if self.cx == self.grid.chunks_x {
+                self.cx = 0;
+                self.y += 1;
+            }
+        }
+    }
+}
+
+/// Row-major mutable iterator over a `ChunkedGrid`.
+pub type IterMut<'a, T> = std::iter::Flatten<std::vec::IntoIter<&'a mut [T]>>;
+
+impl<'a, T> IntoIterator for &'a ChunkedGrid<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ChunkedGrid<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Row-major indexing: `grid[y * width + x]`.
+impl<T> Index<usize> for ChunkedGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &T {
+        self.get(idx % self.width, idx / self.width)
+    }
+}
+
+impl<T> IndexMut<usize> for ChunkedGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        let width = self.width;
+        self.get_mut(idx % width, idx / width)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Serde: serialized as dimensions plus row-major cells
+// ---------------------------------------------------------------------------
+
+#[derive(Serialize)]
+struct RowMajorRef<'a, T> {
+    width: usize,
+    height: usize,
+    cells: Vec<&'a T>,
+}
+
+#[derive(Deserialize)]
+struct RowMajor<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Serialize> Serialize for ChunkedGrid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RowMajorRef {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone + Default> Deserialize<'de> for ChunkedGrid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RowMajor::<T>::deserialize(deserializer)?;
+        Ok(Self::from_row_major(raw.width, raw.height, raw.cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> ChunkedGrid<u32> {
+        let cells = (0..(width * height) as u32).collect();
+        ChunkedGrid::from_row_major(width, height, cells)
+    }
+
+    #[test]
+    fn test_row_major_roundtrip_with_partial_chunks() {
+        let grid = numbered(70, 45);
+        assert_eq!(grid.len(), 70 * 45);
+        assert_eq!((grid.chunks_x(), grid.chunks_y()), (3, 2));
+        assert_eq!(*grid.get(69, 44), 70 * 45 - 1);
+        assert_eq!(grid[70 * 10 + 33], 70 * 10 + 33);
+        let cells = grid.to_row_major();
+        assert!(cells.iter().enumerate().all(|(i, &v)| v == i as u32));
+    }
+
+    #[test]
+    fn test_iter_mut_is_row_major() {
+        let mut grid = ChunkedGrid::new(40, 35, 0u32);
+        for (i, v) in grid.iter_mut().enumerate() {
+            *v = i as u32;
+        }
+        assert_eq!(grid, numbered(40, 35));
+    }
+
+    #[test]
+    fn test_mutation_bumps_only_its_chunk() {
+        let mut grid = ChunkedGrid::new(64, 64, 0u8);
+        let before: Vec<u32> = (0..grid.chunk_count())
+            .map(|ci| grid.chunk_revision(ci))
+            .collect();
+        *grid.get_mut(40, 5) = 7;
+        grid[63 * 64] = 1;
+        let changed: Vec<usize> = (0..grid.chunk_count())
+            .filter(|&ci| grid.chunk_revision(ci) != before[ci])
+            .collect();
+        assert_eq!(changed, vec![grid.chunk_of(40, 5), grid.chunk_of(0, 63)]);
+    }
+
+    #[test]
+    fn test_chunk_cells_cover_the_chunk() {
+        let grid = numbered(50, 50);
+        let ci = grid.chunk_of(45, 45);
+        assert_eq!(grid.chunk_bounds(ci), (32, 32, 50, 50));
+        let cells: Vec<_> = grid.chunk_cells(ci).collect();
+        assert_eq!(cells.len(), 18 * 18);
+        assert!(cells.iter().all(|&(x, y, &v)| v == (y * 50 + x) as u32));
+        assert!(grid.chunk_any(ci, |&v| v == 49 * 50 + 49));
+        assert!(!grid.chunk_any(ci, |&v| v == 0));
+    }
+
+    #[test]
+    fn test_fill_chunk() {
+        let mut grid = ChunkedGrid::new(64, 32, 5u8);
+        grid.fill_chunk(1, 0);
+        assert_eq!(*grid.get(31, 0), 5);
+        assert_eq!(*grid.get(32, 0), 0);
+        assert_eq!(grid.iter().filter(|&&v| v == 0).count(), 32 * 32);
+    }
+
+    #[test]
+    fn test_assign_row_major_bumps_only_changed_chunks() {
+        let mut grid = numbered(50, 40);
+        let before: Vec<u32> = (0..grid.chunk_count())
+            .map(|ci| grid.chunk_revision(ci))
+            .collect();
+        let mut values = grid.to_row_major();
+        values[38 * 50 + 45] = 0;
+        grid.assign_row_major(&values);
+
+        assert_eq!(grid.to_row_major(), values);
+        let changed: Vec<usize> = (0..grid.chunk_count())
+            .filter(|&ci| grid.chunk_revision(ci) != before[ci])
+            .collect();
+        assert_eq!(changed, vec![grid.chunk_of(45, 38)]);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let grid = numbered(33, 9);
+        let json = serde_json::to_string(&grid).unwrap();
+        let back: ChunkedGrid<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, grid);
+    }
+
+    #[test]
+    fn test_large_maps() {
+        for size in [512, 1024] {
+            let mut grid = ChunkedGrid::new(size, size, 0u8);
+            *grid.get_mut(size - 1, size - 1) = 9;
+            assert_eq!(grid.chunk_count(), (size / STORAGE_CHUNK_SIZE).pow(2));
+            assert_eq!(grid[size * size - 1], 9);
+        }
+    }
+}
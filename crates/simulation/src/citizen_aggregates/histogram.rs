@@ -0,0 +1,89 @@
+//! Fixed-bin histogram used by the citizen aggregates.
+
+use serde::{Deserialize, Serialize};
+
+/// Counts of samples falling into fixed bins, plus a running sum for the mean.
+///
+/// `edges` holds the lower bound of each bin; the last bin is open-ended and
+/// samples below the first edge are counted in the first bin.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    pub edges: Vec<f32>,
+    pub counts: Vec<u32>,
+    pub sum: f64,
+    pub total: u32,
+}
+
+impl Histogram {
+    pub fn new(edges: &[f32]) -> Self {
+        Self {
+            edges: edges.to_vec(),
+            counts: vec![0; edges.len()],
+            sum: 0.0,
+            total: 0,
+        }
+    }
+
+    /// Index of the bin a value falls into.
+    pub fn bin_of(&self, value: f32) -> usize {
+        self.edges
+            .iter()
+            .rposition(|&edge| value >= edge)
+            .unwrap_or(0)
+    }
+
+    pub fn add(&mut self, value: f32) {
+        if self.counts.is_empty() {
+            return;
+        }
+        let bin = self.bin_of(value);
+        self.counts[bin] += 1;
+        self.sum += value as f64;
+        self.total += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.counts.iter_mut().for_each(|c| *c = 0);
+        self.sum = 0.0;
+        self.total = 0;
+    }
+
+    pub fn mean(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            (self.sum / self.total as f64) as f32
+        }
+    }
+
+    /// Lower edge of the bin containing the given percentile (0..1).
+    pub fn percentile(&self, p: f32) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let target = (p.clamp(0.0, 1.0) * self.total as f32).ceil().max(1.0) as u32;
+        let mut seen = 0;
+        for (i, &count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return self.edges[i];
+            }
+        }
+        self.edges.last().copied().unwrap_or(0.0)
+    }
+
+    /// Share of samples in each bin (0..1).
+    pub fn fractions(&self) -> Vec<f32> {
+        let total = self.total.max(1) as f32;
+        self.counts.iter().map(|&c| c as f32 / total).collect()
+    }
+
+    /// Human-readable label for a bin, e.g. "20-40" or "80+".
+    pub fn bin_label(&self, bin: usize) -> String {
+        let lo = self.edges[bin];
+        match self.edges.get(bin + 1) {
+            Some(&hi) => format!("{:.0}-{:.0}", lo, hi),
+            None => format!("{:.0}+", lo),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Bulk citizen statistics shared by UI dashboards and the agent API.
+//!
+//! `CitizenAggregates` holds histograms of age, education, income, happiness
+//! and commute time for the whole city and for each player district. They are
+//! rebuilt in a single pass over citizen components once per slow tick, so panels and
+//! the observation builder read cached numbers instead of iterating the full
+//! population every frame.
+//!
+//! Commute times are estimated from home/work distance and the citizen's
+//! chosen transport mode with the same distance model used by mode choice.
+
+pub mod histogram;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use histogram::Histogram;
+pub use systems::{update_citizen_aggregates, CitizenAggregatesPlugin};
+pub use types::*;
@@ -0,0 +1,71 @@
+//! Slow-tick rebuild of the citizen aggregates.
+
+use bevy::prelude::*;
+
+use crate::citizen::{Citizen, CitizenDetails, HomeLocation, WorkLocation};
+use crate::districts::DistrictMap;
+use crate::mode_choice::{ChosenTransportMode, TransportMode};
+use crate::TickCounter;
+
+use super::types::*;
+
+/// Rebuild all histograms in one pass over the citizen population.
+pub fn update_citizen_aggregates(
+    slow_timer: Res<crate::SlowTickTimer>,
+    tick: Res<TickCounter>,
+    district_map: Res<DistrictMap>,
+    citizens: Query<
+        (
+            &CitizenDetails,
+            Option<&HomeLocation>,
+            Option<&WorkLocation>,
+            Option<&ChosenTransportMode>,
+        ),
+        With<Citizen>,
+    >,
+    mut aggregates: ResMut<CitizenAggregates>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let aggregates = &mut *aggregates;
+    aggregates.city.clear();
+    aggregates
+        .districts
+        .resize_with(district_map.districts.len(), Default::default);
+    aggregates.districts.iter_mut().for_each(|d| d.clear());
+
+    for (details, home, work, mode) in &citizens {
+        let job = work.map(|work| {
+            let commute = home.map_or(0.0, |home| {
+                commute_minutes(
+                    (home.grid_x, home.grid_y),
+                    (work.grid_x, work.grid_y),
+                    mode.map_or(TransportMode::Drive, |m| m.0),
+                )
+            });
+            (details.salary, commute)
+        });
+        aggregates
+            .city
+            .record(details.age, details.education, details.happiness, job);
+
+        let district = home.and_then(|h| district_map.get_district_index_at(h.grid_x, h.grid_y));
+        if let Some(d) = district.and_then(|i| aggregates.districts.get_mut(i)) {
+            d.record(details.age, details.education, details.happiness, job);
+        }
+    }
+    aggregates.updated_tick = tick.0;
+}
+
+pub struct CitizenAggregatesPlugin;
+
+impl Plugin for CitizenAggregatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CitizenAggregates>().add_systems(
+            FixedUpdate,
+            update_citizen_aggregates.in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
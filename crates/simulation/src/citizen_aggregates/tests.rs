@@ -0,0 +1,55 @@
+use super::*;
+use crate::mode_choice::TransportMode;
+
+#[test]
+fn histogram_bins_and_mean() {
+    let mut h = Histogram::new(&[0.0, 10.0, 20.0]);
+    for v in [-5.0, 3.0, 10.0, 15.0, 25.0, 100.0] {
+        h.add(v);
+    }
+    assert_eq!(h.counts, vec![2, 2, 2]);
+    assert_eq!(h.total, 6);
+    assert!((h.mean() - 148.0 / 6.0).abs() < 1e-4);
+    assert_eq!(h.bin_label(1), "10-20");
+    assert_eq!(h.bin_label(2), "20+");
+
+    h.clear();
+    assert_eq!(h.total, 0);
+    assert_eq!(h.counts, vec![0, 0, 0]);
+    assert_eq!(h.mean(), 0.0);
+}
+
+#[test]
+fn histogram_percentile_returns_bin_edge() {
+    let mut h = Histogram::new(&[0.0, 10.0, 20.0]);
+    for v in [1.0, 2.0, 3.0, 12.0, 25.0] {
+        h.add(v);
+    }
+    assert_eq!(h.percentile(0.5), 0.0);
+    assert_eq!(h.percentile(0.8), 10.0);
+    assert_eq!(h.percentile(1.0), 20.0);
+    assert_eq!(Histogram::new(&[0.0]).percentile(0.5), 0.0);
+}
+
+#[test]
+fn commute_is_slower_on_foot_than_by_car() {
+    let walk = commute_minutes((0, 0), (50, 50), TransportMode::Walk);
+    let drive = commute_minutes((0, 0), (50, 50), TransportMode::Drive);
+    assert!(walk > drive);
+    // 100 cells on foot at 250 cells/hour.
+    assert!((walk - 24.0).abs() < 0.01);
+}
+
+#[test]
+fn record_only_counts_jobs_for_employed() {
+    let mut agg = DemographicAggregate::default();
+    agg.record(30, 3, 70.0, Some((2500.0, 15.0)));
+    agg.record(10, 1, 90.0, None);
+    assert_eq!(agg.population, 2);
+    assert_eq!(agg.employed, 1);
+    assert_eq!(agg.income.total, 1);
+    assert_eq!(agg.commute.total, 1);
+    assert_eq!(agg.age.total, 2);
+    assert_eq!(agg.education.counts, vec![0, 1, 0, 1]);
+    assert!((agg.employment_rate() - 0.5).abs() < 1e-6);
+}
@@ -0,0 +1,136 @@
+//! Aggregate resource, bin layouts and the commute-time estimate.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::metro_transit::WALK_SPEED_CELLS_PER_HOUR;
+use crate::mode_choice::{
+    manhattan_distance, TransportMode, DRIVE_PARKING_OVERHEAD, TRANSIT_WAIT_OVERHEAD,
+    WALK_SPEED_MULTIPLIER,
+};
+
+use super::histogram::Histogram;
+
+// ---------------------------------------------------------------------------
+// Bin layouts
+// ---------------------------------------------------------------------------
+
+/// Age bins in years: children, young adults, adults, middle age, seniors.
+pub const AGE_BINS: [f32; 6] = [0.0, 18.0, 30.0, 45.0, 65.0, 80.0];
+
+/// Education level bins (0 = none, 1 = elementary, 2 = high school,
+/// 3 = university).
+pub const EDUCATION_BINS: [f32; 4] = [0.0, 1.0, 2.0, 3.0];
+
+/// Monthly salary bins.
+pub const INCOME_BINS: [f32; 6] = [0.0, 1000.0, 2000.0, 3500.0, 5000.0, 8000.0];
+
+/// Happiness bins (0-100).
+pub const HAPPINESS_BINS: [f32; 5] = [0.0, 20.0, 40.0, 60.0, 80.0];
+
+/// One-way commute bins in minutes.
+pub const COMMUTE_BINS: [f32; 6] = [0.0, 10.0, 20.0, 30.0, 45.0, 60.0];
+
+/// Travel speed of the baseline (driving) mode, derived from walking speed.
+pub const BASE_TRAVEL_CELLS_PER_HOUR: f32 = WALK_SPEED_CELLS_PER_HOUR / WALK_SPEED_MULTIPLIER;
+
+/// Estimated one-way commute in game minutes for a trip by the given mode.
+///
+/// Uses the same distance model as mode choice: Manhattan distance plus the
+/// parking / transit-wait overheads, at the mode's share of the base speed.
+pub fn commute_minutes(home: (usize, usize), work: (usize, usize), mode: TransportMode) -> f32 {
+    let overhead = match mode {
+        TransportMode::Drive => DRIVE_PARKING_OVERHEAD,
+        TransportMode::Transit => TRANSIT_WAIT_OVERHEAD,
+        TransportMode::Walk | TransportMode::Bike => 0.0,
+    };
+    let cells = manhattan_distance(home, work) + overhead;
+    cells / (BASE_TRAVEL_CELLS_PER_HOUR * mode.speed_multiplier()) * 60.0
+}
+
+// ---------------------------------------------------------------------------
+// Aggregates
+// ---------------------------------------------------------------------------
+
+/// Histograms over one group of citizens (the whole city or one district).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemographicAggregate {
+    pub population: u32,
+    pub employed: u32,
+    pub age: Histogram,
+    pub education: Histogram,
+    /// Monthly salary of employed citizens.
+    pub income: Histogram,
+    pub happiness: Histogram,
+    /// One-way commute of employed citizens, in minutes.
+    pub commute: Histogram,
+}
+
+impl Default for DemographicAggregate {
+    fn default() -> Self {
+        Self {
+            population: 0,
+            employed: 0,
+            age: Histogram::new(&AGE_BINS),
+            education: Histogram::new(&EDUCATION_BINS),
+            income: Histogram::new(&INCOME_BINS),
+            happiness: Histogram::new(&HAPPINESS_BINS),
+            commute: Histogram::new(&COMMUTE_BINS),
+        }
+    }
+}
+
+impl DemographicAggregate {
+    pub fn clear(&mut self) {
+        self.population = 0;
+        self.employed = 0;
+        self.age.clear();
+        self.education.clear();
+        self.income.clear();
+        self.happiness.clear();
+        self.commute.clear();
+    }
+
+    /// Record one citizen. `salary` and `commute` are only given for
+    /// employed citizens.
+    pub fn record(&mut self, age: u8, education: u8, happiness: f32, job: Option<(f32, f32)>) {
+        self.population += 1;
+        self.age.add(age as f32);
+        self.education.add(education as f32);
+        self.happiness.add(happiness);
+        if let Some((salary, commute)) = job {
+            self.employed += 1;
+            self.income.add(salary);
+            self.commute.add(commute);
+        }
+    }
+
+    pub fn employment_rate(&self) -> f32 {
+        if self.population == 0 {
+            0.0
+        } else {
+            self.employed as f32 / self.population as f32
+        }
+    }
+}
+
+/// Citizen histograms for the whole city and for each player district,
+/// rebuilt in a single pass once per slow tick.
+///
+/// UI dashboards and the agent observation read this instead of running
+/// their own full-population queries. Only real (ECS) citizens are counted;
+/// virtual population is not included.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CitizenAggregates {
+    pub city: DemographicAggregate,
+    /// Indexed like `DistrictMap::districts`.
+    pub districts: Vec<DemographicAggregate>,
+    /// Tick of the last rebuild.
+    pub updated_tick: u64,
+}
+
+impl CitizenAggregates {
+    pub fn district(&self, index: usize) -> Option<&DemographicAggregate> {
+        self.districts.get(index)
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use rand::Rng;
 use crate::sim_rng::SimRng;
 
+use crate::budget::ExtendedBudget;
 use crate::buildings::{Building, MixedUseBuilding, UnderConstruction};
 use crate::citizen::{
     Citizen, CitizenDetails, CitizenState, CitizenStateComp, Family, Gender, HomeLocation, Needs,
@@ -12,7 +13,8 @@ use crate::grid::WorldGrid;
 use crate::lod::LodTier;
 use crate::mode_choice::ChosenTransportMode;
 use crate::movement::ActivityTimer;
-use crate::virtual_population::VirtualPopulation;
+use crate::virtual_cohorts::{CohortJob, VirtualCohorts};
+use crate::virtual_population::{VirtualCitizen, VirtualPopulation};
 
 #[derive(Resource, Default)]
 pub struct CitizenSpawnTimer(pub u32);
@@ -25,6 +27,8 @@ pub fn spawn_citizens(
     mut buildings: Query<(Entity, &mut Building, Option<&mut MixedUseBuilding>)>,
     under_construction: Query<Entity, With<UnderConstruction>>,
     mut virtual_pop: ResMut<VirtualPopulation>,
+    mut cohorts: ResMut<VirtualCohorts>,
+    extended_budget: Res<ExtendedBudget>,
     citizens: Query<&crate::citizen::Citizen>,
     game_params: Res<GameParams>,
     mut rng: ResMut<SimRng>,
@@ -114,9 +118,28 @@ pub fn spawn_citizens(
 
         let (home_wx, home_wy) = WorldGrid::grid_to_world(home_gx, home_gy);
 
+        let age: u8 = rng.0.gen_range(18..65);
+
         if real_count + spawned >= virtual_pop.max_real_citizens {
-            // Over the real-citizen cap: track virtually instead of spawning an entity
-            virtual_pop.total_virtual += 1;
+            // Over the real-citizen cap: join the home district's cohort
+            // instead of spawning an entity
+            let edu = rng.0.gen_range(0u8..=2);
+            let salary = CitizenDetails::base_salary_for_education(edu)
+                * (1.0 + age.saturating_sub(18) as f32 * 0.01);
+            cohorts.absorb(
+                &mut virtual_pop,
+                VirtualPopulation::district_index(home_gx, home_gy),
+                VirtualCitizen {
+                    age,
+                    employed: true,
+                    happiness: 50.0,
+                },
+                Some(CohortJob {
+                    district: VirtualPopulation::district_index(work_gx, work_gy),
+                    salary,
+                }),
+                extended_budget.zone_taxes.residential,
+            );
             if let Ok((_, mut home_b, home_mu)) = buildings.get_mut(home_entity) {
                 home_b.occupants += 1;
                 if let Some(mut mu) = home_mu {
@@ -133,7 +156,6 @@ pub fn spawn_citizens(
             continue;
         }
 
-        let age: u8 = rng.0.gen_range(18..65);
         let gender = if rng.0.gen::<bool>() {
             Gender::Male
         } else {
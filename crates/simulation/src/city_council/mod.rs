@@ -0,0 +1,26 @@
+//! City council and ordinance voting.
+//!
+//! A nine-seat council split between three blocs (Progressive, Business,
+//! Populist). Bloc shares drift each slow tick toward the leaning of the
+//! electorate, computed per player district from `CitizenAggregates`:
+//! wealthy districts lean Business, educated districts lean Progressive and
+//! unhappy districts lean Populist.
+//!
+//! Major actions are proposed as `Motion`s and voted on at the next session:
+//!
+//! - raising the zone tax cap (tax sliders cannot go above `tax_cap`),
+//! - large and emergency loans,
+//! - stadium construction (a passed vote grants one building permit),
+//! - enacting controversial policies (repealing never needs a vote).
+//!
+//! Each member votes with their bloc's support for the motion, nudged by
+//! city-wide happiness; a simple majority passes. Passed motions are logged
+//! to the event journal.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{electorate_leaning, run_council_session, CityCouncilPlugin};
+pub use types::*;
@@ -0,0 +1,173 @@
+//! Council drift and voting sessions.
+
+use bevy::prelude::*;
+
+use crate::citizen_aggregates::CitizenAggregates;
+use crate::economy::CityBudget;
+use crate::events::{CityEvent, CityEventType, EventJournal};
+use crate::loans::LoanBook;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::policies::Policies;
+use crate::receivership::Receivership;
+use crate::stats::CityStats;
+use crate::time_of_day::GameClock;
+
+use super::types::*;
+
+/// Population-weighted leaning of the electorate. Districts are weighted by
+/// their residents; citizens outside any district vote as one extra group.
+/// Returns `None` while the city has no residents.
+pub fn electorate_leaning(aggregates: &CitizenAggregates) -> Option<[f32; 3]> {
+    let city = &aggregates.city;
+    if city.population == 0 {
+        return None;
+    }
+    let mut weighted = [0.0f32; 3];
+    let mut in_districts = 0;
+    for district in aggregates.districts.iter().filter(|d| d.population > 0) {
+        let lean = leaning(district);
+        for (w, l) in weighted.iter_mut().zip(lean) {
+            *w += l * district.population as f32;
+        }
+        in_districts += district.population;
+    }
+    let outside = city.population.saturating_sub(in_districts);
+    if outside > 0 {
+        let lean = leaning(city);
+        for (w, l) in weighted.iter_mut().zip(lean) {
+            *w += l * outside as f32;
+        }
+    }
+    let total = (in_districts + outside) as f32;
+    Some(weighted.map(|w| w / total))
+}
+
+/// Carry out a motion the council passed.
+fn enact(
+    motion: Motion,
+    council: &mut CityCouncil,
+    policies: &mut Policies,
+    loans: &mut LoanBook,
+    budget: &mut CityBudget,
+    loan_rate: f64,
+    in_receivership: bool,
+) -> Result<(), &'static str> {
+    match motion {
+        Motion::RaiseTaxCap => {
+            council.tax_cap = (council.tax_cap + TAX_CAP_STEP).min(MAX_TAX_CAP);
+        }
+        Motion::TakeLoan(_) if in_receivership => {
+            return Err("the city is in receivership");
+        }
+        Motion::TakeLoan(tier) => {
+            if !loans.take_loan_with_rate(tier, &mut budget.treasury, loan_rate) {
+                return Err("maximum number of loans reached");
+            }
+        }
+        Motion::BuildStadium => council.stadium_permits += 1,
+        Motion::EnactPolicy(policy) => {
+            if !policies.is_active(policy) {
+                policies.toggle(policy);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Each slow tick the council drifts toward its electorate and votes on
+/// every pending motion.
+#[allow(clippy::too_many_arguments)]
+pub fn run_council_session(
+    slow_timer: Res<crate::SlowTickTimer>,
+    aggregates: Res<CitizenAggregates>,
+    stats: Res<CityStats>,
+    clock: Res<GameClock>,
+    mut council: ResMut<CityCouncil>,
+    mut policies: ResMut<Policies>,
+    mut loans: ResMut<LoanBook>,
+    mut budget: ResMut<CityBudget>,
+    receivership: Res<Receivership>,
+    settings: Res<crate::game_settings::GameSettings>,
+    mut journal: ResMut<EventJournal>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    if let Some(target) = electorate_leaning(&aggregates) {
+        council.drift_toward(target);
+    }
+
+    let motions = std::mem::take(&mut council.pending);
+    for motion in motions {
+        let record = council.vote(motion, stats.average_happiness, clock.day);
+        let tally = format!("{}-{}", record.yes, record.no);
+        if !record.passed {
+            notifications.send(NotificationEvent {
+                text: format!("Council rejected: {} ({tally}).", motion.describe()),
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::Finance,
+                location: None,
+            });
+            continue;
+        }
+        match enact(
+            motion,
+            &mut council,
+            &mut policies,
+            &mut loans,
+            &mut budget,
+            settings.difficulty.loan_rate_multiplier,
+            receivership.controls_finances(),
+        ) {
+            Ok(()) => {
+                let description = format!("Council approved: {} ({tally}).", motion.describe());
+                journal.push(CityEvent {
+                    event_type: CityEventType::NewPolicy(motion.describe()),
+                    day: clock.day,
+                    hour: clock.hour,
+                    description: description.clone(),
+                });
+                notifications.send(NotificationEvent {
+                    text: description,
+                    priority: NotificationPriority::Positive,
+                    category: NotificationCategory::Finance,
+                    location: None,
+                });
+            }
+            Err(reason) => {
+                notifications.send(NotificationEvent {
+                    text: format!(
+                        "Council approved {} but it could not be carried out: {reason}.",
+                        motion.describe()
+                    ),
+                    priority: NotificationPriority::Warning,
+                    category: NotificationCategory::Finance,
+                    location: None,
+                });
+            }
+        }
+    }
+}
+
+pub struct CityCouncilPlugin;
+
+impl Plugin for CityCouncilPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CityCouncil>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<CityCouncil>();
+
+        app.add_systems(
+            FixedUpdate,
+            run_council_session
+                .after(crate::citizen_aggregates::update_citizen_aggregates)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::*;
+use crate::citizen_aggregates::{CitizenAggregates, DemographicAggregate};
+use crate::loans::LoanTier;
+use crate::policies::Policy;
+use crate::Saveable;
+
+fn residents(count: u32, education: u8, happiness: f32, salary: f32) -> DemographicAggregate {
+    let mut agg = DemographicAggregate::default();
+    for _ in 0..count {
+        agg.record(35, education, happiness, Some((salary, 10.0)));
+    }
+    agg
+}
+
+#[test]
+fn seats_always_sum_to_council_size() {
+    for shares in [
+        [1.0, 0.0, 0.0],
+        [0.34, 0.33, 0.33],
+        [0.5, 0.25, 0.25],
+        [0.1, 0.6, 0.3],
+    ] {
+        assert_eq!(apportion_seats(shares).iter().sum::<u32>(), COUNCIL_SEATS);
+    }
+    assert_eq!(apportion_seats([1.0, 0.0, 0.0]), [COUNCIL_SEATS, 0, 0]);
+}
+
+#[test]
+fn leaning_follows_wealth_education_and_mood() {
+    let wealthy = leaning(&residents(10, 0, 80.0, 8000.0));
+    assert!(wealthy[Faction::Business.index()] > wealthy[Faction::Progressive.index()]);
+
+    let educated = leaning(&residents(10, 3, 80.0, 500.0));
+    assert!(educated[Faction::Progressive.index()] > educated[Faction::Business.index()]);
+
+    let unhappy = leaning(&residents(10, 0, 5.0, 500.0));
+    assert!(unhappy[Faction::Populist.index()] > unhappy[Faction::Business.index()]);
+    assert!((unhappy.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn electorate_weights_districts_by_population() {
+    let mut aggregates = CitizenAggregates::default();
+    assert!(electorate_leaning(&aggregates).is_none());
+
+    let rich = residents(90, 0, 80.0, 8000.0);
+    let poor = residents(10, 0, 10.0, 200.0);
+    aggregates.city = residents(100, 0, 73.0, 7220.0);
+    aggregates.districts = vec![rich, poor];
+    let lean = electorate_leaning(&aggregates).unwrap();
+    assert!(lean[Faction::Business.index()] > lean[Faction::Populist.index()]);
+}
+
+#[test]
+fn council_drifts_toward_electorate() {
+    let mut council = CityCouncil::default();
+    for _ in 0..200 {
+        council.drift_toward([0.1, 0.8, 0.1]);
+    }
+    assert!(council.seats_of(Faction::Business) >= 6);
+}
+
+#[test]
+fn propose_rejects_duplicates_and_capped_taxes() {
+    let mut council = CityCouncil::default();
+    assert!(council.propose(Motion::BuildStadium));
+    assert!(!council.propose(Motion::BuildStadium));
+    council.tax_cap = MAX_TAX_CAP;
+    assert!(!council.propose(Motion::RaiseTaxCap));
+}
+
+#[test]
+fn unanimous_bloc_decides_the_vote() {
+    let mut council = CityCouncil::default();
+    council.shares = [0.0, 1.0, 0.0];
+    council.seats = [0, COUNCIL_SEATS, 0];
+    let passed = (0..20)
+        .filter(|_| council.vote(Motion::RaiseTaxCap, 50.0, 1).passed)
+        .count();
+    assert!(passed <= 2, "business council passed {passed} tax hikes");
+    assert_eq!(council.history.len(), MAX_VOTE_HISTORY);
+
+    council.shares = [0.0, 0.0, 1.0];
+    council.seats = [0, 0, COUNCIL_SEATS];
+    let passed = (0..20)
+        .filter(|_| council.vote(Motion::BuildStadium, 80.0, 1).passed)
+        .count();
+    assert!(
+        passed >= 15,
+        "populist council passed only {passed} stadiums"
+    );
+}
+
+#[test]
+fn vote_requirements() {
+    assert!(policy_requires_vote(Policy::RentControl));
+    assert!(!policy_requires_vote(Policy::RecyclingProgram));
+    assert!(loan_requires_vote(LoanTier::Large));
+    assert!(!loan_requires_vote(LoanTier::Small));
+}
+
+#[test]
+fn council_roundtrips_through_save() {
+    let mut council = CityCouncil::default();
+    council.propose(Motion::EnactPolicy(Policy::MinimumWage));
+    council.propose(Motion::TakeLoan(LoanTier::Large));
+    council.vote(Motion::BuildStadium, 60.0, 4);
+    council.tax_cap = 0.2;
+    let bytes = council.save_to_bytes().unwrap();
+    let restored = CityCouncil::load_from_bytes(&bytes);
+    assert_eq!(restored.pending, council.pending);
+    assert_eq!(restored.history, council.history);
+    assert_eq!(restored.tax_cap, 0.2);
+}
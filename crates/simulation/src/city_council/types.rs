@@ -0,0 +1,292 @@
+//! Council composition, motions and voting.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::citizen_aggregates::DemographicAggregate;
+use crate::loans::LoanTier;
+use crate::policies::Policy;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Number of council seats.
+pub const COUNCIL_SEATS: u32 = 9;
+
+/// Fraction of the gap to the electorate's leaning closed each slow tick.
+pub const COUNCIL_DRIFT_RATE: f32 = 0.05;
+
+/// Tax cap every new city starts with.
+pub const DEFAULT_TAX_CAP: f32 = 0.15;
+
+/// How much each approved motion raises the tax cap.
+pub const TAX_CAP_STEP: f32 = 0.05;
+
+/// Highest tax cap the council can approve (matches the tax slider range).
+pub const MAX_TAX_CAP: f32 = 0.25;
+
+/// Salary at which a district counts as fully wealthy for its leaning.
+pub const WEALTHY_SALARY: f32 = 5000.0;
+
+/// Past votes kept for the council panel.
+pub const MAX_VOTE_HISTORY: usize = 20;
+
+/// Policies with enough controversy that enacting them needs a vote.
+pub const CONTROVERSIAL_POLICIES: [Policy; 7] = [
+    Policy::EminentDomain,
+    Policy::HighRiseBan,
+    Policy::CombustionEngineBan,
+    Policy::HeavyTrafficBan,
+    Policy::RentControl,
+    Policy::MinimumWage,
+    Policy::PetBan,
+];
+
+/// Whether enacting a policy requires a council vote. Repeals never do.
+pub fn policy_requires_vote(policy: Policy) -> bool {
+    CONTROVERSIAL_POLICIES.contains(&policy)
+}
+
+/// Whether taking a loan of this tier requires a council vote.
+pub fn loan_requires_vote(tier: LoanTier) -> bool {
+    matches!(tier, LoanTier::Large | LoanTier::Emergency)
+}
+
+// ---------------------------------------------------------------------------
+// Factions
+// ---------------------------------------------------------------------------
+
+/// Voting bloc a council seat belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize)]
+pub enum Faction {
+    /// Backed by well-educated districts; favours taxes and regulation.
+    Progressive,
+    /// Backed by wealthy districts; favours growth, dislikes taxes and debt.
+    Business,
+    /// Backed by unhappy districts; favours spending and spectacle.
+    Populist,
+}
+
+impl Faction {
+    pub const ALL: [Faction; 3] = [Self::Progressive, Self::Business, Self::Populist];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Progressive => "Progressive",
+            Self::Business => "Business",
+            Self::Populist => "Populist",
+        }
+    }
+
+    pub fn index(self) -> usize {
+        match self {
+            Self::Progressive => 0,
+            Self::Business => 1,
+            Self::Populist => 2,
+        }
+    }
+
+    /// Base chance that a member of this bloc votes for a motion.
+    pub fn support(self, motion: Motion) -> f32 {
+        match (self, motion) {
+            (Self::Progressive, Motion::RaiseTaxCap) => 0.7,
+            (Self::Business, Motion::RaiseTaxCap) => 0.15,
+            (Self::Populist, Motion::RaiseTaxCap) => 0.3,
+            (Self::Progressive, Motion::TakeLoan(_)) => 0.55,
+            (Self::Business, Motion::TakeLoan(_)) => 0.3,
+            (Self::Populist, Motion::TakeLoan(_)) => 0.65,
+            (Self::Progressive, Motion::BuildStadium) => 0.35,
+            (Self::Business, Motion::BuildStadium) => 0.7,
+            (Self::Populist, Motion::BuildStadium) => 0.75,
+            (Self::Progressive, Motion::EnactPolicy(_)) => 0.65,
+            (Self::Business, Motion::EnactPolicy(_)) => 0.3,
+            (Self::Populist, Motion::EnactPolicy(_)) => 0.5,
+        }
+    }
+}
+
+/// Bloc shares (Progressive, Business, Populist) preferred by a group of
+/// residents. Wealth pulls toward Business, education toward Progressive and
+/// unhappiness toward Populist.
+pub fn leaning(agg: &DemographicAggregate) -> [f32; 3] {
+    let wealth = (agg.income.mean() / WEALTHY_SALARY).clamp(0.0, 1.0);
+    let education = (agg.education.mean() / 3.0).clamp(0.0, 1.0);
+    let discontent = (1.0 - agg.happiness.mean() / 100.0).clamp(0.0, 1.0);
+    let raw = [0.2 + education, 0.2 + wealth, 0.2 + discontent];
+    let total: f32 = raw.iter().sum();
+    raw.map(|w| w / total)
+}
+
+/// Split `COUNCIL_SEATS` between blocs by largest remainder.
+pub fn apportion_seats(shares: [f32; 3]) -> [u32; 3] {
+    let total: f32 = shares.iter().sum::<f32>().max(f32::EPSILON);
+    let quotas = shares.map(|s| s / total * COUNCIL_SEATS as f32);
+    let mut seats = quotas.map(|q| q.floor() as u32);
+    let mut order = [0usize, 1, 2];
+    order.sort_by(|&a, &b| {
+        let ra = quotas[a] - quotas[a].floor();
+        let rb = quotas[b] - quotas[b].floor();
+        rb.partial_cmp(&ra).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut i = 0;
+    while seats.iter().sum::<u32>() < COUNCIL_SEATS {
+        seats[order[i % 3]] += 1;
+        i += 1;
+    }
+    seats
+}
+
+// ---------------------------------------------------------------------------
+// Motions
+// ---------------------------------------------------------------------------
+
+/// A major action that needs council approval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum Motion {
+    /// Raise the ceiling on zone tax rates by `TAX_CAP_STEP`.
+    RaiseTaxCap,
+    /// Borrow a large or emergency loan.
+    TakeLoan(LoanTier),
+    /// Permit construction of one stadium.
+    BuildStadium,
+    /// Enact a controversial policy.
+    EnactPolicy(Policy),
+}
+
+impl Motion {
+    pub fn describe(self) -> String {
+        match self {
+            Self::RaiseTaxCap => "Raise the tax cap".to_string(),
+            Self::TakeLoan(tier) => format!("Take a {}", tier.name()),
+            Self::BuildStadium => "Build a stadium".to_string(),
+            Self::EnactPolicy(policy) => format!("Enact {}", policy.name()),
+        }
+    }
+}
+
+/// Outcome of one council vote.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct VoteRecord {
+    pub motion: Motion,
+    pub day: u32,
+    pub yes: u32,
+    pub no: u32,
+    pub passed: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// The city council: bloc composition, motions awaiting a vote, and the
+/// approvals it has granted.
+#[derive(Resource, Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct CityCouncil {
+    /// Share of the council each bloc holds, indexed by `Faction::index`.
+    pub shares: [f32; 3],
+    pub seats: [u32; 3],
+    /// Maximum zone tax rate the council has approved.
+    pub tax_cap: f32,
+    /// Approved stadiums not yet built.
+    pub stadium_permits: u32,
+    /// Motions to be voted on at the next session (slow tick).
+    pub pending: Vec<Motion>,
+    /// Most recent votes, oldest first.
+    pub history: Vec<VoteRecord>,
+    pub rng_state: u64,
+}
+
+impl Default for CityCouncil {
+    fn default() -> Self {
+        let shares = [1.0 / 3.0; 3];
+        Self {
+            shares,
+            seats: apportion_seats(shares),
+            tax_cap: DEFAULT_TAX_CAP,
+            stadium_permits: 0,
+            pending: Vec::new(),
+            history: Vec::new(),
+            rng_state: 0xC0_0C11,
+        }
+    }
+}
+
+impl CityCouncil {
+    /// Put a motion on the agenda. Returns false if it is already pending
+    /// or can have no effect.
+    pub fn propose(&mut self, motion: Motion) -> bool {
+        if self.pending.contains(&motion) {
+            return false;
+        }
+        if motion == Motion::RaiseTaxCap && self.tax_cap >= MAX_TAX_CAP {
+            return false;
+        }
+        self.pending.push(motion);
+        true
+    }
+
+    pub fn is_pending(&self, motion: Motion) -> bool {
+        self.pending.contains(&motion)
+    }
+
+    pub fn seats_of(&self, faction: Faction) -> u32 {
+        self.seats[faction.index()]
+    }
+
+    /// Move bloc shares toward the electorate's leaning and reapportion.
+    pub fn drift_toward(&mut self, target: [f32; 3]) {
+        for (share, t) in self.shares.iter_mut().zip(target) {
+            *share += (t - *share) * COUNCIL_DRIFT_RATE;
+        }
+        self.seats = apportion_seats(self.shares);
+    }
+
+    /// Vote on a motion. `approval` (0..100, city happiness) shifts every
+    /// member's support by up to +/-10 points.
+    pub fn vote(&mut self, motion: Motion, approval: f32, day: u32) -> VoteRecord {
+        let mood = ((approval - 50.0) / 500.0).clamp(-0.1, 0.1);
+        let mut yes = 0;
+        for faction in Faction::ALL {
+            let chance = (faction.support(motion) + mood).clamp(0.05, 0.95);
+            for _ in 0..self.seats_of(faction) {
+                if self.next_random() < chance {
+                    yes += 1;
+                }
+            }
+        }
+        let record = VoteRecord {
+            motion,
+            day,
+            yes,
+            no: COUNCIL_SEATS - yes,
+            passed: yes * 2 > COUNCIL_SEATS,
+        };
+        self.history.push(record.clone());
+        if self.history.len() > MAX_VOTE_HISTORY {
+            self.history.remove(0);
+        }
+        record
+    }
+
+    pub(crate) fn next_random(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x % 10000) as f32 / 10000.0
+    }
+}
+
+impl Saveable for CityCouncil {
+    const SAVE_KEY: &'static str = "city_council";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -72,6 +72,10 @@ pub struct CityObservation {
     #[serde(default)]
     pub zone_distribution: ZoneDistribution,
 
+    // -- Demographics (refreshed every slow tick) ---------------------------
+    #[serde(default)]
+    pub demographics: DemographicsSnapshot,
+
     // -- Warnings -----------------------------------------------------------
     pub warnings: Vec<CityWarning>,
 
@@ -146,6 +150,30 @@ pub struct ZoneDistribution {
     pub mixed_use: u32,
 }
 
+/// Citizen histograms from `CitizenAggregates`. Bins are `(label, count)`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DemographicsSnapshot {
+    pub avg_age: f32,
+    pub avg_income: f32,
+    pub avg_commute_minutes: f32,
+    pub age_bins: Vec<(String, u32)>,
+    pub income_bins: Vec<(String, u32)>,
+    pub happiness_bins: Vec<(String, u32)>,
+    pub commute_bins: Vec<(String, u32)>,
+    pub districts: Vec<DistrictDemographics>,
+}
+
+/// Headline demographics of one player district.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DistrictDemographics {
+    pub name: String,
+    pub population: u32,
+    pub employment_rate: f32,
+    pub avg_happiness: f32,
+    pub avg_income: f32,
+    pub avg_commute_minutes: f32,
+}
+
 // ---------------------------------------------------------------------------
 // Warnings
 // ---------------------------------------------------------------------------
@@ -255,10 +283,7 @@ mod tests {
             },
             happiness: HappinessSnapshot {
                 overall: 65.0,
-                components: vec![
-                    ("employment".into(), 80.0),
-                    ("safety".into(), 50.0),
-                ],
+                components: vec![("employment".into(), 80.0), ("safety".into(), 50.0)],
             },
             attractiveness_score: 65.0,
             attractiveness: AttractivenessSnapshot {
@@ -283,6 +308,7 @@ mod tests {
                 office: 20,
                 mixed_use: 10,
             },
+            demographics: DemographicsSnapshot::default(),
             warnings: vec![CityWarning::NegativeBudget],
             recent_action_results: vec![ActionResultEntry {
                 action_summary: "Built road".into(),
@@ -324,6 +350,7 @@ mod tests {
         assert!(obs.overview_map.is_empty());
         assert_eq!(obs.building_breakdown.residential, 0);
         assert_eq!(obs.zone_distribution.commercial, 0);
+        assert!(obs.demographics.districts.is_empty());
     }
 
     #[test]
@@ -82,28 +82,31 @@ pub(crate) fn is_water_adjacent(grid: &WorldGrid, x: usize, y: usize) -> bool {
     false
 }
 
-/// Find the elevation threshold for sea level rise flooding.
-/// Returns the elevation below which water-adjacent cells should flood.
-pub(crate) fn sea_level_rise_threshold(grid: &WorldGrid) -> f32 {
-    // Collect elevations of all non-water, water-adjacent cells
-    let mut coastal_elevations: Vec<f32> = Vec::new();
+/// CO2 in tons from one sample of the vehicles on the road.
+pub fn traffic_co2(vehicles: u64) -> f64 {
+    vehicles as f64 * TRAFFIC_CO2_PER_VEHICLE_SAMPLE
+}
+
+/// Sea level rise over one year at the given warming, in elevation units.
+pub fn yearly_sea_level_rise(temp_increase_f: f32) -> f32 {
+    temp_increase_f.max(0.0) * SEA_LEVEL_RISE_PER_F
+}
+
+/// Dry cells on the shore that lie below `sea_level`. Only the current
+/// shoreline is returned, so the sea advances at most one cell per
+/// assessment.
+pub(crate) fn cells_below_sea_level(grid: &WorldGrid, sea_level: f32) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
     for y in 0..GRID_HEIGHT {
         for x in 0..GRID_WIDTH {
             let cell = grid.get(x, y);
-            if cell.cell_type != CellType::Water && is_water_adjacent(grid, x, y) {
-                coastal_elevations.push(cell.elevation);
+            if cell.cell_type != CellType::Water
+                && cell.elevation < sea_level
+                && is_water_adjacent(grid, x, y)
+            {
+                cells.push((x, y));
             }
         }
     }
-
-    if coastal_elevations.is_empty() {
-        return 0.0;
-    }
-
-    coastal_elevations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-    // Find the elevation at the given percentile
-    let index = ((coastal_elevations.len() as f32 * SEA_LEVEL_RISE_ELEVATION_PERCENTILE) as usize)
-        .min(coastal_elevations.len().saturating_sub(1));
-    coastal_elevations[index]
+    cells
 }
@@ -34,6 +34,12 @@ pub(crate) const DISASTER_FREQUENCY_INCREASE_PER_F: f32 = 0.10;
 /// Number of game days per year (used for yearly assessments).
 pub(crate) const DAYS_PER_YEAR: u32 = 360;
 
-/// Elevation percentile threshold for sea level rise flooding.
-/// At +3F, water-adjacent cells with elevation below this percentile flood permanently.
-pub(crate) const SEA_LEVEL_RISE_ELEVATION_PERCENTILE: f32 = 0.15;
+/// Tons of CO2 per vehicle on the road each time traffic is sampled (every
+/// slow tick).
+pub(crate) const TRAFFIC_CO2_PER_VEHICLE_SAMPLE: f64 = 0.000_1;
+
+/// Regional warming per year that happens whatever the city emits (F).
+pub(crate) const BACKGROUND_WARMING_F_PER_YEAR: f32 = 0.03;
+
+/// Yearly sea level rise per 1F of warming, in elevation units (0-1 scale).
+pub(crate) const SEA_LEVEL_RISE_PER_F: f32 = 0.002;
@@ -1,9 +1,11 @@
 //! Long-term climate change from cumulative CO2 emissions (WEATHER-016).
 //!
-//! Tracks cumulative CO2 emissions from fossil fuel power plants and industrial
-//! buildings. As emissions accumulate past thresholds, long-term climate effects
-//! are triggered: temperature increases, more extreme weather events, sea level
-//! rise (permanent flooding of low-elevation coastal cells), and longer droughts.
+//! Tracks cumulative CO2 emissions from fossil fuel power plants, industrial
+//! buildings and road traffic. The region warms slowly whatever the city does;
+//! the city's own emissions add to that warming, which raises the seasonal
+//! temperatures of the `ClimateZone` and brings more extreme weather events,
+//! sea level rise (permanent flooding of low-elevation coastal cells), and
+//! longer droughts.
 //!
 //! CO2 emission rates per MWh:
 //! - Coal power plant: 1.0 ton/MWh
@@ -17,8 +19,10 @@
 //! - 20,000,000 tons: +3F average temperature increase
 //!
 //! Effects:
+//! - Seasonal temperatures rise with the warming
 //! - Disaster frequency increases by +10% per 1F increase
-//! - At +3F, lowest-elevation water-adjacent cells flood permanently
+//! - The sea rises each year in proportion to the warming; shoreline cells
+//!   that fall below it flood permanently unless a seawall stands on them
 //! - Drought duration extends with temperature increase
 
 pub mod calculations;
@@ -44,8 +48,11 @@ impl Plugin for ClimateChangePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ClimateState>().add_systems(
             FixedUpdate,
-            yearly_climate_assessment
-                .after(crate::weather::update_weather)
+            (
+                sample_traffic_emissions,
+                yearly_climate_assessment.after(crate::weather::update_weather),
+            )
+                .chain()
                 .in_set(crate::SimulationSet::Simulation),
         );
 
@@ -12,14 +12,22 @@ pub struct ClimateState {
     pub cumulative_co2: f64,
     /// CO2 emitted during the most recent yearly assessment.
     pub yearly_co2: f64,
+    /// Part of `yearly_co2` that came from road traffic.
+    pub yearly_traffic_co2: f64,
+    /// Traffic CO2 sampled since the last assessment.
+    pub traffic_co2_pending: f64,
+    /// Regional warming in Fahrenheit that happens whatever the city emits.
+    pub background_warming_f: f32,
     /// Current temperature increase in Fahrenheit due to climate change.
     pub temperature_increase_f: f32,
     /// Disaster frequency multiplier (1.0 = normal, 1.1 = +10%, etc.).
     pub disaster_frequency_multiplier: f32,
-    /// Whether sea level rise flooding has been applied.
-    pub sea_level_rise_applied: bool,
+    /// Rise of the sea above its original level, in elevation units.
+    pub sea_level_rise: f32,
     /// Number of cells permanently flooded by sea level rise.
     pub flooded_cells_count: u32,
+    /// Coastal cells below the sea that seawalls held at the last assessment.
+    pub cells_held_by_seawalls: u32,
     /// Environmental score (0-100, higher = better/cleaner).
     pub environmental_score: f32,
     /// Last game day a yearly assessment was performed.
@@ -33,10 +41,14 @@ impl Default for ClimateState {
         Self {
             cumulative_co2: 0.0,
             yearly_co2: 0.0,
+            yearly_traffic_co2: 0.0,
+            traffic_co2_pending: 0.0,
+            background_warming_f: 0.0,
             temperature_increase_f: 0.0,
             disaster_frequency_multiplier: 1.0,
-            sea_level_rise_applied: false,
+            sea_level_rise: 0.0,
             flooded_cells_count: 0,
+            cells_held_by_seawalls: 0,
             environmental_score: 100.0,
             last_assessment_day: 0,
             drought_duration_multiplier: 1.0,
@@ -44,6 +56,18 @@ impl Default for ClimateState {
     }
 }
 
+impl ClimateState {
+    /// Temperature increase in Celsius, as applied to the weather.
+    pub fn warming_c(&self) -> f32 {
+        self.temperature_increase_f * 5.0 / 9.0
+    }
+
+    /// Current sea level in elevation units.
+    pub fn sea_level(&self) -> f32 {
+        crate::config::WATER_THRESHOLD + self.sea_level_rise
+    }
+}
+
 impl Saveable for ClimateState {
     const SAVE_KEY: &'static str = "climate_change";
 
@@ -1,34 +1,61 @@
 //! Bevy ECS systems for climate change simulation.
 
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 
 use crate::buildings::Building;
-use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::flood_protection::{FloodProtectionState, ProtectionType};
 use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::time_of_day::GameClock;
+use crate::traffic::TrafficGrid;
 use crate::utilities::UtilitySource;
-use crate::SlowTickTimer;
+use crate::{SlowTickTimer, TestSafetyNet};
 
 use super::calculations::*;
 use super::constants::*;
 use super::state::ClimateState;
 
+/// Every slow tick, add the CO2 of the vehicles on the road to this year's
+/// emissions.
+pub fn sample_traffic_emissions(
+    timer: Res<SlowTickTimer>,
+    traffic: Res<TrafficGrid>,
+    mut climate: ResMut<ClimateState>,
+) {
+    if !timer.should_run() {
+        return;
+    }
+    let vehicles: u64 = traffic.density.iter().map(|&d| d as u64).sum();
+    if vehicles > 0 {
+        climate.traffic_co2_pending += traffic_co2(vehicles);
+    }
+}
+
 /// Yearly climate assessment system. Runs every slow tick but only performs the
 /// assessment once per game year (every 360 days).
 ///
-/// 1. Calculates CO2 emissions from power plants and industrial buildings.
+/// 1. Calculates CO2 emissions from power plants, industrial buildings and
+///    the traffic sampled over the year.
 /// 2. Updates cumulative CO2 total.
-/// 3. Determines temperature increase and disaster frequency multiplier.
-/// 4. At +3F, applies sea level rise (permanent coastal flooding).
+/// 3. Adds a year of background warming, then determines the temperature
+///    increase and disaster frequency multiplier.
+/// 4. Raises the sea with the warming; shoreline cells below it flood
+///    permanently unless a seawall holds them.
 /// 5. Updates environmental score.
 #[allow(clippy::too_many_arguments)]
 pub fn yearly_climate_assessment(
+    mut commands: Commands,
     timer: Res<SlowTickTimer>,
     clock: Res<GameClock>,
+    safety_net: Option<Res<TestSafetyNet>>,
     mut climate: ResMut<ClimateState>,
     mut grid: ResMut<WorldGrid>,
+    protection: Res<FloodProtectionState>,
     utility_sources: Query<&UtilitySource>,
     buildings: Query<&Building>,
+    mut notifications: EventWriter<NotificationEvent>,
 ) {
     if !timer.should_run() {
         return;
@@ -60,13 +87,20 @@ pub fn yearly_climate_assessment(
         .count();
     yearly_co2 += industrial_count as f64 * INDUSTRIAL_CO2_PER_BUILDING as f64;
 
+    // CO2 from road traffic
+    let traffic_co2 = std::mem::take(&mut climate.traffic_co2_pending);
+    yearly_co2 += traffic_co2;
+    climate.yearly_traffic_co2 = traffic_co2;
+
     // --- Update cumulative totals ---
     climate.cumulative_co2 += yearly_co2;
     climate.yearly_co2 = yearly_co2;
     climate.last_assessment_day = current_day;
 
     // --- Calculate climate effects ---
-    climate.temperature_increase_f = temperature_increase_from_co2(climate.cumulative_co2);
+    climate.background_warming_f += BACKGROUND_WARMING_F_PER_YEAR;
+    climate.temperature_increase_f =
+        climate.background_warming_f + temperature_increase_from_co2(climate.cumulative_co2);
     climate.disaster_frequency_multiplier =
         disaster_multiplier_from_temp_increase(climate.temperature_increase_f);
     climate.drought_duration_multiplier =
@@ -74,36 +108,51 @@ pub fn yearly_climate_assessment(
     climate.environmental_score =
         calculate_environmental_score(climate.cumulative_co2, climate.yearly_co2);
 
-    // --- Sea level rise at +3F ---
-    if climate.temperature_increase_f >= 3.0 && !climate.sea_level_rise_applied {
-        let threshold = sea_level_rise_threshold(&grid);
-        let mut flooded = 0u32;
-
-        for y in 0..GRID_HEIGHT {
-            for x in 0..GRID_WIDTH {
-                let cell = grid.get(x, y);
-                if cell.cell_type != CellType::Water
-                    && cell.elevation <= threshold
-                    && is_water_adjacent(&grid, x, y)
-                {
-                    let cell_mut = grid.get_mut(x, y);
-                    cell_mut.cell_type = CellType::Water;
-                    cell_mut.zone = ZoneType::None;
-                    cell_mut.building_id = None;
-                    flooded += 1;
-                }
-            }
+    // --- Sea level rise ---
+    climate.sea_level_rise += yearly_sea_level_rise(climate.temperature_increase_f);
+    let seawalls: HashSet<(usize, usize)> = protection
+        .structures
+        .iter()
+        .filter(|s| s.protection_type == ProtectionType::Seawall && !s.failed)
+        .map(|s| (s.grid_x as usize, s.grid_y as usize))
+        .collect();
+    let mut flooded = 0u32;
+    let mut held = 0u32;
+    let shoreline = if safety_net.is_none() {
+        cells_below_sea_level(&grid, climate.sea_level())
+    } else {
+        Vec::new()
+    };
+    for (x, y) in shoreline {
+        if seawalls.contains(&(x, y)) {
+            held += 1;
+            continue;
         }
-
-        climate.sea_level_rise_applied = true;
-        climate.flooded_cells_count = flooded;
-
-        if flooded > 0 {
-            info!(
-                "CLIMATE CHANGE: Sea level rise has permanently flooded {} coastal cells!",
-                flooded
-            );
+        let cell = grid.get_mut(x, y);
+        if let Some(entity) = cell.building_id.take() {
+            commands.entity(entity).despawn();
         }
+        cell.cell_type = CellType::Water;
+        cell.zone = ZoneType::None;
+        flooded += 1;
+    }
+    climate.flooded_cells_count += flooded;
+    climate.cells_held_by_seawalls = held;
+
+    if flooded > 0 {
+        info!(
+            "CLIMATE CHANGE: Sea level rise has permanently flooded {} coastal cells!",
+            flooded
+        );
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Rising seas have permanently claimed {flooded} coastal cells; \
+                 seawalls can hold the shoreline"
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::Disaster,
+            location: None,
+        });
     }
 
     // Log the assessment
@@ -327,36 +327,46 @@ mod tests {
     }
 
     // -------------------------------------------------------------------------
-    // Sea level rise threshold tests
+    // Sea level rise tests
     // -------------------------------------------------------------------------
 
     #[test]
-    fn test_sea_level_rise_threshold_no_water() {
+    fn test_cells_below_sea_level_no_water() {
         let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
-        // No water cells, threshold should be 0
-        let threshold = sea_level_rise_threshold(&grid);
-        assert_eq!(threshold, 0.0);
+        // No water cells, so there is no shore to flood
+        assert!(cells_below_sea_level(&grid, 1.0).is_empty());
     }
 
     #[test]
-    fn test_sea_level_rise_threshold_with_coastal_cells() {
+    fn test_cells_below_sea_level_only_shoreline() {
         let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
-        // Create a water body on the left edge
+        // Water body on the left edge, land sloping up from the shore
         for y in 0..GRID_HEIGHT {
             grid.get_mut(0, y).cell_type = CellType::Water;
-            grid.get_mut(0, y).elevation = 0.0;
+            grid.get_mut(0, y).elevation = 0.2;
+            for x in 1..4 {
+                grid.get_mut(x, y).elevation = 0.3 + x as f32 * 0.01;
+            }
         }
-        // Set coastal cell elevations (cells at x=1 are adjacent to water)
-        for y in 0..GRID_HEIGHT {
-            grid.get_mut(1, y).elevation = y as f32 * 0.1;
-        }
-        // The threshold should be at the 15th percentile of coastal elevations
-        let threshold = sea_level_rise_threshold(&grid);
-        assert!(
-            threshold >= 0.0,
-            "Threshold should be non-negative, got {}",
-            threshold
-        );
+        grid.get_mut(1, 7).elevation = 0.5;
+
+        let cells = cells_below_sea_level(&grid, 0.4);
+        assert_eq!(cells.len(), GRID_HEIGHT - 1, "one shoreline cell per row");
+        assert!(cells.iter().all(|&(x, _)| x == 1));
+        assert!(!cells.contains(&(1, 7)), "high ground stays dry");
+    }
+
+    #[test]
+    fn test_yearly_sea_level_rise_scales_with_warming() {
+        assert_eq!(yearly_sea_level_rise(0.0), 0.0);
+        assert_eq!(yearly_sea_level_rise(-1.0), 0.0);
+        assert!((yearly_sea_level_rise(2.0) - 2.0 * SEA_LEVEL_RISE_PER_F).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_traffic_co2_per_vehicle() {
+        assert_eq!(traffic_co2(0), 0.0);
+        assert!((traffic_co2(10_000) - 10_000.0 * TRAFFIC_CO2_PER_VEHICLE_SAMPLE).abs() < 1e-9);
     }
 
     // -------------------------------------------------------------------------
@@ -370,13 +380,32 @@ mod tests {
         assert_eq!(state.yearly_co2, 0.0);
         assert_eq!(state.temperature_increase_f, 0.0);
         assert!((state.disaster_frequency_multiplier - 1.0).abs() < f32::EPSILON);
-        assert!(!state.sea_level_rise_applied);
+        assert_eq!(state.sea_level_rise, 0.0);
         assert_eq!(state.flooded_cells_count, 0);
+        assert_eq!(state.background_warming_f, 0.0);
+        assert_eq!(state.traffic_co2_pending, 0.0);
         assert!((state.environmental_score - 100.0).abs() < f32::EPSILON);
         assert_eq!(state.last_assessment_day, 0);
         assert!((state.drought_duration_multiplier - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_warming_in_celsius() {
+        let state = ClimateState {
+            temperature_increase_f: 1.8,
+            ..Default::default()
+        };
+        assert!((state.warming_c() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sea_level_starts_at_water_threshold() {
+        let mut state = ClimateState::default();
+        assert_eq!(state.sea_level(), crate::config::WATER_THRESHOLD);
+        state.sea_level_rise = 0.05;
+        assert!((state.sea_level() - crate::config::WATER_THRESHOLD - 0.05).abs() < 1e-6);
+    }
+
     // -------------------------------------------------------------------------
     // Saveable implementation tests
     // -------------------------------------------------------------------------
@@ -0,0 +1,27 @@
+//! Coastal erosion and beach management.
+//!
+//! Low-lying, undeveloped land on the shore is sandy beach. Once a day the
+//! sea takes sand from every beach: slowly in calm weather, much faster in
+//! storms and hurricanes, and faster still as the sea rises with climate
+//! change. Beaches draw tourists in proportion to the sand left on them.
+//!
+//! Two costly interventions hold the coast:
+//! - Beach nourishment tops every beach back up with sand, priced by how
+//!   much sand is missing. It does nothing to slow the sea, so it has to
+//!   be repeated.
+//! - Breakwaters built offshore shelter the beaches behind them from most
+//!   erosion, but wear down over the years (and in every storm) until they
+//!   crumble and must be rebuilt.
+//!
+//! The erosion grid backs the erosion overlay along the coast.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    can_place_breakwater, find_beach_cells, handle_nourishment_requests, update_coast,
+    CoastalErosionPlugin,
+};
+pub use types::*;
@@ -0,0 +1,165 @@
+//! Daily beach erosion, breakwater wear and beach nourishment.
+
+use bevy::prelude::*;
+
+use crate::climate_change::ClimateState;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::economy::CityBudget;
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::hurricane::{day_fraction, HurricaneState};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+use crate::weather::{Weather, WeatherCondition};
+
+use super::types::*;
+
+/// Undeveloped, low-lying land cells on the shore, in grid order.
+pub fn find_beach_cells(grid: &WorldGrid) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let cell = grid.get(x, y);
+            if cell.cell_type != CellType::Grass
+                || cell.zone != ZoneType::None
+                || cell.building_id.is_some()
+                || cell.elevation >= BEACH_MAX_ELEVATION
+            {
+                continue;
+            }
+            let (neighbors, count) = grid.neighbors4(x, y);
+            if neighbors[..count]
+                .iter()
+                .any(|&(nx, ny)| grid.get(nx, ny).cell_type == CellType::Water)
+            {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// Breakwaters are built in the water, just off the shore.
+pub fn can_place_breakwater(grid: &WorldGrid, x: usize, y: usize) -> bool {
+    if !grid.in_bounds(x, y) || grid.get(x, y).cell_type != CellType::Water {
+        return false;
+    }
+    let (neighbors, count) = grid.neighbors4(x, y);
+    neighbors[..count]
+        .iter()
+        .any(|&(nx, ny)| grid.get(nx, ny).cell_type != CellType::Water)
+}
+
+/// Once a day, survey the shoreline, let the sea take sand from the
+/// beaches and wear down the breakwaters.
+#[allow(clippy::too_many_arguments)]
+pub fn update_coast(
+    clock: Res<GameClock>,
+    grid: Res<WorldGrid>,
+    weather: Res<Weather>,
+    hurricane: Res<HurricaneState>,
+    climate: Res<ClimateState>,
+    mut coast: ResMut<CoastState>,
+    mut erosion: ResMut<ErosionGrid>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= coast.last_update_day {
+        return;
+    }
+    coast.last_update_day = clock.day;
+
+    coast.resurvey(&find_beach_cells(&grid));
+    let sea = SeaConditions {
+        storm: weather.current_event == WeatherCondition::Storm,
+        hurricane: hurricane
+            .active(day_fraction(clock.day, clock.hour))
+            .is_some(),
+        sea_level_rise: climate.sea_level_rise,
+    };
+    coast.erode(sea);
+    let crumbled = coast.wear_breakwaters(sea);
+    erosion.refresh(&coast);
+
+    if crumbled > 0 {
+        notifications.send(NotificationEvent {
+            text: format!("{crumbled} breakwater(s) have crumbled into the sea"),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+    let health = coast.health();
+    if health < BEACH_WARNING_HEALTH && !coast.warned {
+        coast.warned = true;
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Beaches have eroded to {:.0}% of their sand; nourishment can restore them",
+                health * 100.0
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    } else if health >= BEACH_WARNING_HEALTH && coast.warned {
+        coast.warned = false;
+    }
+}
+
+/// Pay for and carry out beach nourishment requested from the UI.
+pub fn handle_nourishment_requests(
+    mut requests: EventReader<BeachNourishmentRequest>,
+    mut budget: ResMut<CityBudget>,
+    mut coast: ResMut<CoastState>,
+    mut erosion: ResMut<ErosionGrid>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for _ in requests.read() {
+        let cost = coast.nourishment_cost();
+        if cost <= 0.0 {
+            continue;
+        }
+        if budget.treasury < cost {
+            notifications.send(NotificationEvent {
+                text: format!("Beach nourishment needs ${cost:.0}, more than the treasury holds"),
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::General,
+                location: None,
+            });
+            continue;
+        }
+        budget.treasury -= coast.nourish();
+        erosion.refresh(&coast);
+        notifications.send(NotificationEvent {
+            text: format!("Beaches restored with fresh sand for ${cost:.0}"),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+}
+
+pub struct CoastalErosionPlugin;
+
+impl Plugin for CoastalErosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoastState>()
+            .init_resource::<ErosionGrid>()
+            .add_event::<BeachNourishmentRequest>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_coast
+                        .after(crate::weather::update_weather)
+                        .after(crate::hurricane::advance_hurricane)
+                        .after(crate::climate_change::yearly_climate_assessment),
+                    handle_nourishment_requests,
+                )
+                    .chain()
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<CoastState>();
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::Saveable;
+
+fn beach(x: u16, y: u16, sand: f32) -> Beach {
+    Beach { x, y, sand }
+}
+
+#[test]
+fn test_storms_hurricanes_and_rising_seas_speed_erosion() {
+    let calm = daily_erosion(SeaConditions::default());
+    assert_eq!(calm, BASE_EROSION_PER_DAY);
+    let storm = daily_erosion(SeaConditions {
+        storm: true,
+        ..Default::default()
+    });
+    assert!(storm > calm);
+    let risen = daily_erosion(SeaConditions {
+        sea_level_rise: 0.02,
+        ..Default::default()
+    });
+    assert!((risen - 2.0 * calm).abs() < 1e-6);
+    let hurricane = daily_erosion(SeaConditions {
+        hurricane: true,
+        ..Default::default()
+    });
+    assert!(hurricane > storm);
+}
+
+#[test]
+fn test_resurvey_keeps_known_beaches_and_adds_new_ones() {
+    let mut coast = CoastState {
+        beaches: vec![beach(5, 1, 40.0), beach(2, 3, 10.0), beach(7, 3, 0.0)],
+        ..Default::default()
+    };
+    // (5, 1) was built over; (4, 2) is new shore.
+    coast.resurvey(&[(4, 2), (2, 3), (7, 3)]);
+    assert_eq!(
+        coast.beaches,
+        vec![beach(4, 2, MAX_SAND), beach(2, 3, 10.0), beach(7, 3, 0.0)]
+    );
+}
+
+#[test]
+fn test_breakwater_shelters_nearby_beaches() {
+    let mut coast = CoastState {
+        beaches: vec![beach(10, 10, 50.0), beach(40, 40, 50.0)],
+        breakwaters: vec![Breakwater::new(12, 10)],
+        ..Default::default()
+    };
+    assert_eq!(coast.shelter_at(10, 10), BREAKWATER_PROTECTION);
+    assert_eq!(coast.shelter_at(40, 40), 0.0);
+
+    coast.erode(SeaConditions {
+        storm: true,
+        ..Default::default()
+    });
+    assert!(coast.beaches[0].sand > coast.beaches[1].sand);
+    assert!(coast.beaches[1].sand < 50.0);
+}
+
+#[test]
+fn test_worn_breakwaters_shelter_less_and_crumble() {
+    let mut coast = CoastState {
+        breakwaters: vec![Breakwater::new(0, 0)],
+        ..Default::default()
+    };
+    let hurricane = SeaConditions {
+        hurricane: true,
+        ..Default::default()
+    };
+    assert_eq!(coast.wear_breakwaters(hurricane), 0);
+    assert!(coast.shelter_at(0, 0) < BREAKWATER_PROTECTION);
+    let mut crumbled = 0;
+    for _ in 0..100 {
+        crumbled += coast.wear_breakwaters(hurricane);
+    }
+    assert_eq!(crumbled, 1);
+    assert!(coast.breakwaters.is_empty());
+}
+
+#[test]
+fn test_sand_never_goes_negative() {
+    let mut coast = CoastState {
+        beaches: vec![beach(1, 1, 1.0)],
+        ..Default::default()
+    };
+    coast.erode(SeaConditions {
+        hurricane: true,
+        ..Default::default()
+    });
+    assert_eq!(coast.beaches[0].sand, 0.0);
+    assert_eq!(coast.health(), 0.0);
+}
+
+#[test]
+fn test_nourishment_costs_the_missing_sand() {
+    let mut coast = CoastState {
+        beaches: vec![beach(1, 1, MAX_SAND), beach(2, 1, 0.0), beach(3, 1, 50.0)],
+        ..Default::default()
+    };
+    let expected = NOURISHMENT_COST_PER_BEACH * 1.5;
+    assert!((coast.nourishment_cost() - expected).abs() < 1e-6);
+    assert!((coast.nourish() - expected).abs() < 1e-6);
+    assert_eq!(coast.health(), 1.0);
+    assert_eq!(coast.nourishment_cost(), 0.0);
+    assert_eq!(coast.nourishments, 1);
+}
+
+#[test]
+fn test_tourism_follows_sand() {
+    let mut coast = CoastState::default();
+    assert_eq!(coast.tourism_points(), 0.0);
+    assert_eq!(coast.health(), 1.0, "no beaches, nothing eroding");
+    coast.beaches = (0..FULL_BEACH_CELLS as u16)
+        .map(|x| beach(x, 0, MAX_SAND))
+        .collect();
+    assert_eq!(coast.tourism_points(), BEACH_TOURISM_POINTS);
+    for b in &mut coast.beaches {
+        b.sand = MAX_SAND / 2.0;
+    }
+    assert!((coast.tourism_points() - BEACH_TOURISM_POINTS / 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_erosion_grid_marks_beaches() {
+    let coast = CoastState {
+        beaches: vec![beach(3, 4, MAX_SAND), beach(5, 4, 0.0)],
+        ..Default::default()
+    };
+    let mut grid = ErosionGrid::default();
+    grid.refresh(&coast);
+    assert_eq!(grid.get(3, 4), 255);
+    assert_eq!(grid.get(5, 4), 1);
+    assert_eq!(grid.get(4, 4), 0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(CoastState::default().save_to_bytes().is_none());
+    let coast = CoastState {
+        beaches: vec![beach(1, 2, 33.0)],
+        breakwaters: vec![Breakwater::new(3, 4)],
+        last_update_day: 12,
+        nourishments: 2,
+        total_spent: 900.0,
+        warned: true,
+    };
+    let bytes = coast.save_to_bytes().expect("non-default state saves");
+    assert_eq!(CoastState::load_from_bytes(&bytes), coast);
+}
@@ -0,0 +1,321 @@
+//! Beaches, breakwaters, the erosion model and the saved coast state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH, WATER_THRESHOLD};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Sand on a healthy beach.
+pub const MAX_SAND: f32 = 100.0;
+
+/// Shore cells lower than this are sandy beach.
+pub const BEACH_MAX_ELEVATION: f32 = WATER_THRESHOLD + 0.05;
+
+/// Sand a beach loses on a calm day.
+pub const BASE_EROSION_PER_DAY: f32 = 0.03;
+
+/// How much faster beaches erode on a stormy day.
+pub const STORM_EROSION_MULTIPLIER: f32 = 8.0;
+
+/// Sand a beach loses each day a hurricane is crossing the map.
+pub const HURRICANE_EROSION_PER_DAY: f32 = 15.0;
+
+/// Extra erosion per unit of sea level rise (elevation units); a rise of
+/// 0.02 doubles the rate.
+pub const SEA_LEVEL_EROSION_PER_UNIT: f32 = 50.0;
+
+/// Share of erosion a new breakwater stops on the beaches behind it.
+pub const BREAKWATER_PROTECTION: f32 = 0.75;
+
+/// Beaches within this many cells of a breakwater are sheltered by it.
+pub const BREAKWATER_RADIUS: i32 = 6;
+
+/// Cost of building a breakwater.
+pub const BREAKWATER_COST: f64 = 20_000.0;
+
+/// Days a breakwater lasts in calm seas.
+pub const BREAKWATER_LIFETIME_DAYS: f32 = 7_200.0;
+
+/// Extra condition a breakwater loses on a stormy day.
+pub const STORM_BREAKWATER_WEAR: f32 = 0.005;
+
+/// Extra condition a breakwater loses each hurricane day.
+pub const HURRICANE_BREAKWATER_WEAR: f32 = 0.05;
+
+/// Cost of restoring one beach cell from bare to full sand.
+pub const NOURISHMENT_COST_PER_BEACH: f64 = 400.0;
+
+/// Beach cells of full sand at which beaches add their whole tourism draw.
+pub const FULL_BEACH_CELLS: f32 = 200.0;
+
+/// Raw natural beauty points for tourism from a full stretch of beach.
+pub const BEACH_TOURISM_POINTS: f32 = 25.0;
+
+/// Average beach health below which the player is warned.
+pub const BEACH_WARNING_HEALTH: f32 = 0.3;
+
+// ---------------------------------------------------------------------------
+// Erosion model
+// ---------------------------------------------------------------------------
+
+/// Today's sea conditions along the coast.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SeaConditions {
+    pub storm: bool,
+    pub hurricane: bool,
+    /// Sea level rise so far, in elevation units.
+    pub sea_level_rise: f32,
+}
+
+/// Sand an unsheltered beach loses today.
+pub fn daily_erosion(sea: SeaConditions) -> f32 {
+    let mut rate = BASE_EROSION_PER_DAY;
+    if sea.storm {
+        rate *= STORM_EROSION_MULTIPLIER;
+    }
+    rate *= 1.0 + sea.sea_level_rise.max(0.0) * SEA_LEVEL_EROSION_PER_UNIT;
+    if sea.hurricane {
+        rate += HURRICANE_EROSION_PER_DAY;
+    }
+    rate
+}
+
+/// Condition a breakwater loses today.
+pub fn daily_breakwater_wear(sea: SeaConditions) -> f32 {
+    let mut wear = 1.0 / BREAKWATER_LIFETIME_DAYS;
+    if sea.storm {
+        wear += STORM_BREAKWATER_WEAR;
+    }
+    if sea.hurricane {
+        wear += HURRICANE_BREAKWATER_WEAR;
+    }
+    wear
+}
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// A sandy shore cell.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Beach {
+    pub x: u16,
+    pub y: u16,
+    /// Sand left, 0 (washed away) to `MAX_SAND`.
+    pub sand: f32,
+}
+
+impl Beach {
+    /// Row-major sort key.
+    fn grid_key(&self) -> (usize, usize) {
+        (self.y as usize, self.x as usize)
+    }
+}
+
+/// An offshore breakwater sheltering the beaches behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Breakwater {
+    pub x: u16,
+    pub y: u16,
+    /// 1.0 when new; the breakwater crumbles at 0.
+    pub condition: f32,
+}
+
+impl Breakwater {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self {
+            x: x as u16,
+            y: y as u16,
+            condition: 1.0,
+        }
+    }
+
+    /// Share of erosion stopped at (`x`, `y`), or 0 when out of reach.
+    pub fn shelter(&self, x: usize, y: usize) -> f32 {
+        let dx = x as i32 - self.x as i32;
+        let dy = y as i32 - self.y as i32;
+        if dx * dx + dy * dy > BREAKWATER_RADIUS * BREAKWATER_RADIUS {
+            return 0.0;
+        }
+        BREAKWATER_PROTECTION * self.condition.clamp(0.0, 1.0)
+    }
+}
+
+/// The city's beaches and the works protecting them.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct CoastState {
+    /// Beach cells in grid order.
+    pub beaches: Vec<Beach>,
+    pub breakwaters: Vec<Breakwater>,
+    pub last_update_day: u32,
+    pub nourishments: u32,
+    /// Spent on nourishment and breakwaters.
+    pub total_spent: f64,
+    /// Whether the player has been warned about the current erosion.
+    pub warned: bool,
+}
+
+impl CoastState {
+    /// Keep the beaches still on `cells` and add new ones with full sand.
+    /// Beaches no longer on the list (flooded or built over) are dropped.
+    pub fn resurvey(&mut self, cells: &[(usize, usize)]) {
+        let mut old = std::mem::take(&mut self.beaches).into_iter().peekable();
+        for &(x, y) in cells {
+            // Both lists are in grid order, so old beaches merge in one pass.
+            let key = (y, x);
+            while old.next_if(|b| b.grid_key() < key).is_some() {}
+            let sand = old
+                .next_if(|b| b.grid_key() == key)
+                .map_or(MAX_SAND, |b| b.sand);
+            self.beaches.push(Beach {
+                x: x as u16,
+                y: y as u16,
+                sand,
+            });
+        }
+    }
+
+    /// Share of erosion stopped at (`x`, `y`) by the best breakwater.
+    pub fn shelter_at(&self, x: usize, y: usize) -> f32 {
+        self.breakwaters
+            .iter()
+            .map(|b| b.shelter(x, y))
+            .fold(0.0, f32::max)
+    }
+
+    /// Erode every beach for one day at sea.
+    pub fn erode(&mut self, sea: SeaConditions) {
+        let rate = daily_erosion(sea);
+        let shelter: Vec<f32> = self
+            .beaches
+            .iter()
+            .map(|b| self.shelter_at(b.x as usize, b.y as usize))
+            .collect();
+        for (beach, shelter) in self.beaches.iter_mut().zip(shelter) {
+            beach.sand = (beach.sand - rate * (1.0 - shelter)).max(0.0);
+        }
+    }
+
+    /// Wear every breakwater for one day and remove those that crumble.
+    /// Returns how many crumbled.
+    pub fn wear_breakwaters(&mut self, sea: SeaConditions) -> usize {
+        let wear = daily_breakwater_wear(sea);
+        for breakwater in &mut self.breakwaters {
+            breakwater.condition -= wear;
+        }
+        let before = self.breakwaters.len();
+        self.breakwaters.retain(|b| b.condition > 0.0);
+        before - self.breakwaters.len()
+    }
+
+    /// Average sand left on the beaches, 0-1. A city without beaches is
+    /// healthy.
+    pub fn health(&self) -> f32 {
+        if self.beaches.is_empty() {
+            return 1.0;
+        }
+        self.beaches.iter().map(|b| b.sand).sum::<f32>() / (self.beaches.len() as f32 * MAX_SAND)
+    }
+
+    /// Beach cells' worth of full sand.
+    pub fn sandy_cells(&self) -> f32 {
+        self.beaches.iter().map(|b| b.sand / MAX_SAND).sum()
+    }
+
+    /// Raw natural beauty points the beaches add to tourism.
+    pub fn tourism_points(&self) -> f32 {
+        (self.sandy_cells() / FULL_BEACH_CELLS).min(1.0) * BEACH_TOURISM_POINTS
+    }
+
+    /// Cost of topping every beach back up to full sand.
+    pub fn nourishment_cost(&self) -> f64 {
+        self.beaches
+            .iter()
+            .map(|b| ((MAX_SAND - b.sand) / MAX_SAND) as f64 * NOURISHMENT_COST_PER_BEACH)
+            .sum()
+    }
+
+    /// Restore every beach to full sand and record the project.
+    pub fn nourish(&mut self) -> f64 {
+        let cost = self.nourishment_cost();
+        for beach in &mut self.beaches {
+            beach.sand = MAX_SAND;
+        }
+        self.nourishments += 1;
+        self.total_spent += cost;
+        cost
+    }
+
+    pub fn has_breakwater_at(&self, x: usize, y: usize) -> bool {
+        self.breakwaters
+            .iter()
+            .any(|b| b.x as usize == x && b.y as usize == y)
+    }
+}
+
+impl Saveable for CoastState {
+    const SAVE_KEY: &'static str = "coastal_erosion";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Erosion grid
+// ---------------------------------------------------------------------------
+
+/// Per-cell beach condition for the erosion overlay: 0 off the beach,
+/// otherwise 1 (washed away) to 255 (full sand).
+#[derive(Resource)]
+pub struct ErosionGrid {
+    pub levels: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for ErosionGrid {
+    fn default() -> Self {
+        Self {
+            levels: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            width: GRID_WIDTH,
+            height: GRID_HEIGHT,
+        }
+    }
+}
+
+impl ErosionGrid {
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.levels[y * self.width + x]
+    }
+
+    /// Redraw the grid from the beaches.
+    pub fn refresh(&mut self, coast: &CoastState) {
+        self.levels.fill(0);
+        for beach in &coast.beaches {
+            let idx = beach.y as usize * self.width + beach.x as usize;
+            self.levels[idx] = 1 + (beach.sand / MAX_SAND * 254.0) as u8;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Ask for every beach to be topped back up with sand.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BeachNourishmentRequest;
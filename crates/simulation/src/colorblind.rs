@@ -2,12 +2,11 @@
 //!
 //! Provides a `ColorblindMode` resource that indicates which color vision
 //! deficiency adaptation is active. Rendering and UI systems read this resource
-//! to select appropriate color palettes.
+//! to select appropriate color palettes. The mode is a player preference kept
+//! in the player profile (see [`crate::profile`]).
 
 use bevy::prelude::*;
 
-use crate::{decode_or_warn, Saveable};
-
 /// The active colorblind accessibility mode.
 ///
 /// Affects overlay color ramps, zone ground colors, traffic LOS indicators,
@@ -66,32 +65,10 @@ pub struct ColorblindSettings {
     pub mode: ColorblindMode,
 }
 
-impl Saveable for ColorblindSettings {
-    const SAVE_KEY: &'static str = "colorblind_settings";
-
-    fn save_to_bytes(&self) -> Option<Vec<u8>> {
-        if self.mode == ColorblindMode::Normal {
-            None // skip saving default state
-        } else {
-            Some(bitcode::encode(&self.mode))
-        }
-    }
-
-    fn load_from_bytes(bytes: &[u8]) -> Self {
-        let mode: ColorblindMode = decode_or_warn(Self::SAVE_KEY, bytes);
-        Self { mode }
-    }
-}
-
 pub struct ColorblindPlugin;
 
 impl Plugin for ColorblindPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ColorblindSettings>();
-        // Register for save/load via extension map
-        let mut registry = app
-            .world_mut()
-            .get_resource_or_insert_with(crate::SaveableRegistry::default);
-        registry.register::<ColorblindSettings>();
     }
 }
@@ -0,0 +1,24 @@
+//! Per-cell commute and transit grids behind the commute overlays.
+//!
+//! - `CommuteTimeGrid`: average one-way commute of the employed citizens
+//!   living in each cell, using the same estimate as the demographics
+//!   panel.
+//! - `TransitRidershipGrid`: recent ridership of every bus and tram stop
+//!   and metro and train station, painted around the stop.
+//! - `JobAccessGrid`: jobs reachable within `JOB_ACCESS_MINUTES` without a
+//!   car, on foot or by transit where a stop is within walking range.
+//!
+//! Job accessibility compares every cell with every job cluster, so the
+//! per-cell grids are rebuilt a band of `SWEEP_ROWS` rows per slow tick
+//! rather than all at once; a full sweep takes a few slow ticks. Stops are
+//! few, so ridership is rebuilt every slow tick.
+//!
+//! All three are derived from the rest of the city and are not saved.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_commute_grids, update_transit_ridership, CommuteGridsPlugin};
+pub use types::*;
@@ -0,0 +1,156 @@
+//! Slow-tick updates of the commute and transit grids.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::buildings::{Building, MixedUseBuilding};
+use crate::bus_transit::BusTransitState;
+use crate::citizen::{Citizen, HomeLocation, WorkLocation};
+use crate::citizen_aggregates::commute_minutes;
+use crate::grid::{CellType, WorldGrid};
+use crate::metro_transit::MetroTransitState;
+use crate::mode_choice::{ChosenTransportMode, TransportMode};
+use crate::train_transit::TrainTransitState;
+use crate::tram_transit::TramTransitState;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// The four transit systems, read together.
+pub type TransitStates<'w> = (
+    Res<'w, BusTransitState>,
+    Res<'w, TramTransitState>,
+    Res<'w, MetroTransitState>,
+    Res<'w, TrainTransitState>,
+);
+
+/// Recent riders of every stop and station, by cell. Bus and tram lines
+/// only count riders per line, so a line's riders are split evenly over its
+/// stops; metro and train stations count their own.
+pub fn stop_ridership(
+    bus: &BusTransitState,
+    tram: &TramTransitState,
+    metro: &MetroTransitState,
+    train: &TrainTransitState,
+) -> Vec<((usize, usize), u32)> {
+    let mut riders: HashMap<(usize, usize), u32> = HashMap::new();
+    for stop in &bus.stops {
+        riders.entry((stop.grid_x, stop.grid_y)).or_default();
+    }
+    for route in bus.routes.iter().filter(|r| !r.stop_ids.is_empty()) {
+        let per_stop = route.monthly_ridership / route.stop_ids.len() as u32;
+        for stop in route.stop_ids.iter().filter_map(|&id| bus.stop_by_id(id)) {
+            *riders.entry((stop.grid_x, stop.grid_y)).or_default() += per_stop;
+        }
+    }
+    for stop in &tram.stops {
+        riders.entry((stop.grid_x, stop.grid_y)).or_default();
+    }
+    for line in tram.lines.iter().filter(|l| !l.stop_ids.is_empty()) {
+        let per_stop = line.period_ridership / line.stop_ids.len() as u32;
+        for stop in line.stop_ids.iter().filter_map(|&id| tram.stop_by_id(id)) {
+            *riders.entry((stop.grid_x, stop.grid_y)).or_default() += per_stop;
+        }
+    }
+    for station in &metro.stations {
+        *riders.entry((station.grid_x, station.grid_y)).or_default() += station.period_ridership;
+    }
+    for station in &train.stations {
+        *riders.entry((station.grid_x, station.grid_y)).or_default() += station.period_ridership;
+    }
+    let mut stops: Vec<_> = riders.into_iter().collect();
+    stops.sort_unstable();
+    stops
+}
+
+/// Jobs a building offers: its capacity for workplaces, the commercial
+/// floors for mixed use.
+fn building_jobs(building: &Building, mixed: Option<&MixedUseBuilding>) -> u32 {
+    match mixed {
+        Some(mixed) => mixed.commercial_capacity,
+        None if building.zone_type.is_job_zone() => building.capacity,
+        None => 0,
+    }
+}
+
+/// System: rebuild the next band of rows of the commute time and job
+/// accessibility grids. Job and stop locations are taken at the start of
+/// each sweep so a sweep is consistent.
+#[allow(clippy::too_many_arguments)]
+pub fn update_commute_grids(
+    slow_timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    transit: TransitStates,
+    citizens: Query<(&HomeLocation, &WorkLocation, Option<&ChosenTransportMode>), With<Citizen>>,
+    buildings: Query<(&Building, Option<&MixedUseBuilding>)>,
+    mut sweep: ResMut<CommuteSweep>,
+    mut commute: ResMut<CommuteTimeGrid>,
+    mut job_access: ResMut<JobAccessGrid>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    if sweep.next_row == 0 {
+        let (bus, tram, metro, train) = &transit;
+        let jobs = buildings
+            .iter()
+            .map(|(b, mixed)| ((b.grid_x, b.grid_y), building_jobs(b, mixed)));
+        let stops = stop_ridership(bus, tram, metro, train)
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect();
+        job_access.set_sources(jobs, stops);
+    }
+    let rows = sweep.next_band();
+
+    commute.clear_rows(rows.clone());
+    for (home, work, mode) in &citizens {
+        if rows.contains(&home.grid_y) {
+            let minutes = commute_minutes(
+                (home.grid_x, home.grid_y),
+                (work.grid_x, work.grid_y),
+                mode.map_or(TransportMode::Drive, |m| m.0),
+            );
+            commute.record(home.grid_x, home.grid_y, minutes);
+        }
+    }
+
+    job_access.sweep_rows(rows, |x, y| {
+        grid.in_bounds(x, y) && grid.get(x, y).cell_type != CellType::Water
+    });
+}
+
+/// System: rebuild the ridership grid, touching it only when ridership
+/// changed.
+pub fn update_transit_ridership(
+    slow_timer: Res<SlowTickTimer>,
+    transit: TransitStates,
+    mut ridership: ResMut<TransitRidershipGrid>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let (bus, tram, metro, train) = &transit;
+    let updated = TransitRidershipGrid::from_stops(&stop_ridership(bus, tram, metro, train));
+    if *ridership != updated {
+        *ridership = updated;
+    }
+}
+
+pub struct CommuteGridsPlugin;
+
+impl Plugin for CommuteGridsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CommuteTimeGrid>()
+            .init_resource::<TransitRidershipGrid>()
+            .init_resource::<JobAccessGrid>()
+            .init_resource::<CommuteSweep>()
+            .add_systems(
+                FixedUpdate,
+                (update_commute_grids, update_transit_ridership)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::bus_transit::{BusRoute, BusStop, BusTransitState};
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::metro_transit::{MetroStation, MetroTransitState};
+use crate::train_transit::TrainTransitState;
+use crate::tram_transit::TramTransitState;
+
+fn bus_stop(id: u32, grid_x: usize, grid_y: usize) -> BusStop {
+    BusStop {
+        id,
+        grid_x,
+        grid_y,
+        waiting: 0,
+    }
+}
+
+#[test]
+fn test_commute_average_per_cell() {
+    let mut grid = CommuteTimeGrid::default();
+    assert_eq!(grid.average(3, 4), None);
+    grid.record(3, 4, 10.0);
+    grid.record(3, 4, 20.0);
+    assert_eq!(grid.average(3, 4), Some(15.0));
+
+    grid.clear_rows(0..4);
+    assert_eq!(grid.average(3, 4), Some(15.0));
+    grid.clear_rows(4..5);
+    assert_eq!(grid.average(3, 4), None);
+}
+
+#[test]
+fn test_bus_line_ridership_is_split_over_stops() {
+    let bus = BusTransitState {
+        stops: vec![
+            bus_stop(0, 10, 10),
+            bus_stop(1, 20, 10),
+            bus_stop(2, 30, 10),
+        ],
+        routes: vec![BusRoute {
+            id: 0,
+            name: "Route 1".to_string(),
+            stop_ids: vec![0, 1],
+            active: true,
+            total_ridership: 500,
+            monthly_ridership: 100,
+        }],
+        ..Default::default()
+    };
+    let metro = MetroTransitState {
+        stations: vec![MetroStation {
+            id: 0,
+            grid_x: 20,
+            grid_y: 10,
+            name: "Central".to_string(),
+            total_ridership: 0,
+            period_ridership: 30,
+        }],
+        ..Default::default()
+    };
+    let stops = stop_ridership(
+        &bus,
+        &TramTransitState::default(),
+        &metro,
+        &TrainTransitState::default(),
+    );
+    // The unserved stop still shows up, with no riders; the metro station
+    // shares a cell with a bus stop.
+    assert_eq!(stops, vec![((10, 10), 50), ((20, 10), 80), ((30, 10), 0)]);
+}
+
+#[test]
+fn test_ridership_grid_marks_cells_around_stops() {
+    let grid = TransitRidershipGrid::from_stops(&[((0, 0), 40), ((10, 10), 80)]);
+    assert_eq!(grid.max_riders, 80);
+    assert_eq!(grid.share(10, 10), Some(1.0));
+    assert_eq!(grid.share(11, 9), Some(1.0));
+    assert_eq!(grid.share(1, 1), Some(0.5));
+    assert_eq!(grid.share(5, 5), None);
+}
+
+#[test]
+fn test_job_access_walk_range_and_transit() {
+    let mut access = JobAccessGrid::default();
+    // One cluster of jobs near the top-left corner.
+    access.set_sources([((4, 4), 100), ((5, 5), 50)], Vec::new());
+    assert_eq!(access.total_jobs, 150);
+    assert_eq!(access.reachable_from(10, 10), 150);
+    assert_eq!(access.share(10, 10), 1.0);
+
+    // Out of walking range of the far corner, reachable once a stop is near.
+    let far = (GRID_WIDTH - 1, 4);
+    assert_eq!(access.reachable_from(far.0, far.1), 0);
+    access.set_sources([((4, 4), 150)], vec![(far.0 - 2, far.1)]);
+    assert!(access.has_transit(far.0, far.1));
+    assert_eq!(access.reachable_from(far.0, far.1), 150);
+}
+
+#[test]
+fn test_job_access_sweep_skips_water() {
+    let mut access = JobAccessGrid::default();
+    access.set_sources([((4, 4), 10)], Vec::new());
+    access.sweep_rows(0..2, |x, _| x != 1);
+    assert_eq!(access.get(0, 0), 10);
+    assert_eq!(access.get(1, 0), 0);
+    assert_eq!(access.get(0, 1), 10);
+    assert_eq!(access.get(0, 2), 0);
+    assert_eq!(access.share(0, 0), 1.0);
+}
+
+#[test]
+fn test_sweep_covers_the_grid_and_wraps() {
+    let mut sweep = CommuteSweep::default();
+    let mut covered = 0;
+    loop {
+        let band = sweep.next_band();
+        assert!(band.len() <= SWEEP_ROWS);
+        covered += band.len();
+        if sweep.next_row == 0 {
+            break;
+        }
+    }
+    assert_eq!(covered, GRID_HEIGHT);
+    assert_eq!(sweep.next_band(), 0..SWEEP_ROWS.min(GRID_HEIGHT));
+}
@@ -0,0 +1,254 @@
+//! Grid resources and the pure parts of their updates.
+
+use bevy::prelude::*;
+
+use crate::citizen_aggregates::commute_minutes;
+use crate::config::{CHUNK_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::mode_choice::{manhattan_distance, TransportMode, MAX_TRANSIT_ACCESS_DISTANCE};
+
+/// Travel time budget for job accessibility, in game minutes.
+pub const JOB_ACCESS_MINUTES: f32 = 30.0;
+
+/// Rows of the per-cell grids rebuilt per slow tick.
+pub const SWEEP_ROWS: usize = 32;
+
+/// Cells around a stop that show its ridership, so single-cell stops stay
+/// visible on the overlay.
+pub const STOP_MARKER_RADIUS: usize = 1;
+
+// =============================================================================
+// Commute time
+// =============================================================================
+
+/// Average one-way commute of the employed citizens living in each cell.
+#[derive(Resource, Clone, Debug)]
+pub struct CommuteTimeGrid {
+    /// Sum of commute minutes, indexed as `y * GRID_WIDTH + x`.
+    pub total_minutes: Vec<f32>,
+    /// Employed citizens counted in `total_minutes`.
+    pub commuters: Vec<u32>,
+}
+
+impl Default for CommuteTimeGrid {
+    fn default() -> Self {
+        Self {
+            total_minutes: vec![0.0; GRID_WIDTH * GRID_HEIGHT],
+            commuters: vec![0; GRID_WIDTH * GRID_HEIGHT],
+        }
+    }
+}
+
+impl CommuteTimeGrid {
+    /// Average commute in minutes, `None` where no employed citizen lives.
+    pub fn average(&self, x: usize, y: usize) -> Option<f32> {
+        let i = y * GRID_WIDTH + x;
+        match self.commuters[i] {
+            0 => None,
+            n => Some(self.total_minutes[i] / n as f32),
+        }
+    }
+
+    /// Forget the commutes of rows `rows`.
+    pub fn clear_rows(&mut self, rows: std::ops::Range<usize>) {
+        let cells = rows.start * GRID_WIDTH..rows.end * GRID_WIDTH;
+        self.total_minutes[cells.clone()].fill(0.0);
+        self.commuters[cells].fill(0);
+    }
+
+    /// Count one commuter living at `(x, y)`.
+    pub fn record(&mut self, x: usize, y: usize, minutes: f32) {
+        let i = y * GRID_WIDTH + x;
+        self.total_minutes[i] += minutes;
+        self.commuters[i] += 1;
+    }
+}
+
+// =============================================================================
+// Transit ridership
+// =============================================================================
+
+/// Recent ridership of transit stops, painted into the cells around them.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct TransitRidershipGrid {
+    /// Riders of the busiest stop covering each cell, indexed as
+    /// `y * GRID_WIDTH + x`.
+    pub riders: Vec<u32>,
+    /// Cells covered by at least one stop.
+    pub covered: Vec<bool>,
+    /// Riders of the busiest stop in the city.
+    pub max_riders: u32,
+}
+
+impl Default for TransitRidershipGrid {
+    fn default() -> Self {
+        Self {
+            riders: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            covered: vec![false; GRID_WIDTH * GRID_HEIGHT],
+            max_riders: 0,
+        }
+    }
+}
+
+impl TransitRidershipGrid {
+    /// Build the grid from `(cell, riders)` of every stop.
+    pub fn from_stops(stops: &[((usize, usize), u32)]) -> Self {
+        let mut grid = Self::default();
+        for &((sx, sy), riders) in stops {
+            let xs = sx.saturating_sub(STOP_MARKER_RADIUS)..=(sx + STOP_MARKER_RADIUS);
+            for y in sy.saturating_sub(STOP_MARKER_RADIUS)..=(sy + STOP_MARKER_RADIUS) {
+                for x in xs.clone() {
+                    if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+                        continue;
+                    }
+                    let i = y * GRID_WIDTH + x;
+                    grid.covered[i] = true;
+                    grid.riders[i] = grid.riders[i].max(riders);
+                }
+            }
+            grid.max_riders = grid.max_riders.max(riders);
+        }
+        grid
+    }
+
+    /// Ridership relative to the busiest stop, `None` away from stops.
+    pub fn share(&self, x: usize, y: usize) -> Option<f32> {
+        let i = y * GRID_WIDTH + x;
+        if !self.covered[i] {
+            return None;
+        }
+        Some(if self.max_riders == 0 {
+            0.0
+        } else {
+            self.riders[i] as f32 / self.max_riders as f32
+        })
+    }
+}
+
+// =============================================================================
+// Job accessibility
+// =============================================================================
+
+/// Jobs reachable from each cell within `JOB_ACCESS_MINUTES` without a car.
+#[derive(Resource, Clone, Debug)]
+pub struct JobAccessGrid {
+    /// Reachable jobs, indexed as `y * GRID_WIDTH + x`.
+    pub jobs: Vec<u32>,
+    /// Jobs in the whole city when the current sweep started.
+    pub total_jobs: u32,
+    /// Jobs per chunk, as (chunk centre, jobs), for chunks that have any.
+    clusters: Vec<((usize, usize), u32)>,
+    /// Cells with a transit stop, for the transit option.
+    stops: Vec<(usize, usize)>,
+}
+
+impl Default for JobAccessGrid {
+    fn default() -> Self {
+        Self {
+            jobs: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            total_jobs: 0,
+            clusters: Vec::new(),
+            stops: Vec::new(),
+        }
+    }
+}
+
+impl JobAccessGrid {
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> u32 {
+        self.jobs[y * GRID_WIDTH + x]
+    }
+
+    /// Share of the city's jobs reachable from `(x, y)`.
+    pub fn share(&self, x: usize, y: usize) -> f32 {
+        if self.total_jobs == 0 {
+            0.0
+        } else {
+            self.get(x, y) as f32 / self.total_jobs as f32
+        }
+    }
+
+    /// Take new job locations and stops for the next sweep. `jobs` yields
+    /// the cell and job count of every workplace.
+    pub fn set_sources(
+        &mut self,
+        jobs: impl IntoIterator<Item = ((usize, usize), u32)>,
+        stops: Vec<(usize, usize)>,
+    ) {
+        let chunks_x = GRID_WIDTH.div_ceil(CHUNK_SIZE);
+        let chunks_y = GRID_HEIGHT.div_ceil(CHUNK_SIZE);
+        let mut per_chunk = vec![0u32; chunks_x * chunks_y];
+        for ((x, y), count) in jobs {
+            per_chunk[(y / CHUNK_SIZE) * chunks_x + x / CHUNK_SIZE] += count;
+        }
+        self.total_jobs = per_chunk.iter().sum();
+        self.clusters = per_chunk
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(i, &count)| {
+                let centre = (
+                    (i % chunks_x) * CHUNK_SIZE + CHUNK_SIZE / 2,
+                    (i / chunks_x) * CHUNK_SIZE + CHUNK_SIZE / 2,
+                );
+                (centre, count)
+            })
+            .collect();
+        self.stops = stops;
+    }
+
+    /// Whether a transit stop is within walking range of `(x, y)`.
+    pub fn has_transit(&self, x: usize, y: usize) -> bool {
+        self.stops
+            .iter()
+            .any(|&stop| manhattan_distance((x, y), stop) <= MAX_TRANSIT_ACCESS_DISTANCE)
+    }
+
+    /// Jobs reachable from `(x, y)` on foot, or by transit when a stop is
+    /// near.
+    pub fn reachable_from(&self, x: usize, y: usize) -> u32 {
+        let transit = self.has_transit(x, y);
+        self.clusters
+            .iter()
+            .filter(|&&(centre, _)| {
+                let within = |mode| commute_minutes((x, y), centre, mode) <= JOB_ACCESS_MINUTES;
+                within(TransportMode::Walk) || (transit && within(TransportMode::Transit))
+            })
+            .map(|&(_, count)| count)
+            .sum()
+    }
+
+    /// Recompute rows `rows`; `is_land(x, y)` filters out water.
+    pub fn sweep_rows(
+        &mut self,
+        rows: std::ops::Range<usize>,
+        is_land: impl Fn(usize, usize) -> bool,
+    ) {
+        for y in rows {
+            for x in 0..GRID_WIDTH {
+                let jobs = if is_land(x, y) {
+                    self.reachable_from(x, y)
+                } else {
+                    0
+                };
+                self.jobs[y * GRID_WIDTH + x] = jobs;
+            }
+        }
+    }
+}
+
+/// Position of the per-cell grid sweep.
+#[derive(Resource, Debug, Default)]
+pub struct CommuteSweep {
+    /// First row of the next band.
+    pub next_row: usize,
+}
+
+impl CommuteSweep {
+    /// The next band of rows, advancing (and wrapping) the sweep.
+    pub fn next_band(&mut self) -> std::ops::Range<usize> {
+        let start = self.next_row;
+        let end = (start + SWEEP_ROWS).min(GRID_HEIGHT);
+        self.next_row = if end >= GRID_HEIGHT { 0 } else { end };
+        start..end
+    }
+}
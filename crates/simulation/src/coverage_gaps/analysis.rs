@@ -0,0 +1,158 @@
+//! Population-weighted coverage gaps and greedy placement search.
+
+use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid};
+use crate::happiness::ServiceCoverageGrid;
+use crate::services::{ServiceBuilding, ServiceType};
+
+use super::types::PlacementSuggestion;
+
+/// Residents per grid cell, from residential and mixed-use buildings.
+pub fn resident_demand<'a>(
+    buildings: impl Iterator<Item = &'a crate::buildings::Building>,
+) -> Vec<u32> {
+    let mut demand = vec![0u32; GRID_WIDTH * GRID_HEIGHT];
+    for building in buildings {
+        if building.zone_type.is_residential() || building.zone_type.is_mixed_use() {
+            demand[ServiceCoverageGrid::idx(building.grid_x, building.grid_y)] +=
+                building.occupants;
+        }
+    }
+    demand
+}
+
+/// Cell offsets within `radius` (world units) of a service anchor, using the
+/// same disc test as `update_service_coverage`.
+fn disc_offsets(radius: f32) -> Vec<(i32, i32)> {
+    let radius_cells = (radius / CELL_SIZE).ceil() as i32;
+    let r2 = radius * radius;
+    let mut offsets = Vec::new();
+    for dy in -radius_cells..=radius_cells {
+        for dx in -radius_cells..=radius_cells {
+            let wx = dx as f32 * CELL_SIZE;
+            let wy = dy as f32 * CELL_SIZE;
+            if wx * wx + wy * wy <= r2 {
+                offsets.push((dx, dy));
+            }
+        }
+    }
+    offsets
+}
+
+fn offset_cell(x: usize, y: usize, (dx, dy): (i32, i32)) -> Option<usize> {
+    let cx = x as i32 + dx;
+    let cy = y as i32 + dy;
+    if cx < 0 || cy < 0 || cx >= GRID_WIDTH as i32 || cy >= GRID_HEIGHT as i32 {
+        return None;
+    }
+    Some(ServiceCoverageGrid::idx(cx as usize, cy as usize))
+}
+
+/// Whether the service's footprint, anchored at (x, y), covers only free grass.
+pub fn footprint_is_free(grid: &WorldGrid, service_type: ServiceType, x: usize, y: usize) -> bool {
+    let (fw, fh) = ServiceBuilding::footprint(service_type);
+    (0..fh).all(|dy| {
+        (0..fw).all(|dx| {
+            let (cx, cy) = (x + dx, y + dy);
+            grid.in_bounds(cx, cy) && {
+                let cell = grid.get(cx, cy);
+                cell.cell_type == CellType::Grass && cell.building_id.is_none()
+            }
+        })
+    })
+}
+
+/// Result of a gap analysis.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GapReport {
+    /// Residents in the analysed area.
+    pub total_demand: u32,
+    /// Residents outside the current coverage of the service category.
+    pub uncovered_demand: u32,
+    pub suggestions: Vec<PlacementSuggestion>,
+}
+
+/// Greedy set cover: repeatedly pick the free site whose coverage disc holds
+/// the most still-uncovered residents, up to `count` sites.
+pub fn suggest_placements(
+    grid: &WorldGrid,
+    coverage: &ServiceCoverageGrid,
+    demand: &[u32],
+    service_type: ServiceType,
+    coverage_bit: u8,
+    radius: f32,
+    count: usize,
+) -> GapReport {
+    let offsets = disc_offsets(radius);
+
+    // Uncovered demand points and the gain each candidate site would get.
+    let mut uncovered: Vec<(usize, usize, u32)> = Vec::new();
+    let mut total_demand = 0;
+    for (idx, &residents) in demand.iter().enumerate() {
+        if residents == 0 {
+            continue;
+        }
+        total_demand += residents;
+        if coverage.flags[idx] & coverage_bit == 0 {
+            uncovered.push((idx % GRID_WIDTH, idx / GRID_WIDTH, residents));
+        }
+    }
+    let uncovered_demand = uncovered.iter().map(|&(_, _, r)| r).sum();
+
+    let mut gain = vec![0u32; GRID_WIDTH * GRID_HEIGHT];
+    for &(x, y, residents) in &uncovered {
+        for &offset in &offsets {
+            if let Some(i) = offset_cell(x, y, offset) {
+                gain[i] += residents;
+            }
+        }
+    }
+
+    let mut suggestions = Vec::new();
+    let mut rejected = vec![false; GRID_WIDTH * GRID_HEIGHT];
+    while suggestions.len() < count {
+        let best = gain
+            .iter()
+            .enumerate()
+            .filter(|&(i, &g)| g > 0 && !rejected[i])
+            .max_by_key(|&(i, &g)| (g, std::cmp::Reverse(i)))
+            .map(|(i, &g)| (i, g));
+        let Some((best_idx, best_gain)) = best else {
+            break;
+        };
+        let (bx, by) = (best_idx % GRID_WIDTH, best_idx / GRID_WIDTH);
+        if !footprint_is_free(grid, service_type, bx, by) {
+            rejected[best_idx] = true;
+            continue;
+        }
+        suggestions.push(PlacementSuggestion {
+            grid_x: bx,
+            grid_y: by,
+            newly_covered: best_gain,
+        });
+
+        // Residents now covered no longer count toward other sites.
+        let r2 = radius * radius;
+        uncovered.retain(|&(x, y, residents)| {
+            let wx = (x as f32 - bx as f32) * CELL_SIZE;
+            let wy = (y as f32 - by as f32) * CELL_SIZE;
+            if wx * wx + wy * wy > r2 {
+                return true;
+            }
+            for &offset in &offsets {
+                if let Some(i) = offset_cell(x, y, offset) {
+                    gain[i] -= residents;
+                }
+            }
+            false
+        });
+        // The chosen footprint is no longer free.
+        rejected[best_idx] = true;
+    }
+
+    GapReport {
+        total_demand,
+        uncovered_demand,
+        suggestions,
+    }
+}
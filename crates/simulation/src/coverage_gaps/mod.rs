@@ -0,0 +1,23 @@
+//! Service coverage gap analysis and placement suggestions.
+//!
+//! For a chosen service type, residents living outside that service
+//! category's current coverage (from `ServiceCoverageGrid`) are treated as
+//! demand points weighted by building occupants. A greedy set cover then
+//! picks up to N free sites, each time taking the site whose coverage disc
+//! (radius scaled by the service's budget level) reaches the most residents
+//! still uncovered.
+//!
+//! Analyses are run on request (`CoverageGapRequest`) rather than every
+//! tick. Suggestions can be previewed on the map and built in one click
+//! with `BuildSuggestion`, which charges the normal cost and is undoable
+//! like any other service placement.
+
+pub mod analysis;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use analysis::{footprint_is_free, resident_demand, suggest_placements, GapReport};
+pub use systems::{handle_build_suggestion, handle_coverage_gap_requests, CoverageGapsPlugin};
+pub use types::*;
@@ -0,0 +1,150 @@
+//! Systems that run gap analyses and build suggested sites.
+
+use bevy::prelude::*;
+
+use crate::budget::ExtendedBudget;
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::happiness::{coverage_bit, ServiceCoverageGrid};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::{self, ServiceBuilding};
+use crate::undo_redo::CityAction;
+
+use super::analysis::{resident_demand, suggest_placements};
+use super::types::*;
+
+/// Compute coverage gaps and placement suggestions for each request.
+pub fn handle_coverage_gap_requests(
+    mut requests: EventReader<CoverageGapRequest>,
+    grid: Res<WorldGrid>,
+    coverage: Res<ServiceCoverageGrid>,
+    ext_budget: Res<ExtendedBudget>,
+    buildings: Query<&Building>,
+    mut analysis: ResMut<CoverageGapAnalysis>,
+) {
+    // Only the latest request matters.
+    let Some(request) = requests.read().last().copied() else {
+        return;
+    };
+    let Some(bit) = coverage_bit(request.service_type) else {
+        warn!(
+            "{} provides no area coverage to analyse",
+            request.service_type.name()
+        );
+        return;
+    };
+
+    let radius = ServiceBuilding::coverage_radius(request.service_type)
+        * ext_budget.service_budgets.for_service(request.service_type);
+    let demand = resident_demand(buildings.iter());
+    let report = suggest_placements(
+        &grid,
+        &coverage,
+        &demand,
+        request.service_type,
+        bit,
+        radius,
+        request.count.min(MAX_SUGGESTION_COUNT),
+    );
+
+    *analysis = CoverageGapAnalysis {
+        service_type: Some(request.service_type),
+        total_demand: report.total_demand,
+        uncovered_demand: report.uncovered_demand,
+        suggestions: report.suggestions,
+        preview: None,
+    };
+}
+
+/// Place the service at a suggested site, charging the usual cost.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_build_suggestion(
+    mut commands: Commands,
+    mut events: EventReader<BuildSuggestion>,
+    mut analysis: ResMut<CoverageGapAnalysis>,
+    mut grid: ResMut<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut actions: EventWriter<CityAction>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for event in events.read() {
+        let Some(service_type) = analysis.service_type else {
+            continue;
+        };
+        let Some(&site) = analysis.suggestions.get(event.index) else {
+            continue;
+        };
+        let location = Some(WorldGrid::grid_to_world(site.grid_x, site.grid_y));
+
+        let cost = ServiceBuilding::cost(service_type);
+        if budget.treasury < cost {
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "Cannot build {}: needs ${:.0}, treasury has ${:.0}.",
+                    service_type.name(),
+                    cost,
+                    budget.treasury
+                ),
+                priority: NotificationPriority::Info,
+                category: NotificationCategory::General,
+                location,
+            });
+            continue;
+        }
+        if !services::place_service(
+            &mut commands,
+            &mut grid,
+            service_type,
+            site.grid_x,
+            site.grid_y,
+        ) {
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "Cannot build {}: the site is no longer free.",
+                    service_type.name()
+                ),
+                priority: NotificationPriority::Info,
+                category: NotificationCategory::General,
+                location,
+            });
+            continue;
+        }
+
+        budget.treasury -= cost;
+        actions.send(CityAction::PlaceService {
+            service_type,
+            grid_x: site.grid_x,
+            grid_y: site.grid_y,
+            cost,
+        });
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Built {} covering {} more residents.",
+                service_type.name(),
+                site.newly_covered
+            ),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location,
+        });
+
+        analysis.suggestions.remove(event.index);
+        analysis.uncovered_demand = analysis.uncovered_demand.saturating_sub(site.newly_covered);
+        analysis.preview = None;
+    }
+}
+
+pub struct CoverageGapsPlugin;
+
+impl Plugin for CoverageGapsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoverageGapAnalysis>()
+            .add_event::<CoverageGapRequest>()
+            .add_event::<BuildSuggestion>()
+            .add_systems(
+                Update,
+                (handle_coverage_gap_requests, handle_build_suggestion).chain(),
+            );
+    }
+}
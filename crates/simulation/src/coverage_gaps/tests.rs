@@ -0,0 +1,112 @@
+use super::*;
+use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid};
+use crate::happiness::{coverage_bit, ServiceCoverageGrid, COVERAGE_POLICE};
+use crate::services::ServiceType;
+
+fn demand_at(cells: &[(usize, usize, u32)]) -> Vec<u32> {
+    let mut demand = vec![0; GRID_WIDTH * GRID_HEIGHT];
+    for &(x, y, residents) in cells {
+        demand[ServiceCoverageGrid::idx(x, y)] = residents;
+    }
+    demand
+}
+
+fn empty_coverage() -> ServiceCoverageGrid {
+    ServiceCoverageGrid::default()
+}
+
+#[test]
+fn suggestable_services_all_provide_coverage() {
+    for service_type in SUGGESTABLE_SERVICES {
+        assert!(coverage_bit(service_type).is_some(), "{service_type:?}");
+    }
+}
+
+#[test]
+fn first_site_covers_the_largest_cluster() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let demand = demand_at(&[(20, 20, 50), (21, 20, 50), (100, 100, 10)]);
+    let radius = 3.0 * CELL_SIZE;
+    let report = suggest_placements(
+        &grid,
+        &empty_coverage(),
+        &demand,
+        ServiceType::PoliceKiosk,
+        COVERAGE_POLICE,
+        radius,
+        2,
+    );
+    assert_eq!(report.total_demand, 110);
+    assert_eq!(report.uncovered_demand, 110);
+    assert_eq!(report.suggestions.len(), 2);
+    let first = report.suggestions[0];
+    assert_eq!(first.newly_covered, 100);
+    assert!(first.grid_x.abs_diff(20) <= 3 && first.grid_y.abs_diff(20) <= 3);
+    assert_eq!(report.suggestions[1].newly_covered, 10);
+}
+
+#[test]
+fn covered_residents_are_not_counted() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let demand = demand_at(&[(20, 20, 50), (80, 80, 30)]);
+    let mut coverage = empty_coverage();
+    coverage.flags[ServiceCoverageGrid::idx(20, 20)] |= COVERAGE_POLICE;
+    let report = suggest_placements(
+        &grid,
+        &coverage,
+        &demand,
+        ServiceType::PoliceKiosk,
+        COVERAGE_POLICE,
+        2.0 * CELL_SIZE,
+        3,
+    );
+    assert_eq!(report.uncovered_demand, 30);
+    // Only one uncovered cluster remains, so one site is enough.
+    assert_eq!(report.suggestions.len(), 1);
+    assert_eq!(report.suggestions[0].newly_covered, 30);
+}
+
+#[test]
+fn sites_avoid_blocked_cells() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let demand = demand_at(&[(40, 40, 20)]);
+    // Block every cell except one corner of the coverage disc.
+    for y in 36..=44 {
+        for x in 36..=44 {
+            grid.get_mut(x, y).cell_type = CellType::Road;
+        }
+    }
+    let report = suggest_placements(
+        &grid,
+        &empty_coverage(),
+        &demand,
+        ServiceType::PoliceKiosk,
+        COVERAGE_POLICE,
+        6.0 * CELL_SIZE,
+        1,
+    );
+    let site = report.suggestions[0];
+    assert!(footprint_is_free(
+        &grid,
+        ServiceType::PoliceKiosk,
+        site.grid_x,
+        site.grid_y
+    ));
+    assert!(!(36..=44).contains(&site.grid_x) || !(36..=44).contains(&site.grid_y));
+}
+
+#[test]
+fn no_suggestions_without_gaps() {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let report = suggest_placements(
+        &grid,
+        &empty_coverage(),
+        &demand_at(&[]),
+        ServiceType::FireHouse,
+        coverage_bit(ServiceType::FireHouse).unwrap(),
+        5.0 * CELL_SIZE,
+        5,
+    );
+    assert_eq!(report, GapReport::default());
+}
@@ -0,0 +1,79 @@
+//! Resources and events for coverage gap analysis.
+
+use bevy::prelude::*;
+
+use crate::services::ServiceType;
+
+/// Default number of sites suggested per analysis.
+pub const DEFAULT_SUGGESTION_COUNT: usize = 3;
+
+/// Upper bound on sites per analysis (keeps the greedy search cheap).
+pub const MAX_SUGGESTION_COUNT: usize = 10;
+
+/// Service types offered by the suggester: small-footprint buildings that
+/// provide area coverage, grouped by coverage category.
+pub const SUGGESTABLE_SERVICES: [ServiceType; 17] = [
+    ServiceType::MedicalClinic,
+    ServiceType::Hospital,
+    ServiceType::Kindergarten,
+    ServiceType::ElementarySchool,
+    ServiceType::HighSchool,
+    ServiceType::Library,
+    ServiceType::PoliceKiosk,
+    ServiceType::PoliceStation,
+    ServiceType::FireHouse,
+    ServiceType::FireStation,
+    ServiceType::SmallPark,
+    ServiceType::Playground,
+    ServiceType::Plaza,
+    ServiceType::SportsField,
+    ServiceType::CellTower,
+    ServiceType::BusDepot,
+    ServiceType::SubwayStation,
+];
+
+/// A recommended site for a new service building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlacementSuggestion {
+    /// Footprint anchor (top-left cell), as passed to `place_service`.
+    pub grid_x: usize,
+    pub grid_y: usize,
+    /// Residents this site would bring into coverage, not counting those
+    /// already covered by higher-ranked suggestions.
+    pub newly_covered: u32,
+}
+
+/// Latest coverage gap analysis. Not saved; recomputed on request.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CoverageGapAnalysis {
+    /// Service type analysed, `None` until the first request.
+    pub service_type: Option<ServiceType>,
+    /// Residents in the city.
+    pub total_demand: u32,
+    /// Residents outside the service category's current coverage.
+    pub uncovered_demand: u32,
+    /// Suggested sites, best first.
+    pub suggestions: Vec<PlacementSuggestion>,
+    /// Suggestion whose coverage disc is previewed on the map.
+    pub preview: Option<usize>,
+}
+
+impl CoverageGapAnalysis {
+    /// Residents the suggestions would cover in total.
+    pub fn suggested_coverage(&self) -> u32 {
+        self.suggestions.iter().map(|s| s.newly_covered).sum()
+    }
+}
+
+/// Run a gap analysis for a service type.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CoverageGapRequest {
+    pub service_type: ServiceType,
+    pub count: usize,
+}
+
+/// Build the suggestion at `index` in the current analysis.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BuildSuggestion {
+    pub index: usize,
+}
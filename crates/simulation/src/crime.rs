@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::chunked_grid::ChunkedGrid;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::WorldGrid;
 use crate::land_value::LandValueGrid;
@@ -8,7 +9,7 @@ use crate::services::{ServiceBuilding, ServiceType};
 /// Crime probability grid - higher values = more crime
 #[derive(Resource)]
 pub struct CrimeGrid {
-    pub levels: Vec<u8>,
+    pub levels: ChunkedGrid<u8>,
     pub width: usize,
     pub height: usize,
 }
@@ -16,7 +17,7 @@ pub struct CrimeGrid {
 impl Default for CrimeGrid {
     fn default() -> Self {
         Self {
-            levels: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            levels: ChunkedGrid::new(GRID_WIDTH, GRID_HEIGHT, 0),
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
         }
@@ -25,10 +26,10 @@ impl Default for CrimeGrid {
 
 impl CrimeGrid {
     pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.levels[y * self.width + x]
+        *self.levels.get(x, y)
     }
     pub fn set(&mut self, x: usize, y: usize, val: u8) {
-        self.levels[y * self.width + x] = val;
+        *self.levels.get_mut(x, y) = val;
     }
 }
 
@@ -38,60 +39,42 @@ pub fn update_crime(
     grid: Res<WorldGrid>,
     land_value: Res<LandValueGrid>,
     services: Query<&ServiceBuilding>,
-    ext_budget: Res<crate::budget::ExtendedBudget>,
 ) {
     if !slow_timer.should_run() {
         return;
     }
-    // Base crime level from land value (low value = more crime)
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            let cell = grid.get(x, y);
-            if cell.zone == crate::grid::ZoneType::None && cell.building_id.is_none() {
-                crime.set(x, y, 0);
-                continue;
-            }
-
-            // Base crime inversely proportional to land value
-            let lv = land_value.get(x, y) as i32;
-            let base_crime = ((100 - lv).max(0) / 4) as u8; // 0-25 base
-            crime.set(x, y, base_crime);
-        }
-    }
-
-    // Police services reduce crime in radius
-    let mut prison_count = 0u32;
-    for service in &services {
-        if service.service_type == ServiceType::Prison {
-            prison_count += 1;
+    // Base crime level from land value (low value = more crime). Chunks
+    // with nothing built or zoned are cleared without visiting each cell.
+    for ci in 0..crime.levels.chunk_count() {
+        if !grid.is_chunk_developed(ci) {
+            crime.levels.fill_chunk(ci, 0);
             continue;
         }
-        if !ServiceBuilding::is_police(service.service_type) {
-            continue;
-        }
-        let police_budget = ext_budget.service_budgets.police;
-        let radius = (service.radius / 16.0) as i32;
-        let base_reduction = match service.service_type {
-            ServiceType::PoliceKiosk => 10u8,
-            ServiceType::PoliceStation => 20u8,
-            ServiceType::PoliceHQ => 30u8,
-            _ => 15u8,
-        };
-        let reduction = (base_reduction as f32 * police_budget) as u8;
-        for dy in -radius..=radius {
-            for dx in -radius..=radius {
-                let nx = service.grid_x as i32 + dx;
-                let ny = service.grid_y as i32 + dy;
-                if nx >= 0 && ny >= 0 && (nx as usize) < GRID_WIDTH && (ny as usize) < GRID_HEIGHT {
-                    let dist = dx.abs() + dy.abs();
-                    let effect = reduction.saturating_sub(dist as u8);
-                    let idx = ny as usize * GRID_WIDTH + nx as usize;
-                    crime.levels[idx] = crime.levels[idx].saturating_sub(effect);
+        let (x0, y0, x1, y1) = crime.levels.chunk_bounds(ci);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let cell = grid.get(x, y);
+                if cell.zone == crate::grid::ZoneType::None && cell.building_id.is_none() {
+                    crime.set(x, y, 0);
+                    continue;
                 }
+
+                // Base crime inversely proportional to land value
+                let lv = land_value.get(x, y) as i32;
+                let base_crime = ((100 - lv).max(0) / 4) as u8; // 0-25 base
+                crime.set(x, y, base_crime);
             }
         }
     }
 
+    // Police reduce crime only through `police_patrol` (patrol beats and
+    // response times), which runs after this system; `police_tiers` just
+    // tracks per-tier stats. Prisons provide a flat city-wide reduction here.
+    let prison_count = services
+        .iter()
+        .filter(|s| s.service_type == ServiceType::Prison)
+        .count() as u32;
+
     // Prison provides flat city-wide crime reduction (10% per prison)
     if prison_count > 0 {
         let flat_reduction = (prison_count * 3).min(10) as u8; // up to -10
@@ -107,9 +90,7 @@ impl Plugin for CrimePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CrimeGrid>().add_systems(
             FixedUpdate,
-            update_crime
-                .after(crate::imports_exports::process_trade)
-                .in_set(crate::SimulationSet::Simulation),
+            update_crime.in_set(crate::GridPropagationSet::Outcome),
         );
     }
 }
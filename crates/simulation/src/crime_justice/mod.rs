@@ -0,0 +1,47 @@
+//! SERV-005: Crime Types and Justice Pipeline
+//!
+//! Typed crime events, a justice pipeline (crime -> police response -> arrest
+//! -> court -> jail), and per-district crime statistics. Crime frequency
+//! depends on poverty, unemployment, and density. Police effectiveness and
+//! jail capacity create feedback loops influencing deterrence.
+
+mod systems;
+#[cfg(test)]
+mod tests;
+mod types;
+
+pub use systems::{advance_justice_pipeline, generate_crimes, update_police_effectiveness};
+pub use types::*;
+
+use bevy::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+pub struct CrimeJusticePlugin;
+
+impl Plugin for CrimeJusticePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CrimeJusticeState>()
+            .add_event::<ConvictionEvent>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<CrimeJusticeState>();
+
+        app.add_systems(
+            FixedUpdate,
+            (
+                update_police_effectiveness,
+                generate_crimes,
+                advance_justice_pipeline,
+            )
+                .chain()
+                .after(crate::crime::update_crime)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
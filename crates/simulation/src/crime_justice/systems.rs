@@ -0,0 +1,217 @@
+//! Police effectiveness, crime generation and the justice pipeline.
+
+use bevy::prelude::*;
+
+use super::types::*;
+use crate::crime::CrimeGrid;
+use crate::districts::{Districts, DISTRICTS_X, DISTRICTS_Y, DISTRICT_SIZE};
+use crate::services::{ServiceBuilding, ServiceType};
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+pub fn update_police_effectiveness(
+    slow_timer: Res<crate::SlowTickTimer>,
+    services: Query<&ServiceBuilding>,
+    ext_budget: Res<crate::budget::ExtendedBudget>,
+    mut state: ResMut<CrimeJusticeState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let budget = ext_budget.service_budgets.police;
+    let mut score: f32 = 0.0;
+    let mut prison_count: u32 = 0;
+    for service in &services {
+        match service.service_type {
+            ServiceType::PoliceKiosk => score += 5.0,
+            ServiceType::PoliceStation => score += 15.0,
+            ServiceType::PoliceHQ => score += 30.0,
+            ServiceType::Prison => prison_count += 1,
+            _ => {}
+        }
+    }
+    score *= budget;
+    state.police_effectiveness = (1.0 - 1.0 / (1.0 + score * 0.02)).clamp(0.0, 1.0);
+    state.jail_capacity = prison_count * PRISON_CAPACITY;
+    if state.jail_capacity == 0 {
+        state.deterrence = 0.1;
+    } else {
+        let util = state.jail_population as f32 / state.jail_capacity as f32;
+        state.deterrence = (1.0 - util * 0.5).clamp(0.1, 1.0);
+    }
+}
+
+pub fn generate_crimes(
+    slow_timer: Res<crate::SlowTickTimer>,
+    districts: Res<Districts>,
+    crime_grid: Res<CrimeGrid>,
+    mut state: ResMut<CrimeJusticeState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    for dy in 0..DISTRICTS_Y {
+        for dx in 0..DISTRICTS_X {
+            let dd = districts.get(dx, dy);
+            if dd.population == 0 {
+                continue;
+            }
+            let total_jobs = dd.commercial_jobs + dd.industrial_jobs + dd.office_jobs;
+            let unemployment_rate = if total_jobs > 0 {
+                1.0 - (dd.employed as f32 / dd.population as f32).clamp(0.0, 1.0)
+            } else {
+                0.8
+            };
+            let poverty_rate = (1.0 - dd.avg_happiness / 100.0).clamp(0.0, 1.0);
+            let density_rate = if dd.residential_capacity > 0 {
+                (dd.population as f32 / dd.residential_capacity as f32).clamp(0.0, 2.0) / 2.0
+            } else {
+                0.0
+            };
+            // Average crime level across district cells
+            let (mut crime_sum, mut cell_count) = (0.0f32, 0u32);
+            let (xs, ys) = (dx * DISTRICT_SIZE, dy * DISTRICT_SIZE);
+            for cy in ys..(ys + DISTRICT_SIZE).min(crate::config::GRID_HEIGHT) {
+                for cx in xs..(xs + DISTRICT_SIZE).min(crate::config::GRID_WIDTH) {
+                    let c = crime_grid.get(cx, cy);
+                    if c > 0 {
+                        crime_sum += c as f32;
+                        cell_count += 1;
+                    }
+                }
+            }
+            let grid_factor = if cell_count > 0 {
+                (crime_sum / cell_count as f32 / 25.0).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let deterrence_mod = 1.0 - state.deterrence * 0.3;
+            let police_mod = 1.0 - state.police_effectiveness * 0.4;
+
+            for &ct in &ALL_CRIME_TYPES {
+                let combined = (poverty_rate * ct.poverty_factor()
+                    + unemployment_rate * ct.unemployment_factor()
+                    + density_rate * ct.density_factor())
+                    / 3.0;
+                let prob = BASE_CRIMES_PER_TICK
+                    * ct.base_weight()
+                    * combined
+                    * grid_factor
+                    * deterrence_mod
+                    * police_mod;
+                if state.next_random() < prob {
+                    state.events.push(CrimeEvent {
+                        crime_type: ct,
+                        district_x: dx,
+                        district_y: dy,
+                        stage: JusticeStage::Reported,
+                        stage_timer: 0,
+                    });
+                    state.get_district_stats_mut(dx, dy).increment(ct);
+                }
+            }
+        }
+    }
+}
+
+pub fn advance_justice_pipeline(
+    slow_timer: Res<crate::SlowTickTimer>,
+    mut state: ResMut<CrimeJusticeState>,
+    mut convictions: EventWriter<ConvictionEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let effectiveness = state.police_effectiveness;
+    let jail_cap = state.jail_capacity;
+    let mut jail_pop = state.jail_population;
+    for s in &mut state.district_stats {
+        s.active_cases = 0;
+    }
+    let events = std::mem::take(&mut state.events);
+    let mut kept = Vec::with_capacity(events.len());
+    for mut ev in events {
+        match ev.stage {
+            JusticeStage::Reported => {
+                ev.stage = JusticeStage::PoliceResponding;
+                ev.stage_timer = 1;
+                kept.push(ev);
+            }
+            JusticeStage::PoliceResponding => {
+                if ev.stage_timer > 0 {
+                    ev.stage_timer -= 1;
+                    kept.push(ev);
+                    continue;
+                }
+                let roll = {
+                    let mut x = state.rng_state;
+                    x ^= x << 13;
+                    x ^= x >> 7;
+                    x ^= x << 17;
+                    state.rng_state = x;
+                    (x % 10000) as f32 / 10000.0
+                };
+                if roll < effectiveness * 0.7 + 0.1 {
+                    ev.stage = JusticeStage::Arrested;
+                    ev.stage_timer = 1;
+                    let di = ev.district_y * DISTRICTS_X + ev.district_x;
+                    if di < state.district_stats.len() {
+                        state.district_stats[di].total_arrests += 1;
+                    }
+                    kept.push(ev);
+                }
+                // else criminal escapes
+            }
+            JusticeStage::Arrested => {
+                if ev.stage_timer > 0 {
+                    ev.stage_timer -= 1;
+                    kept.push(ev);
+                    continue;
+                }
+                ev.stage = JusticeStage::InCourt;
+                ev.stage_timer = 1;
+                kept.push(ev);
+            }
+            JusticeStage::InCourt => {
+                if ev.stage_timer > 0 {
+                    ev.stage_timer -= 1;
+                    kept.push(ev);
+                    continue;
+                }
+                if jail_cap > 0 {
+                    // Custody passes to the prison system, which applies
+                    // sentencing policy and handles overcrowding.
+                    let di = ev.district_y * DISTRICTS_X + ev.district_x;
+                    if di < state.district_stats.len() {
+                        state.district_stats[di].total_convictions += 1;
+                    }
+                    convictions.send(ConvictionEvent {
+                        crime_type: ev.crime_type,
+                        district_x: ev.district_x,
+                        district_y: ev.district_y,
+                    });
+                }
+                // else released (no prison to hold the offender)
+            }
+            JusticeStage::InJail => {
+                if ev.stage_timer > 0 {
+                    ev.stage_timer -= 1;
+                    kept.push(ev);
+                } else {
+                    jail_pop = jail_pop.saturating_sub(1);
+                }
+            }
+            JusticeStage::Resolved => {} // drop
+        }
+    }
+    for ev in &kept {
+        let di = ev.district_y * DISTRICTS_X + ev.district_x;
+        if di < state.district_stats.len() {
+            state.district_stats[di].active_cases += 1;
+        }
+    }
+    state.events = kept;
+    state.jail_population = jail_pop;
+}
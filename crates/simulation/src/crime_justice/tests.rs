@@ -0,0 +1,70 @@
+use super::types::ALL_CRIME_TYPES;
+use super::*;
+use crate::districts::{DISTRICTS_X, DISTRICTS_Y};
+use crate::Saveable;
+
+use super::*;
+
+#[test]
+fn test_crime_type_weights_sum_to_one() {
+    let sum: f32 = ALL_CRIME_TYPES.iter().map(|c| c.base_weight()).sum();
+    assert!((sum - 1.0).abs() < 0.01);
+}
+#[test]
+fn test_crime_type_jail_times_ordered() {
+    assert!(CrimeType::PettyTheft.jail_time() < CrimeType::Burglary.jail_time());
+    assert!(CrimeType::Burglary.jail_time() < CrimeType::Assault.jail_time());
+    assert!(CrimeType::Assault.jail_time() < CrimeType::OrganizedCrime.jail_time());
+}
+#[test]
+fn test_default_state() {
+    let s = CrimeJusticeState::default();
+    assert!(s.events.is_empty());
+    assert_eq!(s.jail_population, 0);
+    assert_eq!(s.district_stats.len(), DISTRICTS_X * DISTRICTS_Y);
+}
+#[test]
+fn test_district_crime_stats_increment() {
+    let mut s = DistrictCrimeStats::default();
+    s.increment(CrimeType::PettyTheft);
+    s.increment(CrimeType::PettyTheft);
+    s.increment(CrimeType::Assault);
+    assert_eq!(s.petty_theft_count, 2);
+    assert_eq!(s.assault_count, 1);
+    assert_eq!(s.total_crimes(), 3);
+}
+#[test]
+fn test_rng_deterministic() {
+    let mut a = CrimeJusticeState::default();
+    let mut b = CrimeJusticeState::default();
+    let sa: Vec<f32> = (0..10).map(|_| a.next_random()).collect();
+    let sb: Vec<f32> = (0..10).map(|_| b.next_random()).collect();
+    assert_eq!(sa, sb);
+}
+#[test]
+fn test_rng_values_in_range() {
+    let mut s = CrimeJusticeState::default();
+    for _ in 0..100 {
+        let v = s.next_random();
+        assert!(v >= 0.0 && v < 1.0, "value {v} out of range");
+    }
+}
+#[test]
+fn test_saveable_roundtrip() {
+    let mut s = CrimeJusticeState::default();
+    s.jail_population = 42;
+    s.police_effectiveness = 0.75;
+    s.events.push(CrimeEvent {
+        crime_type: CrimeType::Burglary,
+        district_x: 3,
+        district_y: 5,
+        stage: JusticeStage::InJail,
+        stage_timer: 2,
+    });
+    let bytes = s.save_to_bytes().unwrap();
+    let r = CrimeJusticeState::load_from_bytes(&bytes);
+    assert_eq!(r.jail_population, 42);
+    assert!((r.police_effectiveness - 0.75).abs() < 0.001);
+    assert_eq!(r.events.len(), 1);
+    assert_eq!(r.events[0].crime_type, CrimeType::Burglary);
+}
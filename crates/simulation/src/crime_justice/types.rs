@@ -0,0 +1,193 @@
+//! Crime types, justice stages and the justice pipeline state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::districts::{DISTRICTS_X, DISTRICTS_Y};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Crime types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize)]
+pub enum CrimeType {
+    PettyTheft,
+    Burglary,
+    Assault,
+    OrganizedCrime,
+}
+
+impl CrimeType {
+    pub fn base_weight(self) -> f32 {
+        match self {
+            Self::PettyTheft => 0.50,
+            Self::Burglary => 0.25,
+            Self::Assault => 0.15,
+            Self::OrganizedCrime => 0.10,
+        }
+    }
+    pub fn poverty_factor(self) -> f32 {
+        match self {
+            Self::PettyTheft => 1.5,
+            Self::Burglary => 1.2,
+            Self::Assault => 0.8,
+            Self::OrganizedCrime => 1.0,
+        }
+    }
+    pub fn unemployment_factor(self) -> f32 {
+        match self {
+            Self::PettyTheft => 1.3,
+            Self::Burglary => 1.4,
+            Self::Assault => 0.6,
+            Self::OrganizedCrime => 1.6,
+        }
+    }
+    pub fn density_factor(self) -> f32 {
+        match self {
+            Self::PettyTheft => 1.0,
+            Self::Burglary => 0.8,
+            Self::Assault => 1.3,
+            Self::OrganizedCrime => 1.5,
+        }
+    }
+    pub fn jail_time(self) -> u32 {
+        match self {
+            Self::PettyTheft => 1,
+            Self::Burglary => 3,
+            Self::Assault => 5,
+            Self::OrganizedCrime => 10,
+        }
+    }
+}
+
+pub(crate) const ALL_CRIME_TYPES: [CrimeType; 4] = [
+    CrimeType::PettyTheft,
+    CrimeType::Burglary,
+    CrimeType::Assault,
+    CrimeType::OrganizedCrime,
+];
+
+// ---------------------------------------------------------------------------
+// Justice pipeline stages
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct CrimeEvent {
+    pub crime_type: CrimeType,
+    pub district_x: usize,
+    pub district_y: usize,
+    pub stage: JusticeStage,
+    pub stage_timer: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum JusticeStage {
+    Reported,
+    PoliceResponding,
+    Arrested,
+    InCourt,
+    InJail,
+    Resolved,
+}
+
+/// Emitted when a court case ends in a conviction. The prison system takes
+/// custody of the offender and tracks the sentence from here on.
+#[derive(Event, Debug, Clone)]
+pub struct ConvictionEvent {
+    pub crime_type: CrimeType,
+    pub district_x: usize,
+    pub district_y: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Per-district crime statistics
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct DistrictCrimeStats {
+    pub petty_theft_count: u32,
+    pub burglary_count: u32,
+    pub assault_count: u32,
+    pub organized_crime_count: u32,
+    pub total_arrests: u32,
+    pub total_convictions: u32,
+    pub active_cases: u32,
+}
+
+impl DistrictCrimeStats {
+    pub fn total_crimes(&self) -> u32 {
+        self.petty_theft_count
+            + self.burglary_count
+            + self.assault_count
+            + self.organized_crime_count
+    }
+    pub(crate) fn increment(&mut self, ct: CrimeType) {
+        match ct {
+            CrimeType::PettyTheft => self.petty_theft_count += 1,
+            CrimeType::Burglary => self.burglary_count += 1,
+            CrimeType::Assault => self.assault_count += 1,
+            CrimeType::OrganizedCrime => self.organized_crime_count += 1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+pub const PRISON_CAPACITY: u32 = 50;
+pub(crate) const BASE_CRIMES_PER_TICK: f32 = 0.5;
+
+#[derive(Resource, Clone, Debug, Encode, Decode, Serialize, Deserialize)]
+pub struct CrimeJusticeState {
+    pub events: Vec<CrimeEvent>,
+    pub district_stats: Vec<DistrictCrimeStats>,
+    pub jail_population: u32,
+    pub jail_capacity: u32,
+    pub police_effectiveness: f32,
+    pub deterrence: f32,
+    pub rng_state: u64,
+}
+
+impl Default for CrimeJusticeState {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            district_stats: vec![DistrictCrimeStats::default(); DISTRICTS_X * DISTRICTS_Y],
+            jail_population: 0,
+            jail_capacity: 0,
+            police_effectiveness: 0.0,
+            deterrence: 0.5,
+            rng_state: 12345,
+        }
+    }
+}
+
+impl CrimeJusticeState {
+    pub fn get_district_stats(&self, dx: usize, dy: usize) -> &DistrictCrimeStats {
+        &self.district_stats[dy * DISTRICTS_X + dx]
+    }
+    pub fn get_district_stats_mut(&mut self, dx: usize, dy: usize) -> &mut DistrictCrimeStats {
+        &mut self.district_stats[dy * DISTRICTS_X + dx]
+    }
+    pub(crate) fn next_random(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x % 10000) as f32 / 10000.0
+    }
+}
+
+impl Saveable for CrimeJusticeState {
+    const SAVE_KEY: &'static str = "crime_justice";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -29,6 +29,12 @@
 //!    - `move_citizens` — per-tick citizen movement along paths
 //!    - `building_spawner` — zone-demand-driven building placement
 //!    - `update_traffic` — traffic density grid recalculation
+//!    - `process_path_requests` / `collect_path_results` — pathfinding task
+//!      dispatch and collection
+//!    - `update_spatial_grid` / `assign_lod_tiers` — LOD bookkeeping
+//!
+//! The app's `--bench` mode also times these spans and reports them per
+//! system when built with `--features trace`.
 //!
 //! Without the `trace` feature, the `info_span!` calls compile to no-ops and
 //! have zero runtime cost.
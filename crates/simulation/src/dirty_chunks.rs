@@ -0,0 +1,390 @@
+//! Per-chunk dirty tracking for the slow grid systems.
+//!
+//! The `SlowTickTimer` grid systems used to recompute the whole map on every
+//! pass even when nothing had been built. `DirtyChunks` watches the
+//! `WorldGrid` storage chunks and keeps a generation per chunk that is
+//! bumped whenever a building, road or zone in it changes. Utility flags
+//! (`has_power`, `has_water`) are rewritten far more often than the city's
+//! layout changes and deliberately do not count.
+//!
+//! Each consumer owns a `DirtyChunkCursor` and calls `DirtyChunks::take` to
+//! get the `DirtyRegion` that changed since its previous call. The region is
+//! full on a cursor's first call, after a load, and whenever so much of the
+//! map changed that per-chunk work would not pay off; consumers then fall
+//! back to recomputing everything.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+use crate::chunked_grid::STORAGE_CHUNK_SIZE;
+use crate::grid::WorldGrid;
+
+/// Share of the map's chunks above which a region is treated as the whole
+/// map: past this point the per-chunk bookkeeping costs more than it saves.
+pub const FULL_RECOMPUTE_SHARE: f32 = 0.5;
+
+/// Chunk generations of the `WorldGrid` layout.
+#[derive(Resource, Default)]
+pub struct DirtyChunks {
+    width: usize,
+    height: usize,
+    /// Layout signature per chunk, as of its last scan.
+    signatures: Vec<u64>,
+    /// `WorldGrid` chunk revisions seen by the last scan.
+    scanned_revisions: Vec<Option<u32>>,
+    /// Bumped whenever a chunk's layout changes.
+    generations: Vec<u64>,
+    /// Bumped by `mark_all`; cursors from an older epoch get a full region.
+    epoch: u64,
+    /// Set by `mark_all` so the next update rescans every chunk.
+    rescan: bool,
+}
+
+/// A consumer's position in the `DirtyChunks` history.
+#[derive(Debug, Default)]
+pub struct DirtyChunkCursor {
+    epoch: Option<u64>,
+    seen: Vec<u64>,
+}
+
+impl DirtyChunks {
+    /// Forget every chunk's history: all cursors get a full region on their
+    /// next `take`. Called when the grid is replaced, e.g. by a load.
+    pub fn mark_all(&mut self) {
+        self.epoch = self.epoch.wrapping_add(1);
+        self.scanned_revisions.fill(None);
+        self.rescan = true;
+    }
+
+    /// Rescan the chunks of `grid` modified since the last scan and bump the
+    /// generation of those whose layout changed.
+    pub fn scan(&mut self, grid: &WorldGrid) {
+        let chunk_count = grid.cells.chunk_count();
+        if self.width != grid.width
+            || self.height != grid.height
+            || self.generations.len() != chunk_count
+        {
+            self.width = grid.width;
+            self.height = grid.height;
+            self.signatures = vec![0; chunk_count];
+            self.scanned_revisions = vec![None; chunk_count];
+            self.generations = vec![0; chunk_count];
+            self.epoch = self.epoch.wrapping_add(1);
+        }
+        self.rescan = false;
+
+        for ci in 0..chunk_count {
+            let revision = grid.cells.chunk_revision(ci);
+            let previous = self.scanned_revisions[ci].replace(revision);
+            if previous == Some(revision) {
+                continue;
+            }
+            let signature = layout_signature(grid, ci);
+            if previous.is_some() && signature != self.signatures[ci] {
+                self.generations[ci] = self.generations[ci].wrapping_add(1);
+            }
+            self.signatures[ci] = signature;
+        }
+    }
+
+    /// The chunks whose layout changed since `cursor` last took from this
+    /// tracker, advancing the cursor.
+    pub fn take(&self, cursor: &mut DirtyChunkCursor) -> DirtyRegion {
+        if cursor.epoch != Some(self.epoch) || cursor.seen.len() != self.generations.len() {
+            cursor.epoch = Some(self.epoch);
+            cursor.seen.clone_from(&self.generations);
+            return DirtyRegion::full(self.width, self.height);
+        }
+        let mut region = DirtyRegion::empty(self.width, self.height);
+        for (ci, (&generation, seen)) in self.generations.iter().zip(&mut cursor.seen).enumerate() {
+            if generation != *seen {
+                region.mark_chunk(ci);
+                *seen = generation;
+            }
+        }
+        region.promote_if_large();
+        region
+    }
+}
+
+/// Hash of the parts of a chunk the slow grid systems derive their layers
+/// from: terrain, roads, zones and buildings.
+fn layout_signature(grid: &WorldGrid, ci: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for (_, _, cell) in grid.cells.chunk_cells(ci) {
+        cell.cell_type.hash(&mut hasher);
+        cell.zone.hash(&mut hasher);
+        cell.road_type.hash(&mut hasher);
+        cell.building_id.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// A set of storage chunks a consumer has to recompute, laid out like the
+/// `ChunkedGrid` chunks of a `width` x `height` grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirtyRegion {
+    width: usize,
+    height: usize,
+    chunks_x: usize,
+    /// Per chunk: whether it is in the region. Ignored when `full`.
+    mask: Vec<bool>,
+    full: bool,
+}
+
+impl DirtyRegion {
+    /// A region holding no chunks.
+    pub fn empty(width: usize, height: usize) -> Self {
+        let chunks_x = width.div_ceil(STORAGE_CHUNK_SIZE);
+        let chunks_y = height.div_ceil(STORAGE_CHUNK_SIZE);
+        Self {
+            width,
+            height,
+            chunks_x,
+            mask: vec![false; chunks_x * chunks_y],
+            full: false,
+        }
+    }
+
+    /// A region holding every chunk.
+    pub fn full(width: usize, height: usize) -> Self {
+        let mut region = Self::empty(width, height);
+        region.full = true;
+        region
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.mask.len()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.full
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.full && !self.mask.contains(&true)
+    }
+
+    pub fn set_full(&mut self) {
+        self.full = true;
+    }
+
+    pub fn mark_chunk(&mut self, ci: usize) {
+        self.mask[ci] = true;
+    }
+
+    /// Add the chunk holding (`x`, `y`); out-of-bounds cells are ignored.
+    pub fn mark_cell(&mut self, x: usize, y: usize) {
+        if x < self.width && y < self.height {
+            let ci = (y / STORAGE_CHUNK_SIZE) * self.chunks_x + x / STORAGE_CHUNK_SIZE;
+            self.mask[ci] = true;
+        }
+    }
+
+    pub fn contains_chunk(&self, ci: usize) -> bool {
+        self.full || self.mask[ci]
+    }
+
+    pub fn contains_cell(&self, x: usize, y: usize) -> bool {
+        self.full || self.mask[(y / STORAGE_CHUNK_SIZE) * self.chunks_x + x / STORAGE_CHUNK_SIZE]
+    }
+
+    /// Whether any chunk overlapping cells `x0..x1` x `y0..y1` is in the
+    /// region.
+    pub fn intersects(&self, x0: usize, y0: usize, x1: usize, y1: usize) -> bool {
+        let (x1, y1) = (x1.min(self.width), y1.min(self.height));
+        if x0 >= x1 || y0 >= y1 {
+            return false;
+        }
+        if self.full {
+            return true;
+        }
+        let columns = x0 / STORAGE_CHUNK_SIZE..=(x1 - 1) / STORAGE_CHUNK_SIZE;
+        (y0 / STORAGE_CHUNK_SIZE..=(y1 - 1) / STORAGE_CHUNK_SIZE)
+            .any(|cy| columns.clone().any(|cx| self.mask[cy * self.chunks_x + cx]))
+    }
+
+    /// Add every chunk of `other`, which must share this region's layout.
+    pub fn union(&mut self, other: &DirtyRegion) {
+        debug_assert_eq!(self.mask.len(), other.mask.len());
+        self.full |= other.full;
+        for (mine, &theirs) in self.mask.iter_mut().zip(&other.mask) {
+            *mine |= theirs;
+        }
+        self.promote_if_large();
+    }
+
+    /// Grow the region by at least `cells` in every direction, so it holds
+    /// every cell whose result can depend on a cell inside it.
+    pub fn dilate(&mut self, cells: usize) {
+        if self.full || cells == 0 {
+            return;
+        }
+        let reach = cells.div_ceil(STORAGE_CHUNK_SIZE) as isize;
+        let chunks_x = self.chunks_x as isize;
+        let chunks_y = (self.mask.len() / self.chunks_x.max(1)) as isize;
+        let mut grown = self.mask.clone();
+        for ci in self.chunks().collect::<Vec<_>>() {
+            let (cx, cy) = ((ci % self.chunks_x) as isize, (ci / self.chunks_x) as isize);
+            for ny in (cy - reach).max(0)..=(cy + reach).min(chunks_y - 1) {
+                for nx in (cx - reach).max(0)..=(cx + reach).min(chunks_x - 1) {
+                    grown[(ny * chunks_x + nx) as usize] = true;
+                }
+            }
+        }
+        self.mask = grown;
+        self.promote_if_large();
+    }
+
+    /// Indices of the chunks in the region; every chunk when full.
+    pub fn chunks(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.mask.len()).filter(move |&ci| self.contains_chunk(ci))
+    }
+
+    /// Cell bounds of chunk `ci`: `(x0, y0, x1, y1)`, end-exclusive.
+    pub fn chunk_bounds(&self, ci: usize) -> (usize, usize, usize, usize) {
+        let x0 = (ci % self.chunks_x) * STORAGE_CHUNK_SIZE;
+        let y0 = (ci / self.chunks_x) * STORAGE_CHUNK_SIZE;
+        (
+            x0,
+            y0,
+            (x0 + STORAGE_CHUNK_SIZE).min(self.width),
+            (y0 + STORAGE_CHUNK_SIZE).min(self.height),
+        )
+    }
+
+    /// The x ranges of row `y` that lie inside the region.
+    pub fn row_spans(&self, y: usize) -> impl Iterator<Item = Range<usize>> + '_ {
+        let row = (y / STORAGE_CHUNK_SIZE) * self.chunks_x;
+        (0..self.chunks_x)
+            .filter(move |&cx| self.contains_chunk(row + cx))
+            .map(move |cx| {
+                let x0 = cx * STORAGE_CHUNK_SIZE;
+                x0..(x0 + STORAGE_CHUNK_SIZE).min(self.width)
+            })
+    }
+
+    fn promote_if_large(&mut self) {
+        if self.full || self.mask.is_empty() {
+            return;
+        }
+        let marked = self.mask.iter().filter(|&&m| m).count();
+        if marked as f32 > self.mask.len() as f32 * FULL_RECOMPUTE_SHARE {
+            self.full = true;
+        }
+    }
+}
+
+/// Rescan `WorldGrid` chunks touched since the previous tick.
+pub fn track_dirty_chunks(grid: Res<WorldGrid>, mut dirty: ResMut<DirtyChunks>) {
+    if grid.is_added() {
+        dirty.mark_all();
+    }
+    if grid.is_changed() || dirty.rescan {
+        dirty.scan(&grid);
+    }
+}
+
+pub struct DirtyChunksPlugin;
+
+impl Plugin for DirtyChunksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DirtyChunks>().add_systems(
+            FixedUpdate,
+            track_dirty_chunks
+                .after(crate::grid_chunk_activity::refresh_grid_chunk_activity)
+                .in_set(crate::SimulationSet::PreSim),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{CellType, ZoneType};
+
+    fn scanned(grid: &WorldGrid) -> DirtyChunks {
+        let mut dirty = DirtyChunks::default();
+        dirty.scan(grid);
+        dirty
+    }
+
+    #[test]
+    fn first_take_is_full_then_only_changed_chunks() {
+        let mut grid = WorldGrid::new(128, 128);
+        let mut dirty = scanned(&grid);
+        let mut cursor = DirtyChunkCursor::default();
+        assert!(dirty.take(&mut cursor).is_full());
+        assert!(dirty.take(&mut cursor).is_empty());
+
+        grid.get_mut(40, 70).cell_type = CellType::Road;
+        grid.get_mut(41, 70).zone = ZoneType::Industrial;
+        dirty.scan(&grid);
+        let region = dirty.take(&mut cursor);
+        assert!(!region.is_full());
+        assert_eq!(
+            region.chunks().collect::<Vec<_>>(),
+            vec![grid.cells.chunk_of(40, 70)]
+        );
+        assert!(dirty.take(&mut cursor).is_empty());
+    }
+
+    #[test]
+    fn utility_flags_do_not_dirty_chunks() {
+        let mut grid = WorldGrid::new(64, 64);
+        let mut dirty = scanned(&grid);
+        let mut cursor = DirtyChunkCursor::default();
+        dirty.take(&mut cursor);
+
+        grid.get_mut(3, 3).has_power = true;
+        grid.get_mut(50, 50).has_water = true;
+        dirty.scan(&grid);
+        assert!(dirty.take(&mut cursor).is_empty());
+    }
+
+    #[test]
+    fn mark_all_gives_every_cursor_a_full_region() {
+        let grid = WorldGrid::new(64, 64);
+        let mut dirty = scanned(&grid);
+        let mut cursor = DirtyChunkCursor::default();
+        dirty.take(&mut cursor);
+
+        dirty.mark_all();
+        dirty.scan(&grid);
+        assert!(dirty.take(&mut cursor).is_full());
+    }
+
+    #[test]
+    fn dilate_reaches_neighbouring_chunks_and_promotes_large_regions() {
+        let mut region = DirtyRegion::empty(256, 256);
+        region.mark_cell(100, 100);
+        region.dilate(1);
+        assert_eq!(region.chunks().count(), 9);
+        assert!(region.contains_cell(64, 64));
+        assert!(!region.contains_cell(170, 170));
+        assert!(region.intersects(120, 120, 140, 140));
+        assert!(!region.intersects(128, 0, 256, 60));
+
+        region.dilate(STORAGE_CHUNK_SIZE * 3);
+        assert!(region.is_full());
+    }
+
+    #[test]
+    fn row_spans_cover_only_marked_chunks() {
+        let mut region = DirtyRegion::empty(80, 80);
+        region.mark_cell(70, 10);
+        assert_eq!(region.row_spans(10).collect::<Vec<_>>(), vec![64..80]);
+        assert_eq!(region.row_spans(40).count(), 0);
+    }
+}
@@ -0,0 +1,24 @@
+//! Disaster insurance and federal aid.
+//!
+//! Disaster systems document the property they destroy or damage, and each
+//! disaster becomes a claim once the damage stops for a few days. The city
+//! can buy an insurance policy: higher coverage levels cost a larger monthly
+//! premium on the insured property value but pay out more of each claim
+//! above a smaller deductible. Coverage applies from the level in force when
+//! the damage started.
+//!
+//! Once a claim is settled the city can ask for federal aid. After a review
+//! period, aid covers most of the uninsured damage if the documented loss
+//! clears the declaration threshold, and is denied otherwise. Premiums,
+//! payouts and aid all flow through the monthly budget collection and show
+//! up in `ExtendedBudget`'s income and expense breakdowns.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    document_damage, handle_aid_requests, settle_claims, update_premium, DisasterInsurancePlugin,
+};
+pub use types::*;
@@ -0,0 +1,194 @@
+//! Damage documentation, claim settlement, premiums and aid requests.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::flood_simulation::FloodState;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Record damage reported by disaster systems, plus the damage of the
+/// current flood each time it reaches a new peak.
+pub fn document_damage(
+    clock: Res<GameClock>,
+    flood: Res<FloodState>,
+    mut events: EventReader<DisasterDamage>,
+    mut insurance: ResMut<DisasterInsurance>,
+) {
+    for damage in events.read() {
+        insurance.document(damage, clock.day);
+    }
+
+    if !flood.is_flooding {
+        if insurance.flood_peak > 0.0 {
+            insurance.flood_peak = 0.0;
+        }
+        return;
+    }
+    if flood.total_damage > insurance.flood_peak {
+        let damage = DisasterDamage {
+            kind: DisasterKind::Flood,
+            loss: flood.total_damage - insurance.flood_peak,
+            destroyed: 0,
+            damaged: 0,
+            displaced: 0,
+        };
+        insurance.flood_peak = flood.total_damage;
+        insurance.document(&damage, clock.day);
+    }
+}
+
+/// Every slow tick: settle claims whose window has closed and decide on aid
+/// requests whose review is over. Money is paid at the next collection.
+pub fn settle_claims(
+    slow_timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    mut insurance: ResMut<DisasterInsurance>,
+    mut settled: EventWriter<ClaimSettled>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let insurance = &mut *insurance;
+    for claim in &mut insurance.claims {
+        if !claim.settled && clock.day > claim.last_day + CLAIM_WINDOW_DAYS {
+            claim.settled = true;
+            claim.insurance_payout = claim.coverage.payout(claim.loss);
+            insurance.pending_payouts += claim.insurance_payout;
+            insurance.total_payouts += claim.insurance_payout;
+            settled.send(ClaimSettled { claim_id: claim.id });
+            let text = if claim.insurance_payout > 0.0 {
+                format!(
+                    "Insurance will pay ${:.0} of ${:.0} in {} damage",
+                    claim.insurance_payout,
+                    claim.loss,
+                    claim.kind.name().to_lowercase()
+                )
+            } else {
+                format!(
+                    "${:.0} in {} damage is not covered by insurance",
+                    claim.loss,
+                    claim.kind.name().to_lowercase()
+                )
+            };
+            notifications.send(NotificationEvent {
+                text,
+                priority: NotificationPriority::Info,
+                category: NotificationCategory::Finance,
+                location: None,
+            });
+        }
+
+        let AidStatus::Pending { decision_day } = claim.aid else {
+            continue;
+        };
+        if clock.day < decision_day {
+            continue;
+        }
+        if claim.qualifies_for_aid() {
+            let amount = claim.aid_amount();
+            claim.aid = AidStatus::Approved { amount };
+            insurance.pending_aid += amount;
+            insurance.total_aid += amount;
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "Federal disaster aid approved: ${amount:.0} for the {}",
+                    claim.kind.name().to_lowercase()
+                ),
+                priority: NotificationPriority::Positive,
+                category: NotificationCategory::Finance,
+                location: None,
+            });
+        } else {
+            claim.aid = AidStatus::Denied;
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "Federal aid denied: {} damage below the ${:.0} declaration threshold",
+                    claim.kind.name().to_lowercase(),
+                    AID_DECLARATION_THRESHOLD
+                ),
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::Finance,
+                location: None,
+            });
+        }
+    }
+}
+
+/// Every slow tick, price next month's premium from the insured property
+/// value.
+pub fn update_premium(
+    slow_timer: Res<SlowTickTimer>,
+    mut insurance: ResMut<DisasterInsurance>,
+    buildings: Query<&Building>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let rate = insurance.coverage.premium_rate();
+    let premium = if rate > 0.0 {
+        buildings.iter().map(property_value).sum::<f64>() * rate
+    } else {
+        0.0
+    };
+    if insurance.monthly_premium != premium {
+        insurance.monthly_premium = premium;
+    }
+}
+
+/// Apply aid requests from the UI.
+pub fn handle_aid_requests(
+    mut requests: EventReader<AidRequest>,
+    clock: Res<GameClock>,
+    mut insurance: ResMut<DisasterInsurance>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for request in requests.read() {
+        if !insurance.request_aid(request.claim_id, clock.day) {
+            continue;
+        }
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Federal aid requested; a decision is expected in {AID_REVIEW_DAYS} days"
+            ),
+            priority: NotificationPriority::Info,
+            category: NotificationCategory::Finance,
+            location: None,
+        });
+    }
+}
+
+pub struct DisasterInsurancePlugin;
+
+impl Plugin for DisasterInsurancePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisasterInsurance>()
+            .add_event::<DisasterDamage>()
+            .add_event::<ClaimSettled>()
+            .add_event::<AidRequest>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    document_damage
+                        .after(crate::disasters::process_active_disaster)
+                        .after(crate::hurricane::advance_hurricane)
+                        .after(crate::flood_simulation::update_flood_simulation),
+                    settle_claims,
+                    update_premium,
+                )
+                    .chain()
+                    .in_set(crate::SimulationSet::Simulation),
+            )
+            .add_systems(Update, handle_aid_requests);
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<DisasterInsurance>();
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::Saveable;
+
+fn damage(kind: DisasterKind, loss: f64) -> DisasterDamage {
+    DisasterDamage {
+        kind,
+        loss,
+        destroyed: 1,
+        damaged: 0,
+        displaced: 0,
+    }
+}
+
+#[test]
+fn test_higher_coverage_costs_more_and_pays_more() {
+    for pair in CoverageLevel::ALL.windows(2) {
+        assert!(pair[1].premium_rate() > pair[0].premium_rate());
+        assert!(pair[1].payout(200_000.0) > pair[0].payout(200_000.0));
+    }
+}
+
+#[test]
+fn test_payout_respects_deductible() {
+    let level = CoverageLevel::Basic;
+    assert_eq!(level.payout(level.deductible()), 0.0);
+    assert_eq!(CoverageLevel::None.payout(1_000_000.0), 0.0);
+}
+
+#[test]
+fn test_damage_within_window_joins_claim() {
+    let mut insurance = DisasterInsurance::default();
+    insurance.document(&damage(DisasterKind::Hurricane, 10_000.0), 5);
+    insurance.document(
+        &damage(DisasterKind::Hurricane, 5_000.0),
+        5 + CLAIM_WINDOW_DAYS,
+    );
+    insurance.document(&damage(DisasterKind::Flood, 1_000.0), 6);
+    assert_eq!(insurance.claims.len(), 2);
+    assert_eq!(insurance.claims[0].loss, 15_000.0);
+    assert_eq!(insurance.claims[0].destroyed, 2);
+
+    insurance.document(&damage(DisasterKind::Hurricane, 1_000.0), 30);
+    assert_eq!(insurance.claims.len(), 3, "a later storm is a new claim");
+}
+
+#[test]
+fn test_claim_keeps_coverage_in_force_when_damage_started() {
+    let mut insurance = DisasterInsurance::default();
+    insurance.document(&damage(DisasterKind::Flood, 100_000.0), 1);
+    insurance.coverage = CoverageLevel::Comprehensive;
+    insurance.document(&damage(DisasterKind::Flood, 100_000.0), 2);
+    assert_eq!(insurance.claims[0].coverage, CoverageLevel::None);
+}
+
+#[test]
+fn test_aid_only_for_settled_claims_once() {
+    let mut insurance = DisasterInsurance::default();
+    insurance.document(&damage(DisasterKind::Tornado, 80_000.0), 1);
+    assert!(!insurance.request_aid(0, 2), "claim still open");
+    insurance.claims[0].settled = true;
+    assert!(insurance.request_aid(0, 10));
+    assert_eq!(
+        insurance.claims[0].aid,
+        AidStatus::Pending {
+            decision_day: 10 + AID_REVIEW_DAYS
+        }
+    );
+    assert!(!insurance.request_aid(0, 11));
+    assert!(!insurance.request_aid(99, 11));
+}
+
+#[test]
+fn test_aid_covers_share_of_uninsured_loss() {
+    let mut insurance = DisasterInsurance::default();
+    insurance.document(&damage(DisasterKind::Earthquake, 100_000.0), 1);
+    let claim = &mut insurance.claims[0];
+    claim.insurance_payout = 40_000.0;
+    assert!(claim.qualifies_for_aid());
+    assert_eq!(claim.aid_amount(), 60_000.0 * AID_COST_SHARE);
+}
+
+#[test]
+fn test_collect_drains_pending_money() {
+    let mut insurance = DisasterInsurance {
+        monthly_premium: 300.0,
+        pending_payouts: 1_000.0,
+        pending_aid: 2_000.0,
+        ..Default::default()
+    };
+    assert_eq!(insurance.collect(), (300.0, 1_000.0, 2_000.0));
+    assert_eq!(insurance.collect(), (300.0, 0.0, 0.0));
+    assert_eq!(insurance.total_premiums, 600.0);
+}
+
+#[test]
+fn test_old_claims_are_pruned() {
+    let mut insurance = DisasterInsurance::default();
+    for day in 0..(MAX_CLAIMS as u32 + 5) {
+        insurance.document(&damage(DisasterKind::Tornado, 1_000.0), day * 10);
+        for claim in &mut insurance.claims {
+            claim.settled = true;
+        }
+    }
+    assert_eq!(insurance.claims.len(), MAX_CLAIMS);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    let mut insurance = DisasterInsurance::default();
+    assert!(insurance.save_to_bytes().is_none());
+    insurance.coverage = CoverageLevel::Standard;
+    insurance.document(&damage(DisasterKind::Flood, 12_345.0), 4);
+    let bytes = insurance.save_to_bytes().expect("non-default state saves");
+    assert_eq!(DisasterInsurance::load_from_bytes(&bytes), insurance);
+}
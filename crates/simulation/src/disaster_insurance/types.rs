@@ -0,0 +1,324 @@
+//! Coverage levels, damage claims, aid requests and the saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::buildings::Building;
+use crate::disasters::DisasterType;
+use crate::flood_simulation::damage_curves::BASE_PROPERTY_VALUE_PER_CAPACITY;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Damage of one kind within this many days of the last is part of the same
+/// claim. Claims are settled once the window closes.
+pub const CLAIM_WINDOW_DAYS: u32 = 3;
+
+/// Days the federal government takes to decide on an aid request.
+pub const AID_REVIEW_DAYS: u32 = 14;
+
+/// Documented damage below this does not qualify for federal aid.
+pub const AID_DECLARATION_THRESHOLD: f64 = 50_000.0;
+
+/// Share of the uninsured documented damage that approved aid covers.
+pub const AID_COST_SHARE: f64 = 0.75;
+
+/// Settled claims kept for the report.
+pub const MAX_CLAIMS: usize = 20;
+
+/// Replacement value of a building, as used by the flood damage curves.
+pub fn property_value(building: &Building) -> f64 {
+    building.capacity as f64 * building.level as f64 * BASE_PROPERTY_VALUE_PER_CAPACITY
+}
+
+// ---------------------------------------------------------------------------
+// Coverage
+// ---------------------------------------------------------------------------
+
+/// The city's insurance policy. Higher coverage pays out more of each claim
+/// for a higher premium.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum CoverageLevel {
+    #[default]
+    None,
+    Basic,
+    Standard,
+    Comprehensive,
+}
+
+impl CoverageLevel {
+    pub const ALL: [CoverageLevel; 4] = [
+        CoverageLevel::None,
+        CoverageLevel::Basic,
+        CoverageLevel::Standard,
+        CoverageLevel::Comprehensive,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CoverageLevel::None => "Uninsured",
+            CoverageLevel::Basic => "Basic",
+            CoverageLevel::Standard => "Standard",
+            CoverageLevel::Comprehensive => "Comprehensive",
+        }
+    }
+
+    /// Monthly premium as a fraction of the insured property value.
+    pub fn premium_rate(self) -> f64 {
+        match self {
+            CoverageLevel::None => 0.0,
+            CoverageLevel::Basic => 0.000_4,
+            CoverageLevel::Standard => 0.000_8,
+            CoverageLevel::Comprehensive => 0.001_5,
+        }
+    }
+
+    /// Share of each claim above the deductible that the insurer pays.
+    pub fn coverage(self) -> f64 {
+        match self {
+            CoverageLevel::None => 0.0,
+            CoverageLevel::Basic => 0.5,
+            CoverageLevel::Standard => 0.75,
+            CoverageLevel::Comprehensive => 0.9,
+        }
+    }
+
+    /// Damage the city pays itself before the insurer pays anything.
+    pub fn deductible(self) -> f64 {
+        match self {
+            CoverageLevel::None => 0.0,
+            CoverageLevel::Basic => 25_000.0,
+            CoverageLevel::Standard => 10_000.0,
+            CoverageLevel::Comprehensive => 5_000.0,
+        }
+    }
+
+    /// Insurance payout for `loss` worth of damage.
+    pub fn payout(self, loss: f64) -> f64 {
+        (loss - self.deductible()).max(0.0) * self.coverage()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Damage and claims
+// ---------------------------------------------------------------------------
+
+/// The kind of disaster behind a claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum DisasterKind {
+    Tornado,
+    Earthquake,
+    Flood,
+    Hurricane,
+}
+
+impl DisasterKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            DisasterKind::Tornado => "Tornado",
+            DisasterKind::Earthquake => "Earthquake",
+            DisasterKind::Flood => "Flood",
+            DisasterKind::Hurricane => "Hurricane",
+        }
+    }
+}
+
+impl From<DisasterType> for DisasterKind {
+    fn from(disaster: DisasterType) -> Self {
+        match disaster {
+            DisasterType::Tornado => DisasterKind::Tornado,
+            DisasterType::Earthquake => DisasterKind::Earthquake,
+            DisasterType::Flood => DisasterKind::Flood,
+        }
+    }
+}
+
+/// Sent by disaster systems to document property damage.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DisasterDamage {
+    pub kind: DisasterKind,
+    /// Replacement value lost.
+    pub loss: f64,
+    pub destroyed: u32,
+    pub damaged: u32,
+    /// Residents of destroyed homes.
+    pub displaced: u32,
+}
+
+/// Progress of a federal aid request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub enum AidStatus {
+    #[default]
+    NotRequested,
+    Pending {
+        decision_day: u32,
+    },
+    Approved {
+        amount: f64,
+    },
+    Denied,
+}
+
+/// Documented damage from one disaster and what was paid for it.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct DamageClaim {
+    pub id: u32,
+    pub kind: DisasterKind,
+    pub first_day: u32,
+    /// Day damage was last documented.
+    pub last_day: u32,
+    pub loss: f64,
+    pub destroyed: u32,
+    pub damaged: u32,
+    pub displaced: u32,
+    /// Policy in force when the damage started.
+    pub coverage: CoverageLevel,
+    /// Whether the claim window has closed and the insurer has paid.
+    pub settled: bool,
+    pub insurance_payout: f64,
+    pub aid: AidStatus,
+}
+
+impl DamageClaim {
+    /// Whether damage on `day` still belongs to this claim.
+    pub fn is_open(&self, day: u32) -> bool {
+        !self.settled && day <= self.last_day + CLAIM_WINDOW_DAYS
+    }
+
+    /// Whether the documented damage is large enough for federal aid.
+    pub fn qualifies_for_aid(&self) -> bool {
+        self.loss >= AID_DECLARATION_THRESHOLD
+    }
+
+    /// Federal aid for the damage insurance did not cover.
+    pub fn aid_amount(&self) -> f64 {
+        (self.loss - self.insurance_payout).max(0.0) * AID_COST_SHARE
+    }
+
+    pub fn can_request_aid(&self) -> bool {
+        self.settled && self.aid == AidStatus::NotRequested
+    }
+}
+
+/// The city's insurance policy, its damage claims and the payouts waiting
+/// for the next budget collection.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct DisasterInsurance {
+    pub coverage: CoverageLevel,
+    /// Premium charged at the next collection.
+    pub monthly_premium: f64,
+    pub claims: Vec<DamageClaim>,
+    pub next_claim_id: u32,
+    /// Highest damage of the current flood, so rising water is only
+    /// documented once.
+    pub flood_peak: f64,
+    /// Insurance payouts and approved aid not yet collected.
+    pub pending_payouts: f64,
+    pub pending_aid: f64,
+    pub total_premiums: f64,
+    pub total_payouts: f64,
+    pub total_aid: f64,
+}
+
+impl DisasterInsurance {
+    /// Document damage on `day`, adding to the open claim for the same kind
+    /// of disaster or opening a new one.
+    pub fn document(&mut self, damage: &DisasterDamage, day: u32) {
+        if let Some(claim) = self
+            .claims
+            .iter_mut()
+            .rev()
+            .find(|c| c.kind == damage.kind && c.is_open(day))
+        {
+            claim.last_day = day;
+            claim.loss += damage.loss;
+            claim.destroyed += damage.destroyed;
+            claim.damaged += damage.damaged;
+            claim.displaced += damage.displaced;
+            return;
+        }
+        self.claims.push(DamageClaim {
+            id: self.next_claim_id,
+            kind: damage.kind,
+            first_day: day,
+            last_day: day,
+            loss: damage.loss,
+            destroyed: damage.destroyed,
+            damaged: damage.damaged,
+            displaced: damage.displaced,
+            coverage: self.coverage,
+            settled: false,
+            insurance_payout: 0.0,
+            aid: AidStatus::NotRequested,
+        });
+        self.next_claim_id += 1;
+        if self.claims.len() > MAX_CLAIMS {
+            if let Some(oldest) = self
+                .claims
+                .iter()
+                .position(|c| c.settled && !matches!(c.aid, AidStatus::Pending { .. }))
+            {
+                self.claims.remove(oldest);
+            }
+        }
+    }
+
+    pub fn claim(&self, id: u32) -> Option<&DamageClaim> {
+        self.claims.iter().find(|c| c.id == id)
+    }
+
+    /// File a federal aid request for a settled claim. Returns false if the
+    /// claim is unknown, still open or already requested.
+    pub fn request_aid(&mut self, id: u32, day: u32) -> bool {
+        let Some(claim) = self.claims.iter_mut().find(|c| c.id == id) else {
+            return false;
+        };
+        if !claim.can_request_aid() {
+            return false;
+        }
+        claim.aid = AidStatus::Pending {
+            decision_day: day + AID_REVIEW_DAYS,
+        };
+        true
+    }
+
+    /// Take this month's premium, payouts and aid for the budget collection,
+    /// returning `(premium, payouts, aid)`.
+    pub fn collect(&mut self) -> (f64, f64, f64) {
+        let premium = self.monthly_premium;
+        let payouts = std::mem::take(&mut self.pending_payouts);
+        let aid = std::mem::take(&mut self.pending_aid);
+        self.total_premiums += premium;
+        (premium, payouts, aid)
+    }
+}
+
+impl Saveable for DisasterInsurance {
+    const SAVE_KEY: &'static str = "disaster_insurance";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Sent when a claim's window closes and the insurer has paid.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ClaimSettled {
+    pub claim_id: u32,
+}
+
+/// Sent by the UI to ask for federal aid for a settled claim.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AidRequest {
+    pub claim_id: u32,
+}
@@ -0,0 +1,24 @@
+//! Disaster reports and recovery.
+//!
+//! Once a disaster's insurance claim is settled, a structured report of the
+//! buildings destroyed and damaged, casualties, economic loss and insurance
+//! payout is logged to the event journal.
+//!
+//! Destroyed buildings leave debris behind, and nothing can be built on a
+//! debris cell until cleanup crews clear it. The city always has one crew,
+//! plus one for each garbage service building, and pays for every cell
+//! cleared. Residents of destroyed homes are displaced: those without a
+//! shelter bed add to residential demand until they resettle. While
+//! reconstruction subsidies are on, buildings started on cleared sites are
+//! built in half the time for a payment from the treasury.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    clear_debris, publish_disaster_reports, subsidize_reconstruction, temporary_housing_demand,
+    track_displaced, DisasterRecoveryPlugin,
+};
+pub use types::*;
@@ -0,0 +1,163 @@
+//! Disaster reports, debris clearing, temporary housing demand and
+//! reconstruction subsidies.
+
+use bevy::prelude::*;
+
+use crate::buildings::{Building, UnderConstruction};
+use crate::disaster_insurance::{ClaimSettled, DisasterDamage, DisasterInsurance};
+use crate::economy::CityBudget;
+use crate::emergency_management::EmergencyManagementState;
+use crate::events::{CityEvent, CityEventType, EventJournal};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::ServiceBuilding;
+use crate::time_of_day::GameClock;
+use crate::zones::ZoneDemand;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Log a structured report to the event journal for each settled claim.
+pub fn publish_disaster_reports(
+    clock: Res<GameClock>,
+    insurance: Res<DisasterInsurance>,
+    emergency: Res<EmergencyManagementState>,
+    mut settled: EventReader<ClaimSettled>,
+    mut journal: ResMut<EventJournal>,
+) {
+    for event in settled.read() {
+        let Some(claim) = insurance.claim(event.claim_id) else {
+            continue;
+        };
+        let report = disaster_report(claim, emergency.casualty_modifier);
+        let description = format!(
+            "{} report: {} buildings destroyed, {} damaged, {} casualties, \
+             ${:.0} in losses, ${:.0} paid by insurance",
+            report.disaster,
+            report.buildings_destroyed,
+            report.buildings_damaged,
+            report.casualties,
+            report.economic_loss,
+            report.insurance_payout
+        );
+        journal.push(CityEvent {
+            event_type: CityEventType::DisasterReport(report),
+            day: clock.day,
+            hour: clock.hour,
+            description,
+        });
+    }
+}
+
+/// Residents of destroyed homes join the displaced.
+pub fn track_displaced(mut events: EventReader<DisasterDamage>, mut state: ResMut<RecoveryState>) {
+    for damage in events.read() {
+        if damage.displaced > 0 {
+            state.displaced += damage.displaced;
+        }
+    }
+}
+
+/// Every slow tick, cleanup crews clear the oldest debris as far as the
+/// treasury allows.
+pub fn clear_debris(
+    slow_timer: Res<SlowTickTimer>,
+    mut budget: ResMut<CityBudget>,
+    mut state: ResMut<RecoveryState>,
+    services: Query<&ServiceBuilding>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() || state.debris.is_empty() {
+        return;
+    }
+
+    let crews = 1 + services
+        .iter()
+        .filter(|s| ServiceBuilding::is_garbage(s.service_type))
+        .count();
+    let affordable = (budget.treasury.max(0.0) / DEBRIS_CLEARING_COST) as usize;
+    let count = (crews * DEBRIS_CELLS_PER_CREW).min(affordable);
+    if count == 0 {
+        return;
+    }
+    let cleared = state.clear_debris(count);
+    budget.treasury -= cleared.len() as f64 * DEBRIS_CLEARING_COST;
+
+    if state.debris.is_empty() {
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Disaster debris cleared; {} sites are ready for rebuilding",
+                state.cleared_sites.len()
+            ),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::Disaster,
+            location: None,
+        });
+    }
+}
+
+/// Every slow tick, displaced residents without a shelter bed add demand for
+/// housing, and some of the displaced find a permanent home.
+pub fn temporary_housing_demand(
+    slow_timer: Res<SlowTickTimer>,
+    emergency: Res<EmergencyManagementState>,
+    mut state: ResMut<RecoveryState>,
+    mut demand: ResMut<ZoneDemand>,
+) {
+    if !slow_timer.should_run() || state.displaced == 0 {
+        return;
+    }
+    let extra = state.housing_demand(emergency.shelter_capacity);
+    if extra > 0.0 {
+        demand.residential = (demand.residential + extra).min(1.0);
+    }
+    state.resettle();
+}
+
+/// Buildings started on cleared disaster sites count as rebuilt and, while
+/// subsidies are on, go up faster at the city's expense.
+pub fn subsidize_reconstruction(
+    mut budget: ResMut<CityBudget>,
+    mut state: ResMut<RecoveryState>,
+    mut started: Query<(&Building, &mut UnderConstruction), Added<UnderConstruction>>,
+) {
+    if state.cleared_sites.is_empty() {
+        return;
+    }
+    for (building, mut construction) in &mut started {
+        if !state.rebuild(building.grid_x, building.grid_y) {
+            continue;
+        }
+        if !state.subsidies || budget.treasury < RECONSTRUCTION_SUBSIDY {
+            continue;
+        }
+        let saved = (construction.ticks_remaining as f32 * SUBSIDY_SPEEDUP) as u32;
+        construction.ticks_remaining -= saved;
+        construction.total_ticks -= saved;
+        budget.treasury -= RECONSTRUCTION_SUBSIDY;
+        state.total_subsidies += RECONSTRUCTION_SUBSIDY;
+    }
+}
+
+pub struct DisasterRecoveryPlugin;
+
+impl Plugin for DisasterRecoveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecoveryState>().add_systems(
+            FixedUpdate,
+            (
+                publish_disaster_reports.after(crate::disaster_insurance::settle_claims),
+                track_displaced.after(crate::disasters::process_active_disaster),
+                clear_debris,
+                temporary_housing_demand.after(crate::zones::update_zone_demand),
+                subsidize_reconstruction,
+            )
+                .chain()
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<RecoveryState>();
+    }
+}
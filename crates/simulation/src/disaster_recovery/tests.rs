@@ -0,0 +1,129 @@
+use super::*;
+use crate::disaster_insurance::{AidStatus, CoverageLevel, DamageClaim, DisasterKind};
+use crate::Saveable;
+
+fn claim(displaced: u32) -> DamageClaim {
+    DamageClaim {
+        id: 0,
+        kind: DisasterKind::Earthquake,
+        first_day: 12,
+        last_day: 13,
+        loss: 90_000.0,
+        destroyed: 4,
+        damaged: 6,
+        displaced,
+        coverage: CoverageLevel::Standard,
+        settled: true,
+        insurance_payout: 60_000.0,
+        aid: AidStatus::NotRequested,
+    }
+}
+
+#[test]
+fn test_casualties_scale_with_modifier() {
+    assert_eq!(casualties(0, 1.0), 0);
+    assert_eq!(casualties(100, 1.0), 2);
+    assert_eq!(casualties(100, 0.5), 1);
+    assert_eq!(casualties(100, 2.0), 4);
+}
+
+#[test]
+fn test_report_copies_claim() {
+    let report = disaster_report(&claim(200), 1.0);
+    assert_eq!(report.disaster, "Earthquake");
+    assert_eq!(report.day, 12);
+    assert_eq!(report.buildings_destroyed, 4);
+    assert_eq!(report.buildings_damaged, 6);
+    assert_eq!(report.casualties, 4);
+    assert_eq!(report.economic_loss, 90_000.0);
+    assert_eq!(report.insurance_payout, 60_000.0);
+}
+
+#[test]
+fn test_debris_added_once() {
+    let mut state = RecoveryState::default();
+    state.add_debris(10, 20);
+    state.add_debris(10, 20);
+    assert_eq!(state.debris.len(), 1);
+    assert!(state.has_debris(10, 20));
+    assert!(!state.has_debris(20, 10));
+}
+
+#[test]
+fn test_clearing_takes_oldest_first() {
+    let mut state = RecoveryState::default();
+    state.add_debris(1, 1);
+    state.add_debris(2, 2);
+    state.add_debris(3, 3);
+    assert_eq!(state.clear_debris(2), vec![(1, 1), (2, 2)]);
+    assert!(state.has_debris(3, 3));
+    assert_eq!(state.cleared_sites.len(), 2);
+    assert_eq!(state.total_cleared, 2);
+    assert_eq!(state.clear_debris(5), vec![(3, 3)]);
+    assert!(state.debris.is_empty());
+}
+
+#[test]
+fn test_cleared_sites_capped() {
+    let mut state = RecoveryState::default();
+    for i in 0..MAX_CLEARED_SITES + 10 {
+        state.add_debris(i % 200, i / 200);
+    }
+    state.clear_debris(MAX_CLEARED_SITES + 10);
+    assert_eq!(state.cleared_sites.len(), MAX_CLEARED_SITES);
+    assert!(!state.rebuild(0, 0), "oldest sites are forgotten");
+}
+
+#[test]
+fn test_rebuild_only_on_cleared_sites() {
+    let mut state = RecoveryState::default();
+    state.add_debris(5, 5);
+    assert!(!state.rebuild(5, 5), "debris is not a cleared site");
+    state.clear_debris(1);
+    assert!(state.rebuild(5, 5));
+    assert!(!state.rebuild(5, 5));
+    assert_eq!(state.total_rebuilt, 1);
+}
+
+#[test]
+fn test_housing_demand_counts_unsheltered() {
+    let state = RecoveryState {
+        displaced: 100,
+        ..Default::default()
+    };
+    assert_eq!(state.unsheltered(40), 60);
+    assert_eq!(state.housing_demand(100), 0.0);
+    assert!((state.housing_demand(40) - 60.0 * HOUSING_DEMAND_PER_RESIDENT).abs() < 1e-6);
+    let crowded = RecoveryState {
+        displaced: 10_000,
+        ..Default::default()
+    };
+    assert_eq!(crowded.housing_demand(0), MAX_HOUSING_DEMAND);
+}
+
+#[test]
+fn test_resettle_reaches_zero() {
+    let mut state = RecoveryState {
+        displaced: 30,
+        ..Default::default()
+    };
+    state.resettle();
+    assert_eq!(state.displaced, 28);
+    for _ in 0..100 {
+        state.resettle();
+    }
+    assert_eq!(state.displaced, 0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(RecoveryState::default().save_to_bytes().is_none());
+    let mut state = RecoveryState {
+        displaced: 12,
+        subsidies: true,
+        ..Default::default()
+    };
+    state.add_debris(7, 9);
+    let bytes = state.save_to_bytes().expect("non-default state saves");
+    assert_eq!(RecoveryState::load_from_bytes(&bytes), state);
+}
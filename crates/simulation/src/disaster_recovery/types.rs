@@ -0,0 +1,162 @@
+//! Debris, displaced residents, reconstruction subsidies and the saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::GRID_WIDTH;
+use crate::disaster_insurance::DamageClaim;
+use crate::events::DisasterReport;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Share of residents of destroyed homes who become casualties, before the
+/// emergency management casualty modifier.
+pub const CASUALTY_RATE: f32 = 0.02;
+
+/// Debris cells one cleanup crew clears each slow tick. The city always has
+/// one crew, plus one per garbage service building.
+pub const DEBRIS_CELLS_PER_CREW: usize = 2;
+
+/// Cost of clearing one debris cell.
+pub const DEBRIS_CLEARING_COST: f64 = 400.0;
+
+/// Share of the displaced who find permanent housing each slow tick.
+pub const RESETTLE_RATE: f32 = 0.05;
+
+/// Residential demand added per displaced resident without a shelter bed.
+pub const HOUSING_DEMAND_PER_RESIDENT: f32 = 0.002;
+
+/// Cap on the residential demand added by temporary housing needs.
+pub const MAX_HOUSING_DEMAND: f32 = 0.3;
+
+/// City subsidy for each building started on a cleared disaster site.
+pub const RECONSTRUCTION_SUBSIDY: f64 = 1_500.0;
+
+/// Share of the construction time a subsidy saves.
+pub const SUBSIDY_SPEEDUP: f32 = 0.5;
+
+/// Cleared sites remembered for subsidies; the oldest are forgotten first.
+pub const MAX_CLEARED_SITES: usize = 1_024;
+
+/// Casualties among `displaced` residents of destroyed homes.
+pub fn casualties(displaced: u32, casualty_modifier: f32) -> u32 {
+    (displaced as f32 * CASUALTY_RATE * casualty_modifier).round() as u32
+}
+
+/// Structured report for a settled claim, as logged to the event journal.
+pub fn disaster_report(claim: &DamageClaim, casualty_modifier: f32) -> DisasterReport {
+    DisasterReport {
+        disaster: claim.kind.name().to_string(),
+        day: claim.first_day,
+        buildings_destroyed: claim.destroyed,
+        buildings_damaged: claim.damaged,
+        casualties: casualties(claim.displaced, casualty_modifier),
+        economic_loss: claim.loss,
+        insurance_payout: claim.insurance_payout,
+    }
+}
+
+fn cell_index(x: usize, y: usize) -> u32 {
+    (y * GRID_WIDTH + x) as u32
+}
+
+fn cell_coords(index: u32) -> (usize, usize) {
+    let index = index as usize;
+    (index % GRID_WIDTH, index / GRID_WIDTH)
+}
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// What is left to do after disasters: debris blocking reconstruction,
+/// residents without a home, and sites cleared for rebuilding.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct RecoveryState {
+    /// Cells covered in debris, oldest first.
+    pub debris: Vec<u32>,
+    /// Cleared cells not yet rebuilt, oldest first.
+    pub cleared_sites: Vec<u32>,
+    /// Residents still without permanent housing.
+    pub displaced: u32,
+    /// Whether the city subsidizes reconstruction on cleared sites.
+    pub subsidies: bool,
+    pub total_cleared: u32,
+    pub total_rebuilt: u32,
+    pub total_subsidies: f64,
+}
+
+impl RecoveryState {
+    pub fn has_debris(&self, x: usize, y: usize) -> bool {
+        self.debris.contains(&cell_index(x, y))
+    }
+
+    pub fn add_debris(&mut self, x: usize, y: usize) {
+        let index = cell_index(x, y);
+        if !self.debris.contains(&index) {
+            self.debris.push(index);
+        }
+    }
+
+    /// Clear up to `count` of the oldest debris cells, returning the cells
+    /// now ready for rebuilding.
+    pub fn clear_debris(&mut self, count: usize) -> Vec<(usize, usize)> {
+        let count = count.min(self.debris.len());
+        let cleared: Vec<u32> = self.debris.drain(..count).collect();
+        self.total_cleared += cleared.len() as u32;
+        self.cleared_sites.extend(&cleared);
+        if self.cleared_sites.len() > MAX_CLEARED_SITES {
+            let excess = self.cleared_sites.len() - MAX_CLEARED_SITES;
+            self.cleared_sites.drain(..excess);
+        }
+        cleared.into_iter().map(cell_coords).collect()
+    }
+
+    /// Mark a cleared site as rebuilt. Returns false if the cell was not a
+    /// cleared disaster site.
+    pub fn rebuild(&mut self, x: usize, y: usize) -> bool {
+        let index = cell_index(x, y);
+        let Some(pos) = self.cleared_sites.iter().position(|&c| c == index) else {
+            return false;
+        };
+        self.cleared_sites.remove(pos);
+        self.total_rebuilt += 1;
+        true
+    }
+
+    /// Displaced residents who have no shelter bed.
+    pub fn unsheltered(&self, shelter_capacity: u32) -> u32 {
+        self.displaced.saturating_sub(shelter_capacity)
+    }
+
+    /// Residential demand for temporary housing.
+    pub fn housing_demand(&self, shelter_capacity: u32) -> f32 {
+        (self.unsheltered(shelter_capacity) as f32 * HOUSING_DEMAND_PER_RESIDENT)
+            .min(MAX_HOUSING_DEMAND)
+    }
+
+    /// Some of the displaced find permanent housing.
+    pub fn resettle(&mut self) {
+        let resettled = (self.displaced as f32 * RESETTLE_RATE).ceil() as u32;
+        self.displaced = self.displaced.saturating_sub(resettled);
+    }
+}
+
+impl Saveable for RecoveryState {
+    const SAVE_KEY: &'static str = "disaster_recovery";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,112 @@
+//! Earthquakes: where they strike, how far they reach and what the shaking
+//! does to buildings. The damage model itself lives in `crate::seismic`.
+
+use bevy::prelude::*;
+
+use super::{rand_f32, rand_usize, splitmix64};
+use crate::buildings::Building;
+use crate::grid::{CellType, WorldGrid};
+use crate::seismic::{QuakeDamage, QuakeReport, SeismicState};
+use crate::terrain_generation::BiomeGrid;
+
+/// A point on one of the city's fault lines, if it is on land.
+pub(super) fn fault_epicenter_on_land(
+    seed: u64,
+    seismic: &SeismicState,
+    grid: &WorldGrid,
+) -> Option<(usize, usize)> {
+    let fault_seed = splitmix64(seed.wrapping_mul(0xfa17));
+    let (x, y) = crate::seismic::fault_epicenter(
+        &seismic.faults,
+        rand_usize(fault_seed, usize::MAX),
+        rand_f32(fault_seed.wrapping_add(1)),
+        rand_f32(fault_seed.wrapping_add(2)),
+        rand_f32(fault_seed.wrapping_add(3)),
+    )?;
+    (grid.get(x, y).cell_type != CellType::Water).then_some((x, y))
+}
+
+/// Felt radius of a quake with a random magnitude.
+pub(super) fn random_radius(seed: u64) -> usize {
+    let magnitude = crate::seismic::random_magnitude(rand_f32(seed.wrapping_mul(0x5e15)));
+    crate::seismic::felt_radius(magnitude)
+}
+
+/// Damage to the buildings in `sites` from a quake at `epicenter`. Returns
+/// the collapsed buildings and the levels each damaged building loses, and
+/// records the quake in `seismic.last_quake`.
+pub(super) fn damage_in_radius(
+    sites: &[(Entity, usize, usize, u8, f32)],
+    grid: &WorldGrid,
+    biomes: &BiomeGrid,
+    seismic: &mut SeismicState,
+    tick: u64,
+    epicenter: (usize, usize),
+    radius: usize,
+) -> (Vec<(Entity, usize, usize)>, Vec<(Entity, u8)>) {
+    let mut destroyed = Vec::new();
+    let mut downgraded = Vec::new();
+    // Damage follows the shaking at each site; retrofits help
+    let mut retrofits_held = 0;
+    for (idx, &(entity, gx, gy, level, _elev)) in sites.iter().enumerate() {
+        let shaking = crate::seismic::shaking_at(grid, biomes, epicenter, radius, (gx, gy));
+        let retrofitted = seismic.is_retrofitted(gx, gy);
+        let hash_seed = tick.wrapping_add(idx as u64).wrapping_mul(0xbadf00d);
+        match crate::seismic::quake_damage(shaking, retrofitted, rand_f32(hash_seed)) {
+            QuakeDamage::Collapse => destroyed.push((entity, gx, gy)),
+            QuakeDamage::LevelsLost(levels) => {
+                if level > 1 {
+                    downgraded.push((entity, levels));
+                }
+            }
+            QuakeDamage::None => {
+                if retrofitted {
+                    retrofits_held += 1;
+                }
+            }
+        }
+    }
+    for &(_, gx, gy) in &destroyed {
+        seismic.clear_cell(gx, gy);
+    }
+    seismic.last_quake = Some(QuakeReport {
+        epicenter,
+        magnitude: crate::seismic::magnitude_for_radius(radius),
+        collapsed: destroyed.len() as u32,
+        damaged: downgraded.len() as u32,
+        retrofits_held,
+    });
+    (destroyed, downgraded)
+}
+
+/// Marker component for buildings damaged by an earthquake.
+#[derive(Component)]
+pub struct EarthquakeDamaged {
+    /// Levels the building loses (never below level 1).
+    pub levels_lost: u8,
+}
+
+/// System that applies earthquake damage to marked buildings, then removes the marker.
+/// Each lost level also wears down the building's structural condition.
+pub fn apply_earthquake_damage(
+    mut commands: Commands,
+    mut buildings: Query<(Entity, &mut Building, &EarthquakeDamaged)>,
+    mut structural: ResMut<crate::structural_integrity::StructuralState>,
+) {
+    for (entity, mut building, damage) in &mut buildings {
+        structural.wear(
+            building.grid_x,
+            building.grid_y,
+            crate::structural_integrity::QUAKE_WEAR_PER_LEVEL * damage.levels_lost as f32,
+        );
+        if building.level > 1 {
+            building.level = building.level.saturating_sub(damage.levels_lost).max(1);
+            building.capacity = Building::capacity_for_level(building.zone_type, building.level);
+            // Evict excess occupants
+            if building.occupants > building.capacity {
+                building.occupants = building.capacity;
+            }
+        }
+        commands.entity(entity).remove::<EarthquakeDamaged>();
+    }
+}
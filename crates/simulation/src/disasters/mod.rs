@@ -2,11 +2,21 @@ use bevy::prelude::*;
 
 use crate::buildings::Building;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::disaster_insurance::{property_value, DisasterDamage};
+use crate::disaster_recovery::RecoveryState;
 use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::seismic::SeismicState;
+use crate::terrain_generation::BiomeGrid;
 use crate::SlowTickTimer;
 use crate::TickCounter;
 use crate::TestSafetyNet;
 
+mod earthquake;
+#[cfg(test)]
+mod tests;
+
+pub use earthquake::{apply_earthquake_damage, EarthquakeDamaged};
+
 // =============================================================================
 // Types
 // =============================================================================
@@ -43,6 +53,12 @@ pub struct ActiveDisaster {
     pub current: Option<DisasterInstance>,
 }
 
+/// Offsets the sequence of random disasters. The game always plays with 0;
+/// batch runs (`--batch`) set it to sweep over different disaster histories
+/// for the same city.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DisasterSeed(pub u64);
+
 // =============================================================================
 // Deterministic pseudo-random helpers (no rand crate)
 // =============================================================================
@@ -78,10 +94,10 @@ const TORNADO_RADIUS: usize = 5;
 const TORNADO_DURATION: u32 = 50;
 const TORNADO_DESTROY_PCT: f32 = 0.30;
 
-/// Earthquake configuration.
+/// Earthquake configuration. The radius is the felt radius at the
+/// reference magnitude; see `crate::seismic` for the damage model.
 const EARTHQUAKE_RADIUS: usize = 10;
-const EARTHQUAKE_DURATION: u32 = 20;
-const EARTHQUAKE_DESTROY_PCT: f32 = 0.10;
+pub(crate) const EARTHQUAKE_DURATION: u32 = 20;
 
 /// Flood configuration.
 const FLOOD_RADIUS: usize = 8;
@@ -94,12 +110,17 @@ const FLOOD_ELEVATION_THRESHOLD: f32 = 0.45;
 
 /// Triggers a random disaster with very low probability each slow tick.
 /// Only triggers if no disaster is currently active and disasters are enabled.
+/// Earthquakes strike near a fault line with a random magnitude.
+#[allow(clippy::too_many_arguments)]
 pub fn trigger_random_disaster(
     slow_timer: Res<SlowTickTimer>,
     tick: Res<TickCounter>,
     mut active: ResMut<ActiveDisaster>,
     grid: Res<WorldGrid>,
     weather: Res<crate::weather::Weather>,
+    settings: Res<crate::game_settings::GameSettings>,
+    seismic: Res<SeismicState>,
+    disaster_seed: Res<DisasterSeed>,
 ) {
     if !slow_timer.should_run() {
         return;
@@ -115,9 +136,10 @@ pub fn trigger_random_disaster(
         return;
     }
 
-    let seed = tick.0;
+    let seed = tick.0 ^ disaster_seed.0.wrapping_mul(0x9e3779b97f4a7c15);
     let roll = rand_f32(seed.wrapping_mul(0xdeadbeef));
-    if roll >= DISASTER_CHANCE {
+    // Difficulty scales the chance; the no-disasters toggle zeroes it
+    if roll >= DISASTER_CHANCE * settings.disaster_chance_multiplier() {
         return;
     }
 
@@ -148,13 +170,22 @@ pub fn trigger_random_disaster(
         }
     }
 
+    // Earthquakes start on a fault, if that spot is on land
+    if disaster_type == DisasterType::Earthquake {
+        if let Some((x, y)) = earthquake::fault_epicenter_on_land(seed, &seismic, &grid) {
+            center_x = x;
+            center_y = y;
+            found = true;
+        }
+    }
+
     if !found {
         return;
     }
 
     let (radius, duration) = match disaster_type {
         DisasterType::Tornado => (TORNADO_RADIUS, TORNADO_DURATION),
-        DisasterType::Earthquake => (EARTHQUAKE_RADIUS, EARTHQUAKE_DURATION),
+        DisasterType::Earthquake => (earthquake::random_radius(seed), EARTHQUAKE_DURATION),
         DisasterType::Flood => (FLOOD_RADIUS, FLOOD_DURATION),
     };
 
@@ -186,6 +217,10 @@ pub fn process_active_disaster(
     buildings: Query<(Entity, &Building)>,
     tick: Res<TickCounter>,
     safety_net: Option<Res<TestSafetyNet>>,
+    biomes: Res<BiomeGrid>,
+    mut seismic: ResMut<SeismicState>,
+    mut damage: EventWriter<DisasterDamage>,
+    mut recovery: ResMut<RecoveryState>,
 ) {
     if safety_net.is_some() {
         return;
@@ -223,7 +258,7 @@ pub fn process_active_disaster(
         }
 
         let mut destroyed: Vec<(Entity, usize, usize)> = Vec::new();
-        let mut downgraded: Vec<Entity> = Vec::new();
+        let mut downgraded: Vec<(Entity, u8)> = Vec::new();
 
         match dtype {
             DisasterType::Tornado => {
@@ -238,16 +273,15 @@ pub fn process_active_disaster(
                 }
             }
             DisasterType::Earthquake => {
-                // All buildings in radius lose 1 level (min 1), 10% destroyed
-                for (idx, &(entity, gx, gy, level, _elev)) in buildings_in_radius.iter().enumerate()
-                {
-                    let hash_seed = tick.0.wrapping_add(idx as u64).wrapping_mul(0xbadf00d);
-                    if rand_f32(hash_seed) < EARTHQUAKE_DESTROY_PCT {
-                        destroyed.push((entity, gx, gy));
-                    } else if level > 1 {
-                        downgraded.push(entity);
-                    }
-                }
+                (destroyed, downgraded) = earthquake::damage_in_radius(
+                    &buildings_in_radius,
+                    &grid,
+                    &biomes,
+                    &mut seismic,
+                    tick.0,
+                    (cx, cy),
+                    radius,
+                );
             }
             DisasterType::Flood => {
                 // Destroy all buildings on cells with elevation < threshold within radius
@@ -271,21 +305,52 @@ pub fn process_active_disaster(
             // Actually, we already have the entity IDs. We can't mutate through
             // an immutable query, so let's collect what we need and handle it
             // outside the borrow.
-            for entity in &downgraded {
+            for &(entity, levels_lost) in &downgraded {
                 // We'll use commands to insert a marker and handle downgrade below
-                commands.entity(*entity).insert(EarthquakeDamaged);
+                commands.entity(entity).insert(EarthquakeDamaged { levels_lost });
             }
         }
 
+        // Document the property loss for insurance and aid claims
+        let destroyed_loss: f64 = destroyed
+            .iter()
+            .filter_map(|&(entity, _, _)| buildings.get(entity).ok())
+            .map(|(_, building)| property_value(building))
+            .sum();
+        let downgraded_loss: f64 = downgraded
+            .iter()
+            .filter_map(|&(entity, levels)| Some((buildings.get(entity).ok()?.1, levels)))
+            .map(|(building, levels)| {
+                let lost = levels.min(building.level.saturating_sub(1));
+                property_value(building) * lost as f64 / building.level as f64
+            })
+            .sum();
+        let displaced: u32 = destroyed
+            .iter()
+            .filter_map(|&(entity, _, _)| buildings.get(entity).ok())
+            .filter(|(_, building)| building.zone_type.is_residential())
+            .map(|(_, building)| building.occupants)
+            .sum();
+        if destroyed_loss + downgraded_loss > 0.0 {
+            damage.send(DisasterDamage {
+                kind: dtype.into(),
+                loss: destroyed_loss + downgraded_loss,
+                destroyed: destroyed.len() as u32,
+                damaged: downgraded.len() as u32,
+                displaced,
+            });
+        }
+
         let destroyed_count = destroyed.len();
 
-        // Despawn destroyed buildings and clear grid cells
+        // Despawn destroyed buildings, clear grid cells and leave debris
         for (entity, gx, gy) in destroyed {
             let cell = grid.get_mut(gx, gy);
             if cell.building_id == Some(entity) {
                 cell.building_id = None;
             }
             cell.zone = ZoneType::None;
+            recovery.add_debris(gx, gy);
             commands.entity(entity).despawn();
         }
 
@@ -315,161 +380,23 @@ pub fn process_active_disaster(
     }
 }
 
-/// Marker component for buildings damaged by an earthquake (lose 1 level).
-#[derive(Component)]
-pub struct EarthquakeDamaged;
-
-/// System that applies earthquake damage to marked buildings, then removes the marker.
-pub fn apply_earthquake_damage(
-    mut commands: Commands,
-    mut buildings: Query<(Entity, &mut Building), With<EarthquakeDamaged>>,
-) {
-    for (entity, mut building) in &mut buildings {
-        if building.level > 1 {
-            building.level -= 1;
-            building.capacity = Building::capacity_for_level(building.zone_type, building.level);
-            // Evict excess occupants
-            if building.occupants > building.capacity {
-                building.occupants = building.capacity;
-            }
-        }
-        commands.entity(entity).remove::<EarthquakeDamaged>();
-    }
-}
-
-// =============================================================================
-// Tests
-// =============================================================================
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_disaster_type_name() {
-        assert_eq!(DisasterType::Tornado.name(), "Tornado");
-        assert_eq!(DisasterType::Earthquake.name(), "Earthquake");
-        assert_eq!(DisasterType::Flood.name(), "Flood");
-    }
-
-    #[test]
-    fn test_active_disaster_default() {
-        let ad = ActiveDisaster::default();
-        assert!(ad.current.is_none());
-    }
-
-    #[test]
-    fn test_splitmix64_deterministic() {
-        let a = splitmix64(42);
-        let b = splitmix64(42);
-        assert_eq!(a, b);
-        // Different seeds produce different values
-        let c = splitmix64(43);
-        assert_ne!(a, c);
-    }
-
-    #[test]
-    fn test_rand_f32_range() {
-        for seed in 0..1000u64 {
-            let val = rand_f32(seed);
-            assert!(
-                val >= 0.0 && val < 1.0,
-                "rand_f32({}) = {} out of range",
-                seed,
-                val
-            );
-        }
-    }
-
-    #[test]
-    fn test_rand_usize_range() {
-        for seed in 0..1000u64 {
-            let val = rand_usize(seed, 256);
-            assert!(
-                val < 256,
-                "rand_usize({}, 256) = {} out of range",
-                seed,
-                val
-            );
-        }
-    }
-
-    #[test]
-    fn test_disaster_chance_very_low() {
-        // Verify that disaster chance is indeed 0.05%
-        assert!((DISASTER_CHANCE - 0.0005).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_tornado_config() {
-        assert_eq!(TORNADO_RADIUS, 5);
-        assert_eq!(TORNADO_DURATION, 50);
-        assert!((TORNADO_DESTROY_PCT - 0.30).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_earthquake_config() {
-        assert_eq!(EARTHQUAKE_RADIUS, 10);
-        assert_eq!(EARTHQUAKE_DURATION, 20);
-        assert!((EARTHQUAKE_DESTROY_PCT - 0.10).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_flood_config() {
-        assert_eq!(FLOOD_RADIUS, 8);
-        assert_eq!(FLOOD_DURATION, 100);
-        assert!((FLOOD_ELEVATION_THRESHOLD - 0.45).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_disaster_instance_creation() {
-        let instance = DisasterInstance {
-            disaster_type: DisasterType::Tornado,
-            center_x: 100,
-            center_y: 150,
-            radius: TORNADO_RADIUS,
-            ticks_remaining: TORNADO_DURATION,
-            damage_applied: false,
-        };
-        assert_eq!(instance.disaster_type, DisasterType::Tornado);
-        assert_eq!(instance.center_x, 100);
-        assert_eq!(instance.center_y, 150);
-        assert_eq!(instance.radius, 5);
-        assert_eq!(instance.ticks_remaining, 50);
-        assert!(!instance.damage_applied);
-    }
-
-    #[test]
-    fn test_rand_distribution_reasonable() {
-        // Check that over many samples, results spread reasonably
-        let mut below_half = 0u32;
-        let samples = 10_000u64;
-        for seed in 0..samples {
-            if rand_f32(seed.wrapping_mul(0x9876)) < 0.5 {
-                below_half += 1;
-            }
-        }
-        // Should be roughly 50% (allow wide margin for deterministic hash)
-        let ratio = below_half as f64 / samples as f64;
-        assert!(ratio > 0.3 && ratio < 0.7, "Distribution skewed: {}", ratio);
-    }
-}
-
 pub struct DisastersPlugin;
 
 impl Plugin for DisastersPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<ActiveDisaster>().add_systems(
-            FixedUpdate,
-            (
-                trigger_random_disaster,
-                process_active_disaster,
-                bevy::ecs::schedule::apply_deferred,
-                apply_earthquake_damage,
-            )
-                .chain()
-                .after(crate::fire::fire_damage)
-                .in_set(crate::SimulationSet::Simulation),
-        );
+        app.init_resource::<ActiveDisaster>()
+            .init_resource::<DisasterSeed>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    trigger_random_disaster,
+                    process_active_disaster,
+                    bevy::ecs::schedule::apply_deferred,
+                    apply_earthquake_damage,
+                )
+                    .chain()
+                    .after(crate::fire::fire_damage)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
     }
 }
@@ -0,0 +1,113 @@
+use super::*;
+
+#[test]
+fn test_disaster_type_name() {
+    assert_eq!(DisasterType::Tornado.name(), "Tornado");
+    assert_eq!(DisasterType::Earthquake.name(), "Earthquake");
+    assert_eq!(DisasterType::Flood.name(), "Flood");
+}
+
+#[test]
+fn test_active_disaster_default() {
+    let ad = ActiveDisaster::default();
+    assert!(ad.current.is_none());
+}
+
+#[test]
+fn test_splitmix64_deterministic() {
+    let a = splitmix64(42);
+    let b = splitmix64(42);
+    assert_eq!(a, b);
+    // Different seeds produce different values
+    let c = splitmix64(43);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_rand_f32_range() {
+    for seed in 0..1000u64 {
+        let val = rand_f32(seed);
+        assert!(
+            val >= 0.0 && val < 1.0,
+            "rand_f32({}) = {} out of range",
+            seed,
+            val
+        );
+    }
+}
+
+#[test]
+fn test_rand_usize_range() {
+    for seed in 0..1000u64 {
+        let val = rand_usize(seed, 256);
+        assert!(
+            val < 256,
+            "rand_usize({}, 256) = {} out of range",
+            seed,
+            val
+        );
+    }
+}
+
+#[test]
+fn test_disaster_chance_very_low() {
+    // Verify that disaster chance is indeed 0.05%
+    assert!((DISASTER_CHANCE - 0.0005).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_tornado_config() {
+    assert_eq!(TORNADO_RADIUS, 5);
+    assert_eq!(TORNADO_DURATION, 50);
+    assert!((TORNADO_DESTROY_PCT - 0.30).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_earthquake_config() {
+    assert_eq!(EARTHQUAKE_RADIUS, 10);
+    assert_eq!(EARTHQUAKE_DURATION, 20);
+    assert_eq!(
+        crate::seismic::felt_radius(crate::seismic::REFERENCE_MAGNITUDE),
+        EARTHQUAKE_RADIUS
+    );
+}
+
+#[test]
+fn test_flood_config() {
+    assert_eq!(FLOOD_RADIUS, 8);
+    assert_eq!(FLOOD_DURATION, 100);
+    assert!((FLOOD_ELEVATION_THRESHOLD - 0.45).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_disaster_instance_creation() {
+    let instance = DisasterInstance {
+        disaster_type: DisasterType::Tornado,
+        center_x: 100,
+        center_y: 150,
+        radius: TORNADO_RADIUS,
+        ticks_remaining: TORNADO_DURATION,
+        damage_applied: false,
+    };
+    assert_eq!(instance.disaster_type, DisasterType::Tornado);
+    assert_eq!(instance.center_x, 100);
+    assert_eq!(instance.center_y, 150);
+    assert_eq!(instance.radius, 5);
+    assert_eq!(instance.ticks_remaining, 50);
+    assert!(!instance.damage_applied);
+}
+
+#[test]
+fn test_rand_distribution_reasonable() {
+    // Check that over many samples, results spread reasonably
+    let mut below_half = 0u32;
+    let samples = 10_000u64;
+    for seed in 0..samples {
+        if rand_f32(seed.wrapping_mul(0x9876)) < 0.5 {
+            below_half += 1;
+        }
+    }
+    // Should be roughly 50% (allow wide margin for deterministic hash)
+    let ratio = below_half as f64 / samples as f64;
+    assert!(ratio > 0.3 && ratio < 0.7, "Distribution skewed: {}", ratio);
+}
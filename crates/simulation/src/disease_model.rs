@@ -410,6 +410,7 @@ impl Plugin for DiseaseModelPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chunked_grid::ChunkedGrid;
 
     #[test]
     fn test_disease_base_infection_rates() {
@@ -433,7 +434,7 @@ mod tests {
     #[test]
     fn test_average_pollution_empty() {
         let grid = PollutionGrid {
-            levels: vec![],
+            levels: ChunkedGrid::new(0, 0, 0),
             width: 0,
             height: 0,
         };
@@ -458,7 +459,7 @@ mod tests {
     #[test]
     fn test_sanitation_risk_clamped() {
         let extreme = PollutionGrid {
-            levels: vec![255; 100],
+            levels: ChunkedGrid::new(10, 10, 255),
             width: 10,
             height: 10,
         };
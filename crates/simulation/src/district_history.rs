@@ -0,0 +1,258 @@
+//! Per-district statistics history for the district manager panel.
+//!
+//! Every few game days each player-defined district gets a snapshot of its
+//! population, happiness, crime, jobs and average land value, so the panel
+//! can draw trends and compare districts. Snapshots are indexed like
+//! `DistrictMap.districts`; when the number of districts changes (for example
+//! after auto-generating districts) the history starts over.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+
+use crate::buildings::{Building, MixedUseBuilding};
+use crate::districts::{District, DistrictMap};
+use crate::land_value::LandValueGrid;
+use crate::time_of_day::GameClock;
+
+/// Game days between snapshots.
+pub const RECORD_INTERVAL_DAYS: u32 = 5;
+
+/// Snapshots kept per district.
+pub const MAX_DISTRICT_SNAPSHOTS: usize = 120;
+
+/// One district's statistics on a given day.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct DistrictSnapshot {
+    pub day: u32,
+    pub population: u32,
+    pub happiness: f32,
+    pub crime: f32,
+    pub jobs: u32,
+    pub land_value: f32,
+}
+
+/// Snapshot history of every player-defined district.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode)]
+pub struct DistrictHistory {
+    /// One series per district, oldest snapshot first.
+    pub series: Vec<Vec<DistrictSnapshot>>,
+    pub last_record_day: u32,
+}
+
+impl DistrictHistory {
+    /// Snapshots of a district, oldest first.
+    pub fn series(&self, district_idx: usize) -> &[DistrictSnapshot] {
+        self.series
+            .get(district_idx)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Most recent snapshot of a district.
+    pub fn latest(&self, district_idx: usize) -> Option<&DistrictSnapshot> {
+        self.series(district_idx).last()
+    }
+
+    /// Append one snapshot per district, starting over if the districts changed.
+    pub fn record(&mut self, snapshots: Vec<DistrictSnapshot>) {
+        if self.series.len() != snapshots.len() {
+            self.series = vec![Vec::new(); snapshots.len()];
+        }
+        for (series, snapshot) in self.series.iter_mut().zip(snapshots) {
+            series.push(snapshot);
+            if series.len() > MAX_DISTRICT_SNAPSHOTS {
+                let excess = series.len() - MAX_DISTRICT_SNAPSHOTS;
+                series.drain(0..excess);
+            }
+        }
+    }
+}
+
+/// Jobs a building provides: the commercial floors of mixed-use buildings
+/// and the full capacity of commercial, industrial and office buildings.
+pub fn building_jobs(building: &Building) -> u32 {
+    if building.zone_type.is_mixed_use() {
+        MixedUseBuilding::capacities_for_level(building.level).0
+    } else if building.zone_type.is_job_zone() {
+        building.capacity
+    } else {
+        0
+    }
+}
+
+/// Snapshot of one district from its computed stats plus jobs and land value.
+pub fn snapshot_district(
+    day: u32,
+    district: &District,
+    jobs: u32,
+    land_value: &LandValueGrid,
+) -> DistrictSnapshot {
+    let land_sum: u64 = district
+        .cells
+        .iter()
+        .map(|&(x, y)| land_value.get(x, y) as u64)
+        .sum();
+    DistrictSnapshot {
+        day,
+        population: district.stats.population,
+        happiness: district.stats.avg_happiness,
+        crime: district.stats.crime,
+        jobs,
+        land_value: if district.cells.is_empty() {
+            0.0
+        } else {
+            land_sum as f32 / district.cells.len() as f32
+        },
+    }
+}
+
+/// Records a snapshot of every district every `RECORD_INTERVAL_DAYS`.
+pub fn record_district_history(
+    clock: Res<GameClock>,
+    district_map: Res<DistrictMap>,
+    land_value: Res<LandValueGrid>,
+    buildings: Query<&Building>,
+    mut history: ResMut<DistrictHistory>,
+) {
+    if clock.day < history.last_record_day + RECORD_INTERVAL_DAYS {
+        return;
+    }
+    history.last_record_day = clock.day;
+
+    let mut jobs = vec![0u32; district_map.districts.len()];
+    for building in &buildings {
+        if let Some(di) = district_map.get_district_index_at(building.grid_x, building.grid_y) {
+            if let Some(j) = jobs.get_mut(di) {
+                *j += building_jobs(building);
+            }
+        }
+    }
+
+    let snapshots = district_map
+        .districts
+        .iter()
+        .zip(jobs)
+        .map(|(district, jobs)| snapshot_district(clock.day, district, jobs, &land_value))
+        .collect();
+    history.record(snapshots);
+}
+
+impl crate::Saveable for DistrictHistory {
+    const SAVE_KEY: &'static str = "district_history";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.series.iter().all(Vec::is_empty) {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+pub struct DistrictHistoryPlugin;
+
+impl Plugin for DistrictHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DistrictHistory>().add_systems(
+            FixedUpdate,
+            record_district_history
+                .after(crate::districts::district_stats)
+                .in_set(crate::SimulationSet::PostSim),
+        );
+
+        let mut registry = app
+            .world_mut()
+            .get_resource_or_insert_with(crate::SaveableRegistry::default);
+        registry.register::<DistrictHistory>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::ZoneType;
+    use crate::Saveable;
+
+    fn snapshot(day: u32, population: u32) -> DistrictSnapshot {
+        DistrictSnapshot {
+            day,
+            population,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_appends_and_trims() {
+        let mut history = DistrictHistory::default();
+        for day in 0..(MAX_DISTRICT_SNAPSHOTS as u32 + 10) {
+            history.record(vec![snapshot(day, day), snapshot(day, 0)]);
+        }
+        assert_eq!(history.series(0).len(), MAX_DISTRICT_SNAPSHOTS);
+        assert_eq!(history.series(0)[0].day, 10);
+        assert_eq!(
+            history.latest(0).unwrap().population,
+            MAX_DISTRICT_SNAPSHOTS as u32 + 9
+        );
+        assert!(history.series(5).is_empty());
+    }
+
+    #[test]
+    fn test_record_starts_over_when_districts_change() {
+        let mut history = DistrictHistory::default();
+        history.record(vec![snapshot(1, 10), snapshot(1, 20)]);
+        history.record(vec![snapshot(2, 5)]);
+        assert_eq!(history.series.len(), 1);
+        assert_eq!(history.series(0), &[snapshot(2, 5)]);
+    }
+
+    #[test]
+    fn test_building_jobs_by_zone() {
+        let mut building = Building {
+            zone_type: ZoneType::ResidentialLow,
+            level: 2,
+            grid_x: 0,
+            grid_y: 0,
+            capacity: 30,
+            occupants: 10,
+        };
+        assert_eq!(building_jobs(&building), 0);
+        building.zone_type = ZoneType::Industrial;
+        assert_eq!(building_jobs(&building), 30);
+        building.zone_type = ZoneType::MixedUse;
+        assert_eq!(
+            building_jobs(&building),
+            MixedUseBuilding::capacities_for_level(2).0
+        );
+    }
+
+    #[test]
+    fn test_snapshot_averages_land_value() {
+        let mut district = District::new("Test".to_string(), [1.0; 4]);
+        district.cells.insert((1, 1));
+        district.cells.insert((2, 1));
+        district.stats.population = 42;
+        let mut land_value = LandValueGrid::default();
+        land_value.set(1, 1, 100);
+        land_value.set(2, 1, 50);
+        let snap = snapshot_district(7, &district, 12, &land_value);
+        assert_eq!(snap.day, 7);
+        assert_eq!(snap.population, 42);
+        assert_eq!(snap.jobs, 12);
+        assert_eq!(snap.land_value, 75.0);
+    }
+
+    #[test]
+    fn test_saveable_roundtrip() {
+        let mut history = DistrictHistory::default();
+        assert!(history.save_to_bytes().is_none());
+        history.record(vec![snapshot(5, 100)]);
+        history.last_record_day = 5;
+        let bytes = history.save_to_bytes().expect("non-empty history saves");
+        let restored = DistrictHistory::load_from_bytes(&bytes);
+        assert_eq!(restored.series(0), &[snapshot(5, 100)]);
+        assert_eq!(restored.last_record_day, 5);
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use bevy::prelude::*;
 
 use crate::budget::ZoneTaxRates;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::districts::DistrictMap;
 
 use super::types::*;
@@ -34,6 +35,9 @@ pub struct DistrictPolicyLookup {
     pub park_multiplier: HashMap<usize, f32>,
     /// Per-district service budget multiplier (district_idx -> multiplier).
     pub service_budget_multiplier: HashMap<usize, f32>,
+    /// Per-cell building level cap from density and setback rules
+    /// (`u8::MAX` = uncapped). Empty when no district sets a cap.
+    pub level_caps: Vec<u8>,
 }
 
 impl DistrictPolicyLookup {
@@ -119,6 +123,13 @@ impl DistrictPolicyLookup {
         NORMAL_MAX_LEVEL
     }
 
+    /// Highest building level the district rules allow at a cell, or `None`
+    /// if the cell is uncapped.
+    pub fn level_cap_at(&self, x: usize, y: usize) -> Option<u8> {
+        let cap = *self.level_caps.get(y * GRID_WIDTH + x)?;
+        (cap != u8::MAX).then_some(cap)
+    }
+
     /// Check if heavy industry is banned at a cell.
     pub fn is_heavy_industry_banned(&self, x: usize, y: usize, district_map: &DistrictMap) -> bool {
         if let Some(di) = district_map.get_district_index_at(x, y) {
@@ -201,6 +212,58 @@ pub fn compute_max_building_level(overrides: &DistrictPolicyOverrides) -> u8 {
     }
 }
 
+/// Compute the building level cap for a district from its density cap and
+/// high-rise ban, or `None` if neither is set.
+pub fn compute_level_cap(overrides: &DistrictPolicyOverrides) -> Option<u8> {
+    let ban = overrides.high_rise_ban.then_some(HIGH_RISE_BAN_MAX_LEVEL);
+    match (overrides.max_level, ban) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Build the per-cell level cap grid. Cells within `setback_cells` of a cell
+/// outside the district get a cap one level lower than the district's.
+pub fn build_level_caps(
+    overrides: &HashMap<usize, DistrictPolicyOverrides>,
+    district_map: &DistrictMap,
+) -> Vec<u8> {
+    let mut caps = Vec::new();
+    for (&di, o) in overrides {
+        let Some(cap) = compute_level_cap(o) else {
+            continue;
+        };
+        let Some(district) = district_map.districts.get(di) else {
+            continue;
+        };
+        if caps.is_empty() {
+            caps = vec![u8::MAX; GRID_WIDTH * GRID_HEIGHT];
+        }
+        let setback = o.setback_cells as i32;
+        for &(x, y) in &district.cells {
+            let near_edge = setback > 0
+                && (-setback..=setback).any(|dy| {
+                    (-setback..=setback).any(|dx| {
+                        let nx = x as i32 + dx;
+                        let ny = y as i32 + dy;
+                        nx >= 0
+                            && ny >= 0
+                            && (nx as usize) < GRID_WIDTH
+                            && (ny as usize) < GRID_HEIGHT
+                            && district_map.get_district_index_at(nx as usize, ny as usize)
+                                != Some(di)
+                    })
+                });
+            caps[y * GRID_WIDTH + x] = if near_edge {
+                cap.saturating_sub(1)
+            } else {
+                cap
+            };
+        }
+    }
+    caps
+}
+
 /// Compute the commercial demand bonus for a district.
 pub fn compute_commercial_bonus(overrides: &DistrictPolicyOverrides) -> f32 {
     if overrides.small_business_incentive {
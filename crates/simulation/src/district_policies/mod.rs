@@ -12,6 +12,13 @@
 //! - **Noise ordinance**: reduces happiness penalty from noise in the district
 //! - **Green space mandate**: boosts park effectiveness in the district
 //! - **Service budget multiplier**: scales service effectiveness in the district
+//! - **Density cap**: highest building level allowed; 0 freezes new construction
+//! - **Edge setback**: a strip along the district edge capped one level lower,
+//!   so towers step down toward neighbouring low-rise areas
+//!
+//! Density caps, setbacks and the high-rise ban are enforced per cell by the
+//! building spawner (no new buildings where the cap is 0) and by building
+//! upgrades. Buildings already above a newly set cap are grandfathered.
 //!
 //! The system runs on the slow tick timer to compute per-district effective
 //! policy values that other systems can query via `DistrictPolicyLookup`.
@@ -21,6 +28,7 @@ pub mod systems;
 pub mod types;
 
 mod tests;
+mod tests_density;
 mod tests_lookup;
 
 // Re-export all public items so callers can use `crate::district_policies::*`.
@@ -12,7 +12,8 @@ use super::types::*;
 // System
 // =============================================================================
 
-/// System: update district policy lookup tables every slow tick.
+/// System: update district policy lookup tables every slow tick, and
+/// immediately whenever the policies or district boundaries change.
 ///
 /// Reads `DistrictPolicyState` and city-wide `ExtendedBudget` to compute
 /// effective per-district values, writing them to `DistrictPolicyLookup`.
@@ -23,7 +24,7 @@ pub fn update_district_policies(
     district_map: Res<DistrictMap>,
     mut lookup: ResMut<DistrictPolicyLookup>,
 ) {
-    if !slow_timer.should_run() {
+    if !slow_timer.should_run() && !state.is_changed() && !district_map.is_changed() {
         return;
     }
 
@@ -85,6 +86,9 @@ pub fn update_district_policies(
         }
     }
 
+    // Density and setback caps, per cell
+    lookup.level_caps = build_level_caps(&state.overrides, &district_map);
+
     // Update aggregate stats
     state.total_monthly_cost = compute_total_monthly_cost(&state.overrides);
     state.total_active_policies = compute_total_active_policies(&state.overrides);
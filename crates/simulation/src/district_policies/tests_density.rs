@@ -0,0 +1,65 @@
+//! Unit tests for district density caps and edge setbacks.
+
+#[cfg(test)]
+mod tests {
+    use crate::district_policies::lookup::*;
+    use crate::district_policies::types::*;
+    use crate::districts::DistrictMap;
+
+    #[test]
+    fn test_level_cap_combines_density_cap_and_high_rise_ban() {
+        let mut o = DistrictPolicyOverrides::default();
+        assert_eq!(compute_level_cap(&o), None);
+        o.max_level = Some(4);
+        assert_eq!(compute_level_cap(&o), Some(4));
+        o.high_rise_ban = true;
+        assert_eq!(compute_level_cap(&o), Some(HIGH_RISE_BAN_MAX_LEVEL));
+        o.max_level = Some(1);
+        assert_eq!(compute_level_cap(&o), Some(1));
+    }
+
+    #[test]
+    fn test_setters_clamp_density_rules() {
+        let mut state = DistrictPolicyState::default();
+        state.set_max_level(0, Some(9));
+        state.set_setback_cells(0, 10);
+        let o = state.get(0).unwrap();
+        assert_eq!(o.max_level, Some(MAX_DENSITY_LEVEL));
+        assert_eq!(o.setback_cells, MAX_SETBACK_CELLS);
+        assert!(!o.is_default());
+    }
+
+    #[test]
+    fn test_setback_lowers_cap_along_district_edge() {
+        let mut district_map = DistrictMap::default();
+        for y in 10..20 {
+            for x in 10..20 {
+                district_map.assign_cell_to_district(x, y, 0);
+            }
+        }
+        let mut state = DistrictPolicyState::default();
+        state.set_max_level(0, Some(5));
+        state.set_setback_cells(0, 2);
+
+        let lookup = DistrictPolicyLookup {
+            level_caps: build_level_caps(&state.overrides, &district_map),
+            ..Default::default()
+        };
+        assert_eq!(lookup.level_cap_at(15, 15), Some(5));
+        assert_eq!(lookup.level_cap_at(10, 15), Some(4));
+        assert_eq!(lookup.level_cap_at(11, 11), Some(4));
+        assert_eq!(lookup.level_cap_at(12, 12), Some(5));
+        // Outside the district: uncapped.
+        assert_eq!(lookup.level_cap_at(30, 30), None);
+    }
+
+    #[test]
+    fn test_no_caps_without_density_rules() {
+        let mut state = DistrictPolicyState::default();
+        state.toggle_noise_ordinance(0);
+        let caps = build_level_caps(&state.overrides, &DistrictMap::default());
+        assert!(caps.is_empty());
+        let lookup = DistrictPolicyLookup::default();
+        assert_eq!(lookup.level_cap_at(0, 0), None);
+    }
+}
@@ -24,6 +24,12 @@ pub const HIGH_RISE_BAN_MAX_LEVEL: u8 = 2;
 /// Normal maximum building level.
 pub const NORMAL_MAX_LEVEL: u8 = 3;
 
+/// Highest density cap a district can set (the tallest building level).
+pub const MAX_DENSITY_LEVEL: u8 = 5;
+
+/// Widest height-transition setback along a district's edge, in cells.
+pub const MAX_SETBACK_CELLS: u8 = 3;
+
 /// Commercial demand bonus from small business incentive.
 pub const SMALL_BUSINESS_DEMAND_BONUS: f32 = 0.15;
 
@@ -72,6 +78,11 @@ pub struct DistrictPolicyOverrides {
     pub green_space_mandate: bool,
     /// Service budget multiplier for this district (None = use city-wide).
     pub service_budget_multiplier: Option<f32>,
+    /// Density cap: highest building level allowed (0 = no new buildings).
+    pub max_level: Option<u8>,
+    /// Width in cells of the strip along the district edge where the level
+    /// cap is one lower, stepping heights down toward neighbouring areas.
+    pub setback_cells: u8,
 }
 
 impl DistrictPolicyOverrides {
@@ -87,6 +98,8 @@ impl DistrictPolicyOverrides {
             && !self.noise_ordinance
             && !self.green_space_mandate
             && self.service_budget_multiplier.is_none()
+            && self.max_level.is_none()
+            && self.setback_cells == 0
     }
 
     /// Count the number of active boolean policies in this district.
@@ -188,6 +201,17 @@ impl DistrictPolicyState {
             Some(multiplier.clamp(MIN_SERVICE_BUDGET_MULTIPLIER, MAX_SERVICE_BUDGET_MULTIPLIER));
     }
 
+    /// Set (or clear) the density cap for a district.
+    pub fn set_max_level(&mut self, district_idx: usize, max_level: Option<u8>) {
+        self.get_or_create_mut(district_idx).max_level =
+            max_level.map(|level| level.min(MAX_DENSITY_LEVEL));
+    }
+
+    /// Set the edge setback width for a district (0 = no setback).
+    pub fn set_setback_cells(&mut self, district_idx: usize, cells: u8) {
+        self.get_or_create_mut(district_idx).setback_cells = cells.min(MAX_SETBACK_CELLS);
+    }
+
     /// Toggle a boolean policy for a district.
     pub fn toggle_high_rise_ban(&mut self, district_idx: usize) {
         let o = self.get_or_create_mut(district_idx);
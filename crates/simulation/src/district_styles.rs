@@ -0,0 +1,193 @@
+//! District architecture styles.
+//!
+//! The player can give each district an architectural theme (White City
+//! Bauhaus, Ottoman stone or modern glass). The theme is purely visual:
+//! the renderer uses it to pick building models and proportions so that
+//! neighbourhoods look distinct beyond their zoning and levels.
+//!
+//! Styles are stored per district index in a separate resource, so saves
+//! made before themes existed load with every district in the mixed style.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::districts::DistrictMap;
+use crate::Saveable;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// Architectural theme of a district's zoned buildings.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum ArchitectureStyle {
+    /// No theme: the regular per-zone mix of building models.
+    #[default]
+    Mixed,
+    /// White City Bauhaus: low, wide, flat-roofed blocks.
+    WhiteCity,
+    /// Ottoman stone: squat masonry houses with pitched roofs.
+    OttomanStone,
+    /// Modern glass: tall, slender towers.
+    ModernGlass,
+}
+
+impl ArchitectureStyle {
+    pub const ALL: [ArchitectureStyle; 4] = [
+        ArchitectureStyle::Mixed,
+        ArchitectureStyle::WhiteCity,
+        ArchitectureStyle::OttomanStone,
+        ArchitectureStyle::ModernGlass,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ArchitectureStyle::Mixed => "Mixed",
+            ArchitectureStyle::WhiteCity => "Bauhaus / White City",
+            ArchitectureStyle::OttomanStone => "Ottoman Stone",
+            ArchitectureStyle::ModernGlass => "Modern Glass",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ArchitectureStyle::Mixed => "The usual mix of building models.",
+            ArchitectureStyle::WhiteCity => "Low, wide, flat-roofed white blocks.",
+            ArchitectureStyle::OttomanStone => "Squat stone houses with pitched roofs.",
+            ArchitectureStyle::ModernGlass => "Tall, slender glass towers.",
+        }
+    }
+
+    /// Position in `ALL`, used to seed model selection.
+    pub fn index(self) -> usize {
+        match self {
+            ArchitectureStyle::Mixed => 0,
+            ArchitectureStyle::WhiteCity => 1,
+            ArchitectureStyle::OttomanStone => 2,
+            ArchitectureStyle::ModernGlass => 3,
+        }
+    }
+}
+
+// =============================================================================
+// Resource
+// =============================================================================
+
+/// Architecture style assigned to each district, keyed by index into
+/// `DistrictMap::districts`. Districts without an entry are `Mixed`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct DistrictStyles {
+    pub styles: HashMap<usize, ArchitectureStyle>,
+}
+
+impl DistrictStyles {
+    /// Style of a district; `Mixed` unless one has been assigned.
+    pub fn get(&self, district_idx: usize) -> ArchitectureStyle {
+        self.styles.get(&district_idx).copied().unwrap_or_default()
+    }
+
+    /// Assign a style to a district. Assigning `Mixed` clears the theme.
+    pub fn set(&mut self, district_idx: usize, style: ArchitectureStyle) {
+        if style == ArchitectureStyle::Mixed {
+            self.styles.remove(&district_idx);
+        } else {
+            self.styles.insert(district_idx, style);
+        }
+    }
+
+    /// Style of the district containing cell (x, y); `Mixed` outside any
+    /// district.
+    pub fn style_at(&self, x: usize, y: usize, district_map: &DistrictMap) -> ArchitectureStyle {
+        if self.styles.is_empty() {
+            return ArchitectureStyle::Mixed;
+        }
+        district_map
+            .get_district_index_at(x, y)
+            .map_or(ArchitectureStyle::Mixed, |di| self.get(di))
+    }
+}
+
+impl Saveable for DistrictStyles {
+    const SAVE_KEY: &'static str = "district_styles";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.styles.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// =============================================================================
+// Plugin
+// =============================================================================
+
+pub struct DistrictStylesPlugin;
+
+impl Plugin for DistrictStylesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DistrictStyles>();
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<DistrictStyles>();
+    }
+}
+
+// =============================================================================
+// Unit tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unassigned_district_is_mixed() {
+        let styles = DistrictStyles::default();
+        assert_eq!(styles.get(3), ArchitectureStyle::Mixed);
+    }
+
+    #[test]
+    fn test_assigning_mixed_clears_the_theme() {
+        let mut styles = DistrictStyles::default();
+        styles.set(1, ArchitectureStyle::OttomanStone);
+        assert_eq!(styles.get(1), ArchitectureStyle::OttomanStone);
+        styles.set(1, ArchitectureStyle::Mixed);
+        assert!(styles.styles.is_empty());
+    }
+
+    #[test]
+    fn test_style_at_follows_the_district_map() {
+        let mut map = DistrictMap::default();
+        map.assign_cell_to_district(10, 10, 2);
+        let mut styles = DistrictStyles::default();
+        styles.set(2, ArchitectureStyle::WhiteCity);
+        assert_eq!(styles.style_at(10, 10, &map), ArchitectureStyle::WhiteCity);
+        assert_eq!(styles.style_at(11, 10, &map), ArchitectureStyle::Mixed);
+    }
+
+    #[test]
+    fn test_save_roundtrip() {
+        let mut styles = DistrictStyles::default();
+        assert!(styles.save_to_bytes().is_none());
+        styles.set(0, ArchitectureStyle::ModernGlass);
+        let bytes = styles.save_to_bytes().expect("themed districts are saved");
+        assert_eq!(DistrictStyles::load_from_bytes(&bytes), styles);
+    }
+
+    #[test]
+    fn test_indices_match_all() {
+        for (i, style) in ArchitectureStyle::ALL.iter().enumerate() {
+            assert_eq!(style.index(), i);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Water utility drought staging and rationing tiers.
+//!
+//! Drought severity (`DroughtState`) and reservoir storage
+//! (`ReservoirState`) determine a formal drought stage. Each stage activates
+//! predefined restrictions, cumulatively:
+//!
+//! | Stage | Restriction            | Target demand cut |
+//! |-------|------------------------|-------------------|
+//! | 1     | Voluntary conservation | 5%                |
+//! | 2     | Irrigation ban         | 10%               |
+//! | 3     | Industrial cuts        | 10%               |
+//! | 4     | Rationing              | 15%               |
+//!
+//! How much demand actually drops depends on compliance: each restriction
+//! has a base compliance, scaled by public awareness (driven by the chosen
+//! `PublicCommunication` level) and eroded by fatigue the longer
+//! restrictions last. The realised reduction is removed from
+//! `WaterSupply::total_demand_gpd` before reservoirs are drawn down.
+//!
+//! Stages escalate immediately but relax one step at a time, and only after
+//! holding for `MIN_STAGE_DAYS`.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_drought_staging, DroughtStagingPlugin};
+pub use types::*;
@@ -0,0 +1,131 @@
+//! Stage declaration, outreach, and demand curtailment.
+
+use bevy::prelude::*;
+
+use crate::drought::DroughtState;
+use crate::economy::CityBudget;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::reservoir::ReservoirState;
+use crate::time_of_day::GameClock;
+use crate::water_demand::WaterSupply;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// System: Declare the drought stage from drought and reservoir conditions,
+/// advance public awareness and fatigue, and curtail city water demand by
+/// the share restrictions actually achieve.
+///
+/// Runs on the slow tick, after demand and supply are aggregated and before
+/// reservoirs are drawn down.
+#[allow(clippy::too_many_arguments)]
+pub fn update_drought_staging(
+    timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    drought: Res<DroughtState>,
+    reservoir: Res<ReservoirState>,
+    mut state: ResMut<DroughtStagingState>,
+    mut water_supply: ResMut<WaterSupply>,
+    mut budget: ResMut<CityBudget>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !timer.should_run() {
+        return;
+    }
+
+    // Reservoir storage only matters once the city has reservoirs.
+    let reservoir_tier = (reservoir.reservoir_count > 0).then_some(reservoir.warning_tier);
+    let required = required_stage(drought.current_tier, reservoir_tier);
+
+    let previous = state.stage;
+    if state.transition(required) {
+        let escalated = state.stage > previous;
+        if escalated {
+            // Announcing a new stage gets people's attention.
+            let target = state.communication.target_awareness();
+            state.awareness = state.awareness.max(target * 0.5);
+        }
+        let (text, priority) = if state.stage == DroughtStage::None {
+            (
+                "Drought restrictions lifted.".to_string(),
+                NotificationPriority::Positive,
+            )
+        } else if escalated {
+            (
+                format!("Drought {} declared.", state.stage.name()),
+                NotificationPriority::Warning,
+            )
+        } else {
+            (
+                format!("Drought restrictions eased to {}.", state.stage.name()),
+                NotificationPriority::Info,
+            )
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Disaster,
+            location: None,
+        });
+    }
+
+    // Daily bookkeeping: awareness drifts toward the outreach level, fatigue
+    // accumulates, and outreach is paid for while restrictions are in force.
+    if clock.day > state.last_update_day {
+        let days = clock.day - state.last_update_day;
+        state.last_update_day = clock.day;
+        let target = if state.stage == DroughtStage::None {
+            0.0
+        } else {
+            state.communication.target_awareness()
+        };
+        for _ in 0..days.min(30) {
+            state.awareness += (target - state.awareness) * AWARENESS_ADJUST_RATE;
+        }
+        if state.stage != DroughtStage::None {
+            state.days_in_stage += days;
+            budget.treasury -= state.communication.daily_cost() * days as f64;
+        }
+    }
+
+    state.update_compliance();
+
+    state.unrestricted_demand_gpd = water_supply.total_demand_gpd;
+    state.demand_saved_gpd = water_supply.total_demand_gpd * state.demand_reduction;
+    if state.demand_saved_gpd > 0.0 {
+        water_supply.total_demand_gpd -= state.demand_saved_gpd;
+        water_supply.supply_ratio = if water_supply.total_demand_gpd > 0.0 {
+            water_supply.total_supply_gpd / water_supply.total_demand_gpd
+        } else {
+            1.0
+        };
+    }
+}
+
+// =============================================================================
+// Plugin
+// =============================================================================
+
+pub struct DroughtStagingPlugin;
+
+impl Plugin for DroughtStagingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DroughtStagingState>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<DroughtStagingState>();
+
+        app.add_systems(
+            FixedUpdate,
+            update_drought_staging
+                .after(crate::drought::update_drought_index)
+                .after(crate::water_demand::aggregate_water_supply)
+                .after(crate::water_sources::aggregate_water_source_supply)
+                .before(crate::reservoir::update_reservoir_levels)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+use crate::drought::DroughtTier;
+use crate::reservoir::ReservoirWarningTier;
+use crate::Saveable;
+
+#[test]
+fn test_stage_restrictions_are_cumulative() {
+    assert!(DroughtStage::None.restrictions().is_empty());
+    assert_eq!(
+        DroughtStage::Advisory.restrictions(),
+        &[Restriction::VoluntaryConservation]
+    );
+    assert_eq!(DroughtStage::Rationing.restrictions().len(), 4);
+    assert_eq!(
+        DroughtStage::IndustrialCuts.restrictions().last(),
+        Some(&Restriction::IndustrialCuts)
+    );
+}
+
+#[test]
+fn test_required_stage_without_reservoirs_follows_drought() {
+    assert_eq!(
+        required_stage(DroughtTier::Normal, None),
+        DroughtStage::None
+    );
+    assert_eq!(
+        required_stage(DroughtTier::Severe, None),
+        DroughtStage::IrrigationBan
+    );
+    assert_eq!(
+        required_stage(DroughtTier::Extreme, None),
+        DroughtStage::IndustrialCuts
+    );
+}
+
+#[test]
+fn test_required_stage_takes_stricter_signal() {
+    assert_eq!(
+        required_stage(DroughtTier::Normal, Some(ReservoirWarningTier::Critical)),
+        DroughtStage::Rationing
+    );
+    assert_eq!(
+        required_stage(DroughtTier::Moderate, Some(ReservoirWarningTier::Normal)),
+        DroughtStage::Advisory
+    );
+}
+
+#[test]
+fn test_severe_drought_with_low_storage_escalates() {
+    assert_eq!(
+        required_stage(DroughtTier::Severe, Some(ReservoirWarningTier::Warning)),
+        DroughtStage::IndustrialCuts
+    );
+    assert_eq!(
+        required_stage(DroughtTier::Extreme, Some(ReservoirWarningTier::Critical)),
+        DroughtStage::Rationing
+    );
+}
+
+#[test]
+fn test_escalation_is_immediate() {
+    let mut state = DroughtStagingState::default();
+    assert!(state.transition(DroughtStage::IndustrialCuts));
+    assert_eq!(state.stage, DroughtStage::IndustrialCuts);
+    assert_eq!(state.days_in_stage, 0);
+}
+
+#[test]
+fn test_relaxing_waits_and_steps_down() {
+    let mut state = DroughtStagingState::default();
+    state.transition(DroughtStage::IndustrialCuts);
+    state.days_in_stage = MIN_STAGE_DAYS - 1;
+    assert!(!state.transition(DroughtStage::None));
+    assert_eq!(state.stage, DroughtStage::IndustrialCuts);
+
+    state.days_in_stage = MIN_STAGE_DAYS;
+    assert!(state.transition(DroughtStage::None));
+    assert_eq!(state.stage, DroughtStage::IrrigationBan);
+}
+
+#[test]
+fn test_awareness_raises_compliance() {
+    let low = compliance(
+        Restriction::IrrigationBan,
+        0.2,
+        0,
+        PublicCommunication::Minimal,
+    );
+    let high = compliance(
+        Restriction::IrrigationBan,
+        1.0,
+        0,
+        PublicCommunication::Campaign,
+    );
+    assert!(high > low);
+    assert!((high - Restriction::IrrigationBan.base_compliance()).abs() < 1e-6);
+}
+
+#[test]
+fn test_fatigue_erodes_compliance_less_with_campaign() {
+    let fresh = compliance(Restriction::Rationing, 1.0, 0, PublicCommunication::Notices);
+    let tired = compliance(
+        Restriction::Rationing,
+        1.0,
+        365,
+        PublicCommunication::Notices,
+    );
+    let tired_campaign = compliance(
+        Restriction::Rationing,
+        1.0,
+        365,
+        PublicCommunication::Campaign,
+    );
+    assert!(tired < fresh);
+    assert!(tired_campaign > tired);
+}
+
+#[test]
+fn test_demand_reduction_grows_with_stage() {
+    let mut previous = 0.0;
+    for stage in [
+        DroughtStage::Advisory,
+        DroughtStage::IrrigationBan,
+        DroughtStage::IndustrialCuts,
+        DroughtStage::Rationing,
+    ] {
+        let mut state = DroughtStagingState {
+            stage,
+            awareness: 1.0,
+            ..Default::default()
+        };
+        state.update_compliance();
+        assert!(state.demand_reduction > previous);
+        assert!(state.demand_reduction <= MAX_DEMAND_REDUCTION);
+        previous = state.demand_reduction;
+    }
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    let state = DroughtStagingState {
+        stage: DroughtStage::IrrigationBan,
+        days_in_stage: 12,
+        communication: PublicCommunication::Campaign,
+        awareness: 0.8,
+        ..Default::default()
+    };
+    let bytes = state.save_to_bytes().expect("should serialize");
+    let restored = DroughtStagingState::load_from_bytes(&bytes);
+    assert_eq!(restored.stage, DroughtStage::IrrigationBan);
+    assert_eq!(restored.days_in_stage, 12);
+    assert_eq!(restored.communication, PublicCommunication::Campaign);
+    assert!((restored.awareness - 0.8).abs() < f32::EPSILON);
+}
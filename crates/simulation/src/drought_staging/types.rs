@@ -0,0 +1,341 @@
+//! Drought stages, restriction tiers, and the compliance model.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::drought::DroughtTier;
+use crate::reservoir::ReservoirWarningTier;
+use crate::Saveable;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Minimum days a stage stays in force before it can be relaxed.
+pub const MIN_STAGE_DAYS: u32 = 7;
+
+/// Days of restrictions after which compliance fatigue is at its maximum.
+pub const FATIGUE_FULL_DAYS: f32 = 120.0;
+
+/// Maximum compliance lost to restriction fatigue.
+pub const MAX_FATIGUE: f32 = 0.3;
+
+/// Fraction of the gap to the target awareness closed each day.
+pub const AWARENESS_ADJUST_RATE: f32 = 0.2;
+
+/// Upper bound on the combined demand reduction from all restrictions.
+pub const MAX_DEMAND_REDUCTION: f32 = 0.5;
+
+// =============================================================================
+// Stages and restrictions
+// =============================================================================
+
+/// Formal drought stage declared by the water utility.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Encode,
+    Decode,
+    Serialize,
+    Deserialize,
+)]
+pub enum DroughtStage {
+    #[default]
+    None,
+    /// Stage 1: voluntary conservation advisory.
+    Advisory,
+    /// Stage 2: outdoor irrigation ban.
+    IrrigationBan,
+    /// Stage 3: mandatory cuts for industrial users.
+    IndustrialCuts,
+    /// Stage 4: household rationing.
+    Rationing,
+}
+
+impl DroughtStage {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "No restrictions",
+            Self::Advisory => "Stage 1: Advisory",
+            Self::IrrigationBan => "Stage 2: Irrigation ban",
+            Self::IndustrialCuts => "Stage 3: Industrial cuts",
+            Self::Rationing => "Stage 4: Rationing",
+        }
+    }
+
+    fn from_level(level: u8) -> Self {
+        match level {
+            0 => Self::None,
+            1 => Self::Advisory,
+            2 => Self::IrrigationBan,
+            3 => Self::IndustrialCuts,
+            _ => Self::Rationing,
+        }
+    }
+
+    /// Restrictions in force at this stage (each stage includes the previous).
+    pub fn restrictions(self) -> &'static [Restriction] {
+        &ALL_RESTRICTIONS[..self as usize]
+    }
+}
+
+static ALL_RESTRICTIONS: [Restriction; 4] = [
+    Restriction::VoluntaryConservation,
+    Restriction::IrrigationBan,
+    Restriction::IndustrialCuts,
+    Restriction::Rationing,
+];
+
+/// Predefined restriction tier activated by a drought stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Restriction {
+    VoluntaryConservation,
+    IrrigationBan,
+    IndustrialCuts,
+    Rationing,
+}
+
+impl Restriction {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::VoluntaryConservation => "Voluntary conservation",
+            Self::IrrigationBan => "Irrigation ban",
+            Self::IndustrialCuts => "Industrial cuts",
+            Self::Rationing => "Rationing",
+        }
+    }
+
+    /// Share of city demand removed at full compliance.
+    pub fn target_reduction(self) -> f32 {
+        match self {
+            Self::VoluntaryConservation => 0.05,
+            Self::IrrigationBan => 0.10,
+            Self::IndustrialCuts => 0.10,
+            Self::Rationing => 0.15,
+        }
+    }
+
+    /// Compliance with full public awareness and no fatigue. Voluntary
+    /// measures rely on goodwill; metered industrial cuts are enforceable.
+    pub fn base_compliance(self) -> f32 {
+        match self {
+            Self::VoluntaryConservation => 0.45,
+            Self::IrrigationBan => 0.8,
+            Self::IndustrialCuts => 0.9,
+            Self::Rationing => 0.75,
+        }
+    }
+}
+
+/// Stage implied by drought severity alone.
+pub fn stage_from_drought(tier: DroughtTier) -> DroughtStage {
+    match tier {
+        DroughtTier::Normal => DroughtStage::None,
+        DroughtTier::Moderate => DroughtStage::Advisory,
+        DroughtTier::Severe => DroughtStage::IrrigationBan,
+        DroughtTier::Extreme => DroughtStage::IndustrialCuts,
+    }
+}
+
+/// Stage implied by reservoir storage alone.
+pub fn stage_from_reservoir(tier: ReservoirWarningTier) -> DroughtStage {
+    match tier {
+        ReservoirWarningTier::Normal => DroughtStage::None,
+        ReservoirWarningTier::Watch => DroughtStage::Advisory,
+        ReservoirWarningTier::Warning => DroughtStage::IrrigationBan,
+        ReservoirWarningTier::Critical => DroughtStage::Rationing,
+    }
+}
+
+/// Combine both signals: the stricter one wins, and a severe drought with
+/// already-depleted storage escalates one further stage.
+pub fn required_stage(
+    drought: DroughtTier,
+    reservoir: Option<ReservoirWarningTier>,
+) -> DroughtStage {
+    let from_drought = stage_from_drought(drought);
+    let Some(reservoir) = reservoir else {
+        return from_drought;
+    };
+    let from_reservoir = stage_from_reservoir(reservoir);
+    let stage = from_drought.max(from_reservoir);
+    let compounding = matches!(drought, DroughtTier::Severe | DroughtTier::Extreme)
+        && matches!(
+            reservoir,
+            ReservoirWarningTier::Warning | ReservoirWarningTier::Critical
+        );
+    if compounding {
+        DroughtStage::from_level(stage as u8 + 1)
+    } else {
+        stage
+    }
+}
+
+// =============================================================================
+// Public communication
+// =============================================================================
+
+/// How actively the utility communicates restrictions to the public.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum PublicCommunication {
+    /// Restrictions are published but not promoted.
+    Minimal,
+    /// Notices on bills and in local media.
+    #[default]
+    Notices,
+    /// Full outreach campaign; also slows restriction fatigue.
+    Campaign,
+}
+
+impl PublicCommunication {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Minimal => "Minimal",
+            Self::Notices => "Notices",
+            Self::Campaign => "Campaign",
+        }
+    }
+
+    /// Public awareness this level of outreach settles at (0..1).
+    pub fn target_awareness(self) -> f32 {
+        match self {
+            Self::Minimal => 0.3,
+            Self::Notices => 0.65,
+            Self::Campaign => 1.0,
+        }
+    }
+
+    /// Treasury cost per day while restrictions are in force.
+    pub fn daily_cost(self) -> f64 {
+        match self {
+            Self::Minimal => 0.0,
+            Self::Notices => 50.0,
+            Self::Campaign => 250.0,
+        }
+    }
+
+    /// Multiplier on accumulated restriction fatigue.
+    pub fn fatigue_factor(self) -> f32 {
+        match self {
+            Self::Campaign => 0.5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Compliance with a restriction given awareness and days under restrictions.
+pub fn compliance(
+    restriction: Restriction,
+    awareness: f32,
+    days_in_stage: u32,
+    communication: PublicCommunication,
+) -> f32 {
+    let awareness = awareness.clamp(0.0, 1.0);
+    let fatigue = (days_in_stage as f32 / FATIGUE_FULL_DAYS).min(1.0)
+        * MAX_FATIGUE
+        * communication.fatigue_factor();
+    (restriction.base_compliance() * (0.5 + 0.5 * awareness) * (1.0 - fatigue)).clamp(0.0, 1.0)
+}
+
+// =============================================================================
+// Resource
+// =============================================================================
+
+/// Current drought stage, public outreach, and realised demand savings.
+#[derive(Resource, Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct DroughtStagingState {
+    pub stage: DroughtStage,
+    /// Stage recommended by current drought and reservoir conditions.
+    pub required_stage: DroughtStage,
+    pub days_in_stage: u32,
+    pub communication: PublicCommunication,
+    /// Public awareness of the restrictions in force (0..1).
+    pub awareness: f32,
+    /// Average compliance across active restrictions (0..1).
+    pub compliance: f32,
+    /// Share of demand actually removed by restrictions.
+    pub demand_reduction: f32,
+    /// City demand before restrictions (gallons per day).
+    pub unrestricted_demand_gpd: f32,
+    /// Demand removed by restrictions (gallons per day).
+    pub demand_saved_gpd: f32,
+    pub last_update_day: u32,
+}
+
+impl Default for DroughtStagingState {
+    fn default() -> Self {
+        Self {
+            stage: DroughtStage::None,
+            required_stage: DroughtStage::None,
+            days_in_stage: 0,
+            communication: PublicCommunication::default(),
+            awareness: 0.0,
+            compliance: 0.0,
+            demand_reduction: 0.0,
+            unrestricted_demand_gpd: 0.0,
+            demand_saved_gpd: 0.0,
+            last_update_day: 0,
+        }
+    }
+}
+
+impl DroughtStagingState {
+    /// Move towards the required stage. Escalation is immediate; relaxing
+    /// waits until the current stage has held for `MIN_STAGE_DAYS`.
+    /// Returns true when the stage changed.
+    pub fn transition(&mut self, required: DroughtStage) -> bool {
+        self.required_stage = required;
+        let next = if required > self.stage {
+            required
+        } else if required < self.stage && self.days_in_stage >= MIN_STAGE_DAYS {
+            DroughtStage::from_level(self.stage as u8 - 1)
+        } else {
+            self.stage
+        };
+        if next == self.stage {
+            return false;
+        }
+        self.stage = next;
+        self.days_in_stage = 0;
+        true
+    }
+
+    /// Recompute compliance and the resulting demand reduction.
+    pub fn update_compliance(&mut self) {
+        let restrictions = self.stage.restrictions();
+        if restrictions.is_empty() {
+            self.compliance = 0.0;
+            self.demand_reduction = 0.0;
+            return;
+        }
+        let mut total_compliance = 0.0;
+        let mut reduction = 0.0;
+        for &r in restrictions {
+            let c = compliance(r, self.awareness, self.days_in_stage, self.communication);
+            total_compliance += c;
+            reduction += r.target_reduction() * c;
+        }
+        self.compliance = total_compliance / restrictions.len() as f32;
+        self.demand_reduction = reduction.min(MAX_DEMAND_REDUCTION);
+    }
+}
+
+impl Saveable for DroughtStagingState {
+    const SAVE_KEY: &'static str = "drought_staging";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,31 @@
+//! Earthworks for the terrain tools.
+//!
+//! Raising, lowering and levelling terrain moves soil. Each stroke is
+//! measured as cut (soil dug out) and fill (soil placed) in cubic metres and
+//! charged per cubic metre moved. Cut soil goes to the city's stockpile and
+//! fill is taken from it; fill beyond the stockpile is imported and cut
+//! beyond its capacity is hauled away, both at extra cost. Terrain under a
+//! building cannot be reshaped until the building is demolished.
+
+pub mod plan;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use plan::{apply_terraform, plan_terraform};
+pub use types::*;
+
+use bevy::prelude::*;
+
+pub struct EarthworksPlugin;
+
+impl Plugin for EarthworksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Earthworks>();
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<Earthworks>();
+    }
+}
@@ -0,0 +1,82 @@
+//! Planning and carrying out terrain brush strokes.
+
+use crate::economy::CityBudget;
+use crate::grid::{CellType, WorldGrid};
+
+use super::types::*;
+
+/// Elevation above which a raised water cell becomes land.
+const WATER_TO_LAND_ELEVATION: f32 = 0.35;
+
+/// Work out what a brush stroke centred on (`gx`, `gy`) would change.
+/// Fails if the brush covers a building, which must be demolished first.
+pub fn plan_terraform(
+    grid: &WorldGrid,
+    gx: usize,
+    gy: usize,
+    op: TerraformOp,
+) -> Result<EarthworkPlan, String> {
+    let target = grid.get(gx, gy).elevation;
+    let mut plan = EarthworkPlan::default();
+    for dy in -BRUSH_RADIUS..=BRUSH_RADIUS {
+        for dx in -BRUSH_RADIUS..=BRUSH_RADIUS {
+            let (nx, ny) = (gx as i32 + dx, gy as i32 + dy);
+            if nx < 0 || ny < 0 || !grid.in_bounds(nx as usize, ny as usize) {
+                continue;
+            }
+            let dist = ((dx * dx + dy * dy) as f32).sqrt();
+            if dist > BRUSH_RADIUS as f32 {
+                continue;
+            }
+            let (x, y) = (nx as usize, ny as usize);
+            let cell = grid.get(x, y);
+            let strength = BRUSH_STRENGTH * (1.0 - dist / BRUSH_RADIUS as f32);
+            let new = match op {
+                TerraformOp::Raise => (cell.elevation + strength).min(1.0),
+                TerraformOp::Lower => (cell.elevation - strength).max(0.0),
+                TerraformOp::Level => cell.elevation + (target - cell.elevation) * LEVEL_RATE,
+            };
+            let delta = new - cell.elevation;
+            if delta.abs() < f32::EPSILON {
+                continue;
+            }
+            if cell.building_id.is_some() {
+                return Err("Demolish the building first to reshape the ground under it".into());
+            }
+            let volume = delta.abs() as f64 * CELL_VOLUME_PER_ELEVATION;
+            if delta > 0.0 {
+                plan.fill_m3 += volume;
+            } else {
+                plan.cut_m3 += volume;
+            }
+            plan.changes.push((x, y, new));
+        }
+    }
+    Ok(plan)
+}
+
+/// Carry out `plan` if the city can pay for it, updating the stockpile.
+pub fn apply_terraform(
+    plan: &EarthworkPlan,
+    grid: &mut WorldGrid,
+    earthworks: &mut Earthworks,
+    budget: &mut CityBudget,
+) -> Result<EarthworkBill, String> {
+    let bill = earthworks.bill(plan);
+    if budget.treasury < bill.cost {
+        return Err(format!(
+            "Earthworks need ${:.0}, treasury has ${:.0}",
+            bill.cost, budget.treasury
+        ));
+    }
+    for &(x, y, elevation) in &plan.changes {
+        let cell = grid.get_mut(x, y);
+        cell.elevation = elevation;
+        if elevation > WATER_TO_LAND_ELEVATION && cell.cell_type == CellType::Water {
+            cell.cell_type = CellType::Grass;
+        }
+    }
+    budget.treasury -= bill.cost;
+    earthworks.record(plan, &bill);
+    Ok(bill)
+}
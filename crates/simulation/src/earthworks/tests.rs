@@ -0,0 +1,118 @@
+use bevy::prelude::Entity;
+
+use super::*;
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::Saveable;
+
+fn flat_grid(elevation: f32) -> WorldGrid {
+    let mut grid = WorldGrid::new(32, 32);
+    for y in 0..32 {
+        for x in 0..32 {
+            grid.get_mut(x, y).elevation = elevation;
+        }
+    }
+    grid
+}
+
+fn budget(treasury: f64) -> CityBudget {
+    CityBudget {
+        treasury,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_raising_is_fill_and_lowering_is_cut() {
+    let grid = flat_grid(0.5);
+    let raise = plan_terraform(&grid, 16, 16, TerraformOp::Raise).unwrap();
+    assert!(raise.fill_m3 > 0.0);
+    assert_eq!(raise.cut_m3, 0.0);
+    let lower = plan_terraform(&grid, 16, 16, TerraformOp::Lower).unwrap();
+    assert!(lower.cut_m3 > 0.0);
+    assert_eq!(lower.fill_m3, 0.0);
+    assert!((raise.fill_m3 - lower.cut_m3).abs() < 1e-6);
+}
+
+#[test]
+fn test_levelling_flat_ground_moves_nothing() {
+    let grid = flat_grid(0.5);
+    let plan = plan_terraform(&grid, 16, 16, TerraformOp::Level).unwrap();
+    assert!(plan.changes.is_empty());
+    assert_eq!(Earthworks::default().bill(&plan).cost, 0.0);
+}
+
+#[test]
+fn test_fill_comes_from_the_stockpile_before_imports() {
+    let plan = EarthworkPlan {
+        changes: Vec::new(),
+        cut_m3: 0.0,
+        fill_m3: 1_000.0,
+    };
+    let empty = Earthworks::default().bill(&plan);
+    assert_eq!(empty.imported_m3, 1_000.0);
+
+    let stocked = Earthworks {
+        stockpile_m3: 600.0,
+        ..Default::default()
+    };
+    let bill = stocked.bill(&plan);
+    assert_eq!(bill.imported_m3, 400.0);
+    assert!(bill.cost < empty.cost);
+}
+
+#[test]
+fn test_cut_beyond_capacity_is_exported() {
+    let earthworks = Earthworks {
+        stockpile_m3: STOCKPILE_CAPACITY_M3 - 100.0,
+        ..Default::default()
+    };
+    let plan = EarthworkPlan {
+        changes: Vec::new(),
+        cut_m3: 300.0,
+        fill_m3: 0.0,
+    };
+    let bill = earthworks.bill(&plan);
+    assert_eq!(bill.exported_m3, 200.0);
+    assert_eq!(bill.imported_m3, 0.0);
+
+    let mut earthworks = earthworks;
+    earthworks.record(&plan, &bill);
+    assert_eq!(earthworks.stockpile_m3, STOCKPILE_CAPACITY_M3);
+}
+
+#[test]
+fn test_cut_then_fill_balances_the_stockpile() {
+    let mut grid = flat_grid(0.5);
+    let mut earthworks = Earthworks::default();
+    let mut budget = budget(100_000.0);
+
+    let cut = plan_terraform(&grid, 10, 10, TerraformOp::Lower).unwrap();
+    apply_terraform(&cut, &mut grid, &mut earthworks, &mut budget).unwrap();
+    assert!(grid.get(10, 10).elevation < 0.5);
+    assert!((earthworks.stockpile_m3 - cut.cut_m3).abs() < 1e-6);
+
+    let fill = plan_terraform(&grid, 20, 20, TerraformOp::Raise).unwrap();
+    let bill = apply_terraform(&fill, &mut grid, &mut earthworks, &mut budget).unwrap();
+    assert!(
+        bill.imported_m3 < 1e-6,
+        "the earlier cut should cover this fill"
+    );
+    assert!(budget.treasury < 100_000.0);
+    assert!(earthworks.save_to_bytes().is_some());
+}
+
+#[test]
+fn test_cannot_reshape_under_a_building_or_without_funds() {
+    let mut grid = flat_grid(0.5);
+    grid.get_mut(12, 10).building_id = Some(Entity::from_raw(7));
+    assert!(plan_terraform(&grid, 10, 10, TerraformOp::Raise).is_err());
+    assert!(plan_terraform(&grid, 20, 20, TerraformOp::Raise).is_ok());
+
+    let plan = plan_terraform(&grid, 20, 20, TerraformOp::Raise).unwrap();
+    let mut earthworks = Earthworks::default();
+    let mut broke = budget(0.0);
+    assert!(apply_terraform(&plan, &mut grid, &mut earthworks, &mut broke).is_err());
+    assert_eq!(grid.get(20, 20).elevation, 0.5);
+    assert_eq!(earthworks, Earthworks::default());
+}
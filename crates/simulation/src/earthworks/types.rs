@@ -0,0 +1,131 @@
+//! Earthwork volumes, prices and the soil stockpile.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CELL_SIZE, TERRAIN_HEIGHT_SCALE};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Cubic metres of soil in one cell per unit of elevation.
+pub const CELL_VOLUME_PER_ELEVATION: f64 = (CELL_SIZE * CELL_SIZE * TERRAIN_HEIGHT_SCALE) as f64;
+
+/// Cost of digging, moving and compacting one cubic metre.
+pub const EXCAVATION_COST_PER_M3: f64 = 0.02;
+
+/// Extra cost of buying and trucking in one cubic metre of fill.
+pub const SOIL_IMPORT_COST_PER_M3: f64 = 0.05;
+
+/// Extra cost of hauling away one cubic metre the stockpile cannot hold.
+pub const SOIL_EXPORT_COST_PER_M3: f64 = 0.03;
+
+/// Most soil the city can keep on hand (cubic metres).
+pub const STOCKPILE_CAPACITY_M3: f64 = 500_000.0;
+
+/// Radius of the terrain brush, in cells.
+pub const BRUSH_RADIUS: i32 = 3;
+
+/// Elevation raised or lowered at the brush centre per application.
+pub const BRUSH_STRENGTH: f32 = 0.01;
+
+/// Share of the gap to the target elevation closed per levelling pass.
+pub const LEVEL_RATE: f32 = 0.3;
+
+// ---------------------------------------------------------------------------
+// Operations
+// ---------------------------------------------------------------------------
+
+/// What a terrain brush stroke does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerraformOp {
+    Raise,
+    Lower,
+    /// Pull cells towards the elevation under the brush centre.
+    Level,
+}
+
+/// Elevation changes of one brush stroke and the soil they move.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EarthworkPlan {
+    /// `(x, y, new_elevation)` for every cell that changes.
+    pub changes: Vec<(usize, usize, f32)>,
+    /// Soil dug out (cubic metres).
+    pub cut_m3: f64,
+    /// Soil placed (cubic metres).
+    pub fill_m3: f64,
+}
+
+/// What carrying out a plan costs against the current stockpile.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EarthworkBill {
+    pub moved_m3: f64,
+    pub imported_m3: f64,
+    pub exported_m3: f64,
+    pub cost: f64,
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// The city's soil stockpile and earthwork totals.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Earthworks {
+    /// Soil on hand from earlier cuts (cubic metres).
+    pub stockpile_m3: f64,
+    pub total_cut_m3: f64,
+    pub total_fill_m3: f64,
+    pub imported_m3: f64,
+    pub exported_m3: f64,
+    pub total_spent: f64,
+}
+
+impl Earthworks {
+    /// Price `plan`: every cubic metre moved is paid for, fill the
+    /// stockpile cannot cover is imported and cut it cannot hold exported.
+    pub fn bill(&self, plan: &EarthworkPlan) -> EarthworkBill {
+        let after = self.stockpile_m3 + plan.cut_m3 - plan.fill_m3;
+        let imported_m3 = (-after).max(0.0);
+        let exported_m3 = (after - STOCKPILE_CAPACITY_M3).max(0.0);
+        let moved_m3 = plan.cut_m3 + plan.fill_m3;
+        EarthworkBill {
+            moved_m3,
+            imported_m3,
+            exported_m3,
+            cost: moved_m3 * EXCAVATION_COST_PER_M3
+                + imported_m3 * SOIL_IMPORT_COST_PER_M3
+                + exported_m3 * SOIL_EXPORT_COST_PER_M3,
+        }
+    }
+
+    /// Book a carried-out plan against the stockpile and totals.
+    pub fn record(&mut self, plan: &EarthworkPlan, bill: &EarthworkBill) {
+        self.stockpile_m3 = (self.stockpile_m3 + plan.cut_m3 - plan.fill_m3 + bill.imported_m3
+            - bill.exported_m3)
+            .clamp(0.0, STOCKPILE_CAPACITY_M3);
+        self.total_cut_m3 += plan.cut_m3;
+        self.total_fill_m3 += plan.fill_m3;
+        self.imported_m3 += bill.imported_m3;
+        self.exported_m3 += bill.exported_m3;
+        self.total_spent += bill.cost;
+    }
+}
+
+impl Saveable for Earthworks {
+    const SAVE_KEY: &'static str = "earthworks";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -60,6 +60,7 @@ pub fn collect_taxes(
     policies: Res<crate::policies::Policies>,
     tourism: Res<crate::tourism::Tourism>,
     mut extended: ResMut<crate::budget::ExtendedBudget>,
+    unlocks: Res<crate::unlocks::UnlockState>,
     params: (
         Res<GameParams>,
         Res<crate::coal_power::CoalPowerState>,
@@ -67,6 +68,8 @@ pub fn collect_taxes(
         Res<crate::nuclear_power::NuclearPowerState>,
         Res<crate::oil_power::OilPowerState>,
         Res<crate::biomass_power::BiomassPowerState>,
+        ResMut<crate::disaster_insurance::DisasterInsurance>,
+        ResMut<crate::carbon_ledger::CarbonLedger>,
     ),
 ) {
     let (
@@ -76,6 +79,8 @@ pub fn collect_taxes(
         nuclear_state,
         oil_state,
         biomass_state,
+        mut insurance,
+        mut carbon,
     ) = params;
 
     // Collect every N days (configurable via GameParams)
@@ -185,7 +190,8 @@ pub fn collect_taxes(
         .iter()
         .filter(|c| c.cell_type == CellType::Road)
         .map(|c| c.road_type.maintenance_cost())
-        .sum();
+        .sum::<f64>()
+        * unlocks.road_maintenance_multiplier();
 
     // Service maintenance costs — scaled by service budget slider
     let service_budgets = &extended.service_budgets;
@@ -210,6 +216,15 @@ pub fn collect_taxes(
         &biomass_state,
     );
 
+    // Disaster insurance premium, plus insurance payouts and federal aid
+    // settled since the last collection
+    let (insurance_premium, insurance_payouts, disaster_aid) = insurance.collect();
+    income += insurance_payouts + disaster_aid;
+
+    // Carbon tax or permit revenue raised since the last collection
+    let carbon_revenue = carbon.collect();
+    income += carbon_revenue;
+
     // Loan payments
     let loan_payments = extended.process_loan_payments(&mut budget.treasury);
 
@@ -219,15 +234,19 @@ pub fn collect_taxes(
     extended.income_breakdown.industrial_tax = industrial_tax;
     extended.income_breakdown.office_tax = office_tax;
     extended.income_breakdown.trade_income = tourism.monthly_tourism_income;
+    extended.income_breakdown.insurance_payouts = insurance_payouts;
+    extended.income_breakdown.disaster_aid = disaster_aid;
+    extended.income_breakdown.carbon_revenue = carbon_revenue;
     extended.expense_breakdown.road_maintenance = road_expense;
     extended.expense_breakdown.service_costs = service_expense;
     extended.expense_breakdown.policy_costs = policy_expense;
     extended.expense_breakdown.loan_payments = loan_payments;
     extended.expense_breakdown.fuel_costs = fuel_expense;
+    extended.expense_breakdown.insurance_premiums = insurance_premium;
 
     budget.monthly_income = income;
     budget.monthly_expenses =
-        road_expense + service_expense + policy_expense + fuel_expense;
+        road_expense + service_expense + policy_expense + fuel_expense + insurance_premium;
     budget.treasury += budget.monthly_income - budget.monthly_expenses;
 }
 
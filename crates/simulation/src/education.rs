@@ -1,12 +1,13 @@
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::{CellType, WorldGrid};
+use crate::packed_grid::{BitGrid, PackedGrid};
 use crate::services::ServiceBuilding;
 use bevy::prelude::*;
 use std::collections::VecDeque;
 
 #[derive(Resource)]
 pub struct EducationGrid {
-    pub levels: Vec<u8>, // 0=None, 1=Elementary, 2=HighSchool, 3=University
+    pub levels: PackedGrid<2>, // 0=None, 1=Elementary, 2=HighSchool, 3=University
     pub width: usize,
     pub height: usize,
 }
@@ -14,7 +15,7 @@ pub struct EducationGrid {
 impl Default for EducationGrid {
     fn default() -> Self {
         Self {
-            levels: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            levels: PackedGrid::new(GRID_WIDTH, GRID_HEIGHT),
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
         }
@@ -23,10 +24,10 @@ impl Default for EducationGrid {
 
 impl EducationGrid {
     pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.levels[y * self.width + x]
+        self.levels.get(x, y)
     }
     pub fn set(&mut self, x: usize, y: usize, val: u8) {
-        self.levels[y * self.width + x] = val;
+        self.levels.set(x, y, val);
     }
 }
 
@@ -37,19 +38,13 @@ pub fn propagate_education(
     mut edu_grid: ResMut<EducationGrid>,
     grid: Res<WorldGrid>,
     services: Query<&ServiceBuilding>,
-    mut visited_buf: Local<Vec<bool>>,
+    mut visited_buf: Local<BitGrid>,
 ) {
     if !slow_tick.should_run() {
         return;
     }
     edu_grid.levels.fill(0);
 
-    // Lazily initialize the reusable visited buffer
-    let grid_len = GRID_WIDTH * GRID_HEIGHT;
-    if visited_buf.len() != grid_len {
-        *visited_buf = vec![false; grid_len];
-    }
-
     // Collect education sources sorted by level (highest first so they override)
     let mut sources: Vec<(usize, usize, u8, u32)> = Vec::new();
     for service in &services {
@@ -70,7 +65,7 @@ pub fn propagate_education(
 fn bfs_education(
     edu_grid: &mut EducationGrid,
     grid: &WorldGrid,
-    visited: &mut [bool],
+    visited: &mut BitGrid,
     sx: usize,
     sy: usize,
     level: u8,
@@ -79,7 +74,7 @@ fn bfs_education(
     visited.fill(false);
     let mut queue = VecDeque::new();
     queue.push_back(((sx, sy), 0u32));
-    visited[sy * GRID_WIDTH + sx] = true;
+    visited.set(sx, sy, true);
 
     // Mark source
     if edu_grid.get(sx, sy) < level {
@@ -92,13 +87,12 @@ fn bfs_education(
         }
         let (neighbors, ncount) = grid.neighbors4(x, y);
         for &(nx, ny) in &neighbors[..ncount] {
-            let idx = ny * GRID_WIDTH + nx;
-            if visited[idx] {
+            if visited.get(nx, ny) {
                 continue;
             }
             let cell_type = grid.get(nx, ny).cell_type;
             if cell_type == CellType::Road || cell_type == CellType::Grass {
-                visited[idx] = true;
+                visited.set(nx, ny, true);
                 if edu_grid.get(nx, ny) < level {
                     edu_grid.set(nx, ny, level);
                 }
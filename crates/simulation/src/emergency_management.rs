@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use crate::buildings::Building;
 use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 use crate::disasters::ActiveDisaster;
+use crate::packed_grid::BitGrid;
 
 use crate::services::{ServiceBuilding, ServiceType};
 use crate::SlowTickTimer;
@@ -86,10 +87,10 @@ pub struct EmergencyManagementState {
     pub disasters_survived: u32,
     /// Total buildings saved by emergency response (cumulative).
     pub buildings_saved: u32,
-    /// Grid of siren coverage (true = covered by at least one siren).
+    /// Grid of siren coverage (set = covered by at least one siren).
     #[serde(skip)]
     #[bitcode(skip)]
-    pub siren_coverage: Vec<bool>,
+    pub siren_coverage: BitGrid,
 }
 
 impl Default for EmergencyManagementState {
@@ -104,7 +105,7 @@ impl Default for EmergencyManagementState {
             response_time_modifier: NO_EOC_RESPONSE_MULTIPLIER,
             disasters_survived: 0,
             buildings_saved: 0,
-            siren_coverage: vec![false; GRID_WIDTH * GRID_HEIGHT],
+            siren_coverage: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
         }
     }
 }
@@ -113,8 +114,7 @@ impl EmergencyManagementState {
     /// Check if a specific cell is covered by emergency sirens.
     #[inline]
     pub fn has_siren_coverage(&self, x: usize, y: usize) -> bool {
-        let idx = y * GRID_WIDTH + x;
-        idx < self.siren_coverage.len() && self.siren_coverage[idx]
+        self.siren_coverage.get(x, y)
     }
 
     /// Calculate the fraction of grid cells covered by sirens (0.0 to 1.0).
@@ -122,8 +122,7 @@ impl EmergencyManagementState {
         if self.siren_coverage.is_empty() {
             return 0.0;
         }
-        let covered = self.siren_coverage.iter().filter(|&&c| c).count();
-        covered as f32 / self.siren_coverage.len() as f32
+        self.siren_coverage.count_ones() as f32 / self.siren_coverage.len() as f32
     }
 }
 
@@ -142,7 +141,7 @@ impl crate::Saveable for EmergencyManagementState {
     fn load_from_bytes(bytes: &[u8]) -> Self {
         let mut state: Self = crate::decode_or_warn(Self::SAVE_KEY, bytes);
         // Rebuild transient siren coverage grid
-        state.siren_coverage = vec![false; GRID_WIDTH * GRID_HEIGHT];
+        state.siren_coverage = BitGrid::new(GRID_WIDTH, GRID_HEIGHT);
         state
     }
 }
@@ -212,8 +211,7 @@ pub fn update_emergency_infrastructure(
                 let wx = dx as f32 * CELL_SIZE;
                 let wy = dy as f32 * CELL_SIZE;
                 if wx * wx + wy * wy <= r2 {
-                    let idx = cy as usize * GRID_WIDTH + cx as usize;
-                    state.siren_coverage[idx] = true;
+                    state.siren_coverage.set(cx as usize, cy as usize, true);
                 }
             }
         }
@@ -341,7 +339,7 @@ mod tests {
         let total = state.siren_coverage.len();
         // Cover half the cells
         for i in 0..total / 2 {
-            state.siren_coverage[i] = true;
+            state.siren_coverage.set_index(i, true);
         }
         let frac = state.siren_coverage_fraction();
         assert!((frac - 0.5).abs() < 0.01);
@@ -0,0 +1,487 @@
+//! In-game encyclopedia of game mechanics.
+//!
+//! Entries are generated from the same functions and constants the
+//! simulation uses (`ServiceBuilding::monthly_maintenance`, `utility_cost`,
+//! the power plant capacity constants, `RoadType::capacity` and so on), so
+//! the numbers shown to the player cannot drift from the code.  Only the
+//! short summaries are hand-written.
+
+use crate::budget::ZoneTaxRates;
+use crate::buildings::Building;
+use crate::bulldoze_refund::{refund_for_service, refund_for_utility, REFUND_RATE};
+use crate::climate_change::co2_rate_for_utility;
+use crate::config::CELL_SIZE;
+use crate::grid::{RoadType, ZoneType};
+use crate::policies::Policy;
+use crate::services::{utility_cost, utility_range, ServiceBuilding, ServiceType};
+use crate::utilities::UtilityType;
+use crate::water_demand::supply_capacity_for_utility;
+
+/// Section of the encyclopedia an entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncyclopediaCategory {
+    Services,
+    Utilities,
+    Roads,
+    Zones,
+    Policies,
+    Systems,
+}
+
+impl EncyclopediaCategory {
+    pub const ALL: [EncyclopediaCategory; 6] = [
+        EncyclopediaCategory::Services,
+        EncyclopediaCategory::Utilities,
+        EncyclopediaCategory::Roads,
+        EncyclopediaCategory::Zones,
+        EncyclopediaCategory::Policies,
+        EncyclopediaCategory::Systems,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EncyclopediaCategory::Services => "Services",
+            EncyclopediaCategory::Utilities => "Utilities",
+            EncyclopediaCategory::Roads => "Roads",
+            EncyclopediaCategory::Zones => "Zones",
+            EncyclopediaCategory::Policies => "Policies",
+            EncyclopediaCategory::Systems => "Systems",
+        }
+    }
+}
+
+/// One article: a title, a short summary and a table of facts.
+#[derive(Debug, Clone)]
+pub struct EncyclopediaEntry {
+    pub category: EncyclopediaCategory,
+    pub title: &'static str,
+    pub summary: &'static str,
+    /// Label and formatted value, in display order.
+    pub facts: Vec<(&'static str, String)>,
+}
+
+impl EncyclopediaEntry {
+    /// Case-insensitive match of `query` against the title, category and
+    /// summary.  An empty query matches everything.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.title.to_lowercase().contains(&query)
+            || self.category.label().to_lowercase().contains(&query)
+            || self.summary.to_lowercase().contains(&query)
+    }
+
+    /// The value of a fact, if the entry has it.
+    pub fn fact(&self, label: &str) -> Option<&str> {
+        self.facts
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Entries matching a search query and optional category, in encyclopedia order.
+pub fn filter_entries<'a>(
+    entries: &'a [EncyclopediaEntry],
+    query: &str,
+    category: Option<EncyclopediaCategory>,
+) -> Vec<&'a EncyclopediaEntry> {
+    entries
+        .iter()
+        .filter(|e| category.is_none_or(|c| e.category == c))
+        .filter(|e| e.matches(query))
+        .collect()
+}
+
+/// Generate every encyclopedia entry.
+pub fn all_entries() -> Vec<EncyclopediaEntry> {
+    let mut entries = Vec::new();
+    entries.extend(ServiceType::ALL.into_iter().map(service_entry));
+    entries.extend(UtilityType::ALL.into_iter().map(utility_entry));
+    entries.extend(RoadType::ALL.into_iter().map(road_entry));
+    entries.extend(ZONES.into_iter().map(zone_entry));
+    entries.extend(Policy::all().iter().map(|&p| policy_entry(p)));
+    entries.extend(system_entries());
+    entries
+}
+
+fn money(amount: f64) -> String {
+    format!("${:.0}", amount)
+}
+
+// ---------------------------------------------------------------------------
+// Services
+// ---------------------------------------------------------------------------
+
+fn service_summary(service: ServiceType) -> &'static str {
+    use ServiceType::*;
+    match service {
+        FireStation | FireHouse | FireHQ => {
+            "Fire engines respond to fires within the coverage radius and lower fire risk."
+        }
+        HazmatResponse => "Crews contain chemical spills and industrial hazards.",
+        PoliceStation | PoliceKiosk | PoliceHQ => {
+            "Police patrols reduce crime within the coverage radius."
+        }
+        Prison => "Holds arrested criminals; its effect on crime is city-wide.",
+        Hospital | MedicalClinic | MedicalCenter => {
+            "Treats sick citizens and raises health within the coverage radius."
+        }
+        Kindergarten | ElementarySchool | HighSchool | University | Library => {
+            "Educates citizens up to its education level, unlocking better jobs."
+        }
+        Daycare | Eldercare => "Cares for children or the elderly so their families can work.",
+        SmallPark | LargePark | Playground | Plaza | SportsField | Stadium => {
+            "Recreation that raises happiness and land value nearby."
+        }
+        Landfill | RecyclingCenter | Incinerator | TransferStation => {
+            "Collects and disposes of the city's garbage."
+        }
+        Cemetery | Crematorium => "Handles the deceased before bodies pile up in homes.",
+        CityHall | Museum | Cathedral | TVStation => {
+            "Landmark that boosts happiness, land value and tourism nearby."
+        }
+        BusDepot | TrainStation | SubwayStation | TramDepot | FerryPier => {
+            "Public transit that takes cars off the road."
+        }
+        SmallAirstrip | RegionalAirport | InternationalAirport => {
+            "Connects the city to the outside world, bringing tourists and trade."
+        }
+        CellTower | DataCenter => "Telecommunications coverage for homes and businesses.",
+        PostOffice | MailSortingCenter => "Delivers mail to homes and businesses.",
+        HeatingBoiler | DistrictHeatingPlant | GeothermalPlant => {
+            "Supplies heat to buildings through the heating network in cold weather."
+        }
+        WaterTreatmentPlant | WellPump => "Supplies clean water to the pipe network.",
+        HomelessShelter | WelfareOffice => "Supports homeless and low-income citizens.",
+        CommunityCenter | SubstanceAbuseTreatmentCenter | SeniorCenter | YouthCenter => {
+            "Social services that improve wellbeing in the surrounding neighborhood."
+        }
+        Hotel => "Rooms for overnight visitors; its nightly rate follows occupancy.",
+        ConventionCenter => "Hosts conventions whose attendees fill the city's hotels.",
+    }
+}
+
+fn service_entry(service: ServiceType) -> EncyclopediaEntry {
+    let mut facts = vec![
+        ("Build cost", money(ServiceBuilding::cost(service))),
+        (
+            "Maintenance",
+            format!(
+                "{}/month",
+                money(ServiceBuilding::monthly_maintenance(service))
+            ),
+        ),
+    ];
+    let radius = ServiceBuilding::coverage_radius(service);
+    let coverage = if radius > 0.0 {
+        format!("{:.0} cells", radius / CELL_SIZE)
+    } else {
+        "City-wide".to_string()
+    };
+    facts.push(("Coverage", coverage));
+    let (w, h) = ServiceBuilding::footprint(service);
+    facts.push(("Footprint", format!("{}x{}", w, h)));
+    let level = ServiceBuilding::education_level(service);
+    if level > 0 {
+        facts.push(("Education level", level.to_string()));
+    }
+    facts.push(("Bulldoze refund", money(refund_for_service(service))));
+    EncyclopediaEntry {
+        category: EncyclopediaCategory::Services,
+        title: service.name(),
+        summary: service_summary(service),
+        facts,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Utilities
+// ---------------------------------------------------------------------------
+
+/// Nameplate capacity and fuel cost per MWh of a power plant.
+fn power_specs(utility: UtilityType) -> Option<(f32, f32)> {
+    match utility {
+        UtilityType::PowerPlant => Some((
+            crate::coal_power::COAL_CAPACITY_MW,
+            crate::coal_power::COAL_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::SolarFarm => Some((
+            crate::solar_power::SOLAR_NAMEPLATE_MW,
+            crate::solar_power::SOLAR_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::WindTurbine => Some((
+            crate::wind_power::WIND_FARM_NAMEPLATE_MW,
+            crate::wind_power::WIND_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::NuclearPlant => Some((
+            crate::nuclear_power::NUCLEAR_CAPACITY_MW,
+            crate::nuclear_power::NUCLEAR_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::Geothermal => Some((
+            crate::geothermal_power::GEOTHERMAL_CAPACITY_MW,
+            crate::geothermal_power::GEOTHERMAL_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::HydroDam => Some((
+            crate::hydro_power::HYDRO_NAMEPLATE_MW,
+            crate::hydro_power::HYDRO_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::OilPlant => Some((
+            crate::oil_power::OIL_CAPACITY_MW,
+            crate::oil_power::OIL_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::GasPlant => Some((
+            crate::gas_power::GAS_CAPACITY_MW,
+            crate::gas_power::GAS_FUEL_COST_PER_MWH,
+        )),
+        UtilityType::WaterTower
+        | UtilityType::SewagePlant
+        | UtilityType::PumpingStation
+        | UtilityType::WaterTreatment => None,
+    }
+}
+
+fn utility_summary(utility: UtilityType) -> &'static str {
+    match utility {
+        UtilityType::PowerPlant => "Coal-fired plant: cheap, steady power with heavy emissions.",
+        UtilityType::SolarFarm => "Clean power that only generates while the sun is up.",
+        UtilityType::WindTurbine => "Clean power that varies with wind speed; turbines are noisy.",
+        UtilityType::NuclearPlant => "Huge zero-carbon output, expensive to build.",
+        UtilityType::Geothermal => "Steady zero-carbon power from underground heat.",
+        UtilityType::HydroDam => "Zero-carbon power from a river dam.",
+        UtilityType::OilPlant => "Flexible fossil power with costly fuel.",
+        UtilityType::GasPlant => "Large fossil plant, cleaner than coal or oil.",
+        UtilityType::WaterTower => "Stores and distributes water to nearby buildings.",
+        UtilityType::SewagePlant => "Treats wastewater before it pollutes rivers and lakes.",
+        UtilityType::PumpingStation => "Pumps water into the network.",
+        UtilityType::WaterTreatment => "Purifies water for the city's supply.",
+    }
+}
+
+fn utility_entry(utility: UtilityType) -> EncyclopediaEntry {
+    let mut facts = vec![
+        ("Build cost", money(utility_cost(utility))),
+        ("Range", format!("{} cells", utility_range(utility))),
+    ];
+    if let Some((capacity_mw, fuel_cost)) = power_specs(utility) {
+        facts.push(("Capacity", format!("{:.0} MW", capacity_mw)));
+        facts.push(("Fuel cost", format!("${:.0}/MWh", fuel_cost)));
+        facts.push(("CO2", format!("{:.2} t/MWh", co2_rate_for_utility(utility))));
+    }
+    let water = supply_capacity_for_utility(utility);
+    if water > 0.0 {
+        facts.push(("Water supply", format!("{:.0} gal/day", water)));
+    }
+    facts.push(("Bulldoze refund", money(refund_for_utility(utility))));
+    EncyclopediaEntry {
+        category: EncyclopediaCategory::Utilities,
+        title: utility.name(),
+        summary: utility_summary(utility),
+        facts,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Roads
+// ---------------------------------------------------------------------------
+
+fn road_entry(road: RoadType) -> EncyclopediaEntry {
+    let (title, summary) = match road {
+        RoadType::Local => ("Local Road", "Two-lane street for neighborhood traffic."),
+        RoadType::Avenue => ("Avenue", "Four-lane road for medium traffic."),
+        RoadType::Boulevard => ("Boulevard", "Wide six-lane road with high capacity."),
+        RoadType::Highway => (
+            "Highway",
+            "Fast limited-access road; buildings cannot be zoned along it.",
+        ),
+        RoadType::OneWay => ("One-Way Road", "Two lanes in a single direction."),
+        RoadType::Path => ("Path", "For pedestrians and bicycles only."),
+    };
+    let mut facts = vec![
+        ("Cost", format!("{} per cell", money(road.cost()))),
+        (
+            "Maintenance",
+            format!("${:.1} per cell/month", road.maintenance_cost()),
+        ),
+        ("Speed", format!("{:.0}", road.speed())),
+        ("Lanes", road.lane_count().to_string()),
+        ("Capacity", road.capacity().to_string()),
+        ("Noise radius", format!("{} cells", road.noise_radius())),
+        (
+            "Allows zoning",
+            if road.allows_zoning() { "Yes" } else { "No" }.to_string(),
+        ),
+    ];
+    if let Some(cost) = road.upgrade_cost() {
+        facts.push(("Upgrade cost", format!("{} per cell", money(cost))));
+    }
+    EncyclopediaEntry {
+        category: EncyclopediaCategory::Roads,
+        title,
+        summary,
+        facts,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Zones
+// ---------------------------------------------------------------------------
+
+const ZONES: [ZoneType; 8] = [
+    ZoneType::ResidentialLow,
+    ZoneType::ResidentialMedium,
+    ZoneType::ResidentialHigh,
+    ZoneType::CommercialLow,
+    ZoneType::CommercialHigh,
+    ZoneType::Industrial,
+    ZoneType::Office,
+    ZoneType::MixedUse,
+];
+
+fn zone_entry(zone: ZoneType) -> EncyclopediaEntry {
+    let (title, summary) = match zone {
+        ZoneType::ResidentialLow => ("Low-Density Residential", "Houses and small apartments."),
+        ZoneType::ResidentialMedium => (
+            "Medium-Density Residential",
+            "Townhouses, duplexes and small apartment buildings.",
+        ),
+        ZoneType::ResidentialHigh => (
+            "High-Density Residential",
+            "Apartment blocks and residential towers.",
+        ),
+        ZoneType::CommercialLow => ("Low-Density Commercial", "Shops and small stores."),
+        ZoneType::CommercialHigh => ("High-Density Commercial", "Malls and department stores."),
+        ZoneType::Industrial => (
+            "Industrial",
+            "Factories and warehouses; polluting and noisy.",
+        ),
+        ZoneType::Office => ("Office", "Office buildings and business parks."),
+        ZoneType::MixedUse => ("Mixed Use", "Shops on the ground floor with homes above."),
+        ZoneType::None => ("Unzoned", ""),
+    };
+    let capacity_label = if zone.is_residential() {
+        "Residents by level"
+    } else {
+        "Capacity by level"
+    };
+    let capacities: Vec<String> = (1..=zone.max_level())
+        .map(|level| Building::capacity_for_level(zone, level).to_string())
+        .collect();
+    EncyclopediaEntry {
+        category: EncyclopediaCategory::Zones,
+        title,
+        summary,
+        facts: vec![
+            ("Max level", zone.max_level().to_string()),
+            ("Floor area ratio", format!("{:.1}", zone.default_far())),
+            (capacity_label, capacities.join(" / ")),
+        ],
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Policies and systems
+// ---------------------------------------------------------------------------
+
+fn policy_entry(policy: Policy) -> EncyclopediaEntry {
+    EncyclopediaEntry {
+        category: EncyclopediaCategory::Policies,
+        title: policy.name(),
+        summary: policy.description(),
+        facts: vec![("Upkeep", format!("{}/month", money(policy.monthly_cost())))],
+    }
+}
+
+fn system_entries() -> Vec<EncyclopediaEntry> {
+    let taxes = ZoneTaxRates::default();
+    let pct = |rate: f32| format!("{:.0}%", rate * 100.0);
+    vec![
+        EncyclopediaEntry {
+            category: EncyclopediaCategory::Systems,
+            title: "Taxes",
+            summary: "Each zone type is taxed at its own rate. Higher taxes raise \
+                      income but lower demand for that zone.",
+            facts: vec![
+                ("Default residential", pct(taxes.residential)),
+                ("Default commercial", pct(taxes.commercial)),
+                ("Default industrial", pct(taxes.industrial)),
+                ("Default office", pct(taxes.office)),
+            ],
+        },
+        EncyclopediaEntry {
+            category: EncyclopediaCategory::Systems,
+            title: "Bulldozing",
+            summary: "Demolishing roads, services and utilities refunds part of \
+                      their build cost.",
+            facts: vec![(
+                "Refund",
+                format!("{:.0}% of build cost", REFUND_RATE * 100.0),
+            )],
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_building_and_policy_has_an_entry() {
+        let entries = all_entries();
+        let count = |c| entries.iter().filter(|e| e.category == c).count();
+        assert_eq!(
+            count(EncyclopediaCategory::Services),
+            ServiceType::ALL.len()
+        );
+        assert_eq!(
+            count(EncyclopediaCategory::Utilities),
+            UtilityType::ALL.len()
+        );
+        assert_eq!(count(EncyclopediaCategory::Roads), RoadType::ALL.len());
+        assert_eq!(count(EncyclopediaCategory::Policies), Policy::all().len());
+    }
+
+    #[test]
+    fn titles_are_unique() {
+        let entries = all_entries();
+        let mut titles: Vec<&str> = entries.iter().map(|e| e.title).collect();
+        titles.sort_unstable();
+        titles.dedup();
+        assert_eq!(titles.len(), entries.len());
+    }
+
+    #[test]
+    fn numbers_come_from_the_simulation() {
+        let entries = all_entries();
+        let find = |title| entries.iter().find(|e| e.title == title).unwrap();
+
+        let hospital = find(ServiceType::Hospital.name());
+        let maintenance = ServiceBuilding::monthly_maintenance(ServiceType::Hospital);
+        assert_eq!(
+            hospital.fact("Maintenance"),
+            Some(format!("${:.0}/month", maintenance).as_str())
+        );
+
+        let nuclear = find(UtilityType::NuclearPlant.name());
+        let capacity = crate::nuclear_power::NUCLEAR_CAPACITY_MW;
+        assert_eq!(
+            nuclear.fact("Capacity"),
+            Some(format!("{:.0} MW", capacity).as_str())
+        );
+        assert!(find(UtilityType::WaterTower.name())
+            .fact("Capacity")
+            .is_none());
+    }
+
+    #[test]
+    fn search_filters_by_text_and_category() {
+        let entries = all_entries();
+        let hits = filter_entries(&entries, "  FIRE ", None);
+        assert!(hits.iter().any(|e| e.title == "Fire Station"));
+        assert!(hits.iter().all(|e| e.matches("fire")));
+
+        let policies = filter_entries(&entries, "", Some(EncyclopediaCategory::Policies));
+        assert_eq!(policies.len(), Policy::all().len());
+        assert!(filter_entries(&entries, "fire", Some(EncyclopediaCategory::Roads)).is_empty());
+    }
+}
@@ -2,17 +2,21 @@
 //!
 //! These grids are recomputed every slow tick, but persisting them avoids
 //! a full recomputation gap after a save/load cycle. Each grid stores a
-//! `Vec<u8>` (or `Vec<bool>` / `Vec<f32>`) that is encoded via `bitcode`.
+//! `Vec<u8>` (or `Vec<bool>` / `Vec<f32>`) that is encoded via `bitcode`;
+//! chunked grids are encoded in row-major order, so the format does not
+//! depend on the storage layout.
 //!
 //! The plugin registers all grids with the `SaveableRegistry` so the save
 //! system picks them up automatically.
 
 use bevy::prelude::*;
 
+use crate::chunked_grid::ChunkedGrid;
 use crate::crime::CrimeGrid;
 use crate::forest_fire::ForestFireGrid;
 use crate::groundwater::{GroundwaterGrid, WaterQualityGrid};
 use crate::noise::NoisePollutionGrid;
+use crate::packed_grid::BitGrid;
 use crate::pollution::PollutionGrid;
 use crate::stormwater::StormwaterGrid;
 use crate::trees::TreeGrid;
@@ -51,14 +55,18 @@ impl Saveable for PollutionGrid {
         if self.levels.iter().all(|&v| v == 0) {
             return None;
         }
-        Some(encode_u8_grid(&self.levels))
+        Some(encode_u8_grid(&self.levels.to_row_major()))
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
         let expected = crate::config::GRID_WIDTH * crate::config::GRID_HEIGHT;
         let levels = decode_u8_grid(bytes, expected);
         Self {
-            levels,
+            levels: ChunkedGrid::from_row_major(
+                crate::config::GRID_WIDTH,
+                crate::config::GRID_HEIGHT,
+                levels,
+            ),
             width: crate::config::GRID_WIDTH,
             height: crate::config::GRID_HEIGHT,
         }
@@ -101,14 +109,18 @@ impl Saveable for CrimeGrid {
         if self.levels.iter().all(|&v| v == 0) {
             return None;
         }
-        Some(encode_u8_grid(&self.levels))
+        Some(encode_u8_grid(&self.levels.to_row_major()))
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
         let expected = crate::config::GRID_WIDTH * crate::config::GRID_HEIGHT;
         let levels = decode_u8_grid(bytes, expected);
         Self {
-            levels,
+            levels: ChunkedGrid::from_row_major(
+                crate::config::GRID_WIDTH,
+                crate::config::GRID_HEIGHT,
+                levels,
+            ),
             width: crate::config::GRID_WIDTH,
             height: crate::config::GRID_HEIGHT,
         }
@@ -123,17 +135,17 @@ impl Saveable for TreeGrid {
     const SAVE_KEY: &'static str = "tree_grid";
 
     fn save_to_bytes(&self) -> Option<Vec<u8>> {
-        if self.cells.iter().all(|&v| !v) {
+        if !self.cells.any() {
             return None;
         }
-        Some(bitcode::encode(&self.cells))
+        Some(bitcode::encode(&self.cells.to_bools()))
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
-        let expected = crate::config::GRID_WIDTH * crate::config::GRID_HEIGHT;
+        let (w, h) = (crate::config::GRID_WIDTH, crate::config::GRID_HEIGHT);
         let cells = match bitcode::decode::<Vec<bool>>(bytes) {
-            Ok(v) if v.len() == expected => v,
-            _ => vec![false; expected],
+            Ok(v) if v.len() == w * h => BitGrid::from_bools(w, h, &v),
+            _ => BitGrid::new(w, h),
         };
         Self {
             cells,
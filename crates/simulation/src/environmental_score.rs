@@ -148,7 +148,7 @@ fn compute_noise_score(noise: &NoisePollutionGrid) -> f32 {
 /// Green coverage = (cells with trees / total cells) × 100.
 /// We cap at 100% so any tree density > 100% of cells is still 100.
 fn compute_green_coverage(tree_grid: &TreeGrid) -> f32 {
-    let tree_count: usize = tree_grid.cells.iter().filter(|&&v| v).count();
+    let tree_count: usize = tree_grid.tree_count();
     let total_cells = (GRID_WIDTH * GRID_HEIGHT) as f32;
     if total_cells == 0.0 {
         return 0.0;
@@ -0,0 +1,43 @@
+//! Reading and writing the event catalog config file.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use super::types::EventCatalog;
+
+/// Catalog file, relative to the working directory (next to save files).
+pub const CATALOG_PATH: &str = "event_catalog.json";
+
+impl EventCatalog {
+    /// Parse and validate a catalog from JSON.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let catalog: EventCatalog =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        catalog.validate()?;
+        Ok(catalog)
+    }
+
+    /// Pretty-printed JSON for the config file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load the catalog from `path`. A missing file yields the built-in
+    /// catalog; an unreadable or invalid one is reported as an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::builtin()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+
+    /// Validate and write the catalog to `path`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        self.validate()?;
+        let json = self.to_json()?;
+        std::fs::write(path, json).map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+}
@@ -0,0 +1,23 @@
+//! Data-driven catalog of random city events.
+//!
+//! The random events rolled by `events::random_city_events` are described by
+//! `EventDefinition`s: a per-slow-tick chance, prerequisites, and a list of
+//! effects. The catalog is read from `event_catalog.json` at startup (falling
+//! back to the built-in festival / boom / epidemic set) and can be edited and
+//! written back from the in-game event editor.
+//!
+//! Rolls come from the seeded `SimRng`, one per definition in catalog order,
+//! so a given seed and catalog always produce the same event history. Players
+//! can disable whole categories per save with `DisabledEventCategories`;
+//! disabled events still consume their roll so toggling a category does not
+//! reshuffle the others.
+
+pub mod io;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use io::CATALOG_PATH;
+pub use systems::EventCatalogPlugin;
+pub use types::*;
@@ -0,0 +1,33 @@
+//! Plugin wiring for the event catalog.
+
+use bevy::prelude::*;
+
+use super::types::{DisabledEventCategories, EventCatalog};
+
+/// Load the catalog config, falling back to the built-in events on error.
+fn initial_catalog() -> EventCatalog {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = std::path::Path::new(super::CATALOG_PATH);
+        match EventCatalog::load(path) {
+            Ok(catalog) => return catalog,
+            Err(e) => warn!("Ignoring event catalog: {e}"),
+        }
+    }
+    EventCatalog::builtin()
+}
+
+pub struct EventCatalogPlugin;
+
+impl Plugin for EventCatalogPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(initial_catalog())
+            .init_resource::<DisabledEventCategories>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<DisabledEventCategories>();
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+use crate::events::CityEventType;
+use crate::Saveable;
+
+fn definition(id: &str, chance: f32) -> EventDefinition {
+    EventDefinition {
+        id: id.to_string(),
+        name: id.to_string(),
+        category: EventCategory::Economy,
+        chance,
+        prerequisites: EventPrerequisites::default(),
+        effects: vec![EventEffect::Treasury { amount: 100.0 }],
+        journal: JournalKind::Disaster,
+        description: String::new(),
+    }
+}
+
+#[test]
+fn builtin_catalog_is_valid() {
+    let catalog = EventCatalog::builtin();
+    assert!(catalog.validate().is_ok());
+    assert_eq!(catalog.get("festival").unwrap().chance, 0.02);
+    assert_eq!(catalog.get("economic_boom").unwrap().chance, 0.01);
+    assert_eq!(catalog.get("epidemic").unwrap().chance, 0.005);
+}
+
+#[test]
+fn json_roundtrip_preserves_catalog() {
+    let catalog = EventCatalog::builtin();
+    let json = catalog.to_json().unwrap();
+    assert_eq!(EventCatalog::from_json(&json).unwrap(), catalog);
+}
+
+#[test]
+fn json_uses_defaults_for_optional_fields() {
+    let json = r#"{"events":[{"id":"grant","name":"State Grant","category":"economy",
+        "chance":0.5,"effects":[{"type":"treasury","amount":2500.0}],
+        "description":"A state grant arrives."}]}"#;
+    let catalog = EventCatalog::from_json(json).unwrap();
+    let def = &catalog.events[0];
+    assert_eq!(def.prerequisites, EventPrerequisites::default());
+    assert_eq!(def.journal, JournalKind::Disaster);
+    assert_eq!(def.effects, vec![EventEffect::Treasury { amount: 2500.0 }]);
+}
+
+#[test]
+fn validate_rejects_duplicate_ids_and_bad_chances() {
+    let dup = EventCatalog {
+        events: vec![definition("a", 0.1), definition("a", 0.1)],
+    };
+    assert!(dup.validate().is_err());
+
+    let bad = EventCatalog {
+        events: vec![definition("a", 1.5)],
+    };
+    assert!(bad.validate().is_err());
+    assert!(EventCatalog::from_json(&bad.to_json().unwrap()).is_err());
+
+    let empty_id = EventCatalog {
+        events: vec![definition(" ", 0.1)],
+    };
+    assert!(empty_id.validate().is_err());
+}
+
+#[test]
+fn prerequisites_check_population_treasury_and_day() {
+    let prereq = EventPrerequisites {
+        min_population: 1000,
+        max_population: Some(5000),
+        min_treasury: Some(0.0),
+        min_day: 10,
+    };
+    let ok = EventContext {
+        population: 2000,
+        treasury: 50.0,
+        day: 12,
+    };
+    assert!(prereq.met(&ok));
+    assert!(!prereq.met(&EventContext {
+        population: 500,
+        ..ok
+    }));
+    assert!(!prereq.met(&EventContext {
+        population: 6000,
+        ..ok
+    }));
+    assert!(!prereq.met(&EventContext {
+        treasury: -1.0,
+        ..ok
+    }));
+    assert!(!prereq.met(&EventContext { day: 3, ..ok }));
+}
+
+#[test]
+fn describe_fills_export_rate() {
+    let def = EventCatalog::builtin()
+        .get("economic_boom")
+        .unwrap()
+        .clone();
+    assert_eq!(
+        def.describe(2.0),
+        "Economic boom! Trade income surges (export rate: 4.0/building)."
+    );
+}
+
+#[test]
+fn journal_kind_maps_to_event_type() {
+    assert!(matches!(
+        JournalKind::Festival.event_type("x"),
+        CityEventType::Festival
+    ));
+    assert!(matches!(
+        JournalKind::Disaster.event_type("Meteor"),
+        CityEventType::DisasterStrike(name) if name == "Meteor"
+    ));
+}
+
+#[test]
+fn disabled_categories_toggle_and_roundtrip() {
+    let mut disabled = DisabledEventCategories::default();
+    assert!(disabled.save_to_bytes().is_none());
+    disabled.set_enabled(EventCategory::Health, false);
+    disabled.set_enabled(EventCategory::Health, false);
+    assert_eq!(disabled.disabled, vec![EventCategory::Health]);
+    assert!(!disabled.is_enabled(EventCategory::Health));
+
+    let bytes = disabled.save_to_bytes().unwrap();
+    let restored = DisabledEventCategories::load_from_bytes(&bytes);
+    assert_eq!(restored, disabled);
+
+    disabled.set_enabled(EventCategory::Health, true);
+    assert!(disabled.is_enabled(EventCategory::Health));
+}
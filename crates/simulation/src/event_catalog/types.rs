@@ -0,0 +1,305 @@
+//! Event definitions, effects, prerequisites, and category toggles.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::events::CityEventType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Categories
+// ---------------------------------------------------------------------------
+
+/// Broad grouping players can switch off per save.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventCategory {
+    Celebration,
+    Economy,
+    Health,
+    Disaster,
+}
+
+impl EventCategory {
+    pub const ALL: [EventCategory; 4] = [
+        Self::Celebration,
+        Self::Economy,
+        Self::Health,
+        Self::Disaster,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Celebration => "Celebrations",
+            Self::Economy => "Economy",
+            Self::Health => "Health",
+            Self::Disaster => "Disasters",
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Effects
+// ---------------------------------------------------------------------------
+
+/// What happens when an event fires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventEffect {
+    /// Add to every citizen's happiness (clamped to 0..100).
+    Happiness { amount: f32 },
+    /// Add to every citizen's health (clamped to 0..100).
+    Health { amount: f32 },
+    /// One-off treasury change.
+    Treasury { amount: f64 },
+    /// Start a festival (ongoing happiness) for `ticks` slow ticks.
+    Festival { ticks: u32 },
+    /// Start an economic boom (doubled trade income) for `ticks` slow ticks.
+    EconomicBoom { ticks: u32 },
+    /// Start an epidemic (ongoing health drain) for `ticks` slow ticks.
+    Epidemic { ticks: u32 },
+}
+
+impl EventEffect {
+    /// One effect of each kind with a neutral default value, for editors.
+    pub fn templates() -> [EventEffect; 6] {
+        [
+            Self::Happiness { amount: 5.0 },
+            Self::Health { amount: -5.0 },
+            Self::Treasury { amount: 1000.0 },
+            Self::Festival { ticks: 10 },
+            Self::EconomicBoom { ticks: 20 },
+            Self::Epidemic { ticks: 10 },
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Happiness { .. } => "Happiness",
+            Self::Health { .. } => "Health",
+            Self::Treasury { .. } => "Treasury",
+            Self::Festival { .. } => "Festival",
+            Self::EconomicBoom { .. } => "Economic boom",
+            Self::Epidemic { .. } => "Epidemic",
+        }
+    }
+}
+
+/// Journal entry type recorded when an event fires.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalKind {
+    Festival,
+    EconomicBoom,
+    Epidemic,
+    PopulationBoom,
+    #[default]
+    Disaster,
+}
+
+impl JournalKind {
+    pub const ALL: [JournalKind; 5] = [
+        Self::Festival,
+        Self::EconomicBoom,
+        Self::Epidemic,
+        Self::PopulationBoom,
+        Self::Disaster,
+    ];
+
+    pub fn event_type(self, name: &str) -> CityEventType {
+        match self {
+            Self::Festival => CityEventType::Festival,
+            Self::EconomicBoom => CityEventType::EconomicBoom,
+            Self::Epidemic => CityEventType::Epidemic,
+            Self::PopulationBoom => CityEventType::PopulationBoom,
+            Self::Disaster => CityEventType::DisasterStrike(name.to_string()),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Prerequisites
+// ---------------------------------------------------------------------------
+
+/// City state an event is checked against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventContext {
+    pub population: u32,
+    pub treasury: f64,
+    pub day: u32,
+}
+
+/// Conditions that must hold for an event to be eligible.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventPrerequisites {
+    pub min_population: u32,
+    pub max_population: Option<u32>,
+    pub min_treasury: Option<f64>,
+    pub min_day: u32,
+}
+
+impl EventPrerequisites {
+    pub fn met(&self, ctx: &EventContext) -> bool {
+        ctx.population >= self.min_population
+            && self.max_population.is_none_or(|max| ctx.population <= max)
+            && self.min_treasury.is_none_or(|min| ctx.treasury >= min)
+            && ctx.day >= self.min_day
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Definitions and catalog
+// ---------------------------------------------------------------------------
+
+/// Placeholder in descriptions replaced with the boosted export rate.
+pub const EXPORT_RATE_PLACEHOLDER: &str = "{export_rate}";
+
+/// One random event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventDefinition {
+    /// Stable identifier, unique within the catalog.
+    pub id: String,
+    pub name: String,
+    pub category: EventCategory,
+    /// Chance of firing per slow tick (0..1).
+    pub chance: f32,
+    #[serde(default)]
+    pub prerequisites: EventPrerequisites,
+    pub effects: Vec<EventEffect>,
+    #[serde(default)]
+    pub journal: JournalKind,
+    /// Journal text; may contain `{export_rate}`.
+    pub description: String,
+}
+
+impl EventDefinition {
+    /// Journal text with placeholders filled in.
+    pub fn describe(&self, export_income_per_industrial: f64) -> String {
+        self.description.replace(
+            EXPORT_RATE_PLACEHOLDER,
+            &format!("{:.1}", export_income_per_industrial * 2.0),
+        )
+    }
+}
+
+/// All random events the simulation can roll.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventCatalog {
+    pub events: Vec<EventDefinition>,
+}
+
+impl Default for EventCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl EventCatalog {
+    /// The events shipped with the game.
+    pub fn builtin() -> Self {
+        Self {
+            events: vec![
+                EventDefinition {
+                    id: "festival".to_string(),
+                    name: "City Festival".to_string(),
+                    category: EventCategory::Celebration,
+                    chance: 0.02,
+                    prerequisites: EventPrerequisites::default(),
+                    effects: vec![
+                        EventEffect::Festival { ticks: 10 },
+                        EventEffect::Happiness { amount: 5.0 },
+                    ],
+                    journal: JournalKind::Festival,
+                    description: "A city festival is underway! Citizens are happier.".to_string(),
+                },
+                EventDefinition {
+                    id: "economic_boom".to_string(),
+                    name: "Economic Boom".to_string(),
+                    category: EventCategory::Economy,
+                    chance: 0.01,
+                    prerequisites: EventPrerequisites::default(),
+                    effects: vec![EventEffect::EconomicBoom { ticks: 20 }],
+                    journal: JournalKind::EconomicBoom,
+                    description: format!(
+                        "Economic boom! Trade income surges (export rate: {EXPORT_RATE_PLACEHOLDER}/building)."
+                    ),
+                },
+                EventDefinition {
+                    id: "epidemic".to_string(),
+                    name: "Epidemic".to_string(),
+                    category: EventCategory::Health,
+                    chance: 0.005,
+                    prerequisites: EventPrerequisites::default(),
+                    effects: vec![
+                        EventEffect::Epidemic { ticks: 10 },
+                        EventEffect::Health { amount: -5.0 },
+                    ],
+                    journal: JournalKind::Epidemic,
+                    description: "An epidemic has broken out! Citizens' health is declining."
+                        .to_string(),
+                },
+            ],
+        }
+    }
+
+    /// Check ids are present and unique and chances lie in 0..1.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, def) in self.events.iter().enumerate() {
+            if def.id.trim().is_empty() {
+                return Err(format!("event #{} has an empty id", i + 1));
+            }
+            if self.events[..i].iter().any(|other| other.id == def.id) {
+                return Err(format!("duplicate event id '{}'", def.id));
+            }
+            if !def.chance.is_finite() || !(0.0..=1.0).contains(&def.chance) {
+                return Err(format!(
+                    "event '{}' has chance {} outside 0..1",
+                    def.id, def.chance
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&EventDefinition> {
+        self.events.iter().find(|def| def.id == id)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Per-save toggles
+// ---------------------------------------------------------------------------
+
+/// Event categories the player has switched off for this city.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct DisabledEventCategories {
+    pub disabled: Vec<EventCategory>,
+}
+
+impl DisabledEventCategories {
+    pub fn is_enabled(&self, category: EventCategory) -> bool {
+        !self.disabled.contains(&category)
+    }
+
+    pub fn set_enabled(&mut self, category: EventCategory, enabled: bool) {
+        self.disabled.retain(|&c| c != category);
+        if !enabled {
+            self.disabled.push(category);
+        }
+    }
+}
+
+impl Saveable for DisabledEventCategories {
+    const SAVE_KEY: &'static str = "event_categories";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.disabled.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -1,10 +1,11 @@
+use crate::sim_rng::SimRng;
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use rand::Rng;
-use crate::sim_rng::SimRng;
 
 use crate::citizen::{Citizen, CitizenDetails};
 use crate::economy::CityBudget;
+use crate::event_catalog::{DisabledEventCategories, EventCatalog, EventContext, EventEffect};
 use crate::imports_exports::TradeConnections;
 use crate::stats::CityStats;
 use crate::time_of_day::GameClock;
@@ -26,6 +27,20 @@ pub enum CityEventType {
     Festival,                   // happiness boost event
     EconomicBoom,               // trade income surge
     ResourceDepleted(String),   // "Oil deposit at (x,y) depleted"
+    DisasterReport(DisasterReport),
+}
+
+/// Aftermath of one disaster, logged once its insurance claim is settled.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct DisasterReport {
+    pub disaster: String,
+    /// Day the disaster struck.
+    pub day: u32,
+    pub buildings_destroyed: u32,
+    pub buildings_damaged: u32,
+    pub casualties: u32,
+    pub economic_loss: f64,
+    pub insurance_payout: f64,
 }
 
 // =============================================================================
@@ -107,15 +122,17 @@ pub struct MilestoneTracker {
 // Random City Events System
 // =============================================================================
 
-/// Runs on SlowTickTimer ticks. Generates random city events and checks
-/// for budget crises and population milestones.
+/// Runs on SlowTickTimer ticks. Rolls the random events in the
+/// `EventCatalog` and checks for budget crises and population milestones.
 #[allow(clippy::too_many_arguments)]
 pub fn random_city_events(
     slow_tick: Res<SlowTickTimer>,
     clock: Res<GameClock>,
     stats: Res<CityStats>,
-    budget: Res<CityBudget>,
+    mut budget: ResMut<CityBudget>,
     trade: Res<TradeConnections>,
+    catalog: Res<EventCatalog>,
+    disabled: Res<DisabledEventCategories>,
     mut journal: ResMut<EventJournal>,
     mut effects: ResMut<ActiveCityEffects>,
     mut milestones: ResMut<MilestoneTracker>,
@@ -138,46 +155,46 @@ pub fn random_city_events(
     }
 
     // --- Random events ---
-
-    // 2% chance: Festival (boost happiness by 5 for all citizens for 10 ticks)
-    if rng.0.gen::<f32>() < 0.02 {
-        effects.festival_ticks = 10;
-        for mut details in citizens.iter_mut() {
-            details.happiness = (details.happiness + 5.0).min(100.0);
+    // Every definition consumes one roll, eligible or not, so the seeded
+    // stream stays stable when categories are toggled.
+    let context = EventContext {
+        population: stats.population,
+        treasury: budget.treasury,
+        day: clock.day,
+    };
+    for def in &catalog.events {
+        let roll = rng.0.gen::<f32>();
+        if roll >= def.chance
+            || !disabled.is_enabled(def.category)
+            || !def.prerequisites.met(&context)
+        {
+            continue;
         }
-        journal.push(CityEvent {
-            event_type: CityEventType::Festival,
-            day: clock.day,
-            hour: clock.hour,
-            description: "A city festival is underway! Citizens are happier.".to_string(),
-        });
-    }
-
-    // 1% chance: Economic Boom (double trade income for 20 ticks)
-    if rng.0.gen::<f32>() < 0.01 {
-        effects.economic_boom_ticks = 20;
-        journal.push(CityEvent {
-            event_type: CityEventType::EconomicBoom,
-            day: clock.day,
-            hour: clock.hour,
-            description: format!(
-                "Economic boom! Trade income surges (export rate: {:.1}/building).",
-                trade.export_income_per_industrial * 2.0
-            ),
-        });
-    }
 
-    // 0.5% chance: Epidemic (reduce health for citizens by 5)
-    if rng.0.gen::<f32>() < 0.005 {
-        effects.epidemic_ticks = 10;
-        for mut details in citizens.iter_mut() {
-            details.health = (details.health - 5.0).max(0.0);
+        for effect in &def.effects {
+            match *effect {
+                EventEffect::Happiness { amount } => {
+                    for mut details in citizens.iter_mut() {
+                        details.happiness = (details.happiness + amount).clamp(0.0, 100.0);
+                    }
+                }
+                EventEffect::Health { amount } => {
+                    for mut details in citizens.iter_mut() {
+                        details.health = (details.health + amount).clamp(0.0, 100.0);
+                    }
+                }
+                EventEffect::Treasury { amount } => budget.treasury += amount,
+                EventEffect::Festival { ticks } => effects.festival_ticks = ticks,
+                EventEffect::EconomicBoom { ticks } => effects.economic_boom_ticks = ticks,
+                EventEffect::Epidemic { ticks } => effects.epidemic_ticks = ticks,
+            }
         }
+
         journal.push(CityEvent {
-            event_type: CityEventType::Epidemic,
+            event_type: def.journal.event_type(&def.name),
             day: clock.day,
             hour: clock.hour,
-            description: "An epidemic has broken out! Citizens' health is declining.".to_string(),
+            description: def.describe(trade.export_income_per_industrial),
         });
     }
 
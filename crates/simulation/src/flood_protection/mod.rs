@@ -38,4 +38,4 @@ pub use systems::{
     can_place_floodgate, can_place_levee, can_place_seawall, daily_maintenance_cost,
     is_adjacent_to_water, should_fail, update_flood_protection, FloodProtectionPlugin,
 };
-pub use types::{FloodProtectionState, ProtectionStructure, ProtectionType};
+pub use types::{FloodProtectionState, ProtectionStructure, ProtectionType, SEAWALL_COST};
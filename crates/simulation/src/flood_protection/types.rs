@@ -18,6 +18,9 @@ pub(crate) const SEAWALL_DESIGN_HEIGHT: f32 = 15.0;
 /// Default design height for closed floodgates in feet.
 pub(crate) const FLOODGATE_DESIGN_HEIGHT: f32 = 12.0;
 
+/// Construction cost of one seawall cell in dollars.
+pub const SEAWALL_COST: f64 = 5_000.0;
+
 /// Annual maintenance cost per protection cell in dollars.
 pub(crate) const MAINTENANCE_COST_PER_CELL_PER_YEAR: f64 = 2_000.0;
 
@@ -171,6 +174,15 @@ impl Default for FloodProtectionState {
     }
 }
 
+impl FloodProtectionState {
+    /// Whether a protection structure stands on the given cell.
+    pub fn has_structure_at(&self, x: usize, y: usize) -> bool {
+        self.structures
+            .iter()
+            .any(|s| s.grid_x as usize == x && s.grid_y as usize == y)
+    }
+}
+
 impl Saveable for FloodProtectionState {
     const SAVE_KEY: &'static str = "flood_protection";
 
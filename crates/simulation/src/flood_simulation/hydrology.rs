@@ -0,0 +1,353 @@
+//! Terrain-following surface flow, spatial drainage and the river channel.
+//!
+//! Ground height is converted from the grid's normalised elevation to feet so
+//! that it can be compared with flood depth: water always moves towards the
+//! lowest water surface (ground + depth), which makes it run down slopes and
+//! pool in valleys. Narrow water bodies (rivers and canals such as the Yarkon)
+//! are classified as channels and carry water away through `RiverState`; open
+//! water (sea and lakes) absorbs whatever reaches it.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid};
+use crate::storm_drainage::{StormDrainageInfrastructure, StormDrainageType};
+use crate::Saveable;
+
+use super::damage_curves::{NATURAL_DRAIN_RATE, SPREAD_RATE, STORM_DRAIN_RATE};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Feet of relief represented by the full [0, 1] elevation range.
+pub(crate) const ELEVATION_TO_FEET: f32 = 100.0;
+
+/// Largest fraction of a surface difference that moves to one neighbor per
+/// iteration. Keeping it at or below 1/4 stops pools from sloshing back and
+/// forth between cells.
+pub(crate) const MAX_LEVELING_FRACTION: f32 = 0.2;
+
+/// A water cell with land on both sides within this many cells (along x or y)
+/// is part of a river channel rather than open water.
+pub(crate) const CHANNEL_MAX_WIDTH: usize = 6;
+
+/// Cells (Chebyshev distance) served by one storm drain, rain garden or
+/// retention pond inlet.
+pub(crate) const DRAIN_REACH: usize = 2;
+
+/// Extra drainage at a rain garden's own cell (feet per tick). Neighbors
+/// within `DRAIN_REACH` get `STORM_DRAIN_RATE`.
+pub(crate) const RAIN_GARDEN_DRAIN_RATE: f32 = 0.10;
+
+/// Drainage into a retention pond's inlets while it still has room (feet per
+/// tick).
+pub(crate) const RETENTION_INTAKE_RATE: f32 = 0.20;
+
+/// River stage rise (feet per tick) for each inch/hr of rain falling on the
+/// upstream catchment.
+pub(crate) const RIVER_RAIN_TO_STAGE: f32 = 1.0;
+
+/// Fraction of the river stage discharged to the sea each tick.
+pub(crate) const RIVER_DISCHARGE_RATE: f32 = 0.1;
+
+/// Highest stage the river can reach (feet above its bed).
+pub(crate) const RIVER_MAX_STAGE_FT: f32 = 30.0;
+
+/// Fraction of the difference between the river surface and a lower bank's
+/// water surface that spills onto the bank each tick.
+pub(crate) const OVERTOP_RATE: f32 = 0.25;
+
+// =============================================================================
+// Surface classification
+// =============================================================================
+
+/// How a cell takes part in surface flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurfaceKind {
+    /// Water can pool here.
+    Land,
+    /// River or canal: water that reaches it joins the river flow.
+    Channel,
+    /// Sea or lake: water that reaches it is gone.
+    OpenWater,
+}
+
+/// Cached classification of every cell, rebuilt when the world grid changes.
+#[derive(Debug, Clone, Default)]
+pub struct SurfaceMap {
+    pub kinds: Vec<SurfaceKind>,
+    /// Ground height of every cell in feet.
+    pub ground_ft: Vec<f32>,
+    /// Indices of all channel cells.
+    pub channel_cells: Vec<usize>,
+}
+
+impl SurfaceMap {
+    pub fn build(grid: &WorldGrid) -> Self {
+        let mut kinds = vec![SurfaceKind::Land; GRID_WIDTH * GRID_HEIGHT];
+        let mut ground_ft = vec![0.0; GRID_WIDTH * GRID_HEIGHT];
+        let mut channel_cells = Vec::new();
+        for y in 0..GRID_HEIGHT {
+            for x in 0..GRID_WIDTH {
+                let idx = y * GRID_WIDTH + x;
+                ground_ft[idx] = grid.get(x, y).elevation * ELEVATION_TO_FEET;
+                if grid.get(x, y).cell_type != CellType::Water {
+                    continue;
+                }
+                if is_channel(grid, x, y) {
+                    kinds[idx] = SurfaceKind::Channel;
+                    channel_cells.push(idx);
+                } else {
+                    kinds[idx] = SurfaceKind::OpenWater;
+                }
+            }
+        }
+        Self {
+            kinds,
+            ground_ft,
+            channel_cells,
+        }
+    }
+}
+
+/// True if the water cell at (`x`, `y`) has land on both sides within
+/// `CHANNEL_MAX_WIDTH` cells, either across x or across y.
+pub fn is_channel(grid: &WorldGrid, x: usize, y: usize) -> bool {
+    let land = |cx: usize, cy: usize| grid.get(cx, cy).cell_type != CellType::Water;
+    let reaches = |dx: isize, dy: isize| {
+        (1..=CHANNEL_MAX_WIDTH as isize).any(|step| {
+            let cx = x as isize + dx * step;
+            let cy = y as isize + dy * step;
+            cx >= 0
+                && cy >= 0
+                && grid.in_bounds(cx as usize, cy as usize)
+                && land(cx as usize, cy as usize)
+        })
+    };
+    (reaches(-1, 0) && reaches(1, 0)) || (reaches(0, -1) && reaches(0, 1))
+}
+
+// =============================================================================
+// Surface flow
+// =============================================================================
+
+/// One iteration of surface flow over a `width` x `height` grid.
+///
+/// Each wet cell moves up to `SPREAD_RATE` of its depth to neighbors with a
+/// lower water surface, split in proportion to the surface difference and
+/// never more than `MAX_LEVELING_FRACTION` of any one difference. Channel
+/// surfaces stand `river_stage` feet above their bed, so a high river stops
+/// taking water from its banks. Water that lands on a channel or open water
+/// cell is removed from `depth`; the amount that entered a channel is
+/// returned.
+pub fn flow_step(
+    depth: &mut [f32],
+    ground_ft: &[f32],
+    kinds: &[SurfaceKind],
+    river_stage: f32,
+    width: usize,
+    height: usize,
+) -> f32 {
+    let snapshot = depth.to_vec();
+    for y in 0..height {
+        for x in 0..width {
+            let idx = y * width + x;
+            let current = snapshot[idx];
+            if current <= 0.0 || kinds[idx] != SurfaceKind::Land {
+                continue;
+            }
+            let surface = ground_ft[idx] + current;
+
+            let mut lower = [(0usize, 0.0f32); 4];
+            let mut lower_count = 0;
+            let mut total_diff = 0.0;
+            let mut push = |nidx: usize| {
+                let stage = if kinds[nidx] == SurfaceKind::Channel {
+                    river_stage
+                } else {
+                    0.0
+                };
+                let diff = surface - (ground_ft[nidx] + snapshot[nidx] + stage);
+                if diff > 0.0 {
+                    lower[lower_count] = (nidx, diff);
+                    lower_count += 1;
+                    total_diff += diff;
+                }
+            };
+            if x > 0 {
+                push(idx - 1);
+            }
+            if x + 1 < width {
+                push(idx + 1);
+            }
+            if y > 0 {
+                push(idx - width);
+            }
+            if y + 1 < height {
+                push(idx + width);
+            }
+            if lower_count == 0 {
+                continue;
+            }
+
+            let transferable = current * SPREAD_RATE;
+            for &(nidx, diff) in &lower[..lower_count] {
+                let transfer = (transferable * diff / total_diff).min(diff * MAX_LEVELING_FRACTION);
+                depth[idx] -= transfer;
+                depth[nidx] += transfer;
+            }
+        }
+    }
+
+    let mut into_channels = 0.0;
+    for (idx, kind) in kinds.iter().enumerate() {
+        match kind {
+            SurfaceKind::Land => {}
+            SurfaceKind::Channel => {
+                into_channels += depth[idx];
+                depth[idx] = 0.0;
+            }
+            SurfaceKind::OpenWater => depth[idx] = 0.0,
+        }
+    }
+    into_channels
+}
+
+// =============================================================================
+// Drainage
+// =============================================================================
+
+/// Per-cell drainage rate (feet per tick) from natural infiltration plus the
+/// storm drainage infrastructure around each cell. Retention ponds only take
+/// water while `ponds_have_room`.
+pub fn drainage_rates<'a>(
+    infrastructure: impl IntoIterator<Item = &'a StormDrainageInfrastructure>,
+    ponds_have_room: bool,
+) -> Vec<f32> {
+    let mut rates = vec![NATURAL_DRAIN_RATE; GRID_WIDTH * GRID_HEIGHT];
+    for infra in infrastructure {
+        let (own, around) = match infra.drainage_type {
+            StormDrainageType::StormDrain => (STORM_DRAIN_RATE, STORM_DRAIN_RATE),
+            StormDrainageType::RainGarden => (RAIN_GARDEN_DRAIN_RATE, STORM_DRAIN_RATE),
+            StormDrainageType::RetentionPond if ponds_have_room => {
+                (RETENTION_INTAKE_RATE, RETENTION_INTAKE_RATE)
+            }
+            StormDrainageType::RetentionPond => continue,
+        };
+        let (cx, cy) = (infra.grid_x, infra.grid_y);
+        if cx >= GRID_WIDTH || cy >= GRID_HEIGHT {
+            continue;
+        }
+        for y in cy.saturating_sub(DRAIN_REACH)..=(cy + DRAIN_REACH).min(GRID_HEIGHT - 1) {
+            for x in cx.saturating_sub(DRAIN_REACH)..=(cx + DRAIN_REACH).min(GRID_WIDTH - 1) {
+                let rate = if (x, y) == (cx, cy) { own } else { around };
+                rates[y * GRID_WIDTH + x] += rate;
+            }
+        }
+    }
+    rates
+}
+
+// =============================================================================
+// River
+// =============================================================================
+
+/// Water level of the city's river channels, driven by rain on the upstream
+/// catchment and by surface water draining into them.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct RiverState {
+    /// Height of the river surface above its bed, in feet.
+    pub stage_ft: f32,
+    /// Highest stage reached since the river was last at its bed.
+    pub peak_stage_ft: f32,
+    /// Bank cells the river spilled onto during the last update.
+    pub overtopped_cells: u32,
+}
+
+impl RiverState {
+    /// Raise the stage by rain on the catchment (`precipitation` in in/hr),
+    /// then discharge part of it downstream.
+    pub fn update_stage(&mut self, precipitation: f32) {
+        self.stage_ft += precipitation.max(0.0) * RIVER_RAIN_TO_STAGE;
+        self.stage_ft -= self.stage_ft * RIVER_DISCHARGE_RATE;
+        self.stage_ft = self.stage_ft.clamp(0.0, RIVER_MAX_STAGE_FT);
+        if self.stage_ft < 0.01 {
+            self.stage_ft = 0.0;
+            self.peak_stage_ft = 0.0;
+        }
+        self.peak_stage_ft = self.peak_stage_ft.max(self.stage_ft);
+    }
+
+    /// Add `volume` (feet of water summed over cells) spread over
+    /// `channel_count` channel cells.
+    pub fn add_inflow(&mut self, volume: f32, channel_count: usize) {
+        if channel_count == 0 || volume <= 0.0 {
+            return;
+        }
+        self.stage_ft = (self.stage_ft + volume / channel_count as f32).min(RIVER_MAX_STAGE_FT);
+        self.peak_stage_ft = self.peak_stage_ft.max(self.stage_ft);
+    }
+
+    /// Spill the river onto land next to a channel wherever the river surface
+    /// stands above the bank's water surface. Returns the volume spilled.
+    pub fn overtop(&mut self, map: &SurfaceMap, depth: &mut [f32]) -> f32 {
+        self.overtopped_cells = 0;
+        if self.stage_ft <= 0.0 || map.channel_cells.is_empty() {
+            return 0.0;
+        }
+        let mut spilled = 0.0;
+        for &cidx in &map.channel_cells {
+            let river_surface = map.ground_ft[cidx] + self.stage_ft;
+            let (x, y) = (cidx % GRID_WIDTH, cidx / GRID_WIDTH);
+            let mut neighbors = [None; 4];
+            if x > 0 {
+                neighbors[0] = Some(cidx - 1);
+            }
+            if x + 1 < GRID_WIDTH {
+                neighbors[1] = Some(cidx + 1);
+            }
+            if y > 0 {
+                neighbors[2] = Some(cidx - GRID_WIDTH);
+            }
+            if y + 1 < GRID_HEIGHT {
+                neighbors[3] = Some(cidx + GRID_WIDTH);
+            }
+            for nidx in neighbors.into_iter().flatten() {
+                if map.kinds[nidx] != SurfaceKind::Land {
+                    continue;
+                }
+                let bank_surface = map.ground_ft[nidx] + depth[nidx];
+                if bank_surface >= river_surface {
+                    continue;
+                }
+                let spill = (river_surface - bank_surface) * OVERTOP_RATE;
+                if depth[nidx] <= 0.0 {
+                    self.overtopped_cells += 1;
+                }
+                depth[nidx] += spill;
+                spilled += spill;
+            }
+        }
+        let channel_count = map.channel_cells.len() as f32;
+        self.stage_ft = (self.stage_ft - spilled / channel_count).max(0.0);
+        spilled
+    }
+}
+
+impl Saveable for RiverState {
+    const SAVE_KEY: &'static str = "river_state";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,205 @@
+//! Tests for terrain-following flow, spatial drainage and the river stage.
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+    use crate::flood_simulation::damage_curves::{NATURAL_DRAIN_RATE, STORM_DRAIN_RATE};
+    use crate::flood_simulation::hydrology::*;
+    use crate::grid::{CellType, WorldGrid};
+    use crate::storm_drainage::{StormDrainageInfrastructure, StormDrainageType};
+    use crate::Saveable;
+
+    fn run(depth: &mut [f32], ground: &[f32], kinds: &[SurfaceKind], w: usize, h: usize) -> f32 {
+        (0..20)
+            .map(|_| flow_step(depth, ground, kinds, 0.0, w, h))
+            .sum()
+    }
+
+    // -------------------------------------------------------------------------
+    // Surface flow
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_water_collects_in_valley() {
+        // V-shaped row: ground falls towards the middle cell.
+        let ground = [40.0, 30.0, 20.0, 10.0, 20.0, 30.0, 40.0];
+        let kinds = [SurfaceKind::Land; 7];
+        let mut depth = [1.0; 7];
+        run(&mut depth, &ground, &kinds, 7, 1);
+
+        assert!(
+            depth[3] > 3.0,
+            "valley floor should collect water: {depth:?}"
+        );
+        assert!(depth[0] < 0.5 && depth[6] < 0.5);
+        let total: f32 = depth.iter().sum();
+        assert!((total - 7.0).abs() < 1e-3, "flow must conserve water");
+    }
+
+    #[test]
+    fn test_flat_pool_stays_level() {
+        let ground = [10.0; 9];
+        let kinds = [SurfaceKind::Land; 9];
+        let mut depth = [2.0; 9];
+        run(&mut depth, &ground, &kinds, 3, 3);
+        assert!(depth.iter().all(|&d| (d - 2.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_leveling_does_not_overshoot() {
+        // A nearly level pair must not swap which side is higher.
+        let ground = [0.0, 0.0];
+        let kinds = [SurfaceKind::Land; 2];
+        let mut depth = [1.1, 1.0];
+        flow_step(&mut depth, &ground, &kinds, 0.0, 2, 1);
+        assert!(depth[0] >= depth[1]);
+    }
+
+    #[test]
+    fn test_channel_takes_water_and_sea_absorbs_it() {
+        let ground = [10.0, 5.0, 10.0, 5.0];
+        let kinds = [
+            SurfaceKind::Land,
+            SurfaceKind::Channel,
+            SurfaceKind::Land,
+            SurfaceKind::OpenWater,
+        ];
+        let mut depth = [1.0, 0.0, 1.0, 0.0];
+        let into_river = flow_step(&mut depth, &ground, &kinds, 0.0, 4, 1);
+        assert!(into_river > 0.0);
+        assert_eq!(depth[1], 0.0);
+        assert_eq!(depth[3], 0.0);
+        assert!(depth[0] < 1.0 && depth[2] < 1.0);
+    }
+
+    #[test]
+    fn test_high_river_stops_taking_water() {
+        let ground = [10.0, 5.0];
+        let kinds = [SurfaceKind::Land, SurfaceKind::Channel];
+        let mut depth = [1.0, 0.0];
+        let into_river = flow_step(&mut depth, &ground, &kinds, 8.0, 2, 1);
+        assert_eq!(into_river, 0.0);
+        assert_eq!(depth[0], 1.0);
+    }
+
+    // -------------------------------------------------------------------------
+    // Surface classification
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_narrow_water_is_channel_wide_water_is_open() {
+        let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        // River 4 cells wide across the map.
+        for x in 0..GRID_WIDTH {
+            for y in 100..104 {
+                grid.get_mut(x, y).cell_type = CellType::Water;
+            }
+        }
+        // Sea covering the west edge.
+        for x in 0..20 {
+            for y in 0..GRID_HEIGHT {
+                grid.get_mut(x, y).cell_type = CellType::Water;
+            }
+        }
+        assert!(is_channel(&grid, 100, 100));
+        assert!(is_channel(&grid, 100, 103));
+        assert!(!is_channel(&grid, 5, 50));
+
+        let map = SurfaceMap::build(&grid);
+        assert_eq!(map.kinds[101 * GRID_WIDTH + 100], SurfaceKind::Channel);
+        assert_eq!(map.kinds[50 * GRID_WIDTH + 5], SurfaceKind::OpenWater);
+        assert_eq!(map.kinds[50 * GRID_WIDTH + 50], SurfaceKind::Land);
+    }
+
+    // -------------------------------------------------------------------------
+    // Drainage
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_drainage_is_local_to_infrastructure() {
+        let drain = StormDrainageInfrastructure {
+            drainage_type: StormDrainageType::StormDrain,
+            grid_x: 50,
+            grid_y: 50,
+        };
+        let rates = drainage_rates([&drain], true);
+        let at = |x: usize, y: usize| rates[y * GRID_WIDTH + x];
+        assert!((at(50, 50) - (NATURAL_DRAIN_RATE + STORM_DRAIN_RATE)).abs() < 1e-6);
+        assert!(at(52, 52) > NATURAL_DRAIN_RATE);
+        assert_eq!(at(53, 50), NATURAL_DRAIN_RATE);
+    }
+
+    #[test]
+    fn test_full_retention_pond_stops_draining() {
+        let pond = StormDrainageInfrastructure {
+            drainage_type: StormDrainageType::RetentionPond,
+            grid_x: 10,
+            grid_y: 10,
+        };
+        let idx = 10 * GRID_WIDTH + 10;
+        assert!(drainage_rates([&pond], true)[idx] > NATURAL_DRAIN_RATE);
+        assert_eq!(drainage_rates([&pond], false)[idx], NATURAL_DRAIN_RATE);
+    }
+
+    // -------------------------------------------------------------------------
+    // River
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_river_rises_with_rain_and_recedes() {
+        let mut river = RiverState::default();
+        for _ in 0..50 {
+            river.update_stage(2.0);
+        }
+        let flood_stage = river.stage_ft;
+        assert!(flood_stage > 10.0);
+        assert!(river.peak_stage_ft >= flood_stage);
+
+        for _ in 0..10 {
+            river.update_stage(0.0);
+        }
+        assert!(river.stage_ft < flood_stage / 2.0);
+        assert!(river.stage_ft <= RIVER_MAX_STAGE_FT);
+    }
+
+    #[test]
+    fn test_river_overtops_low_banks_only() {
+        let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        for x in 0..GRID_WIDTH {
+            grid.get_mut(x, 100).cell_type = CellType::Water;
+            grid.get_mut(x, 100).elevation = 0.2;
+            grid.get_mut(x, 99).elevation = 0.6;
+            grid.get_mut(x, 101).elevation = 0.3;
+        }
+        let map = SurfaceMap::build(&grid);
+        let mut depth = vec![0.0; GRID_WIDTH * GRID_HEIGHT];
+
+        let mut calm = RiverState {
+            stage_ft: 5.0,
+            ..Default::default()
+        };
+        assert_eq!(calm.overtop(&map, &mut depth), 0.0);
+
+        let mut flood = RiverState {
+            stage_ft: 15.0,
+            ..Default::default()
+        };
+        assert!(flood.overtop(&map, &mut depth) > 0.0);
+        assert_eq!(flood.overtopped_cells, GRID_WIDTH as u32);
+        assert!(depth[101 * GRID_WIDTH + 50] > 0.0);
+        assert_eq!(depth[99 * GRID_WIDTH + 50], 0.0);
+        assert!(flood.stage_ft < 15.0);
+    }
+
+    #[test]
+    fn test_river_state_saveable_roundtrip() {
+        assert!(RiverState::default().save_to_bytes().is_none());
+        let river = RiverState {
+            stage_ft: 12.5,
+            peak_stage_ft: 14.0,
+            overtopped_cells: 3,
+        };
+        let restored = RiverState::load_from_bytes(&river.save_to_bytes().unwrap());
+        assert_eq!(restored, river);
+    }
+}
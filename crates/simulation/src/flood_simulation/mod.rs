@@ -1,32 +1,38 @@
 //! Urban flooding simulation and depth-damage curves (FLOOD-961).
 //!
-//! When stormwater runoff exceeds storm drainage capacity, excess water pools on
-//! the surface and spreads via a simplified shallow-water model. The `FloodGrid`
-//! resource tracks per-cell flood depth (in feet) while the `FloodState` resource
-//! provides aggregate statistics (total flooded cells, cumulative damage, maximum
-//! depth).
+//! Rain drives flooding two ways. When stormwater runoff exceeds storm drainage
+//! capacity, excess water pools on the surface; and rain on the upstream
+//! catchment raises the river (`RiverState`) until it spills over any bank
+//! lower than its surface. Surface water then flows over the terrain with a
+//! simplified shallow-water model: ground elevation is converted to feet and
+//! water always moves towards the lowest water surface, so floods follow
+//! valleys and collect in low ground. Narrow water bodies such as the Yarkon
+//! are river channels that carry water away (raising the stage); the sea and
+//! lakes absorb whatever reaches them. Drainage is spatial: cells near storm
+//! drains, rain gardens and retention ponds with room to spare drain faster.
+//!
+//! The `FloodGrid` resource tracks per-cell flood depth (in feet) while the
+//! `FloodState` resource provides aggregate statistics (total flooded cells,
+//! cumulative damage, maximum depth).
 //!
 //! Depth-damage curves translate flood depth into a fractional damage value for
 //! each zone type (Residential, Commercial, Industrial). Damage is applied to
 //! buildings based on their estimated property value.
 //!
-//! The `update_flood_simulation` system runs every slow tick and performs:
-//!   1. Checks if flooding conditions exist (storm drainage overflow > threshold)
-//!   2. Initializes the FloodGrid from stormwater overflow
-//!   3. Runs 5 iterations of water spreading (high elevation to low, 4-connected)
-//!   4. Applies drainage rates (natural drain + enhanced drain for cells with drains)
-//!   5. Calculates building damage using depth-damage curves
-//!   6. Updates FloodState with aggregate statistics
-//!   7. Clears FloodGrid when flooding subsides
+//! See `systems` for the per-tick steps of `update_flood_simulation`.
 
 pub mod damage_curves;
+pub mod hydrology;
 pub mod resources;
 pub mod systems;
 
+#[cfg(test)]
+mod hydrology_tests;
 #[cfg(test)]
 mod system_tests;
 
 // Re-export all public items for backward compatibility.
 pub use damage_curves::{depth_damage_fraction, interpolate_damage};
+pub use hydrology::{RiverState, SurfaceKind, SurfaceMap};
 pub use resources::{FloodGrid, FloodState};
 pub use systems::{update_flood_simulation, FloodSimulationPlugin};
@@ -5,6 +5,7 @@ use crate::fire::FireGrid;
 use crate::grid::{CellType, WorldGrid};
 use crate::land_value::LandValueGrid;
 use crate::trees::TreeGrid;
+use crate::urban_forestry::UrbanForestry;
 use crate::weather::{Weather, WeatherCondition};
 use crate::wind::WindState;
 use crate::TickCounter;
@@ -29,6 +30,7 @@ pub fn update_forest_fire(
     grid: Res<WorldGrid>,
     weather: Res<Weather>,
     wind: Res<WindState>,
+    forestry: Res<UrbanForestry>,
     mut stats: ResMut<ForestFireStats>,
 ) {
     if !tick.0.is_multiple_of(FIRE_UPDATE_INTERVAL) {
@@ -66,11 +68,13 @@ pub fn update_forest_fire(
             }
 
             let has_tree = tree_grid.has_tree(x, y);
+            // Resinous species catch more readily than fire-resistant ones.
+            let susceptibility = forestry.fire_susceptibility_at(x, y);
 
             // Lightning strikes during storms (only on tree cells)
             if is_storm && has_tree {
                 let h = fire_hash(tick.0, idx, 0) % 100_000;
-                if h < LIGHTNING_CHANCE_PER_CELL {
+                if (h as f32) < LIGHTNING_CHANCE_PER_CELL as f32 * susceptibility {
                     forest_fire.set(x, y, INITIAL_INTENSITY);
                     new_ignitions += 1;
                     continue;
@@ -98,7 +102,7 @@ pub fn update_forest_fire(
                 // Check if near industrial zone (within distance 3)
                 if is_near_industrial(&grid, x, y, 3) {
                     let h = fire_hash(tick.0, idx, 2) % 100_000;
-                    if h < INDUSTRIAL_IGNITION_THRESHOLD {
+                    if (h as f32) < INDUSTRIAL_IGNITION_THRESHOLD as f32 * susceptibility {
                         forest_fire.set(x, y, INITIAL_INTENSITY);
                         new_ignitions += 1;
                     }
@@ -137,7 +141,8 @@ pub fn update_forest_fire(
 
                 // Trees are highly flammable
                 if tree_grid.has_tree(nx, ny) {
-                    spread_chance += 80; // 8% base for forested
+                    // 8% base for forested, scaled by species
+                    spread_chance += (80.0 * forestry.fire_susceptibility_at(nx, ny)) as u64;
                 } else if ncell.cell_type == CellType::Grass {
                     spread_chance += 20; // 2% base for grass
                 }
@@ -12,6 +12,7 @@
 //! - Trucks add to traffic density on the road grid via `TrafficGrid`
 //! - Trucks increase road wear in `RoadConditionGrid`
 //! - Heavy traffic ban per district blocks truck routing through those districts
+//! - Air freight unloaded at airport cargo terminals is trucked to the nearest shops
 //! - Freight satisfaction affects commercial/industrial productivity
 
 mod constants;
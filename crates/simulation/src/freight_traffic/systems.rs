@@ -47,7 +47,8 @@ pub fn compute_freight_demand(
     freight.commercial_demand = com_demand;
 }
 
-/// Generate freight trips: match industrial origins to commercial destinations.
+/// Generate freight trips: truck air freight from cargo terminals and match
+/// industrial origins to commercial destinations.
 /// Spawns trucks with pre-computed A* routes on the road network.
 #[allow(clippy::too_many_arguments)]
 pub fn generate_freight_trips(
@@ -85,55 +86,81 @@ pub fn generate_freight_trips(
         }
     }
 
-    if origins.is_empty() || destinations.is_empty() {
+    if destinations.is_empty() {
         return;
     }
 
+    let mut budget = MAX_TRIPS_PER_CYCLE.min(MAX_FREIGHT_TRUCKS - freight.trucks.len());
+
+    // Air freight goes first: cargo terminals take turns sending a truck for
+    // each waiting truckload.
+    let terminals = freight.air_cargo_terminals.clone();
+    if !terminals.is_empty() {
+        let air_trips = (freight.air_cargo_backlog as usize).min(budget);
+        for i in 0..air_trips {
+            let terminal = terminals[i % terminals.len()];
+            if spawn_freight_trip(&grid, &csr, &traffic, &destinations, terminal, &mut freight) {
+                freight.air_cargo_backlog -= 1.0;
+                budget -= 1;
+            }
+        }
+    }
+
     // Determine how many trips to generate this cycle based on demand
     let demand = freight.industrial_demand.min(freight.commercial_demand);
-    let trips_to_generate = (demand as usize)
-        .min(MAX_TRIPS_PER_CYCLE)
-        .min(MAX_FREIGHT_TRUCKS - freight.trucks.len());
-
-    if trips_to_generate == 0 {
-        return;
-    }
+    let trips_to_generate = (demand as usize).min(budget);
 
     // Use a simple hash-based matching: pair origins with nearest destinations
     let mut generated = 0usize;
-    for &(ox, oy) in origins.iter() {
+    for &origin in origins.iter() {
         if generated >= trips_to_generate {
             break;
         }
-
-        // Find nearest commercial destination within range
-        let dest = find_nearest_destination(&destinations, ox, oy, MAX_FREIGHT_DISTANCE);
-        let Some((dx, dy)) = dest else {
-            continue;
-        };
-
-        // Resolve to road nodes
-        let start = nearest_road_grid(&grid, ox, oy);
-        let goal = nearest_road_grid(&grid, dx, dy);
-
-        if let (Some(start_node), Some(goal_node)) = (start, goal) {
-            // Compute route using A* pathfinding
-            if let Some(route) =
-                csr_find_path_with_traffic(&csr, start_node, goal_node, &grid, &traffic)
-            {
-                freight.trucks.push(FreightTruck {
-                    route,
-                    current_index: 0,
-                    origin: (ox, oy),
-                    destination: (dx, dy),
-                });
-                freight.trips_generated += 1;
-                generated += 1;
-            }
+        if spawn_freight_trip(&grid, &csr, &traffic, &destinations, origin, &mut freight) {
+            generated += 1;
         }
     }
 }
 
+/// Route a truck from `origin` to the nearest destination in range and put
+/// it on the road. Returns whether a truck was spawned.
+fn spawn_freight_trip(
+    grid: &WorldGrid,
+    csr: &CsrGraph,
+    traffic: &TrafficGrid,
+    destinations: &[(usize, usize)],
+    origin: (usize, usize),
+    freight: &mut FreightTrafficState,
+) -> bool {
+    let (ox, oy) = origin;
+
+    // Find nearest commercial destination within range
+    let Some((dx, dy)) = find_nearest_destination(destinations, ox, oy, MAX_FREIGHT_DISTANCE)
+    else {
+        return false;
+    };
+
+    // Resolve to road nodes
+    let start = nearest_road_grid(grid, ox, oy);
+    let goal = nearest_road_grid(grid, dx, dy);
+    let (Some(start_node), Some(goal_node)) = (start, goal) else {
+        return false;
+    };
+
+    // Compute route using A* pathfinding
+    let Some(route) = csr_find_path_with_traffic(csr, start_node, goal_node, grid, traffic) else {
+        return false;
+    };
+    freight.trucks.push(FreightTruck {
+        route,
+        current_index: 0,
+        origin: (ox, oy),
+        destination: (dx, dy),
+    });
+    freight.trips_generated += 1;
+    true
+}
+
 /// Move freight trucks along their routes and apply traffic/wear effects.
 pub fn move_freight_trucks(
     tick: Res<TickCounter>,
@@ -54,6 +54,10 @@ pub struct FreightTrafficState {
     pub trips_generated: u64,
     /// Per-district heavy traffic ban. Key = district index, value = banned.
     pub heavy_traffic_ban: HashMap<usize, bool>,
+    /// Airports with cargo terminals, where air freight is trucked from.
+    pub air_cargo_terminals: Vec<(usize, usize)>,
+    /// Truckloads of air freight waiting at the cargo terminals.
+    pub air_cargo_backlog: f32,
 }
 
 impl Default for FreightTrafficState {
@@ -66,6 +70,8 @@ impl Default for FreightTrafficState {
             trips_completed: 0,
             trips_generated: 0,
             heavy_traffic_ban: HashMap::new(),
+            air_cargo_terminals: Vec::new(),
+            air_cargo_backlog: 0.0,
         }
     }
 }
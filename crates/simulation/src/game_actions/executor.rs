@@ -14,6 +14,7 @@ use crate::budget::ExtendedBudget;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::economy::CityBudget;
 use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::map_tiles::MapTiles;
 use crate::roads::RoadNetwork;
 use crate::services::{ServiceBuilding, ServiceType};
 use crate::time_of_day::GameClock;
@@ -38,6 +39,7 @@ pub fn execute_queued_actions(
     mut budget: ResMut<CityBudget>,
     mut extended: ResMut<ExtendedBudget>,
     mut clock: ResMut<GameClock>,
+    tiles: Res<MapTiles>,
 ) {
     let actions = queue.drain();
     for queued in actions {
@@ -49,6 +51,7 @@ pub fn execute_queued_actions(
             &mut budget,
             &mut extended,
             &mut clock,
+            &tiles,
         );
         log.push(queued.action, result);
     }
@@ -67,23 +70,24 @@ fn execute_single(
     budget: &mut CityBudget,
     extended: &mut ExtendedBudget,
     clock: &mut GameClock,
+    tiles: &MapTiles,
 ) -> ActionResult {
     match action {
         GameAction::PlaceRoadLine {
             start,
             end,
             road_type,
-        } => execute_place_road_line(*start, *end, *road_type, grid, roads, budget),
+        } => execute_place_road_line(*start, *end, *road_type, grid, roads, budget, tiles),
         GameAction::ZoneRect {
             min,
             max,
             zone_type,
-        } => execute_zone_rect(*min, *max, *zone_type, grid),
+        } => execute_zone_rect(*min, *max, *zone_type, grid, tiles),
         GameAction::PlaceUtility { pos, utility_type } => {
-            execute_place_utility(*pos, *utility_type, commands, grid, budget)
+            execute_place_utility(*pos, *utility_type, commands, grid, budget, tiles)
         }
         GameAction::PlaceService { pos, service_type } => {
-            execute_place_service(*pos, *service_type, commands, grid, budget)
+            execute_place_service(*pos, *service_type, commands, grid, budget, tiles)
         }
         GameAction::BulldozeRect { min, max } => execute_bulldoze_rect(*min, *max, grid, budget),
         GameAction::SetTaxRates {
@@ -120,6 +124,7 @@ fn bounds_check(x: u32, y: u32) -> Result<(usize, usize), ActionResult> {
 }
 
 /// Place a straight line of road cells from `start` to `end`.
+#[allow(clippy::too_many_arguments)]
 fn execute_place_road_line(
     start: (u32, u32),
     end: (u32, u32),
@@ -127,6 +132,7 @@ fn execute_place_road_line(
     grid: &mut WorldGrid,
     roads: &mut RoadNetwork,
     budget: &mut CityBudget,
+    tiles: &MapTiles,
 ) -> ActionResult {
     let (x0, y0) = match bounds_check(start.0, start.1) {
         Ok(v) => v,
@@ -150,6 +156,9 @@ fn execute_place_road_line(
 
     // Check all cells are placeable
     for &(cx, cy) in &cells {
+        if !tiles.contains(cx, cy) {
+            return ActionResult::Error(ActionError::OutsideOwnedTiles);
+        }
         let cell = grid.get(cx, cy);
         if cell.cell_type == CellType::Water {
             return ActionResult::Error(ActionError::BlockedByWater);
@@ -168,13 +177,15 @@ fn execute_place_road_line(
     ActionResult::Success
 }
 
-/// Zone a rectangular area. Only grass cells adjacent to a road are zoned.
+/// Zone a rectangular area. Only grass cells adjacent to a road, on owned
+/// map tiles, are zoned.
 /// Returns a warning if any cells with a different zone type were overwritten.
 fn execute_zone_rect(
     min: (u32, u32),
     max: (u32, u32),
     zone_type: ZoneType,
     grid: &mut WorldGrid,
+    tiles: &MapTiles,
 ) -> ActionResult {
     let (x0, y0) = match bounds_check(min.0, min.1) {
         Ok(v) => v,
@@ -194,7 +205,7 @@ fn execute_zone_rect(
     let mut overwritten: HashMap<ZoneType, u32> = HashMap::new();
     for y in ly..=hy {
         for x in lx..=hx {
-            if !grid.in_bounds(x, y) {
+            if !grid.in_bounds(x, y) || !tiles.contains(x, y) {
                 continue;
             }
             let cell = grid.get(x, y);
@@ -216,7 +227,7 @@ fn execute_zone_rect(
     let mut zoned_count: u32 = 0;
     for y in ly..=hy {
         for x in lx..=hx {
-            if !grid.in_bounds(x, y) {
+            if !grid.in_bounds(x, y) || !tiles.contains(x, y) {
                 continue;
             }
             let cell = grid.get(x, y);
@@ -255,9 +266,10 @@ fn execute_place_utility(
     commands: &mut Commands,
     grid: &mut WorldGrid,
     budget: &mut CityBudget,
+    tiles: &MapTiles,
 ) -> ActionResult {
     let cost = crate::services::utility_cost(utility_type);
-    let (x, y) = match validate_building_placement(pos, cost, grid, budget) {
+    let (x, y) = match validate_building_placement(pos, cost, grid, budget, tiles) {
         Ok(coords) => coords,
         Err(result) => return result,
     };
@@ -286,15 +298,19 @@ fn execute_place_service(
     commands: &mut Commands,
     grid: &mut WorldGrid,
     budget: &mut CityBudget,
+    tiles: &MapTiles,
 ) -> ActionResult {
     let cost = ServiceBuilding::cost(service_type);
-    let (x, y) = match validate_building_placement(pos, cost, grid, budget) {
+    let (x, y) = match validate_building_placement(pos, cost, grid, budget, tiles) {
         Ok(coords) => coords,
         Err(result) => return result,
     };
 
     // Check the full footprint is clear
     let (fw, fh) = ServiceBuilding::footprint(service_type);
+    if !tiles.contains_footprint(x, y, fw, fh) {
+        return ActionResult::Error(ActionError::OutsideOwnedTiles);
+    }
     for dy in 0..fh {
         for dx in 0..fw {
             let cx = x + dx;
@@ -346,9 +362,14 @@ fn validate_building_placement(
     cost: f64,
     grid: &WorldGrid,
     budget: &CityBudget,
+    tiles: &MapTiles,
 ) -> Result<(usize, usize), ActionResult> {
     let (x, y) = bounds_check(pos.0, pos.1)?;
 
+    if !tiles.contains(x, y) {
+        return Err(ActionResult::Error(ActionError::OutsideOwnedTiles));
+    }
+
     if budget.treasury < cost {
         return Err(ActionResult::Error(ActionError::InsufficientFunds));
     }
@@ -42,4 +42,6 @@ pub enum ActionError {
     NotFound,
     InvalidParameter(String),
     NoCellsZoned,
+    /// The map tile has not been bought yet.
+    OutsideOwnedTiles,
 }
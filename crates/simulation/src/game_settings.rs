@@ -0,0 +1,281 @@
+//! Difficulty presets and sandbox toggles.
+//!
+//! [`GameSettings`] is chosen in the New Game dialog alongside
+//! [`NewGameConfig`](crate::new_game_config::NewGameConfig) and saved with the
+//! city. Difficulty scales the starting treasury, how strongly zone demand
+//! responds to the market, how often random disasters strike and the interest
+//! charged on loans. Sandbox toggles remove money, disasters or construction
+//! time from the game entirely.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::economy::CityBudget;
+use crate::Saveable;
+
+/// Treasury an "unlimited money" city is kept topped up to.
+pub const UNLIMITED_TREASURY: f64 = 10_000_000.0;
+
+// ---------------------------------------------------------------------------
+// Difficulty
+// ---------------------------------------------------------------------------
+
+/// Named difficulty presets. `Custom` keeps whatever values were set by hand.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    bitcode::Encode,
+    bitcode::Decode,
+)]
+pub enum Difficulty {
+    Relaxed,
+    #[default]
+    Normal,
+    Hard,
+    Custom,
+}
+
+impl Difficulty {
+    pub const PRESETS: [Difficulty; 3] =
+        [Difficulty::Relaxed, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "Relaxed",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Custom => "Custom",
+        }
+    }
+
+    /// The values this preset stands for. `Custom` starts from `Normal`.
+    pub fn settings(self) -> DifficultySettings {
+        match self {
+            Difficulty::Relaxed => DifficultySettings {
+                starting_treasury: 100_000.0,
+                demand_elasticity: 1.25,
+                disaster_frequency: 0.5,
+                loan_rate_multiplier: 0.5,
+            },
+            Difficulty::Normal | Difficulty::Custom => DifficultySettings::default(),
+            Difficulty::Hard => DifficultySettings {
+                starting_treasury: 25_000.0,
+                demand_elasticity: 0.8,
+                disaster_frequency: 2.0,
+                loan_rate_multiplier: 1.5,
+            },
+        }
+    }
+}
+
+/// The values a difficulty preset adjusts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, bitcode::Encode, bitcode::Decode)]
+pub struct DifficultySettings {
+    /// Treasury a new city starts with.
+    pub starting_treasury: f64,
+    /// Scales the demand the market asks for; above 1.0 buildings fill in
+    /// faster, below 1.0 the city has to work for growth.
+    pub demand_elasticity: f32,
+    /// Multiplier on the chance of a random disaster.
+    pub disaster_frequency: f32,
+    /// Multiplier on the interest rate of new loans.
+    pub loan_rate_multiplier: f64,
+}
+
+impl Default for DifficultySettings {
+    fn default() -> Self {
+        Self {
+            starting_treasury: 50_000.0,
+            demand_elasticity: 1.0,
+            disaster_frequency: 1.0,
+            loan_rate_multiplier: 1.0,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sandbox toggles
+// ---------------------------------------------------------------------------
+
+#[derive(
+    Debug, Clone, Default, PartialEq, Serialize, Deserialize, bitcode::Encode, bitcode::Decode,
+)]
+pub struct SandboxToggles {
+    /// Keep the treasury topped up to [`UNLIMITED_TREASURY`].
+    pub unlimited_money: bool,
+    /// Never roll random disasters.
+    pub no_disasters: bool,
+    /// Buildings finish the tick they are placed.
+    pub instant_construction: bool,
+}
+
+impl SandboxToggles {
+    pub fn any(&self) -> bool {
+        self.unlimited_money || self.no_disasters || self.instant_construction
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Difficulty and sandbox settings for the current city.
+#[derive(
+    Resource,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    bitcode::Encode,
+    bitcode::Decode,
+)]
+pub struct GameSettings {
+    pub preset: Difficulty,
+    pub difficulty: DifficultySettings,
+    pub sandbox: SandboxToggles,
+}
+
+impl GameSettings {
+    /// Switch to a preset, replacing the difficulty values.
+    pub fn apply_preset(&mut self, preset: Difficulty) {
+        self.preset = preset;
+        if preset != Difficulty::Custom {
+            self.difficulty = preset.settings();
+        }
+    }
+
+    /// Treasury a new city should start with.
+    pub fn starting_treasury(&self) -> f64 {
+        if self.sandbox.unlimited_money {
+            UNLIMITED_TREASURY
+        } else {
+            self.difficulty.starting_treasury
+        }
+    }
+
+    /// Multiplier on the per-tick random disaster chance; zero in sandbox.
+    pub fn disaster_chance_multiplier(&self) -> f32 {
+        if self.sandbox.no_disasters {
+            0.0
+        } else {
+            self.difficulty.disaster_frequency
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Refill the treasury while unlimited money is on.
+pub fn apply_unlimited_money(settings: Res<GameSettings>, mut budget: ResMut<CityBudget>) {
+    if settings.sandbox.unlimited_money && budget.treasury < UNLIMITED_TREASURY {
+        budget.treasury = UNLIMITED_TREASURY;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Saveable implementation
+// ---------------------------------------------------------------------------
+
+impl Saveable for GameSettings {
+    const SAVE_KEY: &'static str = "game_settings";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+pub struct GameSettingsPlugin;
+
+impl Plugin for GameSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameSettings>().add_systems(
+            FixedUpdate,
+            apply_unlimited_money.in_set(crate::SimulationSet::PreSim),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<GameSettings>();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Unit tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_matches_game_params() {
+        let params = crate::game_params::GameParams::default();
+        assert_eq!(
+            Difficulty::Normal.settings().starting_treasury,
+            params.economy.starting_treasury
+        );
+    }
+
+    #[test]
+    fn test_presets_order_by_difficulty() {
+        let relaxed = Difficulty::Relaxed.settings();
+        let hard = Difficulty::Hard.settings();
+        assert!(relaxed.starting_treasury > hard.starting_treasury);
+        assert!(relaxed.demand_elasticity > hard.demand_elasticity);
+        assert!(relaxed.disaster_frequency < hard.disaster_frequency);
+        assert!(relaxed.loan_rate_multiplier < hard.loan_rate_multiplier);
+    }
+
+    #[test]
+    fn test_apply_preset_keeps_custom_values() {
+        let mut settings = GameSettings::default();
+        settings.apply_preset(Difficulty::Hard);
+        assert_eq!(settings.difficulty, Difficulty::Hard.settings());
+
+        settings.difficulty.starting_treasury = 1.0;
+        settings.apply_preset(Difficulty::Custom);
+        assert_eq!(settings.difficulty.starting_treasury, 1.0);
+    }
+
+    #[test]
+    fn test_sandbox_overrides() {
+        let mut settings = GameSettings::default();
+        settings.sandbox.unlimited_money = true;
+        settings.sandbox.no_disasters = true;
+        assert_eq!(settings.starting_treasury(), UNLIMITED_TREASURY);
+        assert_eq!(settings.disaster_chance_multiplier(), 0.0);
+    }
+
+    #[test]
+    fn test_saveable_roundtrip() {
+        assert!(GameSettings::default().save_to_bytes().is_none());
+
+        let mut settings = GameSettings::default();
+        settings.apply_preset(Difficulty::Relaxed);
+        settings.sandbox.instant_construction = true;
+        let bytes = settings.save_to_bytes().unwrap();
+        assert_eq!(GameSettings::load_from_bytes(&bytes), settings);
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+use crate::chunked_grid::ChunkedGrid;
 use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -41,6 +42,16 @@ pub enum RoadType {
 }
 
 impl RoadType {
+    /// Every road type, in declaration order.
+    pub const ALL: [RoadType; 6] = [
+        RoadType::Local,
+        RoadType::Avenue,
+        RoadType::Boulevard,
+        RoadType::Highway,
+        RoadType::OneWay,
+        RoadType::Path,
+    ];
+
     pub fn speed(self) -> f32 {
         match self {
             RoadType::Local => 30.0,
@@ -226,11 +237,28 @@ impl Default for Cell {
     }
 }
 
+impl Cell {
+    /// Whether anything has been built or zoned here. Chunks without any
+    /// such cell are skipped by systems that only care about the city.
+    pub fn is_developed(&self) -> bool {
+        self.cell_type == CellType::Road
+            || self.zone != ZoneType::None
+            || self.building_id.is_some()
+    }
+}
+
 #[derive(Resource, Serialize, Deserialize)]
 pub struct WorldGrid {
-    pub cells: Vec<Cell>,
+    pub cells: ChunkedGrid<Cell>,
     pub width: usize,
     pub height: usize,
+    /// Per storage chunk: whether it holds any developed cell, as of the
+    /// last `refresh_chunk_activity`.
+    #[serde(skip)]
+    developed_chunks: Vec<bool>,
+    /// Chunk revisions seen by the last `refresh_chunk_activity`.
+    #[serde(skip)]
+    scanned_revisions: Vec<Option<u32>>,
 }
 
 impl Default for WorldGrid {
@@ -241,10 +269,14 @@ impl Default for WorldGrid {
 
 impl WorldGrid {
     pub fn new(width: usize, height: usize) -> Self {
+        // Chunk activity starts unknown, so every chunk counts as developed
+        // until the first refresh.
         Self {
-            cells: vec![Cell::default(); width * height],
+            cells: ChunkedGrid::new(width, height, Cell::default()),
             width,
             height,
+            developed_chunks: Vec::new(),
+            scanned_revisions: Vec::new(),
         }
     }
 
@@ -260,13 +292,40 @@ impl WorldGrid {
 
     #[inline]
     pub fn get(&self, x: usize, y: usize) -> &Cell {
-        &self.cells[self.index(x, y)]
+        self.cells.get(x, y)
     }
 
     #[inline]
     pub fn get_mut(&mut self, x: usize, y: usize) -> &mut Cell {
-        let idx = self.index(x, y);
-        &mut self.cells[idx]
+        self.cells.get_mut(x, y)
+    }
+
+    /// Rescan the storage chunks modified since the last call and update
+    /// which of them hold developed cells.
+    pub fn refresh_chunk_activity(&mut self) {
+        let chunk_count = self.cells.chunk_count();
+        if self.scanned_revisions.len() != chunk_count {
+            self.developed_chunks = vec![false; chunk_count];
+            self.scanned_revisions = vec![None; chunk_count];
+        }
+        for ci in 0..chunk_count {
+            let revision = self.cells.chunk_revision(ci);
+            if self.scanned_revisions[ci] == Some(revision) {
+                continue;
+            }
+            self.developed_chunks[ci] = self.cells.chunk_any(ci, Cell::is_developed);
+            self.scanned_revisions[ci] = Some(revision);
+        }
+    }
+
+    /// Whether storage chunk `ci` held a developed cell at the last refresh.
+    pub fn is_chunk_developed(&self, ci: usize) -> bool {
+        self.developed_chunks.get(ci).copied().unwrap_or(true)
+    }
+
+    /// Storage chunks holding developed cells, as of the last refresh.
+    pub fn developed_chunks(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.cells.chunk_count()).filter(|&ci| self.is_chunk_developed(ci))
     }
 
     pub fn world_to_grid(world_x: f32, world_y: f32) -> (i32, i32) {
@@ -358,6 +417,27 @@ mod tests {
         assert_eq!(grid.neighbors4(255, 255).1, 2);
     }
 
+    #[test]
+    fn test_developed_chunks_follow_edits() {
+        let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        grid.refresh_chunk_activity();
+        assert_eq!(grid.developed_chunks().count(), 0);
+
+        grid.get_mut(100, 40).zone = ZoneType::Industrial;
+        grid.get_mut(5, 250).cell_type = CellType::Road;
+        grid.refresh_chunk_activity();
+        let developed: Vec<usize> = grid.developed_chunks().collect();
+        assert_eq!(
+            developed,
+            vec![grid.cells.chunk_of(100, 40), grid.cells.chunk_of(5, 250)]
+        );
+
+        grid.get_mut(100, 40).zone = ZoneType::None;
+        grid.refresh_chunk_activity();
+        assert_eq!(grid.developed_chunks().count(), 1);
+        assert!(grid.is_chunk_developed(grid.cells.chunk_of(0, 255)));
+    }
+
     #[test]
     fn test_residential_medium_is_residential() {
         assert!(ZoneType::ResidentialMedium.is_residential());
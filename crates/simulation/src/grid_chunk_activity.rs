@@ -0,0 +1,26 @@
+//! Developed-chunk bookkeeping for `WorldGrid`.
+//!
+//! `WorldGrid` remembers which of its storage chunks hold any developed cell
+//! so that per-cell systems such as crime can skip empty regions. This
+//! plugin refreshes those flags at the start of every simulation tick.
+
+use bevy::prelude::*;
+
+use crate::grid::WorldGrid;
+
+/// Keep `WorldGrid`'s developed-chunk flags current. Bypasses change
+/// detection: the flags are derived bookkeeping, not a change to the map.
+pub fn refresh_grid_chunk_activity(mut grid: ResMut<WorldGrid>) {
+    grid.bypass_change_detection().refresh_chunk_activity();
+}
+
+pub struct GridChunkActivityPlugin;
+
+impl Plugin for GridChunkActivityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            refresh_grid_chunk_activity.in_set(crate::SimulationSet::PreSim),
+        );
+    }
+}
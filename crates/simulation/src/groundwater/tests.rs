@@ -0,0 +1,94 @@
+use super::*;
+use crate::grid::WorldGrid;
+
+#[test]
+fn test_groundwater_grid_default() {
+    let gw = GroundwaterGrid::default();
+    assert_eq!(gw.levels.len(), GRID_WIDTH * GRID_HEIGHT);
+    assert_eq!(gw.get(0, 0), 128);
+}
+
+#[test]
+fn test_water_quality_grid_default() {
+    let wq = WaterQualityGrid::default();
+    assert_eq!(wq.levels.len(), GRID_WIDTH * GRID_HEIGHT);
+    assert_eq!(wq.get(0, 0), 200);
+}
+
+#[test]
+fn test_groundwater_add_sub() {
+    let mut gw = GroundwaterGrid::default();
+    gw.set(5, 5, 100);
+    gw.add(5, 5, 50);
+    assert_eq!(gw.get(5, 5), 150);
+    gw.sub(5, 5, 200);
+    assert_eq!(gw.get(5, 5), 0); // saturating sub
+    gw.set(5, 5, 250);
+    gw.add(5, 5, 10);
+    assert_eq!(gw.get(5, 5), 255); // saturating add
+}
+
+#[test]
+fn test_water_quality_add_sub() {
+    let mut wq = WaterQualityGrid::default();
+    wq.set(3, 3, 100);
+    wq.sub(3, 3, 30);
+    assert_eq!(wq.get(3, 3), 70);
+    wq.add(3, 3, 200);
+    assert_eq!(wq.get(3, 3), 255); // saturating add
+    wq.sub(3, 3, 255);
+    assert_eq!(wq.get(3, 3), 0); // saturating sub
+}
+
+#[test]
+fn test_init_groundwater_elevation_correlation() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    // Set up: low elevation cell and high elevation cell
+    grid.get_mut(10, 10).elevation = 0.2; // low => high water
+    grid.get_mut(10, 10).cell_type = CellType::Grass;
+    grid.get_mut(20, 20).elevation = 0.8; // high => low water
+    grid.get_mut(20, 20).cell_type = CellType::Grass;
+
+    let (gw, _wq) = init_groundwater(&grid);
+
+    // Low elevation should have more groundwater than high elevation
+    assert!(
+        gw.get(10, 10) > gw.get(20, 20),
+        "low elevation ({}) should have more groundwater than high ({})",
+        gw.get(10, 10),
+        gw.get(20, 20)
+    );
+}
+
+#[test]
+fn test_init_groundwater_near_water_boost() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    // Set equal elevations
+    grid.get_mut(50, 50).elevation = 0.5;
+    grid.get_mut(50, 50).cell_type = CellType::Grass;
+    grid.get_mut(100, 100).elevation = 0.5;
+    grid.get_mut(100, 100).cell_type = CellType::Grass;
+
+    // Put a water cell adjacent to (50,50)
+    grid.get_mut(51, 50).cell_type = CellType::Water;
+    grid.get_mut(51, 50).elevation = 0.2;
+
+    let (gw, _wq) = init_groundwater(&grid);
+
+    // Cell near water should have higher groundwater
+    assert!(
+        gw.get(50, 50) > gw.get(100, 100),
+        "near-water cell ({}) should have more groundwater than distant cell ({})",
+        gw.get(50, 50),
+        gw.get(100, 100)
+    );
+}
+
+#[test]
+fn test_groundwater_stats_default() {
+    let stats = GroundwaterStats::default();
+    assert_eq!(stats.avg_level, 0.0);
+    assert_eq!(stats.avg_quality, 0.0);
+    assert_eq!(stats.contaminated_cells, 0);
+    assert_eq!(stats.treatment_capacity, 0);
+}
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
 use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::dirty_chunks::DirtyRegion;
 use crate::services::{ServiceBuilding, ServiceType};
 
 use super::constants::*;
@@ -67,66 +70,130 @@ impl ServiceCoverageGrid {
     }
 }
 
+/// The coverage bit a service type contributes to, if it provides area
+/// coverage at all.
+pub fn coverage_bit(service_type: ServiceType) -> Option<u8> {
+    let bit = match service_type {
+        ServiceType::Hospital | ServiceType::MedicalClinic | ServiceType::MedicalCenter => {
+            COVERAGE_HEALTH
+        }
+        ServiceType::ElementarySchool
+        | ServiceType::HighSchool
+        | ServiceType::University
+        | ServiceType::Library
+        | ServiceType::Kindergarten => COVERAGE_EDUCATION,
+        ServiceType::PoliceStation
+        | ServiceType::PoliceKiosk
+        | ServiceType::PoliceHQ
+        | ServiceType::Prison => COVERAGE_POLICE,
+        ServiceType::SmallPark | ServiceType::LargePark | ServiceType::Playground => COVERAGE_PARK,
+        ServiceType::Stadium | ServiceType::Plaza | ServiceType::SportsField => {
+            COVERAGE_ENTERTAINMENT
+        }
+        ServiceType::CellTower | ServiceType::DataCenter => COVERAGE_TELECOM,
+        ServiceType::BusDepot
+        | ServiceType::TrainStation
+        | ServiceType::SubwayStation
+        | ServiceType::TramDepot
+        | ServiceType::FerryPier
+        | ServiceType::SmallAirstrip
+        | ServiceType::RegionalAirport
+        | ServiceType::InternationalAirport => COVERAGE_TRANSPORT,
+        ServiceType::FireStation | ServiceType::FireHouse | ServiceType::FireHQ => COVERAGE_FIRE,
+        _ => return None,
+    };
+    Some(bit)
+}
+
+/// Where each service was last stamped and how far the widest one reached,
+/// so a moved or removed service's old footprint can be cleared.
+#[derive(Default)]
+pub struct CoverageStamps {
+    positions: HashMap<Entity, (usize, usize)>,
+    max_radius_cells: usize,
+}
+
+/// Recompute coverage around services that appeared, changed or were
+/// removed since the last run. A budget change or an explicit `dirty` flag
+/// (set after loads) recomputes the whole grid.
 pub fn update_service_coverage(
-    services: Query<&ServiceBuilding>,
-    added_services: Query<Entity, Added<ServiceBuilding>>,
+    services: Query<(Entity, &ServiceBuilding)>,
+    changed_services: Query<(Entity, &ServiceBuilding), Changed<ServiceBuilding>>,
+    mut removed_services: RemovedComponents<ServiceBuilding>,
     mut coverage: ResMut<ServiceCoverageGrid>,
     ext_budget: Res<crate::budget::ExtendedBudget>,
+    mut stamps: Local<CoverageStamps>,
 ) {
-    if !added_services.is_empty() {
-        coverage.dirty = true;
+    let mut region = DirtyRegion::empty(GRID_WIDTH, GRID_HEIGHT);
+    for (entity, service) in &changed_services {
+        region.mark_cell(service.grid_x, service.grid_y);
+        if let Some((x, y)) = stamps
+            .positions
+            .insert(entity, (service.grid_x, service.grid_y))
+        {
+            region.mark_cell(x, y);
+        }
+    }
+    for entity in removed_services.read() {
+        if let Some((x, y)) = stamps.positions.remove(&entity) {
+            region.mark_cell(x, y);
+        }
     }
-    if ext_budget.is_changed() {
-        coverage.dirty = true;
+    if coverage.dirty || ext_budget.is_changed() {
+        region.set_full();
     }
-    if !coverage.dirty {
+    if region.is_empty() {
         return;
     }
     coverage.dirty = false;
-    coverage.clear();
 
-    for service in &services {
+    let radius_of = |service: &ServiceBuilding| {
         let budget_level = ext_budget.service_budgets.for_service(service.service_type);
-        let effective_radius = service.radius * budget_level;
+        service.radius * budget_level
+    };
+    let max_radius_cells = services
+        .iter()
+        .map(|(_, service)| (radius_of(service) / CELL_SIZE).ceil() as usize)
+        .max()
+        .unwrap_or(0);
+    // A removed service may have reached further than any remaining one.
+    region.dilate(max_radius_cells.max(stamps.max_radius_cells));
+    stamps.max_radius_cells = max_radius_cells;
+
+    if region.is_full() {
+        coverage.clear();
+        stamps.positions = services
+            .iter()
+            .map(|(entity, service)| (entity, (service.grid_x, service.grid_y)))
+            .collect();
+    } else {
+        for ci in region.chunks() {
+            let (x0, y0, x1, y1) = region.chunk_bounds(ci);
+            for y in y0..y1 {
+                let row = ServiceCoverageGrid::idx(x0, y)..ServiceCoverageGrid::idx(x1, y);
+                coverage.flags[row].fill(0);
+            }
+        }
+    }
+
+    for (_, service) in &services {
+        // Determine which coverage bits this service sets
+        let Some(bits) = coverage_bit(service.service_type) else {
+            continue;
+        };
+        let effective_radius = radius_of(service);
         let radius_cells = (effective_radius / CELL_SIZE).ceil() as i32;
         let sx = service.grid_x as i32;
         let sy = service.grid_y as i32;
         let r2 = effective_radius * effective_radius;
 
-        // Determine which coverage bits this service sets
-        let bits = match service.service_type {
-            ServiceType::Hospital | ServiceType::MedicalClinic | ServiceType::MedicalCenter => {
-                COVERAGE_HEALTH
-            }
-            ServiceType::ElementarySchool
-            | ServiceType::HighSchool
-            | ServiceType::University
-            | ServiceType::Library
-            | ServiceType::Kindergarten => COVERAGE_EDUCATION,
-            ServiceType::PoliceStation
-            | ServiceType::PoliceKiosk
-            | ServiceType::PoliceHQ
-            | ServiceType::Prison => COVERAGE_POLICE,
-            ServiceType::SmallPark | ServiceType::LargePark | ServiceType::Playground => {
-                COVERAGE_PARK
-            }
-            ServiceType::Stadium | ServiceType::Plaza | ServiceType::SportsField => {
-                COVERAGE_ENTERTAINMENT
-            }
-            ServiceType::CellTower | ServiceType::DataCenter => COVERAGE_TELECOM,
-            ServiceType::BusDepot
-            | ServiceType::TrainStation
-            | ServiceType::SubwayStation
-            | ServiceType::TramDepot
-            | ServiceType::FerryPier
-            | ServiceType::SmallAirstrip
-            | ServiceType::RegionalAirport
-            | ServiceType::InternationalAirport => COVERAGE_TRANSPORT,
-            ServiceType::FireStation | ServiceType::FireHouse | ServiceType::FireHQ => {
-                COVERAGE_FIRE
-            }
-            _ => continue,
-        };
+        let x0 = (sx - radius_cells).max(0) as usize;
+        let y0 = (sy - radius_cells).max(0) as usize;
+        let x1 = (sx + radius_cells + 1).max(0) as usize;
+        let y1 = (sy + radius_cells + 1).max(0) as usize;
+        if !region.intersects(x0, y0, x1, y1) {
+            continue;
+        }
 
         for dy in -radius_cells..=radius_cells {
             for dx in -radius_cells..=radius_cells {
@@ -135,6 +202,9 @@ pub fn update_service_coverage(
                 if cx < 0 || cy < 0 || cx >= GRID_WIDTH as i32 || cy >= GRID_HEIGHT as i32 {
                     continue;
                 }
+                if !region.contains_cell(cx as usize, cy as usize) {
+                    continue;
+                }
                 let wx_diff = dx as f32 * CELL_SIZE;
                 let wy_diff = dy as f32 * CELL_SIZE;
                 if wx_diff * wx_diff + wy_diff * wy_diff > r2 {
@@ -6,6 +6,6 @@ mod systems;
 mod tests;
 
 pub use constants::*;
-pub use coverage::{update_service_coverage, ServiceCoverageGrid};
+pub use coverage::{coverage_bit, update_service_coverage, ServiceCoverageGrid};
 pub use plugin::HappinessPlugin;
 pub use systems::{update_happiness, HappinessExtras};
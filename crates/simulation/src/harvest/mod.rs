@@ -0,0 +1,15 @@
+//! The yearly harvest.
+//!
+//! Crops on the city's farms grow through the growing season, a little each
+//! day in proportion to the crop yield modifier (rain, warmth, soil and
+//! irrigation). A month into autumn the crop is brought in: the better the
+//! season, the bigger the harvest, less whatever frost destroyed. The crop
+//! is sold into the treasury and the next year's crop starts from nothing.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_harvest, HarvestPlugin};
+pub use types::*;
@@ -0,0 +1,88 @@
+//! Daily crop growth and the autumn harvest.
+
+use bevy::prelude::*;
+
+use crate::agriculture::AgricultureState;
+use crate::economy::CityBudget;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+
+use super::types::*;
+
+/// Once a day, grow the crop while the growing season lasts and bring it in
+/// when harvest time comes round.
+pub fn update_harvest(
+    clock: Res<GameClock>,
+    agriculture: Res<AgricultureState>,
+    mut state: ResMut<HarvestState>,
+    mut budget: ResMut<CityBudget>,
+    mut harvests: EventWriter<HarvestEvent>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= state.last_update_day {
+        return;
+    }
+    state.last_update_day = clock.day;
+
+    if agriculture.growing_season_active && agriculture.farm_count > 0 {
+        state.grow(agriculture.crop_yield_modifier);
+    }
+
+    if !state.is_due(clock.day) {
+        return;
+    }
+    let harvest = state.harvest(
+        clock.day,
+        agriculture.farm_count,
+        agriculture.frost_damage_total,
+    );
+    if agriculture.farm_count == 0 {
+        return;
+    }
+    budget.treasury += harvest.revenue;
+    harvests.send(harvest);
+
+    let (text, priority) = if harvest.quality < POOR_HARVEST_QUALITY {
+        (
+            format!(
+                "Poor harvest: only {:.0} t of crops brought in, worth ${:.0}",
+                harvest.tons, harvest.revenue
+            ),
+            NotificationPriority::Warning,
+        )
+    } else {
+        (
+            format!(
+                "Harvest brought in: {:.0} t of crops sold for ${:.0}",
+                harvest.tons, harvest.revenue
+            ),
+            NotificationPriority::Positive,
+        )
+    };
+    notifications.send(NotificationEvent {
+        text,
+        priority,
+        category: NotificationCategory::General,
+        location: None,
+    });
+}
+
+pub struct HarvestPlugin;
+
+impl Plugin for HarvestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HarvestState>()
+            .add_event::<HarvestEvent>()
+            .add_systems(
+                FixedUpdate,
+                update_harvest
+                    .after(crate::agriculture::update_agriculture)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<HarvestState>();
+    }
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_crop_grows_to_maturity() {
+    let mut state = HarvestState::default();
+    for _ in 0..GROWING_DAYS_TO_MATURITY as u32 {
+        state.grow(1.0);
+    }
+    assert!((state.crop_growth - 1.0).abs() < 1e-3);
+    for _ in 0..1_000 {
+        state.grow(1.5);
+    }
+    assert_eq!(state.crop_growth, MAX_CROP_GROWTH);
+}
+
+#[test]
+fn test_harvest_due_once_a_year() {
+    let mut state = HarvestState::default();
+    assert!(!state.is_due(HARVEST_DAY_OF_YEAR - 1));
+    assert!(state.is_due(HARVEST_DAY_OF_YEAR));
+    state.harvest(HARVEST_DAY_OF_YEAR, 1, 0.0);
+    assert!(!state.is_due(HARVEST_DAY_OF_YEAR + 1));
+    assert!(!state.is_due(360));
+    assert!(!state.is_due(361), "not due again until next autumn");
+    assert!(state.is_due(360 + HARVEST_DAY_OF_YEAR));
+}
+
+#[test]
+fn test_harvest_sells_crop_and_resets() {
+    let mut state = HarvestState {
+        crop_growth: 1.0,
+        ..Default::default()
+    };
+    let harvest = state.harvest(HARVEST_DAY_OF_YEAR, 4, 0.0);
+    assert_eq!(harvest.tons, 4.0 * CROP_TONS_PER_FARM);
+    assert_eq!(
+        harvest.revenue,
+        4.0 * CROP_TONS_PER_FARM as f64 * CROP_PRICE_PER_TON
+    );
+    assert_eq!(harvest.quality, 1.0);
+    assert_eq!(state.crop_growth, 0.0);
+    assert_eq!(state.harvested_year, 1);
+    assert_eq!(state.total_revenue, harvest.revenue);
+}
+
+#[test]
+fn test_frost_cuts_the_harvest() {
+    let mut state = HarvestState {
+        crop_growth: 1.0,
+        ..Default::default()
+    };
+    let harvest = state.harvest(HARVEST_DAY_OF_YEAR, 1, 0.3);
+    assert!((harvest.quality - 0.7).abs() < 1e-5);
+
+    state.crop_growth = 1.0;
+    let harvest = state.harvest(360 + HARVEST_DAY_OF_YEAR, 1, 5.0);
+    assert!((harvest.quality - (1.0 - MAX_FROST_LOSS)).abs() < 1e-5);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(HarvestState::default().save_to_bytes().is_none());
+    let mut state = HarvestState {
+        crop_growth: 0.8,
+        ..Default::default()
+    };
+    state.harvest(HARVEST_DAY_OF_YEAR, 3, 0.1);
+    state.grow(1.2);
+    let bytes = state.save_to_bytes().expect("non-default state saves");
+    assert_eq!(HarvestState::load_from_bytes(&bytes), state);
+}
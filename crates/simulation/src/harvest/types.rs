@@ -0,0 +1,115 @@
+//! Crop growth, the harvest and its sale.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::time_of_day::DAYS_PER_YEAR;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Day of the year the crop is brought in, a month into autumn.
+pub const HARVEST_DAY_OF_YEAR: u32 = 210;
+
+/// Growing days at a yield modifier of 1.0 for a full crop.
+pub const GROWING_DAYS_TO_MATURITY: f32 = 120.0;
+
+/// Cap on crop growth; an exceptional season yields at most this many full
+/// crops.
+pub const MAX_CROP_GROWTH: f32 = 1.5;
+
+/// Tons of crops a farm brings in from a full crop.
+pub const CROP_TONS_PER_FARM: f32 = 100.0;
+
+/// Sale price of harvested crops ($ per ton).
+pub const CROP_PRICE_PER_TON: f64 = 2.0;
+
+/// Below this fraction of a full crop the harvest counts as poor.
+pub const POOR_HARVEST_QUALITY: f32 = 0.5;
+
+/// Crop destroyed by frost never exceeds this fraction.
+pub const MAX_FROST_LOSS: f32 = 0.9;
+
+// ---------------------------------------------------------------------------
+// Harvest
+// ---------------------------------------------------------------------------
+
+/// Fired when the year's crop is brought in.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct HarvestEvent {
+    pub tons: f32,
+    pub revenue: f64,
+    /// Fraction of a full crop harvested.
+    pub quality: f32,
+}
+
+/// Game year (starting at 1) that `day` falls in.
+pub fn year_of_day(day: u32) -> u32 {
+    day.saturating_sub(1) / DAYS_PER_YEAR + 1
+}
+
+/// Crop growing on the city's farms and the last harvest.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HarvestState {
+    /// Crop grown this year, in full crops.
+    pub crop_growth: f32,
+    /// Year of the last harvest.
+    pub harvested_year: u32,
+    pub last_harvest_tons: f32,
+    pub last_harvest_revenue: f64,
+    pub last_harvest_quality: f32,
+    pub total_revenue: f64,
+    pub last_update_day: u32,
+}
+
+impl HarvestState {
+    /// One day of growth at the given crop yield modifier.
+    pub fn grow(&mut self, yield_modifier: f32) {
+        self.crop_growth = (self.crop_growth + yield_modifier.max(0.0) / GROWING_DAYS_TO_MATURITY)
+            .min(MAX_CROP_GROWTH);
+    }
+
+    /// Whether this year's crop is due on `day`.
+    pub fn is_due(&self, day: u32) -> bool {
+        let day_of_year = day.saturating_sub(1) % DAYS_PER_YEAR + 1;
+        day_of_year >= HARVEST_DAY_OF_YEAR && year_of_day(day) > self.harvested_year
+    }
+
+    /// Bring in the crop from `farms` farms after frost destroyed
+    /// `frost_damage` of it, sell it, and start the next year's crop.
+    pub fn harvest(&mut self, day: u32, farms: u32, frost_damage: f32) -> HarvestEvent {
+        let quality = self.crop_growth * (1.0 - frost_damage.clamp(0.0, MAX_FROST_LOSS));
+        let tons = farms as f32 * CROP_TONS_PER_FARM * quality;
+        let revenue = tons as f64 * CROP_PRICE_PER_TON;
+
+        self.crop_growth = 0.0;
+        self.harvested_year = year_of_day(day);
+        self.last_harvest_tons = tons;
+        self.last_harvest_revenue = revenue;
+        self.last_harvest_quality = quality;
+        self.total_revenue += revenue;
+        HarvestEvent {
+            tons,
+            revenue,
+            quality,
+        }
+    }
+}
+
+impl Saveable for HarvestState {
+    const SAVE_KEY: &'static str = "harvest";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,21 @@
+//! Industrial hazmat accidents: chemical spills and explosions.
+//!
+//! Heavy industry and hazardous waste facilities occasionally have an
+//! accident, more often when the city produces more hazardous waste than it
+//! can treat. A spill or explosion dumps contamination into the soil around
+//! the site and keeps leaking into soil and groundwater until it is
+//! contained; the soil contamination outlasts the incident by decades unless
+//! it is remediated. Buildings around the site are evacuated until then.
+//!
+//! Hazmat Response Centers send cleanup crews to incidents in their coverage
+//! radius. Crews contain the material quickly and scrub the soil they work
+//! on; without them an incident only disperses on its own over weeks.
+//! Explosions also damage the building they start in and set it on fire.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{enforce_evacuation, respond_to_hazmat, roll_hazmat_incidents, HazmatPlugin};
+pub use types::*;
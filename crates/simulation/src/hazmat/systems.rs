@@ -0,0 +1,262 @@
+//! Hazmat accidents, contamination, cleanup crews and evacuation.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::config::CELL_SIZE;
+use crate::fire::OnFire;
+use crate::game_settings::GameSettings;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::groundwater::WaterQualityGrid;
+use crate::hazardous_waste::HazardousWasteState;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::service_building_capacity::tier_capacity;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::soil_contamination::SoilContaminationGrid;
+use crate::structural_integrity::StructuralState;
+use crate::time_of_day::GameClock;
+use crate::weather::Weather;
+use crate::wind_damage::types::{rand_f32, splitmix64};
+use crate::{SlowTickTimer, TestSafetyNet, TickCounter};
+
+use super::types::*;
+
+/// Cells within `incident.radius` of the site, with their contamination
+/// falloff.
+fn footprint(
+    incident: &HazmatIncident,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize, f32)> + '_ {
+    let r = incident.radius as usize;
+    let x0 = incident.x.saturating_sub(r);
+    let y0 = incident.y.saturating_sub(r);
+    let x1 = (incident.x + r).min(width - 1);
+    let y1 = (incident.y + r).min(height - 1);
+    (y0..=y1)
+        .flat_map(move |y| (x0..=x1).map(move |x| (x, y)))
+        .map(|(x, y)| (x, y, incident.falloff(x, y)))
+}
+
+/// Add `amount` of soil contamination at the incident site, tapering off
+/// towards the edge of its radius.
+fn contaminate_soil(soil: &mut SoilContaminationGrid, incident: &HazmatIncident, amount: f32) {
+    for (x, y, falloff) in footprint(incident, soil.width, soil.height) {
+        soil.set(x, y, soil.get(x, y) + amount * falloff);
+    }
+}
+
+/// Once a day, roll for accidents at heavy industry and hazardous waste
+/// facilities. Untreated waste overflow makes accidents more likely.
+#[allow(clippy::too_many_arguments)]
+pub fn roll_hazmat_incidents(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    tick: Res<TickCounter>,
+    weather: Res<Weather>,
+    settings: Res<GameSettings>,
+    waste: Res<HazardousWasteState>,
+    safety_net: Option<Res<TestSafetyNet>>,
+    grid: Res<WorldGrid>,
+    mut state: ResMut<HazmatState>,
+    mut soil: ResMut<SoilContaminationGrid>,
+    mut structural: ResMut<StructuralState>,
+    buildings: Query<&Building>,
+    services: Query<&ServiceBuilding>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= state.last_roll_day {
+        return;
+    }
+    state.last_roll_day = clock.day;
+    if safety_net.is_some()
+        || !weather.disasters_enabled
+        || state.incidents.len() >= MAX_ACTIVE_INCIDENTS
+    {
+        return;
+    }
+
+    let risk =
+        (1.0 + waste.overflow * OVERFLOW_RISK_PER_TON) * settings.disaster_chance_multiplier();
+    let industry = buildings
+        .iter()
+        .filter(|b| b.zone_type == ZoneType::Industrial)
+        .map(|b| {
+            (
+                b.grid_x,
+                b.grid_y,
+                INCIDENT_CHANCE_PER_LEVEL * b.level as f32,
+            )
+        });
+    let facilities = services
+        .iter()
+        .filter(|s| s.service_type == ServiceType::Incinerator)
+        .map(|s| (s.grid_x, s.grid_y, FACILITY_INCIDENT_CHANCE));
+    let base_seed = splitmix64(tick.0 ^ 0x4a2d_5e11);
+    let Some((x, y, seed)) = industry.chain(facilities).find_map(|(x, y, chance)| {
+        let seed = splitmix64(base_seed ^ (((y as u64) << 16) | x as u64));
+        (rand_f32(seed) < chance * risk).then_some((x, y, seed))
+    }) else {
+        return;
+    };
+
+    let kind = if rand_f32(seed.wrapping_add(1)) < EXPLOSION_FRACTION {
+        HazmatKind::Explosion
+    } else {
+        HazmatKind::Spill
+    };
+    let incident = state.start(kind, x, y, clock.day).clone();
+    contaminate_soil(&mut soil, &incident, incident.volume * INITIAL_SOIL_PER_TON);
+
+    if kind == HazmatKind::Explosion {
+        structural.wear(x, y, EXPLOSION_STRUCTURAL_DAMAGE);
+        if let Some(entity) = grid.get(x, y).building_id {
+            commands.entity(entity).insert(OnFire {
+                intensity: EXPLOSION_FIRE_INTENSITY,
+                ticks_burning: 0,
+            });
+        }
+    }
+
+    notifications.send(NotificationEvent {
+        text: format!(
+            "{} at ({x}, {y}): residents within {} cells are being evacuated",
+            kind.name(),
+            incident.evacuation_radius()
+        ),
+        priority: NotificationPriority::Emergency,
+        category: NotificationCategory::Disaster,
+        location: Some(WorldGrid::grid_to_world(x, y)),
+    });
+}
+
+/// Every slow tick: dispatch cleanup crews, let uncontained material leak
+/// into soil and groundwater, and close incidents once they are contained.
+///
+/// Each Hazmat Response Center sends its crews to the nearest incident in
+/// its coverage radius. Without crews an incident only disperses slowly and
+/// keeps leaking for weeks.
+pub fn respond_to_hazmat(
+    slow_timer: Res<SlowTickTimer>,
+    mut state: ResMut<HazmatState>,
+    mut soil: ResMut<SoilContaminationGrid>,
+    mut water_quality: Option<ResMut<WaterQualityGrid>>,
+    services: Query<&ServiceBuilding>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() || state.incidents.is_empty() {
+        return;
+    }
+
+    for incident in &mut state.incidents {
+        incident.crews = 0;
+    }
+    for service in &services {
+        if service.service_type != ServiceType::HazmatResponse {
+            continue;
+        }
+        let reach = (service.radius / CELL_SIZE) as u32;
+        let nearest = state
+            .incidents
+            .iter_mut()
+            .filter(|i| i.distance(service.grid_x, service.grid_y) <= reach)
+            .min_by_key(|i| (i.distance(service.grid_x, service.grid_y), i.id));
+        if let Some(incident) = nearest {
+            incident.crews += tier_capacity(ServiceType::HazmatResponse);
+        }
+    }
+
+    let mut cleaned = 0.0;
+    for incident in &mut state.incidents {
+        // Leak what is still loose into the ground.
+        contaminate_soil(&mut soil, incident, incident.volume * LEAK_SOIL_PER_TON);
+        if let Some(wq) = water_quality.as_mut() {
+            for (x, y, falloff) in footprint(incident, wq.width, wq.height) {
+                let damage = (LEAK_GROUNDWATER_DAMAGE as f32 * falloff).round() as u8;
+                wq.set(x, y, wq.get(x, y).saturating_sub(damage));
+            }
+        }
+
+        // Crews contain material and scrub the soil they are working on.
+        let crew_tons = (incident.crews as f32 * CREW_CLEANUP_TONS).min(incident.volume);
+        cleaned += crew_tons;
+        incident.volume -= crew_tons;
+        incident.volume *= 1.0 - NATURAL_DISPERSAL_RATE;
+        if incident.crews > 0 {
+            let keep = (1.0 - CREW_SOIL_CLEANUP * incident.crews as f32).max(0.0);
+            for (x, y, falloff) in footprint(incident, soil.width, soil.height) {
+                if falloff > 0.0 {
+                    soil.set(x, y, soil.get(x, y) * keep);
+                }
+            }
+        }
+    }
+    state.tons_cleaned += cleaned;
+
+    let before = state.incidents.len();
+    state.incidents.retain(|incident| {
+        if incident.volume >= CONTAINED_VOLUME_TONS {
+            return true;
+        }
+        notifications.send(NotificationEvent {
+            text: format!(
+                "{} at ({}, {}) contained; the evacuation has been lifted",
+                incident.kind.name(),
+                incident.x,
+                incident.y
+            ),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::Disaster,
+            location: Some(WorldGrid::grid_to_world(incident.x, incident.y)),
+        });
+        false
+    });
+    state.total_contained += (before - state.incidents.len()) as u32;
+}
+
+/// Keep buildings inside an evacuation zone empty, and let people back in
+/// once the zone is lifted.
+pub fn enforce_evacuation(
+    mut commands: Commands,
+    state: Res<HazmatState>,
+    mut buildings: Query<(Entity, &mut Building, Has<Evacuated>)>,
+) {
+    if state.incidents.is_empty() && !state.is_changed() {
+        return;
+    }
+    for (entity, mut building, marked) in &mut buildings {
+        let inside = state.is_evacuated(building.grid_x, building.grid_y);
+        if inside {
+            if building.occupants > 0 {
+                building.occupants = 0;
+            }
+            if !marked {
+                commands.entity(entity).insert(Evacuated);
+            }
+        } else if marked {
+            commands.entity(entity).remove::<Evacuated>();
+        }
+    }
+}
+
+pub struct HazmatPlugin;
+
+impl Plugin for HazmatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HazmatState>().add_systems(
+            FixedUpdate,
+            (
+                roll_hazmat_incidents.after(crate::hazardous_waste::update_hazardous_waste),
+                respond_to_hazmat,
+                enforce_evacuation,
+            )
+                .chain()
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<HazmatState>();
+    }
+}
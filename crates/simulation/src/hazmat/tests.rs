@@ -0,0 +1,46 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_explosions_are_larger_than_spills() {
+    assert!(HazmatKind::Explosion.radius() > HazmatKind::Spill.radius());
+    assert!(HazmatKind::Explosion.volume() > HazmatKind::Spill.volume());
+}
+
+#[test]
+fn test_falloff_tapers_to_edge() {
+    let incident = HazmatIncident::new(0, HazmatKind::Spill, 50, 50, 1);
+    assert_eq!(incident.falloff(50, 50), 1.0);
+    let edge = incident.falloff(50 + SPILL_RADIUS as usize, 50);
+    assert!(edge > 0.0 && edge < 1.0);
+    assert_eq!(incident.falloff(51 + SPILL_RADIUS as usize, 50), 0.0);
+}
+
+#[test]
+fn test_evacuation_zone_extends_past_contamination() {
+    let incident = HazmatIncident::new(0, HazmatKind::Spill, 50, 50, 1);
+    let edge = 50 + (SPILL_RADIUS + EVACUATION_MARGIN) as usize;
+    assert!(incident.is_evacuated(edge, edge));
+    assert!(!incident.is_evacuated(edge + 1, 50));
+}
+
+#[test]
+fn test_start_assigns_ids_and_counts() {
+    let mut state = HazmatState::default();
+    assert_eq!(state.start(HazmatKind::Spill, 10, 10, 3).id, 0);
+    assert_eq!(state.start(HazmatKind::Explosion, 90, 90, 4).id, 1);
+    assert_eq!(state.total_incidents, 2);
+    assert!(state.is_evacuated(11, 9));
+    assert!(!state.is_evacuated(50, 50));
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(HazmatState::default().save_to_bytes().is_none());
+    let mut state = HazmatState::default();
+    state.start(HazmatKind::Explosion, 30, 40, 12);
+    state.incidents[0].volume = 12.5;
+    state.tons_cleaned = 7.0;
+    let restored = HazmatState::load_from_bytes(&state.save_to_bytes().unwrap());
+    assert_eq!(restored, state);
+}
@@ -0,0 +1,200 @@
+//! Hazmat incidents, their contamination footprint and the saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Daily chance of an accident per industrial building level.
+pub const INCIDENT_CHANCE_PER_LEVEL: f32 = 0.000_4;
+
+/// Daily chance of an accident at each hazardous waste facility.
+pub const FACILITY_INCIDENT_CHANCE: f32 = 0.002;
+
+/// Extra risk per ton of untreated hazardous waste overflow.
+pub const OVERFLOW_RISK_PER_TON: f32 = 0.05;
+
+/// Share of accidents that are explosions rather than spills.
+pub const EXPLOSION_FRACTION: f32 = 0.25;
+
+/// No new accidents start while this many are still uncontained.
+pub const MAX_ACTIVE_INCIDENTS: usize = 3;
+
+/// Contamination radius (cells) and released volume (tons) of a spill.
+pub const SPILL_RADIUS: u32 = 3;
+pub const SPILL_VOLUME_TONS: f32 = 40.0;
+
+/// Contamination radius (cells) and released volume (tons) of an explosion.
+pub const EXPLOSION_RADIUS: u32 = 6;
+pub const EXPLOSION_VOLUME_TONS: f32 = 80.0;
+
+/// Buildings this many cells beyond the contamination radius are evacuated
+/// too.
+pub const EVACUATION_MARGIN: u32 = 2;
+
+/// Soil contamination deposited at the center per ton when the accident
+/// happens.
+pub const INITIAL_SOIL_PER_TON: f32 = 2.0;
+
+/// Soil contamination leaked at the center per ton still uncontained, each
+/// slow tick.
+pub const LEAK_SOIL_PER_TON: f32 = 0.05;
+
+/// Groundwater quality lost at the center each slow tick while the incident
+/// is uncontained.
+pub const LEAK_GROUNDWATER_DAMAGE: u8 = 2;
+
+/// Fraction of the remaining volume that disperses on its own each slow tick.
+pub const NATURAL_DISPERSAL_RATE: f32 = 0.01;
+
+/// Tons one cleanup crew contains per slow tick.
+pub const CREW_CLEANUP_TONS: f32 = 2.0;
+
+/// Fraction of soil contamination inside the radius one crew removes each
+/// slow tick while it works the site.
+pub const CREW_SOIL_CLEANUP: f32 = 0.02;
+
+/// An incident is contained once less than this volume remains.
+pub const CONTAINED_VOLUME_TONS: f32 = 1.0;
+
+/// Structural damage and fire intensity at the source of an explosion.
+pub const EXPLOSION_STRUCTURAL_DAMAGE: f32 = 50.0;
+pub const EXPLOSION_FIRE_INTENSITY: f32 = 40.0;
+
+// ---------------------------------------------------------------------------
+// Incidents
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum HazmatKind {
+    Spill,
+    Explosion,
+}
+
+impl HazmatKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            HazmatKind::Spill => "Chemical spill",
+            HazmatKind::Explosion => "Chemical explosion",
+        }
+    }
+
+    pub fn radius(self) -> u32 {
+        match self {
+            HazmatKind::Spill => SPILL_RADIUS,
+            HazmatKind::Explosion => EXPLOSION_RADIUS,
+        }
+    }
+
+    pub fn volume(self) -> f32 {
+        match self {
+            HazmatKind::Spill => SPILL_VOLUME_TONS,
+            HazmatKind::Explosion => EXPLOSION_VOLUME_TONS,
+        }
+    }
+}
+
+/// An uncontained spill or explosion site.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HazmatIncident {
+    pub id: u32,
+    pub kind: HazmatKind,
+    pub x: usize,
+    pub y: usize,
+    /// Contamination radius in cells.
+    pub radius: u32,
+    /// Tons of material still uncontained.
+    pub volume: f32,
+    pub started_day: u32,
+    /// Cleanup crews working the site during the last update.
+    pub crews: u32,
+}
+
+impl HazmatIncident {
+    pub fn new(id: u32, kind: HazmatKind, x: usize, y: usize, day: u32) -> Self {
+        Self {
+            id,
+            kind,
+            x,
+            y,
+            radius: kind.radius(),
+            volume: kind.volume(),
+            started_day: day,
+            crews: 0,
+        }
+    }
+
+    pub fn evacuation_radius(&self) -> u32 {
+        self.radius + EVACUATION_MARGIN
+    }
+
+    /// Chebyshev distance from the incident to (`x`, `y`).
+    pub fn distance(&self, x: usize, y: usize) -> u32 {
+        self.x.abs_diff(x).max(self.y.abs_diff(y)) as u32
+    }
+
+    pub fn is_evacuated(&self, x: usize, y: usize) -> bool {
+        self.distance(x, y) <= self.evacuation_radius()
+    }
+
+    /// Share of the center's contamination that reaches (`x`, `y`): 1 at the
+    /// center, falling linearly to 0 beyond the radius.
+    pub fn falloff(&self, x: usize, y: usize) -> f32 {
+        let d = self.distance(x, y) as f32;
+        (1.0 - d / (self.radius as f32 + 1.0)).max(0.0)
+    }
+}
+
+/// Active hazmat incidents and running totals.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HazmatState {
+    pub incidents: Vec<HazmatIncident>,
+    pub next_id: u32,
+    /// Last day the daily accident roll ran.
+    pub last_roll_day: u32,
+    pub total_incidents: u32,
+    pub total_contained: u32,
+    /// Tons contained by cleanup crews (as opposed to dispersing on its own).
+    pub tons_cleaned: f32,
+}
+
+impl HazmatState {
+    /// Whether (`x`, `y`) is inside any incident's evacuation zone.
+    pub fn is_evacuated(&self, x: usize, y: usize) -> bool {
+        self.incidents.iter().any(|i| i.is_evacuated(x, y))
+    }
+
+    /// Record a new incident and return it.
+    pub fn start(&mut self, kind: HazmatKind, x: usize, y: usize, day: u32) -> &HazmatIncident {
+        let incident = HazmatIncident::new(self.next_id, kind, x, y, day);
+        self.next_id += 1;
+        self.total_incidents += 1;
+        self.incidents.push(incident);
+        self.incidents.last().unwrap()
+    }
+}
+
+impl Saveable for HazmatState {
+    const SAVE_KEY: &'static str = "hazmat_state";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Marker for buildings inside an evacuation zone. Evacuated buildings stay
+/// empty until the incident is contained.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Evacuated;
@@ -0,0 +1,24 @@
+//! Heat-health action plan.
+//!
+//! When the weather forecast sees a heat event coming, or a heat wave
+//! arrives unannounced, the city raises a heat alert and activates the
+//! measures the player has adopted:
+//!
+//! - **Cooling centers**: libraries, community centers and senior centers
+//!   open as cooling shelters. Their effect scales with the share of
+//!   residents living within reach of one.
+//! - **Outreach**: staff check on elderly and isolated residents.
+//! - **Hydration stations**: each serves a fixed number of residents.
+//!
+//! Every measure is more effective the more warning the forecast gave, up
+//! to `FULL_WARNING_DAYS`. The plan reduces the excess mortality computed
+//! by `heat_wave`, on top of the standing measures in `heat_mitigation`,
+//! and charges daily running costs for as long as the alert is up.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_heat_health, HeatHealthPlugin};
+pub use types::*;
@@ -0,0 +1,125 @@
+//! Daily heat alert handling and plan effectiveness.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::heat_wave::{HeatWaveSeverity, HeatWaveState};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::ServiceBuilding;
+use crate::time_of_day::GameClock;
+use crate::weather::WeatherForecast;
+
+use super::types::*;
+
+/// Once per game day: raise or stand down the heat alert from the forecast
+/// and heat wave state, and while it is up, work out how much of the heat
+/// wave's excess mortality the plan prevents and charge its running costs.
+#[allow(clippy::too_many_arguments)]
+pub fn update_heat_health(
+    clock: Res<GameClock>,
+    forecast: Res<WeatherForecast>,
+    heat_wave: Res<HeatWaveState>,
+    services: Query<&ServiceBuilding>,
+    buildings: Query<&Building>,
+    mut plan: ResMut<HeatHealthPlan>,
+    mut budget: ResMut<CityBudget>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day == plan.last_day {
+        return;
+    }
+    plan.last_day = clock.day;
+
+    let heat_now = heat_wave.severity != HeatWaveSeverity::None;
+    if !plan.alert_active {
+        let lead = if heat_now {
+            Some(0)
+        } else {
+            forecast.heat_wave_in_days
+        };
+        let Some(lead) = lead else {
+            return;
+        };
+        plan.raise_alert(clock.day, lead);
+        let text = if lead == 0 {
+            "Heat-health alert: a heat wave has arrived without warning.".to_string()
+        } else {
+            format!("Heat-health alert: a heat wave is forecast in {lead} days.")
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::Citizens,
+            location: None,
+        });
+    } else if !heat_now
+        && forecast.heat_wave_in_days.is_none()
+        && clock.day > plan.alert_day + plan.lead_days + ALERT_GRACE_DAYS
+    {
+        plan.end_alert();
+        return;
+    }
+
+    // Cooling site coverage of residents.
+    let sites: Vec<(f32, f32)> = services
+        .iter()
+        .filter(|s| COOLING_SITE_TYPES.contains(&s.service_type))
+        .map(|s| WorldGrid::grid_to_world(s.grid_x, s.grid_y))
+        .collect();
+    let homes: Vec<(f32, f32, u32)> = buildings
+        .iter()
+        .filter(|b| b.zone_type.is_residential() || b.zone_type.is_mixed_use())
+        .map(|b| {
+            let (x, y) = WorldGrid::grid_to_world(b.grid_x, b.grid_y);
+            (x, y, b.occupants)
+        })
+        .collect();
+    let population: u32 = homes.iter().map(|&(_, _, n)| n).sum();
+
+    plan.cooling_sites = if plan.cooling_centers {
+        sites.len() as u32
+    } else {
+        0
+    };
+    plan.cooling_coverage = if plan.cooling_centers {
+        cooling_coverage(&sites, &homes)
+    } else {
+        0.0
+    };
+    plan.readiness = readiness(plan.lead_days);
+    plan.mortality_reduction = plan_reduction(
+        plan.cooling_coverage,
+        plan.outreach,
+        hydration_coverage(plan.hydration_stations, population),
+        plan.readiness,
+    );
+
+    let excess = heat_wave.excess_mortality_per_100k;
+    plan.net_excess_mortality_per_100k = excess * (1.0 - plan.mortality_reduction);
+    plan.lives_saved += excess * plan.mortality_reduction * population as f32 / 100_000.0;
+
+    let cost = plan.daily_cost();
+    budget.treasury -= cost;
+    plan.total_cost += cost;
+}
+
+pub struct HeatHealthPlugin;
+
+impl Plugin for HeatHealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeatHealthPlan>().add_systems(
+            FixedUpdate,
+            update_heat_health
+                .after(crate::heat_wave::update_heat_wave)
+                .after(crate::weather::update_forecast)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<HeatHealthPlan>();
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_readiness_grows_with_warning() {
+    assert_eq!(readiness(0), UNWARNED_READINESS);
+    assert!(readiness(1) > readiness(0));
+    assert_eq!(readiness(FULL_WARNING_DAYS), 1.0);
+    assert_eq!(readiness(FULL_WARNING_DAYS + 5), 1.0);
+}
+
+#[test]
+fn test_hydration_coverage_scales_with_population() {
+    assert_eq!(hydration_coverage(5, 0), 0.0);
+    assert_eq!(hydration_coverage(0, 1_000), 0.0);
+    assert_eq!(hydration_coverage(1, RESIDENTS_PER_HYDRATION_STATION), 1.0);
+    assert!((hydration_coverage(1, RESIDENTS_PER_HYDRATION_STATION * 4) - 0.25).abs() < 1e-6);
+    assert_eq!(hydration_coverage(10, 1_000), 1.0);
+}
+
+#[test]
+fn test_cooling_coverage_counts_residents_in_reach() {
+    let sites = [(0.0, 0.0)];
+    let homes = [
+        (COOLING_CENTER_RADIUS * 0.5, 0.0, 30),
+        (COOLING_CENTER_RADIUS * 2.0, 0.0, 10),
+    ];
+    assert!((cooling_coverage(&sites, &homes) - 0.75).abs() < 1e-6);
+    assert_eq!(cooling_coverage(&[], &homes), 0.0);
+    assert_eq!(cooling_coverage(&sites, &[]), 0.0);
+}
+
+#[test]
+fn test_plan_reduction_combines_measures() {
+    assert_eq!(plan_reduction(0.0, false, 0.0, 1.0), 0.0);
+    assert!((plan_reduction(1.0, false, 0.0, 1.0) - COOLING_CENTER_REDUCTION).abs() < 1e-6);
+
+    let all = plan_reduction(1.0, true, 1.0, 1.0);
+    assert!(all > COOLING_CENTER_REDUCTION);
+    assert!(all < COOLING_CENTER_REDUCTION + OUTREACH_REDUCTION + HYDRATION_REDUCTION);
+
+    // Less warning, less effect.
+    assert!(plan_reduction(1.0, true, 1.0, readiness(0)) < all);
+}
+
+#[test]
+fn test_daily_cost_only_counts_enabled_measures() {
+    let mut plan = HeatHealthPlan {
+        cooling_sites: 4,
+        ..Default::default()
+    };
+    assert_eq!(plan.daily_cost(), 0.0);
+    plan.cooling_centers = true;
+    plan.outreach = true;
+    plan.hydration_stations = 10;
+    let expected =
+        4.0 * COOLING_SITE_DAILY_COST + OUTREACH_DAILY_COST + 10.0 * HYDRATION_STATION_DAILY_COST;
+    assert!((plan.daily_cost() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_end_alert_clears_derived_values() {
+    let mut plan = HeatHealthPlan::default();
+    plan.raise_alert(120, 2);
+    plan.mortality_reduction = 0.4;
+    plan.net_excess_mortality_per_100k = 1.0;
+    plan.end_alert();
+    assert!(!plan.alert_active);
+    assert_eq!(plan.mortality_reduction, 0.0);
+    assert_eq!(plan.net_excess_mortality_per_100k, 0.0);
+}
+
+#[test]
+fn test_save_skipped_at_default_and_roundtrips() {
+    let mut plan = HeatHealthPlan::default();
+    assert!(plan.save_to_bytes().is_none());
+    plan.outreach = true;
+    plan.hydration_stations = 7;
+    let restored = HeatHealthPlan::load_from_bytes(&plan.save_to_bytes().unwrap());
+    assert!(restored.outreach);
+    assert_eq!(restored.hydration_stations, 7);
+}
@@ -0,0 +1,200 @@
+//! Heat-health action plan settings, effectiveness model and state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::CELL_SIZE;
+use crate::services::ServiceType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Public buildings opened as cooling centers during a heat alert.
+pub const COOLING_SITE_TYPES: [ServiceType; 3] = [
+    ServiceType::Library,
+    ServiceType::CommunityCenter,
+    ServiceType::SeniorCenter,
+];
+
+/// How far residents will travel to a cooling center (world units).
+pub const COOLING_CENTER_RADIUS: f32 = 12.0 * CELL_SIZE;
+
+/// Share of heat deaths cooling centers prevent with full coverage.
+pub const COOLING_CENTER_REDUCTION: f32 = 0.5;
+
+/// Share of heat deaths prevented by checking on elderly and isolated
+/// residents.
+pub const OUTREACH_REDUCTION: f32 = 0.2;
+
+/// Share of heat deaths prevented when hydration stations reach everyone.
+pub const HYDRATION_REDUCTION: f32 = 0.15;
+
+/// Residents one hydration station serves.
+pub const RESIDENTS_PER_HYDRATION_STATION: u32 = 2_000;
+
+/// Most hydration stations the plan can deploy.
+pub const MAX_HYDRATION_STATIONS: u32 = 50;
+
+/// Days of warning needed for the plan to be fully in place.
+pub const FULL_WARNING_DAYS: u32 = 3;
+
+/// Effectiveness of the plan when the heat arrives without warning.
+pub const UNWARNED_READINESS: f32 = 0.4;
+
+/// Days an alert stays up after the forecast onset if no heat wave forms.
+pub const ALERT_GRACE_DAYS: u32 = 3;
+
+/// Daily running costs while an alert is in force.
+pub const COOLING_SITE_DAILY_COST: f64 = 1_500.0;
+pub const OUTREACH_DAILY_COST: f64 = 5_000.0;
+pub const HYDRATION_STATION_DAILY_COST: f64 = 300.0;
+
+// ---------------------------------------------------------------------------
+// Effectiveness
+// ---------------------------------------------------------------------------
+
+/// How prepared the plan is, given the days of warning before the heat.
+pub fn readiness(lead_days: u32) -> f32 {
+    let warned = (lead_days as f32 / FULL_WARNING_DAYS as f32).min(1.0);
+    UNWARNED_READINESS + (1.0 - UNWARNED_READINESS) * warned
+}
+
+/// Fraction of residents the hydration stations can serve.
+pub fn hydration_coverage(stations: u32, population: u32) -> f32 {
+    if population == 0 {
+        return 0.0;
+    }
+    (stations as f32 * RESIDENTS_PER_HYDRATION_STATION as f32 / population as f32).min(1.0)
+}
+
+/// Fraction of residents (weighted by occupants) living within reach of a
+/// cooling site. `sites` and `homes` are world positions.
+pub fn cooling_coverage(sites: &[(f32, f32)], homes: &[(f32, f32, u32)]) -> f32 {
+    let r2 = COOLING_CENTER_RADIUS * COOLING_CENTER_RADIUS;
+    let total: u32 = homes.iter().map(|&(_, _, n)| n).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let covered: u32 = homes
+        .iter()
+        .filter(|&&(hx, hy, _)| {
+            sites
+                .iter()
+                .any(|&(sx, sy)| (hx - sx).powi(2) + (hy - sy).powi(2) <= r2)
+        })
+        .map(|&(_, _, n)| n)
+        .sum();
+    covered as f32 / total as f32
+}
+
+/// Combined share of heat deaths the plan prevents. Each measure acts on
+/// the deaths the others did not prevent.
+pub fn plan_reduction(
+    cooling_coverage: f32,
+    outreach: bool,
+    hydration_coverage: f32,
+    readiness: f32,
+) -> f32 {
+    let cooling = COOLING_CENTER_REDUCTION * cooling_coverage * readiness;
+    let outreach = if outreach {
+        OUTREACH_REDUCTION * readiness
+    } else {
+        0.0
+    };
+    let hydration = HYDRATION_REDUCTION * hydration_coverage * readiness;
+    1.0 - (1.0 - cooling) * (1.0 - outreach) * (1.0 - hydration)
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// The city's heat-health action plan: which measures are activated on a
+/// heat alert, and how the current alert is going.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct HeatHealthPlan {
+    // --- Player settings ---
+    /// Open libraries and community centers as cooling centers.
+    pub cooling_centers: bool,
+    /// Check on elderly and isolated residents.
+    pub outreach: bool,
+    /// Hydration stations deployed during alerts.
+    pub hydration_stations: u32,
+
+    // --- Current alert ---
+    /// Whether a heat alert is in force.
+    pub alert_active: bool,
+    /// Day the current alert was raised.
+    pub alert_day: u32,
+    /// Days of warning the forecast gave before the heat was due.
+    pub lead_days: u32,
+
+    // --- Derived (refreshed daily during an alert) ---
+    pub cooling_sites: u32,
+    pub cooling_coverage: f32,
+    pub readiness: f32,
+    /// Share of heat deaths currently prevented (0.0-1.0).
+    pub mortality_reduction: f32,
+    /// Excess deaths per 100k from `HeatWaveState` after the plan.
+    pub net_excess_mortality_per_100k: f32,
+
+    // --- Totals ---
+    /// Estimated deaths prevented since the game began.
+    pub lives_saved: f32,
+    /// Spent running the plan.
+    pub total_cost: f64,
+    /// Last game day processed.
+    pub last_day: u32,
+}
+
+impl HeatHealthPlan {
+    /// Whether any measure is enabled.
+    pub fn has_measures(&self) -> bool {
+        self.cooling_centers || self.outreach || self.hydration_stations > 0
+    }
+
+    /// Running cost for one day of an alert.
+    pub fn daily_cost(&self) -> f64 {
+        let mut cost = 0.0;
+        if self.cooling_centers {
+            cost += self.cooling_sites as f64 * COOLING_SITE_DAILY_COST;
+        }
+        if self.outreach {
+            cost += OUTREACH_DAILY_COST;
+        }
+        cost + self.hydration_stations as f64 * HYDRATION_STATION_DAILY_COST
+    }
+
+    /// Raise an alert for heat due in `lead_days`.
+    pub fn raise_alert(&mut self, day: u32, lead_days: u32) {
+        self.alert_active = true;
+        self.alert_day = day;
+        self.lead_days = lead_days;
+    }
+
+    /// Stand the plan down and clear the alert's derived values.
+    pub fn end_alert(&mut self) {
+        self.alert_active = false;
+        self.cooling_sites = 0;
+        self.cooling_coverage = 0.0;
+        self.readiness = 0.0;
+        self.mortality_reduction = 0.0;
+        self.net_excess_mortality_per_100k = 0.0;
+    }
+}
+
+impl Saveable for HeatHealthPlan {
+    const SAVE_KEY: &'static str = "heat_health_plan";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if !self.has_measures() && !self.alert_active && self.total_cost == 0.0 {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
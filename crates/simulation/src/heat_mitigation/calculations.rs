@@ -12,7 +12,7 @@ pub fn average_tree_coverage(tree_grid: &TreeGrid) -> f32 {
     if total == 0.0 {
         return 0.0;
     }
-    let count = tree_grid.cells.iter().filter(|&&has_tree| has_tree).count() as f32;
+    let count = tree_grid.tree_count() as f32;
     count / total
 }
 
@@ -0,0 +1,136 @@
+//! Decoding heightmap PNGs and writing them onto the world grid.
+
+use std::path::Path;
+
+use bevy::image::{CompressedImageFormats, Image, ImageSampler, ImageType};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::TextureFormat;
+
+use crate::config::WATER_THRESHOLD;
+use crate::grid::{CellType, WorldGrid};
+use crate::terrain_generation::{classify_biome, Biome, BiomeGrid};
+
+use super::types::*;
+
+/// Moisture used to classify imported land, which carries no moisture map.
+const IMPORTED_MOISTURE: f32 = 0.5;
+
+/// Read, decode and validate the heightmap PNG at `path`.
+pub fn read_heightmap_file(path: &Path) -> Result<Heightmap, HeightmapError> {
+    let bytes = std::fs::read(path).map_err(|e| HeightmapError::Io(e.to_string()))?;
+    decode_heightmap_png(&bytes)
+}
+
+/// Decode and validate a 16-bit grayscale PNG.
+pub fn decode_heightmap_png(bytes: &[u8]) -> Result<Heightmap, HeightmapError> {
+    let image = Image::from_buffer(
+        bytes,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        false,
+        ImageSampler::Default,
+        RenderAssetUsages::MAIN_WORLD,
+    )
+    .map_err(|e| HeightmapError::Decode(e.to_string()))?;
+
+    if image.texture_descriptor.format != TextureFormat::R16Unorm {
+        return Err(HeightmapError::NotGrayscale16);
+    }
+    let heightmap = Heightmap {
+        width: image.width(),
+        height: image.height(),
+        samples: image
+            .data
+            .chunks_exact(2)
+            .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+            .collect(),
+    };
+    heightmap.validate()?;
+    Ok(heightmap)
+}
+
+/// Normalized sea level of `heightmap`: a flat floor at the darkest value
+/// covering enough of the image is sea, otherwise the map is all land.
+pub fn detect_sea_level(heightmap: &Heightmap) -> f32 {
+    let Some(&min) = heightmap.samples.iter().min() else {
+        return 0.0;
+    };
+    let at_floor = heightmap.samples.iter().filter(|&&s| s == min).count();
+    if at_floor as f32 >= heightmap.samples.len() as f32 * MIN_SEA_FRACTION {
+        SEA_MARGIN
+    } else {
+        0.0
+    }
+}
+
+/// Map a normalized height onto grid elevation so that `sea_level` lands on
+/// the water threshold: the sea fills the range below it and the land the
+/// range above.
+pub fn remap_elevation(normalized: f32, sea_level: f32) -> f32 {
+    if normalized < sea_level {
+        normalized / sea_level * WATER_THRESHOLD
+    } else {
+        let land = (normalized - sea_level) / (1.0 - sea_level);
+        WATER_THRESHOLD + land * (1.0 - WATER_THRESHOLD)
+    }
+}
+
+/// Write `heightmap` onto `grid`: elevations, sea cells, biomes and the
+/// cells too steep to build on.
+pub fn apply_heightmap(
+    grid: &mut WorldGrid,
+    heightmap: &Heightmap,
+) -> (BiomeGrid, BuildabilityGrid) {
+    let (width, height) = (grid.width, grid.height);
+
+    let raw: Vec<f32> = (0..width * height)
+        .map(|i| heightmap.height_at(i % width, i / width, width, height))
+        .collect();
+    let min = raw.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = raw.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    let sea_level = detect_sea_level(heightmap);
+
+    for y in 0..height {
+        for x in 0..width {
+            let normalized = (raw[y * width + x] - min) / range;
+            let elevation = remap_elevation(normalized, sea_level);
+            let cell = grid.get_mut(x, y);
+            cell.elevation = elevation;
+            cell.cell_type = if elevation < WATER_THRESHOLD {
+                CellType::Water
+            } else {
+                CellType::Grass
+            };
+        }
+    }
+
+    let mut biomes = BiomeGrid {
+        biomes: vec![Biome::Grassland; width * height],
+        width,
+        height,
+    };
+    let mut buildability = BuildabilityGrid {
+        steep: vec![false; width * height],
+        width,
+    };
+    for y in 0..height {
+        for x in 0..width {
+            let cell = grid.get(x, y);
+            biomes.biomes[y * width + x] = classify_biome(cell.elevation, IMPORTED_MOISTURE);
+            if cell.cell_type == CellType::Water {
+                continue;
+            }
+            let (neighbors, count) = grid.neighbors4(x, y);
+            let slope = neighbors[..count]
+                .iter()
+                .map(|&(nx, ny)| grid.get(nx, ny))
+                .filter(|n| n.cell_type != CellType::Water)
+                .map(|n| (n.elevation - cell.elevation).abs())
+                .fold(0.0, f32::max);
+            buildability.steep[y * width + x] = slope > MAX_BUILDABLE_SLOPE;
+        }
+    }
+
+    (biomes, buildability)
+}
@@ -0,0 +1,32 @@
+//! Heightmap image import for new games.
+//!
+//! A 16-bit grayscale PNG can replace procedural terrain when a new game
+//! starts. The image is validated, resampled onto the grid and normalized:
+//! a flat floor at the darkest value is read as sea and flooded, the rest
+//! is stretched over the land elevation range. Cells whose slope is too
+//! steep to build on are recorded in [`BuildabilityGrid`], which zoning
+//! respects.
+
+pub mod import;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use import::{apply_heightmap, decode_heightmap_png, read_heightmap_file};
+pub use types::*;
+
+use bevy::prelude::*;
+
+pub struct HeightmapImportPlugin;
+
+impl Plugin for HeightmapImportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingHeightmap>()
+            .init_resource::<BuildabilityGrid>();
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<BuildabilityGrid>();
+    }
+}
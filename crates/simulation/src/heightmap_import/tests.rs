@@ -0,0 +1,138 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH, WATER_THRESHOLD};
+use crate::grid::{CellType, WorldGrid};
+use crate::Saveable;
+
+use super::import::{detect_sea_level, remap_elevation};
+
+/// A `size` x `size` heightmap rising from west to east, with the western
+/// `sea_columns` columns flat at zero.
+fn ramp(size: u32, sea_columns: u32) -> Heightmap {
+    let mut samples = Vec::new();
+    for _ in 0..size {
+        for x in 0..size {
+            let value = if x < sea_columns {
+                0
+            } else {
+                (x as f32 / (size - 1) as f32 * u16::MAX as f32) as u16
+            };
+            samples.push(value);
+        }
+    }
+    Heightmap {
+        width: size,
+        height: size,
+        samples,
+    }
+}
+
+#[test]
+fn test_validation_errors() {
+    assert_eq!(ramp(128, 0).validate(), Ok(()));
+    assert_eq!(ramp(32, 0).validate(), Err(HeightmapError::BadSize(32)));
+
+    let mut wide = ramp(64, 0);
+    wide.height = 32;
+    wide.samples.truncate(64 * 32);
+    assert_eq!(
+        wide.validate(),
+        Err(HeightmapError::NotSquare {
+            width: 64,
+            height: 32
+        })
+    );
+
+    let flat = Heightmap {
+        width: 64,
+        height: 64,
+        samples: vec![1000; 64 * 64],
+    };
+    assert_eq!(flat.validate(), Err(HeightmapError::Flat));
+}
+
+#[test]
+fn test_garbage_bytes_are_rejected() {
+    let err = decode_heightmap_png(b"not a png").unwrap_err();
+    assert!(matches!(err, HeightmapError::Decode(_)));
+    assert!(!err.to_string().is_empty());
+}
+
+#[test]
+fn test_flat_floor_is_read_as_sea() {
+    assert!(detect_sea_level(&ramp(128, 32)) > 0.0);
+    assert_eq!(detect_sea_level(&ramp(128, 0)), 0.0);
+}
+
+#[test]
+fn test_remap_puts_sea_level_on_water_threshold() {
+    assert_eq!(remap_elevation(0.0, 0.0), WATER_THRESHOLD);
+    assert_eq!(remap_elevation(1.0, 0.0), 1.0);
+    assert!(remap_elevation(0.0, SEA_MARGIN) < WATER_THRESHOLD);
+    assert!((remap_elevation(SEA_MARGIN, SEA_MARGIN) - WATER_THRESHOLD).abs() < 1e-6);
+}
+
+#[test]
+fn test_bilinear_sampling_spans_the_image() {
+    let map = ramp(64, 0);
+    let west = map.height_at(0, 10, GRID_WIDTH, GRID_HEIGHT);
+    let east = map.height_at(GRID_WIDTH - 1, 10, GRID_WIDTH, GRID_HEIGHT);
+    let middle = map.height_at(GRID_WIDTH / 2, 10, GRID_WIDTH, GRID_HEIGHT);
+    assert!(west < 0.01);
+    assert!(east > 0.99);
+    assert!(west < middle && middle < east);
+}
+
+#[test]
+fn test_apply_floods_the_sea_and_keeps_land_dry() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let (biomes, buildability) = apply_heightmap(&mut grid, &ramp(256, 64));
+
+    assert_eq!(grid.get(10, 100).cell_type, CellType::Water);
+    assert_eq!(grid.get(200, 100).cell_type, CellType::Grass);
+    assert!(grid.get(250, 100).elevation > grid.get(100, 100).elevation);
+    assert!(
+        buildability.is_buildable(200, 100),
+        "a gentle ramp is buildable"
+    );
+    assert_ne!(
+        biomes.get(200, 100),
+        crate::terrain_generation::Biome::DeepWater
+    );
+}
+
+#[test]
+fn test_cliffs_are_not_buildable() {
+    // A gently rising plain on the west half, a sheer plateau on the east.
+    let size = 128;
+    let samples = (0..size * size)
+        .map(|i| {
+            let x = i % size;
+            if x < size / 2 {
+                10_000 + x as u16 * 100
+            } else {
+                u16::MAX
+            }
+        })
+        .collect();
+    let cliff = Heightmap {
+        width: size,
+        height: size,
+        samples,
+    };
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let (_, buildability) = apply_heightmap(&mut grid, &cliff);
+
+    assert!(!buildability.is_buildable(GRID_WIDTH / 2, 50));
+    assert!(buildability.is_buildable(10, 50));
+    assert!(buildability.is_buildable(GRID_WIDTH - 10, 50));
+    assert!(buildability.steep_count() > 0);
+}
+
+#[test]
+fn test_buildability_saveable_roundtrip() {
+    assert!(BuildabilityGrid::default().save_to_bytes().is_none());
+    let mut grid = BuildabilityGrid::default();
+    grid.steep[42] = true;
+    let bytes = grid.save_to_bytes().expect("steep cells save");
+    assert_eq!(BuildabilityGrid::load_from_bytes(&bytes), grid);
+}
@@ -0,0 +1,180 @@
+//! Decoded heightmaps, import errors and the per-cell buildability grid.
+
+use std::fmt;
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Smallest heightmap accepted, in pixels per side.
+pub const MIN_HEIGHTMAP_SIZE: u32 = 64;
+
+/// Largest heightmap accepted, in pixels per side.
+pub const MAX_HEIGHTMAP_SIZE: u32 = 4096;
+
+/// Share of pixels that must sit at the darkest value for it to be read as
+/// a flat sea floor.
+pub const MIN_SEA_FRACTION: f32 = 0.01;
+
+/// Normalized height below which cells join the sea, when a sea floor is
+/// found.
+pub const SEA_MARGIN: f32 = 0.01;
+
+/// Largest elevation step to a neighbouring cell that can still be built
+/// on, in normalized elevation.
+pub const MAX_BUILDABLE_SLOPE: f32 = 0.04;
+
+// ---------------------------------------------------------------------------
+// Heightmap
+// ---------------------------------------------------------------------------
+
+/// A decoded 16-bit grayscale heightmap, row-major.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heightmap {
+    pub width: u32,
+    pub height: u32,
+    pub samples: Vec<u16>,
+}
+
+impl Heightmap {
+    /// Raw sample at pixel (`x`, `y`).
+    pub fn sample(&self, x: u32, y: u32) -> u16 {
+        self.samples[(y * self.width + x) as usize]
+    }
+
+    /// Bilinearly sampled height in [0, 1] at grid cell (`gx`, `gy`) of a
+    /// `grid_w` x `grid_h` grid.
+    pub fn height_at(&self, gx: usize, gy: usize, grid_w: usize, grid_h: usize) -> f32 {
+        let fx = (gx as f32 + 0.5) / grid_w as f32 * self.width as f32 - 0.5;
+        let fy = (gy as f32 + 0.5) / grid_h as f32 * self.height as f32 - 0.5;
+        let fx = fx.clamp(0.0, (self.width - 1) as f32);
+        let fy = fy.clamp(0.0, (self.height - 1) as f32);
+        let (x0, y0) = (fx.floor() as u32, fy.floor() as u32);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (tx, ty) = (fx - x0 as f32, fy - y0 as f32);
+
+        let s = |x, y| self.sample(x, y) as f32 / u16::MAX as f32;
+        let top = s(x0, y0) * (1.0 - tx) + s(x1, y0) * tx;
+        let bottom = s(x0, y1) * (1.0 - tx) + s(x1, y1) * tx;
+        top * (1.0 - ty) + bottom * ty
+    }
+
+    /// Check the image can be used as terrain.
+    pub fn validate(&self) -> Result<(), HeightmapError> {
+        if self.width != self.height {
+            return Err(HeightmapError::NotSquare {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        if !(MIN_HEIGHTMAP_SIZE..=MAX_HEIGHTMAP_SIZE).contains(&self.width) {
+            return Err(HeightmapError::BadSize(self.width));
+        }
+        let min = self.samples.iter().min().copied().unwrap_or(0);
+        let max = self.samples.iter().max().copied().unwrap_or(0);
+        if min == max {
+            return Err(HeightmapError::Flat);
+        }
+        Ok(())
+    }
+}
+
+/// Why a heightmap could not be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeightmapError {
+    /// The file could not be read.
+    Io(String),
+    /// The bytes are not a readable PNG.
+    Decode(String),
+    /// The PNG is not 16-bit grayscale.
+    NotGrayscale16,
+    NotSquare {
+        width: u32,
+        height: u32,
+    },
+    /// The side length is outside the accepted range.
+    BadSize(u32),
+    /// Every pixel has the same value, so there is no terrain.
+    Flat,
+}
+
+impl fmt::Display for HeightmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeightmapError::Io(msg) => write!(f, "Could not read the file: {msg}"),
+            HeightmapError::Decode(msg) => write!(f, "Not a valid PNG: {msg}"),
+            HeightmapError::NotGrayscale16 => {
+                write!(f, "The heightmap must be a 16-bit grayscale PNG")
+            }
+            HeightmapError::NotSquare { width, height } => {
+                write!(f, "The heightmap must be square (got {width}x{height})")
+            }
+            HeightmapError::BadSize(size) => write!(
+                f,
+                "The heightmap must be {MIN_HEIGHTMAP_SIZE} to {MAX_HEIGHTMAP_SIZE} pixels \
+                 wide (got {size})"
+            ),
+            HeightmapError::Flat => write!(f, "The heightmap is completely flat"),
+        }
+    }
+}
+
+impl std::error::Error for HeightmapError {}
+
+/// A validated heightmap waiting for the next new game to use it instead of
+/// procedural terrain.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PendingHeightmap(pub Option<Heightmap>);
+
+// ---------------------------------------------------------------------------
+// Buildability
+// ---------------------------------------------------------------------------
+
+/// Cells too steep to build on, set when terrain is imported.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct BuildabilityGrid {
+    /// Row-major; true where the slope rules out construction.
+    pub steep: Vec<bool>,
+    pub width: usize,
+}
+
+impl Default for BuildabilityGrid {
+    fn default() -> Self {
+        Self {
+            steep: vec![false; GRID_WIDTH * GRID_HEIGHT],
+            width: GRID_WIDTH,
+        }
+    }
+}
+
+impl BuildabilityGrid {
+    pub fn is_buildable(&self, x: usize, y: usize) -> bool {
+        !self.steep.get(y * self.width + x).copied().unwrap_or(false)
+    }
+
+    pub fn steep_count(&self) -> usize {
+        self.steep.iter().filter(|&&s| s).count()
+    }
+}
+
+impl Saveable for BuildabilityGrid {
+    const SAVE_KEY: &'static str = "buildability_grid";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if !self.steep.iter().any(|&s| s) {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -2,7 +2,9 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::buildings::{Building, UnderConstruction};
-use crate::citizen::{Citizen, CitizenDetails, HomeLocation};
+use crate::citizen::{Citizen, CitizenDetails, HomeLocation, Position};
+use crate::grid::WorldGrid;
+use crate::spatial_grid::SpatialGrid;
 use crate::TickCounter;
 
 // ---------------------------------------------------------------------------
@@ -122,12 +124,22 @@ pub fn check_homelessness(
 // System: seek_shelter
 // ---------------------------------------------------------------------------
 
-/// Homeless citizens attempt to find a shelter with available capacity.
+/// Homeless citizens attempt to find a shelter with available capacity,
+/// taking the nearest one that still has room.
 /// If sheltered, the ongoing happiness penalty is reduced from -30 to -10.
+#[allow(clippy::type_complexity)]
 pub fn seek_shelter(
     tick: Res<TickCounter>,
-    mut homeless_citizens: Query<(Entity, &mut Homeless, &mut CitizenDetails), With<Citizen>>,
-    mut shelters: Query<&mut HomelessShelter>,
+    mut homeless_citizens: Query<
+        (
+            Entity,
+            &mut Homeless,
+            &mut CitizenDetails,
+            Option<&Position>,
+        ),
+        With<Citizen>,
+    >,
+    mut shelters: Query<(Entity, &mut HomelessShelter)>,
     mut stats: ResMut<HomelessnessStats>,
 ) {
     if !tick.0.is_multiple_of(CHECK_INTERVAL) {
@@ -135,15 +147,21 @@ pub fn seek_shelter(
     }
 
     // Increment ticks_homeless for all homeless citizens
-    for (_entity, mut homeless, _details) in &mut homeless_citizens {
+    for (_entity, mut homeless, _details, _pos) in &mut homeless_citizens {
         homeless.ticks_homeless += 1;
     }
 
+    let mut shelter_index = SpatialGrid::default();
+    for (entity, shelter) in &shelters {
+        let (x, y) = WorldGrid::grid_to_world(shelter.grid_x, shelter.grid_y);
+        shelter_index.insert(entity, x, y);
+    }
+
     // Try to place unsheltered homeless citizens into shelters
     let mut sheltered_count = 0u32;
     let mut total_homeless = 0u32;
 
-    for (_entity, mut homeless, mut details) in &mut homeless_citizens {
+    for (_entity, mut homeless, mut details, pos) in &mut homeless_citizens {
         total_homeless += 1;
 
         if homeless.sheltered {
@@ -151,19 +169,25 @@ pub fn seek_shelter(
             continue;
         }
 
-        // Try to find a shelter with space
-        for mut shelter in &mut shelters {
-            if shelter.current_occupants < shelter.capacity {
-                shelter.current_occupants += 1;
-                homeless.sheltered = true;
+        // Citizens without a position take whichever shelter has room.
+        let (x, y) = pos.map_or((0.0, 0.0), |p| (p.x, p.y));
+        let nearest = shelter_index.nearest_matching(x, y, f32::INFINITY, |e| {
+            shelters
+                .get(e)
+                .is_ok_and(|(_, s)| s.current_occupants < s.capacity)
+        });
+        let Some((shelter_entity, _)) = nearest else {
+            continue;
+        };
+        if let Ok((_, mut shelter)) = shelters.get_mut(shelter_entity) {
+            shelter.current_occupants += 1;
+            homeless.sheltered = true;
 
-                // Restore some happiness (difference between unsheltered and sheltered penalty)
-                let restored = HOMELESS_PENALTY - SHELTERED_PENALTY;
-                details.happiness = (details.happiness + restored).min(100.0);
+            // Restore some happiness (difference between unsheltered and sheltered penalty)
+            let restored = HOMELESS_PENALTY - SHELTERED_PENALTY;
+            details.happiness = (details.happiness + restored).min(100.0);
 
-                sheltered_count += 1;
-                break;
-            }
+            sheltered_count += 1;
         }
     }
 
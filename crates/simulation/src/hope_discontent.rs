@@ -226,7 +226,7 @@ pub fn update_hope_discontent(
 
 /// Compute the average of a u8 grid, only counting non-zero cells to avoid
 /// dilution from empty wilderness. Returns 0.0 if no non-zero cells exist.
-fn compute_average_level(levels: &[u8]) -> f32 {
+fn compute_average_level<'a>(levels: impl IntoIterator<Item = &'a u8>) -> f32 {
     let mut sum: u64 = 0;
     let mut count: u64 = 0;
     for &v in levels {
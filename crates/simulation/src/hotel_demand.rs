@@ -4,10 +4,11 @@
 //! on city attractiveness (landmarks, services, parks, culture), and computes
 //! occupancy rates and hotel tax revenue.
 //!
-//! - Hotels have room capacity (50-500 rooms depending on commercial building level)
-//! - Tourist demand based on city attractiveness from the Tourism resource
-//! - Occupancy rate = demand / capacity
-//! - Revenue from hotel tax on occupied rooms
+//! - Hotels have room capacity (50-500 rooms depending on commercial building
+//!   level, 300 for a placed hotel); see `hotels`
+//! - Demand = rooms booked tonight plus room-nights turned away
+//! - Occupancy rate = booked rooms / capacity
+//! - Revenue from hotel tax on tonight's room revenue
 //! - Over-capacity (demand > capacity) = lost tourism revenue
 //! - Under-capacity (capacity >> demand) = wasted investment
 
@@ -15,10 +16,8 @@ use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
-use crate::buildings::Building;
-use crate::grid::ZoneType;
+use crate::hotels::{Hotel, HotelIndustry};
 use crate::services::{ServiceBuilding, ServiceType};
-use crate::tourism::Tourism;
 use crate::SlowTickTimer;
 
 // ---------------------------------------------------------------------------
@@ -26,19 +25,16 @@ use crate::SlowTickTimer;
 // ---------------------------------------------------------------------------
 
 /// Base nightly room rate used for revenue calculation.
-const BASE_ROOM_RATE: f64 = 120.0;
+pub const BASE_ROOM_RATE: f64 = 120.0;
 
 /// Default hotel tax rate (percentage of room revenue collected as tax).
 const DEFAULT_HOTEL_TAX_RATE: f32 = 0.12;
 
 /// Days per month for revenue calculation.
-const DAYS_PER_MONTH: f64 = 30.0;
-
-/// Average length of stay in nights per tourist visit.
-const AVG_STAY_NIGHTS: f64 = 3.0;
+pub const DAYS_PER_MONTH: f64 = 30.0;
 
 /// Number of tourists sharing a single hotel room on average.
-const TOURISTS_PER_ROOM: f64 = 1.8;
+pub const TOURISTS_PER_ROOM: f64 = 1.8;
 
 // ---------------------------------------------------------------------------
 // Hotel capacity by building level
@@ -57,6 +53,19 @@ pub fn hotel_rooms_for_level(level: u8) -> u32 {
     }
 }
 
+/// Room rate multiplier for an occupancy rate (higher occupancy = higher rates).
+pub fn occupancy_rate_multiplier(occupancy_rate: f32) -> f64 {
+    if occupancy_rate > 0.9 {
+        1.5 // high demand premium
+    } else if occupancy_rate > 0.7 {
+        1.2
+    } else if occupancy_rate > 0.5 {
+        1.0
+    } else {
+        0.8 // discount rates when low occupancy
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Attractiveness scoring
 // ---------------------------------------------------------------------------
@@ -145,16 +154,7 @@ impl HotelDemandState {
 
     /// Nightly room rate adjusted by occupancy (higher occupancy = higher rates).
     pub fn effective_room_rate(&self) -> f64 {
-        let occupancy_multiplier = if self.occupancy_rate > 0.9 {
-            1.5 // high demand premium
-        } else if self.occupancy_rate > 0.7 {
-            1.2
-        } else if self.occupancy_rate > 0.5 {
-            1.0
-        } else {
-            0.8 // discount rates when low occupancy
-        };
-        BASE_ROOM_RATE * occupancy_multiplier
+        BASE_ROOM_RATE * occupancy_rate_multiplier(self.occupancy_rate)
     }
 }
 
@@ -181,17 +181,13 @@ impl crate::Saveable for HotelDemandState {
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Calculate hotel capacity from commercial high-density buildings.
-/// All CommercialHigh buildings contribute hotel room capacity (large enough to
-/// accommodate visitors regardless of current occupancy).
-fn count_hotel_capacity(buildings: &Query<&Building>) -> (u32, u32) {
+/// Calculate hotel capacity from zoned and placed hotels.
+fn count_hotel_capacity(hotels: &Query<&Hotel>) -> (u32, u32) {
     let mut total_capacity = 0u32;
     let mut hotel_count = 0u32;
-    for building in buildings.iter() {
-        if building.zone_type == ZoneType::CommercialHigh {
-            total_capacity += hotel_rooms_for_level(building.level);
-            hotel_count += 1;
-        }
+    for hotel in hotels.iter() {
+        total_capacity += hotel.rooms;
+        hotel_count += 1;
     }
     (total_capacity, hotel_count)
 }
@@ -205,15 +201,6 @@ fn calculate_attractiveness(services: &Query<&ServiceBuilding>) -> f32 {
     score.min(100.0)
 }
 
-/// Convert monthly tourist visitors into nightly room demand.
-fn visitors_to_room_demand(monthly_visitors: u32) -> u32 {
-    // Monthly visitors stay AVG_STAY_NIGHTS nights, sharing TOURISTS_PER_ROOM per room.
-    // Demand = (visitors * avg_stay) / (days_per_month * tourists_per_room)
-    let room_nights = monthly_visitors as f64 * AVG_STAY_NIGHTS;
-    let nightly_demand = room_nights / (DAYS_PER_MONTH * TOURISTS_PER_ROOM);
-    nightly_demand as u32
-}
-
 /// Calculate lost revenue when demand exceeds capacity.
 fn calculate_lost_revenue(rooms_demanded: u32, total_capacity: u32, effective_rate: f64) -> f64 {
     if rooms_demanded > total_capacity {
@@ -239,11 +226,12 @@ fn calculate_wasted_investment(
     }
 }
 
-/// Main update system: runs on slow tick to recalculate hotel demand metrics.
+/// Main update system: runs on slow tick to recalculate hotel demand metrics
+/// from tonight's bookings.
 pub fn update_hotel_demand(
     slow_tick: Res<SlowTickTimer>,
-    tourism: Res<Tourism>,
-    buildings: Query<&Building>,
+    industry: Res<HotelIndustry>,
+    hotels: Query<&Hotel>,
     services: Query<&ServiceBuilding>,
     mut state: ResMut<HotelDemandState>,
 ) {
@@ -253,21 +241,21 @@ pub fn update_hotel_demand(
 
     state.last_update_tick = slow_tick.counter;
 
-    // 1. Count hotel capacity from commercial buildings
-    let (capacity, count) = count_hotel_capacity(&buildings);
+    // 1. Count hotel capacity from zoned and placed hotels
+    let (capacity, count) = count_hotel_capacity(&hotels);
     state.total_capacity = capacity;
     state.hotel_count = count;
 
     // 2. Calculate attractiveness from services
     state.attractiveness_score = calculate_attractiveness(&services);
 
-    // 3. Convert tourism visitors to room demand
-    state.rooms_demanded = visitors_to_room_demand(tourism.monthly_visitors);
+    // 3. Room demand: rooms booked tonight plus room-nights turned away
+    state.rooms_demanded = industry.rooms_booked + industry.turned_away;
 
     // 4. Calculate occupancy
     if state.total_capacity > 0 {
-        state.occupancy_rate = (state.rooms_demanded as f32 / state.total_capacity as f32).min(1.0);
-        state.rooms_occupied = state.rooms_demanded.min(state.total_capacity);
+        state.rooms_occupied = industry.rooms_booked.min(state.total_capacity);
+        state.occupancy_rate = state.rooms_occupied as f32 / state.total_capacity as f32;
     } else {
         state.occupancy_rate = 0.0;
         state.rooms_occupied = 0;
@@ -276,8 +264,8 @@ pub fn update_hotel_demand(
     // 5. Calculate effective room rate (adjusts with occupancy)
     let effective_rate = state.effective_room_rate();
 
-    // 6. Calculate monthly tax revenue from occupied rooms
-    let monthly_room_revenue = state.rooms_occupied as f64 * effective_rate * DAYS_PER_MONTH;
+    // 6. Calculate monthly tax revenue from tonight's room revenue
+    let monthly_room_revenue = industry.revenue_tonight * DAYS_PER_MONTH;
     state.monthly_tax_revenue = monthly_room_revenue * state.hotel_tax_rate as f64;
 
     // 7. Calculate lost revenue and wasted investment
@@ -343,14 +331,11 @@ mod tests {
     }
 
     #[test]
-    fn test_visitors_to_room_demand() {
-        // 1000 visitors * 3 nights / (30 days * 1.8 per room) ~= 55
-        let demand = visitors_to_room_demand(1000);
-        assert!(demand > 0);
-        assert!(demand < 100);
-
-        // Zero visitors = zero demand
-        assert_eq!(visitors_to_room_demand(0), 0);
+    fn test_occupancy_rate_multiplier_tiers() {
+        assert_eq!(occupancy_rate_multiplier(0.95), 1.5);
+        assert_eq!(occupancy_rate_multiplier(0.8), 1.2);
+        assert_eq!(occupancy_rate_multiplier(0.6), 1.0);
+        assert_eq!(occupancy_rate_multiplier(0.0), 0.8);
     }
 
     #[test]
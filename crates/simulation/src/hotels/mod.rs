@@ -0,0 +1,26 @@
+//! Hotels and tourist accommodation.
+//!
+//! Every high-density commercial building rents rooms as a zoned hotel, and
+//! the Hotel service places a larger full-service one. Each game night
+//! guests check out, and arriving tourists book rooms: leisure parties (from
+//! `Tourism` visitors and stay length) take the cheapest free room, while
+//! convention attendees book blocks at the hotels nearest their convention
+//! center. Every hotel reprices from its own occupancy for the next night.
+//!
+//! Convention centers announce a convention every two weeks, drawing
+//! attendees by city attractiveness and air access. Room revenue and
+//! convention attendees are accounted per tourism month and folded into
+//! `Tourism` as hotel tax and visitors; `HotelDemandState` reports capacity,
+//! bookings and turned-away demand, and tourists turned away from full
+//! hotels raise commercial demand.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    close_hotel_month, hotel_shortage_demand, run_hotel_nights, sync_placed_hotels,
+    sync_zoned_hotels, HotelsPlugin,
+};
+pub use types::*;
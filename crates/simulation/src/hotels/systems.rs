@@ -0,0 +1,140 @@
+//! Hotel syncing, nightly bookings, the hotel month and the demand response
+//! to a hotel shortage.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::grid::ZoneType;
+use crate::hotel_demand::hotel_rooms_for_level;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::time_of_day::GameClock;
+use crate::tourism::Tourism;
+use crate::zones::ZoneDemand;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Attach a `Hotel` to every high-density commercial building and keep its
+/// rooms in step with the building's level.
+pub fn sync_zoned_hotels(
+    mut commands: Commands,
+    mut buildings: Query<(Entity, &Building, Option<&mut Hotel>), Changed<Building>>,
+) {
+    for (entity, building, hotel) in &mut buildings {
+        if building.zone_type != ZoneType::CommercialHigh {
+            continue;
+        }
+        match hotel {
+            Some(mut hotel) => {
+                let rooms = hotel_rooms_for_level(building.level);
+                if hotel.rooms != rooms {
+                    hotel.rooms = rooms;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(Hotel::zoned(
+                    building.grid_x,
+                    building.grid_y,
+                    building.level,
+                ));
+            }
+        }
+    }
+}
+
+/// Attach a `Hotel` to newly placed hotel service buildings.
+pub fn sync_placed_hotels(
+    mut commands: Commands,
+    services: Query<(Entity, &ServiceBuilding), Added<ServiceBuilding>>,
+) {
+    for (entity, service) in &services {
+        if service.service_type == ServiceType::Hotel {
+            commands
+                .entity(entity)
+                .insert(Hotel::placed(service.grid_x, service.grid_y));
+        }
+    }
+}
+
+/// Close the hotel month on the tick `update_tourism` starts a new tourism
+/// month, so it reads the month that just ended.
+pub fn close_hotel_month(
+    clock: Res<GameClock>,
+    tourism: Res<Tourism>,
+    mut hotels: Query<&mut Hotel>,
+    mut industry: ResMut<HotelIndustry>,
+) {
+    if !tourism.update_due(clock.day) {
+        return;
+    }
+    let mut hotels: Vec<&mut Hotel> = hotels.iter_mut().map(Mut::into_inner).collect();
+    industry.close_month(&mut hotels);
+}
+
+/// Once per game day: announce conventions at convention centers and run the
+/// night's check-outs and bookings.
+pub fn run_hotel_nights(
+    clock: Res<GameClock>,
+    tourism: Res<Tourism>,
+    services: Query<&ServiceBuilding>,
+    mut hotels: Query<&mut Hotel>,
+    mut industry: ResMut<HotelIndustry>,
+) {
+    if clock.day == industry.last_night {
+        return;
+    }
+    let venues: Vec<(u16, u16)> = services
+        .iter()
+        .filter(|s| s.service_type == ServiceType::ConventionCenter)
+        .map(|s| (s.grid_x as u16, s.grid_y as u16))
+        .collect();
+    let attendees = convention_attendees(tourism.attractiveness, tourism.airport_multiplier);
+    industry.schedule_conventions(clock.day, &venues, attendees);
+
+    let leisure = LeisureDemand::from_tourism(&tourism, industry.last_month.convention_attendees);
+    let mut hotels: Vec<&mut Hotel> = hotels.iter_mut().map(Mut::into_inner).collect();
+    industry.run_night(clock.day, &mut hotels, leisure);
+}
+
+/// Tourists turned away from full hotels raise commercial demand, so more
+/// high-density commercial buildings, and with them hotel rooms, get built.
+pub fn hotel_shortage_demand(
+    slow_timer: Res<SlowTickTimer>,
+    industry: Res<HotelIndustry>,
+    mut demand: ResMut<ZoneDemand>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let bonus = industry.shortage_demand();
+    if bonus > 0.0 {
+        demand.commercial = (demand.commercial + bonus).min(1.0);
+    }
+}
+
+pub struct HotelsPlugin;
+
+impl Plugin for HotelsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HotelIndustry>().add_systems(
+            FixedUpdate,
+            (
+                (sync_zoned_hotels, sync_placed_hotels),
+                close_hotel_month.before(crate::tourism::update_tourism),
+                run_hotel_nights
+                    .after(crate::tourism::update_tourism)
+                    .before(crate::hotel_demand::update_hotel_demand),
+                hotel_shortage_demand
+                    .after(crate::zones::update_zone_demand)
+                    .after(crate::hotel_demand::update_hotel_demand),
+            )
+                .chain()
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<HotelIndustry>();
+    }
+}
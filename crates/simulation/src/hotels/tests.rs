@@ -0,0 +1,213 @@
+use std::collections::BTreeSet;
+
+use super::*;
+use crate::hotel_demand::{
+    hotel_rooms_for_level, BASE_ROOM_RATE, DAYS_PER_MONTH, TOURISTS_PER_ROOM,
+};
+use crate::tourism::Tourism;
+use crate::Saveable;
+
+fn leisure(rooms_per_night: f64, stay: u8) -> LeisureDemand {
+    LeisureDemand {
+        rooms_per_night,
+        stay,
+    }
+}
+
+fn night(industry: &mut HotelIndustry, day: u32, hotels: &mut [Hotel], demand: LeisureDemand) {
+    let mut refs: Vec<&mut Hotel> = hotels.iter_mut().collect();
+    industry.run_night(day, &mut refs, demand);
+}
+
+#[test]
+fn test_leisure_books_cheapest_rooms_and_turns_away_the_rest() {
+    let mut hotels = vec![Hotel::placed(10, 10), Hotel::zoned(20, 20, 1)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(400.0, 2));
+
+    // The zoned hotel is cheaper and fills first.
+    assert_eq!(hotels[1].booked, 50);
+    assert_eq!(hotels[0].booked, 300);
+    assert_eq!(industry.rooms_booked, 350);
+    assert_eq!(industry.turned_away, 50 * 2);
+    assert!(industry.revenue_tonight > 0.0);
+    assert_eq!(industry.current_month.room_nights, 350);
+}
+
+#[test]
+fn test_guests_check_out_after_their_stay() {
+    let mut hotels = vec![Hotel::zoned(5, 5, 2)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(30.0, 2));
+    assert_eq!(hotels[0].booked, 30);
+    night(&mut industry, 2, &mut hotels, leisure(0.0, 2));
+    assert_eq!(hotels[0].booked, 30, "second night of the stay");
+    night(&mut industry, 3, &mut hotels, leisure(0.0, 2));
+    assert_eq!(hotels[0].booked, 0);
+    assert!(industry.bookings.is_empty());
+}
+
+#[test]
+fn test_fractional_arrivals_carry_over() {
+    let mut hotels = vec![Hotel::zoned(5, 5, 1)];
+    let mut industry = HotelIndustry::default();
+    for day in 1..=4 {
+        night(&mut industry, day, &mut hotels, leisure(0.5, 5));
+    }
+    assert_eq!(hotels[0].booked, 2);
+}
+
+#[test]
+fn test_rates_follow_occupancy() {
+    let mut hotels = vec![Hotel::zoned(5, 5, 1)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(48.0, 3));
+    assert!((hotels[0].nightly_rate - BASE_ROOM_RATE * 1.5).abs() < 1e-9);
+    night(&mut industry, 2, &mut hotels, leisure(0.0, 3));
+    night(&mut industry, 3, &mut hotels, leisure(0.0, 3));
+    night(&mut industry, 4, &mut hotels, leisure(0.0, 3));
+    assert_eq!(hotels[0].booked, 0);
+    assert!((hotels[0].nightly_rate - BASE_ROOM_RATE * 0.8).abs() < 1e-9);
+}
+
+#[test]
+fn test_closed_or_shrunk_hotels_lose_guests() {
+    let mut hotels = vec![Hotel::zoned(5, 5, 2), Hotel::zoned(9, 9, 2)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(240.0, 5));
+    assert_eq!(industry.rooms_booked, 240);
+
+    hotels[0].rooms = hotel_rooms_for_level(1);
+    hotels.pop();
+    night(&mut industry, 2, &mut hotels, leisure(0.0, 5));
+    assert_eq!(hotels[0].booked, 50);
+    assert_eq!(industry.rooms_booked, 50);
+}
+
+#[test]
+fn test_convention_attendees_book_near_the_venue() {
+    let mut hotels = vec![
+        Hotel::zoned(100, 100, 5),
+        Hotel::zoned(12, 10, 1),
+        Hotel::zoned(30, 30, 2),
+    ];
+    let mut industry = HotelIndustry::default();
+    industry.conventions.push(Convention {
+        venue: (10, 10),
+        attendees: 120,
+        start_day: 4,
+        days: 2,
+    });
+    night(&mut industry, 4, &mut hotels, LeisureDemand::default());
+
+    // 120 attendees need 100 rooms: the nearest hotel fills, the next takes
+    // the rest and the far one stays empty.
+    assert_eq!(hotels[1].booked, 50);
+    assert_eq!(hotels[2].booked, 50);
+    assert_eq!(hotels[0].booked, 0);
+    assert_eq!(industry.current_month.convention_attendees, 120);
+    assert!(industry.bookings.iter().all(|b| b.convention));
+}
+
+#[test]
+fn test_convention_overflow_is_turned_away() {
+    let mut hotels = vec![Hotel::zoned(12, 10, 1)];
+    let mut industry = HotelIndustry::default();
+    industry.conventions.push(Convention {
+        venue: (10, 10),
+        attendees: 1200,
+        start_day: 4,
+        days: 3,
+    });
+    night(&mut industry, 4, &mut hotels, LeisureDemand::default());
+    assert_eq!(industry.rooms_booked, 50);
+    assert_eq!(industry.turned_away, (1000 - 50) * 3);
+    assert_eq!(industry.current_month.convention_attendees, 60);
+    assert!(industry.shortage_demand() > 0.0);
+}
+
+#[test]
+fn test_conventions_are_announced_once_per_interval() {
+    let mut industry = HotelIndustry::default();
+    let mut starts = BTreeSet::new();
+    for day in 1..=CONVENTION_INTERVAL_DAYS * 3 {
+        industry.schedule_conventions(day, &[(7, 3)], 900);
+        industry.schedule_conventions(day, &[(7, 3)], 900);
+        starts.extend(industry.conventions.iter().map(|c| c.start_day));
+    }
+    let starts: Vec<u32> = starts.into_iter().collect();
+    assert_eq!(starts.len(), 3);
+    assert!(starts
+        .windows(2)
+        .all(|w| w[1] - w[0] == CONVENTION_INTERVAL_DAYS));
+    assert_eq!(industry.conventions.len(), 1, "finished ones are dropped");
+    let convention = industry.conventions[0];
+    assert_eq!(convention.attendees, 900);
+    assert_eq!(convention.days, CONVENTION_DAYS);
+}
+
+#[test]
+fn test_finished_conventions_are_dropped() {
+    let mut industry = HotelIndustry::default();
+    industry.conventions.push(Convention {
+        venue: (1, 1),
+        attendees: 100,
+        start_day: 2,
+        days: 3,
+    });
+    industry.schedule_conventions(4, &[], 100);
+    assert_eq!(industry.conventions.len(), 1);
+    industry.schedule_conventions(5, &[], 100);
+    assert!(industry.conventions.is_empty());
+}
+
+#[test]
+fn test_convention_attendees_scale_with_attractiveness_and_flights() {
+    assert!(convention_attendees(90.0, 1.0) > convention_attendees(10.0, 1.0));
+    assert!(convention_attendees(50.0, 2.0) > convention_attendees(50.0, 1.0));
+    assert_eq!(
+        convention_attendees(50.0, 1.0),
+        BASE_CONVENTION_ATTENDEES as u32
+    );
+}
+
+#[test]
+fn test_leisure_demand_excludes_convention_visitors() {
+    let tourism = Tourism {
+        monthly_visitors: 5400 + 600,
+        average_stay_days: 7.6,
+        ..Default::default()
+    };
+    let demand = LeisureDemand::from_tourism(&tourism, 600);
+    let expected = 5400.0 / (DAYS_PER_MONTH * TOURISTS_PER_ROOM);
+    assert!((demand.rooms_per_night - expected).abs() < 1e-9);
+    assert_eq!(demand.stay, MAX_LEISURE_STAY);
+}
+
+#[test]
+fn test_close_month_rolls_totals() {
+    let mut hotels = vec![Hotel::zoned(5, 5, 1)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(10.0, 1));
+    let revenue = industry.current_month.room_revenue;
+    assert!(revenue > 0.0);
+    assert_eq!(hotels[0].month_revenue, revenue);
+
+    let mut refs: Vec<&mut Hotel> = hotels.iter_mut().collect();
+    industry.close_month(&mut refs);
+    assert_eq!(industry.last_month.room_revenue, revenue);
+    assert_eq!(industry.current_month, HotelMonth::default());
+    assert_eq!(hotels[0].month_revenue, 0.0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(HotelIndustry::default().save_to_bytes().is_none());
+
+    let mut hotels = vec![Hotel::zoned(5, 5, 1)];
+    let mut industry = HotelIndustry::default();
+    night(&mut industry, 1, &mut hotels, leisure(12.5, 3));
+    let bytes = industry.save_to_bytes().expect("bookings should be saved");
+    assert_eq!(HotelIndustry::load_from_bytes(&bytes), industry);
+    assert_eq!(HotelIndustry::SAVE_KEY, "hotel_industry");
+}
@@ -0,0 +1,394 @@
+//! Hotels, room bookings, conventions and the monthly hotel accounts.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::hotel_demand::{
+    hotel_rooms_for_level, occupancy_rate_multiplier, BASE_ROOM_RATE, DAYS_PER_MONTH,
+    TOURISTS_PER_ROOM,
+};
+use crate::tourism::Tourism;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Rooms in a placed full-service hotel.
+pub const PLACED_HOTEL_ROOMS: u32 = 300;
+
+/// Base rate of a placed hotel relative to `BASE_ROOM_RATE`.
+pub const PLACED_HOTEL_RATE_FACTOR: f64 = 1.4;
+
+/// Longest leisure stay in nights.
+pub const MAX_LEISURE_STAY: u8 = 5;
+
+/// Days between conventions at one convention center.
+pub const CONVENTION_INTERVAL_DAYS: u32 = 14;
+
+/// Days between a convention being announced and its opening night.
+pub const CONVENTION_LEAD_DAYS: u32 = 3;
+
+/// Length of a convention in days; attendees stay one night per day.
+pub const CONVENTION_DAYS: u8 = 3;
+
+/// Attendees at a convention in a city with attractiveness 50.
+pub const BASE_CONVENTION_ATTENDEES: f32 = 1500.0;
+
+/// Convention attendees per booked room (most travel alone).
+pub const ATTENDEES_PER_ROOM: f64 = 1.2;
+
+/// Commercial demand added per update when tourists are turned away from
+/// every hotel in the city.
+pub const MAX_SHORTAGE_DEMAND: f32 = 0.05;
+
+// ---------------------------------------------------------------------------
+// Hotel component
+// ---------------------------------------------------------------------------
+
+/// Rooms for rent on a building: a zoned high-density commercial building or
+/// a placed `ServiceType::Hotel`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Hotel {
+    pub grid_x: usize,
+    pub grid_y: usize,
+    pub rooms: u32,
+    /// Rooms booked tonight.
+    pub booked: u32,
+    /// Nightly rate at 50-70% occupancy.
+    pub base_rate: f64,
+    /// Tonight's rate: `base_rate` scaled by last night's occupancy.
+    pub nightly_rate: f64,
+    /// Room revenue since the hotel month started.
+    pub month_revenue: f64,
+}
+
+impl Hotel {
+    pub fn new(grid_x: usize, grid_y: usize, rooms: u32, base_rate: f64) -> Self {
+        Self {
+            grid_x,
+            grid_y,
+            rooms,
+            booked: 0,
+            base_rate,
+            nightly_rate: base_rate * occupancy_rate_multiplier(0.0),
+            month_revenue: 0.0,
+        }
+    }
+
+    /// A hotel on a high-density commercial building of `level`.
+    pub fn zoned(grid_x: usize, grid_y: usize, level: u8) -> Self {
+        Self::new(grid_x, grid_y, hotel_rooms_for_level(level), BASE_ROOM_RATE)
+    }
+
+    /// A placed full-service hotel.
+    pub fn placed(grid_x: usize, grid_y: usize) -> Self {
+        Self::new(
+            grid_x,
+            grid_y,
+            PLACED_HOTEL_ROOMS,
+            BASE_ROOM_RATE * PLACED_HOTEL_RATE_FACTOR,
+        )
+    }
+
+    /// The cell bookings refer to this hotel by.
+    pub fn cell(&self) -> (u16, u16) {
+        (self.grid_x as u16, self.grid_y as u16)
+    }
+
+    pub fn vacancies(&self) -> u32 {
+        self.rooms.saturating_sub(self.booked)
+    }
+
+    /// Booked rooms over rooms; 0 for a hotel without rooms.
+    pub fn occupancy(&self) -> f32 {
+        if self.rooms > 0 {
+            self.booked as f32 / self.rooms as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Set tomorrow's rate from tonight's occupancy.
+    pub fn reprice(&mut self) {
+        self.nightly_rate = self.base_rate * occupancy_rate_multiplier(self.occupancy());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bookings and conventions
+// ---------------------------------------------------------------------------
+
+/// Rooms held at one hotel by guests who arrived on the same night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct Booking {
+    /// Cell of the booked hotel.
+    pub hotel: (u16, u16),
+    pub rooms: u32,
+    /// Nights still to stay, including tonight.
+    pub nights_left: u8,
+    /// Booked by convention attendees rather than leisure tourists.
+    pub convention: bool,
+}
+
+/// A convention announced at a convention center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct Convention {
+    /// Cell of the convention center.
+    pub venue: (u16, u16),
+    pub attendees: u32,
+    /// Day attendees arrive.
+    pub start_day: u32,
+    pub days: u8,
+}
+
+impl Convention {
+    /// Whether the convention has ended by `day`.
+    pub fn is_over(&self, day: u32) -> bool {
+        day >= self.start_day + self.days as u32
+    }
+}
+
+/// Attendees a convention draws, scaled by city attractiveness and flights.
+pub fn convention_attendees(attractiveness: f32, airport_multiplier: f32) -> u32 {
+    let draw = 0.5 + attractiveness.clamp(0.0, 100.0) / 100.0;
+    (BASE_CONVENTION_ATTENDEES * draw * airport_multiplier.max(0.0)) as u32
+}
+
+/// Offset that staggers conventions at different venues.
+fn venue_stagger(venue: (u16, u16)) -> u32 {
+    (venue.0 as u32 * 31 + venue.1 as u32 * 17) % CONVENTION_INTERVAL_DAYS
+}
+
+/// Leisure arrivals for one night.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LeisureDemand {
+    /// Rooms arriving per night; fractions carry over to the next night.
+    pub rooms_per_night: f64,
+    /// Nights each party stays.
+    pub stay: u8,
+}
+
+impl LeisureDemand {
+    /// Leisure demand from the month's tourism, excluding the convention
+    /// attendees `update_tourism` counted as visitors.
+    pub fn from_tourism(tourism: &Tourism, convention_visitors: u32) -> Self {
+        let leisure = tourism.monthly_visitors.saturating_sub(convention_visitors);
+        Self {
+            rooms_per_night: leisure as f64 / (DAYS_PER_MONTH * TOURISTS_PER_ROOM),
+            stay: (tourism.average_stay_days.round() as u8).clamp(1, MAX_LEISURE_STAY),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Hotel totals for one tourism month.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HotelMonth {
+    /// Room-nights sold.
+    pub room_nights: u64,
+    /// Room-nights asked for that no hotel had free.
+    pub turned_away_room_nights: u64,
+    pub room_revenue: f64,
+    /// Convention attendees who found a room.
+    pub convention_attendees: u32,
+}
+
+/// City-wide hotel bookings, announced conventions and hotel accounts.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HotelIndustry {
+    pub bookings: Vec<Booking>,
+    pub conventions: Vec<Convention>,
+    /// Day of the last processed night.
+    pub last_night: u32,
+    /// Fractional leisure rooms not yet arrived.
+    pub arrival_carry: f64,
+    /// Rooms booked tonight across all hotels.
+    pub rooms_booked: u32,
+    /// Room-nights turned away tonight.
+    pub turned_away: u32,
+    /// Room revenue tonight.
+    pub revenue_tonight: f64,
+    pub current_month: HotelMonth,
+    /// The month `update_tourism` last folded into `Tourism`.
+    pub last_month: HotelMonth,
+}
+
+impl HotelIndustry {
+    /// Close the hotel month; called as the tourism month rolls over.
+    pub fn close_month(&mut self, hotels: &mut [&mut Hotel]) {
+        self.last_month = std::mem::take(&mut self.current_month);
+        for hotel in hotels.iter_mut() {
+            hotel.month_revenue = 0.0;
+        }
+    }
+
+    /// Drop finished conventions and announce the next one at each venue
+    /// whose turn comes up on `day`.
+    pub fn schedule_conventions(&mut self, day: u32, venues: &[(u16, u16)], attendees: u32) {
+        self.conventions.retain(|c| !c.is_over(day));
+        for &venue in venues {
+            if (day + venue_stagger(venue)) % CONVENTION_INTERVAL_DAYS != 0 {
+                continue;
+            }
+            let announced = self
+                .conventions
+                .iter()
+                .any(|c| c.venue == venue && c.start_day > day);
+            if announced || attendees == 0 {
+                continue;
+            }
+            self.conventions.push(Convention {
+                venue,
+                attendees,
+                start_day: day + CONVENTION_LEAD_DAYS,
+                days: CONVENTION_DAYS,
+            });
+        }
+    }
+
+    /// Run night `day`: check guests out, book tonight's arrivals, collect
+    /// room revenue and reprice every hotel for tomorrow.
+    ///
+    /// Convention attendees book blocks at the hotels nearest their venue;
+    /// leisure tourists take the cheapest free room. Guests of a hotel that
+    /// closed or shrank lose their rooms.
+    pub fn run_night(&mut self, day: u32, hotels: &mut [&mut Hotel], leisure: LeisureDemand) {
+        self.last_night = day;
+        let index: HashMap<(u16, u16), usize> = hotels
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (h.cell(), i))
+            .collect();
+        for hotel in hotels.iter_mut() {
+            hotel.booked = 0;
+        }
+
+        self.bookings.retain_mut(|booking| {
+            let Some(&i) = index.get(&booking.hotel) else {
+                return false;
+            };
+            if booking.nights_left == 0 {
+                return false;
+            }
+            booking.rooms = booking.rooms.min(hotels[i].vacancies());
+            hotels[i].booked += booking.rooms;
+            booking.rooms > 0
+        });
+
+        self.turned_away = 0;
+        let opening: Vec<Convention> = self
+            .conventions
+            .iter()
+            .filter(|c| c.start_day == day)
+            .copied()
+            .collect();
+        for convention in opening {
+            let (vx, vy) = (convention.venue.0 as i64, convention.venue.1 as i64);
+            let mut order: Vec<usize> = (0..hotels.len()).collect();
+            order.sort_by_key(|&i| {
+                let (dx, dy) = (hotels[i].grid_x as i64 - vx, hotels[i].grid_y as i64 - vy);
+                (dx * dx + dy * dy, hotels[i].cell())
+            });
+            let wanted = (convention.attendees as f64 / ATTENDEES_PER_ROOM).ceil() as u32;
+            let booked = self.book(hotels, &order, wanted, convention.days, true);
+            let housed = (booked as f64 * ATTENDEES_PER_ROOM) as u32;
+            self.current_month.convention_attendees += housed.min(convention.attendees);
+            self.turned_away += (wanted - booked) * convention.days as u32;
+        }
+
+        self.arrival_carry += leisure.rooms_per_night;
+        let wanted = self.arrival_carry.floor();
+        self.arrival_carry -= wanted;
+        let wanted = wanted as u32;
+        let mut order: Vec<usize> = (0..hotels.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&hotels[a], &hotels[b]);
+            a.nightly_rate
+                .total_cmp(&b.nightly_rate)
+                .then(a.cell().cmp(&b.cell()))
+        });
+        let booked = self.book(hotels, &order, wanted, leisure.stay, false);
+        self.turned_away += (wanted - booked) * leisure.stay as u32;
+
+        self.rooms_booked = 0;
+        self.revenue_tonight = 0.0;
+        for hotel in hotels.iter_mut() {
+            let revenue = hotel.booked as f64 * hotel.nightly_rate;
+            hotel.month_revenue += revenue;
+            self.revenue_tonight += revenue;
+            self.rooms_booked += hotel.booked;
+            hotel.reprice();
+        }
+        for booking in &mut self.bookings {
+            booking.nights_left = booking.nights_left.saturating_sub(1);
+        }
+
+        let month = &mut self.current_month;
+        month.room_nights += self.rooms_booked as u64;
+        month.turned_away_room_nights += self.turned_away as u64;
+        month.room_revenue += self.revenue_tonight;
+    }
+
+    /// Book up to `rooms` rooms for `nights` nights, filling hotels in
+    /// `order`. Returns the rooms booked.
+    fn book(
+        &mut self,
+        hotels: &mut [&mut Hotel],
+        order: &[usize],
+        rooms: u32,
+        nights: u8,
+        convention: bool,
+    ) -> u32 {
+        let mut remaining = rooms;
+        for &i in order {
+            if remaining == 0 {
+                break;
+            }
+            let taken = remaining.min(hotels[i].vacancies());
+            if taken == 0 {
+                continue;
+            }
+            hotels[i].booked += taken;
+            remaining -= taken;
+            self.bookings.push(Booking {
+                hotel: hotels[i].cell(),
+                rooms: taken,
+                nights_left: nights,
+                convention,
+            });
+        }
+        rooms - remaining
+    }
+
+    /// Commercial demand added for tourists no hotel could take: scales with
+    /// turned-away room-nights relative to rooms booked.
+    pub fn shortage_demand(&self) -> f32 {
+        if self.turned_away == 0 {
+            return 0.0;
+        }
+        let shortage = self.turned_away as f32 / self.rooms_booked.max(1) as f32;
+        shortage.min(1.0) * MAX_SHORTAGE_DEMAND
+    }
+}
+
+impl Saveable for HotelIndustry {
+    const SAVE_KEY: &'static str = "hotel_industry";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
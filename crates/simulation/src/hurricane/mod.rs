@@ -0,0 +1,19 @@
+//! Hurricanes and windstorms that cross the map on a tracked path.
+//!
+//! A storm forms with a small daily chance (higher in tropical climates) and
+//! is forecast a few days before landfall, when the player sees its projected
+//! track as a cone that widens with lead time. After landfall its center
+//! moves in a straight line across the map. The wind field around it is calm
+//! in the eye, strongest in the eyewall and tapers towards the edge; it
+//! spirals counterclockwise and sets the city-wide `WindState`, so the
+//! regular wind damage model responds too. Along the path the storm knocks
+//! down trees, brings down power line segments until crews repair them, and
+//! tears roofs off buildings, which lose a level.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{advance_hurricane, cut_downed_lines, forecast_hurricanes, HurricanePlugin};
+pub use types::*;
@@ -0,0 +1,224 @@
+//! Hurricane formation, movement, wind field and path damage.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::disaster_insurance::{property_value, DisasterDamage, DisasterKind};
+use crate::game_settings::GameSettings;
+use crate::grid::WorldGrid;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::power_lines::PowerLineGrid;
+use crate::time_of_day::GameClock;
+use crate::trees::TreeGrid;
+use crate::weather::{ClimateZone, Weather};
+use crate::wind::WindState;
+use crate::wind_damage::types::{rand_f32, splitmix64};
+use crate::wind_damage::{
+    power_outage_probability, tree_knockdown_probability, wind_damage_amount,
+};
+use crate::{SlowTickTimer, TestSafetyNet, TickCounter};
+
+use super::types::*;
+
+/// Once a day, roll for a new hurricane. A storm that forms is forecast
+/// `FORECAST_LEAD_DAYS` before it makes landfall.
+#[allow(clippy::too_many_arguments)]
+pub fn forecast_hurricanes(
+    clock: Res<GameClock>,
+    tick: Res<TickCounter>,
+    weather: Res<Weather>,
+    climate: Res<ClimateZone>,
+    settings: Res<GameSettings>,
+    safety_net: Option<Res<TestSafetyNet>>,
+    mut state: ResMut<HurricaneState>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= state.last_roll_day {
+        return;
+    }
+    state.last_roll_day = clock.day;
+    if safety_net.is_some() || !weather.disasters_enabled || state.storm.is_some() {
+        return;
+    }
+
+    let seed = splitmix64(tick.0 ^ 0x4a11_ca7e);
+    let chance =
+        HURRICANE_CHANCE_PER_DAY * climate_factor(*climate) * settings.disaster_chance_multiplier();
+    if rand_f32(seed) >= chance {
+        return;
+    }
+
+    let track = StormTrack::generate(seed, clock.day + FORECAST_LEAD_DAYS);
+    notifications.send(NotificationEvent {
+        text: format!(
+            "Hurricane forecast: a Category {} storm is expected to make landfall in {} days",
+            track.category, FORECAST_LEAD_DAYS
+        ),
+        priority: NotificationPriority::Warning,
+        category: NotificationCategory::Disaster,
+        location: None,
+    });
+    state.storm = Some(track);
+}
+
+/// Every slow tick while a hurricane crosses the map: drive the global wind
+/// from the storm's wind field and damage trees, power lines and roofs under
+/// it. Downed lines are repaired after `LINE_REPAIR_DAYS`.
+#[allow(clippy::too_many_arguments)]
+pub fn advance_hurricane(
+    slow_timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    tick: Res<TickCounter>,
+    grid: Res<WorldGrid>,
+    mut state: ResMut<HurricaneState>,
+    mut wind: ResMut<WindState>,
+    mut trees: ResMut<TreeGrid>,
+    power_lines: Res<PowerLineGrid>,
+    mut buildings: Query<&mut Building>,
+    mut notifications: EventWriter<NotificationEvent>,
+    mut damage: EventWriter<DisasterDamage>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let now = day_fraction(clock.day, clock.hour);
+    state.downed_lines.retain(|d| clock.day < d.repair_day);
+
+    let Some(track) = state.storm.clone() else {
+        return;
+    };
+    if now < track.landfall_day as f32 {
+        return;
+    }
+    if track.has_passed(now) {
+        let mut report = std::mem::take(&mut state.current);
+        report.category = track.category;
+        info!(
+            "Hurricane passed: {} trees down, {} power lines down, {} roofs damaged",
+            report.trees_down, report.lines_down, report.roofs_damaged
+        );
+        state.last_report = Some(report);
+        state.storm = None;
+        return;
+    }
+
+    let center = track.center_at(now);
+    if !track.landed {
+        notifications.send(NotificationEvent {
+            text: format!("Category {} hurricane has made landfall", track.category),
+            priority: NotificationPriority::Emergency,
+            category: NotificationCategory::Disaster,
+            location: Some((center.0 * CELL_SIZE, center.1 * CELL_SIZE)),
+        });
+        if let Some(storm) = state.storm.as_mut() {
+            storm.landed = true;
+        }
+        state.current.category = track.category;
+    }
+
+    // The storm's wind at the middle of the map sets the city-wide wind.
+    let mid = (GRID_WIDTH / 2, GRID_HEIGHT / 2);
+    let mid_wind = track.wind_at(now, mid);
+    if mid_wind > 0.0 {
+        wind.speed = wind.speed.max(mid_wind);
+        wind.direction = wind_direction_at(center, (mid.0 as f32, mid.1 as f32));
+    }
+
+    // Damage under the wind field.
+    if center.0 + track.radius < 0.0 || center.1 + track.radius < 0.0 {
+        return;
+    }
+    let x0 = (center.0 - track.radius).max(0.0) as usize;
+    let y0 = (center.1 - track.radius).max(0.0) as usize;
+    let x1 = ((center.0 + track.radius) as usize).min(GRID_WIDTH - 1);
+    let y1 = ((center.1 + track.radius) as usize).min(GRID_HEIGHT - 1);
+    let repair_day = clock.day + LINE_REPAIR_DAYS;
+    let mut roofs = 0;
+    let mut loss = 0.0;
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            let speed = track.wind_at(now, (x, y));
+            if speed <= 0.0 {
+                continue;
+            }
+            let cell = (y * GRID_WIDTH + x) as u32;
+            let seed = splitmix64(tick.0.wrapping_mul(0x5707_3a11) ^ cell as u64);
+
+            if trees.has_tree(x, y) && rand_f32(seed) < tree_knockdown_probability(speed) {
+                trees.set(x, y, false);
+                state.current.trees_down += 1;
+            }
+
+            if power_lines.has_line.get_index(cell as usize)
+                && !state.is_line_down(cell)
+                && rand_f32(seed.wrapping_add(1))
+                    < power_outage_probability(speed) * LINE_FAILURE_SCALE
+            {
+                state.downed_lines.push(DownedLine { cell, repair_day });
+                state.current.lines_down += 1;
+            }
+
+            let Some(entity) = grid.get(x, y).building_id else {
+                continue;
+            };
+            if rand_f32(seed.wrapping_add(2)) >= wind_damage_amount(speed) / ROOF_DAMAGE_SCALE {
+                continue;
+            }
+            let Ok(mut building) = buildings.get_mut(entity) else {
+                continue;
+            };
+            state.current.roofs_damaged += 1;
+            roofs += 1;
+            if building.level > 1 {
+                let value = property_value(&building);
+                building.level -= 1;
+                building.capacity =
+                    Building::capacity_for_level(building.zone_type, building.level);
+                building.occupants = building.occupants.min(building.capacity);
+                loss += value - property_value(&building);
+            }
+        }
+    }
+    if roofs > 0 {
+        damage.send(DisasterDamage {
+            kind: DisasterKind::Hurricane,
+            loss,
+            destroyed: 0,
+            damaged: roofs,
+            displaced: 0,
+        });
+    }
+}
+
+/// Take downed lines out of the power line grid until they are repaired.
+pub fn cut_downed_lines(state: Res<HurricaneState>, mut power_lines: ResMut<PowerLineGrid>) {
+    for line in &state.downed_lines {
+        power_lines.has_line.set_index(line.cell as usize, false);
+    }
+}
+
+pub struct HurricanePlugin;
+
+impl Plugin for HurricanePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HurricaneState>().add_systems(
+            FixedUpdate,
+            (
+                forecast_hurricanes,
+                advance_hurricane
+                    .after(crate::wind::update_wind)
+                    .before(crate::wind_damage::update_wind_damage),
+                cut_downed_lines
+                    .after(crate::power_lines::install_power_lines)
+                    .before(crate::power_lines::propagate_power_coverage),
+            )
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<HurricaneState>();
+    }
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+fn track(landfall_day: u32) -> StormTrack {
+    StormTrack {
+        origin: (0.0, 128.0),
+        heading: 0.0,
+        speed: 100.0,
+        category: 3,
+        radius: storm_radius(3),
+        landfall_day,
+        landed: false,
+    }
+}
+
+#[test]
+fn test_wind_field_profile() {
+    let radius = storm_radius(4);
+    let peak = peak_wind(4);
+    assert!(wind_speed_at(4, radius, 0.0) < peak * 0.5, "eye is calm");
+    assert_eq!(
+        wind_speed_at(4, radius, radius * MAX_WIND_RADIUS_FRACTION),
+        peak
+    );
+    let outer = wind_speed_at(4, radius, radius * 0.9);
+    assert!(outer > 0.0 && outer < peak);
+    assert_eq!(wind_speed_at(4, radius, radius), 0.0);
+    assert!(peak_wind(5) > peak_wind(1));
+    assert!(storm_radius(5) > storm_radius(1));
+}
+
+#[test]
+fn test_wind_turns_counterclockwise() {
+    // East of the center the wind blows roughly north.
+    let dir = wind_direction_at((0.0, 0.0), (10.0, 0.0));
+    assert!((dir - (std::f32::consts::FRAC_PI_2 + INFLOW_ANGLE)).abs() < 1e-5);
+}
+
+#[test]
+fn test_track_moves_and_passes() {
+    let storm = track(10);
+    assert_eq!(storm.center_at(10.0), (0.0, 128.0));
+    let (x, y) = storm.center_at(11.0);
+    assert!((x - 100.0).abs() < 1e-3 && (y - 128.0).abs() < 1e-3);
+    assert!(storm.center_at(9.5).0 < 0.0, "approaching from offshore");
+
+    assert!(!storm.has_passed(9.0));
+    assert!(!storm.has_passed(12.0));
+    assert!(storm.has_passed(13.0));
+}
+
+#[test]
+fn test_forecast_then_active() {
+    let state = HurricaneState {
+        storm: Some(track(10)),
+        ..Default::default()
+    };
+    assert!(state.forecast(8.0).is_some());
+    assert!(state.active(8.0).is_none());
+    assert!(state.forecast(10.5).is_none());
+    assert!(state.active(10.5).is_some());
+    assert!(state.active(14.0).is_none());
+}
+
+#[test]
+fn test_cone_widens_with_lead_time() {
+    let storm = track(10);
+    let cone = storm.cone(7.0, 0.25);
+    assert!(cone.len() > 4);
+    for pair in cone.windows(2) {
+        assert!(pair[1].1 > pair[0].1);
+    }
+    assert!((cone[0].1 - storm.radius * CONE_BASE_FRACTION).abs() < 1e-4);
+}
+
+#[test]
+fn test_generated_tracks_head_into_the_map() {
+    for seed in 0..50 {
+        let storm = StormTrack::generate(seed, 5);
+        assert!((1..=5).contains(&storm.category));
+        assert!(storm.speed >= MIN_FORWARD_SPEED && storm.speed <= MAX_FORWARD_SPEED);
+        // Half a day after landfall the storm is over the map.
+        let (x, y) = storm.center_at(5.5);
+        assert!(x > 0.0 && x < GRID_WIDTH as f32 && y > 0.0 && y < GRID_HEIGHT as f32);
+    }
+    assert_eq!(StormTrack::generate(9, 5), StormTrack::generate(9, 5));
+}
+
+#[test]
+fn test_tropical_storms_most_common() {
+    use crate::weather::ClimateZone;
+    for zone in ClimateZone::all() {
+        assert!(climate_factor(ClimateZone::Tropical) >= climate_factor(*zone));
+    }
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(HurricaneState::default().save_to_bytes().is_none());
+    let state = HurricaneState {
+        storm: Some(track(4)),
+        current: StormReport {
+            category: 3,
+            trees_down: 12,
+            lines_down: 2,
+            roofs_damaged: 5,
+        },
+        downed_lines: vec![DownedLine {
+            cell: 77,
+            repair_day: 6,
+        }],
+        ..Default::default()
+    };
+    let restored = HurricaneState::load_from_bytes(&state.save_to_bytes().unwrap());
+    assert_eq!(restored, state);
+    assert!(restored.is_line_down(77));
+}
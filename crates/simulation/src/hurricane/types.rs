@@ -0,0 +1,285 @@
+//! Storm tracks, the hurricane wind field and the storm's saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::weather::ClimateZone;
+use crate::wind_damage::types::{rand_f32, splitmix64};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Chance per game day that a hurricane forms (before climate and difficulty
+/// scaling).
+pub const HURRICANE_CHANCE_PER_DAY: f32 = 0.004;
+
+/// Days between the forecast and landfall.
+pub const FORECAST_LEAD_DAYS: u32 = 3;
+
+/// Range of forward speeds, in cells per game day.
+pub const MIN_FORWARD_SPEED: f32 = 80.0;
+pub const MAX_FORWARD_SPEED: f32 = 160.0;
+
+/// Radius of the wind field at category 1, and its growth per category.
+pub const BASE_STORM_RADIUS: f32 = 16.0;
+pub const STORM_RADIUS_PER_CATEGORY: f32 = 4.0;
+
+/// Calm eye at the center of the storm, in cells.
+pub const EYE_RADIUS: f32 = 2.0;
+
+/// Fraction of the storm radius where the strongest winds blow.
+pub const MAX_WIND_RADIUS_FRACTION: f32 = 0.3;
+
+/// Wind at the edge of the storm relative to its peak.
+pub const EDGE_WIND_FRACTION: f32 = 0.45;
+
+/// Angle (radians) by which surface winds spiral in towards the center.
+pub const INFLOW_ANGLE: f32 = 0.35;
+
+/// Forecast cone half-width at landfall time, as a fraction of the storm
+/// radius, and its growth per day of lead time.
+pub const CONE_BASE_FRACTION: f32 = 0.5;
+pub const CONE_SPREAD_PER_DAY: f32 = 12.0;
+
+/// A storm is dropped this many days after landfall even if it stalls.
+pub const MAX_STORM_DAYS: f32 = 5.0;
+
+/// Scales `power_outage_probability` into a per-update chance that a line
+/// segment comes down.
+pub const LINE_FAILURE_SCALE: f32 = 0.1;
+
+/// Days until a downed power line is repaired.
+pub const LINE_REPAIR_DAYS: u32 = 2;
+
+/// Divides `wind_damage_amount` into a per-update chance that a roof fails
+/// and the building loses a level.
+pub const ROOF_DAMAGE_SCALE: f32 = 2000.0;
+
+// ---------------------------------------------------------------------------
+// Wind field
+// ---------------------------------------------------------------------------
+
+/// Peak normalized wind speed for a Saffir-Simpson style category (1-5).
+pub fn peak_wind(category: u8) -> f32 {
+    (0.75 + 0.05 * category.clamp(1, 5) as f32).min(1.0)
+}
+
+/// Radius of the wind field for a category, in cells.
+pub fn storm_radius(category: u8) -> f32 {
+    BASE_STORM_RADIUS + STORM_RADIUS_PER_CATEGORY * category.clamp(1, 5) as f32
+}
+
+/// Normalized wind speed `distance` cells from the center of a storm: light
+/// in the eye, strongest in the eyewall, tapering towards the edge and zero
+/// beyond it.
+pub fn wind_speed_at(category: u8, radius: f32, distance: f32) -> f32 {
+    if distance >= radius {
+        return 0.0;
+    }
+    let peak = peak_wind(category);
+    if distance < EYE_RADIUS {
+        return peak * 0.2;
+    }
+    let max_wind_radius = radius * MAX_WIND_RADIUS_FRACTION;
+    if distance <= max_wind_radius {
+        return peak;
+    }
+    let t = (distance - max_wind_radius) / (radius - max_wind_radius);
+    peak * (1.0 - (1.0 - EDGE_WIND_FRACTION) * t)
+}
+
+/// Direction (radians, same convention as `WindState`) the wind blows at
+/// `(x, y)`: counterclockwise around the center, spiralling inwards.
+pub fn wind_direction_at((cx, cy): (f32, f32), (x, y): (f32, f32)) -> f32 {
+    let outward = (y - cy).atan2(x - cx);
+    (outward + std::f32::consts::FRAC_PI_2 + INFLOW_ANGLE).rem_euclid(std::f32::consts::TAU)
+}
+
+/// How often hurricanes reach a city in each climate, relative to the base
+/// chance.
+pub fn climate_factor(zone: ClimateZone) -> f32 {
+    match zone {
+        ClimateZone::Tropical => 3.0,
+        ClimateZone::Temperate | ClimateZone::Oceanic => 0.5,
+        ClimateZone::Mediterranean => 0.3,
+        ClimateZone::Arid => 0.2,
+        ClimateZone::Continental | ClimateZone::Subarctic => 0.1,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Track
+// ---------------------------------------------------------------------------
+
+/// A storm's straight-line path across the map. The center is at `origin`
+/// at landfall and moves `speed` cells per day along `heading`.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct StormTrack {
+    pub origin: (f32, f32),
+    /// Direction of travel in radians.
+    pub heading: f32,
+    /// Forward speed in cells per game day.
+    pub speed: f32,
+    /// Saffir-Simpson style category, 1-5.
+    pub category: u8,
+    /// Radius of the wind field in cells.
+    pub radius: f32,
+    /// Day the storm reaches the map edge.
+    pub landfall_day: u32,
+    /// Whether the landfall warning has gone out.
+    pub landed: bool,
+}
+
+impl StormTrack {
+    /// Lay out a storm that comes in from a random map edge towards the
+    /// middle of the map and makes landfall on `landfall_day`.
+    pub fn generate(seed: u64, landfall_day: u32) -> Self {
+        let roll = |salt: u64| rand_f32(splitmix64(seed ^ salt));
+        let category = 1 + ((roll(1) * roll(1) * 5.0) as u8).min(4);
+        let radius = storm_radius(category);
+        let w = GRID_WIDTH as f32;
+        let h = GRID_HEIGHT as f32;
+        let along = 0.2 + 0.6 * roll(2);
+        let origin = match (roll(3) * 4.0) as u32 {
+            0 => (0.0, along * h),
+            1 => (w - 1.0, along * h),
+            2 => (along * w, 0.0),
+            _ => (along * w, h - 1.0),
+        };
+        let to_center = (h * 0.5 - origin.1).atan2(w * 0.5 - origin.0);
+        Self {
+            origin,
+            heading: to_center + (roll(4) - 0.5),
+            speed: MIN_FORWARD_SPEED + (MAX_FORWARD_SPEED - MIN_FORWARD_SPEED) * roll(5),
+            category,
+            radius,
+            landfall_day,
+            landed: false,
+        }
+    }
+
+    /// Center of the storm at fractional game day `day`. Before landfall the
+    /// storm is still offshore on its approach.
+    pub fn center_at(&self, day: f32) -> (f32, f32) {
+        let t = day - self.landfall_day as f32;
+        (
+            self.origin.0 + self.heading.cos() * self.speed * t,
+            self.origin.1 + self.heading.sin() * self.speed * t,
+        )
+    }
+
+    /// Whether the wind field at `day` still reaches the map.
+    pub fn on_map(&self, day: f32) -> bool {
+        let (x, y) = self.center_at(day);
+        x > -self.radius
+            && y > -self.radius
+            && x < GRID_WIDTH as f32 + self.radius
+            && y < GRID_HEIGHT as f32 + self.radius
+    }
+
+    /// Whether the storm is over at `day`: it has left the map, or it has
+    /// been on land for `MAX_STORM_DAYS`.
+    pub fn has_passed(&self, day: f32) -> bool {
+        let t = day - self.landfall_day as f32;
+        t > 0.0 && (!self.on_map(day) || t > MAX_STORM_DAYS)
+    }
+
+    /// Forecast cone from `now` until the storm passes: sample centers every
+    /// `step_days` with the cone's half-width, which grows with lead time.
+    pub fn cone(&self, now: f32, step_days: f32) -> Vec<((f32, f32), f32)> {
+        let mut samples = Vec::new();
+        let end = self.landfall_day as f32 + MAX_STORM_DAYS;
+        let mut day = now;
+        while day <= end && !self.has_passed(day) {
+            let lead = (day - now).max(0.0);
+            let half_width = self.radius * CONE_BASE_FRACTION + CONE_SPREAD_PER_DAY * lead;
+            samples.push((self.center_at(day), half_width));
+            day += step_days;
+        }
+        samples
+    }
+
+    /// Normalized wind speed at grid cell `(x, y)` on `day`.
+    pub fn wind_at(&self, day: f32, (x, y): (usize, usize)) -> f32 {
+        let (cx, cy) = self.center_at(day);
+        let dx = x as f32 - cx;
+        let dy = y as f32 - cy;
+        wind_speed_at(self.category, self.radius, (dx * dx + dy * dy).sqrt())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// Damage done along a storm's path.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct StormReport {
+    pub category: u8,
+    pub trees_down: u32,
+    pub lines_down: u32,
+    pub roofs_damaged: u32,
+}
+
+/// A power line cell brought down by the wind, out until `repair_day`.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct DownedLine {
+    pub cell: u32,
+    pub repair_day: u32,
+}
+
+/// The forecast or active hurricane, downed power lines awaiting repair and
+/// the report from the last storm.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct HurricaneState {
+    /// Forecast storm (before `landfall_day`) or the storm crossing the map.
+    pub storm: Option<StormTrack>,
+    /// Damage from the current storm so far.
+    pub current: StormReport,
+    pub last_report: Option<StormReport>,
+    pub downed_lines: Vec<DownedLine>,
+    /// Last day the daily formation roll ran.
+    pub last_roll_day: u32,
+}
+
+impl HurricaneState {
+    /// The storm if it has been forecast but not yet made landfall.
+    pub fn forecast(&self, now: f32) -> Option<&StormTrack> {
+        self.storm.as_ref().filter(|s| now < s.landfall_day as f32)
+    }
+
+    /// The storm if it is crossing the map.
+    pub fn active(&self, now: f32) -> Option<&StormTrack> {
+        self.storm
+            .as_ref()
+            .filter(|s| now >= s.landfall_day as f32 && !s.has_passed(now))
+    }
+
+    pub fn is_line_down(&self, cell: u32) -> bool {
+        self.downed_lines.iter().any(|d| d.cell == cell)
+    }
+}
+
+/// Fractional game day for the clock.
+pub fn day_fraction(day: u32, hour: f32) -> f32 {
+    day as f32 + hour / 24.0
+}
+
+impl Saveable for HurricaneState {
+    const SAVE_KEY: &'static str = "hurricane_state";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::agriculture::UrbanFarms;
 use crate::buildings::Building;
 use crate::economy::CityBudget;
 use crate::grid::ZoneType;
@@ -28,6 +29,7 @@ pub fn process_trade(
     clock: Res<GameClock>,
     mut trade: ResMut<TradeConnections>,
     mut budget: ResMut<CityBudget>,
+    farms: Res<UrbanFarms>,
     buildings: Query<&Building>,
 ) {
     // Process every 30 days
@@ -47,7 +49,9 @@ pub fn process_trade(
         .count() as f64;
 
     let export_income = industrial_count * trade.export_income_per_industrial;
-    let import_cost = commercial_count * trade.import_cost_per_commercial;
+    // Food grown on the city's rooftop and vertical farms replaces imports
+    let import_cost =
+        commercial_count * trade.import_cost_per_commercial * farms.import_cost_factor();
 
     budget.treasury += export_income - import_cost;
 }
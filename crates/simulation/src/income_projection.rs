@@ -46,6 +46,7 @@ pub fn update_income_projection(
     extended: Res<ExtendedBudget>,
     policies: Res<Policies>,
     tourism: Res<Tourism>,
+    unlocks: Res<crate::unlocks::UnlockState>,
     params: (
         Res<crate::coal_power::CoalPowerState>,
         Res<crate::gas_power::GasPowerState>,
@@ -139,7 +140,8 @@ pub fn update_income_projection(
         .iter()
         .filter(|c| c.cell_type == CellType::Road)
         .map(|c| c.road_type.maintenance_cost())
-        .sum();
+        .sum::<f64>()
+        * unlocks.road_maintenance_multiplier();
 
     let service_budgets = &extended.service_budgets;
     let service_expense: f64 = services_q
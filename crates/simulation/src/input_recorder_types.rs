@@ -185,6 +185,9 @@ pub enum RecordedServiceType {
     SubstanceAbuseTreatmentCenter,
     SeniorCenter,
     YouthCenter,
+    HazmatResponse,
+    Hotel,
+    ConventionCenter,
 }
 
 /// Bitcode-serializable mirror of `UtilityType`.
@@ -298,6 +301,9 @@ impl From<ServiceType> for RecordedServiceType {
             }
             ServiceType::SeniorCenter => Self::SeniorCenter,
             ServiceType::YouthCenter => Self::YouthCenter,
+            ServiceType::HazmatResponse => Self::HazmatResponse,
+            ServiceType::Hotel => Self::Hotel,
+            ServiceType::ConventionCenter => Self::ConventionCenter,
         }
     }
 }
@@ -0,0 +1,126 @@
+//! Integration tests for lingering, rain-washed air pollution and the city
+//! air quality metric with smog alerts.
+
+use crate::air_quality::*;
+use crate::citizen::CitizenDetails;
+use crate::coal_power::PowerPlant;
+use crate::grid::ZoneType;
+use crate::pollution::PollutionGrid;
+use crate::test_harness::TestCity;
+use crate::weather::Weather;
+use crate::wind::WindState;
+
+fn calm(city: &mut TestCity) {
+    city.world_mut().resource_mut::<WindState>().speed = 0.0;
+}
+
+/// Hold the weather in a multi-day event with the given atmosphere so the
+/// hourly weather update keeps it.
+fn set_weather(city: &mut TestCity, cloud_cover: f32, atmo_precipitation: f32) {
+    let mut weather = city.world_mut().resource_mut::<Weather>();
+    weather.cloud_cover = cloud_cover;
+    weather.atmo_precipitation = atmo_precipitation;
+    weather.temperature = 15.0;
+    weather.event_days_remaining = 3;
+}
+
+#[test]
+fn test_rain_washes_pollution_out() {
+    let mut dry = TestCity::new();
+    let mut wet = TestCity::new();
+    for city in [&mut dry, &mut wet] {
+        city.world_mut().spawn(PowerPlant::new_coal(60, 60));
+        calm(city);
+    }
+    set_weather(&mut dry, 0.1, 0.0);
+    set_weather(&mut wet, 0.9, 0.8);
+
+    dry.tick_slow_cycles(3);
+    wet.tick_slow_cycles(3);
+
+    assert!(wet.resource::<Weather>().precipitation_intensity > 0.0);
+    let dry_level = dry.resource::<PollutionGrid>().get(64, 60);
+    let wet_level = wet.resource::<PollutionGrid>().get(64, 60);
+    assert!(
+        wet_level < dry_level,
+        "rain should wash pollution out (wet={wet_level}, dry={dry_level})"
+    );
+}
+
+#[test]
+fn test_pollution_lingers_after_source_closes() {
+    let mut city = TestCity::new();
+    let plant = city.world_mut().spawn(PowerPlant::new_coal(60, 60)).id();
+    calm(&mut city);
+    city.tick_slow_cycle();
+    let before = city.resource::<PollutionGrid>().get(60, 60);
+
+    city.world_mut().despawn(plant);
+    calm(&mut city);
+    city.tick_slow_cycle();
+    let after = city.resource::<PollutionGrid>().get(60, 60);
+
+    assert!(after > 0, "some pollution should linger");
+    assert!(after < before, "lingering pollution should thin out");
+}
+
+/// Three residents downwind of three coal plants, and one across town.
+fn smoggy_city() -> TestCity {
+    let mut city = TestCity::new()
+        .with_building(61, 60, ZoneType::ResidentialLow, 1)
+        .with_building(200, 200, ZoneType::ResidentialLow, 1)
+        .with_unemployed_citizen((61, 60))
+        .with_unemployed_citizen((61, 60))
+        .with_unemployed_citizen((61, 60))
+        .with_unemployed_citizen((200, 200));
+    for _ in 0..3 {
+        city.world_mut().spawn(PowerPlant::new_coal(60, 60));
+    }
+    city
+}
+
+#[test]
+fn test_sustained_smog_issues_alert() {
+    let mut city = smoggy_city();
+    for _ in 0..SMOG_SUSTAINED_TICKS {
+        calm(&mut city);
+        city.tick_slow_cycle();
+    }
+
+    let air = city.resource::<AirQuality>();
+    assert!(air.city_level >= SMOG_ALERT_LEVEL, "got {}", air.city_level);
+    assert!(air.peak_level >= UNHEALTHY_LEVEL);
+    assert_eq!(air.exposed_residents, 3);
+    assert!(air.smog_alert);
+    assert_eq!(air.smog_alerts_issued, 1);
+}
+
+#[test]
+fn test_smog_harms_residents_in_clean_air() {
+    let mut control = TestCity::new()
+        .with_building(200, 200, ZoneType::ResidentialLow, 1)
+        .with_unemployed_citizen((200, 200));
+    let mut city = smoggy_city();
+    for _ in 0..SMOG_SUSTAINED_TICKS + 2 {
+        calm(&mut control);
+        calm(&mut city);
+        control.tick_slow_cycle();
+        city.tick_slow_cycle();
+    }
+
+    let health_at = |city: &mut TestCity| {
+        let world = city.world_mut();
+        let mut query = world.query::<(&crate::citizen::HomeLocation, &CitizenDetails)>();
+        query
+            .iter(world)
+            .find(|(home, _)| home.grid_x == 200)
+            .map(|(_, details)| details.health)
+            .expect("resident across town")
+    };
+    let clean = health_at(&mut control);
+    let smog = health_at(&mut city);
+    assert!(
+        smog < clean,
+        "a smog alert should hurt every resident ({smog} vs {clean})"
+    );
+}
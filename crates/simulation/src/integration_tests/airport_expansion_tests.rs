@@ -0,0 +1,176 @@
+//! Integration tests for airport runways, gates and cargo terminals, the
+//! flight board and runway noise contours.
+
+use crate::airport::{
+    AirportExpansion, AirportExpansionRequest, AirportOperations, AirportStats, FlightBoard,
+};
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::fog::FogState;
+use crate::freight_traffic::FreightTrafficState;
+use crate::grid::{RoadType, ZoneType};
+use crate::land_value::LandValueGrid;
+use crate::noise::NoisePollutionGrid;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::virtual_population::VirtualPopulation;
+
+fn set_population(city: &mut TestCity, population: u32) {
+    city.world_mut()
+        .resource_mut::<VirtualPopulation>()
+        .total_virtual = population;
+}
+
+fn expand(city: &mut TestCity, airport: (u16, u16), kind: AirportExpansion) {
+    city.world_mut()
+        .send_event(AirportExpansionRequest { airport, kind });
+    city.tick(1);
+}
+
+#[test]
+fn test_gates_cap_regional_airport_flights() {
+    let mut city = TestCity::new().with_service(50, 50, ServiceType::RegionalAirport);
+    set_population(&mut city, 1_000_000);
+    city.tick_slow_cycles(2);
+
+    let stats = city.resource::<AirportStats>();
+    assert_eq!(
+        (stats.runways, stats.gates, stats.cargo_terminals),
+        (1, 10, 0)
+    );
+    assert_eq!(stats.passenger_capacity, 10 * 12 * 30);
+    assert_eq!(stats.passenger_flights_per_month, stats.passenger_capacity);
+}
+
+#[test]
+fn test_gate_expansion_raises_capacity_for_a_price() {
+    let mut city =
+        TestCity::new()
+            .with_budget(50_000.0)
+            .with_service(50, 50, ServiceType::RegionalAirport);
+    set_population(&mut city, 1_000_000);
+    city.tick_slow_cycles(2);
+    let before = city.resource::<AirportStats>().passenger_flights_per_month;
+    let treasury = city.resource::<CityBudget>().treasury;
+
+    expand(&mut city, (50, 50), AirportExpansion::Gate);
+    let operations = city.resource::<AirportOperations>();
+    assert_eq!(operations.get((50, 50)).unwrap().gates, 11);
+    assert_eq!(operations.expansion_spent, AirportExpansion::Gate.cost());
+    assert!(city.resource::<CityBudget>().treasury < treasury);
+
+    city.tick_slow_cycle();
+    let after = city.resource::<AirportStats>().passenger_flights_per_month;
+    assert_eq!(after, before + 12 * 30);
+}
+
+#[test]
+fn test_expansion_refused_without_funds_or_past_the_tier_maximum() {
+    let mut city = TestCity::new().with_service(50, 50, ServiceType::SmallAirstrip);
+    city.tick_slow_cycle();
+    city.world_mut().resource_mut::<CityBudget>().treasury = 0.0;
+    expand(&mut city, (50, 50), AirportExpansion::Gate);
+    assert_eq!(
+        city.resource::<AirportOperations>()
+            .get((50, 50))
+            .unwrap()
+            .gates,
+        2
+    );
+
+    city.world_mut().resource_mut::<CityBudget>().treasury = 50_000.0;
+    expand(&mut city, (50, 50), AirportExpansion::Runway);
+    let operations = city.resource::<AirportOperations>();
+    let airport = operations.get((50, 50)).unwrap();
+    assert_eq!(airport.runways, 1, "small airstrips have a single runway");
+    assert_eq!(operations.expansion_spent, 0.0);
+}
+
+#[test]
+fn test_flight_board_lists_the_days_flights_and_fog_cancels_them() {
+    let mut city = TestCity::new().with_service(128, 128, ServiceType::InternationalAirport);
+    set_population(&mut city, 100_000);
+    city.tick_slow_cycles(2);
+
+    let board = city.resource::<FlightBoard>();
+    assert_eq!(board.day, city.clock().day);
+    assert!(!board.flights.is_empty());
+    assert!(board.flights.iter().all(|f| f.airport == (128, 128)));
+    assert_eq!(board.cancelled(), 0);
+
+    city.world_mut()
+        .resource_mut::<FogState>()
+        .flights_suspended = true;
+    city.tick(1);
+    let minute = (city.clock().hour * 60.0) as u16;
+    let board = city.resource::<FlightBoard>();
+    assert!(board
+        .flights
+        .iter()
+        .filter(|f| f.time() > minute)
+        .all(|f| f.cancelled));
+}
+
+#[test]
+fn test_runway_noise_contours_lower_land_value_under_the_approach() {
+    let busy_city = || {
+        let mut city = TestCity::new().with_service(128, 128, ServiceType::InternationalAirport);
+        set_population(&mut city, 100_000);
+        city
+    };
+    let mut busy = busy_city();
+    let mut grounded = busy_city();
+    grounded
+        .world_mut()
+        .resource_mut::<FogState>()
+        .flights_suspended = true;
+    busy.tick_slow_cycles(2);
+    grounded.tick_slow_cycles(2);
+
+    // Under the approach path, beyond the airport's own noise footprint.
+    let (x, y) = (170, 128);
+    let busy_noise = busy.resource::<NoisePollutionGrid>().get(x, y);
+    assert!(busy_noise > grounded.resource::<NoisePollutionGrid>().get(x, y));
+    assert!(busy.resource::<AirportStats>().noise_contour_cells[0] > 0);
+    assert_eq!(
+        grounded.resource::<AirportStats>().noise_contour_cells,
+        [0, 0, 0]
+    );
+    assert!(
+        busy.resource::<LandValueGrid>().get(x, y) < grounded.resource::<LandValueGrid>().get(x, y)
+    );
+}
+
+#[test]
+fn test_cargo_terminals_fly_freighters_and_truck_air_freight_to_shops() {
+    let mut city = TestCity::new()
+        .with_road(62, 40, 62, 70, RoadType::Local)
+        .with_service(60, 50, ServiceType::InternationalAirport)
+        .with_building(61, 65, ZoneType::CommercialHigh, 3)
+        .rebuild_csr();
+    {
+        let world = city.world_mut();
+        let mut buildings = world.query::<&mut Building>();
+        for mut building in buildings.iter_mut(world) {
+            building.occupants = 1000;
+        }
+    }
+    city.tick_slow_cycles(2);
+
+    let stats = city.resource::<AirportStats>();
+    assert!(stats.freighter_flights_per_month > 0);
+    assert!(stats.cargo_flights_per_month >= stats.freighter_flights_per_month);
+    assert_eq!(
+        city.resource::<FreightTrafficState>().air_cargo_terminals,
+        vec![(60, 50)]
+    );
+
+    city.world_mut()
+        .resource_mut::<FreightTrafficState>()
+        .air_cargo_backlog = 5.0;
+    let generated = city.resource::<FreightTrafficState>().trips_generated;
+    city.tick(20);
+    let freight = city.resource::<FreightTrafficState>();
+    assert!(freight.trips_generated > generated);
+    assert!(freight.air_cargo_backlog < 5.0);
+}
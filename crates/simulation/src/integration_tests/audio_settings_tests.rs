@@ -1,8 +1,8 @@
 //! Integration tests for audio system infrastructure (PLAY-007).
 //!
 //! Verifies that `AudioSettings` is properly registered as a resource,
-//! the `PlaySfxEvent` event type works, and the `Saveable` round-trip
-//! preserves all settings.
+//! the `PlaySfxEvent` event type works, and the settings survive a new city
+//! through the player profile.
 
 use crate::audio_settings::{AudioSettings, PlaySfxEvent, SfxEvent};
 use crate::test_harness::TestCity;
@@ -73,29 +73,35 @@ fn test_toggle_mute_preserves_volumes() {
 }
 
 // =============================================================================
-// Saveable round-trip
+// Player profile
 // =============================================================================
 
 #[test]
-fn test_audio_settings_saveable_roundtrip() {
-    use crate::Saveable;
-
-    let original = AudioSettings {
-        master_volume: 0.3,
-        music_volume: 0.8,
-        sfx_volume: 0.1,
-        ui_volume: 0.9,
-        muted: true,
-    };
-
-    let bytes = original.save_to_bytes().expect("should serialize");
-    let loaded = AudioSettings::load_from_bytes(&bytes);
-
-    assert_eq!(loaded.master_volume, 0.3);
-    assert_eq!(loaded.music_volume, 0.8);
-    assert_eq!(loaded.sfx_volume, 0.1);
-    assert_eq!(loaded.ui_volume, 0.9);
-    assert!(loaded.muted);
+fn test_audio_settings_survive_new_city() {
+    use crate::SaveableRegistry;
+
+    let mut city = TestCity::new();
+    {
+        let mut settings = city.world_mut().resource_mut::<AudioSettings>();
+        settings.set_master_volume(0.3);
+        settings.muted = true;
+    }
+
+    // Starting a new city resets every saveable resource; audio settings
+    // belong to the player profile instead.
+    {
+        let w = city.world_mut();
+        let r = w.remove_resource::<SaveableRegistry>().unwrap();
+        r.reset_all(w);
+        w.insert_resource(r);
+    }
+    city.tick(1);
+
+    let settings = city.resource::<AudioSettings>();
+    assert_eq!(settings.master_volume, 0.3);
+    assert!(settings.muted);
+    let profile = city.resource::<crate::profile::PlayerProfile>();
+    assert_eq!(profile.audio, *settings);
 }
 
 // =============================================================================
@@ -0,0 +1,70 @@
+//! Integration tests for automatic district generation.
+
+use bevy::prelude::*;
+
+use crate::auto_district::AutoDistrictRequest;
+use crate::districts::DistrictMap;
+use crate::grid::{RoadType, ZoneType};
+use crate::test_harness::TestCity;
+
+fn request_auto_districts(city: &mut TestCity) {
+    city.world_mut().send_event(AutoDistrictRequest::default());
+    city.world_mut().run_schedule(Update);
+}
+
+#[test]
+fn test_auto_district_populates_district_map() {
+    let mut city = TestCity::new()
+        .with_road(80, 100, 160, 100, RoadType::Local)
+        .with_zone_rect(80, 90, 160, 99, ZoneType::ResidentialLow)
+        .with_zone_rect(80, 101, 160, 110, ZoneType::CommercialLow);
+
+    request_auto_districts(&mut city);
+
+    let map = city.resource::<DistrictMap>();
+    let painted: Vec<_> = map
+        .districts
+        .iter()
+        .filter(|d| !d.cells.is_empty())
+        .collect();
+    assert!(!painted.is_empty(), "should create at least one district");
+    assert!(
+        map.get_district_index_at(100, 95).is_some(),
+        "zoned cells should be assigned"
+    );
+    assert!(
+        map.get_district_index_at(10, 10).is_none(),
+        "undeveloped land should stay unassigned"
+    );
+    for d in &painted {
+        assert!(!d.name.is_empty());
+    }
+}
+
+#[test]
+fn test_auto_district_on_empty_city_clears_districts() {
+    let mut city = TestCity::new();
+    city.world_mut()
+        .resource_mut::<DistrictMap>()
+        .assign_cell_to_district(50, 50, 0);
+
+    request_auto_districts(&mut city);
+
+    let map = city.resource::<DistrictMap>();
+    assert!(map.get_district_at(50, 50).is_none());
+    assert!(map.districts.iter().all(|d| d.cells.is_empty()));
+    assert!(!map.districts.is_empty(), "paint targets should remain");
+}
+
+#[test]
+fn test_auto_district_on_tel_aviv() {
+    let mut city = TestCity::with_tel_aviv();
+    request_auto_districts(&mut city);
+    let map = city.resource::<DistrictMap>();
+    let painted = map.districts.iter().filter(|d| !d.cells.is_empty()).count();
+    assert!(
+        painted >= 2,
+        "a real city should split into several districts"
+    );
+    assert!(painted <= 8);
+}
@@ -5,6 +5,7 @@ use crate::coal_power::PowerPlant;
 use crate::energy_demand::{EnergyConsumer, LoadPriority};
 use crate::energy_dispatch::EnergyDispatchState;
 use crate::grid::ZoneType;
+use crate::packed_grid::BitGrid;
 use crate::services::ServiceType;
 use crate::test_harness::TestCity;
 use crate::time_of_day::GameClock;
@@ -343,7 +344,7 @@ fn test_saveable_roundtrip() {
         load_shed_fraction: 0.35,
         shed_by_tier: [10, 20, 12, 0],
         hospital_casualties: 3,
-        blackout_grid: vec![],
+        blackout_grid: BitGrid::new(0, 0),
     };
 
     let bytes = state.save_to_bytes().unwrap();
@@ -0,0 +1,91 @@
+//! Integration tests for capturing services into blueprints and stamping
+//! them rotated, all or nothing.
+
+use crate::blueprints::{
+    Blueprint, BlueprintLibrary, BlueprintRotation, CaptureBlueprint, PlaceBlueprint,
+};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::test_harness::TestCity;
+
+#[test]
+fn test_blueprint_events_capture_services_and_stamp_rotated() {
+    let mut city = TestCity::new()
+        .with_budget(100_000.0)
+        .with_zone_rect(20, 20, 22, 20, ZoneType::ResidentialLow)
+        .with_service(21, 21, ServiceType::FireStation);
+
+    city.world_mut().send_event(CaptureBlueprint {
+        origin_x: 20,
+        origin_y: 20,
+        width: 3,
+        height: 2,
+        name: "Corner".to_string(),
+    });
+    city.tick(1);
+    let library = city.resource::<BlueprintLibrary>();
+    assert_eq!(library.count(), 1);
+    assert_eq!(library.get(0).unwrap().services.len(), 1);
+    assert_eq!(library.get(0).unwrap().zone_cells.len(), 3);
+
+    let treasury_before = city.budget().treasury;
+    city.world_mut().send_event(PlaceBlueprint {
+        blueprint_index: 0,
+        target_x: 100,
+        target_y: 100,
+        rotation: BlueprintRotation::Quarter,
+    });
+    city.tick(1);
+
+    // The 3x2 row of zones along the top becomes a 2x3 column on the right.
+    for y in 100..103 {
+        assert_eq!(city.cell(101, y).zone, ZoneType::ResidentialLow);
+    }
+    // The fire station at (1, 1) lands on (0, 1).
+    let world = city.world_mut();
+    let stations: Vec<(usize, usize)> = world
+        .query::<&ServiceBuilding>()
+        .iter(world)
+        .filter(|s| s.service_type == ServiceType::FireStation)
+        .map(|s| (s.grid_x, s.grid_y))
+        .collect();
+    assert!(stations.contains(&(100, 101)), "stations: {stations:?}");
+    assert!(city.budget().treasury < treasury_before);
+}
+
+#[test]
+fn test_blueprint_stamp_over_water_is_rejected() {
+    let mut city = TestCity::new().with_budget(100_000.0).with_zone_rect(
+        20,
+        20,
+        22,
+        20,
+        ZoneType::ResidentialLow,
+    );
+    let bp = Blueprint::capture(
+        city.grid(),
+        city.road_segments(),
+        20,
+        20,
+        3,
+        1,
+        "Row".to_string(),
+    );
+    city.world_mut().resource_mut::<BlueprintLibrary>().add(bp);
+    city.world_mut()
+        .resource_mut::<WorldGrid>()
+        .get_mut(101, 100)
+        .cell_type = CellType::Water;
+
+    city.world_mut().send_event(PlaceBlueprint {
+        blueprint_index: 0,
+        target_x: 100,
+        target_y: 100,
+        rotation: BlueprintRotation::None,
+    });
+    city.tick(1);
+
+    // All or nothing: the dry cells are not zoned either.
+    assert_eq!(city.cell(100, 100).zone, ZoneType::None);
+    assert_eq!(city.cell(102, 100).zone, ZoneType::None);
+}
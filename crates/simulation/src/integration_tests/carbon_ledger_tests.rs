@@ -0,0 +1,83 @@
+//! Integration tests for carbon accounting and carbon pricing.
+
+use crate::budget::ExtendedBudget;
+use crate::carbon_ledger::*;
+use crate::coal_power::PowerPlant;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::zones::ZoneDemand;
+
+fn ledger(city: &TestCity) -> &CarbonLedger {
+    city.resource::<CarbonLedger>()
+}
+
+/// Jump past the end of the current ledger month and run a slow cycle.
+fn next_month(city: &mut TestCity) {
+    let start = ledger(city).month_start_day;
+    city.world_mut().resource_mut::<GameClock>().day = start + MONTH_DAYS + 1;
+    city.tick_slow_cycle();
+}
+
+#[test]
+fn test_coal_plant_emissions_are_booked_to_power() {
+    let mut city = TestCity::new();
+    city.world_mut().spawn(PowerPlant::new_coal(50, 50));
+    city.tick_slow_cycles(2);
+
+    let current = ledger(&city).current;
+    assert!(current.power > 0.0, "coal burns carbon");
+    assert_eq!(current.industry, 0.0);
+}
+
+#[test]
+fn test_month_closes_into_history_with_baseline() {
+    let mut city = TestCity::new();
+    city.world_mut().spawn(PowerPlant::new_coal(50, 50));
+    city.tick_slow_cycles(2);
+    next_month(&mut city);
+
+    let ledger = ledger(&city);
+    assert_eq!(ledger.months_closed, 1);
+    let month = ledger.last_month().expect("a month was closed");
+    assert!(month.emissions.power > 0.0);
+    assert_eq!(ledger.baseline, Some(month.emissions.net()));
+    assert_eq!(month.target, ledger.baseline);
+}
+
+#[test]
+fn test_carbon_tax_revenue_reaches_budget() {
+    let mut city = TestCity::new().with_budget(10_000.0);
+    city.world_mut().spawn(PowerPlant::new_coal(50, 50));
+    city.world_mut().resource_mut::<CarbonLedger>().pricing = CarbonPricing::Tax;
+    city.tick_slow_cycles(2);
+    next_month(&mut city);
+    next_month(&mut city);
+
+    let ledger = ledger(&city);
+    assert!(ledger.total_revenue > 0.0);
+    assert_eq!(ledger.last_price, DEFAULT_CARBON_TAX);
+    let collected = city
+        .resource::<ExtendedBudget>()
+        .income_breakdown
+        .carbon_revenue;
+    assert!(collected > 0.0, "carbon revenue should be collected");
+}
+
+#[test]
+fn test_carbon_price_dampens_industrial_demand() {
+    let mut control = TestCity::new();
+    let mut city = TestCity::new();
+    control.world_mut().resource_mut::<ZoneDemand>().industrial = 0.5;
+    city.world_mut().resource_mut::<ZoneDemand>().industrial = 0.5;
+    city.world_mut().resource_mut::<CarbonLedger>().last_price = MAX_CARBON_TAX;
+
+    control.tick_slow_cycle();
+    city.tick_slow_cycle();
+
+    let base = control.resource::<ZoneDemand>().industrial;
+    let priced = city.resource::<ZoneDemand>().industrial;
+    assert!(
+        priced < base,
+        "carbon price should lower industrial demand ({priced} vs {base})"
+    );
+}
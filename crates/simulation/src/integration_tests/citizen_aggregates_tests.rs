@@ -0,0 +1,64 @@
+//! Integration tests for the slow-tick citizen aggregates.
+
+use crate::citizen_aggregates::CitizenAggregates;
+use crate::districts::DistrictMap;
+use crate::grid::ZoneType;
+use crate::observation_builder::CurrentObservation;
+use crate::test_harness::TestCity;
+
+#[test]
+fn test_citizen_aggregates_resource_exists() {
+    let city = TestCity::new();
+    city.assert_resource_exists::<CitizenAggregates>();
+}
+
+#[test]
+fn test_aggregates_count_citizens_after_slow_tick() {
+    let mut city = TestCity::new()
+        .with_building(10, 10, ZoneType::ResidentialLow, 1)
+        .with_building(20, 10, ZoneType::CommercialLow, 1)
+        .with_citizen((10, 10), (20, 10))
+        .with_citizen((10, 10), (20, 10));
+    city.tick_slow_cycle();
+
+    let agg = city.resource::<CitizenAggregates>();
+    assert!(agg.city.population >= 2);
+    assert_eq!(agg.city.age.total, agg.city.population);
+    assert_eq!(agg.city.commute.total, agg.city.employed);
+    assert!(agg.city.employed >= 1);
+    assert!(agg.city.commute.mean() > 0.0);
+}
+
+#[test]
+fn test_aggregates_split_by_player_district() {
+    let mut city = TestCity::new()
+        .with_building(10, 10, ZoneType::ResidentialLow, 1)
+        .with_building(20, 10, ZoneType::CommercialLow, 1)
+        .with_citizen((10, 10), (20, 10));
+    city.world_mut()
+        .resource_mut::<DistrictMap>()
+        .assign_cell_to_district(10, 10, 1);
+    city.tick_slow_cycle();
+
+    let agg = city.resource::<CitizenAggregates>();
+    assert!(agg.district(1).is_some_and(|d| d.population >= 1));
+    assert_eq!(agg.district(0).map(|d| d.population), Some(0));
+}
+
+#[test]
+fn test_observation_includes_demographics() {
+    let mut city = TestCity::new()
+        .with_building(10, 10, ZoneType::ResidentialLow, 1)
+        .with_building(20, 10, ZoneType::CommercialLow, 1)
+        .with_citizen((10, 10), (20, 10));
+    city.tick_slow_cycle();
+
+    let obs = &city.resource::<CurrentObservation>().observation;
+    let counted: u32 = obs.demographics.age_bins.iter().map(|(_, n)| n).sum();
+    assert_eq!(
+        counted,
+        city.resource::<CitizenAggregates>().city.population
+    );
+    assert!(counted >= 1);
+    assert!(obs.population.employed >= 1);
+}
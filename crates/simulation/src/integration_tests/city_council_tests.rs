@@ -0,0 +1,96 @@
+//! Integration tests for council composition and motion voting.
+
+use crate::city_council::{CityCouncil, Motion, DEFAULT_TAX_CAP, TAX_CAP_STEP};
+use crate::events::EventJournal;
+use crate::grid::ZoneType;
+use crate::loans::{LoanBook, LoanTier};
+use crate::policies::{Policies, Policy};
+use crate::test_harness::TestCity;
+
+fn propose(city: &mut TestCity, motion: Motion) {
+    city.world_mut()
+        .resource_mut::<CityCouncil>()
+        .propose(motion);
+}
+
+#[test]
+fn test_council_resource_exists() {
+    let city = TestCity::new();
+    city.assert_resource_exists::<CityCouncil>();
+    assert_eq!(city.resource::<CityCouncil>().tax_cap, DEFAULT_TAX_CAP);
+}
+
+#[test]
+fn test_pending_motions_are_voted_on_slow_tick() {
+    let mut city = TestCity::new();
+    propose(&mut city, Motion::BuildStadium);
+    propose(&mut city, Motion::RaiseTaxCap);
+    city.tick_slow_cycle();
+
+    let council = city.resource::<CityCouncil>();
+    assert!(council.pending.is_empty());
+    assert_eq!(council.history.len(), 2);
+    for record in &council.history {
+        assert_eq!(record.yes + record.no, 9);
+    }
+}
+
+#[test]
+fn test_passed_motions_take_effect() {
+    let mut city = TestCity::new();
+    propose(&mut city, Motion::BuildStadium);
+    propose(&mut city, Motion::RaiseTaxCap);
+    propose(&mut city, Motion::EnactPolicy(Policy::RentControl));
+    propose(&mut city, Motion::TakeLoan(LoanTier::Large));
+    city.tick_slow_cycle();
+
+    let council = city.resource::<CityCouncil>().clone();
+    let passed = |motion: Motion| {
+        council
+            .history
+            .iter()
+            .any(|r| r.motion == motion && r.passed)
+    };
+    assert_eq!(
+        council.stadium_permits,
+        u32::from(passed(Motion::BuildStadium))
+    );
+    let expected_cap = if passed(Motion::RaiseTaxCap) {
+        DEFAULT_TAX_CAP + TAX_CAP_STEP
+    } else {
+        DEFAULT_TAX_CAP
+    };
+    assert!((council.tax_cap - expected_cap).abs() < 1e-6);
+    assert_eq!(
+        city.resource::<Policies>().is_active(Policy::RentControl),
+        passed(Motion::EnactPolicy(Policy::RentControl))
+    );
+    assert_eq!(
+        city.resource::<LoanBook>().active_loans.len(),
+        usize::from(passed(Motion::TakeLoan(LoanTier::Large)))
+    );
+
+    let approvals = council.history.iter().filter(|r| r.passed).count();
+    let journaled = city
+        .resource::<EventJournal>()
+        .events
+        .iter()
+        .filter(|e| e.description.starts_with("Council approved"))
+        .count();
+    assert_eq!(journaled, approvals);
+}
+
+#[test]
+fn test_council_drifts_with_residents() {
+    let mut city = TestCity::new()
+        .with_building(10, 10, ZoneType::ResidentialLow, 1)
+        .with_building(20, 10, ZoneType::CommercialLow, 1)
+        .with_citizen((10, 10), (20, 10))
+        .with_citizen((10, 10), (20, 10));
+    let before = city.resource::<CityCouncil>().shares;
+    city.tick_slow_cycles(3);
+
+    let council = city.resource::<CityCouncil>();
+    assert_ne!(council.shares, before);
+    assert_eq!(council.seats.iter().sum::<u32>(), 9);
+}
@@ -0,0 +1,105 @@
+//! Integration tests for long-term warming, traffic emissions and sea level
+//! rise held back by seawalls.
+
+use crate::buildings::Building;
+use crate::climate_change::constants::BACKGROUND_WARMING_F_PER_YEAR;
+use crate::climate_change::ClimateState;
+use crate::flood_protection::{FloodProtectionState, ProtectionStructure, ProtectionType};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::weather::Weather;
+
+/// Jump to the next yearly assessment and run it.
+fn run_assessment(city: &mut TestCity) {
+    let last = city.resource::<ClimateState>().last_assessment_day;
+    city.world_mut().resource_mut::<GameClock>().day = last + 360;
+    city.tick_slow_cycle();
+}
+
+#[test]
+fn test_region_warms_without_emissions() {
+    let mut city = TestCity::new();
+    run_assessment(&mut city);
+
+    let climate = city.resource::<ClimateState>();
+    assert_eq!(climate.cumulative_co2, 0.0);
+    assert!((climate.temperature_increase_f - BACKGROUND_WARMING_F_PER_YEAR).abs() < 1e-6);
+    assert!(climate.sea_level_rise > 0.0);
+}
+
+#[test]
+fn test_traffic_counts_toward_yearly_emissions() {
+    let mut city = TestCity::new();
+    city.world_mut()
+        .resource_mut::<ClimateState>()
+        .traffic_co2_pending = 5_000.0;
+    run_assessment(&mut city);
+
+    let climate = city.resource::<ClimateState>();
+    assert_eq!(climate.yearly_traffic_co2, 5_000.0);
+    assert!(climate.yearly_co2 >= 5_000.0);
+    assert_eq!(climate.traffic_co2_pending, 0.0);
+}
+
+#[test]
+fn test_warming_raises_weather_temperature() {
+    let mut control = TestCity::new();
+    let mut warm = TestCity::new();
+    warm.world_mut()
+        .resource_mut::<ClimateState>()
+        .temperature_increase_f = 9.0;
+
+    control.tick(600);
+    warm.tick(600);
+
+    let base = control.resource::<Weather>().temperature;
+    let warmer = warm.resource::<Weather>().temperature;
+    assert!(
+        warmer > base + 2.0,
+        "warming should lift temperatures ({warmer} vs {base})"
+    );
+}
+
+#[test]
+fn test_rising_sea_floods_shore_unless_seawalled() {
+    let mut city = TestCity::new().with_building(51, 45, ZoneType::ResidentialLow, 1);
+    city.world_mut().remove_resource::<crate::TestSafetyNet>();
+    {
+        let world = city.world_mut();
+        let mut grid = world.resource_mut::<WorldGrid>();
+        for y in 40..60 {
+            grid.get_mut(50, y).cell_type = CellType::Water;
+            grid.get_mut(51, y).elevation = 0.3;
+            grid.get_mut(52, y).elevation = 0.6;
+        }
+        world
+            .resource_mut::<FloodProtectionState>()
+            .structures
+            .push(ProtectionStructure::new(51, 50, ProtectionType::Seawall));
+    }
+    run_assessment(&mut city);
+
+    let grid = city.resource::<WorldGrid>();
+    assert_eq!(grid.get(51, 45).cell_type, CellType::Water);
+    assert_eq!(grid.get(51, 50).cell_type, CellType::Grass, "seawall holds");
+    assert_eq!(
+        grid.get(52, 45).cell_type,
+        CellType::Grass,
+        "high ground stays dry"
+    );
+
+    let climate = city.resource::<ClimateState>();
+    assert!(climate.flooded_cells_count > 0);
+    assert_eq!(climate.cells_held_by_seawalls, 1);
+
+    let world = city.world_mut();
+    let mut q = world.query::<&Building>();
+    assert_eq!(
+        q.iter(world)
+            .filter(|b| (b.grid_x, b.grid_y) == (51, 45))
+            .count(),
+        0,
+        "flooded buildings are lost"
+    );
+}
@@ -0,0 +1,138 @@
+//! Integration tests for beach erosion, breakwaters and beach nourishment.
+
+use crate::coastal_erosion::*;
+use crate::config::WATER_THRESHOLD;
+use crate::economy::CityBudget;
+use crate::grid::{CellType, WorldGrid};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::tourism::Tourism;
+
+/// A strip of sea along x = 50 with a low beach to the east and a bluff to
+/// the west.
+fn coastal_city() -> TestCity {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for y in 40..60 {
+            grid.get_mut(50, y).cell_type = CellType::Water;
+            grid.get_mut(49, y).elevation = 0.6;
+            grid.get_mut(51, y).elevation = WATER_THRESHOLD + 0.01;
+        }
+    }
+    city
+}
+
+/// Run `days` daily coast updates.
+fn advance_days(city: &mut TestCity, days: u32) {
+    for _ in 0..days {
+        city.world_mut().resource_mut::<GameClock>().day += 1;
+        city.tick(1);
+    }
+}
+
+fn sand_at(city: &TestCity, x: u16, y: u16) -> f32 {
+    city.resource::<CoastState>()
+        .beaches
+        .iter()
+        .find(|b| b.x == x && b.y == y)
+        .map(|b| b.sand)
+        .expect("beach cell")
+}
+
+#[test]
+fn test_low_shore_becomes_beach() {
+    let mut city = coastal_city();
+    advance_days(&mut city, 1);
+
+    let coast = city.resource::<CoastState>();
+    assert!(coast.beaches.iter().any(|b| (b.x, b.y) == (51, 45)));
+    assert!(
+        !coast.beaches.iter().any(|b| b.x == 49),
+        "the bluff is not a beach"
+    );
+    assert!(city.resource::<ErosionGrid>().get(51, 45) > 0);
+    assert_eq!(city.resource::<ErosionGrid>().get(49, 45), 0);
+}
+
+#[test]
+fn test_beaches_erode_over_time() {
+    let mut city = coastal_city();
+    advance_days(&mut city, 1);
+    let start = sand_at(&city, 51, 45);
+    advance_days(&mut city, 30);
+    assert!(sand_at(&city, 51, 45) < start);
+    assert!(city.resource::<CoastState>().health() < 1.0);
+}
+
+#[test]
+fn test_breakwater_shelters_the_beach_behind_it() {
+    let mut city = coastal_city();
+    city.world_mut()
+        .resource_mut::<CoastState>()
+        .breakwaters
+        .push(Breakwater::new(50, 42));
+    advance_days(&mut city, 30);
+
+    assert!(sand_at(&city, 51, 42) > sand_at(&city, 51, 57));
+}
+
+#[test]
+fn test_breakwaters_go_in_the_water_off_the_shore() {
+    let city = coastal_city();
+    let grid = city.resource::<WorldGrid>();
+    assert!(can_place_breakwater(grid, 50, 45));
+    assert!(!can_place_breakwater(grid, 51, 45), "not on land");
+}
+
+#[test]
+fn test_nourishment_restores_sand_for_a_price() {
+    let mut city = coastal_city();
+    advance_days(&mut city, 60);
+    let cost = city.resource::<CoastState>().nourishment_cost();
+    assert!(cost > 0.0);
+    let treasury = city.resource::<CityBudget>().treasury;
+
+    city.world_mut().send_event(BeachNourishmentRequest);
+    city.tick(1);
+
+    let coast = city.resource::<CoastState>();
+    assert_eq!(coast.health(), 1.0);
+    assert_eq!(coast.nourishments, 1);
+    assert!(city.resource::<CityBudget>().treasury < treasury);
+}
+
+#[test]
+fn test_nourishment_refused_without_funds() {
+    let mut city = coastal_city();
+    advance_days(&mut city, 60);
+    city.world_mut().resource_mut::<CityBudget>().treasury = 0.0;
+
+    city.world_mut().send_event(BeachNourishmentRequest);
+    city.tick(1);
+
+    let coast = city.resource::<CoastState>();
+    assert!(coast.health() < 1.0);
+    assert_eq!(coast.nourishments, 0);
+}
+
+#[test]
+fn test_eroded_beaches_draw_fewer_tourists() {
+    let mut sandy = coastal_city();
+    let mut eroded = coastal_city();
+    advance_days(&mut sandy, 1);
+    advance_days(&mut eroded, 1);
+    for beach in &mut eroded.world_mut().resource_mut::<CoastState>().beaches {
+        beach.sand = 0.0;
+    }
+    assert!(sandy.resource::<CoastState>().tourism_points() > 0.0);
+    assert_eq!(eroded.resource::<CoastState>().tourism_points(), 0.0);
+
+    // Run the monthly tourism update.
+    advance_days(&mut sandy, 31);
+    advance_days(&mut eroded, 31);
+    assert!(
+        sandy.resource::<Tourism>().natural_beauty_score
+            > eroded.resource::<Tourism>().natural_beauty_score
+    );
+}
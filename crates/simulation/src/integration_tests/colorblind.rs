@@ -27,22 +27,25 @@ fn test_colorblind_mode_persists_across_ticks() {
 }
 
 #[test]
-fn test_colorblind_settings_saveable() {
+fn test_colorblind_mode_survives_new_city() {
     use crate::colorblind::{ColorblindMode, ColorblindSettings};
-    use crate::Saveable;
+    use crate::SaveableRegistry;
 
-    // Default should not save
-    let default_settings = ColorblindSettings::default();
-    assert!(
-        default_settings.save_to_bytes().is_none(),
-        "default settings should skip save"
-    );
+    let mut city = TestCity::new();
+    city.world_mut().resource_mut::<ColorblindSettings>().mode = ColorblindMode::Deuteranopia;
+
+    // Starting a new city resets every saveable resource; the mode is a
+    // player preference, kept in the profile rather than the save.
+    {
+        let w = city.world_mut();
+        let r = w.remove_resource::<SaveableRegistry>().unwrap();
+        r.reset_all(w);
+        w.insert_resource(r);
+    }
+    city.tick(1);
 
-    // Non-default should save and restore
-    let settings = ColorblindSettings {
-        mode: ColorblindMode::Deuteranopia,
-    };
-    let bytes = settings.save_to_bytes().expect("should save non-default");
-    let restored = ColorblindSettings::load_from_bytes(&bytes);
-    assert_eq!(restored.mode, ColorblindMode::Deuteranopia);
+    let settings = city.resource::<ColorblindSettings>();
+    assert_eq!(settings.mode, ColorblindMode::Deuteranopia);
+    let profile = city.resource::<crate::profile::PlayerProfile>();
+    assert_eq!(profile.colorblind, ColorblindMode::Deuteranopia);
 }
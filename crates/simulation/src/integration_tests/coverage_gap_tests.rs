@@ -0,0 +1,89 @@
+//! Integration tests for coverage gap analysis and placement suggestions.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::coverage_gaps::{BuildSuggestion, CoverageGapAnalysis, CoverageGapRequest};
+use crate::economy::CityBudget;
+use crate::grid::ZoneType;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::test_harness::TestCity;
+
+/// A city with one populated residential block and no services.
+fn populated_city(treasury: f64) -> TestCity {
+    let mut city =
+        TestCity::new()
+            .with_budget(treasury)
+            .with_building(60, 60, ZoneType::ResidentialMedium, 2);
+    let world = city.world_mut();
+    let mut q = world.query::<&mut Building>();
+    for mut building in q.iter_mut(world) {
+        building.occupants = 40;
+    }
+    city.tick(1);
+    city
+}
+
+fn analyse(city: &mut TestCity, service_type: ServiceType) {
+    city.world_mut().send_event(CoverageGapRequest {
+        service_type,
+        count: 3,
+    });
+    city.world_mut().run_schedule(Update);
+}
+
+fn service_count(city: &mut TestCity) -> usize {
+    let world = city.world_mut();
+    let mut q = world.query::<&ServiceBuilding>();
+    q.iter(world).count()
+}
+
+#[test]
+fn test_analysis_reports_uncovered_residents() {
+    let mut city = populated_city(100_000.0);
+    analyse(&mut city, ServiceType::FireHouse);
+
+    let analysis = city.resource::<CoverageGapAnalysis>();
+    assert_eq!(analysis.service_type, Some(ServiceType::FireHouse));
+    assert!(analysis.uncovered_demand >= 40);
+    assert!(!analysis.suggestions.is_empty());
+    assert!(analysis.suggestions[0].newly_covered >= 40);
+}
+
+#[test]
+fn test_build_suggestion_places_service_and_charges() {
+    let mut city = populated_city(100_000.0);
+    analyse(&mut city, ServiceType::PoliceKiosk);
+    let site = city.resource::<CoverageGapAnalysis>().suggestions[0];
+
+    city.world_mut().send_event(BuildSuggestion { index: 0 });
+    city.world_mut().run_schedule(Update);
+    city.tick(1);
+
+    assert_eq!(service_count(&mut city), 1);
+    let world = city.world_mut();
+    let mut q = world.query::<&ServiceBuilding>();
+    let placed = q.iter(world).next().unwrap();
+    assert_eq!((placed.grid_x, placed.grid_y), (site.grid_x, site.grid_y));
+    let expected = 100_000.0 - ServiceBuilding::cost(ServiceType::PoliceKiosk);
+    assert!(city.resource::<CityBudget>().treasury <= expected);
+
+    // The block is now covered, so a fresh analysis finds no gap there.
+    analyse(&mut city, ServiceType::PoliceKiosk);
+    assert_eq!(city.resource::<CoverageGapAnalysis>().uncovered_demand, 0);
+}
+
+#[test]
+fn test_build_suggestion_requires_funds() {
+    let mut city = populated_city(0.0);
+    analyse(&mut city, ServiceType::Hospital);
+
+    city.world_mut().send_event(BuildSuggestion { index: 0 });
+    city.world_mut().run_schedule(Update);
+
+    assert_eq!(service_count(&mut city), 0);
+    assert!(!city
+        .resource::<CoverageGapAnalysis>()
+        .suggestions
+        .is_empty());
+}
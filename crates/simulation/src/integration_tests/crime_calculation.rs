@@ -173,7 +173,7 @@ fn test_crime_police_station_reduces_nearby_crime() {
 
 #[test]
 fn test_crime_police_hq_reduces_crime_more_than_kiosk() {
-    // PoliceHQ has base_reduction=30 vs PoliceKiosk=10.
+    // PoliceHQ fields six patrol units against the kiosk's one.
     let (x, y) = (100, 100);
 
     // City with PoliceKiosk
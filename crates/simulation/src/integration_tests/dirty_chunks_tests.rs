@@ -0,0 +1,126 @@
+//! Integration tests for per-chunk dirty tracking of the slow grid systems.
+
+use crate::dirty_chunks::{DirtyChunkCursor, DirtyChunks};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::happiness::ServiceCoverageGrid;
+use crate::land_value::LandValueGrid;
+use crate::pollution::PollutionGrid;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::test_harness::TestCity;
+
+fn chunk_revisions(grid: &LandValueGrid) -> Vec<u32> {
+    (0..grid.values.chunk_count())
+        .map(|ci| grid.values.chunk_revision(ci))
+        .collect()
+}
+
+#[test]
+fn test_layout_changes_dirty_only_their_chunk() {
+    let mut city = TestCity::new();
+    city.tick(1);
+    let mut cursor = DirtyChunkCursor::default();
+    assert!(city.resource::<DirtyChunks>().take(&mut cursor).is_full());
+
+    {
+        let world = city.world_mut();
+        let mut grid = world.resource_mut::<WorldGrid>();
+        grid.get_mut(100, 100).zone = ZoneType::ResidentialLow;
+        grid.get_mut(10, 10).has_power = true;
+    }
+    city.tick(1);
+
+    let region = city.resource::<DirtyChunks>().take(&mut cursor);
+    let chunk = city.resource::<WorldGrid>().cells.chunk_of(100, 100);
+    assert_eq!(region.chunks().collect::<Vec<_>>(), vec![chunk]);
+}
+
+#[test]
+fn test_removed_service_clears_its_coverage() {
+    let mut city = TestCity::new().with_service(128, 128, ServiceType::Hospital);
+    city.tick_slow_cycle();
+    let idx = ServiceCoverageGrid::idx(140, 128);
+    assert!(city.resource::<ServiceCoverageGrid>().has_health(idx));
+
+    city.bulldoze_service_at(128, 128);
+    city.tick_slow_cycle();
+    assert!(
+        !city.resource::<ServiceCoverageGrid>().has_health(idx),
+        "coverage of a bulldozed hospital should be cleared"
+    );
+}
+
+#[test]
+fn test_service_added_later_keeps_existing_coverage() {
+    let mut city = TestCity::new().with_service(60, 60, ServiceType::FireStation);
+    city.tick_slow_cycle();
+
+    let entity = city
+        .world_mut()
+        .spawn(ServiceBuilding {
+            service_type: ServiceType::PoliceStation,
+            grid_x: 70,
+            grid_y: 60,
+            radius: ServiceBuilding::coverage_radius(ServiceType::PoliceStation),
+        })
+        .id();
+    city.world_mut()
+        .resource_mut::<WorldGrid>()
+        .get_mut(70, 60)
+        .building_id = Some(entity);
+    city.tick(1);
+
+    let coverage = city.resource::<ServiceCoverageGrid>();
+    let idx = ServiceCoverageGrid::idx(65, 60);
+    assert!(coverage.has_fire(idx));
+    assert!(coverage.has_police(idx));
+    assert!(coverage.has_fire(ServiceCoverageGrid::idx(45, 60)));
+}
+
+#[test]
+fn test_settled_land_value_chunks_are_not_rewritten() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycles(40);
+    let centre = city.resource::<LandValueGrid>().values.chunk_of(128, 128);
+    let before = chunk_revisions(city.resource::<LandValueGrid>());
+
+    city.tick_slow_cycles(10);
+    let after = chunk_revisions(city.resource::<LandValueGrid>());
+    assert_eq!(before[centre], after[centre]);
+}
+
+#[test]
+fn test_land_value_reacts_to_zoning_after_settling() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycles(40);
+    assert_eq!(city.resource::<LandValueGrid>().get(128, 128), 50);
+
+    city.world_mut()
+        .resource_mut::<WorldGrid>()
+        .get_mut(128, 128)
+        .zone = ZoneType::Industrial;
+    city.tick_slow_cycles(3);
+    assert!(city.resource::<LandValueGrid>().get(128, 128) < 50);
+}
+
+#[test]
+fn test_road_built_after_start_emits_pollution() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycle();
+    {
+        let world = city.world_mut();
+        let mut grid = world.resource_mut::<WorldGrid>();
+        for x in 100..120 {
+            grid.get_mut(x, 100).cell_type = CellType::Road;
+        }
+    }
+    city.tick_slow_cycles(2);
+
+    let pollution = city.resource::<PollutionGrid>();
+    let chunk = pollution.levels.chunk_of(110, 100);
+    let total: u32 = pollution
+        .levels
+        .chunk_cells(chunk)
+        .map(|(_, _, &level)| level as u32)
+        .sum();
+    assert!(total > 0, "new road cells should be picked up as sources");
+}
@@ -0,0 +1,129 @@
+//! Integration tests for disaster insurance claims, premiums and federal aid.
+
+use bevy::prelude::*;
+
+use crate::budget::ExtendedBudget;
+use crate::disaster_insurance::{
+    AidRequest, AidStatus, CoverageLevel, DisasterDamage, DisasterInsurance, DisasterKind,
+    AID_REVIEW_DAYS, CLAIM_WINDOW_DAYS,
+};
+use crate::economy::CityBudget;
+use crate::grid::ZoneType;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+
+fn set_day(city: &mut TestCity, day: u32) {
+    city.world_mut().resource_mut::<GameClock>().day = day;
+}
+
+/// Document `loss` of tornado damage and let the claim window close.
+fn settled_claim(coverage: CoverageLevel, loss: f64) -> TestCity {
+    let mut city = TestCity::new();
+    city.world_mut()
+        .resource_mut::<DisasterInsurance>()
+        .coverage = coverage;
+    city.world_mut().send_event(DisasterDamage {
+        kind: DisasterKind::Tornado,
+        loss,
+        destroyed: 3,
+        damaged: 2,
+        displaced: 10,
+    });
+    city.tick(1);
+    let day = city.resource::<GameClock>().day;
+    set_day(&mut city, day + CLAIM_WINDOW_DAYS + 1);
+    city.tick_slow_cycle();
+    city
+}
+
+/// Advance past the next budget collection.
+fn collect(city: &mut TestCity) {
+    let last = city.resource::<CityBudget>().last_collection_day;
+    set_day(city, last + 31);
+    city.tick(1);
+}
+
+#[test]
+fn test_insured_claim_pays_out_through_budget() {
+    let mut city = settled_claim(CoverageLevel::Comprehensive, 200_000.0);
+    let insurance = city.resource::<DisasterInsurance>();
+    assert_eq!(insurance.claims.len(), 1);
+    assert!(insurance.claims[0].settled);
+    let payout = CoverageLevel::Comprehensive.payout(200_000.0);
+    assert_eq!(insurance.claims[0].insurance_payout, payout);
+    assert_eq!(insurance.pending_payouts, payout);
+
+    collect(&mut city);
+    let extended = city.resource::<ExtendedBudget>();
+    assert_eq!(extended.income_breakdown.insurance_payouts, payout);
+    assert_eq!(city.resource::<DisasterInsurance>().pending_payouts, 0.0);
+}
+
+#[test]
+fn test_uninsured_claim_pays_nothing() {
+    let city = settled_claim(CoverageLevel::None, 200_000.0);
+    let insurance = city.resource::<DisasterInsurance>();
+    assert!(insurance.claims[0].settled);
+    assert_eq!(insurance.pending_payouts, 0.0);
+}
+
+#[test]
+fn test_premium_is_charged_as_expense() {
+    let mut city = TestCity::new()
+        .with_building(50, 50, ZoneType::ResidentialLow, 2)
+        .with_building(52, 50, ZoneType::CommercialLow, 1);
+    city.world_mut()
+        .resource_mut::<DisasterInsurance>()
+        .coverage = CoverageLevel::Standard;
+    city.tick_slow_cycle();
+    let premium = city.resource::<DisasterInsurance>().monthly_premium;
+    assert!(premium > 0.0);
+
+    collect(&mut city);
+    let extended = city.resource::<ExtendedBudget>();
+    assert_eq!(extended.expense_breakdown.insurance_premiums, premium);
+    assert!(city.resource::<CityBudget>().monthly_expenses >= premium);
+}
+
+fn request_aid(city: &mut TestCity) {
+    city.world_mut().send_event(AidRequest { claim_id: 0 });
+    city.world_mut().run_schedule(Update);
+    let day = city.resource::<GameClock>().day;
+    set_day(city, day + AID_REVIEW_DAYS);
+    city.tick_slow_cycle();
+}
+
+#[test]
+fn test_federal_aid_approved_for_major_damage() {
+    let mut city = settled_claim(CoverageLevel::Basic, 500_000.0);
+    request_aid(&mut city);
+
+    let insurance = city.resource::<DisasterInsurance>();
+    let claim = &insurance.claims[0];
+    assert_eq!(
+        claim.aid,
+        AidStatus::Approved {
+            amount: claim.aid_amount()
+        }
+    );
+    assert!(insurance.pending_aid > 0.0);
+
+    let aid = insurance.pending_aid;
+    collect(&mut city);
+    assert_eq!(
+        city.resource::<ExtendedBudget>()
+            .income_breakdown
+            .disaster_aid,
+        aid
+    );
+}
+
+#[test]
+fn test_federal_aid_denied_for_minor_damage() {
+    let mut city = settled_claim(CoverageLevel::None, 5_000.0);
+    request_aid(&mut city);
+
+    let insurance = city.resource::<DisasterInsurance>();
+    assert_eq!(insurance.claims[0].aid, AidStatus::Denied);
+    assert_eq!(insurance.pending_aid, 0.0);
+}
@@ -0,0 +1,200 @@
+//! Integration tests for disaster reports, debris clearing, temporary
+//! housing demand and reconstruction subsidies.
+
+use crate::buildings::{Building, UnderConstruction};
+use crate::disaster_insurance::{DisasterDamage, DisasterKind, CLAIM_WINDOW_DAYS};
+use crate::disaster_recovery::*;
+use crate::economy::CityBudget;
+use crate::events::{CityEventType, EventJournal};
+use crate::grid::{RoadType, ZoneType};
+use crate::immigration::CityAttractiveness;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::utilities::UtilityType;
+use crate::zones::ZoneDemand;
+
+fn recovery(city: &TestCity) -> &RecoveryState {
+    city.resource::<RecoveryState>()
+}
+
+fn add_debris(city: &mut TestCity, cells: &[(usize, usize)]) {
+    let world = city.world_mut();
+    let mut state = world.resource_mut::<RecoveryState>();
+    for &(x, y) in cells {
+        state.add_debris(x, y);
+    }
+}
+
+#[test]
+fn test_settled_claim_logs_disaster_report() {
+    let mut city = TestCity::new();
+    city.world_mut().send_event(DisasterDamage {
+        kind: DisasterKind::Tornado,
+        loss: 120_000.0,
+        destroyed: 5,
+        damaged: 1,
+        displaced: 150,
+    });
+    city.tick(1);
+    assert_eq!(recovery(&city).displaced, 150);
+
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<GameClock>().day = day + CLAIM_WINDOW_DAYS + 1;
+    city.tick_slow_cycle();
+
+    let journal = city.resource::<EventJournal>();
+    let report = journal
+        .events
+        .iter()
+        .find_map(|e| match &e.event_type {
+            CityEventType::DisasterReport(report) => Some(report),
+            _ => None,
+        })
+        .expect("settled claim should be reported in the journal");
+    assert_eq!(report.disaster, "Tornado");
+    assert_eq!(report.buildings_destroyed, 5);
+    assert_eq!(report.buildings_damaged, 1);
+    assert_eq!(report.economic_loss, 120_000.0);
+    assert!(report.casualties > 0);
+}
+
+#[test]
+fn test_debris_blocks_rebuilding() {
+    let mut city = TestCity::new()
+        .with_budget(0.0)
+        .with_road(100, 128, 140, 128, RoadType::Avenue)
+        .with_zone_rect(102, 125, 138, 127, ZoneType::ResidentialLow)
+        .with_utility(100, 128, UtilityType::PowerPlant)
+        .with_utility(140, 128, UtilityType::WaterTower);
+    let debris: Vec<(usize, usize)> = (125..=127)
+        .flat_map(|y| (102..=120).map(move |x| (x, y)))
+        .collect();
+    add_debris(&mut city, &debris);
+    {
+        let mut attr = city.world_mut().resource_mut::<CityAttractiveness>();
+        attr.overall_score = 90.0;
+        attr.housing_factor = 1.0;
+    }
+
+    city.tick(500);
+
+    let world = city.world_mut();
+    let mut q = world.query::<&Building>();
+    let on_debris = q.iter(world).filter(|b| b.grid_x <= 120).count();
+    assert_eq!(on_debris, 0, "nothing should be built on uncleared debris");
+    assert!(city.building_count() > 0, "clear cells should still grow");
+    assert_eq!(
+        recovery(&city).debris.len(),
+        debris.len(),
+        "no money, no clearing"
+    );
+}
+
+#[test]
+fn test_crews_clear_debris_for_a_fee() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    let debris: Vec<(usize, usize)> = (0..10).map(|x| (50 + x, 50)).collect();
+    add_debris(&mut city, &debris);
+
+    city.tick_slow_cycle();
+
+    let state = recovery(&city);
+    assert_eq!(state.total_cleared as usize, DEBRIS_CELLS_PER_CREW);
+    assert_eq!(state.cleared_sites.len(), DEBRIS_CELLS_PER_CREW);
+    assert!(state.has_debris(59, 50));
+    assert!(city.resource::<CityBudget>().treasury < 100_000.0);
+}
+
+#[test]
+fn test_garbage_services_add_cleanup_crews() {
+    let mut city =
+        TestCity::new()
+            .with_budget(100_000.0)
+            .with_service(80, 80, ServiceType::Landfill);
+    let debris: Vec<(usize, usize)> = (0..10).map(|x| (50 + x, 50)).collect();
+    add_debris(&mut city, &debris);
+
+    city.tick_slow_cycle();
+
+    assert_eq!(
+        recovery(&city).total_cleared as usize,
+        2 * DEBRIS_CELLS_PER_CREW
+    );
+}
+
+#[test]
+fn test_unsheltered_displaced_raise_housing_demand() {
+    let mut control = TestCity::new();
+    let mut city = TestCity::new();
+    city.world_mut().resource_mut::<RecoveryState>().displaced = 100;
+
+    control.tick_slow_cycle();
+    city.tick_slow_cycle();
+
+    let base = control.resource::<ZoneDemand>().residential;
+    let boosted = city.resource::<ZoneDemand>().residential;
+    assert!(
+        boosted > base,
+        "displaced residents should add housing demand ({boosted} vs {base})"
+    );
+    assert!(recovery(&city).displaced < 100, "some displaced resettle");
+}
+
+fn start_construction(city: &mut TestCity, x: usize, y: usize) {
+    city.world_mut().spawn((
+        Building {
+            zone_type: ZoneType::ResidentialLow,
+            level: 1,
+            grid_x: x,
+            grid_y: y,
+            capacity: 10,
+            occupants: 0,
+        },
+        UnderConstruction {
+            ticks_remaining: 200,
+            total_ticks: 200,
+        },
+    ));
+}
+
+fn construction_ticks(city: &mut TestCity) -> u32 {
+    let world = city.world_mut();
+    let mut q = world.query::<&UnderConstruction>();
+    q.iter(world).map(|c| c.ticks_remaining).next().unwrap()
+}
+
+#[test]
+fn test_subsidies_speed_up_reconstruction() {
+    let mut city = TestCity::new().with_budget(50_000.0);
+    {
+        let mut state = city.world_mut().resource_mut::<RecoveryState>();
+        state.add_debris(60, 60);
+        state.clear_debris(1);
+        state.subsidies = true;
+    }
+    start_construction(&mut city, 60, 60);
+    city.tick(1);
+
+    assert!(construction_ticks(&mut city) <= 100);
+    let state = recovery(&city);
+    assert_eq!(state.total_rebuilt, 1);
+    assert_eq!(state.total_subsidies, RECONSTRUCTION_SUBSIDY);
+    assert!(state.cleared_sites.is_empty());
+}
+
+#[test]
+fn test_no_subsidy_off_disaster_sites() {
+    let mut city = TestCity::new().with_budget(50_000.0);
+    {
+        let mut state = city.world_mut().resource_mut::<RecoveryState>();
+        state.add_debris(60, 60);
+        state.clear_debris(1);
+        state.subsidies = true;
+    }
+    start_construction(&mut city, 70, 70);
+    city.tick(1);
+
+    assert!(construction_ticks(&mut city) > 100);
+    assert_eq!(recovery(&city).total_subsidies, 0.0);
+}
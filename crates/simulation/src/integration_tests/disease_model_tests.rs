@@ -1,5 +1,6 @@
 //! Integration tests for the disease model (SERV-006).
 
+use crate::chunked_grid::ChunkedGrid;
 use crate::disease_model::{DiseaseState, DiseaseStatus, DiseaseType};
 use crate::pollution::PollutionGrid;
 use crate::services::ServiceType;
@@ -172,12 +173,12 @@ fn test_mortality_starts_at_zero() {
 #[test]
 fn test_high_pollution_increases_respiratory_risk() {
     let clean = PollutionGrid {
-        levels: vec![0; 100],
+        levels: ChunkedGrid::new(10, 10, 0),
         width: 10,
         height: 10,
     };
     let dirty = PollutionGrid {
-        levels: vec![200; 100],
+        levels: ChunkedGrid::new(10, 10, 200),
         width: 10,
         height: 10,
     };
@@ -0,0 +1,72 @@
+//! Integration tests for per-district density caps and setbacks.
+
+use crate::district_policies::{DistrictPolicyLookup, DistrictPolicyState};
+use crate::districts::DistrictMap;
+use crate::grid::{RoadType, ZoneType};
+use crate::immigration::CityAttractiveness;
+use crate::test_harness::TestCity;
+use crate::utilities::UtilityType;
+
+/// Two residential strips on either side of a powered, watered road.
+fn two_strip_city() -> TestCity {
+    TestCity::new()
+        .with_budget(500_000.0)
+        .with_road(100, 128, 160, 128, RoadType::Avenue)
+        .with_zone_rect(102, 125, 128, 127, ZoneType::ResidentialLow)
+        .with_zone_rect(132, 125, 158, 127, ZoneType::ResidentialLow)
+        .with_utility(100, 128, UtilityType::PowerPlant)
+        .with_utility(160, 128, UtilityType::WaterTower)
+}
+
+/// Put the western strip in district 0 with the given density cap.
+fn cap_western_strip(city: &mut TestCity, max_level: u8) {
+    let world = city.world_mut();
+    {
+        let mut map = world.resource_mut::<DistrictMap>();
+        for y in 125..=127 {
+            for x in 102..=128 {
+                map.assign_cell_to_district(x, y, 0);
+            }
+        }
+    }
+    world
+        .resource_mut::<DistrictPolicyState>()
+        .set_max_level(0, Some(max_level));
+    let mut attr = world.resource_mut::<CityAttractiveness>();
+    attr.overall_score = 90.0;
+    attr.housing_factor = 1.0;
+}
+
+fn buildings_west_of(city: &mut TestCity, x_limit: usize) -> usize {
+    let world = city.world_mut();
+    let mut q = world.query::<&crate::buildings::Building>();
+    q.iter(world).filter(|b| b.grid_x <= x_limit).count()
+}
+
+#[test]
+fn test_density_cap_is_applied_without_waiting_for_slow_tick() {
+    let mut city = two_strip_city();
+    cap_western_strip(&mut city, 2);
+    city.tick(1);
+
+    let lookup = city.resource::<DistrictPolicyLookup>();
+    assert_eq!(lookup.level_cap_at(110, 126), Some(2));
+    assert_eq!(lookup.level_cap_at(140, 126), None);
+}
+
+#[test]
+fn test_zero_density_cap_freezes_construction() {
+    let mut city = two_strip_city();
+    cap_western_strip(&mut city, 0);
+    city.tick(500);
+
+    assert_eq!(
+        buildings_west_of(&mut city, 128),
+        0,
+        "no buildings should spawn in a district capped at level 0"
+    );
+    assert!(
+        city.building_count() > 0,
+        "the uncapped strip should still grow"
+    );
+}
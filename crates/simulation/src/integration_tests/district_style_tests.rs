@@ -0,0 +1,39 @@
+//! Integration tests for per-district architecture styles.
+
+use crate::district_styles::{ArchitectureStyle, DistrictStyles};
+use crate::districts::DistrictMap;
+use crate::test_harness::TestCity;
+use crate::SaveableRegistry;
+
+#[test]
+fn test_district_styles_exist_and_default_to_mixed() {
+    let city = TestCity::new();
+    city.assert_resource_exists::<DistrictStyles>();
+    let styles = city.resource::<DistrictStyles>();
+    let map = city.resource::<DistrictMap>();
+    assert_eq!(styles.style_at(50, 50, map), ArchitectureStyle::Mixed);
+}
+
+#[test]
+fn test_district_styles_survive_save_load() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        let mut styles = world.resource_mut::<DistrictStyles>();
+        styles.set(0, ArchitectureStyle::WhiteCity);
+        styles.set(3, ArchitectureStyle::OttomanStone);
+    }
+
+    let world = city.world_mut();
+    let registry = world.remove_resource::<SaveableRegistry>().unwrap();
+    let extensions = registry.save_all(world);
+    registry.reset_all(world);
+    assert!(world.resource::<DistrictStyles>().styles.is_empty());
+    registry.load_all(world, &extensions);
+    world.insert_resource(registry);
+
+    let styles = city.resource::<DistrictStyles>();
+    assert_eq!(styles.get(0), ArchitectureStyle::WhiteCity);
+    assert_eq!(styles.get(3), ArchitectureStyle::OttomanStone);
+    assert_eq!(styles.get(1), ArchitectureStyle::Mixed);
+}
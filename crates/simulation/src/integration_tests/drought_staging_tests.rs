@@ -0,0 +1,75 @@
+//! Integration tests for drought staging and rationing tiers.
+
+use crate::drought::DroughtState;
+use crate::drought_staging::{DroughtStage, DroughtStagingState};
+use crate::grid::ZoneType;
+use crate::test_harness::TestCity;
+use crate::water_demand::WaterSupply;
+
+/// Pin the drought index by filling the rainfall history and preventing new
+/// daily records from being appended.
+fn set_rainfall(city: &mut TestCity, daily_mm: f32) {
+    let mut drought = city.world_mut().resource_mut::<DroughtState>();
+    drought.rainfall_history = vec![daily_mm; 30];
+    drought.last_record_day = u32::MAX;
+}
+
+fn city_with_homes() -> TestCity {
+    TestCity::new()
+        .with_budget(100_000.0)
+        .with_building(100, 100, ZoneType::ResidentialLow, 2)
+        .with_building(102, 100, ZoneType::ResidentialLow, 2)
+        .with_building(104, 100, ZoneType::Industrial, 2)
+}
+
+#[test]
+fn test_no_stage_without_drought() {
+    let mut city = city_with_homes();
+    set_rainfall(&mut city, 5.0);
+    city.tick_slow_cycles(2);
+    let state = city.resource::<DroughtStagingState>();
+    assert_eq!(state.stage, DroughtStage::None);
+    assert!(state.demand_saved_gpd.abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_extreme_drought_declares_industrial_cuts() {
+    let mut city = city_with_homes();
+    set_rainfall(&mut city, 0.0);
+    city.tick_slow_cycles(2);
+    let state = city.resource::<DroughtStagingState>();
+    assert_eq!(state.stage, DroughtStage::IndustrialCuts);
+    assert!(state.demand_reduction > 0.0);
+}
+
+#[test]
+fn test_restrictions_reduce_city_demand() {
+    let mut city = city_with_homes();
+    set_rainfall(&mut city, 0.0);
+    city.tick_slow_cycles(2);
+    let state = city.resource::<DroughtStagingState>().clone();
+    let supply = city.resource::<WaterSupply>();
+    assert!(
+        state.unrestricted_demand_gpd > 0.0,
+        "buildings should use water"
+    );
+    assert!(state.demand_saved_gpd > 0.0);
+    assert!(
+        (supply.total_demand_gpd - (state.unrestricted_demand_gpd - state.demand_saved_gpd)).abs()
+            < 1.0
+    );
+}
+
+#[test]
+fn test_stage_persists_after_rain_returns() {
+    let mut city = city_with_homes();
+    set_rainfall(&mut city, 0.0);
+    city.tick_slow_cycles(2);
+    set_rainfall(&mut city, 5.0);
+    city.tick_slow_cycle();
+    assert_eq!(
+        city.resource::<DroughtStagingState>().stage,
+        DroughtStage::IndustrialCuts,
+        "restrictions should hold for the minimum stage duration"
+    );
+}
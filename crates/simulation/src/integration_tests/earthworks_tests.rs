@@ -0,0 +1,45 @@
+//! Integration tests for terraforming earthworks.
+
+use crate::earthworks::{apply_terraform, plan_terraform, Earthworks, TerraformOp};
+use crate::economy::CityBudget;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::test_harness::TestCity;
+
+fn terraform(city: &mut TestCity, x: usize, y: usize, op: TerraformOp) -> Result<f64, String> {
+    let world = city.world_mut();
+    world.resource_scope(|world, mut grid: bevy::prelude::Mut<WorldGrid>| {
+        world.resource_scope(|world, mut earthworks: bevy::prelude::Mut<Earthworks>| {
+            let mut budget = world.resource_mut::<CityBudget>();
+            let plan = plan_terraform(&grid, x, y, op)?;
+            apply_terraform(&plan, &mut grid, &mut earthworks, &mut budget).map(|bill| bill.cost)
+        })
+    })
+}
+
+#[test]
+fn test_terraforming_is_charged_and_balances_soil() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    let cost = terraform(&mut city, 50, 50, TerraformOp::Raise).expect("open ground");
+    assert!(cost > 0.0);
+    assert!(city.budget().treasury < 100_000.0);
+    assert!(city.cell(50, 50).elevation > 0.0);
+    assert!(
+        city.resource::<Earthworks>().imported_m3 > 0.0,
+        "with an empty stockpile the fill is imported"
+    );
+
+    terraform(&mut city, 50, 50, TerraformOp::Lower).expect("open ground");
+    assert!(city.resource::<Earthworks>().stockpile_m3 > 0.0);
+}
+
+#[test]
+fn test_terraforming_next_to_a_building_is_refused() {
+    let mut city =
+        TestCity::new()
+            .with_budget(100_000.0)
+            .with_building(51, 50, ZoneType::ResidentialLow, 1);
+    let elevation = city.cell(51, 50).elevation;
+    assert!(terraform(&mut city, 50, 50, TerraformOp::Raise).is_err());
+    assert_eq!(city.cell(51, 50).elevation, elevation);
+    assert_eq!(city.resource::<Earthworks>().total_spent, 0.0);
+}
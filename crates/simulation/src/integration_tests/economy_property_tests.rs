@@ -364,7 +364,10 @@ fn test_property_income_breakdown_sums_to_total_across_seeds() {
             + ib.commercial_tax
             + ib.industrial_tax
             + ib.office_tax
-            + ib.trade_income;
+            + ib.trade_income
+            + ib.insurance_payouts
+            + ib.disaster_aid
+            + ib.carbon_revenue;
 
         assert!(
             (budget.monthly_income - sum).abs() < 0.01,
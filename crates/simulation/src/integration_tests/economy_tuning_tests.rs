@@ -64,7 +64,8 @@ fn test_expense_breakdown_with_fuel_sums_to_total() {
     let breakdown_sum = ext.expense_breakdown.road_maintenance
         + ext.expense_breakdown.service_costs
         + ext.expense_breakdown.policy_costs
-        + ext.expense_breakdown.fuel_costs;
+        + ext.expense_breakdown.fuel_costs
+        + ext.expense_breakdown.insurance_premiums;
 
     assert!(
         (budget.monthly_expenses - breakdown_sum).abs() < 0.01,
@@ -0,0 +1,160 @@
+//! Integration tests checking that emergency dispatch routes through the path
+//! queue at `PathPriority::Emergency`, is not held up by citizen requests, and
+//! sends the station with the shortest route rather than the nearest one.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::citizen::{Citizen, CitizenState, CitizenStateComp, PathCache, PathRequest};
+use crate::fire::OnFire;
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::movement::PathPriority;
+use crate::service_road_dispatch::{EmergencyKind, ResponderRoute, ServiceDispatchState};
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+
+fn fire_city() -> TestCity {
+    let mut city = TestCity::new()
+        .with_road(10, 50, 40, 50, RoadType::Local)
+        .with_service(10, 49, ServiceType::FireStation)
+        .with_building(40, 49, ZoneType::Industrial, 1)
+        .rebuild_csr();
+    ignite_target(&mut city);
+    city
+}
+
+/// A fire at (40, 49) with one station 12 cells away across a river whose
+/// only bridge is 30 cells upstream, and one 20 cells away on the same bank.
+fn river_city() -> TestCity {
+    let mut city = TestCity::new()
+        .with_road(20, 50, 70, 50, RoadType::Local)
+        .with_road(70, 50, 70, 60, RoadType::Local)
+        .with_road(40, 60, 70, 60, RoadType::Local)
+        .with_service(40, 61, ServiceType::FireStation)
+        .with_service(20, 49, ServiceType::FireStation)
+        .with_building(40, 49, ZoneType::Industrial, 1)
+        .rebuild_csr();
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for y in 52..=58 {
+            for x in 20..70 {
+                grid.get_mut(x, y).cell_type = CellType::Water;
+            }
+        }
+    }
+    ignite_target(&mut city);
+    city
+}
+
+fn ignite_target(city: &mut TestCity) {
+    let world = city.world_mut();
+    let mut buildings = world.query::<(Entity, &Building)>();
+    let burning: Vec<Entity> = buildings
+        .iter(world)
+        .filter(|(_, b)| (b.grid_x, b.grid_y) == (40, 49))
+        .map(|(e, _)| e)
+        .collect();
+    for entity in burning {
+        world.entity_mut(entity).insert(OnFire {
+            intensity: 60.0,
+            ticks_burning: 0,
+        });
+    }
+}
+
+fn fire_trucks(city: &TestCity) -> usize {
+    city.resource::<ServiceDispatchState>()
+        .vehicles
+        .iter()
+        .filter(|v| v.kind == EmergencyKind::Fire && v.target == (40, 49))
+        .count()
+}
+
+fn waiting_citizens(city: &mut TestCity) -> usize {
+    let world = city.world_mut();
+    world
+        .query_filtered::<(), (With<Citizen>, With<PathRequest>)>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn test_emergency_dispatch_requests_route_with_emergency_priority() {
+    let mut city = fire_city();
+    let mut responder_priorities = Vec::new();
+    for _ in 0..40 {
+        city.tick(1);
+        let world = city.world_mut();
+        let mut routes = world.query::<(&ResponderRoute, Option<&PathPriority>)>();
+        responder_priorities.extend(routes.iter(world).map(|(_, p)| p.copied()));
+        if fire_trucks(&city) > 0 {
+            break;
+        }
+    }
+
+    assert!(
+        !responder_priorities.is_empty(),
+        "dispatch should go through a responder's path request"
+    );
+    assert!(responder_priorities
+        .iter()
+        .all(|&p| p == Some(PathPriority::Emergency)));
+    assert_eq!(
+        fire_trucks(&city),
+        1,
+        "the truck should set off on its route"
+    );
+}
+
+#[test]
+fn test_emergency_dispatch_overtakes_saturated_path_queue() {
+    let mut city = fire_city();
+    for _ in 0..5000 {
+        city.world_mut().spawn((
+            Citizen,
+            CitizenStateComp(CitizenState::AtHome),
+            PathCache::new(Vec::new()),
+            PathRequest {
+                from_gx: 10,
+                from_gy: 50,
+                to_gx: 40,
+                to_gy: 50,
+                target_state: CitizenState::CommutingToWork,
+            },
+        ));
+    }
+
+    let mut ticks = 0;
+    while fire_trucks(&city) == 0 && ticks < 40 {
+        city.tick(1);
+        ticks += 1;
+    }
+
+    assert_eq!(fire_trucks(&city), 1, "no truck after {ticks} ticks");
+    assert!(
+        waiting_citizens(&mut city) > 0,
+        "the truck should set off while citizens are still queued"
+    );
+}
+
+#[test]
+fn test_emergency_dispatch_sends_station_with_shortest_route() {
+    let mut city = river_city();
+    let mut ticks = 0;
+    while fire_trucks(&city) == 0 && ticks < 40 {
+        city.tick(1);
+        ticks += 1;
+    }
+
+    let dispatch = city.resource::<ServiceDispatchState>();
+    let truck = dispatch
+        .vehicles
+        .iter()
+        .find(|v| v.kind == EmergencyKind::Fire)
+        .unwrap_or_else(|| panic!("no truck after {ticks} ticks"));
+    assert_eq!(
+        truck.origin,
+        (20, 49),
+        "the station across the river is nearer but has the longer route"
+    );
+}
@@ -304,7 +304,7 @@ fn test_env_grid_corrupted_bytes_fallback_to_default() {
 
     let trees = TreeGrid::load_from_bytes(&garbage);
     assert_eq!(trees.cells.len(), GRID_WIDTH * GRID_HEIGHT);
-    assert!(trees.cells.iter().all(|&v| !v));
+    assert_eq!(trees.tree_count(), 0);
 
     let groundwater = GroundwaterGrid::load_from_bytes(&garbage);
     assert!(groundwater.levels.iter().all(|&v| v == 128));
@@ -76,7 +76,7 @@ fn test_green_coverage_with_trees() {
         let mut tree_grid = world.resource_mut::<TreeGrid>();
         let target = tree_grid.cells.len() / 10;
         for i in 0..target {
-            tree_grid.cells[i] = true;
+            tree_grid.cells.set_index(i, true);
         }
     }
 
@@ -212,9 +212,7 @@ fn test_full_trees_boost_green_coverage_and_overall() {
     {
         let world = city.world_mut();
         let mut tree_grid = world.resource_mut::<TreeGrid>();
-        for v in tree_grid.cells.iter_mut() {
-            *v = true;
-        }
+        tree_grid.cells.fill(true);
     }
 
     city.tick_slow_cycle();
@@ -0,0 +1,69 @@
+use crate::event_catalog::{
+    DisabledEventCategories, EventCatalog, EventCategory, EventDefinition, EventEffect,
+    EventPrerequisites, JournalKind,
+};
+use crate::events::{CityEventType, EventJournal};
+use crate::test_harness::TestCity;
+
+fn grant_catalog(prerequisites: EventPrerequisites) -> EventCatalog {
+    EventCatalog {
+        events: vec![EventDefinition {
+            id: "state_grant".to_string(),
+            name: "State Grant".to_string(),
+            category: EventCategory::Economy,
+            chance: 1.0,
+            prerequisites,
+            effects: vec![EventEffect::Treasury { amount: 1000.0 }],
+            journal: JournalKind::Disaster,
+            description: "A state grant arrives.".to_string(),
+        }],
+    }
+}
+
+fn grant_count(city: &TestCity) -> usize {
+    city.resource::<EventJournal>()
+        .events
+        .iter()
+        .filter(|e| matches!(&e.event_type, CityEventType::DisasterStrike(n) if n == "State Grant"))
+        .count()
+}
+
+#[test]
+fn test_event_catalog_resources_exist() {
+    let city = TestCity::new();
+    city.assert_resource_exists::<EventCatalog>();
+    city.assert_resource_exists::<DisabledEventCategories>();
+}
+
+#[test]
+fn test_event_catalog_custom_event_fires() {
+    let mut city = TestCity::new().with_budget(10_000.0);
+    city.world_mut()
+        .insert_resource(grant_catalog(EventPrerequisites::default()));
+    city.tick_slow_cycle();
+    assert_eq!(grant_count(&city), 1);
+}
+
+#[test]
+fn test_event_catalog_disabled_category_does_not_fire() {
+    let mut city = TestCity::new().with_budget(10_000.0);
+    city.world_mut()
+        .insert_resource(grant_catalog(EventPrerequisites::default()));
+    city.world_mut()
+        .resource_mut::<DisabledEventCategories>()
+        .set_enabled(EventCategory::Economy, false);
+    city.tick_slow_cycles(3);
+    assert_eq!(grant_count(&city), 0);
+}
+
+#[test]
+fn test_event_catalog_prerequisites_block_event() {
+    let mut city = TestCity::new().with_budget(10_000.0);
+    city.world_mut()
+        .insert_resource(grant_catalog(EventPrerequisites {
+            min_population: 1_000_000,
+            ..Default::default()
+        }));
+    city.tick_slow_cycles(3);
+    assert_eq!(grant_count(&city), 0);
+}
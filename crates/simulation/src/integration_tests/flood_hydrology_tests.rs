@@ -0,0 +1,89 @@
+//! Integration tests for the rainfall-driven flood model: terrain-following
+//! flow, spatial drainage and river overtopping.
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::flood_simulation::{FloodGrid, RiverState};
+use crate::grid::{CellType, WorldGrid};
+use crate::storm_drainage::{StormDrainageInfrastructure, StormDrainageType};
+use crate::test_harness::TestCity;
+
+fn flood_patch(city: &mut TestCity, xs: std::ops::Range<usize>, ys: std::ops::Range<usize>) {
+    let mut flood = city.world_mut().resource_mut::<FloodGrid>();
+    for y in ys {
+        for x in xs.clone() {
+            flood.set(x, y, 2.0);
+        }
+    }
+}
+
+#[test]
+fn test_flood_water_collects_in_valley() {
+    let mut city = TestCity::new();
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for y in 0..GRID_HEIGHT {
+            for x in 0..GRID_WIDTH {
+                grid.get_mut(x, y).elevation = 0.3 + x.abs_diff(100) as f32 * 0.005;
+            }
+        }
+    }
+    flood_patch(&mut city, 90..111, 90..110);
+
+    city.tick_slow_cycles(3);
+
+    let flood = city.resource::<FloodGrid>();
+    let floor = flood.get(100, 100);
+    let slope = flood.get(94, 100);
+    assert!(
+        floor > slope,
+        "valley floor should be deeper than its slope: {floor} vs {slope}"
+    );
+}
+
+#[test]
+fn test_storm_drain_clears_nearby_flooding() {
+    let mut city = TestCity::new();
+    flood_patch(&mut city, 50..55, 50..55);
+    flood_patch(&mut city, 150..155, 50..55);
+    city.world_mut().spawn(StormDrainageInfrastructure {
+        drainage_type: StormDrainageType::StormDrain,
+        grid_x: 52,
+        grid_y: 52,
+    });
+
+    city.tick_slow_cycles(2);
+
+    let flood = city.resource::<FloodGrid>();
+    let drained = flood.get(52, 52);
+    let undrained = flood.get(152, 52);
+    assert!(
+        drained < undrained,
+        "drain should lower nearby water: {drained} vs {undrained}"
+    );
+}
+
+#[test]
+fn test_high_river_floods_low_bank() {
+    let mut city = TestCity::new();
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for x in 0..GRID_WIDTH {
+            for y in 0..GRID_HEIGHT {
+                let cell = grid.get_mut(x, y);
+                cell.elevation = if y < 100 { 0.6 } else { 0.3 };
+            }
+            let river = grid.get_mut(x, 100);
+            river.cell_type = CellType::Water;
+            river.elevation = 0.2;
+        }
+    }
+    city.world_mut().resource_mut::<RiverState>().stage_ft = 25.0;
+
+    city.tick_slow_cycle();
+
+    let river = city.resource::<RiverState>();
+    assert!(river.overtopped_cells > 0);
+    let flood = city.resource::<FloodGrid>();
+    assert!(flood.get(50, 101) > 0.0, "low bank should flood");
+    assert_eq!(flood.get(50, 99), 0.0, "high bank should stay dry");
+}
@@ -97,6 +97,7 @@ fn test_blueprint_place_skips_water_cells() {
                 zone_type: crate::blueprints::BlueprintZoneType::ResidentialLow,
             },
         ],
+        services: vec![],
     };
 
     let world = city.world_mut();
@@ -130,6 +131,7 @@ fn test_blueprint_library_saveable_persistence() {
             dy: 0,
             zone_type: crate::blueprints::BlueprintZoneType::CommercialHigh,
         }],
+        services: vec![],
     });
 
     // Save to bytes and restore
@@ -0,0 +1,92 @@
+//! Integration tests for difficulty presets and sandbox toggles.
+
+use bevy::prelude::*;
+
+use crate::buildings::{Building, UnderConstruction};
+use crate::economy::CityBudget;
+use crate::game_settings::{Difficulty, GameSettings, UNLIMITED_TREASURY};
+use crate::grid::ZoneType;
+use crate::loans::{LoanBook, LoanTier};
+use crate::test_harness::TestCity;
+use crate::zones::ZoneDemand;
+
+/// Spawn a building that still has a long way to go.
+fn spawn_construction_site(city: &mut TestCity) -> Entity {
+    city.world_mut()
+        .spawn((
+            Building {
+                zone_type: ZoneType::ResidentialLow,
+                level: 1,
+                grid_x: 20,
+                grid_y: 20,
+                capacity: Building::capacity_for_level(ZoneType::ResidentialLow, 1),
+                occupants: 0,
+            },
+            UnderConstruction {
+                ticks_remaining: 500,
+                total_ticks: 500,
+            },
+        ))
+        .id()
+}
+
+#[test]
+fn test_instant_construction_finishes_next_tick() {
+    let mut city = TestCity::new();
+    let mut settings = GameSettings::default();
+    settings.sandbox.instant_construction = true;
+    city.world_mut().insert_resource(settings);
+
+    let site = spawn_construction_site(&mut city);
+    city.tick(2);
+    assert!(city.world_mut().get::<UnderConstruction>(site).is_none());
+}
+
+#[test]
+fn test_construction_takes_time_by_default() {
+    let mut city = TestCity::new();
+    let site = spawn_construction_site(&mut city);
+    city.tick(2);
+    assert!(city.world_mut().get::<UnderConstruction>(site).is_some());
+}
+
+#[test]
+fn test_unlimited_money_refills_treasury() {
+    let mut city = TestCity::new().with_budget(-5_000.0);
+    let mut settings = GameSettings::default();
+    settings.sandbox.unlimited_money = true;
+    city.world_mut().insert_resource(settings);
+
+    city.tick(1);
+    assert!(city.resource::<CityBudget>().treasury > UNLIMITED_TREASURY * 0.99);
+}
+
+#[test]
+fn test_difficulty_scales_loan_rates() {
+    let rate = |preset: Difficulty| {
+        let mut book = LoanBook::default();
+        let mut treasury = 0.0;
+        let multiplier = preset.settings().loan_rate_multiplier;
+        assert!(book.take_loan_with_rate(LoanTier::Small, &mut treasury, multiplier));
+        book.active_loans[0].interest_rate
+    };
+    let normal = rate(Difficulty::Normal);
+    assert!((normal - LoanTier::Small.interest_rate()).abs() < 1e-9);
+    assert!(rate(Difficulty::Relaxed) < normal);
+    assert!(rate(Difficulty::Hard) > normal);
+}
+
+#[test]
+fn test_zero_elasticity_flattens_demand() {
+    let mut city = TestCity::new();
+    let mut settings = GameSettings::default();
+    settings.difficulty.demand_elasticity = 0.0;
+    city.world_mut().insert_resource(settings);
+
+    city.tick_slow_cycles(5);
+    let demand = city.resource::<ZoneDemand>();
+    assert!(demand.residential.abs() < 1e-6);
+    assert!(demand.commercial.abs() < 1e-6);
+    assert!(demand.industrial.abs() < 1e-6);
+    assert!(demand.office.abs() < 1e-6);
+}
@@ -51,7 +51,7 @@ fn all_citizen_happiness(city: &mut TestCity) -> Vec<f32> {
 }
 
 /// Spawn a service building at a grid location. The coverage system
-/// (`update_service_coverage`) will detect it via `Added<ServiceBuilding>`
+/// (`update_service_coverage`) will detect it via `Changed<ServiceBuilding>`
 /// and naturally compute coverage flags, which survives change detection.
 fn spawn_service(city: &mut TestCity, gx: usize, gy: usize, service_type: ServiceType) {
     let radius = ServiceBuilding::coverage_radius(service_type);
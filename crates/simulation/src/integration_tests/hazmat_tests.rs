@@ -0,0 +1,84 @@
+//! Integration tests for hazmat spills, cleanup crews and evacuation.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::grid::ZoneType;
+use crate::hazmat::{Evacuated, HazmatKind, HazmatState, SPILL_VOLUME_TONS};
+use crate::services::ServiceType;
+use crate::soil_contamination::SoilContaminationGrid;
+use crate::test_harness::TestCity;
+
+const SITE: (usize, usize) = (80, 80);
+
+/// A city with a factory on the spill site and a house next door.
+fn city_with_spill(city: TestCity) -> TestCity {
+    let mut city = city
+        .with_building(SITE.0, SITE.1, ZoneType::Industrial, 2)
+        .with_building(SITE.0 + 2, SITE.1, ZoneType::ResidentialLow, 1);
+    let house = city.grid().get(SITE.0 + 2, SITE.1).building_id.unwrap();
+    city.world_mut()
+        .get_mut::<Building>(house)
+        .unwrap()
+        .occupants = 5;
+    city.world_mut()
+        .resource_mut::<HazmatState>()
+        .start(HazmatKind::Spill, SITE.0, SITE.1, 1);
+    city
+}
+
+fn house(city: &TestCity) -> Entity {
+    city.grid().get(SITE.0 + 2, SITE.1).building_id.unwrap()
+}
+
+#[test]
+fn test_uncontained_spill_evacuates_and_contaminates() {
+    let mut city = city_with_spill(TestCity::new());
+    city.tick_slow_cycles(5);
+
+    let state = city.resource::<HazmatState>();
+    assert_eq!(state.incidents.len(), 1, "no crews, still leaking");
+    assert!(state.incidents[0].volume < SPILL_VOLUME_TONS);
+    assert!(city.resource::<SoilContaminationGrid>().get(SITE.0, SITE.1) > 0.0);
+
+    let house = house(&city);
+    let world = city.world_mut();
+    assert!(world.get::<Evacuated>(house).is_some());
+    assert_eq!(world.get::<Building>(house).unwrap().occupants, 0);
+}
+
+#[test]
+fn test_cleanup_crews_contain_spill_and_lift_evacuation() {
+    let mut city = city_with_spill(TestCity::new().with_service(
+        SITE.0 + 10,
+        SITE.1,
+        ServiceType::HazmatResponse,
+    ));
+    city.tick_slow_cycles(20);
+
+    let state = city.resource::<HazmatState>();
+    assert!(state.incidents.is_empty());
+    assert_eq!(state.total_contained, 1);
+    assert!(state.tons_cleaned > SPILL_VOLUME_TONS * 0.5);
+
+    let house = house(&city);
+    assert!(city.world_mut().get::<Evacuated>(house).is_none());
+    assert!(
+        city.resource::<SoilContaminationGrid>().get(SITE.0, SITE.1) > 0.0,
+        "soil contamination outlasts the incident"
+    );
+}
+
+#[test]
+fn test_crews_out_of_range_do_not_respond() {
+    let mut city = city_with_spill(TestCity::new().with_service(
+        SITE.0 + 100,
+        SITE.1 + 100,
+        ServiceType::HazmatResponse,
+    ));
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<HazmatState>();
+    assert_eq!(state.incidents.len(), 1);
+    assert_eq!(state.incidents[0].crews, 0);
+}
@@ -0,0 +1,73 @@
+//! Integration tests for the heat-health action plan.
+
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::grid::ZoneType;
+use crate::heat_health::HeatHealthPlan;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::weather::forecast::{event_roll, ForecastEvent};
+use crate::weather::{ClimateZone, WeatherForecast};
+
+/// A summer day that starts a heat event, preceded by two quiet days.
+fn heat_day() -> u32 {
+    (93..=180)
+        .find(|&d| {
+            event_roll(d, ClimateZone::Temperate) == Some(ForecastEvent::HeatWave)
+                && event_roll(d - 1, ClimateZone::Temperate).is_none()
+                && event_roll(d - 2, ClimateZone::Temperate).is_none()
+        })
+        .expect("a forecastable heat day exists")
+}
+
+/// A library next to a populated block, with the full plan adopted.
+fn prepared_city() -> TestCity {
+    let mut city = TestCity::new()
+        .with_budget(100_000.0)
+        .with_service(50, 50, ServiceType::Library)
+        .with_building(53, 53, ZoneType::ResidentialMedium, 2);
+    let world = city.world_mut();
+    let mut q = world.query::<&mut Building>();
+    for mut building in q.iter_mut(world) {
+        building.occupants = 100;
+    }
+    let mut plan = world.resource_mut::<HeatHealthPlan>();
+    plan.cooling_centers = true;
+    plan.outreach = true;
+    plan.hydration_stations = 1;
+    city
+}
+
+#[test]
+fn test_forecast_raises_alert_with_lead_time() {
+    let mut city = prepared_city();
+    city.world_mut().resource_mut::<GameClock>().day = heat_day() - 2;
+    let before = city.resource::<CityBudget>().treasury;
+    city.tick(1);
+
+    assert_eq!(
+        city.resource::<WeatherForecast>().heat_wave_in_days,
+        Some(2)
+    );
+    let plan = city.resource::<HeatHealthPlan>();
+    assert!(plan.alert_active);
+    assert_eq!(plan.lead_days, 2);
+    assert_eq!(plan.cooling_sites, 1);
+    assert!((plan.cooling_coverage - 1.0).abs() < 1e-6);
+    assert!(plan.mortality_reduction > 0.0);
+    assert!(plan.total_cost > 0.0);
+    assert!(city.resource::<CityBudget>().treasury < before);
+}
+
+#[test]
+fn test_no_alert_without_forecast_heat() {
+    let mut city = prepared_city();
+    // Mid-spring: heat events never roll.
+    city.world_mut().resource_mut::<GameClock>().day = 30;
+    city.tick(1);
+
+    let plan = city.resource::<HeatHealthPlan>();
+    assert!(!plan.alert_active);
+    assert_eq!(plan.total_cost, 0.0);
+}
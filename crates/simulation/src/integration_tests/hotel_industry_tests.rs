@@ -0,0 +1,123 @@
+//! Integration tests for hotels: room bookings by tourists, conventions at
+//! convention centers, and hotel revenue feeding `Tourism`.
+
+use crate::grid::ZoneType;
+use crate::hotel_demand::HotelDemandState;
+use crate::hotels::{Hotel, HotelIndustry, PLACED_HOTEL_ROOMS};
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::tourism::Tourism;
+
+/// Run one tick as game day `day`.
+fn run_day(city: &mut TestCity, day: u32) {
+    city.world_mut().resource_mut::<GameClock>().day = day;
+    city.tick(1);
+}
+
+/// Fix this month's tourism so the monthly update does not overwrite it.
+fn set_tourism(city: &mut TestCity, monthly_visitors: u32) {
+    let day = city.clock().day;
+    let mut tourism = city.world_mut().resource_mut::<Tourism>();
+    tourism.monthly_visitors = monthly_visitors;
+    tourism.average_stay_days = 2.0;
+    tourism.last_update_day = day;
+}
+
+fn hotels(city: &mut TestCity) -> Vec<Hotel> {
+    let world = city.world_mut();
+    let mut query = world.query::<&Hotel>();
+    query.iter(world).cloned().collect()
+}
+
+#[test]
+fn test_zoned_and_placed_hotels_get_rooms() {
+    let mut city = TestCity::new()
+        .with_building(50, 50, ZoneType::CommercialHigh, 1)
+        .with_building(54, 50, ZoneType::CommercialLow, 2)
+        .with_service(60, 60, ServiceType::Hotel);
+    city.tick_slow_cycle();
+
+    let mut rooms: Vec<u32> = hotels(&mut city).iter().map(|h| h.rooms).collect();
+    rooms.sort_unstable();
+    assert_eq!(rooms, vec![50, PLACED_HOTEL_ROOMS]);
+    let state = city.resource::<HotelDemandState>();
+    assert_eq!(state.hotel_count, 2);
+    assert_eq!(state.total_capacity, 50 + PLACED_HOTEL_ROOMS);
+}
+
+#[test]
+fn test_tourists_book_rooms_and_pay_hotel_tax() {
+    let mut city = TestCity::new().with_service(60, 60, ServiceType::Hotel);
+    city.tick(1);
+    // 2700 visitors a month = 50 rooms arriving per night for two nights.
+    set_tourism(&mut city, 2700);
+    let start = city.clock().day;
+    for day in start + 1..=start + 3 {
+        run_day(&mut city, day);
+    }
+
+    let industry = city.resource::<HotelIndustry>();
+    assert_eq!(industry.rooms_booked, 100);
+    assert_eq!(industry.turned_away, 0);
+    assert!(industry.current_month.room_revenue > 0.0);
+    assert_eq!(hotels(&mut city)[0].booked, 100);
+
+    city.tick_slow_cycle();
+    let state = city.resource::<HotelDemandState>();
+    assert_eq!(state.rooms_occupied, 100);
+    assert!(state.monthly_tax_revenue > 0.0);
+    assert!(!state.is_over_capacity());
+}
+
+#[test]
+fn test_full_hotels_turn_tourists_away_and_raise_commercial_demand() {
+    let mut city = TestCity::new().with_building(50, 50, ZoneType::CommercialHigh, 1);
+    city.tick(1);
+    set_tourism(&mut city, 27_000);
+    let start = city.clock().day;
+    run_day(&mut city, start + 1);
+
+    let industry = city.resource::<HotelIndustry>();
+    assert_eq!(industry.rooms_booked, 50);
+    assert!(industry.turned_away > 0);
+    assert!(industry.shortage_demand() > 0.0);
+
+    city.tick_slow_cycle();
+    let state = city.resource::<HotelDemandState>();
+    assert!(state.is_over_capacity());
+    assert!(state.lost_revenue > 0.0);
+}
+
+#[test]
+fn test_convention_center_spikes_bookings_and_feeds_tourism() {
+    let mut city = TestCity::new()
+        .with_service(40, 40, ServiceType::ConventionCenter)
+        .with_service(46, 40, ServiceType::Hotel)
+        .with_building(40, 50, ZoneType::CommercialHigh, 5);
+    city.tick(1);
+    set_tourism(&mut city, 0);
+
+    let start = city.clock().day;
+    let mut peak = 0;
+    for day in start + 1..=start + 20 {
+        run_day(&mut city, day);
+        peak = peak.max(city.resource::<HotelIndustry>().rooms_booked);
+    }
+    let industry = city.resource::<HotelIndustry>();
+    let attendees = industry.current_month.convention_attendees;
+    assert!(attendees > 0, "a convention should have been held");
+    assert!(peak > 0, "attendees should have booked rooms");
+    assert!(industry.current_month.room_revenue > 0.0);
+
+    // The next tourism month counts attendees as visitors and collects
+    // hotel tax on their rooms.
+    let due = city.resource::<Tourism>().last_update_day + 31;
+    run_day(&mut city, due);
+    let tourism = city.resource::<Tourism>();
+    assert!(tourism.monthly_visitors >= attendees);
+    let industry = city.resource::<HotelIndustry>();
+    assert_eq!(industry.last_month.convention_attendees, attendees);
+    assert_eq!(industry.current_month.convention_attendees, 0);
+    assert!(tourism.monthly_tourism_income > 0.0);
+}
@@ -0,0 +1,99 @@
+//! Integration tests for hurricanes crossing the map.
+
+use crate::grid::ZoneType;
+use crate::hurricane::{storm_radius, DownedLine, HurricaneState, StormTrack};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::trees::TreeGrid;
+
+/// A slow-moving category 5 storm centered on (x, y) from `landfall_day`.
+fn storm_over(x: f32, y: f32, landfall_day: u32) -> StormTrack {
+    StormTrack {
+        origin: (x, y),
+        heading: 0.0,
+        speed: 1.0,
+        category: 5,
+        radius: storm_radius(5),
+        landfall_day,
+        landed: false,
+    }
+}
+
+fn city_with_trees() -> TestCity {
+    let mut city = TestCity::new();
+    for x in 120..130 {
+        city = city.with_building(x, 140, ZoneType::ResidentialLow, 3);
+    }
+    {
+        let mut trees = city.world_mut().resource_mut::<TreeGrid>();
+        for y in 120..136 {
+            for x in 120..136 {
+                trees.set(x, y, true);
+            }
+        }
+    }
+    city
+}
+
+fn trees_standing(city: &TestCity) -> usize {
+    let trees = city.resource::<TreeGrid>();
+    (120..136)
+        .flat_map(|y| (120..136).map(move |x| (x, y)))
+        .filter(|&(x, y)| trees.has_tree(x, y))
+        .count()
+}
+
+#[test]
+fn test_hurricane_damages_trees_and_roofs_along_path() {
+    let mut city = city_with_trees();
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<HurricaneState>().storm = Some(storm_over(128.0, 132.0, day));
+
+    city.tick_slow_cycles(10);
+
+    let state = city.resource::<HurricaneState>();
+    assert!(state.storm.as_ref().is_some_and(|s| s.landed));
+    assert!(state.current.trees_down > 0);
+    assert!(state.current.roofs_damaged > 0);
+    assert!(trees_standing(&city) < 256);
+}
+
+#[test]
+fn test_forecast_storm_does_no_damage_before_landfall() {
+    let mut city = city_with_trees();
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<HurricaneState>().storm =
+        Some(storm_over(128.0, 132.0, day + 3));
+
+    city.tick_slow_cycles(5);
+
+    let state = city.resource::<HurricaneState>();
+    assert!(state.forecast(day as f32).is_some());
+    assert_eq!(state.current.trees_down, 0);
+    assert_eq!(trees_standing(&city), 256);
+}
+
+#[test]
+fn test_passed_storm_files_report_and_lines_get_repaired() {
+    let mut city = TestCity::new();
+    let day = city.resource::<GameClock>().day;
+    {
+        let mut state = city.world_mut().resource_mut::<HurricaneState>();
+        // Landed long ago: the storm has already left the map.
+        let mut storm = storm_over(128.0, 128.0, day.saturating_sub(1));
+        storm.speed = 1_000.0;
+        state.storm = Some(storm);
+        state.current.lines_down = 1;
+        state.downed_lines.push(DownedLine {
+            cell: 0,
+            repair_day: day,
+        });
+    }
+
+    city.tick_slow_cycle();
+
+    let state = city.resource::<HurricaneState>();
+    assert!(state.storm.is_none());
+    assert_eq!(state.last_report.as_ref().map(|r| r.lines_down), Some(1));
+    assert!(state.downed_lines.is_empty());
+}
@@ -38,20 +38,14 @@ fn test_keybindings_rebind_and_conflict_detection() {
 }
 
 #[test]
-fn test_keybindings_saveable_roundtrip() {
+fn test_keybindings_json_roundtrip() {
     use crate::keybindings::{BindableAction, KeyBinding, KeyBindings};
-    use crate::Saveable;
     use bevy::prelude::KeyCode;
 
-    assert!(
-        KeyBindings::default().save_to_bytes().is_none(),
-        "default should skip save"
-    );
-
     let mut kb = KeyBindings::default();
     kb.set(BindableAction::Screenshot, KeyBinding::simple(KeyCode::F11));
-    let bytes = kb.save_to_bytes().expect("modified should save");
-    let restored = KeyBindings::load_from_bytes(&bytes);
+    let json = kb.to_json().expect("bindings should encode");
+    let restored = KeyBindings::from_json(&json).expect("bindings should decode");
     assert_eq!(restored.get(BindableAction::Screenshot).key, KeyCode::F11);
     assert_eq!(
         restored.get(BindableAction::TogglePause).key,
@@ -59,6 +53,19 @@ fn test_keybindings_saveable_roundtrip() {
     );
 }
 
+#[test]
+fn test_keybindings_not_stored_in_save_files() {
+    let city = TestCity::new();
+    let registry = city.resource::<crate::SaveableRegistry>();
+    assert!(
+        !registry
+            .entries
+            .iter()
+            .any(|entry| entry.key == "keybindings"),
+        "keybindings are a player preference and must not be saved with the city"
+    );
+}
+
 #[test]
 fn test_keybindings_reset_to_defaults() {
     use crate::keybindings::{BindableAction, KeyBinding, KeyBindings};
@@ -73,6 +80,19 @@ fn test_keybindings_reset_to_defaults() {
 
     kb = KeyBindings::default();
     assert_eq!(kb.get(BindableAction::TogglePause).key, KeyCode::Space);
+
+    kb.set(BindableAction::MenuRoads, KeyBinding::simple(KeyCode::KeyQ));
+    kb.set(
+        BindableAction::ToggleMinimap,
+        KeyBinding::simple(KeyCode::F7),
+    );
+    kb.reset(BindableAction::MenuRoads);
+    assert_eq!(kb.get(BindableAction::MenuRoads).key, KeyCode::KeyR);
+    assert_eq!(
+        kb.get(BindableAction::ToggleMinimap).key,
+        KeyCode::F7,
+        "resetting one action must leave the others alone"
+    );
 }
 
 #[test]
@@ -0,0 +1,178 @@
+//! Integration tests for city land purchases, leases and auctions.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::grid::{RoadType, ZoneType};
+use crate::immigration::CityAttractiveness;
+use crate::land_ownership::*;
+use crate::land_value::LandValueGrid;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::urban_growth_boundary::UrbanGrowthBoundary;
+use crate::utilities::UtilityType;
+
+fn act(city: &mut TestCity, x: usize, y: usize, kind: LandActionKind) {
+    city.world_mut().send_event(LandAction {
+        grid_x: x,
+        grid_y: y,
+        kind,
+    });
+    city.world_mut().run_schedule(Update);
+}
+
+fn treasury(city: &TestCity) -> f64 {
+    city.resource::<CityBudget>().treasury
+}
+
+fn land_value_at(city: &TestCity, x: usize, y: usize) -> u8 {
+    city.resource::<LandValueGrid>().get(x, y)
+}
+
+fn building_count_at(city: &mut TestCity, x: usize, y: usize) -> usize {
+    let world = city.world_mut();
+    let mut q = world.query::<&Building>();
+    q.iter(world)
+        .filter(|b| b.grid_x == x && b.grid_y == y)
+        .count()
+}
+
+#[test]
+fn test_buying_vacant_parcel_charges_market_price() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    let price = market_price(land_value_at(&city, 50, 50));
+    let before = treasury(&city);
+
+    act(&mut city, 50, 50, LandActionKind::Buy);
+
+    let land = city.resource::<LandOwnership>();
+    assert_eq!(land.owner(50, 50), ParcelOwner::City);
+    assert_eq!(land.seizures, 0);
+    assert_eq!(land.grievance, 0.0);
+    assert!((before - treasury(&city) - price).abs() < 1.0);
+}
+
+#[test]
+fn test_purchase_refused_without_funds() {
+    let mut city = TestCity::new().with_budget(0.0);
+    act(&mut city, 50, 50, LandActionKind::Buy);
+    assert_eq!(
+        city.resource::<LandOwnership>().owner(50, 50),
+        ParcelOwner::Private
+    );
+}
+
+#[test]
+fn test_eminent_domain_demolishes_and_compensates() {
+    let mut city =
+        TestCity::new()
+            .with_budget(100_000.0)
+            .with_building(40, 40, ZoneType::ResidentialLow, 1);
+    city.tick(1);
+    let price = seizure_price(land_value_at(&city, 40, 40), 1);
+    let before = treasury(&city);
+
+    act(&mut city, 40, 40, LandActionKind::Buy);
+    assert!((before - treasury(&city) - price).abs() < 1.0);
+    city.tick(1);
+
+    let land = city.resource::<LandOwnership>();
+    assert_eq!(land.owner(40, 40), ParcelOwner::City);
+    assert_eq!(land.seizures, 1);
+    assert!(land.grievance > 0.0);
+    assert_eq!(building_count_at(&mut city, 40, 40), 0);
+    assert!(city.grid().get(40, 40).building_id.is_none());
+}
+
+#[test]
+fn test_auction_raises_cash_and_gives_up_control() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    act(&mut city, 50, 50, LandActionKind::Buy);
+    let proceeds = auction_price(land_value_at(&city, 50, 50));
+    let before = treasury(&city);
+
+    act(&mut city, 50, 50, LandActionKind::Auction);
+
+    let land = city.resource::<LandOwnership>();
+    assert_eq!(land.owner(50, 50), ParcelOwner::Developer);
+    assert!(!land.allows_rezoning(50, 50));
+    assert!((treasury(&city) - before - proceeds).abs() < 1.0);
+
+    // Buying it back is eminent domain.
+    act(&mut city, 50, 50, LandActionKind::Buy);
+    assert_eq!(city.resource::<LandOwnership>().seizures, 1);
+}
+
+#[test]
+fn test_no_bids_outside_growth_boundary() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    act(&mut city, 5, 5, LandActionKind::Buy);
+    {
+        let mut ugb = city.world_mut().resource_mut::<UrbanGrowthBoundary>();
+        ugb.enabled = true;
+        ugb.vertices = vec![
+            (100.0, 100.0),
+            (150.0, 100.0),
+            (150.0, 150.0),
+            (100.0, 150.0),
+        ];
+    }
+
+    act(&mut city, 5, 5, LandActionKind::Auction);
+    assert_eq!(
+        city.resource::<LandOwnership>().owner(5, 5),
+        ParcelOwner::City
+    );
+}
+
+#[test]
+fn test_lease_collects_monthly_rent() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    act(&mut city, 50, 50, LandActionKind::Buy);
+    act(&mut city, 50, 50, LandActionKind::Lease);
+    assert_eq!(
+        city.resource::<LandOwnership>().owner(50, 50),
+        ParcelOwner::Leased
+    );
+
+    city.world_mut().resource_mut::<GameClock>().day = LEASE_PERIOD_DAYS;
+    city.tick(1);
+
+    let land = city.resource::<LandOwnership>();
+    assert!(land.last_lease_income > 0.0);
+    assert_eq!(land.last_lease_day, LEASE_PERIOD_DAYS);
+}
+
+#[test]
+fn test_held_city_land_stays_undeveloped() {
+    let mut city = TestCity::new()
+        .with_budget(500_000.0)
+        .with_road(100, 128, 140, 128, RoadType::Avenue)
+        .with_zone_rect(102, 125, 138, 127, ZoneType::ResidentialLow)
+        .with_utility(100, 128, UtilityType::PowerPlant)
+        .with_utility(140, 128, UtilityType::WaterTower);
+    {
+        let world = city.world_mut();
+        let mut land = world.resource_mut::<LandOwnership>();
+        for y in 125..=127 {
+            for x in 102..=120 {
+                land.set_owner(x, y, ParcelOwner::City);
+            }
+        }
+        let mut attr = world.resource_mut::<CityAttractiveness>();
+        attr.overall_score = 90.0;
+        attr.housing_factor = 1.0;
+    }
+
+    city.tick(500);
+
+    let world = city.world_mut();
+    let mut q = world.query::<&Building>();
+    let on_city_land = q.iter(world).filter(|b| b.grid_x <= 120).count();
+    assert_eq!(on_city_land, 0, "city-held parcels should not be developed");
+    assert!(
+        city.building_count() > 0,
+        "private parcels should still grow"
+    );
+}
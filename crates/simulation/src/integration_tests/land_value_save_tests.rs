@@ -89,18 +89,18 @@ fn test_land_value_overlay_matches_pre_save_state() {
     city.tick_slow_cycles(20);
 
     // Snapshot the entire grid before save
-    let snapshot: Vec<u8> = city.resource::<LandValueGrid>().values.clone();
+    let snapshot = city.resource::<LandValueGrid>().values.to_row_major();
 
     roundtrip(&mut city);
 
-    let restored = &city.resource::<LandValueGrid>().values;
+    let restored = city.resource::<LandValueGrid>().values.to_row_major();
     assert_eq!(
         snapshot.len(),
         restored.len(),
         "Grid size should match after roundtrip"
     );
     assert_eq!(
-        &snapshot, restored,
+        snapshot, restored,
         "Every cell in the land value overlay must match pre-save state"
     );
 }
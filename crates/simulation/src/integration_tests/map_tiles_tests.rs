@@ -0,0 +1,69 @@
+//! Integration tests for buying map tiles during play.
+
+use bevy::prelude::*;
+
+use crate::economy::CityBudget;
+use crate::land_value::LandValueGrid;
+use crate::map_tiles::*;
+use crate::milestones::{MilestoneProgress, MilestoneTier};
+use crate::test_harness::TestCity;
+
+fn restricted_city(treasury: f64, tier: MilestoneTier) -> TestCity {
+    let mut city = TestCity::new().with_budget(treasury);
+    city.world_mut()
+        .insert_resource(MapTiles::starting(256, 256));
+    city.world_mut()
+        .resource_mut::<MilestoneProgress>()
+        .current_tier = tier;
+    city
+}
+
+fn buy(city: &mut TestCity, tile_x: usize, tile_y: usize) {
+    city.world_mut().send_event(TilePurchase { tile_x, tile_y });
+    city.world_mut().run_schedule(Update);
+}
+
+fn treasury(city: &TestCity) -> f64 {
+    city.resource::<CityBudget>().treasury
+}
+
+#[test]
+fn test_buying_a_bordering_tile_charges_its_price() {
+    let mut city = restricted_city(1_000_000.0, MilestoneTier::Village);
+    let price = {
+        let tiles = city.resource::<MapTiles>();
+        tile_price(tile_land_value(
+            tiles,
+            city.resource::<LandValueGrid>(),
+            5,
+            3,
+        ))
+    };
+    let before = treasury(&city);
+
+    buy(&mut city, 5, 3);
+
+    let tiles = city.resource::<MapTiles>();
+    assert!(tiles.is_owned(5, 3));
+    assert_eq!(tiles.owned_count(), STARTING_TILES + 1);
+    assert!((before - treasury(&city) - price).abs() < 1.0);
+}
+
+#[test]
+fn test_purchase_needs_a_milestone() {
+    let mut city = restricted_city(1_000_000.0, MilestoneTier::Hamlet);
+    buy(&mut city, 5, 3);
+    assert!(!city.resource::<MapTiles>().is_owned(5, 3));
+    assert_eq!(treasury(&city), 1_000_000.0);
+}
+
+#[test]
+fn test_purchase_refused_for_distant_tiles_and_without_funds() {
+    let mut city = restricted_city(1_000_000.0, MilestoneTier::Village);
+    buy(&mut city, 0, 0);
+    assert!(!city.resource::<MapTiles>().is_owned(0, 0));
+
+    let mut broke = restricted_city(0.0, MilestoneTier::Village);
+    buy(&mut broke, 5, 3);
+    assert!(!broke.resource::<MapTiles>().is_owned(5, 3));
+}
@@ -0,0 +1,158 @@
+//! Integration tests for the structure-of-arrays citizen motion buffer and
+//! its write-back to `Position` / `PathCache`.
+
+use bevy::prelude::*;
+
+use crate::citizen::{
+    Citizen, CitizenDetails, CitizenState, CitizenStateComp, Gender, HomeLocation, Needs,
+    PathCache, Position, Velocity, WorkLocation,
+};
+use crate::grid::{RoadType, WorldGrid, ZoneType};
+use crate::lod::LodTier;
+use crate::mode_choice::ChosenTransportMode;
+use crate::movement::{ActivityTimer, CitizenMotionBuffer, SYNC_INTERVAL};
+use crate::roads::RoadNode;
+use crate::test_harness::TestCity;
+
+fn commuter_city() -> TestCity {
+    TestCity::new()
+        .with_road(100, 100, 100, 120, RoadType::Local)
+        .with_building(100, 100, ZoneType::ResidentialLow, 1)
+        .with_building(100, 120, ZoneType::CommercialLow, 1)
+        .rebuild_csr()
+}
+
+fn spawn_commuter(city: &mut TestCity, tier: LodTier) -> Entity {
+    let world = city.world_mut();
+    let (x, y) = WorldGrid::grid_to_world(100, 100);
+    world
+        .spawn((
+            Citizen,
+            Position { x, y },
+            Velocity { x: 0.0, y: 0.0 },
+            HomeLocation {
+                grid_x: 100,
+                grid_y: 100,
+                building: Entity::PLACEHOLDER,
+            },
+            WorkLocation {
+                grid_x: 100,
+                grid_y: 120,
+                building: Entity::PLACEHOLDER,
+            },
+            CitizenStateComp(CitizenState::CommutingToWork),
+            PathCache::new((101..=120).map(|y| RoadNode(100, y)).collect()),
+            CitizenDetails {
+                age: 30,
+                gender: Gender::Female,
+                education: 2,
+                happiness: 80.0,
+                health: 100.0,
+                salary: 3500.0,
+                savings: 10000.0,
+            },
+            Needs::default(),
+            ActivityTimer::default(),
+            ChosenTransportMode::default(),
+            tier,
+        ))
+        .id()
+}
+
+fn ecs_position(city: &mut TestCity, entity: Entity) -> (f32, f32) {
+    let pos = city.world_mut().get::<Position>(entity).unwrap();
+    (pos.x, pos.y)
+}
+
+fn buffered_position(city: &TestCity, entity: Entity) -> Option<(f32, f32)> {
+    city.resource::<CitizenMotionBuffer>()
+        .motion(entity)
+        .map(|m| (m.x, m.y))
+}
+
+#[test]
+fn test_full_detail_commuter_is_synced_every_tick() {
+    let mut city = commuter_city();
+    let entity = spawn_commuter(&mut city, LodTier::Full);
+
+    for _ in 0..10 {
+        city.tick(1);
+        assert_eq!(
+            buffered_position(&city, entity),
+            Some(ecs_position(&mut city, entity))
+        );
+    }
+}
+
+#[test]
+fn test_simplified_commuter_syncs_on_interval_and_arrives() {
+    let mut city = commuter_city();
+    let entity = spawn_commuter(&mut city, LodTier::Simplified);
+    let (end_x, end_y) = WorldGrid::grid_to_world(100, 120);
+
+    let mut ticks_since_sync = 0;
+    let mut lagged = false;
+    for _ in 0..400 {
+        city.tick(1);
+        let complete = city
+            .world_mut()
+            .get::<PathCache>(entity)
+            .unwrap()
+            .is_complete();
+        if complete {
+            assert_eq!(ecs_position(&mut city, entity), (end_x, end_y));
+            assert!(lagged, "simplified rows should not be written every tick");
+            return;
+        }
+        if buffered_position(&city, entity) == Some(ecs_position(&mut city, entity)) {
+            ticks_since_sync = 0;
+        } else {
+            lagged = true;
+            ticks_since_sync += 1;
+            assert!(ticks_since_sync < SYNC_INTERVAL);
+        }
+    }
+    panic!("commuter never reached the end of its path");
+}
+
+#[test]
+fn test_commuter_moved_elsewhere_continues_from_new_position() {
+    let mut city = commuter_city();
+    let entity = spawn_commuter(&mut city, LodTier::Full);
+    city.tick(3);
+
+    let (x, y) = WorldGrid::grid_to_world(100, 110);
+    {
+        let world = city.world_mut();
+        let mut pos = world.get_mut::<Position>(entity).unwrap();
+        pos.x = x;
+        pos.y = y;
+    }
+    city.tick(1);
+
+    let (bx, by) = buffered_position(&city, entity).unwrap();
+    assert!(
+        (bx - x).abs() < 8.0 && (by - y).abs() < 8.0,
+        "buffer should pick up the new position, got ({bx}, {by})"
+    );
+}
+
+#[test]
+fn test_despawned_and_abstract_citizens_leave_the_buffer() {
+    let mut city = commuter_city();
+    let despawned = spawn_commuter(&mut city, LodTier::Full);
+    let abstracted = spawn_commuter(&mut city, LodTier::Full);
+    city.tick(1);
+    assert!(city.resource::<CitizenMotionBuffer>().contains(despawned));
+    assert!(city.resource::<CitizenMotionBuffer>().contains(abstracted));
+
+    city.world_mut().despawn(despawned);
+    *city.world_mut().get_mut::<LodTier>(abstracted).unwrap() = LodTier::Abstract;
+    let frozen = buffered_position(&city, abstracted).unwrap();
+    city.tick(1);
+
+    assert_eq!(ecs_position(&mut city, abstracted), frozen);
+    let buffer = city.resource::<CitizenMotionBuffer>();
+    assert!(!buffer.contains(despawned));
+    assert!(!buffer.contains(abstracted));
+}
@@ -12,7 +12,7 @@ use crate::app_state::AppState;
 use crate::grid::{CellType, WorldGrid};
 use crate::terrain_generation::generate_procedural_terrain;
 use crate::test_harness::TestCity;
-use crate::tutorial::{TutorialState, TutorialStep};
+use crate::tutorial::TutorialState;
 use crate::TickCounter;
 
 // ---------------------------------------------------------------------------
@@ -106,7 +106,7 @@ fn test_new_player_golden_path() {
         let mut tutorial = app.world_mut().resource_mut::<TutorialState>();
         tutorial.active = true;
         tutorial.completed = false;
-        tutorial.current_step = TutorialStep::Welcome;
+        tutorial.current_step = 0;
     }
 
     // Verify tutorial is active at Welcome step
@@ -114,8 +114,8 @@ fn test_new_player_golden_path() {
         let tutorial = app.world().resource::<TutorialState>();
         assert!(tutorial.active, "Tutorial should be active for new player");
         assert_eq!(
-            tutorial.current_step,
-            TutorialStep::Welcome,
+            tutorial.step_id(),
+            "welcome",
             "Tutorial should start at Welcome step"
         );
     }
@@ -51,7 +51,7 @@ fn test_playground_marks_family_coverage() {
 
     let effects = city.resource::<ParkEffectsGrid>();
     let idx = ParkEffectsGrid::idx(12, 15);
-    assert!(effects.has_playground[idx], "Playground should mark family coverage");
+    assert!(effects.has_playground.get_index(idx), "Playground should mark family coverage");
     assert!(effects.happiness_at(12, 15) >= 5.0, "Playground should provide happiness");
 }
 
@@ -79,7 +79,7 @@ fn test_plaza_provides_commercial_boost() {
 
     let effects = city.resource::<ParkEffectsGrid>();
     let idx = ParkEffectsGrid::idx(12, 15);
-    assert!(effects.has_plaza_boost[idx], "Plaza should mark commercial boost");
+    assert!(effects.has_plaza_boost.get_index(idx), "Plaza should mark commercial boost");
     assert!(effects.happiness_at(12, 15) >= 3.0, "Plaza should provide happiness >= 3.0");
 }
 
@@ -0,0 +1,90 @@
+//! Integration tests for the prioritised, budgeted pathfinding queue.
+
+use bevy::prelude::*;
+
+use crate::citizen::{Citizen, CitizenState, CitizenStateComp, PathCache, PathRequest};
+use crate::grid::RoadType;
+use crate::lod::LodTier;
+use crate::movement::{ComputingPath, PathPriority};
+use crate::test_harness::TestCity;
+
+fn road_city() -> TestCity {
+    TestCity::new()
+        .with_road(50, 50, 50, 90, RoadType::Local)
+        .rebuild_csr()
+}
+
+fn request_path(city: &mut TestCity, tier: LodTier, priority: Option<PathPriority>) -> Entity {
+    let mut entity = city.world_mut().spawn((
+        Citizen,
+        CitizenStateComp(CitizenState::AtHome),
+        PathCache::new(Vec::new()),
+        tier,
+        PathRequest {
+            from_gx: 50,
+            from_gy: 50,
+            to_gx: 50,
+            to_gy: 90,
+            target_state: CitizenState::CommutingToWork,
+        },
+    ));
+    if let Some(priority) = priority {
+        entity.insert(priority);
+    }
+    entity.id()
+}
+
+fn waiting(city: &mut TestCity, entity: Entity) -> bool {
+    city.world_mut().get::<PathRequest>(entity).is_some()
+}
+
+#[test]
+fn test_request_spike_is_spread_over_ticks_by_priority() {
+    let mut city = road_city();
+    let visible: Vec<Entity> = (0..300)
+        .map(|_| request_path(&mut city, LodTier::Full, None))
+        .collect();
+    let background = request_path(&mut city, LodTier::Abstract, None);
+    let emergency = request_path(&mut city, LodTier::Abstract, Some(PathPriority::Emergency));
+
+    city.tick(1);
+    assert!(
+        !waiting(&mut city, emergency),
+        "emergency requests skip the budget"
+    );
+    assert!(!waiting(&mut city, visible[0]));
+    assert!(
+        waiting(&mut city, visible[299]),
+        "the spike should not start at once"
+    );
+    assert!(waiting(&mut city, background));
+
+    city.tick(1);
+    assert!(!waiting(&mut city, visible[299]));
+    assert!(!waiting(&mut city, background));
+}
+
+#[test]
+fn test_requests_dropped_while_queued_are_skipped() {
+    let mut city = road_city();
+    let requesters: Vec<Entity> = (0..300)
+        .map(|_| request_path(&mut city, LodTier::Full, None))
+        .collect();
+    city.tick(1);
+
+    let (withdrawn, despawned) = (requesters[298], requesters[299]);
+    assert!(waiting(&mut city, withdrawn));
+    city.world_mut()
+        .entity_mut(withdrawn)
+        .remove::<PathRequest>();
+    city.world_mut().despawn(despawned);
+    city.tick(1);
+
+    assert!(!waiting(&mut city, requesters[297]));
+    let world = city.world_mut();
+    assert!(world.get::<ComputingPath>(withdrawn).is_none());
+    assert_eq!(
+        world.get::<CitizenStateComp>(withdrawn).unwrap().0,
+        CitizenState::AtHome
+    );
+}
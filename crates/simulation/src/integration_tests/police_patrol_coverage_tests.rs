@@ -0,0 +1,51 @@
+//! Integration tests checking that patrols are the only police effect on
+//! crime, and that the crime they remove is credited to the police tiers.
+
+use crate::budget::ExtendedBudget;
+use crate::crime::CrimeGrid;
+use crate::grid::ZoneType;
+use crate::immigration::CityAttractiveness;
+use crate::police_tiers::PoliceTiersState;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+
+fn crime_city() -> TestCity {
+    let mut city = TestCity::new()
+        .with_zone_rect(96, 96, 104, 104, ZoneType::ResidentialLow)
+        .with_building(94, 100, ZoneType::Industrial, 3)
+        .with_building(106, 100, ZoneType::Industrial, 3);
+    city.world_mut()
+        .resource_mut::<CityAttractiveness>()
+        .overall_score = 80.0;
+    city
+}
+
+#[test]
+fn test_police_patrol_coverage_unfunded_station_reduces_no_crime() {
+    let mut city = crime_city().with_service(100, 101, ServiceType::PoliceStation);
+    city.world_mut()
+        .resource_mut::<ExtendedBudget>()
+        .service_budgets
+        .police = 0.0;
+    city.tick_slow_cycles(5);
+
+    // The station still covers cells, but fields no patrols.
+    let tiers = city.resource::<PoliceTiersState>();
+    assert!(tiers.station_stats.cells_covered > 0);
+    assert_eq!(tiers.station_stats.crime_reduced, 0);
+    assert!(city.resource::<CrimeGrid>().get(100, 100) > 0);
+}
+
+#[test]
+fn test_police_patrol_coverage_credits_crime_reduced_to_tier() {
+    let mut city = crime_city().with_service(100, 101, ServiceType::PoliceStation);
+    city.tick_slow_cycles(5);
+
+    let tiers = city.resource::<PoliceTiersState>();
+    assert!(
+        tiers.station_stats.crime_reduced > 0,
+        "patrol deterrence should be credited to the station tier"
+    );
+    assert_eq!(tiers.kiosk_stats.crime_reduced, 0);
+    assert_eq!(tiers.hq_stats.crime_reduced, 0);
+}
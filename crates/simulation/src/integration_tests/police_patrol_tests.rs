@@ -0,0 +1,153 @@
+//! Integration tests for police patrol beats and response times.
+
+use crate::budget::ExtendedBudget;
+use crate::crime::CrimeGrid;
+use crate::grid::{RoadType, WorldGrid, ZoneType};
+use crate::immigration::CityAttractiveness;
+use crate::police_patrol::{PolicePatrolState, MAX_RESPONSE_MINUTES};
+use crate::road_segments::RoadSegmentStore;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+
+fn prevent_emigration(city: &mut TestCity) {
+    city.world_mut()
+        .resource_mut::<CityAttractiveness>()
+        .overall_score = 80.0;
+}
+
+fn crime_city() -> TestCity {
+    TestCity::new()
+        .with_zone_rect(96, 96, 104, 104, ZoneType::ResidentialLow)
+        .with_building(94, 100, ZoneType::Industrial, 3)
+        .with_building(106, 100, ZoneType::Industrial, 3)
+}
+
+#[test]
+fn test_police_patrol_no_police_has_no_units() {
+    let mut city = crime_city();
+    prevent_emigration(&mut city);
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<PolicePatrolState>();
+    assert_eq!(state.total_units, 0.0);
+    assert!(state.beats.iter().all(|b| b.assigned_units == 0.0));
+    assert_eq!(state.beat(6, 6).response_minutes, MAX_RESPONSE_MINUTES);
+}
+
+#[test]
+fn test_police_patrol_station_assigns_units_to_home_beat() {
+    let mut city = crime_city().with_service(100, 101, ServiceType::PoliceStation);
+    prevent_emigration(&mut city);
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<PolicePatrolState>();
+    assert!(state.total_units > 0.0);
+    let beat = state.beat(6, 6);
+    assert!(
+        beat.assigned_units > 0.0,
+        "home beat should receive patrol units"
+    );
+    assert!(beat.response_minutes < MAX_RESPONSE_MINUTES);
+}
+
+#[test]
+fn test_police_patrol_reduces_crime_in_beat() {
+    let mut without = crime_city();
+    prevent_emigration(&mut without);
+    without.tick_slow_cycles(10);
+    let crime_without = without.resource::<CrimeGrid>().get(100, 100);
+
+    let mut with = crime_city().with_service(100, 103, ServiceType::PoliceStation);
+    prevent_emigration(&mut with);
+    with.tick_slow_cycles(10);
+    let crime_with = with.resource::<CrimeGrid>().get(100, 100);
+
+    assert!(crime_without > 0, "baseline crime should be positive");
+    assert!(
+        crime_with < crime_without,
+        "patrols should reduce crime: with={crime_with}, without={crime_without}"
+    );
+}
+
+#[test]
+fn test_police_patrol_zero_budget_fields_no_units() {
+    let mut city = crime_city().with_service(100, 101, ServiceType::PoliceStation);
+    prevent_emigration(&mut city);
+    city.world_mut()
+        .resource_mut::<ExtendedBudget>()
+        .service_budgets
+        .police = 0.0;
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<PolicePatrolState>();
+    assert_eq!(state.total_units, 0.0);
+    assert_eq!(state.beat(6, 6).deterrence, 0);
+}
+
+#[test]
+fn test_police_patrol_response_slower_far_from_station() {
+    let mut city = TestCity::new()
+        .with_zone_rect(20, 20, 24, 24, ZoneType::ResidentialLow)
+        .with_building(22, 26, ZoneType::Industrial, 3)
+        .with_zone_rect(200, 200, 204, 204, ZoneType::ResidentialLow)
+        .with_building(202, 206, ZoneType::Industrial, 3)
+        .with_service(22, 20, ServiceType::PoliceHQ);
+    prevent_emigration(&mut city);
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<PolicePatrolState>();
+    let near = state.beat(1, 1).response_minutes;
+    let far = state.beat(12, 12).response_minutes;
+    assert!(
+        near < far,
+        "response near the HQ ({near}) should beat the far side ({far})"
+    );
+}
+
+#[test]
+fn test_police_patrol_segments_get_frequency() {
+    let mut city = crime_city()
+        .with_road(90, 98, 110, 98, RoadType::Local)
+        .with_service(100, 97, ServiceType::PoliceStation);
+    prevent_emigration(&mut city);
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<PolicePatrolState>();
+    assert!(
+        !state.segment_patrols.is_empty(),
+        "road segments inside patrolled beats should carry a patrol frequency"
+    );
+    assert!(state.segment_patrols.iter().all(|s| s.frequency > 0.0));
+}
+
+#[test]
+fn test_police_patrol_favours_segments_beside_crime() {
+    let mut city = crime_city()
+        .with_road(96, 98, 110, 98, RoadType::Local)
+        .with_road(96, 110, 110, 110, RoadType::Local)
+        .with_service(100, 97, ServiceType::PoliceStation);
+    prevent_emigration(&mut city);
+    city.tick_slow_cycles(3);
+
+    let frequency_at_row = |city: &TestCity, row: usize| {
+        let segment = city
+            .resource::<RoadSegmentStore>()
+            .segments
+            .iter()
+            .find(|seg| {
+                let mid = seg.evaluate(0.5);
+                WorldGrid::world_to_grid(mid.x, mid.y).1 == row as i32
+            })
+            .expect("segment along the row")
+            .id
+            .0;
+        city.resource::<PolicePatrolState>()
+            .segment_frequency(segment)
+    };
+    let beside_crime = frequency_at_row(&city, 98);
+    let quiet = frequency_at_row(&city, 110);
+    assert!(
+        beside_crime > quiet,
+        "road beside the crime: {beside_crime}, quiet road: {quiet}"
+    );
+}
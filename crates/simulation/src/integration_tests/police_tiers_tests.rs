@@ -252,7 +252,6 @@ fn test_tier_properties_valid() {
         PoliceTier::Headquarters,
     ] {
         assert!(tier.coverage_radius() > 0);
-        assert!(tier.crime_reduction() > 0);
         assert!(tier.response_time() > 0);
         assert!(tier.maintenance_cost() > 0.0);
     }
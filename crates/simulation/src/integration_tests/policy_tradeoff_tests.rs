@@ -38,8 +38,8 @@ fn test_tradeoff_at_least_15_policies_exist() {
 }
 
 #[test]
-fn test_tradeoff_policy_count_is_29() {
-    assert_eq!(Policy::all().len(), 29, "should have exactly 29 policies");
+fn test_tradeoff_policy_count_is_30() {
+    assert_eq!(Policy::all().len(), 30, "should have exactly 30 policies");
 }
 
 #[test]
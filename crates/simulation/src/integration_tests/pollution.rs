@@ -367,7 +367,7 @@ fn test_pollution_wind_northward_shifts_pollution_north() {
 }
 
 // ====================================================================
-// Pollution resets each slow tick (recalculated from scratch)
+// Pollution lingers between slow ticks but does not pile up
 // ====================================================================
 
 #[test]
@@ -381,11 +381,11 @@ fn test_pollution_recalculated_each_slow_tick() {
     city.tick_slow_cycle();
     let second = city.resource::<PollutionGrid>().get(128, 128);
 
-    // Because pollution is recalculated from scratch each tick (levels.fill(0) then rebuild),
-    // the value should be roughly the same (may differ slightly due to wind drift changes).
+    // Only AIR_RETENTION of the previous tick lingers (and drifts away with the
+    // wind), so the value grows by at most that share of the first tick.
     // The key invariant is that it does NOT accumulate unboundedly.
     assert!(
-        second <= first + 5,
+        second as f32 <= first as f32 * (1.0 + crate::wind_pollution::AIR_RETENTION) + 5.0,
         "pollution should not accumulate unboundedly: first={}, second={}",
         first,
         second
@@ -31,15 +31,14 @@ fn test_power_lines_follow_roads_from_generator() {
     tick_power(&mut city);
 
     let plg = city.resource::<PowerLineGrid>();
-    let w = plg.width;
 
     // Road cells between generator and endpoint should have power lines.
-    assert!(plg.has_line[50 * w + 55], "Road cell (55,50) should have power line");
-    assert!(plg.has_line[50 * w + 60], "Road cell (60,50) should have power line");
-    assert!(plg.has_line[50 * w + 65], "Road cell (65,50) should have power line");
+    assert!(plg.has_line.get(55, 50), "Road cell (55,50) should have power line");
+    assert!(plg.has_line.get(60, 50), "Road cell (60,50) should have power line");
+    assert!(plg.has_line.get(65, 50), "Road cell (65,50) should have power line");
 
     // A disconnected cell should not have power lines.
-    assert!(!plg.has_line[10 * w + 10], "Disconnected cell should not have power line");
+    assert!(!plg.has_line.get(10, 10), "Disconnected cell should not have power line");
 }
 
 #[test]
@@ -85,13 +84,12 @@ fn test_disconnected_road_no_power() {
     tick_power(&mut city);
 
     let plg = city.resource::<PowerLineGrid>();
-    let w = plg.width;
 
     // Connected road has power lines.
-    assert!(plg.has_line[50 * w + 55]);
+    assert!(plg.has_line.get(55, 50));
 
     // Disconnected road does NOT have power lines.
-    assert!(!plg.has_line[50 * w + 85], "Disconnected road should not have power lines");
+    assert!(!plg.has_line.get(85, 50), "Disconnected road should not have power lines");
 
     // Building near disconnected road should not have power.
     let grid = city.grid();
@@ -208,7 +206,7 @@ fn test_saveable_roundtrip() {
     use crate::Saveable;
 
     let mut plg = PowerLineGrid::default();
-    plg.has_line[500] = true;
+    plg.has_line.set_index(500, true);
     plg.efficiency[500] = 0.92;
     plg.line_cell_count = 1;
     plg.powered_cell_count = 10;
@@ -216,7 +214,7 @@ fn test_saveable_roundtrip() {
     let bytes = plg.save_to_bytes().unwrap();
     let restored = PowerLineGrid::load_from_bytes(&bytes);
 
-    assert!(restored.has_line[500]);
+    assert!(restored.has_line.get_index(500));
     assert!((restored.efficiency[500] - 0.92).abs() < f32::EPSILON);
     assert_eq!(restored.line_cell_count, 1);
     assert_eq!(restored.powered_cell_count, 10);
@@ -0,0 +1,145 @@
+//! Integration tests for the prison system (sentencing, recidivism,
+//! overcrowding).
+
+use crate::crime_justice::{
+    CrimeEvent, CrimeJusticeState, CrimeType, JusticeStage, PRISON_CAPACITY,
+};
+use crate::prison_system::{Inmate, PrisonState, SentencingPolicy};
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+
+fn city_with_prison() -> TestCity {
+    TestCity::new()
+        .with_budget(100_000.0)
+        .with_service(50, 50, ServiceType::Prison)
+}
+
+fn court_case(crime_type: CrimeType) -> CrimeEvent {
+    CrimeEvent {
+        crime_type,
+        district_x: 3,
+        district_y: 3,
+        stage: JusticeStage::InCourt,
+        stage_timer: 0,
+    }
+}
+
+fn long_sentence_inmate() -> Inmate {
+    Inmate {
+        crime_type: CrimeType::OrganizedCrime,
+        district_x: 3,
+        district_y: 3,
+        sentence: 50,
+        remaining: 50,
+        education: 0.0,
+        prior_offenses: 0,
+    }
+}
+
+#[test]
+fn test_prison_building_registers_capacity() {
+    let mut city = city_with_prison();
+    city.tick_slow_cycle();
+    let state = city.resource::<PrisonState>();
+    assert_eq!(state.prisons.len(), 1);
+    assert_eq!(state.capacity(), PRISON_CAPACITY);
+}
+
+#[test]
+fn test_conviction_admits_inmate_with_policy_sentence() {
+    let mut city = city_with_prison();
+    city.tick_slow_cycle();
+    {
+        let world = city.world_mut();
+        world.resource_mut::<PrisonState>().policy = SentencingPolicy::Punitive;
+        world
+            .resource_mut::<CrimeJusticeState>()
+            .events
+            .push(court_case(CrimeType::Assault));
+    }
+    city.tick_slow_cycle();
+
+    let state = city.resource::<PrisonState>();
+    assert_eq!(state.total_admitted, 1);
+    let inmate = &state.prisons[0].inmates[0];
+    assert_eq!(inmate.crime_type, CrimeType::Assault);
+    assert_eq!(
+        inmate.sentence,
+        SentencingPolicy::Punitive.sentence_for(CrimeType::Assault)
+    );
+    assert_eq!(city.resource::<CrimeJusticeState>().jail_population, 1);
+}
+
+#[test]
+fn test_no_prison_means_no_inmates() {
+    let mut city = TestCity::new();
+    city.world_mut()
+        .resource_mut::<CrimeJusticeState>()
+        .events
+        .push(court_case(CrimeType::Burglary));
+    city.tick_slow_cycle();
+    let state = city.resource::<PrisonState>();
+    assert_eq!(state.population(), 0);
+    assert_eq!(state.total_admitted, 0);
+}
+
+#[test]
+fn test_sentence_served_then_released() {
+    let mut city = city_with_prison();
+    city.tick_slow_cycle();
+    city.world_mut()
+        .resource_mut::<CrimeJusticeState>()
+        .events
+        .push(court_case(CrimeType::PettyTheft));
+    city.tick_slow_cycle();
+    assert_eq!(city.resource::<PrisonState>().population(), 1);
+
+    let sentence = SentencingPolicy::Standard.sentence_for(CrimeType::PettyTheft);
+    city.tick_slow_cycles(sentence);
+    let state = city.resource::<PrisonState>();
+    assert_eq!(state.population(), 0);
+    assert_eq!(state.total_released, 1);
+}
+
+#[test]
+fn test_overcrowding_triggers_early_release() {
+    let mut city = city_with_prison();
+    city.tick_slow_cycle();
+    {
+        let world = city.world_mut();
+        let mut state = world.resource_mut::<PrisonState>();
+        state.prisons[0].capacity = 2;
+        for _ in 0..5 {
+            state.prisons[0].inmates.push(long_sentence_inmate());
+        }
+    }
+    city.tick_slow_cycle();
+
+    let state = city.resource::<PrisonState>();
+    assert_eq!(
+        state.population(),
+        2,
+        "prison should be trimmed to capacity"
+    );
+    assert_eq!(state.total_early_released, 3);
+    assert_eq!(state.total_released, 3);
+}
+
+#[test]
+fn test_education_program_charges_per_inmate() {
+    let mut city = city_with_prison();
+    city.tick_slow_cycle();
+    {
+        let world = city.world_mut();
+        let mut state = world.resource_mut::<PrisonState>();
+        state.education_program = true;
+        for _ in 0..4 {
+            state.prisons[0].inmates.push(long_sentence_inmate());
+        }
+    }
+    city.tick_slow_cycle();
+
+    let state = city.resource::<PrisonState>();
+    assert!(state.education_cost > 0.0);
+    assert!(state.prisons[0].inmates.iter().all(|i| i.education > 0.0));
+}
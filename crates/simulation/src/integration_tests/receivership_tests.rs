@@ -0,0 +1,123 @@
+//! Integration tests for bankruptcy, receivership and recovery.
+
+use bevy::prelude::*;
+
+use crate::budget::ExtendedBudget;
+use crate::economy::CityBudget;
+use crate::loans::{BankruptcyEvent, LoanBook, LoanTier};
+use crate::receivership::{
+    FiscalPhase, Receivership, AUSTERITY_BUDGET_CAP, IMPOSED_TAX_RATE, MISSED_OBLIGATION_LIMIT,
+    RECEIVERSHIP_SOLVENT_DAYS,
+};
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+
+/// Advance the clock by a day and run one tick so the daily update fires.
+fn next_day(city: &mut TestCity) {
+    city.world_mut().resource_mut::<GameClock>().day += 1;
+    city.tick(1);
+}
+
+fn set_treasury(city: &mut TestCity, treasury: f64) {
+    city.world_mut().resource_mut::<CityBudget>().treasury = treasury;
+}
+
+fn park_count(city: &mut TestCity) -> usize {
+    let world = city.world_mut();
+    let mut q = world.query::<&ServiceBuilding>();
+    q.iter(world)
+        .filter(|s| s.service_type == ServiceType::LargePark)
+        .count()
+}
+
+#[test]
+fn test_bankruptcy_event_starts_receivership() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        let mut treasury = 0.0;
+        let mut book = world.resource_mut::<LoanBook>();
+        book.take_loan(LoanTier::Small, &mut treasury);
+        book.take_loan(LoanTier::Medium, &mut treasury);
+        let mut ext = world.resource_mut::<ExtendedBudget>();
+        ext.zone_taxes.residential = 0.05;
+        ext.service_budgets.fire = 1.4;
+        world.send_event(BankruptcyEvent);
+    }
+    city.tick(1);
+
+    let state = city.resource::<Receivership>();
+    assert_eq!(state.phase, FiscalPhase::Receivership);
+    assert_eq!(state.times_entered, 1);
+    assert_eq!(city.resource::<LoanBook>().active_loans.len(), 1);
+    let ext = city.resource::<ExtendedBudget>();
+    assert!(ext.zone_taxes.residential >= IMPOSED_TAX_RATE);
+    assert!(ext.service_budgets.fire <= AUSTERITY_BUDGET_CAP);
+}
+
+#[test]
+fn test_missed_obligations_trigger_receivership() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        let mut treasury = 0.0;
+        world
+            .resource_mut::<LoanBook>()
+            .take_loan(LoanTier::Small, &mut treasury);
+        world.resource_mut::<Receivership>().missed_days = MISSED_OBLIGATION_LIMIT - 1;
+    }
+    set_treasury(&mut city, -20_000.0);
+    next_day(&mut city);
+
+    assert_eq!(
+        city.resource::<Receivership>().phase,
+        FiscalPhase::Receivership
+    );
+}
+
+#[test]
+fn test_receiver_sells_non_essential_buildings() {
+    let mut city = TestCity::new()
+        .with_service(40, 40, ServiceType::LargePark)
+        .with_service(60, 60, ServiceType::FireStation);
+    city.world_mut()
+        .resource_mut::<Receivership>()
+        .enter(0, &Default::default());
+    set_treasury(&mut city, -1_000_000.0);
+    next_day(&mut city);
+    city.tick(1);
+
+    assert_eq!(park_count(&mut city), 0);
+    let state = city.resource::<Receivership>();
+    assert_eq!(state.assets_sold, 1);
+    assert!(state.sale_proceeds > 0.0);
+    let world = city.world_mut();
+    let mut q = world.query::<&ServiceBuilding>();
+    assert!(q
+        .iter(world)
+        .any(|s| s.service_type == ServiceType::FireStation));
+}
+
+#[test]
+fn test_solvent_city_leaves_receivership() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        let mut state = world.resource_mut::<Receivership>();
+        let budgets = crate::budget::ServiceBudgets {
+            healthcare: 1.2,
+            ..Default::default()
+        };
+        state.enter(0, &budgets);
+        state.solvent_days = RECEIVERSHIP_SOLVENT_DAYS - 1;
+    }
+    set_treasury(&mut city, 10_000.0);
+    next_day(&mut city);
+
+    assert_eq!(city.resource::<Receivership>().phase, FiscalPhase::Recovery);
+    assert_eq!(
+        city.resource::<ExtendedBudget>().service_budgets.healthcare,
+        1.2
+    );
+}
@@ -0,0 +1,203 @@
+//! Integration tests for replay checkpoints, scrubbing and taking over.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::game_actions::{ActionQueue, ActionSource, GameAction};
+use crate::replay::format::{
+    ReplayEntry, ReplayFile, ReplayFooter, ReplayHeader, CURRENT_FORMAT_VERSION,
+};
+use crate::replay::{
+    take_over_replay, CheckpointCodec, ReplayCheckpoints, ReplayPlayer, ReplayRecorder, ReplaySeek,
+    ReplayViewerMode,
+};
+use crate::sim_rng::SimRng;
+use crate::test_harness::TestCity;
+use crate::{Saveable, SaveableRegistry, TickCounter};
+
+/// Codec over the `SaveableRegistry` only; the full codec lives in the save
+/// crate, which the simulation tests cannot depend on.
+fn registry_codec() -> CheckpointCodec {
+    fn capture(world: &mut World) -> Result<Vec<u8>, String> {
+        let map =
+            world.resource_scope(|world, registry: Mut<SaveableRegistry>| registry.save_all(world));
+        Ok(bitcode::encode(&map))
+    }
+    fn restore(world: &mut World, bytes: &[u8]) -> Result<(), String> {
+        let map: BTreeMap<String, Vec<u8>> = bitcode::decode(bytes).map_err(|e| e.to_string())?;
+        world.resource_scope(|world, registry: Mut<SaveableRegistry>| {
+            registry.load_all(world, &map)
+        });
+        Ok(())
+    }
+    CheckpointCodec { capture, restore }
+}
+
+fn replay_with_speed_changes(ticks: &[u64]) -> ReplayFile {
+    let entries: Vec<ReplayEntry> = ticks
+        .iter()
+        .map(|&tick| ReplayEntry {
+            tick,
+            action: GameAction::SetSpeed { speed: 1 },
+        })
+        .collect();
+    ReplayFile {
+        header: ReplayHeader {
+            format_version: CURRENT_FORMAT_VERSION,
+            seed: 42,
+            city_name: "Scrub".to_string(),
+            start_tick: 0,
+        },
+        footer: ReplayFooter {
+            end_tick: 100,
+            final_state_hash: 0,
+            entry_count: entries.len() as u64,
+        },
+        entries,
+        checksums: vec![],
+    }
+}
+
+/// A city playing `replay` with checkpoints every 10 ticks.
+fn playing_city(replay: ReplayFile) -> TestCity {
+    let mut city = TestCity::new();
+    let world = city.world_mut();
+    world.insert_resource(registry_codec());
+    world.insert_resource(ReplayCheckpoints::with_interval(10));
+    world.resource_mut::<ReplayPlayer>().load(replay);
+    city
+}
+
+fn tick_until(city: &mut TestCity, tick: u64) {
+    while city.resource::<TickCounter>().0 < tick {
+        city.tick(1);
+    }
+}
+
+fn finish_seek(city: &mut TestCity) {
+    for _ in 0..100 {
+        if !city.resource::<ReplaySeek>().is_seeking() {
+            return;
+        }
+        city.world_mut().run_schedule(Update);
+    }
+    panic!("seek did not finish");
+}
+
+fn rng_bytes(city: &TestCity) -> Vec<u8> {
+    city.resource::<SimRng>().save_to_bytes().unwrap()
+}
+
+#[test]
+fn test_checkpoints_captured_during_playback() {
+    let mut city = playing_city(replay_with_speed_changes(&[5, 15]));
+    let start = city.resource::<TickCounter>().0;
+    tick_until(&mut city, start + 35);
+
+    let checkpoints = city.resource::<ReplayCheckpoints>().checkpoints();
+    assert_eq!(
+        checkpoints[0].tick, start,
+        "first checkpoint at playback start"
+    );
+    assert!(checkpoints.len() >= 4, "{} checkpoints", checkpoints.len());
+    assert!(checkpoints[1..].iter().all(|c| c.tick.is_multiple_of(10)));
+    assert!(checkpoints.windows(2).all(|w| w[0].cursor <= w[1].cursor));
+}
+
+#[test]
+fn test_seek_backwards_restores_state_and_cursor() {
+    let mut city = playing_city(replay_with_speed_changes(&[5, 15, 25]));
+    tick_until(&mut city, 17);
+    let cursor_at_17 = city.resource::<ReplayPlayer>().cursor();
+    let rng_at_17 = rng_bytes(&city);
+    tick_until(&mut city, 40);
+    assert!(city.resource::<ReplayPlayer>().cursor() > cursor_at_17);
+
+    city.world_mut().resource_mut::<ReplaySeek>().request(17);
+    finish_seek(&mut city);
+
+    assert_eq!(city.resource::<TickCounter>().0, 17);
+    assert_eq!(city.resource::<ReplayPlayer>().cursor(), cursor_at_17);
+    assert_eq!(rng_bytes(&city), rng_at_17);
+    assert!(city.resource::<ReplaySeek>().last_error.is_none());
+}
+
+#[test]
+fn test_seek_forwards_simulates_ahead() {
+    let mut city = playing_city(replay_with_speed_changes(&[5]));
+    let start = city.resource::<TickCounter>().0;
+
+    city.world_mut()
+        .resource_mut::<ReplaySeek>()
+        .request(start + 25);
+    finish_seek(&mut city);
+
+    assert_eq!(city.resource::<TickCounter>().0, start + 25);
+    assert!(city.resource::<ReplayPlayer>().cursor() >= 1);
+}
+
+#[test]
+fn test_seek_without_codec_cannot_rewind() {
+    let mut city = playing_city(replay_with_speed_changes(&[5]));
+    city.world_mut().remove_resource::<CheckpointCodec>();
+    tick_until(&mut city, 20);
+
+    city.world_mut().resource_mut::<ReplaySeek>().request(3);
+    city.world_mut().run_schedule(Update);
+
+    let seek = city.resource::<ReplaySeek>();
+    assert!(!seek.is_seeking());
+    assert!(seek.last_error.is_some());
+    assert_eq!(city.resource::<TickCounter>().0, 20);
+}
+
+#[test]
+fn test_take_over_forks_the_timeline() {
+    let mut city = playing_city(replay_with_speed_changes(&[5, 15, 500]));
+    city.world_mut().insert_resource(ReplayViewerMode);
+    tick_until(&mut city, 20);
+
+    let fork_tick = take_over_replay(city.world_mut()).expect("take over");
+    assert_eq!(fork_tick, 20);
+    assert!(!city.world_mut().contains_resource::<ReplayViewerMode>());
+    assert!(!city.resource::<ReplayPlayer>().is_playing());
+    assert!(city
+        .resource::<ReplayCheckpoints>()
+        .checkpoints()
+        .is_empty());
+    assert!(city.resource::<ReplayRecorder>().is_recording());
+    let kept = city
+        .resource::<ReplayRecorder>()
+        .checkpoints()
+        .checkpoints();
+    assert!(!kept.is_empty(), "checkpoints before the fork are kept");
+    assert!(kept.iter().all(|c| c.tick <= fork_tick));
+
+    {
+        let world = city.world_mut();
+        world.resource_mut::<ActionQueue>().push(
+            fork_tick,
+            ActionSource::Player,
+            GameAction::SetPaused { paused: true },
+        );
+    }
+    city.tick(1);
+
+    let forked = {
+        let world = city.world_mut();
+        let tick = world.resource::<TickCounter>().0;
+        world.resource_mut::<ReplayRecorder>().stop(tick, 0)
+    };
+    assert!(forked.validate().is_ok());
+    assert_eq!(forked.header.city_name, "Scrub");
+    let ticks: Vec<u64> = forked.entries.iter().map(|e| e.tick).collect();
+    assert_eq!(&ticks[..2], &[5, 15], "played entries are kept");
+    assert!(!ticks.contains(&500), "unplayed entries are dropped");
+    assert_eq!(
+        forked.entries.last().unwrap().action,
+        GameAction::SetPaused { paused: true }
+    );
+
+    assert!(take_over_replay(city.world_mut()).is_err());
+}
@@ -52,6 +52,7 @@ fn sample_replay() -> ReplayFile {
             final_state_hash: 0xDEAD_BEEF,
             entry_count: 4,
         },
+        checksums: vec![],
     }
 }
 
@@ -117,6 +118,7 @@ fn test_replay_file_validate_empty_entries() {
             final_state_hash: 0,
             entry_count: 0,
         },
+        checksums: vec![],
     };
     assert!(replay.validate().is_ok());
 }
@@ -0,0 +1,55 @@
+//! Integration tests checking that a mine whose deposit runs out retires to
+//! processing imported ore, and a working mine keeps extracting.
+
+use crate::grid::ZoneType;
+use crate::test_harness::TestCity;
+
+/// An industrial building forced to work as a mine over the given ore.
+fn city_with_mine(ore_amount: u32) -> TestCity {
+    let mut city = TestCity::new()
+        .with_road(50, 50, 50, 60, crate::grid::RoadType::Local)
+        .with_building(49, 52, ZoneType::Industrial, 2);
+    city.tick(5);
+    let world = city.world_mut();
+    {
+        let mut resource_grid = world.resource_mut::<crate::natural_resources::ResourceGrid>();
+        let mut deposit = crate::natural_resources::ResourceDeposit::new(
+            crate::natural_resources::ResourceType::Ore,
+            5000,
+        );
+        deposit.amount = ore_amount;
+        resource_grid.set(47, 52, deposit);
+    }
+    let mut q = world.query::<&mut crate::production::IndustryBuilding>();
+    for mut industry in q.iter_mut(world) {
+        industry.industry_type = crate::production::IndustryType::Mining;
+    }
+    city
+}
+
+fn industry_types(city: &mut TestCity) -> Vec<crate::production::IndustryType> {
+    let world = city.world_mut();
+    let mut q = world.query::<&crate::production::IndustryBuilding>();
+    q.iter(world).map(|i| i.industry_type).collect()
+}
+
+#[test]
+fn natural_resources_exhausted_mine_becomes_smelter() {
+    let mut city = city_with_mine(0);
+    city.tick(20);
+    assert_eq!(
+        industry_types(&mut city),
+        vec![crate::production::IndustryType::Smelter],
+        "a mine with no ore left should switch to processing imported ore"
+    );
+}
+
+#[test]
+fn natural_resources_working_mine_keeps_extracting() {
+    let mut city = city_with_mine(5000);
+    city.tick(20);
+    assert_eq!(
+        industry_types(&mut city),
+        vec![crate::production::IndustryType::Mining]
+    );
+}
@@ -0,0 +1,84 @@
+//! Integration tests for the Yarkon's flow, the dam release and the
+//! downstream reach.
+
+use crate::grid::{CellType, WorldGrid};
+use crate::river_flow::*;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::wildlife::BiodiversityGrid;
+
+/// Run `days` daily river updates, ending on a slow tick.
+fn advance_days(city: &mut TestCity, days: u32) {
+    for _ in 0..days {
+        city.world_mut().resource_mut::<GameClock>().day += 1;
+        city.tick_slow_cycle();
+    }
+}
+
+fn set_release(city: &mut TestCity, mgd: f32) {
+    city.world_mut()
+        .resource_mut::<RiverFlowState>()
+        .release_target_mgd = mgd;
+}
+
+/// A three-cell-wide river running east-west through open grass.
+fn river_city() -> TestCity {
+    let mut city = TestCity::new();
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for x in 60..160 {
+            for y in 100..103 {
+                grid.get_mut(x, y).cell_type = CellType::Water;
+            }
+        }
+    }
+    city
+}
+
+#[test]
+fn test_river_flows_past_a_city_without_intakes() {
+    let mut city = TestCity::new();
+    advance_days(&mut city, 3);
+
+    let river = city.resource::<RiverFlowState>();
+    assert!(river.inflow_mgd > 0.0);
+    assert_eq!(river.extraction_mgd, 0.0);
+    assert_eq!(river.downstream_mgd, river.release_mgd);
+    assert!(!river.is_dry());
+}
+
+#[test]
+fn test_holding_back_the_dam_dries_the_reach_and_fills_storage() {
+    let mut city = TestCity::new();
+    advance_days(&mut city, 1);
+    let storage = city.resource::<RiverFlowState>().dam_storage_mg;
+
+    set_release(&mut city, 0.0);
+    advance_days(&mut city, 2);
+    let river = city.resource::<RiverFlowState>();
+    assert!(river.is_dry());
+    assert!(river.dry_days >= 1);
+    assert!(river.dam_storage_mg > storage);
+    assert_eq!(river.tourism_points(), 0.0);
+
+    set_release(&mut city, DEFAULT_RELEASE_MGD * 2.0);
+    advance_days(&mut city, 1);
+    let river = city.resource::<RiverFlowState>();
+    assert!(!river.is_dry());
+    assert_eq!(river.dry_days, 0);
+    assert_eq!(river.tourism_points(), RIVER_TOURISM_POINTS);
+}
+
+#[test]
+fn test_dry_river_supports_less_wildlife() {
+    let mut flowing = river_city();
+    let mut dry = river_city();
+    set_release(&mut dry, 0.0);
+    advance_days(&mut flowing, 2);
+    advance_days(&mut dry, 2);
+
+    let flowing_level = flowing.resource::<BiodiversityGrid>().get(100, 101);
+    let dry_level = dry.resource::<BiodiversityGrid>().get(100, 101);
+    assert!(flowing_level > 0);
+    assert!(dry_level < flowing_level);
+}
@@ -0,0 +1,142 @@
+//! Integration tests for scenarios and their objectives.
+
+use crate::disasters::{ActiveDisaster, DisasterType};
+use crate::economy::CityBudget;
+use crate::natural_resources::{ResourceGrid, ResourceType};
+use crate::scenario::*;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+
+fn scenario(objectives: Vec<Objective>) -> ScenarioDefinition {
+    ScenarioDefinition {
+        id: "test".to_string(),
+        name: "Test".to_string(),
+        description: String::new(),
+        map: StartingMap::Procedural { seed: None },
+        starting_treasury: None,
+        locked_tools: Vec::new(),
+        opening: None,
+        deposits: Vec::new(),
+        objectives,
+        lose_conditions: vec![Condition::Bankrupt],
+    }
+}
+
+fn treasury_objective(at_least: f64) -> Objective {
+    Objective {
+        description: "Save up".to_string(),
+        condition: Condition::Treasury { at_least },
+        deadline_days: Some(365),
+        required: true,
+        reward: None,
+    }
+}
+
+/// Move to the next day and run a tick so the daily check fires.
+fn next_day(city: &mut TestCity) {
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<GameClock>().day = day + 1;
+    city.tick(1);
+}
+
+#[test]
+fn test_begin_scenario_applies_opening() {
+    let mut city = TestCity::new();
+    let earthquake = builtin_scenarios()
+        .into_iter()
+        .find(|s| s.id == "earthquake_rebuild")
+        .unwrap();
+    begin_scenario(city.world_mut(), earthquake.clone());
+
+    assert_eq!(
+        Some(city.resource::<CityBudget>().treasury),
+        earthquake.starting_treasury
+    );
+    let disaster = city.resource::<ActiveDisaster>().current.clone().unwrap();
+    assert_eq!(disaster.disaster_type, DisasterType::Earthquake);
+    let state = city.resource::<ScenarioState>();
+    assert!(state.is_active());
+    assert_eq!(state.objectives.len(), earthquake.objectives.len());
+}
+
+#[test]
+fn test_begin_scenario_lays_deposits() {
+    let mut city = TestCity::new();
+    let mut definition = scenario(vec![treasury_objective(1.0)]);
+    definition.deposits.push(ScenarioDeposit {
+        resource: ResourceType::Oil,
+        x: 80,
+        y: 80,
+        radius: 2,
+        amount: Some(750),
+    });
+    begin_scenario(city.world_mut(), definition);
+
+    let resources = city.resource::<ResourceGrid>();
+    let deposit = resources
+        .get(80, 80)
+        .as_ref()
+        .expect("oil laid at the centre");
+    assert_eq!(deposit.resource_type, ResourceType::Oil);
+    assert_eq!(deposit.amount, 750);
+    assert_eq!(resources.total_remaining(ResourceType::Oil), 13 * 750);
+}
+
+#[test]
+fn test_objective_met_wins_scenario() {
+    let mut city = TestCity::new().with_budget(200_000.0);
+    begin_scenario(
+        city.world_mut(),
+        scenario(vec![treasury_objective(100_000.0)]),
+    );
+    next_day(&mut city);
+
+    let state = city.resource::<ScenarioState>();
+    assert!(matches!(
+        state.objectives[0],
+        ObjectiveStatus::Complete { .. }
+    ));
+    assert!(matches!(state.outcome, ScenarioOutcome::Won { .. }));
+    assert!(!state.is_active());
+}
+
+#[test]
+fn test_objective_reward_pays_grant() {
+    let mut city = TestCity::new().with_budget(200_000.0);
+    let mut objective = treasury_objective(100_000.0);
+    objective.reward = Some(ObjectiveReward::Treasury { amount: 50_000.0 });
+    begin_scenario(city.world_mut(), scenario(vec![objective]));
+    next_day(&mut city);
+
+    assert!(matches!(
+        city.resource::<ScenarioState>().objectives[0],
+        ObjectiveStatus::Complete { .. }
+    ));
+    assert!(city.resource::<CityBudget>().treasury > 240_000.0);
+}
+
+#[test]
+fn test_bankruptcy_loses_scenario() {
+    let mut city = TestCity::new().with_budget(-1_000.0);
+    begin_scenario(
+        city.world_mut(),
+        scenario(vec![treasury_objective(100_000.0)]),
+    );
+    city.tick_slow_cycle();
+    next_day(&mut city);
+
+    let state = city.resource::<ScenarioState>();
+    assert!(matches!(state.outcome, ScenarioOutcome::Lost { .. }));
+    assert_eq!(state.objectives[0], ObjectiveStatus::Pending);
+}
+
+#[test]
+fn test_sandbox_has_no_scenario() {
+    let mut city = TestCity::new();
+    next_day(&mut city);
+    let state = city.resource::<ScenarioState>();
+    assert!(state.definition.is_none());
+    assert!(!state.locks(ToolLock::Zone {
+        zone: crate::grid::ZoneType::Industrial
+    }));
+}
@@ -0,0 +1,61 @@
+//! Integration tests for the seasonal cycle: day length and the harvest.
+
+use crate::harvest::{HarvestState, HARVEST_DAY_OF_YEAR};
+use crate::solar_power::SolarPowerState;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::utilities::UtilityType;
+use crate::weather::Weather;
+
+/// Sunny skies on `day`, with the hour set so a slow cycle ends just after
+/// six in the evening.
+fn sunny_evening(day: u32) -> TestCity {
+    let mut city = TestCity::new()
+        .with_utility(50, 50, UtilityType::SolarFarm)
+        .with_time(16.5);
+    let world = city.world_mut();
+    let mut weather = world.resource_mut::<Weather>();
+    weather.cloud_cover = 0.1;
+    weather.atmo_precipitation = 0.0;
+    weather.humidity = 0.3;
+    weather.temperature = 20.0;
+    weather.event_days_remaining = 100;
+    world.resource_mut::<GameClock>().day = day;
+    city
+}
+
+#[test]
+fn test_summer_evenings_stay_light_longer_than_winter() {
+    let mut summer = sunny_evening(135);
+    summer.tick_slow_cycle();
+    let mut winter = sunny_evening(315);
+    winter.tick_slow_cycle();
+
+    let summer_clock = summer.resource::<GameClock>();
+    let winter_clock = winter.resource::<GameClock>();
+    assert!(
+        summer_clock.is_daylight(),
+        "the sun is still up at 18:00 in summer"
+    );
+    assert!(
+        !winter_clock.is_daylight(),
+        "the sun has set by 18:00 in winter"
+    );
+
+    assert!(summer.resource::<SolarPowerState>().total_output_mw > 0.0);
+    assert_eq!(winter.resource::<SolarPowerState>().total_output_mw, 0.0);
+}
+
+#[test]
+fn test_harvest_without_farms_clears_the_year() {
+    let mut city = TestCity::new();
+    city.world_mut().resource_mut::<GameClock>().day = HARVEST_DAY_OF_YEAR;
+    city.world_mut().resource_mut::<HarvestState>().crop_growth = 1.0;
+    city.tick(1);
+
+    let state = city.resource::<HarvestState>();
+    assert_eq!(state.harvested_year, 1);
+    assert_eq!(state.crop_growth, 0.0);
+    assert_eq!(state.last_harvest_tons, 0.0);
+    assert_eq!(state.total_revenue, 0.0);
+}
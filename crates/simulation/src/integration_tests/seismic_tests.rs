@@ -0,0 +1,130 @@
+//! Integration tests for earthquake shaking and the retrofit program.
+
+use crate::buildings::Building;
+use crate::disasters::{ActiveDisaster, DisasterInstance, DisasterType};
+use crate::grid::ZoneType;
+use crate::policies::{Policies, Policy};
+use crate::seismic::{SeismicState, RETROFIT_MONTHS};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+
+fn quake_at(city: &mut TestCity, x: usize, y: usize, radius: usize) {
+    city.world_mut().remove_resource::<crate::TestSafetyNet>();
+    city.world_mut().resource_mut::<ActiveDisaster>().current = Some(DisasterInstance {
+        disaster_type: DisasterType::Earthquake,
+        center_x: x,
+        center_y: y,
+        radius,
+        ticks_remaining: 20,
+        damage_applied: false,
+    });
+}
+
+/// Advance to the next retrofit installment.
+fn next_month(city: &mut TestCity) {
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<GameClock>().day = day + 30;
+    city.tick(1);
+}
+
+/// Sum of levels lost, counting a collapse as the whole building.
+fn levels_lost(city: &mut TestCity, cells: &[(usize, usize)], before: u8) -> u32 {
+    let world = city.world_mut();
+    let mut query = world.query::<&Building>();
+    let standing: Vec<&Building> = query.iter(world).collect();
+    cells
+        .iter()
+        .map(|&(x, y)| {
+            standing
+                .iter()
+                .find(|b| b.grid_x == x && b.grid_y == y)
+                .map_or(before as u32, |b| (before - b.level) as u32)
+        })
+        .sum()
+}
+
+#[test]
+fn test_earthquake_records_report() {
+    let mut city = TestCity::new().with_building(128, 128, ZoneType::ResidentialLow, 3);
+    quake_at(&mut city, 128, 128, 20);
+    city.tick(2);
+
+    let report = city
+        .resource::<SeismicState>()
+        .last_quake
+        .clone()
+        .expect("earthquake should be reported");
+    assert_eq!(report.epicenter, (128, 128));
+    assert!((report.magnitude - 7.5).abs() < 1e-4);
+    assert_eq!(report.collapsed + report.damaged, 1);
+}
+
+#[test]
+fn test_retrofitted_buildings_fare_better() {
+    // Two rows near the edge of the felt radius, one of them retrofitted.
+    let plain: Vec<(usize, usize)> = (124..134).map(|x| (x, 136)).collect();
+    let braced: Vec<(usize, usize)> = (124..134).map(|x| (x, 120)).collect();
+    let mut city = TestCity::new();
+    for &(x, y) in plain.iter().chain(&braced) {
+        city = city.with_building(x, y, ZoneType::ResidentialLow, 3);
+    }
+    {
+        let mut state = city.world_mut().resource_mut::<SeismicState>();
+        for &(x, y) in &braced {
+            state.mark_retrofitted(x, y);
+        }
+    }
+
+    quake_at(&mut city, 128, 128, 10);
+    city.tick(2);
+
+    let plain_lost = levels_lost(&mut city, &plain, 3);
+    let braced_lost = levels_lost(&mut city, &braced, 3);
+    assert!(plain_lost >= 10, "every plain building should be damaged");
+    assert!(
+        braced_lost < plain_lost,
+        "retrofits should limit damage: {braced_lost} vs {plain_lost}"
+    );
+}
+
+#[test]
+fn test_retrofit_program_completes_over_a_year() {
+    let mut city =
+        TestCity::new()
+            .with_budget(1_000_000.0)
+            .with_building(50, 50, ZoneType::ResidentialLow, 2);
+    city.world_mut()
+        .resource_mut::<Policies>()
+        .toggle(Policy::SeismicRetrofit);
+
+    next_month(&mut city);
+    {
+        let state = city.resource::<SeismicState>();
+        assert!(state.is_retrofitting(50, 50));
+        assert!(!state.is_retrofitted(50, 50));
+    }
+
+    for _ in 1..RETROFIT_MONTHS {
+        next_month(&mut city);
+    }
+    let state = city.resource::<SeismicState>();
+    assert!(state.is_retrofitted(50, 50));
+    assert!(state.projects.is_empty());
+    assert!(state.total_spent > 0.0);
+}
+
+#[test]
+fn test_retrofit_waits_when_treasury_is_empty() {
+    let mut city =
+        TestCity::new()
+            .with_budget(0.0)
+            .with_building(50, 50, ZoneType::ResidentialLow, 2);
+    city.world_mut()
+        .resource_mut::<Policies>()
+        .toggle(Policy::SeismicRetrofit);
+
+    next_month(&mut city);
+    let state = city.resource::<SeismicState>();
+    assert_eq!(state.projects[0].months_done, 0);
+    assert_eq!(state.total_spent, 0.0);
+}
@@ -0,0 +1,107 @@
+//! Integration tests for in-place service building upgrades.
+
+use bevy::prelude::*;
+
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::service_capacity::ServiceCapacity;
+use crate::service_upgrade::{upgrade_cost, ServiceUpgradeRequest};
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::test_harness::TestCity;
+
+fn service_entity(city: &mut TestCity) -> Entity {
+    let world = city.world_mut();
+    let mut q = world.query_filtered::<Entity, With<ServiceBuilding>>();
+    q.iter(world).next().expect("service building should exist")
+}
+
+/// Place a service and let the capacity component attach.
+fn city_with(service_type: ServiceType, treasury: f64) -> (TestCity, Entity) {
+    let mut city = TestCity::new()
+        .with_budget(treasury)
+        .with_service(50, 50, service_type);
+    city.tick(1);
+    let entity = service_entity(&mut city);
+    (city, entity)
+}
+
+fn set_usage_fraction(city: &mut TestCity, entity: Entity, fraction: f32) {
+    let mut capacity = city
+        .world_mut()
+        .get_mut::<ServiceCapacity>(entity)
+        .expect("capacity should be attached");
+    capacity.current_usage = (capacity.capacity as f32 * fraction) as u32;
+}
+
+fn request_upgrade(city: &mut TestCity, entity: Entity) {
+    city.world_mut()
+        .send_event(ServiceUpgradeRequest { entity });
+    city.world_mut().run_schedule(Update);
+}
+
+#[test]
+fn test_service_upgrade_clinic_to_hospital_in_place() {
+    let (mut city, entity) = city_with(ServiceType::MedicalClinic, 100_000.0);
+    set_usage_fraction(&mut city, entity, 0.9);
+    let usage = city
+        .world_mut()
+        .get::<ServiceCapacity>(entity)
+        .unwrap()
+        .current_usage;
+    let treasury = city.resource::<CityBudget>().treasury;
+
+    request_upgrade(&mut city, entity);
+
+    let world = city.world_mut();
+    let service = world.get::<ServiceBuilding>(entity).unwrap();
+    assert_eq!(service.service_type, ServiceType::Hospital);
+    assert_eq!((service.grid_x, service.grid_y), (50, 50));
+    assert_eq!(
+        service.radius,
+        ServiceBuilding::coverage_radius(ServiceType::Hospital)
+    );
+    let capacity = world.get::<ServiceCapacity>(entity).unwrap();
+    assert_eq!(capacity.current_usage, usage, "usage history is kept");
+    assert!(capacity.capacity > usage);
+
+    let spent = treasury - city.resource::<CityBudget>().treasury;
+    let expected = upgrade_cost(ServiceType::MedicalClinic, ServiceType::Hospital);
+    assert!((spent - expected).abs() < 0.01);
+}
+
+#[test]
+fn test_service_upgrade_blocked_without_demand() {
+    let (mut city, entity) = city_with(ServiceType::FireHouse, 100_000.0);
+    set_usage_fraction(&mut city, entity, 0.2);
+
+    request_upgrade(&mut city, entity);
+
+    let service = city.world_mut().get::<ServiceBuilding>(entity).unwrap();
+    assert_eq!(service.service_type, ServiceType::FireHouse);
+}
+
+#[test]
+fn test_service_upgrade_blocked_without_funds() {
+    let (mut city, entity) = city_with(ServiceType::FireHouse, 10.0);
+    set_usage_fraction(&mut city, entity, 1.0);
+
+    request_upgrade(&mut city, entity);
+
+    let service = city.world_mut().get::<ServiceBuilding>(entity).unwrap();
+    assert_eq!(service.service_type, ServiceType::FireHouse);
+}
+
+#[test]
+fn test_service_upgrade_to_hq_claims_footprint() {
+    let (mut city, entity) = city_with(ServiceType::FireStation, 100_000.0);
+    set_usage_fraction(&mut city, entity, 1.0);
+
+    request_upgrade(&mut city, entity);
+
+    let service = city.world_mut().get::<ServiceBuilding>(entity).unwrap();
+    assert_eq!(service.service_type, ServiceType::FireHQ);
+    let grid = city.resource::<WorldGrid>();
+    for (x, y) in [(50, 50), (52, 50), (50, 52), (52, 52)] {
+        assert_eq!(grid.get(x, y).building_id, Some(entity));
+    }
+}
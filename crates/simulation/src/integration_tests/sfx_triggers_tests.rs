@@ -55,7 +55,7 @@ fn test_sfx_emitted_on_building_spawn() {
 
 #[test]
 fn test_sfx_emitted_on_notification_event() {
-    use crate::notifications::{NotificationEvent, NotificationPriority};
+    use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 
     let mut city = TestCity::new();
 
@@ -63,6 +63,7 @@ fn test_sfx_emitted_on_notification_event() {
     city.world_mut().send_event(NotificationEvent {
         text: "Test notification".to_string(),
         priority: NotificationPriority::Info,
+        category: NotificationCategory::General,
         location: None,
     });
 
@@ -79,13 +80,14 @@ fn test_sfx_emitted_on_notification_event() {
 
 #[test]
 fn test_sfx_warning_on_emergency_notification() {
-    use crate::notifications::{NotificationEvent, NotificationPriority};
+    use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 
     let mut city = TestCity::new();
 
     city.world_mut().send_event(NotificationEvent {
         text: "Emergency!".to_string(),
         priority: NotificationPriority::Emergency,
+        category: NotificationCategory::General,
         location: None,
     });
 
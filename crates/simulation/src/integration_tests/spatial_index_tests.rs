@@ -0,0 +1,69 @@
+//! Integration tests for systems that look up the nearest building through
+//! the two-level `SpatialGrid`.
+
+use bevy::prelude::*;
+
+use crate::citizen::{Citizen, CitizenDetails, Gender, Position};
+use crate::grid::WorldGrid;
+use crate::homelessness::{Homeless, HomelessShelter};
+use crate::test_harness::TestCity;
+
+fn spawn_shelter(city: &mut TestCity, grid_x: usize, grid_y: usize) -> Entity {
+    city.world_mut()
+        .spawn(HomelessShelter {
+            grid_x,
+            grid_y,
+            capacity: 1,
+            current_occupants: 0,
+        })
+        .id()
+}
+
+fn spawn_homeless(city: &mut TestCity, grid_x: usize, grid_y: usize) -> Entity {
+    let (x, y) = WorldGrid::grid_to_world(grid_x, grid_y);
+    city.world_mut()
+        .spawn((
+            Citizen,
+            Position { x, y },
+            CitizenDetails {
+                age: 30,
+                gender: Gender::Male,
+                education: 1,
+                happiness: 50.0,
+                health: 100.0,
+                salary: 0.0,
+                savings: 0.0,
+            },
+            Homeless {
+                ticks_homeless: 0,
+                sheltered: false,
+            },
+        ))
+        .id()
+}
+
+fn occupants(city: &mut TestCity, shelter: Entity) -> u32 {
+    city.world_mut()
+        .get::<HomelessShelter>(shelter)
+        .unwrap()
+        .current_occupants
+}
+
+#[test]
+fn test_homeless_citizen_takes_nearest_shelter_with_room() {
+    let mut city = TestCity::new();
+    let far = spawn_shelter(&mut city, 200, 200);
+    let near = spawn_shelter(&mut city, 60, 60);
+    let first = spawn_homeless(&mut city, 58, 58);
+    city.tick(50);
+
+    assert!(city.world_mut().get::<Homeless>(first).unwrap().sheltered);
+    assert_eq!(occupants(&mut city, near), 1);
+    assert_eq!(occupants(&mut city, far), 0);
+
+    // The near shelter is full now, so the next citizen walks further.
+    spawn_homeless(&mut city, 62, 62);
+    city.tick(50);
+    assert_eq!(occupants(&mut city, near), 1);
+    assert_eq!(occupants(&mut city, far), 1);
+}
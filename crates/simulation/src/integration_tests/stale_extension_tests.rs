@@ -43,7 +43,7 @@ fn test_sequential_load_resets_climate_state_when_absent_in_save_b() {
         let mut climate = city.world_mut().resource_mut::<ClimateState>();
         climate.cumulative_co2 = 500_000.0;
         climate.temperature_increase_f = 2.5;
-        climate.sea_level_rise_applied = true;
+        climate.sea_level_rise = 0.05;
         climate.flooded_cells_count = 42;
     }
 
@@ -70,9 +70,9 @@ fn test_sequential_load_resets_climate_state_when_absent_in_save_b() {
         climate.temperature_increase_f, 0.0,
         "temperature_increase_f must reset to default"
     );
-    assert!(
-        !climate.sea_level_rise_applied,
-        "sea_level_rise_applied must reset to false"
+    assert_eq!(
+        climate.sea_level_rise, 0.0,
+        "sea_level_rise must reset to 0"
     );
     assert_eq!(
         climate.flooded_cells_count, 0,
@@ -0,0 +1,44 @@
+//! Integration tests for street lighting: lamps along new roads and the
+//! night crime of unlit streets.
+
+use crate::crime::CrimeGrid;
+use crate::grid::{RoadType, ZoneType};
+use crate::street_lighting::{StreetLighting, UNLIT_NIGHT_CRIME};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::utilities::UtilityType;
+
+#[test]
+fn test_new_roads_get_lamps() {
+    let mut city = TestCity::new().with_road(40, 50, 60, 50, RoadType::Local);
+    city.tick(1);
+
+    let lighting = city.resource::<StreetLighting>();
+    assert!(
+        lighting.iter().count() > 0,
+        "a new road should be lined with lamps"
+    );
+    assert!(lighting.iter().all(|(_, y, _)| y == 50));
+}
+
+#[test]
+fn test_unlit_streets_draw_night_crime() {
+    let night = |lit: bool| {
+        let mut city = TestCity::new()
+            .with_road(40, 50, 60, 50, RoadType::Local)
+            .with_utility(40, 49, UtilityType::PowerPlant)
+            .with_building(50, 51, ZoneType::ResidentialLow, 1);
+        city.world_mut().resource_mut::<GameClock>().hour = 23.0;
+        if !lit {
+            city.world_mut().resource_mut::<StreetLighting>().auto_place = false;
+        }
+        city.tick_slow_cycle();
+        city.resource::<CrimeGrid>().get(50, 51)
+    };
+    let lit = night(true);
+    let dark = night(false);
+    assert!(
+        dark >= lit + UNLIT_NIGHT_CRIME / 2,
+        "an unlit street should have more crime at night ({dark} vs {lit})"
+    );
+}
@@ -0,0 +1,149 @@
+//! Integration tests for building condition, inspections and condemnation.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::disasters::EarthquakeDamaged;
+use crate::economy::CityBudget;
+use crate::grid::ZoneType;
+use crate::structural_integrity::{
+    renovation_cost, Condemned, StructuralAction, StructuralActionRequest, StructuralState,
+    StructuralStatus, CONDEMNED_GRACE_DAYS, INSPECTION_PERIOD_DAYS, MAX_CONDITION,
+    QUAKE_WEAR_PER_LEVEL,
+};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+
+const X: usize = 60;
+const Y: usize = 60;
+
+fn building_entity(city: &TestCity) -> Entity {
+    city.grid()
+        .get(X, Y)
+        .building_id
+        .expect("building should exist")
+}
+
+/// A level 2 house already worn down to `condition`.
+fn city_with_worn_house(condition: f32) -> TestCity {
+    let mut city =
+        TestCity::new()
+            .with_budget(100_000.0)
+            .with_building(X, Y, ZoneType::ResidentialLow, 2);
+    city.world_mut()
+        .resource_mut::<StructuralState>()
+        .wear(X, Y, MAX_CONDITION - condition);
+    city
+}
+
+/// Move the clock forward `days` and let the inspection run.
+fn advance_days(city: &mut TestCity, days: u32) {
+    city.world_mut().resource_mut::<GameClock>().day += days;
+    city.tick(2);
+}
+
+fn request(city: &mut TestCity, action: StructuralAction) {
+    city.world_mut().send_event(StructuralActionRequest {
+        grid_x: X,
+        grid_y: Y,
+        action,
+    });
+    city.world_mut().run_schedule(Update);
+}
+
+#[test]
+fn test_inspection_flags_unsafe_building() {
+    let mut city = city_with_worn_house(30.0);
+    advance_days(&mut city, INSPECTION_PERIOD_DAYS);
+
+    let state = city.resource::<StructuralState>();
+    assert_eq!(state.status(X, Y), StructuralStatus::Unsafe);
+    assert_eq!(state.unsafe_count, 1);
+    assert_eq!(state.condemned_count, 0);
+    assert_eq!(
+        state.last_inspection.as_ref().map(|r| r.newly_unsafe),
+        Some(1)
+    );
+    assert!(state.condition(X, Y) < 30.0, "age wear applied");
+}
+
+#[test]
+fn test_critically_damaged_building_is_condemned_and_vacated() {
+    let mut city = city_with_worn_house(10.0);
+    let entity = building_entity(&city);
+    city.world_mut()
+        .get_mut::<Building>(entity)
+        .unwrap()
+        .occupants = 8;
+
+    advance_days(&mut city, INSPECTION_PERIOD_DAYS);
+
+    assert!(city.resource::<StructuralState>().is_condemned(X, Y));
+    assert_eq!(city.resource::<StructuralState>().condemned_count, 1);
+    let world = city.world_mut();
+    assert!(world.get::<Condemned>(entity).is_some());
+    assert_eq!(world.get::<Building>(entity).unwrap().occupants, 0);
+}
+
+#[test]
+fn test_renovation_restores_condition_and_lifts_condemnation() {
+    let mut city = city_with_worn_house(10.0);
+    advance_days(&mut city, INSPECTION_PERIOD_DAYS);
+    let entity = building_entity(&city);
+    let treasury = city.resource::<CityBudget>().treasury;
+
+    request(&mut city, StructuralAction::Renovate);
+
+    let state = city.resource::<StructuralState>();
+    assert_eq!(state.condition(X, Y), MAX_CONDITION);
+    assert!(!state.is_condemned(X, Y));
+    assert_eq!(state.condemned_count, 0);
+    let spent = treasury - city.resource::<CityBudget>().treasury;
+    assert!((spent - renovation_cost(2)).abs() < 1e-6);
+    assert!(city.world_mut().get::<Condemned>(entity).is_none());
+}
+
+#[test]
+fn test_player_can_demolish_damaged_building() {
+    let mut city = city_with_worn_house(30.0);
+    request(&mut city, StructuralAction::Demolish);
+    city.tick(1);
+
+    assert_eq!(city.building_count(), 0);
+    assert!(city.grid().get(X, Y).building_id.is_none());
+}
+
+#[test]
+fn test_condemned_building_demolished_after_grace_period() {
+    let mut city = city_with_worn_house(10.0);
+    city.world_mut().remove_resource::<crate::TestSafetyNet>();
+    advance_days(&mut city, INSPECTION_PERIOD_DAYS);
+    assert_eq!(
+        city.building_count(),
+        1,
+        "condemned buildings get a grace period"
+    );
+
+    advance_days(&mut city, CONDEMNED_GRACE_DAYS);
+
+    assert_eq!(city.building_count(), 0);
+    let state = city.resource::<StructuralState>();
+    assert_eq!(
+        state.last_inspection.as_ref().map(|r| r.demolished),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_earthquake_damage_wears_condition() {
+    let mut city = TestCity::new().with_building(X, Y, ZoneType::ResidentialLow, 3);
+    let entity = building_entity(&city);
+    city.world_mut()
+        .entity_mut(entity)
+        .insert(EarthquakeDamaged { levels_lost: 2 });
+
+    city.tick(1);
+
+    let condition = city.resource::<StructuralState>().condition(X, Y);
+    assert_eq!(condition, MAX_CONDITION - 2.0 * QUAKE_WEAR_PER_LEVEL);
+}
@@ -11,7 +11,7 @@
 //!   - `update_happiness` reads `TrafficGrid` and `ServiceCoverageGrid`
 //!
 //! The service coverage → happiness chain is testable end-to-end because
-//! `update_service_coverage` uses `Added<ServiceBuilding>` change detection,
+//! `update_service_coverage` uses `Changed<ServiceBuilding>` change detection,
 //! so spawning a hospital right before a happiness tick proves the chain.
 //!
 //! The traffic → happiness dependency is verified by confirming that
@@ -315,7 +315,7 @@ fn test_coverage_and_happiness_chained_within_single_tick() {
         });
     }
 
-    // Tick once — update_service_coverage detects Added<ServiceBuilding>
+    // Tick once — update_service_coverage detects Changed<ServiceBuilding>
     // and computes coverage, THEN update_happiness reads it.
     stabilize_needs(&mut city);
     city.tick(1);
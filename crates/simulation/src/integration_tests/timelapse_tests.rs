@@ -0,0 +1,52 @@
+//! Integration tests for time-lapse snapshot capture.
+
+use crate::grid::{RoadType, ZoneType};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::timelapse::{FrameCell, TimelapseHistory, FRAME_WIDTH};
+
+#[test]
+fn test_timelapse_captures_founding_frame() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycle();
+
+    let history = city.resource::<TimelapseHistory>();
+    assert_eq!(history.frames.len(), 1, "first slow tick should capture");
+}
+
+#[test]
+fn test_timelapse_waits_for_interval_before_next_frame() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycles(3);
+    assert_eq!(city.resource::<TimelapseHistory>().frames.len(), 1);
+
+    let interval = city.resource::<TimelapseHistory>().interval_days;
+    city.world_mut().resource_mut::<GameClock>().day += interval;
+    city.tick_slow_cycle();
+    assert_eq!(city.resource::<TimelapseHistory>().frames.len(), 2);
+}
+
+#[test]
+fn test_timelapse_frame_shows_roads_and_zones() {
+    let mut city = TestCity::new()
+        .with_road(100, 100, 120, 100, RoadType::Local)
+        .with_zone_rect(100, 102, 110, 106, ZoneType::ResidentialLow);
+    city.tick_slow_cycle();
+
+    let history = city.resource::<TimelapseHistory>();
+    let pixels = history.frames[0].decode();
+    assert_eq!(pixels[50 * FRAME_WIDTH + 55], FrameCell::Road as u8);
+    let zoned = pixels[52 * FRAME_WIDTH + 52];
+    assert!(
+        zoned == FrameCell::ZonedResidential as u8 || zoned == FrameCell::BuiltResidential as u8,
+        "zoned cell should show as residential, got {zoned}"
+    );
+}
+
+#[test]
+fn test_timelapse_disabled_captures_nothing() {
+    let mut city = TestCity::new();
+    city.world_mut().resource_mut::<TimelapseHistory>().enabled = false;
+    city.tick_slow_cycles(2);
+    assert!(city.resource::<TimelapseHistory>().frames.is_empty());
+}
@@ -9,7 +9,7 @@ use crate::grid::{RoadType, ZoneType};
 use crate::stats::CityStats;
 use crate::test_harness::TestCity;
 use crate::time_of_day::GameClock;
-use crate::tutorial::{TutorialState, TutorialStep};
+use crate::tutorial::{TutorialSignal, TutorialState, SIGNAL_BUDGET_OPENED};
 use crate::utilities::UtilityType;
 
 // ---------------------------------------------------------------------------
@@ -17,12 +17,12 @@ use crate::utilities::UtilityType;
 // ---------------------------------------------------------------------------
 
 /// Activate the tutorial at a specific step on a fresh TestCity.
-fn activate_tutorial_at(city: &mut TestCity, step: TutorialStep) {
+fn activate_tutorial_at(city: &mut TestCity, step: &str) {
     let world = city.world_mut();
     let mut tutorial = world.resource_mut::<TutorialState>();
     tutorial.active = true;
     tutorial.completed = false;
-    tutorial.current_step = step;
+    assert!(tutorial.go_to(step), "no tutorial step '{step}'");
     tutorial.paused_by_tutorial = false;
 }
 
@@ -39,51 +39,45 @@ fn run_update(city: &mut TestCity) {
 fn test_tutorial_full_flow_welcome_to_completed() {
     let mut state = TutorialState {
         active: true,
-        completed: false,
-        current_step: TutorialStep::Welcome,
-        paused_by_tutorial: false,
+        ..Default::default()
     };
 
     // Verify all 9 steps have titles and descriptions
     let expected_steps = [
-        TutorialStep::Welcome,
-        TutorialStep::PlaceRoad,
-        TutorialStep::ZoneResidential,
-        TutorialStep::ZoneCommercial,
-        TutorialStep::PlacePowerPlant,
-        TutorialStep::PlaceWaterTower,
-        TutorialStep::ObserveGrowth,
-        TutorialStep::ManageBudget,
-        TutorialStep::Completed,
+        "welcome",
+        "place_road",
+        "zone_residential",
+        "zone_commercial",
+        "place_power_plant",
+        "place_water_tower",
+        "observe_growth",
+        "manage_budget",
+        "completed",
     ];
 
     for (i, &expected) in expected_steps.iter().enumerate() {
         assert_eq!(
-            state.current_step, expected,
-            "Step {i} mismatch: expected {expected:?}, got {:?}",
-            state.current_step
+            state.step_id(),
+            expected,
+            "Step {i} mismatch: expected {expected}, got {}",
+            state.step_id()
         );
+        let step = state.step();
+        assert!(!step.title.is_empty(), "Step {i} ({expected}) has empty title");
         assert!(
-            !state.current_step.title().is_empty(),
-            "Step {i} ({expected:?}) has empty title"
-        );
-        assert!(
-            !state.current_step.description().is_empty(),
-            "Step {i} ({expected:?}) has empty description"
-        );
-        assert!(
-            !state.current_step.hint().is_empty(),
-            "Step {i} ({expected:?}) has empty hint"
+            !step.description.is_empty(),
+            "Step {i} ({expected}) has empty description"
         );
+        assert!(!step.hint.is_empty(), "Step {i} ({expected}) has empty hint");
 
-        if expected != TutorialStep::Completed {
+        if expected != "completed" {
             assert!(state.advance(), "Failed to advance from step {i}");
         }
     }
 
     assert!(state.completed);
     assert!(!state.active);
-    assert_eq!(state.current_step, TutorialStep::Completed);
+    assert_eq!(state.step_id(), "completed");
 }
 
 // ---------------------------------------------------------------------------
@@ -95,13 +89,13 @@ fn test_tutorial_place_road_auto_advances() {
     let mut city = TestCity::new()
         .with_road(10, 10, 10, 15, RoadType::Local);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlaceRoad);
+    activate_tutorial_at(&mut city, "place_road");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneResidential,
+        tutorial.step_id(),
+        "zone_residential",
         "PlaceRoad should auto-advance to ZoneResidential after placing a road"
     );
 }
@@ -115,13 +109,13 @@ fn test_tutorial_zone_residential_auto_advances() {
     let mut city = TestCity::new()
         .with_zone_rect(12, 10, 13, 14, ZoneType::ResidentialLow); // 2x5 = 10 cells
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneResidential);
+    activate_tutorial_at(&mut city, "zone_residential");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneCommercial,
+        tutorial.step_id(),
+        "zone_commercial",
         "ZoneResidential should auto-advance to ZoneCommercial after zoning >= 10 residential cells"
     );
 }
@@ -135,13 +129,13 @@ fn test_tutorial_zone_commercial_auto_advances() {
     let mut city = TestCity::new()
         .with_zone_rect(14, 10, 18, 10, ZoneType::CommercialLow); // 5x1 = 5 cells
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneCommercial);
+    activate_tutorial_at(&mut city, "zone_commercial");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::PlacePowerPlant,
+        tutorial.step_id(),
+        "place_power_plant",
         "ZoneCommercial should auto-advance to PlacePowerPlant after zoning >= 5 commercial cells"
     );
 }
@@ -155,13 +149,13 @@ fn test_tutorial_place_power_auto_advances() {
     let mut city = TestCity::new()
         .with_utility(20, 20, UtilityType::PowerPlant);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlacePowerPlant);
+    activate_tutorial_at(&mut city, "place_power_plant");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::PlaceWaterTower,
+        tutorial.step_id(),
+        "place_water_tower",
         "PlacePowerPlant should auto-advance to PlaceWaterTower after placing power"
     );
 }
@@ -175,13 +169,13 @@ fn test_tutorial_place_water_auto_advances() {
     let mut city = TestCity::new()
         .with_utility(22, 20, UtilityType::WaterTower);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlaceWaterTower);
+    activate_tutorial_at(&mut city, "place_water_tower");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ObserveGrowth,
+        tutorial.step_id(),
+        "observe_growth",
         "PlaceWaterTower should auto-advance to ObserveGrowth after placing water tower"
     );
 }
@@ -194,14 +188,14 @@ fn test_tutorial_place_water_auto_advances() {
 fn test_tutorial_observe_growth_waits_for_population_and_building() {
     let mut city = TestCity::new()
         .with_building(12, 10, ZoneType::ResidentialLow, 1);
-    activate_tutorial_at(&mut city, TutorialStep::ObserveGrowth);
+    activate_tutorial_at(&mut city, "observe_growth");
 
     // With population = 0, should NOT advance even with a building
     run_update(&mut city);
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ObserveGrowth,
+        tutorial.step_id(),
+        "observe_growth",
         "ObserveGrowth should not advance with population 0"
     );
 
@@ -214,8 +208,8 @@ fn test_tutorial_observe_growth_waits_for_population_and_building() {
     run_update(&mut city);
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ManageBudget,
+        tutorial.step_id(),
+        "manage_budget",
         "ObserveGrowth should advance to ManageBudget when population >= 5 and building exists"
     );
 }
@@ -227,38 +221,37 @@ fn test_tutorial_observe_growth_waits_for_population_and_building() {
 #[test]
 fn test_tutorial_skip_at_every_step() {
     let action_steps = [
-        TutorialStep::Welcome,
-        TutorialStep::PlaceRoad,
-        TutorialStep::ZoneResidential,
-        TutorialStep::ZoneCommercial,
-        TutorialStep::PlacePowerPlant,
-        TutorialStep::PlaceWaterTower,
-        TutorialStep::ObserveGrowth,
-        TutorialStep::ManageBudget,
+        "welcome",
+        "place_road",
+        "zone_residential",
+        "zone_commercial",
+        "place_power_plant",
+        "place_water_tower",
+        "observe_growth",
+        "manage_budget",
     ];
 
-    for &step in &action_steps {
+    for step in action_steps {
         let mut state = TutorialState {
             active: true,
-            completed: false,
-            current_step: step,
-            paused_by_tutorial: false,
+            ..Default::default()
         };
+        assert!(state.go_to(step));
 
         state.skip();
 
         assert_eq!(
-            state.current_step,
-            TutorialStep::Completed,
-            "Skip from {step:?} should jump to Completed"
+            state.step_id(),
+            "completed",
+            "Skip from {step} should jump to Completed"
         );
         assert!(
             state.completed,
-            "Skip from {step:?} should mark completed"
+            "Skip from {step} should mark completed"
         );
         assert!(
             !state.active,
-            "Skip from {step:?} should deactivate tutorial"
+            "Skip from {step} should deactivate tutorial"
         );
     }
 }
@@ -270,15 +263,15 @@ fn test_tutorial_skip_at_every_step() {
 #[test]
 fn test_tutorial_pauses_during_instruction_steps() {
     let pause_steps = [
-        TutorialStep::Welcome,
-        TutorialStep::PlaceRoad,
-        TutorialStep::ZoneResidential,
-        TutorialStep::ZoneCommercial,
-        TutorialStep::PlacePowerPlant,
-        TutorialStep::PlaceWaterTower,
+        "welcome",
+        "place_road",
+        "zone_residential",
+        "zone_commercial",
+        "place_power_plant",
+        "place_water_tower",
     ];
 
-    for &step in &pause_steps {
+    for step in pause_steps {
         let mut city = TestCity::new();
         activate_tutorial_at(&mut city, step);
 
@@ -293,13 +286,13 @@ fn test_tutorial_pauses_during_instruction_steps() {
         let clock = city.resource::<GameClock>();
         assert!(
             clock.paused,
-            "GameClock should be paused during instruction step {step:?}"
+            "GameClock should be paused during instruction step {step}"
         );
 
         let tutorial = city.resource::<TutorialState>();
         assert!(
             tutorial.paused_by_tutorial,
-            "paused_by_tutorial should be set during {step:?}"
+            "paused_by_tutorial should be set during {step}"
         );
     }
 }
@@ -311,7 +304,7 @@ fn test_tutorial_pauses_during_instruction_steps() {
 #[test]
 fn test_tutorial_unpauses_on_skip() {
     let mut city = TestCity::new();
-    activate_tutorial_at(&mut city, TutorialStep::PlaceRoad);
+    activate_tutorial_at(&mut city, "place_road");
 
     // Run update to trigger the pause
     run_update(&mut city);
@@ -358,3 +351,29 @@ fn test_tutorial_unpauses_on_skip() {
     assert!(tutorial.completed);
     assert!(!tutorial.active);
 }
+
+// ---------------------------------------------------------------------------
+// 11. ManageBudget advances when the budget window is opened
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_tutorial_manage_budget_waits_for_budget_signal() {
+    let mut city = TestCity::new();
+    activate_tutorial_at(&mut city, "manage_budget");
+
+    city.world_mut()
+        .send_event(TutorialSignal("charts_opened".to_string()));
+    run_update(&mut city);
+    assert_eq!(
+        city.resource::<TutorialState>().step_id(),
+        "manage_budget",
+        "ManageBudget should ignore other windows"
+    );
+
+    city.world_mut()
+        .send_event(TutorialSignal(SIGNAL_BUDGET_OPENED.to_string()));
+    run_update(&mut city);
+    let tutorial = city.resource::<TutorialState>();
+    assert_eq!(tutorial.step_id(), "completed");
+    assert!(tutorial.completed);
+}
@@ -1,28 +1,27 @@
 //! PLAY-P0-02: Tutorial step threshold sensitivity tests (issue #1745).
 //!
 //! Verifies that tutorial steps require minimum thresholds before advancing,
-//! preventing players from accidentally skipping through the tutorial.
+//! preventing players from accidentally skipping through the tutorial. The
+//! thresholds are the ones set in the basics tutorial file.
 
 use bevy::prelude::*;
 
 use crate::grid::{RoadType, ZoneType};
 use crate::stats::CityStats;
 use crate::test_harness::TestCity;
-use crate::tutorial::{
-    TutorialState, TutorialStep, MIN_COMMERCIAL_ZONES, MIN_RESIDENTIAL_ZONES, MIN_ROAD_CELLS,
-};
+use crate::tutorial::TutorialState;
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
 /// Activate the tutorial at a specific step on a fresh TestCity.
-fn activate_tutorial_at(city: &mut TestCity, step: TutorialStep) {
+fn activate_tutorial_at(city: &mut TestCity, step: &str) {
     let world = city.world_mut();
     let mut tutorial = world.resource_mut::<TutorialState>();
     tutorial.active = true;
     tutorial.completed = false;
-    tutorial.current_step = step;
+    assert!(tutorial.go_to(step), "no tutorial step '{step}'");
     tutorial.paused_by_tutorial = false;
 }
 
@@ -41,14 +40,14 @@ fn test_tutorial_place_road_single_cell_does_not_advance() {
     let mut city = TestCity::new()
         .with_road(10, 10, 10, 10, RoadType::Local);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlaceRoad);
+    activate_tutorial_at(&mut city, "place_road");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::PlaceRoad,
-        "PlaceRoad should NOT advance with fewer than {MIN_ROAD_CELLS} road cells"
+        tutorial.step_id(),
+        "place_road",
+        "PlaceRoad should NOT advance with fewer than 5 road cells"
     );
 }
 
@@ -58,32 +57,32 @@ fn test_tutorial_place_road_below_threshold_does_not_advance() {
     let mut city = TestCity::new()
         .with_road(10, 10, 10, 12, RoadType::Local);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlaceRoad);
+    activate_tutorial_at(&mut city, "place_road");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::PlaceRoad,
-        "PlaceRoad should NOT advance with fewer than {MIN_ROAD_CELLS} road cells"
+        tutorial.step_id(),
+        "place_road",
+        "PlaceRoad should NOT advance with fewer than 5 road cells"
     );
 }
 
 #[test]
 fn test_tutorial_place_road_at_threshold_advances() {
-    // Place a road segment that produces at least MIN_ROAD_CELLS cells.
+    // Place a road segment that produces at least 5 cells.
     // Road from (10,10) to (10,15) = 6 cells >= 5.
     let mut city = TestCity::new()
         .with_road(10, 10, 10, 15, RoadType::Local);
 
-    activate_tutorial_at(&mut city, TutorialStep::PlaceRoad);
+    activate_tutorial_at(&mut city, "place_road");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneResidential,
-        "PlaceRoad should advance when >= {MIN_ROAD_CELLS} road cells are placed"
+        tutorial.step_id(),
+        "zone_residential",
+        "PlaceRoad should advance when >= 5 road cells are placed"
     );
 }
 
@@ -96,14 +95,14 @@ fn test_tutorial_zone_residential_single_cell_does_not_advance() {
     let mut city = TestCity::new()
         .with_zone(12, 10, ZoneType::ResidentialLow);
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneResidential);
+    activate_tutorial_at(&mut city, "zone_residential");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneResidential,
-        "ZoneResidential should NOT advance with 1 cell (need {MIN_RESIDENTIAL_ZONES})"
+        tutorial.step_id(),
+        "zone_residential",
+        "ZoneResidential should NOT advance with 1 cell (need 10)"
     );
 }
 
@@ -113,14 +112,14 @@ fn test_tutorial_zone_residential_below_threshold_does_not_advance() {
     let mut city = TestCity::new()
         .with_zone_rect(12, 10, 14, 12, ZoneType::ResidentialLow); // 3x3 = 9 cells
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneResidential);
+    activate_tutorial_at(&mut city, "zone_residential");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneResidential,
-        "ZoneResidential should NOT advance with 9 cells (need {MIN_RESIDENTIAL_ZONES})"
+        tutorial.step_id(),
+        "zone_residential",
+        "ZoneResidential should NOT advance with 9 cells (need 10)"
     );
 }
 
@@ -130,14 +129,14 @@ fn test_tutorial_zone_residential_at_threshold_advances() {
     let mut city = TestCity::new()
         .with_zone_rect(12, 10, 13, 14, ZoneType::ResidentialLow);
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneResidential);
+    activate_tutorial_at(&mut city, "zone_residential");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneCommercial,
-        "ZoneResidential should advance when >= {MIN_RESIDENTIAL_ZONES} cells are zoned"
+        tutorial.step_id(),
+        "zone_commercial",
+        "ZoneResidential should advance when >= 10 cells are zoned"
     );
 }
 
@@ -150,14 +149,14 @@ fn test_tutorial_zone_commercial_single_cell_does_not_advance() {
     let mut city = TestCity::new()
         .with_zone(14, 10, ZoneType::CommercialLow);
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneCommercial);
+    activate_tutorial_at(&mut city, "zone_commercial");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneCommercial,
-        "ZoneCommercial should NOT advance with 1 cell (need {MIN_COMMERCIAL_ZONES})"
+        tutorial.step_id(),
+        "zone_commercial",
+        "ZoneCommercial should NOT advance with 1 cell (need 5)"
     );
 }
 
@@ -167,14 +166,14 @@ fn test_tutorial_zone_commercial_below_threshold_does_not_advance() {
     let mut city = TestCity::new()
         .with_zone_rect(14, 10, 17, 10, ZoneType::CommercialLow); // 4x1 = 4 cells
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneCommercial);
+    activate_tutorial_at(&mut city, "zone_commercial");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ZoneCommercial,
-        "ZoneCommercial should NOT advance with 4 cells (need {MIN_COMMERCIAL_ZONES})"
+        tutorial.step_id(),
+        "zone_commercial",
+        "ZoneCommercial should NOT advance with 4 cells (need 5)"
     );
 }
 
@@ -184,14 +183,14 @@ fn test_tutorial_zone_commercial_at_threshold_advances() {
     let mut city = TestCity::new()
         .with_zone_rect(14, 10, 18, 10, ZoneType::CommercialLow);
 
-    activate_tutorial_at(&mut city, TutorialStep::ZoneCommercial);
+    activate_tutorial_at(&mut city, "zone_commercial");
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::PlacePowerPlant,
-        "ZoneCommercial should advance when >= {MIN_COMMERCIAL_ZONES} cells are zoned"
+        tutorial.step_id(),
+        "place_power_plant",
+        "ZoneCommercial should advance when >= 5 cells are zoned"
     );
 }
 
@@ -203,7 +202,7 @@ fn test_tutorial_zone_commercial_at_threshold_advances() {
 fn test_tutorial_observe_growth_population_alone_does_not_advance() {
     // Population >= 5 but NO buildings should NOT advance.
     let mut city = TestCity::new();
-    activate_tutorial_at(&mut city, TutorialStep::ObserveGrowth);
+    activate_tutorial_at(&mut city, "observe_growth");
 
     {
         let mut stats = city.world_mut().resource_mut::<CityStats>();
@@ -214,8 +213,8 @@ fn test_tutorial_observe_growth_population_alone_does_not_advance() {
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ObserveGrowth,
+        tutorial.step_id(),
+        "observe_growth",
         "ObserveGrowth should NOT advance with population alone (needs a building too)"
     );
 }
@@ -225,14 +224,14 @@ fn test_tutorial_observe_growth_building_alone_does_not_advance() {
     // A building exists but population is 0: should NOT advance.
     let mut city = TestCity::new()
         .with_building(12, 10, ZoneType::ResidentialLow, 1);
-    activate_tutorial_at(&mut city, TutorialStep::ObserveGrowth);
+    activate_tutorial_at(&mut city, "observe_growth");
 
     run_update(&mut city);
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ObserveGrowth,
+        tutorial.step_id(),
+        "observe_growth",
         "ObserveGrowth should NOT advance with a building but no population"
     );
 }
@@ -242,7 +241,7 @@ fn test_tutorial_observe_growth_building_and_population_advances() {
     // Both a building AND population >= 5: should advance.
     let mut city = TestCity::new()
         .with_building(12, 10, ZoneType::ResidentialLow, 1);
-    activate_tutorial_at(&mut city, TutorialStep::ObserveGrowth);
+    activate_tutorial_at(&mut city, "observe_growth");
 
     {
         let mut stats = city.world_mut().resource_mut::<CityStats>();
@@ -253,8 +252,8 @@ fn test_tutorial_observe_growth_building_and_population_advances() {
 
     let tutorial = city.resource::<TutorialState>();
     assert_eq!(
-        tutorial.current_step,
-        TutorialStep::ManageBudget,
+        tutorial.step_id(),
+        "manage_budget",
         "ObserveGrowth should advance when both building exists and population >= 5"
     );
 }
@@ -4,14 +4,14 @@
 //! resource initialization or save/load round-trips.
 
 use crate::test_harness::TestCity;
-use crate::tutorial::{TutorialState, TutorialStep};
+use crate::tutorial::TutorialState;
 
 #[test]
 fn test_tutorial_default_is_inactive() {
     let state = TutorialState::default();
     assert!(!state.active, "Default TutorialState must be inactive");
     assert!(!state.completed);
-    assert_eq!(state.current_step, TutorialStep::Welcome);
+    assert_eq!(state.step_id(), "welcome");
 }
 
 #[test]
@@ -21,7 +21,7 @@ fn test_tutorial_skip_works() {
     state.skip();
     assert!(!state.active);
     assert!(state.completed);
-    assert_eq!(state.current_step, TutorialStep::Completed);
+    assert_eq!(state.step_id(), "completed");
 }
 
 #[test]
@@ -29,7 +29,7 @@ fn test_tutorial_advance_from_active() {
     let mut state = TutorialState::default();
     state.active = true; // simulate new-game activation
     assert!(state.advance());
-    assert_eq!(state.current_step, TutorialStep::PlaceRoad);
+    assert_eq!(state.step_id(), "place_road");
     assert!(state.active);
     assert!(!state.completed);
 }
@@ -45,7 +45,7 @@ fn test_tutorial_saveable_roundtrip_preserves_state() {
 
     let bytes = state.save_to_bytes().expect("in-progress should save");
     let restored = TutorialState::load_from_bytes(&bytes);
-    assert_eq!(restored.current_step, TutorialStep::ZoneResidential);
+    assert_eq!(restored.step_id(), "zone_residential");
     assert!(restored.active);
     assert!(!restored.completed);
 }
@@ -1,41 +1,44 @@
 //! Unit and integration tests for tutorial UX polish (issue #1702).
 //!
-//! Covers: TutorialStep::previous(), TutorialState::go_back(),
-//! TutorialUiHint targets, and step metadata.
+//! Covers: TutorialState::go_back(), TutorialUiHint targets, and step
+//! metadata.
 
-use crate::tutorial::{TutorialState, TutorialStep};
+use crate::tutorial::TutorialState;
 use crate::tutorial_hints::TutorialUiHint;
 use crate::Saveable;
 
-// ---- TutorialStep navigation ----
+// ---- Step navigation ----
 
 #[test]
-fn test_tutorial_step_previous_from_welcome() {
-    assert_eq!(TutorialStep::Welcome.previous(), None);
-}
-
-#[test]
-fn test_tutorial_step_previous_from_place_road() {
-    assert_eq!(
-        TutorialStep::PlaceRoad.previous(),
-        Some(TutorialStep::Welcome)
-    );
-}
-
-#[test]
-fn test_tutorial_step_previous_from_completed() {
-    assert_eq!(
-        TutorialStep::Completed.previous(),
-        Some(TutorialStep::ManageBudget)
-    );
+fn test_tutorial_go_back_from_welcome() {
+    let mut state = TutorialState::default();
+    assert!(!state.go_back());
+    assert_eq!(state.step_id(), "welcome");
 }
 
 #[test]
-fn test_tutorial_step_previous_round_trip() {
-    // For every step except Welcome, prev -> next should return the same step.
-    for &step in TutorialStep::ALL.iter().skip(1) {
-        let prev = step.previous().expect("non-Welcome should have previous");
-        assert_eq!(prev.next(), Some(step));
+fn test_tutorial_go_back_from_completed_step() {
+    let mut state = TutorialState::default();
+    assert!(state.go_to("completed"));
+    assert!(state.go_back());
+    assert_eq!(state.step_id(), "manage_budget");
+}
+
+#[test]
+fn test_tutorial_go_back_round_trip() {
+    // For every step except Welcome, back -> next should return the same step.
+    let ids: Vec<String> = TutorialState::default()
+        .tutorial
+        .steps
+        .iter()
+        .map(|s| s.id.clone())
+        .collect();
+    for id in ids.iter().skip(1) {
+        let mut state = TutorialState::default();
+        assert!(state.go_to(id));
+        assert!(state.go_back(), "{id} should have a previous step");
+        assert!(state.advance());
+        assert_eq!(state.step_id(), id);
     }
 }
 
@@ -47,16 +50,16 @@ fn test_tutorial_go_back_from_zone_residential() {
     state.active = true;
     state.advance(); // Welcome -> PlaceRoad
     state.advance(); // PlaceRoad -> ZoneResidential
-    assert_eq!(state.current_step, TutorialStep::ZoneResidential);
+    assert_eq!(state.step_id(), "zone_residential");
 
     assert!(state.go_back());
-    assert_eq!(state.current_step, TutorialStep::PlaceRoad);
+    assert_eq!(state.step_id(), "place_road");
 
     assert!(state.go_back());
-    assert_eq!(state.current_step, TutorialStep::Welcome);
+    assert_eq!(state.step_id(), "welcome");
 
     assert!(!state.go_back()); // Cannot go before Welcome
-    assert_eq!(state.current_step, TutorialStep::Welcome);
+    assert_eq!(state.step_id(), "welcome");
 }
 
 #[test]
@@ -70,28 +73,30 @@ fn test_tutorial_go_back_when_completed() {
 
 #[test]
 fn test_all_steps_have_nonempty_metadata() {
-    for &step in TutorialStep::ALL {
-        assert!(!step.title().is_empty(), "{:?} has empty title", step);
+    for step in &TutorialState::default().tutorial.steps {
+        assert!(!step.title.is_empty(), "{} has empty title", step.id);
         assert!(
-            !step.description().is_empty(),
-            "{:?} has empty description",
-            step
+            !step.description.is_empty(),
+            "{} has empty description",
+            step.id
         );
-        assert!(!step.hint().is_empty(), "{:?} has empty hint", step);
+        assert!(!step.hint.is_empty(), "{} has empty hint", step.id);
     }
 }
 
 #[test]
 fn test_step_count_consistency() {
-    assert_eq!(TutorialStep::ALL.len(), 9);
-    assert_eq!(TutorialStep::total_steps(), 8);
+    let state = TutorialState::default();
+    assert_eq!(state.tutorial.steps.len(), 9);
+    assert_eq!(state.total_steps(), 8);
 }
 
 #[test]
 fn test_step_indices() {
-    assert_eq!(TutorialStep::Welcome.index(), 0);
-    assert_eq!(TutorialStep::PlaceRoad.index(), 1);
-    assert_eq!(TutorialStep::Completed.index(), 8);
+    let tutorial = TutorialState::default().tutorial;
+    assert_eq!(tutorial.step_index("welcome"), Some(0));
+    assert_eq!(tutorial.step_index("place_road"), Some(1));
+    assert_eq!(tutorial.step_index("completed"), Some(8));
 }
 
 // ---- Full progression ----
@@ -100,10 +105,10 @@ fn test_step_indices() {
 fn test_full_forward_progression() {
     let mut state = TutorialState::default();
     for i in 0..8 {
-        assert_eq!(state.current_step, TutorialStep::ALL[i]);
+        assert_eq!(state.current_step, i);
         assert!(state.advance());
     }
-    assert_eq!(state.current_step, TutorialStep::Completed);
+    assert_eq!(state.step_id(), "completed");
     assert!(state.completed);
     assert!(!state.advance());
 }
@@ -117,7 +122,7 @@ fn test_saveable_roundtrip_in_progress() {
     state.advance();
     let bytes = state.save_to_bytes().expect("should save");
     let restored = TutorialState::load_from_bytes(&bytes);
-    assert_eq!(restored.current_step, TutorialStep::ZoneResidential);
+    assert_eq!(restored.step_id(), "zone_residential");
 }
 
 #[test]
@@ -142,7 +147,7 @@ fn test_hint_highlight_roads() {
     let hint = TutorialUiHint::default();
     // We test via the public fields after the system would run, but we can
     // at least verify the default is None.
-    assert!(hint.highlight_target.is_none());
+    assert!(hint.highlight.is_none());
     assert!(hint.camera_target.is_none());
 }
 
@@ -150,35 +155,27 @@ fn test_hint_highlight_roads() {
 
 #[test]
 fn test_manual_steps() {
-    let manual = [
-        TutorialStep::Welcome,
-        TutorialStep::ManageBudget,
-        TutorialStep::Completed,
-    ];
-    for step in manual {
-        let state = TutorialState {
-            current_step: step,
-            ..Default::default()
-        };
-        assert!(state.is_manual_step(), "{:?} should be manual", step);
+    for step in ["welcome", "completed"] {
+        let mut state = TutorialState::default();
+        assert!(state.go_to(step));
+        assert!(state.is_manual_step(), "{step} should be manual");
     }
 }
 
 #[test]
 fn test_non_manual_steps() {
     let auto = [
-        TutorialStep::PlaceRoad,
-        TutorialStep::ZoneResidential,
-        TutorialStep::ZoneCommercial,
-        TutorialStep::PlacePowerPlant,
-        TutorialStep::PlaceWaterTower,
-        TutorialStep::ObserveGrowth,
+        "place_road",
+        "zone_residential",
+        "zone_commercial",
+        "place_power_plant",
+        "place_water_tower",
+        "observe_growth",
+        "manage_budget",
     ];
     for step in auto {
-        let state = TutorialState {
-            current_step: step,
-            ..Default::default()
-        };
-        assert!(!state.is_manual_step(), "{:?} should not be manual", step);
+        let mut state = TutorialState::default();
+        assert!(state.go_to(step));
+        assert!(!state.is_manual_step(), "{step} should not be manual");
     }
 }
@@ -0,0 +1,58 @@
+//! Integration tests for the underground infrastructure layer.
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{RoadType, WorldGrid, ZoneType};
+use crate::metro_transit::MetroTransitState;
+use crate::test_harness::TestCity;
+use crate::underground::{
+    UndergroundKind, UndergroundLayer, BORED_TUNNEL_DEPTH_M, CUT_AND_COVER_DEPTH_M,
+};
+
+fn add_metro_line(city: &mut TestCity, from: (usize, usize), to: (usize, usize)) {
+    let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut metro = city.world_mut().resource_mut::<MetroTransitState>();
+    let a = metro
+        .add_station(from.0, from.1, "West".into(), &grid)
+        .unwrap();
+    let b = metro.add_station(to.0, to.1, "East".into(), &grid).unwrap();
+    metro.add_line("Red".into(), vec![a, b]);
+}
+
+#[test]
+fn test_layer_has_mains_under_roads_and_parking_under_towers() {
+    let mut city = TestCity::new()
+        .with_road(40, 40, 60, 40, RoadType::Local)
+        .with_building(50, 41, ZoneType::Office, 4)
+        .with_building(52, 41, ZoneType::ResidentialLow, 1);
+    city.tick_slow_cycle();
+
+    let layer = city.resource::<UndergroundLayer>();
+    assert!(layer.has(45, 40, UndergroundKind::WaterMain));
+    assert!(layer.has(45, 40, UndergroundKind::SewerMain));
+    assert!(!layer.has(45, 42, UndergroundKind::WaterMain));
+    assert!(layer.has(50, 41, UndergroundKind::Parking));
+    assert!(!layer.has(52, 41, UndergroundKind::Parking));
+    assert!(layer.foundation_depth(50, 41) > layer.foundation_depth(52, 41));
+}
+
+#[test]
+fn test_metro_bores_under_a_tower_and_caps_low_buildings() {
+    let mut city = TestCity::new()
+        .with_building(70, 50, ZoneType::Office, 5)
+        .with_building(70, 60, ZoneType::Office, 2);
+    add_metro_line(&mut city, (60, 50), (80, 50));
+    add_metro_line(&mut city, (60, 60), (80, 60));
+    city.tick_slow_cycle();
+
+    let layer = city.resource::<UndergroundLayer>();
+    assert_eq!(layer.tunnel_depth(70, 50), Some(BORED_TUNNEL_DEPTH_M));
+    assert!(layer
+        .level_cap_at(70, 50, ZoneType::Office, false)
+        .is_none());
+
+    assert_eq!(layer.tunnel_depth(70, 60), Some(CUT_AND_COVER_DEPTH_M));
+    let cap = layer
+        .level_cap_at(70, 60, ZoneType::Office, false)
+        .expect("the shallow tunnel limits the building above");
+    assert!(cap >= 2 && cap < ZoneType::Office.max_level());
+}
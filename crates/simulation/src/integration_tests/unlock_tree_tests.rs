@@ -0,0 +1,124 @@
+//! Integration tests for the branch points of the unlock tree.
+
+use crate::grid::RoadType;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::unlocks::{UnlockBranch, UnlockNode, UnlockState};
+use crate::utilities::UtilityType;
+
+/// An unlock state past every branch prerequisite with points to spend.
+fn developed_state() -> UnlockState {
+    let mut state = UnlockState {
+        development_points: 20,
+        ..Default::default()
+    };
+    for branch in UnlockBranch::ALL {
+        state.unlocked_nodes.push(branch.prerequisite());
+    }
+    state
+}
+
+#[test]
+fn test_every_branch_node_belongs_to_one_branch() {
+    for branch in UnlockBranch::ALL {
+        for node in branch.options() {
+            assert_eq!(node.branch(), Some(branch));
+            assert!(!node.description().is_empty());
+            assert_eq!(
+                node.required_population(),
+                branch.prerequisite().required_population()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_branch_needs_prerequisite_and_population() {
+    let mut state = UnlockState {
+        development_points: 20,
+        ..Default::default()
+    };
+    assert!(!state.can_purchase(UnlockNode::TransitFirst, 100_000));
+
+    state.unlocked_nodes.push(UnlockNode::PublicTransport);
+    assert!(!state.can_purchase(UnlockNode::TransitFirst, 4_999));
+    assert!(state.can_purchase(UnlockNode::TransitFirst, 5_000));
+}
+
+#[test]
+fn test_choosing_a_branch_closes_the_alternative() {
+    let mut state = developed_state();
+    assert!(state.purchase(UnlockNode::TransitFirst));
+    assert_eq!(
+        state.chosen(UnlockBranch::Mobility),
+        Some(UnlockNode::TransitFirst)
+    );
+    assert!(state.is_foreclosed(UnlockNode::CarCentric));
+    assert!(!state.can_purchase(UnlockNode::CarCentric, 100_000));
+    assert!(!state.purchase(UnlockNode::CarCentric));
+    assert_eq!(state.spent_points, UnlockNode::TransitFirst.cost());
+
+    // Other branches are unaffected.
+    assert!(state.can_purchase(UnlockNode::DenseCore, 100_000));
+}
+
+#[test]
+fn test_branch_grants_trunk_content_early() {
+    let mut state = developed_state();
+    assert!(!state.is_service_unlocked(ServiceType::SubwayStation));
+    state.purchase(UnlockNode::TransitFirst);
+    assert!(state.is_service_unlocked(ServiceType::SubwayStation));
+    assert!(state.is_service_unlocked(ServiceType::TramDepot));
+    // Granted, not unlocked: the milestone still unlocks it later.
+    assert!(state.has_access(UnlockNode::AdvancedTransport));
+    assert!(!state.is_unlocked(UnlockNode::AdvancedTransport));
+
+    state.purchase(UnlockNode::GardenSuburbs);
+    assert!(state.is_service_unlocked(ServiceType::LargePark));
+    assert!(!state.has_access(UnlockNode::HighDensityResidential));
+}
+
+#[test]
+fn test_renewable_mandate_bans_fossil_plants() {
+    let mut state = developed_state();
+    assert!(state.is_utility_unlocked(UtilityType::GasPlant));
+    assert!(!state.is_utility_unlocked(UtilityType::SolarFarm));
+
+    state.purchase(UnlockNode::RenewableMandate);
+    assert!(state.is_utility_unlocked(UtilityType::SolarFarm));
+    assert!(state.is_utility_unlocked(UtilityType::WindTurbine));
+    for fossil in [
+        UtilityType::PowerPlant,
+        UtilityType::OilPlant,
+        UtilityType::GasPlant,
+    ] {
+        assert!(!state.is_utility_unlocked(fossil));
+        assert_eq!(state.banned_by(fossil), Some(UnlockNode::RenewableMandate));
+    }
+    assert!(!state.is_utility_unlocked(UtilityType::NuclearPlant));
+}
+
+#[test]
+fn test_car_centric_cuts_road_maintenance() {
+    let run = |car_centric: bool| {
+        let mut city =
+            TestCity::new()
+                .with_budget(100_000.0)
+                .with_road(50, 50, 80, 50, RoadType::Avenue);
+        if car_centric {
+            let mut state = developed_state();
+            state.purchase(UnlockNode::CarCentric);
+            city.world_mut().insert_resource(state);
+        }
+        city.world_mut().resource_mut::<GameClock>().day = 32;
+        city.tick(10);
+        city.resource::<crate::budget::ExtendedBudget>()
+            .expense_breakdown
+            .road_maintenance
+    };
+    let normal = run(false);
+    let reduced = run(true);
+    assert!(normal > 0.0);
+    assert!((reduced - normal * 0.7).abs() < 1e-6);
+}
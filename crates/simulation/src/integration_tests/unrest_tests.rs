@@ -0,0 +1,110 @@
+//! Integration tests for district tension, riots and their resolution.
+
+use crate::economy::CityBudget;
+use crate::grid::ZoneType;
+use crate::services::ServiceType;
+use crate::structural_integrity::{StructuralState, MAX_CONDITION};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::unrest::{
+    district_index, Resolution, UnrestResolutionRequest, UnrestState, RIOT_BURNOUT_DAYS,
+    RIOT_TENSION,
+};
+
+const HOME: (usize, usize) = (100, 100);
+
+fn district() -> usize {
+    district_index(HOME.0, HOME.1)
+}
+
+/// A city with a riot already under way around `HOME`.
+fn rioting_city(city: TestCity) -> TestCity {
+    let mut city =
+        city.with_budget(100_000.0)
+            .with_building(HOME.0, HOME.1, ZoneType::ResidentialLow, 1);
+    let day = city.resource::<GameClock>().day;
+    city.world_mut()
+        .resource_mut::<UnrestState>()
+        .start(district(), 100, day);
+    city
+}
+
+fn resolve(city: &mut TestCity, resolution: Resolution) {
+    let district = district();
+    city.world_mut().send_event(UnrestResolutionRequest {
+        district,
+        resolution,
+    });
+    city.world_mut().run_schedule(bevy::prelude::Update);
+}
+
+#[test]
+fn test_unemployment_builds_tension() {
+    let mut city = TestCity::new().with_building(HOME.0, HOME.1, ZoneType::ResidentialLow, 1);
+    for _ in 0..10 {
+        city = city.with_unemployed_citizen(HOME);
+    }
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<UnrestState>();
+    assert!(state.tension[district()] > 0.0);
+    assert!(state.tension[district()] < RIOT_TENSION);
+    assert!(state.riots.is_empty(), "too few residents to riot");
+}
+
+#[test]
+fn test_riot_damages_property_and_bills_police_overtime() {
+    let mut city =
+        rioting_city(TestCity::new().with_service(HOME.0 + 3, HOME.1, ServiceType::PoliceStation));
+    city.tick_slow_cycles(3);
+
+    let state = city.resource::<UnrestState>();
+    assert_eq!(state.riots.len(), 1);
+    assert!(state.riots[0].overtime_cost > 0.0);
+    assert!(city.resource::<StructuralState>().condition(HOME.0, HOME.1) < MAX_CONDITION);
+}
+
+#[test]
+fn test_concessions_end_riot_and_cost_money() {
+    let mut city = rioting_city(TestCity::new());
+    let before = city.resource::<CityBudget>().treasury;
+    resolve(&mut city, Resolution::Concessions);
+
+    let state = city.resource::<UnrestState>();
+    assert!(state.riots.is_empty());
+    assert!(state.happiness_modifier(district()) > 0.0);
+    assert!(city.resource::<CityBudget>().treasury < before);
+}
+
+#[test]
+fn test_crackdown_leaves_lasting_resentment() {
+    let mut city = rioting_city(TestCity::new());
+    resolve(&mut city, Resolution::Crackdown);
+
+    let state = city.resource::<UnrestState>();
+    assert!(state.riots.is_empty());
+    assert!(state.happiness_modifier(district()) < 0.0);
+    assert!(state.tension_rate(district()) > 1.0);
+}
+
+#[test]
+fn test_unaffordable_concessions_are_refused() {
+    let mut city = rioting_city(TestCity::new()).with_budget(10.0);
+    resolve(&mut city, Resolution::Concessions);
+    assert_eq!(city.resource::<UnrestState>().riots.len(), 1);
+}
+
+#[test]
+fn test_unresolved_riot_burns_out() {
+    let mut city = rioting_city(TestCity::new());
+    city.world_mut().resource_mut::<GameClock>().day += RIOT_BURNOUT_DAYS;
+    city.tick_slow_cycle();
+
+    let state = city.resource::<UnrestState>();
+    assert!(state.riots.is_empty());
+    assert!(
+        state.effects.is_empty(),
+        "burning out earns no approval effect"
+    );
+    assert_eq!(state.total_riots, 1);
+}
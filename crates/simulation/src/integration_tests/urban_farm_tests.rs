@@ -0,0 +1,70 @@
+//! Integration tests for rooftop and vertical farms.
+
+use crate::agriculture::{UrbanFarmKind, UrbanFarms};
+use crate::energy_demand::EnergyGrid;
+use crate::grid::{RoadType, ZoneType};
+use crate::natural_resources::ResourceBalance;
+use crate::test_harness::TestCity;
+use crate::urban_heat_island::UhiGrid;
+use crate::utilities::UtilityType;
+
+fn serviced_tower_city() -> TestCity {
+    TestCity::new()
+        .with_road(80, 80, 90, 80, RoadType::Local)
+        .with_utility(80, 80, UtilityType::PowerPlant)
+        .with_utility(80, 81, UtilityType::WaterTower)
+        .with_building(85, 79, ZoneType::ResidentialHigh, 3)
+}
+
+fn build_farm(city: &mut TestCity, x: usize, y: usize, kind: UrbanFarmKind) {
+    city.world_mut()
+        .resource_mut::<UrbanFarms>()
+        .build(x, y, kind);
+}
+
+#[test]
+fn test_serviced_vertical_farm_grows_food_and_draws_power() {
+    let mut city = serviced_tower_city();
+    build_farm(&mut city, 85, 79, UrbanFarmKind::Vertical);
+    city.tick_slow_cycle();
+    city.tick(4);
+
+    let farms = city.resource::<UrbanFarms>();
+    assert_eq!(farms.active_count, 1);
+    assert!(farms.food_output > 0.0);
+    assert!(farms.water_demand_gpd > 0.0);
+    assert!(
+        city.resource::<ResourceBalance>().food_production >= farms.food_output,
+        "the harvest should reach the food balance"
+    );
+    assert!(city.resource::<EnergyGrid>().total_demand_mwh >= farms.power_demand_mw);
+}
+
+#[test]
+fn test_farm_without_a_dense_building_is_removed() {
+    let mut city = serviced_tower_city();
+    build_farm(&mut city, 85, 79, UrbanFarmKind::Rooftop);
+    build_farm(&mut city, 60, 60, UrbanFarmKind::Rooftop);
+    city.tick_slow_cycle();
+
+    let farms = city.resource::<UrbanFarms>();
+    assert_eq!(farms.farms.len(), 1);
+    assert!(farms.farm_at(85, 79).is_some());
+    assert_eq!(farms.farms_lost, 1);
+}
+
+#[test]
+fn test_rooftop_farm_cools_its_cell() {
+    let mut bare = serviced_tower_city();
+    bare.tick_slow_cycle();
+    let mut farmed = serviced_tower_city();
+    build_farm(&mut farmed, 85, 79, UrbanFarmKind::Rooftop);
+    farmed.tick_slow_cycle();
+
+    let bare_uhi = bare.resource::<UhiGrid>().get(85, 79);
+    let farmed_uhi = farmed.resource::<UhiGrid>().get(85, 79);
+    assert!(
+        farmed_uhi < bare_uhi,
+        "a rooftop farm should lower the heat island ({farmed_uhi} vs {bare_uhi})"
+    );
+}
@@ -0,0 +1,76 @@
+//! Integration tests for urban forestry: tree growth, upkeep and the canopy.
+
+use crate::config::GRID_WIDTH;
+use crate::land_value::LandValueGrid;
+use crate::test_harness::TestCity;
+use crate::time_of_day::{GameClock, DAYS_PER_YEAR};
+use crate::trees::TreeGrid;
+use crate::urban_forestry::{TreeSpecies, UrbanForestry, UrbanTree};
+
+fn city_with_tree(x: usize, y: usize, tree: UrbanTree) -> TestCity {
+    let mut city = TestCity::new();
+    let world = city.world_mut();
+    world.resource_mut::<TreeGrid>().set(x, y, true);
+    world.resource_mut::<UrbanForestry>().trees[y * GRID_WIDTH + x] = Some(tree);
+    city
+}
+
+fn next_day(city: &mut TestCity) {
+    city.world_mut().resource_mut::<GameClock>().day += 1;
+    city.tick(1);
+}
+
+#[test]
+fn test_new_trees_are_recorded_and_age_daily() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        world.resource_mut::<TreeGrid>().set(40, 40, true);
+        world.resource_mut::<UrbanForestry>().planting_species = TreeSpecies::Palm;
+    }
+    city.tick(1);
+    next_day(&mut city);
+
+    let forestry = city.resource::<UrbanForestry>();
+    let tree = forestry.get(40, 40).expect("the tree should be recorded");
+    assert_eq!(tree.species, TreeSpecies::Palm);
+    assert_eq!(tree.age_days, 2);
+    assert_eq!(forestry.tree_count, 1);
+    assert!(forestry.daily_cost > 0.0);
+}
+
+#[test]
+fn test_neglected_trees_die_and_are_cleared() {
+    let dying = UrbanTree {
+        age_days: 20 * DAYS_PER_YEAR,
+        health: 0.005,
+        ..UrbanTree::sapling(TreeSpecies::Plane)
+    };
+    let mut city = city_with_tree(40, 40, dying);
+    city.world_mut().resource_mut::<UrbanForestry>().funding = 0.0;
+    city.tick(1);
+
+    assert!(!city.resource::<TreeGrid>().has_tree(40, 40));
+    let forestry = city.resource::<UrbanForestry>();
+    assert!(forestry.get(40, 40).is_none());
+    assert_eq!(forestry.trees_lost, 1);
+}
+
+#[test]
+fn test_mature_trees_lift_land_value_more_than_saplings() {
+    let mature = UrbanTree {
+        age_days: 20 * DAYS_PER_YEAR,
+        ..UrbanTree::sapling(TreeSpecies::Plane)
+    };
+    let mut old_street = city_with_tree(40, 40, mature);
+    old_street.tick_slow_cycle();
+    let mut new_street = city_with_tree(40, 40, UrbanTree::sapling(TreeSpecies::Plane));
+    new_street.tick_slow_cycle();
+
+    let old_value = old_street.resource::<LandValueGrid>().get(41, 40);
+    let new_value = new_street.resource::<LandValueGrid>().get(41, 40);
+    assert!(
+        old_value > new_value,
+        "a mature tree should lift land value more ({old_value} vs {new_value})"
+    );
+}
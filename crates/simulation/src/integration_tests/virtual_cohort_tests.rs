@@ -0,0 +1,148 @@
+//! Integration tests for the statistical tier of virtual citizens: moving
+//! citizens between tiers as the cap changes, cohort employment and the
+//! commute load cohorts put on the road network.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::citizen::{Citizen, WorkLocation};
+use crate::grid::{RoadType, ZoneType};
+use crate::lod::LodTier;
+use crate::test_harness::TestCity;
+use crate::traffic::TrafficGrid;
+use crate::virtual_cohorts::{VirtualCohorts, REBALANCE_INTERVAL};
+use crate::virtual_population::{VirtualCitizen, VirtualPopulation};
+
+const HOME: (usize, usize) = (50, 50);
+const WORK: (usize, usize) = (100, 100);
+
+/// Five employed citizens, off-screen and at home, in buildings the
+/// spawner considers full.
+fn city_with_offscreen_workers() -> TestCity {
+    let mut city = TestCity::new()
+        .with_time(2.0)
+        .with_building(HOME.0, HOME.1, ZoneType::ResidentialHigh, 3)
+        .with_building(WORK.0, WORK.1, ZoneType::CommercialHigh, 3);
+    for _ in 0..5 {
+        city = city.with_citizen(HOME, WORK);
+    }
+    let world = city.world_mut();
+    let mut buildings = world.query::<&mut Building>();
+    for mut building in buildings.iter_mut(world) {
+        building.occupants = if building.zone_type.is_residential() {
+            building.capacity
+        } else {
+            5
+        };
+    }
+    let mut citizens = world.query_filtered::<Entity, With<Citizen>>();
+    let entities: Vec<Entity> = citizens.iter(world).collect();
+    for entity in entities {
+        world.entity_mut(entity).insert(LodTier::Abstract);
+    }
+    city
+}
+
+fn set_cap(city: &mut TestCity, cap: u32) {
+    city.world_mut()
+        .resource_mut::<VirtualPopulation>()
+        .max_real_citizens = cap;
+}
+
+#[test]
+fn test_lowering_the_cap_moves_citizens_into_cohorts() {
+    let mut city = city_with_offscreen_workers();
+    set_cap(&mut city, 2);
+    city.tick(REBALANCE_INTERVAL as u32);
+
+    assert_eq!(city.citizen_count(), 2);
+    let population = city.resource::<VirtualPopulation>();
+    assert_eq!(population.total_virtual, 3);
+    assert_eq!(population.virtual_employed, 3);
+    let home_district = VirtualPopulation::district_index(HOME.0, HOME.1);
+    let stats = &population.district_stats[home_district];
+    assert_eq!(stats.population, 3);
+    assert!(stats.tax_contribution > 0.0);
+
+    let cohort = city
+        .resource::<VirtualCohorts>()
+        .district(home_district)
+        .cloned()
+        .unwrap();
+    let work_district = VirtualPopulation::district_index(WORK.0, WORK.1);
+    assert_eq!(cohort.destinations, vec![(work_district as u32, 3)]);
+    assert!((cohort.payroll - 3.0 * 3500.0).abs() < 0.01);
+}
+
+#[test]
+fn test_raising_the_cap_promotes_cohorts_back_to_entities() {
+    let mut city = city_with_offscreen_workers();
+    set_cap(&mut city, 2);
+    city.tick(REBALANCE_INTERVAL as u32);
+    set_cap(&mut city, 10_000);
+    city.tick(REBALANCE_INTERVAL as u32);
+
+    assert_eq!(city.citizen_count(), 5);
+    let population = city.resource::<VirtualPopulation>();
+    assert_eq!(population.total_virtual, 0);
+    assert_eq!(population.virtual_employed, 0);
+    assert_eq!(city.resource::<VirtualCohorts>().total_workers(), 0);
+
+    let world = city.world_mut();
+    let mut workers = world.query_filtered::<&WorkLocation, With<Citizen>>();
+    let jobs: Vec<(usize, usize)> = workers.iter(world).map(|w| (w.grid_x, w.grid_y)).collect();
+    assert_eq!(jobs, vec![WORK; 5], "promoted citizens keep their jobs");
+}
+
+#[test]
+fn test_cohort_employment_follows_real_residents() {
+    let mut city = city_with_offscreen_workers();
+    // Keep the cohort virtual for the whole cycle.
+    set_cap(&mut city, 5);
+    let home_district = VirtualPopulation::district_index(HOME.0, HOME.1);
+    {
+        let world = city.world_mut();
+        world.resource_scope(|world, mut cohorts: Mut<VirtualCohorts>| {
+            let mut population = world.resource_mut::<VirtualPopulation>();
+            for _ in 0..40 {
+                let citizen = VirtualCitizen {
+                    age: 30,
+                    employed: false,
+                    happiness: 50.0,
+                };
+                cohorts.absorb(&mut population, home_district, citizen, None, 0.1);
+            }
+        });
+    }
+
+    city.tick_slow_cycle();
+
+    // Every real resident works, so the cohort closes a quarter of its gap.
+    let population = city.resource::<VirtualPopulation>();
+    assert_eq!(population.virtual_employed, 10);
+    assert_eq!(population.district_stats[home_district].employed, 10);
+    assert_eq!(city.resource::<VirtualCohorts>().total_workers(), 10);
+}
+
+#[test]
+fn test_cohort_commuters_load_roads_during_rush_hour() {
+    let mut city = TestCity::new()
+        .with_time(7.0)
+        .with_road(40, 33, 40, 46, RoadType::Local);
+    let district = VirtualPopulation::district_index(40, 40);
+    {
+        let mut cohorts = city.world_mut().resource_mut::<VirtualCohorts>();
+        for _ in 0..200 {
+            cohorts.district_mut(district).add_worker(district, 2000.0);
+        }
+    }
+
+    city.tick(5);
+
+    let traffic = city.resource::<TrafficGrid>();
+    let load: u32 = (32..48)
+        .flat_map(|y| (32..48).map(move |x| (x, y)))
+        .map(|(x, y)| traffic.get(x, y) as u32)
+        .sum();
+    assert_eq!(load, 50, "a quarter of the cohort is on the road");
+}
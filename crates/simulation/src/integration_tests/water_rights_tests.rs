@@ -0,0 +1,145 @@
+//! Integration tests for regional water agreements.
+
+use bevy::prelude::*;
+
+use crate::drought::DroughtState;
+use crate::economy::CityBudget;
+use crate::outside_connections::{ConnectionType, OutsideConnection, OutsideConnections};
+use crate::test_harness::TestCity;
+use crate::time_of_day::GameClock;
+use crate::water_demand::WaterSupply;
+use crate::water_rights::*;
+
+fn act(city: &mut TestCity, action: WaterRightsAction) {
+    city.world_mut().send_event(action);
+    city.world_mut().run_schedule(Update);
+}
+
+fn treasury(city: &TestCity) -> f64 {
+    city.resource::<CityBudget>().treasury
+}
+
+/// Pin the drought index, as in the drought staging tests.
+fn set_rainfall(city: &mut TestCity, daily_mm: f32) {
+    let mut drought = city.world_mut().resource_mut::<DroughtState>();
+    drought.rainfall_history = vec![daily_mm; 30];
+    drought.last_record_day = u32::MAX;
+}
+
+/// A city with a highway leaving the north edge and a pipeline to Northvale.
+fn city_with_north_pipeline() -> TestCity {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    city.world_mut()
+        .resource_mut::<OutsideConnections>()
+        .connections
+        .push(OutsideConnection {
+            connection_type: ConnectionType::Highway,
+            grid_x: 128,
+            grid_y: 0,
+            capacity: 1000,
+            utilization: 0.0,
+        });
+    act(&mut city, WaterRightsAction::BuildPipeline(MapEdge::North));
+    city
+}
+
+fn sign(city: &mut TestCity, region: MapEdge, direction: TradeDirection, volume_mgd: f32) {
+    act(
+        city,
+        WaterRightsAction::Sign {
+            region,
+            direction,
+            volume_mgd,
+            clause: CurtailmentClause::Standard,
+        },
+    );
+}
+
+#[test]
+fn test_pipeline_needs_right_of_way() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    act(&mut city, WaterRightsAction::BuildPipeline(MapEdge::North));
+    assert!(!city.resource::<WaterRights>().has_pipeline(MapEdge::North));
+    assert_eq!(treasury(&city), 100_000.0);
+}
+
+#[test]
+fn test_pipeline_is_charged() {
+    let city = city_with_north_pipeline();
+    assert!(city.resource::<WaterRights>().has_pipeline(MapEdge::North));
+    assert!((100_000.0 - treasury(&city) - PIPELINE_COST).abs() < 1.0);
+}
+
+#[test]
+fn test_agreement_needs_a_route_and_spare_water() {
+    let mut city = TestCity::new().with_budget(100_000.0);
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 2.0);
+    assert!(city.resource::<WaterRights>().agreements.is_empty());
+
+    let mut city = city_with_north_pipeline();
+    let spare = region(MapEdge::North).spare_mgd;
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, spare + 1.0);
+    assert!(city.resource::<WaterRights>().agreements.is_empty());
+
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 2.0);
+    let rights = city.resource::<WaterRights>();
+    assert_eq!(rights.agreements.len(), 1);
+    assert_eq!(rights.imported_mgd, 2.0);
+}
+
+#[test]
+fn test_imports_and_exports_change_water_balance() {
+    let mut city = city_with_north_pipeline();
+    set_rainfall(&mut city, 5.0);
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 3.0);
+    // Eastport has no pipeline of its own; water is wheeled through Northvale.
+    sign(&mut city, MapEdge::East, TradeDirection::Sell, 1.0);
+    city.tick_slow_cycle();
+
+    let supply = city.resource::<WaterSupply>();
+    assert!(supply.total_supply_gpd >= 3.0 * MGD_TO_GPD - 1.0);
+    assert!(supply.total_demand_gpd >= 1.0 * MGD_TO_GPD - 1.0);
+    let rights = city.resource::<WaterRights>();
+    assert_eq!(rights.agreements[1].hops, 1);
+    assert!((rights.exported_mgd - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_drought_curtails_deliveries() {
+    let mut city = city_with_north_pipeline();
+    set_rainfall(&mut city, 0.0);
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 4.0);
+    city.tick_slow_cycle();
+
+    let rights = city.resource::<WaterRights>();
+    assert!(rights.agreements[0].curtailed);
+    assert!(rights.imported_mgd < 4.0);
+}
+
+#[test]
+fn test_daily_settlement_pays_for_water() {
+    let mut city = city_with_north_pipeline();
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 3.0);
+    let before = treasury(&city);
+    let daily = city.resource::<WaterRights>().monthly_net() / 30.0;
+    assert!(daily < 0.0);
+
+    let day = city.resource::<GameClock>().day;
+    city.world_mut().resource_mut::<GameClock>().day = day + 1;
+    city.tick(1);
+
+    let rights = city.resource::<WaterRights>();
+    assert!((rights.last_daily_net - daily).abs() < 1e-6);
+    assert!(rights.total_paid > 0.0);
+    assert!(treasury(&city) < before);
+}
+
+#[test]
+fn test_cancelling_stops_flows() {
+    let mut city = city_with_north_pipeline();
+    sign(&mut city, MapEdge::North, TradeDirection::Buy, 3.0);
+    act(&mut city, WaterRightsAction::Cancel(0));
+    let rights = city.resource::<WaterRights>();
+    assert!(rights.agreements.is_empty());
+    assert_eq!(rights.imported_mgd, 0.0);
+}
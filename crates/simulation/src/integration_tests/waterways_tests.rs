@@ -0,0 +1,48 @@
+//! Integration tests for player-made waterways.
+
+use crate::grid::{CellType, WorldGrid};
+use crate::test_harness::TestCity;
+use crate::water_sources::{WaterSource, WaterSourceType};
+use crate::waterways::{carve_waterway, WaterwayNetwork, Waterways};
+
+/// A city with a river along x = 120 and a canal carved west from it
+/// along row 100.
+fn city_with_canal() -> TestCity {
+    let mut city = TestCity::new();
+    {
+        let mut grid = city.world_mut().resource_mut::<WorldGrid>();
+        for y in 0..grid.height {
+            grid.get_mut(120, y).cell_type = CellType::Water;
+        }
+    }
+    let world = city.world_mut();
+    world.resource_scope(|world, mut grid: bevy::prelude::Mut<WorldGrid>| {
+        let mut waterways = world.resource_mut::<Waterways>();
+        for x in (100..=118).step_by(2) {
+            carve_waterway(&mut grid, &mut waterways, x, 100);
+        }
+    });
+    city
+}
+
+#[test]
+fn test_canal_joined_to_river_flows_towards_it() {
+    let mut city = city_with_canal();
+    city.tick_slow_cycle();
+    let network = city.resource::<WaterwayNetwork>();
+    let canal = network.body_at(110, 100).expect("carved canal");
+    assert!(canal.is_connected());
+    assert_eq!(network.flow_at(98, 100), Some((1, 0)));
+}
+
+#[test]
+fn test_reservoir_beside_canal_is_fed() {
+    let mut city = city_with_canal();
+    let mut reservoir = WaterSource::new(WaterSourceType::Reservoir, 104, 103);
+    reservoir.stored_gallons = 0.0;
+    city.world_mut().spawn(reservoir);
+    city.tick_slow_cycle();
+    let network = city.resource::<WaterwayNetwork>();
+    assert_eq!(network.reservoirs_fed, 1);
+    assert!(network.reservoir_inflow_gallons > 0.0);
+}
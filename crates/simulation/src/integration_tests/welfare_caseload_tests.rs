@@ -0,0 +1,74 @@
+use crate::grid::ZoneType;
+use crate::services::ServiceType;
+use crate::test_harness::TestCity;
+use crate::welfare::{WelfareStaffing, WelfareStats, MIN_STAFFING_LEVEL};
+
+fn city_with_applicants(count: usize) -> TestCity {
+    let mut city = TestCity::new()
+        .with_building(30, 30, ZoneType::ResidentialLow, 1)
+        .with_service(31, 31, ServiceType::WelfareOffice);
+    for _ in 0..count {
+        city = city.with_unemployed_citizen((30, 30));
+    }
+    city
+}
+
+#[test]
+fn test_welfare_caseload_baseline_processes_small_queue() {
+    let mut city = city_with_applicants(3);
+    city.tick_slow_cycle();
+    let stats = city.resource::<WelfareStats>();
+    assert!(stats.caseworkers > 0);
+    assert_eq!(stats.queue_length, 0, "small caseload should clear at once");
+    assert!(stats.total_welfare_recipients <= 3);
+}
+
+#[test]
+fn test_welfare_caseload_understaffed_office_builds_queue() {
+    let mut city = city_with_applicants(20);
+    city.world_mut().resource_mut::<WelfareStaffing>().level = MIN_STAFFING_LEVEL;
+    city.tick_slow_cycle();
+    let first = city.resource::<WelfareStats>().queue_length;
+    assert!(
+        first > 0,
+        "understaffed office should leave applicants queued"
+    );
+
+    city.tick_slow_cycle();
+    let stats = city.resource::<WelfareStats>();
+    assert!(stats.queue_length < first, "queue should drain over time");
+    assert!(
+        stats.avg_wait_ticks >= 1.0,
+        "remaining applicants have waited"
+    );
+}
+
+#[test]
+fn test_welfare_caseload_more_staff_shortens_queue() {
+    let mut low = city_with_applicants(20);
+    low.world_mut().resource_mut::<WelfareStaffing>().level = MIN_STAFFING_LEVEL;
+    low.tick_slow_cycle();
+
+    let mut high = city_with_applicants(20);
+    high.world_mut().resource_mut::<WelfareStaffing>().level = 2.0;
+    high.tick_slow_cycle();
+
+    assert!(
+        high.resource::<WelfareStats>().queue_length < low.resource::<WelfareStats>().queue_length
+    );
+}
+
+#[test]
+fn test_welfare_caseload_staffing_changes_cost() {
+    let mut base = city_with_applicants(0);
+    base.tick_slow_cycle();
+    let mut extra = city_with_applicants(0);
+    extra.world_mut().resource_mut::<WelfareStaffing>().level = 2.0;
+    extra.tick_slow_cycle();
+
+    let base_stats = base.resource::<WelfareStats>();
+    let extra_stats = extra.resource::<WelfareStats>();
+    assert_eq!(base_stats.staffing_cost, 0.0);
+    assert!(extra_stats.staffing_cost > 0.0);
+    assert!(extra_stats.monthly_cost > base_stats.monthly_cost);
+}
@@ -0,0 +1,124 @@
+//! Integration tests for green corridors, wildlife and nature reserves.
+
+use crate::coal_power::PowerPlant;
+use crate::grid::{CellType, WorldGrid};
+use crate::park_districts::{ParkDistrictState, ParkType};
+use crate::test_harness::TestCity;
+use crate::trees::TreeGrid;
+use crate::wildlife::*;
+
+/// Plant a forest covering `x0..x1` by `y0..y1`.
+fn plant_forest(city: &mut TestCity, x0: usize, x1: usize, y0: usize, y1: usize) {
+    let mut trees = city.world_mut().resource_mut::<TreeGrid>();
+    for y in y0..y1 {
+        for x in x0..x1 {
+            trees.set(x, y, true);
+        }
+    }
+}
+
+fn forest_city() -> TestCity {
+    let mut city = TestCity::new();
+    plant_forest(&mut city, 100, 115, 100, 130);
+    city
+}
+
+fn wildlife(city: &TestCity) -> &WildlifeState {
+    city.resource::<WildlifeState>()
+}
+
+#[test]
+fn test_bare_city_has_no_wildlife() {
+    let mut city = TestCity::new();
+    city.tick_slow_cycles(2);
+    assert_eq!(wildlife(&city).carrying_capacity, 0.0);
+    assert_eq!(wildlife(&city).population, 0.0);
+}
+
+#[test]
+fn test_forest_supports_growing_wildlife() {
+    let mut city = forest_city();
+    city.tick_slow_cycle();
+    let early = wildlife(&city).population;
+    city.tick_slow_cycles(3);
+
+    let state = wildlife(&city);
+    assert_eq!(state.viable_patches, 1);
+    assert_eq!(state.largest_patch, 15 * 30);
+    assert!(state.carrying_capacity > 0.0);
+    assert!(state.population > early && early > 0.0);
+    assert!(city.resource::<BiodiversityGrid>().get(107, 115) > 0);
+    assert_eq!(city.resource::<BiodiversityGrid>().get(50, 50), 0);
+}
+
+#[test]
+fn test_road_through_forest_fragments_habitat() {
+    let mut intact = forest_city();
+    let mut cut = forest_city();
+    {
+        let mut grid = cut.world_mut().resource_mut::<WorldGrid>();
+        for x in 95..120 {
+            grid.get_mut(x, 115).cell_type = CellType::Road;
+        }
+    }
+    intact.tick_slow_cycle();
+    cut.tick_slow_cycle();
+
+    assert!(wildlife(&cut).viable_patches >= 2);
+    assert!(
+        wildlife(&cut).carrying_capacity < wildlife(&intact).carrying_capacity,
+        "a road through the forest should support less wildlife"
+    );
+}
+
+#[test]
+fn test_pollution_degrades_habitat() {
+    let mut clean = forest_city();
+    let mut polluted = forest_city();
+    polluted.world_mut().spawn(PowerPlant::new_coal(107, 115));
+    clean.tick_slow_cycles(2);
+    polluted.tick_slow_cycles(2);
+
+    assert!(
+        wildlife(&polluted).carrying_capacity < wildlife(&clean).carrying_capacity,
+        "a coal plant in the forest should drive wildlife out"
+    );
+}
+
+#[test]
+fn test_nature_reserve_raises_capacity() {
+    let mut open = forest_city();
+    let mut reserve = forest_city();
+    reserve
+        .world_mut()
+        .resource_mut::<ParkDistrictState>()
+        .create_district(ParkType::NatureReserve, 107, 115);
+    open.tick_slow_cycle();
+    reserve.tick_slow_cycle();
+
+    assert!(reserve
+        .resource::<ParkDistrictState>()
+        .nature_reserve_at(107, 115));
+    assert!(wildlife(&reserve).carrying_capacity > wildlife(&open).carrying_capacity);
+}
+
+#[test]
+fn test_wildlife_draws_park_visitors() {
+    let mut bare = TestCity::new();
+    let mut wooded = forest_city();
+    for city in [&mut bare, &mut wooded] {
+        city.world_mut()
+            .resource_mut::<ParkDistrictState>()
+            .create_district(ParkType::CityPark, 107, 115);
+        city.tick_slow_cycles(2);
+    }
+
+    let visitors =
+        |city: &TestCity| city.resource::<ParkDistrictState>().districts[0].total_visitors;
+    assert!(
+        visitors(&wooded) > visitors(&bare),
+        "wildlife should draw visitors to the park ({} vs {})",
+        visitors(&wooded),
+        visitors(&bare)
+    );
+}
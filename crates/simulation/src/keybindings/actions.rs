@@ -36,6 +36,7 @@ pub enum BindableAction {
 
     // Overlays
     OverlayCycleNext,
+    ToggleUndergroundView,
 
     // Panels
     ToggleJournal,
@@ -56,8 +57,36 @@ pub enum BindableAction {
     QuickLoad,
     NewGame,
 
-    // Screenshot
+    // Screenshot and photo mode
     Screenshot,
+    TogglePhotoMode,
+
+    // Build menus (two-key shortcuts: menu key, then a digit)
+    MenuRoads,
+    MenuZones,
+    MenuUtilities,
+    MenuEmergency,
+    MenuEducation,
+    MenuParks,
+    MenuLandmarks,
+    MenuSanitation,
+    MenuTransport,
+    MenuTelecom,
+    MenuViews,
+    MenuEnvironment,
+    MenuTerrain,
+    MenuDistricts,
+    MenuTools,
+
+    // More panels and tools
+    ToggleMinimap,
+    ToggleServiceCoverage,
+    ToggleFreehandDraw,
+    ToggleParallelSnap,
+    RotateBlueprint,
+    ToggleDistrictManager,
+    ToggleEncyclopedia,
+    ToggleAccessibleLog,
 }
 
 impl BindableAction {
@@ -86,6 +115,7 @@ impl BindableAction {
             Self::DeleteBuilding => "Delete Building",
             Self::Escape => "Cancel / Deselect",
             Self::OverlayCycleNext => "Cycle Overlay",
+            Self::ToggleUndergroundView => "Toggle Underground View",
             Self::ToggleJournal => "Toggle Journal",
             Self::ToggleCharts => "Toggle Charts",
             Self::ToggleAdvisor => "Toggle Advisor",
@@ -100,6 +130,30 @@ impl BindableAction {
             Self::QuickLoad => "Quick Load",
             Self::NewGame => "New Game",
             Self::Screenshot => "Screenshot",
+            Self::TogglePhotoMode => "Toggle Photo Mode",
+            Self::MenuRoads => "Menu: Roads",
+            Self::MenuZones => "Menu: Zones",
+            Self::MenuUtilities => "Menu: Utilities",
+            Self::MenuEmergency => "Menu: Emergency",
+            Self::MenuEducation => "Menu: Education",
+            Self::MenuParks => "Menu: Parks",
+            Self::MenuLandmarks => "Menu: Landmarks",
+            Self::MenuSanitation => "Menu: Sanitation",
+            Self::MenuTransport => "Menu: Transport",
+            Self::MenuTelecom => "Menu: Telecom",
+            Self::MenuViews => "Menu: Views",
+            Self::MenuEnvironment => "Menu: Environment",
+            Self::MenuTerrain => "Menu: Terrain",
+            Self::MenuDistricts => "Menu: Districts",
+            Self::MenuTools => "Menu: Tools",
+            Self::ToggleMinimap => "Toggle Minimap",
+            Self::ToggleServiceCoverage => "Toggle Service Coverage",
+            Self::ToggleFreehandDraw => "Toggle Freehand Draw",
+            Self::ToggleParallelSnap => "Toggle Parallel Snap",
+            Self::RotateBlueprint => "Rotate Blueprint",
+            Self::ToggleDistrictManager => "Toggle District Manager",
+            Self::ToggleEncyclopedia => "Toggle Encyclopedia",
+            Self::ToggleAccessibleLog => "Toggle Accessible Log",
         }
     }
 
@@ -126,10 +180,13 @@ impl BindableAction {
             | Self::ToolInspect
             | Self::ToggleGridSnap
             | Self::ToggleCurveDraw
+            | Self::ToggleFreehandDraw
+            | Self::ToggleParallelSnap
+            | Self::RotateBlueprint
             | Self::DeleteBuilding
             | Self::Escape => "Tools",
 
-            Self::OverlayCycleNext => "Overlays",
+            Self::OverlayCycleNext | Self::ToggleUndergroundView => "Overlays",
 
             Self::ToggleJournal
             | Self::ToggleCharts
@@ -137,17 +194,56 @@ impl BindableAction {
             | Self::TogglePolicies
             | Self::ToggleSettings
             | Self::ToggleSearch
-            | Self::ToggleHelp => "Panels",
+            | Self::ToggleHelp
+            | Self::ToggleMinimap
+            | Self::ToggleServiceCoverage
+            | Self::ToggleDistrictManager
+            | Self::ToggleEncyclopedia
+            | Self::ToggleAccessibleLog => "Panels",
 
             Self::ToggleEnergyDashboard
             | Self::ToggleWaterDashboard
             | Self::ToggleWasteDashboard => "Dashboards",
 
-            Self::QuickSave | Self::QuickLoad | Self::NewGame | Self::Screenshot => "System",
+            Self::QuickSave
+            | Self::QuickLoad
+            | Self::NewGame
+            | Self::Screenshot
+            | Self::TogglePhotoMode => "System",
+
+            Self::MenuRoads
+            | Self::MenuZones
+            | Self::MenuUtilities
+            | Self::MenuEmergency
+            | Self::MenuEducation
+            | Self::MenuParks
+            | Self::MenuLandmarks
+            | Self::MenuSanitation
+            | Self::MenuTransport
+            | Self::MenuTelecom
+            | Self::MenuViews
+            | Self::MenuEnvironment
+            | Self::MenuTerrain
+            | Self::MenuDistricts
+            | Self::MenuTools => "Build Menus",
+        }
+    }
+
+    /// Categories in order of first appearance in `ALL`. Settings UIs group
+    /// by these rather than by runs in `ALL`, since actions added later go at
+    /// the end whatever their category.
+    pub fn categories() -> Vec<&'static str> {
+        let mut categories = Vec::new();
+        for action in Self::ALL {
+            if !categories.contains(&action.category()) {
+                categories.push(action.category());
+            }
         }
+        categories
     }
 
-    /// All bindable actions in display order.
+    /// All bindable actions in display order. Saved bindings refer to
+    /// actions by their index here, so new actions go at the end.
     pub const ALL: &'static [BindableAction] = &[
         Self::CameraPanUp,
         Self::CameraPanDown,
@@ -185,5 +281,30 @@ impl BindableAction {
         Self::QuickLoad,
         Self::NewGame,
         Self::Screenshot,
+        Self::ToggleUndergroundView,
+        Self::TogglePhotoMode,
+        Self::MenuRoads,
+        Self::MenuZones,
+        Self::MenuUtilities,
+        Self::MenuEmergency,
+        Self::MenuEducation,
+        Self::MenuParks,
+        Self::MenuLandmarks,
+        Self::MenuSanitation,
+        Self::MenuTransport,
+        Self::MenuTelecom,
+        Self::MenuViews,
+        Self::MenuEnvironment,
+        Self::MenuTerrain,
+        Self::MenuDistricts,
+        Self::MenuTools,
+        Self::ToggleMinimap,
+        Self::ToggleServiceCoverage,
+        Self::ToggleFreehandDraw,
+        Self::ToggleParallelSnap,
+        Self::RotateBlueprint,
+        Self::ToggleDistrictManager,
+        Self::ToggleEncyclopedia,
+        Self::ToggleAccessibleLog,
     ];
 }
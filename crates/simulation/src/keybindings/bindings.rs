@@ -1,10 +1,10 @@
 //! Key binding types, the `KeyBindings` resource, serialization, plugin, and rebind system.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::actions::BindableAction;
 use super::key_helpers::{keycode_label, keycode_to_u16, u16_to_keycode};
-use crate::Saveable;
 
 // =============================================================================
 // Key binding definition
@@ -77,7 +77,7 @@ impl KeyBinding {
 // Serializable binding for persistence
 // =============================================================================
 
-#[derive(bitcode::Encode, bitcode::Decode, Clone, Copy, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
 struct SerBinding {
     key_disc: u16,
     ctrl: bool,
@@ -102,8 +102,8 @@ impl SerBinding {
     }
 }
 
-#[derive(bitcode::Encode, bitcode::Decode, Clone, Default, PartialEq)]
-struct SerKeyBindings {
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub(super) struct SerKeyBindings {
     bindings: Vec<(u8, SerBinding)>,
 }
 
@@ -113,7 +113,7 @@ struct SerKeyBindings {
 
 /// Central resource holding all configurable keybindings.
 /// Systems should read from this instead of hardcoding `KeyCode` values.
-#[derive(Resource, Clone)]
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct KeyBindings {
     // Camera
     pub camera_pan_up: KeyBinding,
@@ -149,6 +149,7 @@ pub struct KeyBindings {
 
     // Overlays
     pub overlay_cycle_next: KeyBinding,
+    pub toggle_underground_view: KeyBinding,
 
     // Panels
     pub toggle_journal: KeyBinding,
@@ -169,54 +170,34 @@ pub struct KeyBindings {
     pub quick_load: KeyBinding,
     pub new_game: KeyBinding,
     pub screenshot: KeyBinding,
-}
-
-impl Default for KeyBindings {
-    fn default() -> Self {
-        Self {
-            camera_pan_up: KeyBinding::simple(KeyCode::KeyW),
-            camera_pan_up_alt: KeyBinding::simple(KeyCode::ArrowUp),
-            camera_pan_down: KeyBinding::simple(KeyCode::KeyS),
-            camera_pan_down_alt: KeyBinding::simple(KeyCode::ArrowDown),
-            camera_pan_left: KeyBinding::simple(KeyCode::KeyA),
-            camera_pan_left_alt: KeyBinding::simple(KeyCode::ArrowLeft),
-            camera_pan_right: KeyBinding::simple(KeyCode::KeyD),
-            camera_pan_right_alt: KeyBinding::simple(KeyCode::ArrowRight),
-            camera_rotate_left: KeyBinding::simple(KeyCode::KeyQ),
-            camera_rotate_right: KeyBinding::simple(KeyCode::KeyE),
-            camera_zoom_in: KeyBinding::simple(KeyCode::NumpadAdd),
-            camera_zoom_out: KeyBinding::simple(KeyCode::NumpadSubtract),
-            toggle_pause: KeyBinding::simple(KeyCode::Space),
-            speed_normal: KeyBinding::simple(KeyCode::Digit1),
-            speed_fast: KeyBinding::simple(KeyCode::Digit2),
-            speed_fastest: KeyBinding::simple(KeyCode::Digit3),
-            tool_road: KeyBinding::simple(KeyCode::KeyR),
-            tool_zone_res: KeyBinding::simple(KeyCode::KeyZ),
-            tool_zone_com: KeyBinding::simple(KeyCode::KeyV),
-            tool_bulldoze: KeyBinding::simple(KeyCode::KeyB),
-            tool_inspect: KeyBinding::simple(KeyCode::KeyI),
-            toggle_grid_snap: KeyBinding::simple(KeyCode::KeyF),
-            toggle_curve_draw: KeyBinding { key: KeyCode::KeyG, ctrl: false, shift: true },
-            delete_building: KeyBinding::simple(KeyCode::Delete),
-            delete_building_alt: KeyBinding::simple(KeyCode::Backspace),
-            escape: KeyBinding::simple(KeyCode::Escape),
-            overlay_cycle_next: KeyBinding::simple(KeyCode::Tab),
-            toggle_journal: KeyBinding::simple(KeyCode::KeyJ),
-            toggle_charts: KeyBinding::simple(KeyCode::KeyC),
-            toggle_advisor: KeyBinding::simple(KeyCode::F2),
-            toggle_policies: KeyBinding::simple(KeyCode::KeyP),
-            toggle_settings: KeyBinding::simple(KeyCode::F10),
-            toggle_search: KeyBinding::ctrl(KeyCode::KeyF),
-            toggle_help: KeyBinding::simple(KeyCode::F1),
-            toggle_energy_dashboard: KeyBinding::simple(KeyCode::F3),
-            toggle_water_dashboard: KeyBinding::simple(KeyCode::F4),
-            toggle_waste_dashboard: KeyBinding::simple(KeyCode::F6),
-            quick_save: KeyBinding::simple(KeyCode::F5),
-            quick_load: KeyBinding::simple(KeyCode::F9),
-            new_game: KeyBinding::ctrl(KeyCode::KeyN),
-            screenshot: KeyBinding::simple(KeyCode::F12),
-        }
-    }
+    pub toggle_photo_mode: KeyBinding,
+
+    // Build menus
+    pub menu_roads: KeyBinding,
+    pub menu_zones: KeyBinding,
+    pub menu_utilities: KeyBinding,
+    pub menu_emergency: KeyBinding,
+    pub menu_education: KeyBinding,
+    pub menu_parks: KeyBinding,
+    pub menu_landmarks: KeyBinding,
+    pub menu_sanitation: KeyBinding,
+    pub menu_transport: KeyBinding,
+    pub menu_telecom: KeyBinding,
+    pub menu_views: KeyBinding,
+    pub menu_environment: KeyBinding,
+    pub menu_terrain: KeyBinding,
+    pub menu_districts: KeyBinding,
+    pub menu_tools: KeyBinding,
+
+    // More panels and tools
+    pub toggle_minimap: KeyBinding,
+    pub toggle_service_coverage: KeyBinding,
+    pub toggle_freehand_draw: KeyBinding,
+    pub toggle_parallel_snap: KeyBinding,
+    pub rotate_blueprint: KeyBinding,
+    pub toggle_district_manager: KeyBinding,
+    pub toggle_encyclopedia: KeyBinding,
+    pub toggle_accessible_log: KeyBinding,
 }
 
 impl KeyBindings {
@@ -245,6 +226,7 @@ impl KeyBindings {
             BindableAction::DeleteBuilding => self.delete_building,
             BindableAction::Escape => self.escape,
             BindableAction::OverlayCycleNext => self.overlay_cycle_next,
+            BindableAction::ToggleUndergroundView => self.toggle_underground_view,
             BindableAction::ToggleJournal => self.toggle_journal,
             BindableAction::ToggleCharts => self.toggle_charts,
             BindableAction::ToggleAdvisor => self.toggle_advisor,
@@ -259,6 +241,30 @@ impl KeyBindings {
             BindableAction::QuickLoad => self.quick_load,
             BindableAction::NewGame => self.new_game,
             BindableAction::Screenshot => self.screenshot,
+            BindableAction::TogglePhotoMode => self.toggle_photo_mode,
+            BindableAction::MenuRoads => self.menu_roads,
+            BindableAction::MenuZones => self.menu_zones,
+            BindableAction::MenuUtilities => self.menu_utilities,
+            BindableAction::MenuEmergency => self.menu_emergency,
+            BindableAction::MenuEducation => self.menu_education,
+            BindableAction::MenuParks => self.menu_parks,
+            BindableAction::MenuLandmarks => self.menu_landmarks,
+            BindableAction::MenuSanitation => self.menu_sanitation,
+            BindableAction::MenuTransport => self.menu_transport,
+            BindableAction::MenuTelecom => self.menu_telecom,
+            BindableAction::MenuViews => self.menu_views,
+            BindableAction::MenuEnvironment => self.menu_environment,
+            BindableAction::MenuTerrain => self.menu_terrain,
+            BindableAction::MenuDistricts => self.menu_districts,
+            BindableAction::MenuTools => self.menu_tools,
+            BindableAction::ToggleMinimap => self.toggle_minimap,
+            BindableAction::ToggleServiceCoverage => self.toggle_service_coverage,
+            BindableAction::ToggleFreehandDraw => self.toggle_freehand_draw,
+            BindableAction::ToggleParallelSnap => self.toggle_parallel_snap,
+            BindableAction::RotateBlueprint => self.rotate_blueprint,
+            BindableAction::ToggleDistrictManager => self.toggle_district_manager,
+            BindableAction::ToggleEncyclopedia => self.toggle_encyclopedia,
+            BindableAction::ToggleAccessibleLog => self.toggle_accessible_log,
         }
     }
 
@@ -287,6 +293,7 @@ impl KeyBindings {
             BindableAction::DeleteBuilding => self.delete_building = binding,
             BindableAction::Escape => self.escape = binding,
             BindableAction::OverlayCycleNext => self.overlay_cycle_next = binding,
+            BindableAction::ToggleUndergroundView => self.toggle_underground_view = binding,
             BindableAction::ToggleJournal => self.toggle_journal = binding,
             BindableAction::ToggleCharts => self.toggle_charts = binding,
             BindableAction::ToggleAdvisor => self.toggle_advisor = binding,
@@ -301,6 +308,30 @@ impl KeyBindings {
             BindableAction::QuickLoad => self.quick_load = binding,
             BindableAction::NewGame => self.new_game = binding,
             BindableAction::Screenshot => self.screenshot = binding,
+            BindableAction::TogglePhotoMode => self.toggle_photo_mode = binding,
+            BindableAction::MenuRoads => self.menu_roads = binding,
+            BindableAction::MenuZones => self.menu_zones = binding,
+            BindableAction::MenuUtilities => self.menu_utilities = binding,
+            BindableAction::MenuEmergency => self.menu_emergency = binding,
+            BindableAction::MenuEducation => self.menu_education = binding,
+            BindableAction::MenuParks => self.menu_parks = binding,
+            BindableAction::MenuLandmarks => self.menu_landmarks = binding,
+            BindableAction::MenuSanitation => self.menu_sanitation = binding,
+            BindableAction::MenuTransport => self.menu_transport = binding,
+            BindableAction::MenuTelecom => self.menu_telecom = binding,
+            BindableAction::MenuViews => self.menu_views = binding,
+            BindableAction::MenuEnvironment => self.menu_environment = binding,
+            BindableAction::MenuTerrain => self.menu_terrain = binding,
+            BindableAction::MenuDistricts => self.menu_districts = binding,
+            BindableAction::MenuTools => self.menu_tools = binding,
+            BindableAction::ToggleMinimap => self.toggle_minimap = binding,
+            BindableAction::ToggleServiceCoverage => self.toggle_service_coverage = binding,
+            BindableAction::ToggleFreehandDraw => self.toggle_freehand_draw = binding,
+            BindableAction::ToggleParallelSnap => self.toggle_parallel_snap = binding,
+            BindableAction::RotateBlueprint => self.rotate_blueprint = binding,
+            BindableAction::ToggleDistrictManager => self.toggle_district_manager = binding,
+            BindableAction::ToggleEncyclopedia => self.toggle_encyclopedia = binding,
+            BindableAction::ToggleAccessibleLog => self.toggle_accessible_log = binding,
         }
     }
 
@@ -322,7 +353,17 @@ impl KeyBindings {
         conflicts
     }
 
-    fn to_ser(&self) -> SerKeyBindings {
+    /// Restore the default binding for a single action.
+    pub fn reset(&mut self, action: BindableAction) {
+        self.set(action, KeyBindings::default().get(action));
+    }
+
+    /// Whether an action is still bound to its default key.
+    pub fn is_default(&self, action: BindableAction) -> bool {
+        self.get(action) == KeyBindings::default().get(action)
+    }
+
+    pub(super) fn to_ser(&self) -> SerKeyBindings {
         let mut bindings = Vec::new();
         for (i, &action) in BindableAction::ALL.iter().enumerate() {
             bindings.push((i as u8, SerBinding::from_binding(self.get(action))));
@@ -330,7 +371,7 @@ impl KeyBindings {
         SerKeyBindings { bindings }
     }
 
-    fn from_ser(ser: &SerKeyBindings) -> Self {
+    pub(super) fn from_ser(ser: &SerKeyBindings) -> Self {
         let mut kb = KeyBindings::default();
         for &(idx, sb) in &ser.bindings {
             if let Some(&action) = BindableAction::ALL.get(idx as usize) {
@@ -341,38 +382,6 @@ impl KeyBindings {
     }
 }
 
-// =============================================================================
-// Saveable implementation
-// =============================================================================
-
-impl Saveable for KeyBindings {
-    const SAVE_KEY: &'static str = "keybindings";
-
-    fn save_to_bytes(&self) -> Option<Vec<u8>> {
-        let default = KeyBindings::default();
-        let self_ser = self.to_ser();
-        let default_ser = default.to_ser();
-        if self_ser == default_ser {
-            return None;
-        }
-        Some(bitcode::encode(&self_ser))
-    }
-
-    fn load_from_bytes(bytes: &[u8]) -> Self {
-        match bitcode::decode::<SerKeyBindings>(bytes) {
-            Ok(ser) => Self::from_ser(&ser),
-            Err(e) => {
-                warn!(
-                    "KeyBindings: failed to decode {} bytes, falling back to default: {}",
-                    bytes.len(),
-                    e
-                );
-                Self::default()
-            }
-        }
-    }
-}
-
 // =============================================================================
 // State resource for the rebind UI
 // =============================================================================
@@ -397,12 +406,6 @@ impl Plugin for KeyBindingsPlugin {
                 Update,
                 capture_rebind_input.in_set(crate::SimulationUpdateSet::Input),
             );
-
-        // Register for save/load via the SaveableRegistry.
-        app.init_resource::<crate::SaveableRegistry>();
-        app.world_mut()
-            .resource_mut::<crate::SaveableRegistry>()
-            .register::<KeyBindings>();
     }
 }
 
@@ -0,0 +1,79 @@
+//! The binding table: the default key for every bindable action, used for a
+//! fresh profile and by per-action reset.
+
+use bevy::prelude::*;
+
+use super::bindings::{KeyBinding, KeyBindings};
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            camera_pan_up: KeyBinding::simple(KeyCode::KeyW),
+            camera_pan_up_alt: KeyBinding::simple(KeyCode::ArrowUp),
+            camera_pan_down: KeyBinding::simple(KeyCode::KeyS),
+            camera_pan_down_alt: KeyBinding::simple(KeyCode::ArrowDown),
+            camera_pan_left: KeyBinding::simple(KeyCode::KeyA),
+            camera_pan_left_alt: KeyBinding::simple(KeyCode::ArrowLeft),
+            camera_pan_right: KeyBinding::simple(KeyCode::KeyD),
+            camera_pan_right_alt: KeyBinding::simple(KeyCode::ArrowRight),
+            camera_rotate_left: KeyBinding::simple(KeyCode::KeyQ),
+            camera_rotate_right: KeyBinding::simple(KeyCode::KeyE),
+            camera_zoom_in: KeyBinding::simple(KeyCode::NumpadAdd),
+            camera_zoom_out: KeyBinding::simple(KeyCode::NumpadSubtract),
+            toggle_pause: KeyBinding::simple(KeyCode::Space),
+            speed_normal: KeyBinding::simple(KeyCode::Digit1),
+            speed_fast: KeyBinding::simple(KeyCode::Digit2),
+            speed_fastest: KeyBinding::simple(KeyCode::Digit3),
+            tool_road: KeyBinding::simple(KeyCode::KeyR),
+            tool_zone_res: KeyBinding::simple(KeyCode::KeyZ),
+            tool_zone_com: KeyBinding::simple(KeyCode::KeyV),
+            tool_bulldoze: KeyBinding::simple(KeyCode::KeyB),
+            tool_inspect: KeyBinding::simple(KeyCode::KeyI),
+            toggle_grid_snap: KeyBinding::simple(KeyCode::KeyF),
+            toggle_curve_draw: KeyBinding { key: KeyCode::KeyG, ctrl: false, shift: true },
+            delete_building: KeyBinding::simple(KeyCode::Delete),
+            delete_building_alt: KeyBinding::simple(KeyCode::Backspace),
+            escape: KeyBinding::simple(KeyCode::Escape),
+            overlay_cycle_next: KeyBinding::simple(KeyCode::Tab),
+            toggle_underground_view: KeyBinding { key: KeyCode::KeyU, ctrl: false, shift: true },
+            toggle_journal: KeyBinding::simple(KeyCode::KeyJ),
+            toggle_charts: KeyBinding::simple(KeyCode::KeyC),
+            toggle_advisor: KeyBinding::simple(KeyCode::F2),
+            toggle_policies: KeyBinding::simple(KeyCode::KeyP),
+            toggle_settings: KeyBinding::simple(KeyCode::F10),
+            toggle_search: KeyBinding::ctrl(KeyCode::KeyF),
+            toggle_help: KeyBinding::simple(KeyCode::F1),
+            toggle_energy_dashboard: KeyBinding::simple(KeyCode::F3),
+            toggle_water_dashboard: KeyBinding::simple(KeyCode::F4),
+            toggle_waste_dashboard: KeyBinding::simple(KeyCode::F6),
+            quick_save: KeyBinding::simple(KeyCode::F5),
+            quick_load: KeyBinding::simple(KeyCode::F9),
+            new_game: KeyBinding::ctrl(KeyCode::KeyN),
+            screenshot: KeyBinding::simple(KeyCode::F12),
+            toggle_photo_mode: KeyBinding { key: KeyCode::F12, ctrl: false, shift: true },
+            menu_roads: KeyBinding::simple(KeyCode::KeyR),
+            menu_zones: KeyBinding::simple(KeyCode::KeyZ),
+            menu_utilities: KeyBinding::simple(KeyCode::KeyU),
+            menu_emergency: KeyBinding::simple(KeyCode::KeyE),
+            menu_education: KeyBinding::simple(KeyCode::KeyH),
+            menu_parks: KeyBinding::simple(KeyCode::KeyK),
+            menu_landmarks: KeyBinding::simple(KeyCode::KeyL),
+            menu_sanitation: KeyBinding::simple(KeyCode::KeyG),
+            menu_transport: KeyBinding::simple(KeyCode::KeyX),
+            menu_telecom: KeyBinding::simple(KeyCode::KeyN),
+            menu_views: KeyBinding::simple(KeyCode::KeyV),
+            menu_environment: KeyBinding::simple(KeyCode::KeyF),
+            menu_terrain: KeyBinding::simple(KeyCode::KeyY),
+            menu_districts: KeyBinding::simple(KeyCode::KeyO),
+            menu_tools: KeyBinding::simple(KeyCode::KeyT),
+            toggle_minimap: KeyBinding::simple(KeyCode::KeyM),
+            toggle_service_coverage: KeyBinding::simple(KeyCode::KeyK),
+            toggle_freehand_draw: KeyBinding::simple(KeyCode::KeyH),
+            toggle_parallel_snap: KeyBinding::simple(KeyCode::KeyP),
+            rotate_blueprint: KeyBinding { key: KeyCode::KeyR, ctrl: false, shift: true },
+            toggle_district_manager: KeyBinding { key: KeyCode::KeyD, ctrl: false, shift: true },
+            toggle_encyclopedia: KeyBinding { key: KeyCode::F1, ctrl: false, shift: true },
+            toggle_accessible_log: KeyBinding { key: KeyCode::F10, ctrl: false, shift: true },
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Reading and writing keybindings as JSON.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::bindings::{KeyBindings, SerKeyBindings};
+
+/// Keybindings file written by builds before the player profile, relative to
+/// the working directory. Only read to import old bindings into the profile.
+pub const KEYBINDINGS_PATH: &str = "keybindings.json";
+
+// The player profile stores bindings in the same form as the config file.
+impl Serialize for KeyBindings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_ser().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SerKeyBindings::deserialize(deserializer).map(|ser| Self::from_ser(&ser))
+    }
+}
+
+impl KeyBindings {
+    /// Parse bindings from JSON. Actions missing from the file keep their
+    /// default binding.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let ser: SerKeyBindings =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        Ok(Self::from_ser(&ser))
+    }
+
+    /// Pretty-printed JSON for the config file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(&self.to_ser()).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load bindings from `path`. A missing file yields the defaults; an
+    /// unreadable or invalid one is reported as an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
@@ -271,25 +271,54 @@ mod tests {
     }
 
     #[test]
-    fn test_saveable_roundtrip() {
-        use crate::Saveable;
+    fn test_json_roundtrip() {
         let mut kb = KeyBindings::default();
         kb.set(
             BindableAction::TogglePause,
             KeyBinding::simple(KeyCode::KeyX),
         );
-        let bytes = kb.save_to_bytes().expect("should save");
-        let loaded = KeyBindings::load_from_bytes(&bytes);
+        let json = kb.to_json().expect("should encode");
+        let loaded = KeyBindings::from_json(&json).expect("should decode");
         assert_eq!(
             loaded.get(BindableAction::TogglePause),
             KeyBinding::simple(KeyCode::KeyX)
         );
+        assert!(loaded.is_default(BindableAction::MenuRoads));
     }
 
     #[test]
-    fn test_saveable_skip_default() {
-        use crate::Saveable;
-        assert!(KeyBindings::default().save_to_bytes().is_none());
+    fn test_invalid_json_is_an_error() {
+        assert!(KeyBindings::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_reset_single_action() {
+        let mut kb = KeyBindings::default();
+        kb.set(BindableAction::MenuZones, KeyBinding::simple(KeyCode::KeyQ));
+        kb.set(
+            BindableAction::ToggleMinimap,
+            KeyBinding::simple(KeyCode::F7),
+        );
+        assert!(!kb.is_default(BindableAction::MenuZones));
+
+        kb.reset(BindableAction::MenuZones);
+        assert_eq!(
+            kb.get(BindableAction::MenuZones),
+            KeyBinding::simple(KeyCode::KeyZ)
+        );
+        assert!(!kb.is_default(BindableAction::ToggleMinimap));
+    }
+
+    #[test]
+    fn test_categories_listed_once() {
+        let categories = BindableAction::categories();
+        assert_eq!(categories.first(), Some(&"Camera"));
+        assert_eq!(
+            categories.iter().filter(|c| **c == "Panels").count(),
+            1,
+            "actions appended to an existing category must not repeat it"
+        );
+        assert!(categories.contains(&"Build Menus"));
     }
 
     #[test]
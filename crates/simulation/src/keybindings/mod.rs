@@ -2,13 +2,20 @@
 //!
 //! Provides a `KeyBindings` resource containing all configurable keyboard
 //! shortcuts. Systems read from this resource instead of hardcoding `KeyCode`
-//! values. A settings UI allows rebinding, with conflict detection and
-//! "Reset to Defaults".
+//! values. A settings UI allows rebinding, with conflict detection, per-action
+//! reset and "Reset to Defaults".
+//!
+//! Bindings are a player preference rather than part of a city, so they are
+//! kept in the player profile (see [`crate::profile`]) instead of travelling
+//! with save files.
 
 mod actions;
 mod bindings;
+mod defaults;
+mod io;
 pub(crate) mod key_helpers;
 
 pub use actions::BindableAction;
 pub use bindings::{KeyBinding, KeyBindings, KeyBindingsPlugin, RebindState};
+pub use io::KEYBINDINGS_PATH;
 pub use key_helpers::keycode_label;
@@ -0,0 +1,25 @@
+//! City-owned land, leasing and land auctions.
+//!
+//! Every parcel starts privately held. The city can buy parcels at a price
+//! driven by land value; parcels with a building on them, or that were sold
+//! to a developer, can only be taken by eminent domain, which pays a premium
+//! plus compensation per building level, demolishes the building and costs
+//! city-wide happiness that fades over time (waived while the Eminent Domain
+//! policy is in force, since that policy carries its own cost).
+//!
+//! City land is held back from private development. It can be leased, which
+//! lets developers build while the city collects monthly rent, or auctioned
+//! for immediate cash above market price. Auctioned parcels are out of the
+//! city's control: they cannot be rezoned, and buying them back means
+//! eminent domain. No developer bids on land outside an active urban growth
+//! boundary.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    apply_seizure_grievance, collect_lease_rent, handle_land_actions, LandOwnershipPlugin,
+};
+pub use types::*;
@@ -0,0 +1,221 @@
+//! Land purchases, leases, auctions and their ongoing effects.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::citizen::{Citizen, CitizenDetails};
+use crate::economy::CityBudget;
+use crate::grid::{CellType, WorldGrid};
+use crate::land_value::LandValueGrid;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::policies::{Policies, Policy};
+use crate::time_of_day::GameClock;
+use crate::urban_growth_boundary::UrbanGrowthBoundary;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Carry out one land action. Returns the notification text on success and
+/// the reason on failure.
+#[allow(clippy::too_many_arguments)]
+fn apply_land_action(
+    action: &LandAction,
+    land: &mut LandOwnership,
+    commands: &mut Commands,
+    grid: &mut WorldGrid,
+    land_value: &LandValueGrid,
+    ugb: &UrbanGrowthBoundary,
+    policies: &Policies,
+    budget: &mut CityBudget,
+    buildings: &Query<&Building>,
+) -> Result<String, String> {
+    let (x, y) = (action.grid_x, action.grid_y);
+    if !grid.in_bounds(x, y) || grid.get(x, y).cell_type != CellType::Grass {
+        return Err("Only land parcels can be traded.".to_string());
+    }
+    let lv = land_value.get(x, y);
+    let owner = land.owner(x, y);
+    let building_id = grid.get(x, y).building_id;
+
+    match action.kind {
+        LandActionKind::Buy => {
+            if owner.is_city() {
+                return Err("The city already owns this parcel.".to_string());
+            }
+            let building = match building_id {
+                Some(entity) => match buildings.get(entity) {
+                    Ok(b) => Some((entity, b.level)),
+                    Err(_) => return Err("A public building stands here.".to_string()),
+                },
+                None => None,
+            };
+            let seize = building.is_some() || owner == ParcelOwner::Developer;
+            let price = if seize {
+                seizure_price(lv, building.map_or(0, |(_, level)| level))
+            } else {
+                market_price(lv)
+            };
+            if budget.treasury < price {
+                return Err(format!(
+                    "Buying this parcel needs ${price:.0}, treasury has ${:.0}.",
+                    budget.treasury
+                ));
+            }
+
+            budget.treasury -= price;
+            land.total_spent += price;
+            land.set_owner(x, y, ParcelOwner::City);
+            if !seize {
+                return Ok(format!("Bought parcel ({x}, {y}) for ${price:.0}."));
+            }
+
+            land.seizures += 1;
+            // The Eminent Domain policy already carries a city-wide cost.
+            if !policies.is_active(Policy::EminentDomain) {
+                land.add_grievance();
+            }
+            if let Some((entity, _)) = building {
+                grid.get_mut(x, y).building_id = None;
+                commands.entity(entity).despawn();
+            }
+            Ok(format!(
+                "Took parcel ({x}, {y}) by eminent domain, paying ${price:.0} in compensation."
+            ))
+        }
+        LandActionKind::Lease => {
+            if owner != ParcelOwner::City {
+                return Err("Only vacant city land can be leased.".to_string());
+            }
+            land.set_owner(x, y, ParcelOwner::Leased);
+            Ok(format!(
+                "Leased parcel ({x}, {y}) for ${:.0} a month.",
+                lease_rent(lv)
+            ))
+        }
+        LandActionKind::EndLease => {
+            if owner != ParcelOwner::Leased {
+                return Err("This parcel is not leased.".to_string());
+            }
+            if building_id.is_some() {
+                return Err("The tenant has already built here.".to_string());
+            }
+            land.set_owner(x, y, ParcelOwner::City);
+            Ok(format!("Ended the lease on parcel ({x}, {y})."))
+        }
+        LandActionKind::Auction => {
+            if !owner.is_city() {
+                return Err("Only city land can be auctioned.".to_string());
+            }
+            if !ugb.contains(x, y) {
+                return Err("No developer will bid outside the growth boundary.".to_string());
+            }
+            let price = auction_price(lv);
+            budget.treasury += price;
+            land.total_auctioned += price;
+            land.set_owner(x, y, ParcelOwner::Developer);
+            Ok(format!(
+                "Auctioned parcel ({x}, {y}) to a developer for ${price:.0}."
+            ))
+        }
+    }
+}
+
+/// Handle land actions requested from the UI or agents.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_land_actions(
+    mut commands: Commands,
+    mut events: EventReader<LandAction>,
+    mut land: ResMut<LandOwnership>,
+    mut grid: ResMut<WorldGrid>,
+    land_value: Res<LandValueGrid>,
+    ugb: Res<UrbanGrowthBoundary>,
+    policies: Res<Policies>,
+    mut budget: ResMut<CityBudget>,
+    buildings: Query<&Building>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for action in events.read() {
+        let result = apply_land_action(
+            action,
+            &mut land,
+            &mut commands,
+            &mut grid,
+            &land_value,
+            &ugb,
+            &policies,
+            &mut budget,
+            &buildings,
+        );
+        let (text, priority) = match result {
+            Ok(text) => (text, NotificationPriority::Info),
+            Err(text) => (text, NotificationPriority::Attention),
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Finance,
+            location: Some(WorldGrid::grid_to_world(action.grid_x, action.grid_y)),
+        });
+    }
+}
+
+/// Collect rent on leased parcels every `LEASE_PERIOD_DAYS`.
+pub fn collect_lease_rent(
+    clock: Res<GameClock>,
+    land_value: Res<LandValueGrid>,
+    mut land: ResMut<LandOwnership>,
+    mut budget: ResMut<CityBudget>,
+) {
+    if clock.day < land.last_lease_day + LEASE_PERIOD_DAYS {
+        return;
+    }
+    let rent: f64 = land
+        .leased_cells()
+        .map(|(x, y)| lease_rent(land_value.get(x, y)))
+        .sum();
+    land.last_lease_day = clock.day;
+    land.last_lease_income = rent;
+    budget.treasury += rent;
+}
+
+/// Apply the city-wide happiness cost of recent seizures, then let it fade.
+pub fn apply_seizure_grievance(
+    timer: Res<SlowTickTimer>,
+    mut land: ResMut<LandOwnership>,
+    mut citizens: Query<&mut CitizenDetails, With<Citizen>>,
+) {
+    if !timer.should_run() || land.grievance <= 0.0 {
+        return;
+    }
+    let penalty = land.grievance;
+    citizens.par_iter_mut().for_each(|mut details| {
+        details.happiness = (details.happiness - penalty).max(0.0);
+    });
+    land.grievance *= GRIEVANCE_DECAY;
+    if land.grievance < 0.1 {
+        land.grievance = 0.0;
+    }
+}
+
+pub struct LandOwnershipPlugin;
+
+impl Plugin for LandOwnershipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LandOwnership>()
+            .add_event::<LandAction>()
+            .add_systems(Update, handle_land_actions)
+            .add_systems(
+                FixedUpdate,
+                (
+                    collect_lease_rent,
+                    apply_seizure_grievance.after(crate::nimby::apply_nimby_happiness),
+                )
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<LandOwnership>();
+    }
+}
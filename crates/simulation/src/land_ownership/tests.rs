@@ -0,0 +1,73 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_prices_scale_with_land_value() {
+    assert_eq!(market_price(0), 0.0);
+    assert!(market_price(200) > market_price(100));
+    assert!(auction_price(100) > market_price(100));
+    // Prime land draws a larger premium over market.
+    assert!(auction_price(250) / market_price(250) > auction_price(50) / market_price(50));
+    assert!((lease_rent(100) - market_price(100) * LEASE_RATE).abs() < 1e-9);
+}
+
+#[test]
+fn test_seizure_costs_more_than_market() {
+    let vacant = seizure_price(100, 0);
+    assert!(vacant > market_price(100));
+    assert!((seizure_price(100, 3) - vacant - 3.0 * COMPENSATION_PER_LEVEL).abs() < 1e-9);
+}
+
+#[test]
+fn test_owners_allocated_lazily() {
+    let mut land = LandOwnership::default();
+    land.set_owner(5, 5, ParcelOwner::Private);
+    assert!(land.owners.is_empty());
+    assert_eq!(land.owner(5, 5), ParcelOwner::Private);
+
+    land.set_owner(5, 5, ParcelOwner::City);
+    assert_eq!(land.owner(5, 5), ParcelOwner::City);
+    assert_eq!(land.owner(6, 5), ParcelOwner::Private);
+    assert_eq!(land.city_parcels(), 1);
+}
+
+#[test]
+fn test_ownership_controls_development_and_zoning() {
+    let mut land = LandOwnership::default();
+    land.set_owner(1, 1, ParcelOwner::City);
+    land.set_owner(2, 1, ParcelOwner::Leased);
+    land.set_owner(3, 1, ParcelOwner::Developer);
+
+    assert!(!land.allows_private_development(1, 1));
+    assert!(land.allows_private_development(2, 1));
+    assert!(land.allows_private_development(3, 1));
+    assert!(land.allows_private_development(4, 1));
+
+    assert!(land.allows_rezoning(1, 1));
+    assert!(land.allows_rezoning(2, 1));
+    assert!(!land.allows_rezoning(3, 1));
+
+    assert_eq!(land.leased_cells().collect::<Vec<_>>(), vec![(2, 1)]);
+}
+
+#[test]
+fn test_grievance_is_capped() {
+    let mut land = LandOwnership::default();
+    for _ in 0..20 {
+        land.add_grievance();
+    }
+    assert_eq!(land.grievance, MAX_GRIEVANCE);
+}
+
+#[test]
+fn test_save_skipped_at_default_and_roundtrips() {
+    let mut land = LandOwnership::default();
+    assert!(land.save_to_bytes().is_none());
+
+    land.set_owner(10, 20, ParcelOwner::Leased);
+    land.total_spent = 4_000.0;
+    let bytes = land.save_to_bytes().expect("non-default state saves");
+    let restored = LandOwnership::load_from_bytes(&bytes);
+    assert_eq!(restored.owner(10, 20), ParcelOwner::Leased);
+    assert_eq!(restored.total_spent, 4_000.0);
+}
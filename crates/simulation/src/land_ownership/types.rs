@@ -0,0 +1,219 @@
+//! Parcel ownership, prices and the land action events.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Market price of one parcel per point of land value.
+pub const PRICE_PER_LAND_VALUE: f64 = 40.0;
+
+/// Extra paid over market price when a parcel is taken by eminent domain.
+pub const EMINENT_DOMAIN_PREMIUM: f64 = 0.5;
+
+/// Compensation per building level when a built parcel is seized.
+pub const COMPENSATION_PER_LEVEL: f64 = 5_000.0;
+
+/// Monthly lease rent as a fraction of the parcel's market price.
+pub const LEASE_RATE: f64 = 0.02;
+
+/// Winning bid at land value 0, as a multiple of market price.
+pub const AUCTION_BASE_MULTIPLIER: f64 = 1.1;
+
+/// Extra bid multiplier at land value 255; developers compete harder for
+/// prime land.
+pub const AUCTION_PRIME_BONUS: f64 = 0.4;
+
+/// Happiness points lost city-wide per parcel seized.
+pub const SEIZURE_GRIEVANCE: f32 = 3.0;
+
+/// Cap on the accumulated seizure grievance.
+pub const MAX_GRIEVANCE: f32 = 15.0;
+
+/// Fraction of the grievance that remains after each slow tick.
+pub const GRIEVANCE_DECAY: f32 = 0.9;
+
+/// Days between lease rent collections.
+pub const LEASE_PERIOD_DAYS: u32 = 30;
+
+/// Market price of a parcel.
+pub fn market_price(land_value: u8) -> f64 {
+    land_value as f64 * PRICE_PER_LAND_VALUE
+}
+
+/// Winning developer bid for a parcel at auction.
+pub fn auction_price(land_value: u8) -> f64 {
+    let prime = land_value as f64 / 255.0;
+    market_price(land_value) * (AUCTION_BASE_MULTIPLIER + AUCTION_PRIME_BONUS * prime)
+}
+
+/// Monthly rent for a leased parcel.
+pub fn lease_rent(land_value: u8) -> f64 {
+    market_price(land_value) * LEASE_RATE
+}
+
+/// Cost of taking a parcel by eminent domain: market price plus the premium,
+/// and compensation for any building on it.
+pub fn seizure_price(land_value: u8, building_level: u8) -> f64 {
+    market_price(land_value) * (1.0 + EMINENT_DOMAIN_PREMIUM)
+        + building_level as f64 * COMPENSATION_PER_LEVEL
+}
+
+// ---------------------------------------------------------------------------
+// Ownership
+// ---------------------------------------------------------------------------
+
+/// Who owns a grid cell.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum ParcelOwner {
+    /// Privately held; developers build as zoning and demand allow.
+    #[default]
+    Private,
+    /// Held by the city; no private development.
+    City,
+    /// City-owned but leased out; developers may build and the city collects
+    /// rent.
+    Leased,
+    /// Auctioned to a developer. The city can no longer rezone it and must
+    /// use eminent domain to buy it back.
+    Developer,
+}
+
+impl ParcelOwner {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Private => "Private",
+            Self::City => "City",
+            Self::Leased => "City (leased)",
+            Self::Developer => "Developer",
+        }
+    }
+
+    /// Whether the city owns the parcel.
+    pub fn is_city(self) -> bool {
+        matches!(self, Self::City | Self::Leased)
+    }
+}
+
+/// Action the player takes on a parcel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LandActionKind {
+    /// Buy a private parcel; built or developer-owned parcels are taken by
+    /// eminent domain.
+    Buy,
+    /// Lease a city parcel to developers.
+    Lease,
+    /// Take a leased parcel back while it is still vacant.
+    EndLease,
+    /// Sell a city parcel to the highest developer bid.
+    Auction,
+}
+
+/// Request to buy, lease or auction a parcel.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LandAction {
+    pub grid_x: usize,
+    pub grid_y: usize,
+    pub kind: LandActionKind,
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Per-cell land ownership and the city's land dealings.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct LandOwnership {
+    /// One entry per grid cell; empty while every parcel is private.
+    pub owners: Vec<ParcelOwner>,
+    /// Happiness points currently lost city-wide over seizures.
+    pub grievance: f32,
+    /// Parcels taken by eminent domain.
+    pub seizures: u32,
+    /// Totals spent buying and raised auctioning land.
+    pub total_spent: f64,
+    pub total_auctioned: f64,
+    /// Rent collected at the last lease collection.
+    pub last_lease_income: f64,
+    pub last_lease_day: u32,
+}
+
+impl LandOwnership {
+    pub fn owner(&self, x: usize, y: usize) -> ParcelOwner {
+        self.owners
+            .get(y * GRID_WIDTH + x)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set_owner(&mut self, x: usize, y: usize, owner: ParcelOwner) {
+        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+            return;
+        }
+        if self.owners.is_empty() {
+            if owner == ParcelOwner::Private {
+                return;
+            }
+            self.owners = vec![ParcelOwner::Private; GRID_WIDTH * GRID_HEIGHT];
+        }
+        self.owners[y * GRID_WIDTH + x] = owner;
+    }
+
+    /// Whether developers may build on the parcel.
+    pub fn allows_private_development(&self, x: usize, y: usize) -> bool {
+        self.owner(x, y) != ParcelOwner::City
+    }
+
+    /// Whether the player may change the parcel's zoning.
+    pub fn allows_rezoning(&self, x: usize, y: usize) -> bool {
+        self.owner(x, y) != ParcelOwner::Developer
+    }
+
+    /// Parcels currently owned by the city, leased or not.
+    pub fn city_parcels(&self) -> usize {
+        self.owners.iter().filter(|o| o.is_city()).count()
+    }
+
+    /// Leased parcels as (x, y) cells.
+    pub fn leased_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.owners
+            .iter()
+            .enumerate()
+            .filter(|(_, &o)| o == ParcelOwner::Leased)
+            .map(|(i, _)| (i % GRID_WIDTH, i / GRID_WIDTH))
+    }
+
+    /// Add the grievance from one seizure.
+    pub fn add_grievance(&mut self) {
+        self.grievance = (self.grievance + SEIZURE_GRIEVANCE).min(MAX_GRIEVANCE);
+    }
+
+    fn is_default(&self) -> bool {
+        self.owners.iter().all(|&o| o == ParcelOwner::Private)
+            && self.grievance == 0.0
+            && self.seizures == 0
+            && self.total_spent == 0.0
+            && self.total_auctioned == 0.0
+    }
+}
+
+impl Saveable for LandOwnership {
+    const SAVE_KEY: &'static str = "land_ownership";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.is_default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -1,5 +1,8 @@
+use crate::chunked_grid::ChunkedGrid;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::dirty_chunks::{DirtyChunkCursor, DirtyChunks, DirtyRegion};
 use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::grid_parallel::par_rows_mut;
 use crate::pollution::PollutionGrid;
 use crate::services::ServiceBuilding;
 use crate::urban_growth_boundary::UrbanGrowthBoundary;
@@ -15,9 +18,9 @@ const SMOOTHING_ALPHA: f32 = 0.1;
 /// With 0.02 per neighbour, self retains 84 % of its value.
 const DIFFUSION_WEIGHT: f32 = 0.02;
 
-#[derive(Resource, bitcode::Encode, bitcode::Decode)]
+#[derive(Resource)]
 pub struct LandValueGrid {
-    pub values: Vec<u8>,
+    pub values: ChunkedGrid<u8>,
     pub width: usize,
     pub height: usize,
 }
@@ -25,7 +28,7 @@ pub struct LandValueGrid {
 impl Default for LandValueGrid {
     fn default() -> Self {
         Self {
-            values: vec![50; GRID_WIDTH * GRID_HEIGHT], // start at baseline 50
+            values: ChunkedGrid::new(GRID_WIDTH, GRID_HEIGHT, 50), // start at baseline 50
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
         }
@@ -34,10 +37,10 @@ impl Default for LandValueGrid {
 
 impl LandValueGrid {
     pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.values[y * self.width + x]
+        *self.values.get(x, y)
     }
     pub fn set(&mut self, x: usize, y: usize, val: u8) {
-        self.values[y * self.width + x] = val;
+        *self.values.get_mut(x, y) = val;
     }
     pub fn average(&self) -> f32 {
         if self.values.is_empty() {
@@ -52,17 +55,136 @@ impl LandValueGrid {
 // Saveable implementation — persists land values across save / load
 // ---------------------------------------------------------------------------
 
+/// On-disk layout: row-major values, independent of chunked storage.
+#[derive(bitcode::Encode, bitcode::Decode)]
+struct LandValueSave {
+    values: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl Default for LandValueSave {
+    fn default() -> Self {
+        let grid = LandValueGrid::default();
+        Self {
+            values: grid.values.to_row_major(),
+            width: grid.width,
+            height: grid.height,
+        }
+    }
+}
+
 impl crate::Saveable for LandValueGrid {
     const SAVE_KEY: &'static str = "land_value";
 
     fn save_to_bytes(&self) -> Option<Vec<u8>> {
         // Always save — the grid is large and we can't cheaply detect
         // "still at default".
-        Some(bitcode::encode(self))
+        Some(bitcode::encode(&LandValueSave {
+            values: self.values.to_row_major(),
+            width: self.width,
+            height: self.height,
+        }))
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
-        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+        let save: LandValueSave = crate::decode_or_warn(Self::SAVE_KEY, bytes);
+        Self {
+            values: ChunkedGrid::from_row_major(save.width, save.height, save.values),
+            width: save.width,
+            height: save.height,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dirty-region bookkeeping
+// ---------------------------------------------------------------------------
+
+/// Slow passes between full recomputes. Inputs without per-chunk change
+/// tracking (waste, the growth boundary) are picked up at least this often.
+const FULL_REFRESH_PASSES: u32 = 8;
+
+/// Farthest a layout change can move a land value: the park boost radius,
+/// the water-adjacency check and one diffusion step.
+const LAYOUT_REACH: usize = 10;
+
+/// Which chunks the next pass has to recompute. Besides chunks near layout
+/// changes, that is every chunk whose pollution moved, that another system
+/// wrote to, or whose values have not settled yet, plus their neighbours
+/// (diffusion reads one cell across chunk borders). Everything else sits at
+/// its fixed point and would come out unchanged.
+#[derive(Default)]
+pub struct LandValueDirtyState {
+    cursor: DirtyChunkCursor,
+    /// Pollution chunk revisions seen by the last pass.
+    pollution_seen: Vec<u32>,
+    /// Land value chunk revisions left by the last pass.
+    written: Vec<u32>,
+    /// Chunks whose values changed in the last pass.
+    unsettled: Vec<bool>,
+    passes_since_full: u32,
+}
+
+impl LandValueDirtyState {
+    fn region(
+        &mut self,
+        dirty_chunks: &DirtyChunks,
+        land_value: &LandValueGrid,
+        pollution: &PollutionGrid,
+    ) -> DirtyRegion {
+        let mut region = dirty_chunks.take(&mut self.cursor);
+        let chunk_count = land_value.values.chunk_count();
+        self.passes_since_full += 1;
+        if region.is_full()
+            || self.passes_since_full >= FULL_REFRESH_PASSES
+            || region.width() != land_value.width
+            || region.height() != land_value.height
+            || pollution.levels.chunk_count() != chunk_count
+            || self.written.len() != chunk_count
+        {
+            return DirtyRegion::full(land_value.width, land_value.height);
+        }
+        region.dilate(LAYOUT_REACH);
+
+        let mut moved = DirtyRegion::empty(region.width(), region.height());
+        for ci in 0..chunk_count {
+            if self.unsettled[ci]
+                || self.written[ci] != land_value.values.chunk_revision(ci)
+                || self.pollution_seen[ci] != pollution.levels.chunk_revision(ci)
+            {
+                moved.mark_chunk(ci);
+            }
+        }
+        moved.dilate(1);
+        region.union(&moved);
+        region
+    }
+
+    /// Record what a pass over `region` left behind.
+    fn finish(
+        &mut self,
+        region: &DirtyRegion,
+        changed: Vec<bool>,
+        land_value: &LandValueGrid,
+        pollution: &PollutionGrid,
+    ) {
+        if region.is_full() {
+            self.passes_since_full = 0;
+        }
+        let chunk_count = land_value.values.chunk_count();
+        self.unsettled = changed;
+        self.written = (0..chunk_count)
+            .map(|ci| land_value.values.chunk_revision(ci))
+            .collect();
+        self.pollution_seen = (0..pollution.levels.chunk_count())
+            .map(|ci| pollution.levels.chunk_revision(ci))
+            .collect();
+    }
+
+    /// Forget everything so the next pass recomputes the whole grid.
+    fn reset(&mut self) {
+        self.written.clear();
     }
 }
 
@@ -80,18 +202,39 @@ pub fn update_land_value(
     waste_collection: Res<crate::garbage::WasteCollectionGrid>,
     waste_accumulation: Res<crate::waste_effects::WasteAccumulation>,
     ugb: Res<UrbanGrowthBoundary>,
+    dirty_chunks: Res<DirtyChunks>,
+    mut dirty_state: Local<LandValueDirtyState>,
 ) {
     if !slow_timer.should_run() {
         return;
     }
 
+    if land_value.is_added() || pollution.is_added() || ugb.is_changed() {
+        dirty_state.reset();
+    }
+    let region = dirty_state.region(&dirty_chunks, &land_value, &pollution);
+    if region.is_empty() {
+        let settled = vec![false; region.chunk_count()];
+        dirty_state.finish(&region, settled, &land_value, &pollution);
+        return;
+    }
+    // Diffusion of a cell in the region reads its neighbours' smoothed
+    // values, so those are computed one chunk further out.
+    let mut computed = region.clone();
+    computed.dilate(1);
+
     let total = GRID_WIDTH * GRID_HEIGHT;
+    let grid = &*grid;
+    let pollution_grid = &*pollution;
+    let waste_collection = &*waste_collection;
+    let waste_accumulation = &*waste_accumulation;
+    let ugb = &*ugb;
 
     // ---- Phase 1: compute raw "target" value per cell -----------------------
     let mut target = vec![0i32; total];
 
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
+    par_rows_mut(&mut target, GRID_WIDTH, |y, row| {
+        for x in computed.row_spans(y).flatten() {
             let cell = grid.get(x, y);
             let mut value: i32 = 50;
 
@@ -115,7 +258,7 @@ pub fn update_land_value(
             }
 
             // Pollution reduces value
-            let poll = pollution.get(x, y) as i32;
+            let poll = pollution_grid.get(x, y) as i32;
             value -= poll / 3;
 
             // Uncollected waste reduces land value (WASTE-003: -10% penalty).
@@ -128,7 +271,7 @@ pub fn update_land_value(
 
             // Accumulated waste reduces land value (WASTE-010: -20% if nearby > 500 lbs).
             let waste_modifier =
-                crate::waste_effects::waste_land_value_modifier(&waste_accumulation, x, y);
+                crate::waste_effects::waste_land_value_modifier(waste_accumulation, x, y);
             if waste_modifier < 1.0 {
                 value = (value as f32 * waste_modifier) as i32;
             }
@@ -136,9 +279,9 @@ pub fn update_land_value(
             // Urban Growth Boundary: premium inside, penalty outside (ZONE-009).
             value += ugb.land_value_modifier(x, y);
 
-            target[y * GRID_WIDTH + x] = value.clamp(0, 255);
+            row[x] = value.clamp(0, 255);
         }
-    }
+    });
 
     // Parks and services boost target values in radius
     for service in &services {
@@ -164,22 +307,25 @@ pub fn update_land_value(
 
     // ---- Phase 2: exponential smoothing toward targets ----------------------
     // new = alpha * target + (1 - alpha) * previous
-    for (cell, &tgt) in land_value.values.iter_mut().zip(target.iter()) {
-        let prev = *cell as f32;
-        let smoothed = SMOOTHING_ALPHA * tgt as f32 + (1.0 - SMOOTHING_ALPHA) * prev;
-        *cell = smoothed.round().clamp(0.0, 255.0) as u8;
-    }
+    let mut smoothed = vec![0u8; total];
+    let previous = &land_value.values;
+    par_rows_mut(&mut smoothed, GRID_WIDTH, |y, row| {
+        for x in computed.row_spans(y).flatten() {
+            let prev = *previous.get(x, y) as f32;
+            let tgt = target[y * GRID_WIDTH + x] as f32;
+            let value = SMOOTHING_ALPHA * tgt + (1.0 - SMOOTHING_ALPHA) * prev;
+            row[x] = value.round().clamp(0.0, 255.0) as u8;
+        }
+    });
 
     // ---- Phase 3: neighbourhood diffusion -----------------------------------
     // Each cell blends slightly with its 8 neighbours.
-    // We read from a snapshot so writes don't cascade within one tick.
-    let snapshot = land_value.values.clone();
+    // We read from the smoothed values so writes don't cascade within one tick.
     let self_weight = 1.0 - 8.0 * DIFFUSION_WEIGHT;
-
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            let idx = y * GRID_WIDTH + x;
-            let mut sum = snapshot[idx] as f32 * self_weight;
+    let mut diffused = vec![0u8; total];
+    par_rows_mut(&mut diffused, GRID_WIDTH, |y, row| {
+        for x in region.row_spans(y).flatten() {
+            let mut sum = smoothed[y * GRID_WIDTH + x] as f32 * self_weight;
 
             for dy in -1i32..=1 {
                 for dx in -1i32..=1 {
@@ -193,16 +339,33 @@ pub fn update_land_value(
                         && (nx as usize) < GRID_WIDTH
                         && (ny as usize) < GRID_HEIGHT
                     {
-                        sum += snapshot[ny as usize * GRID_WIDTH + nx as usize] as f32
-                            * DIFFUSION_WEIGHT;
+                        let idx = ny as usize * GRID_WIDTH + nx as usize;
+                        sum += smoothed[idx] as f32 * DIFFUSION_WEIGHT;
                     }
                     // Out-of-bounds neighbours contribute 0 (natural boundary).
                 }
             }
 
-            land_value.values[idx] = sum.round().clamp(0.0, 255.0) as u8;
+            row[x] = sum.round().clamp(0.0, 255.0) as u8;
+        }
+    });
+
+    // Write back only the cells that moved, so settled chunks keep their
+    // revisions.
+    let mut changed = vec![false; land_value.values.chunk_count()];
+    for ci in region.chunks() {
+        let (x0, y0, x1, y1) = region.chunk_bounds(ci);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let value = diffused[y * GRID_WIDTH + x];
+                if *land_value.values.get(x, y) != value {
+                    *land_value.values.get_mut(x, y) = value;
+                    changed[ci] = true;
+                }
+            }
         }
     }
+    dirty_state.finish(&region, changed, &land_value, &pollution);
 }
 
 pub struct LandValuePlugin;
@@ -211,9 +374,7 @@ impl Plugin for LandValuePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LandValueGrid>().add_systems(
             FixedUpdate,
-            update_land_value
-                .after(crate::wind_pollution::update_pollution_gaussian_plume)
-                .in_set(crate::SimulationSet::Simulation),
+            update_land_value.in_set(crate::GridPropagationSet::Exposure),
         );
 
         // Register for save/load via the SaveableRegistry
@@ -4,7 +4,7 @@ use std::collections::BTreeMap;
 pub use app_state::AppState;
 pub use pre_load_app_state::PreLoadAppState;
 pub use save_load_state::SaveLoadState;
-pub use simulation_sets::{SimulationSet, SimulationUpdateSet};
+pub use simulation_sets::{GridPropagationSet, SimulationSet, SimulationUpdateSet};
 
 // Auto-discover all public modules from src/ directory.
 // Modules that need special attributes (cfg-gated, private) are declared manually below.
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::economy::CityBudget;
@@ -49,7 +50,7 @@ impl Loan {
 // Loan tiers
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
 pub enum LoanTier {
     Small,
     Medium,
@@ -138,13 +139,24 @@ impl LoanBook {
     /// Try to take a loan of the given tier. Returns `true` on success.
     /// The loan amount is added to the treasury immediately.
     pub fn take_loan(&mut self, tier: LoanTier, treasury: &mut f64) -> bool {
+        self.take_loan_with_rate(tier, treasury, 1.0)
+    }
+
+    /// Like [`take_loan`](Self::take_loan), with the tier's interest rate
+    /// scaled by `rate_multiplier` (the difficulty's loan rate).
+    pub fn take_loan_with_rate(
+        &mut self,
+        tier: LoanTier,
+        treasury: &mut f64,
+        rate_multiplier: f64,
+    ) -> bool {
         if self.active_loans.len() >= self.max_loans {
             return false;
         }
         let loan = Loan::new(
             tier.name().to_string(),
             tier.amount(),
-            tier.interest_rate(),
+            tier.interest_rate() * rate_multiplier,
             tier.term_months(),
         );
         *treasury += loan.amount;
@@ -148,6 +148,8 @@ pub fn update_spatial_grid(
     mut spatial: ResMut<SpatialGrid>,
     citizens: Query<(Entity, &Position), With<Citizen>>,
 ) {
+    #[cfg(feature = "trace")]
+    let _span = bevy::log::info_span!("update_spatial_grid").entered();
     spatial.clear();
     for (entity, pos) in &citizens {
         spatial.insert(entity, pos.x, pos.y);
@@ -214,6 +216,8 @@ pub fn assign_lod_tiers(
     _spatial: Res<SpatialGrid>,
     mut citizens: Query<(Entity, &Position, &mut LodTier), With<Citizen>>,
 ) {
+    #[cfg(feature = "trace")]
+    let _span = bevy::log::info_span!("assign_lod_tiers").entered();
     let margins = LodMargins::new();
 
     for (_entity, pos, mut tier) in &mut citizens {
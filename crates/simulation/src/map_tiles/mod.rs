@@ -0,0 +1,15 @@
+//! Map tiles bought during play.
+//!
+//! A new city starts on the central tiles of the map and buys neighbouring
+//! tiles as it grows. Each milestone tier raises how many tiles the city may
+//! own, and a tile's price rises with the average land value of its cells.
+//! Roads, zones and buildings can only be placed on owned tiles, and the
+//! terrain shades the tiles the city does not own yet.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{handle_tile_purchases, purchase_blocker, tile_land_value, MapTilesPlugin};
+pub use types::*;
@@ -0,0 +1,125 @@
+//! Tile purchases.
+
+use bevy::prelude::*;
+
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::land_value::LandValueGrid;
+use crate::milestones::{MilestoneProgress, MilestoneTier};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+
+use super::types::*;
+
+/// Average land value over the cells of a tile.
+pub fn tile_land_value(tiles: &MapTiles, land_value: &LandValueGrid, tx: usize, ty: usize) -> f32 {
+    let (x0, y0, x1, y1) = tiles.tile_bounds(tx, ty);
+    let cells = (x1 - x0) * (y1 - y0);
+    if cells == 0 {
+        return 0.0;
+    }
+    let total: u32 = (y0..y1)
+        .flat_map(|y| (x0..x1).map(move |x| (x, y)))
+        .map(|(x, y)| land_value.get(x, y) as u32)
+        .sum();
+    total as f32 / cells as f32
+}
+
+/// Why a tile cannot be bought at milestone `tier`, if anything stands in
+/// the way other than money.
+pub fn purchase_blocker(
+    tiles: &MapTiles,
+    tx: usize,
+    ty: usize,
+    tier: MilestoneTier,
+) -> Option<String> {
+    if tx >= TILES_PER_SIDE || ty >= TILES_PER_SIDE {
+        return Some("There is no such map tile.".to_string());
+    }
+    if tiles.is_owned(tx, ty) {
+        return Some("The city already owns this tile.".to_string());
+    }
+    if !tiles.is_adjacent_to_owned(tx, ty) {
+        return Some("Only tiles bordering the city can be bought.".to_string());
+    }
+    if tiles.owned_count() >= max_owned_tiles(tier) {
+        let next = MilestoneTier::ALL
+            .iter()
+            .find(|t| max_owned_tiles(**t) > tiles.owned_count());
+        return Some(match next {
+            Some(t) => format!("Reach the {} milestone to buy another tile.", t.name()),
+            None => "No more tiles can be bought.".to_string(),
+        });
+    }
+    None
+}
+
+/// Buy one tile. Returns the notification text on success and the reason
+/// on failure.
+fn apply_tile_purchase(
+    purchase: &TilePurchase,
+    tiles: &mut MapTiles,
+    land_value: &LandValueGrid,
+    tier: MilestoneTier,
+    budget: &mut CityBudget,
+) -> Result<String, String> {
+    let (tx, ty) = (purchase.tile_x, purchase.tile_y);
+    if let Some(reason) = purchase_blocker(tiles, tx, ty, tier) {
+        return Err(reason);
+    }
+    let price = tile_price(tile_land_value(tiles, land_value, tx, ty));
+    if budget.treasury < price {
+        return Err(format!(
+            "Buying this tile needs ${price:.0}, treasury has ${:.0}.",
+            budget.treasury
+        ));
+    }
+    budget.treasury -= price;
+    tiles.buy(tx, ty, price);
+    Ok(format!("Bought map tile ({tx}, {ty}) for ${price:.0}."))
+}
+
+/// Handle tile purchases requested from the UI.
+pub fn handle_tile_purchases(
+    mut events: EventReader<TilePurchase>,
+    mut tiles: ResMut<MapTiles>,
+    land_value: Res<LandValueGrid>,
+    progress: Res<MilestoneProgress>,
+    mut budget: ResMut<CityBudget>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for purchase in events.read() {
+        let result = apply_tile_purchase(
+            purchase,
+            &mut tiles,
+            &land_value,
+            progress.current_tier,
+            &mut budget,
+        );
+        let (text, priority) = match result {
+            Ok(text) => (text, NotificationPriority::Info),
+            Err(text) => (text, NotificationPriority::Attention),
+        };
+        let (x0, y0, x1, y1) = tiles.tile_bounds(purchase.tile_x, purchase.tile_y);
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Finance,
+            location: Some(WorldGrid::grid_to_world((x0 + x1) / 2, (y0 + y1) / 2)),
+        });
+    }
+}
+
+pub struct MapTilesPlugin;
+
+impl Plugin for MapTilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapTiles>()
+            .add_event::<TilePurchase>()
+            .add_systems(Update, handle_tile_purchases);
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<MapTiles>();
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::milestones::MilestoneTier;
+use crate::Saveable;
+
+#[test]
+fn test_default_leaves_the_map_open() {
+    let tiles = MapTiles::default();
+    assert!(!tiles.is_restricted());
+    assert!(tiles.contains(0, 0));
+    assert!(tiles.contains(GRID_WIDTH - 1, GRID_HEIGHT - 1));
+    assert_eq!(tiles.owned_count(), TILES_PER_SIDE * TILES_PER_SIDE);
+    assert!(tiles.save_to_bytes().is_none());
+}
+
+#[test]
+fn test_new_city_owns_the_central_tiles() {
+    let tiles = MapTiles::starting(256, 256);
+    assert!(tiles.is_restricted());
+    assert_eq!(tiles.owned_count(), STARTING_TILES);
+    assert_eq!(tiles.tile_size(), (32, 32));
+    assert!(tiles.contains(128, 128));
+    assert!(tiles.contains(96, 96));
+    assert!(!tiles.contains(95, 128));
+    assert!(!tiles.contains(0, 0));
+    assert!(tiles.contains_footprint(126, 126, 4, 4));
+    assert!(!tiles.contains_footprint(158, 126, 3, 3));
+}
+
+#[test]
+fn test_buying_extends_the_owned_area() {
+    let mut tiles = MapTiles::starting(256, 256);
+    assert!(tiles.is_adjacent_to_owned(2, 3));
+    assert!(!tiles.is_adjacent_to_owned(0, 0));
+    assert!(!tiles.is_adjacent_to_owned(2, 2));
+
+    tiles.buy(2, 3, 50_000.0);
+    assert!(tiles.is_owned(2, 3));
+    assert!(tiles.contains(64, 96));
+    assert_eq!(tiles.purchased, 1);
+    assert_eq!(tiles.total_spent, 50_000.0);
+
+    let restored = MapTiles::load_from_bytes(&tiles.save_to_bytes().unwrap());
+    assert_eq!(restored, tiles);
+}
+
+#[test]
+fn test_owning_every_tile_opens_the_map() {
+    let mut tiles = MapTiles::starting(256, 256);
+    for ty in 0..TILES_PER_SIDE {
+        for tx in 0..TILES_PER_SIDE {
+            tiles.buy(tx, ty, 1.0);
+        }
+    }
+    assert!(!tiles.is_restricted());
+    assert_eq!(
+        tiles.purchased as usize,
+        TILES_PER_SIDE * TILES_PER_SIDE - STARTING_TILES
+    );
+}
+
+#[test]
+fn test_milestones_raise_the_allowance() {
+    assert_eq!(max_owned_tiles(MilestoneTier::Hamlet), STARTING_TILES);
+    let mut last = 0;
+    for &tier in MilestoneTier::ALL {
+        assert!(max_owned_tiles(tier) >= last);
+        last = max_owned_tiles(tier);
+    }
+    assert_eq!(
+        max_owned_tiles(MilestoneTier::Megalopolis),
+        TILES_PER_SIDE * TILES_PER_SIDE
+    );
+}
+
+#[test]
+fn test_purchase_blockers() {
+    let tiles = MapTiles::starting(256, 256);
+    assert!(purchase_blocker(&tiles, 3, 3, MilestoneTier::Town).is_some());
+    assert!(purchase_blocker(&tiles, 0, 0, MilestoneTier::Town).is_some());
+    assert!(purchase_blocker(&tiles, 2, 3, MilestoneTier::Hamlet).is_some());
+    assert!(purchase_blocker(&tiles, 2, 3, MilestoneTier::SmallSettlement).is_none());
+    assert!(tile_price(200.0) > tile_price(50.0));
+}
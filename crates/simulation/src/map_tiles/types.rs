@@ -0,0 +1,213 @@
+//! Map tiles, their prices and the milestone allowance.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::milestones::MilestoneTier;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// The map is divided into this many tiles along each side.
+pub const TILES_PER_SIDE: usize = 8;
+
+/// Side of the square of tiles a new city starts with, centred on the map.
+pub const STARTING_TILES_PER_SIDE: usize = 2;
+
+/// Tiles owned at the start of a new game.
+pub const STARTING_TILES: usize = STARTING_TILES_PER_SIDE * STARTING_TILES_PER_SIDE;
+
+/// Extra tiles the city may own for each milestone tier reached.
+pub const TILES_PER_MILESTONE: usize = 5;
+
+/// Price of a tile with no land value.
+pub const TILE_BASE_PRICE: f64 = 40_000.0;
+
+/// Price added per point of the tile's average land value.
+pub const TILE_PRICE_PER_LAND_VALUE: f64 = 1_500.0;
+
+/// Price of a tile whose cells average `land_value`.
+pub fn tile_price(average_land_value: f32) -> f64 {
+    TILE_BASE_PRICE + average_land_value.max(0.0) as f64 * TILE_PRICE_PER_LAND_VALUE
+}
+
+/// Most tiles the city may own at milestone `tier`; the final tier opens
+/// the whole map.
+pub fn max_owned_tiles(tier: MilestoneTier) -> usize {
+    if tier.next().is_none() {
+        return TILES_PER_SIDE * TILES_PER_SIDE;
+    }
+    (STARTING_TILES + TILES_PER_MILESTONE * tier.index()).min(TILES_PER_SIDE * TILES_PER_SIDE)
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Request to buy the map tile at (`tile_x`, `tile_y`).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TilePurchase {
+    pub tile_x: usize,
+    pub tile_y: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Which parts of the map the city may build on.
+///
+/// The grid is split into `TILES_PER_SIDE` x `TILES_PER_SIDE` tiles. A new
+/// city starts with the central tiles and buys neighbouring ones as it
+/// grows. While `owned` is empty the whole map is open, which is how cities
+/// from before tiles, and scenarios, play.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct MapTiles {
+    /// One entry per tile, row-major; empty while the whole map is open.
+    pub owned: Vec<bool>,
+    /// Grid size in cells the tiles divide.
+    pub width: usize,
+    pub height: usize,
+    /// Tiles bought during play.
+    pub purchased: u32,
+    pub total_spent: f64,
+}
+
+impl Default for MapTiles {
+    fn default() -> Self {
+        Self {
+            owned: Vec::new(),
+            width: GRID_WIDTH,
+            height: GRID_HEIGHT,
+            purchased: 0,
+            total_spent: 0.0,
+        }
+    }
+}
+
+impl MapTiles {
+    /// Tiles for a new city on a `width` x `height` grid: only the central
+    /// `STARTING_TILES_PER_SIDE` square is owned.
+    pub fn starting(width: usize, height: usize) -> Self {
+        let mut tiles = Self {
+            owned: vec![false; TILES_PER_SIDE * TILES_PER_SIDE],
+            width,
+            height,
+            ..Self::default()
+        };
+        let first = (TILES_PER_SIDE - STARTING_TILES_PER_SIDE) / 2;
+        for ty in first..first + STARTING_TILES_PER_SIDE {
+            for tx in first..first + STARTING_TILES_PER_SIDE {
+                tiles.owned[ty * TILES_PER_SIDE + tx] = true;
+            }
+        }
+        tiles
+    }
+
+    /// Whether building is limited to owned tiles.
+    pub fn is_restricted(&self) -> bool {
+        !self.owned.is_empty()
+    }
+
+    /// Cells along each side of a tile.
+    pub fn tile_size(&self) -> (usize, usize) {
+        (
+            self.width.div_ceil(TILES_PER_SIDE).max(1),
+            self.height.div_ceil(TILES_PER_SIDE).max(1),
+        )
+    }
+
+    /// The tile holding cell (`x`, `y`).
+    pub fn tile_of(&self, x: usize, y: usize) -> (usize, usize) {
+        let (tw, th) = self.tile_size();
+        (
+            (x / tw).min(TILES_PER_SIDE - 1),
+            (y / th).min(TILES_PER_SIDE - 1),
+        )
+    }
+
+    /// Cell bounds of a tile: `(x0, y0, x1, y1)`, end-exclusive.
+    pub fn tile_bounds(&self, tx: usize, ty: usize) -> (usize, usize, usize, usize) {
+        let (tw, th) = self.tile_size();
+        let (x0, y0) = ((tx * tw).min(self.width), (ty * th).min(self.height));
+        (
+            x0,
+            y0,
+            (x0 + tw).min(self.width),
+            (y0 + th).min(self.height),
+        )
+    }
+
+    pub fn is_owned(&self, tx: usize, ty: usize) -> bool {
+        if tx >= TILES_PER_SIDE || ty >= TILES_PER_SIDE {
+            return false;
+        }
+        !self.is_restricted() || self.owned[ty * TILES_PER_SIDE + tx]
+    }
+
+    /// Whether cell (`x`, `y`) lies in an owned tile.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        if !self.is_restricted() {
+            return true;
+        }
+        let (tx, ty) = self.tile_of(x, y);
+        self.is_owned(tx, ty)
+    }
+
+    /// Whether every cell of the `w` x `h` footprint at (`x`, `y`) lies in
+    /// an owned tile.
+    pub fn contains_footprint(&self, x: usize, y: usize, w: usize, h: usize) -> bool {
+        (y..y + h.max(1)).all(|fy| (x..x + w.max(1)).all(|fx| self.contains(fx, fy)))
+    }
+
+    pub fn owned_count(&self) -> usize {
+        if !self.is_restricted() {
+            return TILES_PER_SIDE * TILES_PER_SIDE;
+        }
+        self.owned.iter().filter(|&&o| o).count()
+    }
+
+    /// Whether an unowned tile borders an owned one.
+    pub fn is_adjacent_to_owned(&self, tx: usize, ty: usize) -> bool {
+        let neighbours = [
+            (tx.wrapping_sub(1), ty),
+            (tx + 1, ty),
+            (tx, ty.wrapping_sub(1)),
+            (tx, ty + 1),
+        ];
+        neighbours.iter().any(|&(nx, ny)| self.is_owned(nx, ny))
+    }
+
+    /// Mark a tile owned and record what was paid for it.
+    pub fn buy(&mut self, tx: usize, ty: usize, price: f64) {
+        if !self.is_restricted() || self.is_owned(tx, ty) {
+            return;
+        }
+        self.owned[ty * TILES_PER_SIDE + tx] = true;
+        self.purchased += 1;
+        self.total_spent += price;
+        // Once every tile is owned the map is simply open.
+        if self.owned.iter().all(|&o| o) {
+            self.owned.clear();
+        }
+    }
+}
+
+impl Saveable for MapTiles {
+    const SAVE_KEY: &'static str = "map_tiles";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
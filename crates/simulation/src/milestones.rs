@@ -2,13 +2,14 @@
 //!
 //! Defines 12 milestone tiers gated by population. Each tier automatically
 //! unlocks a set of `UnlockNode`s when the city reaches the required
-//! population. Progress toward the next milestone and notification events
-//! are provided for UI display.
+//! population and awards development points to spend on the branch points
+//! of the unlock tree. Progress toward the next milestone and notification
+//! events are provided for UI display.
 
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 
-use crate::notifications::{NotificationEvent, NotificationPriority};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::stats::CityStats;
 use crate::unlocks::{UnlockNode, UnlockState};
 use crate::SlowTickTimer;
@@ -321,6 +322,7 @@ pub fn check_milestone_progression(
                     .join(", ")
             ),
             priority: NotificationPriority::Positive,
+            category: NotificationCategory::Achievement,
             location: None,
         });
     }
@@ -0,0 +1,203 @@
+//! Working out which mods load, and in what order.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use super::manifest::{InstalledMod, ModVersion};
+
+/// Owner shown for save keys the base game uses.
+pub const BASE_GAME: &str = "base game";
+
+/// Something wrong with the installed mods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModIssue {
+    /// A manifest that couldn't be read.
+    InvalidManifest(String),
+    /// Two mod folders declare the same id; only the first is used.
+    DuplicateId(String),
+    /// `mod_id` needs a mod that isn't installed, or is disabled or can't
+    /// load itself. `mod_id` isn't loaded.
+    MissingDependency {
+        mod_id: String,
+        dependency: String,
+        required: ModVersion,
+    },
+    /// `mod_id` needs a version of `dependency` other than the installed one.
+    /// `mod_id` isn't loaded.
+    IncompatibleDependency {
+        mod_id: String,
+        dependency: String,
+        required: ModVersion,
+        found: ModVersion,
+    },
+    /// These mods depend on each other in a loop; none of them is loaded.
+    DependencyCycle(Vec<String>),
+    /// Several owners (mods or the base game) use the same save key, so
+    /// they would overwrite each other's data.
+    SaveKeyConflict { key: String, owners: Vec<String> },
+}
+
+impl fmt::Display for ModIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModIssue::InvalidManifest(error) => write!(f, "Invalid manifest: {error}"),
+            ModIssue::DuplicateId(id) => {
+                write!(f, "Several mods use the id '{id}'; only the first is used")
+            }
+            ModIssue::MissingDependency {
+                mod_id,
+                dependency,
+                required,
+            } => write!(
+                f,
+                "'{mod_id}' needs '{dependency}' {required} or newer, which is not available"
+            ),
+            ModIssue::IncompatibleDependency {
+                mod_id,
+                dependency,
+                required,
+                found,
+            } => write!(
+                f,
+                "'{mod_id}' needs '{dependency}' {required} or a newer {}.x, \
+                 but {found} is installed",
+                required.major
+            ),
+            ModIssue::DependencyCycle(ids) => {
+                write!(f, "Circular dependencies between {}", ids.join(", "))
+            }
+            ModIssue::SaveKeyConflict { key, owners } => {
+                write!(f, "Save key '{key}' is used by {}", owners.join(" and "))
+            }
+        }
+    }
+}
+
+/// Work out the load order of the installed mods not in `disabled`.
+///
+/// A mod loads after everything it depends on. Mods whose dependencies are
+/// missing, incompatible or in a loop are left out, and so is anything that
+/// depends on them. Otherwise mods load in id order, so the result doesn't
+/// depend on folder names. Returns the ids in load order and the issues
+/// found.
+pub fn resolve_load_order(
+    installed: &[InstalledMod],
+    disabled: &[String],
+) -> (Vec<String>, Vec<ModIssue>) {
+    let mut issues = Vec::new();
+    let mut seen = BTreeSet::new();
+    let mut candidates = BTreeMap::new();
+    for installed_mod in installed {
+        let manifest = &installed_mod.manifest;
+        if !seen.insert(manifest.id.as_str()) {
+            issues.push(ModIssue::DuplicateId(manifest.id.clone()));
+        } else if !disabled.contains(&manifest.id) {
+            candidates.insert(manifest.id.as_str(), manifest);
+        }
+    }
+
+    // Drop mods whose dependencies can't be met, until nothing changes: a
+    // dropped mod may be someone else's dependency.
+    loop {
+        let mut dropped = Vec::new();
+        for (&id, manifest) in &candidates {
+            for dep in &manifest.dependencies {
+                let issue = match candidates.get(dep.id.as_str()) {
+                    None => ModIssue::MissingDependency {
+                        mod_id: id.to_string(),
+                        dependency: dep.id.clone(),
+                        required: dep.version,
+                    },
+                    Some(found) if !found.version.satisfies(dep.version) => {
+                        ModIssue::IncompatibleDependency {
+                            mod_id: id.to_string(),
+                            dependency: dep.id.clone(),
+                            required: dep.version,
+                            found: found.version,
+                        }
+                    }
+                    Some(_) => continue,
+                };
+                issues.push(issue);
+                dropped.push(id);
+                break;
+            }
+        }
+        if dropped.is_empty() {
+            break;
+        }
+        for id in dropped {
+            candidates.remove(id);
+        }
+    }
+
+    // Topological sort, always taking the lowest ready id.
+    let mut waiting_on: BTreeMap<&str, usize> = candidates
+        .iter()
+        .map(|(&id, manifest)| {
+            let deps: BTreeSet<&str> = manifest
+                .dependencies
+                .iter()
+                .map(|d| d.id.as_str())
+                .collect();
+            (id, deps.len())
+        })
+        .collect();
+    let mut ready: BTreeSet<&str> = waiting_on
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    let mut order = Vec::new();
+    while let Some(id) = ready.pop_first() {
+        waiting_on.remove(id);
+        order.push(id.to_string());
+        for (&other, manifest) in &candidates {
+            if manifest.dependencies.iter().any(|d| d.id == id) {
+                if let Some(count) = waiting_on.get_mut(other) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.insert(other);
+                    }
+                }
+            }
+        }
+    }
+    if !waiting_on.is_empty() {
+        let ids = waiting_on.keys().map(|id| id.to_string()).collect();
+        issues.push(ModIssue::DependencyCycle(ids));
+    }
+    (order, issues)
+}
+
+/// Save keys claimed by more than one of the `active` mods or by a mod and
+/// the base game (`base_keys`).
+pub fn save_key_conflicts(
+    installed: &[InstalledMod],
+    active: &[String],
+    base_keys: &[&str],
+) -> Vec<ModIssue> {
+    let mut owners: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for id in active {
+        let Some(installed_mod) = installed.iter().find(|m| &m.manifest.id == id) else {
+            continue;
+        };
+        for key in &installed_mod.manifest.save_keys {
+            let key_owners = owners.entry(key.as_str()).or_default();
+            if key_owners.is_empty() && base_keys.contains(&key.as_str()) {
+                key_owners.push(BASE_GAME.to_string());
+            }
+            if !key_owners.contains(id) {
+                key_owners.push(id.clone());
+            }
+        }
+    }
+    owners
+        .into_iter()
+        .filter(|(_, owners)| owners.len() > 1)
+        .map(|(key, owners)| ModIssue::SaveKeyConflict {
+            key: key.to_string(),
+            owners,
+        })
+        .collect()
+}
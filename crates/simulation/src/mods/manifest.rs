@@ -0,0 +1,185 @@
+//! The mod package format: a folder under `assets/mods/` holding a
+//! `mod.json` manifest next to the mod's content.
+//!
+//! ```json
+//! {
+//!     "id": "better_buses",
+//!     "name": "Better Buses",
+//!     "version": "1.2.0",
+//!     "description": "Smarter bus routes.",
+//!     "dependencies": [{ "id": "transit_lib", "version": "1.0.0" }],
+//!     "save_keys": ["better_buses_routes"]
+//! }
+//! ```
+//!
+//! A dependency's `version` is the lowest compatible one: any installed
+//! version with the same major number that is at least as new satisfies it.
+//! `save_keys` lists the extension keys the mod stores in save files.
+
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Manifest file name inside each mod folder.
+pub const MANIFEST_FILE: &str = "mod.json";
+
+/// A `major.minor.patch` mod version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ModVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ModVersion {
+    /// Parse `"1.2.3"`; a missing minor or patch number counts as 0.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut parts = text.trim().split('.');
+        let mut next = |required: bool| -> Result<u32, String> {
+            match parts.next() {
+                Some(part) => part
+                    .parse()
+                    .map_err(|_| format!("invalid version '{text}'")),
+                None if required => Err(format!("invalid version '{text}'")),
+                None => Ok(0),
+            }
+        };
+        let version = Self {
+            major: next(true)?,
+            minor: next(false)?,
+            patch: next(false)?,
+        };
+        if parts.next().is_some() {
+            return Err(format!("invalid version '{text}'"));
+        }
+        Ok(version)
+    }
+
+    /// Whether this version can stand in for `required`: same major version
+    /// and at least as new.
+    pub fn satisfies(self, required: ModVersion) -> bool {
+        self.major == required.major && self >= required
+    }
+}
+
+impl fmt::Display for ModVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl Serialize for ModVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ModVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Self::parse(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Another mod a mod needs, loaded before it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModDependency {
+    pub id: String,
+    /// Lowest compatible version.
+    #[serde(default)]
+    pub version: ModVersion,
+}
+
+/// A mod's `mod.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModManifest {
+    /// Stable identifier, used for dependencies and in save files.
+    pub id: String,
+    /// Display name; the id if empty.
+    #[serde(default)]
+    pub name: String,
+    pub version: ModVersion,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub dependencies: Vec<ModDependency>,
+    /// Extension keys the mod writes into save files.
+    #[serde(default)]
+    pub save_keys: Vec<String>,
+}
+
+impl ModManifest {
+    /// Parse and validate a manifest.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut manifest: Self =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        manifest.validate()?;
+        if manifest.name.is_empty() {
+            manifest.name = manifest.id.clone();
+        }
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let valid_id = |id: &str| {
+            !id.is_empty()
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        };
+        if !valid_id(&self.id) {
+            return Err(format!(
+                "invalid mod id '{}' (use letters, digits, '_' and '-')",
+                self.id
+            ));
+        }
+        if let Some(dep) = self.dependencies.iter().find(|d| !valid_id(&d.id)) {
+            return Err(format!("invalid dependency id '{}'", dep.id));
+        }
+        if self.dependencies.iter().any(|d| d.id == self.id) {
+            return Err(format!("mod '{}' depends on itself", self.id));
+        }
+        Ok(())
+    }
+}
+
+/// A mod found on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstalledMod {
+    pub manifest: ModManifest,
+    /// The mod's folder.
+    pub dir: PathBuf,
+}
+
+/// Read the manifest of every mod folder in `dir`. Folders without a
+/// `mod.json` are not mods and are skipped; broken manifests are reported in
+/// the returned errors.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn discover_mods(dir: &Path) -> (Vec<InstalledMod>, Vec<String>) {
+    let mut dirs: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.join(MANIFEST_FILE).is_file())
+            .collect(),
+        Err(_) => return (Vec::new(), Vec::new()),
+    };
+    dirs.sort();
+
+    let mut mods = Vec::new();
+    let mut errors = Vec::new();
+    for dir in dirs {
+        let path = dir.join(MANIFEST_FILE);
+        let manifest = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))
+            .and_then(|json| ModManifest::from_json(&json))
+            .map_err(|e| format!("{}: {e}", path.display()));
+        match manifest {
+            Ok(manifest) => mods.push(InstalledMod { manifest, dir }),
+            Err(e) => errors.push(e),
+        }
+    }
+    (mods, errors)
+}
@@ -0,0 +1,184 @@
+//! Mod packages and the mod manager.
+//!
+//! Each folder under `assets/mods/` with a `mod.json` manifest (see
+//! `manifest`) is a mod. At startup the manager reads every manifest, drops
+//! the mods the player switched off, and resolves a load order in which each
+//! mod follows its dependencies; mods whose dependencies can't be met are
+//! not loaded. Save keys claimed by two mods, or by a mod and the base game,
+//! are reported as conflicts. Mod content (for now, Lua scripts in the mod's
+//! `scripts/` folder) is loaded in that order.
+//!
+//! Switching mods on or off is remembered in the player profile and takes
+//! effect at the next start. Saves record the mods they were made with, and
+//! loading a save made with a mod that isn't running warns the player.
+
+pub mod load_order;
+pub mod manifest;
+pub mod saved;
+#[cfg(test)]
+mod tests;
+
+pub use load_order::{resolve_load_order, save_key_conflicts, ModIssue};
+pub use manifest::{InstalledMod, ModDependency, ModManifest, ModVersion};
+pub use saved::{SaveModMismatch, SavedMod, SavedMods};
+
+use bevy::prelude::*;
+
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::profile::PlayerProfile;
+
+/// Folder mods are installed in, relative to the working directory.
+pub const MODS_DIR: &str = "assets/mods";
+
+/// The installed mods and which of them are running.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ModManager {
+    /// Every mod found, in folder name order.
+    pub installed: Vec<InstalledMod>,
+    /// Ids of mods the player switched off.
+    pub disabled: Vec<String>,
+    /// Ids of the mods running this session, in load order.
+    pub load_order: Vec<String>,
+    /// Problems found when resolving the load order.
+    pub issues: Vec<ModIssue>,
+    /// How the last loaded save's mods differ from the running ones.
+    pub save_mismatches: Vec<SaveModMismatch>,
+}
+
+impl ModManager {
+    /// Resolve the load order of `installed` without the `disabled` mods.
+    /// `manifest_errors` are manifests that couldn't be read.
+    pub fn new(
+        installed: Vec<InstalledMod>,
+        disabled: Vec<String>,
+        manifest_errors: Vec<String>,
+    ) -> Self {
+        let mut issues: Vec<ModIssue> = manifest_errors
+            .into_iter()
+            .map(ModIssue::InvalidManifest)
+            .collect();
+        let (load_order, order_issues) = resolve_load_order(&installed, &disabled);
+        issues.extend(order_issues);
+        issues.extend(save_key_conflicts(
+            &installed,
+            &load_order,
+            crate::EXPECTED_SAVEABLE_KEYS,
+        ));
+        Self {
+            installed,
+            disabled,
+            load_order,
+            issues,
+            save_mismatches: Vec::new(),
+        }
+    }
+
+    /// The installed mod `id`.
+    pub fn get(&self, id: &str) -> Option<&InstalledMod> {
+        self.installed.iter().find(|m| m.manifest.id == id)
+    }
+
+    /// The running mods, in load order.
+    pub fn loaded(&self) -> impl Iterator<Item = &InstalledMod> {
+        self.load_order.iter().filter_map(|id| self.get(id))
+    }
+
+    pub fn is_enabled(&self, id: &str) -> bool {
+        !self.disabled.iter().any(|d| d == id)
+    }
+
+    pub fn is_loaded(&self, id: &str) -> bool {
+        self.load_order.iter().any(|l| l == id)
+    }
+
+    /// Switch mod `id` on or off from the next start.
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if enabled {
+            self.disabled.retain(|d| d != id);
+        } else if self.is_enabled(id) {
+            self.disabled.push(id.to_string());
+        }
+    }
+
+    /// Whether the mods switched on now would load differently from the
+    /// running ones, so a restart is needed.
+    pub fn restart_required(&self) -> bool {
+        resolve_load_order(&self.installed, &self.disabled).0 != self.load_order
+    }
+
+    /// The running mods as recorded in saves.
+    pub fn running(&self) -> SavedMods {
+        SavedMods {
+            mods: self
+                .loaded()
+                .map(|m| SavedMod {
+                    id: m.manifest.id.clone(),
+                    version: m.manifest.version.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// System: after a save is loaded (or a new city started), warn about mods
+/// the save was made with that aren't running, then record the running mods
+/// for the next save.
+pub fn check_saved_mods(
+    mut manager: ResMut<ModManager>,
+    mut saved: ResMut<SavedMods>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !saved.is_changed() {
+        return;
+    }
+    let running = manager.running();
+    let mismatches = saved.mismatches(&running);
+    for mismatch in &mismatches {
+        notifications.send(NotificationEvent {
+            text: mismatch.to_string(),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+    if manager.save_mismatches != mismatches {
+        manager.save_mismatches = mismatches;
+    }
+    if *saved != running {
+        *saved.bypass_change_detection() = running;
+    }
+}
+
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        let disabled = app
+            .world()
+            .get_resource::<PlayerProfile>()
+            .map(|profile| profile.disabled_mods.clone())
+            .unwrap_or_default();
+        #[cfg(not(target_arch = "wasm32"))]
+        let (installed, errors) = manifest::discover_mods(std::path::Path::new(MODS_DIR));
+        #[cfg(target_arch = "wasm32")]
+        let (installed, errors) = (Vec::new(), Vec::new());
+
+        let manager = ModManager::new(installed, disabled, errors);
+        for issue in &manager.issues {
+            warn!("Mods: {issue}");
+        }
+        if !manager.load_order.is_empty() {
+            info!("Mods loaded in order: {}", manager.load_order.join(", "));
+        }
+
+        app.insert_resource(manager.running())
+            .insert_resource(manager)
+            .add_systems(Update, check_saved_mods);
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<SavedMods>();
+    }
+}
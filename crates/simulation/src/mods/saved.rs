@@ -0,0 +1,82 @@
+//! The mods a city was saved with, kept in its save file so loading it
+//! without them can be flagged.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+
+/// One mod recorded in a save.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SavedMod {
+    pub id: String,
+    pub version: String,
+}
+
+/// The active mods, in load order. Between loads this mirrors the running
+/// mods; right after a load it holds the save's list until
+/// `check_saved_mods` has compared the two.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+pub struct SavedMods {
+    pub mods: Vec<SavedMod>,
+}
+
+/// How a save's mods differ from the running ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveModMismatch {
+    /// The save used a mod that isn't running now.
+    Missing(SavedMod),
+    /// The save used another version of a running mod.
+    VersionChanged { saved: SavedMod, running: String },
+}
+
+impl std::fmt::Display for SaveModMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveModMismatch::Missing(saved) => write!(
+                f,
+                "This city was saved with mod '{}' {}, which is not active",
+                saved.id, saved.version
+            ),
+            SaveModMismatch::VersionChanged { saved, running } => write!(
+                f,
+                "This city was saved with mod '{}' {}; {running} is active",
+                saved.id, saved.version
+            ),
+        }
+    }
+}
+
+impl SavedMods {
+    /// How the mods in `self` (from a save) differ from `running`.
+    pub fn mismatches(&self, running: &SavedMods) -> Vec<SaveModMismatch> {
+        self.mods
+            .iter()
+            .filter_map(
+                |saved| match running.mods.iter().find(|m| m.id == saved.id) {
+                    None => Some(SaveModMismatch::Missing(saved.clone())),
+                    Some(m) if m.version != saved.version => {
+                        Some(SaveModMismatch::VersionChanged {
+                            saved: saved.clone(),
+                            running: m.version.clone(),
+                        })
+                    }
+                    Some(_) => None,
+                },
+            )
+            .collect()
+    }
+}
+
+impl crate::Saveable for SavedMods {
+    const SAVE_KEY: &'static str = "active_mods";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.mods.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+
+use super::*;
+use crate::test_harness::TestCity;
+
+fn installed(id: &str, version: &str, deps: &[(&str, &str)]) -> InstalledMod {
+    let deps: Vec<String> = deps
+        .iter()
+        .map(|(dep, v)| format!(r#"{{ "id": "{dep}", "version": "{v}" }}"#))
+        .collect();
+    let json = format!(
+        r#"{{ "id": "{id}", "version": "{version}", "dependencies": [{}] }}"#,
+        deps.join(", ")
+    );
+    InstalledMod {
+        manifest: ModManifest::from_json(&json).unwrap(),
+        dir: PathBuf::from(id),
+    }
+}
+
+fn with_save_keys(mut installed_mod: InstalledMod, keys: &[&str]) -> InstalledMod {
+    installed_mod.manifest.save_keys = keys.iter().map(|k| k.to_string()).collect();
+    installed_mod
+}
+
+#[test]
+fn test_version_parse_and_compatibility() {
+    let v = |text| ModVersion::parse(text).unwrap();
+    assert_eq!(
+        v("1.2"),
+        ModVersion {
+            major: 1,
+            minor: 2,
+            patch: 0
+        }
+    );
+    assert_eq!(v("1.2.3").to_string(), "1.2.3");
+    assert!(ModVersion::parse("1.x").is_err());
+    assert!(ModVersion::parse("1.2.3.4").is_err());
+
+    assert!(v("1.4.0").satisfies(v("1.2.0")));
+    assert!(!v("1.1.9").satisfies(v("1.2.0")));
+    assert!(!v("2.0.0").satisfies(v("1.2.0")));
+}
+
+#[test]
+fn test_manifest_validation() {
+    let manifest = ModManifest::from_json(r#"{ "id": "buses", "version": "1.0.0" }"#).unwrap();
+    assert_eq!(manifest.name, "buses");
+    assert!(ModManifest::from_json(r#"{ "id": "bad id", "version": "1" }"#).is_err());
+    assert!(ModManifest::from_json(
+        r#"{ "id": "a", "version": "1", "dependencies": [{ "id": "a" }] }"#
+    )
+    .is_err());
+}
+
+#[test]
+fn test_load_order_follows_dependencies() {
+    let mods = vec![
+        installed("zoning_plus", "1.0.0", &[("core_lib", "1.0.0")]),
+        installed("core_lib", "1.3.0", &[]),
+        installed("alpha", "0.1.0", &[]),
+        installed(
+            "buses",
+            "2.0.0",
+            &[("core_lib", "1.2.0"), ("zoning_plus", "1.0")],
+        ),
+    ];
+    let (order, issues) = resolve_load_order(&mods, &[]);
+    assert_eq!(order, vec!["alpha", "core_lib", "zoning_plus", "buses"]);
+    assert!(issues.is_empty(), "{issues:?}");
+}
+
+#[test]
+fn test_unmet_dependencies_drop_the_mod_and_its_dependents() {
+    let mods = vec![
+        installed("core_lib", "1.0.0", &[]),
+        installed("needs_new_core", "1.0.0", &[("core_lib", "1.5.0")]),
+        installed("needs_needs", "1.0.0", &[("needs_new_core", "1.0.0")]),
+        installed("needs_disabled", "1.0.0", &[("off", "1.0.0")]),
+        installed("off", "1.0.0", &[]),
+        installed("cycle_a", "1.0.0", &[("cycle_b", "1.0.0")]),
+        installed("cycle_b", "1.0.0", &[("cycle_a", "1.0.0")]),
+    ];
+    let (order, issues) = resolve_load_order(&mods, &["off".to_string()]);
+    assert_eq!(order, vec!["core_lib"]);
+    assert!(issues.contains(&ModIssue::IncompatibleDependency {
+        mod_id: "needs_new_core".into(),
+        dependency: "core_lib".into(),
+        required: ModVersion::parse("1.5.0").unwrap(),
+        found: ModVersion::parse("1.0.0").unwrap(),
+    }));
+    assert!(issues.iter().any(|issue| matches!(
+        issue,
+        ModIssue::MissingDependency { mod_id, .. } if mod_id == "needs_needs"
+    )));
+    assert!(issues.iter().any(|issue| matches!(
+        issue,
+        ModIssue::MissingDependency { mod_id, dependency, .. }
+            if mod_id == "needs_disabled" && dependency == "off"
+    )));
+    assert!(issues.contains(&ModIssue::DependencyCycle(vec![
+        "cycle_a".into(),
+        "cycle_b".into()
+    ])));
+}
+
+#[test]
+fn test_save_key_conflicts() {
+    let mods = vec![
+        with_save_keys(installed("a", "1.0.0", &[]), &["shared", "budget_ext"]),
+        with_save_keys(installed("b", "1.0.0", &[]), &["shared"]),
+        with_save_keys(installed("c", "1.0.0", &[]), &["policies"]),
+    ];
+    let active = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let conflicts = save_key_conflicts(&mods, &active, &["policies", "budget"]);
+    assert_eq!(
+        conflicts,
+        vec![
+            ModIssue::SaveKeyConflict {
+                key: "policies".into(),
+                owners: vec![load_order::BASE_GAME.into(), "c".into()],
+            },
+            ModIssue::SaveKeyConflict {
+                key: "shared".into(),
+                owners: vec!["a".into(), "b".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_enable_disable_needs_restart() {
+    let mods = vec![installed("a", "1.0.0", &[]), installed("b", "1.0.0", &[])];
+    let mut manager = ModManager::new(mods, vec!["b".into()], Vec::new());
+    assert_eq!(manager.load_order, vec!["a"]);
+    assert!(!manager.restart_required());
+
+    manager.set_enabled("b", true);
+    assert!(manager.is_enabled("b"));
+    assert!(!manager.is_loaded("b"));
+    assert!(manager.restart_required());
+    manager.set_enabled("b", false);
+    assert!(!manager.restart_required());
+}
+
+#[test]
+fn test_loading_a_save_with_missing_mods_warns() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        let mods = vec![installed("buses", "1.1.0", &[])];
+        let manager = ModManager::new(mods, Vec::new(), Vec::new());
+        world.insert_resource(manager.running());
+        world.insert_resource(manager);
+        world.insert_resource(SavedMods {
+            mods: vec![
+                SavedMod {
+                    id: "buses".into(),
+                    version: "1.0.0".into(),
+                },
+                SavedMod {
+                    id: "trams".into(),
+                    version: "0.3.0".into(),
+                },
+            ],
+        });
+    }
+    city.world_mut().run_schedule(Update);
+
+    let manager = city.resource::<ModManager>();
+    assert_eq!(manager.save_mismatches.len(), 2);
+    assert!(manager.save_mismatches[0]
+        .to_string()
+        .contains("'buses' 1.0.0; 1.1.0 is active"));
+    assert!(manager.save_mismatches[1]
+        .to_string()
+        .contains("'trams' 0.3.0, which is not active"));
+    // The running mods are what the next save records.
+    assert_eq!(*city.resource::<SavedMods>(), manager.running());
+}
@@ -1,9 +1,16 @@
+mod motion_band;
+mod motion_buffer;
+mod path_queue;
 mod pathfinding;
 mod state_machine;
 
+pub use motion_buffer::{
+    ingest_citizen_motion, sync_citizen_motion, CitizenMotionBuffer, SYNC_INTERVAL,
+};
+pub use path_queue::{PathPriority, PathRequestQueue};
 pub use pathfinding::{
-    collect_path_results, move_citizens, process_path_requests, update_pathfinding_snapshot,
-    ComputingPath, PathfindingSnapshot,
+    advance_along_path, collect_path_results, lane_offset, move_citizens, process_path_requests,
+    update_pathfinding_snapshot, ComputingPath, Motion, PathfindingSnapshot,
 };
 pub use state_machine::{
     citizen_state_machine, find_nearest, invalidate_paths_on_road_removal,
@@ -18,6 +25,8 @@ impl Plugin for MovementPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DestinationCache>()
             .init_resource::<PathfindingSnapshot>()
+            .init_resource::<CitizenMotionBuffer>()
+            .init_resource::<PathRequestQueue>()
             .add_systems(
                 FixedUpdate,
                 (
@@ -30,7 +39,9 @@ impl Plugin for MovementPlugin {
                     bevy::ecs::schedule::apply_deferred,
                     collect_path_results,
                     bevy::ecs::schedule::apply_deferred,
+                    ingest_citizen_motion,
                     move_citizens,
+                    sync_citizen_motion,
                 )
                     .chain()
                     .after(crate::citizen_spawner::spawn_citizens)
@@ -0,0 +1,105 @@
+//! Bands of `CitizenMotionBuffer` rows, stepped in parallel on the compute
+//! task pool.
+
+use super::pathfinding::{advance_along_path, Motion};
+use crate::roads::RoadNode;
+use crate::traffic_congestion::TrafficCongestion;
+
+/// A contiguous run of rows, borrowed field by field so bands can be
+/// stepped on separate tasks.
+pub(super) struct Band<'a> {
+    pub(super) pos_x: &'a mut [f32],
+    pub(super) pos_y: &'a mut [f32],
+    pub(super) vel_x: &'a mut [f32],
+    pub(super) vel_y: &'a mut [f32],
+    pub(super) path_index: &'a mut [u32],
+    pub(super) dirty: &'a mut [bool],
+    pub(super) arrived: &'a mut [bool],
+    pub(super) path_start: &'a [u32],
+    pub(super) path_len: &'a [u32],
+    pub(super) lane_offset: &'a [f32],
+    pub(super) mode_multiplier: &'a [f32],
+}
+
+impl<'a> Band<'a> {
+    pub(super) fn split_at(self, mid: usize) -> (Band<'a>, Band<'a>) {
+        let (pos_x, pos_x_tail) = self.pos_x.split_at_mut(mid);
+        let (pos_y, pos_y_tail) = self.pos_y.split_at_mut(mid);
+        let (vel_x, vel_x_tail) = self.vel_x.split_at_mut(mid);
+        let (vel_y, vel_y_tail) = self.vel_y.split_at_mut(mid);
+        let (path_index, path_index_tail) = self.path_index.split_at_mut(mid);
+        let (dirty, dirty_tail) = self.dirty.split_at_mut(mid);
+        let (arrived, arrived_tail) = self.arrived.split_at_mut(mid);
+        let (path_start, path_start_tail) = self.path_start.split_at(mid);
+        let (path_len, path_len_tail) = self.path_len.split_at(mid);
+        let (lane_offset, lane_offset_tail) = self.lane_offset.split_at(mid);
+        let (mode_multiplier, mode_multiplier_tail) = self.mode_multiplier.split_at(mid);
+        (
+            Band {
+                pos_x,
+                pos_y,
+                vel_x,
+                vel_y,
+                path_index,
+                dirty,
+                arrived,
+                path_start,
+                path_len,
+                lane_offset,
+                mode_multiplier,
+            },
+            Band {
+                pos_x: pos_x_tail,
+                pos_y: pos_y_tail,
+                vel_x: vel_x_tail,
+                vel_y: vel_y_tail,
+                path_index: path_index_tail,
+                dirty: dirty_tail,
+                arrived: arrived_tail,
+                path_start: path_start_tail,
+                path_len: path_len_tail,
+                lane_offset: lane_offset_tail,
+                mode_multiplier: mode_multiplier_tail,
+            },
+        )
+    }
+
+    pub(super) fn step(
+        mut self,
+        waypoints: &[RoadNode],
+        speed_per_tick: f32,
+        congestion: &TrafficCongestion,
+    ) {
+        for i in 0..self.pos_x.len() {
+            let start = self.path_start[i] as usize;
+            let path = &waypoints[start..start + self.path_len[i] as usize];
+            let motion = Motion {
+                x: self.pos_x[i],
+                y: self.pos_y[i],
+                vel_x: self.vel_x[i],
+                vel_y: self.vel_y[i],
+                index: self.path_index[i] as usize,
+            };
+            let next = advance_along_path(
+                motion,
+                path,
+                speed_per_tick,
+                self.mode_multiplier[i],
+                self.lane_offset[i],
+                congestion,
+            );
+            if next == motion {
+                continue;
+            }
+            self.pos_x[i] = next.x;
+            self.pos_y[i] = next.y;
+            self.vel_x[i] = next.vel_x;
+            self.vel_y[i] = next.vel_y;
+            self.path_index[i] = next.index as u32;
+            self.dirty[i] = true;
+            if next.index >= path.len() && motion.index < path.len() {
+                self.arrived[i] = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Structure-of-arrays storage for the per-tick citizen movement hot path.
+//!
+//! Stepping 10k–100k commuting citizens through archetype iteration touches
+//! a `Position`, a `Velocity` and a heap-allocated `PathCache` per entity
+//! every tick. `CitizenMotionBuffer` keeps what `move_citizens` needs in
+//! flat per-row arrays instead, with every row's waypoints packed into one
+//! shared vector, and steps them in bands on the compute task pool
+//! (`motion_band.rs`).
+//!
+//! The buffer is authoritative for the citizens it holds. `sync_citizen_motion`
+//! writes rows back to the components:
+//! - `LodTier::Full` citizens every tick, since they are rendered,
+//! - everyone else every `SYNC_INTERVAL` ticks, staggered by entity,
+//! - any citizen that finished its path straight away, so the state machine
+//!   sees the arrival on the next tick exactly as before.
+//!
+//! `ingest_citizen_motion` picks up citizens that start or stop commuting,
+//! change LOD tier or transport mode, get a new path or are moved by another
+//! system. Each row remembers the values last synced to the ECS, which tells
+//! the buffer's own write-backs apart from edits made elsewhere.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+
+use super::motion_band::Band;
+use super::pathfinding::{lane_offset, Motion};
+use crate::citizen::{Citizen, CitizenStateComp, PathCache, Position, Velocity};
+use crate::lod::LodTier;
+use crate::mode_choice::ChosenTransportMode;
+use crate::roads::RoadNode;
+use crate::traffic_congestion::TrafficCongestion;
+
+/// Ticks between write-backs of citizens that are not rendered in full.
+pub const SYNC_INTERVAL: u32 = 4;
+
+/// Rows stepped by one compute task.
+const ROWS_PER_BAND: usize = 1024;
+
+/// Dead waypoints tolerated in the shared store before it is compacted.
+const COMPACT_SLACK: usize = 4096;
+
+/// Per-row movement state of every commuting, non-abstract citizen.
+#[derive(Resource, Default)]
+pub struct CitizenMotionBuffer {
+    entities: Vec<Entity>,
+    rows: HashMap<Entity, usize>,
+    pos_x: Vec<f32>,
+    pos_y: Vec<f32>,
+    vel_x: Vec<f32>,
+    vel_y: Vec<f32>,
+    path_index: Vec<u32>,
+    path_start: Vec<u32>,
+    path_len: Vec<u32>,
+    lane_offset: Vec<f32>,
+    mode_multiplier: Vec<f32>,
+    full_detail: Vec<bool>,
+    /// Row changed since it was last written back.
+    dirty: Vec<bool>,
+    /// Row finished its path since it was last written back.
+    arrived: Vec<bool>,
+    synced_x: Vec<f32>,
+    synced_y: Vec<f32>,
+    synced_index: Vec<u32>,
+    /// Waypoints of every row, each row owning one contiguous span.
+    waypoints: Vec<RoadNode>,
+    live_waypoints: usize,
+    ticks: u32,
+}
+
+impl CitizenMotionBuffer {
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.rows.contains_key(&entity)
+    }
+
+    /// Current state of `entity`'s row, which may be ahead of its components.
+    pub fn motion(&self, entity: Entity) -> Option<Motion> {
+        self.rows.get(&entity).map(|&row| self.row_motion(row))
+    }
+
+    fn row_motion(&self, row: usize) -> Motion {
+        Motion {
+            x: self.pos_x[row],
+            y: self.pos_y[row],
+            vel_x: self.vel_x[row],
+            vel_y: self.vel_y[row],
+            index: self.path_index[row] as usize,
+        }
+    }
+
+    fn path(&self, row: usize) -> &[RoadNode] {
+        let start = self.path_start[row] as usize;
+        &self.waypoints[start..start + self.path_len[row] as usize]
+    }
+
+    /// Add `entity` or bring its row up to date with its components.
+    /// `position` and the path index are only taken over when they differ
+    /// from what the buffer last wrote, i.e. when another system moved the
+    /// citizen; the waypoints only when `path_changed`.
+    pub fn upsert(
+        &mut self,
+        entity: Entity,
+        position: (f32, f32),
+        path: &PathCache,
+        path_changed: bool,
+        full_detail: bool,
+        mode_multiplier: f32,
+    ) {
+        let Some(&row) = self.rows.get(&entity) else {
+            self.push(entity, position, path, full_detail, mode_multiplier);
+            return;
+        };
+        if position != (self.synced_x[row], self.synced_y[row]) {
+            self.pos_x[row] = position.0;
+            self.pos_y[row] = position.1;
+            self.synced_x[row] = position.0;
+            self.synced_y[row] = position.1;
+        }
+        if path_changed && self.path(row) != path.waypoints.as_slice() {
+            self.live_waypoints -= self.path_len[row] as usize;
+            self.path_start[row] = self.store_waypoints(&path.waypoints);
+            self.path_len[row] = path.waypoints.len() as u32;
+            self.path_index[row] = path.current_index as u32;
+            self.synced_index[row] = path.current_index as u32;
+            self.arrived[row] = false;
+            self.compact_if_sparse();
+        } else if path.current_index as u32 != self.synced_index[row] {
+            self.path_index[row] = path.current_index as u32;
+            self.synced_index[row] = path.current_index as u32;
+        }
+        self.full_detail[row] = full_detail;
+        self.mode_multiplier[row] = mode_multiplier;
+    }
+
+    fn push(
+        &mut self,
+        entity: Entity,
+        position: (f32, f32),
+        path: &PathCache,
+        full_detail: bool,
+        mode_multiplier: f32,
+    ) {
+        let start = self.store_waypoints(&path.waypoints);
+        self.rows.insert(entity, self.entities.len());
+        self.entities.push(entity);
+        self.pos_x.push(position.0);
+        self.pos_y.push(position.1);
+        self.vel_x.push(0.0);
+        self.vel_y.push(0.0);
+        self.path_index.push(path.current_index as u32);
+        self.path_start.push(start);
+        self.path_len.push(path.waypoints.len() as u32);
+        self.lane_offset.push(lane_offset(entity));
+        self.mode_multiplier.push(mode_multiplier);
+        self.full_detail.push(full_detail);
+        self.dirty.push(false);
+        self.arrived.push(false);
+        self.synced_x.push(position.0);
+        self.synced_y.push(position.1);
+        self.synced_index.push(path.current_index as u32);
+    }
+
+    fn store_waypoints(&mut self, waypoints: &[RoadNode]) -> u32 {
+        let start = self.waypoints.len() as u32;
+        self.waypoints.extend_from_slice(waypoints);
+        self.live_waypoints += waypoints.len();
+        start
+    }
+
+    /// Drop `entity`'s row. Returns the position to write back when the
+    /// citizen's `Position` (`ecs_position`) still holds what the buffer
+    /// last synced and the row has moved on since.
+    pub fn release(&mut self, entity: Entity, ecs_position: (f32, f32)) -> Option<(f32, f32)> {
+        let row = self.rows.remove(&entity)?;
+        let untouched = ecs_position == (self.synced_x[row], self.synced_y[row]);
+        let position = (self.pos_x[row], self.pos_y[row]);
+        self.live_waypoints -= self.path_len[row] as usize;
+        self.swap_remove(row);
+        self.compact_if_sparse();
+        (untouched && position != ecs_position).then_some(position)
+    }
+
+    fn swap_remove(&mut self, row: usize) {
+        self.entities.swap_remove(row);
+        self.pos_x.swap_remove(row);
+        self.pos_y.swap_remove(row);
+        self.vel_x.swap_remove(row);
+        self.vel_y.swap_remove(row);
+        self.path_index.swap_remove(row);
+        self.path_start.swap_remove(row);
+        self.path_len.swap_remove(row);
+        self.lane_offset.swap_remove(row);
+        self.mode_multiplier.swap_remove(row);
+        self.full_detail.swap_remove(row);
+        self.dirty.swap_remove(row);
+        self.arrived.swap_remove(row);
+        self.synced_x.swap_remove(row);
+        self.synced_y.swap_remove(row);
+        self.synced_index.swap_remove(row);
+        if let Some(&moved) = self.entities.get(row) {
+            self.rows.insert(moved, row);
+        }
+    }
+
+    /// Repack the waypoint store in row order once most of it belongs to
+    /// released or replaced paths.
+    fn compact_if_sparse(&mut self) {
+        if self.waypoints.len() <= self.live_waypoints * 2 + COMPACT_SLACK {
+            return;
+        }
+        let mut packed = Vec::with_capacity(self.live_waypoints);
+        for row in 0..self.entities.len() {
+            let start = packed.len() as u32;
+            packed.extend_from_slice(self.path(row));
+            self.path_start[row] = start;
+        }
+        self.waypoints = packed;
+    }
+
+    /// Advance every row by one tick.
+    pub fn step(&mut self, speed_per_tick: f32, congestion: &TrafficCongestion) {
+        self.ticks = self.ticks.wrapping_add(1);
+        let waypoints = &self.waypoints;
+        let mut rest = Band {
+            pos_x: &mut self.pos_x,
+            pos_y: &mut self.pos_y,
+            vel_x: &mut self.vel_x,
+            vel_y: &mut self.vel_y,
+            path_index: &mut self.path_index,
+            dirty: &mut self.dirty,
+            arrived: &mut self.arrived,
+            path_start: &self.path_start,
+            path_len: &self.path_len,
+            lane_offset: &self.lane_offset,
+            mode_multiplier: &self.mode_multiplier,
+        };
+        let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+        pool.scope(|scope| {
+            while !rest.pos_x.is_empty() {
+                let (band, tail) = rest.split_at(ROWS_PER_BAND.min(rest.pos_x.len()));
+                scope.spawn(async move { band.step(waypoints, speed_per_tick, congestion) });
+                rest = tail;
+            }
+        });
+    }
+
+    /// Hand every row that is due a write-back to `write` and mark it
+    /// synced: arrivals and full-detail rows always, the rest on their
+    /// staggered `SYNC_INTERVAL` tick.
+    pub fn sync_due(&mut self, mut write: impl FnMut(Entity, Motion)) {
+        for row in 0..self.entities.len() {
+            if !self.dirty[row] {
+                continue;
+            }
+            let entity = self.entities[row];
+            let on_interval = self.ticks.wrapping_add(entity.index()) % SYNC_INTERVAL == 0;
+            if !(self.arrived[row] || self.full_detail[row] || on_interval) {
+                continue;
+            }
+            write(entity, self.row_motion(row));
+            self.dirty[row] = false;
+            self.arrived[row] = false;
+            self.synced_x[row] = self.pos_x[row];
+            self.synced_y[row] = self.pos_y[row];
+            self.synced_index[row] = self.path_index[row];
+        }
+    }
+}
+
+/// Keep `CitizenMotionBuffer` in step with the ECS: add citizens that
+/// started commuting, drop those that stopped or despawned, and take over
+/// paths, positions, tiers and modes that other systems changed.
+#[allow(clippy::type_complexity)]
+pub fn ingest_citizen_motion(
+    mut buffer: ResMut<CitizenMotionBuffer>,
+    mut despawned: RemovedComponents<Citizen>,
+    mut changed: Query<
+        (
+            Entity,
+            &CitizenStateComp,
+            &mut Position,
+            &mut Velocity,
+            Ref<PathCache>,
+            Option<&LodTier>,
+            Option<&ChosenTransportMode>,
+        ),
+        (
+            With<Citizen>,
+            Or<(
+                Changed<CitizenStateComp>,
+                Changed<PathCache>,
+                Changed<Position>,
+                Changed<LodTier>,
+                Changed<ChosenTransportMode>,
+            )>,
+        ),
+    >,
+) {
+    for entity in despawned.read() {
+        buffer.release(entity, (f32::NAN, f32::NAN));
+    }
+    for (entity, state, mut pos, mut vel, path, lod, mode) in &mut changed {
+        let tier = lod.copied().unwrap_or_default();
+        if state.0.is_commuting() && tier != LodTier::Abstract {
+            let mode_multiplier = mode.map(|m| m.0.speed_multiplier()).unwrap_or(1.0);
+            buffer.upsert(
+                entity,
+                (pos.x, pos.y),
+                &path,
+                path.is_changed(),
+                tier == LodTier::Full,
+                mode_multiplier,
+            );
+            continue;
+        }
+        if let Some((x, y)) = buffer.release(entity, (pos.x, pos.y)) {
+            pos.x = x;
+            pos.y = y;
+        }
+        if vel.x != 0.0 || vel.y != 0.0 {
+            vel.x = 0.0;
+            vel.y = 0.0;
+        }
+    }
+}
+
+/// Write the rows that are due back to `Position`, `Velocity` and the path
+/// index. The index is written without change detection so the write-back
+/// isn't mistaken for a new path on the next tick.
+pub fn sync_citizen_motion(
+    mut buffer: ResMut<CitizenMotionBuffer>,
+    mut citizens: Query<(&mut Position, &mut Velocity, &mut PathCache), With<Citizen>>,
+) {
+    buffer.sync_due(|entity, motion| {
+        let Ok((mut pos, mut vel, mut path)) = citizens.get_mut(entity) else {
+            return;
+        };
+        pos.x = motion.x;
+        pos.y = motion.y;
+        vel.x = motion.vel_x;
+        vel.y = motion.vel_y;
+        path.bypass_change_detection().current_index = motion.index;
+    });
+}
+
+#[cfg(test)]
+#[path = "motion_buffer_tests.rs"]
+mod motion_buffer_tests;
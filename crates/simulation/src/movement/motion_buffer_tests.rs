@@ -0,0 +1,101 @@
+use super::*;
+use crate::grid::WorldGrid;
+use crate::movement::advance_along_path;
+
+fn straight_path() -> PathCache {
+    PathCache::new((10..20).map(|x| RoadNode(x, 10)).collect())
+}
+
+fn start() -> (f32, f32) {
+    WorldGrid::grid_to_world(10, 10)
+}
+
+#[test]
+fn test_step_matches_per_citizen_update() {
+    let congestion = TrafficCongestion::default();
+    let path = straight_path();
+    let entity = Entity::from_raw(7);
+    let mut buffer = CitizenMotionBuffer::default();
+    buffer.upsert(entity, start(), &path, true, true, 1.0);
+
+    let (x, y) = start();
+    let mut expected = Motion {
+        x,
+        y,
+        vel_x: 0.0,
+        vel_y: 0.0,
+        index: 0,
+    };
+    for _ in 0..40 {
+        buffer.step(1.5, &congestion);
+        expected = advance_along_path(
+            expected,
+            &path.waypoints,
+            1.5,
+            1.0,
+            lane_offset(entity),
+            &congestion,
+        );
+        assert_eq!(buffer.motion(entity), Some(expected));
+    }
+    assert!(expected.index > 0);
+}
+
+#[test]
+fn test_release_keeps_external_moves() {
+    let path = straight_path();
+    let entity = Entity::from_raw(1);
+    let mut buffer = CitizenMotionBuffer::default();
+    buffer.upsert(entity, start(), &path, true, false, 1.0);
+    // The first step only reaches the waypoint the citizen stands on.
+    buffer.step(2.0, &TrafficCongestion::default());
+    buffer.step(2.0, &TrafficCongestion::default());
+
+    let moved = buffer.motion(entity).map(|m| (m.x, m.y));
+    assert_ne!(moved, Some(start()));
+    assert_eq!(buffer.release(entity, start()), moved);
+
+    buffer.upsert(entity, start(), &path, true, false, 1.0);
+    buffer.step(2.0, &TrafficCongestion::default());
+    assert_eq!(buffer.release(entity, (0.0, 0.0)), None);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_full_detail_and_arrivals_sync_every_tick() {
+    let congestion = TrafficCongestion::default();
+    let near = PathCache::new(vec![RoadNode(10, 10)]);
+    let far = straight_path();
+    let mut buffer = CitizenMotionBuffer::default();
+    let arriving = Entity::from_raw(0);
+    let rendered = Entity::from_raw(1);
+    let background = Entity::from_raw(2);
+    buffer.upsert(arriving, start(), &near, true, false, 1.0);
+    buffer.upsert(rendered, start(), &far, true, true, 1.0);
+    buffer.upsert(background, start(), &far, true, false, 1.0);
+
+    let mut synced = Vec::new();
+    for _ in 0..SYNC_INTERVAL {
+        buffer.step(1.0, &congestion);
+        buffer.sync_due(|entity, _| synced.push(entity));
+    }
+    let count = |e: Entity| synced.iter().filter(|&&s| s == e).count();
+    assert_eq!(count(arriving), 1);
+    assert_eq!(count(rendered), SYNC_INTERVAL as usize);
+    assert_eq!(count(background), 1);
+}
+
+#[test]
+fn test_released_waypoints_are_compacted() {
+    let long = PathCache::new(vec![RoadNode(5, 5); COMPACT_SLACK]);
+    let mut buffer = CitizenMotionBuffer::default();
+    for i in 0..4 {
+        buffer.upsert(Entity::from_raw(i), start(), &long, true, false, 1.0);
+    }
+    let kept = Entity::from_raw(3);
+    for i in 0..3 {
+        buffer.release(Entity::from_raw(i), start());
+    }
+    assert_eq!(buffer.waypoints.len(), COMPACT_SLACK);
+    assert_eq!(buffer.path(buffer.rows[&kept]), long.waypoints.as_slice());
+}
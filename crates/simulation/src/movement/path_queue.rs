@@ -0,0 +1,143 @@
+//! Priority queue of pending pathfinding requests.
+//!
+//! Citizens ask for a route by getting a `PathRequest` component. After a
+//! road edit thousands of them can ask in the same tick, so
+//! `process_path_requests` no longer starts a task for every request at
+//! once: requests wait in `PathRequestQueue` and a per-tick budget of them is
+//! handed to the async task pool, highest `PathPriority` first and oldest
+//! first within a class. The `PathRequest` stays on the entity until its task
+//! is started, so the state machine keeps treating the citizen as waiting.
+//!
+//! Emergency responders spawned by `service_road_dispatch` route through the
+//! same queue with `PathPriority::Emergency`, so a saturated queue never
+//! delays a fire truck or ambulance.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use bevy::prelude::*;
+
+use crate::lod::LodTier;
+
+/// Scheduling class of a pathfinding request. Declared lowest first.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PathPriority {
+    /// Citizens that are not rendered.
+    Abstract,
+    /// Citizens on screen, whose waiting is visible to the player.
+    #[default]
+    Visible,
+    /// Emergency responders. Always started the tick they are queued,
+    /// regardless of the budget.
+    Emergency,
+}
+
+impl PathPriority {
+    /// Class of a citizen's request: an explicit `PathPriority` component
+    /// wins, otherwise it follows the citizen's LOD tier.
+    pub fn for_citizen(explicit: Option<&PathPriority>, tier: Option<&LodTier>) -> Self {
+        if let Some(&priority) = explicit {
+            return priority;
+        }
+        match tier {
+            Some(LodTier::Abstract) => PathPriority::Abstract,
+            _ => PathPriority::Visible,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct QueuedRequest {
+    priority: PathPriority,
+    seq: Reverse<u64>,
+    entity: Entity,
+}
+
+impl Ord for QueuedRequest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.priority, self.seq).cmp(&(other.priority, other.seq))
+    }
+}
+
+impl PartialOrd for QueuedRequest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Entities with a `PathRequest` that has not been started yet. Entries for
+/// entities that lost their request in the meantime are skipped when popped.
+#[derive(Resource, Default)]
+pub struct PathRequestQueue {
+    heap: BinaryHeap<QueuedRequest>,
+    next_seq: u64,
+}
+
+impl PathRequestQueue {
+    pub fn push(&mut self, entity: Entity, priority: PathPriority) {
+        self.heap.push(QueuedRequest {
+            priority,
+            seq: Reverse(self.next_seq),
+            entity,
+        });
+        self.next_seq += 1;
+    }
+
+    /// Highest-priority, oldest request.
+    pub fn pop(&mut self) -> Option<(Entity, PathPriority)> {
+        self.heap.pop().map(|r| (r.entity, r.priority))
+    }
+
+    /// Priority of the request `pop` would return next.
+    pub fn peek_priority(&self) -> Option<PathPriority> {
+        self.heap.peek().map(|r| r.priority)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pops_by_priority_then_age() {
+        let mut queue = PathRequestQueue::default();
+        let e = Entity::from_raw;
+        queue.push(e(0), PathPriority::Abstract);
+        queue.push(e(1), PathPriority::Visible);
+        queue.push(e(2), PathPriority::Emergency);
+        queue.push(e(3), PathPriority::Visible);
+        queue.push(e(4), PathPriority::Abstract);
+
+        let order: Vec<u32> = std::iter::from_fn(|| queue.pop())
+            .map(|(entity, _)| entity.index())
+            .collect();
+        assert_eq!(order, vec![2, 1, 3, 0, 4]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_priority_follows_lod_unless_explicit() {
+        let abstract_tier = Some(&LodTier::Abstract);
+        assert_eq!(
+            PathPriority::for_citizen(None, abstract_tier),
+            PathPriority::Abstract
+        );
+        assert_eq!(
+            PathPriority::for_citizen(None, Some(&LodTier::Simplified)),
+            PathPriority::Visible
+        );
+        assert_eq!(PathPriority::for_citizen(None, None), PathPriority::Visible);
+        assert_eq!(
+            PathPriority::for_citizen(Some(&PathPriority::Emergency), abstract_tier),
+            PathPriority::Emergency
+        );
+    }
+}
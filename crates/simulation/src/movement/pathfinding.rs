@@ -4,13 +4,12 @@ use std::time::{Duration, Instant};
 use bevy::prelude::*;
 use bevy::tasks::{block_on, AsyncComputeTaskPool, Task};
 
-use crate::citizen::{
-    Citizen, CitizenState, CitizenStateComp, PathCache, PathRequest, Position, Velocity,
-};
+use super::motion_buffer::CitizenMotionBuffer;
+use super::path_queue::{PathPriority, PathRequestQueue};
+use crate::citizen::{CitizenState, CitizenStateComp, PathCache, PathRequest};
 use crate::game_params::GameParams;
 use crate::grid::{RoadType, WorldGrid};
 use crate::lod::LodTier;
-use crate::mode_choice::ChosenTransportMode;
 use crate::pathfinding_sys::nearest_road_grid;
 use crate::road_graph_csr::{csr_find_path_with_traffic, CsrGraph, PathfindingData};
 use crate::roads::RoadNode;
@@ -24,13 +23,18 @@ use crate::traffic_congestion::TrafficCongestion;
 /// throughput is much higher than the old synchronous cap.
 const MAX_SPAWN_PER_TICK: usize = 256;
 
+/// Maximum number of pathfinding tasks in flight at once. Requests beyond
+/// this wait in the `PathRequestQueue` instead of piling onto the task pool
+/// after a road edit invalidates many routes at once.
+const MAX_IN_FLIGHT: usize = 2048;
+
 /// Fallback count limit for WASM where Instant has poor resolution.
 const MAX_PATHS_PER_TICK_WASM: usize = 256;
 
 /// Time budget for synchronous pathfinding per tick (WASM fallback).
 const PATH_BUDGET_WASM: Duration = Duration::from_millis(2);
 
-/// Marker component for citizens whose pathfinding is being computed
+/// Marker component for entities whose pathfinding is being computed
 /// asynchronously. Holds the spawned task and the target state to transition
 /// to once the path is ready.
 #[derive(Component)]
@@ -91,31 +95,51 @@ pub fn update_pathfinding_snapshot(
 
 /// Dispatch pathfinding requests as async tasks on the `AsyncComputeTaskPool`.
 ///
-/// For each pending `PathRequest`, this system:
+/// New `PathRequest`s are added to the `PathRequestQueue`. Then, in priority
+/// order and within the per-tick budget, this system:
 /// 1. Resolves start/goal grid cells to road nodes (fast, synchronous)
 /// 2. Spawns an A* computation on the async task pool
 /// 3. Replaces the `PathRequest` with a `ComputingPath` marker holding the task
 ///
+/// At most `MAX_SPAWN_PER_TICK` tasks are started per tick and at most
+/// `MAX_IN_FLIGHT` run at once; emergency requests skip both limits.
+///
+/// Requests from entities without a `CitizenStateComp` (emergency
+/// responders) only get their `PathCache` filled in; their `target_state` is
+/// ignored.
+///
 /// On WASM (single-threaded), falls back to synchronous processing since the
 /// async task pool has no extra threads.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn process_path_requests(
     mut commands: Commands,
     grid: Res<WorldGrid>,
     csr: Res<CsrGraph>,
     traffic: Res<TrafficGrid>,
     snapshot: Res<PathfindingSnapshot>,
-    mut query: Query<(Entity, &PathRequest, &mut PathCache, &mut CitizenStateComp), With<Citizen>>,
+    mut queue: ResMut<PathRequestQueue>,
+    new_requests: Query<(Entity, Option<&PathPriority>, Option<&LodTier>), Added<PathRequest>>,
+    computing: Query<(), With<ComputingPath>>,
+    mut query: Query<(&PathRequest, &mut PathCache, Option<&mut CitizenStateComp>)>,
 ) {
+    #[cfg(feature = "trace")]
+    let _span = bevy::log::info_span!("process_path_requests").entered();
+    for (entity, priority, tier) in &new_requests {
+        queue.push(entity, PathPriority::for_citizen(priority, tier));
+    }
+
     // On WASM, use synchronous processing (no multi-threading available)
     if cfg!(target_arch = "wasm32") {
         let start = Instant::now();
-        for (processed, (entity, request, mut path, mut state)) in query.iter_mut().enumerate() {
-            if processed >= MAX_PATHS_PER_TICK_WASM {
+        let mut processed = 0;
+        while processed < MAX_PATHS_PER_TICK_WASM && start.elapsed() < PATH_BUDGET_WASM {
+            let Some((entity, _)) = queue.pop() else {
                 break;
-            }
-            if start.elapsed() >= PATH_BUDGET_WASM {
-                break;
-            }
+            };
+            // Skip entries whose request was dropped while queued
+            let Ok((request, mut path, state)) = query.get_mut(entity) else {
+                continue;
+            };
 
             if let Some(route) = compute_route_csr(
                 &grid,
@@ -127,9 +151,12 @@ pub fn process_path_requests(
                 request.to_gy,
             ) {
                 *path = PathCache::new(route);
-                state.0 = request.target_state;
+                if let Some(mut state) = state {
+                    state.0 = request.target_state;
+                }
             }
             commands.entity(entity).remove::<PathRequest>();
+            processed += 1;
         }
         return;
     }
@@ -138,11 +165,21 @@ pub fn process_path_requests(
     let data = Arc::clone(&snapshot.data);
 
     let pool = AsyncComputeTaskPool::get();
+    let mut in_flight = computing.iter().count();
+    let mut spawned = 0;
 
-    for (spawned, (entity, request, _path, _state)) in query.iter_mut().enumerate() {
-        if spawned >= MAX_SPAWN_PER_TICK {
+    while let Some(priority) = queue.peek_priority() {
+        let over_budget = spawned >= MAX_SPAWN_PER_TICK || in_flight >= MAX_IN_FLIGHT;
+        if over_budget && priority != PathPriority::Emergency {
             break;
         }
+        let Some((entity, _)) = queue.pop() else {
+            break;
+        };
+        // Skip entries whose request was dropped while queued
+        let Ok((request, _, _)) = query.get(entity) else {
+            continue;
+        };
 
         // Resolve grid positions to road nodes synchronously (O(1) lookups)
         let start_node = nearest_road_grid(&grid, request.from_gx, request.from_gy);
@@ -161,6 +198,8 @@ pub fn process_path_requests(
                 commands
                     .entity(entity)
                     .insert(ComputingPath { task, target_state });
+                spawned += 1;
+                in_flight += 1;
             }
             _ => {
                 // No valid road nodes found; skip pathfinding (citizen stays in current state)
@@ -171,32 +210,35 @@ pub fn process_path_requests(
 
 /// Poll in-flight async pathfinding tasks and apply completed results.
 ///
-/// When a task completes, the computed path is written into the citizen's
-/// `PathCache` and their state transitions to the requested target state.
+/// When a task completes, the computed path is written into the entity's
+/// `PathCache` and a citizen's state transitions to the requested target
+/// state.
 pub fn collect_path_results(
     mut commands: Commands,
-    mut query: Query<
-        (
-            Entity,
-            &mut ComputingPath,
-            &mut PathCache,
-            &mut CitizenStateComp,
-        ),
-        With<Citizen>,
-    >,
+    mut query: Query<(
+        Entity,
+        &mut ComputingPath,
+        &mut PathCache,
+        Option<&mut CitizenStateComp>,
+    )>,
 ) {
-    for (entity, mut computing, mut path, mut state) in &mut query {
+    #[cfg(feature = "trace")]
+    let _span = bevy::log::info_span!("collect_path_results").entered();
+    for (entity, mut computing, mut path, state) in &mut query {
         if let Some(result) = block_on(futures_lite::future::poll_once(&mut computing.task)) {
             if let Some(route) = result {
                 *path = PathCache::new(route);
-                state.0 = computing.target_state;
+                if let Some(mut state) = state {
+                    state.0 = computing.target_state;
+                }
             }
             commands.entity(entity).remove::<ComputingPath>();
         }
     }
 }
 
-#[allow(clippy::type_complexity)]
+/// Advance every commuting citizen along its path, stepping the
+/// `CitizenMotionBuffer` rows rather than the components.
 pub fn move_citizens(
     clock: Res<GameClock>,
     game_params: Res<GameParams>,
@@ -204,18 +246,7 @@ pub fn move_citizens(
     fog: Res<crate::fog::FogState>,
     snow_stats: Res<crate::snow::SnowStats>,
     congestion: Res<TrafficCongestion>,
-    mut query: Query<
-        (
-            Entity,
-            &CitizenStateComp,
-            &mut Position,
-            &mut Velocity,
-            &mut PathCache,
-            Option<&LodTier>,
-            Option<&ChosenTransportMode>,
-        ),
-        With<Citizen>,
-    >,
+    mut buffer: ResMut<CitizenMotionBuffer>,
 ) {
     #[cfg(feature = "trace")]
     let _span = bevy::log::info_span!("move_citizens").entered();
@@ -230,81 +261,99 @@ pub fn move_citizens(
         * weather.travel_speed_multiplier_with_fog(fog.traffic_speed_modifier)
         * snow_mult;
 
-    query.par_iter_mut().for_each(
-        |(entity, state, mut pos, mut vel, mut path, lod, transport_mode)| {
-            // Skip abstract citizens entirely
-            if lod == Some(&LodTier::Abstract) {
-                vel.x = 0.0;
-                vel.y = 0.0;
-                return;
-            }
-            // Move during any commuting state
-            if !state.0.is_commuting() {
-                vel.x = 0.0;
-                vel.y = 0.0;
-                return;
-            }
+    buffer.step(speed_per_tick, &congestion);
+}
 
-            if let Some(target) = path.current_target() {
-                // Compute smoothed target using Catmull-Rom interpolation
-                let (tx, ty) = smoothed_waypoint_target(&path, *target, pos.x, pos.y);
-                let dx = tx - pos.x;
-                let dy = ty - pos.y;
-                let dist = (dx * dx + dy * dy).sqrt();
-
-                // Check arrival against the actual waypoint (not the smoothed target)
-                let (raw_tx, raw_ty) = WorldGrid::grid_to_world(target.0, target.1);
-                let raw_dist = ((raw_tx - pos.x).powi(2) + (raw_ty - pos.y).powi(2)).sqrt();
-
-                // Apply traffic congestion: look up the speed multiplier for the
-                // citizen's current grid cell. Congested roads reduce speed, creating
-                // visible traffic bunching.
-                let congestion_mult = congestion.get(target.0, target.1);
-                let mode_mult = transport_mode
-                    .map(|m| m.0.speed_multiplier())
-                    .unwrap_or(1.0);
-                let effective_speed = speed_per_tick * congestion_mult * mode_mult;
-
-                // Use a fixed minimum arrival threshold so that even at very low speeds
-                // (heavy snow/fog/congestion), citizens can still reach waypoints without orbiting.
-                let arrival_dist = effective_speed.max(2.0);
-                if raw_dist < arrival_dist {
-                    pos.x = raw_tx;
-                    pos.y = raw_ty;
-                    vel.x = dx;
-                    vel.y = dy;
-                    path.advance();
-                } else if dist > 0.001 {
-                    let nx = dx / dist;
-                    let ny = dy / dist;
-
-                    // Per-entity lane offset: shift perpendicular to travel direction.
-                    // Scale the offset by (speed / raw_dist) clamped to [0, 1] so that
-                    // lateral drift diminishes as the citizen approaches the waypoint,
-                    // preventing orbiting at low speeds (issue #1163).
-                    let lane = (entity.index() % 3) as f32 - 1.0;
-                    let lane_offset = lane * 2.5;
-                    let perp_x = -ny;
-                    let perp_y = nx;
-                    let offset_scale = (effective_speed / raw_dist).min(1.0);
-
-                    pos.x += nx * effective_speed + perp_x * lane_offset * 0.02 * offset_scale;
-                    pos.y += ny * effective_speed + perp_y * lane_offset * 0.02 * offset_scale;
-                    vel.x = nx * effective_speed;
-                    vel.y = ny * effective_speed;
-                }
-            } else {
-                vel.x = 0.0;
-                vel.y = 0.0;
-            }
-        },
+/// Kinematic state of one citizen for a single movement step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Motion {
+    pub x: f32,
+    pub y: f32,
+    pub vel_x: f32,
+    pub vel_y: f32,
+    /// Index of the waypoint being walked towards.
+    pub index: usize,
+}
+
+/// Lateral lane offset of a citizen, so that neighbours on the same road
+/// don't draw on top of each other.
+pub fn lane_offset(entity: Entity) -> f32 {
+    let lane = (entity.index() % 3) as f32 - 1.0;
+    lane * 2.5
+}
+
+/// Move one citizen a tick along `waypoints`, following the smoothed path
+/// and slowing down on congested roads.
+pub fn advance_along_path(
+    motion: Motion,
+    waypoints: &[RoadNode],
+    speed_per_tick: f32,
+    mode_multiplier: f32,
+    lane_offset: f32,
+    congestion: &TrafficCongestion,
+) -> Motion {
+    let Some(&target) = waypoints.get(motion.index) else {
+        return Motion {
+            vel_x: 0.0,
+            vel_y: 0.0,
+            ..motion
+        };
+    };
+    let mut next = motion;
+
+    // Compute smoothed target using Catmull-Rom interpolation
+    let (tx, ty) = smoothed_waypoint_target(
+        waypoints.get(motion.index + 1).copied(),
+        target,
+        motion.x,
+        motion.y,
     );
+    let dx = tx - motion.x;
+    let dy = ty - motion.y;
+    let dist = (dx * dx + dy * dy).sqrt();
+
+    // Check arrival against the actual waypoint (not the smoothed target)
+    let (raw_tx, raw_ty) = WorldGrid::grid_to_world(target.0, target.1);
+    let raw_dist = ((raw_tx - motion.x).powi(2) + (raw_ty - motion.y).powi(2)).sqrt();
+
+    // Apply traffic congestion: look up the speed multiplier for the
+    // citizen's current grid cell. Congested roads reduce speed, creating
+    // visible traffic bunching.
+    let congestion_mult = congestion.get(target.0, target.1);
+    let effective_speed = speed_per_tick * congestion_mult * mode_multiplier;
+
+    // Use a fixed minimum arrival threshold so that even at very low speeds
+    // (heavy snow/fog/congestion), citizens can still reach waypoints without orbiting.
+    let arrival_dist = effective_speed.max(2.0);
+    if raw_dist < arrival_dist {
+        next.x = raw_tx;
+        next.y = raw_ty;
+        next.vel_x = dx;
+        next.vel_y = dy;
+        next.index += 1;
+    } else if dist > 0.001 {
+        let nx = dx / dist;
+        let ny = dy / dist;
+
+        // Per-entity lane offset: shift perpendicular to travel direction.
+        // Scale the offset by (speed / raw_dist) clamped to [0, 1] so that
+        // lateral drift diminishes as the citizen approaches the waypoint,
+        // preventing orbiting at low speeds (issue #1163).
+        let perp_x = -ny;
+        let perp_y = nx;
+        let offset_scale = (effective_speed / raw_dist).min(1.0);
+        next.x += nx * effective_speed + perp_x * lane_offset * 0.02 * offset_scale;
+        next.y += ny * effective_speed + perp_y * lane_offset * 0.02 * offset_scale;
+        next.vel_x = nx * effective_speed;
+        next.vel_y = ny * effective_speed;
+    }
+    next
 }
 
 /// Compute a smoothed waypoint target using Catmull-Rom interpolation.
 /// Looks at the previous, current, and next waypoints to create a smooth curve.
 fn smoothed_waypoint_target(
-    path: &PathCache,
+    next: Option<RoadNode>,
     current_target: RoadNode,
     current_x: f32,
     current_y: f32,
@@ -312,10 +361,8 @@ fn smoothed_waypoint_target(
     let (tx, ty) = WorldGrid::grid_to_world(current_target.0, current_target.1);
 
     // Get the next waypoint after current (if available)
-    let next = path.peek_next();
     let next_pos = if let Some(n) = next {
-        let (nx, ny) = WorldGrid::grid_to_world(n.0, n.1);
-        (nx, ny)
+        WorldGrid::grid_to_world(n.0, n.1)
     } else {
         (tx, ty) // No next - use current target
     };
@@ -10,6 +10,7 @@ use crate::grid::WorldGrid;
 use crate::lod::LodTier;
 use crate::roads::RoadNetwork;
 use crate::services::{ServiceBuilding, ServiceType};
+use crate::street_lighting::StreetLightCoverage;
 use crate::time_of_day::GameClock;
 
 use super::pathfinding::ComputingPath;
@@ -106,6 +107,7 @@ pub fn citizen_state_machine(
     clock: Res<GameClock>,
     dest_cache: Res<DestinationCache>,
     game_params: Res<GameParams>,
+    street_lights: Res<StreetLightCoverage>,
     mut commands: Commands,
     mut query: Query<
         (
@@ -195,8 +197,14 @@ pub fn citizen_state_machine(
 
                 // Retired/unemployed: go shopping or leisure based on needs
                 if !life_stage.should_attend_school() && (10..=20).contains(&hour) {
+                    let home_cell = (home.grid_x, home.grid_y);
+                    let willing = |dest: &(usize, usize)| {
+                        willing_to_travel(&clock, &street_lights, entity, home_cell, *dest)
+                    };
                     if needs.hunger < 40.0 {
-                        if let Some(dest) = find_nearest(shops, home.grid_x, home.grid_y, 25) {
+                        if let Some(dest) =
+                            find_nearest(shops, home.grid_x, home.grid_y, 25).filter(willing)
+                        {
                             timer.0 = 0;
                             commands.entity(entity).insert(PathRequest {
                                 from_gx: home.grid_x,
@@ -211,6 +219,7 @@ pub fn citizen_state_machine(
                     if needs.fun < 30.0 || needs.social < 30.0 {
                         if let Some(dest) =
                             find_nearest(leisure_spots, home.grid_x, home.grid_y, 25)
+                                .filter(willing)
                         {
                             timer.0 = 0;
                             commands.entity(entity).insert(PathRequest {
@@ -239,8 +248,13 @@ pub fn citizen_state_machine(
                     // After work: check if needs drive a detour
                     if needs.hunger < 35.0 {
                         if let Some(work_loc) = work {
+                            let work_cell = (work_loc.grid_x, work_loc.grid_y);
+                            let willing = |dest: &(usize, usize)| {
+                                willing_to_travel(&clock, &street_lights, entity, work_cell, *dest)
+                            };
                             if let Some(dest) =
                                 find_nearest(shops, work_loc.grid_x, work_loc.grid_y, 20)
+                                    .filter(willing)
                             {
                                 timer.0 = 0;
                                 commands.entity(entity).insert(PathRequest {
@@ -256,8 +270,13 @@ pub fn citizen_state_machine(
                     }
                     if needs.fun < 25.0 || needs.social < 25.0 {
                         if let Some(work_loc) = work {
+                            let work_cell = (work_loc.grid_x, work_loc.grid_y);
+                            let willing = |dest: &(usize, usize)| {
+                                willing_to_travel(&clock, &street_lights, entity, work_cell, *dest)
+                            };
                             if let Some(dest) =
                                 find_nearest(leisure_spots, work_loc.grid_x, work_loc.grid_y, 20)
+                                    .filter(willing)
                             {
                                 timer.0 = 0;
                                 commands.entity(entity).insert(PathRequest {
@@ -388,6 +407,22 @@ pub fn find_nearest(
         .map(|(pos, _)| pos)
 }
 
+/// Whether a citizen sets out on an optional trip. After dark, unlit
+/// streets at either end put some citizens off; `entity` picks which.
+fn willing_to_travel(
+    clock: &GameClock,
+    street_lights: &StreetLightCoverage,
+    entity: Entity,
+    from: (usize, usize),
+    to: (usize, usize),
+) -> bool {
+    if clock.is_daylight() {
+        return true;
+    }
+    let willingness = street_lights.night_travel_willingness(from, to);
+    ((entity.index() % 100) as f32) < willingness * 100.0
+}
+
 fn is_leisure_service(st: ServiceType) -> bool {
     matches!(
         st,
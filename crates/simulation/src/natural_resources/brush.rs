@@ -0,0 +1,57 @@
+use crate::grid::{CellType, WorldGrid};
+
+use super::grid::ResourceGrid;
+use super::types::{ResourceDeposit, ResourceType};
+
+/// Radius of the deposit brush in cells.
+pub const DEPOSIT_BRUSH_RADIUS: i32 = 2;
+
+/// What one stroke of the deposit brush does to each cell it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepositBrush {
+    /// Lay a full deposit of `amount` units.
+    Paint(ResourceType, u32),
+    Erase,
+}
+
+/// Apply `brush` to the disc of `radius` cells around (`gx`, `gy`).
+/// Deposits are never placed on water. Returns the number of cells changed.
+pub fn paint_deposits(
+    resources: &mut ResourceGrid,
+    grid: &WorldGrid,
+    gx: usize,
+    gy: usize,
+    radius: i32,
+    brush: DepositBrush,
+) -> usize {
+    let mut changed = 0;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy > radius * radius {
+                continue;
+            }
+            let (nx, ny) = (gx as i32 + dx, gy as i32 + dy);
+            if nx < 0 || ny < 0 || !grid.in_bounds(nx as usize, ny as usize) {
+                continue;
+            }
+            let (x, y) = (nx as usize, ny as usize);
+            if x >= resources.width || y >= resources.height {
+                continue;
+            }
+            let new = match brush {
+                DepositBrush::Paint(_, _) if grid.get(x, y).cell_type == CellType::Water => {
+                    continue;
+                }
+                DepositBrush::Paint(resource, amount) => {
+                    Some(ResourceDeposit::new(resource, amount))
+                }
+                DepositBrush::Erase => None,
+            };
+            if *resources.get(x, y) != new {
+                *resources.get_mut(x, y) = new;
+                changed += 1;
+            }
+        }
+    }
+    changed
+}
@@ -1,11 +1,16 @@
 use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
 
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
 
-use super::types::ResourceDeposit;
+use super::types::{ResourceDeposit, ResourceType};
 
-/// Grid of natural resource deposits, generated alongside terrain
-#[derive(Resource)]
+/// Grid of natural resource deposits, generated alongside terrain, painted
+/// with the deposit brush or laid out by a scenario. Saved so that what has
+/// been extracted stays extracted.
+#[derive(Resource, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
 pub struct ResourceGrid {
     pub deposits: Vec<Option<ResourceDeposit>>,
     pub width: usize,
@@ -34,4 +39,51 @@ impl ResourceGrid {
     pub fn set(&mut self, x: usize, y: usize, deposit: ResourceDeposit) {
         self.deposits[y * self.width + x] = Some(deposit);
     }
+
+    pub fn clear(&mut self, x: usize, y: usize) {
+        self.deposits[y * self.width + x] = None;
+    }
+
+    /// Amount of `resource` left in deposits within `radius` cells
+    /// (Chebyshev) of (`x`, `y`).
+    pub fn remaining_near(&self, x: usize, y: usize, radius: usize, resource: ResourceType) -> u64 {
+        let x1 = (x + radius).min(self.width.saturating_sub(1));
+        let y1 = (y + radius).min(self.height.saturating_sub(1));
+        let mut total = 0;
+        for ny in y.saturating_sub(radius)..=y1 {
+            for nx in x.saturating_sub(radius)..=x1 {
+                if let Some(d) = self.get(nx, ny) {
+                    if d.resource_type == resource {
+                        total += d.amount as u64;
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    /// Amount of `resource` left across the whole map.
+    pub fn total_remaining(&self, resource: ResourceType) -> u64 {
+        self.deposits
+            .iter()
+            .flatten()
+            .filter(|d| d.resource_type == resource)
+            .map(|d| d.amount as u64)
+            .sum()
+    }
+}
+
+impl Saveable for ResourceGrid {
+    const SAVE_KEY: &'static str = "resource_grid";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.deposits.iter().all(Option::is_none) {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
 }
@@ -1,12 +1,16 @@
 mod balance;
+mod brush;
 mod generation;
 mod grid;
 mod systems;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_brush;
 mod types;
 
 pub use balance::ResourceBalance;
+pub use brush::{paint_deposits, DepositBrush, DEPOSIT_BRUSH_RADIUS};
 pub use generation::generate_resources;
 pub use grid::ResourceGrid;
 pub use systems::update_resource_production;
@@ -26,5 +30,10 @@ impl Plugin for NaturalResourcesPlugin {
                     .after(crate::imports_exports::process_trade)
                     .in_set(crate::SimulationSet::Simulation),
             );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<ResourceGrid>();
     }
 }
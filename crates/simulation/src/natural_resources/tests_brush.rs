@@ -0,0 +1,60 @@
+//! Tests for the deposit brush, exhausted deposits, and ResourceGrid save/load.
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid};
+use crate::natural_resources::{
+    paint_deposits, DepositBrush, ResourceDeposit, ResourceGrid, ResourceType,
+};
+use crate::Saveable;
+
+#[test]
+fn test_brush_paints_full_deposits_but_not_on_water() {
+    let mut world = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    world.get_mut(41, 40).cell_type = CellType::Water;
+    let mut grid = ResourceGrid::default();
+    let changed = paint_deposits(
+        &mut grid,
+        &world,
+        40,
+        40,
+        2,
+        DepositBrush::Paint(ResourceType::Ore, 400),
+    );
+    assert_eq!(
+        changed, 12,
+        "a radius-2 disc is 13 cells, one of them water"
+    );
+    let deposit = grid.get(40, 40).as_ref().expect("painted");
+    assert_eq!(deposit.resource_type, ResourceType::Ore);
+    assert_eq!((deposit.amount, deposit.max_amount), (400, 400));
+    assert!(grid.get(41, 40).is_none(), "no deposits on water");
+    assert_eq!(grid.remaining_near(40, 40, 2, ResourceType::Ore), 12 * 400);
+    assert_eq!(grid.remaining_near(40, 40, 2, ResourceType::Oil), 0);
+
+    let erased = paint_deposits(&mut grid, &world, 40, 40, 1, DepositBrush::Erase);
+    assert_eq!(erased, 4, "the water cell had nothing to erase");
+    assert_eq!(grid.total_remaining(ResourceType::Ore), 8 * 400);
+}
+
+#[test]
+fn test_exhausted_deposit_stays_on_the_grid() {
+    let mut grid = ResourceGrid::default();
+    let mut deposit = ResourceDeposit::new(ResourceType::Oil, 10);
+    deposit.amount = 0;
+    grid.set(5, 5, deposit);
+    assert!(grid.get(5, 5).as_ref().unwrap().is_exhausted());
+    assert_eq!(grid.remaining_near(5, 5, 4, ResourceType::Oil), 0);
+}
+
+#[test]
+fn test_resource_grid_save_roundtrip() {
+    assert!(ResourceGrid::default().save_to_bytes().is_none());
+    let mut grid = ResourceGrid::default();
+    let mut deposit = ResourceDeposit::new(ResourceType::Ore, 5000);
+    deposit.amount = 1234;
+    grid.set(10, 20, deposit);
+    let bytes = grid.save_to_bytes().expect("deposits are saved");
+    let loaded = ResourceGrid::load_from_bytes(&bytes);
+    assert_eq!(loaded, grid);
+    assert_eq!(loaded.get(10, 20).as_ref().unwrap().amount, 1234);
+}
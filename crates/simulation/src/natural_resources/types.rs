@@ -1,14 +1,28 @@
+use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Encode, Decode,
+)]
 pub enum ResourceType {
+    #[serde(alias = "fertile_land")]
     FertileLand,
+    #[serde(alias = "forest")]
     Forest,
+    #[serde(alias = "ore")]
     Ore,
+    #[serde(alias = "oil")]
     Oil,
 }
 
 impl ResourceType {
+    pub const ALL: [ResourceType; 4] = [
+        ResourceType::FertileLand,
+        ResourceType::Forest,
+        ResourceType::Ore,
+        ResourceType::Oil,
+    ];
+
     pub fn is_renewable(self) -> bool {
         matches!(self, ResourceType::FertileLand | ResourceType::Forest)
     }
@@ -20,11 +34,36 @@ impl ResourceType {
             ResourceType::Oil => "Oil Deposit",
         }
     }
+    /// Amount a freshly generated or painted deposit holds.
+    pub fn default_amount(self) -> u32 {
+        match self {
+            ResourceType::FertileLand => 10000,
+            ResourceType::Forest => 8000,
+            ResourceType::Ore => 5000,
+            ResourceType::Oil => 3000,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub struct ResourceDeposit {
     pub resource_type: ResourceType,
     pub amount: u32, // Remaining amount (finite resources deplete)
     pub max_amount: u32,
 }
+
+impl ResourceDeposit {
+    /// A full deposit holding `amount`.
+    pub fn new(resource_type: ResourceType, amount: u32) -> Self {
+        Self {
+            resource_type,
+            amount,
+            max_amount: amount,
+        }
+    }
+
+    /// Whether a finite deposit has been worked out.
+    pub fn is_exhausted(&self) -> bool {
+        self.amount == 0
+    }
+}
@@ -26,6 +26,7 @@ use bevy::prelude::*;
 use crate::buildings::Building;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::grid_parallel::par_rows_mut;
 use crate::services::{ServiceBuilding, ServiceType};
 
 // ---------------------------------------------------------------------------
@@ -128,47 +129,48 @@ impl NoisePollutionGrid {
     pub fn set(&mut self, x: usize, y: usize, val: u8) {
         self.levels[y * self.width + x] = val;
     }
-
-    /// Add noise at a position, capping at 100.
-    fn add(&mut self, x: usize, y: usize, amount: u8) {
-        let idx = y * self.width + x;
-        self.levels[idx] = self.levels[idx].saturating_add(amount).min(100);
-    }
-
-    /// Subtract noise at a position (floor at 0).
-    fn sub(&mut self, x: usize, y: usize, amount: u8) {
-        let idx = y * self.width + x;
-        self.levels[idx] = self.levels[idx].saturating_sub(amount);
-    }
 }
 
 // ---------------------------------------------------------------------------
 // Propagation helper
 // ---------------------------------------------------------------------------
 
-/// Propagate noise from a single source cell `(sx, sy)` with the given dB
-/// level into surrounding cells using logarithmic attenuation.
-fn propagate_noise(noise: &mut NoisePollutionGrid, sx: usize, sy: usize, source_db: f32) {
-    let radius = max_radius(source_db);
-    for dy in -radius..=radius {
-        for dx in -radius..=radius {
-            let nx = sx as i32 + dx;
-            let ny = sy as i32 + dy;
-            if nx < 0 || ny < 0 || nx as usize >= GRID_WIDTH || ny as usize >= GRID_HEIGHT {
-                continue;
-            }
-            let dist = ((dx * dx + dy * dy) as f32).sqrt();
-            let db = attenuated_db(source_db, dist);
-            if db > 0.0 {
-                let val = db_to_grid_u8(db);
-                if val > 0 {
-                    noise.add(nx as usize, ny as usize, val);
-                }
+/// A noise source: its cell, dB level and reach.
+#[derive(Clone, Copy)]
+struct NoiseSource {
+    x: usize,
+    db: f32,
+    radius: i32,
+}
+
+/// Add the noise a source `dy` rows away contributes to `row`, using
+/// logarithmic attenuation. Levels are capped at 100; since every
+/// contribution is non-negative the result does not depend on the order
+/// sources are added in.
+fn add_source_to_row(row: &mut [u8], source: NoiseSource, dy: i32) {
+    for dx in -source.radius..=source.radius {
+        let nx = source.x as i32 + dx;
+        if nx < 0 || nx as usize >= row.len() {
+            continue;
+        }
+        let dist = ((dx * dx + dy * dy) as f32).sqrt();
+        let db = attenuated_db(source.db, dist);
+        if db > 0.0 {
+            let val = db_to_grid_u8(db);
+            if val > 0 {
+                let level = &mut row[nx as usize];
+                *level = level.saturating_add(val).min(100);
             }
         }
     }
 }
 
+/// Whether a cell is open ground whose vegetation absorbs some noise.
+fn absorbs_noise(grid: &WorldGrid, x: usize, y: usize) -> bool {
+    let cell = grid.get(x, y);
+    cell.cell_type == CellType::Grass && cell.building_id.is_none()
+}
+
 // ---------------------------------------------------------------------------
 // Main system
 // ---------------------------------------------------------------------------
@@ -184,8 +186,15 @@ pub fn update_noise_pollution(
         return;
     }
 
-    // Clear the grid each update
-    noise.levels.fill(0);
+    // Sources bucketed by row, so each row of the grid can gather the
+    // sources that reach it.
+    let mut sources_by_row: Vec<Vec<NoiseSource>> = vec![Vec::new(); GRID_HEIGHT];
+    let mut max_reach = 0;
+    let mut add_source = |x: usize, y: usize, db: f32| {
+        let radius = max_radius(db);
+        max_reach = max_reach.max(radius);
+        sources_by_row[y].push(NoiseSource { x, db, radius });
+    };
 
     // --- Roads generate noise using logarithmic attenuation ---
     for y in 0..GRID_HEIGHT {
@@ -194,7 +203,7 @@ pub fn update_noise_pollution(
             if cell.cell_type == CellType::Road {
                 let db = road_source_db(cell.road_type);
                 if db > 0.0 {
-                    propagate_noise(&mut noise, x, y, db);
+                    add_source(x, y, db);
                 }
             }
         }
@@ -203,12 +212,7 @@ pub fn update_noise_pollution(
     // --- Industrial buildings ---
     for building in &buildings {
         if building.zone_type == ZoneType::Industrial {
-            propagate_noise(
-                &mut noise,
-                building.grid_x,
-                building.grid_y,
-                INDUSTRIAL_SOURCE_DB,
-            );
+            add_source(building.grid_x, building.grid_y, INDUSTRIAL_SOURCE_DB);
         }
     }
 
@@ -222,38 +226,40 @@ pub fn update_noise_pollution(
             _ => 0.0,
         };
         if db > 0.0 {
-            propagate_noise(
-                &mut noise,
-                service.grid_x,
-                service.grid_y,
-                db,
-            );
+            add_source(service.grid_x, service.grid_y, db);
         }
     }
 
-    // --- Trees reduce noise: grass cells without buildings reduce noise ---
-    let mut reductions: Vec<(usize, usize)> = Vec::new();
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            let cell = grid.get(x, y);
-            if cell.cell_type == CellType::Grass && cell.building_id.is_none() {
-                reductions.push((x, y));
+    let grid = &*grid;
+    let sources_by_row = &sources_by_row;
+    let reach = max_reach as usize;
+    par_rows_mut(&mut noise.levels, GRID_WIDTH, |y, row| {
+        row.fill(0);
+        let first = y.saturating_sub(reach);
+        let last = (y + reach).min(GRID_HEIGHT - 1);
+        for (sy, sources) in sources_by_row.iter().enumerate().take(last + 1).skip(first) {
+            let dy = y as i32 - sy as i32;
+            for &source in sources {
+                if dy.abs() <= source.radius {
+                    add_source_to_row(row, source, dy);
+                }
             }
         }
-    }
-    for (cx, cy) in reductions {
-        let radius = 1i32;
-        for dy in -radius..=radius {
-            for dx in -radius..=radius {
-                let nx = cx as i32 + dx;
-                let ny = cy as i32 + dy;
-                if nx >= 0 && ny >= 0 && (nx as usize) < GRID_WIDTH && (ny as usize) < GRID_HEIGHT
-                {
-                    noise.sub(nx as usize, ny as usize, 2);
+
+        // --- Trees reduce noise: each grass cell without a building
+        // within one cell takes 2 off ---
+        for (x, level) in row.iter_mut().enumerate() {
+            let mut absorbing = 0u8;
+            for ny in y.saturating_sub(1)..=(y + 1).min(GRID_HEIGHT - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(GRID_WIDTH - 1) {
+                    if absorbs_noise(grid, nx, ny) {
+                        absorbing += 1;
+                    }
                 }
             }
+            *level = level.saturating_sub(2 * absorbing);
         }
-    }
+    });
 }
 
 // ---------------------------------------------------------------------------
@@ -266,9 +272,7 @@ impl Plugin for NoisePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<NoisePollutionGrid>().add_systems(
             FixedUpdate,
-            update_noise_pollution
-                .after(crate::imports_exports::process_trade)
-                .in_set(crate::SimulationSet::Simulation),
+            update_noise_pollution.in_set(crate::GridPropagationSet::Emission),
         );
     }
 }
@@ -371,4 +375,28 @@ mod tests {
         assert!((airport_source_db(ServiceType::RegionalAirport) - 90.0).abs() < f32::EPSILON);
         assert!((airport_source_db(ServiceType::SmallAirstrip) - 80.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_row_contributions_cap_and_do_not_depend_on_order() {
+        let loud = NoiseSource {
+            x: 5,
+            db: 95.0,
+            radius: max_radius(95.0),
+        };
+        let road = NoiseSource {
+            x: 8,
+            db: 55.0,
+            radius: max_radius(55.0),
+        };
+        let mut forward = vec![0u8; 20];
+        add_source_to_row(&mut forward, loud, 0);
+        add_source_to_row(&mut forward, road, 1);
+        let mut backward = vec![0u8; 20];
+        add_source_to_row(&mut backward, road, 1);
+        add_source_to_row(&mut backward, loud, 0);
+
+        assert_eq!(forward, backward);
+        assert_eq!(forward[5], 100, "95 dB at the source saturates the cell");
+        assert!(forward.iter().all(|&v| v <= 100));
+    }
 }
@@ -0,0 +1,211 @@
+//! Which notifications pop up as toasts.
+//!
+//! `NotificationFilters` lets the player turn popups off per category, hide
+//! popups below a priority threshold, and enable do-not-disturb, which keeps
+//! only emergencies and disaster updates on screen while a disaster is under
+//! way. Filtered notifications are still recorded in the journal, so nothing
+//! is lost from the history drawer.
+//!
+//! Emergencies always pop up regardless of these settings.
+//!
+//! The filters are a player preference kept in the player profile (see
+//! [`crate::profile`]), like the UI scale.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::{NotificationCategory, NotificationPriority};
+
+/// Settings file written by builds before the player profile, relative to
+/// the working directory. Only read to import old filters into the profile.
+pub const NOTIFICATION_FILTERS_PATH: &str = "notification_filters.json";
+
+/// Popup filtering preferences.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationFilters {
+    pub disasters: bool,
+    pub finance: bool,
+    pub citizens: bool,
+    pub achievements: bool,
+    pub general: bool,
+    /// Least urgent priority that still pops up.
+    pub min_priority: NotificationPriority,
+    /// Only emergencies and disaster updates pop up during a disaster.
+    pub do_not_disturb: bool,
+}
+
+impl Default for NotificationFilters {
+    fn default() -> Self {
+        Self {
+            disasters: true,
+            finance: true,
+            citizens: true,
+            achievements: true,
+            general: true,
+            min_priority: NotificationPriority::Positive,
+            do_not_disturb: false,
+        }
+    }
+}
+
+impl NotificationFilters {
+    /// Whether popups of `category` are turned on.
+    pub fn shows_category(&self, category: NotificationCategory) -> bool {
+        match category {
+            NotificationCategory::Disaster => self.disasters,
+            NotificationCategory::Finance => self.finance,
+            NotificationCategory::Citizens => self.citizens,
+            NotificationCategory::Achievement => self.achievements,
+            NotificationCategory::General => self.general,
+        }
+    }
+
+    /// Turn popups of `category` on or off.
+    pub fn set_category(&mut self, category: NotificationCategory, shown: bool) {
+        let flag = match category {
+            NotificationCategory::Disaster => &mut self.disasters,
+            NotificationCategory::Finance => &mut self.finance,
+            NotificationCategory::Citizens => &mut self.citizens,
+            NotificationCategory::Achievement => &mut self.achievements,
+            NotificationCategory::General => &mut self.general,
+        };
+        *flag = shown;
+    }
+
+    /// Whether a notification should pop up. `disaster_active` is whether a
+    /// disaster is currently under way, for do-not-disturb.
+    pub fn allows_popup(
+        &self,
+        priority: NotificationPriority,
+        category: NotificationCategory,
+        disaster_active: bool,
+    ) -> bool {
+        if priority == NotificationPriority::Emergency {
+            return true;
+        }
+        if !self.shows_category(category) || priority > self.min_priority {
+            return false;
+        }
+        !(self.do_not_disturb && disaster_active && category != NotificationCategory::Disaster)
+    }
+
+    /// Parse filters from JSON. Missing fields keep their defaults.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))
+    }
+
+    /// Pretty-printed JSON for the settings file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load filters from `path`. A missing file yields the defaults; an
+    /// unreadable or invalid one is reported as an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
+
+pub struct NotificationFiltersPlugin;
+
+impl Plugin for NotificationFiltersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NotificationFilters>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_show_everything() {
+        let filters = NotificationFilters::default();
+        for category in NotificationCategory::ALL {
+            for priority in NotificationPriority::ALL {
+                assert!(filters.allows_popup(priority, category, false));
+            }
+        }
+    }
+
+    #[test]
+    fn test_category_toggle() {
+        let mut filters = NotificationFilters::default();
+        filters.set_category(NotificationCategory::Finance, false);
+        assert!(!filters.shows_category(NotificationCategory::Finance));
+        assert!(!filters.allows_popup(
+            NotificationPriority::Warning,
+            NotificationCategory::Finance,
+            false
+        ));
+        assert!(filters.allows_popup(
+            NotificationPriority::Warning,
+            NotificationCategory::Citizens,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_priority_threshold() {
+        let filters = NotificationFilters {
+            min_priority: NotificationPriority::Attention,
+            ..Default::default()
+        };
+        let general = NotificationCategory::General;
+        assert!(filters.allows_popup(NotificationPriority::Attention, general, false));
+        assert!(!filters.allows_popup(NotificationPriority::Info, general, false));
+        assert!(!filters.allows_popup(NotificationPriority::Positive, general, false));
+    }
+
+    #[test]
+    fn test_emergencies_always_pop_up() {
+        let mut filters = NotificationFilters {
+            min_priority: NotificationPriority::Emergency,
+            do_not_disturb: true,
+            ..Default::default()
+        };
+        filters.set_category(NotificationCategory::General, false);
+        assert!(filters.allows_popup(
+            NotificationPriority::Emergency,
+            NotificationCategory::General,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_do_not_disturb_only_during_disasters() {
+        let filters = NotificationFilters {
+            do_not_disturb: true,
+            ..Default::default()
+        };
+        let info = NotificationPriority::Info;
+        assert!(filters.allows_popup(info, NotificationCategory::Finance, false));
+        assert!(!filters.allows_popup(info, NotificationCategory::Finance, true));
+        assert!(filters.allows_popup(info, NotificationCategory::Disaster, true));
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut filters = NotificationFilters {
+            min_priority: NotificationPriority::Warning,
+            do_not_disturb: true,
+            ..Default::default()
+        };
+        filters.set_category(NotificationCategory::Achievement, false);
+        let json = filters.to_json().unwrap();
+        assert_eq!(NotificationFilters::from_json(&json).unwrap(), filters);
+        assert_eq!(
+            NotificationFilters::from_json("{}").unwrap(),
+            NotificationFilters::default()
+        );
+    }
+}
@@ -6,6 +6,9 @@
 //!
 //! Emergency notifications persist until manually dismissed; lower-priority
 //! notifications auto-dismiss after a configurable timer.
+//!
+//! Each notification also carries a `NotificationCategory` so the player can
+//! choose which kinds pop up (see `notification_filters`).
 
 use bevy::prelude::*;
 
@@ -17,7 +20,9 @@ use crate::SlowTickTimer;
 // =============================================================================
 
 /// Notification priority, from most to least urgent.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub enum NotificationPriority {
     /// Critical city-wide emergencies (fires, disasters). Persists until dismissed.
     Emergency,
@@ -32,6 +37,15 @@ pub enum NotificationPriority {
 }
 
 impl NotificationPriority {
+    /// All priorities, from most to least urgent.
+    pub const ALL: [NotificationPriority; 5] = [
+        NotificationPriority::Emergency,
+        NotificationPriority::Warning,
+        NotificationPriority::Attention,
+        NotificationPriority::Info,
+        NotificationPriority::Positive,
+    ];
+
     /// Auto-dismiss duration in simulation ticks. `None` means persist until dismissed.
     pub fn auto_dismiss_ticks(&self) -> Option<u32> {
         match self {
@@ -55,6 +69,49 @@ impl NotificationPriority {
     }
 }
 
+// =============================================================================
+// Categories
+// =============================================================================
+
+/// What a notification is about, used to filter popups.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+pub enum NotificationCategory {
+    /// Disasters, hazards and droughts.
+    Disaster,
+    /// Treasury, insurance, council and land deals.
+    Finance,
+    /// Residents' health, unrest and justice.
+    Citizens,
+    /// Milestones and scenario objectives.
+    Achievement,
+    /// Everything else: services, infrastructure, saves.
+    #[default]
+    General,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 5] = [
+        NotificationCategory::Disaster,
+        NotificationCategory::Finance,
+        NotificationCategory::Citizens,
+        NotificationCategory::Achievement,
+        NotificationCategory::General,
+    ];
+
+    /// Human-readable label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationCategory::Disaster => "Disasters",
+            NotificationCategory::Finance => "Finance",
+            NotificationCategory::Citizens => "Citizens",
+            NotificationCategory::Achievement => "Achievements",
+            NotificationCategory::General => "General",
+        }
+    }
+}
+
 // =============================================================================
 // Notification Struct
 // =============================================================================
@@ -68,6 +125,8 @@ pub struct Notification {
     pub text: String,
     /// Priority level (determines color, auto-dismiss, and ordering).
     pub priority: NotificationPriority,
+    /// What the notification is about.
+    pub category: NotificationCategory,
     /// Optional world-space location (x, z) to jump camera to on click.
     pub location: Option<(f32, f32)>,
     /// Game day when the notification was created.
@@ -89,6 +148,7 @@ pub struct Notification {
 pub struct JournalEntry {
     pub text: String,
     pub priority: NotificationPriority,
+    pub category: NotificationCategory,
     pub location: Option<(f32, f32)>,
     pub day: u32,
     pub hour: f32,
@@ -106,6 +166,7 @@ pub struct JournalEntry {
 ///     events.send(NotificationEvent {
 ///         text: "Fire in sector 7!".to_string(),
 ///         priority: NotificationPriority::Emergency,
+///         category: NotificationCategory::Disaster,
 ///         location: Some((128.0, 256.0)),
 ///     });
 /// }
@@ -114,6 +175,7 @@ pub struct JournalEntry {
 pub struct NotificationEvent {
     pub text: String,
     pub priority: NotificationPriority,
+    pub category: NotificationCategory,
     /// Optional world-space location (x, z).
     pub location: Option<(f32, f32)>,
 }
@@ -161,6 +223,7 @@ impl NotificationLog {
             id,
             text: event.text.clone(),
             priority: event.priority,
+            category: event.category,
             location: event.location,
             day: clock.day,
             hour: clock.hour,
@@ -172,6 +235,7 @@ impl NotificationLog {
         self.journal.push(JournalEntry {
             text: event.text.clone(),
             priority: event.priority,
+            category: event.category,
             location: event.location,
             day: clock.day,
             hour: clock.hour,
@@ -293,6 +357,7 @@ mod tests {
         let event = NotificationEvent {
             text: "Test notification".to_string(),
             priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
             location: Some((100.0, 200.0)),
         };
         log.push(&event, &clock, 0);
@@ -310,6 +375,7 @@ mod tests {
         let event = NotificationEvent {
             text: "Dismiss me".to_string(),
             priority: NotificationPriority::Emergency,
+            category: NotificationCategory::General,
             location: None,
         };
         log.push(&event, &clock, 0);
@@ -333,6 +399,7 @@ mod tests {
         let event = NotificationEvent {
             text: "Info event".to_string(),
             priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
             location: None,
         };
         log.push(&event, &clock, 100);
@@ -354,6 +421,7 @@ mod tests {
         let event = NotificationEvent {
             text: "Emergency!".to_string(),
             priority: NotificationPriority::Emergency,
+            category: NotificationCategory::General,
             location: Some((50.0, 50.0)),
         };
         log.push(&event, &clock, 0);
@@ -373,6 +441,7 @@ mod tests {
             let event = NotificationEvent {
                 text: format!("Event {}", i),
                 priority: NotificationPriority::Info,
+                category: NotificationCategory::General,
                 location: None,
             };
             log.push(&event, &clock, i);
@@ -392,6 +461,7 @@ mod tests {
             let event = NotificationEvent {
                 text: "test".to_string(),
                 priority: NotificationPriority::Info,
+                category: NotificationCategory::General,
                 location: None,
             };
             log.push(&event, &clock, 0);
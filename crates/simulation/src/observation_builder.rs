@@ -6,14 +6,16 @@
 use bevy::prelude::*;
 
 use crate::ascii_map;
+use crate::chunked_grid::ChunkedGrid;
+use crate::citizen_aggregates::{CitizenAggregates, Histogram};
 use crate::city_observation::{
     ActionResultEntry, AttractivenessSnapshot, BuildingBreakdown, CityObservation, CityWarning,
-    HappinessSnapshot, PopulationSnapshot, ServiceCoverageSnapshot, ZoneDemandSnapshot,
-    ZoneDistribution,
+    DemographicsSnapshot, DistrictDemographics, HappinessSnapshot, PopulationSnapshot,
+    ServiceCoverageSnapshot, ZoneDemandSnapshot, ZoneDistribution,
 };
-use crate::citizen::{Citizen, WorkLocation};
 use crate::coverage_metrics::CoverageMetrics;
 use crate::crime::CrimeGrid;
+use crate::districts::DistrictMap;
 use crate::economy::CityBudget;
 use crate::game_actions::{ActionResult, ActionResultLog};
 use crate::grid::{WorldGrid, ZoneType};
@@ -69,12 +71,15 @@ pub fn build_observation(
     attract: Res<CityAttractiveness>,
     income_proj: Res<IncomeProjection>,
     happiness_breakdown: Res<HappinessBreakdown>,
-    employed_citizens: Query<(), (With<Citizen>, With<WorkLocation>)>,
+    demographics: (Res<CitizenAggregates>, Res<DistrictMap>),
     mut current: ResMut<CurrentObservation>,
 ) {
     let (traffic_congestion, pollution_grid, crime_grid, city_goods) = grids;
+    let (aggregates, district_map) = demographics;
 
-    let real_employed = employed_citizens.iter().count() as u32;
+    // Employment comes from the slow-tick citizen aggregates rather than a
+    // per-tick population query.
+    let real_employed = aggregates.city.employed;
     let total_employed = real_employed + virtual_pop.virtual_employed;
 
     let population_total = stats.population;
@@ -200,6 +205,8 @@ pub fn build_observation(
         building_breakdown,
         zone_distribution,
 
+        demographics: build_demographics(&aggregates, &district_map),
+
         warnings,
 
         recent_action_results,
@@ -218,9 +225,9 @@ fn compute_zone_distribution(grid: &WorldGrid) -> ZoneDistribution {
     let mut dist = ZoneDistribution::default();
     for cell in &grid.cells {
         match cell.zone {
-            ZoneType::ResidentialLow
-            | ZoneType::ResidentialMedium
-            | ZoneType::ResidentialHigh => dist.residential += 1,
+            ZoneType::ResidentialLow | ZoneType::ResidentialMedium | ZoneType::ResidentialHigh => {
+                dist.residential += 1
+            }
             ZoneType::CommercialLow | ZoneType::CommercialHigh => {
                 dist.commercial += 1;
             }
@@ -233,6 +240,48 @@ fn compute_zone_distribution(grid: &WorldGrid) -> ZoneDistribution {
     dist
 }
 
+// ---------------------------------------------------------------------------
+// Demographics helper
+// ---------------------------------------------------------------------------
+
+fn labelled_bins(histogram: &Histogram) -> Vec<(String, u32)> {
+    (0..histogram.counts.len())
+        .map(|i| (histogram.bin_label(i), histogram.counts[i]))
+        .collect()
+}
+
+/// Flatten the cached citizen aggregates into the agent-facing snapshot.
+fn build_demographics(
+    aggregates: &CitizenAggregates,
+    district_map: &DistrictMap,
+) -> DemographicsSnapshot {
+    let city = &aggregates.city;
+    let districts = district_map
+        .districts
+        .iter()
+        .zip(&aggregates.districts)
+        .filter(|(_, agg)| agg.population > 0)
+        .map(|(district, agg)| DistrictDemographics {
+            name: district.name.clone(),
+            population: agg.population,
+            employment_rate: agg.employment_rate(),
+            avg_happiness: agg.happiness.mean(),
+            avg_income: agg.income.mean(),
+            avg_commute_minutes: agg.commute.mean(),
+        })
+        .collect();
+    DemographicsSnapshot {
+        avg_age: city.age.mean(),
+        avg_income: city.income.mean(),
+        avg_commute_minutes: city.commute.mean(),
+        age_bins: labelled_bins(&city.age),
+        income_bins: labelled_bins(&city.income),
+        happiness_bins: labelled_bins(&city.happiness),
+        commute_bins: labelled_bins(&city.commute),
+        districts,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Warning detection helpers
 // ---------------------------------------------------------------------------
@@ -313,7 +362,7 @@ fn average_traffic_speed(congestion: &TrafficCongestion) -> f32 {
 }
 
 /// Average u8 grid level (pollution, crime, etc.).
-fn average_grid_level(levels: &[u8]) -> f32 {
+fn average_grid_level(levels: &ChunkedGrid<u8>) -> f32 {
     if levels.is_empty() {
         return 0.0;
     }
@@ -0,0 +1,362 @@
+//! Turning parsed OSM data into road segments and zoning.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::config::{CELL_SIZE, WORLD_HEIGHT, WORLD_WIDTH};
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::heightmap_import::BuildabilityGrid;
+use crate::road_segments::RoadSegmentStore;
+use crate::roads::RoadNetwork;
+
+use super::types::{OsmData, OsmImportReport, OsmWay};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Metres per degree of latitude.
+const METRES_PER_DEGREE: f64 = 111_320.0;
+
+/// World units per metre: the extract is laid out at real scale.
+pub const WORLD_UNITS_PER_METRE: f32 = 1.0;
+
+/// Streets stay this far inside the map edge.
+pub const EDGE_MARGIN: f32 = CELL_SIZE;
+
+/// Douglas-Peucker tolerance when simplifying street geometry.
+pub const SIMPLIFY_TOLERANCE: f32 = CELL_SIZE * 0.25;
+
+/// Segment endpoints closer than this share a node.
+const NODE_SNAP: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+// Tag mapping
+// ---------------------------------------------------------------------------
+
+/// The road class for an OSM `highway` value, or `None` for ways the game
+/// has no road for (tracks, steps, construction and so on).
+pub fn road_type_for(highway: &str, oneway: bool) -> Option<RoadType> {
+    let road_type = match highway {
+        "motorway" | "motorway_link" | "trunk" | "trunk_link" => RoadType::Highway,
+        "primary" | "primary_link" => RoadType::Boulevard,
+        "secondary" | "secondary_link" | "tertiary" | "tertiary_link" => RoadType::Avenue,
+        "residential" | "unclassified" | "living_street" | "service" | "road" => {
+            if oneway {
+                RoadType::OneWay
+            } else {
+                RoadType::Local
+            }
+        }
+        "footway" | "path" | "pedestrian" | "cycleway" => RoadType::Path,
+        _ => return None,
+    };
+    Some(road_type)
+}
+
+/// The zone for an OSM `landuse` value.
+pub fn zone_for_landuse(landuse: &str) -> Option<ZoneType> {
+    match landuse {
+        "residential" => Some(ZoneType::ResidentialMedium),
+        "retail" => Some(ZoneType::CommercialLow),
+        "commercial" => Some(ZoneType::Office),
+        "industrial" => Some(ZoneType::Industrial),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Projection
+// ---------------------------------------------------------------------------
+
+/// Equirectangular projection centred on the extract's streets, with north
+/// towards the top of the map (low grid y).
+#[derive(Debug, Clone, Copy)]
+pub struct Projection {
+    lat0: f64,
+    lon0: f64,
+    metres_per_degree_lon: f64,
+}
+
+impl Projection {
+    /// Centre on the bounding box of the nodes used by streets.
+    pub fn centred_on(data: &OsmData) -> Option<Self> {
+        let mut bounds: Option<(f64, f64, f64, f64)> = None;
+        let street_nodes = data
+            .ways
+            .iter()
+            .filter(|w| w.highway().is_some())
+            .flat_map(|w| w.refs.iter())
+            .filter_map(|id| data.nodes.get(id));
+        for &(lat, lon) in street_nodes {
+            bounds = Some(match bounds {
+                None => (lat, lat, lon, lon),
+                Some((a, b, c, d)) => (a.min(lat), b.max(lat), c.min(lon), d.max(lon)),
+            });
+        }
+        let (min_lat, max_lat, min_lon, max_lon) = bounds?;
+        let lat0 = (min_lat + max_lat) * 0.5;
+        Some(Self {
+            lat0,
+            lon0: (min_lon + max_lon) * 0.5,
+            metres_per_degree_lon: METRES_PER_DEGREE * lat0.to_radians().cos(),
+        })
+    }
+
+    pub fn project(&self, lat: f64, lon: f64) -> Vec2 {
+        let east = (lon - self.lon0) * self.metres_per_degree_lon;
+        let north = (lat - self.lat0) * METRES_PER_DEGREE;
+        Vec2::new(
+            WORLD_WIDTH * 0.5 + east as f32 * WORLD_UNITS_PER_METRE,
+            WORLD_HEIGHT * 0.5 - north as f32 * WORLD_UNITS_PER_METRE,
+        )
+    }
+}
+
+fn on_map(p: Vec2) -> bool {
+    p.x >= EDGE_MARGIN
+        && p.y >= EDGE_MARGIN
+        && p.x <= WORLD_WIDTH - EDGE_MARGIN
+        && p.y <= WORLD_HEIGHT - EDGE_MARGIN
+}
+
+// ---------------------------------------------------------------------------
+// Geometry
+// ---------------------------------------------------------------------------
+
+/// Split a way's nodes into pieces that end at junctions and never leave
+/// the map. `None` marks a missing or off-map node.
+fn split_at_junctions(points: &[(i64, Option<Vec2>)], usage: &HashMap<i64, u32>) -> Vec<Vec<Vec2>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<Vec2> = Vec::new();
+    for (i, &(id, pos)) in points.iter().enumerate() {
+        let Some(pos) = pos else {
+            if current.len() >= 2 {
+                pieces.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            continue;
+        };
+        current.push(pos);
+        let is_junction = usage.get(&id).copied().unwrap_or(0) >= 2;
+        if is_junction && i > 0 && i + 1 < points.len() && current.len() >= 2 {
+            pieces.push(std::mem::take(&mut current));
+            current.push(pos);
+        }
+    }
+    if current.len() >= 2 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+fn distance_to_line(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq < f32::EPSILON {
+        return (p - a).length();
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    (p - (a + ab * t)).length()
+}
+
+/// Douglas-Peucker simplification, always keeping both ends.
+pub fn simplify(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let (mut worst, mut worst_dist) = (0, 0.0);
+        for (i, &p) in points.iter().enumerate().take(end).skip(start + 1) {
+            let d = distance_to_line(p, points[start], points[end]);
+            if d > worst_dist {
+                worst = i;
+                worst_dist = d;
+            }
+        }
+        if worst_dist > tolerance {
+            keep[worst] = true;
+            stack.push((start, worst));
+            stack.push((worst, end));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, k)| k.then_some(p))
+        .collect()
+}
+
+/// Cubic Bezier control points through a polyline, with Catmull-Rom
+/// tangents so consecutive segments join smoothly.
+pub fn bezier_chain(points: &[Vec2]) -> Vec<[Vec2; 4]> {
+    let n = points.len();
+    let tangent = |i: usize| -> Vec2 {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(n - 1)];
+        let span = if i == 0 || i == n - 1 { 1.0 } else { 2.0 };
+        (next - prev) / span
+    };
+    (0..n.saturating_sub(1))
+        .map(|i| {
+            let (a, b) = (points[i], points[i + 1]);
+            [a, a + tangent(i) / 3.0, b - tangent(i + 1) / 3.0, b]
+        })
+        .collect()
+}
+
+fn point_in_polygon(p: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[j];
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+/// Lay the extract's streets out as road segments and, if asked, zone the
+/// land along them from OSM land-use areas.
+pub fn apply_osm_import(
+    data: &OsmData,
+    zone_from_landuse: bool,
+    grid: &mut WorldGrid,
+    roads: &mut RoadNetwork,
+    segments: &mut RoadSegmentStore,
+    buildable: &BuildabilityGrid,
+) -> OsmImportReport {
+    let mut report = OsmImportReport::default();
+    let Some(projection) = Projection::centred_on(data) else {
+        return report;
+    };
+
+    let streets: Vec<(&OsmWay, RoadType, bool)> = data
+        .ways
+        .iter()
+        .filter_map(|way| {
+            let (oneway, reversed) = way.oneway();
+            road_type_for(way.highway()?, oneway).map(|rt| (way, rt, reversed))
+        })
+        .collect();
+    report.skipped_ways =
+        data.ways.iter().filter(|w| w.highway().is_some()).count() - streets.len();
+
+    // Nodes used by more than one street, or twice by the same one, are
+    // junctions.
+    let mut usage: HashMap<i64, u32> = HashMap::new();
+    for (way, _, _) in &streets {
+        for id in &way.refs {
+            *usage.entry(*id).or_default() += 1;
+        }
+    }
+
+    for (way, road_type, reversed) in streets {
+        let mut points: Vec<(i64, Option<Vec2>)> = way
+            .refs
+            .iter()
+            .map(|id| {
+                let pos = data
+                    .nodes
+                    .get(id)
+                    .map(|&(lat, lon)| projection.project(lat, lon))
+                    .filter(|&p| on_map(p));
+                (*id, pos)
+            })
+            .collect();
+        if reversed {
+            points.reverse();
+        }
+
+        let before = report.segments;
+        for piece in split_at_junctions(&points, &usage) {
+            let simplified = simplify(&piece, SIMPLIFY_TOLERANCE);
+            for [p0, p1, p2, p3] in bezier_chain(&simplified) {
+                if (p3 - p0).length() < NODE_SNAP {
+                    continue;
+                }
+                let start = segments.find_or_create_node(p0, NODE_SNAP);
+                let end = segments.find_or_create_node(p3, NODE_SNAP);
+                segments.add_segment(start, end, p0, p1, p2, p3, road_type, grid, roads);
+                report.segments += 1;
+            }
+        }
+        if report.segments > before {
+            report.streets += 1;
+        } else {
+            report.skipped_ways += 1;
+        }
+    }
+
+    if zone_from_landuse {
+        report.zoned_cells = zone_landuse(data, &projection, grid, buildable);
+    }
+    report
+}
+
+/// Zone grass cells inside land-use areas that front a road.
+fn zone_landuse(
+    data: &OsmData,
+    projection: &Projection,
+    grid: &mut WorldGrid,
+    buildable: &BuildabilityGrid,
+) -> usize {
+    let mut zoned = 0;
+    for way in data.ways.iter().filter(|w| w.is_closed()) {
+        let Some(zone) = way.tag("landuse").and_then(zone_for_landuse) else {
+            continue;
+        };
+        let polygon: Vec<Vec2> = way
+            .refs
+            .iter()
+            .filter_map(|id| data.nodes.get(id))
+            .map(|&(lat, lon)| projection.project(lat, lon))
+            .collect();
+        if polygon.len() < 3 {
+            continue;
+        }
+
+        let (min, max) = polygon.iter().fold((Vec2::MAX, Vec2::MIN), |(lo, hi), &p| {
+            (lo.min(p), hi.max(p))
+        });
+        let (x0, y0) = WorldGrid::world_to_grid(min.x, min.y);
+        let (x1, y1) = WorldGrid::world_to_grid(max.x, max.y);
+        let clamp_x = |v: i32| v.clamp(0, grid.width as i32 - 1) as usize;
+        let clamp_y = |v: i32| v.clamp(0, grid.height as i32 - 1) as usize;
+        for y in clamp_y(y0)..=clamp_y(y1) {
+            for x in clamp_x(x0)..=clamp_x(x1) {
+                let (wx, wy) = WorldGrid::grid_to_world(x, y);
+                if !point_in_polygon(Vec2::new(wx, wy), &polygon) {
+                    continue;
+                }
+                let cell = grid.get(x, y);
+                if cell.cell_type != CellType::Grass
+                    || cell.zone != ZoneType::None
+                    || !buildable.is_buildable(x, y)
+                {
+                    continue;
+                }
+                let (n4, n4c) = grid.neighbors4(x, y);
+                let fronts_road = n4[..n4c]
+                    .iter()
+                    .any(|&(nx, ny)| grid.get(nx, ny).cell_type == CellType::Road);
+                if fronts_road {
+                    grid.get_mut(x, y).zone = zone;
+                    zoned += 1;
+                }
+            }
+        }
+    }
+    zoned
+}
@@ -0,0 +1,53 @@
+//! OpenStreetMap street network import.
+//!
+//! Reads an OSM extract, either XML (`.osm`) or PBF (`.osm.pbf`), and lays
+//! its streets out on the map as Bezier road segments when a new game
+//! starts. Highway tags decide the road class; ways are split at junctions,
+//! simplified and smoothed into curves. Land-use areas can optionally be
+//! turned into zoning along the imported streets.
+//!
+//! The extract is projected at real scale and centred on the map; streets
+//! beyond the map edge are dropped.
+
+pub mod convert;
+pub mod pbf;
+#[cfg(test)]
+mod tests;
+pub mod types;
+pub mod xml;
+
+pub use convert::{apply_osm_import, road_type_for, zone_for_landuse};
+pub use types::*;
+
+use bevy::prelude::*;
+
+use std::path::Path;
+
+/// Parse an OSM extract, detecting XML or PBF from its contents.
+pub fn parse_osm(bytes: &[u8]) -> Result<OsmData, OsmImportError> {
+    let first = bytes.iter().find(|b| !b.is_ascii_whitespace());
+    let is_xml = first == Some(&b'<') || bytes.starts_with(&[0xEF, 0xBB, 0xBF]);
+    if is_xml {
+        xml::parse_osm_xml(bytes)
+    } else {
+        pbf::parse_osm_pbf(bytes)
+    }
+}
+
+/// Read and parse the OSM extract at `path`.
+pub fn read_osm_file(path: &Path) -> Result<OsmData, OsmImportError> {
+    let bytes = std::fs::read(path).map_err(|e| OsmImportError::Io(e.to_string()))?;
+    let data = parse_osm(&bytes)?;
+    if !data.ways.iter().any(|w| w.highway().is_some()) {
+        return Err(OsmImportError::NoStreets);
+    }
+    Ok(data)
+}
+
+pub struct OsmImportPlugin;
+
+impl Plugin for OsmImportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingOsmImport>();
+    }
+}
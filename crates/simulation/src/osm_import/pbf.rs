@@ -0,0 +1,310 @@
+//! OSM PBF (`.osm.pbf`) parsing.
+//!
+//! A PBF file is a sequence of blobs, each preceded by a length-prefixed
+//! header. `OSMData` blobs hold primitive blocks of nodes (plain or dense)
+//! and ways; relations and metadata are skipped. Only the handful of
+//! protobuf fields the importer needs are decoded.
+
+use std::io::Read;
+
+use super::types::{is_relevant_way, OsmData, OsmImportError, OsmWay};
+
+/// Upper bound on a decompressed blob, from the PBF specification.
+const MAX_BLOB_SIZE: usize = 32 * 1024 * 1024;
+
+/// Parse an OSM PBF file into nodes and the street and land-use ways.
+pub fn parse_osm_pbf(bytes: &[u8]) -> Result<OsmData, OsmImportError> {
+    let mut data = OsmData::default();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let len_bytes = bytes
+            .get(pos..pos + 4)
+            .ok_or_else(|| pbf_error("truncated blob header length"))?;
+        let header_len = u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
+        pos += 4;
+        let header = slice(bytes, pos, header_len)?;
+        pos += header_len;
+
+        let mut blob_type = String::new();
+        let mut data_size = 0usize;
+        for field in Fields::new(header) {
+            match field? {
+                (1, Value::Bytes(b)) => blob_type = String::from_utf8_lossy(b).into_owned(),
+                (3, Value::Varint(v)) => data_size = v as usize,
+                _ => {}
+            }
+        }
+        let blob = slice(bytes, pos, data_size)?;
+        pos += data_size;
+
+        if blob_type == "OSMData" {
+            let block = decode_blob(blob)?;
+            parse_primitive_block(&block, &mut data)?;
+        }
+    }
+    Ok(data)
+}
+
+fn decode_blob(blob: &[u8]) -> Result<Vec<u8>, OsmImportError> {
+    for field in Fields::new(blob) {
+        match field? {
+            (1, Value::Bytes(raw)) => return Ok(raw.to_vec()),
+            (3, Value::Bytes(zlib)) => {
+                let mut out = Vec::new();
+                flate2::read::ZlibDecoder::new(zlib)
+                    .take(MAX_BLOB_SIZE as u64)
+                    .read_to_end(&mut out)
+                    .map_err(|e| pbf_error(&e.to_string()))?;
+                return Ok(out);
+            }
+            (4..=7, Value::Bytes(_)) => return Err(OsmImportError::UnsupportedCompression),
+            _ => {}
+        }
+    }
+    Err(pbf_error("blob has no data"))
+}
+
+/// Coordinate scaling shared by the groups of a primitive block.
+struct BlockInfo<'a> {
+    strings: Vec<&'a [u8]>,
+    granularity: i64,
+    lat_offset: i64,
+    lon_offset: i64,
+}
+
+impl BlockInfo<'_> {
+    fn degrees(&self, raw: i64, offset: i64) -> f64 {
+        (offset + self.granularity * raw) as f64 * 1e-9
+    }
+
+    fn string(&self, index: u64) -> String {
+        self.strings
+            .get(index as usize)
+            .map(|s| String::from_utf8_lossy(s).into_owned())
+            .unwrap_or_default()
+    }
+}
+
+fn parse_primitive_block(block: &[u8], data: &mut OsmData) -> Result<(), OsmImportError> {
+    let mut info = BlockInfo {
+        strings: Vec::new(),
+        granularity: 100,
+        lat_offset: 0,
+        lon_offset: 0,
+    };
+    let mut groups = Vec::new();
+    for field in Fields::new(block) {
+        match field? {
+            (1, Value::Bytes(table)) => {
+                for entry in Fields::new(table) {
+                    if let (1, Value::Bytes(s)) = entry? {
+                        info.strings.push(s);
+                    }
+                }
+            }
+            (2, Value::Bytes(group)) => groups.push(group),
+            (17, Value::Varint(v)) => info.granularity = v as i64,
+            (19, Value::Varint(v)) => info.lat_offset = v as i64,
+            (20, Value::Varint(v)) => info.lon_offset = v as i64,
+            _ => {}
+        }
+    }
+
+    for group in groups {
+        for field in Fields::new(group) {
+            match field? {
+                (1, Value::Bytes(node)) => parse_node(node, &info, data)?,
+                (2, Value::Bytes(dense)) => parse_dense_nodes(dense, &info, data)?,
+                (3, Value::Bytes(way)) => parse_way(way, &info, data)?,
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_node(node: &[u8], info: &BlockInfo, data: &mut OsmData) -> Result<(), OsmImportError> {
+    let (mut id, mut lat, mut lon) = (0, 0, 0);
+    for field in Fields::new(node) {
+        match field? {
+            (1, Value::Varint(v)) => id = zigzag(v),
+            (8, Value::Varint(v)) => lat = zigzag(v),
+            (9, Value::Varint(v)) => lon = zigzag(v),
+            _ => {}
+        }
+    }
+    data.nodes.insert(
+        id,
+        (
+            info.degrees(lat, info.lat_offset),
+            info.degrees(lon, info.lon_offset),
+        ),
+    );
+    Ok(())
+}
+
+fn parse_dense_nodes(
+    dense: &[u8],
+    info: &BlockInfo,
+    data: &mut OsmData,
+) -> Result<(), OsmImportError> {
+    let (mut ids, mut lats, mut lons) = (Vec::new(), Vec::new(), Vec::new());
+    for field in Fields::new(dense) {
+        match field? {
+            (1, Value::Bytes(b)) => ids = packed_sint64(b)?,
+            (8, Value::Bytes(b)) => lats = packed_sint64(b)?,
+            (9, Value::Bytes(b)) => lons = packed_sint64(b)?,
+            _ => {}
+        }
+    }
+    let (mut id, mut lat, mut lon) = (0i64, 0i64, 0i64);
+    for ((did, dlat), dlon) in ids.iter().zip(&lats).zip(&lons) {
+        id += did;
+        lat += dlat;
+        lon += dlon;
+        data.nodes.insert(
+            id,
+            (
+                info.degrees(lat, info.lat_offset),
+                info.degrees(lon, info.lon_offset),
+            ),
+        );
+    }
+    Ok(())
+}
+
+fn parse_way(way: &[u8], info: &BlockInfo, data: &mut OsmData) -> Result<(), OsmImportError> {
+    let mut parsed = OsmWay::default();
+    let (mut keys, mut vals) = (Vec::new(), Vec::new());
+    for field in Fields::new(way) {
+        match field? {
+            (1, Value::Varint(v)) => parsed.id = v as i64,
+            (2, Value::Bytes(b)) => keys = packed_uint(b)?,
+            (3, Value::Bytes(b)) => vals = packed_uint(b)?,
+            (8, Value::Bytes(b)) => {
+                let mut id = 0i64;
+                for delta in packed_sint64(b)? {
+                    id += delta;
+                    parsed.refs.push(id);
+                }
+            }
+            _ => {}
+        }
+    }
+    parsed.tags = keys
+        .iter()
+        .zip(&vals)
+        .map(|(&k, &v)| (info.string(k), info.string(v)))
+        .collect();
+    if is_relevant_way(&parsed.tags) {
+        data.ways.push(parsed);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Protobuf wire format
+// ---------------------------------------------------------------------------
+
+/// A decoded protobuf field value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+/// Iterator over the `(field number, value)` pairs of a message.
+pub(crate) struct Fields<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn next_field(&mut self) -> Result<(u32, Value<'a>), OsmImportError> {
+        let key = read_varint(self.buf, &mut self.pos)?;
+        let number = (key >> 3) as u32;
+        let value = match key & 7 {
+            0 => Value::Varint(read_varint(self.buf, &mut self.pos)?),
+            1 => {
+                self.pos += 8;
+                Value::Fixed
+            }
+            2 => {
+                let len = read_varint(self.buf, &mut self.pos)? as usize;
+                let bytes = slice(self.buf, self.pos, len)?;
+                self.pos += len;
+                Value::Bytes(bytes)
+            }
+            5 => {
+                self.pos += 4;
+                Value::Fixed
+            }
+            wire => return Err(pbf_error(&format!("unsupported wire type {wire}"))),
+        };
+        if self.pos > self.buf.len() {
+            return Err(pbf_error("truncated field"));
+        }
+        Ok((number, value))
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<(u32, Value<'a>), OsmImportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.buf.len() {
+            return None;
+        }
+        let field = self.next_field();
+        if field.is_err() {
+            // Stop after the first error rather than reading garbage.
+            self.pos = self.buf.len();
+        }
+        Some(field)
+    }
+}
+
+pub(crate) fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64, OsmImportError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf.get(*pos).ok_or_else(|| pbf_error("truncated varint"))?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(pbf_error("varint too long"))
+}
+
+pub(crate) fn zigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn packed_uint(buf: &[u8]) -> Result<Vec<u64>, OsmImportError> {
+    let mut pos = 0;
+    let mut out = Vec::new();
+    while pos < buf.len() {
+        out.push(read_varint(buf, &mut pos)?);
+    }
+    Ok(out)
+}
+
+fn packed_sint64(buf: &[u8]) -> Result<Vec<i64>, OsmImportError> {
+    Ok(packed_uint(buf)?.into_iter().map(zigzag).collect())
+}
+
+fn slice(buf: &[u8], pos: usize, len: usize) -> Result<&[u8], OsmImportError> {
+    pos.checked_add(len)
+        .and_then(|end| buf.get(pos..end))
+        .ok_or_else(|| pbf_error("truncated data"))
+}
+
+fn pbf_error(msg: &str) -> OsmImportError {
+    OsmImportError::Pbf(msg.to_string())
+}
@@ -0,0 +1,387 @@
+use bevy::math::Vec2;
+
+use super::pbf::{read_varint, zigzag};
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, RoadType, WorldGrid, ZoneType};
+use crate::heightmap_import::BuildabilityGrid;
+use crate::road_segments::RoadSegmentStore;
+use crate::roads::RoadNetwork;
+
+const SAMPLE_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<osm version="0.6">
+  <node id="1" lat="32.0800" lon="34.7800"/>
+  <node id="2" lat="32.0800" lon="34.7850"/>
+  <node id="3" lat="32.0800" lon="34.7900"/>
+  <node id="4" lat="32.0840" lon="34.7850"/>
+  <node id="5" lat="32.0760" lon="34.7850"/>
+  <node id="10" lat="32.0810" lon="34.7810"/>
+  <node id="11" lat="32.0810" lon="34.7855"/>
+  <node id="12" lat="32.0830" lon="34.7855"/>
+  <node id="13" lat="32.0830" lon="34.7810"/>
+  <way id="100">
+    <nd ref="1"/><nd ref="2"/><nd ref="3"/>
+    <tag k="highway" v="primary"/>
+    <tag k="name" v="Main"/>
+  </way>
+  <way id="101">
+    <nd ref="4"/><nd ref="2"/><nd ref="5"/>
+    <tag k="highway" v="residential"/>
+    <tag k="oneway" v="yes"/>
+  </way>
+  <way id="102">
+    <nd ref="1"/><nd ref="4"/>
+    <tag k="highway" v="steps"/>
+  </way>
+  <way id="103">
+    <nd ref="10"/><nd ref="11"/><nd ref="12"/><nd ref="13"/><nd ref="10"/>
+    <tag k="landuse" v="residential"/>
+  </way>
+  <way id="104">
+    <nd ref="10"/><nd ref="12"/>
+    <tag k="building" v="yes"/>
+  </way>
+</osm>"#;
+
+fn import(data: &OsmData, zone: bool) -> (WorldGrid, RoadSegmentStore, OsmImportReport) {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut roads = RoadNetwork::default();
+    let mut segments = RoadSegmentStore::default();
+    let report = apply_osm_import(
+        data,
+        zone,
+        &mut grid,
+        &mut roads,
+        &mut segments,
+        &BuildabilityGrid::default(),
+    );
+    (grid, segments, report)
+}
+
+#[test]
+fn test_parse_xml_keeps_streets_and_landuse() {
+    let data = parse_osm(SAMPLE_XML.as_bytes()).unwrap();
+    assert_eq!(data.nodes.len(), 9);
+    assert_eq!(data.nodes[&4], (32.084, 34.785));
+    let ids: Vec<i64> = data.ways.iter().map(|w| w.id).collect();
+    assert_eq!(
+        ids,
+        vec![100, 101, 102, 103],
+        "untagged building way dropped"
+    );
+    assert_eq!(data.ways[0].refs, vec![1, 2, 3]);
+    assert_eq!(data.ways[0].tag("name"), Some("Main"));
+    assert_eq!(data.ways[1].oneway(), (true, false));
+    assert!(data.ways[3].is_closed());
+}
+
+#[test]
+fn test_invalid_xml_is_an_error() {
+    assert!(matches!(
+        parse_osm(b"<osm><node id=\"x\"/></osm>"),
+        Err(OsmImportError::Xml(_))
+    ));
+    assert!(matches!(
+        parse_osm(b"<osm><way>"),
+        Err(OsmImportError::Xml(_))
+    ));
+}
+
+#[test]
+fn test_road_classes() {
+    assert_eq!(road_type_for("motorway", false), Some(RoadType::Highway));
+    assert_eq!(road_type_for("trunk_link", false), Some(RoadType::Highway));
+    assert_eq!(road_type_for("primary", false), Some(RoadType::Boulevard));
+    assert_eq!(road_type_for("tertiary", false), Some(RoadType::Avenue));
+    assert_eq!(road_type_for("residential", false), Some(RoadType::Local));
+    assert_eq!(road_type_for("residential", true), Some(RoadType::OneWay));
+    assert_eq!(road_type_for("footway", false), Some(RoadType::Path));
+    assert_eq!(road_type_for("steps", false), None);
+    assert_eq!(zone_for_landuse("industrial"), Some(ZoneType::Industrial));
+    assert_eq!(zone_for_landuse("forest"), None);
+}
+
+#[test]
+fn test_varint_and_zigzag() {
+    let mut pos = 0;
+    assert_eq!(read_varint(&[0xAC, 0x02], &mut pos).unwrap(), 300);
+    assert_eq!(pos, 2);
+    assert!(read_varint(&[0x80], &mut 0).is_err());
+    assert_eq!(zigzag(0), 0);
+    assert_eq!(zigzag(1), -1);
+    assert_eq!(zigzag(2), 1);
+    assert_eq!(zigzag(3), -2);
+}
+
+// ---------------------------------------------------------------------------
+// PBF encoding helpers
+// ---------------------------------------------------------------------------
+
+fn varint(mut v: u64, out: &mut Vec<u8>) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn field_varint(number: u32, v: u64, out: &mut Vec<u8>) {
+    varint((number as u64) << 3, out);
+    varint(v, out);
+}
+
+fn field_bytes(number: u32, bytes: &[u8], out: &mut Vec<u8>) {
+    varint(((number as u64) << 3) | 2, out);
+    varint(bytes.len() as u64, out);
+    out.extend_from_slice(bytes);
+}
+
+fn packed_sint(values: &[i64]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &v in values {
+        varint(((v << 1) ^ (v >> 63)) as u64, &mut out);
+    }
+    out
+}
+
+fn packed_uint(values: &[u64]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &v in values {
+        varint(v, &mut out);
+    }
+    out
+}
+
+fn deltas(values: &[i64]) -> Vec<i64> {
+    let mut prev = 0;
+    values
+        .iter()
+        .map(|&v| {
+            let d = v - prev;
+            prev = v;
+            d
+        })
+        .collect()
+}
+
+/// A one-block PBF file with dense nodes and a single tagged way.
+fn sample_pbf(compressed: bool) -> Vec<u8> {
+    let mut strings = Vec::new();
+    for s in ["", "highway", "secondary"] {
+        field_bytes(1, s.as_bytes(), &mut strings);
+    }
+
+    // Coordinates in units of the default 100 nanodegree granularity.
+    let ids = [7, 8, 9];
+    let lats = [320_800_000, 320_800_000, 320_820_000];
+    let lons = [347_800_000, 347_850_000, 347_850_000];
+    let mut dense = Vec::new();
+    field_bytes(1, &packed_sint(&deltas(&ids)), &mut dense);
+    field_bytes(8, &packed_sint(&deltas(&lats)), &mut dense);
+    field_bytes(9, &packed_sint(&deltas(&lons)), &mut dense);
+
+    let mut way = Vec::new();
+    field_varint(1, 55, &mut way);
+    field_bytes(2, &packed_uint(&[1]), &mut way);
+    field_bytes(3, &packed_uint(&[2]), &mut way);
+    field_bytes(8, &packed_sint(&deltas(&ids)), &mut way);
+
+    let mut group = Vec::new();
+    field_bytes(2, &dense, &mut group);
+    field_bytes(3, &way, &mut group);
+
+    let mut block = Vec::new();
+    field_bytes(1, &strings, &mut block);
+    field_bytes(2, &group, &mut block);
+
+    let mut blob = Vec::new();
+    if compressed {
+        use std::io::Write;
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&block).unwrap();
+        field_varint(2, block.len() as u64, &mut blob);
+        field_bytes(3, &encoder.finish().unwrap(), &mut blob);
+    } else {
+        field_bytes(1, &block, &mut blob);
+    }
+
+    let mut header = Vec::new();
+    field_bytes(1, b"OSMData", &mut header);
+    field_varint(3, blob.len() as u64, &mut header);
+
+    let mut file = (header.len() as u32).to_be_bytes().to_vec();
+    file.extend_from_slice(&header);
+    file.extend_from_slice(&blob);
+    file
+}
+
+#[test]
+fn test_parse_pbf_raw_and_zlib() {
+    for compressed in [false, true] {
+        let data = parse_osm(&sample_pbf(compressed)).unwrap();
+        assert_eq!(data.nodes.len(), 3);
+        let (lat, lon) = data.nodes[&9];
+        assert!((lat - 32.082).abs() < 1e-9, "lat {lat}");
+        assert!((lon - 34.785).abs() < 1e-9, "lon {lon}");
+        assert_eq!(data.ways.len(), 1);
+        assert_eq!(data.ways[0].id, 55);
+        assert_eq!(data.ways[0].refs, vec![7, 8, 9]);
+        assert_eq!(data.ways[0].highway(), Some("secondary"));
+    }
+}
+
+#[test]
+fn test_truncated_pbf_is_an_error() {
+    let file = sample_pbf(false);
+    assert!(matches!(
+        parse_osm(&file[..file.len() - 3]),
+        Err(OsmImportError::Pbf(_))
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Conversion
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_simplify_drops_collinear_points() {
+    let line: Vec<Vec2> = (0..10).map(|i| Vec2::new(i as f32 * 10.0, 0.0)).collect();
+    assert_eq!(simplify_points(&line), vec![line[0], line[9]]);
+
+    let bent = vec![Vec2::ZERO, Vec2::new(50.0, 40.0), Vec2::new(100.0, 0.0)];
+    assert_eq!(simplify_points(&bent), bent);
+}
+
+fn simplify_points(points: &[Vec2]) -> Vec<Vec2> {
+    convert::simplify(points, convert::SIMPLIFY_TOLERANCE)
+}
+
+#[test]
+fn test_bezier_chain_is_continuous() {
+    let points = vec![Vec2::ZERO, Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)];
+    let chain = convert::bezier_chain(&points);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[0][3], chain[1][0]);
+    // Tangents match across the joint.
+    let out_dir = (chain[0][3] - chain[0][2]).normalize();
+    let in_dir = (chain[1][1] - chain[1][0]).normalize();
+    assert!(out_dir.dot(in_dir) > 0.999);
+}
+
+#[test]
+fn test_import_builds_segments_with_road_classes() {
+    let data = parse_osm(SAMPLE_XML.as_bytes()).unwrap();
+    let (grid, segments, report) = import(&data, false);
+
+    assert_eq!(report.streets, 2);
+    assert_eq!(report.skipped_ways, 1, "steps have no road class");
+    // Both streets are split at the shared junction node.
+    assert_eq!(report.segments, 4);
+    assert_eq!(segments.segments.len(), 4);
+    let boulevards = segments
+        .segments
+        .iter()
+        .filter(|s| s.road_type == RoadType::Boulevard)
+        .count();
+    let one_ways = segments
+        .segments
+        .iter()
+        .filter(|s| s.road_type == RoadType::OneWay)
+        .count();
+    assert_eq!((boulevards, one_ways), (2, 2));
+
+    // The junction is a single node shared by all four segments.
+    let junction = segments
+        .nodes
+        .iter()
+        .find(|n| n.connected_segments.len() == 4);
+    assert!(junction.is_some(), "streets meet at one node");
+    let centre = junction.unwrap().position;
+    let (gx, gy) = WorldGrid::world_to_grid(centre.x, centre.y);
+    assert_eq!(grid.get(gx as usize, gy as usize).cell_type, CellType::Road);
+    assert!(grid.cells.iter().all(|c| c.zone == ZoneType::None));
+}
+
+#[test]
+fn test_reverse_oneway_follows_traffic() {
+    let xml = SAMPLE_XML.replace(r#"v="yes""#, r#"v="-1""#);
+    let data = parse_osm(xml.as_bytes()).unwrap();
+    let (_, segments, _) = import(&data, false);
+    // Node 4 is north of node 5, so reversed traffic runs south to north:
+    // towards lower world y.
+    for seg in segments
+        .segments
+        .iter()
+        .filter(|s| s.road_type == RoadType::OneWay)
+    {
+        assert!(seg.p3.y < seg.p0.y);
+    }
+}
+
+#[test]
+fn test_off_map_streets_are_clipped() {
+    // Extend the one-way street 13 km north and south, far off the map.
+    let xml = SAMPLE_XML
+        .replace(
+            r#"<way id="100">"#,
+            r#"<node id="6" lat="32.2000" lon="34.7850"/>
+  <node id="7" lat="31.9600" lon="34.7850"/>
+  <way id="100">"#,
+        )
+        .replace(
+            r#"<nd ref="4"/><nd ref="2"/><nd ref="5"/>"#,
+            r#"<nd ref="6"/><nd ref="4"/><nd ref="2"/><nd ref="5"/><nd ref="7"/>"#,
+        );
+    let data = parse_osm(xml.as_bytes()).unwrap();
+    let (_, segments, report) = import(&data, false);
+    assert_eq!(report.streets, 2);
+    assert_eq!(report.segments, 4, "the off-map legs are dropped");
+    for seg in &segments.segments {
+        for p in [seg.p0, seg.p3] {
+            assert!(p.y > 0.0 && p.y < crate::config::WORLD_HEIGHT);
+        }
+    }
+}
+
+#[test]
+fn test_landuse_zones_cells_fronting_roads() {
+    let data = parse_osm(SAMPLE_XML.as_bytes()).unwrap();
+    let (grid, _, report) = import(&data, true);
+    assert!(report.zoned_cells > 0);
+    let mut zoned = 0;
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let cell = grid.get(x, y);
+            if cell.zone == ZoneType::None {
+                continue;
+            }
+            zoned += 1;
+            assert_eq!(cell.zone, ZoneType::ResidentialMedium);
+            assert_eq!(cell.cell_type, CellType::Grass);
+            let (n4, n4c) = grid.neighbors4(x, y);
+            assert!(n4[..n4c]
+                .iter()
+                .any(|&(nx, ny)| grid.get(nx, ny).cell_type == CellType::Road));
+        }
+    }
+    assert_eq!(zoned, report.zoned_cells);
+}
+
+#[test]
+fn test_landuse_skips_steep_cells() {
+    let data = parse_osm(SAMPLE_XML.as_bytes()).unwrap();
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut buildable = BuildabilityGrid::default();
+    buildable.steep.iter_mut().for_each(|s| *s = true);
+    let report = apply_osm_import(
+        &data,
+        true,
+        &mut grid,
+        &mut RoadNetwork::default(),
+        &mut RoadSegmentStore::default(),
+        &buildable,
+    );
+    assert!(report.segments > 0);
+    assert_eq!(report.zoned_cells, 0);
+}
@@ -0,0 +1,128 @@
+//! Parsed OSM data, import options and errors.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::prelude::*;
+
+// ---------------------------------------------------------------------------
+// Parsed data
+// ---------------------------------------------------------------------------
+
+/// An OSM way with the tags the importer cares about.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OsmWay {
+    pub id: i64,
+    /// Node ids, in order.
+    pub refs: Vec<i64>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl OsmWay {
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn highway(&self) -> Option<&str> {
+        self.tag("highway")
+    }
+
+    /// Whether the way is one-way, and whether it runs against its node
+    /// order (`oneway=-1`).
+    pub fn oneway(&self) -> (bool, bool) {
+        match self.tag("oneway") {
+            Some("yes" | "true" | "1") => (true, false),
+            Some("-1" | "reverse") => (true, true),
+            _ => (false, false),
+        }
+    }
+
+    /// Closed ways outline areas.
+    pub fn is_closed(&self) -> bool {
+        self.refs.len() >= 4 && self.refs.first() == self.refs.last()
+    }
+}
+
+/// Whether a way is worth keeping: streets and land-use areas.
+pub fn is_relevant_way(tags: &[(String, String)]) -> bool {
+    tags.iter().any(|(k, _)| k == "highway" || k == "landuse")
+}
+
+/// Node positions and the relevant ways of an OSM extract.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OsmData {
+    /// Node id to (latitude, longitude) in degrees.
+    pub nodes: HashMap<i64, (f64, f64)>,
+    pub ways: Vec<OsmWay>,
+}
+
+// ---------------------------------------------------------------------------
+// Import options and results
+// ---------------------------------------------------------------------------
+
+/// A parsed extract waiting for the next new game, with the player's
+/// choices.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PendingOsmImport(pub Option<(OsmData, OsmImportOptions)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OsmImportOptions {
+    /// Zone the land along imported streets from OSM land-use areas.
+    pub zone_from_landuse: bool,
+}
+
+impl Default for OsmImportOptions {
+    fn default() -> Self {
+        Self {
+            zone_from_landuse: true,
+        }
+    }
+}
+
+/// What an import placed on the map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OsmImportReport {
+    pub streets: usize,
+    pub segments: usize,
+    pub zoned_cells: usize,
+    /// Streets entirely off the map or of a class the game has no road for.
+    pub skipped_ways: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Why an OSM extract could not be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OsmImportError {
+    Io(String),
+    Xml(String),
+    Pbf(String),
+    /// A PBF blob uses a compression other than zlib.
+    UnsupportedCompression,
+    /// The extract contains no streets.
+    NoStreets,
+}
+
+impl fmt::Display for OsmImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OsmImportError::Io(msg) => write!(f, "Could not read the file: {msg}"),
+            OsmImportError::Xml(msg) => write!(f, "Invalid OSM XML: {msg}"),
+            OsmImportError::Pbf(msg) => write!(f, "Invalid OSM PBF: {msg}"),
+            OsmImportError::UnsupportedCompression => {
+                write!(
+                    f,
+                    "Only zlib-compressed or uncompressed PBF files are supported"
+                )
+            }
+            OsmImportError::NoStreets => write!(f, "The extract contains no streets"),
+        }
+    }
+}
+
+impl std::error::Error for OsmImportError {}
@@ -0,0 +1,59 @@
+//! OSM XML (`.osm`) parsing.
+
+use super::types::{is_relevant_way, OsmData, OsmImportError, OsmWay};
+
+/// Parse an OSM XML document into nodes and the street and land-use ways.
+pub fn parse_osm_xml(bytes: &[u8]) -> Result<OsmData, OsmImportError> {
+    let text = std::str::from_utf8(bytes).map_err(|e| OsmImportError::Xml(e.to_string()))?;
+    let text = text.trim_start_matches('\u{feff}');
+    let doc = roxmltree::Document::parse(text).map_err(|e| OsmImportError::Xml(e.to_string()))?;
+
+    let mut data = OsmData::default();
+    for element in doc.root_element().children().filter(|n| n.is_element()) {
+        match element.tag_name().name() {
+            "node" => {
+                let id = attr::<i64>(&element, "id")?;
+                let lat = attr::<f64>(&element, "lat")?;
+                let lon = attr::<f64>(&element, "lon")?;
+                data.nodes.insert(id, (lat, lon));
+            }
+            "way" => {
+                let mut way = OsmWay {
+                    id: attr::<i64>(&element, "id")?,
+                    ..Default::default()
+                };
+                for child in element.children().filter(|n| n.is_element()) {
+                    match child.tag_name().name() {
+                        "nd" => way.refs.push(attr::<i64>(&child, "ref")?),
+                        "tag" => {
+                            if let (Some(k), Some(v)) = (child.attribute("k"), child.attribute("v"))
+                            {
+                                way.tags.push((k.to_string(), v.to_string()));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                if is_relevant_way(&way.tags) {
+                    data.ways.push(way);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(data)
+}
+
+fn attr<T: std::str::FromStr>(
+    node: &roxmltree::Node<'_, '_>,
+    name: &str,
+) -> Result<T, OsmImportError> {
+    node.attribute(name)
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| {
+            OsmImportError::Xml(format!(
+                "<{}> missing or invalid '{name}'",
+                node.tag_name().name()
+            ))
+        })
+}
@@ -1,6 +1,7 @@
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::{CellType, RoadType, WorldGrid};
 use crate::services::{ServiceBuilding, ServiceType};
+use crate::waterways::{WaterwayNetwork, FERRY_REACH};
 
 use super::types::{ConnectionType, OutsideConnection};
 
@@ -131,15 +132,18 @@ pub(super) fn detect_railway_connections(
     connections
 }
 
-/// Detect sea port connections from FerryPier service buildings near water edge.
+/// Detect sea port connections from FerryPier service buildings near water edge,
+/// or on a carved waterway that joins existing water.
 pub(super) fn detect_seaport_connections(
     services: &[(&ServiceBuilding,)],
     grid: &WorldGrid,
+    waterways: &WaterwayNetwork,
 ) -> Vec<OutsideConnection> {
     let mut connections = Vec::new();
     for (service,) in services {
+        let (x, y) = (service.grid_x, service.grid_y);
         if service.service_type == ServiceType::FerryPier
-            && is_near_water_edge(service.grid_x, service.grid_y, grid)
+            && (is_near_water_edge(x, y, grid) || waterways.is_navigable_near(x, y, FERRY_REACH))
         {
             connections.push(OutsideConnection {
                 connection_type: ConnectionType::SeaPort,
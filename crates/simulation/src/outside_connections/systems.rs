@@ -7,6 +7,7 @@ use crate::production::{CityGoods, GoodsType};
 use crate::services::ServiceBuilding;
 use crate::stats::CityStats;
 use crate::tourism::Tourism;
+use crate::waterways::WaterwayNetwork;
 use crate::TickCounter;
 
 use super::detection::{
@@ -28,7 +29,7 @@ const UPDATE_INTERVAL: u64 = 100;
 /// Runs every 100 ticks. Scans for:
 /// - Highway/boulevard road cells at map edges
 /// - TrainStation near map edge -> Railway
-/// - FerryPier near water edge -> SeaPort
+/// - FerryPier near water edge or on a connected waterway -> SeaPort
 /// - SmallAirstrip/InternationalAirport -> Airport
 ///
 /// Then computes utilization and applies economic effects.
@@ -36,6 +37,7 @@ const UPDATE_INTERVAL: u64 = 100;
 pub fn update_outside_connections(
     tick: Res<TickCounter>,
     grid: Res<WorldGrid>,
+    waterways: Res<WaterwayNetwork>,
     services: Query<&ServiceBuilding>,
     stats: Res<CityStats>,
     mut outside: ResMut<OutsideConnections>,
@@ -56,7 +58,7 @@ pub fn update_outside_connections(
     let mut all_connections = Vec::new();
     all_connections.extend(detect_highway_connections(&grid));
     all_connections.extend(detect_railway_connections(&service_list));
-    all_connections.extend(detect_seaport_connections(&service_list, &grid));
+    all_connections.extend(detect_seaport_connections(&service_list, &grid, &waterways));
     all_connections.extend(detect_airport_connections(&service_list));
 
     // -------------------------------------------------------------------------
@@ -5,6 +5,7 @@ mod tests {
     use crate::outside_connections::detection::*;
     use crate::outside_connections::*;
     use crate::services::{ServiceBuilding, ServiceType};
+    use crate::waterways::{build_network, carve_waterway, WaterwayNetwork, Waterways};
 
     // =========================================================================
     // Railway detection
@@ -86,7 +87,7 @@ mod tests {
             grid_y: 0,
             radius: 30.0,
         },)];
-        let connections = detect_seaport_connections(&services, &grid);
+        let connections = detect_seaport_connections(&services, &grid, &WaterwayNetwork::default());
         assert_eq!(connections.len(), 1);
         assert_eq!(connections[0].connection_type, ConnectionType::SeaPort);
         assert_eq!(connections[0].capacity, 3000);
@@ -102,7 +103,7 @@ mod tests {
             grid_y: 128,
             radius: 30.0,
         },)];
-        let connections = detect_seaport_connections(&services, &grid);
+        let connections = detect_seaport_connections(&services, &grid, &WaterwayNetwork::default());
         assert!(connections.is_empty());
     }
 
@@ -117,10 +118,34 @@ mod tests {
             grid_y: 128,
             radius: 30.0,
         },)];
-        let connections = detect_seaport_connections(&services, &grid);
+        let connections = detect_seaport_connections(&services, &grid, &WaterwayNetwork::default());
         assert!(connections.is_empty());
     }
 
+    #[test]
+    fn test_seaport_detected_for_ferry_pier_on_connected_canal() {
+        let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        for y in 0..GRID_HEIGHT {
+            grid.get_mut(128, y).cell_type = CellType::Water;
+        }
+        let mut waterways = Waterways::default();
+        for x in (110..=126).step_by(2) {
+            carve_waterway(&mut grid, &mut waterways, x, 100);
+        }
+        let services = vec![(&ServiceBuilding {
+            service_type: ServiceType::FerryPier,
+            grid_x: 110,
+            grid_y: 103,
+            radius: 30.0,
+        },)];
+        assert!(
+            detect_seaport_connections(&services, &grid, &WaterwayNetwork::default()).is_empty()
+        );
+        let network = build_network(&grid, &waterways, 0.0);
+        let connections = detect_seaport_connections(&services, &grid, &network);
+        assert_eq!(connections.len(), 1);
+    }
+
     // =========================================================================
     // Airport detection
     // =========================================================================
@@ -252,7 +277,8 @@ mod tests {
             grid_y: 0,
             radius: 30.0,
         },)];
-        let port_conns = detect_seaport_connections(&port_services, &water_grid);
+        let port_conns =
+            detect_seaport_connections(&port_services, &water_grid, &WaterwayNetwork::default());
         assert_eq!(
             port_conns[0].capacity, 3000,
             "SeaPort capacity should be 3000"
@@ -0,0 +1,161 @@
+//! Bit-packed storage for boolean and low-range per-cell layers.
+//!
+//! A `Vec<bool>` or `Vec<u8>` layer spends a byte per cell even when it only
+//! needs one or two bits, so a full sweep of it touches up to eight times the
+//! cache lines its data needs. `BitGrid` stores one bit per cell and
+//! `PackedGrid<BITS>` stores values below `2^BITS` in 1, 2, 4 or 8 bits, both
+//! in `u64` words. Cells are indexed row-major (`y * width + x`) like every
+//! other grid, so index math carries over unchanged.
+//!
+//! Save formats are not affected: layers that persist convert to and from
+//! `Vec<bool>` / `Vec<u8>` at the save boundary.
+
+mod packed;
+#[cfg(test)]
+mod tests;
+
+pub use packed::PackedGrid;
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// One bit per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl Default for BitGrid {
+    fn default() -> Self {
+        Self::new(GRID_WIDTH, GRID_HEIGHT)
+    }
+}
+
+impl BitGrid {
+    /// A grid with every cell cleared.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            words: vec![0; (width * height).div_ceil(WORD_BITS)],
+            width,
+            height,
+        }
+    }
+
+    /// Pack a row-major slice of `width * height` flags.
+    pub fn from_bools(width: usize, height: usize, values: &[bool]) -> Self {
+        let mut grid = Self::new(width, height);
+        for (idx, _) in values.iter().enumerate().filter(|(_, &v)| v) {
+            grid.set_index(idx, true);
+        }
+        grid
+    }
+
+    /// Unpack into a row-major `Vec<bool>`.
+    pub fn to_bools(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether `(x, y)` is set; out-of-bounds cells read as unset.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.get_index(y * self.width + x)
+    }
+
+    /// Set or clear `(x, y)`; out-of-bounds writes are ignored.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        if x < self.width && y < self.height {
+            self.set_index(y * self.width + x, value);
+        }
+    }
+
+    /// Whether the cell at a row-major index is set.
+    #[inline]
+    pub fn get_index(&self, idx: usize) -> bool {
+        idx < self.len() && (self.words[idx / WORD_BITS] >> (idx % WORD_BITS)) & 1 == 1
+    }
+
+    #[inline]
+    pub fn set_index(&mut self, idx: usize, value: bool) {
+        if idx >= self.len() {
+            return;
+        }
+        let mask = 1u64 << (idx % WORD_BITS);
+        let word = &mut self.words[idx / WORD_BITS];
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        if value {
+            self.clear_padding();
+        }
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(|idx| self.get_index(idx))
+    }
+
+    /// Row-major indices of the set cells, skipping empty words.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut bits = word;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(w * WORD_BITS + bit)
+            })
+        })
+    }
+
+    /// Bytes of cell storage.
+    pub fn storage_bytes(&self) -> usize {
+        self.words.len() * std::mem::size_of::<u64>()
+    }
+
+    /// Keep the bits past the last cell clear so counts stay exact.
+    fn clear_padding(&mut self) {
+        let used = self.len() % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << used) - 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+
+use super::WORD_BITS;
+
+/// Values `0..=PackedGrid::<BITS>::MAX` packed `BITS` bits per cell.
+///
+/// `BITS` must divide 64 (1, 2, 4 or 8) so no value straddles two words.
+/// Writes above `MAX` saturate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedGrid<const BITS: usize> {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl<const BITS: usize> Default for PackedGrid<BITS> {
+    fn default() -> Self {
+        Self::new(GRID_WIDTH, GRID_HEIGHT)
+    }
+}
+
+impl<const BITS: usize> PackedGrid<BITS> {
+    /// Largest storable value.
+    pub const MAX: u8 = ((1u16 << BITS) - 1) as u8;
+    const PER_WORD: usize = WORD_BITS / BITS;
+    const MASK: u64 = Self::MAX as u64;
+    const VALID_WIDTH: () = assert!(
+        matches!(BITS, 1 | 2 | 4 | 8),
+        "PackedGrid supports 1, 2, 4 or 8 bits per cell"
+    );
+
+    /// A grid with every cell at 0.
+    pub fn new(width: usize, height: usize) -> Self {
+        let () = Self::VALID_WIDTH;
+        Self {
+            words: vec![0; (width * height).div_ceil(Self::PER_WORD)],
+            width,
+            height,
+        }
+    }
+
+    /// Pack a row-major slice of `width * height` values, saturating at `MAX`.
+    pub fn from_values(width: usize, height: usize, values: &[u8]) -> Self {
+        let mut grid = Self::new(width, height);
+        for (idx, &v) in values.iter().enumerate().filter(|(_, &v)| v != 0) {
+            grid.set_index(idx, v);
+        }
+        grid
+    }
+
+    /// Unpack into a row-major `Vec<u8>`.
+    pub fn to_values(&self) -> Vec<u8> {
+        self.iter().collect()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Value at `(x, y)`; out-of-bounds cells read as 0.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        if x < self.width && y < self.height {
+            self.get_index(y * self.width + x)
+        } else {
+            0
+        }
+    }
+
+    /// Write `(x, y)`; out-of-bounds writes are ignored.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: u8) {
+        if x < self.width && y < self.height {
+            self.set_index(y * self.width + x, value);
+        }
+    }
+
+    /// Value at a row-major index; out-of-range indices read as 0.
+    #[inline]
+    pub fn get_index(&self, idx: usize) -> u8 {
+        if idx >= self.len() {
+            return 0;
+        }
+        let shift = (idx % Self::PER_WORD) * BITS;
+        ((self.words[idx / Self::PER_WORD] >> shift) & Self::MASK) as u8
+    }
+
+    #[inline]
+    pub fn set_index(&mut self, idx: usize, value: u8) {
+        if idx >= self.len() {
+            return;
+        }
+        let shift = (idx % Self::PER_WORD) * BITS;
+        let word = &mut self.words[idx / Self::PER_WORD];
+        *word = (*word & !(Self::MASK << shift)) | ((value.min(Self::MAX) as u64) << shift);
+    }
+
+    pub fn fill(&mut self, value: u8) {
+        let value = value.min(Self::MAX) as u64;
+        let pattern = (0..Self::PER_WORD).fold(0u64, |w, i| w | (value << (i * BITS)));
+        self.words.fill(pattern);
+        let used = self.len() % Self::PER_WORD;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << (used * BITS)) - 1;
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..self.len()).map(|idx| self.get_index(idx))
+    }
+
+    /// Sum of every cell's value.
+    pub fn sum(&self) -> u64 {
+        self.iter().map(u64::from).sum()
+    }
+
+    /// Bytes of cell storage.
+    pub fn storage_bytes(&self) -> usize {
+        self.words.len() * std::mem::size_of::<u64>()
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+fn test_bit_grid_matches_vec_bool() {
+    // 13 × 11 = 143 cells: the last word is partly padding.
+    let (w, h) = (13, 11);
+    let mut expected = vec![false; w * h];
+    let mut grid = BitGrid::new(w, h);
+    for idx in (0..w * h).filter(|i| i % 3 == 0 || i % 7 == 0) {
+        expected[idx] = true;
+        grid.set_index(idx, true);
+    }
+    grid.set(4, 2, false);
+    expected[2 * w + 4] = false;
+
+    assert_eq!(grid.to_bools(), expected);
+    assert_eq!(grid.count_ones(), expected.iter().filter(|&&v| v).count());
+    let ones: Vec<usize> = grid.iter_ones().collect();
+    let expected_ones: Vec<usize> = (0..w * h).filter(|&i| expected[i]).collect();
+    assert_eq!(ones, expected_ones);
+    assert_eq!(BitGrid::from_bools(w, h, &expected), grid);
+}
+
+#[test]
+fn test_bit_grid_bounds_and_fill() {
+    let mut grid = BitGrid::new(10, 10);
+    grid.set(10, 0, true);
+    grid.set_index(100, true);
+    assert!(!grid.any());
+    assert!(!grid.get(10, 0));
+
+    grid.fill(true);
+    assert_eq!(grid.count_ones(), 100, "padding bits stay clear");
+    assert!(grid.get(9, 9));
+    grid.fill(false);
+    assert_eq!(grid.count_ones(), 0);
+}
+
+#[test]
+fn test_bit_grid_is_an_eighth_of_vec_bool() {
+    let grid = BitGrid::default();
+    assert_eq!(grid.len(), GRID_WIDTH * GRID_HEIGHT);
+    assert_eq!(grid.storage_bytes() * 8, GRID_WIDTH * GRID_HEIGHT);
+}
+
+#[test]
+fn test_packed_grid_round_trips_values() {
+    let (w, h) = (9, 7);
+    let values: Vec<u8> = (0..w * h).map(|i| (i % 4) as u8).collect();
+    let grid = PackedGrid::<2>::from_values(w, h, &values);
+    assert_eq!(grid.to_values(), values);
+    assert_eq!(grid.get(3, 1), values[w + 3]);
+    assert_eq!(grid.get(w, 0), 0);
+    assert_eq!(grid.sum(), values.iter().map(|&v| v as u64).sum::<u64>());
+}
+
+#[test]
+fn test_packed_grid_saturates_and_isolates_neighbours() {
+    let mut grid = PackedGrid::<4>::new(5, 5);
+    grid.set(1, 1, 200);
+    assert_eq!(grid.get(1, 1), PackedGrid::<4>::MAX);
+    assert_eq!(grid.get(0, 1), 0);
+    assert_eq!(grid.get(2, 1), 0);
+
+    grid.set(1, 1, 3);
+    assert_eq!(grid.get(1, 1), 3);
+
+    grid.fill(9);
+    assert!(grid.iter().all(|v| v == 9));
+    assert_eq!(grid.sum(), 9 * 25);
+}
+
+#[test]
+fn test_packed_grid_storage_scales_with_bits() {
+    assert_eq!(
+        PackedGrid::<1>::default().storage_bytes() * 8,
+        GRID_WIDTH * GRID_HEIGHT
+    );
+    assert_eq!(
+        PackedGrid::<2>::default().storage_bytes() * 4,
+        GRID_WIDTH * GRID_HEIGHT
+    );
+    assert_eq!(
+        PackedGrid::<8>::default().storage_bytes(),
+        GRID_WIDTH * GRID_HEIGHT
+    );
+}
@@ -189,6 +189,20 @@ impl ParkDistrictState {
     pub fn get_district_mut(&mut self, id: u32) -> Option<&mut ParkDistrict> {
         self.districts.iter_mut().find(|d| d.id == id)
     }
+
+    pub fn nature_reserves(&self) -> impl Iterator<Item = &ParkDistrict> {
+        self.districts.iter().filter(|d| d.park_type == ParkType::NatureReserve)
+    }
+
+    /// Whether a nature reserve already covers the given cell.
+    pub fn nature_reserve_at(&self, x: usize, y: usize) -> bool {
+        self.nature_reserves().any(|d| {
+            let dx = x as i32 - d.center_x as i32;
+            let dy = y as i32 - d.center_y as i32;
+            let r = d.radius_cells();
+            dx * dx + dy * dy <= r * r
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
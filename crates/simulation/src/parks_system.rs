@@ -14,6 +14,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use crate::packed_grid::BitGrid;
 use crate::services::{ServiceBuilding, ServiceType};
 use crate::SlowTickTimer;
 
@@ -55,7 +56,7 @@ const LARGE_PARK_POLLUTION_REDUCTION: u8 = 8;
 // ---------------------------------------------------------------------------
 
 /// Precomputed per-cell park effects, updated each slow tick.
-#[derive(Resource, Clone, Serialize, Deserialize, bitcode::Encode, bitcode::Decode)]
+#[derive(Resource, Clone)]
 pub struct ParkEffectsGrid {
     /// Happiness bonus per cell from differentiated park tiers.
     pub happiness_bonus: Vec<f32>,
@@ -66,9 +67,9 @@ pub struct ParkEffectsGrid {
     /// Pollution reduction per cell from LargePark proximity.
     pub pollution_reduction: Vec<u8>,
     /// Whether the cell has playground coverage (family happiness).
-    pub has_playground: Vec<bool>,
+    pub has_playground: BitGrid,
     /// Whether the cell has plaza commercial boost.
-    pub has_plaza_boost: Vec<bool>,
+    pub has_plaza_boost: BitGrid,
 }
 
 impl Default for ParkEffectsGrid {
@@ -79,8 +80,8 @@ impl Default for ParkEffectsGrid {
             land_value_bonus: vec![0.0; n],
             health_bonus: vec![0.0; n],
             pollution_reduction: vec![0; n],
-            has_playground: vec![false; n],
-            has_plaza_boost: vec![false; n],
+            has_playground: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
+            has_plaza_boost: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
         }
     }
 }
@@ -270,7 +271,7 @@ fn apply_tier_effects(service_type: ServiceType, idx: usize, effects: &mut ParkE
             // Playground gives happiness to all, but the family bonus is tracked separately
             effects.happiness_bonus[idx] =
                 effects.happiness_bonus[idx].max(PLAYGROUND_HAPPINESS);
-            effects.has_playground[idx] = true;
+            effects.has_playground.set_index(idx, true);
         }
         ServiceType::LargePark => {
             effects.happiness_bonus[idx] = effects.happiness_bonus[idx].max(LARGE_PARK_HAPPINESS);
@@ -287,7 +288,7 @@ fn apply_tier_effects(service_type: ServiceType, idx: usize, effects: &mut ParkE
         }
         ServiceType::Plaza => {
             effects.happiness_bonus[idx] = effects.happiness_bonus[idx].max(PLAZA_HAPPINESS);
-            effects.has_plaza_boost[idx] = true;
+            effects.has_plaza_boost.set_index(idx, true);
             effects.land_value_bonus[idx] =
                 effects.land_value_bonus[idx].max(PLAZA_COMMERCIAL_BOOST);
         }
@@ -363,7 +364,7 @@ mod tests {
         let idx = ParkEffectsGrid::idx(5, 5);
         apply_tier_effects(ServiceType::Playground, idx, &mut effects);
         assert!((effects.happiness_bonus[idx] - PLAYGROUND_HAPPINESS).abs() < f32::EPSILON);
-        assert!(effects.has_playground[idx]);
+        assert!(effects.has_playground.get_index(idx));
     }
 
     #[test]
@@ -381,7 +382,7 @@ mod tests {
         let idx = ParkEffectsGrid::idx(5, 5);
         apply_tier_effects(ServiceType::Plaza, idx, &mut effects);
         assert!((effects.happiness_bonus[idx] - PLAZA_HAPPINESS).abs() < f32::EPSILON);
-        assert!(effects.has_plaza_boost[idx]);
+        assert!(effects.has_plaza_boost.get_index(idx));
     }
 
     #[test]
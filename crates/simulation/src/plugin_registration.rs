@@ -11,6 +11,8 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     // Core simulation chain
     app.add_plugins(sim_rng::SimRngPlugin);
     app.add_plugins(game_params::GameParamsPlugin);
+    app.add_plugins(grid_chunk_activity::GridChunkActivityPlugin);
+    app.add_plugins(dirty_chunks::DirtyChunksPlugin);
     app.add_plugins(time_of_day::TimeOfDayPlugin);
     app.add_plugins(zones::ZonesPlugin);
     app.add_plugins(buildings::BuildingsPlugin);
@@ -33,12 +35,14 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(service_budget::ServiceBudgetPlugin);
     app.add_plugins(stats::StatsPlugin);
     app.add_plugins(chart_data::ChartDataPlugin);
+    app.add_plugins(timelapse::TimelapsePlugin);
     app.add_plugins(utilities::UtilitiesPlugin);
     app.add_plugins(network_viz::NetworkVizPlugin);
     app.add_plugins(education::EducationPlugin);
     app.add_plugins(education_pipeline::EducationPipelinePlugin);
 
     // Pollution, land value, garbage, districts
+    app.add_plugins(simulation_sets::GridPropagationPlugin);
     app.add_plugins(pollution::PollutionPlugin);
     app.add_plugins(building_emissions::BuildingEmissionsPlugin);
     app.add_plugins(pollution_health::PollutionHealthPlugin);
@@ -47,12 +51,15 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(garbage::GarbagePlugin);
     app.add_plugins(districts::DistrictsPlugin);
     app.add_plugins(district_policies::DistrictPoliciesPlugin);
+    app.add_plugins(district_history::DistrictHistoryPlugin);
     app.add_plugins(policy_effects::PolicyTradeoffsPlugin);
     app.add_plugins(districts_save::DistrictSavePlugin);
+    app.add_plugins(auto_district::AutoDistrictPlugin);
     app.add_plugins(superblock::SuperblockPlugin);
     app.add_plugins(superblock_policy::SuperblockPolicyPlugin);
     app.add_plugins(neighborhood_quality::NeighborhoodQualityPlugin);
     app.add_plugins(lifecycle::LifecyclePlugin);
+    app.add_plugins(building_events::BuildingEventsPlugin);
     app.add_plugins(building_upgrade::BuildingUpgradePlugin);
     app.add_plugins(imports_exports::ImportsExportsPlugin);
     app.add_plugins(historic_preservation::HistoricPreservationPlugin);
@@ -104,7 +111,9 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(noise_barriers::NoiseBarriersPlugin);
     app.add_plugins(crime::CrimePlugin);
     app.add_plugins(crime_justice::CrimeJusticePlugin);
+    app.add_plugins(prison_system::PrisonSystemPlugin);
     app.add_plugins(police_tiers::PoliceTiersPlugin);
+    app.add_plugins(police_patrol::PolicePatrolPlugin);
     app.add_plugins(health::HealthPlugin);
     app.add_plugins(disease_model::DiseaseModelPlugin);
     app.add_plugins(death_care::DeathCarePlugin);
@@ -120,6 +129,7 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(water_demand::WaterDemandPlugin);
     app.add_plugins(heat_wave::HeatWavePlugin);
     app.add_plugins(heat_mitigation::HeatMitigationPlugin);
+    app.add_plugins(heat_health::HeatHealthPlugin);
     app.add_plugins(composting::CompostingPlugin);
     app.add_plugins(cold_snap::ColdSnapPlugin);
     app.add_plugins(cso::CsoPlugin);
@@ -145,9 +155,13 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(wealth::WealthPlugin);
     app.add_plugins(tourism::TourismPlugin);
     app.add_plugins(hotel_demand::HotelDemandPlugin);
+    app.add_plugins(hotels::HotelsPlugin);
     app.add_plugins(unlocks::UnlocksPlugin);
     app.add_plugins(milestones::MilestonesPlugin);
+    app.add_plugins(scenario::ScenarioPlugin);
     app.add_plugins(reservoir::ReservoirPlugin);
+    app.add_plugins(drought_staging::DroughtStagingPlugin);
+    app.add_plugins(water_rights::WaterRightsPlugin);
     app.add_plugins(flood_simulation::FloodSimulationPlugin);
     app.add_plugins(flood_protection::FloodProtectionPlugin);
     app.add_plugins(stormwater_mgmt::StormwaterMgmtPlugin);
@@ -155,6 +169,7 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(tree_absorption::TreeAbsorptionPlugin);
     app.add_plugins(airport::AirportPlugin);
     app.add_plugins(metro_transit::MetroTransitPlugin);
+    app.add_plugins(underground::UndergroundPlugin);
     app.add_plugins(train_transit::TrainTransitPlugin);
     app.add_plugins(snow::SnowPlugin);
     app.add_plugins(solar_power::SolarPowerPlugin);
@@ -162,14 +177,18 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     // Transit and connections
     app.add_plugins(tram_transit::TramTransitPlugin);
     app.add_plugins(outside_connections::OutsideConnectionsPlugin);
+    app.add_plugins(commute_grids::CommuteGridsPlugin);
 
     // Production and economy
     app.add_plugins(agriculture::AgriculturePlugin);
     app.add_plugins(production::ProductionPlugin);
     app.add_plugins(market::MarketPlugin);
     app.add_plugins(events::EventsPlugin);
+    app.add_plugins(event_catalog::EventCatalogPlugin);
     app.add_plugins(event_journal_save::EventJournalSavePlugin);
     app.add_plugins(notifications::NotificationsPlugin);
+    app.add_plugins(notification_filters::NotificationFiltersPlugin);
+    app.add_plugins(watchlist::WatchlistPlugin);
     app.add_plugins(specialization::SpecializationPlugin);
     app.add_plugins(specialization_save::SpecializationSavePlugin);
     app.add_plugins(advisors::AdvisorsPlugin);
@@ -183,6 +202,21 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(fire_tiers::FireTiersPlugin);
     app.add_plugins(forest_fire::ForestFirePlugin);
     app.add_plugins(disasters::DisastersPlugin);
+    app.add_plugins(seismic::SeismicPlugin);
+    app.add_plugins(hurricane::HurricanePlugin);
+    app.add_plugins(structural_integrity::StructuralIntegrityPlugin);
+    app.add_plugins(hazmat::HazmatPlugin);
+    app.add_plugins(unrest::UnrestPlugin);
+    app.add_plugins(disaster_insurance::DisasterInsurancePlugin);
+    app.add_plugins(disaster_recovery::DisasterRecoveryPlugin);
+    app.add_plugins(carbon_ledger::CarbonLedgerPlugin);
+    app.add_plugins(air_quality::AirQualityPlugin);
+    app.add_plugins(wildlife::WildlifePlugin);
+    app.add_plugins(coastal_erosion::CoastalErosionPlugin);
+    app.add_plugins(river_flow::RiverFlowPlugin);
+    app.add_plugins(harvest::HarvestPlugin);
+    app.add_plugins(urban_forestry::UrbanForestryPlugin);
+    app.add_plugins(street_lighting::StreetLightingPlugin);
     app.add_plugins(disaster_save::DisasterSavePlugin);
     app.add_plugins(emergency_management::EmergencyManagementPlugin);
 
@@ -190,13 +224,18 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(life_simulation::LifeSimulationPlugin);
     app.add_plugins(homelessness::HomelessnessPlugin);
     app.add_plugins(welfare::WelfarePlugin);
+    app.add_plugins(citizen_aggregates::CitizenAggregatesPlugin);
+    app.add_plugins(city_council::CityCouncilPlugin);
     app.add_plugins(daycare_eldercare::DaycareEldercarePlugin);
     app.add_plugins(immigration::ImmigrationPlugin);
     app.add_plugins(population_tiers::PopulationTiersPlugin);
     app.add_plugins(lod::LodPlugin);
     app.add_plugins(virtual_population::VirtualPopulationPlugin);
     app.add_plugins(virtual_population_save::VirtualPopulationSavePlugin);
+    app.add_plugins(virtual_cohorts::VirtualCohortsPlugin);
     app.add_plugins(urban_growth_boundary::UrbanGrowthBoundaryPlugin);
+    app.add_plugins(land_ownership::LandOwnershipPlugin);
+    app.add_plugins(map_tiles::MapTilesPlugin);
     app.add_plugins(nimby::NimbyPlugin);
     app.add_plugins(walkability::WalkabilityPlugin);
     app.add_plugins(form_transect::FormTransectPlugin);
@@ -216,10 +255,18 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
 
     // Accessibility
     app.add_plugins(colorblind::ColorblindPlugin);
+    app.add_plugins(ui_scale::UiScalePlugin);
+    app.add_plugins(accessibility::AccessibilityPlugin);
 
     // Customizable keybindings
     app.add_plugins(keybindings::KeyBindingsPlugin);
 
+    // Player profile (settings and progress shared by every city)
+    app.add_plugins(profile::ProfilePlugin);
+
+    // Mod packages: load order, compatibility checks and save records
+    app.add_plugins(mods::ModsPlugin);
+
     // Freehand road drawing (UX-020)
     app.add_plugins(freehand_road::FreehandRoadPlugin);
 
@@ -282,9 +329,13 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
 
     // Industrial specializations (SERV-008)
     app.add_plugins(industrial_specializations::IndustrialSpecializationPlugin);
+    // Per-district architecture styles for building models
+    app.add_plugins(district_styles::DistrictStylesPlugin);
     // Park district system with levels (SERV-007)
     app.add_plugins(park_districts::ParkDistrictPlugin);
     app.add_plugins(diagnostics::DiagnosticsPlugin);
+    // Tick timing and gauges for the metrics exporter, collected on demand
+    app.add_plugins(sim_metrics::SimMetricsPlugin);
 
     // Post-load derived state rebuild (SAVE-026)
     app.add_plugins(post_load_rebuild::PostLoadRebuildPlugin);
@@ -295,8 +346,14 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
     app.add_plugins(cultural_buildings::CulturalBuildingsPlugin);
     // Service building capacity limits and staffing (SVC-002)
     app.add_plugins(service_building_capacity::ServiceBuildingCapacityPlugin);
+    app.add_plugins(service_upgrade::ServiceUpgradePlugin);
+    app.add_plugins(coverage_gaps::CoverageGapsPlugin);
     // Procedural terrain generation (REND-002)
     app.add_plugins(terrain_generation::TerrainGenerationPlugin);
+    app.add_plugins(heightmap_import::HeightmapImportPlugin);
+    app.add_plugins(osm_import::OsmImportPlugin);
+    app.add_plugins(earthworks::EarthworksPlugin);
+    app.add_plugins(waterways::WaterwaysPlugin);
     // Multiple Named Save Slots (SAVE-014)
     app.add_plugins(save_slots::SaveSlotsPlugin);
     // Hydroelectric Dam Power Plant (POWER-007)
@@ -361,8 +418,10 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
 
     // New game map options (PLAY-019)
     app.add_plugins(new_game_config::NewGameConfigPlugin);
+    app.add_plugins(game_settings::GameSettingsPlugin);
     // Bankruptcy and game over warning (PLAY-021)
     app.add_plugins(bankruptcy_warning::BankruptcyWarningPlugin);
+    app.add_plugins(receivership::ReceivershipPlugin);
 
     // Input action recorder for deterministic replay (STAB-03)
     app.add_plugins(input_recorder::InputRecorderPlugin);
@@ -384,4 +443,7 @@ pub(crate) fn register_feature_plugins(app: &mut App) {
 
     // WorldSnapshot spatial state serialization (#1903)
     app.add_plugins(world_snapshot::WorldSnapshotPlugin);
+
+    // Lua scripts from the mods folder (native only)
+    app.add_plugins(scripting::ScriptingPlugin);
 }
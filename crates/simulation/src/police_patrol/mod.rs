@@ -0,0 +1,26 @@
+//! Police patrol routes and response-time model.
+//!
+//! Replaces static radius coverage with patrol units. Each police building
+//! fields a number of units (scaled by tier, police budget, and the HQ
+//! coordination bonus) that are assigned to beats — one per 16x16
+//! statistical district — weighted by crime demand and distance.
+//!
+//! - **Patrol frequency**: a beat's patrol passes are shared by its road
+//!   segments, mostly along the crime beside each one. Cells near a road are
+//!   deterred by how often that road is patrolled; cells away from roads get
+//!   half the beat's average.
+//! - **Response time**: dispatch overhead plus travel from the nearest unit,
+//!   inflated when open incidents from `CrimeJusticeState` outnumber units.
+//!   Fast responses add extra deterrence.
+//!
+//! Both effects are subtracted from the `CrimeGrid`, which in turn feeds the
+//! per-district crime stats. Per-district response times are exposed on
+//! `PolicePatrolState` for safety dashboards.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_police_patrols, PolicePatrolPlugin};
+pub use types::*;
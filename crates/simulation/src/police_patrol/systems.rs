@@ -0,0 +1,362 @@
+//! Patrol assignment, response-time estimation, and crime deterrence.
+
+use bevy::prelude::*;
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::crime::CrimeGrid;
+use crate::crime_justice::{CrimeJusticeState, JusticeStage};
+use crate::districts::{DistrictMap, DISTRICTS_X, DISTRICTS_Y, DISTRICT_SIZE};
+use crate::grid::{CellType, WorldGrid};
+use crate::police_tiers::{PoliceTier, PoliceTiersState};
+use crate::road_segments::RoadSegmentStore;
+use crate::services::ServiceBuilding;
+
+use super::types::*;
+
+/// A police building fielding patrol units this tick.
+struct PatrolStation {
+    tier: PoliceTier,
+    grid_x: usize,
+    grid_y: usize,
+    units: f32,
+}
+
+fn beat_of(x: usize, y: usize) -> (usize, usize) {
+    (
+        (x / DISTRICT_SIZE).min(DISTRICTS_X - 1),
+        (y / DISTRICT_SIZE).min(DISTRICTS_Y - 1),
+    )
+}
+
+fn beat_center(bx: usize, by: usize) -> (f32, f32) {
+    let half = DISTRICT_SIZE as f32 / 2.0;
+    (
+        (bx * DISTRICT_SIZE) as f32 + half,
+        (by * DISTRICT_SIZE) as f32 + half,
+    )
+}
+
+/// Cells within `PATROL_SIGHT_RADIUS` of (x, y).
+fn cells_in_sight(x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+    let r = PATROL_SIGHT_RADIUS;
+    (-r..=r)
+        .flat_map(move |dy| (-r..=r).map(move |dx| (x as i32 + dx, y as i32 + dy)))
+        .filter(|&(nx, ny)| {
+            nx >= 0 && ny >= 0 && (nx as usize) < GRID_WIDTH && (ny as usize) < GRID_HEIGHT
+        })
+        .map(|(nx, ny)| (nx as usize, ny as usize))
+}
+
+/// Patrol frequency of each road segment, in `segments` order. The passes
+/// of a beat (`assigned_units * PATROL_CELLS_PER_UNIT`) go to the segments
+/// whose midpoint lies in it: `BASE_SEGMENT_SHARE` by length, the rest by
+/// the crime in sight of each segment.
+fn segment_frequencies(
+    segments: &RoadSegmentStore,
+    beats: &[BeatStats],
+    crime: &CrimeGrid,
+) -> Vec<f32> {
+    // Beat, length and crime in sight of each segment.
+    let loads: Vec<Option<(usize, f32, f32)>> = segments
+        .segments
+        .iter()
+        .map(|seg| {
+            let mid = seg.evaluate(0.5);
+            let (gx, gy) = WorldGrid::world_to_grid(mid.x, mid.y);
+            if gx < 0 || gy < 0 {
+                return None;
+            }
+            let (bx, by) = beat_of(gx as usize, gy as usize);
+            let seen: f32 = seg
+                .rasterized_cells
+                .iter()
+                .flat_map(|&(x, y)| cells_in_sight(x, y))
+                .map(|(x, y)| crime.get(x, y) as f32)
+                .sum();
+            let length = seg.rasterized_cells.len().max(1) as f32;
+            Some((by * DISTRICTS_X + bx, length, seen))
+        })
+        .collect();
+
+    let mut beat_length = vec![0.0f32; BEAT_COUNT];
+    let mut beat_crime = vec![0.0f32; BEAT_COUNT];
+    for &(bi, length, seen) in loads.iter().flatten() {
+        beat_length[bi] += length;
+        beat_crime[bi] += seen;
+    }
+    loads
+        .iter()
+        .map(|load| {
+            let Some((bi, length, seen)) = *load else {
+                return 0.0;
+            };
+            let passes = beats[bi].assigned_units * PATROL_CELLS_PER_UNIT;
+            let share = if beat_crime[bi] > 0.0 {
+                BASE_SEGMENT_SHARE * length / beat_length[bi]
+                    + (1.0 - BASE_SEGMENT_SHARE) * seen / beat_crime[bi]
+            } else {
+                length / beat_length[bi]
+            };
+            passes * share / length
+        })
+        .collect()
+}
+
+/// Assign patrol units to beats, estimate per-beat response times, and apply
+/// patrol and response deterrence to the `CrimeGrid`.
+///
+/// Each police building spreads its units across the beats within its
+/// coverage radius, weighted by the crime demand of each beat and its
+/// distance from the station. The crime removed in a beat is credited to
+/// the police tiers by their share of the units patrolling it.
+#[allow(clippy::too_many_arguments)]
+pub fn update_police_patrols(
+    slow_timer: Res<crate::SlowTickTimer>,
+    services: Query<&ServiceBuilding>,
+    ext_budget: Res<crate::budget::ExtendedBudget>,
+    mut tiers: ResMut<PoliceTiersState>,
+    grid: Res<WorldGrid>,
+    segments: Res<RoadSegmentStore>,
+    justice: Res<CrimeJusticeState>,
+    district_map: Res<DistrictMap>,
+    mut crime: ResMut<CrimeGrid>,
+    mut state: ResMut<PolicePatrolState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let budget = ext_budget.service_budgets.police.max(0.0);
+    let stations: Vec<PatrolStation> = services
+        .iter()
+        .filter_map(|s| {
+            let tier = PoliceTier::from_service_type(s.service_type)?;
+            let coord = if tier != PoliceTier::Headquarters {
+                tiers.coordination_multiplier
+            } else {
+                1.0
+            };
+            Some(PatrolStation {
+                tier,
+                grid_x: s.grid_x,
+                grid_y: s.grid_y,
+                units: patrol_units_for(tier) * budget * coord,
+            })
+        })
+        .collect();
+
+    // Beat demand (crime load) and road length.
+    let mut demand = vec![0.0f32; BEAT_COUNT];
+    let mut beats = vec![BeatStats::default(); BEAT_COUNT];
+    // Units in each beat by tier (kiosk, station, HQ).
+    let mut tier_units = vec![[0.0f32; 3]; BEAT_COUNT];
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let (bx, by) = beat_of(x, y);
+            let bi = by * DISTRICTS_X + bx;
+            demand[bi] += crime.get(x, y) as f32;
+            if grid.get(x, y).cell_type == CellType::Road {
+                beats[bi].road_cells += 1;
+            }
+        }
+    }
+    for ev in &justice.events {
+        if matches!(
+            ev.stage,
+            JusticeStage::Reported | JusticeStage::PoliceResponding
+        ) && ev.district_x < DISTRICTS_X
+            && ev.district_y < DISTRICTS_Y
+        {
+            beats[ev.district_y * DISTRICTS_X + ev.district_x].incidents += 1;
+        }
+    }
+
+    // Distribute each station's units across the beats it can reach.
+    for st in &stations {
+        if st.units <= 0.0 {
+            continue;
+        }
+        let (hx, hy) = beat_of(st.grid_x, st.grid_y);
+        let reach = (st.tier.coverage_radius() as usize).div_ceil(DISTRICT_SIZE);
+        let mut weights: Vec<(usize, f32)> = Vec::new();
+        for by in hy.saturating_sub(reach)..=(hy + reach).min(DISTRICTS_Y - 1) {
+            for bx in hx.saturating_sub(reach)..=(hx + reach).min(DISTRICTS_X - 1) {
+                let bi = by * DISTRICTS_X + bx;
+                if demand[bi] <= 0.0 {
+                    continue;
+                }
+                let dist = bx.abs_diff(hx).max(by.abs_diff(hy)) as f32;
+                weights.push((bi, demand[bi] / (1.0 + dist)));
+            }
+        }
+        let mut total: f32 = weights.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            // No crime in reach: the units stay on the home beat.
+            weights = vec![(hy * DISTRICTS_X + hx, 1.0)];
+            total = 1.0;
+        }
+        for (bi, w) in weights {
+            let units = st.units * w / total;
+            beats[bi].assigned_units += units;
+            tier_units[bi][st.tier as usize] += units;
+        }
+    }
+
+    // Per-beat frequency, response time, and deterrence.
+    for by in 0..DISTRICTS_Y {
+        for bx in 0..DISTRICTS_X {
+            let bi = by * DISTRICTS_X + bx;
+            let (cx, cy) = beat_center(bx, by);
+            let mut nearest = f32::MAX;
+            let mut units_in_range = 0.0;
+            for st in stations.iter().filter(|s| s.units > 0.0) {
+                let d = (st.grid_x as f32 - cx).abs() + (st.grid_y as f32 - cy).abs();
+                if d <= st.tier.coverage_radius() as f32 + DISTRICT_SIZE as f32 {
+                    units_in_range += st.units;
+                }
+                nearest = nearest.min(d);
+            }
+            let beat = &mut beats[bi];
+            if beat.assigned_units >= 0.5 {
+                nearest = nearest.min(ON_BEAT_RESPONSE_CELLS);
+                units_in_range += beat.assigned_units;
+            }
+            beat.patrol_frequency = patrol_frequency(beat.assigned_units, beat.road_cells);
+            beat.response_minutes = if nearest == f32::MAX {
+                MAX_RESPONSE_MINUTES
+            } else {
+                response_time_minutes(nearest, units_in_range, beat.incidents)
+            };
+            let total = patrol_deterrence(beat.patrol_frequency)
+                + response_deterrence(beat.response_minutes);
+            beat.deterrence = total.round().clamp(0.0, u8::MAX as f32) as u8;
+        }
+    }
+
+    // How often each road cell is patrolled: the busiest segment over it, or
+    // the beat's average for roads no segment covers.
+    let frequencies = segment_frequencies(&segments, &beats, &crime);
+    let mut road_frequency = vec![None::<f32>; GRID_WIDTH * GRID_HEIGHT];
+    for (seg, &frequency) in segments.segments.iter().zip(&frequencies) {
+        for &(x, y) in &seg.rasterized_cells {
+            if x < GRID_WIDTH && y < GRID_HEIGHT {
+                let cell = &mut road_frequency[y * GRID_WIDTH + x];
+                *cell = Some(cell.map_or(frequency, |f| f.max(frequency)));
+            }
+        }
+    }
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let cell = &mut road_frequency[y * GRID_WIDTH + x];
+            if cell.is_none() && grid.get(x, y).cell_type == CellType::Road {
+                let (bx, by) = beat_of(x, y);
+                *cell = Some(beats[by * DISTRICTS_X + bx].patrol_frequency);
+            }
+        }
+    }
+
+    // Apply deterrence: by the most patrolled road in sight, or half the
+    // beat's when no road is in sight.
+    let mut reduced = vec![0u32; BEAT_COUNT];
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let level = crime.get(x, y);
+            if level == 0 {
+                continue;
+            }
+            let (bx, by) = beat_of(x, y);
+            let beat = &beats[by * DISTRICTS_X + bx];
+            if beat.deterrence == 0 {
+                continue;
+            }
+            let in_sight = cells_in_sight(x, y)
+                .filter_map(|(nx, ny)| road_frequency[ny * GRID_WIDTH + nx])
+                .reduce(f32::max);
+            let reduction = match in_sight {
+                Some(frequency) => {
+                    let total =
+                        patrol_deterrence(frequency) + response_deterrence(beat.response_minutes);
+                    total.round().clamp(0.0, u8::MAX as f32) as u8
+                }
+                None if beat.road_cells == 0 => beat.deterrence,
+                None => beat.deterrence / 2,
+            };
+            let new_level = level.saturating_sub(reduction);
+            crime.set(x, y, new_level);
+            reduced[by * DISTRICTS_X + bx] += u32::from(level - new_level);
+        }
+    }
+    for tier in [
+        PoliceTier::Kiosk,
+        PoliceTier::Station,
+        PoliceTier::Headquarters,
+    ] {
+        let credited: f32 = (0..BEAT_COUNT)
+            .filter(|&bi| beats[bi].assigned_units > 0.0)
+            .map(|bi| reduced[bi] as f32 * tier_units[bi][tier as usize] / beats[bi].assigned_units)
+            .sum();
+        tiers.stats_for_tier_mut(tier).crime_reduced = credited.round() as u32;
+    }
+
+    state.segment_patrols = segments
+        .segments
+        .iter()
+        .zip(frequencies)
+        .filter(|&(_, frequency)| frequency > 0.0)
+        .map(|(seg, frequency)| SegmentPatrol {
+            segment_id: seg.id.0,
+            frequency,
+        })
+        .collect();
+
+    // Demand-weighted average response time across beats with crime.
+    let (mut weighted, mut weight) = (0.0f32, 0.0f32);
+    for (bi, beat) in beats.iter().enumerate() {
+        if demand[bi] > 0.0 {
+            weighted += beat.response_minutes * demand[bi];
+            weight += demand[bi];
+        }
+    }
+    state.avg_response_minutes = if weight > 0.0 {
+        weighted / weight
+    } else {
+        MAX_RESPONSE_MINUTES
+    };
+
+    // Player district safety stats: mean response time over district cells.
+    state.district_response_minutes = district_map
+        .districts
+        .iter()
+        .map(|d| {
+            if d.cells.is_empty() {
+                return MAX_RESPONSE_MINUTES;
+            }
+            let sum: f32 = d
+                .cells
+                .iter()
+                .map(|&(x, y)| {
+                    let (bx, by) = beat_of(x, y);
+                    beats[by * DISTRICTS_X + bx].response_minutes
+                })
+                .sum();
+            sum / d.cells.len() as f32
+        })
+        .collect();
+
+    state.total_units = stations.iter().map(|s| s.units).sum();
+    state.beats = beats;
+}
+
+pub struct PolicePatrolPlugin;
+
+impl Plugin for PolicePatrolPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PolicePatrolState>().add_systems(
+            FixedUpdate,
+            update_police_patrols
+                .after(crate::police_tiers::update_police_tiers)
+                .after(crate::crime_justice::advance_justice_pipeline)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use super::types::*;
+use crate::police_tiers::PoliceTier;
+
+#[test]
+fn test_patrol_units_ordered_by_tier() {
+    assert!(patrol_units_for(PoliceTier::Kiosk) < patrol_units_for(PoliceTier::Station));
+    assert!(patrol_units_for(PoliceTier::Station) < patrol_units_for(PoliceTier::Headquarters));
+}
+
+#[test]
+fn test_patrol_frequency_uses_minimum_road_length() {
+    let f_empty = patrol_frequency(1.0, 0);
+    let f_min = patrol_frequency(1.0, MIN_BEAT_ROAD_CELLS);
+    assert!((f_empty - f_min).abs() < f32::EPSILON);
+    assert!(patrol_frequency(1.0, 400) < f_min);
+}
+
+#[test]
+fn test_patrol_deterrence_saturates() {
+    assert_eq!(patrol_deterrence(0.0), 0.0);
+    let low = patrol_deterrence(1.0);
+    let high = patrol_deterrence(100.0);
+    assert!(low > 0.0 && low < high);
+    assert!(high <= MAX_PATROL_DETERRENCE);
+}
+
+#[test]
+fn test_response_time_without_units_is_max() {
+    assert_eq!(response_time_minutes(5.0, 0.0, 0), MAX_RESPONSE_MINUTES);
+}
+
+#[test]
+fn test_response_time_grows_with_distance_and_queue() {
+    let near = response_time_minutes(4.0, 2.0, 0);
+    let far = response_time_minutes(40.0, 2.0, 0);
+    let queued = response_time_minutes(4.0, 2.0, 8);
+    assert!(near < far);
+    assert!(near < queued);
+    assert!((queued - near * 4.0).abs() < 0.001);
+}
+
+#[test]
+fn test_response_deterrence_rewards_fast_response() {
+    let fast = response_deterrence(TARGET_RESPONSE_MINUTES);
+    let slow = response_deterrence(TARGET_RESPONSE_MINUTES * 3.0);
+    assert!((fast - MAX_RESPONSE_DETERRENCE).abs() < f32::EPSILON);
+    assert!(slow < fast);
+    assert_eq!(response_deterrence(MAX_RESPONSE_MINUTES), 0.0);
+}
+
+#[test]
+fn test_default_state_has_one_beat_per_district() {
+    let s = PolicePatrolState::default();
+    assert_eq!(s.beats.len(), BEAT_COUNT);
+    assert_eq!(s.segment_frequency(0), 0.0);
+}
@@ -0,0 +1,170 @@
+//! Types and pure helper functions for the police patrol model.
+
+use bevy::prelude::*;
+
+use crate::districts::{DISTRICTS_X, DISTRICTS_Y};
+use crate::police_tiers::PoliceTier;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Road cells a single patrol unit can sweep in one slow tick.
+pub const PATROL_CELLS_PER_UNIT: f32 = 200.0;
+
+/// Minimum road length assumed for a beat (foot patrol in road-less areas).
+pub const MIN_BEAT_ROAD_CELLS: u32 = 16;
+
+/// Maximum crime reduction from patrol presence on a fully patrolled beat.
+/// With the response bonus this can clear most of the base crime (at most
+/// 25), since patrols are the only police effect on the `CrimeGrid`.
+pub const MAX_PATROL_DETERRENCE: f32 = 16.0;
+
+/// Patrol frequency at which deterrence reaches ~63% of its maximum.
+pub const DETERRENCE_FREQUENCY_SCALE: f32 = 4.0;
+
+/// Cells within this Chebyshev distance of a road are deterred by the
+/// patrols on it; cells further away receive half the beat's deterrence.
+pub const PATROL_SIGHT_RADIUS: i32 = 2;
+
+/// Share of a beat's patrol passes spread over its road segments by length;
+/// the rest follows the crime in sight of each segment.
+pub const BASE_SEGMENT_SHARE: f32 = 0.25;
+
+/// Fixed dispatch overhead before a unit starts moving (game minutes).
+pub const DISPATCH_MINUTES: f32 = 2.0;
+
+/// Travel time per grid cell (game minutes).
+pub const MINUTES_PER_CELL: f32 = 0.25;
+
+/// Response time target; faster responses earn the full response bonus.
+pub const TARGET_RESPONSE_MINUTES: f32 = 8.0;
+
+/// Response time reported for beats no unit can reach.
+pub const MAX_RESPONSE_MINUTES: f32 = 60.0;
+
+/// Maximum extra crime reduction from fast incident response.
+pub const MAX_RESPONSE_DETERRENCE: f32 = 8.0;
+
+/// Distance (cells) a unit already patrolling the beat needs to reach an incident.
+pub const ON_BEAT_RESPONSE_CELLS: f32 = 4.0;
+
+/// Number of beats (one per statistical district).
+pub const BEAT_COUNT: usize = DISTRICTS_X * DISTRICTS_Y;
+
+// ---------------------------------------------------------------------------
+// Per-beat and per-segment stats
+// ---------------------------------------------------------------------------
+
+/// Patrol statistics for one beat (a 16x16 statistical district).
+#[derive(Debug, Clone, Default)]
+pub struct BeatStats {
+    /// Patrol units assigned to this beat (fractional when shared).
+    pub assigned_units: f32,
+    /// Road cells inside the beat.
+    pub road_cells: u32,
+    /// Patrol passes per road cell per slow tick.
+    pub patrol_frequency: f32,
+    /// Estimated response time to an incident in this beat (game minutes).
+    pub response_minutes: f32,
+    /// Open incidents (reported or awaiting response) in this beat.
+    pub incidents: u32,
+    /// Crime reduction at the beat's average patrol frequency. Cells in
+    /// sight of a road are deterred by that road's own patrols instead.
+    pub deterrence: u8,
+}
+
+/// Patrol frequency on a single road segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentPatrol {
+    pub segment_id: u32,
+    pub frequency: f32,
+}
+
+/// City-wide patrol state recomputed every slow tick.
+#[derive(Resource, Debug, Clone)]
+pub struct PolicePatrolState {
+    pub beats: Vec<BeatStats>,
+    pub segment_patrols: Vec<SegmentPatrol>,
+    /// Total patrol units fielded by all police buildings.
+    pub total_units: f32,
+    /// Average response time across beats with crime, weighted by each
+    /// beat's crime demand.
+    pub avg_response_minutes: f32,
+    /// Average response time per player-defined district (indexed like `DistrictMap::districts`).
+    pub district_response_minutes: Vec<f32>,
+}
+
+impl Default for PolicePatrolState {
+    fn default() -> Self {
+        Self {
+            beats: vec![BeatStats::default(); BEAT_COUNT],
+            segment_patrols: Vec::new(),
+            total_units: 0.0,
+            avg_response_minutes: MAX_RESPONSE_MINUTES,
+            district_response_minutes: Vec::new(),
+        }
+    }
+}
+
+impl PolicePatrolState {
+    pub fn beat(&self, bx: usize, by: usize) -> &BeatStats {
+        &self.beats[by * DISTRICTS_X + bx]
+    }
+
+    /// Patrol frequency for a road segment, or 0.0 if it is not patrolled.
+    pub fn segment_frequency(&self, segment_id: u32) -> f32 {
+        self.segment_patrols
+            .iter()
+            .find(|s| s.segment_id == segment_id)
+            .map_or(0.0, |s| s.frequency)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pure helpers
+// ---------------------------------------------------------------------------
+
+/// Patrol units fielded by a police building of the given tier at full budget.
+pub fn patrol_units_for(tier: PoliceTier) -> f32 {
+    match tier {
+        PoliceTier::Kiosk => 1.0,
+        PoliceTier::Station => 3.0,
+        PoliceTier::Headquarters => 6.0,
+    }
+}
+
+/// Patrol passes per road cell given the units assigned to a beat.
+pub fn patrol_frequency(units: f32, road_cells: u32) -> f32 {
+    let cells = road_cells.max(MIN_BEAT_ROAD_CELLS) as f32;
+    units.max(0.0) * PATROL_CELLS_PER_UNIT / cells
+}
+
+/// Crime reduction from patrol presence; saturates as frequency grows.
+pub fn patrol_deterrence(frequency: f32) -> f32 {
+    if frequency <= 0.0 {
+        return 0.0;
+    }
+    MAX_PATROL_DETERRENCE * (1.0 - (-frequency / DETERRENCE_FREQUENCY_SCALE).exp())
+}
+
+/// Dispatch-based response time for an incident `distance_cells` away.
+///
+/// When open incidents outnumber the units able to respond, calls queue and
+/// the response time grows proportionally.
+pub fn response_time_minutes(distance_cells: f32, units: f32, incidents: u32) -> f32 {
+    if units <= 0.0 {
+        return MAX_RESPONSE_MINUTES;
+    }
+    let travel = DISPATCH_MINUTES + distance_cells.max(0.0) * MINUTES_PER_CELL;
+    let queue = (incidents as f32 / units).max(1.0);
+    (travel * queue).min(MAX_RESPONSE_MINUTES)
+}
+
+/// Extra crime reduction earned by responding quickly.
+pub fn response_deterrence(minutes: f32) -> f32 {
+    if minutes >= MAX_RESPONSE_MINUTES {
+        return 0.0;
+    }
+    MAX_RESPONSE_DETERRENCE * (TARGET_RESPONSE_MINUTES / minutes.max(TARGET_RESPONSE_MINUTES))
+}
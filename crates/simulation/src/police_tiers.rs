@@ -1,7 +1,7 @@
 //! SVC-005: Police Service Multi-Tier System
 //!
-//! Implements a three-tier police system with varying coverage, crime
-//! reduction, response time, and maintenance cost:
+//! Implements a three-tier police system with varying coverage, patrol
+//! strength, response time, and maintenance cost:
 //!
 //! - **Small Police Station (PoliceKiosk):** Local patrol, low cost, small
 //!   radius, basic crime reduction.
@@ -10,16 +10,17 @@
 //! - **Police HQ:** City-wide coordination bonus, large radius, high crime
 //!   reduction, fast response, highest maintenance.
 //!
-//! The system tracks per-tier statistics and applies differentiated crime
-//! reduction to the `CrimeGrid`. A coordination bonus is applied when a
-//! Police HQ exists, boosting all lower-tier stations in the city.
+//! The system tracks per-tier statistics and the coordination bonus applied
+//! when a Police HQ exists, boosting all lower-tier stations in the city.
+//! It does not touch the `CrimeGrid`: crime is reduced only by the patrol
+//! units each building fields (see `police_patrol`), which also fills in
+//! each tier's `crime_reduced`.
 
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
-use crate::crime::CrimeGrid;
 use crate::services::{ServiceBuilding, ServiceType};
 use crate::Saveable;
 
@@ -48,15 +49,6 @@ impl PoliceTier {
         }
     }
 
-    /// Base crime reduction applied within coverage (higher = stronger).
-    pub fn crime_reduction(self) -> u8 {
-        match self {
-            Self::Kiosk => 8,
-            Self::Station => 18,
-            Self::Headquarters => 30,
-        }
-    }
-
     /// Response time in slow ticks (lower = faster response).
     pub fn response_time(self) -> u32 {
         match self {
@@ -97,7 +89,8 @@ pub struct TierStats {
     pub building_count: u32,
     /// Total grid cells within coverage radius of this tier's buildings.
     pub cells_covered: u32,
-    /// Cumulative crime points reduced this tick.
+    /// Crime points removed this tick by the patrols of this tier's
+    /// buildings, filled in by `police_patrol`.
     pub crime_reduced: u32,
     /// Total monthly maintenance cost for this tier.
     pub total_maintenance: f64,
@@ -150,7 +143,7 @@ impl PoliceTiersState {
         }
     }
 
-    fn stats_for_tier_mut(&mut self, tier: PoliceTier) -> &mut TierStats {
+    pub(crate) fn stats_for_tier_mut(&mut self, tier: PoliceTier) -> &mut TierStats {
         match tier {
             PoliceTier::Kiosk => &mut self.kiosk_stats,
             PoliceTier::Station => &mut self.station_stats,
@@ -187,14 +180,12 @@ impl Saveable for PoliceTiersState {
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Gather police service buildings, compute per-tier stats, and apply
-/// differentiated crime reduction to the `CrimeGrid`.
+/// Gather police service buildings and compute per-tier counts, coverage
+/// and maintenance, and the HQ coordination bonus.
 pub fn update_police_tiers(
     slow_timer: Res<crate::SlowTickTimer>,
     services: Query<&ServiceBuilding>,
-    ext_budget: Res<crate::budget::ExtendedBudget>,
     mut state: ResMut<PoliceTiersState>,
-    mut crime_grid: ResMut<CrimeGrid>,
 ) {
     if !slow_timer.should_run() {
         return;
@@ -205,8 +196,6 @@ pub fn update_police_tiers(
     state.station_stats = TierStats::default();
     state.hq_stats = TierStats::default();
 
-    let police_budget = ext_budget.service_budgets.police;
-
     // Collect police buildings by tier.
     struct PoliceUnit {
         tier: PoliceTier,
@@ -233,30 +222,16 @@ pub fn update_police_tiers(
     state.coordination_active = has_hq;
     state.coordination_multiplier = if has_hq { HQ_COORDINATION_BONUS } else { 1.0 };
 
-    let coord_mult = state.coordination_multiplier;
-
     // Track which cells are covered (for city_coverage stat).
     let total_cells = GRID_WIDTH * GRID_HEIGHT;
     let mut covered = vec![false; total_cells];
 
-    // Apply crime reduction per unit.
+    // Mark the cells within each unit's coverage radius.
     for unit in &units {
         let radius = unit.tier.coverage_radius();
-        let base_reduction = unit.tier.crime_reduction();
-        // HQ coordination bonus applies to non-HQ tiers when HQ exists.
-        let tier_multiplier = if has_hq && unit.tier != PoliceTier::Headquarters {
-            coord_mult
-        } else {
-            1.0
-        };
-        let effective_reduction =
-            (base_reduction as f32 * police_budget * tier_multiplier) as u8;
-
-        let mut tier_reduced: u32 = 0;
-        let mut tier_cells: u32 = 0;
-
         let gx = unit.grid_x as i32;
         let gy = unit.grid_y as i32;
+        let mut tier_cells: u32 = 0;
         for dy in -radius..=radius {
             for dx in -radius..=radius {
                 let nx = gx + dx;
@@ -268,32 +243,11 @@ pub fn update_police_tiers(
                 {
                     continue;
                 }
-                let ux = nx as usize;
-                let uy = ny as usize;
-
-                // Count all cells within radius as covered.
-                let idx = uy * GRID_WIDTH + ux;
-                covered[idx] = true;
+                covered[ny as usize * GRID_WIDTH + nx as usize] = true;
                 tier_cells += 1;
-
-                // Falloff: reduction decreases with Manhattan distance.
-                let dist = dx.abs() + dy.abs();
-                let falloff_reduction = effective_reduction.saturating_sub(
-                    (dist as f32 / radius as f32 * effective_reduction as f32) as u8,
-                );
-                if falloff_reduction == 0 {
-                    continue;
-                }
-
-                let old = crime_grid.levels[idx];
-                crime_grid.levels[idx] = old.saturating_sub(falloff_reduction);
-                tier_reduced += (old - crime_grid.levels[idx]) as u32;
             }
         }
-
-        let ts = state.stats_for_tier_mut(unit.tier);
-        ts.cells_covered += tier_cells;
-        ts.crime_reduced += tier_reduced;
+        state.stats_for_tier_mut(unit.tier).cells_covered += tier_cells;
     }
 
     // Compute city coverage ratio.
@@ -341,14 +295,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_tier_crime_reduction_ordering() {
-        assert!(PoliceTier::Kiosk.crime_reduction() < PoliceTier::Station.crime_reduction());
-        assert!(
-            PoliceTier::Station.crime_reduction() < PoliceTier::Headquarters.crime_reduction()
-        );
-    }
-
     #[test]
     fn test_tier_response_time_ordering() {
         // Lower is faster — HQ should be fastest.
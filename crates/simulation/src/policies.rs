@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 /// City-wide and district-level policies that modify simulation parameters
@@ -7,7 +8,7 @@ pub struct Policies {
     pub active: Vec<Policy>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
 pub enum Policy {
     // Economy
     FreePublicTransport,
@@ -49,6 +50,9 @@ pub enum Policy {
     TaxIncentiveZone,
     PetBan,
     ParksAndRec,
+
+    // Public safety
+    SeismicRetrofit,
 }
 
 impl Policy {
@@ -84,6 +88,7 @@ impl Policy {
             Policy::TaxIncentiveZone => 0.0,
             Policy::PetBan => 5.0,
             Policy::ParksAndRec => 20.0,
+            Policy::SeismicRetrofit => 25.0,
         }
     }
 
@@ -118,6 +123,7 @@ impl Policy {
             Policy::TaxIncentiveZone => "Tax Incentive Zone",
             Policy::PetBan => "Pet Ban",
             Policy::ParksAndRec => "Parks & Rec",
+            Policy::SeismicRetrofit => "Seismic Retrofit",
         }
     }
 
@@ -131,64 +137,35 @@ impl Policy {
             }
             Policy::TourismPromotion => "Increases commercial demand from tourism",
             Policy::SmallBusinessGrant => "Boosts commercial growth, costs money",
-            Policy::RecyclingProgram => {
-                "Reduces garbage by 30%, costs +10% garbage budget"
-            }
+            Policy::RecyclingProgram => "Reduces garbage by 30%, costs +10% garbage budget",
             Policy::IndustrialAirFilters => "Reduces industrial pollution by 40%",
-            Policy::WaterConservation => {
-                "Reduces water consumption, saves money long-term"
-            }
+            Policy::WaterConservation => "Reduces water consumption, saves money long-term",
             Policy::GreenSpaceInitiative => "Boosts park effectiveness by 50%",
-            Policy::EducationPush => {
-                "Faster education progression, increases education spending"
-            }
+            Policy::EducationPush => "Faster education progression, increases education spending",
             Policy::HealthcareForAll => "Increases health coverage, expensive",
             Policy::SmokeDetectorMandate => "Reduces fire risk, small upkeep",
             Policy::NeighborhoodWatch => "Reduces crime, small upkeep",
-            Policy::HighRiseBan => {
-                "Caps building level at 2, preserves neighborhood character"
-            }
-            Policy::NightShiftBan => {
-                "Increases happiness +3, reduces commercial output"
-            }
-            Policy::IndustrialZoningRestriction => {
-                "Limits new industrial zoning near residential"
-            }
-            Policy::EminentDomain => {
-                "Override citizen opposition at a happiness cost"
-            }
-            Policy::CumulativeZoning => {
-                "Higher-intensity zones allow lower-intensity uses"
-            }
-            Policy::EncourageBiking => {
-                "+15% cycling rate, -10% car trips when bike infra exists"
-            }
+            Policy::HighRiseBan => "Caps building level at 2, preserves neighborhood character",
+            Policy::NightShiftBan => "Increases happiness +3, reduces commercial output",
+            Policy::IndustrialZoningRestriction => "Limits new industrial zoning near residential",
+            Policy::EminentDomain => "Override citizen opposition at a happiness cost",
+            Policy::CumulativeZoning => "Higher-intensity zones allow lower-intensity uses",
+            Policy::EncourageBiking => "+15% cycling rate, -10% car trips when bike infra exists",
             Policy::CombustionEngineBan => {
                 "Bans private cars: forces transit/walking, cuts pollution 30%"
             }
-            Policy::SmallBusinessEnthusiast => {
-                "Caps commercial at level 2, +20% small biz growth"
-            }
-            Policy::HeavyTrafficBan => {
-                "Bans trucks: -40% road noise, -15% industrial output"
-            }
-            Policy::SmokeDetectorDistribution => {
-                "-50% fire hazard, costs $0.5/citizen/month"
-            }
-            Policy::OldTownHistoric => {
-                "Prevents building changes, +15% tourism, -20% growth"
-            }
+            Policy::SmallBusinessEnthusiast => "Caps commercial at level 2, +20% small biz growth",
+            Policy::HeavyTrafficBan => "Bans trucks: -40% road noise, -15% industrial output",
+            Policy::SmokeDetectorDistribution => "-50% fire hazard, costs $0.5/citizen/month",
+            Policy::OldTownHistoric => "Prevents building changes, +15% tourism, -20% growth",
             Policy::IndustrialSpacePlanning => "+50% industrial output, +10% pollution",
-            Policy::RentControl => {
-                "Prevents rent increases, -25% new construction rate"
-            }
-            Policy::MinimumWage => {
-                "Sets wage floor: -20% poverty, +10% business costs"
-            }
+            Policy::RentControl => "Prevents rent increases, -25% new construction rate",
+            Policy::MinimumWage => "Sets wage floor: -20% poverty, +10% business costs",
             Policy::TaxIncentiveZone => "-50% property tax, +25% construction rate",
             Policy::PetBan => "-10% garbage, -5 happiness",
-            Policy::ParksAndRec => {
-                "+10% park land value boost, +10% parks budget cost"
+            Policy::ParksAndRec => "+10% park land value boost, +10% parks budget cost",
+            Policy::SeismicRetrofit => {
+                "Strengthens the most exposed buildings against earthquakes, paid over a year each"
             }
         }
     }
@@ -225,6 +202,7 @@ impl Policy {
             Policy::TaxIncentiveZone,
             Policy::PetBan,
             Policy::ParksAndRec,
+            Policy::SeismicRetrofit,
         ]
     }
 }
@@ -332,6 +332,15 @@ pub fn get_tradeoff(policy: Policy) -> PolicyTradeoff {
                 ("Monthly cost $20", -20.0),
             ],
         },
+        Policy::SeismicRetrofit => PolicyTradeoff {
+            policy,
+            category: PolicyCategory::PublicSafety,
+            benefits: &[("Earthquake collapse risk -75%", 75.0)],
+            drawbacks: &[
+                ("Retrofit installments $200/level/month", -20.0),
+                ("Monthly cost $25", -25.0),
+            ],
+        },
     }
 }
 
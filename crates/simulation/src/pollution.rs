@@ -1,9 +1,10 @@
+use crate::chunked_grid::ChunkedGrid;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use bevy::prelude::*;
 
 #[derive(Resource)]
 pub struct PollutionGrid {
-    pub levels: Vec<u8>,
+    pub levels: ChunkedGrid<u8>,
     pub width: usize,
     pub height: usize,
 }
@@ -11,7 +12,7 @@ pub struct PollutionGrid {
 impl Default for PollutionGrid {
     fn default() -> Self {
         Self {
-            levels: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            levels: ChunkedGrid::new(GRID_WIDTH, GRID_HEIGHT, 0),
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
         }
@@ -20,10 +21,10 @@ impl Default for PollutionGrid {
 
 impl PollutionGrid {
     pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.levels[y * self.width + x]
+        *self.levels.get(x, y)
     }
     pub fn set(&mut self, x: usize, y: usize, val: u8) {
-        self.levels[y * self.width + x] = val;
+        *self.levels.get_mut(x, y) = val;
     }
 }
 
@@ -34,8 +35,7 @@ impl Plugin for PollutionPlugin {
         app.init_resource::<PollutionGrid>().add_systems(
             FixedUpdate,
             crate::wind_pollution::update_pollution_gaussian_plume
-                .after(crate::education::propagate_education)
-                .in_set(crate::SimulationSet::Simulation),
+                .in_set(crate::GridPropagationSet::Emission),
         );
     }
 }
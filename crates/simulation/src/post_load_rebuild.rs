@@ -8,9 +8,12 @@
 //! - **Service coverage grid**: marked dirty so the next tick recalculates it
 //! - **Traffic grid**: zeroed out (stale density data is meaningless after load)
 //! - **Spatial grid**: cleared (rebuilt every frame by the LOD system)
+//! - **Dirty chunks**: every chunk marked dirty so slow grid systems do a
+//!   full recompute
 
 use bevy::prelude::*;
 
+use crate::dirty_chunks::DirtyChunks;
 use crate::happiness::ServiceCoverageGrid;
 use crate::oneway::OneWayDirectionMap;
 use crate::road_graph_csr::CsrGraph;
@@ -51,6 +54,7 @@ pub(crate) fn post_load_rebuild_derived_state(
     mut coverage: ResMut<ServiceCoverageGrid>,
     mut traffic: ResMut<TrafficGrid>,
     mut spatial: ResMut<SpatialGrid>,
+    mut dirty_chunks: ResMut<DirtyChunks>,
 ) {
     info!("Post-load rebuild: reconstructing derived state...");
 
@@ -66,6 +70,9 @@ pub(crate) fn post_load_rebuild_derived_state(
     // 4. Clear spatial grid (rebuilt every frame by the LOD update_spatial_grid system).
     spatial.clear();
 
+    // 5. Mark every grid chunk dirty so slow grid systems recompute in full.
+    dirty_chunks.mark_all();
+
     info!(
         "Post-load rebuild complete: CSR graph has {} nodes / {} edges, \
          traffic grid zeroed, service coverage marked dirty, spatial grid cleared",
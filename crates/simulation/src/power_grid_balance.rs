@@ -15,7 +15,7 @@ use crate::energy_demand::{EnergyConsumer, EnergyGrid};
 use crate::energy_dispatch::EnergyDispatchState;
 use crate::gas_power::GasPowerState;
 use crate::grid::ZoneType;
-use crate::notifications::{NotificationEvent, NotificationPriority};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::services::ServiceBuilding;
 use crate::solar_power::SolarPowerState;
 use crate::time_of_day::GameClock;
@@ -377,6 +377,7 @@ pub fn notify_power_shortage(
     notifications.send(NotificationEvent {
         text: message,
         priority,
+        category: NotificationCategory::General,
         location: None,
     });
     balance.last_notification_tick = tick.0;
@@ -12,12 +12,12 @@
 
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
-use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 use crate::coal_power::PowerPlant;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::grid::{CellType, WorldGrid};
+use crate::packed_grid::BitGrid;
 use crate::{decode_or_warn, Saveable, SaveableRegistry, SimulationSet, TickCounter};
 
 // ---------------------------------------------------------------------------
@@ -38,10 +38,10 @@ const PROPAGATION_INTERVAL: u64 = 8;
 // ---------------------------------------------------------------------------
 
 /// Tracks which cells have power lines installed and per-cell transmission efficiency.
-#[derive(Resource, Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+#[derive(Resource, Debug, Clone)]
 pub struct PowerLineGrid {
-    /// True if a power line exists on this cell (indexed as `y * width + x`).
-    pub has_line: Vec<bool>,
+    /// Set if a power line exists on this cell (indexed as `y * width + x`).
+    pub has_line: BitGrid,
     /// Transmission efficiency at each cell (1.0 = no loss, 0.0 = total loss).
     /// Only meaningful where `has_line` is true.
     pub efficiency: Vec<f32>,
@@ -59,7 +59,7 @@ impl Default for PowerLineGrid {
     fn default() -> Self {
         let size = GRID_WIDTH * GRID_HEIGHT;
         Self {
-            has_line: vec![false; size],
+            has_line: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
             efficiency: vec![0.0; size],
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
@@ -69,15 +69,46 @@ impl Default for PowerLineGrid {
     }
 }
 
+/// On-disk layout of `PowerLineGrid`, with the line layer unpacked so saves
+/// keep their original encoding.
+#[derive(Encode, Decode, Default)]
+struct PowerLineSaveData {
+    has_line: Vec<bool>,
+    efficiency: Vec<f32>,
+    width: usize,
+    height: usize,
+    powered_cell_count: u32,
+    line_cell_count: u32,
+}
+
 impl Saveable for PowerLineGrid {
     const SAVE_KEY: &'static str = "power_lines";
 
     fn save_to_bytes(&self) -> Option<Vec<u8>> {
-        Some(bitcode::encode(self))
+        Some(bitcode::encode(&PowerLineSaveData {
+            has_line: self.has_line.to_bools(),
+            efficiency: self.efficiency.clone(),
+            width: self.width,
+            height: self.height,
+            powered_cell_count: self.powered_cell_count,
+            line_cell_count: self.line_cell_count,
+        }))
     }
 
     fn load_from_bytes(bytes: &[u8]) -> Self {
-        decode_or_warn(Self::SAVE_KEY, bytes)
+        let data: PowerLineSaveData = decode_or_warn(Self::SAVE_KEY, bytes);
+        let size = GRID_WIDTH * GRID_HEIGHT;
+        if data.has_line.len() != size || data.efficiency.len() != size {
+            return Self::default();
+        }
+        Self {
+            has_line: BitGrid::from_bools(GRID_WIDTH, GRID_HEIGHT, &data.has_line),
+            efficiency: data.efficiency,
+            width: data.width,
+            height: data.height,
+            powered_cell_count: data.powered_cell_count,
+            line_cell_count: data.line_cell_count,
+        }
     }
 }
 
@@ -105,9 +136,7 @@ pub fn install_power_lines(
     let total = w * h;
 
     // Reset power line state.
-    for v in power_grid.has_line.iter_mut() {
-        *v = false;
-    }
+    power_grid.has_line.fill(false);
     for v in power_grid.efficiency.iter_mut() {
         *v = 0.0;
     }
@@ -134,7 +163,7 @@ pub fn install_power_lines(
         let idx = y * w + x;
 
         // Mark as power line cell.
-        power_grid.has_line[idx] = true;
+        power_grid.has_line.set_index(idx, true);
 
         // Compute efficiency from distance.
         let eff = efficiency_at_distance(dist);
@@ -159,7 +188,7 @@ pub fn install_power_lines(
     }
 
     // Count line cells.
-    power_grid.line_cell_count = power_grid.has_line.iter().filter(|&&v| v).count() as u32;
+    power_grid.line_cell_count = power_grid.has_line.count_ones() as u32;
 }
 
 /// Computes the transmission efficiency at a given distance from a generator.
@@ -201,7 +230,7 @@ pub fn propagate_power_coverage(
     for y in 0..h {
         for x in 0..w {
             let idx = y * w + x;
-            if power_grid.has_line[idx] {
+            if power_grid.has_line.get_index(idx) {
                 visited[idx] = true;
                 grid.get_mut(x, y).has_power = true;
                 queue.push_back((x, y, 0));
@@ -289,13 +318,13 @@ mod tests {
         assert_eq!(plg.has_line.len(), GRID_WIDTH * GRID_HEIGHT);
         assert_eq!(plg.powered_cell_count, 0);
         assert_eq!(plg.line_cell_count, 0);
-        assert!(plg.has_line.iter().all(|&v| !v));
+        assert!(!plg.has_line.any());
     }
 
     #[test]
     fn test_saveable_roundtrip() {
         let mut plg = PowerLineGrid::default();
-        plg.has_line[100] = true;
+        plg.has_line.set_index(100, true);
         plg.efficiency[100] = 0.95;
         plg.line_cell_count = 1;
         plg.powered_cell_count = 7;
@@ -303,7 +332,7 @@ mod tests {
         let bytes = plg.save_to_bytes().unwrap();
         let restored = PowerLineGrid::load_from_bytes(&bytes);
 
-        assert!(restored.has_line[100]);
+        assert!(restored.has_line.get_index(100));
         assert!((restored.efficiency[100] - 0.95).abs() < f32::EPSILON);
         assert_eq!(restored.line_cell_count, 1);
         assert_eq!(restored.powered_cell_count, 7);
@@ -0,0 +1,33 @@
+//! Prison population, sentencing policy, and recidivism.
+//!
+//! Convictions from the justice pipeline (`ConvictionEvent`) are admitted as
+//! inmates into the city's prison buildings. Each prison holds
+//! `PRISON_CAPACITY` inmates.
+//!
+//! - **Sentencing policy**: Rehabilitative / Standard / Punitive scales the
+//!   base jail time of each crime type and the chance of reoffending.
+//! - **Education programs**: when enabled, inmates gain education while
+//!   serving, at a per-inmate treasury cost; education lowers recidivism.
+//! - **Recidivism**: released inmates may reoffend, which reports a new crime
+//!   in their home district; repeat offenders carry prior offenses into
+//!   their next sentence.
+//! - **Overcrowding**: prisons over capacity (or demolished prisons with no
+//!   room elsewhere) release inmates early, raising a warning.
+//!
+//! Total inmates are mirrored into `CrimeJusticeState::jail_population`,
+//! which drives the deterrence feedback loop. The player sets the policy and
+//! education program from the Justice Policy window.
+//!
+//! Like the justice pipeline that feeds it, the prison population is
+//! statistical: an `Inmate` is a record of a conviction in a district, not a
+//! `Citizen` entity. Admission removes no one from the population and release
+//! adds no one back; a reoffense is reported as a new crime in the inmate's
+//! home district, and prior offenses follow the district's next conviction.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_prisons, PrisonSystemPlugin};
+pub use types::*;
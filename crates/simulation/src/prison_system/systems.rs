@@ -0,0 +1,266 @@
+//! Admission, sentence serving, release, and overcrowding.
+
+use bevy::prelude::*;
+
+use crate::crime_justice::{
+    ConvictionEvent, CrimeEvent, CrimeJusticeState, JusticeStage, PRISON_CAPACITY,
+};
+use crate::districts::DISTRICTS_X;
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::{ServiceBuilding, ServiceType};
+
+use super::types::*;
+
+impl PrisonState {
+    /// Match prison records to the prison buildings currently standing.
+    /// Inmates of demolished prisons are transferred where space allows and
+    /// released early otherwise. Returns the inmates released early.
+    pub fn sync_prisons(&mut self, positions: &[(usize, usize)]) -> Vec<Inmate> {
+        let mut displaced = Vec::new();
+        self.prisons.retain_mut(|p| {
+            let standing = positions.contains(&(p.grid_x, p.grid_y));
+            if !standing {
+                displaced.append(&mut p.inmates);
+            }
+            standing
+        });
+        for &(gx, gy) in positions {
+            if !self
+                .prisons
+                .iter()
+                .any(|p| p.grid_x == gx && p.grid_y == gy)
+            {
+                self.prisons.push(PrisonRecord {
+                    grid_x: gx,
+                    grid_y: gy,
+                    capacity: PRISON_CAPACITY,
+                    inmates: Vec::new(),
+                });
+            }
+        }
+        let mut early = Vec::new();
+        for inmate in displaced {
+            match self.least_occupied_with_space() {
+                Some(i) => self.prisons[i].inmates.push(inmate),
+                None => early.push(inmate),
+            }
+        }
+        early
+    }
+
+    fn least_occupied_with_space(&self) -> Option<usize> {
+        self.prisons
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !p.is_full())
+            .min_by_key(|(_, p)| p.inmates.len())
+            .map(|(i, _)| i)
+    }
+
+    /// Take custody of a convicted offender. Prisons may temporarily exceed
+    /// capacity; `relieve_overcrowding` resolves that with early releases.
+    /// Returns false if the city has no prison at all.
+    pub fn admit(&mut self, conviction: &ConvictionEvent) -> bool {
+        if self.prisons.is_empty() {
+            return false;
+        }
+        let prior_offenses = match self.repeat_offenders.iter().position(|r| {
+            r.district_x == conviction.district_x && r.district_y == conviction.district_y
+        }) {
+            Some(i) => self.repeat_offenders.remove(i).prior_offenses,
+            None => 0,
+        };
+        let sentence = self.policy.sentence_for(conviction.crime_type);
+        let inmate = Inmate {
+            crime_type: conviction.crime_type,
+            district_x: conviction.district_x,
+            district_y: conviction.district_y,
+            sentence,
+            remaining: sentence,
+            education: 0.0,
+            prior_offenses,
+        };
+        let target = self.least_occupied_with_space().unwrap_or_else(|| {
+            (0..self.prisons.len())
+                .min_by_key(|&i| self.prisons[i].inmates.len())
+                .unwrap_or(0)
+        });
+        self.prisons[target].inmates.push(inmate);
+        self.total_admitted += 1;
+        true
+    }
+
+    /// Advance every sentence by one slow tick, applying education progress.
+    /// Returns the inmates whose sentence is complete.
+    pub fn serve_sentences(&mut self) -> Vec<Inmate> {
+        let educate = self.education_program;
+        let mut released = Vec::new();
+        for prison in &mut self.prisons {
+            let mut kept = Vec::with_capacity(prison.inmates.len());
+            for mut inmate in prison.inmates.drain(..) {
+                inmate.remaining = inmate.remaining.saturating_sub(1);
+                if educate {
+                    inmate.education = (inmate.education + EDUCATION_PER_TICK).min(1.0);
+                }
+                if inmate.remaining == 0 {
+                    released.push(inmate);
+                } else {
+                    kept.push(inmate);
+                }
+            }
+            prison.inmates = kept;
+        }
+        released
+    }
+
+    /// Release inmates closest to the end of their sentence from any prison
+    /// holding more than its capacity. Returns the released inmates.
+    pub fn relieve_overcrowding(&mut self) -> Vec<Inmate> {
+        let mut released = Vec::new();
+        for prison in &mut self.prisons {
+            let cap = prison.capacity as usize;
+            if prison.inmates.len() <= cap {
+                continue;
+            }
+            prison
+                .inmates
+                .sort_by_key(|i| std::cmp::Reverse(i.remaining));
+            released.extend(prison.inmates.drain(cap..));
+        }
+        released
+    }
+
+    /// Roll recidivism for a released inmate. Reoffenders are remembered so
+    /// their record follows them into their next sentence.
+    pub fn release(&mut self, inmate: &Inmate) -> bool {
+        self.total_released += 1;
+        let chance = recidivism_chance(inmate, self.policy);
+        if self.next_random() >= chance {
+            return false;
+        }
+        self.total_reoffended += 1;
+        if self.repeat_offenders.len() >= MAX_TRACKED_REOFFENDERS {
+            self.repeat_offenders.remove(0);
+        }
+        self.repeat_offenders.push(RepeatOffender {
+            district_x: inmate.district_x,
+            district_y: inmate.district_y,
+            prior_offenses: inmate
+                .prior_offenses
+                .saturating_add(1)
+                .min(MAX_PRIOR_OFFENSES),
+        });
+        true
+    }
+}
+
+/// Report a new crime by a released inmate to the justice pipeline.
+fn report_reoffense(justice: &mut CrimeJusticeState, inmate: &Inmate) {
+    let di = inmate.district_y * DISTRICTS_X + inmate.district_x;
+    if let Some(stats) = justice.district_stats.get_mut(di) {
+        stats.increment(inmate.crime_type);
+    }
+    justice.events.push(CrimeEvent {
+        crime_type: inmate.crime_type,
+        district_x: inmate.district_x,
+        district_y: inmate.district_y,
+        stage: JusticeStage::Reported,
+        stage_timer: 0,
+    });
+}
+
+/// Convictions are only emitted on the slow tick, so the whole update runs
+/// there: sentences are served before new inmates are admitted.
+pub fn update_prisons(
+    slow_timer: Res<crate::SlowTickTimer>,
+    services: Query<&ServiceBuilding>,
+    mut convictions: EventReader<ConvictionEvent>,
+    mut state: ResMut<PrisonState>,
+    mut justice: ResMut<CrimeJusticeState>,
+    mut budget: ResMut<CityBudget>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    let positions: Vec<(usize, usize)> = services
+        .iter()
+        .filter(|s| s.service_type == ServiceType::Prison)
+        .map(|s| (s.grid_x, s.grid_y))
+        .collect();
+    let mut early_released = state.sync_prisons(&positions);
+
+    // Education programs are paid per enrolled inmate.
+    state.education_cost = if state.education_program {
+        state.population() as f64 * EDUCATION_COST_PER_INMATE
+    } else {
+        0.0
+    };
+    budget.treasury -= state.education_cost;
+
+    for inmate in state.serve_sentences() {
+        if state.release(&inmate) {
+            report_reoffense(&mut justice, &inmate);
+        }
+    }
+
+    for conviction in convictions.read() {
+        state.admit(conviction);
+    }
+
+    let crowded_at = state
+        .prisons
+        .iter()
+        .find(|p| p.inmates.len() as u32 > p.capacity)
+        .map(|p| WorldGrid::grid_to_world(p.grid_x, p.grid_y));
+    early_released.extend(state.relieve_overcrowding());
+    if !early_released.is_empty() {
+        state.total_early_released += early_released.len() as u32;
+        for inmate in &early_released {
+            if state.release(inmate) {
+                report_reoffense(&mut justice, inmate);
+            }
+        }
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Prison overcrowding: {} inmates released early.",
+                early_released.len()
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::Citizens,
+            location: crowded_at,
+        });
+    }
+
+    // Sentences from older saves may still be tracked by the justice pipeline.
+    let legacy = justice
+        .events
+        .iter()
+        .filter(|e| e.stage == JusticeStage::InJail)
+        .count() as u32;
+    justice.jail_population = state.population() + legacy;
+}
+
+pub struct PrisonSystemPlugin;
+
+impl Plugin for PrisonSystemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PrisonState>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<PrisonState>();
+
+        app.add_systems(
+            FixedUpdate,
+            update_prisons
+                .after(crate::crime_justice::advance_justice_pipeline)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
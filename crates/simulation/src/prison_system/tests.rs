@@ -0,0 +1,163 @@
+use super::*;
+use crate::crime_justice::{ConvictionEvent, CrimeType};
+use crate::Saveable;
+
+fn conviction(crime_type: CrimeType) -> ConvictionEvent {
+    ConvictionEvent {
+        crime_type,
+        district_x: 2,
+        district_y: 3,
+    }
+}
+
+fn inmate(education: f32, prior_offenses: u8) -> Inmate {
+    Inmate {
+        crime_type: CrimeType::Burglary,
+        district_x: 0,
+        district_y: 0,
+        sentence: 3,
+        remaining: 0,
+        education,
+        prior_offenses,
+    }
+}
+
+fn state_with_prison(capacity: u32) -> PrisonState {
+    let mut state = PrisonState::default();
+    state.sync_prisons(&[(10, 10)]);
+    state.prisons[0].capacity = capacity;
+    state
+}
+
+#[test]
+fn test_sentence_lengths_follow_policy() {
+    for crime in [
+        CrimeType::PettyTheft,
+        CrimeType::Assault,
+        CrimeType::OrganizedCrime,
+    ] {
+        let rehab = SentencingPolicy::Rehabilitative.sentence_for(crime);
+        let standard = SentencingPolicy::Standard.sentence_for(crime);
+        let punitive = SentencingPolicy::Punitive.sentence_for(crime);
+        assert!(rehab >= 1);
+        assert!(rehab <= standard && standard < punitive);
+    }
+    assert_eq!(
+        SentencingPolicy::Standard.sentence_for(CrimeType::Assault),
+        CrimeType::Assault.jail_time()
+    );
+}
+
+#[test]
+fn test_education_reduces_recidivism() {
+    let policy = SentencingPolicy::Standard;
+    let none = recidivism_chance(&inmate(0.0, 0), policy);
+    let full = recidivism_chance(&inmate(1.0, 0), policy);
+    assert!((none - BASE_RECIDIVISM).abs() < 1e-6);
+    assert!(full < none);
+}
+
+#[test]
+fn test_priors_increase_recidivism() {
+    let policy = SentencingPolicy::Standard;
+    assert!(
+        recidivism_chance(&inmate(0.0, 3), policy) > recidivism_chance(&inmate(0.0, 0), policy)
+    );
+}
+
+#[test]
+fn test_admit_without_prison_fails() {
+    let mut state = PrisonState::default();
+    assert!(!state.admit(&conviction(CrimeType::Burglary)));
+    assert_eq!(state.population(), 0);
+}
+
+#[test]
+fn test_admit_uses_policy_sentence() {
+    let mut state = state_with_prison(10);
+    state.policy = SentencingPolicy::Punitive;
+    assert!(state.admit(&conviction(CrimeType::Assault)));
+    let inmate = &state.prisons[0].inmates[0];
+    assert_eq!(
+        inmate.sentence,
+        SentencingPolicy::Punitive.sentence_for(CrimeType::Assault)
+    );
+    assert_eq!(inmate.remaining, inmate.sentence);
+}
+
+#[test]
+fn test_serve_sentences_releases_and_educates() {
+    let mut state = state_with_prison(10);
+    state.education_program = true;
+    state.admit(&conviction(CrimeType::PettyTheft));
+    state.admit(&conviction(CrimeType::OrganizedCrime));
+    let released = state.serve_sentences();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].crime_type, CrimeType::PettyTheft);
+    let remaining = &state.prisons[0].inmates[0];
+    assert!((remaining.education - EDUCATION_PER_TICK).abs() < 1e-6);
+}
+
+#[test]
+fn test_overcrowding_releases_shortest_sentences() {
+    let mut state = state_with_prison(2);
+    state.admit(&conviction(CrimeType::OrganizedCrime));
+    state.admit(&conviction(CrimeType::PettyTheft));
+    state.admit(&conviction(CrimeType::Assault));
+    assert_eq!(state.population(), 3);
+    let released = state.relieve_overcrowding();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].crime_type, CrimeType::PettyTheft);
+    assert_eq!(state.population(), 2);
+}
+
+#[test]
+fn test_demolished_prison_transfers_inmates() {
+    let mut state = PrisonState::default();
+    state.sync_prisons(&[(1, 1), (5, 5)]);
+    state.prisons[1].capacity = 1;
+    state.prisons[0].inmates.push(inmate(0.0, 0));
+    state.prisons[0].inmates.push(inmate(0.0, 0));
+    let early = state.sync_prisons(&[(5, 5)]);
+    assert_eq!(state.prisons.len(), 1);
+    assert_eq!(state.population(), 1);
+    assert_eq!(early.len(), 1);
+}
+
+#[test]
+fn test_reoffender_priors_carry_into_next_sentence() {
+    let mut state = state_with_prison(10);
+    let mut released = inmate(0.0, 0);
+    released.district_x = 2;
+    released.district_y = 3;
+    // Force a reoffense by making the chance near-certain.
+    released.prior_offenses = MAX_PRIOR_OFFENSES;
+    let mut reoffended = false;
+    for _ in 0..20 {
+        if state.release(&released) {
+            reoffended = true;
+            break;
+        }
+    }
+    assert!(reoffended);
+    state.admit(&conviction(CrimeType::Burglary));
+    assert_eq!(
+        state.prisons[0].inmates[0].prior_offenses,
+        MAX_PRIOR_OFFENSES
+    );
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    let mut state = state_with_prison(10);
+    state.policy = SentencingPolicy::Rehabilitative;
+    state.education_program = true;
+    state.admit(&conviction(CrimeType::Burglary));
+    state.total_released = 7;
+    let bytes = state.save_to_bytes().expect("should serialize");
+    let restored = PrisonState::load_from_bytes(&bytes);
+    assert_eq!(restored.policy, SentencingPolicy::Rehabilitative);
+    assert!(restored.education_program);
+    assert_eq!(restored.prisons, state.prisons);
+    assert_eq!(restored.total_released, 7);
+}
@@ -0,0 +1,226 @@
+//! Types and pure helper functions for the prison system.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::crime_justice::CrimeType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Chance that a released inmate with no education and no priors reoffends.
+pub const BASE_RECIDIVISM: f32 = 0.45;
+
+/// Fraction of recidivism removed by a fully completed education program.
+pub const EDUCATION_RECIDIVISM_REDUCTION: f32 = 0.5;
+
+/// Extra recidivism per prior offense (multiplicative).
+pub const PRIOR_OFFENSE_FACTOR: f32 = 0.1;
+
+/// Education progress gained per slow tick while enrolled (0..1).
+pub const EDUCATION_PER_TICK: f32 = 0.2;
+
+/// Treasury cost per enrolled inmate per slow tick.
+pub const EDUCATION_COST_PER_INMATE: f64 = 0.5;
+
+/// Upper bound on tracked prior offenses.
+pub const MAX_PRIOR_OFFENSES: u8 = 10;
+
+/// Repeat offenders remembered while at large (oldest dropped first).
+pub const MAX_TRACKED_REOFFENDERS: usize = 256;
+
+// ---------------------------------------------------------------------------
+// Sentencing policy
+// ---------------------------------------------------------------------------
+
+/// City-wide justice policy controlling sentence lengths.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum SentencingPolicy {
+    /// Short sentences focused on reintegration.
+    Rehabilitative,
+    #[default]
+    Standard,
+    /// Long sentences; fuller prisons and harder reintegration.
+    Punitive,
+}
+
+impl SentencingPolicy {
+    pub const ALL: [SentencingPolicy; 3] = [Self::Rehabilitative, Self::Standard, Self::Punitive];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Rehabilitative => "Rehabilitative",
+            Self::Standard => "Standard",
+            Self::Punitive => "Punitive",
+        }
+    }
+
+    /// Multiplier applied to the base jail time of a crime.
+    pub fn sentence_multiplier(self) -> f32 {
+        match self {
+            Self::Rehabilitative => 0.6,
+            Self::Standard => 1.0,
+            Self::Punitive => 1.8,
+        }
+    }
+
+    /// Multiplier applied to the recidivism chance on release.
+    pub fn recidivism_multiplier(self) -> f32 {
+        match self {
+            Self::Rehabilitative => 0.8,
+            Self::Standard => 1.0,
+            Self::Punitive => 1.15,
+        }
+    }
+
+    /// Sentence length in slow ticks for a crime (at least one).
+    pub fn sentence_for(self, crime: CrimeType) -> u32 {
+        ((crime.jail_time() as f32 * self.sentence_multiplier()).round() as u32).max(1)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Inmates and prisons
+// ---------------------------------------------------------------------------
+
+/// A convicted citizen serving a sentence.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Inmate {
+    pub crime_type: CrimeType,
+    /// Statistical district the offender came from (and returns to).
+    pub district_x: usize,
+    pub district_y: usize,
+    pub sentence: u32,
+    /// Slow ticks left to serve.
+    pub remaining: u32,
+    /// Education program progress (0..1).
+    pub education: f32,
+    pub prior_offenses: u8,
+}
+
+/// A released inmate who committed a new crime.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct RepeatOffender {
+    pub district_x: usize,
+    pub district_y: usize,
+    pub prior_offenses: u8,
+}
+
+/// One prison building and the inmates it holds.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct PrisonRecord {
+    pub grid_x: usize,
+    pub grid_y: usize,
+    pub capacity: u32,
+    pub inmates: Vec<Inmate>,
+}
+
+impl PrisonRecord {
+    pub fn is_full(&self) -> bool {
+        self.inmates.len() as u32 >= self.capacity
+    }
+}
+
+/// Chance that an inmate reoffends after release.
+pub fn recidivism_chance(inmate: &Inmate, policy: SentencingPolicy) -> f32 {
+    let education = inmate.education.clamp(0.0, 1.0);
+    let priors = 1.0 + PRIOR_OFFENSE_FACTOR * inmate.prior_offenses as f32;
+    (BASE_RECIDIVISM
+        * (1.0 - EDUCATION_RECIDIVISM_REDUCTION * education)
+        * policy.recidivism_multiplier()
+        * priors)
+        .clamp(0.0, 0.95)
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Prison population, justice policy, and release outcomes.
+#[derive(Resource, Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct PrisonState {
+    pub prisons: Vec<PrisonRecord>,
+    pub policy: SentencingPolicy,
+    /// Whether inmates are enrolled in education programs.
+    pub education_program: bool,
+    pub total_admitted: u32,
+    pub total_released: u32,
+    pub total_reoffended: u32,
+    pub total_early_released: u32,
+    /// Education cost charged on the last slow tick.
+    pub education_cost: f64,
+    /// Released inmates who reoffended and have not been convicted again yet.
+    /// Their prior-offense count carries into their next sentence.
+    pub repeat_offenders: Vec<RepeatOffender>,
+    pub rng_state: u64,
+}
+
+impl Default for PrisonState {
+    fn default() -> Self {
+        Self {
+            prisons: Vec::new(),
+            policy: SentencingPolicy::default(),
+            education_program: false,
+            total_admitted: 0,
+            total_released: 0,
+            total_reoffended: 0,
+            total_early_released: 0,
+            education_cost: 0.0,
+            repeat_offenders: Vec::new(),
+            rng_state: 0x5EED_1A11,
+        }
+    }
+}
+
+impl PrisonState {
+    pub fn population(&self) -> u32 {
+        self.prisons.iter().map(|p| p.inmates.len() as u32).sum()
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.prisons.iter().map(|p| p.capacity).sum()
+    }
+
+    /// Occupancy as a fraction of capacity (0 when there are no prisons).
+    pub fn occupancy(&self) -> f32 {
+        let cap = self.capacity();
+        if cap == 0 {
+            0.0
+        } else {
+            self.population() as f32 / cap as f32
+        }
+    }
+
+    /// Fraction of released inmates that went on to reoffend.
+    pub fn recidivism_rate(&self) -> f32 {
+        if self.total_released == 0 {
+            0.0
+        } else {
+            self.total_reoffended as f32 / self.total_released as f32
+        }
+    }
+
+    pub(crate) fn next_random(&mut self) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x % 10000) as f32 / 10000.0
+    }
+}
+
+impl Saveable for PrisonState {
+    const SAVE_KEY: &'static str = "prison_system";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::types::{GoodsType, IndustryType};
+
+/// Industries getting less than this share of the inputs (or deposits) they
+/// need are reported as bottlenecks.
+pub const BOTTLENECK_SATISFACTION: f32 = 0.9;
+
+// =============================================================================
+// Flow nodes and edges
+// =============================================================================
+
+/// A producer or consumer of goods in the flow diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowNode {
+    Imports,
+    Industry(IndustryType),
+    /// Shops and households buying goods for the population.
+    Commerce,
+    Exports,
+}
+
+impl FlowNode {
+    /// Left-to-right column of the node: imports, extraction, processing,
+    /// manufacturing, then commerce and exports.
+    pub fn column(self) -> usize {
+        match self {
+            Self::Imports => 0,
+            Self::Industry(i) if i.is_extraction() => 1,
+            Self::Industry(i) if i.is_processing() => 2,
+            Self::Industry(_) => 3,
+            Self::Commerce | Self::Exports => 4,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Imports => "Imports",
+            Self::Industry(i) => i.name(),
+            Self::Commerce => "Commerce",
+            Self::Exports => "Exports",
+        }
+    }
+}
+
+/// Goods moving from one node to another during the last production cycle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowEdge {
+    pub from: FlowNode,
+    pub to: FlowNode,
+    pub goods: GoodsType,
+    pub amount: f32,
+}
+
+// =============================================================================
+// Per-cycle flow tracking
+// =============================================================================
+
+/// What the buildings of one industry did during the last production cycle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndustryFlow {
+    /// Buildings of this industry, staffed or not.
+    pub buildings: u32,
+    /// Staffed buildings that got less than `BOTTLENECK_SATISFACTION` of
+    /// their inputs.
+    pub starved: u32,
+    /// Share of the wanted inputs received, summed over staffed buildings.
+    satisfaction_sum: f32,
+    staffed: u32,
+    pub produced: HashMap<GoodsType, f32>,
+    pub consumed: HashMap<GoodsType, f32>,
+    /// Inputs the buildings would have used with full supply.
+    pub wanted: HashMap<GoodsType, f32>,
+}
+
+impl IndustryFlow {
+    /// Average share of wanted inputs received by staffed buildings, 1.0
+    /// when none are staffed.
+    pub fn satisfaction(&self) -> f32 {
+        if self.staffed == 0 {
+            1.0
+        } else {
+            self.satisfaction_sum / self.staffed as f32
+        }
+    }
+
+    pub fn is_bottleneck(&self) -> bool {
+        self.starved > 0 && self.satisfaction() < BOTTLENECK_SATISFACTION
+    }
+
+    /// Total output across all goods.
+    pub fn throughput(&self) -> f32 {
+        self.produced.values().sum()
+    }
+
+    /// The input this industry is shortest of, by share received.
+    pub fn scarcest_input(&self) -> Option<GoodsType> {
+        self.wanted
+            .iter()
+            .filter(|(_, w)| **w > 0.0)
+            .map(|(g, w)| (*g, self.consumed.get(g).copied().unwrap_or(0.0) / w))
+            .filter(|&(_, share)| share < BOTTLENECK_SATISFACTION)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(g, _)| g)
+    }
+}
+
+/// Goods flows between industries, commerce, imports and exports during the
+/// last production cycle, for the flow diagram.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GoodsFlows {
+    pub industries: HashMap<IndustryType, IndustryFlow>,
+    pub imports: HashMap<GoodsType, f32>,
+    pub exports: HashMap<GoodsType, f32>,
+    /// Goods bought by the population through commerce.
+    pub commerce: HashMap<GoodsType, f32>,
+}
+
+fn add(map: &mut HashMap<GoodsType, f32>, goods: GoodsType, amount: f32) {
+    if amount > 0.0 {
+        *map.entry(goods).or_insert(0.0) += amount;
+    }
+}
+
+impl GoodsFlows {
+    /// Record a building of `industry`. `satisfaction` is the share of its
+    /// wanted inputs or deposits it received; `None` if it had no workers.
+    pub fn record_building(&mut self, industry: IndustryType, satisfaction: Option<f32>) {
+        let flow = self.industries.entry(industry).or_default();
+        flow.buildings += 1;
+        if let Some(share) = satisfaction {
+            flow.staffed += 1;
+            flow.satisfaction_sum += share.clamp(0.0, 1.0);
+            if share < BOTTLENECK_SATISFACTION {
+                flow.starved += 1;
+            }
+        }
+    }
+
+    pub fn record_output(&mut self, industry: IndustryType, goods: GoodsType, amount: f32) {
+        add(
+            &mut self.industries.entry(industry).or_default().produced,
+            goods,
+            amount,
+        );
+    }
+
+    pub fn record_input(
+        &mut self,
+        industry: IndustryType,
+        goods: GoodsType,
+        wanted: f32,
+        consumed: f32,
+    ) {
+        let flow = self.industries.entry(industry).or_default();
+        add(&mut flow.wanted, goods, wanted);
+        add(&mut flow.consumed, goods, consumed);
+    }
+
+    pub fn record_commerce(&mut self, goods: GoodsType, amount: f32) {
+        add(&mut self.commerce, goods, amount);
+    }
+
+    pub fn record_import(&mut self, goods: GoodsType, amount: f32) {
+        add(&mut self.imports, goods, amount);
+    }
+
+    pub fn record_export(&mut self, goods: GoodsType, amount: f32) {
+        add(&mut self.exports, goods, amount);
+    }
+
+    /// Nodes with any activity, in column order.
+    pub fn nodes(&self) -> Vec<FlowNode> {
+        let mut nodes = Vec::new();
+        if !self.imports.is_empty() {
+            nodes.push(FlowNode::Imports);
+        }
+        for &industry in IndustryType::all() {
+            if self
+                .industries
+                .get(&industry)
+                .is_some_and(|f| f.buildings > 0)
+            {
+                nodes.push(FlowNode::Industry(industry));
+            }
+        }
+        if !self.commerce.is_empty() {
+            nodes.push(FlowNode::Commerce);
+        }
+        if !self.exports.is_empty() {
+            nodes.push(FlowNode::Exports);
+        }
+        nodes
+    }
+
+    /// Supply of `goods` by node: industry output plus imports.
+    fn sources(&self, goods: GoodsType) -> Vec<(FlowNode, f32)> {
+        let mut sources: Vec<(FlowNode, f32)> = IndustryType::all()
+            .iter()
+            .filter_map(|&i| {
+                let amount = self.industries.get(&i)?.produced.get(&goods).copied()?;
+                Some((FlowNode::Industry(i), amount))
+            })
+            .collect();
+        if let Some(&amount) = self.imports.get(&goods) {
+            sources.push((FlowNode::Imports, amount));
+        }
+        sources
+    }
+
+    /// Demand met for `goods` by node: industry inputs, commerce and exports.
+    fn sinks(&self, goods: GoodsType) -> Vec<(FlowNode, f32)> {
+        let mut sinks: Vec<(FlowNode, f32)> = IndustryType::all()
+            .iter()
+            .filter_map(|&i| {
+                let amount = self.industries.get(&i)?.consumed.get(&goods).copied()?;
+                Some((FlowNode::Industry(i), amount))
+            })
+            .collect();
+        if let Some(&amount) = self.commerce.get(&goods) {
+            sinks.push((FlowNode::Commerce, amount));
+        }
+        if let Some(&amount) = self.exports.get(&goods) {
+            sinks.push((FlowNode::Exports, amount));
+        }
+        sinks
+    }
+
+    /// Edges between nodes. Goods go into one city-wide stockpile, so each
+    /// consumer's intake is split across producers by their share of supply.
+    pub fn edges(&self) -> Vec<FlowEdge> {
+        let mut edges = Vec::new();
+        for &goods in GoodsType::all() {
+            let sources = self.sources(goods);
+            let supply: f32 = sources.iter().map(|(_, a)| a).sum();
+            if supply <= 0.0 {
+                continue;
+            }
+            for (to, taken) in self.sinks(goods) {
+                for &(from, supplied) in &sources {
+                    let amount = taken * supplied / supply;
+                    if from != to && amount > 0.0 {
+                        edges.push(FlowEdge {
+                            from,
+                            to,
+                            goods,
+                            amount,
+                        });
+                    }
+                }
+            }
+        }
+        edges
+    }
+
+    /// Goods that industries wanted more of than they got.
+    pub fn is_short(&self, goods: GoodsType) -> bool {
+        self.industries.values().any(|f| {
+            let wanted = f.wanted.get(&goods).copied().unwrap_or(0.0);
+            let consumed = f.consumed.get(&goods).copied().unwrap_or(0.0);
+            wanted > 0.0 && consumed < wanted * BOTTLENECK_SATISFACTION
+        })
+    }
+
+    /// Industries whose buildings are starved, in column order.
+    pub fn bottlenecks(&self) -> Vec<IndustryType> {
+        IndustryType::all()
+            .iter()
+            .copied()
+            .filter(|i| {
+                self.industries
+                    .get(i)
+                    .is_some_and(IndustryFlow::is_bottleneck)
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,13 @@
+pub(crate) mod flows;
 pub(crate) mod systems;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_flows;
 pub(crate) mod types;
 
-pub use systems::{assign_industry_type, update_production_chains};
+pub use flows::{FlowEdge, FlowNode, GoodsFlows, IndustryFlow, BOTTLENECK_SATISFACTION};
+pub use systems::{assign_industry_type, retire_exhausted_extraction, update_production_chains};
 pub use types::{CityGoods, GoodsType, IndustryBuilding, IndustryType, ProductionChain};
 
 use bevy::prelude::*;
@@ -12,12 +16,18 @@ pub struct ProductionPlugin;
 
 impl Plugin for ProductionPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CityGoods>().add_systems(
-            FixedUpdate,
-            (assign_industry_type, update_production_chains)
-                .chain()
-                .after(crate::agriculture::update_agriculture)
-                .in_set(crate::SimulationSet::Simulation),
-        );
+        app.init_resource::<CityGoods>()
+            .init_resource::<GoodsFlows>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    assign_industry_type,
+                    update_production_chains,
+                    retire_exhausted_extraction,
+                )
+                    .chain()
+                    .after(crate::agriculture::update_agriculture)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
     }
 }
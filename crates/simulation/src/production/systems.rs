@@ -6,16 +6,21 @@ use crate::buildings::Building;
 use crate::citizen::{CitizenDetails, WorkLocation};
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::economy::CityBudget;
-use crate::grid::ZoneType;
+use crate::grid::{WorldGrid, ZoneType};
 use crate::natural_resources::{ResourceGrid, ResourceType};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use crate::TickCounter;
 
+use super::flows::GoodsFlows;
 use super::types::{chain_for, CityGoods, GoodsType, IndustryBuilding, IndustryType};
 
 /// How often the production chains run (every N ticks). At 10Hz fixed update,
 /// 10 ticks = 1 second of game time.
 const PRODUCTION_INTERVAL: u64 = 10;
 
+/// How far (in cells) an extraction building reaches for its deposits.
+pub(crate) const EXTRACTION_RADIUS: isize = 4;
+
 /// When a new industrial Building spawns without an IndustryBuilding component,
 /// auto-assign an IndustryType based on nearby ResourceGrid deposits.
 /// Falls back to Manufacturing if no notable deposits are found.
@@ -122,6 +127,9 @@ pub(crate) fn pick_industry_from_nearby_resources(
 /// 3. Processing/Manufacturing consume inputs from CityGoods and produce outputs.
 /// 4. Computes city-wide consumption from population.
 /// 5. Surplus generates export income; deficit triggers expensive imports.
+///
+/// Every cycle also records the flows between industries, commerce, imports
+/// and exports in `GoodsFlows` for the flow diagram.
 #[allow(clippy::too_many_arguments)]
 pub fn update_production_chains(
     tick: Res<TickCounter>,
@@ -131,11 +139,12 @@ pub fn update_production_chains(
     workers_q: Query<(&WorkLocation, &CitizenDetails)>,
     stats: Res<crate::stats::CityStats>,
     mut budget: ResMut<CityBudget>,
+    mut flows: ResMut<GoodsFlows>,
 ) {
     if !tick.0.is_multiple_of(PRODUCTION_INTERVAL) {
         return;
     }
-
+    *flows = GoodsFlows::default();
 
     // -------------------------------------------------------------------------
     // 1. Reset per-cycle production/consumption rates
@@ -170,6 +179,7 @@ pub fn update_production_chains(
 
         if industry.workers == 0 {
             industry.efficiency = 0.0;
+            flows.record_building(industry.industry_type, None);
             continue;
         }
 
@@ -204,9 +214,12 @@ pub fn update_production_chains(
                 production_scale,
             );
 
+            flows.record_building(industry.industry_type, Some(extracted));
+
             // Add extracted goods to city stockpile
             for (goods, amount) in &chain.outputs {
                 let produced = amount * extracted;
+                flows.record_output(industry.industry_type, *goods, produced);
                 *city_goods.available.entry(*goods).or_insert(0.0) += produced;
                 *city_goods.production_rate.entry(*goods).or_insert(0.0) += produced;
                 *industry.output_storage.entry(*goods).or_insert(0.0) += produced;
@@ -229,6 +242,16 @@ pub fn update_production_chains(
                 }
             }
 
+            if !can_produce {
+                limiting_factor = 0.0;
+            }
+            flows.record_building(industry.industry_type, Some(limiting_factor));
+            for (goods, amount_needed) in &chain.inputs {
+                let wanted = amount_needed * production_scale;
+                let consumed = wanted * limiting_factor;
+                flows.record_input(industry.industry_type, *goods, wanted, consumed);
+            }
+
             if can_produce {
                 let actual_scale = production_scale * limiting_factor;
 
@@ -245,6 +268,7 @@ pub fn update_production_chains(
                 // Produce outputs
                 for (goods, amount) in &chain.outputs {
                     let produced = amount * actual_scale;
+                    flows.record_output(industry.industry_type, *goods, produced);
                     *city_goods.available.entry(*goods).or_insert(0.0) += produced;
                     *city_goods.production_rate.entry(*goods).or_insert(0.0) += produced;
                     *industry.output_storage.entry(*goods).or_insert(0.0) += produced;
@@ -263,11 +287,17 @@ pub fn update_production_chains(
     let electronics_demand = pop * 0.001;
 
     // Consume ProcessedFood first, then RawFood as fallback
-    consume_goods(&mut city_goods, GoodsType::ProcessedFood, food_demand * 0.7);
-    consume_goods(&mut city_goods, GoodsType::RawFood, food_demand * 0.3);
-    consume_goods(&mut city_goods, GoodsType::ConsumerGoods, goods_demand);
-    consume_goods(&mut city_goods, GoodsType::Fuel, fuel_demand);
-    consume_goods(&mut city_goods, GoodsType::Electronics, electronics_demand);
+    let demand = [
+        (GoodsType::ProcessedFood, food_demand * 0.7),
+        (GoodsType::RawFood, food_demand * 0.3),
+        (GoodsType::ConsumerGoods, goods_demand),
+        (GoodsType::Fuel, fuel_demand),
+        (GoodsType::Electronics, electronics_demand),
+    ];
+    for (goods, amount) in demand {
+        consume_goods(&mut city_goods, goods, amount);
+        flows.record_commerce(goods, amount);
+    }
 
     // -------------------------------------------------------------------------
     // 5. Trade: surplus -> export income, deficit -> import cost
@@ -278,6 +308,7 @@ pub fn update_production_chains(
         // Surplus threshold: anything above 100 units gets exported
         if stock > 100.0 {
             let surplus = stock - 100.0;
+            flows.record_export(g, surplus);
             trade_balance += surplus as f64 * g.export_price() * 0.01; // per-tick fraction
                                                                        // Cap stockpile at 100 (export the rest)
             city_goods.available.insert(g, 100.0);
@@ -291,6 +322,7 @@ pub fn update_production_chains(
             trade_balance -= deficit as f64 * g.import_price() * 0.01;
             // Add imported goods to stockpile
             *city_goods.available.entry(g).or_insert(0.0) += deficit * 0.5;
+            flows.record_import(g, deficit * 0.5);
         }
     }
 
@@ -325,7 +357,7 @@ fn extract_from_deposits(
         _ => return 0.0,
     };
 
-    let search_radius: isize = 4;
+    let search_radius = EXTRACTION_RADIUS;
     let mut total_extracted = 0.0f32;
     let mut remaining_demand = production_scale;
 
@@ -372,3 +404,66 @@ fn extract_from_deposits(
         0.0
     }
 }
+
+/// Extraction buildings whose deposits have run dry switch over to the
+/// processing stage of their chain, working imported raw materials instead.
+/// A building that never had a deposit in reach is left alone.
+pub fn retire_exhausted_extraction(
+    tick: Res<TickCounter>,
+    resource_grid: Res<ResourceGrid>,
+    mut industry_q: Query<(&Building, &mut IndustryBuilding)>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !tick.0.is_multiple_of(PRODUCTION_INTERVAL) {
+        return;
+    }
+
+    let radius = EXTRACTION_RADIUS as usize;
+    for (building, mut industry) in &mut industry_q {
+        let Some(resource) = industry.industry_type.extracted_resource() else {
+            continue;
+        };
+        let (gx, gy) = (building.grid_x, building.grid_y);
+        if resource_grid.remaining_near(gx, gy, radius, resource) > 0
+            || !has_exhausted_deposit_near(&resource_grid, gx, gy, radius, resource)
+        {
+            continue;
+        }
+
+        let from = industry.industry_type;
+        let to = from.processing_stage();
+        *industry = IndustryBuilding::new(to);
+        notifications.send(NotificationEvent {
+            text: format!(
+                "{} deposits are exhausted; the {} site now runs as {}",
+                resource.name(),
+                from.name(),
+                to.name()
+            ),
+            priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
+            location: Some(WorldGrid::grid_to_world(gx, gy)),
+        });
+    }
+}
+
+fn has_exhausted_deposit_near(
+    resource_grid: &ResourceGrid,
+    gx: usize,
+    gy: usize,
+    radius: usize,
+    resource: ResourceType,
+) -> bool {
+    let (x1, y1) = (
+        (gx + radius).min(GRID_WIDTH - 1),
+        (gy + radius).min(GRID_HEIGHT - 1),
+    );
+    (gy.saturating_sub(radius)..=y1).any(|y| {
+        (gx.saturating_sub(radius)..=x1).any(|x| {
+            resource_grid
+                .get(x, y)
+                .as_ref()
+                .is_some_and(|d| d.resource_type == resource && d.is_exhausted())
+        })
+    })
+}
@@ -0,0 +1,90 @@
+//! Tests for the goods flows behind the production chain diagram.
+
+#[cfg(test)]
+mod tests {
+    use crate::production::flows::{FlowEdge, FlowNode, GoodsFlows};
+    use crate::production::types::{GoodsType, IndustryType};
+
+    /// Steel from mining and imports, feeding a starved manufacturer.
+    fn steel_flows() -> GoodsFlows {
+        let mut flows = GoodsFlows::default();
+        flows.record_building(IndustryType::Mining, Some(1.0));
+        flows.record_output(IndustryType::Mining, GoodsType::Steel, 3.0);
+        flows.record_import(GoodsType::Steel, 1.0);
+        flows.record_building(IndustryType::Manufacturing, Some(0.5));
+        flows.record_building(IndustryType::Manufacturing, None);
+        flows.record_input(IndustryType::Manufacturing, GoodsType::Steel, 4.0, 2.0);
+        flows.record_output(IndustryType::Manufacturing, GoodsType::ConsumerGoods, 1.2);
+        flows.record_commerce(GoodsType::ConsumerGoods, 1.0);
+        flows
+    }
+
+    #[test]
+    fn test_goods_flows_split_intake_by_supply_share() {
+        let flows = steel_flows();
+        let edges = flows.edges();
+        let manufacturing = FlowNode::Industry(IndustryType::Manufacturing);
+        assert!(edges.contains(&FlowEdge {
+            from: FlowNode::Industry(IndustryType::Mining),
+            to: manufacturing,
+            goods: GoodsType::Steel,
+            amount: 1.5,
+        }));
+        assert!(edges.contains(&FlowEdge {
+            from: FlowNode::Imports,
+            to: manufacturing,
+            goods: GoodsType::Steel,
+            amount: 0.5,
+        }));
+        assert!(edges.contains(&FlowEdge {
+            from: manufacturing,
+            to: FlowNode::Commerce,
+            goods: GoodsType::ConsumerGoods,
+            amount: 1.0,
+        }));
+        assert_eq!(edges.len(), 3);
+    }
+
+    #[test]
+    fn test_goods_flows_nodes_in_column_order() {
+        let nodes = steel_flows().nodes();
+        assert_eq!(
+            nodes,
+            vec![
+                FlowNode::Imports,
+                FlowNode::Industry(IndustryType::Mining),
+                FlowNode::Industry(IndustryType::Manufacturing),
+                FlowNode::Commerce,
+            ]
+        );
+        let columns: Vec<usize> = nodes.iter().map(|n| n.column()).collect();
+        assert_eq!(columns, vec![0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_goods_flows_bottlenecks() {
+        let flows = steel_flows();
+        assert_eq!(flows.bottlenecks(), vec![IndustryType::Manufacturing]);
+        assert!(flows.is_short(GoodsType::Steel));
+        assert!(!flows.is_short(GoodsType::ConsumerGoods));
+
+        let manufacturing = &flows.industries[&IndustryType::Manufacturing];
+        assert_eq!(manufacturing.buildings, 2);
+        assert_eq!(manufacturing.starved, 1);
+        assert_eq!(manufacturing.satisfaction(), 0.5);
+        assert_eq!(manufacturing.scarcest_input(), Some(GoodsType::Steel));
+        assert_eq!(
+            flows.industries[&IndustryType::Mining].scarcest_input(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_goods_flows_skip_self_loops() {
+        let mut flows = GoodsFlows::default();
+        flows.record_building(IndustryType::Refinery, Some(1.0));
+        flows.record_input(IndustryType::Refinery, GoodsType::Fuel, 1.0, 1.0);
+        flows.record_output(IndustryType::Refinery, GoodsType::Fuel, 1.5);
+        assert!(flows.edges().is_empty());
+    }
+}
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::natural_resources::ResourceType;
+
 // =============================================================================
 // Industry Types
 // =============================================================================
@@ -47,6 +49,29 @@ impl IndustryType {
         matches!(self, Self::Manufacturing | Self::TechAssembly)
     }
 
+    /// The deposit an extraction industry works.
+    pub fn extracted_resource(self) -> Option<ResourceType> {
+        match self {
+            Self::Agriculture => Some(ResourceType::FertileLand),
+            Self::Forestry => Some(ResourceType::Forest),
+            Self::Mining => Some(ResourceType::Ore),
+            Self::OilExtraction => Some(ResourceType::Oil),
+            _ => None,
+        }
+    }
+
+    /// The processing stage fed by an extraction industry; other industries
+    /// are returned unchanged.
+    pub fn processing_stage(self) -> Self {
+        match self {
+            Self::Agriculture => Self::FoodProcessing,
+            Self::Forestry => Self::SawMill,
+            Self::Mining => Self::Smelter,
+            Self::OilExtraction => Self::Refinery,
+            other => other,
+        }
+    }
+
     /// Human-readable name.
     pub fn name(self) -> &'static str {
         match self {
@@ -62,6 +87,22 @@ impl IndustryType {
             Self::TechAssembly => "Tech Assembly",
         }
     }
+
+    /// All industry types, in chain order.
+    pub fn all() -> &'static [IndustryType] {
+        &[
+            Self::Agriculture,
+            Self::Forestry,
+            Self::Mining,
+            Self::OilExtraction,
+            Self::FoodProcessing,
+            Self::SawMill,
+            Self::Smelter,
+            Self::Refinery,
+            Self::Manufacturing,
+            Self::TechAssembly,
+        ]
+    }
 }
 
 // =============================================================================
@@ -0,0 +1,126 @@
+//! The player profile: preferences and progress that belong to the player
+//! rather than to any one city.
+//!
+//! `PlayerProfile` holds the keybindings, UI scale, accessibility, popup
+//! filter, audio and colorblind settings, the mods switched off, plus every
+//! achievement unlocked and tutorial completed in any city. It lives outside save files, in
+//! `profile.json` in the per-user config directory on desktop and in
+//! `localStorage` in the browser, so starting a new city or deleting a save
+//! never resets it.
+//!
+//! The profile is read once at startup, where it seeds the settings
+//! resources, and `sync_profile` writes it back whenever one of them changes
+//! or the city earns something new. Its JSON carries its own
+//! `PROFILE_VERSION`, independent of the save version. The first run of a
+//! build with profiles imports the separate settings files older builds
+//! wrote to the working directory.
+
+pub mod store;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use store::{load_profile, ProfileStore, PROFILE_FILE, PROFILE_STORAGE_KEY};
+pub use types::{PlayerProfile, PROFILE_VERSION};
+
+use bevy::prelude::*;
+
+use crate::accessibility::AccessibilitySettings;
+use crate::achievements::AchievementTracker;
+use crate::audio_settings::AudioSettings;
+use crate::colorblind::ColorblindSettings;
+use crate::keybindings::KeyBindings;
+use crate::mods::ModManager;
+use crate::notification_filters::NotificationFilters;
+use crate::tutorial::TutorialState;
+use crate::ui_scale::UiScaleSettings;
+
+/// Copy `value` into `slot` if they differ. Returns whether it did.
+fn sync<T: PartialEq + Clone>(slot: &mut T, value: &T) -> bool {
+    if slot == value {
+        return false;
+    }
+    *slot = value.clone();
+    true
+}
+
+/// System: fold changed settings, new achievements and finished tutorials
+/// into the profile, and write it to the store when anything changed.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_profile(
+    mut profile: ResMut<PlayerProfile>,
+    store: Res<ProfileStore>,
+    keybindings: Res<KeyBindings>,
+    ui_scale: Res<UiScaleSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    notification_filters: Res<NotificationFilters>,
+    audio: Res<AudioSettings>,
+    colorblind: Res<ColorblindSettings>,
+    mods: Res<ModManager>,
+    achievements: Res<AchievementTracker>,
+    tutorial: Res<TutorialState>,
+) {
+    let mut changed = false;
+    {
+        let p = profile.bypass_change_detection();
+        if keybindings.is_changed() {
+            changed |= sync(&mut p.keybindings, &keybindings);
+        }
+        if ui_scale.is_changed() {
+            changed |= sync(&mut p.ui_scale, &ui_scale);
+        }
+        if accessibility.is_changed() {
+            changed |= sync(&mut p.accessibility, &accessibility);
+        }
+        if notification_filters.is_changed() {
+            changed |= sync(&mut p.notification_filters, &notification_filters);
+        }
+        if audio.is_changed() {
+            changed |= sync(&mut p.audio, &audio);
+        }
+        if colorblind.is_changed() {
+            changed |= sync(&mut p.colorblind, &colorblind.mode);
+        }
+        if mods.is_changed() {
+            changed |= sync(&mut p.disabled_mods, &mods.disabled);
+        }
+        if achievements.is_changed() {
+            changed |= p.record_achievements(&achievements);
+        }
+        if tutorial.is_changed() && tutorial.completed {
+            changed |= p.record_tutorial(&tutorial.tutorial.id);
+        }
+    }
+    if !changed {
+        return;
+    }
+    profile.set_changed();
+    if let Err(e) = profile.to_json().and_then(|json| store.write(&json)) {
+        warn!("Failed to save player profile: {e}");
+    }
+}
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        let mut store = app
+            .world()
+            .get_resource::<ProfileStore>()
+            .cloned()
+            .unwrap_or_default();
+        let profile = load_profile(&mut store);
+
+        app.insert_resource(profile.keybindings.clone())
+            .insert_resource(profile.ui_scale.clone())
+            .insert_resource(profile.accessibility.clone())
+            .insert_resource(profile.notification_filters.clone())
+            .insert_resource(profile.audio.clone())
+            .insert_resource(ColorblindSettings {
+                mode: profile.colorblind,
+            })
+            .insert_resource(profile)
+            .insert_resource(store)
+            .add_systems(Update, sync_profile);
+    }
+}
@@ -0,0 +1,183 @@
+//! Where the profile is kept: a file in the per-user config directory on
+//! desktop, `localStorage` in the browser.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use super::types::PlayerProfile;
+
+/// Name of the profile file inside [`profile_dir`].
+pub const PROFILE_FILE: &str = "profile.json";
+
+/// `localStorage` key the browser build keeps the profile under.
+pub const PROFILE_STORAGE_KEY: &str = "megacity_profile";
+
+/// Where the profile is read from and written to.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub enum ProfileStore {
+    /// A JSON file on disk.
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+    /// The browser's `localStorage`, under [`PROFILE_STORAGE_KEY`].
+    #[cfg(target_arch = "wasm32")]
+    LocalStorage,
+    /// Nowhere: the profile lasts as long as the app. Used by tests and
+    /// headless runs, which must not touch the player's real profile.
+    Memory,
+}
+
+impl Default for ProfileStore {
+    fn default() -> Self {
+        #[cfg(test)]
+        {
+            Self::Memory
+        }
+        #[cfg(all(not(test), not(target_arch = "wasm32")))]
+        {
+            Self::File(profile_dir().join(PROFILE_FILE))
+        }
+        #[cfg(all(not(test), target_arch = "wasm32"))]
+        {
+            Self::LocalStorage
+        }
+    }
+}
+
+impl ProfileStore {
+    /// The stored profile JSON, or `None` if nothing has been stored yet.
+    pub fn read(&self) -> Result<Option<String>, String> {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::File(path) => match std::fs::read_to_string(path) {
+                Ok(json) => Ok(Some(json)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+            },
+            #[cfg(target_arch = "wasm32")]
+            Self::LocalStorage => local_storage()?
+                .get_item(PROFILE_STORAGE_KEY)
+                .map_err(|_| "failed to read localStorage".to_string()),
+            Self::Memory => Ok(None),
+        }
+    }
+
+    /// Replace the stored profile with `json`.
+    pub fn write(&self, json: &str) -> Result<(), String> {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::File(path) => write_file(path, json),
+            #[cfg(target_arch = "wasm32")]
+            Self::LocalStorage => local_storage()?
+                .set_item(PROFILE_STORAGE_KEY, json)
+                .map_err(|_| "failed to write localStorage (quota exceeded?)".to_string()),
+            Self::Memory => Ok(()),
+        }
+    }
+}
+
+/// The per-user config directory: `%APPDATA%\megacity` on Windows,
+/// `~/Library/Application Support/megacity` on macOS and
+/// `$XDG_CONFIG_HOME/megacity` (default `~/.config/megacity`) elsewhere.
+/// Falls back to the working directory when none of those is set.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn profile_dir() -> PathBuf {
+    let var = |name: &str| {
+        std::env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(windows) {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config")))
+    };
+    base.map_or_else(|| PathBuf::from("."), |dir| dir.join("megacity"))
+}
+
+/// Write through a temporary file so a crash mid-write can't leave a
+/// truncated profile behind.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_file(path: &Path, json: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or_else(|| "no window".to_string())?
+        .local_storage()
+        .ok()
+        .flatten()
+        .ok_or_else(|| "localStorage is unavailable".to_string())
+}
+
+/// The profile of a player upgrading from a build without one: the settings
+/// files those builds kept in the working directory. Missing files keep the
+/// defaults; unreadable ones are reported and skipped.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn import_legacy_files(dir: &Path) -> PlayerProfile {
+    use crate::accessibility::{AccessibilitySettings, ACCESSIBILITY_PATH};
+    use crate::keybindings::{KeyBindings, KEYBINDINGS_PATH};
+    use crate::notification_filters::{NotificationFilters, NOTIFICATION_FILTERS_PATH};
+    use crate::ui_scale::{UiScaleSettings, UI_SCALE_PATH};
+
+    fn or_default<T: Default>(result: Result<T, String>) -> T {
+        result.unwrap_or_else(|e| {
+            warn!("Not importing old settings file: {e}");
+            T::default()
+        })
+    }
+
+    PlayerProfile {
+        keybindings: or_default(KeyBindings::load(&dir.join(KEYBINDINGS_PATH))),
+        ui_scale: or_default(UiScaleSettings::load(&dir.join(UI_SCALE_PATH))),
+        accessibility: or_default(AccessibilitySettings::load(&dir.join(ACCESSIBILITY_PATH))),
+        notification_filters: or_default(NotificationFilters::load(
+            &dir.join(NOTIFICATION_FILTERS_PATH),
+        )),
+        ..PlayerProfile::default()
+    }
+}
+
+/// Read the profile from `store`. A store with nothing in it yet starts from
+/// the old settings files (and saves the result if there were any). A
+/// profile that can't be read is left as it is, and `store` is switched to
+/// [`ProfileStore::Memory`] so this session's changes don't overwrite it.
+pub fn load_profile(store: &mut ProfileStore) -> PlayerProfile {
+    match store.read() {
+        Ok(Some(json)) => match PlayerProfile::from_json(&json) {
+            Ok(profile) => return profile,
+            Err(e) => warn!("Ignoring player profile: {e}; changes won't be saved this session"),
+        },
+        Ok(None) => {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                if matches!(store, ProfileStore::File(_)) {
+                    let profile = import_legacy_files(Path::new("."));
+                    if profile != PlayerProfile::default() {
+                        info!("Imported settings files into the player profile");
+                        if let Err(e) = profile.to_json().and_then(|json| store.write(&json)) {
+                            warn!("Failed to save player profile: {e}");
+                        }
+                    }
+                    return profile;
+                }
+            }
+            return PlayerProfile::default();
+        }
+        Err(e) => warn!("Ignoring player profile: {e}; changes won't be saved this session"),
+    }
+    *store = ProfileStore::Memory;
+    PlayerProfile::default()
+}
@@ -0,0 +1,129 @@
+use super::*;
+use crate::achievements::Achievement;
+use crate::colorblind::ColorblindMode;
+use crate::keybindings::BindableAction;
+use crate::test_harness::TestCity;
+
+fn customized() -> PlayerProfile {
+    let mut profile = PlayerProfile::default();
+    let mut binding = profile.keybindings.get(BindableAction::ToggleGridSnap);
+    binding.key = KeyCode::F9;
+    profile
+        .keybindings
+        .set(BindableAction::ToggleGridSnap, binding);
+    profile.ui_scale.set_scale(1.5);
+    profile.accessibility.enabled = true;
+    profile.notification_filters.do_not_disturb = true;
+    profile.audio.set_music_volume(0.2);
+    profile.colorblind = ColorblindMode::Tritanopia;
+    profile.achievements = vec![Achievement::Population1K];
+    profile.completed_tutorials = vec!["basics".to_string()];
+    profile.disabled_mods = vec!["old_mod".to_string()];
+    profile
+}
+
+#[test]
+fn test_json_roundtrip() {
+    let profile = customized();
+    let json = profile.to_json().unwrap();
+    assert!(json.contains("\"version\": 1"), "got: {json}");
+    assert_eq!(PlayerProfile::from_json(&json).unwrap(), profile);
+}
+
+#[test]
+fn test_from_json_fills_defaults_and_clamps() {
+    let profile = PlayerProfile::from_json(r#"{ "ui_scale": { "scale": 9.0 } }"#).unwrap();
+    assert_eq!(profile.version, PROFILE_VERSION);
+    assert_eq!(profile.ui_scale.scale, crate::ui_scale::MAX_UI_SCALE);
+    assert_eq!(profile.audio, AudioSettings::default());
+    assert!(profile.achievements.is_empty());
+    assert!(PlayerProfile::from_json("not json").is_err());
+}
+
+#[test]
+fn test_from_json_rejects_newer_version() {
+    let json = format!("{{ \"version\": {} }}", PROFILE_VERSION + 1);
+    let err = PlayerProfile::from_json(&json).unwrap_err();
+    assert!(err.contains("newer"), "got: {err}");
+}
+
+#[test]
+fn test_record_achievements_in_unlock_order_once() {
+    let mut tracker = AchievementTracker::default();
+    tracker.unlocked.insert(Achievement::Population5K, 200);
+    tracker.unlocked.insert(Achievement::Population1K, 100);
+
+    let mut profile = PlayerProfile::default();
+    assert!(profile.record_achievements(&tracker));
+    assert_eq!(
+        profile.achievements,
+        vec![Achievement::Population1K, Achievement::Population5K]
+    );
+    assert!(!profile.record_achievements(&tracker));
+
+    assert!(profile.record_tutorial("basics"));
+    assert!(!profile.record_tutorial("basics"));
+    assert!(profile.has_completed_tutorial("basics"));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_file_store_roundtrip_and_legacy_import() {
+    let dir = std::env::temp_dir().join(format!("megacity_profile_test_{}", std::process::id()));
+    let store = ProfileStore::File(dir.join("config").join(PROFILE_FILE));
+    assert_eq!(store.read().unwrap(), None);
+
+    let json = customized().to_json().unwrap();
+    store.write(&json).unwrap();
+    assert_eq!(store.read().unwrap(), Some(json));
+
+    std::fs::write(
+        dir.join(crate::ui_scale::UI_SCALE_PATH),
+        r#"{ "auto": false, "scale": 1.25 }"#,
+    )
+    .unwrap();
+    let imported = store::import_legacy_files(&dir);
+    assert_eq!(imported.ui_scale.scale, 1.25);
+    assert_eq!(imported.keybindings, KeyBindings::default());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_unreadable_profile_is_not_overwritten() {
+    let mut store = ProfileStore::Memory;
+    assert_eq!(load_profile(&mut store), PlayerProfile::default());
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let dir = std::env::temp_dir().join(format!("megacity_profile_bad_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(PROFILE_FILE);
+        std::fs::write(&path, "{ \"version\": 999 }").unwrap();
+        let mut store = ProfileStore::File(path);
+        assert_eq!(load_profile(&mut store), PlayerProfile::default());
+        assert_eq!(store, ProfileStore::Memory);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
+
+#[test]
+fn test_changed_settings_and_progress_reach_the_profile() {
+    let mut city = TestCity::new();
+    {
+        let world = city.world_mut();
+        world.resource_mut::<AudioSettings>().set_master_volume(0.1);
+        world.resource_mut::<ColorblindSettings>().mode = ColorblindMode::Protanopia;
+        world
+            .resource_mut::<AchievementTracker>()
+            .unlocked
+            .insert(Achievement::Population1K, 5);
+    }
+    city.tick(1);
+
+    let profile = city.resource::<PlayerProfile>();
+    assert_eq!(profile.audio.master_volume, 0.1);
+    assert_eq!(profile.colorblind, ColorblindMode::Protanopia);
+    assert!(profile.has_achievement(Achievement::Population1K));
+    // The harness finishes the basics tutorial before the first frame.
+    assert!(profile.has_completed_tutorial(crate::tutorial::BASICS_TUTORIAL_ID));
+}
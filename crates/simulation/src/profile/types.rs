@@ -0,0 +1,111 @@
+//! The player profile and its versioned JSON form.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::accessibility::AccessibilitySettings;
+use crate::achievements::{Achievement, AchievementTracker};
+use crate::audio_settings::AudioSettings;
+use crate::colorblind::ColorblindMode;
+use crate::keybindings::KeyBindings;
+use crate::notification_filters::NotificationFilters;
+use crate::ui_scale::UiScaleSettings;
+
+/// Version written into new profiles. Bump it when a field changes meaning
+/// and teach [`PlayerProfile::from_json`] to convert the older form; adding
+/// a field needs no bump, since missing fields take their defaults.
+pub const PROFILE_VERSION: u32 = 1;
+
+/// Everything about the player that outlives any one city.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerProfile {
+    /// [`PROFILE_VERSION`] of the build that wrote the profile.
+    pub version: u32,
+    pub keybindings: KeyBindings,
+    pub ui_scale: UiScaleSettings,
+    pub accessibility: AccessibilitySettings,
+    pub notification_filters: NotificationFilters,
+    pub audio: AudioSettings,
+    pub colorblind: ColorblindMode,
+    /// Achievements unlocked in any city, in the order they were first earned.
+    pub achievements: Vec<Achievement>,
+    /// Ids of the tutorials finished or skipped in any city.
+    pub completed_tutorials: Vec<String>,
+    /// Ids of installed mods the player switched off.
+    pub disabled_mods: Vec<String>,
+}
+
+impl Default for PlayerProfile {
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            keybindings: KeyBindings::default(),
+            ui_scale: UiScaleSettings::default(),
+            accessibility: AccessibilitySettings::default(),
+            notification_filters: NotificationFilters::default(),
+            audio: AudioSettings::default(),
+            colorblind: ColorblindMode::default(),
+            achievements: Vec::new(),
+            completed_tutorials: Vec::new(),
+            disabled_mods: Vec::new(),
+        }
+    }
+}
+
+impl PlayerProfile {
+    /// Parse a profile from JSON. Missing fields keep their defaults; a
+    /// profile written by a newer build is rejected rather than guessed at.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut profile: Self =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        if profile.version > PROFILE_VERSION {
+            return Err(format!(
+                "profile version {} is newer than this build supports ({PROFILE_VERSION})",
+                profile.version
+            ));
+        }
+        profile.version = PROFILE_VERSION;
+        profile.ui_scale.clamp_scale();
+        Ok(profile)
+    }
+
+    /// Pretty-printed JSON for the profile store.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Whether `achievement` was unlocked in any city.
+    pub fn has_achievement(&self, achievement: Achievement) -> bool {
+        self.achievements.contains(&achievement)
+    }
+
+    /// Whether the tutorial `id` was finished or skipped in any city.
+    pub fn has_completed_tutorial(&self, id: &str) -> bool {
+        self.completed_tutorials.iter().any(|t| t == id)
+    }
+
+    /// Add the city's achievements the profile doesn't have yet. Returns
+    /// whether any were added.
+    pub fn record_achievements(&mut self, tracker: &AchievementTracker) -> bool {
+        let mut new: Vec<(u64, Achievement)> = tracker
+            .unlocked
+            .iter()
+            .filter(|(achievement, _)| !self.has_achievement(**achievement))
+            .map(|(&achievement, &tick)| (tick, achievement))
+            .collect();
+        new.sort_by_key(|&(tick, achievement)| (tick, achievement as u32));
+        self.achievements
+            .extend(new.iter().map(|&(_, achievement)| achievement));
+        !new.is_empty()
+    }
+
+    /// Mark the tutorial `id` as completed. Returns whether it wasn't already.
+    pub fn record_tutorial(&mut self, id: &str) -> bool {
+        if self.has_completed_tutorial(id) {
+            return false;
+        }
+        self.completed_tutorials.push(id.to_string());
+        true
+    }
+}
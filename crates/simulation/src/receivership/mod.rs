@@ -0,0 +1,30 @@
+//! Bankruptcy, receivership and the recovery path.
+//!
+//! Missed obligations no longer leave the treasury negative forever. The
+//! city falls into receivership when either:
+//!
+//! - the treasury stays negative for `MISSED_OBLIGATION_LIMIT` days while
+//!   loan payments or running costs are due, or
+//! - `BankruptcyEvent` fires (deep debt with every loan slot used).
+//!
+//! While in receivership the receiver consolidates all loans into one long,
+//! low-interest loan, and then each day:
+//!
+//! - raises every zone tax to at least `IMPOSED_TAX_RATE`,
+//! - cuts every service budget to at most `AUSTERITY_BUDGET_CAP`,
+//! - sells the most valuable non-essential building while still in the red.
+//!
+//! The player cannot take new loans or change taxes and budgets beyond
+//! those limits. After `RECEIVERSHIP_SOLVENT_DAYS` solvent days, control
+//! returns with the player's old service budgets and the recovery phase
+//! begins. The city is fully discharged once it balances its budget,
+//! rebuilds a reserve and restores its credit rating. Missing obligations
+//! again during recovery sends the city straight back into receivership.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_receivership, ReceivershipPlugin};
+pub use types::*;
@@ -0,0 +1,256 @@
+//! Daily insolvency tracking, austerity enforcement and recovery.
+
+use bevy::prelude::*;
+
+use crate::budget::ExtendedBudget;
+use crate::economy::CityBudget;
+use crate::events::{CityEvent, CityEventType, EventJournal};
+use crate::grid::{WorldGrid, ZoneType};
+use crate::happiness::ServiceCoverageGrid;
+use crate::loans::{BankruptcyEvent, LoanBook};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::ServiceBuilding;
+use crate::time_of_day::GameClock;
+
+use super::types::*;
+
+/// Apply the receiver's tax floor and service cuts, touching the budget
+/// resources only when something actually changes.
+fn enforce_austerity(budget: &mut CityBudget, ext_budget: &mut ExtendedBudget) {
+    let mut taxes = ext_budget.zone_taxes.clone();
+    if impose_taxes(&mut taxes) {
+        budget.tax_rate =
+            (taxes.residential + taxes.commercial + taxes.industrial + taxes.office) / 4.0;
+        ext_budget.zone_taxes = taxes;
+    }
+    let mut budgets = ext_budget.service_budgets.clone();
+    if impose_service_cuts(&mut budgets) {
+        ext_budget.service_budgets = budgets;
+    }
+}
+
+/// Sell the most valuable non-essential service building. Returns the sale
+/// price and location, or `None` if nothing is left to sell.
+fn sell_asset(
+    commands: &mut Commands,
+    grid: &mut WorldGrid,
+    services: &Query<(Entity, &ServiceBuilding)>,
+) -> Option<(ServiceBuilding, f64)> {
+    let (entity, service) = services
+        .iter()
+        .filter(|(_, s)| is_saleable(s.service_type))
+        .max_by(|(_, a), (_, b)| {
+            ServiceBuilding::cost(a.service_type)
+                .total_cmp(&ServiceBuilding::cost(b.service_type))
+                .then((b.grid_y, b.grid_x).cmp(&(a.grid_y, a.grid_x)))
+        })?;
+    let (fw, fh) = ServiceBuilding::footprint(service.service_type);
+    for y in service.grid_y..service.grid_y + fh {
+        for x in service.grid_x..service.grid_x + fw {
+            if grid.in_bounds(x, y) {
+                let cell = grid.get_mut(x, y);
+                cell.building_id = None;
+                cell.zone = ZoneType::None;
+            }
+        }
+    }
+    commands.entity(entity).despawn();
+    Some((
+        service.clone(),
+        ServiceBuilding::cost(service.service_type) * ASSET_SALE_RATE,
+    ))
+}
+
+/// Appoint a receiver: restructure debt and impose austerity.
+#[allow(clippy::too_many_arguments)]
+fn enter_receivership(
+    state: &mut Receivership,
+    clock: &GameClock,
+    budget: &mut CityBudget,
+    ext_budget: &mut ExtendedBudget,
+    loan_book: &mut LoanBook,
+    journal: &mut EventJournal,
+    notifications: &mut EventWriter<NotificationEvent>,
+) {
+    state.enter(clock.day, &ext_budget.service_budgets);
+    let debt = restructure_loans(loan_book);
+    enforce_austerity(budget, ext_budget);
+
+    let description = format!(
+        "The city has defaulted and been placed in receivership. Debts of ${debt:.0} were \
+         restructured; taxes are raised and services cut until the books balance."
+    );
+    journal.push(CityEvent {
+        event_type: CityEventType::BudgetCrisis,
+        day: clock.day,
+        hour: clock.hour,
+        description: description.clone(),
+    });
+    notifications.send(NotificationEvent {
+        text: description,
+        priority: NotificationPriority::Emergency,
+        category: NotificationCategory::Finance,
+        location: None,
+    });
+}
+
+/// Once per game day: count missed obligations, run receivership, and track
+/// the recovery milestones. A `BankruptcyEvent` triggers receivership at once.
+#[allow(clippy::too_many_arguments)]
+pub fn update_receivership(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    mut state: ResMut<Receivership>,
+    mut budget: ResMut<CityBudget>,
+    mut ext_budget: ResMut<ExtendedBudget>,
+    mut loan_book: ResMut<LoanBook>,
+    mut bankruptcy_events: EventReader<BankruptcyEvent>,
+    services: Query<(Entity, &ServiceBuilding)>,
+    mut grid: ResMut<WorldGrid>,
+    mut coverage: ResMut<ServiceCoverageGrid>,
+    mut journal: ResMut<EventJournal>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let bankrupt = bankruptcy_events.read().count() > 0;
+    if bankrupt && !state.controls_finances() {
+        enter_receivership(
+            &mut state,
+            &clock,
+            &mut budget,
+            &mut ext_budget,
+            &mut loan_book,
+            &mut journal,
+            &mut notifications,
+        );
+    }
+
+    if clock.day <= state.last_day {
+        return;
+    }
+    state.last_day = clock.day;
+
+    let in_red = budget.treasury < 0.0;
+    let bills_due = !loan_book.active_loans.is_empty() || budget.monthly_expenses > 0.0;
+
+    match state.phase {
+        FiscalPhase::Solvent | FiscalPhase::Recovery => {
+            if in_red && bills_due {
+                state.missed_days += 1;
+            } else {
+                state.missed_days = 0;
+            }
+            if state.missed_days >= MISSED_OBLIGATION_LIMIT {
+                enter_receivership(
+                    &mut state,
+                    &clock,
+                    &mut budget,
+                    &mut ext_budget,
+                    &mut loan_book,
+                    &mut journal,
+                    &mut notifications,
+                );
+                return;
+            }
+            if state.phase != FiscalPhase::Recovery {
+                return;
+            }
+
+            for milestone in RecoveryMilestone::ALL {
+                if !state.milestone_met(milestone)
+                    && milestone.is_met(
+                        budget.monthly_income,
+                        budget.monthly_expenses,
+                        budget.treasury,
+                        loan_book.credit_rating,
+                    )
+                {
+                    state.milestones[milestone.index()] = true;
+                    notifications.send(NotificationEvent {
+                        text: format!("Recovery milestone reached: {}.", milestone.describe()),
+                        priority: NotificationPriority::Positive,
+                        category: NotificationCategory::Finance,
+                        location: None,
+                    });
+                }
+            }
+            if state.milestones.iter().all(|&met| met) {
+                state.phase = FiscalPhase::Solvent;
+                loan_book.credit_rating =
+                    (loan_book.credit_rating + COMEBACK_CREDIT_BONUS).min(2.0);
+                let description = format!(
+                    "The city completed its recovery {} days after entering receivership.",
+                    clock.day.saturating_sub(state.entered_day)
+                );
+                journal.push(CityEvent {
+                    event_type: CityEventType::MilestoneReached("Fiscal comeback".to_string()),
+                    day: clock.day,
+                    hour: clock.hour,
+                    description: description.clone(),
+                });
+                notifications.send(NotificationEvent {
+                    text: description,
+                    priority: NotificationPriority::Positive,
+                    category: NotificationCategory::Finance,
+                    location: None,
+                });
+            }
+        }
+        FiscalPhase::Receivership => {
+            enforce_austerity(&mut budget, &mut ext_budget);
+
+            if !in_red {
+                state.solvent_days += 1;
+                if state.solvent_days >= RECEIVERSHIP_SOLVENT_DAYS {
+                    let mut budgets = ext_budget.service_budgets.clone();
+                    state.begin_recovery(&mut budgets);
+                    ext_budget.service_budgets = budgets;
+                    notifications.send(NotificationEvent {
+                        text: "The receiver has handed back control. Service budgets are \
+                               restored; complete the recovery plan to clear the city's record."
+                            .to_string(),
+                        priority: NotificationPriority::Positive,
+                        category: NotificationCategory::Finance,
+                        location: None,
+                    });
+                }
+                return;
+            }
+
+            state.solvent_days = 0;
+            if let Some((sold, price)) = sell_asset(&mut commands, &mut grid, &services) {
+                budget.treasury += price;
+                state.assets_sold += 1;
+                state.sale_proceeds += price;
+                coverage.dirty = true;
+                notifications.send(NotificationEvent {
+                    text: format!(
+                        "The receiver sold the {} for ${:.0}.",
+                        sold.service_type.name(),
+                        price
+                    ),
+                    priority: NotificationPriority::Warning,
+                    category: NotificationCategory::Finance,
+                    location: Some(WorldGrid::grid_to_world(sold.grid_x, sold.grid_y)),
+                });
+            }
+        }
+    }
+}
+
+pub struct ReceivershipPlugin;
+
+impl Plugin for ReceivershipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Receivership>().add_systems(
+            FixedUpdate,
+            update_receivership
+                .after(crate::loans::update_credit_rating)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<Receivership>();
+    }
+}
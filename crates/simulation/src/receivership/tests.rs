@@ -0,0 +1,97 @@
+use super::*;
+use crate::budget::{ServiceBudgets, ZoneTaxRates};
+use crate::loans::{LoanBook, LoanTier};
+use crate::services::ServiceType;
+use crate::Saveable;
+
+#[test]
+fn austerity_raises_taxes_and_cuts_services() {
+    let mut taxes = ZoneTaxRates {
+        residential: 0.05,
+        commercial: 0.20,
+        ..Default::default()
+    };
+    assert!(impose_taxes(&mut taxes));
+    assert_eq!(taxes.residential, IMPOSED_TAX_RATE);
+    assert_eq!(taxes.commercial, 0.20);
+    assert!(!impose_taxes(&mut taxes));
+
+    let mut budgets = ServiceBudgets {
+        fire: 1.2,
+        police: 0.5,
+        ..Default::default()
+    };
+    assert!(impose_service_cuts(&mut budgets));
+    assert_eq!(budgets.fire, AUSTERITY_BUDGET_CAP);
+    assert_eq!(budgets.police, 0.5);
+    assert!(!impose_service_cuts(&mut budgets));
+}
+
+#[test]
+fn restructuring_consolidates_debt() {
+    let mut book = LoanBook::default();
+    let mut treasury = 0.0;
+    book.take_loan(LoanTier::Small, &mut treasury);
+    book.take_loan(LoanTier::Large, &mut treasury);
+    let payments_before = book.total_monthly_payments();
+
+    let debt = restructure_loans(&mut book);
+    assert!((debt - 210_000.0).abs() < 0.01);
+    assert_eq!(book.active_loans.len(), 1);
+    assert!(book.total_monthly_payments() < payments_before);
+
+    let mut empty = LoanBook::default();
+    assert_eq!(restructure_loans(&mut empty), 0.0);
+    assert!(empty.active_loans.is_empty());
+}
+
+#[test]
+fn essential_services_are_never_sold() {
+    assert!(is_saleable(ServiceType::Stadium));
+    assert!(is_saleable(ServiceType::SmallPark));
+    assert!(!is_saleable(ServiceType::FireStation));
+    assert!(!is_saleable(ServiceType::Hospital));
+    assert!(!is_saleable(ServiceType::ElementarySchool));
+}
+
+#[test]
+fn recovery_restores_saved_budgets() {
+    let mut state = Receivership::default();
+    let mut budgets = ServiceBudgets {
+        education: 1.3,
+        ..Default::default()
+    };
+    state.enter(12, &budgets);
+    assert!(state.controls_finances());
+    assert_eq!(state.times_entered, 1);
+
+    impose_service_cuts(&mut budgets);
+    state.begin_recovery(&mut budgets);
+    assert_eq!(state.phase, FiscalPhase::Recovery);
+    assert_eq!(budgets.education, 1.3);
+}
+
+#[test]
+fn milestones_check_finances() {
+    let m = RecoveryMilestone::BalancedBudget;
+    assert!(m.is_met(100.0, 90.0, 0.0, 0.1));
+    assert!(!m.is_met(80.0, 90.0, 0.0, 0.1));
+    assert!(RecoveryMilestone::ReserveRebuilt.is_met(0.0, 0.0, RECOVERY_RESERVE, 0.1));
+    assert!(!RecoveryMilestone::CreditRestored.is_met(0.0, 0.0, 0.0, 0.5));
+}
+
+#[test]
+fn receivership_roundtrips_through_save() {
+    assert!(Receivership::default().save_to_bytes().is_none());
+
+    let mut state = Receivership::default();
+    state.enter(40, &ServiceBudgets::default());
+    state.assets_sold = 2;
+    state.milestones[1] = true;
+    let bytes = state.save_to_bytes().unwrap();
+    let restored = Receivership::load_from_bytes(&bytes);
+    assert_eq!(restored.phase, FiscalPhase::Receivership);
+    assert_eq!(restored.entered_day, 40);
+    assert_eq!(restored.assets_sold, 2);
+    assert_eq!(restored.milestones, [false, true, false]);
+}
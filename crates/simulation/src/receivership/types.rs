@@ -0,0 +1,288 @@
+//! Fiscal phases, austerity terms and recovery milestones.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::budget::{ServiceBudgets, ZoneTaxRates};
+use crate::loans::{Loan, LoanBook};
+use crate::services::ServiceType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Consecutive days in the red (with loans or expenses to pay) before the
+/// state appoints a receiver.
+pub const MISSED_OBLIGATION_LIMIT: u32 = 30;
+
+/// Consecutive solvent days needed to leave receivership.
+pub const RECEIVERSHIP_SOLVENT_DAYS: u32 = 30;
+
+/// Highest service budget level the receiver allows.
+pub const AUSTERITY_BUDGET_CAP: f32 = 0.75;
+
+/// Lowest zone tax rate the receiver allows.
+pub const IMPOSED_TAX_RATE: f32 = 0.15;
+
+/// Fraction of construction cost recovered when the receiver sells a building.
+pub const ASSET_SALE_RATE: f64 = 0.6;
+
+/// Interest rate of the consolidated loan debts are restructured into.
+pub const RESTRUCTURED_RATE: f64 = 0.04;
+
+/// Term of the consolidated loan, in months.
+pub const RESTRUCTURED_TERM_MONTHS: u32 = 120;
+
+/// Treasury the city must rebuild before the recovery is complete.
+pub const RECOVERY_RESERVE: f64 = 25_000.0;
+
+/// Credit rating the city must regain before the recovery is complete.
+pub const RECOVERY_CREDIT_RATING: f64 = 0.8;
+
+/// Credit rating bonus granted when the recovery completes.
+pub const COMEBACK_CREDIT_BONUS: f64 = 0.2;
+
+// ---------------------------------------------------------------------------
+// Phases and milestones
+// ---------------------------------------------------------------------------
+
+/// Where the city stands on the path from insolvency back to health.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Encode, Decode, Serialize, Deserialize)]
+pub enum FiscalPhase {
+    /// Normal operation; the player controls taxes, budgets and loans.
+    #[default]
+    Solvent,
+    /// A receiver runs the city's finances: taxes and budgets are imposed,
+    /// loans are frozen and non-essential buildings are sold.
+    Receivership,
+    /// Control is back with the player, who must hit every recovery
+    /// milestone before the city is fully discharged.
+    Recovery,
+}
+
+impl FiscalPhase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Solvent => "Solvent",
+            Self::Receivership => "Receivership",
+            Self::Recovery => "Recovery",
+        }
+    }
+}
+
+/// Goals the city must meet during the recovery phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryMilestone {
+    /// Monthly income covers monthly expenses.
+    BalancedBudget,
+    /// Treasury holds at least `RECOVERY_RESERVE`.
+    ReserveRebuilt,
+    /// Credit rating back to `RECOVERY_CREDIT_RATING`.
+    CreditRestored,
+}
+
+impl RecoveryMilestone {
+    pub const ALL: [RecoveryMilestone; 3] = [
+        Self::BalancedBudget,
+        Self::ReserveRebuilt,
+        Self::CreditRestored,
+    ];
+
+    pub fn index(self) -> usize {
+        match self {
+            Self::BalancedBudget => 0,
+            Self::ReserveRebuilt => 1,
+            Self::CreditRestored => 2,
+        }
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::BalancedBudget => "Balance the monthly budget",
+            Self::ReserveRebuilt => "Rebuild a $25,000 reserve",
+            Self::CreditRestored => "Restore the credit rating to 0.80",
+        }
+    }
+
+    /// Whether the milestone is met by the current finances.
+    pub fn is_met(self, income: f64, expenses: f64, treasury: f64, credit_rating: f64) -> bool {
+        match self {
+            Self::BalancedBudget => income >= expenses,
+            Self::ReserveRebuilt => treasury >= RECOVERY_RESERVE,
+            Self::CreditRestored => credit_rating >= RECOVERY_CREDIT_RATING,
+        }
+    }
+}
+
+/// Whether the receiver may sell a building of this type. Parks, plazas and
+/// landmarks go first; safety, health, education, sanitation and transport
+/// are never sold.
+pub fn is_saleable(service_type: ServiceType) -> bool {
+    matches!(
+        service_type,
+        ServiceType::SmallPark
+            | ServiceType::LargePark
+            | ServiceType::Playground
+            | ServiceType::Plaza
+            | ServiceType::SportsField
+            | ServiceType::Stadium
+            | ServiceType::Museum
+            | ServiceType::Cathedral
+            | ServiceType::TVStation
+            | ServiceType::DataCenter
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Austerity terms
+// ---------------------------------------------------------------------------
+
+fn tax_array(taxes: &ZoneTaxRates) -> [f32; 4] {
+    [
+        taxes.residential,
+        taxes.commercial,
+        taxes.industrial,
+        taxes.office,
+    ]
+}
+
+fn budget_array(budgets: &ServiceBudgets) -> [f32; 6] {
+    [
+        budgets.fire,
+        budgets.police,
+        budgets.healthcare,
+        budgets.education,
+        budgets.sanitation,
+        budgets.transport,
+    ]
+}
+
+/// Raise every zone tax to at least `IMPOSED_TAX_RATE`. Returns true if any
+/// rate changed.
+pub fn impose_taxes(taxes: &mut ZoneTaxRates) -> bool {
+    let before = tax_array(taxes);
+    for rate in [
+        &mut taxes.residential,
+        &mut taxes.commercial,
+        &mut taxes.industrial,
+        &mut taxes.office,
+    ] {
+        *rate = rate.max(IMPOSED_TAX_RATE);
+    }
+    before != tax_array(taxes)
+}
+
+/// Cut every service budget to at most `AUSTERITY_BUDGET_CAP`. Returns true
+/// if any level changed.
+pub fn impose_service_cuts(budgets: &mut ServiceBudgets) -> bool {
+    let before = budget_array(budgets);
+    for level in [
+        &mut budgets.fire,
+        &mut budgets.police,
+        &mut budgets.healthcare,
+        &mut budgets.education,
+        &mut budgets.sanitation,
+        &mut budgets.transport,
+    ] {
+        *level = level.min(AUSTERITY_BUDGET_CAP);
+    }
+    before != budget_array(budgets)
+}
+
+/// Consolidate all outstanding loans into one long, cheap loan. Returns the
+/// consolidated balance.
+pub fn restructure_loans(book: &mut LoanBook) -> f64 {
+    let balance = book.total_debt();
+    book.active_loans.clear();
+    if balance > 0.0 {
+        book.active_loans.push(Loan::new(
+            "Restructured Debt".to_string(),
+            balance,
+            RESTRUCTURED_RATE,
+            RESTRUCTURED_TERM_MONTHS,
+        ));
+    }
+    balance
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Insolvency state: tracks missed obligations, the receiver's terms and the
+/// recovery milestones.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct Receivership {
+    pub phase: FiscalPhase,
+    /// Consecutive days the treasury has been negative with bills due.
+    pub missed_days: u32,
+    /// Consecutive solvent days while in receivership.
+    pub solvent_days: u32,
+    /// Last game day processed.
+    pub last_day: u32,
+    /// Day the current receivership began.
+    pub entered_day: u32,
+    /// How many times the city has been placed in receivership.
+    pub times_entered: u32,
+    /// Buildings sold by the receiver and what they raised, this episode.
+    pub assets_sold: u32,
+    pub sale_proceeds: f64,
+    /// Service budgets the player had before the cuts, restored on exit.
+    pub saved_service_budgets: [f32; 6],
+    /// Recovery milestones reached, indexed by `RecoveryMilestone::index`.
+    pub milestones: [bool; 3],
+}
+
+impl Receivership {
+    /// Whether the receiver currently controls taxes, budgets and loans.
+    pub fn controls_finances(&self) -> bool {
+        self.phase == FiscalPhase::Receivership
+    }
+
+    pub fn milestone_met(&self, milestone: RecoveryMilestone) -> bool {
+        self.milestones[milestone.index()]
+    }
+
+    /// Start receivership, remembering the player's service budgets.
+    pub fn enter(&mut self, day: u32, budgets: &ServiceBudgets) {
+        self.phase = FiscalPhase::Receivership;
+        self.entered_day = day;
+        self.times_entered += 1;
+        self.solvent_days = 0;
+        self.missed_days = 0;
+        self.assets_sold = 0;
+        self.sale_proceeds = 0.0;
+        self.saved_service_budgets = budget_array(budgets);
+        self.milestones = [false; 3];
+    }
+
+    /// Hand control back to the player and start the recovery phase.
+    pub fn begin_recovery(&mut self, budgets: &mut ServiceBudgets) {
+        self.phase = FiscalPhase::Recovery;
+        let [fire, police, healthcare, education, sanitation, transport] =
+            self.saved_service_budgets;
+        *budgets = ServiceBudgets {
+            fire,
+            police,
+            healthcare,
+            education,
+            sanitation,
+            transport,
+        };
+    }
+}
+
+impl Saveable for Receivership {
+    const SAVE_KEY: &'static str = "receivership";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.phase == FiscalPhase::Solvent && self.times_entered == 0 && self.missed_days == 0 {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
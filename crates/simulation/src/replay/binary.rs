@@ -0,0 +1,280 @@
+//! Compact binary replay container.
+//!
+//! Layout (all integers little-endian):
+//!
+//! | bytes | field                                          |
+//! |-------|------------------------------------------------|
+//! | 4     | magic `MCRP`                                   |
+//! | 2     | container version (`BINARY_CONTAINER_VERSION`) |
+//! | 8     | compressed payload length                      |
+//! | n     | zlib-compressed bitcode payload                |
+//!
+//! The payload holds the `ReplayFile` (header, entries, footer and state
+//! checksums) plus any full state checkpoints taken while recording, so a
+//! viewer can seek anywhere without re-simulating from the start. JSON
+//! replays carry the checksums but never the checkpoints.
+
+use std::io::{Read, Write};
+
+use bitcode::{Decode, Encode};
+
+use super::checkpoint::ReplayCheckpoint;
+use super::format::ReplayFile;
+
+/// Magic bytes that open every binary replay.
+pub const REPLAY_MAGIC: [u8; 4] = *b"MCRP";
+
+/// Container version, bumped when the layout above changes.
+pub const BINARY_CONTAINER_VERSION: u16 = 1;
+
+const HEADER_LEN: usize = 4 + 2 + 8;
+
+/// A `ReplayCheckpoint` as stored in the container.
+#[derive(Encode, Decode)]
+struct StoredCheckpoint {
+    tick: u64,
+    slow_tick: u32,
+    cursor: u64,
+    state: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+struct BinaryPayload {
+    replay: ReplayFile,
+    checkpoints: Vec<StoredCheckpoint>,
+}
+
+/// A replay read from disk, with the checkpoints that came with it.
+#[derive(Debug, Clone)]
+pub struct LoadedReplay {
+    pub replay: ReplayFile,
+    pub checkpoints: Vec<ReplayCheckpoint>,
+}
+
+/// On-disk replay encodings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayEncoding {
+    Json,
+    Binary,
+}
+
+impl ReplayEncoding {
+    /// The encoding a path asks for: JSON for `.json`, binary otherwise.
+    pub fn for_path(path: &str) -> Self {
+        if path.to_ascii_lowercase().ends_with(".json") {
+            Self::Json
+        } else {
+            Self::Binary
+        }
+    }
+}
+
+/// Whether `bytes` start like a binary replay.
+pub fn is_binary_replay(bytes: &[u8]) -> bool {
+    bytes.starts_with(&REPLAY_MAGIC)
+}
+
+/// Encode `replay` and `checkpoints` into a binary container.
+pub fn encode_binary_replay(replay: &ReplayFile, checkpoints: &[ReplayCheckpoint]) -> Vec<u8> {
+    let payload = BinaryPayload {
+        replay: replay.clone(),
+        checkpoints: checkpoints
+            .iter()
+            .map(|c| StoredCheckpoint {
+                tick: c.tick,
+                slow_tick: c.slow_tick,
+                cursor: c.cursor as u64,
+                state: c.state.clone(),
+            })
+            .collect(),
+    };
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&bitcode::encode(&payload))
+        .expect("writing to a Vec cannot fail");
+    let compressed = encoder.finish().expect("writing to a Vec cannot fail");
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + compressed.len());
+    bytes.extend_from_slice(&REPLAY_MAGIC);
+    bytes.extend_from_slice(&BINARY_CONTAINER_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(compressed.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&compressed);
+    bytes
+}
+
+/// Decode a binary container, validating the replay and its checkpoints.
+pub fn decode_binary_replay(bytes: &[u8]) -> Result<LoadedReplay, String> {
+    if !is_binary_replay(bytes) {
+        return Err("not a binary replay (bad magic)".to_string());
+    }
+    if bytes.len() < HEADER_LEN {
+        return Err("binary replay is truncated".to_string());
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version > BINARY_CONTAINER_VERSION {
+        return Err(format!(
+            "binary replay version {version} is newer than supported ({BINARY_CONTAINER_VERSION})"
+        ));
+    }
+    let mut len = [0u8; 8];
+    len.copy_from_slice(&bytes[6..HEADER_LEN]);
+    let compressed = &bytes[HEADER_LEN..];
+    if u64::from_le_bytes(len) != compressed.len() as u64 {
+        return Err("binary replay is truncated".to_string());
+    }
+
+    let mut raw = Vec::new();
+    flate2::read::ZlibDecoder::new(compressed)
+        .read_to_end(&mut raw)
+        .map_err(|e| format!("binary replay is corrupted: {e}"))?;
+    let payload: BinaryPayload =
+        bitcode::decode(&raw).map_err(|e| format!("bitcode decode error: {e}"))?;
+    payload.replay.validate()?;
+
+    let entry_count = payload.replay.entries.len() as u64;
+    let mut checkpoints = Vec::with_capacity(payload.checkpoints.len());
+    for stored in payload.checkpoints {
+        if stored.cursor > entry_count {
+            return Err(format!(
+                "checkpoint at tick {} points past the last entry",
+                stored.tick
+            ));
+        }
+        checkpoints.push(ReplayCheckpoint {
+            tick: stored.tick,
+            slow_tick: stored.slow_tick,
+            cursor: stored.cursor as usize,
+            state: stored.state,
+        });
+    }
+    if checkpoints.windows(2).any(|w| w[1].tick <= w[0].tick) {
+        return Err("checkpoints are not sorted by tick".to_string());
+    }
+    Ok(LoadedReplay {
+        replay: payload.replay,
+        checkpoints,
+    })
+}
+
+/// Read a replay in either encoding, telling them apart by the magic bytes.
+pub fn read_replay(bytes: &[u8]) -> Result<LoadedReplay, String> {
+    if is_binary_replay(bytes) {
+        return decode_binary_replay(bytes);
+    }
+    let json = std::str::from_utf8(bytes).map_err(|e| format!("replay is not UTF-8 JSON: {e}"))?;
+    Ok(LoadedReplay {
+        replay: ReplayFile::from_json(json)?,
+        checkpoints: Vec::new(),
+    })
+}
+
+/// Encode `loaded` as `encoding`. Checkpoints are dropped when writing JSON.
+pub fn write_replay(loaded: &LoadedReplay, encoding: ReplayEncoding) -> Vec<u8> {
+    match encoding {
+        ReplayEncoding::Json => loaded.replay.to_json().into_bytes(),
+        ReplayEncoding::Binary => encode_binary_replay(&loaded.replay, &loaded.checkpoints),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_actions::GameAction;
+    use crate::replay::format::{
+        ReplayChecksum, ReplayEntry, ReplayFooter, ReplayHeader, CURRENT_FORMAT_VERSION,
+    };
+
+    fn sample() -> LoadedReplay {
+        let entries: Vec<ReplayEntry> = (0..50)
+            .map(|i| ReplayEntry {
+                tick: i * 3,
+                action: GameAction::SetSpeed { speed: 1 },
+            })
+            .collect();
+        LoadedReplay {
+            replay: ReplayFile {
+                header: ReplayHeader {
+                    format_version: CURRENT_FORMAT_VERSION,
+                    seed: 9,
+                    city_name: "Binary".to_string(),
+                    start_tick: 0,
+                },
+                footer: ReplayFooter {
+                    end_tick: 200,
+                    final_state_hash: 7,
+                    entry_count: entries.len() as u64,
+                },
+                entries,
+                checksums: vec![ReplayChecksum {
+                    tick: 100,
+                    entry_index: 34,
+                    state_hash: 0x1234,
+                }],
+            },
+            checkpoints: vec![ReplayCheckpoint {
+                tick: 60,
+                slow_tick: 60,
+                cursor: 20,
+                state: vec![1, 2, 3, 4],
+            }],
+        }
+    }
+
+    #[test]
+    fn binary_roundtrip_keeps_checkpoints() {
+        let original = sample();
+        let bytes = write_replay(&original, ReplayEncoding::Binary);
+        assert!(is_binary_replay(&bytes));
+
+        let decoded = read_replay(&bytes).unwrap();
+        assert_eq!(decoded.replay, original.replay);
+        assert_eq!(decoded.checkpoints.len(), 1);
+        assert_eq!(decoded.checkpoints[0].cursor, 20);
+        assert_eq!(decoded.checkpoints[0].state, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn binary_is_smaller_than_json() {
+        let original = sample();
+        let json = write_replay(&original, ReplayEncoding::Json);
+        let binary = write_replay(&original, ReplayEncoding::Binary);
+        assert!(
+            binary.len() < json.len() / 4,
+            "{} vs {}",
+            binary.len(),
+            json.len()
+        );
+    }
+
+    #[test]
+    fn json_conversion_drops_checkpoints_only() {
+        let original = sample();
+        let json = write_replay(&original, ReplayEncoding::Json);
+        let decoded = read_replay(&json).unwrap();
+        assert_eq!(decoded.replay, original.replay);
+        assert!(decoded.checkpoints.is_empty());
+    }
+
+    #[test]
+    fn corrupted_and_truncated_containers_are_rejected() {
+        let bytes = write_replay(&sample(), ReplayEncoding::Binary);
+        assert!(decode_binary_replay(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode_binary_replay(&bytes[..5]).is_err());
+
+        let mut corrupted = bytes.clone();
+        let last = corrupted.len() - 3;
+        corrupted[last] ^= 0xff;
+        assert!(decode_binary_replay(&corrupted).is_err());
+
+        let mut future = bytes;
+        future[4] = 0xff;
+        let err = decode_binary_replay(&future).unwrap_err();
+        assert!(err.contains("newer than supported"));
+    }
+
+    #[test]
+    fn encoding_follows_extension() {
+        assert_eq!(ReplayEncoding::for_path("a/b.JSON"), ReplayEncoding::Json);
+        assert_eq!(ReplayEncoding::for_path("a/b.mcr"), ReplayEncoding::Binary);
+    }
+}
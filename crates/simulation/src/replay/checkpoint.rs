@@ -0,0 +1,276 @@
+//! Replay checkpoints: full state snapshots taken while a replay plays.
+//!
+//! Each checkpoint is captured at the start of a tick, before any system of
+//! that tick has run, together with the player's cursor. Restoring one puts
+//! the world back exactly where playback was, so scrubbing backwards only has
+//! to re-simulate from the nearest earlier checkpoint.
+//!
+//! The state itself is encoded by a `CheckpointCodec` installed by the save
+//! crate (which owns the full-world save pipeline). Without a codec no
+//! checkpoints are taken and the replay can only be scrubbed forwards.
+
+use bevy::prelude::*;
+
+use crate::{SlowTickTimer, TickCounter};
+
+use super::player::ReplayPlayer;
+use super::recorder::ReplayRecorder;
+
+/// Ticks between checkpoints when a replay starts (one minute at 1x speed).
+pub const DEFAULT_CHECKPOINT_INTERVAL: u64 = 600;
+
+/// Most checkpoints kept at once. When exceeded, every other checkpoint is
+/// dropped and the interval doubles, so long replays stay bounded in memory.
+pub const MAX_CHECKPOINTS: usize = 32;
+
+/// Captures and restores the full simulation state as bytes.
+#[derive(Resource, Clone, Copy)]
+pub struct CheckpointCodec {
+    pub capture: fn(&mut World) -> Result<Vec<u8>, String>,
+    pub restore: fn(&mut World, &[u8]) -> Result<(), String>,
+}
+
+/// One full state snapshot taken during playback.
+#[derive(Debug, Clone)]
+pub struct ReplayCheckpoint {
+    /// `TickCounter` value when the snapshot was taken.
+    pub tick: u64,
+    /// `SlowTickTimer` counter, which the save format does not store.
+    pub slow_tick: u32,
+    /// Number of replay entries already fed to the action queue.
+    pub cursor: usize,
+    /// State encoded by the `CheckpointCodec`.
+    pub state: Vec<u8>,
+}
+
+/// Checkpoints of the replay currently loaded in the `ReplayPlayer`, sorted
+/// by tick.
+#[derive(Resource, Debug)]
+pub struct ReplayCheckpoints {
+    /// `ReplayPlayer::generation` of the replay these checkpoints belong to.
+    generation: u64,
+    interval: u64,
+    checkpoints: Vec<ReplayCheckpoint>,
+}
+
+impl Default for ReplayCheckpoints {
+    fn default() -> Self {
+        Self {
+            generation: 0,
+            interval: DEFAULT_CHECKPOINT_INTERVAL,
+            checkpoints: Vec::new(),
+        }
+    }
+}
+
+impl ReplayCheckpoints {
+    /// Start with a checkpoint every `interval` ticks instead of the default.
+    pub fn with_interval(interval: u64) -> Self {
+        Self {
+            interval: interval.max(1),
+            ..default()
+        }
+    }
+
+    /// Ticks between checkpoints.
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// All checkpoints, oldest first.
+    pub fn checkpoints(&self) -> &[ReplayCheckpoint] {
+        &self.checkpoints
+    }
+
+    /// The latest checkpoint at or before `tick`.
+    pub fn nearest_before(&self, tick: u64) -> Option<&ReplayCheckpoint> {
+        self.checkpoints.iter().rev().find(|c| c.tick <= tick)
+    }
+
+    /// Whether a checkpoint should be taken at `tick`: the first one is
+    /// taken as soon as playback starts, later ones on interval boundaries.
+    pub fn is_due(&self, tick: u64) -> bool {
+        match self.checkpoints.first() {
+            None => true,
+            Some(_) => {
+                tick.is_multiple_of(self.interval)
+                    && !self.checkpoints.iter().any(|c| c.tick == tick)
+            }
+        }
+    }
+
+    /// Insert a checkpoint in tick order, thinning the list if it is full.
+    /// The first checkpoint is always kept so the start stays reachable.
+    pub fn insert(&mut self, checkpoint: ReplayCheckpoint) {
+        let index = self
+            .checkpoints
+            .partition_point(|c| c.tick < checkpoint.tick);
+        if self.checkpoints.get(index).map(|c| c.tick) == Some(checkpoint.tick) {
+            self.checkpoints[index] = checkpoint;
+        } else {
+            self.checkpoints.insert(index, checkpoint);
+        }
+        while self.checkpoints.len() > MAX_CHECKPOINTS {
+            self.interval *= 2;
+            let interval = self.interval;
+            let mut first = true;
+            self.checkpoints.retain(|c| {
+                let keep = first || c.tick.is_multiple_of(interval);
+                first = false;
+                keep
+            });
+        }
+    }
+
+    /// Drop every checkpoint and reset the interval, e.g. when a different
+    /// replay is loaded or the player takes over.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Start from checkpoints that came with the replay `player` just
+    /// loaded (e.g. embedded in a binary replay), so seeking can use them
+    /// right away.
+    pub fn adopt(&mut self, player: &ReplayPlayer, checkpoints: Vec<ReplayCheckpoint>) {
+        self.clear();
+        self.generation = player.generation();
+        for checkpoint in checkpoints {
+            self.insert(checkpoint);
+        }
+    }
+
+    /// Consume the list, oldest first.
+    pub fn into_checkpoints(self) -> Vec<ReplayCheckpoint> {
+        self.checkpoints
+    }
+}
+
+/// Snapshot the world with the installed codec, if there is one.
+fn capture(world: &mut World, tick: u64, cursor: usize) -> Option<ReplayCheckpoint> {
+    let codec = world.get_resource::<CheckpointCodec>().copied()?;
+    match (codec.capture)(world) {
+        Ok(state) => Some(ReplayCheckpoint {
+            tick,
+            slow_tick: world.resource::<SlowTickTimer>().counter,
+            cursor,
+            state,
+        }),
+        Err(e) => {
+            warn!("Failed to capture replay checkpoint at tick {tick}: {e}");
+            None
+        }
+    }
+}
+
+/// Exclusive system that snapshots the world on checkpoint ticks while a
+/// replay plays. Runs at the very start of each tick (see `ReplayPlugin`).
+pub fn capture_replay_checkpoints(world: &mut World) {
+    if !world.contains_resource::<CheckpointCodec>() {
+        return;
+    }
+    let player = world.resource::<ReplayPlayer>();
+    if !player.is_playing() {
+        return;
+    }
+    let cursor = player.cursor();
+    let generation = player.generation();
+    let mut checkpoints = world.resource_mut::<ReplayCheckpoints>();
+    if checkpoints.generation != generation {
+        checkpoints.clear();
+        checkpoints.generation = generation;
+    }
+    let tick = world.resource::<TickCounter>().0;
+    if !world.resource::<ReplayCheckpoints>().is_due(tick) {
+        return;
+    }
+    if let Some(checkpoint) = capture(world, tick, cursor) {
+        world.resource_mut::<ReplayCheckpoints>().insert(checkpoint);
+    }
+}
+
+/// Exclusive system that snapshots the world on checkpoint ticks while a
+/// session is being recorded, for embedding in binary replays. The cursor
+/// is the number of entries recorded so far, which is what the player's
+/// cursor will be at the same point of playback.
+pub fn capture_recording_checkpoints(world: &mut World) {
+    if !world.contains_resource::<CheckpointCodec>() {
+        return;
+    }
+    let recorder = world.resource::<ReplayRecorder>();
+    let tick = world.resource::<TickCounter>().0;
+    if !recorder.is_recording() || !recorder.checkpoints().is_due(tick) {
+        return;
+    }
+    let cursor = recorder.entry_count();
+    if let Some(checkpoint) = capture(world, tick, cursor) {
+        world
+            .resource_mut::<ReplayRecorder>()
+            .checkpoints_mut()
+            .insert(checkpoint);
+    }
+}
+
+/// Put the world back into the state of `checkpoint`, including the tick
+/// counters and the player's cursor.
+pub fn restore_checkpoint(world: &mut World, checkpoint: &ReplayCheckpoint) -> Result<(), String> {
+    let codec = world
+        .get_resource::<CheckpointCodec>()
+        .copied()
+        .ok_or_else(|| "no checkpoint codec is installed".to_string())?;
+    (codec.restore)(world, &checkpoint.state)?;
+    world.resource_mut::<TickCounter>().0 = checkpoint.tick;
+    world.resource_mut::<SlowTickTimer>().counter = checkpoint.slow_tick;
+    world
+        .resource_mut::<ReplayPlayer>()
+        .set_cursor(checkpoint.cursor);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint(tick: u64) -> ReplayCheckpoint {
+        ReplayCheckpoint {
+            tick,
+            slow_tick: 0,
+            cursor: 0,
+            state: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn first_checkpoint_is_due_immediately() {
+        let mut checkpoints = ReplayCheckpoints::default();
+        assert!(checkpoints.is_due(7));
+        checkpoints.insert(checkpoint(7));
+        assert!(!checkpoints.is_due(8));
+        assert!(checkpoints.is_due(DEFAULT_CHECKPOINT_INTERVAL));
+    }
+
+    #[test]
+    fn nearest_before_picks_latest_earlier_checkpoint() {
+        let mut checkpoints = ReplayCheckpoints::default();
+        for tick in [0, 600, 1200] {
+            checkpoints.insert(checkpoint(tick));
+        }
+        assert_eq!(checkpoints.nearest_before(900).unwrap().tick, 600);
+        assert_eq!(checkpoints.nearest_before(1200).unwrap().tick, 1200);
+        assert_eq!(checkpoints.nearest_before(5000).unwrap().tick, 1200);
+    }
+
+    #[test]
+    fn thinning_doubles_interval_and_keeps_first() {
+        let mut checkpoints = ReplayCheckpoints::default();
+        checkpoints.insert(checkpoint(3));
+        for i in 1..=MAX_CHECKPOINTS as u64 {
+            checkpoints.insert(checkpoint(i * DEFAULT_CHECKPOINT_INTERVAL));
+        }
+        assert!(checkpoints.checkpoints().len() <= MAX_CHECKPOINTS);
+        assert_eq!(checkpoints.interval(), DEFAULT_CHECKPOINT_INTERVAL * 2);
+        assert_eq!(checkpoints.checkpoints()[0].tick, 3);
+        assert!(checkpoints.checkpoints()[1..]
+            .iter()
+            .all(|c| c.tick.is_multiple_of(checkpoints.interval())));
+    }
+}
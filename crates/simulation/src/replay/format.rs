@@ -34,6 +34,18 @@ pub struct ReplayEntry {
     pub action: GameAction,
 }
 
+/// Simulation state hash recorded at the end of a tick, for checking that
+/// playback stays in sync with the original session.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, PartialEq)]
+pub struct ReplayChecksum {
+    /// Tick whose end-of-tick `StateHash` this is.
+    pub tick: u64,
+    /// Number of entries recorded up to and including `tick`.
+    pub entry_index: u64,
+    /// The `StateHash` value at the end of `tick`.
+    pub state_hash: u64,
+}
+
 /// Footer metadata written after all entries.
 #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, PartialEq)]
 pub struct ReplayFooter {
@@ -45,12 +57,15 @@ pub struct ReplayFooter {
     pub entry_count: u64,
 }
 
-/// Complete replay file: header + entries + footer.
+/// Complete replay file: header + entries + footer, plus periodic state
+/// checksums (absent from replays recorded before they were added).
 #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, PartialEq)]
 pub struct ReplayFile {
     pub header: ReplayHeader,
     pub entries: Vec<ReplayEntry>,
     pub footer: ReplayFooter,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<ReplayChecksum>,
 }
 
 impl ReplayFile {
@@ -74,9 +89,40 @@ impl ReplayFile {
         serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))
     }
 
+    /// The timeline up to the point where `cursor` entries have been played
+    /// at `tick`: later entries and checksums are dropped.
+    pub fn truncated(&self, cursor: usize, tick: u64) -> ReplayFile {
+        let entries = self.entries[..cursor.min(self.entries.len())].to_vec();
+        let checksums = self
+            .checksums
+            .iter()
+            .filter(|c| c.tick < tick && c.entry_index <= entries.len() as u64)
+            .cloned()
+            .collect();
+        ReplayFile {
+            header: self.header.clone(),
+            footer: ReplayFooter {
+                end_tick: tick,
+                final_state_hash: 0,
+                entry_count: entries.len() as u64,
+            },
+            entries,
+            checksums,
+        }
+    }
+
+    /// The checksum recorded for `tick`, if any.
+    pub fn checksum_at(&self, tick: u64) -> Option<&ReplayChecksum> {
+        self.checksums
+            .binary_search_by_key(&tick, |c| c.tick)
+            .ok()
+            .map(|i| &self.checksums[i])
+    }
+
     /// Validate internal consistency:
     /// - `footer.entry_count` matches `entries.len()`
     /// - Entries are sorted by tick (non-decreasing)
+    /// - Checksums are sorted by tick and point inside the entry list
     pub fn validate(&self) -> Result<(), String> {
         if self.footer.entry_count != self.entries.len() as u64 {
             return Err(format!(
@@ -95,6 +141,25 @@ impl ReplayFile {
             }
         }
 
+        for window in self.checksums.windows(2) {
+            if window[1].tick <= window[0].tick {
+                return Err(format!(
+                    "checksums not sorted by tick: tick {} followed by {}",
+                    window[0].tick, window[1].tick
+                ));
+            }
+        }
+        if let Some(c) = self
+            .checksums
+            .iter()
+            .find(|c| c.entry_index > self.footer.entry_count)
+        {
+            return Err(format!(
+                "checksum at tick {} points past the last entry",
+                c.tick
+            ));
+        }
+
         Ok(())
     }
 }
@@ -135,6 +200,11 @@ mod tests {
                 final_state_hash: 0,
                 entry_count: 3,
             },
+            checksums: vec![ReplayChecksum {
+                tick: 50,
+                entry_index: 3,
+                state_hash: 0xfeed,
+            }],
         }
     }
 
@@ -176,4 +246,24 @@ mod tests {
         let err = replay.validate().unwrap_err();
         assert!(err.contains("not sorted by tick"));
     }
+
+    #[test]
+    fn json_without_checksums_still_parses() {
+        let mut replay = sample_replay();
+        replay.checksums.clear();
+        let json = replay.to_json();
+        assert!(!json.contains("checksums"));
+        assert_eq!(ReplayFile::from_json(&json).unwrap(), replay);
+    }
+
+    #[test]
+    fn checksum_lookup_and_validation() {
+        let mut replay = sample_replay();
+        assert_eq!(replay.checksum_at(50).unwrap().state_hash, 0xfeed);
+        assert!(replay.checksum_at(51).is_none());
+
+        replay.checksums[0].entry_index = 4;
+        let err = replay.validate().unwrap_err();
+        assert!(err.contains("points past the last entry"));
+    }
 }
@@ -2,15 +2,26 @@
 //!
 //! Operates at the `GameAction` level — records player/agent actions by tick
 //! and replays them through the same `ActionQueue` executor path.
+//! Checkpoints taken during playback let the viewer scrub to any tick and
+//! take over from there as normal play. Replays are stored as JSON or as a
+//! compact binary container that can also embed checkpoints (`binary`).
 
+pub mod binary;
+pub mod checkpoint;
 pub mod format;
 pub mod player;
 pub mod plugin;
 pub mod recorder;
+pub mod seek;
+pub mod take_over;
 pub mod viewer;
 
-pub use format::{ReplayEntry, ReplayFile, ReplayFooter, ReplayHeader};
-pub use player::ReplayPlayer;
+pub use binary::{read_replay, write_replay, LoadedReplay, ReplayEncoding};
+pub use checkpoint::{CheckpointCodec, ReplayCheckpoint, ReplayCheckpoints};
+pub use format::{ReplayChecksum, ReplayEntry, ReplayFile, ReplayFooter, ReplayHeader};
+pub use player::{ReplayPlayer, ReplayVerification};
 pub use plugin::ReplayPlugin;
 pub use recorder::ReplayRecorder;
+pub use seek::ReplaySeek;
+pub use take_over::{take_over_replay, ReplayTakeOverEvent};
 pub use viewer::{ReplayViewerInfo, ReplayViewerMode};
@@ -3,11 +3,14 @@
 //!
 //! The player system runs in `PreSim` before the executor, injecting actions
 //! with `ActionSource::Replay` so the simulation processes them through the
-//! same code path as live play — deterministic by design.
+//! same code path as live play — deterministic by design. Checksums recorded
+//! with the replay are compared against the live `StateHash` as playback
+//! passes them (`ReplayVerification`).
 
 use bevy::prelude::*;
 
 use crate::game_actions::{ActionQueue, ActionSource};
+use crate::state_hash::StateHash;
 use crate::TickCounter;
 
 use super::format::ReplayFile;
@@ -19,6 +22,9 @@ pub struct ReplayPlayer {
     /// Index into `replay.entries` — entries before this index have been fed.
     cursor: usize,
     playing: bool,
+    /// Incremented on every `load`, so per-replay state such as checkpoints
+    /// can tell when a different replay was loaded.
+    generation: u64,
 }
 
 impl ReplayPlayer {
@@ -27,6 +33,7 @@ impl ReplayPlayer {
         self.replay = Some(replay);
         self.cursor = 0;
         self.playing = true;
+        self.generation += 1;
     }
 
     /// Whether playback is currently active.
@@ -87,6 +94,77 @@ impl ReplayPlayer {
     pub fn cursor(&self) -> usize {
         self.cursor
     }
+
+    /// Move the cursor, e.g. after restoring a checkpoint. Clamped to the
+    /// entry count.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        let len = self.replay.as_ref().map_or(0, |r| r.entries.len());
+        self.cursor = cursor.min(len);
+    }
+
+    /// Number of replays loaded so far.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The loaded replay, if any.
+    pub fn replay(&self) -> Option<&ReplayFile> {
+        self.replay.as_ref()
+    }
+}
+
+/// Result of comparing playback against the replay's recorded checksums.
+#[derive(Resource, Default, Debug, Clone, PartialEq)]
+pub struct ReplayVerification {
+    /// `ReplayPlayer::generation` of the replay being verified.
+    generation: u64,
+    /// Last tick compared, so ticks re-simulated after a seek are not
+    /// counted twice.
+    last_tick: Option<u64>,
+    /// Checksums compared so far.
+    pub checked: u64,
+    /// Checksums whose state hash or entry count differed.
+    pub mismatches: u64,
+    /// Tick of the first mismatch: playback diverged from the recording at
+    /// or before it.
+    pub first_mismatch: Option<u64>,
+}
+
+/// System that compares the end-of-tick `StateHash` with the checksum the
+/// replay recorded for the same tick, if any.
+pub fn verify_replay_checksums(
+    state_hash: Res<StateHash>,
+    player: Res<ReplayPlayer>,
+    mut verification: ResMut<ReplayVerification>,
+) {
+    if !player.is_playing() {
+        return;
+    }
+    if verification.generation != player.generation() {
+        *verification = ReplayVerification {
+            generation: player.generation(),
+            ..default()
+        };
+    }
+    let tick = state_hash.tick;
+    if verification.last_tick.is_some_and(|last| tick <= last) {
+        return;
+    }
+    let Some(expected) = player.replay().and_then(|r| r.checksum_at(tick)) else {
+        return;
+    };
+    verification.last_tick = Some(tick);
+    verification.checked += 1;
+    if expected.state_hash != state_hash.hash || expected.entry_index != player.cursor() as u64 {
+        verification.mismatches += 1;
+        if verification.first_mismatch.is_none() {
+            warn!(
+                "Replay diverged at tick {tick}: state hash {:#018x}, recorded {:#018x}",
+                state_hash.hash, expected.state_hash
+            );
+            verification.first_mismatch = Some(tick);
+        }
+    }
 }
 
 /// System that feeds replay actions into the `ActionQueue` at the correct tick.
@@ -146,6 +224,7 @@ mod tests {
                 final_state_hash: 0,
                 entry_count: 3,
             },
+            checksums: vec![],
         }
     }
 
@@ -188,4 +267,18 @@ mod tests {
         assert!(player.is_finished());
         assert_eq!(player.cursor(), 0);
     }
+
+    #[test]
+    fn set_cursor_rewinds_and_clamps() {
+        let mut player = ReplayPlayer::default();
+        player.load(test_replay());
+        player.actions_for_tick(5);
+        assert!(player.is_finished());
+
+        player.set_cursor(2);
+        assert_eq!(player.actions_for_tick(5).len(), 1);
+
+        player.set_cursor(99);
+        assert_eq!(player.cursor(), 3);
+    }
 }
@@ -2,9 +2,17 @@
 
 use bevy::prelude::*;
 
-use super::player::{feed_replay_actions, ReplayPlayer};
-use super::recorder::{record_actions, ReplayRecorder};
+use super::checkpoint::{
+    capture_recording_checkpoints, capture_replay_checkpoints, ReplayCheckpoints,
+};
+use super::player::{
+    feed_replay_actions, verify_replay_checksums, ReplayPlayer, ReplayVerification,
+};
+use super::recorder::{record_actions, record_checksums, ReplayRecorder};
+use super::seek::{process_replay_seek, ReplaySeek};
+use super::take_over::{handle_replay_take_over, ReplayTakeOverEvent};
 use crate::game_actions::{execute_queued_actions, ActionQueue};
+use crate::state_hash::update_state_hash;
 use crate::SimulationSet;
 
 /// Plugin that provides deterministic replay recording and playback.
@@ -15,18 +23,33 @@ use crate::SimulationSet;
 /// Both systems run in `PreSim` with explicit ordering:
 /// `feed_replay_actions` → `record_actions` (so replayed actions are visible
 /// to the recorder if nested recording is desired in the future).
+///
+/// Checkpoints are captured before both (and before the tick counter
+/// advances), so a checkpoint holds the state at the very start of a tick.
+/// State checksums are recorded and verified in `PostSim`, after the tick's
+/// `StateHash` is computed. Seeking and taking over run in `Update`, outside the fixed loop.
 pub struct ReplayPlugin;
 
 impl Plugin for ReplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ReplayRecorder>();
         app.init_resource::<ReplayPlayer>();
+        app.init_resource::<ReplayCheckpoints>();
+        app.init_resource::<ReplaySeek>();
+        app.init_resource::<ReplayVerification>();
+        app.add_event::<ReplayTakeOverEvent>();
         // Ensure ActionQueue exists (it may not be registered by another plugin yet).
         app.init_resource::<ActionQueue>();
 
         app.add_systems(
             FixedUpdate,
             (
+                capture_replay_checkpoints
+                    .before(feed_replay_actions)
+                    .before(crate::tick_slow_timer),
+                capture_recording_checkpoints
+                    .before(record_actions)
+                    .before(crate::tick_slow_timer),
                 feed_replay_actions,
                 record_actions
                     .after(feed_replay_actions)
@@ -34,5 +57,15 @@ impl Plugin for ReplayPlugin {
             )
                 .in_set(SimulationSet::PreSim),
         );
+        app.add_systems(
+            FixedUpdate,
+            (record_checksums, verify_replay_checksums)
+                .after(update_state_hash)
+                .in_set(SimulationSet::PostSim),
+        );
+        app.add_systems(
+            Update,
+            (process_replay_seek, handle_replay_take_over).chain(),
+        );
     }
 }
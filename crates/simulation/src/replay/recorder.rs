@@ -3,22 +3,53 @@
 //! The recorder system runs in `PreSim` and snapshots all pending actions
 //! before the executor drains them. This ensures every action that the
 //! simulation processes is faithfully captured for later replay.
+//!
+//! Alongside the actions it records a `StateHash` checksum every
+//! `CHECKSUM_INTERVAL` ticks and, when a `CheckpointCodec` is installed, a
+//! full state checkpoint every `RECORDED_CHECKPOINT_INTERVAL` ticks, which
+//! the binary replay container can embed for instant seeking.
 
 use bevy::prelude::*;
 
 use crate::game_actions::{ActionQueue, ActionSource, GameAction};
+use crate::state_hash::StateHash;
 use crate::TickCounter;
 
-use super::format::{ReplayEntry, ReplayFile, ReplayFooter, ReplayHeader, CURRENT_FORMAT_VERSION};
+use super::checkpoint::{ReplayCheckpoint, ReplayCheckpoints};
+use super::format::{
+    ReplayChecksum, ReplayEntry, ReplayFile, ReplayFooter, ReplayHeader, CURRENT_FORMAT_VERSION,
+};
+
+/// Ticks between recorded state checksums (ten seconds at 1x speed).
+pub const CHECKSUM_INTERVAL: u64 = 100;
+
+/// Ticks between full state checkpoints taken while recording (ten minutes
+/// at 1x speed). Thinned like viewer checkpoints once `MAX_CHECKPOINTS` is
+/// reached.
+pub const RECORDED_CHECKPOINT_INTERVAL: u64 = 6000;
 
 /// Resource that accumulates `ReplayEntry` items while recording is active.
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct ReplayRecorder {
     header: Option<ReplayHeader>,
     entries: Vec<ReplayEntry>,
+    checksums: Vec<ReplayChecksum>,
+    checkpoints: ReplayCheckpoints,
     recording: bool,
 }
 
+impl Default for ReplayRecorder {
+    fn default() -> Self {
+        Self {
+            header: None,
+            entries: Vec::new(),
+            checksums: Vec::new(),
+            checkpoints: ReplayCheckpoints::with_interval(RECORDED_CHECKPOINT_INTERVAL),
+            recording: false,
+        }
+    }
+}
+
 impl ReplayRecorder {
     /// Begin recording a new replay session.
     ///
@@ -26,6 +57,8 @@ impl ReplayRecorder {
     pub fn start(&mut self, seed: u64, city_name: String, start_tick: u64) {
         self.recording = true;
         self.entries.clear();
+        self.checksums.clear();
+        self.checkpoints = ReplayCheckpoints::with_interval(RECORDED_CHECKPOINT_INTERVAL);
         self.header = Some(ReplayHeader {
             format_version: CURRENT_FORMAT_VERSION,
             seed,
@@ -34,6 +67,24 @@ impl ReplayRecorder {
         });
     }
 
+    /// Continue recording on top of an existing timeline: the entries and
+    /// checksums of `replay` are kept as they are and new ones are appended
+    /// after them, along with `checkpoints` taken on that timeline.
+    ///
+    /// Used when the player takes over a replay (see
+    /// `ReplayFile::truncated`), so the new recording replays from the
+    /// original start with the same seed.
+    pub fn resume(&mut self, replay: ReplayFile, checkpoints: Vec<ReplayCheckpoint>) {
+        self.recording = true;
+        self.entries = replay.entries;
+        self.checksums = replay.checksums;
+        self.checkpoints = ReplayCheckpoints::with_interval(RECORDED_CHECKPOINT_INTERVAL);
+        for checkpoint in checkpoints {
+            self.checkpoints.insert(checkpoint);
+        }
+        self.header = Some(replay.header);
+    }
+
     /// Append a single action entry. Only records if currently recording.
     pub fn record(&mut self, tick: u64, action: GameAction) {
         if self.recording {
@@ -41,10 +92,24 @@ impl ReplayRecorder {
         }
     }
 
+    /// Append a state checksum for the end of `tick`. Only records if
+    /// currently recording; a tick is never recorded twice.
+    pub fn record_checksum(&mut self, tick: u64, state_hash: u64) {
+        if !self.recording || self.checksums.last().is_some_and(|c| c.tick >= tick) {
+            return;
+        }
+        self.checksums.push(ReplayChecksum {
+            tick,
+            entry_index: self.entries.len() as u64,
+            state_hash,
+        });
+    }
+
     /// Stop recording and produce a finalized `ReplayFile`.
     ///
     /// `state_hash` is the hash of the simulation state at `end_tick`
-    /// (pass 0 if hashing is unavailable).
+    /// (pass 0 if hashing is unavailable). Checkpoints taken while
+    /// recording stay available through `take_checkpoints`.
     pub fn stop(&mut self, end_tick: u64, state_hash: u64) -> ReplayFile {
         self.recording = false;
         let header = self.header.take().unwrap_or(ReplayHeader {
@@ -63,9 +128,29 @@ impl ReplayRecorder {
                 final_state_hash: state_hash,
                 entry_count,
             },
+            checksums: std::mem::take(&mut self.checksums),
         }
     }
 
+    /// Hand over the full state checkpoints taken while recording, oldest
+    /// first, e.g. to embed them in a binary replay after `stop`.
+    pub fn take_checkpoints(&mut self) -> Vec<ReplayCheckpoint> {
+        std::mem::replace(
+            &mut self.checkpoints,
+            ReplayCheckpoints::with_interval(RECORDED_CHECKPOINT_INTERVAL),
+        )
+        .into_checkpoints()
+    }
+
+    /// Checkpoints taken so far in this recording.
+    pub fn checkpoints(&self) -> &ReplayCheckpoints {
+        &self.checkpoints
+    }
+
+    pub(crate) fn checkpoints_mut(&mut self) -> &mut ReplayCheckpoints {
+        &mut self.checkpoints
+    }
+
     /// Whether the recorder is currently capturing actions.
     pub fn is_recording(&self) -> bool {
         self.recording
@@ -99,6 +184,14 @@ pub fn record_actions(
     }
 }
 
+/// System that records the end-of-tick `StateHash` every
+/// `CHECKSUM_INTERVAL` ticks while recording.
+pub fn record_checksums(state_hash: Res<StateHash>, mut recorder: ResMut<ReplayRecorder>) {
+    if recorder.is_recording() && state_hash.tick.is_multiple_of(CHECKSUM_INTERVAL) {
+        recorder.record_checksum(state_hash.tick, state_hash.hash);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +236,45 @@ mod tests {
         recorder.record(1, GameAction::SetSpeed { speed: 1 });
         assert_eq!(recorder.entry_count(), 0);
     }
+
+    #[test]
+    fn resume_keeps_existing_entries() {
+        let mut recorder = ReplayRecorder::default();
+        recorder.start(7, "Fork".to_string(), 0);
+        recorder.record(1, GameAction::SetSpeed { speed: 2 });
+        recorder.record_checksum(5, 0xabc);
+        let original = recorder.stop(10, 0);
+
+        recorder.resume(original.clone(), Vec::new());
+        recorder.record(12, GameAction::SetPaused { paused: true });
+        recorder.record_checksum(15, 0xdef);
+        let forked = recorder.stop(20, 0);
+
+        assert_eq!(forked.header, original.header);
+        assert_eq!(forked.entries.len(), 2);
+        assert_eq!(forked.entries[0], original.entries[0]);
+        assert_eq!(forked.checksums.len(), 2);
+        assert_eq!(forked.checksums[1].entry_index, 2);
+        assert!(forked.validate().is_ok());
+    }
+
+    #[test]
+    fn checksums_are_recorded_once_per_tick() {
+        let mut recorder = ReplayRecorder::default();
+        recorder.record_checksum(100, 1);
+        recorder.start(1, String::new(), 0);
+        recorder.record(3, GameAction::SetSpeed { speed: 1 });
+        recorder.record_checksum(100, 1);
+        recorder.record_checksum(100, 2);
+
+        let replay = recorder.stop(150, 0);
+        assert_eq!(
+            replay.checksums,
+            vec![ReplayChecksum {
+                tick: 100,
+                entry_index: 1,
+                state_hash: 1,
+            }]
+        );
+    }
 }
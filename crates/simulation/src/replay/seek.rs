@@ -0,0 +1,113 @@
+//! Scrubbing: move replay playback to any tick.
+//!
+//! Seeking backwards restores the nearest earlier checkpoint and then
+//! re-simulates forward; seeking forwards simulates ahead from wherever
+//! playback is, jumping over already-recorded checkpoints when it can.
+//! Re-simulation is spread over frames (`SEEK_TICKS_PER_FRAME`) so the
+//! window stays responsive during long seeks.
+
+use bevy::prelude::*;
+
+use crate::TickCounter;
+
+use super::checkpoint::{restore_checkpoint, ReplayCheckpoints};
+use super::player::ReplayPlayer;
+
+/// Most ticks re-simulated per frame while seeking.
+pub const SEEK_TICKS_PER_FRAME: u32 = 200;
+
+/// Pending seek request, set through `request`.
+#[derive(Resource, Default, Debug)]
+pub struct ReplaySeek {
+    target: Option<u64>,
+    /// Whether the nearest checkpoint has been restored for this request.
+    positioned: bool,
+    /// Why the last seek could not be completed.
+    pub last_error: Option<String>,
+}
+
+impl ReplaySeek {
+    /// Move playback to `tick`, replacing any seek in progress.
+    pub fn request(&mut self, tick: u64) {
+        self.target = Some(tick);
+        self.positioned = false;
+        self.last_error = None;
+    }
+
+    /// The tick being sought, if a seek is in progress.
+    pub fn target(&self) -> Option<u64> {
+        self.target
+    }
+
+    pub fn is_seeking(&self) -> bool {
+        self.target.is_some()
+    }
+
+    fn fail(&mut self, error: String) {
+        warn!("Replay seek failed: {error}");
+        self.target = None;
+        self.last_error = Some(error);
+    }
+}
+
+/// Exclusive system that carries out a pending seek.
+///
+/// A restored checkpoint is given a frame of its own so the post-load
+/// rebuild is flagged before the first re-simulated tick, exactly as after
+/// loading a save.
+pub fn process_replay_seek(world: &mut World) {
+    let Some(target) = world.resource::<ReplaySeek>().target else {
+        return;
+    };
+    if world.resource::<ReplayPlayer>().replay().is_none() {
+        world
+            .resource_mut::<ReplaySeek>()
+            .fail("no replay is loaded".to_string());
+        return;
+    }
+
+    if !world.resource::<ReplaySeek>().positioned {
+        world.resource_mut::<ReplaySeek>().positioned = true;
+        let tick = world.resource::<TickCounter>().0;
+        let restored = world.resource_scope(|world, checkpoints: Mut<ReplayCheckpoints>| {
+            let nearest = checkpoints.nearest_before(target);
+            let worth_restoring = target < tick || nearest.is_some_and(|c| c.tick > tick);
+            match nearest {
+                Some(checkpoint) if worth_restoring => {
+                    restore_checkpoint(world, checkpoint).map(|_| true)
+                }
+                None if target < tick => Err(format!("no checkpoint at or before tick {target}")),
+                _ => Ok(false),
+            }
+        });
+        match restored {
+            Ok(true) => {
+                if world.resource::<TickCounter>().0 >= target {
+                    world.resource_mut::<ReplaySeek>().target = None;
+                }
+                return;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                world.resource_mut::<ReplaySeek>().fail(e);
+                return;
+            }
+        }
+    }
+
+    for _ in 0..SEEK_TICKS_PER_FRAME {
+        let before = world.resource::<TickCounter>().0;
+        if before >= target {
+            break;
+        }
+        world.run_schedule(FixedUpdate);
+        if world.resource::<TickCounter>().0 == before {
+            // The simulation is gated off (e.g. a save is loading); try again
+            // next frame.
+            return;
+        }
+    }
+    if world.resource::<TickCounter>().0 >= target {
+        world.resource_mut::<ReplaySeek>().target = None;
+    }
+}
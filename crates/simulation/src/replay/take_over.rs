@@ -0,0 +1,80 @@
+//! Taking over a replay: leave viewer mode and keep playing from the
+//! current tick, forking the timeline.
+//!
+//! The fork keeps the original header (seed, city name, start tick), every
+//! entry and checksum already played and the checkpoints taken so far, and
+//! records the player's own actions after them, so the new recording
+//! replays from the same start as the original.
+
+use bevy::prelude::*;
+
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::TickCounter;
+
+use super::checkpoint::ReplayCheckpoints;
+use super::player::ReplayPlayer;
+use super::recorder::ReplayRecorder;
+use super::seek::ReplaySeek;
+use super::viewer::{ReplayViewerInfo, ReplayViewerMode};
+
+/// Sent (e.g. by the replay viewer UI) to take over the loaded replay.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ReplayTakeOverEvent;
+
+/// Stop playback at the current tick and continue as normal play.
+///
+/// Returns the tick the timeline was forked at.
+pub fn take_over_replay(world: &mut World) -> Result<u64, String> {
+    if world.resource::<ReplaySeek>().is_seeking() {
+        return Err("wait for the seek to finish".to_string());
+    }
+    let tick = world.resource::<TickCounter>().0;
+    let player = world.resource::<ReplayPlayer>();
+    let replay = player.replay().ok_or("no replay is loaded")?;
+    let fork = replay.truncated(player.cursor(), tick);
+
+    world.resource_mut::<ReplayPlayer>().stop();
+    // Checkpoints up to the fork are still valid on the new timeline.
+    let checkpoints: Vec<_> = std::mem::take(&mut *world.resource_mut::<ReplayCheckpoints>())
+        .into_checkpoints()
+        .into_iter()
+        .filter(|c| c.tick <= tick)
+        .collect();
+    world.remove_resource::<ReplayViewerMode>();
+    world.remove_resource::<ReplayViewerInfo>();
+    world
+        .resource_mut::<ReplayRecorder>()
+        .resume(fork, checkpoints);
+
+    info!("Took over replay at tick {tick}");
+    world.send_event(NotificationEvent {
+        text: format!(
+            "Took over the replay at tick {tick}. Your actions now start a new timeline."
+        ),
+        priority: NotificationPriority::Info,
+        category: NotificationCategory::General,
+        location: None,
+    });
+    Ok(tick)
+}
+
+/// Exclusive system that handles `ReplayTakeOverEvent`.
+pub fn handle_replay_take_over(world: &mut World) {
+    let requested = world
+        .resource_mut::<Events<ReplayTakeOverEvent>>()
+        .drain()
+        .count()
+        > 0;
+    if !requested {
+        return;
+    }
+    if let Err(e) = take_over_replay(world) {
+        warn!("Cannot take over replay: {e}");
+        world.send_event(NotificationEvent {
+            text: format!("Cannot take over the replay: {e}"),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+}
@@ -5,7 +5,10 @@ use bevy::prelude::*;
 /// Viewer mode is watch-only:
 /// - camera movement remains enabled
 /// - build/edit input is disabled
-/// - UI is reduced to playback controls
+/// - UI adds playback, scrubbing and take-over controls
+///
+/// Taking over the replay (`take_over_replay`) removes this resource, which
+/// re-enables build/edit input for normal play.
 #[derive(Resource, Default)]
 pub struct ReplayViewerMode;
 
@@ -0,0 +1,19 @@
+//! Flow of the Yarkon through the city.
+//!
+//! The river's flow comes from springs upstream, which weaken in droughts,
+//! and from storm runoff off the catchment. An upstream dam stores that
+//! water and lets it through at a release rate the player sets; a full dam
+//! spills, an empty one can only pass on what flows in.
+//!
+//! Below the dam, water treatment plants, irrigated farms and the watering
+//! of street trees draw from the river. What they leave is the flow of the
+//! downstream reach to the sea: draw too much, or release too little, and
+//! that reach dries out, taking its habitat and its tourism draw with it.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{extraction_demand, update_river_flow, RiverFlowPlugin};
+pub use types::*;
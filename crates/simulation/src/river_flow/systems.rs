@@ -0,0 +1,89 @@
+//! Daily river flow: catchment inflow, dam release, extraction and the
+//! state of the downstream reach.
+
+use bevy::prelude::*;
+
+use crate::agriculture::AgricultureState;
+use crate::drought::DroughtState;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+use crate::urban_forestry::UrbanForestry;
+use crate::water_treatment::WaterTreatmentState;
+use crate::weather::Weather;
+
+use super::types::*;
+
+/// Water the treatment plants and irrigated farms want from the river.
+pub fn extraction_demand(treatment: &WaterTreatmentState, agriculture: &AgricultureState) -> f32 {
+    let irrigation = if agriculture.has_irrigation && agriculture.growing_season_active {
+        agriculture.farm_count as f32 * IRRIGATION_MGD_PER_FARM
+    } else {
+        0.0
+    };
+    treatment.total_flow_mgd + irrigation
+}
+
+/// Once a day, route the river from the catchment through the dam and past
+/// the intakes, and tell the player when the downstream reach dries out or
+/// recovers.
+pub fn update_river_flow(
+    clock: Res<GameClock>,
+    weather: Res<Weather>,
+    drought: Res<DroughtState>,
+    treatment: Res<WaterTreatmentState>,
+    agriculture: Res<AgricultureState>,
+    forestry: Res<UrbanForestry>,
+    mut river: ResMut<RiverFlowState>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= river.last_update_day {
+        return;
+    }
+    river.last_update_day = clock.day;
+
+    let was_dry = river.is_dry();
+    river.catchment(weather.precipitation_intensity, drought.current_index);
+    river.operate_dam();
+    river.extract(extraction_demand(&treatment, &agriculture) + forestry.water_use_mgd);
+
+    if river.is_dry() && !was_dry {
+        notifications.send(NotificationEvent {
+            text: format!(
+                "The Yarkon is running dry below the intakes ({:.1} MGD left); \
+                 release more from the dam or draw less",
+                river.downstream_mgd
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    } else if was_dry && !river.is_dry() {
+        notifications.send(NotificationEvent {
+            text: "The Yarkon is flowing again below the intakes".to_string(),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+}
+
+pub struct RiverFlowPlugin;
+
+impl Plugin for RiverFlowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RiverFlowState>().add_systems(
+            FixedUpdate,
+            update_river_flow
+                .after(crate::weather::update_weather)
+                .after(crate::drought::update_drought_index)
+                .after(crate::water_treatment::update_water_treatment)
+                .after(crate::agriculture::update_agriculture)
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<RiverFlowState>();
+    }
+}
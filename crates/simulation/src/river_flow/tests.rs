@@ -0,0 +1,120 @@
+use super::*;
+use crate::agriculture::AgricultureState;
+use crate::water_treatment::WaterTreatmentState;
+use crate::Saveable;
+
+#[test]
+fn test_rain_runoff_recedes_over_days() {
+    let mut river = RiverFlowState::default();
+    river.catchment(0.5, 1.0);
+    let storm_day = river.inflow_mgd;
+    assert!(storm_day > BASEFLOW_MGD);
+    river.catchment(0.0, 1.0);
+    assert!(river.inflow_mgd > BASEFLOW_MGD && river.inflow_mgd < storm_day);
+    for _ in 0..60 {
+        river.catchment(0.0, 1.0);
+    }
+    assert!((river.inflow_mgd - BASEFLOW_MGD).abs() < 0.01);
+}
+
+#[test]
+fn test_drought_weakens_baseflow() {
+    let mut river = RiverFlowState::default();
+    river.catchment(0.0, 0.0);
+    assert_eq!(river.inflow_mgd, BASEFLOW_MGD * MIN_BASEFLOW_FACTOR);
+    river.catchment(0.0, 10.0);
+    assert_eq!(river.inflow_mgd, BASEFLOW_MGD * MAX_BASEFLOW_FACTOR);
+}
+
+#[test]
+fn test_dam_stores_what_it_does_not_release() {
+    let mut river = RiverFlowState {
+        inflow_mgd: 50.0,
+        release_target_mgd: 20.0,
+        ..Default::default()
+    };
+    let before = river.dam_storage_mg;
+    river.operate_dam();
+    assert_eq!(river.release_mgd, 20.0);
+    assert_eq!(river.dam_storage_mg, before + 30.0);
+}
+
+#[test]
+fn test_empty_dam_passes_only_inflow() {
+    let mut river = RiverFlowState {
+        inflow_mgd: 10.0,
+        dam_storage_mg: 0.0,
+        release_target_mgd: 100.0,
+        ..Default::default()
+    };
+    river.operate_dam();
+    assert_eq!(river.release_mgd, 10.0);
+    assert_eq!(river.dam_storage_mg, 0.0);
+}
+
+#[test]
+fn test_full_dam_spills() {
+    let mut river = RiverFlowState {
+        inflow_mgd: 100.0,
+        dam_storage_mg: DAM_CAPACITY_MG,
+        release_target_mgd: 0.0,
+        ..Default::default()
+    };
+    river.operate_dam();
+    assert_eq!(river.release_mgd, 100.0);
+    assert_eq!(river.dam_storage_mg, DAM_CAPACITY_MG);
+}
+
+#[test]
+fn test_over_extraction_dries_the_downstream_reach() {
+    let mut river = RiverFlowState {
+        release_mgd: 30.0,
+        ..Default::default()
+    };
+    river.extract(10.0);
+    assert_eq!(river.downstream_mgd, 20.0);
+    assert_eq!(river.health(), 1.0);
+    assert!(!river.is_dry());
+
+    river.extract(40.0);
+    assert_eq!(river.extraction_mgd, 30.0);
+    assert_eq!(river.shortfall_mgd(), 10.0);
+    assert_eq!(river.downstream_mgd, 0.0);
+    assert!(river.is_dry());
+    assert_eq!(river.dry_days, 1);
+    assert_eq!(river.tourism_points(), 0.0);
+    assert_eq!(river.channel_habitat(), DRY_CHANNEL_HABITAT);
+}
+
+#[test]
+fn test_irrigation_draws_only_in_growing_season() {
+    let treatment = WaterTreatmentState {
+        total_flow_mgd: 5.0,
+        ..Default::default()
+    };
+    let mut agriculture = AgricultureState {
+        has_irrigation: true,
+        farm_count: 10,
+        ..Default::default()
+    };
+    assert_eq!(extraction_demand(&treatment, &agriculture), 5.0);
+    agriculture.growing_season_active = true;
+    assert_eq!(
+        extraction_demand(&treatment, &agriculture),
+        5.0 + 10.0 * IRRIGATION_MGD_PER_FARM
+    );
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(RiverFlowState::default().save_to_bytes().is_none());
+    let river = RiverFlowState {
+        release_target_mgd: 75.0,
+        dam_storage_mg: 1_234.0,
+        dry_days: 3,
+        last_update_day: 40,
+        ..Default::default()
+    };
+    let bytes = river.save_to_bytes().expect("non-default state saves");
+    assert_eq!(RiverFlowState::load_from_bytes(&bytes), river);
+}
@@ -0,0 +1,195 @@
+//! River flow constants, the dam and the saved river state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Dry-weather flow reaching the dam from springs upstream (MGD) when
+/// rainfall is normal.
+pub const BASEFLOW_MGD: f32 = 40.0;
+
+/// Floor and ceiling on the baseflow multiplier taken from the drought
+/// index.
+pub const MIN_BASEFLOW_FACTOR: f32 = 0.25;
+pub const MAX_BASEFLOW_FACTOR: f32 = 1.5;
+
+/// Storm runoff added to the river per inch/hr of rain on the catchment
+/// (MGD).
+pub const RAIN_RUNOFF_MGD: f32 = 400.0;
+
+/// Share of the storm runoff that has passed the dam by the next day.
+pub const RUNOFF_RECESSION: f32 = 0.3;
+
+/// Storage behind the upstream dam (million gallons).
+pub const DAM_CAPACITY_MG: f32 = 5_000.0;
+
+/// Release rate a new city starts with (MGD).
+pub const DEFAULT_RELEASE_MGD: f32 = 40.0;
+
+/// Highest release rate the dam's outlets allow (MGD).
+pub const MAX_RELEASE_MGD: f32 = 200.0;
+
+/// Flow the downstream reach needs to stay healthy (MGD).
+pub const ENVIRONMENTAL_FLOW_MGD: f32 = 15.0;
+
+/// Downstream health below which the reach counts as dried out.
+pub const DRY_HEALTH: f32 = 0.25;
+
+/// Irrigation drawn by each irrigated farm in the growing season (MGD).
+pub const IRRIGATION_MGD_PER_FARM: f32 = 0.5;
+
+/// Raw natural beauty points for tourism from a healthy river.
+pub const RIVER_TOURISM_POINTS: f32 = 15.0;
+
+/// Share of its habitat value a dried-out channel keeps.
+pub const DRY_CHANNEL_HABITAT: f32 = 0.2;
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+/// Flow of the Yarkon through the city, in million gallons per day.
+///
+/// Rain and springs feed the catchment upstream of the dam. The dam stores
+/// what it does not release; the release then runs down the city reach,
+/// where treatment plants and irrigated farms draw from it, and whatever is
+/// left flows on to the sea.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct RiverFlowState {
+    /// Storm runoff still draining off the catchment.
+    pub runoff_mgd: f32,
+    /// Flow arriving at the dam.
+    pub inflow_mgd: f32,
+    /// Water stored behind the dam (million gallons).
+    pub dam_storage_mg: f32,
+    /// Release rate the player has asked for.
+    pub release_target_mgd: f32,
+    /// Flow let through the dam, including any spill over a full dam.
+    pub release_mgd: f32,
+    /// What treatment plants and farms wanted to draw.
+    pub extraction_demand_mgd: f32,
+    /// What they actually drew.
+    pub extraction_mgd: f32,
+    /// Flow left in the reach below the intakes.
+    pub downstream_mgd: f32,
+    /// Consecutive days the downstream reach has been dry.
+    pub dry_days: u32,
+    pub last_update_day: u32,
+}
+
+impl Default for RiverFlowState {
+    fn default() -> Self {
+        Self {
+            runoff_mgd: 0.0,
+            inflow_mgd: BASEFLOW_MGD,
+            dam_storage_mg: DAM_CAPACITY_MG * 0.5,
+            release_target_mgd: DEFAULT_RELEASE_MGD,
+            release_mgd: DEFAULT_RELEASE_MGD,
+            extraction_demand_mgd: 0.0,
+            extraction_mgd: 0.0,
+            downstream_mgd: DEFAULT_RELEASE_MGD,
+            dry_days: 0,
+            last_update_day: 0,
+        }
+    }
+}
+
+impl RiverFlowState {
+    /// Update the flow reaching the dam from today's rain (`precipitation`
+    /// in in/hr) and the drought index (1.0 = normal rainfall).
+    pub fn catchment(&mut self, precipitation: f32, drought_index: f32) {
+        self.runoff_mgd += precipitation.max(0.0) * RAIN_RUNOFF_MGD;
+        let drained = self.runoff_mgd * RUNOFF_RECESSION;
+        self.runoff_mgd -= drained;
+        if self.runoff_mgd < 0.01 {
+            self.runoff_mgd = 0.0;
+        }
+        let baseflow = BASEFLOW_MGD * drought_index.clamp(MIN_BASEFLOW_FACTOR, MAX_BASEFLOW_FACTOR);
+        self.inflow_mgd = baseflow + drained;
+    }
+
+    /// Run the dam for a day: release the target rate while storage lasts
+    /// and spill whatever a full dam cannot hold.
+    pub fn operate_dam(&mut self) {
+        let available = self.dam_storage_mg + self.inflow_mgd;
+        let mut release = self
+            .release_target_mgd
+            .clamp(0.0, MAX_RELEASE_MGD)
+            .min(available);
+        let stored = available - release;
+        let spill = (stored - DAM_CAPACITY_MG).max(0.0);
+        release += spill;
+        self.dam_storage_mg = stored - spill;
+        self.release_mgd = release;
+    }
+
+    /// Draw `demand` MGD from the released flow and record what is left for
+    /// the downstream reach.
+    pub fn extract(&mut self, demand: f32) {
+        self.extraction_demand_mgd = demand.max(0.0);
+        self.extraction_mgd = self.extraction_demand_mgd.min(self.release_mgd);
+        self.downstream_mgd = self.release_mgd - self.extraction_mgd;
+        if self.is_dry() {
+            self.dry_days += 1;
+        } else {
+            self.dry_days = 0;
+        }
+    }
+
+    /// Downstream flow against the environmental flow, 0-1.
+    pub fn health(&self) -> f32 {
+        (self.downstream_mgd / ENVIRONMENTAL_FLOW_MGD).clamp(0.0, 1.0)
+    }
+
+    pub fn is_dry(&self) -> bool {
+        self.health() < DRY_HEALTH
+    }
+
+    /// Draws the river could not supply.
+    pub fn shortfall_mgd(&self) -> f32 {
+        self.extraction_demand_mgd - self.extraction_mgd
+    }
+
+    pub fn dam_fill(&self) -> f32 {
+        self.dam_storage_mg / DAM_CAPACITY_MG
+    }
+
+    /// Days the dam can keep up the target release with no inflow.
+    pub fn dam_days_left(&self) -> f32 {
+        if self.release_target_mgd <= 0.0 {
+            return f32::INFINITY;
+        }
+        self.dam_storage_mg / self.release_target_mgd
+    }
+
+    /// Share of its habitat value a river channel keeps at today's flow.
+    pub fn channel_habitat(&self) -> f32 {
+        DRY_CHANNEL_HABITAT + (1.0 - DRY_CHANNEL_HABITAT) * self.health()
+    }
+
+    /// Raw natural beauty points the river adds to tourism.
+    pub fn tourism_points(&self) -> f32 {
+        self.health() * RIVER_TOURISM_POINTS
+    }
+}
+
+impl Saveable for RiverFlowState {
+    const SAVE_KEY: &'static str = "river_flow";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -168,6 +168,7 @@ pub fn update_road_maintenance_stats(
     condition_grid: Res<RoadConditionGrid>,
     mut maint_budget: ResMut<RoadMaintenanceBudget>,
     mut stats: ResMut<RoadMaintenanceStats>,
+    unlocks: Res<crate::unlocks::UnlockState>,
 ) {
     if !tick.0.is_multiple_of(50) {
         return;
@@ -213,7 +214,9 @@ pub fn update_road_maintenance_stats(
             }
         }
     }
-    maint_budget.monthly_cost = total_maintenance_cost * maint_budget.budget_level as f64;
+    maint_budget.monthly_cost = total_maintenance_cost
+        * maint_budget.budget_level as f64
+        * unlocks.road_maintenance_multiplier();
 }
 
 #[cfg(test)]
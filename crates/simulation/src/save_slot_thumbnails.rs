@@ -0,0 +1,101 @@
+//! Thumbnails of the city for the save slot browser.
+//!
+//! Saving to a slot captures a minimap-style frame of the `WorldGrid`, the
+//! same run-length-encoded `TimelapseFrame` the time-lapse records, so a
+//! thumbnail costs a few kilobytes at most. Thumbnails live beside the
+//! `SaveSlotManager` under their own save key rather than inside
+//! `SaveSlotInfo`, so slot registries written before thumbnails existed still
+//! decode.
+
+use bevy::prelude::*;
+
+use crate::grid::WorldGrid;
+use crate::timelapse::TimelapseFrame;
+use crate::Saveable;
+
+/// Thumbnail of every save slot that has one, by slot index.
+#[derive(Resource, Debug, Clone, Default, bitcode::Encode, bitcode::Decode)]
+pub struct SaveSlotThumbnails {
+    /// `(slot_index, frame)` pairs, ordered by slot index.
+    pub frames: Vec<(u32, TimelapseFrame)>,
+}
+
+impl SaveSlotThumbnails {
+    /// The thumbnail of slot `slot_index`, if one was captured.
+    pub fn get(&self, slot_index: u32) -> Option<&TimelapseFrame> {
+        self.frames
+            .iter()
+            .find(|(i, _)| *i == slot_index)
+            .map(|(_, frame)| frame)
+    }
+
+    /// Store `frame` as the thumbnail of `slot_index`, replacing any older one.
+    pub fn set(&mut self, slot_index: u32, frame: TimelapseFrame) {
+        self.remove(slot_index);
+        self.frames.push((slot_index, frame));
+        self.frames.sort_by_key(|(i, _)| *i);
+    }
+
+    /// Drop the thumbnail of `slot_index`.
+    pub fn remove(&mut self, slot_index: u32) {
+        self.frames.retain(|(i, _)| *i != slot_index);
+    }
+
+    /// Capture the current city as the thumbnail of `slot_index`.
+    pub fn capture(&mut self, slot_index: u32, grid: &WorldGrid, day: u32, population: u32) {
+        self.set(slot_index, TimelapseFrame::capture(grid, day, population));
+    }
+}
+
+impl Saveable for SaveSlotThumbnails {
+    const SAVE_KEY: &'static str = "save_slot_thumbnails";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+
+    fn frame(day: u32) -> TimelapseFrame {
+        TimelapseFrame::from_pixels(day, 0, &[0, 0, 1])
+    }
+
+    #[test]
+    fn test_set_replaces_and_keeps_order() {
+        let mut thumbnails = SaveSlotThumbnails::default();
+        thumbnails.set(3, frame(1));
+        thumbnails.set(0, frame(2));
+        thumbnails.set(3, frame(5));
+        let slots: Vec<u32> = thumbnails.frames.iter().map(|(i, _)| *i).collect();
+        assert_eq!(slots, vec![0, 3]);
+        assert_eq!(thumbnails.get(3).map(|f| f.day), Some(5));
+
+        thumbnails.remove(3);
+        assert!(thumbnails.get(3).is_none());
+    }
+
+    #[test]
+    fn test_capture_and_saveable_roundtrip() {
+        let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        let mut thumbnails = SaveSlotThumbnails::default();
+        assert!(thumbnails.save_to_bytes().is_none());
+
+        thumbnails.capture(2, &grid, 12, 3400);
+        let bytes = thumbnails.save_to_bytes().unwrap();
+        let loaded = SaveSlotThumbnails::load_from_bytes(&bytes);
+        let thumbnail = loaded.get(2).unwrap();
+        assert_eq!((thumbnail.day, thumbnail.population), (12, 3400));
+        assert_eq!(thumbnail, thumbnails.get(2).unwrap());
+    }
+}
@@ -2,13 +2,15 @@
 //!
 //! Provides a save slot management system supporting multiple named save files.
 //! Each slot has metadata (city name, timestamp, population, treasury, play time)
-//! that can be read without loading the full save.
+//! that can be read without loading the full save, plus a thumbnail of the
+//! city captured on every save (see `save_slot_thumbnails`).
 //!
 //! The simulation crate owns the slot registry and events. The actual file I/O
 //! is performed by the save crate via `PendingSavePath` + `SaveGameEvent`/`LoadGameEvent`.
 
 use bevy::prelude::*;
 
+use crate::save_slot_thumbnails::SaveSlotThumbnails;
 use crate::Saveable;
 
 // =============================================================================
@@ -245,7 +247,8 @@ impl Saveable for SaveSlotManager {
 // Systems
 // =============================================================================
 
-/// Handles `SaveToSlotEvent` by updating the slot manager metadata.
+/// Handles `SaveToSlotEvent` by updating the slot manager metadata and
+/// capturing the slot's thumbnail.
 ///
 /// Runs in `Update`. The UI is responsible for also sending a `SaveGameEvent`
 /// with the appropriate `PendingSavePath` to trigger the actual file write.
@@ -257,6 +260,8 @@ pub fn handle_save_to_slot(
     budget: Res<crate::economy::CityBudget>,
     virtual_pop: Res<crate::virtual_population::VirtualPopulation>,
     play_time: Option<Res<crate::play_time::PlayTime>>,
+    grid: Res<crate::grid::WorldGrid>,
+    mut thumbnails: ResMut<SaveSlotThumbnails>,
 ) {
     for event in events.read() {
         let slot_index = match event.slot_index {
@@ -300,6 +305,7 @@ pub fn handle_save_to_slot(
         } else {
             manager.create_slot(event.display_name.clone(), info);
         }
+        thumbnails.capture(slot_index, &grid, clock.day, virtual_pop.total_virtual);
 
         info!(
             "Save slot {} ('{}') prepared at {}",
@@ -310,15 +316,18 @@ pub fn handle_save_to_slot(
     }
 }
 
-/// Handles `DeleteSlotEvent` by removing the slot from the manager
-/// and deleting the save file from disk.
+/// Handles `DeleteSlotEvent` by removing the slot and its thumbnail from
+/// the manager and deleting the save file from disk. On WASM the save crate
+/// removes the slot's IndexedDB entry.
 pub fn handle_delete_slot(
     mut events: EventReader<DeleteSlotEvent>,
     mut manager: ResMut<SaveSlotManager>,
+    mut thumbnails: ResMut<SaveSlotThumbnails>,
 ) {
     for event in events.read() {
         let path = slot_file_path(event.slot_index);
         if manager.delete_slot(event.slot_index) {
+            thumbnails.remove(event.slot_index);
             #[cfg(not(target_arch = "wasm32"))]
             {
                 if let Err(e) = std::fs::remove_file(&path) {
@@ -346,6 +355,7 @@ pub struct SaveSlotsPlugin;
 impl Plugin for SaveSlotsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SaveSlotManager>()
+            .init_resource::<SaveSlotThumbnails>()
             .add_event::<SaveToSlotEvent>()
             .add_event::<LoadFromSlotEvent>()
             .add_event::<DeleteSlotEvent>()
@@ -357,6 +367,7 @@ impl Plugin for SaveSlotsPlugin {
             .world_mut()
             .get_resource_or_insert_with(crate::SaveableRegistry::default);
         registry.register::<SaveSlotManager>();
+        registry.register::<SaveSlotThumbnails>();
     }
 }
 
@@ -12,7 +12,6 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "achievement_tracker",
     "active_disaster",
     "advisor_panel",
-    "audio_settings",
     "autosave_config",
     "battery_storage",
     "biome_grid",
@@ -27,7 +26,6 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "climate_change",
     "city_specializations",
     "coal_power",
-    "colorblind_settings",
     "crime_grid",
     "crime_justice",
     "daycare_eldercare",
@@ -37,6 +35,7 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "day_night_controls",
     "dismissed_advisor_tips",
     "disease_state",
+    "district_history",
     "district_policies",
     "district_map",
     "education_pipeline",
@@ -67,7 +66,6 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "housing_affordability",
     "inclusionary_zoning",
     "input_recorder",
-    "keybindings",
     "land_value",
     "lifecycle_timer",
     "landfill_state",
@@ -145,6 +143,7 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "cultural_prestige",
     "industrial_specializations",
     "save_slot_manager",
+    "save_slot_thumbnails",
     "production_chain",
     "service_dispatch",
     "service_cross_interaction",
@@ -160,6 +159,45 @@ pub const EXPECTED_SAVEABLE_KEYS: &[&str] = &[
     "combined_heat_power",
     "new_game_config",
     "bankruptcy_state",
+    "timelapse_history",
+    "prison_system",
+    "drought_staging",
+    "welfare_staffing",
+    "event_categories",
+    "city_council",
+    "receivership",
+    "land_ownership",
+    "heat_health_plan",
+    "water_rights",
+    "scenario",
+    "game_settings",
+    "seismic_state",
+    "river_state",
+    "hurricane_state",
+    "structural_state",
+    "hazmat_state",
+    "unrest_state",
+    "disaster_insurance",
+    "disaster_recovery",
+    "carbon_ledger",
+    "air_quality",
+    "wildlife",
+    "coastal_erosion",
+    "river_flow",
+    "harvest",
+    "urban_forestry",
+    "urban_farms",
+    "buildability_grid",
+    "map_tiles",
+    "earthworks",
+    "waterways",
+    "resource_grid",
+    "district_styles",
+    "street_lighting",
+    "active_mods",
+    "virtual_cohorts",
+    "hotel_industry",
+    "airport_operations",
 ];
 /// Startup system that validates the `SaveableRegistry` against the expected key
 /// list. Panics if any expected key is missing (indicating a `Saveable` type whose
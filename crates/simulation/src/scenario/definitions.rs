@@ -0,0 +1,186 @@
+//! Scenario definitions: what a scenario file holds — its starting map,
+//! objectives and rewards, locked tools and opening event.
+
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::types::Condition;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::ZoneType;
+use crate::natural_resources::ResourceType;
+use crate::nimby::types::zone_type_name;
+use crate::services::ServiceType;
+use crate::utilities::UtilityType;
+
+fn default_true() -> bool {
+    true
+}
+
+/// Something the player is asked to achieve.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Objective {
+    pub description: String,
+    pub condition: Condition,
+    /// Days from the scenario start by which the condition must be met.
+    #[serde(default)]
+    pub deadline_days: Option<u32>,
+    /// Required objectives must all be met to win, and missing one's
+    /// deadline loses the scenario. Optional ones are bonuses.
+    #[serde(default = "default_true")]
+    pub required: bool,
+    /// Granted the day the objective is completed.
+    #[serde(default)]
+    pub reward: Option<ObjectiveReward>,
+}
+
+/// What completing an objective earns the player.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ObjectiveReward {
+    /// A grant paid into the treasury.
+    Treasury { amount: f64 },
+    /// Gives back a tool the scenario locked.
+    Unlock { tool: ToolLock },
+}
+
+impl ObjectiveReward {
+    /// Short description for reward previews, e.g. "$25000 grant".
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Treasury { amount } => format!("${amount:.0} grant"),
+            Self::Unlock { tool } => format!("Unlocks {}", tool.name()),
+        }
+    }
+}
+
+/// Map the scenario is played on.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StartingMap {
+    /// Procedurally generated terrain; the player's seed unless one is given.
+    Procedural {
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    /// The prebuilt Tel Aviv city.
+    TelAviv,
+}
+
+/// A placement tool the scenario takes away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolLock {
+    Service { service: ServiceType },
+    Utility { utility: UtilityType },
+    Zone { zone: ZoneType },
+}
+
+impl ToolLock {
+    pub fn name(self) -> String {
+        match self {
+            Self::Service { service } => service.name().to_string(),
+            Self::Utility { utility } => utility.name().to_string(),
+            Self::Zone { zone } => format!("{} zoning", zone_type_name(zone)),
+        }
+    }
+}
+
+/// Something that happens the moment the scenario begins.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OpeningEvent {
+    Earthquake { x: usize, y: usize, radius: usize },
+}
+
+/// A natural resource deposit laid down when the scenario begins, as a
+/// disc of `radius` cells around (`x`, `y`).
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct ScenarioDeposit {
+    pub resource: ResourceType,
+    pub x: usize,
+    pub y: usize,
+    pub radius: usize,
+    /// Units per cell; the resource's usual amount when not given.
+    #[serde(default)]
+    pub amount: Option<u32>,
+}
+
+/// A scenario as written in a scenario file.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct ScenarioDefinition {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub map: StartingMap,
+    #[serde(default)]
+    pub starting_treasury: Option<f64>,
+    #[serde(default)]
+    pub locked_tools: Vec<ToolLock>,
+    #[serde(default)]
+    pub opening: Option<OpeningEvent>,
+    /// Deposits added to the starting map, on top of any it generates.
+    #[serde(default)]
+    pub deposits: Vec<ScenarioDeposit>,
+    pub objectives: Vec<Objective>,
+    /// The scenario is lost as soon as any of these holds.
+    #[serde(default)]
+    pub lose_conditions: Vec<Condition>,
+}
+
+impl ScenarioDefinition {
+    /// Check a definition for mistakes a scenario author could make.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("scenario id is empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err(format!("scenario '{}' has no name", self.id));
+        }
+        if !self.objectives.iter().any(|o| o.required) {
+            return Err(format!("scenario '{}' has no required objectives", self.id));
+        }
+        if self.objectives.iter().any(|o| o.deadline_days == Some(0)) {
+            return Err(format!("scenario '{}' has a zero-day deadline", self.id));
+        }
+        for objective in &self.objectives {
+            match &objective.reward {
+                Some(ObjectiveReward::Treasury { amount }) if *amount <= 0.0 => {
+                    return Err(format!("scenario '{}' has an empty grant", self.id));
+                }
+                Some(ObjectiveReward::Unlock { tool }) if !self.locked_tools.contains(tool) => {
+                    return Err(format!(
+                        "scenario '{}' unlocks {}, which it never locked",
+                        self.id,
+                        tool.name()
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if let Some(OpeningEvent::Earthquake { x, y, radius }) = self.opening {
+            if x >= GRID_WIDTH || y >= GRID_HEIGHT || radius == 0 {
+                return Err(format!(
+                    "scenario '{}' has an earthquake off the map",
+                    self.id
+                ));
+            }
+        }
+        for deposit in &self.deposits {
+            if deposit.x >= GRID_WIDTH || deposit.y >= GRID_HEIGHT {
+                return Err(format!(
+                    "scenario '{}' has a {} deposit off the map",
+                    self.id,
+                    deposit.resource.name()
+                ));
+            }
+            if deposit.amount == Some(0) {
+                return Err(format!(
+                    "scenario '{}' has an empty {} deposit",
+                    self.id,
+                    deposit.resource.name()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Reading scenario files and the built-in scenario set.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use super::definitions::ScenarioDefinition;
+
+/// Directory scanned for extra scenario files, relative to the working
+/// directory (next to save files).
+pub const SCENARIO_DIR: &str = "scenarios";
+
+/// Scenarios shipped with the game.
+const BUILTIN_SCENARIOS: [&str; 3] = [
+    include_str!("data/balanced_budget.json"),
+    include_str!("data/earthquake_rebuild.json"),
+    include_str!("data/carbon_neutral.json"),
+];
+
+impl ScenarioDefinition {
+    /// Parse and validate a scenario from JSON.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let scenario: ScenarioDefinition =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Pretty-printed JSON, for authoring new scenarios from existing ones.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load one scenario file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Self::from_json(&json).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+/// The built-in scenarios, in menu order.
+pub fn builtin_scenarios() -> Vec<ScenarioDefinition> {
+    BUILTIN_SCENARIOS
+        .iter()
+        .map(|json| ScenarioDefinition::from_json(json).expect("built-in scenario is valid"))
+        .collect()
+}
+
+/// Load every `.json` file in `dir`, sorted by file name. A missing
+/// directory yields no scenarios; bad files are reported and skipped.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_scenario_dir(dir: &Path) -> (Vec<ScenarioDefinition>, Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (Vec::new(), Vec::new());
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut scenarios = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        match ScenarioDefinition::load(&path) {
+            Ok(scenario) => scenarios.push(scenario),
+            Err(e) => errors.push(e),
+        }
+    }
+    (scenarios, errors)
+}
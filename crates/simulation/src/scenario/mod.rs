@@ -0,0 +1,27 @@
+//! Scenarios: authored challenges with a starting map, win and lose
+//! conditions, timed objectives and locked tools.
+//!
+//! Scenarios are JSON files. Three ship with the game (a balanced budget
+//! within five years, rebuilding Tel Aviv after an earthquake, and a
+//! carbon-neutral city); more can be dropped into `SCENARIO_DIR` and appear
+//! in the new-game menu alongside them. Choosing one sets `PendingScenario`;
+//! the new-game flow builds its starting map and calls `begin_scenario`,
+//! which applies the opening treasury and event and records the scenario in
+//! `ScenarioState` so its progress is saved with the city.
+//!
+//! Objectives are checked daily. Each completes the first day its condition
+//! holds; a required objective still pending at its deadline loses the
+//! scenario, as does any lose condition. The scenario is won once every
+//! required objective is complete. Play can continue either way.
+
+pub mod definitions;
+pub mod io;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use definitions::*;
+pub use io::{builtin_scenarios, SCENARIO_DIR};
+pub use systems::{annual_emissions, begin_scenario, update_scenario, ScenarioPlugin};
+pub use types::*;
@@ -0,0 +1,217 @@
+//! Starting scenarios and tracking their objectives.
+
+use bevy::prelude::*;
+
+use crate::bankruptcy_warning::{BankruptcyLevel, BankruptcyState};
+use crate::buildings::Building;
+use crate::citizen::Citizen;
+use crate::climate_change::{
+    co2_rate_for_utility, BASE_MWH_PER_PLANT, INDUSTRIAL_CO2_PER_BUILDING,
+};
+use crate::disasters::{ActiveDisaster, DisasterInstance, DisasterType, EARTHQUAKE_DURATION};
+use crate::economy::CityBudget;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::natural_resources::{paint_deposits, DepositBrush, ResourceGrid};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::stats::CityStats;
+use crate::time_of_day::GameClock;
+use crate::tree_absorption::TreeCanopyStats;
+use crate::utilities::UtilitySource;
+
+use super::definitions::*;
+use super::io::builtin_scenarios;
+use super::types::*;
+
+/// Pounds in a short ton, for converting tree absorption.
+const LBS_PER_TON: f64 = 2000.0;
+
+/// Net CO2 per year from power plants and industry, less what trees absorb,
+/// using the rates of the yearly climate assessment.
+pub fn annual_emissions(
+    utilities: impl Iterator<Item = crate::utilities::UtilityType>,
+    industrial_buildings: usize,
+    tree_absorption_lbs: f32,
+) -> f64 {
+    let plants: f64 = utilities
+        .map(|u| (co2_rate_for_utility(u) * BASE_MWH_PER_PLANT) as f64)
+        .sum();
+    let industry = industrial_buildings as f64 * INDUSTRIAL_CO2_PER_BUILDING as f64;
+    plants + industry - tree_absorption_lbs as f64 / LBS_PER_TON
+}
+
+/// Set up a freshly generated world for `definition`: opening treasury,
+/// opening event, resource deposits and objectives. Called by the new-game flow once the
+/// starting map exists.
+pub fn begin_scenario(world: &mut World, definition: ScenarioDefinition) {
+    if let Some(treasury) = definition.starting_treasury {
+        world.resource_mut::<CityBudget>().treasury = treasury;
+    }
+    if let Some(OpeningEvent::Earthquake { x, y, radius }) = definition.opening {
+        world.resource_mut::<ActiveDisaster>().current = Some(DisasterInstance {
+            disaster_type: DisasterType::Earthquake,
+            center_x: x,
+            center_y: y,
+            radius,
+            ticks_remaining: EARTHQUAKE_DURATION,
+            damage_applied: false,
+        });
+    }
+    if !definition.deposits.is_empty() {
+        world.resource_scope(|world, grid: Mut<WorldGrid>| {
+            let mut resources = world.resource_mut::<ResourceGrid>();
+            for deposit in &definition.deposits {
+                let amount = deposit
+                    .amount
+                    .unwrap_or_else(|| deposit.resource.default_amount());
+                paint_deposits(
+                    &mut resources,
+                    &grid,
+                    deposit.x,
+                    deposit.y,
+                    deposit.radius as i32,
+                    DepositBrush::Paint(deposit.resource, amount),
+                );
+            }
+        });
+    }
+
+    let population = world
+        .query_filtered::<(), With<Citizen>>()
+        .iter(world)
+        .count() as u32;
+    let day = world.resource::<GameClock>().day;
+    info!("Scenario '{}' started on day {day}", definition.name);
+    world
+        .resource_mut::<ScenarioState>()
+        .start(definition, day, population);
+}
+
+/// Check the active scenario once a day, pay out objective grants, and
+/// announce objectives met or missed, victory and defeat.
+#[allow(clippy::too_many_arguments)]
+pub fn update_scenario(
+    clock: Res<GameClock>,
+    stats: Res<CityStats>,
+    mut budget: ResMut<CityBudget>,
+    bankruptcy: Res<BankruptcyState>,
+    trees: Res<TreeCanopyStats>,
+    utilities: Query<&UtilitySource>,
+    buildings: Query<&Building>,
+    mut state: ResMut<ScenarioState>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !state.is_active() || clock.day == state.last_check_day {
+        return;
+    }
+    state.last_check_day = clock.day;
+
+    let mut balanced_months = state.metrics.balanced_months;
+    if budget.last_collection_day != state.last_budget_day {
+        state.last_budget_day = budget.last_collection_day;
+        let balanced =
+            budget.monthly_income > 0.0 && budget.monthly_income >= budget.monthly_expenses;
+        balanced_months = if balanced { balanced_months + 1 } else { 0 };
+    }
+
+    let industrial = buildings
+        .iter()
+        .filter(|b| b.zone_type == ZoneType::Industrial)
+        .count();
+    let metrics = CityMetrics {
+        population: stats.population,
+        treasury: budget.treasury,
+        happiness: stats.average_happiness,
+        buildings: buildings.iter().count() as u32,
+        annual_emissions: annual_emissions(
+            utilities.iter().map(|u| u.utility_type),
+            industrial,
+            trees.total_co2_absorption_lbs_per_year,
+        ),
+        bankrupt: bankruptcy.level == BankruptcyLevel::Bankrupt,
+        balanced_months,
+        baseline_population: state.metrics.baseline_population,
+    };
+
+    for news in state.evaluate(metrics, clock.day) {
+        let Some(definition) = state.definition.as_ref() else {
+            break;
+        };
+        let (text, priority) = match news {
+            ScenarioNews::ObjectiveComplete(i) => {
+                let objective = &definition.objectives[i];
+                let text = match &objective.reward {
+                    Some(reward) => {
+                        if let ObjectiveReward::Treasury { amount } = reward {
+                            budget.treasury += amount;
+                        }
+                        format!(
+                            "Objective complete: {} ({})",
+                            objective.description,
+                            reward.describe()
+                        )
+                    }
+                    None => format!("Objective complete: {}", objective.description),
+                };
+                (text, NotificationPriority::Positive)
+            }
+            ScenarioNews::ObjectiveFailed(i) => (
+                format!("Objective missed: {}", definition.objectives[i].description),
+                NotificationPriority::Warning,
+            ),
+            ScenarioNews::Won => (
+                format!("Scenario complete: {}!", definition.name),
+                NotificationPriority::Positive,
+            ),
+            ScenarioNews::Lost(reason) => (
+                format!("Scenario failed: {reason}"),
+                NotificationPriority::Emergency,
+            ),
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Achievement,
+            location: None,
+        });
+    }
+}
+
+/// The built-in scenarios plus any valid files in the scenario directory.
+fn initial_library() -> ScenarioLibrary {
+    let mut library = ScenarioLibrary {
+        scenarios: builtin_scenarios(),
+        errors: Vec::new(),
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let (extra, errors) =
+            super::io::load_scenario_dir(std::path::Path::new(super::io::SCENARIO_DIR));
+        for e in &errors {
+            warn!("Ignoring scenario file: {e}");
+        }
+        library.scenarios.extend(extra);
+        library.errors = errors;
+    }
+    library
+}
+
+pub struct ScenarioPlugin;
+
+impl Plugin for ScenarioPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(initial_library())
+            .init_resource::<PendingScenario>()
+            .init_resource::<ScenarioState>()
+            .add_systems(
+                FixedUpdate,
+                update_scenario
+                    .after(crate::stats::update_stats)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<ScenarioState>();
+    }
+}
@@ -0,0 +1,252 @@
+use super::*;
+use crate::config::GRID_WIDTH;
+use crate::natural_resources::ResourceType;
+use crate::utilities::UtilityType;
+use crate::Saveable;
+
+fn objective(condition: Condition, deadline_days: Option<u32>, required: bool) -> Objective {
+    Objective {
+        description: "test".to_string(),
+        condition,
+        deadline_days,
+        required,
+        reward: None,
+    }
+}
+
+fn scenario(objectives: Vec<Objective>) -> ScenarioDefinition {
+    ScenarioDefinition {
+        id: "test".to_string(),
+        name: "Test".to_string(),
+        description: String::new(),
+        map: StartingMap::Procedural { seed: None },
+        starting_treasury: None,
+        locked_tools: Vec::new(),
+        opening: None,
+        deposits: Vec::new(),
+        objectives,
+        lose_conditions: vec![Condition::Bankrupt],
+    }
+}
+
+fn metrics(population: u32) -> CityMetrics {
+    CityMetrics {
+        population,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_builtin_scenarios_parse() {
+    let scenarios = builtin_scenarios();
+    assert_eq!(scenarios.len(), 3);
+    let ids: Vec<_> = scenarios.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["balanced_budget", "earthquake_rebuild", "carbon_neutral"]
+    );
+    assert_eq!(scenarios[1].map, StartingMap::TelAviv);
+    assert!(scenarios[2].locked_tools.contains(&ToolLock::Utility {
+        utility: UtilityType::OilPlant
+    }));
+}
+
+#[test]
+fn test_json_roundtrip_and_validation() {
+    let original = builtin_scenarios().remove(0);
+    let json = original.to_json().unwrap();
+    assert_eq!(ScenarioDefinition::from_json(&json).unwrap(), original);
+
+    let mut no_required = original.clone();
+    for o in &mut no_required.objectives {
+        o.required = false;
+    }
+    assert!(no_required.validate().is_err());
+
+    assert!(ScenarioDefinition::from_json("{ \"id\": \"x\" }").is_err());
+}
+
+#[test]
+fn test_deposits_parse_and_validate() {
+    let mut with_ore = builtin_scenarios().remove(0);
+    with_ore.deposits.push(ScenarioDeposit {
+        resource: ResourceType::Ore,
+        x: 40,
+        y: 60,
+        radius: 3,
+        amount: None,
+    });
+    let json = with_ore.to_json().unwrap();
+    assert_eq!(ScenarioDefinition::from_json(&json).unwrap(), with_ore);
+
+    let mut off_map = with_ore.clone();
+    off_map.deposits[0].x = GRID_WIDTH;
+    assert!(off_map.validate().is_err());
+    let mut empty = with_ore;
+    empty.deposits[0].amount = Some(0);
+    assert!(empty.validate().is_err());
+}
+
+#[test]
+fn test_conditions() {
+    let m = CityMetrics {
+        population: 900,
+        baseline_population: 1000,
+        annual_emissions: -5.0,
+        ..Default::default()
+    };
+    assert!(Condition::PopulationRecovered { share: 0.9 }.is_met(&m));
+    assert!(!Condition::PopulationRecovered { share: 1.0 }.is_met(&m));
+    assert!((Condition::Population { at_least: 1800 }.progress(&m) - 0.5).abs() < 1e-6);
+
+    // Net zero only counts once the city is big enough.
+    let net_zero = Condition::AnnualEmissions {
+        at_most: 0.0,
+        min_population: 1000,
+    };
+    assert!(!net_zero.is_met(&m));
+    assert!(net_zero.is_met(&CityMetrics {
+        population: 1000,
+        ..m.clone()
+    }));
+}
+
+#[test]
+fn test_objectives_complete_and_win() {
+    let mut state = ScenarioState::default();
+    state.start(
+        scenario(vec![
+            objective(Condition::Population { at_least: 100 }, Some(30), true),
+            objective(Condition::Population { at_least: 500 }, None, false),
+        ]),
+        10,
+        0,
+    );
+    assert!(state.evaluate(metrics(50), 11).is_empty());
+    assert_eq!(state.days_left(0, 20), Some(20));
+
+    let news = state.evaluate(metrics(150), 12);
+    assert_eq!(
+        news,
+        vec![ScenarioNews::ObjectiveComplete(0), ScenarioNews::Won]
+    );
+    assert_eq!(state.objectives[0], ObjectiveStatus::Complete { day: 12 });
+    // The optional objective is not needed to win.
+    assert_eq!(state.objectives[1], ObjectiveStatus::Pending);
+    assert_eq!(state.outcome, ScenarioOutcome::Won { day: 12 });
+    assert!(!state.is_active());
+    assert!(state.evaluate(metrics(600), 13).is_empty());
+}
+
+#[test]
+fn test_missed_deadline_loses() {
+    let mut state = ScenarioState::default();
+    state.start(
+        scenario(vec![objective(
+            Condition::Population { at_least: 100 },
+            Some(30),
+            true,
+        )]),
+        0,
+        0,
+    );
+    assert!(state.evaluate(metrics(10), 29).is_empty());
+    let news = state.evaluate(metrics(10), 30);
+    assert_eq!(news[0], ScenarioNews::ObjectiveFailed(0));
+    assert!(matches!(news[1], ScenarioNews::Lost(_)));
+    assert!(matches!(
+        state.outcome,
+        ScenarioOutcome::Lost { day: 30, .. }
+    ));
+}
+
+#[test]
+fn test_lose_condition() {
+    let mut state = ScenarioState::default();
+    state.start(
+        scenario(vec![objective(
+            Condition::Population { at_least: 100 },
+            None,
+            true,
+        )]),
+        0,
+        0,
+    );
+    let news = state.evaluate(
+        CityMetrics {
+            bankrupt: true,
+            ..Default::default()
+        },
+        5,
+    );
+    assert_eq!(
+        news,
+        vec![ScenarioNews::Lost(Condition::Bankrupt.describe())]
+    );
+}
+
+#[test]
+fn test_tool_locks() {
+    let mut state = ScenarioState::default();
+    assert!(!state.locks_utility(UtilityType::OilPlant));
+    let carbon = builtin_scenarios().remove(2);
+    state.start(carbon, 0, 0);
+    assert!(state.locks_utility(UtilityType::OilPlant));
+    assert!(state.locks_utility(UtilityType::GasPlant));
+    assert!(!state.locks_utility(UtilityType::SolarFarm));
+}
+
+#[test]
+fn test_unlock_reward_gives_tool_back() {
+    let oil = ToolLock::Utility {
+        utility: UtilityType::OilPlant,
+    };
+    let mut definition = scenario(vec![objective(
+        Condition::Population { at_least: 100 },
+        None,
+        true,
+    )]);
+    definition.locked_tools.push(oil);
+    definition.objectives[0].reward = Some(ObjectiveReward::Unlock { tool: oil });
+    assert!(definition.validate().is_ok());
+
+    let mut state = ScenarioState::default();
+    state.start(definition.clone(), 0, 0);
+    assert!(state.locks(oil));
+    state.evaluate(metrics(150), 1);
+    assert!(!state.locks(oil));
+
+    // Rewards must give back a tool that was taken away, or real money.
+    definition.locked_tools.clear();
+    assert!(definition.validate().is_err());
+    definition.objectives[0].reward = Some(ObjectiveReward::Treasury { amount: 0.0 });
+    assert!(definition.validate().is_err());
+}
+
+#[test]
+fn test_annual_emissions() {
+    let clean = annual_emissions([UtilityType::SolarFarm].into_iter(), 0, 0.0);
+    assert_eq!(clean, 0.0);
+    let dirty = annual_emissions([UtilityType::GasPlant].into_iter(), 2, 0.0);
+    assert!(dirty > 0.0);
+    // Enough trees offset it.
+    let offset = annual_emissions(
+        [UtilityType::GasPlant].into_iter(),
+        2,
+        dirty as f32 * 2000.0,
+    );
+    assert!(offset.abs() < 1.0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(ScenarioState::default().save_to_bytes().is_none());
+
+    let mut state = ScenarioState::default();
+    state.start(builtin_scenarios().remove(1), 3, 1234);
+    let bytes = state.save_to_bytes().unwrap();
+    let loaded = ScenarioState::load_from_bytes(&bytes);
+    assert_eq!(loaded.definition, state.definition);
+    assert_eq!(loaded.start_day, 3);
+    assert_eq!(loaded.metrics.baseline_population, 1234);
+}
@@ -0,0 +1,359 @@
+//! Scenario conditions and per-save scenario progress. The definitions a
+//! scenario file holds are in `definitions.rs`.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::definitions::{ObjectiveReward, ScenarioDefinition, ToolLock};
+use crate::grid::ZoneType;
+use crate::services::ServiceType;
+use crate::utilities::UtilityType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Conditions
+// ---------------------------------------------------------------------------
+
+/// City figures that scenario conditions are checked against.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct CityMetrics {
+    pub population: u32,
+    pub treasury: f64,
+    pub happiness: f32,
+    pub buildings: u32,
+    /// CO2 the city emits per year at its current build-out, net of what
+    /// its trees absorb (tons).
+    pub annual_emissions: f64,
+    pub bankrupt: bool,
+    /// Consecutive monthly collections with some income, and income at or
+    /// above expenses.
+    pub balanced_months: u32,
+    /// Population when the scenario began.
+    pub baseline_population: u32,
+}
+
+/// A test on the city's state, used for objectives and lose conditions.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Condition {
+    Population {
+        at_least: u32,
+    },
+    /// Population back to a share of what it was when the scenario began.
+    PopulationRecovered {
+        share: f32,
+    },
+    Treasury {
+        at_least: f64,
+    },
+    TreasuryBelow {
+        amount: f64,
+    },
+    /// Income at or above expenses for this many months in a row.
+    BalancedBudget {
+        months: u32,
+    },
+    Happiness {
+        at_least: f32,
+    },
+    Buildings {
+        at_least: u32,
+    },
+    /// Net annual CO2 at or below this many tons, once the city has at
+    /// least `min_population` residents.
+    AnnualEmissions {
+        at_most: f64,
+        #[serde(default)]
+        min_population: u32,
+    },
+    Bankrupt,
+}
+
+impl Condition {
+    pub fn is_met(&self, m: &CityMetrics) -> bool {
+        match *self {
+            Self::Population { at_least } => m.population >= at_least,
+            Self::PopulationRecovered { share } => {
+                m.population as f32 >= m.baseline_population as f32 * share
+            }
+            Self::Treasury { at_least } => m.treasury >= at_least,
+            Self::TreasuryBelow { amount } => m.treasury < amount,
+            Self::BalancedBudget { months } => m.balanced_months >= months,
+            Self::Happiness { at_least } => m.happiness >= at_least,
+            Self::Buildings { at_least } => m.buildings >= at_least,
+            Self::AnnualEmissions {
+                at_most,
+                min_population,
+            } => m.population >= min_population && m.annual_emissions <= at_most,
+            Self::Bankrupt => m.bankrupt,
+        }
+    }
+
+    /// Progress toward the condition (0..1), for objective bars.
+    pub fn progress(&self, m: &CityMetrics) -> f32 {
+        if self.is_met(m) {
+            return 1.0;
+        }
+        let ratio = |current: f64, target: f64| {
+            if target <= 0.0 {
+                0.0
+            } else {
+                (current / target).clamp(0.0, 1.0) as f32
+            }
+        };
+        match *self {
+            Self::Population { at_least } => ratio(m.population as f64, at_least as f64),
+            Self::PopulationRecovered { share } => ratio(
+                m.population as f64,
+                m.baseline_population as f64 * share as f64,
+            ),
+            Self::Treasury { at_least } => ratio(m.treasury, at_least),
+            Self::BalancedBudget { months } => ratio(m.balanced_months as f64, months as f64),
+            Self::Happiness { at_least } => ratio(m.happiness as f64, at_least as f64),
+            Self::Buildings { at_least } => ratio(m.buildings as f64, at_least as f64),
+            Self::AnnualEmissions { .. } | Self::TreasuryBelow { .. } | Self::Bankrupt => 0.0,
+        }
+    }
+
+    /// Current value against the target, e.g. "1,200 / 5,000 residents".
+    pub fn status(&self, m: &CityMetrics) -> String {
+        match *self {
+            Self::Population { at_least } => {
+                format!("{} / {at_least} residents", m.population)
+            }
+            Self::PopulationRecovered { share } => format!(
+                "{} / {:.0} residents",
+                m.population,
+                m.baseline_population as f32 * share
+            ),
+            Self::Treasury { at_least } => format!("${:.0} / ${at_least:.0}", m.treasury),
+            Self::TreasuryBelow { amount } => format!("${:.0} (limit ${amount:.0})", m.treasury),
+            Self::BalancedBudget { months } => {
+                format!("{} / {months} months", m.balanced_months)
+            }
+            Self::Happiness { at_least } => format!("{:.0} / {at_least:.0}", m.happiness),
+            Self::Buildings { at_least } => format!("{} / {at_least} buildings", m.buildings),
+            Self::AnnualEmissions { at_most, .. } => {
+                format!("{:.0} / {at_most:.0} t CO2 a year", m.annual_emissions)
+            }
+            Self::Bankrupt => {
+                let text = if m.bankrupt { "Bankrupt" } else { "Solvent" };
+                text.to_string()
+            }
+        }
+    }
+
+    /// Plain-language statement of the condition, e.g. for lose reasons.
+    pub fn describe(&self) -> String {
+        match *self {
+            Self::Population { at_least } => format!("Population of {at_least}"),
+            Self::PopulationRecovered { share } => {
+                format!("Population back to {:.0}% of its size", share * 100.0)
+            }
+            Self::Treasury { at_least } => format!("Treasury of ${at_least:.0}"),
+            Self::TreasuryBelow { amount } => format!("Treasury fell below ${amount:.0}"),
+            Self::BalancedBudget { months } => format!("Balanced budget for {months} months"),
+            Self::Happiness { at_least } => format!("Happiness of {at_least:.0}"),
+            Self::Buildings { at_least } => format!("{at_least} buildings"),
+            Self::AnnualEmissions {
+                at_most,
+                min_population,
+            } => {
+                if min_population > 0 {
+                    format!(
+                        "Net emissions at most {at_most:.0} t CO2 a year with {min_population} residents"
+                    )
+                } else {
+                    format!("Net emissions at most {at_most:.0} t CO2 a year")
+                }
+            }
+            Self::Bankrupt => "The city went bankrupt".to_string(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Progress
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum ObjectiveStatus {
+    Pending,
+    Complete { day: u32 },
+    Failed { day: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Encode, Decode, Serialize, Deserialize)]
+pub enum ScenarioOutcome {
+    #[default]
+    InProgress,
+    Won {
+        day: u32,
+    },
+    Lost {
+        day: u32,
+        reason: String,
+    },
+}
+
+/// A change in scenario progress worth telling the player about.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScenarioNews {
+    ObjectiveComplete(usize),
+    ObjectiveFailed(usize),
+    Won,
+    Lost(String),
+}
+
+/// The scenario being played in this save, if any.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct ScenarioState {
+    pub definition: Option<ScenarioDefinition>,
+    pub start_day: u32,
+    /// Status of each objective, parallel to `definition.objectives`.
+    pub objectives: Vec<ObjectiveStatus>,
+    pub outcome: ScenarioOutcome,
+    /// Figures from the last evaluation, for the objectives panel.
+    pub metrics: CityMetrics,
+    pub last_budget_day: u32,
+    pub last_check_day: u32,
+    /// Locked tools given back by objective rewards.
+    pub unlocked_tools: Vec<ToolLock>,
+}
+
+impl ScenarioState {
+    pub fn start(&mut self, definition: ScenarioDefinition, day: u32, population: u32) {
+        *self = Self {
+            objectives: vec![ObjectiveStatus::Pending; definition.objectives.len()],
+            definition: Some(definition),
+            start_day: day,
+            last_check_day: day,
+            metrics: CityMetrics {
+                baseline_population: population,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.definition.is_some() && self.outcome == ScenarioOutcome::InProgress
+    }
+
+    pub fn days_elapsed(&self, day: u32) -> u32 {
+        day.saturating_sub(self.start_day)
+    }
+
+    /// Days left before objective `index` is due, if it has a deadline.
+    pub fn days_left(&self, index: usize, day: u32) -> Option<u32> {
+        let objective = self.definition.as_ref()?.objectives.get(index)?;
+        let deadline = objective.deadline_days?;
+        Some(deadline.saturating_sub(self.days_elapsed(day)))
+    }
+
+    /// Whether the scenario takes this tool away.
+    pub fn locks(&self, lock: ToolLock) -> bool {
+        self.definition
+            .as_ref()
+            .is_some_and(|d| d.locked_tools.contains(&lock))
+            && !self.unlocked_tools.contains(&lock)
+    }
+
+    pub fn locks_service(&self, service: ServiceType) -> bool {
+        self.locks(ToolLock::Service { service })
+    }
+
+    pub fn locks_utility(&self, utility: UtilityType) -> bool {
+        self.locks(ToolLock::Utility { utility })
+    }
+
+    pub fn locks_zone(&self, zone: ZoneType) -> bool {
+        self.locks(ToolLock::Zone { zone })
+    }
+
+    /// Check objectives, deadlines and lose conditions against `metrics`.
+    pub fn evaluate(&mut self, metrics: CityMetrics, day: u32) -> Vec<ScenarioNews> {
+        self.metrics = metrics;
+        let mut news = Vec::new();
+        if !self.is_active() {
+            return news;
+        }
+        let Some(definition) = self.definition.as_ref() else {
+            return news;
+        };
+        let elapsed = day.saturating_sub(self.start_day);
+
+        for (i, objective) in definition.objectives.iter().enumerate() {
+            if self.objectives[i] != ObjectiveStatus::Pending {
+                continue;
+            }
+            if objective.condition.is_met(&self.metrics) {
+                self.objectives[i] = ObjectiveStatus::Complete { day };
+                if let Some(ObjectiveReward::Unlock { tool }) = &objective.reward {
+                    self.unlocked_tools.push(*tool);
+                }
+                news.push(ScenarioNews::ObjectiveComplete(i));
+            } else if objective.deadline_days.is_some_and(|d| elapsed >= d) {
+                self.objectives[i] = ObjectiveStatus::Failed { day };
+                news.push(ScenarioNews::ObjectiveFailed(i));
+            }
+        }
+
+        let failed_required = definition
+            .objectives
+            .iter()
+            .zip(&self.objectives)
+            .find(|(o, s)| o.required && matches!(s, ObjectiveStatus::Failed { .. }));
+        let reason = if let Some((objective, _)) = failed_required {
+            Some(format!("Missed: {}", objective.description))
+        } else {
+            definition
+                .lose_conditions
+                .iter()
+                .find(|c| c.is_met(&self.metrics))
+                .map(|c| c.describe())
+        };
+
+        if let Some(reason) = reason {
+            self.outcome = ScenarioOutcome::Lost {
+                day,
+                reason: reason.clone(),
+            };
+            news.push(ScenarioNews::Lost(reason));
+        } else if definition
+            .objectives
+            .iter()
+            .zip(&self.objectives)
+            .all(|(o, s)| !o.required || matches!(s, ObjectiveStatus::Complete { .. }))
+        {
+            self.outcome = ScenarioOutcome::Won { day };
+            news.push(ScenarioNews::Won);
+        }
+        news
+    }
+}
+
+impl Saveable for ScenarioState {
+    const SAVE_KEY: &'static str = "scenario";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        self.definition.as_ref()?;
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Scenario chosen in the new-game menu, consumed when the new game starts.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PendingScenario(pub Option<ScenarioDefinition>);
+
+/// Every scenario the player can choose from: the built-in set followed by
+/// any valid files in `SCENARIO_DIR`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScenarioLibrary {
+    pub scenarios: Vec<ScenarioDefinition>,
+    /// Files that failed to load, with the reason.
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,148 @@
+//! What scripts see and what they can ask for.
+//!
+//! Scripts never touch the ECS world. Before each batch of hooks they get a
+//! `CitySnapshot`, and everything they want changed comes back as
+//! `ScriptCommand`s, which are applied after every script has run.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::disasters::DisasterInstance;
+use crate::economy::CityBudget;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::policies::{Policies, Policy};
+
+/// Largest treasury change a single `adjust_treasury` call may make.
+pub const MAX_TREASURY_ADJUSTMENT: f64 = 1_000_000.0;
+
+/// The city as scripts can read it.
+#[derive(Debug, Clone, Default)]
+pub struct CitySnapshot {
+    pub tick: u64,
+    pub day: u32,
+    pub hour: f32,
+    pub population: u32,
+    pub treasury: f64,
+    pub happiness: f32,
+    /// Names of the active policies (see [`policy_name`]).
+    pub active_policies: Vec<String>,
+}
+
+/// A building as passed to `on_building_spawned`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildingInfo {
+    pub zone: String,
+    pub level: u8,
+    pub x: usize,
+    pub y: usize,
+    pub capacity: u32,
+}
+
+impl From<&Building> for BuildingInfo {
+    fn from(building: &Building) -> Self {
+        Self {
+            zone: format!("{:?}", building.zone_type),
+            level: building.level,
+            x: building.grid_x,
+            y: building.grid_y,
+            capacity: building.capacity,
+        }
+    }
+}
+
+/// A disaster as passed to `on_disaster`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisasterInfo {
+    pub kind: String,
+    pub x: usize,
+    pub y: usize,
+    pub radius: usize,
+}
+
+impl From<&DisasterInstance> for DisasterInfo {
+    fn from(disaster: &DisasterInstance) -> Self {
+        Self {
+            kind: disaster.disaster_type.name().to_string(),
+            x: disaster.center_x,
+            y: disaster.center_y,
+            radius: disaster.radius,
+        }
+    }
+}
+
+/// A call into scripts: which global function and with what.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptHook {
+    /// `on_tick(tick)`, every simulation tick.
+    Tick(u64),
+    /// `on_building_spawned(building)`.
+    BuildingSpawned(BuildingInfo),
+    /// `on_disaster(disaster)`, when a disaster starts.
+    Disaster(DisasterInfo),
+    /// `on_policy_changed(policy, active)`.
+    PolicyChanged { policy: String, active: bool },
+}
+
+impl ScriptHook {
+    /// Name of the global function the hook calls.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            ScriptHook::Tick(_) => "on_tick",
+            ScriptHook::BuildingSpawned(_) => "on_building_spawned",
+            ScriptHook::Disaster(_) => "on_disaster",
+            ScriptHook::PolicyChanged { .. } => "on_policy_changed",
+        }
+    }
+}
+
+/// Something a script asked the game to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptCommand {
+    /// Show a notification, attributed to the script.
+    Notify(String),
+    /// Turn a policy on or off.
+    SetPolicy { policy: Policy, active: bool },
+    /// Add to (or take from) the treasury.
+    AdjustTreasury(f64),
+}
+
+/// The name scripts use for `policy`, e.g. `"RecyclingProgram"`.
+pub fn policy_name(policy: Policy) -> String {
+    format!("{policy:?}")
+}
+
+/// The policy scripts call `name`.
+pub fn policy_from_name(name: &str) -> Option<Policy> {
+    Policy::all()
+        .iter()
+        .copied()
+        .find(|&policy| policy_name(policy) == name)
+}
+
+/// Apply `script`'s `command` to the city.
+pub fn apply_command(
+    script: &str,
+    command: ScriptCommand,
+    policies: &mut Policies,
+    budget: &mut CityBudget,
+    notifications: &mut EventWriter<NotificationEvent>,
+) {
+    match command {
+        ScriptCommand::Notify(text) => {
+            notifications.send(NotificationEvent {
+                text: format!("[{script}] {text}"),
+                priority: NotificationPriority::Info,
+                category: NotificationCategory::General,
+                location: None,
+            });
+        }
+        ScriptCommand::SetPolicy { policy, active } => {
+            if policies.is_active(policy) != active {
+                policies.toggle(policy);
+            }
+        }
+        ScriptCommand::AdjustTreasury(amount) => {
+            budget.treasury += amount.clamp(-MAX_TREASURY_ADJUSTMENT, MAX_TREASURY_ADJUSTMENT);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! The Lua runtime: one sandboxed interpreter per script.
+//!
+//! Each script gets its own `Lua` state with only the `table`, `string` and
+//! `math` libraries and the base functions that can't reach the file system,
+//! a memory cap, and an instruction budget per call, so a broken or hostile
+//! script can fail or hang only itself. `pcall` and `xpcall` are wrapped so
+//! a script can't catch the budget error and keep running. Errors are counted per script, and a
+//! script that keeps failing is switched off.
+
+use std::cell::{Cell, RefCell};
+use std::path::Path;
+use std::rc::Rc;
+
+use mlua::{Function, HookTriggers, IntoLuaMulti, Lua, LuaOptions, StdLib, Table, VmState};
+
+use super::api::{
+    policy_from_name, BuildingInfo, CitySnapshot, DisasterInfo, ScriptCommand, ScriptHook,
+};
+
+/// Memory each script's interpreter may use.
+pub const MEMORY_LIMIT: usize = 16 * 1024 * 1024;
+/// Lua instructions a single hook call (or loading a script) may run.
+pub const INSTRUCTION_BUDGET: u32 = 1_000_000;
+/// Errors after which a script is switched off.
+pub const MAX_SCRIPT_ERRORS: u32 = 3;
+
+/// How often the budget hook runs, in instructions.
+const HOOK_INTERVAL: u32 = 1_000;
+
+/// Base-library functions removed from every script's globals: they read
+/// files or load precompiled chunks.
+const BLOCKED_GLOBALS: [&str; 4] = ["dofile", "loadfile", "load", "collectgarbage"];
+
+/// Error raised once a call has spent its instruction budget.
+const BUDGET_EXCEEDED: &str = "instruction budget exceeded";
+
+/// Replaces `pcall` and `xpcall` with versions that raise a caught error
+/// again once the budget is spent, so the budget error always reaches the
+/// host. Called with the host's "budget spent" query.
+const PROTECTED_CALL_GUARD: &str = r#"
+local exhausted = ...
+local raw_pcall, raw_xpcall, raise = pcall, xpcall, error
+local function rethrow(ok, ...)
+    if not ok and exhausted() then
+        raise((...), 0)
+    end
+    return ok, ...
+end
+pcall = function(f, ...)
+    return rethrow(raw_pcall(f, ...))
+end
+xpcall = function(f, handler, ...)
+    return rethrow(raw_xpcall(f, handler, ...))
+end
+"#;
+
+/// State shared between a script's interpreter and the host.
+#[derive(Default)]
+struct Shared {
+    snapshot: RefCell<CitySnapshot>,
+    commands: RefCell<Vec<ScriptCommand>>,
+    instructions: Cell<u32>,
+}
+
+impl Shared {
+    fn budget_exhausted(&self) -> bool {
+        self.instructions.get() > INSTRUCTION_BUDGET
+    }
+}
+
+/// One loaded script.
+pub struct Script {
+    pub name: String,
+    lua: Lua,
+    shared: Rc<Shared>,
+    pub errors: u32,
+    pub disabled: bool,
+}
+
+impl Script {
+    /// Create a sandbox for `name` and run `source` in it, which defines the
+    /// script's hook functions.
+    pub fn load(name: &str, source: &str) -> Result<Self, String> {
+        let script = Self::sandbox(name).map_err(|e| format!("{name}: {e}"))?;
+        script.shared.instructions.set(0);
+        script
+            .lua
+            .load(source)
+            .set_name(name)
+            .exec()
+            .map_err(|e| format!("{name}: {e}"))?;
+        if script.shared.budget_exhausted() {
+            return Err(format!("{name}: {BUDGET_EXCEEDED}"));
+        }
+        Ok(script)
+    }
+
+    fn sandbox(name: &str) -> mlua::Result<Self> {
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH,
+            LuaOptions::default(),
+        )?;
+        lua.set_memory_limit(MEMORY_LIMIT)?;
+        let globals = lua.globals();
+        for blocked in BLOCKED_GLOBALS {
+            globals.set(blocked, mlua::Nil)?;
+        }
+
+        let shared = Rc::new(Shared::default());
+        let budget = Rc::clone(&shared);
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(HOOK_INTERVAL),
+            move |_, _| {
+                // Keeps failing on every later hook too, so a script that
+                // catches the error runs out again within the interval.
+                let used = budget.instructions.get().saturating_add(HOOK_INTERVAL);
+                budget.instructions.set(used);
+                if budget.budget_exhausted() {
+                    return Err(mlua::Error::RuntimeError(BUDGET_EXCEEDED.to_string()));
+                }
+                Ok(VmState::Continue)
+            },
+        );
+        let exhausted = Rc::clone(&shared);
+        let exhausted = lua.create_function(move |_, ()| Ok(exhausted.budget_exhausted()))?;
+        lua.load(PROTECTED_CALL_GUARD)
+            .set_name("sandbox")
+            .call::<()>(exhausted)?;
+        globals.set("city", city_api(&lua, &shared, name)?)?;
+        Ok(Self {
+            name: name.to_string(),
+            lua,
+            shared,
+            errors: 0,
+            disabled: false,
+        })
+    }
+
+    /// Call the script's function for `hook`, if it defines one. Commands it
+    /// issues are appended to `commands`.
+    pub fn call(
+        &mut self,
+        hook: &ScriptHook,
+        snapshot: &CitySnapshot,
+        commands: &mut Vec<ScriptCommand>,
+    ) -> Result<(), String> {
+        let function: Option<Function> = self
+            .lua
+            .globals()
+            .get(hook.function_name())
+            .map_err(|e| e.to_string())?;
+        let Some(function) = function else {
+            return Ok(());
+        };
+        *self.shared.snapshot.borrow_mut() = snapshot.clone();
+        self.shared.instructions.set(0);
+        let result = self
+            .hook_args(hook)
+            .and_then(|args| function.call::<()>(args));
+        // Commands issued before an error still count: a script that
+        // notified and then failed has still notified.
+        commands.append(&mut self.shared.commands.borrow_mut());
+        if self.shared.budget_exhausted() {
+            return Err(BUDGET_EXCEEDED.to_string());
+        }
+        result.map_err(|e| e.to_string())
+    }
+
+    fn hook_args(&self, hook: &ScriptHook) -> mlua::Result<mlua::MultiValue> {
+        let lua = &self.lua;
+        match hook {
+            ScriptHook::Tick(tick) => tick.into_lua_multi(lua),
+            ScriptHook::BuildingSpawned(building) => {
+                building_table(lua, building)?.into_lua_multi(lua)
+            }
+            ScriptHook::Disaster(disaster) => disaster_table(lua, disaster)?.into_lua_multi(lua),
+            ScriptHook::PolicyChanged { policy, active } => {
+                (policy.as_str(), *active).into_lua_multi(lua)
+            }
+        }
+    }
+}
+
+fn building_table(lua: &Lua, building: &BuildingInfo) -> mlua::Result<Table> {
+    let table = lua.create_table()?;
+    table.set("zone", building.zone.as_str())?;
+    table.set("level", building.level)?;
+    table.set("x", building.x)?;
+    table.set("y", building.y)?;
+    table.set("capacity", building.capacity)?;
+    Ok(table)
+}
+
+fn disaster_table(lua: &Lua, disaster: &DisasterInfo) -> mlua::Result<Table> {
+    let table = lua.create_table()?;
+    table.set("kind", disaster.kind.as_str())?;
+    table.set("x", disaster.x)?;
+    table.set("y", disaster.y)?;
+    table.set("radius", disaster.radius)?;
+    Ok(table)
+}
+
+/// The `city` table: queries read the snapshot, commands queue
+/// [`ScriptCommand`]s.
+fn city_api(lua: &Lua, shared: &Rc<Shared>, script: &str) -> mlua::Result<Table> {
+    let city = lua.create_table()?;
+
+    macro_rules! query {
+        ($name:literal, |$snapshot:ident| $value:expr) => {{
+            let shared = Rc::clone(shared);
+            city.set(
+                $name,
+                lua.create_function(move |_, ()| {
+                    let $snapshot = shared.snapshot.borrow();
+                    Ok($value)
+                })?,
+            )?;
+        }};
+    }
+    query!("tick", |s| s.tick);
+    query!("day", |s| s.day);
+    query!("hour", |s| s.hour);
+    query!("population", |s| s.population);
+    query!("treasury", |s| s.treasury);
+    query!("happiness", |s| s.happiness);
+    query!("policies", |s| s.active_policies.clone());
+
+    let queue = |shared: &Rc<Shared>| {
+        let shared = Rc::clone(shared);
+        move |command: ScriptCommand| shared.commands.borrow_mut().push(command)
+    };
+
+    let shared_snapshot = Rc::clone(shared);
+    city.set(
+        "policy_active",
+        lua.create_function(move |_, name: String| {
+            Ok(shared_snapshot
+                .snapshot
+                .borrow()
+                .active_policies
+                .contains(&name))
+        })?,
+    )?;
+
+    let push = queue(shared);
+    city.set(
+        "notify",
+        lua.create_function(move |_, text: String| {
+            push(ScriptCommand::Notify(text));
+            Ok(())
+        })?,
+    )?;
+
+    let push = queue(shared);
+    city.set(
+        "set_policy",
+        lua.create_function(move |_, (name, active): (String, bool)| {
+            let policy = policy_from_name(&name)
+                .ok_or_else(|| mlua::Error::RuntimeError(format!("unknown policy '{name}'")))?;
+            push(ScriptCommand::SetPolicy { policy, active });
+            Ok(())
+        })?,
+    )?;
+
+    let push = queue(shared);
+    city.set(
+        "adjust_treasury",
+        lua.create_function(move |_, amount: f64| {
+            if !amount.is_finite() {
+                return Err(mlua::Error::RuntimeError(
+                    "amount must be a finite number".to_string(),
+                ));
+            }
+            push(ScriptCommand::AdjustTreasury(amount));
+            Ok(())
+        })?,
+    )?;
+
+    let name = script.to_string();
+    city.set(
+        "log",
+        lua.create_function(move |_, text: String| {
+            bevy::log::info!("[script {name}] {text}");
+            Ok(())
+        })?,
+    )?;
+
+    Ok(city)
+}
+
+/// Load every `*.lua` file in `dir`, in file name order. Scripts that fail
+/// to load are reported in the returned errors and left out.
+pub fn load_scripts_dir(dir: &Path) -> (Vec<Script>, Vec<String>) {
+    let mut paths: Vec<_> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "lua"))
+            .collect(),
+        Err(_) => return (Vec::new(), Vec::new()),
+    };
+    paths.sort();
+
+    let mut scripts = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let loaded = std::fs::read_to_string(&path)
+            .map_err(|e| format!("{name}: failed to read {}: {e}", path.display()))
+            .and_then(|source| Script::load(&name, &source));
+        match loaded {
+            Ok(script) => scripts.push(script),
+            Err(e) => errors.push(e),
+        }
+    }
+    (scripts, errors)
+}
@@ -0,0 +1,211 @@
+//! Lua scripting for gameplay mods.
+//!
+//! Every `*.lua` file in `assets/mods/scripts/`, then in the `scripts/`
+//! folder of each loaded mod package in load order (see `crate::mods`), is
+//! loaded at startup into its own sandbox (see `host`). A script defines any
+//! of these global functions, which the game calls as things happen:
+//!
+//! ```lua
+//! function on_tick(tick) end
+//! function on_building_spawned(building) end   -- zone, level, x, y, capacity
+//! function on_disaster(disaster) end           -- kind, x, y, radius
+//! function on_policy_changed(policy, active) end
+//! ```
+//!
+//! and uses the `city` table to look at the city (`city.tick()`,
+//! `city.population()`, `city.treasury()`, `city.policy_active(name)`, ...)
+//! and to act on it (`city.notify(text)`, `city.set_policy(name, on)`,
+//! `city.adjust_treasury(amount)`, `city.log(text)`). Scripts only ever see a
+//! snapshot of the city; their commands are applied after all scripts have
+//! run, in script order.
+//!
+//! A script error is logged and counted against that script only; after
+//! `MAX_SCRIPT_ERRORS` the script is switched off and the player is told.
+//! Scripting is native-only: the Lua runtime is C and isn't built for WASM.
+
+pub mod api;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod host;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests;
+
+pub use api::{CitySnapshot, ScriptCommand, ScriptHook};
+
+use bevy::prelude::*;
+
+/// Folder scripts are loaded from, relative to the working directory.
+pub const SCRIPTS_DIR: &str = "assets/mods/scripts";
+/// Folder of a mod package that holds its scripts.
+pub const MOD_SCRIPTS_DIR: &str = "scripts";
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use bevy::prelude::*;
+
+    use super::api::{apply_command, policy_name, BuildingInfo, DisasterInfo};
+    use super::host::{Script, MAX_SCRIPT_ERRORS};
+    use super::{CitySnapshot, ScriptHook};
+    use crate::buildings::Building;
+    use crate::disasters::ActiveDisaster;
+    use crate::economy::CityBudget;
+    use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+    use crate::policies::{Policies, Policy};
+    use crate::stats::CityStats;
+    use crate::time_of_day::GameClock;
+    use crate::TickCounter;
+
+    /// The loaded scripts. A non-send resource: Lua interpreters stay on the
+    /// main thread.
+    #[derive(Default)]
+    pub struct ScriptHost {
+        pub scripts: Vec<Script>,
+        /// Policies active when the scripts last ran, to spot changes. `None`
+        /// until the first run, so a city's starting policies aren't reported.
+        last_policies: Option<Vec<Policy>>,
+        /// Whether a disaster was under way when the scripts last ran.
+        had_disaster: bool,
+    }
+
+    impl ScriptHost {
+        pub fn new(scripts: Vec<Script>) -> Self {
+            Self {
+                scripts,
+                ..default()
+            }
+        }
+    }
+
+    /// System: call the scripts' hooks for this tick and apply what they ask.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_scripts(
+        mut host: NonSendMut<ScriptHost>,
+        tick: Res<TickCounter>,
+        clock: Res<GameClock>,
+        stats: Res<CityStats>,
+        disaster: Res<ActiveDisaster>,
+        new_buildings: Query<&Building, Added<Building>>,
+        mut policies: ResMut<Policies>,
+        mut budget: ResMut<CityBudget>,
+        mut notifications: EventWriter<NotificationEvent>,
+    ) {
+        if host.scripts.iter().all(|script| script.disabled) {
+            return;
+        }
+
+        let mut hooks = vec![ScriptHook::Tick(tick.0)];
+        hooks.extend(
+            new_buildings
+                .iter()
+                .map(|b| ScriptHook::BuildingSpawned(BuildingInfo::from(b))),
+        );
+        if let Some(current) = &disaster.current {
+            if !host.had_disaster {
+                hooks.push(ScriptHook::Disaster(DisasterInfo::from(current)));
+            }
+        }
+        host.had_disaster = disaster.current.is_some();
+        if let Some(last) = host
+            .last_policies
+            .as_ref()
+            .filter(|last| **last != policies.active)
+        {
+            for &policy in Policy::all() {
+                let active = policies.is_active(policy);
+                if active != last.contains(&policy) {
+                    hooks.push(ScriptHook::PolicyChanged {
+                        policy: policy_name(policy),
+                        active,
+                    });
+                }
+            }
+        }
+        host.last_policies = Some(policies.active.clone());
+
+        let snapshot = CitySnapshot {
+            tick: tick.0,
+            day: clock.day,
+            hour: clock.hour,
+            population: stats.population,
+            treasury: budget.treasury,
+            happiness: stats.average_happiness,
+            active_policies: policies.active.iter().map(|&p| policy_name(p)).collect(),
+        };
+
+        for script in host.scripts.iter_mut().filter(|s| !s.disabled) {
+            let mut commands = Vec::new();
+            for hook in &hooks {
+                let Err(e) = script.call(hook, &snapshot, &mut commands) else {
+                    continue;
+                };
+                warn!(
+                    "Script '{}' failed in {}: {e}",
+                    script.name,
+                    hook.function_name()
+                );
+                script.errors += 1;
+                if script.errors >= MAX_SCRIPT_ERRORS {
+                    script.disabled = true;
+                    notifications.send(NotificationEvent {
+                        text: format!(
+                            "Mod script '{}' was disabled after repeated errors",
+                            script.name
+                        ),
+                        priority: NotificationPriority::Warning,
+                        category: NotificationCategory::General,
+                        location: None,
+                    });
+                    break;
+                }
+            }
+            for command in commands {
+                apply_command(
+                    &script.name,
+                    command,
+                    &mut policies,
+                    &mut budget,
+                    &mut notifications,
+                );
+            }
+        }
+    }
+}
+
+pub struct ScriptingPlugin;
+
+impl Plugin for ScriptingPlugin {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build(&self, app: &mut App) {
+        let (mut scripts, mut errors) = host::load_scripts_dir(std::path::Path::new(SCRIPTS_DIR));
+        if let Some(mods) = app.world().get_resource::<crate::mods::ModManager>() {
+            for installed in mods.loaded() {
+                let id = &installed.manifest.id;
+                let (mod_scripts, mod_errors) =
+                    host::load_scripts_dir(&installed.dir.join(MOD_SCRIPTS_DIR));
+                scripts.extend(mod_scripts.into_iter().map(|mut script| {
+                    script.name = format!("{id}/{}", script.name);
+                    script
+                }));
+                errors.extend(mod_errors.into_iter().map(|e| format!("{id}/{e}")));
+            }
+        }
+        for error in &errors {
+            warn!("Mod script not loaded: {error}");
+        }
+        if !scripts.is_empty() {
+            info!("Loaded {} mod script(s)", scripts.len());
+        }
+        app.insert_non_send_resource(ScriptHost::new(scripts))
+            .add_systems(
+                FixedUpdate,
+                run_scripts
+                    .after(crate::tick_slow_timer)
+                    .in_set(crate::SimulationSet::PreSim),
+            );
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn build(&self, _app: &mut App) {}
+}
@@ -0,0 +1,185 @@
+use super::api::{policy_name, MAX_TREASURY_ADJUSTMENT};
+use super::host::{Script, MAX_SCRIPT_ERRORS};
+use super::*;
+use crate::economy::CityBudget;
+use crate::policies::{Policies, Policy};
+use crate::test_harness::TestCity;
+
+fn snapshot() -> CitySnapshot {
+    CitySnapshot {
+        tick: 42,
+        population: 1_000,
+        treasury: 5_000.0,
+        active_policies: vec![policy_name(Policy::RecyclingProgram)],
+        ..Default::default()
+    }
+}
+
+fn run(source: &str, hook: ScriptHook) -> (Result<(), String>, Vec<ScriptCommand>) {
+    let mut script = Script::load("test", source).unwrap();
+    let mut commands = Vec::new();
+    let result = script.call(&hook, &snapshot(), &mut commands);
+    (result, commands)
+}
+
+#[test]
+fn test_hooks_read_snapshot_and_queue_commands() {
+    let source = r#"
+        function on_tick(tick)
+            if tick == city.tick() and city.policy_active("RecyclingProgram") then
+                city.notify("population " .. city.population())
+                city.set_policy("RecyclingProgram", false)
+                city.adjust_treasury(-250)
+            end
+        end
+    "#;
+    let (result, commands) = run(source, ScriptHook::Tick(42));
+    result.unwrap();
+    assert_eq!(
+        commands,
+        vec![
+            ScriptCommand::Notify("population 1000".to_string()),
+            ScriptCommand::SetPolicy {
+                policy: Policy::RecyclingProgram,
+                active: false
+            },
+            ScriptCommand::AdjustTreasury(-250.0),
+        ]
+    );
+}
+
+#[test]
+fn test_missing_hook_is_a_no_op() {
+    let (result, commands) = run("x = 1", ScriptHook::Tick(1));
+    assert!(result.is_ok());
+    assert!(commands.is_empty());
+}
+
+#[test]
+fn test_sandbox_has_no_io() {
+    let source = r#"
+        function on_tick()
+            city.notify(tostring(io) .. tostring(os) .. tostring(dofile)
+                .. tostring(load) .. tostring(require))
+        end
+    "#;
+    let (result, commands) = run(source, ScriptHook::Tick(1));
+    result.unwrap();
+    assert_eq!(
+        commands,
+        vec![ScriptCommand::Notify("nilnilnilnilnil".to_string())]
+    );
+}
+
+#[test]
+fn test_runaway_script_hits_instruction_budget() {
+    let (result, _) = run(
+        "function on_tick() while true do end end",
+        ScriptHook::Tick(1),
+    );
+    let error = result.unwrap_err();
+    assert!(error.contains("instruction budget"), "got: {error}");
+}
+
+#[test]
+fn test_runaway_script_cannot_catch_the_budget_error() {
+    let catch_with_xpcall = r#"
+        function on_tick()
+            while true do
+                xpcall(function() while true do end end, tostring)
+            end
+        end
+    "#;
+    for source in [
+        "function on_tick() while true do pcall(function() while true do end end) end end",
+        catch_with_xpcall,
+    ] {
+        let (result, _) = run(source, ScriptHook::Tick(1));
+        let error = result.unwrap_err();
+        assert!(error.contains("instruction budget"), "got: {error}");
+    }
+}
+
+#[test]
+fn test_pcall_still_catches_ordinary_errors() {
+    let source = r#"
+        function on_tick()
+            local ok = pcall(error, "boom")
+            local handled = select(2, xpcall(error, function() return "handled" end, "boom"))
+            city.notify(tostring(ok) .. " " .. handled)
+        end
+    "#;
+    let (result, commands) = run(source, ScriptHook::Tick(1));
+    result.unwrap();
+    assert_eq!(
+        commands,
+        vec![ScriptCommand::Notify("false handled".to_string())]
+    );
+}
+
+#[test]
+fn test_errors_keep_commands_issued_before_them() {
+    let source = r#"
+        function on_policy_changed(policy, active)
+            city.notify(policy .. " " .. tostring(active))
+            city.set_policy("NoSuchPolicy", true)
+        end
+    "#;
+    let hook = ScriptHook::PolicyChanged {
+        policy: "RecyclingProgram".to_string(),
+        active: true,
+    };
+    let (result, commands) = run(source, hook);
+    assert!(result
+        .unwrap_err()
+        .contains("unknown policy 'NoSuchPolicy'"));
+    assert_eq!(
+        commands,
+        vec![ScriptCommand::Notify("RecyclingProgram true".to_string())]
+    );
+}
+
+#[test]
+fn test_load_error_is_reported() {
+    let error = Script::load("broken", "function on_tick(").err().unwrap();
+    assert!(error.starts_with("broken:"), "got: {error}");
+}
+
+fn city_with_script(source: &str) -> TestCity {
+    let mut city = TestCity::new().with_budget(10_000.0);
+    let script = Script::load("mod", source).unwrap();
+    city.world_mut()
+        .insert_non_send_resource(ScriptHost::new(vec![script]));
+    city
+}
+
+#[test]
+fn test_scripts_change_the_city() {
+    let source = r#"
+        function on_tick(tick)
+            if not city.policy_active("RecyclingProgram") then
+                city.set_policy("RecyclingProgram", true)
+                city.adjust_treasury(1e12)
+            end
+        end
+    "#;
+    let mut city = city_with_script(source);
+    city.tick(1);
+    assert!(city
+        .resource::<Policies>()
+        .is_active(Policy::RecyclingProgram));
+    let treasury = city.resource::<CityBudget>().treasury;
+    assert!(
+        treasury <= 10_000.0 + MAX_TREASURY_ADJUSTMENT,
+        "adjustment not clamped: {treasury}"
+    );
+}
+
+#[test]
+fn test_failing_script_is_disabled() {
+    let mut city = city_with_script("function on_tick() error('boom') end");
+    city.tick(MAX_SCRIPT_ERRORS + 2);
+    let host = city.world_mut().non_send_resource::<ScriptHost>();
+    assert!(host.scripts[0].disabled);
+    assert_eq!(host.scripts[0].errors, MAX_SCRIPT_ERRORS);
+}
@@ -165,7 +165,7 @@ pub fn should_trigger_lightning(weather: &Weather, tick_hash: u32, enabled: bool
 
 /// Count tree cells in the grid (for leaf particle source locations).
 pub fn count_tree_cells(tree_grid: &TreeGrid) -> u32 {
-    tree_grid.cells.iter().filter(|&&has_tree| has_tree).count() as u32
+    tree_grid.tree_count() as u32
 }
 
 /// Count building cells in the grid (for snow roof rendering).
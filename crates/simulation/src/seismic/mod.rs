@@ -0,0 +1,25 @@
+//! Earthquake fault lines, ground shaking and the seismic retrofit program.
+//!
+//! Each map has a few long fault lines laid out from the terrain seed, and
+//! random earthquakes strike on or near them. Magnitude sets the felt radius
+//! (doubling per step) and the peak shaking; shaking falls off with distance
+//! from the epicenter and is amplified by the ground a building stands on:
+//! soft sediment more than stiff soil or rock, saturated lowlands and ridge
+//! tops more than flat ground. Each building's damage follows from the
+//! shaking it feels: no damage, one or two levels lost, or collapse.
+//!
+//! The Seismic Retrofit policy strengthens buildings a few at a time, most
+//! exposed first. Each retrofit is paid in monthly installments over a year;
+//! retrofitted buildings take more shaking before they are damaged and are
+//! far less likely to collapse.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    ensure_fault_lines, fault_epicenter, random_magnitude, run_retrofit_program, shaking_at,
+    SeismicPlugin,
+};
+pub use types::*;
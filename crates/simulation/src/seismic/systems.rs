@@ -0,0 +1,154 @@
+//! Fault generation, earthquake epicenters and the retrofit program.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::economy::CityBudget;
+use crate::grid::WorldGrid;
+use crate::policies::{Policies, Policy};
+use crate::terrain_generation::{BiomeGrid, TerrainConfig};
+use crate::time_of_day::GameClock;
+
+use super::types::*;
+
+/// Random magnitude for an earthquake from a uniform `roll` in [0, 1).
+/// Small earthquakes are much more common than large ones.
+pub fn random_magnitude(roll: f32) -> f32 {
+    MIN_MAGNITUDE + (MAX_MAGNITUDE - MIN_MAGNITUDE) * roll * roll
+}
+
+/// Epicenter on one of the faults: `pick` chooses the fault, `t` the point
+/// along it, and `jitter_x`/`jitter_y` (each in [0, 1)) offset it by up to
+/// a few cells. `None` when the map has no faults.
+pub fn fault_epicenter(
+    faults: &[FaultLine],
+    pick: usize,
+    t: f32,
+    jitter_x: f32,
+    jitter_y: f32,
+) -> Option<(usize, usize)> {
+    if faults.is_empty() {
+        return None;
+    }
+    let (x, y) = faults[pick % faults.len()].point_at(t);
+    let x = (x + (jitter_x - 0.5) * 6.0).clamp(0.0, (GRID_WIDTH - 1) as f32);
+    let y = (y + (jitter_y - 0.5) * 6.0).clamp(0.0, (GRID_HEIGHT - 1) as f32);
+    Some((x as usize, y as usize))
+}
+
+/// Shaking felt by a building at (`x`, `y`) from an earthquake centered at
+/// (`cx`, `cy`) with the given felt radius.
+pub fn shaking_at(
+    grid: &WorldGrid,
+    biomes: &BiomeGrid,
+    (cx, cy): (usize, usize),
+    radius: usize,
+    (x, y): (usize, usize),
+) -> f32 {
+    let dx = x as f32 - cx as f32;
+    let dy = y as f32 - cy as f32;
+    let distance = (dx * dx + dy * dy).sqrt();
+    let soil = SoilClass::from_biome(biomes.get(x, y));
+    bedrock_shaking(magnitude_for_radius(radius), distance, radius)
+        * site_amplification(soil, grid.get(x, y).elevation)
+}
+
+/// Lay out fault lines from the terrain seed the first time they're needed.
+pub fn ensure_fault_lines(terrain: Res<TerrainConfig>, mut state: ResMut<SeismicState>) {
+    if state.faults.is_empty() {
+        state.faults = generate_faults(terrain.seed);
+    }
+}
+
+/// Once a month: pay installments on retrofits in progress, finish those
+/// that are done, and while the Seismic Retrofit policy is active start new
+/// ones on the most exposed buildings. Installments that the treasury can't
+/// cover are skipped and the work waits for the next month.
+pub fn run_retrofit_program(
+    clock: Res<GameClock>,
+    policies: Res<Policies>,
+    grid: Res<WorldGrid>,
+    biomes: Res<BiomeGrid>,
+    buildings: Query<&Building>,
+    mut state: ResMut<SeismicState>,
+    mut budget: ResMut<CityBudget>,
+) {
+    if clock.day < state.last_installment_day + RETROFIT_PERIOD_DAYS {
+        return;
+    }
+    state.last_installment_day = clock.day;
+
+    // Drop retrofits whose building is gone.
+    let standing = |cell: u32| {
+        let (x, y) = cell_coords(cell);
+        grid.get(x, y).building_id.is_some()
+    };
+    state.retrofitted.retain(|&cell| standing(cell));
+    state.projects.retain(|p| standing(p.cell));
+
+    let active = policies.is_active(Policy::SeismicRetrofit);
+    if active {
+        // Most exposed first: strongest site amplification, then tallest.
+        let mut candidates: Vec<(f32, u8, usize, usize)> = buildings
+            .iter()
+            .filter(|b| {
+                !state.is_retrofitted(b.grid_x, b.grid_y)
+                    && !state.is_retrofitting(b.grid_x, b.grid_y)
+            })
+            .map(|b| {
+                let soil = SoilClass::from_biome(biomes.get(b.grid_x, b.grid_y));
+                let site = site_amplification(soil, grid.get(b.grid_x, b.grid_y).elevation);
+                (site, b.level, b.grid_x, b.grid_y)
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)));
+        for &(_, level, x, y) in candidates.iter().take(RETROFIT_STARTS_PER_MONTH) {
+            state.projects.push(RetrofitProject {
+                cell: cell_index(x, y),
+                months_done: 0,
+                monthly_cost: RETROFIT_COST_PER_LEVEL * level as f64 / RETROFIT_MONTHS as f64,
+            });
+        }
+    }
+
+    let mut finished = Vec::new();
+    let mut spent = 0.0;
+    for project in state.projects.iter_mut() {
+        if budget.treasury < project.monthly_cost {
+            continue;
+        }
+        budget.treasury -= project.monthly_cost;
+        spent += project.monthly_cost;
+        project.months_done += 1;
+        if project.months_done >= RETROFIT_MONTHS {
+            finished.push(project.cell);
+        }
+    }
+    state.total_spent += spent;
+    state.projects.retain(|p| p.months_done < RETROFIT_MONTHS);
+    for cell in finished {
+        let (x, y) = cell_coords(cell);
+        state.mark_retrofitted(x, y);
+    }
+}
+
+pub struct SeismicPlugin;
+
+impl Plugin for SeismicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeismicState>().add_systems(
+            FixedUpdate,
+            (
+                ensure_fault_lines.before(crate::disasters::trigger_random_disaster),
+                run_retrofit_program,
+            )
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<SeismicState>();
+    }
+}
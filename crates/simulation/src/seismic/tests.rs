@@ -0,0 +1,133 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+#[test]
+fn test_felt_radius_doubles_per_magnitude() {
+    assert_eq!(felt_radius(REFERENCE_MAGNITUDE), 10);
+    assert_eq!(felt_radius(REFERENCE_MAGNITUDE + 1.0), 20);
+    assert_eq!(felt_radius(REFERENCE_MAGNITUDE - 1.0), 5);
+    for radius in [5, 10, 20] {
+        assert_eq!(felt_radius(magnitude_for_radius(radius)), radius);
+    }
+}
+
+#[test]
+fn test_random_magnitude_in_range_and_skewed_small() {
+    assert_eq!(random_magnitude(0.0), MIN_MAGNITUDE);
+    assert!(random_magnitude(0.999) <= MAX_MAGNITUDE);
+    // Half the rolls land in the bottom quarter of the range.
+    assert!(random_magnitude(0.5) < MIN_MAGNITUDE + 0.26 * (MAX_MAGNITUDE - MIN_MAGNITUDE));
+}
+
+#[test]
+fn test_shaking_attenuates_with_distance() {
+    let m = REFERENCE_MAGNITUDE;
+    assert!((bedrock_shaking(m, 0.0, 10) - 1.0).abs() < 1e-6);
+    assert!((bedrock_shaking(m, 10.0, 10) - 0.5).abs() < 1e-6);
+    assert!(bedrock_shaking(m, 3.0, 10) > bedrock_shaking(m, 6.0, 10));
+    assert!(bedrock_shaking(m + 1.0, 0.0, 20) > bedrock_shaking(m, 0.0, 10));
+}
+
+#[test]
+fn test_soft_ground_and_ridges_amplify() {
+    let flat = 0.5;
+    let rock = site_amplification(SoilClass::Rock, flat);
+    let stiff = site_amplification(SoilClass::StiffSoil, flat);
+    let soft = site_amplification(SoilClass::SoftSediment, flat);
+    assert_eq!(rock, 1.0);
+    assert!(soft > stiff && stiff > rock);
+    assert!(site_amplification(SoilClass::StiffSoil, 0.1) > stiff);
+    assert!(site_amplification(SoilClass::Rock, 0.95) > rock);
+}
+
+#[test]
+fn test_retrofit_reduces_damage() {
+    // Moderate shaking damages an ordinary building but not a retrofitted one.
+    assert_eq!(quake_damage(0.7, false, 0.99), QuakeDamage::LevelsLost(1));
+    assert_eq!(quake_damage(0.7, true, 0.99), QuakeDamage::None);
+    assert_eq!(quake_damage(1.2, false, 0.99), QuakeDamage::LevelsLost(2));
+    assert_eq!(quake_damage(1.2, true, 0.99), QuakeDamage::LevelsLost(1));
+    assert_eq!(quake_damage(0.3, false, 0.99), QuakeDamage::None);
+
+    // A roll that collapses an ordinary building leaves a retrofitted one up.
+    let roll = COLLAPSE_AT_FULL_SHAKING * 0.5;
+    assert_eq!(quake_damage(1.0, false, roll), QuakeDamage::Collapse);
+    assert_ne!(quake_damage(1.0, true, roll), QuakeDamage::Collapse);
+}
+
+#[test]
+fn test_faults_are_deterministic_and_on_map() {
+    let faults = generate_faults(42);
+    assert_eq!(faults.len(), FAULT_COUNT);
+    assert_eq!(faults, generate_faults(42));
+    assert_ne!(faults, generate_faults(43));
+    for fault in &faults {
+        for (x, y) in [(fault.x0, fault.y0), (fault.x1, fault.y1)] {
+            assert!(x >= 0.0 && x < GRID_WIDTH as f32);
+            assert!(y >= 0.0 && y < GRID_HEIGHT as f32);
+        }
+    }
+}
+
+#[test]
+fn test_epicenter_lies_near_a_fault() {
+    let faults = generate_faults(7);
+    let state = SeismicState {
+        faults: faults.clone(),
+        ..Default::default()
+    };
+    for i in 0..20 {
+        let t = i as f32 / 20.0;
+        let (x, y) = fault_epicenter(&faults, i, t, 0.9, 0.1).unwrap();
+        assert!(state.fault_distance(x, y).unwrap() < 5.0);
+    }
+    assert!(fault_epicenter(&[], 0, 0.5, 0.5, 0.5).is_none());
+}
+
+#[test]
+fn test_retrofit_bookkeeping() {
+    let mut state = SeismicState::default();
+    state.mark_retrofitted(10, 4);
+    state.mark_retrofitted(3, 4);
+    state.mark_retrofitted(10, 4);
+    assert_eq!(state.retrofitted.len(), 2);
+    assert!(state.is_retrofitted(3, 4));
+    assert!(!state.is_retrofitted(4, 4));
+
+    state.projects.push(RetrofitProject {
+        cell: cell_index(7, 7),
+        months_done: 2,
+        monthly_cost: 200.0,
+    });
+    assert!(state.is_retrofitting(7, 7));
+    assert_eq!(cell_coords(cell_index(7, 7)), (7, 7));
+
+    state.clear_cell(3, 4);
+    state.clear_cell(7, 7);
+    assert!(!state.is_retrofitted(3, 4));
+    assert!(state.projects.is_empty());
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    let mut state = SeismicState {
+        faults: generate_faults(1),
+        ..Default::default()
+    };
+    assert!(state.save_to_bytes().is_none());
+
+    state.mark_retrofitted(5, 5);
+    state.total_spent = 1_200.0;
+    state.last_quake = Some(QuakeReport {
+        epicenter: (5, 6),
+        magnitude: 6.8,
+        collapsed: 2,
+        damaged: 9,
+        retrofits_held: 1,
+    });
+    let restored = SeismicState::load_from_bytes(&state.save_to_bytes().unwrap());
+    assert_eq!(restored.faults, state.faults);
+    assert!(restored.is_retrofitted(5, 5));
+    assert_eq!(restored.last_quake, state.last_quake);
+}
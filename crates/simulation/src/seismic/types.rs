@@ -0,0 +1,335 @@
+//! Fault lines, ground shaking and the retrofit program's state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::terrain_generation::Biome;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Magnitude whose felt radius is `disasters::EARTHQUAKE_RADIUS`.
+pub const REFERENCE_MAGNITUDE: f32 = 6.5;
+
+/// Felt radius at the reference magnitude, in cells.
+pub const REFERENCE_RADIUS: f32 = 10.0;
+
+/// Range of magnitudes a random earthquake can have.
+pub const MIN_MAGNITUDE: f32 = 5.5;
+pub const MAX_MAGNITUDE: f32 = 7.5;
+
+/// Number of fault lines generated per map.
+pub const FAULT_COUNT: usize = 2;
+
+/// Shaking at which an unreinforced building loses a level.
+pub const DAMAGE_SHAKING: f32 = 0.5;
+
+/// Shaking at which an unreinforced building loses two levels.
+pub const SEVERE_SHAKING: f32 = 1.1;
+
+/// Collapse probability at shaking 1.0, scaled by shaking squared.
+pub const COLLAPSE_AT_FULL_SHAKING: f32 = 0.2;
+
+/// Collapse probability multiplier for a retrofitted building.
+pub const RETROFIT_COLLAPSE_FACTOR: f32 = 0.25;
+
+/// A retrofitted building resists this much more shaking before damage.
+pub const RETROFIT_SHAKING_MARGIN: f32 = 0.4;
+
+/// Elevation below which ground is water-saturated and shakes harder.
+pub const SATURATED_ELEVATION: f32 = 0.35;
+
+/// Elevation above which ridges amplify shaking.
+pub const RIDGE_ELEVATION: f32 = 0.7;
+
+/// Days between retrofit program installments.
+pub const RETROFIT_PERIOD_DAYS: u32 = 30;
+
+/// Months a retrofit takes to complete.
+pub const RETROFIT_MONTHS: u8 = 12;
+
+/// Retrofits the program starts each month while the policy is active.
+pub const RETROFIT_STARTS_PER_MONTH: usize = 5;
+
+/// Total retrofit cost per building level, paid over `RETROFIT_MONTHS`.
+pub const RETROFIT_COST_PER_LEVEL: f64 = 2_400.0;
+
+// ---------------------------------------------------------------------------
+// Magnitude and shaking
+// ---------------------------------------------------------------------------
+
+/// Radius, in cells, within which an earthquake of this magnitude does
+/// damage. Doubles with every magnitude step.
+pub fn felt_radius(magnitude: f32) -> usize {
+    (REFERENCE_RADIUS * 2f32.powf(magnitude - REFERENCE_MAGNITUDE))
+        .round()
+        .max(1.0) as usize
+}
+
+/// Magnitude of an earthquake with the given felt radius. An earthquake's
+/// magnitude is carried in its `DisasterInstance` as its radius.
+pub fn magnitude_for_radius(radius: usize) -> f32 {
+    REFERENCE_MAGNITUDE + (radius.max(1) as f32 / REFERENCE_RADIUS).log2()
+}
+
+/// Ground shaking at `distance` cells from the epicenter, before site
+/// effects. Peaks at the epicenter, stronger for larger magnitudes, and
+/// falls to half the peak at the edge of the felt radius.
+pub fn bedrock_shaking(magnitude: f32, distance: f32, radius: usize) -> f32 {
+    let peak = 1.0 + 0.25 * (magnitude - REFERENCE_MAGNITUDE);
+    let falloff = 1.0 - 0.5 * (distance / radius.max(1) as f32);
+    (peak * falloff).max(0.0)
+}
+
+/// Ground classes by how strongly they amplify shaking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoilClass {
+    Rock,
+    StiffSoil,
+    SoftSediment,
+}
+
+impl SoilClass {
+    pub fn from_biome(biome: Biome) -> Self {
+        match biome {
+            Biome::Highland | Biome::Mountain => SoilClass::Rock,
+            Biome::Grassland | Biome::Forest => SoilClass::StiffSoil,
+            Biome::Beach | Biome::ShallowWater | Biome::DeepWater => SoilClass::SoftSediment,
+        }
+    }
+
+    pub fn amplification(self) -> f32 {
+        match self {
+            SoilClass::Rock => 1.0,
+            SoilClass::StiffSoil => 1.15,
+            SoilClass::SoftSediment => 1.5,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SoilClass::Rock => "Rock",
+            SoilClass::StiffSoil => "Stiff soil",
+            SoilClass::SoftSediment => "Soft sediment",
+        }
+    }
+}
+
+/// How much a site amplifies bedrock shaking: soft ground and saturated
+/// lowlands shake harder, and so do ridge tops. Never below 1.0.
+pub fn site_amplification(soil: SoilClass, elevation: f32) -> f32 {
+    let saturated = ((SATURATED_ELEVATION - elevation) / SATURATED_ELEVATION).clamp(0.0, 1.0);
+    let ridge = ((elevation - RIDGE_ELEVATION) / (1.0 - RIDGE_ELEVATION)).clamp(0.0, 1.0);
+    soil.amplification() + 0.2 * saturated + 0.3 * ridge
+}
+
+/// What shaking of a given strength does to one building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuakeDamage {
+    None,
+    /// Loses this many levels (never below level 1).
+    LevelsLost(u8),
+    Collapse,
+}
+
+/// Damage to a building from `shaking`. `roll` is uniform in [0, 1).
+pub fn quake_damage(shaking: f32, retrofitted: bool, roll: f32) -> QuakeDamage {
+    let (effective, collapse_factor) = if retrofitted {
+        (
+            (shaking - RETROFIT_SHAKING_MARGIN).max(0.0),
+            RETROFIT_COLLAPSE_FACTOR,
+        )
+    } else {
+        (shaking, 1.0)
+    };
+    let collapse = COLLAPSE_AT_FULL_SHAKING * shaking * shaking * collapse_factor;
+    if roll < collapse {
+        QuakeDamage::Collapse
+    } else if effective >= SEVERE_SHAKING {
+        QuakeDamage::LevelsLost(2)
+    } else if effective >= DAMAGE_SHAKING {
+        QuakeDamage::LevelsLost(1)
+    } else {
+        QuakeDamage::None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Fault lines
+// ---------------------------------------------------------------------------
+
+/// A straight fault trace across the map, in cell coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct FaultLine {
+    pub x0: f32,
+    pub y0: f32,
+    pub x1: f32,
+    pub y1: f32,
+}
+
+impl FaultLine {
+    /// Point at fraction `t` (0..=1) along the trace.
+    pub fn point_at(&self, t: f32) -> (f32, f32) {
+        (
+            self.x0 + (self.x1 - self.x0) * t,
+            self.y0 + (self.y1 - self.y0) * t,
+        )
+    }
+
+    /// Distance from a cell to the nearest point on the trace.
+    pub fn distance_to(&self, x: f32, y: f32) -> f32 {
+        let (dx, dy) = (self.x1 - self.x0, self.y1 - self.y0);
+        let len_sq = dx * dx + dy * dy;
+        let t = if len_sq > 0.0 {
+            (((x - self.x0) * dx + (y - self.y0) * dy) / len_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (px, py) = self.point_at(t);
+        ((x - px).powi(2) + (y - py).powi(2)).sqrt()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Retrofit program
+// ---------------------------------------------------------------------------
+
+/// A building part-way through its retrofit.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct RetrofitProject {
+    /// Cell index (`y * GRID_WIDTH + x`) of the building.
+    pub cell: u32,
+    pub months_done: u8,
+    /// Installment paid each month.
+    pub monthly_cost: f64,
+}
+
+/// Outcome of the most recent earthquake.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct QuakeReport {
+    pub epicenter: (usize, usize),
+    pub magnitude: f32,
+    pub collapsed: u32,
+    pub damaged: u32,
+    /// Retrofitted buildings that came through without damage.
+    pub retrofits_held: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Fault lines under the map, retrofitted buildings and the last earthquake.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct SeismicState {
+    /// Empty until generated from the terrain seed.
+    pub faults: Vec<FaultLine>,
+    /// Cell indices of retrofitted buildings, kept sorted.
+    pub retrofitted: Vec<u32>,
+    pub projects: Vec<RetrofitProject>,
+    pub last_installment_day: u32,
+    /// Spent on retrofits so far.
+    pub total_spent: f64,
+    pub last_quake: Option<QuakeReport>,
+}
+
+pub fn cell_index(x: usize, y: usize) -> u32 {
+    (y * GRID_WIDTH + x) as u32
+}
+
+pub fn cell_coords(cell: u32) -> (usize, usize) {
+    let i = cell as usize;
+    (i % GRID_WIDTH, i / GRID_WIDTH)
+}
+
+impl SeismicState {
+    pub fn is_retrofitted(&self, x: usize, y: usize) -> bool {
+        self.retrofitted.binary_search(&cell_index(x, y)).is_ok()
+    }
+
+    pub fn is_retrofitting(&self, x: usize, y: usize) -> bool {
+        let cell = cell_index(x, y);
+        self.projects.iter().any(|p| p.cell == cell)
+    }
+
+    pub fn mark_retrofitted(&mut self, x: usize, y: usize) {
+        let cell = cell_index(x, y);
+        if let Err(i) = self.retrofitted.binary_search(&cell) {
+            self.retrofitted.insert(i, cell);
+        }
+    }
+
+    /// Forget the retrofit on a cell whose building is gone.
+    pub fn clear_cell(&mut self, x: usize, y: usize) {
+        let cell = cell_index(x, y);
+        if let Ok(i) = self.retrofitted.binary_search(&cell) {
+            self.retrofitted.remove(i);
+        }
+        self.projects.retain(|p| p.cell != cell);
+    }
+
+    /// Distance from a cell to the nearest fault, if any faults exist.
+    pub fn fault_distance(&self, x: usize, y: usize) -> Option<f32> {
+        self.faults
+            .iter()
+            .map(|f| f.distance_to(x as f32, y as f32))
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Monthly spending on the projects in progress.
+    pub fn monthly_installments(&self) -> f64 {
+        self.projects.iter().map(|p| p.monthly_cost).sum()
+    }
+}
+
+/// Lay out `FAULT_COUNT` long faults across the map from a seed.
+pub fn generate_faults(seed: u64) -> Vec<FaultLine> {
+    let mut state = seed ^ 0x5e15_71c0_fa17_u64;
+    let mut next = || {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        ((z ^ (z >> 31)) % 1_000_000) as f32 / 1_000_000.0
+    };
+    let (w, h) = (GRID_WIDTH as f32, GRID_HEIGHT as f32);
+    (0..FAULT_COUNT)
+        .map(|_| {
+            // A point well inside the map and a direction; the trace runs
+            // across most of the map through that point.
+            let cx = w * (0.2 + 0.6 * next());
+            let cy = h * (0.2 + 0.6 * next());
+            let angle = next() * std::f32::consts::PI;
+            let half = w.min(h) * (0.3 + 0.2 * next());
+            let (dx, dy) = (angle.cos() * half, angle.sin() * half);
+            FaultLine {
+                x0: (cx - dx).clamp(0.0, w - 1.0),
+                y0: (cy - dy).clamp(0.0, h - 1.0),
+                x1: (cx + dx).clamp(0.0, w - 1.0),
+                y1: (cy + dy).clamp(0.0, h - 1.0),
+            }
+        })
+        .collect()
+}
+
+impl Saveable for SeismicState {
+    const SAVE_KEY: &'static str = "seismic_state";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        // Faults alone are regenerated from the terrain seed.
+        if self.retrofitted.is_empty()
+            && self.projects.is_empty()
+            && self.total_spent == 0.0
+            && self.last_quake.is_none()
+        {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
@@ -81,7 +81,8 @@ impl Department {
 
             ServiceType::FireStation
             | ServiceType::FireHouse
-            | ServiceType::FireHQ => Some(Department::FireEms),
+            | ServiceType::FireHQ
+            | ServiceType::HazmatResponse => Some(Department::FireEms),
 
             ServiceType::Hospital
             | ServiceType::MedicalClinic
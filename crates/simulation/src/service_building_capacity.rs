@@ -25,6 +25,8 @@ use crate::TickCounter;
 /// - Hospital beds: Clinic=50, Hospital=200, MedicalCenter=500
 /// - School students: Elementary=300, HighSchool=1500, University=5000
 /// - Fire trucks: FireHouse=2, FireStation=5, FireHQ=10
+/// - Hazmat cleanup crews: HazmatResponse=3
+/// - Hotel rooms: Hotel=300; convention seats: ConventionCenter=2000
 /// - Police officers: Kiosk=10, Station=30, HQ=100
 pub fn tier_capacity(st: ServiceType) -> u32 {
     use ServiceType::*;
@@ -51,6 +53,8 @@ pub fn tier_capacity(st: ServiceType) -> u32 {
         Daycare => 200,          Eldercare => 150,
         CommunityCenter => 300,  SubstanceAbuseTreatmentCenter => 100,
         SeniorCenter => 200,     YouthCenter => 250,
+        HazmatResponse => 3,
+        Hotel => 300,            ConventionCenter => 2000,
     }
 }
 
@@ -80,6 +84,8 @@ pub fn staff_required(st: ServiceType) -> u32 {
         Daycare => 10,           Eldercare => 8,
         CommunityCenter => 8,   SubstanceAbuseTreatmentCenter => 12,
         SeniorCenter => 6,      YouthCenter => 6,
+        HazmatResponse => 20,
+        Hotel => 40,             ConventionCenter => 30,
     }
 }
 
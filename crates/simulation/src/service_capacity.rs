@@ -157,6 +157,11 @@ pub fn default_capacity(service_type: ServiceType) -> u32 {
         ServiceType::SubstanceAbuseTreatmentCenter => 100,
         ServiceType::SeniorCenter => 200,
         ServiceType::YouthCenter => 250,
+        ServiceType::HazmatResponse => 50,
+
+        // Tourism (hotel rooms are tracked by `hotels::Hotel`)
+        ServiceType::Hotel => 300,
+        ServiceType::ConventionCenter => 2000,
     }
 }
 
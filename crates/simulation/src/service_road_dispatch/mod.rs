@@ -1,20 +1,30 @@
 //! SERV-002: Service Vehicle Dispatch on Road Network
 //!
 //! Dispatches emergency service vehicles (fire trucks, ambulances, police cars)
-//! on the road network using CSR pathfinding. Response time depends on path
-//! distance and traffic conditions, and affects outcomes (fire damage, survival).
-//! Vehicle count is limited by service building capacity.
+//! on the road network. Each call spawns an `EmergencyResponder` that asks the
+//! shared path queue for a route from each of the few stations nearest in a
+//! straight line, with `PathPriority::Emergency` so they are routed ahead of
+//! any waiting citizens; the vehicle sets off from the station with the
+//! shortest route once all of them come back. Response time depends on
+//! path distance and traffic conditions, and affects outcomes (fire damage,
+//! survival). Vehicle count is limited by service building capacity.
 
 use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::buildings::Building;
-use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::fire::{FireGrid, OnFire};
-use crate::road_graph_csr::{csr_find_path, CsrGraph};
+use crate::grid::WorldGrid;
+use crate::road_graph_csr::CsrGraph;
 use crate::roads::RoadNode;
-use crate::services::ServiceBuilding;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::spatial_grid::SpatialGrid;
+
+mod responders;
+
+use responders::{launch_responders, spawn_responder};
+pub use responders::{EmergencyResponder, ResponderRoute};
 
 // ---------------------------------------------------------------------------
 // Types
@@ -98,21 +108,19 @@ fn update_vehicle_capacity(
 
 /// Dispatch fire trucks to active fires from the nearest fire station.
 fn dispatch_fire_trucks(
+    mut commands: Commands,
     fire_buildings: Query<(&Building, &OnFire)>,
-    services: Query<&ServiceBuilding>,
+    services: Query<(Entity, &ServiceBuilding)>,
+    responders: Query<&EmergencyResponder>,
     csr: Res<CsrGraph>,
-    mut state: ResMut<ServiceDispatchState>,
+    state: Res<ServiceDispatchState>,
 ) {
     if csr.node_count() == 0 {
         return;
     }
 
-    let fire_stations: Vec<(usize, usize)> = services
-        .iter()
-        .filter(|s| ServiceBuilding::is_fire(s.service_type))
-        .map(|s| (s.grid_x, s.grid_y))
-        .collect();
-    if fire_stations.is_empty() {
+    let fire_stations = station_index(&services, ServiceBuilding::is_fire);
+    if fire_stations.entity_count() == 0 {
         return;
     }
 
@@ -122,56 +130,58 @@ fn dispatch_fire_trucks(
             continue;
         }
         let tgt = (building.grid_x, building.grid_y);
-        if state.vehicles.iter().any(|v| v.kind == EmergencyKind::Fire && v.target == tgt) {
+        if is_responding(&state, &responders, EmergencyKind::Fire, tgt) {
             continue;
         }
         targets.push((tgt.0, tgt.1, on_fire.intensity));
     }
     targets.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
 
+    let mut pending = responders.iter().count();
     for (tx, ty, _) in targets {
-        if !can_dispatch(&state) {
+        if !can_dispatch(&state, pending) {
             break;
         }
-        if let Some((origin, path)) = find_best_route(&csr, &fire_stations, (tx, ty)) {
-            push_vehicle(&mut state, EmergencyKind::Fire, origin, (tx, ty), path);
+        let stations = stations_by_distance(&fire_stations, &services, (tx, ty));
+        if spawn_responder(&mut commands, EmergencyKind::Fire, (tx, ty), stations) {
+            pending += 1;
         }
     }
 }
 
 /// Dispatch ambulances to buildings with severe fires.
 fn dispatch_ambulances(
+    mut commands: Commands,
     fire_buildings: Query<(&Building, &OnFire)>,
-    services: Query<&ServiceBuilding>,
+    services: Query<(Entity, &ServiceBuilding)>,
+    responders: Query<&EmergencyResponder>,
     csr: Res<CsrGraph>,
-    mut state: ResMut<ServiceDispatchState>,
+    state: Res<ServiceDispatchState>,
 ) {
     if csr.node_count() == 0 {
         return;
     }
 
-    let hospitals: Vec<(usize, usize)> = services
-        .iter()
-        .filter(|s| ServiceBuilding::is_health(s.service_type))
-        .map(|s| (s.grid_x, s.grid_y))
-        .collect();
-    if hospitals.is_empty() {
+    let hospitals = station_index(&services, ServiceBuilding::is_health);
+    if hospitals.entity_count() == 0 {
         return;
     }
 
+    let mut pending = responders.iter().count();
     for (building, on_fire) in &fire_buildings {
         if on_fire.intensity < AMBULANCE_DISPATCH_THRESHOLD {
             continue;
         }
-        if !can_dispatch(&state) {
+        if !can_dispatch(&state, pending) {
             break;
         }
         let tgt = (building.grid_x, building.grid_y);
-        if state.vehicles.iter().any(|v| v.kind == EmergencyKind::Medical && v.target == tgt) {
+        if is_responding(&state, &responders, EmergencyKind::Medical, tgt) {
             continue;
         }
-        if let Some((origin, path)) = find_best_route(&csr, &hospitals, tgt) {
-            push_vehicle(&mut state, EmergencyKind::Medical, origin, tgt, path);
+        let stations = stations_by_distance(&hospitals, &services, tgt);
+        if spawn_responder(&mut commands, EmergencyKind::Medical, tgt, stations) {
+            pending += 1;
         }
     }
 }
@@ -246,9 +256,28 @@ fn cleanup_completed_vehicles(mut state: ResMut<ServiceDispatchState>) {
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn can_dispatch(state: &ServiceDispatchState) -> bool {
-    state.vehicles.len() < MAX_CONCURRENT_DISPATCHES
-        && (state.vehicles.len() as u32) < state.max_vehicles
+/// Whether another vehicle may be sent, counting `pending` responders still
+/// waiting for their route.
+fn can_dispatch(state: &ServiceDispatchState, pending: usize) -> bool {
+    let active = state.vehicles.len() + pending;
+    active < MAX_CONCURRENT_DISPATCHES && (active as u32) < state.max_vehicles
+}
+
+/// Whether a vehicle or pending responder of `kind` is already headed to
+/// `target`.
+fn is_responding(
+    state: &ServiceDispatchState,
+    responders: &Query<&EmergencyResponder>,
+    kind: EmergencyKind,
+    target: (usize, usize),
+) -> bool {
+    state
+        .vehicles
+        .iter()
+        .any(|v| v.kind == kind && v.target == target)
+        || responders
+            .iter()
+            .any(|r| r.kind == kind && r.target == target)
 }
 
 fn push_vehicle(
@@ -256,7 +285,7 @@ fn push_vehicle(
     kind: EmergencyKind,
     origin: (usize, usize),
     target: (usize, usize),
-    path: Vec<RoadNode>,
+    path: &[RoadNode],
 ) {
     let path_coords: Vec<(usize, usize)> = path.iter().map(|n| (n.0, n.1)).collect();
     let path_length = path_coords.len() as u32;
@@ -274,63 +303,34 @@ fn push_vehicle(
     state.total_dispatches += 1;
 }
 
-fn find_best_route(
-    csr: &CsrGraph,
-    stations: &[(usize, usize)],
-    target: (usize, usize),
-) -> Option<((usize, usize), Vec<RoadNode>)> {
-    let target_node = RoadNode(target.0, target.1);
-    let mut best: Option<((usize, usize), Vec<RoadNode>)> = None;
-    for &(sx, sy) in stations {
-        if let Some(path) = find_nearby_path(csr, RoadNode(sx, sy), target_node) {
-            let shorter = best.as_ref().is_none_or(|(_, bp)| path.len() < bp.len());
-            if shorter {
-                best = Some(((sx, sy), path));
-            }
+/// Spatial index of the service buildings whose type passes `is_kind`.
+fn station_index(
+    services: &Query<(Entity, &ServiceBuilding)>,
+    is_kind: fn(ServiceType) -> bool,
+) -> SpatialGrid {
+    let mut index = SpatialGrid::default();
+    for (entity, service) in services {
+        if is_kind(service.service_type) {
+            let (x, y) = WorldGrid::grid_to_world(service.grid_x, service.grid_y);
+            index.insert(entity, x, y);
         }
     }
-    best
+    index
 }
 
-fn find_nearby_path(csr: &CsrGraph, start: RoadNode, goal: RoadNode) -> Option<Vec<RoadNode>> {
-    if let Some(path) = csr_find_path(csr, start, goal) {
-        return Some(path);
-    }
-    let starts = adjacent_nodes(start);
-    let goals = adjacent_nodes(goal);
-    for s in &starts {
-        if let Some(path) = csr_find_path(csr, *s, goal) {
-            return Some(path);
-        }
-        for g in &goals {
-            if let Some(path) = csr_find_path(csr, *s, *g) {
-                return Some(path);
-            }
-        }
-    }
-    for g in &goals {
-        if let Some(path) = csr_find_path(csr, start, *g) {
-            return Some(path);
-        }
-    }
-    None
-}
-
-fn adjacent_nodes(node: RoadNode) -> Vec<RoadNode> {
-    let mut out = Vec::with_capacity(4);
-    if node.0 > 0 {
-        out.push(RoadNode(node.0 - 1, node.1));
-    }
-    if node.1 > 0 {
-        out.push(RoadNode(node.0, node.1 - 1));
-    }
-    if node.0 + 1 < GRID_WIDTH {
-        out.push(RoadNode(node.0 + 1, node.1));
-    }
-    if node.1 + 1 < GRID_HEIGHT {
-        out.push(RoadNode(node.0, node.1 + 1));
-    }
-    out
+/// Grid positions of the stations in `stations`, nearest to `target` first.
+fn stations_by_distance(
+    stations: &SpatialGrid,
+    services: &Query<(Entity, &ServiceBuilding)>,
+    target: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let (x, y) = WorldGrid::grid_to_world(target.0, target.1);
+    stations
+        .k_nearest(x, y, stations.entity_count(), f32::INFINITY)
+        .iter()
+        .filter_map(|&(entity, _)| services.get(entity).ok())
+        .map(|(_, station)| (station.grid_x, station.grid_y))
+        .collect()
 }
 
 fn arrival_tick(vehicle: &ServiceVehicle) -> u32 {
@@ -377,6 +377,7 @@ impl Plugin for ServiceRoadDispatchPlugin {
             (
                 update_vehicle_capacity,
                 dispatch_fire_trucks,
+                bevy::ecs::schedule::apply_deferred,
                 dispatch_ambulances,
                 advance_vehicles,
                 apply_on_scene_effects,
@@ -387,10 +388,16 @@ impl Plugin for ServiceRoadDispatchPlugin {
                 .run_if(slow_tick_guard)
                 .in_set(crate::SimulationSet::Simulation),
         );
+        app.add_systems(
+            FixedUpdate,
+            launch_responders
+                .after(crate::movement::collect_path_results)
+                .in_set(crate::SimulationSet::Simulation),
+        );
     }
 }
 
-/// Only run dispatch systems every 10 ticks to limit pathfinding cost.
+/// Only run dispatch systems every 10 ticks to limit their cost.
 fn slow_tick_guard(tick: Res<crate::TickCounter>) -> bool {
     tick.0.is_multiple_of(10)
 }
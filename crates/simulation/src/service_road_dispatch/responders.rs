@@ -0,0 +1,136 @@
+//! Emergency responders: the calls waiting for their candidate routes from
+//! the path queue, and the launch of each vehicle on the shortest of them.
+
+use bevy::prelude::*;
+
+use crate::citizen::{CitizenState, PathCache, PathRequest};
+use crate::movement::{ComputingPath, PathPriority};
+use crate::roads::RoadNode;
+
+use super::{push_vehicle, EmergencyKind, ServiceDispatchState};
+
+/// Stations, nearest first by straight-line distance, that are routed to an
+/// incident before falling back to the rest.
+const DISPATCH_CANDIDATES: usize = 3;
+
+/// An emergency call waiting for its candidate routes from the path queue.
+/// Each candidate is a `ResponderRoute` entity; once none is pending, the
+/// vehicle leaves on the shortest. If no candidate reaches the target, every
+/// station in `fallbacks` is tried the same way.
+#[derive(Component, Debug, Clone)]
+pub struct EmergencyResponder {
+    pub kind: EmergencyKind,
+    pub target: (usize, usize),
+    /// Candidate routes still being computed.
+    pub pending: usize,
+    /// Shortest route found so far and the station it starts from.
+    pub best: Option<((usize, usize), Vec<RoadNode>)>,
+    /// Stations to try if no candidate can reach the target.
+    pub fallbacks: Vec<(usize, usize)>,
+}
+
+/// One candidate station's route for an `EmergencyResponder`, requested
+/// with `PathPriority::Emergency`.
+#[derive(Component, Debug, Clone)]
+pub struct ResponderRoute {
+    pub responder: Entity,
+    pub origin: (usize, usize),
+}
+
+/// Collect candidate routes that came back from the path queue, then send
+/// off each responder with none pending on its shortest route, or retry from
+/// the farther stations if no candidate reached the target.
+#[allow(clippy::type_complexity)]
+pub(super) fn launch_responders(
+    mut commands: Commands,
+    routes: Query<
+        (Entity, &ResponderRoute, &PathCache),
+        (Without<PathRequest>, Without<ComputingPath>),
+    >,
+    mut responders: Query<(Entity, &mut EmergencyResponder)>,
+    mut state: ResMut<ServiceDispatchState>,
+) {
+    for (entity, route, path) in &routes {
+        commands.entity(entity).despawn();
+        let Ok((_, mut responder)) = responders.get_mut(route.responder) else {
+            continue;
+        };
+        responder.pending = responder.pending.saturating_sub(1);
+        let found = &path.waypoints;
+        let shorter = responder
+            .best
+            .as_ref()
+            .is_none_or(|(_, best)| found.len() < best.len());
+        if !found.is_empty() && shorter {
+            responder.best = Some((route.origin, found.clone()));
+        }
+    }
+
+    for (entity, mut responder) in &mut responders {
+        if responder.pending > 0 {
+            continue;
+        }
+        if let Some((origin, path)) = responder.best.take() {
+            let (kind, target) = (responder.kind, responder.target);
+            push_vehicle(&mut state, kind, origin, target, &path);
+            commands.entity(entity).despawn();
+        } else if !responder.fallbacks.is_empty() {
+            let stations = std::mem::take(&mut responder.fallbacks);
+            responder.pending = stations.len();
+            request_routes(&mut commands, entity, responder.target, &stations);
+        } else {
+            // No station can reach the target over the road network.
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawn a responder routing to `target` from the `DISPATCH_CANDIDATES`
+/// first of `stations`, nearest first. Returns false when there is no
+/// station to send from.
+pub(super) fn spawn_responder(
+    commands: &mut Commands,
+    kind: EmergencyKind,
+    target: (usize, usize),
+    mut stations: Vec<(usize, usize)>,
+) -> bool {
+    if stations.is_empty() {
+        return false;
+    }
+    let fallbacks = stations.split_off(DISPATCH_CANDIDATES.min(stations.len()));
+    let responder = commands
+        .spawn(EmergencyResponder {
+            kind,
+            target,
+            pending: stations.len(),
+            best: None,
+            fallbacks,
+        })
+        .id();
+    request_routes(commands, responder, target, &stations);
+    true
+}
+
+/// Queue one emergency route to `target` from each of `stations`.
+fn request_routes(
+    commands: &mut Commands,
+    responder: Entity,
+    target: (usize, usize),
+    stations: &[(usize, usize)],
+) {
+    for &origin in stations {
+        commands.spawn((
+            ResponderRoute { responder, origin },
+            PathRequest {
+                from_gx: origin.0,
+                from_gy: origin.1,
+                to_gx: target.0,
+                to_gy: target.1,
+                // Responders have no citizen state; the path queue ignores this.
+                target_state: CitizenState::AtHome,
+            },
+            PathCache::new(Vec::new()),
+            PathPriority::Emergency,
+        ));
+    }
+}
@@ -0,0 +1,317 @@
+//! In-place upgrades for tiered service buildings.
+//!
+//! Health, fire and police buildings form three-tier chains:
+//!
+//! - Medical Clinic → Hospital → Medical Center
+//! - Fire House → Fire Station → Fire HQ
+//! - Police Kiosk → Police Station → Police HQ
+//!
+//! An upgrade is requested from the building inspector and only goes through
+//! when the building is busy enough (utilization of its `ServiceCapacity`),
+//! the city can pay the price difference, and the larger footprint fits
+//! around the existing anchor cell.
+//!
+//! The upgraded building keeps its entity and grid anchor, so usage and
+//! staffing history carry over; only the service type, radius, footprint and
+//! capacities change. `ServiceBuilding` is re-inserted so coverage systems
+//! that rebuild on `Added<ServiceBuilding>` pick up the new radius.
+
+use bevy::prelude::*;
+
+use crate::economy::CityBudget;
+use crate::grid::{CellType, WorldGrid};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::service_building_capacity::{staff_required, tier_capacity, ServiceBuildingStaffing};
+use crate::service_capacity::{default_capacity, ServiceCapacity};
+use crate::services::{ServiceBuilding, ServiceType};
+
+/// Minimum capacity utilization before a building may be upgraded.
+pub const UPGRADE_UTILIZATION_THRESHOLD: f32 = 0.8;
+
+/// The next tier of a service building, if it has one.
+pub fn next_tier(service_type: ServiceType) -> Option<ServiceType> {
+    match service_type {
+        ServiceType::MedicalClinic => Some(ServiceType::Hospital),
+        ServiceType::Hospital => Some(ServiceType::MedicalCenter),
+        ServiceType::FireHouse => Some(ServiceType::FireStation),
+        ServiceType::FireStation => Some(ServiceType::FireHQ),
+        ServiceType::PoliceKiosk => Some(ServiceType::PoliceStation),
+        ServiceType::PoliceStation => Some(ServiceType::PoliceHQ),
+        _ => None,
+    }
+}
+
+/// Price of upgrading: the difference in construction cost.
+pub fn upgrade_cost(from: ServiceType, to: ServiceType) -> f64 {
+    (ServiceBuilding::cost(to) - ServiceBuilding::cost(from)).max(0.0)
+}
+
+/// An upgrade the building is eligible for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpgradeOption {
+    pub to: ServiceType,
+    pub cost: f64,
+}
+
+/// Why a building cannot be upgraded right now.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpgradeBlocker {
+    /// Not part of a tier chain, or already at the top tier.
+    MaxTier,
+    /// The building is not busy enough to justify an upgrade.
+    LowDemand {
+        utilization: f32,
+    },
+    InsufficientFunds {
+        cost: f64,
+    },
+    /// The larger footprint overlaps roads, water or other buildings.
+    NoSpace,
+}
+
+impl UpgradeBlocker {
+    pub fn message(&self) -> String {
+        match self {
+            Self::MaxTier => "Already at the highest tier".to_string(),
+            Self::LowDemand { utilization } => format!(
+                "Needs {:.0}% utilization (currently {:.0}%)",
+                UPGRADE_UTILIZATION_THRESHOLD * 100.0,
+                utilization * 100.0
+            ),
+            Self::InsufficientFunds { cost } => format!("Needs ${:.0} in the treasury", cost),
+            Self::NoSpace => "Not enough free space around the building".to_string(),
+        }
+    }
+}
+
+/// Whether the next tier's footprint, anchored at the building's grid cell,
+/// only covers free grass or cells the building already occupies.
+fn footprint_fits(
+    grid: &WorldGrid,
+    entity: Entity,
+    service: &ServiceBuilding,
+    to: ServiceType,
+) -> bool {
+    let (fw, fh) = ServiceBuilding::footprint(to);
+    for dy in 0..fh {
+        for dx in 0..fw {
+            let (cx, cy) = (service.grid_x + dx, service.grid_y + dy);
+            if !grid.in_bounds(cx, cy) {
+                return false;
+            }
+            let cell = grid.get(cx, cy);
+            let free = cell.cell_type == CellType::Grass && cell.building_id.is_none();
+            if !free && cell.building_id != Some(entity) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Check every upgrade gate for a service building.
+pub fn check_upgrade(
+    entity: Entity,
+    service: &ServiceBuilding,
+    utilization: f32,
+    treasury: f64,
+    grid: &WorldGrid,
+) -> Result<UpgradeOption, UpgradeBlocker> {
+    let to = next_tier(service.service_type).ok_or(UpgradeBlocker::MaxTier)?;
+    if utilization < UPGRADE_UTILIZATION_THRESHOLD {
+        return Err(UpgradeBlocker::LowDemand { utilization });
+    }
+    let cost = upgrade_cost(service.service_type, to);
+    if treasury < cost {
+        return Err(UpgradeBlocker::InsufficientFunds { cost });
+    }
+    if !footprint_fits(grid, entity, service, to) {
+        return Err(UpgradeBlocker::NoSpace);
+    }
+    Ok(UpgradeOption { to, cost })
+}
+
+/// Sent by the UI to upgrade a service building to its next tier.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ServiceUpgradeRequest {
+    pub entity: Entity,
+}
+
+/// Apply requested upgrades that pass all gates.
+pub fn handle_service_upgrades(
+    mut commands: Commands,
+    mut requests: EventReader<ServiceUpgradeRequest>,
+    mut grid: ResMut<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut services: Query<(
+        &ServiceBuilding,
+        Option<&mut ServiceCapacity>,
+        Option<&mut ServiceBuildingStaffing>,
+    )>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for request in requests.read() {
+        let Ok((service, capacity, staffing)) = services.get_mut(request.entity) else {
+            continue;
+        };
+        let utilization = capacity.as_ref().map_or(0.0, |c| c.utilization());
+        let option =
+            match check_upgrade(request.entity, service, utilization, budget.treasury, &grid) {
+                Ok(option) => option,
+                Err(blocker) => {
+                    notifications.send(NotificationEvent {
+                        text: format!(
+                            "Cannot upgrade {}: {}",
+                            service.service_type.name(),
+                            blocker.message()
+                        ),
+                        priority: NotificationPriority::Info,
+                        category: NotificationCategory::General,
+                        location: Some(WorldGrid::grid_to_world(service.grid_x, service.grid_y)),
+                    });
+                    continue;
+                }
+            };
+
+        budget.treasury -= option.cost;
+
+        // Claim the enlarged footprint.
+        let (fw, fh) = ServiceBuilding::footprint(option.to);
+        for dy in 0..fh {
+            for dx in 0..fw {
+                grid.get_mut(service.grid_x + dx, service.grid_y + dy)
+                    .building_id = Some(request.entity);
+            }
+        }
+
+        // Keep current usage and assigned staff; raise the limits.
+        if let Some(mut capacity) = capacity {
+            capacity.capacity = default_capacity(option.to);
+        }
+        if let Some(mut staffing) = staffing {
+            staffing.staff_required = staff_required(option.to);
+            staffing.max_capacity = tier_capacity(option.to);
+        }
+
+        let upgraded = ServiceBuilding {
+            service_type: option.to,
+            grid_x: service.grid_x,
+            grid_y: service.grid_y,
+            radius: ServiceBuilding::coverage_radius(option.to),
+        };
+        notifications.send(NotificationEvent {
+            text: format!(
+                "{} upgraded to {}.",
+                service.service_type.name(),
+                option.to.name()
+            ),
+            priority: NotificationPriority::Positive,
+            category: NotificationCategory::General,
+            location: Some(WorldGrid::grid_to_world(service.grid_x, service.grid_y)),
+        });
+        commands
+            .entity(request.entity)
+            .remove::<ServiceBuilding>()
+            .insert(upgraded);
+    }
+}
+
+pub struct ServiceUpgradePlugin;
+
+impl Plugin for ServiceUpgradePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ServiceUpgradeRequest>()
+            .add_systems(Update, handle_service_upgrades);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+
+    fn service(service_type: ServiceType) -> ServiceBuilding {
+        ServiceBuilding {
+            service_type,
+            grid_x: 10,
+            grid_y: 10,
+            radius: ServiceBuilding::coverage_radius(service_type),
+        }
+    }
+
+    #[test]
+    fn tier_chains_end_at_headquarters() {
+        assert_eq!(
+            next_tier(ServiceType::MedicalClinic),
+            Some(ServiceType::Hospital)
+        );
+        assert_eq!(
+            next_tier(ServiceType::Hospital),
+            Some(ServiceType::MedicalCenter)
+        );
+        assert_eq!(next_tier(ServiceType::MedicalCenter), None);
+        assert_eq!(
+            next_tier(ServiceType::FireHouse),
+            Some(ServiceType::FireStation)
+        );
+        assert_eq!(
+            next_tier(ServiceType::FireStation),
+            Some(ServiceType::FireHQ)
+        );
+        assert_eq!(next_tier(ServiceType::FireHQ), None);
+        assert_eq!(next_tier(ServiceType::ElementarySchool), None);
+    }
+
+    #[test]
+    fn upgrade_cost_is_price_difference() {
+        let cost = upgrade_cost(ServiceType::FireHouse, ServiceType::FireStation);
+        assert_eq!(
+            cost,
+            ServiceBuilding::cost(ServiceType::FireStation)
+                - ServiceBuilding::cost(ServiceType::FireHouse)
+        );
+    }
+
+    #[test]
+    fn check_upgrade_applies_each_gate() {
+        let grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        let entity = Entity::from_raw(1);
+        let clinic = service(ServiceType::MedicalClinic);
+
+        assert!(matches!(
+            check_upgrade(entity, &clinic, 0.5, 1e6, &grid),
+            Err(UpgradeBlocker::LowDemand { .. })
+        ));
+        assert!(matches!(
+            check_upgrade(entity, &clinic, 1.0, 0.0, &grid),
+            Err(UpgradeBlocker::InsufficientFunds { .. })
+        ));
+        assert_eq!(
+            check_upgrade(
+                entity,
+                &service(ServiceType::MedicalCenter),
+                1.0,
+                1e6,
+                &grid
+            ),
+            Err(UpgradeBlocker::MaxTier)
+        );
+        let option = check_upgrade(entity, &clinic, 1.0, 1e6, &grid).unwrap();
+        assert_eq!(option.to, ServiceType::Hospital);
+    }
+
+    #[test]
+    fn larger_footprint_must_be_free() {
+        let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+        let entity = Entity::from_raw(1);
+        let hospital = service(ServiceType::Hospital);
+        grid.get_mut(10, 10).building_id = Some(entity);
+        assert!(check_upgrade(entity, &hospital, 1.0, 1e6, &grid).is_ok());
+
+        grid.get_mut(12, 11).cell_type = CellType::Road;
+        assert_eq!(
+            check_upgrade(entity, &hospital, 1.0, 1e6, &grid),
+            Err(UpgradeBlocker::NoSpace)
+        );
+    }
+}
@@ -71,6 +71,9 @@ impl ServiceBuilding {
             ServiceType::SubstanceAbuseTreatmentCenter => 15.0 * CELL_SIZE,
             ServiceType::SeniorCenter => 15.0 * CELL_SIZE,
             ServiceType::YouthCenter => 15.0 * CELL_SIZE,
+            ServiceType::HazmatResponse => 40.0 * CELL_SIZE,
+            ServiceType::Hotel => 10.0 * CELL_SIZE,
+            ServiceType::ConventionCenter => 30.0 * CELL_SIZE,
         }
     }
 
@@ -132,6 +135,9 @@ impl ServiceBuilding {
             ServiceType::SubstanceAbuseTreatmentCenter => 1200.0,
             ServiceType::SeniorCenter => 700.0,
             ServiceType::YouthCenter => 600.0,
+            ServiceType::HazmatResponse => 2500.0,
+            ServiceType::Hotel => 3500.0,
+            ServiceType::ConventionCenter => 6000.0,
         }
     }
 
@@ -193,6 +199,9 @@ impl ServiceBuilding {
             ServiceType::SubstanceAbuseTreatmentCenter => 35.0,
             ServiceType::SeniorCenter => 20.0,
             ServiceType::YouthCenter => 18.0,
+            ServiceType::HazmatResponse => 45.0,
+            ServiceType::Hotel => 10.0,
+            ServiceType::ConventionCenter => 50.0,
         }
     }
 
@@ -219,8 +228,9 @@ impl ServiceBuilding {
             | ServiceType::TramDepot
             | ServiceType::DataCenter
             | ServiceType::DistrictHeatingPlant
-            | ServiceType::TransferStation => (2, 2),
-            ServiceType::GeothermalPlant => (3, 3),
+            | ServiceType::TransferStation
+            | ServiceType::Hotel => (2, 2),
+            ServiceType::GeothermalPlant | ServiceType::ConventionCenter => (3, 3),
             _ => (1, 1),
         }
     }
@@ -60,9 +60,75 @@ pub enum ServiceType {
     SubstanceAbuseTreatmentCenter,
     SeniorCenter,
     YouthCenter,
+    HazmatResponse,
+    Hotel,
+    ConventionCenter,
 }
 
 impl ServiceType {
+    /// Every service building type, in declaration order.
+    pub const ALL: [ServiceType; 59] = [
+        ServiceType::FireStation,
+        ServiceType::PoliceStation,
+        ServiceType::Hospital,
+        ServiceType::ElementarySchool,
+        ServiceType::HighSchool,
+        ServiceType::University,
+        ServiceType::Library,
+        ServiceType::SmallPark,
+        ServiceType::LargePark,
+        ServiceType::Playground,
+        ServiceType::Plaza,
+        ServiceType::SportsField,
+        ServiceType::Stadium,
+        ServiceType::Landfill,
+        ServiceType::RecyclingCenter,
+        ServiceType::Incinerator,
+        ServiceType::Cemetery,
+        ServiceType::Crematorium,
+        ServiceType::CityHall,
+        ServiceType::Museum,
+        ServiceType::Cathedral,
+        ServiceType::TVStation,
+        ServiceType::BusDepot,
+        ServiceType::TrainStation,
+        ServiceType::FireHouse,
+        ServiceType::FireHQ,
+        ServiceType::PoliceKiosk,
+        ServiceType::PoliceHQ,
+        ServiceType::Prison,
+        ServiceType::MedicalClinic,
+        ServiceType::MedicalCenter,
+        ServiceType::Kindergarten,
+        ServiceType::SubwayStation,
+        ServiceType::TramDepot,
+        ServiceType::FerryPier,
+        ServiceType::SmallAirstrip,
+        ServiceType::RegionalAirport,
+        ServiceType::InternationalAirport,
+        ServiceType::TransferStation,
+        ServiceType::CellTower,
+        ServiceType::DataCenter,
+        ServiceType::HomelessShelter,
+        ServiceType::WelfareOffice,
+        ServiceType::PostOffice,
+        ServiceType::MailSortingCenter,
+        ServiceType::HeatingBoiler,
+        ServiceType::DistrictHeatingPlant,
+        ServiceType::GeothermalPlant,
+        ServiceType::WaterTreatmentPlant,
+        ServiceType::WellPump,
+        ServiceType::Daycare,
+        ServiceType::Eldercare,
+        ServiceType::CommunityCenter,
+        ServiceType::SubstanceAbuseTreatmentCenter,
+        ServiceType::SeniorCenter,
+        ServiceType::YouthCenter,
+        ServiceType::HazmatResponse,
+        ServiceType::Hotel,
+        ServiceType::ConventionCenter,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             ServiceType::FireStation => "Fire Station",
@@ -121,6 +187,9 @@ impl ServiceType {
             ServiceType::SubstanceAbuseTreatmentCenter => "Substance Abuse Treatment Center",
             ServiceType::SeniorCenter => "Senior Center",
             ServiceType::YouthCenter => "Youth Center",
+            ServiceType::HazmatResponse => "Hazmat Response Center",
+            ServiceType::Hotel => "Hotel",
+            ServiceType::ConventionCenter => "Convention Center",
         }
     }
 }
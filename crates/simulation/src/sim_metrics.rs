@@ -0,0 +1,337 @@
+//! Runtime metrics for monitoring long runs from outside the game.
+//!
+//! While a `SimMetrics` resource exists, the systems here time each
+//! `FixedUpdate` phase of every tick and record entity counts, population,
+//! treasury and pathfinding backlog into it. Nothing is collected otherwise,
+//! so the normal game pays nothing; the app's `--metrics <port>` exporter
+//! inserts the resource and serves [`SimMetrics::to_prometheus`] over HTTP.
+//!
+//! Phase durations are wall-clock time from the end of one phase to the end
+//! of the next, so they include executor overhead and any work the
+//! multi-threaded executor overlapped with them. The timing systems sit
+//! between the `SimulationSet` phases rather than in one, the only
+//! `FixedUpdate` systems that do.
+//!
+//! Per-system durations come from `trace` spans, which only exist in
+//! `--features trace` builds: the exporter times every span exit and copies
+//! the histograms into [`SimMetrics::system_seconds`].
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use crate::citizen::{Citizen, PathRequest};
+use crate::economy::CityBudget;
+use crate::movement::ComputingPath;
+use crate::stats::CityStats;
+use crate::{SimulationSet, TickCounter};
+
+/// The timed `FixedUpdate` phases, in order, as named in the exported metrics.
+pub const PHASES: [&str; 3] = ["pre_sim", "simulation", "post_sim"];
+
+/// Upper bounds, in seconds, of the per-system duration histogram buckets.
+pub const SYSTEM_SECONDS_BUCKETS: [f64; 8] = [
+    0.000_01, 0.000_05, 0.000_1, 0.000_5, 0.001, 0.005, 0.01, 0.05,
+];
+
+/// Durations of every run of one system.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DurationHistogram {
+    /// Runs that took at most each bound of `SYSTEM_SECONDS_BUCKETS` and
+    /// more than the one before it.
+    pub buckets: [u64; SYSTEM_SECONDS_BUCKETS.len()],
+    pub count: u64,
+    pub sum_seconds: f64,
+}
+
+impl DurationHistogram {
+    pub fn observe(&mut self, seconds: f64) {
+        if let Some(bucket) = SYSTEM_SECONDS_BUCKETS.iter().position(|&le| seconds <= le) {
+            self.buckets[bucket] += 1;
+        }
+        self.count += 1;
+        self.sum_seconds += seconds;
+    }
+}
+
+/// Latest values of the exported metrics.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SimMetrics {
+    /// Simulation tick the values were taken at.
+    pub tick: u64,
+    /// Ticks measured since collection started.
+    pub ticks_measured: u64,
+    /// Wall-clock seconds each phase took in the last tick.
+    pub phase_seconds: [f64; 3],
+    /// Wall-clock seconds each phase has taken over all measured ticks.
+    pub phase_seconds_total: [f64; 3],
+    pub entities: u32,
+    pub citizen_entities: u32,
+    /// Population including virtual citizens, as shown to the player.
+    pub population: u32,
+    pub treasury: f64,
+    /// Citizens waiting for a pathfinding task to be started.
+    pub path_requests_queued: u32,
+    /// Pathfinding tasks started but not yet collected.
+    pub paths_computing: u32,
+    /// Run durations since collection started, by system name.
+    pub system_seconds: BTreeMap<String, DurationHistogram>,
+}
+
+impl SimMetrics {
+    /// The metrics in the Prometheus text exposition format.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: &[(&str, f64)]| {
+            let _ = writeln!(out, "# HELP megacity_{name} {help}");
+            let _ = writeln!(out, "# TYPE megacity_{name} {kind}");
+            for (labels, value) in samples {
+                let _ = writeln!(out, "megacity_{name}{labels} {value}");
+            }
+        };
+        let phase_labels = PHASES.map(|phase| format!("{{phase=\"{phase}\"}}"));
+        let per_phase = |values: [f64; 3]| {
+            let labels = phase_labels.each_ref().map(String::as_str);
+            labels.into_iter().zip(values).collect::<Vec<_>>()
+        };
+
+        metric(
+            "tick",
+            "gauge",
+            "Current simulation tick.",
+            &[("", self.tick as f64)],
+        );
+        metric(
+            "ticks_measured_total",
+            "counter",
+            "Simulation ticks timed since metrics collection started.",
+            &[("", self.ticks_measured as f64)],
+        );
+        metric(
+            "tick_phase_seconds",
+            "gauge",
+            "Wall-clock time of each FixedUpdate phase in the last tick.",
+            &per_phase(self.phase_seconds),
+        );
+        metric(
+            "tick_phase_seconds_total",
+            "counter",
+            "Wall-clock time spent in each FixedUpdate phase.",
+            &per_phase(self.phase_seconds_total),
+        );
+        metric(
+            "entities",
+            "gauge",
+            "ECS entities.",
+            &[("", self.entities.into())],
+        );
+        metric(
+            "citizen_entities",
+            "gauge",
+            "Citizen entities (excluding virtual population).",
+            &[("", self.citizen_entities.into())],
+        );
+        metric(
+            "population",
+            "gauge",
+            "City population including virtual citizens.",
+            &[("", self.population.into())],
+        );
+        metric(
+            "treasury",
+            "gauge",
+            "City treasury.",
+            &[("", self.treasury)],
+        );
+        metric(
+            "pathfinding_queue_depth",
+            "gauge",
+            "Pathfinding requests by state.",
+            &[
+                ("{state=\"queued\"}", self.path_requests_queued.into()),
+                ("{state=\"computing\"}", self.paths_computing.into()),
+            ],
+        );
+        self.write_system_seconds(&mut out);
+        out
+    }
+
+    /// The per-system duration histograms, cumulative buckets as Prometheus
+    /// expects.
+    fn write_system_seconds(&self, out: &mut String) {
+        if self.system_seconds.is_empty() {
+            return;
+        }
+        let name = "megacity_system_seconds";
+        let _ = writeln!(
+            out,
+            "# HELP {name} Wall-clock time of each run of a system, from its trace span."
+        );
+        let _ = writeln!(out, "# TYPE {name} histogram");
+        for (system, histogram) in &self.system_seconds {
+            let mut runs = 0;
+            for (le, count) in SYSTEM_SECONDS_BUCKETS.iter().zip(histogram.buckets) {
+                runs += count;
+                let _ = writeln!(
+                    out,
+                    "{name}_bucket{{system=\"{system}\",le=\"{le}\"}} {runs}"
+                );
+            }
+            let count = histogram.count;
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{system=\"{system}\",le=\"+Inf\"}} {count}"
+            );
+            let _ = writeln!(
+                out,
+                "{name}_sum{{system=\"{system}\"}} {}",
+                histogram.sum_seconds
+            );
+            let _ = writeln!(out, "{name}_count{{system=\"{system}\"}} {count}");
+        }
+    }
+}
+
+/// When the current tick and each of its phases ended.
+#[derive(Resource, Default)]
+struct PhaseClock {
+    tick_start: Option<Instant>,
+    phase_ends: [Option<Instant>; 3],
+}
+
+fn start_tick_clock(mut clock: ResMut<PhaseClock>) {
+    *clock = PhaseClock {
+        tick_start: Some(Instant::now()),
+        ..default()
+    };
+}
+
+fn end_phase<const PHASE: usize>(mut clock: ResMut<PhaseClock>) {
+    clock.phase_ends[PHASE] = Some(Instant::now());
+}
+
+/// System: turn the phase clock into durations and sample the gauges.
+#[allow(clippy::too_many_arguments)]
+pub fn record_tick_metrics(
+    clock: Res<PhaseClock>,
+    mut metrics: ResMut<SimMetrics>,
+    tick: Res<TickCounter>,
+    entities: &Entities,
+    citizens: Query<(), With<Citizen>>,
+    queued: Query<(), With<PathRequest>>,
+    computing: Query<(), With<ComputingPath>>,
+    stats: Res<CityStats>,
+    budget: Res<CityBudget>,
+) {
+    let Some(mut previous) = clock.tick_start else {
+        return;
+    };
+    for (phase, end) in clock.phase_ends.iter().enumerate() {
+        let end = end.unwrap_or(previous);
+        let seconds = end.saturating_duration_since(previous).as_secs_f64();
+        metrics.phase_seconds[phase] = seconds;
+        metrics.phase_seconds_total[phase] += seconds;
+        previous = end;
+    }
+    metrics.ticks_measured += 1;
+    metrics.tick = tick.0;
+    metrics.entities = entities.len();
+    metrics.citizen_entities = citizens.iter().count() as u32;
+    metrics.population = stats.population;
+    metrics.treasury = budget.treasury;
+    metrics.path_requests_queued = queued.iter().count() as u32;
+    metrics.paths_computing = computing.iter().count() as u32;
+}
+
+pub struct SimMetricsPlugin;
+
+impl Plugin for SimMetricsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhaseClock>().add_systems(
+            FixedUpdate,
+            (
+                start_tick_clock.before(SimulationSet::PreSim),
+                end_phase::<0>
+                    .after(SimulationSet::PreSim)
+                    .before(SimulationSet::Simulation),
+                end_phase::<1>
+                    .after(SimulationSet::Simulation)
+                    .before(SimulationSet::PostSim),
+                (end_phase::<2>, record_tick_metrics)
+                    .chain()
+                    .after(SimulationSet::PostSim),
+            )
+                .run_if(resource_exists::<SimMetrics>),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::TestCity;
+
+    #[test]
+    fn test_prometheus_text_has_help_type_and_labels() {
+        let metrics = SimMetrics {
+            tick: 42,
+            phase_seconds: [0.001, 0.004, 0.0005],
+            population: 1200,
+            treasury: 50_000.5,
+            path_requests_queued: 7,
+            ..default()
+        };
+        let text = metrics.to_prometheus();
+        assert!(text.contains("# TYPE megacity_tick gauge\nmegacity_tick 42\n"));
+        assert!(text.contains("megacity_tick_phase_seconds{phase=\"simulation\"} 0.004\n"));
+        assert!(text.contains("megacity_population 1200\n"));
+        assert!(text.contains("megacity_treasury 50000.5\n"));
+        assert!(text.contains("megacity_pathfinding_queue_depth{state=\"queued\"} 7\n"));
+        assert!(text.ends_with('\n'));
+        assert!(!text.contains("megacity_system_seconds"));
+    }
+
+    #[test]
+    fn test_system_histogram_buckets_are_cumulative() {
+        let mut histogram = DurationHistogram::default();
+        for seconds in [0.000_02, 0.000_03, 0.002, 1.0] {
+            histogram.observe(seconds);
+        }
+        let mut metrics = SimMetrics::default();
+        metrics
+            .system_seconds
+            .insert("update_traffic".to_string(), histogram);
+        let text = metrics.to_prometheus();
+        let sample = |line: &str| text.contains(&format!("megacity_system_seconds{line}\n"));
+        assert!(text.contains("# TYPE megacity_system_seconds histogram\n"));
+        assert!(sample(
+            "_bucket{system=\"update_traffic\",le=\"0.00001\"} 0"
+        ));
+        assert!(sample(
+            "_bucket{system=\"update_traffic\",le=\"0.00005\"} 2"
+        ));
+        assert!(sample("_bucket{system=\"update_traffic\",le=\"0.005\"} 3"));
+        assert!(sample("_bucket{system=\"update_traffic\",le=\"0.05\"} 3"));
+        assert!(sample("_bucket{system=\"update_traffic\",le=\"+Inf\"} 4"));
+        assert!(sample("_count{system=\"update_traffic\"} 4"));
+    }
+
+    #[test]
+    fn test_metrics_collected_only_when_enabled() {
+        let mut city = TestCity::new().with_budget(12_345.0);
+        city.tick(2);
+        assert!(city.world_mut().get_resource::<SimMetrics>().is_none());
+
+        city.world_mut().init_resource::<SimMetrics>();
+        city.tick(3);
+        let metrics = city.resource::<SimMetrics>();
+        assert_eq!(metrics.ticks_measured, 3);
+        assert_eq!(metrics.tick, city.resource::<TickCounter>().0);
+        assert!(metrics.entities > 0);
+        assert!(metrics.phase_seconds_total[1] > 0.0);
+        assert!(metrics.treasury > 0.0);
+    }
+}
@@ -30,6 +30,21 @@
 //!   state and never mutate it, so downstream systems (UI, rendering) can
 //!   safely consume their output on the next frame.
 //!
+//! # Grid propagation stages (`GridPropagationSet`)
+//!
+//! ```text
+//! Emission  →  Exposure  →  Outcome      (inside Simulation)
+//! ```
+//!
+//! The slow grid systems are ordered by what they read rather than chained
+//! one after another, so systems within a stage run in parallel:
+//!
+//! * **Emission** – grids computed from the city itself: air pollution and
+//!   noise.
+//! * **Exposure** – grids that read emissions: land value and groundwater
+//!   (both read `PollutionGrid`).
+//! * **Outcome** – grids that read exposure: crime (reads `LandValueGrid`).
+//!
 //! # Update phases (`SimulationUpdateSet`)
 //!
 //! ```text
@@ -66,6 +81,38 @@ pub enum SimulationSet {
     PostSim,
 }
 
+/// Stages of the slow grid propagation systems, chained inside
+/// `SimulationSet::Simulation`. A system goes in the earliest stage after
+/// every grid it reads; systems in the same stage must not write anything
+/// another one reads, so the scheduler can run them side by side.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GridPropagationSet {
+    /// Air pollution and noise, from buildings, roads and traffic.
+    Emission,
+    /// Land value and groundwater, which read the emission grids.
+    Exposure,
+    /// Crime, which reads land value.
+    Outcome,
+}
+
+/// Chains the `GridPropagationSet` stages inside `SimulationSet::Simulation`.
+pub struct GridPropagationPlugin;
+
+impl Plugin for GridPropagationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            FixedUpdate,
+            (
+                GridPropagationSet::Emission,
+                GridPropagationSet::Exposure,
+                GridPropagationSet::Outcome,
+            )
+                .chain()
+                .in_set(SimulationSet::Simulation),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Update phases
 // ---------------------------------------------------------------------------
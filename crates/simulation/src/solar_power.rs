@@ -57,11 +57,17 @@ pub fn seasonal_capacity_factor(season: Season) -> f32 {
 /// - Peak at noon (hour 12): 1.0.
 /// - Smooth sinusoidal curve between sunrise (6) and sunset (18).
 pub fn time_of_day_curve(hour: f32) -> f32 {
-    if !(6.0..18.0).contains(&hour) {
+    daylight_curve(hour, 6.0, 18.0)
+}
+
+/// Like [`time_of_day_curve`], but between the given sunrise and sunset so
+/// that long summer days generate for longer than short winter ones.
+pub fn daylight_curve(hour: f32, sunrise: f32, sunset: f32) -> f32 {
+    if !(sunrise..sunset).contains(&hour) {
         return 0.0;
     }
-    // Map [6, 18] -> [0, PI] for a sine curve peaking at noon.
-    let t = (hour - 6.0) / 12.0 * std::f32::consts::PI;
+    // Map [sunrise, sunset] -> [0, PI] for a sine curve peaking at midday.
+    let t = (hour - sunrise) / (sunset - sunrise) * std::f32::consts::PI;
     t.sin()
 }
 
@@ -172,7 +178,7 @@ pub fn update_solar_power(
         .count() as u32;
 
     let capacity_factor = seasonal_capacity_factor(weather.season);
-    let time_curve = time_of_day_curve(clock.hour);
+    let time_curve = daylight_curve(clock.hour, clock.sunrise(), clock.sunset());
     let weather_mod = weather_modifier(weather.current_event);
 
     let output_per_farm = SOLAR_NAMEPLATE_MW * capacity_factor * time_curve * weather_mod;
@@ -230,6 +236,14 @@ mod tests {
         assert_eq!(time_of_day_curve(23.9), 0.0);
     }
 
+    #[test]
+    fn test_daylight_curve_follows_sunrise_and_sunset() {
+        assert_eq!(daylight_curve(5.5, 5.0, 19.0), daylight_curve(18.5, 5.0, 19.0));
+        assert!(daylight_curve(5.5, 5.0, 19.0) > 0.0, "summer dawn generates");
+        assert_eq!(daylight_curve(6.5, 7.0, 17.0), 0.0, "winter dawn is dark");
+        assert!((daylight_curve(12.0, 7.0, 17.0) - 1.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_time_curve_peaks_at_noon() {
         let noon = time_of_day_curve(12.0);
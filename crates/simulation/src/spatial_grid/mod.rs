@@ -0,0 +1,194 @@
+//! Two-level spatial index over world positions.
+//!
+//! Entities live in fine 128 px buckets. Every 8×8 buckets form a coarse
+//! block that keeps a count of its entities, so rect, radius and
+//! nearest-neighbour queries skip empty parts of the map a block at a time
+//! instead of visiting every bucket. The citizen index is rebuilt by
+//! `lod::update_spatial_grid`; slow systems that search a handful of
+//! buildings (shelters, emergency stations) build their own.
+
+mod nearest;
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_nearest;
+
+use bevy::prelude::*;
+
+use crate::config::{CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+
+const BUCKET_SIZE: f32 = 128.0; // pixels per spatial bucket
+const BUCKETS_X: usize = (GRID_WIDTH as f32 * CELL_SIZE / BUCKET_SIZE) as usize + 1;
+const BUCKETS_Y: usize = (GRID_HEIGHT as f32 * CELL_SIZE / BUCKET_SIZE) as usize + 1;
+const TOTAL_BUCKETS: usize = BUCKETS_X * BUCKETS_Y;
+
+/// Buckets per side of a coarse block.
+const BLOCK_BUCKETS: usize = 8;
+const BLOCKS_X: usize = BUCKETS_X.div_ceil(BLOCK_BUCKETS);
+const BLOCKS_Y: usize = BUCKETS_Y.div_ceil(BLOCK_BUCKETS);
+
+/// An indexed entity and the position it was inserted at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Resource)]
+pub struct SpatialGrid {
+    buckets: Vec<Vec<SpatialEntry>>,
+    /// Entities per coarse block.
+    block_counts: Vec<u32>,
+    len: usize,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self {
+            buckets: (0..TOTAL_BUCKETS).map(|_| Vec::new()).collect(),
+            block_counts: vec![0; BLOCKS_X * BLOCKS_Y],
+            len: 0,
+        }
+    }
+}
+
+impl SpatialGrid {
+    pub fn clear(&mut self) {
+        for block in 0..self.block_counts.len() {
+            if self.block_counts[block] == 0 {
+                continue;
+            }
+            let (bx0, by0, bx1, by1) = Self::block_buckets(block);
+            for by in by0..by1 {
+                for bx in bx0..bx1 {
+                    self.buckets[by * BUCKETS_X + bx].clear();
+                }
+            }
+            self.block_counts[block] = 0;
+        }
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, x: f32, y: f32) {
+        let bx = (x / BUCKET_SIZE).floor() as i32;
+        let by = (y / BUCKET_SIZE).floor() as i32;
+        if let Some(idx) = Self::flat_index(bx, by) {
+            self.buckets[idx].push(SpatialEntry { entity, x, y });
+            self.block_counts[Self::block_of(bx as usize, by as usize)] += 1;
+            self.len += 1;
+        }
+    }
+
+    /// Entities in every bucket the rect touches. Bucket-granular: entities
+    /// just outside the rect but in a touched bucket are included.
+    pub fn query_rect(&self, min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Vec<Entity> {
+        let mut result = Vec::new();
+        self.for_each_bucket_in(min_x, min_y, max_x, max_y, |bucket| {
+            result.extend(bucket.iter().map(|e| e.entity));
+        });
+        result
+    }
+
+    /// Entities within `radius` of `(x, y)`.
+    pub fn query_radius(&self, x: f32, y: f32, radius: f32) -> Vec<Entity> {
+        let r2 = radius * radius;
+        let mut result = Vec::new();
+        self.for_each_bucket_in(x - radius, y - radius, x + radius, y + radius, |bucket| {
+            result.extend(
+                bucket
+                    .iter()
+                    .filter(|e| (e.x - x).powi(2) + (e.y - y).powi(2) <= r2)
+                    .map(|e| e.entity),
+            );
+        });
+        result
+    }
+
+    /// Call `f` with each non-empty bucket overlapping the rect, skipping
+    /// empty coarse blocks.
+    fn for_each_bucket_in(
+        &self,
+        min_x: f32,
+        min_y: f32,
+        max_x: f32,
+        max_y: f32,
+        mut f: impl FnMut(&[SpatialEntry]),
+    ) {
+        let Some((min_bx, min_by, max_bx, max_by)) = Self::bucket_range(min_x, min_y, max_x, max_y)
+        else {
+            return;
+        };
+        for block_y in min_by / BLOCK_BUCKETS..=max_by / BLOCK_BUCKETS {
+            for block_x in min_bx / BLOCK_BUCKETS..=max_bx / BLOCK_BUCKETS {
+                if self.block_counts[block_y * BLOCKS_X + block_x] == 0 {
+                    continue;
+                }
+                let by0 = (block_y * BLOCK_BUCKETS).max(min_by);
+                let by1 = ((block_y + 1) * BLOCK_BUCKETS - 1).min(max_by);
+                let bx0 = (block_x * BLOCK_BUCKETS).max(min_bx);
+                let bx1 = ((block_x + 1) * BLOCK_BUCKETS - 1).min(max_bx);
+                for by in by0..=by1 {
+                    for bx in bx0..=bx1 {
+                        let bucket = &self.buckets[by * BUCKETS_X + bx];
+                        if !bucket.is_empty() {
+                            f(bucket);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Inclusive bucket range covered by a rect, clipped to the grid.
+    fn bucket_range(
+        min_x: f32,
+        min_y: f32,
+        max_x: f32,
+        max_y: f32,
+    ) -> Option<(usize, usize, usize, usize)> {
+        let min_bx = ((min_x / BUCKET_SIZE).floor() as i32).max(0);
+        let min_by = ((min_y / BUCKET_SIZE).floor() as i32).max(0);
+        let max_bx = ((max_x / BUCKET_SIZE).floor() as i32).min(BUCKETS_X as i32 - 1);
+        let max_by = ((max_y / BUCKET_SIZE).floor() as i32).min(BUCKETS_Y as i32 - 1);
+        if min_bx > max_bx || min_by > max_by {
+            return None;
+        }
+        Some((
+            min_bx as usize,
+            min_by as usize,
+            max_bx as usize,
+            max_by as usize,
+        ))
+    }
+
+    #[inline]
+    fn flat_index(bx: i32, by: i32) -> Option<usize> {
+        if bx >= 0 && by >= 0 && (bx as usize) < BUCKETS_X && (by as usize) < BUCKETS_Y {
+            Some(by as usize * BUCKETS_X + bx as usize)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn block_of(bx: usize, by: usize) -> usize {
+        (by / BLOCK_BUCKETS) * BLOCKS_X + bx / BLOCK_BUCKETS
+    }
+
+    /// Bucket range `[x0, x1) × [y0, y1)` of a coarse block.
+    fn block_buckets(block: usize) -> (usize, usize, usize, usize) {
+        let bx0 = (block % BLOCKS_X) * BLOCK_BUCKETS;
+        let by0 = (block / BLOCKS_X) * BLOCK_BUCKETS;
+        (
+            bx0,
+            by0,
+            (bx0 + BLOCK_BUCKETS).min(BUCKETS_X),
+            (by0 + BLOCK_BUCKETS).min(BUCKETS_Y),
+        )
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.len
+    }
+}
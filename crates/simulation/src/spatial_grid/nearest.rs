@@ -0,0 +1,111 @@
+//! Nearest-neighbour search over the two-level grid.
+//!
+//! Non-empty coarse blocks are visited nearest first and the search stops
+//! once the next block is farther than the `k`-th best hit so far; inside a
+//! block, buckets farther than that bound are skipped without looking at
+//! their entries.
+
+use bevy::prelude::*;
+
+use super::{SpatialGrid, BUCKETS_X, BUCKET_SIZE};
+
+impl SpatialGrid {
+    /// The `k` entities closest to `(x, y)` and no farther than
+    /// `max_distance`, nearest first, with their distances. Equally distant
+    /// entities are ordered by `Entity` so results are deterministic.
+    pub fn k_nearest(&self, x: f32, y: f32, k: usize, max_distance: f32) -> Vec<(Entity, f32)> {
+        self.k_nearest_matching(x, y, k, max_distance, |_| true)
+    }
+
+    /// The closest entity to `(x, y)` within `max_distance` that `accept`
+    /// allows.
+    pub fn nearest_matching(
+        &self,
+        x: f32,
+        y: f32,
+        max_distance: f32,
+        accept: impl FnMut(Entity) -> bool,
+    ) -> Option<(Entity, f32)> {
+        self.k_nearest_matching(x, y, 1, max_distance, accept)
+            .into_iter()
+            .next()
+    }
+
+    /// `k_nearest` restricted to the entities `accept` allows. `accept` is
+    /// only asked about entities that would make the cut by distance.
+    pub fn k_nearest_matching(
+        &self,
+        x: f32,
+        y: f32,
+        k: usize,
+        max_distance: f32,
+        mut accept: impl FnMut(Entity) -> bool,
+    ) -> Vec<(Entity, f32)> {
+        if k == 0 || self.len == 0 {
+            return Vec::new();
+        }
+        let limit = max_distance * max_distance;
+        // (squared distance, entity), sorted
+        let mut best: Vec<(f32, Entity)> = Vec::with_capacity(k + 1);
+        let bound = |best: &Vec<(f32, Entity)>| {
+            if best.len() == k {
+                best[k - 1].0
+            } else {
+                limit
+            }
+        };
+
+        let mut blocks: Vec<(f32, usize)> = self
+            .block_counts
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(block, _)| {
+                let (bx0, by0, bx1, by1) = Self::block_buckets(block);
+                (bucket_rect_distance2(x, y, bx0, by0, bx1, by1), block)
+            })
+            .filter(|&(d2, _)| d2 <= limit)
+            .collect();
+        blocks.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+        for (block_d2, block) in blocks {
+            if block_d2 > bound(&best) {
+                break;
+            }
+            let (bx0, by0, bx1, by1) = Self::block_buckets(block);
+            for by in by0..by1 {
+                for bx in bx0..bx1 {
+                    let bucket = &self.buckets[by * BUCKETS_X + bx];
+                    if bucket.is_empty()
+                        || bucket_rect_distance2(x, y, bx, by, bx + 1, by + 1) > bound(&best)
+                    {
+                        continue;
+                    }
+                    for entry in bucket {
+                        let d2 = (entry.x - x).powi(2) + (entry.y - y).powi(2);
+                        if d2 > bound(&best) || !accept(entry.entity) {
+                            continue;
+                        }
+                        let at = best.partition_point(|&(d, e)| {
+                            d < d2 || (d == d2 && e.to_bits() < entry.entity.to_bits())
+                        });
+                        best.insert(at, (d2, entry.entity));
+                        best.truncate(k);
+                    }
+                }
+            }
+        }
+        best.into_iter().map(|(d2, e)| (e, d2.sqrt())).collect()
+    }
+}
+
+/// Squared distance from `(x, y)` to the bucket rect `[bx0, bx1) × [by0, by1)`.
+fn bucket_rect_distance2(x: f32, y: f32, bx0: usize, by0: usize, bx1: usize, by1: usize) -> f32 {
+    let dx = (bx0 as f32 * BUCKET_SIZE - x)
+        .max(x - bx1 as f32 * BUCKET_SIZE)
+        .max(0.0);
+    let dy = (by0 as f32 * BUCKET_SIZE - y)
+        .max(y - by1 as f32 * BUCKET_SIZE)
+        .max(0.0);
+    dx * dx + dy * dy
+}
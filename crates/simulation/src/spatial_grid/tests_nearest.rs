@@ -0,0 +1,110 @@
+use super::*;
+
+// ------------------------------------------------------------------
+// Exact radius and nearest-neighbour queries
+// ------------------------------------------------------------------
+
+#[test]
+fn test_query_radius_is_exact() {
+    let mut grid = SpatialGrid::default();
+    let inside = Entity::from_raw(1);
+    let same_bucket_outside = Entity::from_raw(2);
+    let other_bucket = Entity::from_raw(3);
+    grid.insert(inside, 1030.0, 1030.0);
+    grid.insert(same_bucket_outside, 1150.0, 1150.0);
+    grid.insert(other_bucket, 1200.0, 1024.0);
+
+    let result = grid.query_radius(1024.0, 1024.0, 100.0);
+    assert_eq!(result, vec![inside]);
+}
+
+#[test]
+fn test_k_nearest_orders_by_distance_then_entity() {
+    let mut grid = SpatialGrid::default();
+    grid.insert(Entity::from_raw(5), 2100.0, 2000.0); // 100 px
+    grid.insert(Entity::from_raw(3), 2000.0, 2100.0); // 100 px
+    grid.insert(Entity::from_raw(1), 2030.0, 2040.0); // 50 px
+    grid.insert(Entity::from_raw(9), 3000.0, 2000.0); // 1000 px
+
+    let result = grid.k_nearest(2000.0, 2000.0, 3, f32::INFINITY);
+    let order: Vec<u32> = result.iter().map(|(e, _)| e.index()).collect();
+    assert_eq!(order, vec![1, 3, 5]);
+    assert!((result[0].1 - 50.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_k_nearest_respects_max_distance() {
+    let mut grid = SpatialGrid::default();
+    grid.insert(Entity::from_raw(1), 100.0, 100.0);
+    grid.insert(Entity::from_raw(2), 3000.0, 3000.0);
+
+    let result = grid.k_nearest(100.0, 150.0, 5, 500.0);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, Entity::from_raw(1));
+    assert!(grid.k_nearest(2000.0, 2000.0, 5, 100.0).is_empty());
+}
+
+#[test]
+fn test_nearest_matching_skips_rejected_entities() {
+    let mut grid = SpatialGrid::default();
+    let full = Entity::from_raw(1);
+    let open = Entity::from_raw(2);
+    grid.insert(full, 500.0, 500.0);
+    grid.insert(open, 3500.0, 3500.0);
+
+    let found = grid.nearest_matching(400.0, 400.0, f32::INFINITY, |e| e != full);
+    assert_eq!(found.map(|(e, _)| e), Some(open));
+    assert_eq!(
+        grid.nearest_matching(400.0, 400.0, 1000.0, |e| e != full),
+        None
+    );
+}
+
+#[test]
+fn test_k_nearest_matches_brute_force() {
+    let mut grid = SpatialGrid::default();
+    let mut points = Vec::new();
+    let mut seed: u32 = 12345;
+    let mut next = || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (seed >> 8) as f32 / (1 << 24) as f32 * 4095.0
+    };
+    for i in 0..2000 {
+        let (x, y) = (next(), next());
+        grid.insert(Entity::from_raw(i), x, y);
+        points.push((Entity::from_raw(i), x, y));
+    }
+
+    for _ in 0..50 {
+        let (qx, qy) = (next(), next());
+        let mut expected: Vec<(f32, Entity)> = points
+            .iter()
+            .map(|&(e, x, y)| ((x - qx).powi(2) + (y - qy).powi(2), e))
+            .collect();
+        expected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.to_bits().cmp(&b.1.to_bits())));
+        let expected: Vec<Entity> = expected.iter().take(8).map(|&(_, e)| e).collect();
+
+        let found: Vec<Entity> = grid
+            .k_nearest(qx, qy, 8, f32::INFINITY)
+            .into_iter()
+            .map(|(e, _)| e)
+            .collect();
+        assert_eq!(found, expected);
+    }
+}
+
+#[test]
+fn test_clear_resets_coarse_blocks() {
+    let mut grid = SpatialGrid::default();
+    grid.insert(Entity::from_raw(1), 100.0, 100.0);
+    grid.insert(Entity::from_raw(2), 3000.0, 100.0);
+    grid.clear();
+
+    assert!(grid.block_counts.iter().all(|&c| c == 0));
+    assert!(grid.k_nearest(100.0, 100.0, 1, f32::INFINITY).is_empty());
+    grid.insert(Entity::from_raw(3), 3000.0, 100.0);
+    assert_eq!(
+        grid.k_nearest(100.0, 100.0, 1, f32::INFINITY)[0].0,
+        Entity::from_raw(3)
+    );
+}
@@ -115,7 +115,7 @@ pub fn compute_state_hash(
 // ECS system
 // ---------------------------------------------------------------------------
 
-fn update_state_hash(
+pub(crate) fn update_state_hash(
     tick: Res<TickCounter>,
     budget: Res<CityBudget>,
     stats: Res<CityStats>,
@@ -0,0 +1,20 @@
+//! Street lighting: lamps along the roads and the dark streets between them.
+//!
+//! New road segments are lined with lamps automatically, and the player can
+//! place or remove lamps by hand. A lamp lights the cells around it while
+//! it has power; in a blackout street lighting is the first load shed, so
+//! lamps go dark with the outage.
+//!
+//! After dark, developed cells no lamp reaches draw extra crime, and fewer
+//! citizens are willing to make optional trips to or from unlit streets.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    apply_unlit_night_crime, place_segment_lamps, update_street_light_coverage,
+    StreetLightingPlugin,
+};
+pub use types::*;
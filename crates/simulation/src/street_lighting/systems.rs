@@ -0,0 +1,96 @@
+//! Lamp placement along new roads, lit-cell coverage and the night crime
+//! of dark streets.
+
+use bevy::prelude::*;
+
+use crate::blackout::BlackoutState;
+use crate::crime::CrimeGrid;
+use crate::grid::WorldGrid;
+use crate::road_segments::RoadSegmentStore;
+use crate::time_of_day::GameClock;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Line newly drawn road segments with lamps.
+pub fn place_segment_lamps(segments: Res<RoadSegmentStore>, mut lighting: ResMut<StreetLighting>) {
+    if !segments.is_changed() || !lighting.auto_place {
+        return;
+    }
+    // Only touch the resource when a segment is actually new.
+    let new_segments: Vec<_> = segments
+        .segments
+        .iter()
+        .filter(|segment| !lighting.lamped_segments.contains(&segment.id.0))
+        .collect();
+    for segment in new_segments {
+        lighting.light_segment(segment);
+    }
+}
+
+/// Drop lamps on bulldozed roads and work out which cells are lit.
+pub fn update_street_light_coverage(
+    slow_timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    segments: Res<RoadSegmentStore>,
+    blackout: Res<BlackoutState>,
+    mut lighting: ResMut<StreetLighting>,
+    mut coverage: ResMut<StreetLightCoverage>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    if grid.is_changed() {
+        lighting.prune(&grid, &segments.segments);
+    }
+    coverage.recompute(&lighting, &grid, &blackout);
+}
+
+/// After dark, developed cells no lamp lights draw extra crime.
+pub fn apply_unlit_night_crime(
+    slow_timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    grid: Res<WorldGrid>,
+    coverage: Res<StreetLightCoverage>,
+    mut crime: ResMut<CrimeGrid>,
+) {
+    if !slow_timer.should_run() || clock.is_daylight() {
+        return;
+    }
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            if coverage.is_lit(x, y) || !is_developed_cell(&grid, x, y) {
+                continue;
+            }
+            let level = crime.get(x, y);
+            crime.set(x, y, level.saturating_add(UNLIT_NIGHT_CRIME));
+        }
+    }
+}
+
+pub struct StreetLightingPlugin;
+
+impl Plugin for StreetLightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StreetLighting>()
+            .init_resource::<StreetLightCoverage>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    place_segment_lamps,
+                    update_street_light_coverage
+                        .after(place_segment_lamps)
+                        .after(crate::blackout::evaluate_blackout),
+                    apply_unlit_night_crime
+                        .after(update_street_light_coverage)
+                        .after(crate::crime::update_crime),
+                )
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<StreetLighting>();
+    }
+}
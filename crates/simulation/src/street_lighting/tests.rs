@@ -0,0 +1,132 @@
+use bevy::math::Vec2;
+
+use super::*;
+use crate::blackout::BlackoutState;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, RoadType, WorldGrid};
+use crate::road_segments::RoadSegmentStore;
+use crate::roads::RoadNetwork;
+use crate::Saveable;
+
+/// A grid with a straight powered road along row `y` from `x0` to `x1`,
+/// and the segment store holding it.
+fn road_grid(y: usize, x0: usize, x1: usize) -> (WorldGrid, RoadSegmentStore) {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut roads = RoadNetwork::default();
+    let mut store = RoadSegmentStore::default();
+    let (wx0, wy) = WorldGrid::grid_to_world(x0, y);
+    let (wx1, _) = WorldGrid::grid_to_world(x1, y);
+    store.add_straight_segment(
+        Vec2::new(wx0, wy),
+        Vec2::new(wx1, wy),
+        RoadType::Local,
+        16.0,
+        &mut grid,
+        &mut roads,
+    );
+    for cell in &mut grid.cells {
+        if cell.cell_type == CellType::Road {
+            cell.has_power = true;
+        }
+    }
+    (grid, store)
+}
+
+#[test]
+fn test_segments_are_lined_once() {
+    let (_, store) = road_grid(50, 40, 60);
+    let segment = &store.segments[0];
+    let mut lighting = StreetLighting::default();
+    lighting.light_segment(segment);
+
+    let placed = lighting.iter().count();
+    let expected = segment.rasterized_cells.len().div_ceil(LAMP_SPACING_CELLS);
+    assert_eq!(placed, expected);
+    assert!(lighting
+        .iter()
+        .all(|(_, _, origin)| origin == LampOrigin::Auto));
+
+    // A lamp the player removed stays removed.
+    let (x, y, _) = lighting.iter().next().unwrap();
+    assert!(lighting.remove(x, y));
+    lighting.light_segment(segment);
+    assert_eq!(lighting.iter().count(), expected - 1);
+}
+
+#[test]
+fn test_lamps_on_bulldozed_roads_are_dropped() {
+    let (mut grid, store) = road_grid(50, 40, 60);
+    let mut lighting = StreetLighting::default();
+    lighting.light_segment(&store.segments[0]);
+    let (x, y, _) = lighting.iter().next().unwrap();
+    grid.get_mut(x, y).cell_type = CellType::Grass;
+
+    lighting.prune(&grid, &[]);
+    assert!(lighting.lamp_at(x, y).is_none());
+    assert!(lighting.lamped_segments.is_empty());
+}
+
+#[test]
+fn test_powered_lamps_light_nearby_cells() {
+    let (grid, _) = road_grid(50, 40, 60);
+    let mut lighting = StreetLighting::default();
+    lighting.place(50, 50, LampOrigin::Manual);
+    let mut coverage = StreetLightCoverage::default();
+    coverage.recompute(&lighting, &grid, &BlackoutState::default());
+
+    assert!(coverage.is_lit(50, 50));
+    assert!(coverage.is_lit(50 + LAMP_LIGHT_RADIUS, 50 - LAMP_LIGHT_RADIUS));
+    assert!(!coverage.is_lit(50 + LAMP_LIGHT_RADIUS + 1, 50));
+    assert_eq!(coverage.lamp_count, 1);
+    assert_eq!(coverage.dark_lamps, 0);
+    assert!(coverage.unlit_road_cells > 0);
+}
+
+#[test]
+fn test_lamps_go_dark_without_power() {
+    let (mut grid, _) = road_grid(50, 40, 60);
+    grid.get_mut(50, 50).has_power = false;
+    let mut lighting = StreetLighting::default();
+    lighting.place(50, 50, LampOrigin::Manual);
+    let mut coverage = StreetLightCoverage::default();
+    coverage.recompute(&lighting, &grid, &BlackoutState::default());
+
+    assert!(!coverage.is_lit(50, 50));
+    assert_eq!(coverage.dark_lamps, 1);
+}
+
+#[test]
+fn test_blackouts_shed_street_lighting() {
+    let (grid, _) = road_grid(50, 40, 60);
+    let total_blackout = BlackoutState {
+        active: true,
+        load_shed_fraction: 1.0,
+        ..Default::default()
+    };
+    assert!(lamp_powered(&grid, &BlackoutState::default(), 50, 50));
+    assert!(!lamp_powered(&grid, &total_blackout, 50, 50));
+}
+
+#[test]
+fn test_unlit_streets_deter_night_trips() {
+    let mut coverage = StreetLightCoverage::default();
+    coverage.lit.set(10, 10, true);
+    coverage.lit.set(20, 20, true);
+    assert_eq!(coverage.night_travel_willingness((10, 10), (20, 20)), 1.0);
+    assert_eq!(
+        coverage.night_travel_willingness((10, 10), (30, 30)),
+        UNLIT_NIGHT_TRAVEL_WILLINGNESS
+    );
+}
+
+#[test]
+fn test_save_roundtrip() {
+    assert!(StreetLighting::default().save_to_bytes().is_none());
+
+    let mut lighting = StreetLighting::default();
+    lighting.place(3, 4, LampOrigin::Manual);
+    lighting.auto_place = false;
+    lighting.lamped_segments.push(7);
+    let bytes = lighting.save_to_bytes().expect("changed state should save");
+    assert_eq!(StreetLighting::load_from_bytes(&bytes), lighting);
+}
@@ -0,0 +1,230 @@
+//! Street lamps, the cells they light and what darkness costs the city.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::blackout::BlackoutState;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+use crate::packed_grid::BitGrid;
+use crate::road_segments::RoadSegment;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Automatic placement puts a lamp on every this many cells of a segment.
+pub const LAMP_SPACING_CELLS: usize = 3;
+
+/// Cells around a lamp (in each direction) that it lights.
+pub const LAMP_LIGHT_RADIUS: usize = 2;
+
+/// Cost of placing a lamp by hand.
+pub const STREET_LAMP_COST: f64 = 150.0;
+
+/// Crime added at night to developed cells no lamp lights.
+pub const UNLIT_NIGHT_CRIME: u8 = 8;
+
+/// Share of citizens still willing to make an optional trip after dark
+/// when either end of it is on an unlit street.
+pub const UNLIT_NIGHT_TRAVEL_WILLINGNESS: f32 = 0.4;
+
+// ---------------------------------------------------------------------------
+// Lamps
+// ---------------------------------------------------------------------------
+
+/// How a lamp came to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum LampOrigin {
+    /// Placed along a new road segment.
+    Auto,
+    /// Placed by the player with the street lamp tool.
+    Manual,
+}
+
+/// Every street lamp in the city.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct StreetLighting {
+    /// Lamp on each grid cell, row-major. Lamps stand on road cells.
+    pub lamps: Vec<Option<LampOrigin>>,
+    /// Whether new road segments get lamps automatically.
+    pub auto_place: bool,
+    /// Segments that have already been given lamps, so lamps the player
+    /// removed are not put back.
+    pub lamped_segments: Vec<u32>,
+}
+
+impl Default for StreetLighting {
+    fn default() -> Self {
+        Self {
+            lamps: vec![None; GRID_WIDTH * GRID_HEIGHT],
+            auto_place: true,
+            lamped_segments: Vec::new(),
+        }
+    }
+}
+
+impl StreetLighting {
+    pub fn lamp_at(&self, x: usize, y: usize) -> Option<LampOrigin> {
+        if x < GRID_WIDTH && y < GRID_HEIGHT {
+            self.lamps[y * GRID_WIDTH + x]
+        } else {
+            None
+        }
+    }
+
+    pub fn place(&mut self, x: usize, y: usize, origin: LampOrigin) {
+        if x < GRID_WIDTH && y < GRID_HEIGHT {
+            self.lamps[y * GRID_WIDTH + x] = Some(origin);
+        }
+    }
+
+    /// Remove the lamp at (x, y). Returns whether there was one.
+    pub fn remove(&mut self, x: usize, y: usize) -> bool {
+        x < GRID_WIDTH && y < GRID_HEIGHT && self.lamps[y * GRID_WIDTH + x].take().is_some()
+    }
+
+    /// Line a segment with lamps the first time it is seen.
+    pub fn light_segment(&mut self, segment: &RoadSegment) {
+        if self.lamped_segments.contains(&segment.id.0) {
+            return;
+        }
+        self.lamped_segments.push(segment.id.0);
+        for &(x, y) in segment.rasterized_cells.iter().step_by(LAMP_SPACING_CELLS) {
+            if self.lamp_at(x, y).is_none() {
+                self.place(x, y, LampOrigin::Auto);
+            }
+        }
+    }
+
+    /// Drop lamps whose road is gone and forget segments that no longer
+    /// exist.
+    pub fn prune(&mut self, grid: &WorldGrid, segments: &[RoadSegment]) {
+        for (idx, lamp) in self.lamps.iter_mut().enumerate() {
+            if lamp.is_some() && grid.cells[idx].cell_type != CellType::Road {
+                *lamp = None;
+            }
+        }
+        self.lamped_segments
+            .retain(|id| segments.iter().any(|segment| segment.id.0 == *id));
+    }
+
+    /// Cells with a lamp, row-major.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, LampOrigin)> + '_ {
+        self.lamps.iter().enumerate().filter_map(|(idx, lamp)| {
+            lamp.map(|origin| (idx % GRID_WIDTH, idx / GRID_WIDTH, origin))
+        })
+    }
+}
+
+impl Saveable for StreetLighting {
+    const SAVE_KEY: &'static str = "street_lighting";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Whether the lamp at (x, y) has power. Street lighting is the first load
+/// shed in a blackout, so a share of lamps matching the shed load goes dark
+/// even where the street itself still has power.
+pub fn lamp_powered(grid: &WorldGrid, blackout: &BlackoutState, x: usize, y: usize) -> bool {
+    let idx = y * grid.width + x;
+    if !grid.cells[idx].has_power || blackout.blackout_grid.get_index(idx) {
+        return false;
+    }
+    if !blackout.active {
+        return true;
+    }
+    let slot = x.wrapping_mul(73).wrapping_add(y.wrapping_mul(151)) % 100;
+    slot as f32 >= blackout.load_shed_fraction * 100.0
+}
+
+// ---------------------------------------------------------------------------
+// Coverage
+// ---------------------------------------------------------------------------
+
+/// Which cells are lit, recomputed from the lamps and the power grid.
+#[derive(Resource, Debug, Clone)]
+pub struct StreetLightCoverage {
+    /// Lit flag per grid cell, row-major.
+    pub lit: BitGrid,
+    pub lamp_count: u32,
+    /// Lamps without power.
+    pub dark_lamps: u32,
+    /// Road cells no working lamp reaches.
+    pub unlit_road_cells: u32,
+}
+
+impl Default for StreetLightCoverage {
+    fn default() -> Self {
+        Self {
+            lit: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
+            lamp_count: 0,
+            dark_lamps: 0,
+            unlit_road_cells: 0,
+        }
+    }
+}
+
+impl StreetLightCoverage {
+    pub fn is_lit(&self, x: usize, y: usize) -> bool {
+        self.lit.get(x, y)
+    }
+
+    /// Light the cells around every powered lamp.
+    pub fn recompute(
+        &mut self,
+        lighting: &StreetLighting,
+        grid: &WorldGrid,
+        blackout: &BlackoutState,
+    ) {
+        self.lit.fill(false);
+        self.lamp_count = 0;
+        self.dark_lamps = 0;
+        for (x, y, _) in lighting.iter() {
+            self.lamp_count += 1;
+            if !lamp_powered(grid, blackout, x, y) {
+                self.dark_lamps += 1;
+                continue;
+            }
+            let r = LAMP_LIGHT_RADIUS;
+            for ny in y.saturating_sub(r)..=(y + r).min(GRID_HEIGHT - 1) {
+                for nx in x.saturating_sub(r)..=(x + r).min(GRID_WIDTH - 1) {
+                    self.lit.set(nx, ny, true);
+                }
+            }
+        }
+        self.unlit_road_cells = grid
+            .cells
+            .iter()
+            .zip(self.lit.iter())
+            .filter(|(cell, lit)| cell.cell_type == CellType::Road && !lit)
+            .count() as u32;
+    }
+
+    /// Share of citizens willing to make an optional trip between two cells
+    /// after dark.
+    pub fn night_travel_willingness(&self, from: (usize, usize), to: (usize, usize)) -> f32 {
+        if self.is_lit(from.0, from.1) && self.is_lit(to.0, to.1) {
+            1.0
+        } else {
+            UNLIT_NIGHT_TRAVEL_WILLINGNESS
+        }
+    }
+}
+
+/// Whether a cell is developed enough for darkness to draw crime.
+pub fn is_developed_cell(grid: &WorldGrid, x: usize, y: usize) -> bool {
+    let cell = grid.get(x, y);
+    cell.zone != ZoneType::None || cell.building_id.is_some()
+}
@@ -0,0 +1,21 @@
+//! Structural integrity of buildings and building collapse.
+//!
+//! Every building has a condition from 0 to 100. Age wears it down slowly,
+//! faster as the building gets older, and disasters take bigger bites:
+//! standing flood water, fire and earthquake damage all reduce it. Building
+//! inspectors tour the city once a month. Buildings below
+//! `UNSAFE_CONDITION` are flagged as unsafe and reported by the Safety
+//! advisor; at `CONDEMN_CONDITION` a building is condemned, its occupants
+//! must leave, and the player has `CONDEMNED_GRACE_DAYS` to renovate it
+//! before the city demolishes it.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    handle_structural_actions, run_inspections, vacate_condemned, wear_from_hazards,
+    StructuralIntegrityPlugin,
+};
+pub use types::*;
@@ -0,0 +1,262 @@
+//! Hazard wear, monthly inspections, condemnation and player actions.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::economy::CityBudget;
+use crate::fire::OnFire;
+use crate::flood_simulation::damage_curves::FLOOD_DEPTH_THRESHOLD;
+use crate::flood_simulation::FloodGrid;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+use crate::{SlowTickTimer, TestSafetyNet};
+
+use super::types::*;
+
+/// Clear the grid cell and despawn a building.
+fn demolish(commands: &mut Commands, grid: &mut WorldGrid, entity: Entity, x: usize, y: usize) {
+    if grid.in_bounds(x, y) {
+        let cell = grid.get_mut(x, y);
+        if cell.building_id == Some(entity) {
+            cell.building_id = None;
+            cell.zone = ZoneType::None;
+        }
+    }
+    commands.entity(entity).despawn();
+}
+
+/// Take a building out of the inspection tallies before it is renovated or
+/// demolished.
+fn uncount(state: &mut StructuralState, x: usize, y: usize) {
+    let record = state.record(x, y);
+    if record.condemned_day.is_some() {
+        state.condemned_count = state.condemned_count.saturating_sub(1);
+    } else if record.condition < UNSAFE_CONDITION {
+        state.unsafe_count = state.unsafe_count.saturating_sub(1);
+    }
+}
+
+/// Every slow tick, wear down buildings standing in flood water or on fire.
+pub fn wear_from_hazards(
+    slow_timer: Res<SlowTickTimer>,
+    flood: Res<FloodGrid>,
+    mut state: ResMut<StructuralState>,
+    buildings: Query<(&Building, Option<&OnFire>)>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    let flooding = flood.has_flooding();
+    for (building, on_fire) in &buildings {
+        let (x, y) = (building.grid_x, building.grid_y);
+        if let Some(fire) = on_fire {
+            state.wear(x, y, fire.intensity * FIRE_WEAR_PER_INTENSITY);
+        }
+        if flooding && x < flood.width && y < flood.height {
+            let depth = flood.get(x, y);
+            if depth >= FLOOD_DEPTH_THRESHOLD {
+                state.wear(x, y, depth * FLOOD_WEAR_PER_FOOT);
+            }
+        }
+    }
+}
+
+/// Once a month, inspect every building: apply age wear, flag unsafe
+/// buildings, condemn critically damaged ones and demolish condemned
+/// buildings the player has left standing past the grace period.
+pub fn run_inspections(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    safety_net: Option<Res<TestSafetyNet>>,
+    mut grid: ResMut<WorldGrid>,
+    mut state: ResMut<StructuralState>,
+    buildings: Query<(Entity, &Building, Has<Condemned>)>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day < state.last_inspection_day + INSPECTION_PERIOD_DAYS {
+        return;
+    }
+    let elapsed = if state.last_inspection_day == 0 {
+        INSPECTION_PERIOD_DAYS
+    } else {
+        clock.day - state.last_inspection_day
+    };
+    state.last_inspection_day = clock.day;
+
+    // Drop records for cells whose building is gone.
+    state.buildings.retain(|&cell, _| {
+        let (x, y) = (cell as usize % grid.width, cell as usize / grid.width);
+        grid.in_bounds(x, y) && grid.get(x, y).building_id.is_some()
+    });
+
+    let mut report = InspectionReport {
+        day: clock.day,
+        ..Default::default()
+    };
+    let mut unsafe_count = 0;
+    let mut condemned_count = 0;
+    for (entity, building, marked) in &buildings {
+        let (x, y) = (building.grid_x, building.grid_y);
+        report.inspected += 1;
+
+        let before = state.status(x, y);
+        let record = state.record_mut(x, y);
+        record.condition = (record.condition - age_wear(record.age_days)).max(0.0);
+        record.age_days += elapsed;
+
+        if record.condemned_day.is_none() && record.condition <= CONDEMN_CONDITION {
+            record.condemned_day = Some(clock.day);
+            report.newly_condemned += 1;
+            notifications.send(NotificationEvent {
+                text: format!("Building at ({x}, {y}) condemned: renovate or demolish it"),
+                priority: NotificationPriority::Warning,
+                category: NotificationCategory::General,
+                location: Some(WorldGrid::grid_to_world(x, y)),
+            });
+        }
+
+        let (condition, condemned_day) = (record.condition, record.condemned_day);
+        match condemned_day {
+            Some(day) if safety_net.is_none() && clock.day >= day + CONDEMNED_GRACE_DAYS => {
+                state.clear_cell(x, y);
+                demolish(&mut commands, &mut grid, entity, x, y);
+                report.demolished += 1;
+                continue;
+            }
+            Some(_) => {
+                condemned_count += 1;
+                if !marked {
+                    commands.entity(entity).insert(Condemned);
+                }
+            }
+            None => {
+                if marked {
+                    commands.entity(entity).remove::<Condemned>();
+                }
+                if condition < UNSAFE_CONDITION {
+                    unsafe_count += 1;
+                    if before == StructuralStatus::Sound {
+                        report.newly_unsafe += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    if report.newly_unsafe > 0 {
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Building inspectors found {} newly unsafe buildings",
+                report.newly_unsafe
+            ),
+            priority: NotificationPriority::Attention,
+            category: NotificationCategory::General,
+            location: state
+                .worst_building()
+                .map(|(x, y)| WorldGrid::grid_to_world(x, y)),
+        });
+    }
+    if report.demolished > 0 {
+        notifications.send(NotificationEvent {
+            text: format!(
+                "{} condemned buildings were demolished by the city",
+                report.demolished
+            ),
+            priority: NotificationPriority::Info,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+    state.unsafe_count = unsafe_count;
+    state.condemned_count = condemned_count;
+    state.last_inspection = Some(report);
+}
+
+/// Condemned buildings stay empty.
+pub fn vacate_condemned(mut buildings: Query<&mut Building, With<Condemned>>) {
+    for mut building in &mut buildings {
+        if building.occupants > 0 {
+            building.occupants = 0;
+        }
+    }
+}
+
+/// Apply renovate and demolish requests from the UI.
+pub fn handle_structural_actions(
+    mut commands: Commands,
+    mut requests: EventReader<StructuralActionRequest>,
+    mut grid: ResMut<WorldGrid>,
+    mut budget: ResMut<CityBudget>,
+    mut state: ResMut<StructuralState>,
+    buildings: Query<&Building>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for request in requests.read() {
+        let (x, y) = (request.grid_x, request.grid_y);
+        if !grid.in_bounds(x, y) {
+            continue;
+        }
+        let Some(entity) = grid.get(x, y).building_id else {
+            continue;
+        };
+        let Ok(building) = buildings.get(entity) else {
+            continue;
+        };
+        let location = Some(WorldGrid::grid_to_world(x, y));
+        match request.action {
+            StructuralAction::Renovate => {
+                let cost = renovation_cost(building.level);
+                if budget.treasury < cost {
+                    notifications.send(NotificationEvent {
+                        text: format!("Cannot renovate: need ${cost:.0}"),
+                        priority: NotificationPriority::Info,
+                        category: NotificationCategory::General,
+                        location,
+                    });
+                    continue;
+                }
+                budget.treasury -= cost;
+                uncount(&mut state, x, y);
+                state.renovate(x, y);
+                commands.entity(entity).remove::<Condemned>();
+                notifications.send(NotificationEvent {
+                    text: format!("Building at ({x}, {y}) renovated for ${cost:.0}"),
+                    priority: NotificationPriority::Positive,
+                    category: NotificationCategory::General,
+                    location,
+                });
+            }
+            StructuralAction::Demolish => {
+                uncount(&mut state, x, y);
+                state.clear_cell(x, y);
+                demolish(&mut commands, &mut grid, entity, x, y);
+            }
+        }
+    }
+}
+
+pub struct StructuralIntegrityPlugin;
+
+impl Plugin for StructuralIntegrityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StructuralState>()
+            .add_event::<StructuralActionRequest>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    wear_from_hazards.before(crate::fire::fire_damage),
+                    run_inspections,
+                    vacate_condemned,
+                )
+                    .chain()
+                    .in_set(crate::SimulationSet::Simulation),
+            )
+            .add_systems(Update, handle_structural_actions);
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<StructuralState>();
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_status_thresholds() {
+    assert_eq!(
+        StructuralStatus::for_condition(MAX_CONDITION),
+        StructuralStatus::Sound
+    );
+    assert_eq!(
+        StructuralStatus::for_condition(UNSAFE_CONDITION),
+        StructuralStatus::Sound
+    );
+    assert_eq!(
+        StructuralStatus::for_condition(UNSAFE_CONDITION - 1.0),
+        StructuralStatus::Unsafe
+    );
+    assert_eq!(
+        StructuralStatus::for_condition(CONDEMN_CONDITION),
+        StructuralStatus::Condemned
+    );
+}
+
+#[test]
+fn test_age_wear_accelerates() {
+    assert_eq!(age_wear(0), AGE_WEAR_PER_MONTH);
+    assert!((age_wear(AGE_WEAR_DOUBLING_DAYS as u32) - 2.0 * AGE_WEAR_PER_MONTH).abs() < 1e-5);
+    assert!(age_wear(10_000) > age_wear(1_000));
+}
+
+#[test]
+fn test_unrecorded_buildings_are_sound() {
+    let state = StructuralState::default();
+    assert_eq!(state.condition(10, 10), MAX_CONDITION);
+    assert_eq!(state.status(10, 10), StructuralStatus::Sound);
+    assert!(state.worst_building().is_none());
+}
+
+#[test]
+fn test_wear_and_renovate() {
+    let mut state = StructuralState::default();
+    state.wear(4, 5, 70.0);
+    state.wear(4, 5, 70.0);
+    assert_eq!(state.condition(4, 5), 0.0);
+    state.record_mut(4, 5).condemned_day = Some(12);
+    assert_eq!(state.status(4, 5), StructuralStatus::Condemned);
+
+    state.renovate(4, 5);
+    assert_eq!(state.condition(4, 5), MAX_CONDITION);
+    assert!(!state.is_condemned(4, 5));
+}
+
+#[test]
+fn test_worst_building_points_at_lowest_condition() {
+    let mut state = StructuralState::default();
+    state.wear(1, 1, 65.0);
+    state.wear(7, 3, 80.0);
+    state.wear(9, 9, 10.0);
+    assert_eq!(state.worst_building(), Some((7, 3)));
+}
+
+#[test]
+fn test_renovation_cost_scales_with_level() {
+    assert_eq!(renovation_cost(1), RENOVATION_COST_PER_LEVEL);
+    assert_eq!(renovation_cost(3), 3.0 * RENOVATION_COST_PER_LEVEL);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(StructuralState::default().save_to_bytes().is_none());
+    let mut state = StructuralState {
+        last_inspection_day: 60,
+        unsafe_count: 1,
+        ..Default::default()
+    };
+    state.wear(20, 30, 65.0);
+    state.record_mut(20, 30).age_days = 900;
+    let restored = StructuralState::load_from_bytes(&state.save_to_bytes().unwrap());
+    assert_eq!(restored, state);
+}
@@ -0,0 +1,228 @@
+//! Building condition records, inspection thresholds and player actions.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::GRID_WIDTH;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Condition of a new or fully renovated building.
+pub const MAX_CONDITION: f32 = 100.0;
+
+/// Below this condition inspectors flag a building as unsafe.
+pub const UNSAFE_CONDITION: f32 = 40.0;
+
+/// At or below this condition a building is condemned and must be vacated.
+pub const CONDEMN_CONDITION: f32 = 15.0;
+
+/// Days between city-wide inspections.
+pub const INSPECTION_PERIOD_DAYS: u32 = 30;
+
+/// Condition lost to ordinary wear each month by a new building. Wear grows
+/// with age, doubling every `AGE_WEAR_DOUBLING_DAYS`.
+pub const AGE_WEAR_PER_MONTH: f32 = 0.25;
+pub const AGE_WEAR_DOUBLING_DAYS: f32 = 3650.0;
+
+/// Condition lost per slow tick for each foot of flood water in a building.
+pub const FLOOD_WEAR_PER_FOOT: f32 = 0.5;
+
+/// Condition lost per slow tick for each point of fire intensity.
+pub const FIRE_WEAR_PER_INTENSITY: f32 = 0.1;
+
+/// Condition lost for each level an earthquake knocks off a building.
+pub const QUAKE_WEAR_PER_LEVEL: f32 = 25.0;
+
+/// Renovation cost per building level.
+pub const RENOVATION_COST_PER_LEVEL: f64 = 1_500.0;
+
+/// Days a condemned building may stand before the city demolishes it.
+pub const CONDEMNED_GRACE_DAYS: u32 = 180;
+
+// ---------------------------------------------------------------------------
+// Condition
+// ---------------------------------------------------------------------------
+
+/// Inspection outcome for a building's condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuralStatus {
+    Sound,
+    Unsafe,
+    Condemned,
+}
+
+impl StructuralStatus {
+    pub fn for_condition(condition: f32) -> Self {
+        if condition <= CONDEMN_CONDITION {
+            StructuralStatus::Condemned
+        } else if condition < UNSAFE_CONDITION {
+            StructuralStatus::Unsafe
+        } else {
+            StructuralStatus::Sound
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StructuralStatus::Sound => "Sound",
+            StructuralStatus::Unsafe => "Unsafe",
+            StructuralStatus::Condemned => "Condemned",
+        }
+    }
+}
+
+/// Monthly wear on a building of the given age.
+pub fn age_wear(age_days: u32) -> f32 {
+    AGE_WEAR_PER_MONTH * (1.0 + age_days as f32 / AGE_WEAR_DOUBLING_DAYS)
+}
+
+/// Cost to restore a building of `level` to full condition.
+pub fn renovation_cost(level: u8) -> f64 {
+    RENOVATION_COST_PER_LEVEL * level.max(1) as f64
+}
+
+/// Condition record for the building standing on one cell.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct BuildingCondition {
+    pub condition: f32,
+    /// Days the building has been inspected for.
+    pub age_days: u32,
+    /// Day the building was condemned, if it has been.
+    pub condemned_day: Option<u32>,
+}
+
+impl Default for BuildingCondition {
+    fn default() -> Self {
+        Self {
+            condition: MAX_CONDITION,
+            age_days: 0,
+            condemned_day: None,
+        }
+    }
+}
+
+/// Results of the most recent inspection round.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct InspectionReport {
+    pub day: u32,
+    pub inspected: u32,
+    pub newly_unsafe: u32,
+    pub newly_condemned: u32,
+    pub demolished: u32,
+}
+
+/// Structural condition of every building, keyed by its anchor cell.
+/// Buildings without a record are in full condition.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct StructuralState {
+    pub buildings: HashMap<u32, BuildingCondition>,
+    pub last_inspection_day: u32,
+    pub unsafe_count: u32,
+    pub condemned_count: u32,
+    pub last_inspection: Option<InspectionReport>,
+}
+
+fn cell(x: usize, y: usize) -> u32 {
+    (y * GRID_WIDTH + x) as u32
+}
+
+impl StructuralState {
+    pub fn record(&self, x: usize, y: usize) -> BuildingCondition {
+        self.buildings.get(&cell(x, y)).copied().unwrap_or_default()
+    }
+
+    pub fn record_mut(&mut self, x: usize, y: usize) -> &mut BuildingCondition {
+        self.buildings.entry(cell(x, y)).or_default()
+    }
+
+    pub fn condition(&self, x: usize, y: usize) -> f32 {
+        self.record(x, y).condition
+    }
+
+    pub fn status(&self, x: usize, y: usize) -> StructuralStatus {
+        if self.is_condemned(x, y) {
+            return StructuralStatus::Condemned;
+        }
+        StructuralStatus::for_condition(self.condition(x, y))
+    }
+
+    pub fn is_condemned(&self, x: usize, y: usize) -> bool {
+        self.record(x, y).condemned_day.is_some()
+    }
+
+    /// Reduce the condition of the building on (`x`, `y`).
+    pub fn wear(&mut self, x: usize, y: usize, amount: f32) {
+        if amount <= 0.0 {
+            return;
+        }
+        let record = self.record_mut(x, y);
+        record.condition = (record.condition - amount).max(0.0);
+    }
+
+    /// Restore the building on (`x`, `y`) to full condition and lift any
+    /// condemnation. Its age is kept.
+    pub fn renovate(&mut self, x: usize, y: usize) {
+        let record = self.record_mut(x, y);
+        record.condition = MAX_CONDITION;
+        record.condemned_day = None;
+    }
+
+    /// Forget the building on (`x`, `y`), e.g. after it is demolished.
+    pub fn clear_cell(&mut self, x: usize, y: usize) {
+        self.buildings.remove(&cell(x, y));
+    }
+
+    /// The worst unsafe or condemned building, for the advisor to point at.
+    pub fn worst_building(&self) -> Option<(usize, usize)> {
+        self.buildings
+            .iter()
+            .filter(|(_, r)| r.condemned_day.is_some() || r.condition < UNSAFE_CONDITION)
+            .min_by(|a, b| a.1.condition.total_cmp(&b.1.condition).then(a.0.cmp(b.0)))
+            .map(|(&c, _)| (c as usize % GRID_WIDTH, c as usize / GRID_WIDTH))
+    }
+}
+
+impl Saveable for StructuralState {
+    const SAVE_KEY: &'static str = "structural_state";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Components and events
+// ---------------------------------------------------------------------------
+
+/// Marker for buildings that have been condemned. Condemned buildings stay
+/// empty until they are renovated or demolished.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Condemned;
+
+/// What the player chose to do about a damaged building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuralAction {
+    Renovate,
+    Demolish,
+}
+
+/// Sent by the UI to renovate or demolish the building on a cell.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StructuralActionRequest {
+    pub grid_x: usize,
+    pub grid_y: usize,
+    pub action: StructuralAction,
+}
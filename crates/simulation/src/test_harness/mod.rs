@@ -13,6 +13,7 @@ use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 
 use crate::app_state::AppState;
+use crate::profile::ProfileStore;
 use crate::time_of_day::GameClock;
 use crate::tutorial::TutorialState;
 use crate::SimulationPlugin;
@@ -43,6 +44,8 @@ impl TestCity {
             active: false,
             ..Default::default()
         });
+        // Keep the player's real profile out of tests.
+        app.insert_resource(ProfileStore::Memory);
         // Start in Playing state so simulation systems run during tests.
         app.insert_state(AppState::Playing);
         app.add_plugins(SimulationPlugin);
@@ -85,6 +88,8 @@ impl TestCity {
             active: false,
             ..Default::default()
         });
+        // Keep the player's real profile out of tests.
+        app.insert_resource(ProfileStore::Memory);
         // Start in Playing state so simulation systems run during tests.
         app.insert_state(AppState::Playing);
         app.add_plugins(SimulationPlugin);
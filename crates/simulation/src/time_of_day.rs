@@ -1,6 +1,20 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::weather::Season;
+
+/// Days in a game year (four 90-day seasons).
+pub const DAYS_PER_YEAR: u32 = 360;
+
+/// Hours of daylight on the shortest day, in mid-winter.
+pub const MIN_DAYLIGHT_HOURS: f32 = 10.0;
+
+/// Hours of daylight on the longest day, in mid-summer.
+pub const MAX_DAYLIGHT_HOURS: f32 = 14.0;
+
+/// Day of the year with the most daylight (the middle of summer).
+const LONGEST_DAY_OF_YEAR: u32 = 135;
+
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 pub struct GameClock {
     pub day: u32,
@@ -51,6 +65,40 @@ impl GameClock {
         (17..=18).contains(&h)
     }
 
+    /// Day within the current year, 1 to `DAYS_PER_YEAR`.
+    pub fn day_of_year(&self) -> u32 {
+        self.day.saturating_sub(1) % DAYS_PER_YEAR + 1
+    }
+
+    pub fn season(&self) -> Season {
+        Season::from_day(self.day)
+    }
+
+    /// Hours between sunrise and sunset: longest in mid-summer, shortest in
+    /// mid-winter, following a smooth yearly curve.
+    pub fn daylight_hours(&self) -> f32 {
+        let phase = (self.day_of_year() as f32 - LONGEST_DAY_OF_YEAR as f32)
+            / DAYS_PER_YEAR as f32
+            * std::f32::consts::TAU;
+        let mean = (MAX_DAYLIGHT_HOURS + MIN_DAYLIGHT_HOURS) / 2.0;
+        let amplitude = (MAX_DAYLIGHT_HOURS - MIN_DAYLIGHT_HOURS) / 2.0;
+        mean + amplitude * phase.cos()
+    }
+
+    /// Hour of sunrise; days are centred on noon.
+    pub fn sunrise(&self) -> f32 {
+        12.0 - self.daylight_hours() / 2.0
+    }
+
+    /// Hour of sunset.
+    pub fn sunset(&self) -> f32 {
+        12.0 + self.daylight_hours() / 2.0
+    }
+
+    pub fn is_daylight(&self) -> bool {
+        (self.sunrise()..self.sunset()).contains(&self.hour)
+    }
+
     pub fn formatted(&self) -> String {
         let h = self.hour as u32;
         let m = ((self.hour - h as f32) * 60.0) as u32;
@@ -123,6 +171,56 @@ mod tests {
         assert!(!clock2.is_morning_commute());
         assert!(clock2.is_evening_commute());
     }
+
+    #[test]
+    fn test_day_of_year_and_season() {
+        let clock = GameClock {
+            day: 361,
+            ..Default::default()
+        };
+        assert_eq!(clock.day_of_year(), 1);
+        assert_eq!(clock.season(), Season::Spring);
+        let clock = GameClock {
+            day: 720 + 135,
+            ..Default::default()
+        };
+        assert_eq!(clock.day_of_year(), 135);
+        assert_eq!(clock.season(), Season::Summer);
+    }
+
+    #[test]
+    fn test_daylight_follows_the_year() {
+        let on_day = |day| GameClock {
+            day,
+            ..Default::default()
+        };
+        let midsummer = on_day(LONGEST_DAY_OF_YEAR);
+        let midwinter = on_day(LONGEST_DAY_OF_YEAR + DAYS_PER_YEAR / 2);
+        assert!((midsummer.daylight_hours() - MAX_DAYLIGHT_HOURS).abs() < 0.01);
+        assert!((midwinter.daylight_hours() - MIN_DAYLIGHT_HOURS).abs() < 0.01);
+        assert!(midsummer.sunrise() < midwinter.sunrise());
+        assert!(midsummer.sunset() > midwinter.sunset());
+        assert!((midsummer.sunrise() + midsummer.sunset() - 24.0).abs() < 1e-4);
+
+        let spring = on_day(45);
+        assert!(spring.daylight_hours() > midwinter.daylight_hours());
+        assert!(spring.daylight_hours() < midsummer.daylight_hours());
+    }
+
+    #[test]
+    fn test_is_daylight() {
+        let evening = GameClock {
+            day: LONGEST_DAY_OF_YEAR,
+            hour: 18.5,
+            ..Default::default()
+        };
+        assert!(evening.is_daylight(), "summer evenings are still light");
+        let winter_evening = GameClock {
+            day: LONGEST_DAY_OF_YEAR + DAYS_PER_YEAR / 2,
+            ..evening
+        };
+        assert!(!winter_evening.is_daylight());
+    }
 }
 
 pub struct TimeOfDayPlugin;
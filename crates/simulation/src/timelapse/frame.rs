@@ -0,0 +1,190 @@
+//! Compressed minimap-style frames of the city grid.
+
+use bitcode::{Decode, Encode};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid, ZoneType};
+
+/// Grid cells per frame pixel along each axis (256x256 grid -> 128x128 frame).
+pub const FRAME_DOWNSAMPLE: usize = 2;
+pub const FRAME_WIDTH: usize = GRID_WIDTH / FRAME_DOWNSAMPLE;
+pub const FRAME_HEIGHT: usize = GRID_HEIGHT / FRAME_DOWNSAMPLE;
+
+/// Palette class of a single frame pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FrameCell {
+    Grass = 0,
+    Water = 1,
+    Road = 2,
+    ZonedResidential = 3,
+    ZonedCommercial = 4,
+    ZonedIndustrial = 5,
+    ZonedOffice = 6,
+    BuiltResidential = 7,
+    BuiltCommercial = 8,
+    BuiltIndustrial = 9,
+    BuiltOffice = 10,
+    BuiltMixedUse = 11,
+}
+
+impl FrameCell {
+    /// Number of palette entries.
+    pub const COUNT: usize = 12;
+
+    pub fn from_u8(v: u8) -> Self {
+        match v {
+            1 => Self::Water,
+            2 => Self::Road,
+            3 => Self::ZonedResidential,
+            4 => Self::ZonedCommercial,
+            5 => Self::ZonedIndustrial,
+            6 => Self::ZonedOffice,
+            7 => Self::BuiltResidential,
+            8 => Self::BuiltCommercial,
+            9 => Self::BuiltIndustrial,
+            10 => Self::BuiltOffice,
+            11 => Self::BuiltMixedUse,
+            _ => Self::Grass,
+        }
+    }
+
+    /// Display color (sRGB) used by the player and exporters.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Self::Grass => [80, 150, 60],
+            Self::Water => [60, 120, 200],
+            Self::Road => [140, 140, 140],
+            Self::ZonedResidential => [120, 200, 120],
+            Self::ZonedCommercial => [100, 140, 240],
+            Self::ZonedIndustrial => [220, 200, 90],
+            Self::ZonedOffice => [140, 120, 220],
+            Self::BuiltResidential => [40, 200, 40],
+            Self::BuiltCommercial => [40, 80, 255],
+            Self::BuiltIndustrial => [200, 170, 40],
+            Self::BuiltOffice => [100, 80, 200],
+            Self::BuiltMixedUse => [180, 100, 180],
+        }
+    }
+
+    /// Draw priority when several grid cells collapse into one pixel.
+    fn priority(self) -> u8 {
+        match self {
+            Self::Grass => 0,
+            Self::Water => 1,
+            Self::ZonedResidential
+            | Self::ZonedCommercial
+            | Self::ZonedIndustrial
+            | Self::ZonedOffice => 2,
+            Self::Road => 3,
+            _ => 4,
+        }
+    }
+
+    /// Classify a single grid cell.
+    pub fn classify(cell_type: CellType, zone: ZoneType, built: bool) -> Self {
+        match cell_type {
+            CellType::Water => return Self::Water,
+            CellType::Road => return Self::Road,
+            CellType::Grass => {}
+        }
+        match (zone, built) {
+            (ZoneType::None, _) => Self::Grass,
+            (ZoneType::MixedUse, true) => Self::BuiltMixedUse,
+            (ZoneType::MixedUse, false) => Self::ZonedCommercial,
+            (z, true) if z.is_residential() => Self::BuiltResidential,
+            (z, false) if z.is_residential() => Self::ZonedResidential,
+            (z, true) if z.is_commercial() => Self::BuiltCommercial,
+            (z, false) if z.is_commercial() => Self::ZonedCommercial,
+            (ZoneType::Industrial, true) => Self::BuiltIndustrial,
+            (ZoneType::Industrial, false) => Self::ZonedIndustrial,
+            (_, true) => Self::BuiltOffice,
+            (_, false) => Self::ZonedOffice,
+        }
+    }
+}
+
+/// One run-length-encoded snapshot of the city at a given game day.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct TimelapseFrame {
+    pub day: u32,
+    pub population: u32,
+    /// `(run_length, FrameCell as u8)` pairs in row-major order.
+    pub runs: Vec<(u16, u8)>,
+}
+
+impl TimelapseFrame {
+    /// Capture a downsampled frame from the world grid.
+    pub fn capture(grid: &WorldGrid, day: u32, population: u32) -> Self {
+        let mut pixels = Vec::with_capacity(FRAME_WIDTH * FRAME_HEIGHT);
+        for fy in 0..FRAME_HEIGHT {
+            for fx in 0..FRAME_WIDTH {
+                let mut best = FrameCell::Grass;
+                for sy in 0..FRAME_DOWNSAMPLE {
+                    for sx in 0..FRAME_DOWNSAMPLE {
+                        let cell = grid.get(fx * FRAME_DOWNSAMPLE + sx, fy * FRAME_DOWNSAMPLE + sy);
+                        let class = FrameCell::classify(
+                            cell.cell_type,
+                            cell.zone,
+                            cell.building_id.is_some(),
+                        );
+                        if class.priority() > best.priority() {
+                            best = class;
+                        }
+                    }
+                }
+                pixels.push(best as u8);
+            }
+        }
+        Self::from_pixels(day, population, &pixels)
+    }
+
+    /// Run-length encode raw palette indices.
+    pub fn from_pixels(day: u32, population: u32, pixels: &[u8]) -> Self {
+        let mut runs: Vec<(u16, u8)> = Vec::new();
+        for &p in pixels {
+            match runs.last_mut() {
+                Some((len, v)) if *v == p && *len < u16::MAX => *len += 1,
+                _ => runs.push((1, p)),
+            }
+        }
+        Self {
+            day,
+            population,
+            runs,
+        }
+    }
+
+    /// Expand the runs back into `FRAME_WIDTH * FRAME_HEIGHT` palette indices.
+    pub fn decode(&self) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(FRAME_WIDTH * FRAME_HEIGHT);
+        for &(len, v) in &self.runs {
+            pixels.extend(std::iter::repeat_n(v, len as usize));
+        }
+        pixels.resize(FRAME_WIDTH * FRAME_HEIGHT, FrameCell::Grass as u8);
+        pixels
+    }
+
+    /// Expand into packed sRGB bytes (3 per pixel).
+    pub fn decode_rgb(&self) -> Vec<u8> {
+        self.decode()
+            .into_iter()
+            .flat_map(|p| FrameCell::from_u8(p).rgb())
+            .collect()
+    }
+    /// Expand into packed sRGB bytes with every pixel enlarged to a
+    /// `scale` x `scale` block, for image export.
+    pub fn decode_rgb_scaled(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let rgb = self.decode_rgb();
+        let row_bytes = FRAME_WIDTH * 3;
+        let mut out = Vec::with_capacity(rgb.len() * scale * scale);
+        for row in rgb.chunks(row_bytes) {
+            let scaled_row: Vec<u8> = row.chunks(3).flat_map(|px| px.repeat(scale)).collect();
+            for _ in 0..scale {
+                out.extend_from_slice(&scaled_row);
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,146 @@
+//! Minimal animated GIF encoder for time-lapse export.
+//!
+//! Uses the fixed `FrameCell` palette and an uncompressed LZW stream (a
+//! clear code is emitted before the code table grows past 5 bits), which
+//! keeps the encoder tiny while producing files every viewer accepts.
+
+use super::frame::{FrameCell, TimelapseFrame, FRAME_HEIGHT, FRAME_WIDTH};
+
+/// LZW minimum code size for a 16-entry palette.
+const MIN_CODE_SIZE: u8 = 4;
+const CLEAR_CODE: u16 = 1 << MIN_CODE_SIZE;
+const END_CODE: u16 = CLEAR_CODE + 1;
+const CODE_BITS: u8 = MIN_CODE_SIZE + 1;
+/// Literals emitted between clear codes so the code width never grows.
+const LITERALS_PER_CLEAR: usize = 12;
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u32,
+    nbits: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            acc: 0,
+            nbits: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, bits: u8) {
+        self.acc |= (code as u32) << self.nbits;
+        self.nbits += bits;
+        while self.nbits >= 8 {
+            self.bytes.push((self.acc & 0xFF) as u8);
+            self.acc >>= 8;
+            self.nbits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.nbits > 0 {
+            self.bytes.push((self.acc & 0xFF) as u8);
+        }
+        self.bytes
+    }
+}
+
+fn lzw_encode(indices: &[u8]) -> Vec<u8> {
+    let mut w = BitWriter::new();
+    for chunk in indices.chunks(LITERALS_PER_CLEAR) {
+        w.write(CLEAR_CODE, CODE_BITS);
+        for &i in chunk {
+            w.write(i as u16, CODE_BITS);
+        }
+    }
+    w.write(END_CODE, CODE_BITS);
+    w.finish()
+}
+
+fn push_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+/// Encode frames as a looping animated GIF.
+///
+/// `scale` enlarges each frame pixel (1..=8); `delay_cs` is the per-frame
+/// delay in hundredths of a second. Returns an empty vector for no frames.
+pub fn encode_gif(frames: &[TimelapseFrame], scale: usize, delay_cs: u16) -> Vec<u8> {
+    if frames.is_empty() {
+        return Vec::new();
+    }
+    let scale = scale.clamp(1, 8);
+    let w = FRAME_WIDTH * scale;
+    let h = FRAME_HEIGHT * scale;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(b"GIF89a");
+    push_u16(&mut out, w as u16);
+    push_u16(&mut out, h as u16);
+    // Global color table present, 8-bit color resolution, 16 entries.
+    out.push(0xF0 | (MIN_CODE_SIZE - 1));
+    out.push(0); // background color index
+    out.push(0); // pixel aspect ratio
+    for i in 0..(1usize << MIN_CODE_SIZE) {
+        let rgb = if i < FrameCell::COUNT {
+            FrameCell::from_u8(i as u8).rgb()
+        } else {
+            [0, 0, 0]
+        };
+        out.extend_from_slice(&rgb);
+    }
+
+    // NETSCAPE2.0 extension: loop forever.
+    out.extend_from_slice(&[0x21, 0xFF, 0x0B]);
+    out.extend_from_slice(b"NETSCAPE2.0");
+    out.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for frame in frames {
+        // Graphic control extension with frame delay.
+        out.extend_from_slice(&[0x21, 0xF9, 0x04, 0x00]);
+        push_u16(&mut out, delay_cs);
+        out.extend_from_slice(&[0x00, 0x00]);
+
+        // Image descriptor covering the whole canvas.
+        out.push(0x2C);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, w as u16);
+        push_u16(&mut out, h as u16);
+        out.push(0x00);
+
+        let pixels = frame.decode();
+        let mut indices = Vec::with_capacity(w * h);
+        for y in 0..h {
+            let row = &pixels[(y / scale) * FRAME_WIDTH..(y / scale + 1) * FRAME_WIDTH];
+            for x in 0..w {
+                indices.push(row[x / scale]);
+            }
+        }
+
+        out.push(MIN_CODE_SIZE);
+        for block in lzw_encode(&indices).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend_from_slice(block);
+        }
+        out.push(0x00);
+    }
+
+    out.push(0x3B);
+    out
+}
+
+/// Write an animated GIF of all frames to `path`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_gif(
+    frames: &[TimelapseFrame],
+    path: &std::path::Path,
+    scale: usize,
+    delay_cs: u16,
+) -> std::io::Result<usize> {
+    let bytes = encode_gif(frames, scale, delay_cs);
+    std::fs::write(path, &bytes)?;
+    Ok(bytes.len())
+}
@@ -0,0 +1,98 @@
+//! Recorded frame history and the capture system.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+
+use crate::grid::WorldGrid;
+use crate::stats::CityStats;
+use crate::time_of_day::GameClock;
+use crate::Saveable;
+
+use super::frame::TimelapseFrame;
+
+/// Default number of game days between captured frames.
+pub const DEFAULT_CAPTURE_INTERVAL_DAYS: u32 = 7;
+
+/// Maximum frames kept. When full, every other frame is dropped and the
+/// capture interval doubles, so the history always spans founding to present.
+pub const MAX_TIMELAPSE_FRAMES: usize = 240;
+
+/// All captured time-lapse frames for the current city.
+#[derive(Resource, Debug, Clone, Encode, Decode)]
+pub struct TimelapseHistory {
+    pub frames: Vec<TimelapseFrame>,
+    /// Game days between captures (grows as the history is thinned).
+    pub interval_days: u32,
+    /// Whether automatic capture is enabled.
+    pub enabled: bool,
+}
+
+impl Default for TimelapseHistory {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            interval_days: DEFAULT_CAPTURE_INTERVAL_DAYS,
+            enabled: true,
+        }
+    }
+}
+
+impl TimelapseHistory {
+    /// Whether a frame is due on `day`.
+    pub fn is_due(&self, day: u32) -> bool {
+        match self.frames.last() {
+            None => true,
+            Some(last) => day >= last.day + self.interval_days,
+        }
+    }
+
+    /// Append a frame, thinning the history when it exceeds the cap.
+    pub fn push(&mut self, frame: TimelapseFrame) {
+        self.frames.push(frame);
+        if self.frames.len() > MAX_TIMELAPSE_FRAMES {
+            // Keep the founding frame and every second frame after it.
+            let mut i = 0;
+            self.frames.retain(|_| {
+                let keep = i % 2 == 0;
+                i += 1;
+                keep
+            });
+            self.interval_days = self.interval_days.saturating_mul(2);
+        }
+    }
+
+    /// Total compressed size of all frames in run pairs.
+    pub fn total_runs(&self) -> usize {
+        self.frames.iter().map(|f| f.runs.len()).sum()
+    }
+}
+
+impl Saveable for TimelapseHistory {
+    const SAVE_KEY: &'static str = "timelapse_history";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Capture a new frame whenever the capture interval has elapsed.
+pub fn capture_timelapse_frame(
+    slow_timer: Res<crate::SlowTickTimer>,
+    clock: Res<GameClock>,
+    grid: Res<WorldGrid>,
+    stats: Res<CityStats>,
+    mut history: ResMut<TimelapseHistory>,
+) {
+    if !slow_timer.should_run() || !history.enabled || !history.is_due(clock.day) {
+        return;
+    }
+    let frame = TimelapseFrame::capture(&grid, clock.day, stats.population);
+    history.push(frame);
+}
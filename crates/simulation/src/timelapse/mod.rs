@@ -0,0 +1,48 @@
+//! Time-lapse of city growth from history snapshots.
+//!
+//! Every few game days a downsampled (128x128) zone/building snapshot of the
+//! `WorldGrid` is run-length encoded into `TimelapseHistory`. When the
+//! history fills up, every other frame is dropped and the capture interval
+//! doubles, so the frames always span founding to present while staying
+//! small enough to persist in the save file.
+//!
+//! `TimelapsePlayer` drives playback in the history view, and `gif` exports
+//! the frames as a looping animated GIF.
+
+pub mod frame;
+pub mod gif;
+pub mod history;
+pub mod player;
+#[cfg(test)]
+mod tests;
+
+pub use frame::{FrameCell, TimelapseFrame, FRAME_HEIGHT, FRAME_WIDTH};
+pub use history::{
+    capture_timelapse_frame, TimelapseHistory, DEFAULT_CAPTURE_INTERVAL_DAYS, MAX_TIMELAPSE_FRAMES,
+};
+pub use player::{advance_timelapse_player, TimelapsePlayer};
+
+use bevy::prelude::*;
+
+pub struct TimelapsePlugin;
+
+impl Plugin for TimelapsePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimelapseHistory>()
+            .init_resource::<TimelapsePlayer>();
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<TimelapseHistory>();
+
+        app.add_systems(
+            FixedUpdate,
+            capture_timelapse_frame.in_set(crate::SimulationSet::PostSim),
+        )
+        .add_systems(
+            Update,
+            advance_timelapse_player.in_set(crate::SimulationUpdateSet::Visual),
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Playback state for the time-lapse viewer.
+
+use bevy::prelude::*;
+
+use super::history::TimelapseHistory;
+
+/// Default playback speed in frames per second.
+pub const DEFAULT_PLAYBACK_FPS: f32 = 8.0;
+
+/// Playback cursor over `TimelapseHistory` frames (not saved).
+#[derive(Resource, Debug, Clone)]
+pub struct TimelapsePlayer {
+    pub playing: bool,
+    /// Fractional frame position; the displayed frame is `position.floor()`.
+    pub position: f32,
+    pub fps: f32,
+    pub looping: bool,
+}
+
+impl Default for TimelapsePlayer {
+    fn default() -> Self {
+        Self {
+            playing: false,
+            position: 0.0,
+            fps: DEFAULT_PLAYBACK_FPS,
+            looping: true,
+        }
+    }
+}
+
+impl TimelapsePlayer {
+    /// Index of the frame currently shown, clamped to `frame_count`.
+    pub fn current_frame(&self, frame_count: usize) -> Option<usize> {
+        if frame_count == 0 {
+            return None;
+        }
+        Some((self.position.max(0.0) as usize).min(frame_count - 1))
+    }
+
+    /// Advance playback by `dt` seconds.
+    pub fn advance(&mut self, dt: f32, frame_count: usize) {
+        if !self.playing || frame_count == 0 {
+            return;
+        }
+        self.position += dt * self.fps;
+        let end = frame_count as f32;
+        if self.position >= end {
+            if self.looping {
+                self.position %= end;
+            } else {
+                self.position = end - 1.0;
+                self.playing = false;
+            }
+        }
+    }
+
+    /// Jump to a specific frame and pause.
+    pub fn seek(&mut self, frame: usize) {
+        self.position = frame as f32;
+        self.playing = false;
+    }
+}
+
+/// Advance the time-lapse player using real (unscaled) time.
+pub fn advance_timelapse_player(
+    time: Res<Time<Real>>,
+    history: Res<TimelapseHistory>,
+    mut player: ResMut<TimelapsePlayer>,
+) {
+    if player.playing {
+        player.advance(time.delta_secs(), history.frames.len());
+    }
+}
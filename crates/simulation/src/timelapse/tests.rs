@@ -0,0 +1,174 @@
+use super::frame::*;
+use super::gif::encode_gif;
+use super::history::*;
+use super::player::TimelapsePlayer;
+use crate::Saveable;
+
+fn frame_with(day: u32, fill: FrameCell) -> TimelapseFrame {
+    let pixels = vec![fill as u8; FRAME_WIDTH * FRAME_HEIGHT];
+    TimelapseFrame::from_pixels(day, 0, &pixels)
+}
+
+/// Reference LZW decoder (GIF variant) used to validate the encoder output.
+fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut table: Vec<Vec<u8>> = Vec::new();
+    let reset = |table: &mut Vec<Vec<u8>>| {
+        table.clear();
+        for i in 0..(clear + 2) {
+            table.push(vec![i as u8]);
+        }
+    };
+    reset(&mut table);
+    let mut size = min_code_size + 1;
+    let (mut acc, mut nbits, mut pos) = (0u32, 0u8, 0usize);
+    let mut prev: Option<Vec<u8>> = None;
+    let mut out = Vec::new();
+    loop {
+        while nbits < size {
+            acc |= (data[pos] as u32) << nbits;
+            pos += 1;
+            nbits += 8;
+        }
+        let code = (acc & ((1 << size) - 1)) as u16;
+        acc >>= size;
+        nbits -= size;
+        if code == clear {
+            reset(&mut table);
+            size = min_code_size + 1;
+            prev = None;
+            continue;
+        }
+        if code == end {
+            break;
+        }
+        let entry = if (code as usize) < table.len() {
+            table[code as usize].clone()
+        } else {
+            let p = prev.clone().unwrap();
+            let mut e = p.clone();
+            e.push(p[0]);
+            e
+        };
+        if let Some(p) = prev {
+            let mut n = p;
+            n.push(entry[0]);
+            table.push(n);
+            if table.len() == (1 << size) && size < 12 {
+                size += 1;
+            }
+        }
+        out.extend_from_slice(&entry);
+        prev = Some(entry);
+    }
+    out
+}
+
+#[test]
+fn test_rle_roundtrip() {
+    let mut pixels = vec![FrameCell::Grass as u8; FRAME_WIDTH * FRAME_HEIGHT];
+    for (i, p) in pixels.iter_mut().enumerate().take(500) {
+        *p = (i % FrameCell::COUNT) as u8;
+    }
+    let frame = TimelapseFrame::from_pixels(3, 10, &pixels);
+    assert_eq!(frame.decode(), pixels);
+}
+
+#[test]
+fn test_uniform_frame_compresses_to_few_runs() {
+    let frame = frame_with(1, FrameCell::Grass);
+    assert!(frame.runs.len() <= 1);
+    assert_eq!(frame.decode_rgb().len(), FRAME_WIDTH * FRAME_HEIGHT * 3);
+}
+
+#[test]
+fn test_history_due_and_thinning() {
+    let mut h = TimelapseHistory::default();
+    assert!(h.is_due(1));
+    h.push(frame_with(1, FrameCell::Grass));
+    assert!(!h.is_due(2));
+    assert!(h.is_due(1 + DEFAULT_CAPTURE_INTERVAL_DAYS));
+
+    for i in 1..=MAX_TIMELAPSE_FRAMES as u32 {
+        h.push(frame_with(1 + i, FrameCell::Road));
+    }
+    assert!(h.frames.len() <= MAX_TIMELAPSE_FRAMES);
+    assert_eq!(h.frames[0].day, 1, "founding frame must survive thinning");
+    assert_eq!(h.interval_days, DEFAULT_CAPTURE_INTERVAL_DAYS * 2);
+}
+
+#[test]
+fn test_history_saveable_roundtrip() {
+    let mut h = TimelapseHistory::default();
+    assert!(h.save_to_bytes().is_none());
+    h.push(frame_with(5, FrameCell::Water));
+    let bytes = h.save_to_bytes().unwrap();
+    let restored = TimelapseHistory::load_from_bytes(&bytes);
+    assert_eq!(restored.frames, h.frames);
+}
+
+#[test]
+fn test_player_advance_and_loop() {
+    let mut p = TimelapsePlayer {
+        playing: true,
+        fps: 10.0,
+        ..Default::default()
+    };
+    p.advance(0.25, 4);
+    assert_eq!(p.current_frame(4), Some(2));
+    p.advance(0.25, 4);
+    assert_eq!(p.current_frame(4), Some(0));
+    p.looping = false;
+    p.advance(1.0, 4);
+    assert!(!p.playing);
+    assert_eq!(p.current_frame(4), Some(3));
+    assert_eq!(p.current_frame(0), None);
+}
+
+#[test]
+fn test_gif_structure_and_pixels() {
+    let mut pixels = vec![FrameCell::Grass as u8; FRAME_WIDTH * FRAME_HEIGHT];
+    pixels[0] = FrameCell::Road as u8;
+    pixels[FRAME_WIDTH + 3] = FrameCell::BuiltOffice as u8;
+    let frames = vec![TimelapseFrame::from_pixels(1, 0, &pixels)];
+    let gif = encode_gif(&frames, 1, 10);
+
+    assert_eq!(&gif[0..6], b"GIF89a");
+    assert_eq!(*gif.last().unwrap(), 0x3B);
+
+    // Header (13) + palette (48) + NETSCAPE ext (19) + GCE (8) + descriptor (10).
+    let mut pos = 13 + 48 + 19 + 8 + 10;
+    assert_eq!(gif[pos], 4, "LZW min code size");
+    pos += 1;
+    let mut data = Vec::new();
+    while gif[pos] != 0 {
+        let len = gif[pos] as usize;
+        data.extend_from_slice(&gif[pos + 1..pos + 1 + len]);
+        pos += 1 + len;
+    }
+    assert_eq!(lzw_decode(&data, 4), pixels);
+}
+
+#[test]
+fn test_decode_rgb_scaled_enlarges_pixels() {
+    let mut pixels = vec![FrameCell::Grass as u8; FRAME_WIDTH * FRAME_HEIGHT];
+    pixels[1] = FrameCell::Water as u8;
+    let frame = TimelapseFrame::from_pixels(0, 0, &pixels);
+
+    let rgb = frame.decode_rgb_scaled(2);
+    assert_eq!(rgb.len(), FRAME_WIDTH * FRAME_HEIGHT * 4 * 3);
+    let px = |x: usize, y: usize| &rgb[(y * FRAME_WIDTH * 2 + x) * 3..][..3];
+    let water = FrameCell::Water.rgb();
+    let grass = FrameCell::Grass.rgb();
+    assert_eq!(px(1, 0), grass);
+    assert_eq!(px(2, 0), water);
+    assert_eq!(px(3, 1), water);
+    assert_eq!(px(4, 1), grass);
+    assert_eq!(frame.decode_rgb_scaled(1), frame.decode_rgb());
+}
+
+#[test]
+fn test_gif_empty_frames() {
+    assert!(encode_gif(&[], 2, 10).is_empty());
+}
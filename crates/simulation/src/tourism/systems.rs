@@ -2,13 +2,17 @@
 
 use bevy::prelude::*;
 
+use crate::coastal_erosion::CoastState;
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
 use crate::crime::CrimeGrid;
 use crate::hotel_demand::HotelDemandState;
+use crate::hotels::HotelIndustry;
+use crate::river_flow::RiverFlowState;
 use crate::services::ServiceBuilding;
 use crate::stats::CityStats;
 use crate::trees::TreeGrid;
 use crate::weather::Weather;
+use crate::wildlife::WildlifeState;
 
 use super::attraction_formula::{
     average_stay_days, cultural_facility_score, entertainment_score,
@@ -27,9 +31,11 @@ const ENTERTAINMENT_HALF: f32 = 25.0;
 
 /// Main tourism update system. Runs once per ~30 game days.
 ///
-/// Reads service buildings, crime grid, hotel capacity, tree coverage, and
-/// weather to compute the six-component attraction formula and derive tourist
-/// arrivals, stay duration, and commercial spending.
+/// Reads service buildings, crime grid, hotel capacity, tree coverage,
+/// wildlife, beaches, the river, and weather to compute the six-component
+/// attraction formula and derive tourist arrivals, stay duration, and
+/// commercial spending. Last month's convention attendees and hotel tax are
+/// added to visitors and income.
 #[allow(clippy::too_many_arguments)]
 pub fn update_tourism(
     clock: Res<crate::time_of_day::GameClock>,
@@ -40,9 +46,13 @@ pub fn update_tourism(
     crime_grid: Res<CrimeGrid>,
     trees: Res<TreeGrid>,
     hotel_state: Res<HotelDemandState>,
+    hotels: Res<HotelIndustry>,
+    wildlife: Res<WildlifeState>,
+    coast: Res<CoastState>,
+    river: Res<RiverFlowState>,
 ) {
     // Update monthly
-    if clock.day <= tourism.last_update_day + 30 {
+    if !tourism.update_due(clock.day) {
         return;
     }
     tourism.last_update_day = clock.day;
@@ -79,13 +89,18 @@ pub fn update_tourism(
     let transport_raw = raw_transport * tourism.airport_multiplier;
     tourism.transport_access_score = normalize_score(transport_raw, TRANSPORT_HALF);
 
-    // Natural beauty: parks + tree coverage fraction
+    // Natural beauty: parks + tree coverage fraction + wildlife + beaches + river.
+    // Beaches are a summer draw.
     let total_cells = (GRID_WIDTH * GRID_HEIGHT) as f32;
-    let tree_count = trees.cells.iter().filter(|&&t| t).count() as f32;
+    let tree_count = trees.tree_count() as f32;
     let tree_fraction = tree_count / total_cells;
     // Tree coverage adds up to 30 raw points at 15% coverage
     let tree_bonus = (tree_fraction / 0.15).min(1.0) * 30.0;
-    let raw_nature = raw_nature_services + tree_bonus;
+    let raw_nature = raw_nature_services
+        + tree_bonus
+        + wildlife.tourism_points()
+        + coast.tourism_points() * weather.season.beach_factor()
+        + river.tourism_points();
     tourism.natural_beauty_score = normalize_score(raw_nature, NATURE_HALF);
 
     // Hotel capacity score (from HotelDemandState)
@@ -120,6 +135,8 @@ pub fn update_tourism(
     let season_weather_modifier = tourism_seasonal_modifier(weather.season, &weather);
     tourism.monthly_visitors =
         (base_visitors as f32 * tourism.airport_multiplier * season_weather_modifier) as u32;
+    // Convention attendees come on top of leisure arrivals.
+    tourism.monthly_visitors += hotels.last_month.convention_attendees;
 
     // ---------------------------------------------------------------
     // 5. Stay duration and spending
@@ -133,4 +150,7 @@ pub fn update_tourism(
     let spending_per_visitor = 2.0 * tourism.airport_multiplier as f64;
     tourism.monthly_tourism_income = tourism.monthly_visitors as f64 * spending_per_visitor
         + tourism.commercial_spending * 0.1; // 10% of commercial spending as city tax
+    // Hotel tax on last month's room revenue
+    tourism.monthly_tourism_income +=
+        hotels.last_month.room_revenue * hotel_state.hotel_tax_rate as f64;
 }
@@ -77,6 +77,11 @@ impl Tourism {
         }
     }
 
+    /// Whether the monthly update runs on `day`.
+    pub fn update_due(&self, day: u32) -> bool {
+        day > self.last_update_day + 30
+    }
+
     /// Build an `AttractionBreakdown` from the current component scores.
     pub fn breakdown(&self) -> AttractionBreakdown {
         AttractionBreakdown {
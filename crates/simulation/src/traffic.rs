@@ -128,7 +128,7 @@ impl Plugin for TrafficPlugin {
         app.init_resource::<TrafficGrid>().add_systems(
             FixedUpdate,
             update_traffic_density
-                .after(crate::movement::move_citizens)
+                .after(crate::movement::sync_citizen_motion)
                 .in_set(crate::SimulationSet::Simulation),
         );
     }
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::packed_grid::BitGrid;
 
 /// Tracks which grid cells have player-placed trees.
 #[derive(Resource)]
 pub struct TreeGrid {
-    pub cells: Vec<bool>,
+    pub cells: BitGrid,
     pub width: usize,
     pub height: usize,
 }
@@ -13,7 +14,7 @@ pub struct TreeGrid {
 impl Default for TreeGrid {
     fn default() -> Self {
         Self {
-            cells: vec![false; GRID_WIDTH * GRID_HEIGHT],
+            cells: BitGrid::new(GRID_WIDTH, GRID_HEIGHT),
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
         }
@@ -23,18 +24,17 @@ impl Default for TreeGrid {
 impl TreeGrid {
     #[inline]
     pub fn has_tree(&self, x: usize, y: usize) -> bool {
-        if x < self.width && y < self.height {
-            self.cells[y * self.width + x]
-        } else {
-            false
-        }
+        self.cells.get(x, y)
     }
 
     #[inline]
     pub fn set(&mut self, x: usize, y: usize, val: bool) {
-        if x < self.width && y < self.height {
-            self.cells[y * self.width + x] = val;
-        }
+        self.cells.set(x, y, val);
+    }
+
+    /// Number of cells with a tree.
+    pub fn tree_count(&self) -> usize {
+        self.cells.count_ones()
     }
 }
 
@@ -0,0 +1,62 @@
+//! Reading tutorial files and the built-in tutorial.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use super::types::TutorialDefinition;
+
+/// Directory scanned for extra tutorial files, relative to the working
+/// directory (next to save files).
+pub const TUTORIAL_DIR: &str = "tutorials";
+
+/// Id of the tutorial offered on every new sandbox game.
+pub const BASICS_TUTORIAL_ID: &str = "basics";
+
+const BASICS_TUTORIAL: &str = include_str!("data/basics.json");
+
+impl TutorialDefinition {
+    /// Parse and validate a tutorial from JSON.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let tutorial: TutorialDefinition =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        tutorial.validate()?;
+        Ok(tutorial)
+    }
+
+    /// Load one tutorial file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Self::from_json(&json).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+/// The tutorial every new player is walked through.
+pub fn basics_tutorial() -> TutorialDefinition {
+    TutorialDefinition::from_json(BASICS_TUTORIAL).expect("built-in tutorial is valid")
+}
+
+/// Load every `.json` file in `dir`, sorted by file name. A missing
+/// directory yields no tutorials; bad files are reported and skipped.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_tutorial_dir(dir: &Path) -> (Vec<TutorialDefinition>, Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (Vec::new(), Vec::new());
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut tutorials = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        match TutorialDefinition::load(&path) {
+            Ok(tutorial) => tutorials.push(tutorial),
+            Err(e) => errors.push(e),
+        }
+    }
+    (tutorials, errors)
+}
@@ -0,0 +1,21 @@
+//! Tutorials: step-by-step walkthroughs that point the player at a toolbar
+//! category, UI element or map cell and wait for them to act.
+//!
+//! Tutorials are JSON files. The basics tutorial ships with the game and is
+//! started on every new sandbox city; more can be dropped into
+//! `TUTORIAL_DIR` and started from the help overlay. Each step declares what
+//! it highlights and what completes it (roads placed, zones painted, a
+//! utility built, a window opened); `check_tutorial_progress` advances to
+//! the next step the frame its condition holds. Windows the UI can open are
+//! reported through `TutorialSignal`. Manual steps wait for the Next button,
+//! and the last step is the closing message.
+
+pub mod io;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use io::{basics_tutorial, BASICS_TUTORIAL_ID, TUTORIAL_DIR};
+pub use systems::{check_tutorial_progress, TutorialPlugin};
+pub use types::*;
@@ -0,0 +1,141 @@
+//! Checking tutorial steps against the city and advancing through them.
+
+use bevy::prelude::*;
+
+use super::io::basics_tutorial;
+use super::types::*;
+use crate::buildings::Building;
+use crate::grid::{CellType, WorldGrid};
+use crate::services::ServiceBuilding;
+use crate::stats::CityStats;
+use crate::time_of_day::GameClock;
+use crate::utilities::UtilitySource;
+
+/// Whether the city satisfies a step's condition. `signals` are the
+/// `TutorialSignal`s received this frame.
+fn condition_met(
+    condition: &StepCondition,
+    grid: &WorldGrid,
+    stats: &CityStats,
+    utility_sources: &Query<&UtilitySource>,
+    services: &Query<&ServiceBuilding>,
+    buildings: &Query<&Building>,
+    signals: &[String],
+) -> bool {
+    match condition {
+        StepCondition::Manual => false,
+        StepCondition::RoadCells { at_least } => {
+            let count = grid
+                .cells
+                .iter()
+                .filter(|cell| cell.cell_type == CellType::Road)
+                .count();
+            count >= *at_least
+        }
+        StepCondition::ZoneCells { zone, at_least } => {
+            let count = grid
+                .cells
+                .iter()
+                .filter(|cell| zone.contains(cell.zone))
+                .count();
+            count >= *at_least
+        }
+        StepCondition::PowerPlant => utility_sources.iter().any(|u| u.utility_type.is_power()),
+        StepCondition::Utility { utility } => {
+            utility_sources.iter().any(|u| u.utility_type == *utility)
+        }
+        StepCondition::Service { service } => services.iter().any(|s| s.service_type == *service),
+        StepCondition::Growth { population } => {
+            // A building must have actually spawned, not just been zoned.
+            let has_building = buildings
+                .iter()
+                .any(|b| b.zone_type.is_residential() || b.zone_type.is_commercial());
+            has_building && stats.population >= *population
+        }
+        StepCondition::Signal { name } => signals.contains(name),
+    }
+}
+
+/// System that checks whether the player has completed the current tutorial step's
+/// objective and automatically advances to the next step.
+#[allow(clippy::too_many_arguments)]
+pub fn check_tutorial_progress(
+    mut tutorial: ResMut<TutorialState>,
+    mut signals: EventReader<TutorialSignal>,
+    grid: Res<WorldGrid>,
+    stats: Res<CityStats>,
+    utility_sources: Query<&UtilitySource>,
+    services: Query<&ServiceBuilding>,
+    buildings: Query<&Building>,
+    mut clock: ResMut<GameClock>,
+) {
+    let signals: Vec<String> = signals.read().map(|s| s.0.clone()).collect();
+    if !tutorial.active || tutorial.completed {
+        return;
+    }
+
+    let step = tutorial.step();
+    if step.pause && !clock.paused {
+        clock.paused = true;
+        tutorial.paused_by_tutorial = true;
+    }
+
+    let step = tutorial.step();
+    let completed = condition_met(
+        &step.complete_when,
+        &grid,
+        &stats,
+        &utility_sources,
+        &services,
+        &buildings,
+        &signals,
+    );
+
+    if completed {
+        // Unpause if we paused for this step
+        if tutorial.paused_by_tutorial {
+            clock.paused = false;
+            tutorial.paused_by_tutorial = false;
+        }
+        tutorial.advance();
+    }
+}
+
+/// The built-in tutorial plus any valid files in the tutorial directory.
+fn initial_library() -> TutorialLibrary {
+    let mut library = TutorialLibrary {
+        tutorials: vec![basics_tutorial()],
+        errors: Vec::new(),
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let (extra, errors) =
+            super::io::load_tutorial_dir(std::path::Path::new(super::io::TUTORIAL_DIR));
+        for e in &errors {
+            warn!("Ignoring tutorial file: {e}");
+        }
+        library.tutorials.extend(extra);
+        library.errors = errors;
+    }
+    library
+}
+
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(initial_library())
+            .init_resource::<TutorialState>()
+            .add_event::<TutorialSignal>()
+            .add_systems(
+                Update,
+                check_tutorial_progress.in_set(crate::SimulationUpdateSet::Visual),
+            );
+
+        // Register for save/load via the SaveableRegistry
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<TutorialState>();
+    }
+}
@@ -0,0 +1,157 @@
+use super::*;
+use crate::grid::ZoneType;
+use crate::Saveable;
+
+fn step(id: &str, complete_when: StepCondition) -> TutorialStepDef {
+    TutorialStepDef {
+        id: id.to_string(),
+        title: id.to_string(),
+        description: String::new(),
+        hint: String::new(),
+        highlight: None,
+        camera: None,
+        complete_when,
+        pause: false,
+    }
+}
+
+fn tutorial(steps: Vec<TutorialStepDef>) -> TutorialDefinition {
+    TutorialDefinition {
+        id: "test".to_string(),
+        name: "Test".to_string(),
+        description: String::new(),
+        steps,
+    }
+}
+
+#[test]
+fn test_basics_tutorial_parses() {
+    let basics = basics_tutorial();
+    assert_eq!(basics.id, BASICS_TUTORIAL_ID);
+    let ids: Vec<_> = basics.steps.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        [
+            "welcome",
+            "place_road",
+            "zone_residential",
+            "zone_commercial",
+            "place_power_plant",
+            "place_water_tower",
+            "observe_growth",
+            "manage_budget",
+            "completed",
+        ]
+    );
+    assert_eq!(
+        basics.steps[1].highlight,
+        Some(HighlightTarget::Toolbar {
+            category: "Roads".to_string()
+        })
+    );
+    assert_eq!(
+        basics.steps[7].complete_when,
+        StepCondition::Signal {
+            name: SIGNAL_BUDGET_OPENED.to_string()
+        }
+    );
+}
+
+#[test]
+fn test_tutorial_from_minimal_json() {
+    let json = r#"{
+        "id": "parks",
+        "name": "Parks",
+        "steps": [
+            { "id": "build", "title": "Build a park",
+              "description": "Parks make residents happy.",
+              "highlight": { "type": "cell", "x": 40, "y": 60 },
+              "complete_when": { "type": "service", "service": "SmallPark" } },
+            { "id": "done", "title": "Done", "description": "" }
+        ]
+    }"#;
+    let parks = TutorialDefinition::from_json(json).unwrap();
+    assert_eq!(parks.steps.len(), 2);
+    assert!(!parks.steps[0].pause);
+    assert!(parks.steps[1].is_manual());
+}
+
+#[test]
+fn test_validation_rejects_bad_tutorials() {
+    let ok = tutorial(vec![
+        step("road", StepCondition::RoadCells { at_least: 3 }),
+        step("done", StepCondition::Manual),
+    ]);
+    assert!(ok.validate().is_ok());
+
+    let mut duplicate = ok.clone();
+    duplicate.steps[1].id = "road".to_string();
+    assert!(duplicate.validate().is_err());
+
+    let mut auto_closing = ok.clone();
+    auto_closing.steps[1].complete_when = StepCondition::PowerPlant;
+    assert!(auto_closing.validate().is_err());
+
+    let mut unknown_signal = ok.clone();
+    unknown_signal.steps[0].complete_when = StepCondition::Signal {
+        name: "budget_opend".to_string(),
+    };
+    assert!(unknown_signal.validate().is_err());
+
+    let mut off_map = ok.clone();
+    off_map.steps[0].highlight = Some(HighlightTarget::Cell { x: 9999, y: 0 });
+    assert!(off_map.validate().is_err());
+
+    assert!(tutorial(vec![step("only", StepCondition::Manual)])
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn test_go_to_and_step_id() {
+    let mut state = TutorialState::default();
+    assert_eq!(state.step_id(), "welcome");
+    assert!(state.go_to("observe_growth"));
+    assert_eq!(state.step_id(), "observe_growth");
+    assert!(!state.go_to("no_such_step"));
+    assert_eq!(state.step_id(), "observe_growth");
+}
+
+#[test]
+fn test_start_resets_progress() {
+    let mut state = TutorialState::default();
+    state.skip();
+    let custom = tutorial(vec![
+        step("zone", StepCondition::Manual),
+        step("done", StepCondition::Manual),
+    ]);
+    state.start(custom);
+    assert!(state.active);
+    assert!(!state.completed);
+    assert_eq!(state.step_id(), "zone");
+    assert_eq!(state.total_steps(), 1);
+    assert!(state.advance());
+    assert!(state.completed);
+}
+
+#[test]
+fn test_custom_tutorial_saves_at_first_step() {
+    let mut state = TutorialState::default();
+    state.start(tutorial(vec![
+        step("zone", StepCondition::Manual),
+        step("done", StepCondition::Manual),
+    ]));
+    let bytes = state.save_to_bytes().expect("custom tutorial should save");
+    let restored = TutorialState::load_from_bytes(&bytes);
+    assert_eq!(restored.tutorial.id, "test");
+    assert!(restored.active);
+}
+
+#[test]
+fn test_zone_groups() {
+    assert!(ZoneGroup::Residential.contains(ZoneType::ResidentialHigh));
+    assert!(!ZoneGroup::Residential.contains(ZoneType::CommercialLow));
+    assert!(ZoneGroup::Commercial.contains(ZoneType::CommercialHigh));
+    assert!(ZoneGroup::Office.contains(ZoneType::Office));
+    assert!(!ZoneGroup::Industrial.contains(ZoneType::None));
+}
@@ -0,0 +1,375 @@
+//! Tutorial definitions, step conditions, and per-save tutorial progress.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::ZoneType;
+use crate::services::ServiceType;
+use crate::utilities::UtilityType;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Signals
+// ---------------------------------------------------------------------------
+
+/// Sent when the player does something in the UI that a tutorial step can
+/// wait for, such as opening the budget window.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct TutorialSignal(pub String);
+
+/// The budget window was opened.
+pub const SIGNAL_BUDGET_OPENED: &str = "budget_opened";
+/// The policies window was opened.
+pub const SIGNAL_POLICIES_OPENED: &str = "policies_opened";
+/// The charts window was opened.
+pub const SIGNAL_CHARTS_OPENED: &str = "charts_opened";
+/// The advisor window was opened.
+pub const SIGNAL_ADVISOR_OPENED: &str = "advisor_opened";
+/// The event journal was opened.
+pub const SIGNAL_JOURNAL_OPENED: &str = "journal_opened";
+
+/// Every signal the UI sends; `signal` conditions must name one of these.
+pub const SIGNALS: [&str; 5] = [
+    SIGNAL_BUDGET_OPENED,
+    SIGNAL_POLICIES_OPENED,
+    SIGNAL_CHARTS_OPENED,
+    SIGNAL_ADVISOR_OPENED,
+    SIGNAL_JOURNAL_OPENED,
+];
+
+// ---------------------------------------------------------------------------
+// Steps
+// ---------------------------------------------------------------------------
+
+/// A family of zones counted by `zone_cells` conditions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneGroup {
+    Residential,
+    Commercial,
+    Industrial,
+    Office,
+    MixedUse,
+}
+
+impl ZoneGroup {
+    pub fn contains(self, zone: ZoneType) -> bool {
+        match self {
+            Self::Residential => zone.is_residential(),
+            Self::Commercial => zone.is_commercial(),
+            Self::Industrial => zone == ZoneType::Industrial,
+            Self::Office => zone == ZoneType::Office,
+            Self::MixedUse => zone.is_mixed_use(),
+        }
+    }
+}
+
+/// What completes a tutorial step. Steps advance on their own the first
+/// frame their condition holds; `manual` steps wait for the Next button.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepCondition {
+    #[default]
+    Manual,
+    RoadCells {
+        at_least: usize,
+    },
+    ZoneCells {
+        zone: ZoneGroup,
+        at_least: usize,
+    },
+    /// Any kind of power plant.
+    PowerPlant,
+    Utility {
+        utility: UtilityType,
+    },
+    Service {
+        service: ServiceType,
+    },
+    /// A residential or commercial building has grown and the city has at
+    /// least `population` residents.
+    Growth {
+        population: u32,
+    },
+    /// The UI sent this `TutorialSignal`.
+    Signal {
+        name: String,
+    },
+}
+
+/// What a step points the player at.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HighlightTarget {
+    /// A toolbar category button, by its label (e.g. "Roads").
+    Toolbar { category: String },
+    /// Any other UI element, named as the player sees it.
+    Ui { element: String },
+    /// A grid cell, marked on the map.
+    Cell { x: usize, y: usize },
+}
+
+impl HighlightTarget {
+    /// Short name for the pulsing pointer in the tutorial window.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Toolbar { category } => category.clone(),
+            Self::Ui { element } => element.clone(),
+            Self::Cell { x, y } => format!("Map ({x}, {y})"),
+        }
+    }
+}
+
+/// A grid cell the camera should move to when a step begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct CameraFocus {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// One page of a tutorial.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct TutorialStepDef {
+    /// Stable identifier, unique within the tutorial (e.g. "place_road").
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
+    pub hint: String,
+    #[serde(default)]
+    pub highlight: Option<HighlightTarget>,
+    #[serde(default)]
+    pub camera: Option<CameraFocus>,
+    #[serde(default)]
+    pub complete_when: StepCondition,
+    /// Keep the simulation paused while this step is shown.
+    #[serde(default)]
+    pub pause: bool,
+}
+
+impl TutorialStepDef {
+    pub fn is_manual(&self) -> bool {
+        self.complete_when == StepCondition::Manual
+    }
+}
+
+/// A complete tutorial. The last step is its closing message: reaching it
+/// completes the tutorial.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct TutorialDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub steps: Vec<TutorialStepDef>,
+}
+
+impl TutorialDefinition {
+    /// Check that the tutorial can be played.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err("tutorial needs an id and a name".to_string());
+        }
+        if self.steps.len() < 2 {
+            return Err("tutorial needs at least two steps".to_string());
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            if step.id.trim().is_empty() || step.title.trim().is_empty() {
+                return Err(format!("step {i} needs an id and a title"));
+            }
+            if self.steps[..i].iter().any(|s| s.id == step.id) {
+                return Err(format!("duplicate step id '{}'", step.id));
+            }
+            match &step.complete_when {
+                StepCondition::RoadCells { at_least: 0 }
+                | StepCondition::ZoneCells { at_least: 0, .. } => {
+                    return Err(format!("step '{}' has a zero cell count", step.id));
+                }
+                StepCondition::Signal { name } if !SIGNALS.contains(&name.as_str()) => {
+                    return Err(format!(
+                        "step '{}' waits for unknown signal '{name}'",
+                        step.id
+                    ));
+                }
+                _ => {}
+            }
+            let cells = [
+                step.camera.map(|c| (c.x, c.y)),
+                match step.highlight {
+                    Some(HighlightTarget::Cell { x, y }) => Some((x, y)),
+                    _ => None,
+                },
+            ];
+            if cells
+                .into_iter()
+                .flatten()
+                .any(|(x, y)| x >= GRID_WIDTH || y >= GRID_HEIGHT)
+            {
+                return Err(format!("step '{}' points outside the map", step.id));
+            }
+        }
+        if !self.steps[self.steps.len() - 1].is_manual() {
+            return Err("the closing step must be manual".to_string());
+        }
+        Ok(())
+    }
+
+    /// Index of the step with this id.
+    pub fn step_index(&self, id: &str) -> Option<usize> {
+        self.steps.iter().position(|s| s.id == id)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Progress
+// ---------------------------------------------------------------------------
+
+/// The tutorial being played in this save and how far the player got.
+#[derive(Resource, Debug, Clone, Encode, Decode)]
+pub struct TutorialState {
+    pub tutorial: TutorialDefinition,
+    /// Index into `tutorial.steps`.
+    pub current_step: usize,
+    /// Whether the tutorial has been completed or skipped.
+    pub completed: bool,
+    /// Whether the tutorial is actively being shown.
+    pub active: bool,
+    /// Whether the simulation was paused by the tutorial (to restore on skip/complete).
+    pub paused_by_tutorial: bool,
+}
+
+impl Default for TutorialState {
+    fn default() -> Self {
+        Self {
+            tutorial: super::io::basics_tutorial(),
+            current_step: 0,
+            completed: false,
+            active: false, // Only activated explicitly on New Game
+            paused_by_tutorial: false,
+        }
+    }
+}
+
+impl TutorialState {
+    /// Begin a tutorial from its first step.
+    pub fn start(&mut self, tutorial: TutorialDefinition) {
+        *self = Self {
+            tutorial,
+            active: true,
+            ..Default::default()
+        };
+    }
+
+    /// The step being shown.
+    pub fn step(&self) -> &TutorialStepDef {
+        let last = self.tutorial.steps.len() - 1;
+        &self.tutorial.steps[self.current_step.min(last)]
+    }
+
+    /// Id of the step being shown.
+    pub fn step_id(&self) -> &str {
+        &self.step().id
+    }
+
+    /// Jump to the step with this id. Returns false if there is none.
+    pub fn go_to(&mut self, id: &str) -> bool {
+        match self.tutorial.step_index(id) {
+            Some(index) => {
+                self.current_step = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Number of steps before the closing message.
+    pub fn total_steps(&self) -> usize {
+        self.tutorial.steps.len() - 1
+    }
+
+    /// Whether the closing message is being shown.
+    pub fn is_last_step(&self) -> bool {
+        self.current_step >= self.total_steps()
+    }
+
+    /// Skip the tutorial entirely.
+    pub fn skip(&mut self) {
+        self.current_step = self.total_steps();
+        self.completed = true;
+        self.active = false;
+        self.paused_by_tutorial = false;
+    }
+
+    /// Advance to the next step. Returns true if advanced, false if already completed.
+    pub fn advance(&mut self) -> bool {
+        if self.completed {
+            return false;
+        }
+        if self.is_last_step() {
+            self.skip();
+            return false;
+        }
+        self.current_step += 1;
+        if self.is_last_step() {
+            self.completed = true;
+            self.active = false;
+            self.paused_by_tutorial = false;
+        }
+        true
+    }
+
+    /// Go back to the previous step. Returns true if moved back.
+    pub fn go_back(&mut self) -> bool {
+        if self.completed || self.current_step == 0 {
+            return false;
+        }
+        self.current_step -= 1;
+        true
+    }
+
+    /// Whether the current step requires manual advancement (Next button).
+    pub fn is_manual_step(&self) -> bool {
+        self.step().is_manual()
+    }
+}
+
+// =============================================================================
+// Saveable Implementation
+// =============================================================================
+
+impl Saveable for TutorialState {
+    const SAVE_KEY: &'static str = "tutorial";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        // Remember completion, progress, or a tutorial other than the basics.
+        if self.completed
+            || self.current_step != 0
+            || self.tutorial.id != super::io::BASICS_TUTORIAL_ID
+        {
+            Some(bitcode::encode(self))
+        } else {
+            None // Default state, no need to save
+        }
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Every tutorial the player can start: the built-in basics followed by any
+/// valid files in `TUTORIAL_DIR`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TutorialLibrary {
+    pub tutorials: Vec<TutorialDefinition>,
+    /// Files that failed to load, with the reason.
+    pub errors: Vec<String>,
+}
+
+impl TutorialLibrary {
+    pub fn get(&self, id: &str) -> Option<&TutorialDefinition> {
+        self.tutorials.iter().find(|t| t.id == id)
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use crate::tutorial::{TutorialState, TutorialStep};
+use crate::grid::WorldGrid;
+use crate::tutorial::{HighlightTarget, TutorialState, TutorialStepDef};
 
 // =============================================================================
 // Tutorial UI Hint Resource
@@ -13,30 +14,44 @@ use crate::tutorial::{TutorialState, TutorialStep};
 /// position the camera.
 #[derive(Resource, Debug, Clone, Default)]
 pub struct TutorialUiHint {
-    /// Name of the toolbar category the player should interact with (e.g. "Roads").
-    pub highlight_target: Option<&'static str>,
-    /// World-space position the camera should auto-focus to at tutorial start.
+    /// What the player should interact with (a toolbar category, UI element or cell).
+    pub highlight: Option<HighlightTarget>,
+    /// World-space position the camera should auto-focus to when the step begins.
     pub camera_target: Option<(f32, f32)>,
 }
 
 impl TutorialUiHint {
-    /// Returns the toolbar highlight target for the given step.
-    fn target_for_step(step: TutorialStep) -> Option<&'static str> {
-        match step {
-            TutorialStep::PlaceRoad => Some("Roads"),
-            TutorialStep::ZoneResidential | TutorialStep::ZoneCommercial => Some("Zones"),
-            TutorialStep::PlacePowerPlant | TutorialStep::PlaceWaterTower => Some("Utilities"),
+    /// Hints for a step, as declared in its tutorial file.
+    pub fn for_step(step: &TutorialStepDef) -> Self {
+        Self {
+            highlight: step.highlight.clone(),
+            camera_target: Self::camera_for_step(step),
+        }
+    }
+
+    /// Name of the toolbar category to highlight, if any.
+    pub fn toolbar_category(&self) -> Option<&str> {
+        match &self.highlight {
+            Some(HighlightTarget::Toolbar { category }) => Some(category),
+            _ => None,
+        }
+    }
+
+    /// Grid cell to mark on the map, if any.
+    pub fn cell(&self) -> Option<(usize, usize)> {
+        match self.highlight {
+            Some(HighlightTarget::Cell { x, y }) => Some((x, y)),
             _ => None,
         }
     }
 
-    /// Returns an optional camera focus position for the given step.
-    /// The camera moves to the center of the map at the start of the tutorial.
-    fn camera_for_step(step: TutorialStep) -> Option<(f32, f32)> {
-        match step {
-            // Center the camera on the map for the welcome/first-build steps.
-            // 256 cells * 16.0 CELL_SIZE / 2 = 2048.0 center.
-            TutorialStep::Welcome => Some((2048.0, 2048.0)),
+    /// The step's camera focus, or the highlighted cell when it has none.
+    fn camera_for_step(step: &TutorialStepDef) -> Option<(f32, f32)> {
+        match (step.camera, &step.highlight) {
+            (Some(focus), _) => Some(WorldGrid::grid_to_world(focus.x, focus.y)),
+            (None, Some(HighlightTarget::Cell { x, y })) => {
+                Some(WorldGrid::grid_to_world(*x, *y))
+            }
             _ => None,
         }
     }
@@ -49,14 +64,14 @@ impl TutorialUiHint {
 /// Updates [`TutorialUiHint`] based on the current tutorial step.
 pub fn update_tutorial_hints(tutorial: Res<TutorialState>, mut hint: ResMut<TutorialUiHint>) {
     if !tutorial.active {
-        hint.highlight_target = None;
-        hint.camera_target = None;
+        if hint.highlight.is_some() || hint.camera_target.is_some() {
+            *hint = TutorialUiHint::default();
+        }
         return;
     }
-
-    let step = tutorial.current_step;
-    hint.highlight_target = TutorialUiHint::target_for_step(step);
-    hint.camera_target = TutorialUiHint::camera_for_step(step);
+    if tutorial.is_changed() || hint.is_added() {
+        *hint = TutorialUiHint::for_step(tutorial.step());
+    }
 }
 
 // =============================================================================
@@ -79,64 +94,66 @@ impl Plugin for TutorialHintsPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tutorial::basics_tutorial;
+
+    fn hint_for(id: &str) -> TutorialUiHint {
+        let basics = basics_tutorial();
+        let step = basics.steps.iter().find(|s| s.id == id).unwrap();
+        TutorialUiHint::for_step(step)
+    }
 
     #[test]
     fn test_hint_target_for_road_step() {
-        assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::PlaceRoad),
-            Some("Roads")
-        );
+        assert_eq!(hint_for("place_road").toolbar_category(), Some("Roads"));
     }
 
     #[test]
     fn test_hint_target_for_zone_steps() {
-        assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::ZoneResidential),
-            Some("Zones")
-        );
-        assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::ZoneCommercial),
-            Some("Zones")
-        );
+        assert_eq!(hint_for("zone_residential").toolbar_category(), Some("Zones"));
+        assert_eq!(hint_for("zone_commercial").toolbar_category(), Some("Zones"));
     }
 
     #[test]
     fn test_hint_target_for_utility_steps() {
-        assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::PlacePowerPlant),
-            Some("Utilities")
-        );
-        assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::PlaceWaterTower),
-            Some("Utilities")
-        );
+        assert_eq!(hint_for("place_power_plant").toolbar_category(), Some("Utilities"));
+        assert_eq!(hint_for("place_water_tower").toolbar_category(), Some("Utilities"));
     }
 
     #[test]
     fn test_hint_target_none_for_welcome() {
+        assert!(hint_for("welcome").highlight.is_none());
+    }
+
+    #[test]
+    fn test_budget_step_highlights_ui_element() {
+        let hint = hint_for("manage_budget");
+        assert_eq!(hint.toolbar_category(), None);
         assert_eq!(
-            TutorialUiHint::target_for_step(TutorialStep::Welcome),
-            None
+            hint.highlight.map(|h| h.label()),
+            Some("Budget Details...".to_string())
         );
     }
 
     #[test]
     fn test_camera_target_welcome() {
         assert_eq!(
-            TutorialUiHint::camera_for_step(TutorialStep::Welcome),
-            Some((2048.0, 2048.0))
+            hint_for("welcome").camera_target,
+            Some(WorldGrid::grid_to_world(128, 128))
         );
     }
 
     #[test]
     fn test_camera_target_none_for_other_steps() {
-        assert_eq!(
-            TutorialUiHint::camera_for_step(TutorialStep::PlaceRoad),
-            None
-        );
-        assert_eq!(
-            TutorialUiHint::camera_for_step(TutorialStep::Completed),
-            None
-        );
+        assert_eq!(hint_for("place_road").camera_target, None);
+        assert_eq!(hint_for("completed").camera_target, None);
+    }
+
+    #[test]
+    fn test_cell_highlight_focuses_camera() {
+        let mut step = basics_tutorial().steps.remove(1);
+        step.highlight = Some(HighlightTarget::Cell { x: 10, y: 20 });
+        let hint = TutorialUiHint::for_step(&step);
+        assert_eq!(hint.cell(), Some((10, 20)));
+        assert_eq!(hint.camera_target, Some(WorldGrid::grid_to_world(10, 20)));
     }
 }
@@ -0,0 +1,191 @@
+//! UI scale and font size accessibility options.
+//!
+//! `UiScaleSettings` holds a global scale applied to every egui panel and a
+//! font-size preset for text. While `auto` is on, the UI crate picks the
+//! scale from the display on startup; moving the slider turns it off.
+//!
+//! Like keybindings, these are a player preference rather than part of a
+//! city: they are kept in the player profile (see [`crate::profile`]) instead
+//! of travelling with save files.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Settings file written by builds before the player profile, relative to
+/// the working directory. Only read to import old settings into the profile.
+pub const UI_SCALE_PATH: &str = "ui_scale.json";
+
+/// Smallest UI scale the slider allows.
+pub const MIN_UI_SCALE: f32 = 0.75;
+/// Largest UI scale the slider allows.
+pub const MAX_UI_SCALE: f32 = 2.0;
+
+/// Text size relative to the default egui text styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FontSizePreset {
+    Small,
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+
+impl FontSizePreset {
+    pub const ALL: [FontSizePreset; 4] = [Self::Small, Self::Normal, Self::Large, Self::ExtraLarge];
+
+    /// Human-readable label for display in settings UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Small => "Small",
+            Self::Normal => "Normal",
+            Self::Large => "Large",
+            Self::ExtraLarge => "Extra Large",
+        }
+    }
+
+    /// Multiplier applied to every text style.
+    pub fn factor(self) -> f32 {
+        match self {
+            Self::Small => 0.85,
+            Self::Normal => 1.0,
+            Self::Large => 1.2,
+            Self::ExtraLarge => 1.4,
+        }
+    }
+}
+
+/// Global UI scale and font size.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiScaleSettings {
+    /// Pick the scale from the display on startup.
+    pub auto: bool,
+    /// Multiplier on top of the operating system's DPI scaling.
+    pub scale: f32,
+    pub font_size: FontSizePreset,
+}
+
+impl Default for UiScaleSettings {
+    fn default() -> Self {
+        Self {
+            auto: true,
+            scale: 1.0,
+            font_size: FontSizePreset::Normal,
+        }
+    }
+}
+
+impl UiScaleSettings {
+    /// Set the scale by hand, which turns auto-detection off.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+        self.auto = false;
+    }
+
+    /// Parse settings from JSON. Missing fields keep their defaults and an
+    /// out-of-range scale is clamped.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut settings: Self =
+            serde_json::from_str(json).map_err(|e| format!("JSON decode error: {e}"))?;
+        settings.clamp_scale();
+        Ok(settings)
+    }
+
+    /// Bring a scale read from disk back into the slider's range.
+    pub(crate) fn clamp_scale(&mut self) {
+        self.scale = if self.scale.is_finite() {
+            self.scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE)
+        } else {
+            1.0
+        };
+    }
+
+    /// Pretty-printed JSON for the settings file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("JSON encode error: {e}"))
+    }
+
+    /// Load settings from `path`. A missing file yields the defaults; an
+    /// unreadable or invalid one is reported as an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+        }
+    }
+}
+
+/// Scale to use on a display, given the scale factor the operating system
+/// reports and the window's width in physical pixels. When the system
+/// already scales for DPI nothing extra is added; otherwise large
+/// high-resolution windows get a bigger UI so text stays readable.
+pub fn detect_scale(os_scale_factor: f32, physical_width: u32) -> f32 {
+    if os_scale_factor > 1.0 {
+        1.0
+    } else if physical_width >= 3600 {
+        1.5
+    } else if physical_width >= 2400 {
+        1.25
+    } else {
+        1.0
+    }
+}
+
+pub struct UiScalePlugin;
+
+impl Plugin for UiScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiScaleSettings>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut settings = UiScaleSettings::default();
+        settings.set_scale(1.3);
+        settings.font_size = FontSizePreset::Large;
+        let json = settings.to_json().unwrap();
+        assert_eq!(UiScaleSettings::from_json(&json).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_from_json_clamps_and_fills_defaults() {
+        let settings = UiScaleSettings::from_json(r#"{ "scale": 9.0 }"#).unwrap();
+        assert_eq!(settings.scale, MAX_UI_SCALE);
+        assert!(settings.auto);
+        assert_eq!(settings.font_size, FontSizePreset::Normal);
+        assert!(UiScaleSettings::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_set_scale_turns_off_auto() {
+        let mut settings = UiScaleSettings::default();
+        settings.set_scale(0.1);
+        assert!(!settings.auto);
+        assert_eq!(settings.scale, MIN_UI_SCALE);
+    }
+
+    #[test]
+    fn test_detect_scale() {
+        assert_eq!(detect_scale(2.0, 3840), 1.0);
+        assert_eq!(detect_scale(1.0, 3840), 1.5);
+        assert_eq!(detect_scale(1.0, 2560), 1.25);
+        assert_eq!(detect_scale(1.0, 1920), 1.0);
+    }
+
+    #[test]
+    fn test_font_factors_increase() {
+        let factors: Vec<f32> = FontSizePreset::ALL.iter().map(|p| p.factor()).collect();
+        assert!(factors.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(FontSizePreset::Normal.factor(), 1.0);
+    }
+}
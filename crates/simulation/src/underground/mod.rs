@@ -0,0 +1,17 @@
+//! Underground infrastructure layer.
+//!
+//! Tracks what lies below the surface of each cell: water and sewer mains
+//! under roads, foundations and basement parking under buildings, and
+//! metro tunnels between stations. Tunnels and foundations never overlap:
+//! a tunnel runs cut-and-cover just below the surface where its route is
+//! clear and is bored deep under existing foundations, and a building over
+//! a shallow tunnel stops growing before its foundation reaches it. The
+//! rendering crate draws this layer in its cross-section view.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{dig_metro_tunnels, rebuild_underground_layer, UndergroundPlugin};
+pub use types::*;
@@ -0,0 +1,92 @@
+//! Rebuilding the underground layer from the surface.
+
+use bevy::prelude::*;
+
+use crate::agriculture::is_dense_zone;
+use crate::buildings::Building;
+use crate::grid::{CellType, WorldGrid};
+use crate::metro_transit::MetroTransitState;
+use crate::parking::ParkingPolicyState;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Lay out the tunnels of every metro line. Each stretch between stations
+/// is dug cut-and-cover along whichever L-shaped route misses the
+/// foundations, and bored deep under them when neither does.
+pub fn dig_metro_tunnels(layer: &mut UndergroundLayer, metro: &MetroTransitState) {
+    for line in &metro.lines {
+        let stops: Vec<(usize, usize)> = line
+            .station_ids
+            .iter()
+            .filter_map(|&id| metro.station_by_id(id))
+            .map(|s| (s.grid_x, s.grid_y))
+            .collect();
+        for pair in stops.windows(2) {
+            let x_first = tunnel_route(pair[0], pair[1], true);
+            let y_first = tunnel_route(pair[0], pair[1], false);
+            if layer.route_clear_for_cut_and_cover(&x_first) {
+                layer.dig_tunnel(&x_first, TunnelMethod::CutAndCover);
+            } else if layer.route_clear_for_cut_and_cover(&y_first) {
+                layer.dig_tunnel(&y_first, TunnelMethod::CutAndCover);
+            } else {
+                layer.dig_tunnel(&x_first, TunnelMethod::Bored);
+            }
+        }
+    }
+    for station in &metro.stations {
+        layer.add(station.grid_x, station.grid_y, UndergroundKind::MetroTunnel);
+    }
+}
+
+/// Rebuild the underground layer every slow tick: mains under roads,
+/// foundations and basement parking under buildings, then the metro.
+pub fn rebuild_underground_layer(
+    slow_timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    metro: Res<MetroTransitState>,
+    parking: Res<ParkingPolicyState>,
+    buildings: Query<&Building>,
+    mut layer: ResMut<UndergroundLayer>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    layer.clear(grid.width, grid.height);
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            if grid.get(x, y).cell_type == CellType::Road {
+                layer.add(x, y, UndergroundKind::WaterMain);
+                layer.add(x, y, UndergroundKind::SewerMain);
+            }
+        }
+    }
+
+    for building in &buildings {
+        let (x, y) = (building.grid_x, building.grid_y);
+        let levels = basement_parking_levels(
+            is_dense_zone(building.zone_type),
+            building.level,
+            parking.eliminate_minimums,
+        );
+        layer.set_foundation(x, y, foundation_depth_m(building.level, levels));
+        if levels > 0 {
+            layer.add(x, y, UndergroundKind::Parking);
+        }
+    }
+
+    dig_metro_tunnels(&mut layer, &metro);
+}
+
+pub struct UndergroundPlugin;
+
+impl Plugin for UndergroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UndergroundLayer>().add_systems(
+            FixedUpdate,
+            rebuild_underground_layer.in_set(crate::SimulationSet::Simulation),
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::grid::{WorldGrid, ZoneType};
+use crate::metro_transit::MetroTransitState;
+
+#[test]
+fn test_tunnel_route_runs_along_one_axis_then_the_other() {
+    let route = tunnel_route((2, 2), (5, 0), true);
+    assert_eq!(route, vec![(2, 2), (3, 2), (4, 2), (5, 2), (5, 1), (5, 0)]);
+    let route = tunnel_route((2, 2), (5, 0), false);
+    assert_eq!(route.first(), Some(&(2, 2)));
+    assert_eq!(route[1], (2, 1));
+    assert_eq!(route.last(), Some(&(5, 0)));
+    assert_eq!(tunnel_route((4, 4), (4, 4), true), vec![(4, 4)]);
+}
+
+#[test]
+fn test_foundations_deepen_with_level_and_parking() {
+    assert!(foundation_depth_m(2, 0) > foundation_depth_m(1, 0));
+    assert_eq!(basement_parking_levels(false, 5, false), 0);
+    assert_eq!(basement_parking_levels(true, 2, false), 0);
+    assert_eq!(basement_parking_levels(true, 4, false), 2);
+    assert_eq!(basement_parking_levels(true, 4, true), 0);
+    assert!(foundation_depth_m(5, 3) < BORED_TUNNEL_DEPTH_M - TUNNEL_CLEARANCE_M);
+}
+
+fn metro_line(from: (usize, usize), to: (usize, usize)) -> MetroTransitState {
+    let grid = WorldGrid::new(64, 64);
+    let mut metro = MetroTransitState::default();
+    let a = metro
+        .add_station(from.0, from.1, "A".into(), &grid)
+        .unwrap();
+    let b = metro.add_station(to.0, to.1, "B".into(), &grid).unwrap();
+    metro.add_line("Red".into(), vec![a, b]);
+    metro
+}
+
+#[test]
+fn test_tunnel_is_cut_and_cover_where_the_route_is_clear() {
+    let mut layer = UndergroundLayer::new(64, 64);
+    dig_metro_tunnels(&mut layer, &metro_line((10, 10), (20, 10)));
+    assert!(layer.has(15, 10, UndergroundKind::MetroTunnel));
+    assert_eq!(layer.tunnel_depth(15, 10), Some(CUT_AND_COVER_DEPTH_M));
+    assert_eq!(layer.tunnel_cells, 11);
+    assert_eq!(layer.bored_cells, 0);
+}
+
+#[test]
+fn test_tunnel_detours_or_bores_under_deep_foundations() {
+    let tower = foundation_depth_m(5, 3);
+
+    // The x-first route is blocked, so the tunnel takes the other corner.
+    let mut layer = UndergroundLayer::new(64, 64);
+    layer.set_foundation(20, 10, tower);
+    dig_metro_tunnels(&mut layer, &metro_line((10, 10), (20, 20)));
+    assert!(!layer.has(20, 10, UndergroundKind::MetroTunnel));
+    assert!(layer.has(10, 20, UndergroundKind::MetroTunnel));
+    assert_eq!(layer.bored_cells, 0);
+
+    // On a straight run there is no way round, so it is bored underneath.
+    let mut layer = UndergroundLayer::new(64, 64);
+    layer.set_foundation(15, 10, tower);
+    dig_metro_tunnels(&mut layer, &metro_line((10, 10), (20, 10)));
+    assert_eq!(layer.tunnel_depth(15, 10), Some(BORED_TUNNEL_DEPTH_M));
+    assert_eq!(layer.bored_cells, 11);
+    assert!(layer
+        .level_cap_at(15, 10, ZoneType::Office, false)
+        .is_none());
+}
+
+#[test]
+fn test_shallow_tunnel_caps_buildings_above() {
+    let mut layer = UndergroundLayer::new(64, 64);
+    dig_metro_tunnels(&mut layer, &metro_line((10, 10), (20, 10)));
+
+    let cap = layer
+        .level_cap_at(15, 10, ZoneType::Office, false)
+        .expect("tunnel limits the building");
+    assert!(cap < ZoneType::Office.max_level());
+    let parking = basement_parking_levels(true, cap, false);
+    assert!(foundation_depth_m(cap, parking) < CUT_AND_COVER_DEPTH_M - TUNNEL_CLEARANCE_M);
+
+    // Cells off the tunnel are not limited.
+    assert!(layer
+        .level_cap_at(15, 30, ZoneType::Office, false)
+        .is_none());
+}
@@ -0,0 +1,288 @@
+//! Underground features, their depths and the foundation rules.
+
+use bevy::prelude::*;
+
+use crate::agriculture::is_dense_zone;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::ZoneType;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Depth of water and sewer mains under roads (metres).
+pub const PIPE_DEPTH_M: f32 = 3.0;
+
+/// Depth of one underground parking level (metres).
+pub const PARKING_LEVEL_DEPTH_M: f32 = 3.5;
+
+/// Crown depth of a cut-and-cover metro tunnel (metres). Dug from the
+/// surface, so cheap, but nothing above it may reach down this far.
+pub const CUT_AND_COVER_DEPTH_M: f32 = 10.0;
+
+/// Crown depth of a bored metro tunnel (metres), used where the shallow
+/// route would run into a foundation. Deeper than any foundation.
+pub const BORED_TUNNEL_DEPTH_M: f32 = 30.0;
+
+/// Soil left between a foundation and the tunnel crown below it (metres).
+pub const TUNNEL_CLEARANCE_M: f32 = 1.0;
+
+/// Building level from which dense buildings get basement parking.
+pub const BASEMENT_PARKING_MIN_LEVEL: u8 = 3;
+
+/// Foundation depth of a building with `parking_levels` basement floors
+/// (metres). Footings and piles go deeper as buildings grow taller.
+pub fn foundation_depth_m(level: u8, parking_levels: u8) -> f32 {
+    let footing = 1.5 + 2.0 * level as f32;
+    let basement = if parking_levels > 0 {
+        parking_levels as f32 * PARKING_LEVEL_DEPTH_M + 1.0
+    } else {
+        0.0
+    };
+    footing.max(basement)
+}
+
+/// Basement parking floors under a building. Only dense buildings have
+/// them, and none are built once parking minimums are eliminated.
+pub fn basement_parking_levels(dense: bool, level: u8, minimums_eliminated: bool) -> u8 {
+    if !dense || minimums_eliminated || level < BASEMENT_PARKING_MIN_LEVEL {
+        return 0;
+    }
+    level + 1 - BASEMENT_PARKING_MIN_LEVEL
+}
+
+// ---------------------------------------------------------------------------
+// Features
+// ---------------------------------------------------------------------------
+
+/// Kinds of infrastructure below the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UndergroundKind {
+    MetroTunnel,
+    WaterMain,
+    SewerMain,
+    Parking,
+}
+
+impl UndergroundKind {
+    pub const ALL: [UndergroundKind; 4] = [
+        UndergroundKind::MetroTunnel,
+        UndergroundKind::WaterMain,
+        UndergroundKind::SewerMain,
+        UndergroundKind::Parking,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UndergroundKind::MetroTunnel => "Metro Tunnel",
+            UndergroundKind::WaterMain => "Water Main",
+            UndergroundKind::SewerMain => "Sewer Main",
+            UndergroundKind::Parking => "Underground Parking",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        match self {
+            UndergroundKind::MetroTunnel => 1,
+            UndergroundKind::WaterMain => 1 << 1,
+            UndergroundKind::SewerMain => 1 << 2,
+            UndergroundKind::Parking => 1 << 3,
+        }
+    }
+}
+
+/// How a stretch of metro tunnel was built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TunnelMethod {
+    /// Trench dug from the surface and roofed over.
+    CutAndCover,
+    /// Driven deep by a boring machine under existing foundations.
+    Bored,
+}
+
+impl TunnelMethod {
+    pub fn depth_m(self) -> f32 {
+        match self {
+            TunnelMethod::CutAndCover => CUT_AND_COVER_DEPTH_M,
+            TunnelMethod::Bored => BORED_TUNNEL_DEPTH_M,
+        }
+    }
+}
+
+/// Cells of a tunnel from `from` to `to`, running along one axis then the
+/// other, both ends included.
+pub fn tunnel_route(
+    from: (usize, usize),
+    to: (usize, usize),
+    x_first: bool,
+) -> Vec<(usize, usize)> {
+    let step = |v: usize, target: usize| if v < target { v + 1 } else { v - 1 };
+    let (mut x, mut y) = from;
+    let mut cells = vec![from];
+    if x_first {
+        while x != to.0 {
+            x = step(x, to.0);
+            cells.push((x, y));
+        }
+    }
+    while y != to.1 {
+        y = step(y, to.1);
+        cells.push((x, y));
+    }
+    while x != to.0 {
+        x = step(x, to.0);
+        cells.push((x, y));
+    }
+    cells
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// What lies under each cell: pipes under roads, parking and foundations
+/// under buildings, and metro tunnels between stations.
+///
+/// Rebuilt from the surface every slow tick, so it is not saved.
+#[derive(Resource, Debug, Clone)]
+pub struct UndergroundLayer {
+    pub width: usize,
+    pub height: usize,
+    /// Bit set of `UndergroundKind`s per cell.
+    features: Vec<u8>,
+    /// Deepest point of the building foundation on each cell (metres).
+    foundations: Vec<f32>,
+    /// Tunnel crown depth on each cell (metres); 0 where there is none.
+    tunnels: Vec<f32>,
+    pub tunnel_cells: u32,
+    /// Tunnel cells bored deep to pass under foundations.
+    pub bored_cells: u32,
+    pub pipe_cells: u32,
+    pub parking_cells: u32,
+}
+
+impl Default for UndergroundLayer {
+    fn default() -> Self {
+        Self::new(GRID_WIDTH, GRID_HEIGHT)
+    }
+}
+
+impl UndergroundLayer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            features: vec![0; width * height],
+            foundations: vec![0.0; width * height],
+            tunnels: vec![0.0; width * height],
+            tunnel_cells: 0,
+            bored_cells: 0,
+            pipe_cells: 0,
+            parking_cells: 0,
+        }
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// Empty the layer, resizing it to `width` x `height`.
+    pub fn clear(&mut self, width: usize, height: usize) {
+        *self = Self::new(width, height);
+    }
+
+    pub fn has(&self, x: usize, y: usize, kind: UndergroundKind) -> bool {
+        self.index(x, y)
+            .is_some_and(|i| self.features[i] & kind.bit() != 0)
+    }
+
+    /// Whether anything at all lies under (`x`, `y`).
+    pub fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.index(x, y)
+            .is_some_and(|i| self.features[i] != 0 || self.foundations[i] > 0.0)
+    }
+
+    pub fn add(&mut self, x: usize, y: usize, kind: UndergroundKind) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+        if self.features[i] & kind.bit() != 0 {
+            return;
+        }
+        self.features[i] |= kind.bit();
+        match kind {
+            UndergroundKind::MetroTunnel => self.tunnel_cells += 1,
+            UndergroundKind::WaterMain => self.pipe_cells += 1,
+            UndergroundKind::Parking => self.parking_cells += 1,
+            UndergroundKind::SewerMain => {}
+        }
+    }
+
+    pub fn foundation_depth(&self, x: usize, y: usize) -> f32 {
+        self.index(x, y).map_or(0.0, |i| self.foundations[i])
+    }
+
+    pub fn set_foundation(&mut self, x: usize, y: usize, depth_m: f32) {
+        if let Some(i) = self.index(x, y) {
+            self.foundations[i] = self.foundations[i].max(depth_m);
+        }
+    }
+
+    /// Crown depth of the tunnel under (`x`, `y`), if there is one.
+    pub fn tunnel_depth(&self, x: usize, y: usize) -> Option<f32> {
+        self.index(x, y)
+            .map(|i| self.tunnels[i])
+            .filter(|&d| d > 0.0)
+    }
+
+    /// Whether a cut-and-cover tunnel can pass under every cell of `route`
+    /// without cutting into a foundation.
+    pub fn route_clear_for_cut_and_cover(&self, route: &[(usize, usize)]) -> bool {
+        route
+            .iter()
+            .all(|&(x, y)| self.foundation_depth(x, y) < CUT_AND_COVER_DEPTH_M - TUNNEL_CLEARANCE_M)
+    }
+
+    /// Record a tunnel along `route`. Where two tunnels cross, the shallower
+    /// crown is kept since it is the one foundations must clear.
+    pub fn dig_tunnel(&mut self, route: &[(usize, usize)], method: TunnelMethod) {
+        let depth = method.depth_m();
+        for &(x, y) in route {
+            let Some(i) = self.index(x, y) else {
+                continue;
+            };
+            let was = self.tunnels[i];
+            if was == 0.0 || depth < was {
+                if was == BORED_TUNNEL_DEPTH_M {
+                    self.bored_cells -= 1;
+                }
+                if method == TunnelMethod::Bored {
+                    self.bored_cells += 1;
+                }
+                self.tunnels[i] = depth;
+            }
+            self.add(x, y, UndergroundKind::MetroTunnel);
+        }
+    }
+
+    /// Highest level a `zone` building on (`x`, `y`) may reach with its
+    /// foundation clear of the tunnel below, or `None` if nothing below
+    /// limits it.
+    pub fn level_cap_at(
+        &self,
+        x: usize,
+        y: usize,
+        zone: ZoneType,
+        minimums_eliminated: bool,
+    ) -> Option<u8> {
+        let limit = self.tunnel_depth(x, y)? - TUNNEL_CLEARANCE_M;
+        let dense = is_dense_zone(zone);
+        for level in 1..=zone.max_level() {
+            let parking = basement_parking_levels(dense, level, minimums_eliminated);
+            if foundation_depth_m(level, parking) >= limit {
+                return Some(level.saturating_sub(1).max(1));
+            }
+        }
+        None
+    }
+}
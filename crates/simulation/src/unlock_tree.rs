@@ -0,0 +1,407 @@
+//! Unlock tree nodes and branch points.
+//!
+//! Cost, population threshold and display data of every node in the tree,
+//! and the mutually exclusive options offered at each branch point. Which
+//! nodes a city has taken lives in `UnlockState` (see `unlocks.rs`).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UnlockNode {
+    // Tier 0 — Hamlet (0 pop, starter)
+    BasicRoads,
+    ResidentialZoning,
+    CommercialZoning,
+    IndustrialZoning,
+    BasicPower,
+    BasicWater,
+
+    // Tier 1 — Small Settlement (240 pop)
+    HealthCare,
+    DeathCare,
+    BasicSanitation,
+
+    // Tier 2 — Village (1,200 pop)
+    FireService,
+    PoliceService,
+    ElementaryEducation,
+
+    // Tier 3 — Large Village (2,600 pop)
+    HighSchoolEducation,
+    SmallParks,
+    PolicySystem,
+
+    // Tier 4 — Town (5,000 pop)
+    PublicTransport,
+    Landmarks,
+
+    // Tier 5 — Large Town (7,500 pop)
+    HighDensityResidential,
+    HighDensityCommercial,
+    AdvancedTransport,
+    OfficeZoning,
+
+    // Tier 6 — Small City (12,000 pop)
+    UniversityEducation,
+    AdvancedSanitation,
+    PostalService,
+
+    // Tier 7 — City (20,000 pop)
+    SmallAirstrips,
+    AdvancedParks,
+    WaterInfrastructure,
+
+    // Tier 8 — Large City (36,000 pop)
+    Telecom,
+    Entertainment,
+    BasicHeating,
+
+    // Tier 9 — Metropolis (50,000 pop)
+    RegionalAirports,
+    SolarPower,
+    WindPower,
+    SewagePlant,
+
+    // Tier 10 — Large Metropolis (65,000 pop)
+    AdvancedEmergency,
+    DistrictHeatingNetwork,
+    NuclearPower,
+
+    // Tier 11 — Megalopolis (80,000 pop)
+    InternationalAirports,
+
+    // Branch points — bought with development points, one option each
+    DenseCore,
+    GardenSuburbs,
+    TransitFirst,
+    CarCentric,
+    RenewableMandate,
+    NuclearProgram,
+}
+
+/// A fork in the unlock tree. Taking one option closes the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UnlockBranch {
+    Growth,
+    Mobility,
+    Energy,
+}
+
+impl UnlockBranch {
+    pub const ALL: [UnlockBranch; 3] = [
+        UnlockBranch::Growth,
+        UnlockBranch::Mobility,
+        UnlockBranch::Energy,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UnlockBranch::Growth => "Growth",
+            UnlockBranch::Mobility => "Mobility",
+            UnlockBranch::Energy => "Energy",
+        }
+    }
+
+    /// The two choices offered at this branch point.
+    pub fn options(self) -> [UnlockNode; 2] {
+        match self {
+            UnlockBranch::Growth => [UnlockNode::DenseCore, UnlockNode::GardenSuburbs],
+            UnlockBranch::Mobility => [UnlockNode::TransitFirst, UnlockNode::CarCentric],
+            UnlockBranch::Energy => [UnlockNode::RenewableMandate, UnlockNode::NuclearProgram],
+        }
+    }
+
+    /// The trunk node that must be unlocked before choosing.
+    pub fn prerequisite(self) -> UnlockNode {
+        match self {
+            UnlockBranch::Growth => UnlockNode::PolicySystem,
+            UnlockBranch::Mobility => UnlockNode::PublicTransport,
+            UnlockBranch::Energy => UnlockNode::UniversityEducation,
+        }
+    }
+}
+
+impl UnlockNode {
+    pub fn cost(self) -> u32 {
+        match self {
+            // Tier 0 — free starters
+            UnlockNode::BasicRoads
+            | UnlockNode::ResidentialZoning
+            | UnlockNode::CommercialZoning
+            | UnlockNode::IndustrialZoning
+            | UnlockNode::BasicPower
+            | UnlockNode::BasicWater => 0,
+
+            // Tier 1 (240 pop)
+            UnlockNode::HealthCare
+            | UnlockNode::DeathCare
+            | UnlockNode::BasicSanitation => 1,
+
+            // Tier 2 (1,200 pop)
+            UnlockNode::FireService
+            | UnlockNode::PoliceService
+            | UnlockNode::ElementaryEducation => 1,
+
+            // Tier 3 (2,600 pop)
+            UnlockNode::HighSchoolEducation
+            | UnlockNode::SmallParks
+            | UnlockNode::PolicySystem => 2,
+
+            // Tier 4 (5,000 pop)
+            UnlockNode::PublicTransport
+            | UnlockNode::Landmarks => 2,
+
+            // Tier 5 (7,500 pop)
+            UnlockNode::HighDensityResidential
+            | UnlockNode::HighDensityCommercial
+            | UnlockNode::AdvancedTransport
+            | UnlockNode::OfficeZoning => 3,
+
+            // Tier 6 (12,000 pop)
+            UnlockNode::UniversityEducation
+            | UnlockNode::AdvancedSanitation
+            | UnlockNode::PostalService => 3,
+
+            // Tier 7 (20,000 pop)
+            UnlockNode::SmallAirstrips
+            | UnlockNode::AdvancedParks
+            | UnlockNode::WaterInfrastructure => 3,
+
+            // Tier 8 (36,000 pop)
+            UnlockNode::Telecom
+            | UnlockNode::Entertainment
+            | UnlockNode::BasicHeating => 4,
+
+            // Tier 9 (50,000 pop)
+            UnlockNode::RegionalAirports
+            | UnlockNode::SolarPower
+            | UnlockNode::WindPower
+            | UnlockNode::SewagePlant => 4,
+
+            // Tier 10 (65,000 pop)
+            UnlockNode::AdvancedEmergency
+            | UnlockNode::DistrictHeatingNetwork
+            | UnlockNode::NuclearPower => 5,
+
+            // Tier 11 (80,000 pop)
+            UnlockNode::InternationalAirports => 7,
+
+            // Branch points
+            UnlockNode::DenseCore | UnlockNode::GardenSuburbs => 3,
+            UnlockNode::TransitFirst | UnlockNode::CarCentric => 3,
+            UnlockNode::RenewableMandate | UnlockNode::NuclearProgram => 4,
+        }
+    }
+
+    /// Population threshold aligned with the 12-tier milestone system.
+    pub fn required_population(self) -> u32 {
+        match self {
+            // Tier 0 — Hamlet
+            UnlockNode::BasicRoads
+            | UnlockNode::ResidentialZoning
+            | UnlockNode::CommercialZoning
+            | UnlockNode::IndustrialZoning
+            | UnlockNode::BasicPower
+            | UnlockNode::BasicWater => 0,
+
+            // Tier 1 — Small Settlement
+            UnlockNode::HealthCare
+            | UnlockNode::DeathCare
+            | UnlockNode::BasicSanitation => 240,
+
+            // Tier 2 — Village
+            UnlockNode::FireService
+            | UnlockNode::PoliceService
+            | UnlockNode::ElementaryEducation => 1_200,
+
+            // Tier 3 — Large Village
+            UnlockNode::HighSchoolEducation
+            | UnlockNode::SmallParks
+            | UnlockNode::PolicySystem => 2_600,
+
+            // Tier 4 — Town
+            UnlockNode::PublicTransport
+            | UnlockNode::Landmarks => 5_000,
+
+            // Tier 5 — Large Town
+            UnlockNode::HighDensityResidential
+            | UnlockNode::HighDensityCommercial
+            | UnlockNode::AdvancedTransport
+            | UnlockNode::OfficeZoning => 7_500,
+
+            // Tier 6 — Small City
+            UnlockNode::UniversityEducation
+            | UnlockNode::AdvancedSanitation
+            | UnlockNode::PostalService => 12_000,
+
+            // Tier 7 — City
+            UnlockNode::SmallAirstrips
+            | UnlockNode::AdvancedParks
+            | UnlockNode::WaterInfrastructure => 20_000,
+
+            // Tier 8 — Large City
+            UnlockNode::Telecom
+            | UnlockNode::Entertainment
+            | UnlockNode::BasicHeating => 36_000,
+
+            // Tier 9 — Metropolis
+            UnlockNode::RegionalAirports
+            | UnlockNode::SolarPower
+            | UnlockNode::WindPower
+            | UnlockNode::SewagePlant => 50_000,
+
+            // Tier 10 — Large Metropolis
+            UnlockNode::AdvancedEmergency
+            | UnlockNode::DistrictHeatingNetwork
+            | UnlockNode::NuclearPower => 65_000,
+
+            // Tier 11 — Megalopolis
+            UnlockNode::InternationalAirports => 80_000,
+
+            // Branch points open with their prerequisite
+            UnlockNode::DenseCore
+            | UnlockNode::GardenSuburbs
+            | UnlockNode::TransitFirst
+            | UnlockNode::CarCentric
+            | UnlockNode::RenewableMandate
+            | UnlockNode::NuclearProgram => self
+                .branch()
+                .map_or(0, |b| b.prerequisite().required_population()),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UnlockNode::BasicRoads => "Basic Roads",
+            UnlockNode::ResidentialZoning => "Residential Zoning",
+            UnlockNode::CommercialZoning => "Commercial Zoning",
+            UnlockNode::IndustrialZoning => "Industrial Zoning",
+            UnlockNode::BasicPower => "Power Plant",
+            UnlockNode::BasicWater => "Water Tower",
+            UnlockNode::FireService => "Fire Service",
+            UnlockNode::PoliceService => "Police Service",
+            UnlockNode::ElementaryEducation => "Elementary Education",
+            UnlockNode::SmallParks => "Small Parks",
+            UnlockNode::BasicSanitation => "Basic Sanitation",
+            UnlockNode::HealthCare => "Healthcare",
+            UnlockNode::HighSchoolEducation => "High School",
+            UnlockNode::HighDensityResidential => "High-Density Residential",
+            UnlockNode::HighDensityCommercial => "High-Density Commercial",
+            UnlockNode::SolarPower => "Solar Power",
+            UnlockNode::SewagePlant => "Sewage Plant",
+            UnlockNode::AdvancedParks => "Advanced Parks",
+            UnlockNode::DeathCare => "Death Care",
+            UnlockNode::BasicHeating => "Basic Heating",
+            UnlockNode::DistrictHeatingNetwork => "District Heating",
+            UnlockNode::OfficeZoning => "Office Zoning",
+            UnlockNode::UniversityEducation => "University",
+            UnlockNode::WindPower => "Wind Power",
+            UnlockNode::AdvancedSanitation => "Advanced Sanitation",
+            UnlockNode::PublicTransport => "Public Transport",
+            UnlockNode::Entertainment => "Entertainment",
+            UnlockNode::AdvancedEmergency => "Advanced Emergency",
+            UnlockNode::Telecom => "Telecommunications",
+            UnlockNode::AdvancedTransport => "Advanced Transport",
+            UnlockNode::SmallAirstrips => "Small Airstrips",
+            UnlockNode::PostalService => "Postal Service",
+            UnlockNode::WaterInfrastructure => "Water Infrastructure",
+            UnlockNode::RegionalAirports => "Regional Airports",
+            UnlockNode::InternationalAirports => "International Airports",
+            UnlockNode::Landmarks => "Landmarks",
+            UnlockNode::PolicySystem => "City Policies",
+            UnlockNode::NuclearPower => "Nuclear Power",
+            UnlockNode::DenseCore => "Dense Core",
+            UnlockNode::GardenSuburbs => "Garden Suburbs",
+            UnlockNode::TransitFirst => "Transit First",
+            UnlockNode::CarCentric => "Car-Centric Planning",
+            UnlockNode::RenewableMandate => "Renewable Mandate",
+            UnlockNode::NuclearProgram => "Nuclear Program",
+        }
+    }
+
+    /// What taking a branch option does, for the tree viewer.
+    pub fn description(self) -> &'static str {
+        match self {
+            UnlockNode::DenseCore => "High-density residential and commercial zoning right away",
+            UnlockNode::GardenSuburbs => "Large parks and sports fields right away",
+            UnlockNode::TransitFirst => "Subways, trams and ferries right away",
+            UnlockNode::CarCentric => "Road maintenance costs 30% less",
+            UnlockNode::RenewableMandate => {
+                "Solar and wind power right away; no new fossil-fuel plants"
+            }
+            UnlockNode::NuclearProgram => "Nuclear power right away",
+            _ => "",
+        }
+    }
+
+    /// The branch point this node is an option of, if any.
+    pub fn branch(self) -> Option<UnlockBranch> {
+        UnlockBranch::ALL
+            .into_iter()
+            .find(|b| b.options().contains(&self))
+    }
+
+    /// Trunk nodes this branch option grants ahead of their milestone.
+    pub fn grants(self) -> &'static [UnlockNode] {
+        match self {
+            UnlockNode::DenseCore => &[
+                UnlockNode::HighDensityResidential,
+                UnlockNode::HighDensityCommercial,
+            ],
+            UnlockNode::GardenSuburbs => &[UnlockNode::AdvancedParks],
+            UnlockNode::TransitFirst => &[UnlockNode::AdvancedTransport],
+            UnlockNode::RenewableMandate => &[UnlockNode::SolarPower, UnlockNode::WindPower],
+            UnlockNode::NuclearProgram => &[UnlockNode::NuclearPower],
+            _ => &[],
+        }
+    }
+
+    pub fn all() -> &'static [UnlockNode] {
+        &[
+            UnlockNode::BasicRoads,
+            UnlockNode::ResidentialZoning,
+            UnlockNode::CommercialZoning,
+            UnlockNode::IndustrialZoning,
+            UnlockNode::BasicPower,
+            UnlockNode::BasicWater,
+            UnlockNode::HealthCare,
+            UnlockNode::DeathCare,
+            UnlockNode::BasicSanitation,
+            UnlockNode::FireService,
+            UnlockNode::PoliceService,
+            UnlockNode::ElementaryEducation,
+            UnlockNode::HighSchoolEducation,
+            UnlockNode::SmallParks,
+            UnlockNode::PolicySystem,
+            UnlockNode::PublicTransport,
+            UnlockNode::Landmarks,
+            UnlockNode::HighDensityResidential,
+            UnlockNode::HighDensityCommercial,
+            UnlockNode::AdvancedTransport,
+            UnlockNode::OfficeZoning,
+            UnlockNode::UniversityEducation,
+            UnlockNode::AdvancedSanitation,
+            UnlockNode::PostalService,
+            UnlockNode::SmallAirstrips,
+            UnlockNode::AdvancedParks,
+            UnlockNode::WaterInfrastructure,
+            UnlockNode::Telecom,
+            UnlockNode::Entertainment,
+            UnlockNode::BasicHeating,
+            UnlockNode::RegionalAirports,
+            UnlockNode::SolarPower,
+            UnlockNode::WindPower,
+            UnlockNode::SewagePlant,
+            UnlockNode::AdvancedEmergency,
+            UnlockNode::DistrictHeatingNetwork,
+            UnlockNode::NuclearPower,
+            UnlockNode::InternationalAirports,
+            UnlockNode::DenseCore,
+            UnlockNode::GardenSuburbs,
+            UnlockNode::TransitFirst,
+            UnlockNode::CarCentric,
+            UnlockNode::RenewableMandate,
+            UnlockNode::NuclearProgram,
+        ]
+    }
+}
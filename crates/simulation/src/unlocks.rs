@@ -1,10 +1,20 @@
+//! Unlock tree.
+//!
+//! The trunk of the tree is unlocked tier by tier as the city reaches
+//! population milestones (see `milestones.rs`). Development points awarded
+//! at each milestone are spent on branch points: each branch offers two
+//! mutually exclusive choices, and taking one closes the other for the rest
+//! of the playthrough.
+
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::services::ServiceType;
 use crate::utilities::UtilityType;
 
-/// Milestone-based development points and unlock tree
+pub use crate::unlock_tree::{UnlockBranch, UnlockNode};
+
+/// Development points and the nodes of the unlock tree taken so far.
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 pub struct UnlockState {
     pub development_points: u32,
@@ -32,299 +42,39 @@ impl Default for UnlockState {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum UnlockNode {
-    // Tier 0 — Hamlet (0 pop, starter)
-    BasicRoads,
-    ResidentialZoning,
-    CommercialZoning,
-    IndustrialZoning,
-    BasicPower,
-    BasicWater,
-
-    // Tier 1 — Small Settlement (240 pop)
-    HealthCare,
-    DeathCare,
-    BasicSanitation,
-
-    // Tier 2 — Village (1,200 pop)
-    FireService,
-    PoliceService,
-    ElementaryEducation,
-
-    // Tier 3 — Large Village (2,600 pop)
-    HighSchoolEducation,
-    SmallParks,
-    PolicySystem,
-
-    // Tier 4 — Town (5,000 pop)
-    PublicTransport,
-    Landmarks,
-
-    // Tier 5 — Large Town (7,500 pop)
-    HighDensityResidential,
-    HighDensityCommercial,
-    AdvancedTransport,
-    OfficeZoning,
-
-    // Tier 6 — Small City (12,000 pop)
-    UniversityEducation,
-    AdvancedSanitation,
-    PostalService,
-
-    // Tier 7 — City (20,000 pop)
-    SmallAirstrips,
-    AdvancedParks,
-    WaterInfrastructure,
-
-    // Tier 8 — Large City (36,000 pop)
-    Telecom,
-    Entertainment,
-    BasicHeating,
-
-    // Tier 9 — Metropolis (50,000 pop)
-    RegionalAirports,
-    SolarPower,
-    WindPower,
-    SewagePlant,
-
-    // Tier 10 — Large Metropolis (65,000 pop)
-    AdvancedEmergency,
-    DistrictHeatingNetwork,
-    NuclearPower,
-
-    // Tier 11 — Megalopolis (80,000 pop)
-    InternationalAirports,
-}
-
-impl UnlockNode {
-    pub fn cost(self) -> u32 {
-        match self {
-            // Tier 0 — free starters
-            UnlockNode::BasicRoads
-            | UnlockNode::ResidentialZoning
-            | UnlockNode::CommercialZoning
-            | UnlockNode::IndustrialZoning
-            | UnlockNode::BasicPower
-            | UnlockNode::BasicWater => 0,
-
-            // Tier 1 (240 pop)
-            UnlockNode::HealthCare
-            | UnlockNode::DeathCare
-            | UnlockNode::BasicSanitation => 1,
-
-            // Tier 2 (1,200 pop)
-            UnlockNode::FireService
-            | UnlockNode::PoliceService
-            | UnlockNode::ElementaryEducation => 1,
-
-            // Tier 3 (2,600 pop)
-            UnlockNode::HighSchoolEducation
-            | UnlockNode::SmallParks
-            | UnlockNode::PolicySystem => 2,
-
-            // Tier 4 (5,000 pop)
-            UnlockNode::PublicTransport
-            | UnlockNode::Landmarks => 2,
-
-            // Tier 5 (7,500 pop)
-            UnlockNode::HighDensityResidential
-            | UnlockNode::HighDensityCommercial
-            | UnlockNode::AdvancedTransport
-            | UnlockNode::OfficeZoning => 3,
-
-            // Tier 6 (12,000 pop)
-            UnlockNode::UniversityEducation
-            | UnlockNode::AdvancedSanitation
-            | UnlockNode::PostalService => 3,
-
-            // Tier 7 (20,000 pop)
-            UnlockNode::SmallAirstrips
-            | UnlockNode::AdvancedParks
-            | UnlockNode::WaterInfrastructure => 3,
-
-            // Tier 8 (36,000 pop)
-            UnlockNode::Telecom
-            | UnlockNode::Entertainment
-            | UnlockNode::BasicHeating => 4,
-
-            // Tier 9 (50,000 pop)
-            UnlockNode::RegionalAirports
-            | UnlockNode::SolarPower
-            | UnlockNode::WindPower
-            | UnlockNode::SewagePlant => 4,
-
-            // Tier 10 (65,000 pop)
-            UnlockNode::AdvancedEmergency
-            | UnlockNode::DistrictHeatingNetwork
-            | UnlockNode::NuclearPower => 5,
-
-            // Tier 11 (80,000 pop)
-            UnlockNode::InternationalAirports => 7,
-        }
-    }
-
-    /// Population threshold aligned with the 12-tier milestone system.
-    pub fn required_population(self) -> u32 {
-        match self {
-            // Tier 0 — Hamlet
-            UnlockNode::BasicRoads
-            | UnlockNode::ResidentialZoning
-            | UnlockNode::CommercialZoning
-            | UnlockNode::IndustrialZoning
-            | UnlockNode::BasicPower
-            | UnlockNode::BasicWater => 0,
-
-            // Tier 1 — Small Settlement
-            UnlockNode::HealthCare
-            | UnlockNode::DeathCare
-            | UnlockNode::BasicSanitation => 240,
-
-            // Tier 2 — Village
-            UnlockNode::FireService
-            | UnlockNode::PoliceService
-            | UnlockNode::ElementaryEducation => 1_200,
-
-            // Tier 3 — Large Village
-            UnlockNode::HighSchoolEducation
-            | UnlockNode::SmallParks
-            | UnlockNode::PolicySystem => 2_600,
-
-            // Tier 4 — Town
-            UnlockNode::PublicTransport
-            | UnlockNode::Landmarks => 5_000,
-
-            // Tier 5 — Large Town
-            UnlockNode::HighDensityResidential
-            | UnlockNode::HighDensityCommercial
-            | UnlockNode::AdvancedTransport
-            | UnlockNode::OfficeZoning => 7_500,
-
-            // Tier 6 — Small City
-            UnlockNode::UniversityEducation
-            | UnlockNode::AdvancedSanitation
-            | UnlockNode::PostalService => 12_000,
-
-            // Tier 7 — City
-            UnlockNode::SmallAirstrips
-            | UnlockNode::AdvancedParks
-            | UnlockNode::WaterInfrastructure => 20_000,
-
-            // Tier 8 — Large City
-            UnlockNode::Telecom
-            | UnlockNode::Entertainment
-            | UnlockNode::BasicHeating => 36_000,
-
-            // Tier 9 — Metropolis
-            UnlockNode::RegionalAirports
-            | UnlockNode::SolarPower
-            | UnlockNode::WindPower
-            | UnlockNode::SewagePlant => 50_000,
-
-            // Tier 10 — Large Metropolis
-            UnlockNode::AdvancedEmergency
-            | UnlockNode::DistrictHeatingNetwork
-            | UnlockNode::NuclearPower => 65_000,
-
-            // Tier 11 — Megalopolis
-            UnlockNode::InternationalAirports => 80_000,
-        }
+impl UnlockState {
+    pub fn is_unlocked(&self, node: UnlockNode) -> bool {
+        self.unlocked_nodes.contains(&node)
     }
 
-    pub fn name(self) -> &'static str {
-        match self {
-            UnlockNode::BasicRoads => "Basic Roads",
-            UnlockNode::ResidentialZoning => "Residential Zoning",
-            UnlockNode::CommercialZoning => "Commercial Zoning",
-            UnlockNode::IndustrialZoning => "Industrial Zoning",
-            UnlockNode::BasicPower => "Power Plant",
-            UnlockNode::BasicWater => "Water Tower",
-            UnlockNode::FireService => "Fire Service",
-            UnlockNode::PoliceService => "Police Service",
-            UnlockNode::ElementaryEducation => "Elementary Education",
-            UnlockNode::SmallParks => "Small Parks",
-            UnlockNode::BasicSanitation => "Basic Sanitation",
-            UnlockNode::HealthCare => "Healthcare",
-            UnlockNode::HighSchoolEducation => "High School",
-            UnlockNode::HighDensityResidential => "High-Density Residential",
-            UnlockNode::HighDensityCommercial => "High-Density Commercial",
-            UnlockNode::SolarPower => "Solar Power",
-            UnlockNode::SewagePlant => "Sewage Plant",
-            UnlockNode::AdvancedParks => "Advanced Parks",
-            UnlockNode::DeathCare => "Death Care",
-            UnlockNode::BasicHeating => "Basic Heating",
-            UnlockNode::DistrictHeatingNetwork => "District Heating",
-            UnlockNode::OfficeZoning => "Office Zoning",
-            UnlockNode::UniversityEducation => "University",
-            UnlockNode::WindPower => "Wind Power",
-            UnlockNode::AdvancedSanitation => "Advanced Sanitation",
-            UnlockNode::PublicTransport => "Public Transport",
-            UnlockNode::Entertainment => "Entertainment",
-            UnlockNode::AdvancedEmergency => "Advanced Emergency",
-            UnlockNode::Telecom => "Telecommunications",
-            UnlockNode::AdvancedTransport => "Advanced Transport",
-            UnlockNode::SmallAirstrips => "Small Airstrips",
-            UnlockNode::PostalService => "Postal Service",
-            UnlockNode::WaterInfrastructure => "Water Infrastructure",
-            UnlockNode::RegionalAirports => "Regional Airports",
-            UnlockNode::InternationalAirports => "International Airports",
-            UnlockNode::Landmarks => "Landmarks",
-            UnlockNode::PolicySystem => "City Policies",
-            UnlockNode::NuclearPower => "Nuclear Power",
-        }
+    /// Whether the node is unlocked, or granted early by a branch choice.
+    pub fn has_access(&self, node: UnlockNode) -> bool {
+        self.is_unlocked(node)
+            || self
+                .unlocked_nodes
+                .iter()
+                .any(|chosen| chosen.grants().contains(&node))
     }
 
-    pub fn all() -> &'static [UnlockNode] {
-        &[
-            UnlockNode::BasicRoads,
-            UnlockNode::ResidentialZoning,
-            UnlockNode::CommercialZoning,
-            UnlockNode::IndustrialZoning,
-            UnlockNode::BasicPower,
-            UnlockNode::BasicWater,
-            UnlockNode::HealthCare,
-            UnlockNode::DeathCare,
-            UnlockNode::BasicSanitation,
-            UnlockNode::FireService,
-            UnlockNode::PoliceService,
-            UnlockNode::ElementaryEducation,
-            UnlockNode::HighSchoolEducation,
-            UnlockNode::SmallParks,
-            UnlockNode::PolicySystem,
-            UnlockNode::PublicTransport,
-            UnlockNode::Landmarks,
-            UnlockNode::HighDensityResidential,
-            UnlockNode::HighDensityCommercial,
-            UnlockNode::AdvancedTransport,
-            UnlockNode::OfficeZoning,
-            UnlockNode::UniversityEducation,
-            UnlockNode::AdvancedSanitation,
-            UnlockNode::PostalService,
-            UnlockNode::SmallAirstrips,
-            UnlockNode::AdvancedParks,
-            UnlockNode::WaterInfrastructure,
-            UnlockNode::Telecom,
-            UnlockNode::Entertainment,
-            UnlockNode::BasicHeating,
-            UnlockNode::RegionalAirports,
-            UnlockNode::SolarPower,
-            UnlockNode::WindPower,
-            UnlockNode::SewagePlant,
-            UnlockNode::AdvancedEmergency,
-            UnlockNode::DistrictHeatingNetwork,
-            UnlockNode::NuclearPower,
-            UnlockNode::InternationalAirports,
-        ]
+    /// The choice taken at a branch point, if any.
+    pub fn chosen(&self, branch: UnlockBranch) -> Option<UnlockNode> {
+        branch.options().into_iter().find(|&n| self.is_unlocked(n))
     }
-}
 
-impl UnlockState {
-    pub fn is_unlocked(&self, node: UnlockNode) -> bool {
-        self.unlocked_nodes.contains(&node)
+    /// Whether the node was closed off by taking the other option of its
+    /// branch.
+    pub fn is_foreclosed(&self, node: UnlockNode) -> bool {
+        node.branch()
+            .and_then(|b| self.chosen(b))
+            .is_some_and(|chosen| chosen != node)
     }
 
     pub fn can_purchase(&self, node: UnlockNode, population: u32) -> bool {
         !self.is_unlocked(node)
+            && !self.is_foreclosed(node)
+            && node
+                .branch()
+                .is_none_or(|b| self.is_unlocked(b.prerequisite()))
             && population >= node.required_population()
             && self.available_points() >= node.cost()
     }
@@ -335,7 +85,7 @@ impl UnlockState {
 
     pub fn purchase(&mut self, node: UnlockNode) -> bool {
         let cost = node.cost();
-        if self.available_points() < cost || self.is_unlocked(node) {
+        if self.available_points() < cost || self.is_unlocked(node) || self.is_foreclosed(node) {
             return false;
         }
         self.spent_points += cost;
@@ -349,7 +99,9 @@ impl UnlockState {
             ServiceType::FireStation | ServiceType::FireHouse => {
                 self.is_unlocked(UnlockNode::FireService)
             }
-            ServiceType::FireHQ => self.is_unlocked(UnlockNode::AdvancedEmergency),
+            ServiceType::FireHQ | ServiceType::HazmatResponse => {
+                self.is_unlocked(UnlockNode::AdvancedEmergency)
+            }
             ServiceType::PoliceStation | ServiceType::PoliceKiosk => {
                 self.is_unlocked(UnlockNode::PoliceService)
             }
@@ -375,7 +127,7 @@ impl UnlockState {
                 self.is_unlocked(UnlockNode::SmallParks)
             }
             ServiceType::LargePark | ServiceType::SportsField => {
-                self.is_unlocked(UnlockNode::AdvancedParks)
+                self.has_access(UnlockNode::AdvancedParks)
             }
             ServiceType::Plaza | ServiceType::Stadium => {
                 self.is_unlocked(UnlockNode::Entertainment)
@@ -400,7 +152,7 @@ impl UnlockState {
             ServiceType::SubwayStation
             | ServiceType::TramDepot
             | ServiceType::FerryPier => {
-                self.is_unlocked(UnlockNode::AdvancedTransport)
+                self.has_access(UnlockNode::AdvancedTransport)
             }
             ServiceType::SmallAirstrip => {
                 self.is_unlocked(UnlockNode::SmallAirstrips)
@@ -440,19 +192,24 @@ impl UnlockState {
             | ServiceType::SubstanceAbuseTreatmentCenter
             | ServiceType::SeniorCenter
             | ServiceType::YouthCenter => self.is_unlocked(UnlockNode::HealthCare),
+            ServiceType::Hotel => self.is_unlocked(UnlockNode::Entertainment),
+            ServiceType::ConventionCenter => self.is_unlocked(UnlockNode::Landmarks),
         }
     }
 
     /// Check if a utility type is unlocked
     pub fn is_utility_unlocked(&self, utility_type: UtilityType) -> bool {
+        if self.banned_by(utility_type).is_some() {
+            return false;
+        }
         match utility_type {
             UtilityType::PowerPlant => self.is_unlocked(UnlockNode::BasicPower),
-            UtilityType::SolarFarm => self.is_unlocked(UnlockNode::SolarPower),
-            UtilityType::WindTurbine => self.is_unlocked(UnlockNode::WindPower),
+            UtilityType::SolarFarm => self.has_access(UnlockNode::SolarPower),
+            UtilityType::WindTurbine => self.has_access(UnlockNode::WindPower),
             UtilityType::WaterTower => self.is_unlocked(UnlockNode::BasicWater),
             UtilityType::SewagePlant => self.is_unlocked(UnlockNode::SewagePlant),
             UtilityType::NuclearPlant => {
-                self.is_unlocked(UnlockNode::NuclearPower)
+                self.has_access(UnlockNode::NuclearPower)
             }
             UtilityType::Geothermal => self.is_unlocked(UnlockNode::WindPower),
             UtilityType::PumpingStation => {
@@ -466,6 +223,29 @@ impl UnlockState {
             UtilityType::GasPlant => self.is_unlocked(UnlockNode::BasicPower),
         }
     }
+
+    /// The branch choice that forbids building this utility, if any.
+    pub fn banned_by(&self, utility_type: UtilityType) -> Option<UnlockNode> {
+        (self.is_unlocked(UnlockNode::RenewableMandate) && is_fossil_fuel(utility_type))
+            .then_some(UnlockNode::RenewableMandate)
+    }
+
+    /// Multiplier on road maintenance costs from the Mobility branch.
+    pub fn road_maintenance_multiplier(&self) -> f64 {
+        if self.is_unlocked(UnlockNode::CarCentric) {
+            0.7
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Plants the Renewable Mandate forbids building.
+fn is_fossil_fuel(utility_type: UtilityType) -> bool {
+    matches!(
+        utility_type,
+        UtilityType::PowerPlant | UtilityType::OilPlant | UtilityType::GasPlant
+    )
 }
 
 pub struct UnlocksPlugin;
@@ -0,0 +1,24 @@
+//! Civil unrest and riots.
+//!
+//! Each district builds tension while its residents are unhappy, many of its
+//! working-age residents are out of work, or the city as a whole is split
+//! between rich and poor (from `WealthStats`). Each of those grievances adds
+//! to the tension every slow tick; districts without grievances calm down.
+//! When a populated district boils over it riots: buildings take damage,
+//! rioters set fires that the fire system then has to fight, and every
+//! police station covering the district bills overtime.
+//!
+//! The player ends a riot with concessions, paid per resident, which leave
+//! residents happier for months, or with a cheaper crackdown, which leaves
+//! them resentful and lets tension build faster while the grudge lasts.
+//! Riots nobody deals with burn out after a few days.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    apply_approval_effects, handle_unrest_resolutions, riot_damage, update_unrest, UnrestPlugin,
+};
+pub use types::*;
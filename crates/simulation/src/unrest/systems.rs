@@ -0,0 +1,271 @@
+//! Tension build-up, riots, police overtime and resolution requests.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::citizen::{Citizen, CitizenDetails, HomeLocation, LifeStage, WorkLocation};
+use crate::config::CELL_SIZE;
+use crate::economy::CityBudget;
+use crate::fire::OnFire;
+use crate::grid::WorldGrid;
+use crate::happiness::HAPPINESS_UPDATE_INTERVAL;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::structural_integrity::StructuralState;
+use crate::time_of_day::GameClock;
+use crate::wealth::WealthStats;
+use crate::wind_damage::types::{rand_f32, splitmix64};
+use crate::{SlowTickTimer, TestSafetyNet, TickCounter};
+
+use super::types::*;
+
+fn center_location(district: usize) -> Option<(f32, f32)> {
+    let (x, y) = district_center(district);
+    Some(WorldGrid::grid_to_world(x, y))
+}
+
+/// Per-district resident tallies gathered each slow tick.
+#[derive(Default, Clone, Copy)]
+struct DistrictTally {
+    population: u32,
+    happiness: f32,
+    labor_force: u32,
+    unemployed: u32,
+}
+
+/// Every slow tick, build or relieve tension in each district and start a
+/// riot where it boils over.
+pub fn update_unrest(
+    slow_timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    safety_net: Option<Res<TestSafetyNet>>,
+    wealth: Res<WealthStats>,
+    mut state: ResMut<UnrestState>,
+    citizens: Query<(&CitizenDetails, &HomeLocation, Has<WorkLocation>), With<Citizen>>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    state.effects.retain(|e| e.until_day > clock.day);
+
+    let mut tallies = vec![DistrictTally::default(); state.tension.len()];
+    for (details, home, employed) in &citizens {
+        let Some(tally) = tallies.get_mut(district_index(home.grid_x, home.grid_y)) else {
+            continue;
+        };
+        tally.population += 1;
+        tally.happiness += details.happiness;
+        if matches!(
+            details.life_stage(),
+            LifeStage::YoungAdult | LifeStage::Adult | LifeStage::Senior
+        ) {
+            tally.labor_force += 1;
+            tally.unemployed += !employed as u32;
+        }
+    }
+
+    let inequality = inequality_index(&wealth);
+    for (district, tally) in tallies.into_iter().enumerate() {
+        let grievances = if tally.population == 0 {
+            0
+        } else {
+            let unemployment = tally.unemployed as f32 / tally.labor_force.max(1) as f32;
+            let avg_happiness = tally.happiness / tally.population as f32;
+            grievances(avg_happiness, unemployment, inequality)
+        };
+        let change = if grievances > 0 {
+            grievances as f32 * TENSION_PER_GRIEVANCE * state.tension_rate(district)
+        } else {
+            -TENSION_DECAY
+        };
+        let tension = (state.tension[district] + change).clamp(0.0, RIOT_TENSION);
+        state.tension[district] = tension;
+
+        if tension < RIOT_TENSION
+            || tally.population < MIN_RIOT_POPULATION
+            || safety_net.is_some()
+            || state.riot_in(district).is_some()
+            || state.riots.len() >= MAX_ACTIVE_RIOTS
+        {
+            continue;
+        }
+        state.start(district, tally.population, clock.day);
+        let (x, y) = district_center(district);
+        notifications.send(NotificationEvent {
+            text: format!("Riots have broken out in the district around ({x}, {y})"),
+            priority: NotificationPriority::Emergency,
+            category: NotificationCategory::Citizens,
+            location: center_location(district),
+        });
+    }
+}
+
+/// Every slow tick, rioters damage property and start fires, and police
+/// covering the district work overtime. Riots nobody resolves burn out after
+/// a few days.
+#[allow(clippy::too_many_arguments)]
+pub fn riot_damage(
+    mut commands: Commands,
+    slow_timer: Res<SlowTickTimer>,
+    clock: Res<GameClock>,
+    tick: Res<TickCounter>,
+    mut budget: ResMut<CityBudget>,
+    mut state: ResMut<UnrestState>,
+    mut structural: ResMut<StructuralState>,
+    buildings: Query<(Entity, &Building, Has<OnFire>)>,
+    services: Query<&ServiceBuilding>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !slow_timer.should_run() || state.riots.is_empty() {
+        return;
+    }
+
+    let state = &mut *state;
+    let base_seed = splitmix64(tick.0 ^ 0x7e3a_91c5);
+    for riot in &mut state.riots {
+        let (cx, cy) = district_center(riot.district);
+        let stations = services
+            .iter()
+            .filter(|s| {
+                ServiceBuilding::is_police(s.service_type)
+                    && s.service_type != ServiceType::Prison
+                    && s.grid_x.abs_diff(cx).max(s.grid_y.abs_diff(cy)) as f32
+                        <= s.radius / CELL_SIZE
+            })
+            .count();
+        let overtime = OVERTIME_COST_PER_STATION * stations as f64;
+        budget.treasury -= overtime;
+        riot.overtime_cost += overtime;
+        state.total_overtime += overtime;
+
+        let fire_chance = RIOT_FIRE_CHANCE / (1 + stations) as f32;
+        for (entity, building, on_fire) in &buildings {
+            let (x, y) = (building.grid_x, building.grid_y);
+            if district_index(x, y) != riot.district {
+                continue;
+            }
+            structural.wear(x, y, RIOT_PROPERTY_DAMAGE);
+            let seed = splitmix64(base_seed ^ (((y as u64) << 16) | x as u64));
+            if !on_fire && rand_f32(seed) < fire_chance {
+                commands.entity(entity).insert(OnFire {
+                    intensity: RIOT_FIRE_INTENSITY,
+                    ticks_burning: 0,
+                });
+                riot.buildings_burned += 1;
+                state.total_burned += 1;
+            }
+        }
+    }
+
+    let tension = &mut state.tension;
+    state.riots.retain(|riot| {
+        if clock.day < riot.started_day + RIOT_BURNOUT_DAYS {
+            return true;
+        }
+        tension[riot.district] = RIOT_TENSION * 0.5;
+        notifications.send(NotificationEvent {
+            text: format!(
+                "Riots burned out after {} buildings were set alight",
+                riot.buildings_burned
+            ),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::Citizens,
+            location: center_location(riot.district),
+        });
+        false
+    });
+}
+
+/// Residents of districts where a riot was resolved keep a happiness bonus
+/// or penalty for months. Applied right after happiness is recomputed.
+pub fn apply_approval_effects(
+    tick: Res<TickCounter>,
+    state: Res<UnrestState>,
+    mut citizens: Query<(&mut CitizenDetails, &HomeLocation), With<Citizen>>,
+) {
+    if state.effects.is_empty() || !tick.0.is_multiple_of(HAPPINESS_UPDATE_INTERVAL) {
+        return;
+    }
+    for (mut details, home) in &mut citizens {
+        let modifier = state.happiness_modifier(district_index(home.grid_x, home.grid_y));
+        if modifier != 0.0 {
+            details.happiness = (details.happiness + modifier).clamp(0.0, 100.0);
+        }
+    }
+}
+
+/// Apply concession and crackdown requests from the UI.
+pub fn handle_unrest_resolutions(
+    mut requests: EventReader<UnrestResolutionRequest>,
+    clock: Res<GameClock>,
+    mut budget: ResMut<CityBudget>,
+    mut state: ResMut<UnrestState>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for request in requests.read() {
+        let Some(riot) = state.riot_in(request.district) else {
+            continue;
+        };
+        let cost = match request.resolution {
+            Resolution::Concessions => riot.concession_cost(),
+            Resolution::Crackdown => CRACKDOWN_COST,
+        };
+        let location = center_location(request.district);
+        if budget.treasury < cost {
+            notifications.send(NotificationEvent {
+                text: format!(
+                    "Cannot afford {}: need ${cost:.0}",
+                    request.resolution.name().to_lowercase()
+                ),
+                priority: NotificationPriority::Info,
+                category: NotificationCategory::Citizens,
+                location,
+            });
+            continue;
+        }
+        budget.treasury -= cost;
+        state.resolve(request.district, request.resolution, clock.day);
+        let (text, priority) = match request.resolution {
+            Resolution::Concessions => (
+                "Protesters accepted the city's concessions and went home",
+                NotificationPriority::Positive,
+            ),
+            Resolution::Crackdown => (
+                "Police cleared the riot; residents will not forget it soon",
+                NotificationPriority::Warning,
+            ),
+        };
+        notifications.send(NotificationEvent {
+            text: text.to_string(),
+            priority,
+            category: NotificationCategory::Citizens,
+            location,
+        });
+    }
+}
+
+pub struct UnrestPlugin;
+
+impl Plugin for UnrestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnrestState>()
+            .add_event::<UnrestResolutionRequest>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    apply_approval_effects.after(crate::happiness::update_happiness),
+                    update_unrest.after(crate::wealth::update_wealth_stats),
+                    riot_damage,
+                )
+                    .chain()
+                    .in_set(crate::SimulationSet::Simulation),
+            )
+            .add_systems(Update, handle_unrest_resolutions);
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<UnrestState>();
+    }
+}
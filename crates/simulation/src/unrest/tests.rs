@@ -0,0 +1,80 @@
+use super::*;
+use crate::wealth::WealthStats;
+use crate::Saveable;
+
+#[test]
+fn test_inequality_index_is_zero_for_uniform_city() {
+    let wealth = WealthStats {
+        low_income_count: 0,
+        middle_income_count: 100,
+        high_income_count: 0,
+    };
+    assert_eq!(inequality_index(&wealth), 0.0);
+    assert_eq!(inequality_index(&WealthStats::default()), 0.0);
+}
+
+#[test]
+fn test_inequality_index_peaks_for_polarized_city() {
+    let wealth = WealthStats {
+        low_income_count: 50,
+        middle_income_count: 0,
+        high_income_count: 50,
+    };
+    assert!((inequality_index(&wealth) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_grievances_count_crossed_thresholds() {
+    assert_eq!(grievances(70.0, 0.05, 0.1), 0);
+    assert_eq!(grievances(30.0, 0.05, 0.1), 1);
+    assert_eq!(grievances(30.0, 0.5, 0.9), 3);
+}
+
+#[test]
+fn test_district_center_is_inside_district() {
+    let district = district_index(40, 70);
+    let (x, y) = district_center(district);
+    assert_eq!(district_index(x, y), district);
+}
+
+#[test]
+fn test_concessions_resolve_riot_with_bonus() {
+    let mut state = UnrestState::default();
+    state.tension[3] = RIOT_TENSION;
+    state.start(3, 100, 10);
+    assert!(state.resolve(3, Resolution::Concessions, 12));
+
+    assert!(state.riots.is_empty());
+    assert_eq!(state.tension[3], 0.0);
+    assert_eq!(state.happiness_modifier(3), CONCESSION_HAPPINESS);
+    assert_eq!(state.tension_rate(3), 1.0);
+    assert_eq!(state.happiness_modifier(4), 0.0);
+    assert!(!state.resolve(3, Resolution::Crackdown, 12));
+}
+
+#[test]
+fn test_crackdown_leaves_grudge() {
+    let mut state = UnrestState::default();
+    state.start(5, 100, 10);
+    state.resolve(5, Resolution::Crackdown, 10);
+    assert!(state.happiness_modifier(5) < 0.0);
+    assert_eq!(state.tension_rate(5), CRACKDOWN_TENSION_RATE);
+}
+
+#[test]
+fn test_concession_cost_scales_with_population() {
+    let mut state = UnrestState::default();
+    let small = state.start(1, 100, 1).concession_cost();
+    let large = state.start(2, 400, 1).concession_cost();
+    assert_eq!(large, small * 4.0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    let mut state = UnrestState::default();
+    assert!(state.save_to_bytes().is_none());
+    state.tension[7] = 42.0;
+    state.start(7, 80, 3);
+    let bytes = state.save_to_bytes().expect("non-default state saves");
+    assert_eq!(UnrestState::load_from_bytes(&bytes), state);
+}
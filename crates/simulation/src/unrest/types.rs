@@ -0,0 +1,271 @@
+//! District tension, riots, resolution choices and the saved state.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::districts::{DISTRICTS_X, DISTRICTS_Y, DISTRICT_SIZE};
+use crate::wealth::{WealthStats, WealthTier};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Average happiness below which a district has a grievance.
+pub const UNHAPPY_THRESHOLD: f32 = 40.0;
+
+/// Unemployment rate above which a district has a grievance.
+pub const UNEMPLOYMENT_THRESHOLD: f32 = 0.15;
+
+/// City inequality index above which every district has a grievance.
+pub const INEQUALITY_THRESHOLD: f32 = 0.3;
+
+/// Tension gained per grievance each slow tick.
+pub const TENSION_PER_GRIEVANCE: f32 = 2.0;
+
+/// Tension lost each slow tick by a district without grievances.
+pub const TENSION_DECAY: f32 = 1.5;
+
+/// Tension at which a district riots.
+pub const RIOT_TENSION: f32 = 100.0;
+
+/// Districts with fewer residents than this do not riot.
+pub const MIN_RIOT_POPULATION: u32 = 50;
+
+/// No new riots start while this many are still going on.
+pub const MAX_ACTIVE_RIOTS: usize = 2;
+
+/// An unresolved riot burns itself out after this many days, leaving the
+/// district at half tension.
+pub const RIOT_BURNOUT_DAYS: u32 = 5;
+
+/// Condition lost by every building in a rioting district each slow tick.
+pub const RIOT_PROPERTY_DAMAGE: f32 = 1.0;
+
+/// Chance per slow tick that rioters set a building on fire. Each police
+/// station covering the district divides the chance.
+pub const RIOT_FIRE_CHANCE: f32 = 0.02;
+pub const RIOT_FIRE_INTENSITY: f32 = 25.0;
+
+/// Police overtime charged each slow tick for every station covering a
+/// rioting district.
+pub const OVERTIME_COST_PER_STATION: f64 = 150.0;
+
+/// Concessions cost this much per resident of the district.
+pub const CONCESSION_COST_PER_RESIDENT: f64 = 40.0;
+
+/// Up-front cost of a crackdown.
+pub const CRACKDOWN_COST: f64 = 2_500.0;
+
+/// Days a resolution keeps affecting the district's approval.
+pub const APPROVAL_EFFECT_DAYS: u32 = 180;
+
+/// Happiness bonus for residents after concessions.
+pub const CONCESSION_HAPPINESS: f32 = 6.0;
+
+/// Happiness penalty for residents after a crackdown, and how much faster
+/// tension builds there while the grudge lasts.
+pub const CRACKDOWN_HAPPINESS: f32 = -8.0;
+pub const CRACKDOWN_TENSION_RATE: f32 = 1.5;
+
+// ---------------------------------------------------------------------------
+// Grievances
+// ---------------------------------------------------------------------------
+
+/// Inequality index from the city's wealth tiers: 0 when everyone is in one
+/// tier, 1 when the city is split evenly between the poorest and richest.
+pub fn inequality_index(wealth: &WealthStats) -> f32 {
+    4.0 * wealth.percentage(WealthTier::LowIncome) * wealth.percentage(WealthTier::HighIncome)
+}
+
+/// Number of thresholds a district crosses.
+pub fn grievances(avg_happiness: f32, unemployment: f32, inequality: f32) -> u32 {
+    (avg_happiness < UNHAPPY_THRESHOLD) as u32
+        + (unemployment > UNEMPLOYMENT_THRESHOLD) as u32
+        + (inequality > INEQUALITY_THRESHOLD) as u32
+}
+
+/// Index of the statistical district containing (`x`, `y`).
+pub fn district_index(x: usize, y: usize) -> usize {
+    (y / DISTRICT_SIZE) * DISTRICTS_X + x / DISTRICT_SIZE
+}
+
+/// Center cell of a statistical district.
+pub fn district_center(district: usize) -> (usize, usize) {
+    (
+        (district % DISTRICTS_X) * DISTRICT_SIZE + DISTRICT_SIZE / 2,
+        (district / DISTRICTS_X) * DISTRICT_SIZE + DISTRICT_SIZE / 2,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Riots and resolutions
+// ---------------------------------------------------------------------------
+
+/// How the player ended a riot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum Resolution {
+    /// Meet the protesters' demands: costly, but residents remember it
+    /// fondly.
+    Concessions,
+    /// Send in the police: cheap, but residents resent it and unrest
+    /// returns faster.
+    Crackdown,
+}
+
+impl Resolution {
+    pub fn name(self) -> &'static str {
+        match self {
+            Resolution::Concessions => "Concessions",
+            Resolution::Crackdown => "Crackdown",
+        }
+    }
+}
+
+/// A riot in progress.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Riot {
+    pub district: usize,
+    pub started_day: u32,
+    /// Residents when the riot started.
+    pub population: u32,
+    pub buildings_burned: u32,
+    pub overtime_cost: f64,
+}
+
+impl Riot {
+    pub fn concession_cost(&self) -> f64 {
+        CONCESSION_COST_PER_RESIDENT * self.population as f64
+    }
+}
+
+/// The lasting effect of how a riot was resolved.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct ApprovalEffect {
+    pub district: usize,
+    pub resolution: Resolution,
+    pub until_day: u32,
+}
+
+impl ApprovalEffect {
+    pub fn happiness(&self) -> f32 {
+        match self.resolution {
+            Resolution::Concessions => CONCESSION_HAPPINESS,
+            Resolution::Crackdown => CRACKDOWN_HAPPINESS,
+        }
+    }
+
+    pub fn tension_rate(&self) -> f32 {
+        match self.resolution {
+            Resolution::Concessions => 1.0,
+            Resolution::Crackdown => CRACKDOWN_TENSION_RATE,
+        }
+    }
+}
+
+/// Per-district tension, active riots and lasting resolution effects.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct UnrestState {
+    /// Tension of each statistical district, 0 to `RIOT_TENSION`.
+    pub tension: Vec<f32>,
+    pub riots: Vec<Riot>,
+    pub effects: Vec<ApprovalEffect>,
+    pub total_riots: u32,
+    pub total_burned: u32,
+    pub total_overtime: f64,
+}
+
+impl Default for UnrestState {
+    fn default() -> Self {
+        Self {
+            tension: vec![0.0; DISTRICTS_X * DISTRICTS_Y],
+            riots: Vec::new(),
+            effects: Vec::new(),
+            total_riots: 0,
+            total_burned: 0,
+            total_overtime: 0.0,
+        }
+    }
+}
+
+impl UnrestState {
+    pub fn riot_in(&self, district: usize) -> Option<&Riot> {
+        self.riots.iter().find(|r| r.district == district)
+    }
+
+    /// Record a riot in `district` and return it.
+    pub fn start(&mut self, district: usize, population: u32, day: u32) -> &Riot {
+        self.total_riots += 1;
+        self.riots.push(Riot {
+            district,
+            started_day: day,
+            population,
+            buildings_burned: 0,
+            overtime_cost: 0.0,
+        });
+        self.riots.last().unwrap()
+    }
+
+    /// Happiness adjustment for residents of `district` from past
+    /// resolutions.
+    pub fn happiness_modifier(&self, district: usize) -> f32 {
+        self.effects
+            .iter()
+            .filter(|e| e.district == district)
+            .map(ApprovalEffect::happiness)
+            .sum()
+    }
+
+    /// Multiplier on how quickly tension builds in `district`.
+    pub fn tension_rate(&self, district: usize) -> f32 {
+        self.effects
+            .iter()
+            .filter(|e| e.district == district)
+            .map(ApprovalEffect::tension_rate)
+            .product()
+    }
+
+    /// End the riot in `district` with `resolution`. Returns false if there
+    /// was no riot there.
+    pub fn resolve(&mut self, district: usize, resolution: Resolution, day: u32) -> bool {
+        let before = self.riots.len();
+        self.riots.retain(|r| r.district != district);
+        if self.riots.len() == before {
+            return false;
+        }
+        if let Some(tension) = self.tension.get_mut(district) {
+            *tension = 0.0;
+        }
+        self.effects.push(ApprovalEffect {
+            district,
+            resolution,
+            until_day: day + APPROVAL_EFFECT_DAYS,
+        });
+        true
+    }
+}
+
+impl Saveable for UnrestState {
+    const SAVE_KEY: &'static str = "unrest_state";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        let mut state: Self = crate::decode_or_warn(Self::SAVE_KEY, bytes);
+        state.tension.resize(DISTRICTS_X * DISTRICTS_Y, 0.0);
+        state
+    }
+}
+
+/// Sent by the UI to end the riot in a district.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct UnrestResolutionRequest {
+    pub district: usize,
+    pub resolution: Resolution,
+}
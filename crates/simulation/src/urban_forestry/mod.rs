@@ -0,0 +1,24 @@
+//! Urban forestry: street trees that grow over the years.
+//!
+//! Every planted tree has a species and an age. Trees start as saplings and
+//! grow to full size over several years, at a pace set by their species.
+//! Species differ in how much shade they cast, how much water they need and
+//! how readily they burn in a forest fire.
+//!
+//! Big trees are worth more than saplings: on top of the flat benefits of
+//! any tree, a tree's canopy cools the heat island and lifts nearby land
+//! values in proportion to its size and shade.
+//!
+//! Trees need pruning, paid for from the forestry budget at a funding level
+//! the player sets. Under-funded trees lose health, large trees fastest,
+//! and trees that lose all their health die and are cleared away.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    apply_canopy_cooling, apply_canopy_land_value, update_urban_forestry, UrbanForestryPlugin,
+};
+pub use types::*;
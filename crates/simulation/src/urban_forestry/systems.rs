@@ -0,0 +1,128 @@
+//! Daily tree growth and upkeep, and the canopy's cooling and land-value
+//! benefits.
+
+use bevy::prelude::*;
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::economy::CityBudget;
+use crate::land_value::LandValueGrid;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::time_of_day::GameClock;
+use crate::trees::{PlantedTree, TreeGrid};
+use crate::urban_heat_island::UhiGrid;
+use crate::{SlowTickTimer, TickCounter};
+
+use super::types::*;
+
+/// Canopy cooling runs at the heat-island update interval, right after the
+/// grid is recomputed.
+const CANOPY_COOLING_INTERVAL: u64 = 30;
+
+/// Once a day, grow every tree, pay for its pruning and clear away the
+/// trees that died of neglect.
+pub fn update_urban_forestry(
+    clock: Res<GameClock>,
+    mut tree_grid: ResMut<TreeGrid>,
+    mut forestry: ResMut<UrbanForestry>,
+    mut budget: ResMut<CityBudget>,
+    planted: Query<(Entity, &PlantedTree)>,
+    mut commands: Commands,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if clock.day <= forestry.last_update_day {
+        return;
+    }
+    forestry.last_update_day = clock.day;
+
+    forestry.sync(&tree_grid);
+    let dead = forestry.grow_day();
+    budget.treasury -= forestry.daily_cost;
+
+    if dead.is_empty() {
+        return;
+    }
+    for &(x, y) in &dead {
+        tree_grid.set(x, y, false);
+    }
+    for (entity, tree) in &planted {
+        if dead.contains(&(tree.grid_x, tree.grid_y)) {
+            commands.entity(entity).despawn();
+        }
+    }
+    notifications.send(NotificationEvent {
+        text: format!(
+            "{} neglected trees died and were cleared; fund forestry to keep trees pruned",
+            dead.len()
+        ),
+        priority: NotificationPriority::Warning,
+        category: NotificationCategory::General,
+        location: None,
+    });
+}
+
+/// Large, shady trees cool the heat island beyond the flat effect of any
+/// tree.
+pub fn apply_canopy_cooling(
+    tick: Res<TickCounter>,
+    forestry: Res<UrbanForestry>,
+    mut uhi: ResMut<UhiGrid>,
+) {
+    if !tick.0.is_multiple_of(CANOPY_COOLING_INTERVAL) {
+        return;
+    }
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let shade = forestry.shade_at(x, y);
+            if shade > 0.0 {
+                let current = uhi.get(x, y);
+                uhi.set(x, y, current - CANOPY_UHI_REDUCTION * shade);
+            }
+        }
+    }
+}
+
+/// Large, shady trees lift the land value around them more than saplings.
+pub fn apply_canopy_land_value(
+    slow_timer: Res<SlowTickTimer>,
+    forestry: Res<UrbanForestry>,
+    mut land_value: ResMut<LandValueGrid>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let boost = (CANOPY_LAND_VALUE_BOOST * forestry.shade_at(x, y)).round() as u8;
+            if boost == 0 {
+                continue;
+            }
+            for ny in y.saturating_sub(1)..=(y + 1).min(GRID_HEIGHT - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(GRID_WIDTH - 1) {
+                    let current = land_value.get(nx, ny);
+                    land_value.set(nx, ny, current.saturating_add(boost));
+                }
+            }
+        }
+    }
+}
+
+pub struct UrbanForestryPlugin;
+
+impl Plugin for UrbanForestryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UrbanForestry>().add_systems(
+            FixedUpdate,
+            (
+                update_urban_forestry,
+                apply_canopy_cooling.after(crate::uhi_mitigation::apply_uhi_mitigation),
+                apply_canopy_land_value.after(crate::tree_absorption::tree_absorption_effects),
+            )
+                .in_set(crate::SimulationSet::Simulation),
+        );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<UrbanForestry>();
+    }
+}
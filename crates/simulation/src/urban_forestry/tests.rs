@@ -0,0 +1,142 @@
+use super::*;
+use crate::time_of_day::DAYS_PER_YEAR;
+use crate::trees::TreeGrid;
+use crate::Saveable;
+
+fn aged(species: TreeSpecies, years: f32) -> UrbanTree {
+    UrbanTree {
+        age_days: (years * DAYS_PER_YEAR as f32) as u32,
+        ..UrbanTree::sapling(species)
+    }
+}
+
+#[test]
+fn test_trees_grow_over_years() {
+    let sapling = UrbanTree::sapling(TreeSpecies::Plane);
+    assert_eq!(sapling.size(), SAPLING_SIZE);
+    let young = aged(TreeSpecies::Plane, 4.0);
+    assert!(young.size() > SAPLING_SIZE && young.size() < 1.0);
+    assert!((aged(TreeSpecies::Plane, 8.0).size() - 1.0).abs() < 1e-6);
+    assert_eq!(aged(TreeSpecies::Plane, 50.0).size(), 1.0);
+}
+
+#[test]
+fn test_species_grow_at_their_own_pace() {
+    let palm = aged(TreeSpecies::Palm, 5.0);
+    let oak = aged(TreeSpecies::Oak, 5.0);
+    assert!((palm.size() - 1.0).abs() < 1e-6);
+    assert!(oak.size() < 0.5);
+}
+
+#[test]
+fn test_mature_trees_shade_more_than_saplings() {
+    let sapling = UrbanTree::sapling(TreeSpecies::Plane);
+    let mature = aged(TreeSpecies::Plane, 10.0);
+    assert!(mature.shade() > sapling.shade() * 5.0);
+    assert!(aged(TreeSpecies::Palm, 10.0).shade() < mature.shade());
+}
+
+#[test]
+fn test_species_traits() {
+    for species in TreeSpecies::ALL {
+        assert!(species.years_to_mature() > 0.0);
+        assert!((0.0..=1.0).contains(&species.shade()));
+        assert!(species.water_gpd() > 0.0);
+    }
+    assert!(TreeSpecies::Pine.fire_susceptibility() > TreeSpecies::Oak.fire_susceptibility());
+    assert!(TreeSpecies::Palm.water_gpd() < TreeSpecies::Oak.water_gpd());
+}
+
+#[test]
+fn test_unfunded_trees_decline_and_die() {
+    let mut tree = aged(TreeSpecies::Plane, 10.0);
+    assert!(tree.grow_day(1.0));
+    assert_eq!(tree.health, 1.0);
+
+    let mut days = 0;
+    while tree.grow_day(0.0) {
+        days += 1;
+        assert!(days < 1_000, "an unfunded tree should eventually die");
+    }
+    assert_eq!(tree.health, 0.0);
+
+    // Saplings suffer less from neglect than full-size trees.
+    let mut sapling = UrbanTree::sapling(TreeSpecies::Plane);
+    let mut mature = aged(TreeSpecies::Plane, 10.0);
+    sapling.grow_day(0.5);
+    mature.grow_day(0.5);
+    assert!(sapling.health > mature.health);
+}
+
+#[test]
+fn test_sync_follows_tree_grid() {
+    let mut forestry = UrbanForestry {
+        planting_species: TreeSpecies::Pine,
+        ..Default::default()
+    };
+    let mut grid = TreeGrid::default();
+    grid.set(3, 4, true);
+    forestry.sync(&grid);
+    assert_eq!(
+        forestry.get(3, 4).map(|t| t.species),
+        Some(TreeSpecies::Pine)
+    );
+
+    // Recorded trees keep their species and age.
+    forestry.planting_species = TreeSpecies::Oak;
+    forestry.trees[4 * crate::config::GRID_WIDTH + 3] = Some(aged(TreeSpecies::Pine, 2.0));
+    forestry.sync(&grid);
+    assert_eq!(forestry.get(3, 4), Some(&aged(TreeSpecies::Pine, 2.0)));
+
+    grid.set(3, 4, false);
+    forestry.sync(&grid);
+    assert!(forestry.get(3, 4).is_none());
+}
+
+#[test]
+fn test_grow_day_tallies_cost_water_and_losses() {
+    let mut forestry = UrbanForestry::default();
+    forestry.planting_species = TreeSpecies::Oak;
+    forestry.plant(1, 1);
+    forestry.trees[2] = Some(aged(TreeSpecies::Plane, 20.0));
+
+    let dead = forestry.grow_day();
+    assert!(dead.is_empty());
+    assert_eq!(forestry.tree_count, 2);
+    assert_eq!(forestry.mature_count, 1);
+    assert!(forestry.daily_cost > UPKEEP_PER_TREE_DAY);
+    assert!(forestry.water_use_mgd > 0.0);
+    assert!((forestry.full_upkeep() - forestry.daily_cost).abs() < 1e-9);
+
+    forestry.trees[2].as_mut().unwrap().health = 0.001;
+    forestry.funding = 0.0;
+    let dead = forestry.grow_day();
+    assert_eq!(dead, vec![(2, 0)]);
+    assert_eq!(forestry.trees_lost, 1);
+    assert_eq!(forestry.daily_cost, 0.0);
+    assert!(forestry.full_upkeep() > 0.0);
+}
+
+#[test]
+fn test_untracked_cells_are_neutral() {
+    let forestry = UrbanForestry::default();
+    assert_eq!(forestry.shade_at(10, 10), 0.0);
+    assert_eq!(forestry.fire_susceptibility_at(10, 10), 1.0);
+    assert_eq!(forestry.average_health(), 1.0);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(UrbanForestry::default().save_to_bytes().is_none());
+    let mut forestry = UrbanForestry {
+        planting_species: TreeSpecies::Palm,
+        funding: 0.6,
+        ..Default::default()
+    };
+    forestry.plant(5, 6);
+    forestry.grow_day();
+    let bytes = forestry
+        .save_to_bytes()
+        .expect("non-default forestry saves");
+    assert_eq!(UrbanForestry::load_from_bytes(&bytes), forestry);
+}
@@ -0,0 +1,325 @@
+//! Tree species, individual street trees and the forestry budget.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::time_of_day::DAYS_PER_YEAR;
+use crate::trees::TreeGrid;
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Size of a newly planted sapling, as a fraction of full size.
+pub const SAPLING_SIZE: f32 = 0.1;
+
+/// Daily pruning and care cost of a full-size tree at full funding.
+pub const UPKEEP_PER_TREE_DAY: f64 = 0.5;
+
+/// Health a full-size tree loses per day when forestry is not funded at all.
+pub const NEGLECT_DECLINE_PER_DAY: f32 = 0.01;
+
+/// Health a fully funded tree recovers per day.
+pub const CARE_RECOVERY_PER_DAY: f32 = 0.02;
+
+/// Funding level a new city starts with (1.0 = all upkeep paid).
+pub const DEFAULT_FORESTRY_FUNDING: f32 = 1.0;
+
+/// Extra heat-island cooling (F) under a full-size, full-shade canopy.
+pub const CANOPY_UHI_REDUCTION: f32 = 2.0;
+
+/// Extra land value around a full-size, full-shade tree.
+pub const CANOPY_LAND_VALUE_BOOST: f32 = 6.0;
+
+/// Trees at or above this size count as mature.
+pub const MATURE_SIZE: f32 = 0.8;
+
+// ---------------------------------------------------------------------------
+// Species
+// ---------------------------------------------------------------------------
+
+/// Species of a street tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum TreeSpecies {
+    /// Broad shade at a middling thirst.
+    #[default]
+    Plane,
+    /// Slow growing and thirsty, but long-lived and fire resistant.
+    Oak,
+    /// Quick and drought tolerant, but its resin burns readily.
+    Pine,
+    /// Little shade, little water, quick to establish.
+    Palm,
+}
+
+impl TreeSpecies {
+    pub const ALL: [TreeSpecies; 4] = [
+        TreeSpecies::Plane,
+        TreeSpecies::Oak,
+        TreeSpecies::Pine,
+        TreeSpecies::Palm,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TreeSpecies::Plane => "Plane",
+            TreeSpecies::Oak => "Oak",
+            TreeSpecies::Pine => "Pine",
+            TreeSpecies::Palm => "Palm",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            TreeSpecies::Plane => "Broad shade, moderate water use",
+            TreeSpecies::Oak => "Slow growing and thirsty, resists fire",
+            TreeSpecies::Pine => "Drought tolerant, but burns readily",
+            TreeSpecies::Palm => "Little shade, little water, grows fast",
+        }
+    }
+
+    /// Years from sapling to full size.
+    pub fn years_to_mature(self) -> f32 {
+        match self {
+            TreeSpecies::Plane => 8.0,
+            TreeSpecies::Oak => 15.0,
+            TreeSpecies::Pine => 10.0,
+            TreeSpecies::Palm => 5.0,
+        }
+    }
+
+    /// Shade cast at full size (0.0 to 1.0).
+    pub fn shade(self) -> f32 {
+        match self {
+            TreeSpecies::Plane => 1.0,
+            TreeSpecies::Oak => 0.9,
+            TreeSpecies::Pine => 0.6,
+            TreeSpecies::Palm => 0.3,
+        }
+    }
+
+    /// Water drawn at full size, in gallons per day.
+    pub fn water_gpd(self) -> f32 {
+        match self {
+            TreeSpecies::Plane => 100.0,
+            TreeSpecies::Oak => 150.0,
+            TreeSpecies::Pine => 40.0,
+            TreeSpecies::Palm => 30.0,
+        }
+    }
+
+    /// How readily the tree catches and spreads fire (1.0 = typical).
+    pub fn fire_susceptibility(self) -> f32 {
+        match self {
+            TreeSpecies::Plane => 1.0,
+            TreeSpecies::Oak => 0.6,
+            TreeSpecies::Pine => 2.0,
+            TreeSpecies::Palm => 0.8,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Trees
+// ---------------------------------------------------------------------------
+
+/// One street tree.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct UrbanTree {
+    pub species: TreeSpecies,
+    pub age_days: u32,
+    /// 0.0 (dead) to 1.0 (healthy).
+    pub health: f32,
+}
+
+impl UrbanTree {
+    pub fn sapling(species: TreeSpecies) -> Self {
+        Self {
+            species,
+            age_days: 0,
+            health: 1.0,
+        }
+    }
+
+    /// Size as a fraction of full size, from `SAPLING_SIZE` up to 1.0.
+    pub fn size(&self) -> f32 {
+        let years = self.age_days as f32 / DAYS_PER_YEAR as f32;
+        (SAPLING_SIZE + (1.0 - SAPLING_SIZE) * years / self.species.years_to_mature()).min(1.0)
+    }
+
+    /// Shade cast by the tree at its current size.
+    pub fn shade(&self) -> f32 {
+        self.species.shade() * self.size()
+    }
+
+    /// One day of growth and care at the given funding level. Returns false
+    /// when the tree has died.
+    pub fn grow_day(&mut self, funding: f32) -> bool {
+        self.age_days += 1;
+        let change = if funding >= 1.0 {
+            CARE_RECOVERY_PER_DAY
+        } else {
+            -(1.0 - funding.max(0.0)) * NEGLECT_DECLINE_PER_DAY * self.size()
+        };
+        self.health = (self.health + change).clamp(0.0, 1.0);
+        self.health > 0.0
+    }
+}
+
+/// Every street tree in the city and how the forestry service is run.
+#[derive(Resource, Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct UrbanForestry {
+    /// Tree on each grid cell, row-major.
+    pub trees: Vec<Option<UrbanTree>>,
+    /// Species the tree tool plants.
+    pub planting_species: TreeSpecies,
+    /// Fraction of the pruning upkeep paid (0.0 to 1.0).
+    pub funding: f32,
+    /// Upkeep paid yesterday.
+    pub daily_cost: f64,
+    /// Water the trees draw, in million gallons per day.
+    pub water_use_mgd: f32,
+    pub tree_count: u32,
+    pub mature_count: u32,
+    /// Trees that died of neglect, all time.
+    pub trees_lost: u32,
+    pub last_update_day: u32,
+}
+
+impl Default for UrbanForestry {
+    fn default() -> Self {
+        Self {
+            trees: vec![None; GRID_WIDTH * GRID_HEIGHT],
+            planting_species: TreeSpecies::default(),
+            funding: DEFAULT_FORESTRY_FUNDING,
+            daily_cost: 0.0,
+            water_use_mgd: 0.0,
+            tree_count: 0,
+            mature_count: 0,
+            trees_lost: 0,
+            last_update_day: 0,
+        }
+    }
+}
+
+impl UrbanForestry {
+    pub fn get(&self, x: usize, y: usize) -> Option<&UrbanTree> {
+        if x < GRID_WIDTH && y < GRID_HEIGHT {
+            self.trees[y * GRID_WIDTH + x].as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Record a sapling of the current planting species at (x, y).
+    pub fn plant(&mut self, x: usize, y: usize) {
+        if x < GRID_WIDTH && y < GRID_HEIGHT {
+            self.trees[y * GRID_WIDTH + x] = Some(UrbanTree::sapling(self.planting_species));
+        }
+    }
+
+    /// Bring the records in line with the tree grid: trees that appeared
+    /// without being recorded become saplings, trees that are gone (cut
+    /// down or burnt) are forgotten.
+    pub fn sync(&mut self, tree_grid: &TreeGrid) {
+        let species = self.planting_species;
+        for (idx, tree) in self.trees.iter_mut().enumerate() {
+            let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+            match (tree_grid.has_tree(x, y), tree.is_some()) {
+                (true, false) => *tree = Some(UrbanTree::sapling(species)),
+                (false, true) => *tree = None,
+                _ => {}
+            }
+        }
+    }
+
+    /// One day of growth and care for every tree. Returns the cells whose
+    /// trees died, and refreshes the upkeep, water and count statistics.
+    pub fn grow_day(&mut self) -> Vec<(usize, usize)> {
+        let funding = self.funding;
+        let mut dead = Vec::new();
+        let mut total_size = 0.0f32;
+        let mut water_gpd = 0.0f32;
+        let mut tree_count = 0;
+        let mut mature_count = 0;
+        for (idx, cell) in self.trees.iter_mut().enumerate() {
+            let Some(tree) = cell else {
+                continue;
+            };
+            if !tree.grow_day(funding) {
+                dead.push((idx % GRID_WIDTH, idx / GRID_WIDTH));
+                *cell = None;
+                continue;
+            }
+            let size = tree.size();
+            total_size += size;
+            water_gpd += tree.species.water_gpd() * size;
+            tree_count += 1;
+            if size >= MATURE_SIZE {
+                mature_count += 1;
+            }
+        }
+        self.daily_cost = total_size as f64 * UPKEEP_PER_TREE_DAY * funding.max(0.0) as f64;
+        self.water_use_mgd = water_gpd / 1_000_000.0;
+        self.tree_count = tree_count;
+        self.mature_count = mature_count;
+        self.trees_lost += dead.len() as u32;
+        dead
+    }
+
+    /// Daily upkeep if the forestry service were fully funded.
+    pub fn full_upkeep(&self) -> f64 {
+        if self.funding > 0.0 {
+            self.daily_cost / self.funding as f64
+        } else {
+            let total_size: f32 = self.trees.iter().flatten().map(UrbanTree::size).sum();
+            total_size as f64 * UPKEEP_PER_TREE_DAY
+        }
+    }
+
+    /// Canopy shade over (x, y); 0.0 where no tree is recorded.
+    pub fn shade_at(&self, x: usize, y: usize) -> f32 {
+        self.get(x, y).map_or(0.0, UrbanTree::shade)
+    }
+
+    /// Fire susceptibility of the tree at (x, y); 1.0 where no tree is
+    /// recorded.
+    pub fn fire_susceptibility_at(&self, x: usize, y: usize) -> f32 {
+        self.get(x, y)
+            .map_or(1.0, |tree| tree.species.fire_susceptibility())
+    }
+
+    /// Average health of the city's trees; 1.0 with no trees.
+    pub fn average_health(&self) -> f32 {
+        let (sum, count) = self
+            .trees
+            .iter()
+            .flatten()
+            .fold((0.0f32, 0u32), |(sum, count), tree| {
+                (sum + tree.health, count + 1)
+            });
+        if count == 0 {
+            1.0
+        } else {
+            sum / count as f32
+        }
+    }
+}
+
+impl Saveable for UrbanForestry {
+    const SAVE_KEY: &'static str = "urban_forestry";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
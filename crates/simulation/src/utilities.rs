@@ -24,6 +24,22 @@ pub enum UtilityType {
 }
 
 impl UtilityType {
+    /// Every utility type, in declaration order.
+    pub const ALL: [UtilityType; 12] = [
+        UtilityType::PowerPlant,
+        UtilityType::SolarFarm,
+        UtilityType::WindTurbine,
+        UtilityType::WaterTower,
+        UtilityType::SewagePlant,
+        UtilityType::NuclearPlant,
+        UtilityType::Geothermal,
+        UtilityType::PumpingStation,
+        UtilityType::WaterTreatment,
+        UtilityType::HydroDam,
+        UtilityType::OilPlant,
+        UtilityType::GasPlant,
+    ];
+
     pub fn is_power(self) -> bool {
         matches!(
             self,
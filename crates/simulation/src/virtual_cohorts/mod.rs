@@ -0,0 +1,61 @@
+//! Statistical tier for citizens beyond the real-citizen cap.
+//!
+//! `VirtualPopulation::max_real_citizens` bounds how many citizens exist as
+//! ECS entities. Citizens past it are not dropped: they live on as counts in
+//! per-district cohorts (`VirtualPopulation::district_stats` plus
+//! `VirtualCohorts`) and keep taking part in the city statistically:
+//!
+//! - **Employment** follows the real residents of the same district: each
+//!   slow tick a cohort closes part of the gap to their employment rate,
+//!   hiring into the district with the most vacant jobs.
+//! - **Commute load**: during commute hours a share of each cohort's workers
+//!   is added to `TrafficGrid` on the road cells of their home and work
+//!   districts, after real commuters have been counted.
+//! - **Consumption**: households spend `HOUSEHOLD_SPENDING_SHARE` of payroll,
+//!   the same share `salary_payment` takes from real workers.
+//!
+//! When the cap moves, `rebalance_population_tiers` folds off-screen
+//! citizens into their cohort or turns cohort members back into entities.
+//! Both directions carry the citizen's home district, job and demographics,
+//! so population, employment and tax totals read the same before and after.
+
+mod systems;
+#[cfg(test)]
+mod tests;
+mod types;
+
+pub use systems::{
+    apply_cohort_commute_load, rebalance_population_tiers, simulate_cohorts,
+    COMMUTERS_ON_ROAD_SHARE, EMPLOYMENT_CONVERGENCE, MAX_TIER_TRANSFERS, PROMOTION_HEADROOM,
+    REBALANCE_INTERVAL,
+};
+pub use types::{CohortJob, DistrictCohort, VirtualCohorts, HOUSEHOLD_SPENDING_SHARE};
+
+use bevy::prelude::*;
+
+pub struct VirtualCohortsPlugin;
+
+impl Plugin for VirtualCohortsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VirtualCohorts>()
+            .add_systems(
+                FixedUpdate,
+                rebalance_population_tiers
+                    .after(crate::citizen_spawner::spawn_citizens)
+                    .in_set(crate::SimulationSet::PreSim),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    simulate_cohorts.after(crate::districts::aggregate_districts),
+                    apply_cohort_commute_load.after(crate::traffic::update_traffic_density),
+                )
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<VirtualCohorts>();
+    }
+}
@@ -0,0 +1,373 @@
+//! Tier transfers and the statistical simulation of virtual cohorts.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::budget::ExtendedBudget;
+use crate::buildings::{Building, UnderConstruction};
+use crate::citizen::{
+    Citizen, CitizenDetails, CitizenState, CitizenStateComp, Family, Gender, HomeLocation, Needs,
+    PathCache, Personality, Position, Velocity, WorkLocation,
+};
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::districts::{Districts, DISTRICTS_X, DISTRICTS_Y, DISTRICT_SIZE};
+use crate::grid::{CellType, WorldGrid};
+use crate::lod::LodTier;
+use crate::mode_choice::ChosenTransportMode;
+use crate::movement::ActivityTimer;
+use crate::sim_rng::SimRng;
+use crate::time_of_day::GameClock;
+use crate::traffic::TrafficGrid;
+use crate::virtual_population::{VirtualCitizen, VirtualPopulation};
+use crate::{SlowTickTimer, TickCounter};
+
+use super::types::{CohortJob, VirtualCohorts};
+
+/// Ticks between tier rebalances.
+pub const REBALANCE_INTERVAL: u64 = 50;
+/// Citizens moved between tiers per rebalance, in either direction.
+pub const MAX_TIER_TRANSFERS: u32 = 500;
+/// Promotion waits until the cap leaves this much room above the real
+/// population, so a cap hovering around it does not bounce citizens between
+/// tiers.
+pub const PROMOTION_HEADROOM: u32 = 1_000;
+/// Share of the gap between a cohort's employment rate and that of the real
+/// residents of its district closed each slow tick.
+pub const EMPLOYMENT_CONVERGENCE: f32 = 0.25;
+/// Share of a cohort's workers on the road during a commute hour.
+pub const COMMUTERS_ON_ROAD_SHARE: f32 = 0.25;
+
+const DISTRICT_COUNT: usize = DISTRICTS_X * DISTRICTS_Y;
+/// Education level assumed for promoted workers whose salary is unknown.
+const DEFAULT_EDUCATION: u8 = 1;
+
+// ---------------------------------------------------------------------------
+// Tier transfers
+// ---------------------------------------------------------------------------
+
+/// Keep the real population at `max_real_citizens`: when it is over the cap,
+/// off-screen citizens are folded into their district's cohort and their
+/// entities despawned; when the cap leaves room, cohort members are turned
+/// back into entities in their district. Building occupancy already counts
+/// virtual residents and workers, so it is not touched, and population and
+/// employment totals are the same on both sides of a transfer.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn rebalance_population_tiers(
+    tick: Res<TickCounter>,
+    mut commands: Commands,
+    mut population: ResMut<VirtualPopulation>,
+    mut cohorts: ResMut<VirtualCohorts>,
+    budget: Res<ExtendedBudget>,
+    mut rng: ResMut<SimRng>,
+    citizens: Query<
+        (
+            Entity,
+            &LodTier,
+            &CitizenStateComp,
+            &CitizenDetails,
+            &HomeLocation,
+            Option<&WorkLocation>,
+        ),
+        With<Citizen>,
+    >,
+    buildings: Query<(Entity, &Building), Without<UnderConstruction>>,
+) {
+    if !tick.0.is_multiple_of(REBALANCE_INTERVAL) {
+        return;
+    }
+    let real = citizens.iter().count() as u32;
+    let cap = population.max_real_citizens;
+    let tax_rate = budget.zone_taxes.residential;
+
+    if real > cap {
+        let excess = (real - cap).min(MAX_TIER_TRANSFERS) as usize;
+        // Only citizens nobody is looking at, and not mid-trip.
+        let demoted = citizens
+            .iter()
+            .filter(|(_, tier, state, ..)| **tier == LodTier::Abstract && !state.0.is_commuting())
+            .take(excess);
+        for (entity, _, _, details, home, work) in demoted {
+            let citizen = VirtualCitizen {
+                age: details.age,
+                employed: work.is_some(),
+                happiness: details.happiness,
+            };
+            let job = work.map(|w| CohortJob {
+                district: VirtualPopulation::district_index(w.grid_x, w.grid_y),
+                salary: details.salary,
+            });
+            let home_district = VirtualPopulation::district_index(home.grid_x, home.grid_y);
+            cohorts.absorb(&mut population, home_district, citizen, job, tax_rate);
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if population.total_virtual == 0 || real + PROMOTION_HEADROOM >= cap {
+        return;
+    }
+    let mut room = (cap - PROMOTION_HEADROOM - real).min(MAX_TIER_TRANSFERS);
+
+    let mut homes: HashMap<usize, Vec<(Entity, usize, usize)>> = HashMap::new();
+    let mut workplaces: HashMap<usize, Vec<(Entity, usize, usize)>> = HashMap::new();
+    for (entity, b) in &buildings {
+        if b.occupants == 0 {
+            continue;
+        }
+        let district = VirtualPopulation::district_index(b.grid_x, b.grid_y);
+        if b.zone_type.is_residential() || b.zone_type.is_mixed_use() {
+            homes
+                .entry(district)
+                .or_default()
+                .push((entity, b.grid_x, b.grid_y));
+        }
+        if b.zone_type.is_job_zone() {
+            workplaces
+                .entry(district)
+                .or_default()
+                .push((entity, b.grid_x, b.grid_y));
+        }
+    }
+
+    // Largest cohorts first.
+    let mut order: Vec<usize> = (0..population.district_stats.len())
+        .filter(|&d| population.district_stats[d].population > 0 && homes.contains_key(&d))
+        .collect();
+    order.sort_by_key(|&d| std::cmp::Reverse(population.district_stats[d].population));
+
+    let fallback_salary = CitizenDetails::base_salary_for_education(DEFAULT_EDUCATION);
+    for district in order {
+        let district_homes = &homes[&district];
+        let mut next_home = 0;
+        while room > 0 {
+            let Some((citizen, job)) = cohorts.release(&mut population, district, fallback_salary)
+            else {
+                break;
+            };
+            let home = district_homes[next_home % district_homes.len()];
+            next_home += 1;
+            let work = job.and_then(|job| {
+                let candidates = workplaces
+                    .get(&job.district)
+                    .or_else(|| workplaces.get(&district))?;
+                let w = candidates[rng.0.gen_range(0..candidates.len())];
+                Some((w, job.salary))
+            });
+            spawn_promoted_citizen(&mut commands, &mut rng, citizen, home, work);
+            room -= 1;
+        }
+        if room == 0 {
+            break;
+        }
+    }
+}
+
+/// Spawn an entity for a citizen leaving the statistical tier.
+fn spawn_promoted_citizen(
+    commands: &mut Commands,
+    rng: &mut SimRng,
+    citizen: VirtualCitizen,
+    (home_entity, home_gx, home_gy): (Entity, usize, usize),
+    work: Option<((Entity, usize, usize), f32)>,
+) {
+    let salary = work.map_or(0.0, |(_, salary)| salary);
+    // Highest education level whose base salary the job pays.
+    let education = (0u8..=3)
+        .rev()
+        .find(|&e| CitizenDetails::base_salary_for_education(e) <= salary)
+        .unwrap_or(0);
+    let gender = if rng.0.gen::<bool>() {
+        Gender::Male
+    } else {
+        Gender::Female
+    };
+    let (x, y) = WorldGrid::grid_to_world(home_gx, home_gy);
+
+    let mut entity = commands.spawn((
+        Citizen,
+        Position { x, y },
+        Velocity { x: 0.0, y: 0.0 },
+        HomeLocation {
+            grid_x: home_gx,
+            grid_y: home_gy,
+            building: home_entity,
+        },
+        CitizenStateComp(CitizenState::AtHome),
+        PathCache::new(Vec::new()),
+        CitizenDetails {
+            age: citizen.age,
+            gender,
+            education,
+            happiness: citizen.happiness,
+            health: 85.0 + rng.0.gen_range(0.0..15.0),
+            salary,
+            savings: salary.max(500.0) * rng.0.gen_range(0.5..3.0),
+        },
+        Personality::random(&mut rng.0),
+        Needs::default(),
+        Family::default(),
+        ActivityTimer::default(),
+        LodTier::Abstract,
+        ChosenTransportMode::default(),
+    ));
+    if let Some(((work_entity, work_gx, work_gy), _)) = work {
+        entity.insert(WorkLocation {
+            grid_x: work_gx,
+            grid_y: work_gy,
+            building: work_entity,
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Statistical simulation
+// ---------------------------------------------------------------------------
+
+/// Each slow tick, move every cohort's employment rate toward that of the
+/// real citizens living in the same district (the city-wide rate where the
+/// district has no real residents). New virtual workers take jobs in the
+/// district with the most vacancies; lost jobs come off the busiest commute.
+pub fn simulate_cohorts(
+    slow_tick: Res<SlowTickTimer>,
+    mut population: ResMut<VirtualPopulation>,
+    mut cohorts: ResMut<VirtualCohorts>,
+    districts: Res<Districts>,
+    budget: Res<ExtendedBudget>,
+    citizens: Query<(&CitizenDetails, &HomeLocation, Option<&WorkLocation>), With<Citizen>>,
+) {
+    if !slow_tick.should_run() || population.district_stats.is_empty() {
+        return;
+    }
+    let tax_rate = budget.zone_taxes.residential;
+
+    // (working-age residents, employed residents) of the real population
+    let mut real = vec![(0u32, 0u32); DISTRICT_COUNT];
+    for (details, home, work) in &citizens {
+        if !(18..=64).contains(&details.age) {
+            continue;
+        }
+        let d = VirtualPopulation::district_index(home.grid_x, home.grid_y);
+        real[d].0 += 1;
+        real[d].1 += work.is_some() as u32;
+    }
+    let (city_labour, city_employed) = real
+        .iter()
+        .fold((0, 0), |(l, e), &(dl, de)| (l + dl, e + de));
+    let city_rate = if city_labour > 0 {
+        city_employed as f32 / city_labour as f32
+    } else {
+        1.0
+    };
+
+    let mut vacancies: Vec<u32> = districts
+        .data
+        .iter()
+        .map(|d| (d.commercial_jobs + d.industrial_jobs + d.office_jobs).saturating_sub(d.employed))
+        .collect();
+
+    let fallback_salary = CitizenDetails::base_salary_for_education(DEFAULT_EDUCATION);
+    for d in 0..population.district_stats.len() {
+        let stats = &population.district_stats[d];
+        let labour = stats.labour_force();
+        if labour == 0 {
+            continue;
+        }
+        let rate = match real.get(d) {
+            Some(&(l, e)) if l > 0 => e as f32 / l as f32,
+            _ => city_rate,
+        };
+        let gap = (labour as f32 * rate).round() as i64 - stats.employed as i64;
+        if gap == 0 {
+            continue;
+        }
+        // At least one citizen per slow tick, so small gaps close too.
+        let mut step = (gap as f32 * EMPLOYMENT_CONVERGENCE).round() as i64;
+        if step == 0 {
+            step = gap.signum();
+        }
+
+        let cohort = cohorts.district_mut(d);
+        let salary = cohort.average_salary().unwrap_or(fallback_salary);
+        if step > 0 {
+            let mut remaining = step;
+            while remaining > 0 {
+                let Some(dest) = (0..vacancies.len())
+                    .filter(|&v| vacancies[v] > 0)
+                    .max_by_key(|&v| (vacancies[v], std::cmp::Reverse(v)))
+                else {
+                    break;
+                };
+                let n = remaining.min(vacancies[dest] as i64);
+                vacancies[dest] -= n as u32;
+                for _ in 0..n {
+                    cohort.add_worker(dest, salary);
+                }
+                remaining -= n;
+            }
+            population.adjust_employment(d, (step - remaining) as i32, salary * tax_rate);
+        } else {
+            for _ in 0..-step {
+                cohort.remove_worker();
+            }
+            population.adjust_employment(d, step as i32, salary * tax_rate);
+        }
+    }
+}
+
+/// During commute hours, add the load of each cohort's workers to the
+/// traffic grid. Each trip's share of on-road workers is spread over the
+/// road cells of its home and work districts, half at each end.
+pub fn apply_cohort_commute_load(
+    tick: Res<TickCounter>,
+    clock: Res<GameClock>,
+    cohorts: Res<VirtualCohorts>,
+    grid: Res<WorldGrid>,
+    mut traffic: ResMut<TrafficGrid>,
+) {
+    // Same cadence as `update_traffic_density`, which clears the grid.
+    if !tick.0.is_multiple_of(5) || !(clock.is_morning_commute() || clock.is_evening_commute()) {
+        return;
+    }
+    let mut load = vec![0.0f32; DISTRICT_COUNT];
+    for (home, cohort) in cohorts.districts.iter().enumerate() {
+        for &(work, workers) in &cohort.destinations {
+            let on_road = workers as f32 * COMMUTERS_ON_ROAD_SHARE;
+            if let Some(l) = load.get_mut(home) {
+                *l += on_road / 2.0;
+            }
+            if let Some(l) = load.get_mut(work as usize) {
+                *l += on_road / 2.0;
+            }
+        }
+    }
+
+    for (d, &district_load) in load.iter().enumerate() {
+        if district_load < 1.0 {
+            continue;
+        }
+        let (x0, y0) = (
+            (d % DISTRICTS_X) * DISTRICT_SIZE,
+            (d / DISTRICTS_X) * DISTRICT_SIZE,
+        );
+        let roads: Vec<(usize, usize)> = (y0..(y0 + DISTRICT_SIZE).min(GRID_HEIGHT))
+            .flat_map(|y| (x0..(x0 + DISTRICT_SIZE).min(GRID_WIDTH)).map(move |x| (x, y)))
+            .filter(|&(x, y)| grid.get(x, y).cell_type == CellType::Road)
+            .collect();
+        if roads.is_empty() {
+            continue;
+        }
+        let per_cell = district_load / roads.len() as f32;
+        // Whole vehicles per cell, with the remainder on the first cells.
+        let whole = per_cell.floor() as u16;
+        let extra = ((per_cell - per_cell.floor()) * roads.len() as f32).round() as usize;
+        for (i, &(x, y)) in roads.iter().enumerate() {
+            let add = whole + (i < extra) as u16;
+            if add > 0 {
+                let current = traffic.get(x, y);
+                traffic.set(x, y, current.saturating_add(add));
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::virtual_population::{VirtualCitizen, VirtualPopulation};
+use crate::Saveable;
+
+fn worker(age: u8) -> VirtualCitizen {
+    VirtualCitizen {
+        age,
+        employed: true,
+        happiness: 60.0,
+    }
+}
+
+#[test]
+fn test_remove_worker_takes_from_busiest_destination() {
+    let mut cohort = DistrictCohort::default();
+    cohort.add_worker(3, 1000.0);
+    cohort.add_worker(7, 2000.0);
+    cohort.add_worker(7, 3000.0);
+    assert_eq!(cohort.workers(), 3);
+    assert!((cohort.consumption - 6000.0 * HOUSEHOLD_SPENDING_SHARE).abs() < 0.01);
+
+    let (district, salary) = cohort.remove_worker().unwrap();
+    assert_eq!(district, 7);
+    assert!((salary - 2000.0).abs() < 0.01);
+    assert_eq!(cohort.destinations, vec![(3, 1), (7, 1)]);
+
+    cohort.remove_worker();
+    cohort.remove_worker();
+    assert_eq!(cohort.workers(), 0);
+    assert_eq!(cohort.payroll, 0.0);
+    assert!(cohort.remove_worker().is_none());
+}
+
+#[test]
+fn test_absorb_then_release_restores_population() {
+    let mut population = VirtualPopulation::default();
+    let mut cohorts = VirtualCohorts::default();
+    let job = CohortJob {
+        district: 5,
+        salary: 2500.0,
+    };
+    cohorts.absorb(&mut population, 2, worker(30), Some(job), 0.1);
+    cohorts.absorb(
+        &mut population,
+        2,
+        VirtualCitizen {
+            employed: false,
+            ..worker(70)
+        },
+        None,
+        0.1,
+    );
+    assert_eq!(population.total_virtual, 2);
+    assert_eq!(population.virtual_employed, 1);
+    assert_eq!(cohorts.total_workers(), 1);
+
+    let mut released = Vec::new();
+    while let Some(entry) = cohorts.release(&mut population, 2, 1000.0) {
+        released.push(entry);
+    }
+    assert_eq!(released.len(), 2);
+    assert_eq!(population.total_virtual, 0);
+    assert_eq!(population.virtual_employed, 0);
+    assert_eq!(cohorts.total_workers(), 0);
+    let jobs: Vec<_> = released.iter().filter_map(|(_, job)| *job).collect();
+    assert_eq!(jobs, vec![job]);
+}
+
+#[test]
+fn test_release_without_cohort_uses_home_district() {
+    let mut population = VirtualPopulation::default();
+    population.add_virtual_citizen(4, 40, true, 50.0, 2000.0, 0.1);
+    let mut cohorts = VirtualCohorts::default();
+
+    let (citizen, job) = cohorts.release(&mut population, 4, 1500.0).unwrap();
+    assert!(citizen.employed);
+    assert_eq!(
+        job,
+        Some(CohortJob {
+            district: 4,
+            salary: 1500.0
+        })
+    );
+}
+
+#[test]
+fn test_saveable_skips_empty_cohorts() {
+    let mut cohorts = VirtualCohorts::default();
+    cohorts.district_mut(10);
+    assert!(cohorts.save_to_bytes().is_none());
+
+    cohorts.district_mut(10).add_worker(11, 1800.0);
+    let bytes = cohorts.save_to_bytes().unwrap();
+    let restored = VirtualCohorts::load_from_bytes(&bytes);
+    assert_eq!(restored.districts, cohorts.districts);
+}
@@ -0,0 +1,181 @@
+//! Cohort state of the statistical tier and the transfers that keep it in
+//! step with `VirtualPopulation`.
+
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::virtual_population::{VirtualCitizen, VirtualPopulation};
+
+/// Share of salary households spend each month, as in `salary_payment`.
+pub const HOUSEHOLD_SPENDING_SHARE: f32 = 0.7;
+
+/// What the statistical tier tracks about one district's virtual residents
+/// beyond `DistrictStats`: where the employed ones work, what they earn and
+/// what they spend.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct DistrictCohort {
+    /// `(work district, workers)` for the employed residents.
+    pub destinations: Vec<(u32, u32)>,
+    /// Monthly salaries of the employed residents.
+    pub payroll: f32,
+    /// Monthly household spending of the residents.
+    pub consumption: f32,
+}
+
+impl DistrictCohort {
+    pub fn workers(&self) -> u32 {
+        self.destinations.iter().map(|&(_, n)| n).sum()
+    }
+
+    /// Mean salary of the employed residents.
+    pub fn average_salary(&self) -> Option<f32> {
+        let workers = self.workers();
+        (workers > 0).then(|| self.payroll / workers as f32)
+    }
+
+    pub fn add_worker(&mut self, work_district: usize, salary: f32) {
+        let work_district = work_district as u32;
+        match self
+            .destinations
+            .iter_mut()
+            .find(|(d, _)| *d == work_district)
+        {
+            Some((_, n)) => *n += 1,
+            None => self.destinations.push((work_district, 1)),
+        }
+        self.payroll += salary;
+        self.consumption = self.payroll * HOUSEHOLD_SPENDING_SHARE;
+    }
+
+    /// Take a worker off the busiest destination, returning its work
+    /// district and an average salary.
+    pub fn remove_worker(&mut self) -> Option<(usize, f32)> {
+        let salary = self.average_salary()?;
+        let i =
+            (0..self.destinations.len()).max_by_key(|&i| (self.destinations[i].1, Reverse(i)))?;
+        let district = self.destinations[i].0 as usize;
+        self.destinations[i].1 -= 1;
+        if self.destinations[i].1 == 0 {
+            self.destinations.remove(i);
+        }
+        self.payroll = if self.destinations.is_empty() {
+            0.0
+        } else {
+            (self.payroll - salary).max(0.0)
+        };
+        self.consumption = self.payroll * HOUSEHOLD_SPENDING_SHARE;
+        Some((district, salary))
+    }
+}
+
+/// A citizen's job as seen by the statistical tier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CohortJob {
+    /// Statistical district of the workplace.
+    pub district: usize,
+    pub salary: f32,
+}
+
+/// Per-district cohorts of virtual citizens.
+///
+/// Demographics (population, age, happiness, employment counts, tax) live in
+/// `VirtualPopulation::district_stats`; this resource adds the commute
+/// destinations, payroll and consumption the statistical simulation needs.
+/// Citizens enter and leave the tier through `absorb` and `release` so the
+/// two stay in step.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, Encode, Decode)]
+pub struct VirtualCohorts {
+    /// Indexed like `VirtualPopulation::district_stats`.
+    pub districts: Vec<DistrictCohort>,
+}
+
+impl VirtualCohorts {
+    pub fn district(&self, district_idx: usize) -> Option<&DistrictCohort> {
+        self.districts.get(district_idx)
+    }
+
+    pub fn district_mut(&mut self, district_idx: usize) -> &mut DistrictCohort {
+        if district_idx >= self.districts.len() {
+            self.districts
+                .resize_with(district_idx + 1, DistrictCohort::default);
+        }
+        &mut self.districts[district_idx]
+    }
+
+    pub fn total_workers(&self) -> u32 {
+        self.districts.iter().map(DistrictCohort::workers).sum()
+    }
+
+    /// Monthly household spending of all virtual citizens.
+    pub fn total_consumption(&self) -> f32 {
+        self.districts.iter().map(|c| c.consumption).sum()
+    }
+
+    /// Move a citizen living in `home_district` into the statistical tier.
+    pub fn absorb(
+        &mut self,
+        population: &mut VirtualPopulation,
+        home_district: usize,
+        citizen: VirtualCitizen,
+        job: Option<CohortJob>,
+        tax_rate: f32,
+    ) {
+        let salary = job.map_or(0.0, |j| j.salary);
+        population.add_virtual_citizen(
+            home_district,
+            citizen.age,
+            job.is_some(),
+            citizen.happiness,
+            salary,
+            tax_rate,
+        );
+        if let Some(job) = job {
+            self.district_mut(home_district)
+                .add_worker(job.district, job.salary);
+        }
+    }
+
+    /// Take one citizen out of the statistical tier in `home_district`,
+    /// with its job if it is employed. Employed citizens recorded without a
+    /// cohort (saves from before cohorts existed) work in their home
+    /// district at `fallback_salary`.
+    pub fn release(
+        &mut self,
+        population: &mut VirtualPopulation,
+        home_district: usize,
+        fallback_salary: f32,
+    ) -> Option<(VirtualCitizen, Option<CohortJob>)> {
+        let citizen = population.remove_virtual_citizen(home_district)?;
+        if !citizen.employed {
+            return Some((citizen, None));
+        }
+        let (district, salary) = self
+            .districts
+            .get_mut(home_district)
+            .and_then(DistrictCohort::remove_worker)
+            .unwrap_or((home_district, fallback_salary));
+        Some((citizen, Some(CohortJob { district, salary })))
+    }
+}
+
+impl crate::Saveable for VirtualCohorts {
+    const SAVE_KEY: &'static str = "virtual_cohorts";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self
+            .districts
+            .iter()
+            .all(|c| *c == DistrictCohort::default())
+        {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
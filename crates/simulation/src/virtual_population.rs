@@ -2,6 +2,8 @@ use bevy::prelude::*;
 use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+use crate::districts::{Districts, DISTRICTS_X, DISTRICTS_Y};
+
 /// Hard ceiling on real (ECS) citizens — never exceed this regardless of FPS.
 pub const MAX_REAL_CITIZENS_HARD: u32 = 200_000;
 /// Minimum real citizens — always keep at least this many.
@@ -9,7 +11,8 @@ pub const MIN_REAL_CITIZENS: u32 = 10_000;
 /// Default cap that adjusts dynamically.
 pub const DEFAULT_REAL_CITIZEN_CAP: u32 = 50_000;
 
-/// Per-district virtual population statistics.
+/// Per-district virtual population statistics. Districts are the 16×16-cell
+/// statistical districts of `Districts`, indexed `dy * DISTRICTS_X + dx`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Encode, Decode)]
 pub struct DistrictStats {
     pub population: u32,
@@ -26,6 +29,21 @@ pub struct DistrictStats {
     pub service_demand: f32,
 }
 
+impl DistrictStats {
+    /// Residents of working age (18-64).
+    pub fn labour_force(&self) -> u32 {
+        self.age_brackets[1] + self.age_brackets[2] + self.age_brackets[3]
+    }
+}
+
+/// A virtual citizen taken back out of the district statistics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualCitizen {
+    pub age: u8,
+    pub employed: bool,
+    pub happiness: f32,
+}
+
 #[derive(Resource, Debug, Clone, Serialize, Deserialize, Encode, Decode)]
 pub struct VirtualPopulation {
     pub total_virtual: u32,
@@ -72,6 +90,13 @@ impl VirtualPopulation {
         real_count + self.total_virtual
     }
 
+    /// Index into `district_stats` of the statistical district containing a
+    /// grid cell.
+    pub fn district_index(gx: usize, gy: usize) -> usize {
+        let (dx, dy) = Districts::district_for_grid(gx, gy);
+        dy.min(DISTRICTS_Y - 1) * DISTRICTS_X + dx.min(DISTRICTS_X - 1)
+    }
+
     /// Absorb a new virtual citizen into district statistics.
     pub fn add_virtual_citizen(
         &mut self,
@@ -119,6 +144,89 @@ impl VirtualPopulation {
         ds.service_demand = (ds.population as f32 / 5000.0).min(1.0);
     }
 
+    /// Take one virtual citizen out of a district's statistics, e.g. to turn
+    /// it back into an ECS entity. The citizen is employed when that keeps
+    /// the district's employment rate closest to what it was, and is drawn
+    /// from the fullest age bracket it could belong to, at the district's
+    /// average happiness.
+    pub fn remove_virtual_citizen(&mut self, district_idx: usize) -> Option<VirtualCitizen> {
+        let ds = self
+            .district_stats
+            .get_mut(district_idx)
+            .filter(|ds| ds.population > 0)?;
+
+        let employed = ds.employed > 0 && (ds.employed * 2 >= ds.population);
+        // Employed citizens come out of the working-age brackets when the
+        // district has any.
+        let fullest = |range: std::ops::Range<usize>| {
+            range
+                .filter(|&b| ds.age_brackets[b] > 0)
+                .max_by_key(|&b| (ds.age_brackets[b], std::cmp::Reverse(b)))
+        };
+        let bracket = employed
+            .then(|| fullest(1..4))
+            .flatten()
+            .or_else(|| fullest(0..5))
+            .unwrap_or(1);
+        let (lo, hi) = [(0, 17), (18, 34), (35, 54), (55, 64), (65, 90)][bracket];
+        let age = (ds.avg_age.round() as u8).clamp(lo, hi);
+        let happiness = ds.avg_happiness;
+
+        let n = ds.population as f32;
+        ds.population -= 1;
+        ds.avg_age = if ds.population == 0 {
+            0.0
+        } else {
+            ((ds.avg_age * n - age as f32) / (n - 1.0)).max(0.0)
+        };
+        if ds.population == 0 {
+            ds.avg_happiness = 0.0;
+        }
+        ds.age_brackets[bracket] = ds.age_brackets[bracket].saturating_sub(1);
+        ds.service_demand = (ds.population as f32 / 5000.0).min(1.0);
+        self.total_virtual = self.total_virtual.saturating_sub(1);
+        if employed {
+            let share = ds.tax_contribution / ds.employed as f32;
+            ds.tax_contribution = (ds.tax_contribution - share).max(0.0);
+            ds.employed -= 1;
+            ds.commuters_out = ds.commuters_out.saturating_sub(1);
+            self.virtual_employed = self.virtual_employed.saturating_sub(1);
+        }
+
+        Some(VirtualCitizen {
+            age,
+            employed,
+            happiness,
+        })
+    }
+
+    /// Move a district's virtual residents into work (`delta > 0`) or out of
+    /// it (`delta < 0`), each paying or no longer paying `tax_per_worker`.
+    /// Employment stays within the district's labour force.
+    pub fn adjust_employment(&mut self, district_idx: usize, delta: i32, tax_per_worker: f32) {
+        let Some(ds) = self.district_stats.get_mut(district_idx) else {
+            return;
+        };
+        let before = ds.employed;
+        let after = if delta >= 0 {
+            (before + delta as u32).min(ds.labour_force().max(before))
+        } else {
+            before.saturating_sub(delta.unsigned_abs())
+        };
+        ds.employed = after;
+        if after >= before {
+            let hired = after - before;
+            ds.commuters_out += hired;
+            ds.tax_contribution += hired as f32 * tax_per_worker;
+            self.virtual_employed += hired;
+        } else {
+            let let_go = before - after;
+            ds.commuters_out = ds.commuters_out.saturating_sub(let_go);
+            ds.tax_contribution = (ds.tax_contribution - let_go as f32 * tax_per_worker).max(0.0);
+            self.virtual_employed = self.virtual_employed.saturating_sub(let_go);
+        }
+    }
+
     /// Adjust the real citizen cap based on measured frame time.
     /// Called once per second from the update system.
     pub fn adjust_cap(&mut self, frame_time_secs: f32) {
@@ -240,6 +348,49 @@ mod tests {
         assert_eq!(vp.max_real_citizens, initial);
     }
 
+    #[test]
+    fn test_remove_virtual_citizen_keeps_stats_consistent() {
+        let mut vp = VirtualPopulation::default();
+        vp.add_virtual_citizen(2, 30, true, 60.0, 1000.0, 0.1);
+        vp.add_virtual_citizen(2, 40, true, 80.0, 1000.0, 0.1);
+        vp.add_virtual_citizen(2, 8, false, 70.0, 0.0, 0.0);
+
+        let first = vp.remove_virtual_citizen(2).unwrap();
+        assert!(first.employed);
+        assert!((18..=64).contains(&first.age));
+        assert!((first.happiness - 70.0).abs() < 0.01);
+        let ds = &vp.district_stats[2];
+        assert_eq!(vp.total_virtual, 2);
+        assert_eq!(vp.virtual_employed, 1);
+        assert_eq!((ds.population, ds.employed, ds.commuters_out), (2, 1, 1));
+        assert_eq!(ds.age_brackets.iter().sum::<u32>(), 2);
+        assert!((ds.tax_contribution - 100.0).abs() < 0.01);
+
+        vp.remove_virtual_citizen(2).unwrap();
+        vp.remove_virtual_citizen(2).unwrap();
+        assert_eq!(vp.total_virtual, 0);
+        assert_eq!(vp.virtual_employed, 0);
+        assert!(vp.remove_virtual_citizen(2).is_none());
+        assert!(vp.remove_virtual_citizen(9).is_none());
+    }
+
+    #[test]
+    fn test_adjust_employment_is_bounded_by_labour_force() {
+        let mut vp = VirtualPopulation::default();
+        vp.add_virtual_citizen(0, 30, false, 50.0, 0.0, 0.0);
+        vp.add_virtual_citizen(0, 70, false, 50.0, 0.0, 0.0);
+
+        vp.adjust_employment(0, 5, 200.0);
+        assert_eq!(vp.district_stats[0].employed, 1);
+        assert_eq!(vp.virtual_employed, 1);
+        assert!((vp.district_stats[0].tax_contribution - 200.0).abs() < 0.01);
+
+        vp.adjust_employment(0, -3, 200.0);
+        assert_eq!(vp.district_stats[0].employed, 0);
+        assert_eq!(vp.district_stats[0].commuters_out, 0);
+        assert_eq!(vp.virtual_employed, 0);
+    }
+
     #[test]
     fn test_total_with_real() {
         let mut vp = VirtualPopulation::default();
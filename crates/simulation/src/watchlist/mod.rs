@@ -0,0 +1,19 @@
+//! Watchlist of pinned citizens, buildings and districts.
+//!
+//! The player can pin up to `MAX_WATCHED` entities from their inspection
+//! panels. `Watchlist` holds what is pinned and is saved with the city;
+//! `WatchlistLive` is rebuilt every frame with a couple of live stats per
+//! entry and where to point the camera, for the always-visible panel.
+//!
+//! Entities do not survive a save, so pins refer to stable things: a
+//! building by its anchor cell, a district by its index, and a citizen by a
+//! fingerprint of home cell, age, gender and education that is kept current
+//! while the citizen is tracked and matched again after loading.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{refresh_watchlist, WatchlistPlugin};
+pub use types::*;
@@ -0,0 +1,162 @@
+//! Keeping watchlist rows up to date.
+
+use bevy::prelude::*;
+
+use crate::buildings::Building;
+use crate::citizen::{Citizen, CitizenDetails, HomeLocation, Position};
+use crate::districts::{District, DistrictMap};
+use crate::grid::WorldGrid;
+use crate::structural_integrity::StructuralState;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Center of a district's cells in world coordinates.
+pub fn district_focus(district: &District) -> Option<(f32, f32)> {
+    if district.cells.is_empty() {
+        return None;
+    }
+    let (sx, sy) = district.cells.iter().fold((0.0, 0.0), |(sx, sy), &(x, y)| {
+        let (wx, wy) = WorldGrid::grid_to_world(x, y);
+        (sx + wx, sy + wy)
+    });
+    let n = district.cells.len() as f32;
+    Some((sx / n, sy / n))
+}
+
+type CitizenItem<'a> = (Entity, &'a CitizenDetails, &'a HomeLocation, &'a Position);
+
+/// System: rebuild `WatchlistLive` from the current world.
+///
+/// Tracked citizens are remembered by entity between frames. A citizen that
+/// is not tracked yet, e.g. right after pinning or loading, is matched by
+/// its key when the watchlist changes and otherwise once per slow tick.
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_watchlist(
+    mut watchlist: ResMut<Watchlist>,
+    mut live: ResMut<WatchlistLive>,
+    slow_timer: Res<SlowTickTimer>,
+    citizens: Query<CitizenItem, With<Citizen>>,
+    buildings: Query<&Building>,
+    grid: Res<WorldGrid>,
+    structural: Res<StructuralState>,
+    districts: Res<DistrictMap>,
+) {
+    let rebuild = watchlist.is_changed() || live.rows.len() != watchlist.entries.len();
+    let search = rebuild || slow_timer.should_run();
+
+    let mut rows = Vec::with_capacity(watchlist.entries.len());
+    for (i, target) in watchlist.entries.iter().enumerate() {
+        let row = match *target {
+            WatchTarget::Citizen(key) => {
+                let tracked = if rebuild { None } else { live.rows[i].entity };
+                let mut found = tracked.and_then(|e| citizens.get(e).ok());
+                if found.is_none() && search {
+                    let taken: Vec<Entity> =
+                        rows.iter().filter_map(|r: &WatchRow| r.entity).collect();
+                    found = citizens
+                        .iter()
+                        .find(|(e, d, h, _)| !taken.contains(e) && CitizenKey::new(d, h) == key);
+                }
+                match found {
+                    Some((entity, details, _, pos)) => WatchRow {
+                        target: *target,
+                        entity: Some(entity),
+                        focus: Some((pos.x, pos.y)),
+                        stats: WatchStats::Citizen {
+                            gender: details.gender,
+                            happiness: details.happiness,
+                            health: details.health,
+                        },
+                    },
+                    None => missing(*target),
+                }
+            }
+            WatchTarget::Building { x, y } => {
+                let (x, y) = (x as usize, y as usize);
+                let building = grid
+                    .in_bounds(x, y)
+                    .then(|| grid.get(x, y).building_id)
+                    .flatten()
+                    .and_then(|e| buildings.get(e).ok().map(|b| (e, b)))
+                    .filter(|(_, b)| b.grid_x == x && b.grid_y == y);
+                match building {
+                    Some((entity, b)) => WatchRow {
+                        target: *target,
+                        entity: Some(entity),
+                        focus: Some(WorldGrid::grid_to_world(x, y)),
+                        stats: WatchStats::Building {
+                            zone: b.zone_type,
+                            level: b.level,
+                            occupants: b.occupants,
+                            capacity: b.capacity,
+                            condition: structural.condition(x, y),
+                        },
+                    },
+                    None => missing(*target),
+                }
+            }
+            WatchTarget::District { index } => match districts.districts.get(index as usize) {
+                Some(district) => WatchRow {
+                    target: *target,
+                    entity: None,
+                    focus: district_focus(district),
+                    stats: WatchStats::District {
+                        name: district.name.clone(),
+                        population: district.stats.population,
+                        happiness: district.stats.avg_happiness,
+                    },
+                },
+                None => missing(*target),
+            },
+        };
+        rows.push(row);
+    }
+
+    // Keep citizen keys current (birthdays, moving house) so the saved key
+    // still matches after loading. This must not count as a change, which
+    // would drop the tracked entities.
+    for (target, row) in watchlist
+        .bypass_change_detection()
+        .entries
+        .iter_mut()
+        .zip(&mut rows)
+    {
+        let WatchTarget::Citizen(key) = target else {
+            continue;
+        };
+        if let Some((_, details, home, _)) = row.entity.and_then(|e| citizens.get(e).ok()) {
+            let current = CitizenKey::new(details, home);
+            if current != *key {
+                *key = current;
+                row.target = *target;
+            }
+        }
+    }
+
+    live.rows = rows;
+}
+
+fn missing(target: WatchTarget) -> WatchRow {
+    WatchRow {
+        target,
+        entity: None,
+        focus: None,
+        stats: WatchStats::Missing,
+    }
+}
+
+pub struct WatchlistPlugin;
+
+impl Plugin for WatchlistPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Watchlist>()
+            .init_resource::<WatchlistLive>()
+            .add_systems(Update, refresh_watchlist);
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<Watchlist>();
+    }
+}
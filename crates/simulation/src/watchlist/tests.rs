@@ -0,0 +1,169 @@
+use super::*;
+use crate::buildings::Building;
+use crate::citizen::{Citizen, CitizenDetails, Gender, HomeLocation, Position};
+use crate::districts::{District, DistrictMap};
+use crate::grid::{WorldGrid, ZoneType};
+use crate::structural_integrity::StructuralState;
+use crate::{Saveable, SlowTickTimer};
+use bevy::prelude::*;
+
+fn key(age: u8) -> CitizenKey {
+    CitizenKey {
+        home_x: 3,
+        home_y: 4,
+        age,
+        female: true,
+        education: 2,
+    }
+}
+
+#[test]
+fn test_pin_dedupes_and_caps() {
+    let mut list = Watchlist::default();
+    assert!(list.pin(WatchTarget::building(1, 2)));
+    assert!(!list.pin(WatchTarget::building(1, 2)));
+    for i in 1..MAX_WATCHED {
+        assert!(list.pin(WatchTarget::district(i)));
+    }
+    assert!(list.is_full());
+    assert!(!list.pin(WatchTarget::Citizen(key(30))));
+    assert_eq!(list.entries.len(), MAX_WATCHED);
+}
+
+#[test]
+fn test_unpin() {
+    let mut list = Watchlist::default();
+    list.pin(WatchTarget::district(0));
+    list.pin(WatchTarget::building(5, 5));
+    assert!(list.unpin(&WatchTarget::district(0)));
+    assert!(!list.unpin(&WatchTarget::district(0)));
+    assert_eq!(list.entries, vec![WatchTarget::building(5, 5)]);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(Watchlist::default().save_to_bytes().is_none());
+
+    let mut list = Watchlist::default();
+    list.pin(WatchTarget::Citizen(key(30)));
+    list.pin(WatchTarget::building(7, 9));
+    list.pin(WatchTarget::district(2));
+    let bytes = list.save_to_bytes().unwrap();
+    assert_eq!(Watchlist::load_from_bytes(&bytes), list);
+}
+
+#[test]
+fn test_district_focus_is_cell_centroid() {
+    let mut district = District::new("Test".to_string(), [1.0; 4]);
+    assert_eq!(district_focus(&district), None);
+    district.cells.insert((2, 2));
+    district.cells.insert((4, 2));
+    let (a, _) = WorldGrid::grid_to_world(2, 2);
+    let (b, y) = WorldGrid::grid_to_world(4, 2);
+    assert_eq!(district_focus(&district), Some(((a + b) / 2.0, y)));
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Watchlist>()
+        .init_resource::<WatchlistLive>()
+        .init_resource::<SlowTickTimer>()
+        .init_resource::<StructuralState>()
+        .init_resource::<DistrictMap>()
+        .insert_resource(WorldGrid::new(16, 16))
+        .add_systems(Update, refresh_watchlist);
+    app
+}
+
+fn spawn_citizen(app: &mut App, age: u8) -> Entity {
+    app.world_mut()
+        .spawn((
+            Citizen,
+            CitizenDetails {
+                age,
+                gender: Gender::Female,
+                education: 2,
+                happiness: 65.0,
+                health: 80.0,
+                salary: 0.0,
+                savings: 0.0,
+            },
+            HomeLocation {
+                grid_x: 3,
+                grid_y: 4,
+                building: Entity::PLACEHOLDER,
+            },
+            Position { x: 10.0, y: 20.0 },
+        ))
+        .id()
+}
+
+#[test]
+fn test_citizen_is_resolved_by_key_and_key_follows_birthdays() {
+    let mut app = test_app();
+    let citizen = spawn_citizen(&mut app, 30);
+    app.world_mut()
+        .resource_mut::<Watchlist>()
+        .pin(WatchTarget::Citizen(key(30)));
+    app.update();
+
+    let row = app.world().resource::<WatchlistLive>().rows[0].clone();
+    assert_eq!(row.entity, Some(citizen));
+    assert_eq!(row.focus, Some((10.0, 20.0)));
+    assert!(matches!(row.stats, WatchStats::Citizen { happiness, .. } if happiness == 65.0));
+
+    app.world_mut()
+        .get_mut::<CitizenDetails>(citizen)
+        .unwrap()
+        .age = 31;
+    app.update();
+    assert_eq!(
+        app.world().resource::<Watchlist>().entries,
+        vec![WatchTarget::Citizen(key(31))]
+    );
+
+    app.world_mut().despawn(citizen);
+    app.update();
+    let row = &app.world().resource::<WatchlistLive>().rows[0];
+    assert_eq!(row.stats, WatchStats::Missing);
+}
+
+#[test]
+fn test_building_and_district_rows() {
+    let mut app = test_app();
+    let building = app
+        .world_mut()
+        .spawn(Building {
+            zone_type: ZoneType::ResidentialLow,
+            level: 1,
+            grid_x: 5,
+            grid_y: 6,
+            capacity: 10,
+            occupants: 4,
+        })
+        .id();
+    app.world_mut()
+        .resource_mut::<WorldGrid>()
+        .get_mut(5, 6)
+        .building_id = Some(building);
+    {
+        let mut list = app.world_mut().resource_mut::<Watchlist>();
+        list.pin(WatchTarget::building(5, 6));
+        list.pin(WatchTarget::district(0));
+        list.pin(WatchTarget::district(999));
+    }
+    app.update();
+
+    let rows = &app.world().resource::<WatchlistLive>().rows;
+    assert_eq!(rows.len(), 3);
+    assert!(matches!(
+        rows[0].stats,
+        WatchStats::Building {
+            occupants: 4,
+            capacity: 10,
+            ..
+        }
+    ));
+    assert!(matches!(rows[1].stats, WatchStats::District { .. }));
+    assert_eq!(rows[2].stats, WatchStats::Missing);
+}
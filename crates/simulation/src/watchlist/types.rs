@@ -0,0 +1,154 @@
+//! Pinned targets and the live rows shown for them.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::citizen::{CitizenDetails, Gender, HomeLocation};
+use crate::grid::ZoneType;
+use crate::Saveable;
+
+/// Most entries the watchlist holds.
+pub const MAX_WATCHED: usize = 8;
+
+/// Identifies a citizen across save and load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct CitizenKey {
+    pub home_x: u16,
+    pub home_y: u16,
+    pub age: u8,
+    pub female: bool,
+    pub education: u8,
+}
+
+impl CitizenKey {
+    pub fn new(details: &CitizenDetails, home: &HomeLocation) -> Self {
+        Self {
+            home_x: home.grid_x as u16,
+            home_y: home.grid_y as u16,
+            age: details.age,
+            female: details.gender == Gender::Female,
+            education: details.education,
+        }
+    }
+}
+
+/// Something the player has pinned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum WatchTarget {
+    Citizen(CitizenKey),
+    /// A building, by its anchor cell.
+    Building {
+        x: u16,
+        y: u16,
+    },
+    /// A player district, by its index in `DistrictMap::districts`.
+    District {
+        index: u16,
+    },
+}
+
+impl WatchTarget {
+    pub fn building(x: usize, y: usize) -> Self {
+        Self::Building {
+            x: x as u16,
+            y: y as u16,
+        }
+    }
+
+    pub fn district(index: usize) -> Self {
+        Self::District {
+            index: index as u16,
+        }
+    }
+}
+
+/// Pinned targets, in the order they were pinned. Saved with the city.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Watchlist {
+    pub entries: Vec<WatchTarget>,
+}
+
+impl Watchlist {
+    pub fn contains(&self, target: &WatchTarget) -> bool {
+        self.entries.contains(target)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= MAX_WATCHED
+    }
+
+    /// Pin `target`. Returns false if it is already pinned or the list is
+    /// full.
+    pub fn pin(&mut self, target: WatchTarget) -> bool {
+        if self.contains(&target) || self.is_full() {
+            return false;
+        }
+        self.entries.push(target);
+        true
+    }
+
+    /// Unpin `target`. Returns false if it was not pinned.
+    pub fn unpin(&mut self, target: &WatchTarget) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|t| t != target);
+        self.entries.len() != before
+    }
+}
+
+impl Saveable for Watchlist {
+    const SAVE_KEY: &'static str = "watchlist";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+/// Live stats for one watchlist entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchStats {
+    /// The target no longer exists (moved out, demolished, died).
+    Missing,
+    Citizen {
+        gender: Gender,
+        happiness: f32,
+        health: f32,
+    },
+    Building {
+        zone: ZoneType,
+        level: u8,
+        occupants: u32,
+        capacity: u32,
+        condition: f32,
+    },
+    District {
+        name: String,
+        population: u32,
+        happiness: f32,
+    },
+}
+
+/// One row of the watchlist panel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchRow {
+    pub target: WatchTarget,
+    /// The tracked entity, for citizens and buildings.
+    pub entity: Option<Entity>,
+    /// World position to focus the camera on.
+    pub focus: Option<(f32, f32)>,
+    pub stats: WatchStats,
+}
+
+/// Rows for the watchlist panel, one per entry of `Watchlist`, refreshed
+/// every frame.
+#[derive(Resource, Debug, Default)]
+pub struct WatchlistLive {
+    pub rows: Vec<WatchRow>,
+}
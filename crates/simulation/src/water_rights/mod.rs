@@ -0,0 +1,25 @@
+//! Regional water agreements.
+//!
+//! The city sits in a small regional map with one neighbour per map edge.
+//! Neighbours are joined to each other by fixed interconnects; the city
+//! joins them by building pipelines, which need a highway or rail link on
+//! that edge for right-of-way. Agreements buy water from, or sell it to, a
+//! neighbour at a contracted volume and monthly price. Water is routed over
+//! the pipeline graph, so a region the city has no pipeline to can still be
+//! reached through its neighbours for a wheeling fee per region crossed, as
+//! long as the links have capacity.
+//!
+//! Each agreement carries a drought curtailment clause: the supplier may
+//! cut deliveries once the drought reaches the clause's trigger tier, and
+//! firmer clauses cost more. Imports add to `WaterSupply` supply and exports
+//! to its demand every slow tick; payments are settled daily.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{
+    apply_water_agreements, handle_water_rights_actions, settle_water_agreements, WaterRightsPlugin,
+};
+pub use types::*;
@@ -0,0 +1,242 @@
+//! Pipeline construction, agreement signing and the water they move.
+
+use bevy::prelude::*;
+
+use crate::drought::DroughtState;
+use crate::economy::CityBudget;
+use crate::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
+use crate::outside_connections::OutsideConnections;
+use crate::time_of_day::GameClock;
+use crate::water_demand::WaterSupply;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Carry out one water rights action. Returns the notification text on
+/// success and the reason on failure.
+fn apply_water_rights_action(
+    action: &WaterRightsAction,
+    rights: &mut WaterRights,
+    connections: &OutsideConnections,
+    budget: &mut CityBudget,
+) -> Result<String, String> {
+    match *action {
+        WaterRightsAction::BuildPipeline(edge) => {
+            let name = region(edge).name;
+            if rights.has_pipeline(edge) {
+                return Err(format!("A pipeline to {name} already exists."));
+            }
+            if !has_right_of_way(&connections.connections, edge) {
+                return Err(format!(
+                    "A pipeline to {name} needs a highway or rail link on the {} edge.",
+                    edge.name().to_lowercase()
+                ));
+            }
+            if budget.treasury < PIPELINE_COST {
+                return Err(format!(
+                    "The pipeline costs ${PIPELINE_COST:.0}, treasury has ${:.0}.",
+                    budget.treasury
+                ));
+            }
+            budget.treasury -= PIPELINE_COST;
+            rights.pipelines[edge.index()] = true;
+            Ok(format!("Pipeline to {name} completed."))
+        }
+        WaterRightsAction::Sign {
+            region: edge,
+            direction,
+            volume_mgd,
+            clause,
+        } => {
+            let neighbor = region(edge);
+            if volume_mgd < MIN_AGREEMENT_MGD {
+                return Err(format!(
+                    "Agreements must be for at least {MIN_AGREEMENT_MGD} MGD."
+                ));
+            }
+            if !rights.is_reachable(edge) {
+                return Err(format!("No pipeline route reaches {}.", neighbor.name));
+            }
+            let available = rights.available_with(edge, direction);
+            if volume_mgd > available + f32::EPSILON {
+                let verb = match direction {
+                    TradeDirection::Buy => "sell",
+                    TradeDirection::Sell => "buy",
+                };
+                return Err(format!(
+                    "{} will only {verb} another {available:.1} MGD.",
+                    neighbor.name
+                ));
+            }
+            let price = agreement_price(neighbor, direction, clause);
+            rights.agreements.push(WaterAgreement {
+                region: edge,
+                direction,
+                volume_mgd,
+                clause,
+                price_per_mgd: price,
+                delivered_mgd: 0.0,
+                hops: 0,
+                curtailed: false,
+            });
+            let verb = match direction {
+                TradeDirection::Buy => "buy",
+                TradeDirection::Sell => "sell",
+            };
+            Ok(format!(
+                "Signed a {} agreement to {verb} {volume_mgd:.1} MGD with {} at ${price:.0} per MGD a month.",
+                clause.name().to_lowercase(),
+                neighbor.name
+            ))
+        }
+        WaterRightsAction::Cancel(index) => {
+            if index >= rights.agreements.len() {
+                return Err("No such agreement.".to_string());
+            }
+            let agreement = rights.agreements.remove(index);
+            Ok(format!(
+                "Cancelled the water agreement with {}.",
+                region(agreement.region).name
+            ))
+        }
+    }
+}
+
+/// Handle water rights actions requested from the UI or agents.
+pub fn handle_water_rights_actions(
+    mut events: EventReader<WaterRightsAction>,
+    mut rights: ResMut<WaterRights>,
+    connections: Res<OutsideConnections>,
+    drought: Res<DroughtState>,
+    mut budget: ResMut<CityBudget>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let mut any_applied = false;
+    for action in events.read() {
+        let result = apply_water_rights_action(action, &mut rights, &connections, &mut budget);
+        any_applied |= result.is_ok();
+        let (text, priority) = match result {
+            Ok(text) => (text, NotificationPriority::Info),
+            Err(text) => (text, NotificationPriority::Attention),
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::General,
+            location: None,
+        });
+    }
+    // Show the new flows straight away; the supply balance catches up on
+    // the next slow tick.
+    if any_applied {
+        rights.update_flows(drought.current_tier);
+    }
+}
+
+/// Route agreements through the pipeline graph under the current drought
+/// clauses and fold the water they move into the city's supply and demand.
+///
+/// Runs on the slow tick, after utility and source supply are aggregated and
+/// before drought staging reads the balance.
+pub fn apply_water_agreements(
+    timer: Res<SlowTickTimer>,
+    drought: Res<DroughtState>,
+    mut rights: ResMut<WaterRights>,
+    mut water_supply: ResMut<WaterSupply>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if !timer.should_run() {
+        return;
+    }
+
+    for i in rights.update_flows(drought.current_tier) {
+        let agreement = &rights.agreements[i];
+        let name = region(agreement.region).name;
+        let (text, priority) = match (agreement.curtailed, agreement.direction) {
+            (true, TradeDirection::Buy) => (
+                format!("Drought: {name} has curtailed water deliveries to the city."),
+                NotificationPriority::Warning,
+            ),
+            (true, TradeDirection::Sell) => (
+                format!("Drought: water sales to {name} curtailed."),
+                NotificationPriority::Info,
+            ),
+            (false, _) => (
+                format!("Full water deliveries with {name} restored."),
+                NotificationPriority::Positive,
+            ),
+        };
+        notifications.send(NotificationEvent {
+            text,
+            priority,
+            category: NotificationCategory::Disaster,
+            location: None,
+        });
+    }
+
+    if rights.imported_mgd <= 0.0 && rights.exported_mgd <= 0.0 {
+        return;
+    }
+    water_supply.total_supply_gpd += rights.imported_mgd * MGD_TO_GPD;
+    water_supply.total_demand_gpd += rights.exported_mgd * MGD_TO_GPD;
+    water_supply.supply_ratio = if water_supply.total_demand_gpd > 0.0 {
+        water_supply.total_supply_gpd / water_supply.total_demand_gpd
+    } else {
+        1.0
+    };
+}
+
+/// Pay for and get paid for delivered water once a day, at a thirtieth of
+/// the monthly contract price.
+pub fn settle_water_agreements(
+    clock: Res<GameClock>,
+    mut rights: ResMut<WaterRights>,
+    mut budget: ResMut<CityBudget>,
+) {
+    if clock.day == rights.last_settle_day {
+        return;
+    }
+    rights.last_settle_day = clock.day;
+
+    let mut paid = 0.0;
+    let mut earned = 0.0;
+    for agreement in &rights.agreements {
+        let net = agreement.monthly_net() / 30.0;
+        if net < 0.0 {
+            paid -= net;
+        } else {
+            earned += net;
+        }
+    }
+    rights.total_paid += paid;
+    rights.total_earned += earned;
+    rights.last_daily_net = earned - paid;
+    budget.treasury += earned - paid;
+}
+
+pub struct WaterRightsPlugin;
+
+impl Plugin for WaterRightsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaterRights>()
+            .add_event::<WaterRightsAction>()
+            .add_systems(Update, handle_water_rights_actions)
+            .add_systems(
+                FixedUpdate,
+                (
+                    apply_water_agreements
+                        .after(crate::water_demand::aggregate_water_supply)
+                        .after(crate::water_sources::aggregate_water_source_supply)
+                        .after(crate::drought::update_drought_index)
+                        .before(crate::drought_staging::update_drought_staging),
+                    settle_water_agreements,
+                )
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<WaterRights>();
+    }
+}
@@ -0,0 +1,192 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::drought::DroughtTier;
+use crate::outside_connections::{ConnectionType, OutsideConnection};
+use crate::Saveable;
+
+fn agreement(region: MapEdge, direction: TradeDirection, volume_mgd: f32) -> WaterAgreement {
+    WaterAgreement {
+        region,
+        direction,
+        volume_mgd,
+        clause: CurtailmentClause::Standard,
+        price_per_mgd: 100.0,
+        delivered_mgd: 0.0,
+        hops: 0,
+        curtailed: false,
+    }
+}
+
+#[test]
+fn test_map_edges() {
+    assert_eq!(MapEdge::of_cell(50, 0), Some(MapEdge::North));
+    assert_eq!(MapEdge::of_cell(50, GRID_HEIGHT - 1), Some(MapEdge::South));
+    assert_eq!(MapEdge::of_cell(GRID_WIDTH - 1, 50), Some(MapEdge::East));
+    assert_eq!(MapEdge::of_cell(0, 50), Some(MapEdge::West));
+    assert_eq!(MapEdge::of_cell(50, 50), None);
+
+    assert!(MapEdge::North.is_adjacent(MapEdge::East));
+    assert!(MapEdge::West.is_adjacent(MapEdge::North));
+    assert!(!MapEdge::North.is_adjacent(MapEdge::South));
+    for edge in MapEdge::ALL {
+        assert_eq!(region(edge).edge, edge);
+    }
+}
+
+#[test]
+fn test_right_of_way_needs_highway_or_rail_on_that_edge() {
+    let connection = |connection_type, grid_x, grid_y| OutsideConnection {
+        connection_type,
+        grid_x,
+        grid_y,
+        capacity: 0,
+        utilization: 0.0,
+    };
+    let connections = vec![
+        connection(ConnectionType::Highway, 50, 1),
+        connection(ConnectionType::Airport, GRID_WIDTH - 1, 50),
+    ];
+    assert!(has_right_of_way(&connections, MapEdge::North));
+    assert!(!has_right_of_way(&connections, MapEdge::East));
+    assert!(!has_right_of_way(&connections, MapEdge::South));
+}
+
+#[test]
+fn test_curtailment_clauses() {
+    for tier in [
+        DroughtTier::Normal,
+        DroughtTier::Moderate,
+        DroughtTier::Severe,
+        DroughtTier::Extreme,
+    ] {
+        assert_eq!(CurtailmentClause::Firm.curtailment(tier), 0.0);
+    }
+    assert_eq!(
+        CurtailmentClause::Standard.curtailment(DroughtTier::Moderate),
+        0.0
+    );
+    assert_eq!(
+        CurtailmentClause::Standard.curtailment(DroughtTier::Severe),
+        0.5
+    );
+    assert_eq!(
+        CurtailmentClause::Interruptible.curtailment(DroughtTier::Normal),
+        0.0
+    );
+    assert_eq!(
+        CurtailmentClause::Interruptible.curtailment(DroughtTier::Moderate),
+        1.0
+    );
+
+    let north = region(MapEdge::North);
+    let firm = agreement_price(north, TradeDirection::Buy, CurtailmentClause::Firm);
+    let interruptible =
+        agreement_price(north, TradeDirection::Buy, CurtailmentClause::Interruptible);
+    assert!(firm > north.price_per_mgd && interruptible < north.price_per_mgd);
+    assert!(
+        agreement_price(north, TradeDirection::Sell, CurtailmentClause::Standard)
+            < north.price_per_mgd
+    );
+}
+
+#[test]
+fn test_graph_routes_through_neighbours() {
+    // Only a pipeline to the north: the south is two interconnects away.
+    let mut graph = PipelineGraph::new(&[true, false, false, false]);
+    let south = region_node(MapEdge::South);
+    let path = graph.find_path(south, CITY_NODE).unwrap();
+    assert_eq!(path.len(), 4);
+
+    // Both routes (via east and via west) carry 3 MGD each, but the single
+    // pipeline caps the total at 5.
+    let (routed, hops) = graph.route(south, CITY_NODE, 10.0);
+    assert!((routed - PIPELINE_CAPACITY_MGD).abs() < 1e-4);
+    assert_eq!(hops, 2);
+
+    let graph = PipelineGraph::new(&[false; 4]);
+    assert!(graph.find_path(south, CITY_NODE).is_none());
+}
+
+#[test]
+fn test_update_flows_applies_curtailment_and_capacity() {
+    let mut rights = WaterRights {
+        pipelines: [true, false, true, false],
+        ..Default::default()
+    };
+    rights
+        .agreements
+        .push(agreement(MapEdge::North, TradeDirection::Buy, 4.0));
+    rights
+        .agreements
+        .push(agreement(MapEdge::North, TradeDirection::Buy, 4.0));
+    rights
+        .agreements
+        .push(agreement(MapEdge::East, TradeDirection::Sell, 2.0));
+
+    let changed = rights.update_flows(DroughtTier::Normal);
+    assert!(changed.is_empty());
+    // The second north agreement overflows the direct pipeline and is
+    // partly wheeled round through the east and south.
+    assert_eq!(rights.agreements[0].delivered_mgd, 4.0);
+    assert_eq!(rights.agreements[0].hops, 0);
+    assert!((rights.agreements[1].delivered_mgd - 4.0).abs() < 1e-4);
+    assert_eq!(rights.agreements[1].hops, 2);
+    assert!((rights.imported_mgd - 8.0).abs() < 1e-4);
+    assert!((rights.exported_mgd - 2.0).abs() < 1e-4);
+
+    let changed = rights.update_flows(DroughtTier::Severe);
+    assert_eq!(changed, vec![0, 1, 2]);
+    assert!(rights.agreements.iter().all(|a| a.curtailed));
+    assert!((rights.imported_mgd - 4.0).abs() < 1e-4);
+    assert!((rights.exported_mgd - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_monthly_net_includes_wheeling() {
+    let mut buy = agreement(MapEdge::South, TradeDirection::Buy, 2.0);
+    buy.delivered_mgd = 2.0;
+    buy.hops = 1;
+    assert!((buy.monthly_net() + 2.0 * (100.0 + WHEELING_FEE_PER_MGD)).abs() < 1e-9);
+
+    let mut sell = agreement(MapEdge::East, TradeDirection::Sell, 2.0);
+    sell.delivered_mgd = 2.0;
+    assert!((sell.monthly_net() - 200.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_available_volume_shrinks_with_contracts() {
+    let mut rights = WaterRights::default();
+    let spare = region(MapEdge::North).spare_mgd;
+    assert_eq!(
+        rights.available_with(MapEdge::North, TradeDirection::Buy),
+        spare
+    );
+    rights
+        .agreements
+        .push(agreement(MapEdge::North, TradeDirection::Buy, 3.0));
+    assert_eq!(
+        rights.available_with(MapEdge::North, TradeDirection::Buy),
+        spare - 3.0
+    );
+    assert_eq!(
+        rights.available_with(MapEdge::North, TradeDirection::Sell),
+        0.0
+    );
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(WaterRights::default().save_to_bytes().is_none());
+
+    let mut rights = WaterRights::default();
+    rights.pipelines[2] = true;
+    rights
+        .agreements
+        .push(agreement(MapEdge::South, TradeDirection::Buy, 1.5));
+    rights.total_paid = 1234.0;
+    let bytes = rights.save_to_bytes().unwrap();
+    let loaded = WaterRights::load_from_bytes(&bytes);
+    assert_eq!(loaded.pipelines, rights.pipelines);
+    assert_eq!(loaded.agreements, rights.agreements);
+    assert_eq!(loaded.total_paid, 1234.0);
+}
@@ -0,0 +1,467 @@
+//! Neighbouring regions, the pipeline graph and water agreements.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::drought::DroughtTier;
+use crate::outside_connections::{ConnectionType, OutsideConnection};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Gallons per day in one million gallons per day.
+pub const MGD_TO_GPD: f32 = 1_000_000.0;
+
+/// Cost of laying a pipeline from the city to a neighbouring region.
+pub const PIPELINE_COST: f64 = 8_000.0;
+
+/// Capacity of a city pipeline (MGD).
+pub const PIPELINE_CAPACITY_MGD: f32 = 5.0;
+
+/// Capacity of each interconnect between two neighbouring regions (MGD).
+pub const INTERCONNECT_CAPACITY_MGD: f32 = 3.0;
+
+/// Monthly fee per MGD for every region the water passes through on its way
+/// between the city and the contracted region.
+pub const WHEELING_FEE_PER_MGD: f64 = 15.0;
+
+/// Smallest volume an agreement can be signed for (MGD).
+pub const MIN_AGREEMENT_MGD: f32 = 0.5;
+
+/// Cells this close to the map boundary count toward that edge.
+const EDGE_DEPTH: usize = 3;
+
+// ---------------------------------------------------------------------------
+// Regional map
+// ---------------------------------------------------------------------------
+
+/// Side of the map a neighbouring region lies on.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum MapEdge {
+    /// The y = 0 side of the map.
+    #[default]
+    North,
+    East,
+    South,
+    West,
+}
+
+impl MapEdge {
+    pub const ALL: [MapEdge; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::North => "North",
+            Self::East => "East",
+            Self::South => "South",
+            Self::West => "West",
+        }
+    }
+
+    pub fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+        }
+    }
+
+    /// Edge a grid cell lies against, if it is within `EDGE_DEPTH` of one.
+    /// Corners resolve to the north or south edge.
+    pub fn of_cell(x: usize, y: usize) -> Option<MapEdge> {
+        if y < EDGE_DEPTH {
+            Some(Self::North)
+        } else if y >= GRID_HEIGHT - EDGE_DEPTH {
+            Some(Self::South)
+        } else if x >= GRID_WIDTH - EDGE_DEPTH {
+            Some(Self::East)
+        } else if x < EDGE_DEPTH {
+            Some(Self::West)
+        } else {
+            None
+        }
+    }
+
+    /// Regions on adjacent edges share an interconnect.
+    pub fn is_adjacent(self, other: MapEdge) -> bool {
+        (self.index() + 1) % 4 == other.index() || (other.index() + 1) % 4 == self.index()
+    }
+}
+
+/// A neighbouring region the city can trade water with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NeighborRegion {
+    pub name: &'static str,
+    pub edge: MapEdge,
+    /// Water the region can sell, across all its agreements (MGD).
+    pub spare_mgd: f32,
+    /// Water the region wants to buy, across all its agreements (MGD).
+    pub wanted_mgd: f32,
+    /// Monthly price per MGD on a standard-clause agreement.
+    pub price_per_mgd: f64,
+}
+
+/// The regional map: one neighbour per map edge, indexed by `MapEdge::index`.
+pub const REGIONS: [NeighborRegion; 4] = [
+    NeighborRegion {
+        name: "Northvale",
+        edge: MapEdge::North,
+        spare_mgd: 8.0,
+        wanted_mgd: 0.0,
+        price_per_mgd: 90.0,
+    },
+    NeighborRegion {
+        name: "Eastport",
+        edge: MapEdge::East,
+        spare_mgd: 0.0,
+        wanted_mgd: 6.0,
+        price_per_mgd: 110.0,
+    },
+    NeighborRegion {
+        name: "Southmere",
+        edge: MapEdge::South,
+        spare_mgd: 4.0,
+        wanted_mgd: 2.0,
+        price_per_mgd: 70.0,
+    },
+    NeighborRegion {
+        name: "Westbrook",
+        edge: MapEdge::West,
+        spare_mgd: 2.0,
+        wanted_mgd: 3.0,
+        price_per_mgd: 100.0,
+    },
+];
+
+pub fn region(edge: MapEdge) -> &'static NeighborRegion {
+    &REGIONS[edge.index()]
+}
+
+/// Whether a highway or rail link gives a pipeline right-of-way on this edge.
+pub fn has_right_of_way(connections: &[OutsideConnection], edge: MapEdge) -> bool {
+    connections.iter().any(|c| {
+        matches!(
+            c.connection_type,
+            ConnectionType::Highway | ConnectionType::Railway
+        ) && MapEdge::of_cell(c.grid_x, c.grid_y) == Some(edge)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Agreements
+// ---------------------------------------------------------------------------
+
+/// Whether the city receives or supplies the water.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+}
+
+/// Drought curtailment clause. The supplying side may cut deliveries by the
+/// clause's share once the drought reaches its trigger tier; firmer clauses
+/// cost more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum CurtailmentClause {
+    /// Delivered in full whatever the weather.
+    Firm,
+    /// Cut by half in a severe drought and three quarters in an extreme one.
+    Standard,
+    /// Suspended as soon as a moderate drought is declared.
+    Interruptible,
+}
+
+impl CurtailmentClause {
+    pub const ALL: [CurtailmentClause; 3] = [Self::Firm, Self::Standard, Self::Interruptible];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Firm => "Firm",
+            Self::Standard => "Standard",
+            Self::Interruptible => "Interruptible",
+        }
+    }
+
+    /// Share of the contracted volume withheld at this drought tier.
+    pub fn curtailment(self, tier: DroughtTier) -> f32 {
+        match (self, tier) {
+            (_, DroughtTier::Normal) | (Self::Firm, _) => 0.0,
+            (Self::Standard, DroughtTier::Moderate) => 0.0,
+            (Self::Standard, DroughtTier::Severe) => 0.5,
+            (Self::Standard, DroughtTier::Extreme) => 0.75,
+            (Self::Interruptible, _) => 1.0,
+        }
+    }
+
+    /// Multiplier on the region's standard price.
+    pub fn price_multiplier(self) -> f64 {
+        match self {
+            Self::Firm => 1.4,
+            Self::Standard => 1.0,
+            Self::Interruptible => 0.7,
+        }
+    }
+}
+
+/// A contract to buy water from, or sell it to, a neighbouring region.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct WaterAgreement {
+    pub region: MapEdge,
+    pub direction: TradeDirection,
+    /// Contracted volume (MGD).
+    pub volume_mgd: f32,
+    pub clause: CurtailmentClause,
+    /// Monthly price per MGD, fixed when the agreement was signed.
+    pub price_per_mgd: f64,
+    /// Volume actually flowing after curtailment and pipeline limits (MGD).
+    pub delivered_mgd: f32,
+    /// Regions the water passes through between the city and `region`.
+    pub hops: u32,
+    /// Whether the drought clause is currently cutting deliveries.
+    pub curtailed: bool,
+}
+
+impl WaterAgreement {
+    /// Monthly amount the city pays (negative) or earns (positive) at the
+    /// current delivered volume. Wheeling fees are always the city's.
+    pub fn monthly_net(&self) -> f64 {
+        let value = self.delivered_mgd as f64 * self.price_per_mgd;
+        let wheeling = self.delivered_mgd as f64 * self.hops as f64 * WHEELING_FEE_PER_MGD;
+        match self.direction {
+            TradeDirection::Buy => -value - wheeling,
+            TradeDirection::Sell => value - wheeling,
+        }
+    }
+}
+
+/// Price per MGD a new agreement with `region` would be signed at.
+pub fn agreement_price(
+    region: &NeighborRegion,
+    direction: TradeDirection,
+    clause: CurtailmentClause,
+) -> f64 {
+    // Regions buy at a discount to what they charge.
+    let base = match direction {
+        TradeDirection::Buy => region.price_per_mgd,
+        TradeDirection::Sell => region.price_per_mgd * 0.8,
+    };
+    base * clause.price_multiplier()
+}
+
+// ---------------------------------------------------------------------------
+// Pipeline graph
+// ---------------------------------------------------------------------------
+
+/// Node index of the city in the pipeline graph; regions follow at
+/// `1 + MapEdge::index`.
+pub const CITY_NODE: usize = 0;
+
+pub fn region_node(edge: MapEdge) -> usize {
+    1 + edge.index()
+}
+
+/// Residual capacities between the city and the four regions, per direction.
+/// Pipelines join the city to a region; fixed interconnects join adjacent
+/// regions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineGraph {
+    pub capacity: [[f32; 5]; 5],
+}
+
+impl PipelineGraph {
+    pub fn new(pipelines: &[bool; 4]) -> Self {
+        let mut capacity = [[0.0; 5]; 5];
+        for edge in MapEdge::ALL {
+            let node = region_node(edge);
+            if pipelines[edge.index()] {
+                capacity[CITY_NODE][node] = PIPELINE_CAPACITY_MGD;
+                capacity[node][CITY_NODE] = PIPELINE_CAPACITY_MGD;
+            }
+            for other in MapEdge::ALL {
+                if edge.is_adjacent(other) {
+                    capacity[node][region_node(other)] = INTERCONNECT_CAPACITY_MGD;
+                }
+            }
+        }
+        Self { capacity }
+    }
+
+    /// Shortest path from `from` to `to` over links with capacity left.
+    pub fn find_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut previous = [usize::MAX; 5];
+        let mut visited = [false; 5];
+        let mut queue = std::collections::VecDeque::from([from]);
+        visited[from] = true;
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                let mut path = vec![to];
+                while *path.last().unwrap() != from {
+                    path.push(previous[*path.last().unwrap()]);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in 0..5 {
+                if !visited[next] && self.capacity[node][next] > f32::EPSILON {
+                    visited[next] = true;
+                    previous[next] = node;
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Route up to `volume` from `from` to `to`, splitting across paths as
+    /// links fill. Returns the volume routed and the most regions any part
+    /// of it passed through.
+    pub fn route(&mut self, from: usize, to: usize, volume: f32) -> (f32, u32) {
+        let mut routed = 0.0;
+        let mut hops = 0;
+        while volume - routed > f32::EPSILON {
+            let Some(path) = self.find_path(from, to) else {
+                break;
+            };
+            let bottleneck = path
+                .windows(2)
+                .map(|w| self.capacity[w[0]][w[1]])
+                .fold(volume - routed, f32::min);
+            for w in path.windows(2) {
+                self.capacity[w[0]][w[1]] -= bottleneck;
+            }
+            routed += bottleneck;
+            // Intermediate nodes are all regions (the city is an endpoint).
+            hops = hops.max(path.len() as u32 - 2);
+        }
+        (routed, hops)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Main resource
+// ---------------------------------------------------------------------------
+
+/// Pipelines, agreements and the water they currently move.
+#[derive(Resource, Debug, Clone, Default, Encode, Decode, Serialize, Deserialize)]
+pub struct WaterRights {
+    /// Whether a city pipeline reaches each region, by `MapEdge::index`.
+    pub pipelines: [bool; 4],
+    pub agreements: Vec<WaterAgreement>,
+    /// Water flowing into the city under buy agreements (MGD).
+    pub imported_mgd: f32,
+    /// Water flowing out of the city under sell agreements (MGD).
+    pub exported_mgd: f32,
+    /// Net daily settlement at the last settlement (negative = cost).
+    pub last_daily_net: f64,
+    pub total_paid: f64,
+    pub total_earned: f64,
+    pub last_settle_day: u32,
+}
+
+impl WaterRights {
+    pub fn has_pipeline(&self, edge: MapEdge) -> bool {
+        self.pipelines[edge.index()]
+    }
+
+    /// Volume already contracted with a region in one direction (MGD).
+    pub fn contracted_with(&self, edge: MapEdge, direction: TradeDirection) -> f32 {
+        self.agreements
+            .iter()
+            .filter(|a| a.region == edge && a.direction == direction)
+            .map(|a| a.volume_mgd)
+            .sum()
+    }
+
+    /// Volume a region would still agree to trade in one direction (MGD).
+    pub fn available_with(&self, edge: MapEdge, direction: TradeDirection) -> f32 {
+        let limit = match direction {
+            TradeDirection::Buy => region(edge).spare_mgd,
+            TradeDirection::Sell => region(edge).wanted_mgd,
+        };
+        (limit - self.contracted_with(edge, direction)).max(0.0)
+    }
+
+    /// Whether the city could move water to or from a region over the
+    /// pipelines it has built.
+    pub fn is_reachable(&self, edge: MapEdge) -> bool {
+        PipelineGraph::new(&self.pipelines)
+            .find_path(CITY_NODE, region_node(edge))
+            .is_some()
+    }
+
+    /// Apply drought curtailment and route every agreement through the
+    /// pipeline graph, in signing order. Returns the agreements whose
+    /// curtailment status changed.
+    pub fn update_flows(&mut self, tier: DroughtTier) -> Vec<usize> {
+        let mut graph = PipelineGraph::new(&self.pipelines);
+        let mut changed = Vec::new();
+        self.imported_mgd = 0.0;
+        self.exported_mgd = 0.0;
+        for (i, agreement) in self.agreements.iter_mut().enumerate() {
+            let cut = agreement.clause.curtailment(tier);
+            let curtailed = cut > 0.0;
+            if curtailed != agreement.curtailed {
+                agreement.curtailed = curtailed;
+                changed.push(i);
+            }
+            let wanted = agreement.volume_mgd * (1.0 - cut);
+            let region = region_node(agreement.region);
+            let (delivered, hops) = match agreement.direction {
+                TradeDirection::Buy => graph.route(region, CITY_NODE, wanted),
+                TradeDirection::Sell => graph.route(CITY_NODE, region, wanted),
+            };
+            agreement.delivered_mgd = delivered;
+            agreement.hops = hops;
+            match agreement.direction {
+                TradeDirection::Buy => self.imported_mgd += delivered,
+                TradeDirection::Sell => self.exported_mgd += delivered,
+            }
+        }
+        changed
+    }
+
+    /// Net monthly cash flow across all agreements (negative = cost).
+    pub fn monthly_net(&self) -> f64 {
+        self.agreements.iter().map(|a| a.monthly_net()).sum()
+    }
+}
+
+impl Saveable for WaterRights {
+    const SAVE_KEY: &'static str = "water_rights";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if self.agreements.is_empty() && self.pipelines == [false; 4] {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// A water rights action requested from the UI or by an agent.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub enum WaterRightsAction {
+    /// Lay a pipeline to the region on this edge.
+    BuildPipeline(MapEdge),
+    /// Sign a new agreement with the region on `region`.
+    Sign {
+        region: MapEdge,
+        direction: TradeDirection,
+        volume_mgd: f32,
+        clause: CurtailmentClause,
+    },
+    /// Cancel the agreement at this index.
+    Cancel(usize),
+}
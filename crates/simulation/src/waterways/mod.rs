@@ -0,0 +1,22 @@
+//! Player-made rivers, canals and lakes.
+//!
+//! The water tool carves channels into the terrain. Carved cells that touch
+//! each other form a body; a body that reaches existing water drains through
+//! the lowest water it touches, which gives every cell a flow direction
+//! towards that outlet. A connected body is navigable, so ferry piers on it
+//! reach the sea. Reservoirs beside a waterway take a share of its flow.
+//!
+//! Rain on the land around a waterway runs into it. A body carries flow in
+//! proportion to its size, so a narrow canal through a large catchment
+//! spills onto its banks in a storm where a lake would not; the spilled
+//! water is handed to the flood simulation.
+
+pub mod network;
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use network::{bank_cells, build_network, carve_waterway};
+pub use systems::{update_waterways, WaterwaysPlugin};
+pub use types::*;
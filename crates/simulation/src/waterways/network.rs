@@ -0,0 +1,198 @@
+//! Carving waterways and working out how water moves through them.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::flood_simulation::hydrology::is_channel;
+use crate::grid::{CellType, WorldGrid};
+
+use super::types::*;
+
+/// Turn the disc of cells around (`gx`, `gy`) into water and record them
+/// as carved. Returns the cells that changed.
+pub fn carve_waterway(
+    grid: &mut WorldGrid,
+    waterways: &mut Waterways,
+    gx: usize,
+    gy: usize,
+) -> Vec<(usize, usize)> {
+    let mut changed = Vec::new();
+    for dy in -CARVE_RADIUS..=CARVE_RADIUS {
+        for dx in -CARVE_RADIUS..=CARVE_RADIUS {
+            let (nx, ny) = (gx as i32 + dx, gy as i32 + dy);
+            if nx < 0 || ny < 0 || !grid.in_bounds(nx as usize, ny as usize) {
+                continue;
+            }
+            if ((dx * dx + dy * dy) as f32).sqrt() > CARVE_RADIUS as f32 {
+                continue;
+            }
+            let (x, y) = (nx as usize, ny as usize);
+            let cell = grid.get_mut(x, y);
+            if cell.cell_type == CellType::Water && !waterways.is_carved(x, y) {
+                // Existing water is joined, not re-carved.
+                continue;
+            }
+            cell.cell_type = CellType::Water;
+            cell.elevation = CARVED_BED_ELEVATION;
+            waterways.mark_carved(x, y);
+            changed.push((x, y));
+        }
+    }
+    changed
+}
+
+/// Group the carved cells into bodies and work out where each drains, how
+/// much flows through it at `precipitation` in/hr of rain, and which way.
+pub fn build_network(
+    grid: &WorldGrid,
+    waterways: &Waterways,
+    precipitation: f32,
+) -> WaterwayNetwork {
+    let mut network = WaterwayNetwork::default();
+    let carved = |idx: usize| {
+        let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+        grid.get(x, y).cell_type == CellType::Water && waterways.is_carved(x, y)
+    };
+    let natural_water = |idx: usize| {
+        let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+        grid.get(x, y).cell_type == CellType::Water && !waterways.is_carved(x, y)
+    };
+
+    for &start in &waterways.carved {
+        let start = start as usize;
+        if network.body_of[start] != NO_BODY || !carved(start) {
+            continue;
+        }
+        let id = network.bodies.len() as u32;
+
+        // Flood fill the body.
+        let mut cells = vec![start];
+        network.body_of[start] = id;
+        let mut i = 0;
+        while i < cells.len() {
+            for n in neighbours(cells[i]) {
+                if network.body_of[n] == NO_BODY && carved(n) {
+                    network.body_of[n] = id;
+                    cells.push(n);
+                }
+            }
+            i += 1;
+        }
+
+        // Water runs out through the lowest natural water it touches.
+        let outlet = cells
+            .iter()
+            .flat_map(|&c| neighbours(c))
+            .filter(|&n| natural_water(n))
+            .min_by(|&a, &b| elevation(grid, a).total_cmp(&elevation(grid, b)));
+
+        if let Some(outlet) = outlet {
+            trace_flow(&mut network, id, outlet);
+        }
+
+        let channel_cells = cells
+            .iter()
+            .filter(|&&c| is_channel(grid, c % GRID_WIDTH, c / GRID_WIDTH))
+            .count();
+        let kind = if channel_cells * 2 >= cells.len() {
+            WaterwayKind::Canal
+        } else {
+            WaterwayKind::Lake
+        };
+
+        let catchment_cells = count_catchment(grid, &cells);
+        let runoff =
+            precipitation.max(0.0) * RUNOFF_MGD_PER_CATCHMENT_CELL * catchment_cells as f32;
+        let exchange = if outlet.is_some() {
+            EXCHANGE_MGD_PER_CELL * cells.len() as f32
+        } else {
+            0.0
+        };
+        let capacity_mgd = CAPACITY_MGD_PER_CELL * cells.len() as f32;
+
+        network.bodies.push(WaterwayBody {
+            kind,
+            cells,
+            outlet,
+            catchment_cells,
+            flow_mgd: exchange + runoff,
+            capacity_mgd,
+        });
+    }
+    network
+}
+
+/// Point every cell of body `id` one step closer to `outlet`, breadth
+/// first from the cells beside it.
+fn trace_flow(network: &mut WaterwayNetwork, id: u32, outlet: usize) {
+    let mut queue = VecDeque::new();
+    for n in neighbours(outlet) {
+        if network.body_of[n] == id {
+            network.flow_dir[n] = Some(step(n, outlet));
+            queue.push_back(n);
+        }
+    }
+    while let Some(cell) = queue.pop_front() {
+        for n in neighbours(cell) {
+            if network.body_of[n] == id && network.flow_dir[n].is_none() {
+                network.flow_dir[n] = Some(step(n, cell));
+                queue.push_back(n);
+            }
+        }
+    }
+}
+
+/// Land cells within `CATCHMENT_REACH` of any of `cells`.
+fn count_catchment(grid: &WorldGrid, cells: &[usize]) -> u32 {
+    let mut seen = HashSet::new();
+    for &c in cells {
+        let (x, y) = (c % GRID_WIDTH, c / GRID_WIDTH);
+        let (x1, y1) = (
+            (x + CATCHMENT_REACH).min(GRID_WIDTH - 1),
+            (y + CATCHMENT_REACH).min(GRID_HEIGHT - 1),
+        );
+        for ny in y.saturating_sub(CATCHMENT_REACH)..=y1 {
+            for nx in x.saturating_sub(CATCHMENT_REACH)..=x1 {
+                if grid.get(nx, ny).cell_type != CellType::Water {
+                    seen.insert(ny * GRID_WIDTH + nx);
+                }
+            }
+        }
+    }
+    seen.len() as u32
+}
+
+/// Land cells beside `cells`, where spilled water lands.
+pub fn bank_cells(grid: &WorldGrid, cells: &[usize]) -> Vec<usize> {
+    let mut banks: Vec<usize> = cells
+        .iter()
+        .flat_map(|&c| neighbours(c))
+        .filter(|&n| grid.get(n % GRID_WIDTH, n / GRID_WIDTH).cell_type != CellType::Water)
+        .collect();
+    banks.sort_unstable();
+    banks.dedup();
+    banks
+}
+
+fn elevation(grid: &WorldGrid, idx: usize) -> f32 {
+    grid.get(idx % GRID_WIDTH, idx / GRID_WIDTH).elevation
+}
+
+/// Offset from cell `from` to its neighbour `to`.
+fn step(from: usize, to: usize) -> (i8, i8) {
+    let dx = (to % GRID_WIDTH) as i32 - (from % GRID_WIDTH) as i32;
+    let dy = (to / GRID_WIDTH) as i32 - (from / GRID_WIDTH) as i32;
+    (dx as i8, dy as i8)
+}
+
+fn neighbours(idx: usize) -> impl Iterator<Item = usize> {
+    let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+    [
+        (x > 0).then(|| idx - 1),
+        (x + 1 < GRID_WIDTH).then(|| idx + 1),
+        (y > 0).then(|| idx - GRID_WIDTH),
+        (y + 1 < GRID_HEIGHT).then(|| idx + GRID_WIDTH),
+    ]
+    .into_iter()
+    .flatten()
+}
@@ -0,0 +1,124 @@
+//! Rebuilding the waterway network and what its flow does each slow tick.
+
+use bevy::prelude::*;
+
+use crate::config::GRID_WIDTH;
+use crate::flood_simulation::FloodGrid;
+use crate::grid::{CellType, WorldGrid};
+use crate::water_sources::{WaterSource, WaterSourceType};
+use crate::weather::Weather;
+use crate::SlowTickTimer;
+
+use super::network::{bank_cells, build_network};
+use super::types::*;
+
+/// Gallons in a million gallons.
+const GALLONS_PER_MG: f32 = 1_000_000.0;
+
+/// Rebuild the network from the carved cells, divert flow into reservoirs
+/// beside a waterway, and spill whatever a waterway cannot carry onto its
+/// banks for the flood simulation to spread.
+pub fn update_waterways(
+    slow_timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    weather: Res<Weather>,
+    mut waterways: ResMut<Waterways>,
+    mut network: ResMut<WaterwayNetwork>,
+    mut flood_grid: ResMut<FloodGrid>,
+    mut sources: Query<&mut WaterSource>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+
+    // Cells filled back in by the terrain tools are no longer waterways.
+    let is_water = |x: usize, y: usize| grid.get(x, y).cell_type == CellType::Water;
+    let stale = waterways
+        .carved
+        .iter()
+        .any(|&idx| !is_water(idx as usize % GRID_WIDTH, idx as usize / GRID_WIDTH));
+    if stale {
+        waterways.retain(is_water);
+    }
+
+    if waterways.carved.is_empty() {
+        if !network.bodies.is_empty() {
+            *network = WaterwayNetwork::default();
+        }
+        return;
+    }
+
+    *network = build_network(&grid, &waterways, weather.precipitation_intensity);
+
+    // --- Reservoirs take their share of the flow passing them ---
+    let mut feeders = vec![0u32; network.bodies.len()];
+    for source in &sources {
+        if source.source_type == WaterSourceType::Reservoir {
+            for id in network.bodies_near(source.grid_x, source.grid_y, RESERVOIR_REACH) {
+                feeders[id] += 1;
+            }
+        }
+    }
+    let mut reservoirs_fed = 0;
+    let mut inflow_gallons = 0.0;
+    for mut source in &mut sources {
+        if source.source_type != WaterSourceType::Reservoir {
+            continue;
+        }
+        let share_mgd: f32 = network
+            .bodies_near(source.grid_x, source.grid_y, RESERVOIR_REACH)
+            .into_iter()
+            .map(|id| network.bodies[id].flow_mgd * RESERVOIR_DIVERSION / feeders[id] as f32)
+            .sum();
+        if share_mgd <= 0.0 {
+            continue;
+        }
+        let room = (source.storage_capacity - source.stored_gallons).max(0.0);
+        let added = (share_mgd * GALLONS_PER_MG).min(room);
+        source.stored_gallons += added;
+        inflow_gallons += added;
+        reservoirs_fed += 1;
+    }
+    network.reservoirs_fed = reservoirs_fed;
+    network.reservoir_inflow_gallons = inflow_gallons;
+
+    // --- Over-capacity waterways flood their banks ---
+    for body in &network.bodies {
+        let excess = body.excess_mgd();
+        if excess <= 0.0 {
+            continue;
+        }
+        let banks = bank_cells(&grid, &body.cells);
+        if banks.is_empty() {
+            continue;
+        }
+        let depth = excess * SPILL_MGD_TO_FEET / banks.len() as f32;
+        for idx in banks {
+            let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+            let current = flood_grid.get(x, y);
+            flood_grid.set(x, y, current + depth);
+        }
+    }
+}
+
+pub struct WaterwaysPlugin;
+
+impl Plugin for WaterwaysPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Waterways>()
+            .init_resource::<WaterwayNetwork>()
+            .add_systems(
+                FixedUpdate,
+                update_waterways
+                    .after(crate::water_sources::replenish_reservoirs)
+                    .before(crate::reservoir::update_reservoir_levels)
+                    .before(crate::flood_simulation::update_flood_simulation)
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<Waterways>();
+    }
+}
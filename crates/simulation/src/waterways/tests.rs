@@ -0,0 +1,105 @@
+use super::*;
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::grid::{CellType, WorldGrid};
+use crate::Saveable;
+
+/// A grid with a north-south river three cells wide at x = 100..103.
+fn grid_with_river() -> WorldGrid {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    for y in 0..GRID_HEIGHT {
+        for x in 100..103 {
+            grid.get_mut(x, y).cell_type = CellType::Water;
+        }
+    }
+    grid
+}
+
+/// Carve a straight east-west canal along row `y` between two centres.
+fn carve_row(grid: &mut WorldGrid, waterways: &mut Waterways, x0: usize, x1: usize, y: usize) {
+    for x in (x0..=x1).step_by(2) {
+        carve_waterway(grid, waterways, x, y);
+    }
+}
+
+#[test]
+fn test_carving_records_cells_but_not_existing_water() {
+    let mut grid = grid_with_river();
+    let mut waterways = Waterways::default();
+    let changed = carve_waterway(&mut grid, &mut waterways, 99, 50);
+    assert!(!changed.is_empty());
+    assert!(waterways.is_carved(98, 50));
+    assert_eq!(grid.get(98, 50).cell_type, CellType::Water);
+    assert!(!waterways.is_carved(100, 50), "the river is not carved");
+}
+
+#[test]
+fn test_isolated_pond_does_not_flow() {
+    let mut grid = grid_with_river();
+    let mut waterways = Waterways::default();
+    carve_waterway(&mut grid, &mut waterways, 40, 40);
+    let network = build_network(&grid, &waterways, 0.0);
+    assert_eq!(network.bodies.len(), 1);
+    assert!(!network.bodies[0].is_connected());
+    assert_eq!(network.flow_at(40, 40), None);
+    assert!(!network.is_navigable_near(40, 40, FERRY_REACH));
+}
+
+#[test]
+fn test_canal_joining_the_river_flows_towards_it() {
+    let mut grid = grid_with_river();
+    let mut waterways = Waterways::default();
+    carve_row(&mut grid, &mut waterways, 90, 98, 128);
+    let network = build_network(&grid, &waterways, 0.0);
+    assert_eq!(network.bodies.len(), 1);
+    let body = &network.bodies[0];
+    assert!(body.is_connected());
+    assert_eq!(body.kind, WaterwayKind::Canal);
+    assert!(body.flow_mgd > 0.0);
+    // The far end has a single neighbour in the canal, towards the river.
+    assert_eq!(network.flow_at(88, 128), Some((1, 0)));
+    assert!(network.is_navigable_near(88, 130, FERRY_REACH));
+}
+
+#[test]
+fn test_storm_overflows_a_narrow_canal_but_not_a_lake() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut waterways = Waterways::default();
+    carve_row(&mut grid, &mut waterways, 50, 130, 200);
+    for y in (10..=40).step_by(2) {
+        carve_row(&mut grid, &mut waterways, 10, 40, y);
+    }
+    let network = build_network(&grid, &waterways, 3.0);
+    assert_eq!(network.bodies.len(), 2);
+    let canal = network.body_at(90, 200).unwrap();
+    let lake = network.body_at(25, 25).unwrap();
+    assert_eq!(canal.kind, WaterwayKind::Canal);
+    assert_eq!(lake.kind, WaterwayKind::Lake);
+    assert!(canal.is_overflowing());
+    assert!(!lake.is_overflowing());
+
+    let calm = build_network(&grid, &waterways, 0.0);
+    assert_eq!(calm.overflowing_count(), 0);
+}
+
+#[test]
+fn test_filled_cells_are_dropped() {
+    let mut grid = WorldGrid::new(GRID_WIDTH, GRID_HEIGHT);
+    let mut waterways = Waterways::default();
+    carve_waterway(&mut grid, &mut waterways, 60, 60);
+    grid.get_mut(60, 60).cell_type = CellType::Grass;
+    waterways.retain(|x, y| grid.get(x, y).cell_type == CellType::Water);
+    assert!(!waterways.is_carved(60, 60));
+    assert!(waterways.is_carved(61, 60));
+}
+
+#[test]
+fn test_waterways_save_roundtrip() {
+    assert!(Waterways::default().save_to_bytes().is_none());
+    let mut waterways = Waterways::default();
+    waterways.mark_carved(5, 7);
+    waterways.mark_carved(3, 7);
+    let bytes = waterways.save_to_bytes().expect("carved cells are saved");
+    let loaded = Waterways::load_from_bytes(&bytes);
+    assert_eq!(loaded, waterways);
+    assert!(loaded.is_carved(3, 7));
+}
@@ -0,0 +1,233 @@
+//! Carved waterways, the network they form and its flow.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Radius of the water brush in cells.
+pub const CARVE_RADIUS: i32 = 2;
+
+/// Elevation of a freshly carved channel bed.
+pub const CARVED_BED_ELEVATION: f32 = 0.3;
+
+/// Flow exchanged with the water a waterway joins, per cell of it (MGD).
+pub const EXCHANGE_MGD_PER_CELL: f32 = 0.05;
+
+/// Land within this many cells of a waterway drains into it.
+pub const CATCHMENT_REACH: usize = 4;
+
+/// Storm runoff reaching a waterway per catchment cell for each inch/hr of
+/// rain (MGD).
+pub const RUNOFF_MGD_PER_CATCHMENT_CELL: f32 = 0.1;
+
+/// Flow one cell of waterway can carry before it spills (MGD). Wide lakes
+/// hold far more than narrow canals through a large catchment.
+pub const CAPACITY_MGD_PER_CELL: f32 = 0.4;
+
+/// Flood depth added to the banks per MGD spilled (feet).
+pub const SPILL_MGD_TO_FEET: f32 = 0.05;
+
+/// Reservoirs within this many cells of a waterway draw from it.
+pub const RESERVOIR_REACH: usize = 3;
+
+/// Share of a waterway's flow diverted into the reservoirs beside it.
+pub const RESERVOIR_DIVERSION: f32 = 0.5;
+
+/// Ferry piers within this many cells of a navigable waterway can use it.
+pub const FERRY_REACH: usize = 3;
+
+/// Marks a cell that is in no waterway body.
+pub const NO_BODY: u32 = u32::MAX;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Shape of a connected body of carved water.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaterwayKind {
+    /// Narrow enough to be a channel: a canal or river.
+    Canal,
+    /// Wide open water.
+    Lake,
+}
+
+impl WaterwayKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            WaterwayKind::Canal => "Canal",
+            WaterwayKind::Lake => "Lake",
+        }
+    }
+}
+
+/// A connected body of carved water cells.
+#[derive(Debug, Clone)]
+pub struct WaterwayBody {
+    pub kind: WaterwayKind,
+    /// Grid indices of its cells.
+    pub cells: Vec<usize>,
+    /// The natural water cell it drains into, if it reaches one.
+    pub outlet: Option<usize>,
+    /// Land cells draining into it.
+    pub catchment_cells: u32,
+    /// Flow through the body (MGD).
+    pub flow_mgd: f32,
+    /// Flow it can carry without spilling (MGD).
+    pub capacity_mgd: f32,
+}
+
+impl WaterwayBody {
+    /// Whether it joins existing water, so that it flows and boats can use
+    /// it.
+    pub fn is_connected(&self) -> bool {
+        self.outlet.is_some()
+    }
+
+    /// Flow above capacity, which spills onto the banks (MGD).
+    pub fn excess_mgd(&self) -> f32 {
+        (self.flow_mgd - self.capacity_mgd).max(0.0)
+    }
+
+    pub fn is_overflowing(&self) -> bool {
+        self.excess_mgd() > 0.0
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Saved record of carved cells
+// ---------------------------------------------------------------------------
+
+/// Water cells the player has carved with the water tool.
+///
+/// Everything else about the waterways is worked out from these cells and
+/// the terrain, see `WaterwayNetwork`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Waterways {
+    /// Grid indices of carved cells, sorted.
+    pub carved: Vec<u32>,
+}
+
+impl Waterways {
+    pub fn is_carved(&self, x: usize, y: usize) -> bool {
+        self.carved
+            .binary_search(&((y * GRID_WIDTH + x) as u32))
+            .is_ok()
+    }
+
+    pub fn mark_carved(&mut self, x: usize, y: usize) {
+        let idx = (y * GRID_WIDTH + x) as u32;
+        if let Err(pos) = self.carved.binary_search(&idx) {
+            self.carved.insert(pos, idx);
+        }
+    }
+
+    /// Drop cells that are no longer water, e.g. after being filled in.
+    pub fn retain(&mut self, mut still_water: impl FnMut(usize, usize) -> bool) {
+        self.carved.retain(|&idx| {
+            let idx = idx as usize;
+            still_water(idx % GRID_WIDTH, idx / GRID_WIDTH)
+        });
+    }
+}
+
+impl Saveable for Waterways {
+    const SAVE_KEY: &'static str = "waterways";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Derived network
+// ---------------------------------------------------------------------------
+
+/// The carved waterways grouped into bodies, with the direction water flows
+/// through each cell.
+///
+/// Rebuilt from `Waterways` and the terrain every slow tick, so it is not
+/// saved.
+#[derive(Resource, Debug, Clone)]
+pub struct WaterwayNetwork {
+    pub bodies: Vec<WaterwayBody>,
+    /// Index into `bodies` for each cell, `NO_BODY` where there is none.
+    pub body_of: Vec<u32>,
+    /// Step towards the outlet for each carved cell of a connected body.
+    pub flow_dir: Vec<Option<(i8, i8)>>,
+    /// Reservoirs fed by a waterway during the last update.
+    pub reservoirs_fed: u32,
+    /// Water added to those reservoirs during the last update (gallons).
+    pub reservoir_inflow_gallons: f32,
+}
+
+impl Default for WaterwayNetwork {
+    fn default() -> Self {
+        Self {
+            bodies: Vec::new(),
+            body_of: vec![NO_BODY; GRID_WIDTH * GRID_HEIGHT],
+            flow_dir: vec![None; GRID_WIDTH * GRID_HEIGHT],
+            reservoirs_fed: 0,
+            reservoir_inflow_gallons: 0.0,
+        }
+    }
+}
+
+impl WaterwayNetwork {
+    /// The body holding cell (`x`, `y`), if any.
+    pub fn body_at(&self, x: usize, y: usize) -> Option<&WaterwayBody> {
+        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+            return None;
+        }
+        let id = self.body_of[y * GRID_WIDTH + x];
+        self.bodies.get(id as usize)
+    }
+
+    /// Direction water flows through cell (`x`, `y`).
+    pub fn flow_at(&self, x: usize, y: usize) -> Option<(i8, i8)> {
+        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+            return None;
+        }
+        self.flow_dir[y * GRID_WIDTH + x]
+    }
+
+    /// Bodies within `reach` cells (Chebyshev) of (`x`, `y`).
+    pub fn bodies_near(&self, x: usize, y: usize, reach: usize) -> Vec<usize> {
+        let mut found = Vec::new();
+        for ny in y.saturating_sub(reach)..=(y + reach).min(GRID_HEIGHT - 1) {
+            for nx in x.saturating_sub(reach)..=(x + reach).min(GRID_WIDTH - 1) {
+                let id = self.body_of[ny * GRID_WIDTH + nx];
+                if id != NO_BODY && !found.contains(&(id as usize)) {
+                    found.push(id as usize);
+                }
+            }
+        }
+        found
+    }
+
+    /// Whether a boat could reach (`x`, `y`) along a carved waterway that
+    /// joins existing water.
+    pub fn is_navigable_near(&self, x: usize, y: usize, reach: usize) -> bool {
+        self.bodies_near(x, y, reach)
+            .into_iter()
+            .any(|id| self.bodies[id].is_connected())
+    }
+
+    pub fn overflowing_count(&self) -> usize {
+        self.bodies.iter().filter(|b| b.is_overflowing()).count()
+    }
+}
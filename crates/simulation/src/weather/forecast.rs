@@ -0,0 +1,95 @@
+//! Short-range weather forecast.
+//!
+//! Weather events are rolled deterministically from the day number (see
+//! `update_weather`), so the forecast replays the same daily rolls over the
+//! next few days to see whether a heat event is coming. It only looks at
+//! event onsets; day-to-day temperature swings are not forecast.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::climate::ClimateZone;
+use super::state::Weather;
+use super::types::Season;
+use crate::time_of_day::GameClock;
+
+/// Days ahead the forecast covers.
+pub const FORECAST_HORIZON_DAYS: u32 = 5;
+
+/// Outcome of the daily event roll on a given day, as in `update_weather`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastEvent {
+    HeatWave,
+    /// Any other event (cold snap, rain, storm) lasting this many days.
+    Other(u32),
+}
+
+/// The event `update_weather` starts on `day` if no event is running.
+pub fn event_roll(day: u32, climate: ClimateZone) -> Option<ForecastEvent> {
+    let hash = (day.wrapping_mul(2654435761)) % 100;
+    let season = Season::from_day(day);
+    let params = climate.season_params(season);
+    let precip_threshold = (params.precipitation_chance * 100.0) as u32;
+    let is_extreme_day = hash < 4;
+
+    match (season, is_extreme_day) {
+        (Season::Summer, true) => Some(ForecastEvent::HeatWave),
+        (Season::Winter, true) if params.snow_enabled => Some(ForecastEvent::Other(3 + hash % 5)),
+        _ if hash < precip_threshold => {
+            let is_storm = hash < (precip_threshold / 3).max(1);
+            Some(ForecastEvent::Other(if is_storm {
+                1 + hash % 3
+            } else {
+                2 + hash % 4
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Days from `today` until the next heat event starts, looking at most
+/// `horizon` days ahead. Returns `None` if none is forecast.
+pub fn forecast_heat_onset(
+    weather: &Weather,
+    climate: ClimateZone,
+    today: u32,
+    horizon: u32,
+) -> Option<u32> {
+    let mut remaining = weather.event_days_remaining;
+    for ahead in 1..=horizon {
+        remaining = remaining.saturating_sub(1);
+        if remaining > 0 {
+            continue;
+        }
+        match event_roll(today + ahead, climate) {
+            Some(ForecastEvent::HeatWave) => return Some(ahead),
+            Some(ForecastEvent::Other(days)) => remaining = days,
+            None => {}
+        }
+    }
+    None
+}
+
+/// The current forecast, refreshed once per game day.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WeatherForecast {
+    /// Days until a forecast heat event begins.
+    pub heat_wave_in_days: Option<u32>,
+    /// Day the forecast was last issued.
+    pub issued_day: u32,
+}
+
+/// Re-issue the forecast when the day changes.
+pub fn update_forecast(
+    clock: Res<GameClock>,
+    weather: Res<Weather>,
+    climate: Res<ClimateZone>,
+    mut forecast: ResMut<WeatherForecast>,
+) {
+    if clock.day == forecast.issued_day {
+        return;
+    }
+    forecast.issued_day = clock.day;
+    forecast.heat_wave_in_days =
+        forecast_heat_onset(&weather, *climate, clock.day, FORECAST_HORIZON_DAYS);
+}
@@ -4,15 +4,19 @@
 //! construction modifiers driven by weather conditions and climate zones.
 
 pub mod climate;
+pub mod forecast;
 pub mod state;
 pub mod systems;
 mod tests_climate;
+mod tests_forecast;
+mod tests_seasonal;
 mod tests_systems;
 mod tests_types;
 pub mod types;
 
 // Re-export all public items so callers can use `weather::Weather`, etc.
 pub use climate::{ClimateZone, SeasonClimateParams};
+pub use forecast::{update_forecast, WeatherForecast, FORECAST_HORIZON_DAYS};
 pub use state::{ConstructionModifiers, Weather};
 pub use systems::{
     diurnal_factor, precipitation_intensity_for_event, update_construction_modifiers,
@@ -32,6 +36,7 @@ impl Plugin for WeatherPlugin {
         app.init_resource::<Weather>()
             .init_resource::<ClimateZone>()
             .init_resource::<ConstructionModifiers>()
+            .init_resource::<WeatherForecast>()
             .add_event::<WeatherChangeEvent>()
             .add_systems(
                 FixedUpdate,
@@ -39,6 +44,7 @@ impl Plugin for WeatherPlugin {
                     update_weather,
                     update_precipitation,
                     update_construction_modifiers,
+                    update_forecast.after(update_weather),
                 )
                     .after(crate::imports_exports::process_trade)
                     .in_set(crate::SimulationSet::Simulation),
@@ -48,12 +48,14 @@ pub fn update_construction_modifiers(
 /// - Daily variation via deterministic hash on day
 /// - Atmospheric state updates (cloud_cover, humidity, precipitation)
 /// - Weather condition derived from atmospheric state
-/// - All parameters driven by the active `ClimateZone`.
+/// - All parameters driven by the active `ClimateZone`, shifted up by any
+///   long-term warming from `ClimateState`.
 pub fn update_weather(
     clock: Res<GameClock>,
     mut weather: ResMut<Weather>,
     mut change_events: EventWriter<WeatherChangeEvent>,
     climate: Res<ClimateZone>,
+    climate_change: Option<Res<crate::climate_change::ClimateState>>,
 ) {
     let current_hour = clock.hour_of_day();
 
@@ -84,7 +86,12 @@ pub fn update_weather(
     let climate_params = zone.season_params(weather.season);
 
     // --- Diurnal temperature ---
-    let (t_min, t_max) = (climate_params.t_min, climate_params.t_max);
+    // Long-term warming shifts the whole seasonal range upward
+    let warming = climate_change.map_or(0.0, |c| c.warming_c());
+    let (t_min, t_max) = (
+        climate_params.t_min + warming,
+        climate_params.t_max + warming,
+    );
     // Add daily variation (deterministic based on day) of +/- 3 degrees
     let day_variation = ((clock.day as f32 * 0.1).sin()) * 3.0;
     let effective_min = t_min + day_variation;
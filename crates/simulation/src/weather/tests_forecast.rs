@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::weather::forecast::*;
+    use crate::weather::*;
+
+    /// First summer day on which a heat event would start.
+    fn first_heat_day() -> u32 {
+        (91..=180)
+            .find(|&d| event_roll(d, ClimateZone::Temperate) == Some(ForecastEvent::HeatWave))
+            .expect("some summer day rolls a heat event")
+    }
+
+    #[test]
+    fn test_heat_event_rolls_only_in_summer() {
+        for day in (1..=90).chain(181..=360) {
+            assert_ne!(
+                event_roll(day, ClimateZone::Temperate),
+                Some(ForecastEvent::HeatWave),
+                "day {day}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_forecast_sees_heat_event_ahead() {
+        let day = first_heat_day();
+        let weather = Weather::default();
+        assert_eq!(
+            forecast_heat_onset(&weather, ClimateZone::Temperate, day - 1, 5),
+            Some(1)
+        );
+        assert_eq!(
+            forecast_heat_onset(&weather, ClimateZone::Temperate, day - 1, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_running_event_delays_forecast_onset() {
+        let day = first_heat_day();
+        let weather = Weather {
+            event_days_remaining: 10,
+            ..Default::default()
+        };
+        // The running event covers the heat day, so no onset is forecast.
+        assert_eq!(
+            forecast_heat_onset(&weather, ClimateZone::Temperate, day - 1, 5),
+            None
+        );
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(test)]
+mod tests {
+    use crate::weather::*;
+
+    #[test]
+    fn test_beaches_peak_in_summer() {
+        let summer = Season::Summer.beach_factor();
+        for season in [Season::Spring, Season::Autumn, Season::Winter] {
+            assert!(season.beach_factor() < summer);
+        }
+        assert!(Season::Winter.beach_factor() < Season::Autumn.beach_factor());
+    }
+}
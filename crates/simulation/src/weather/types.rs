@@ -125,6 +125,17 @@ impl Season {
         }
     }
 
+    /// How much of a beach's pull on visitors is felt this season: beaches
+    /// draw crowds in summer and little in winter.
+    pub fn beach_factor(self) -> f32 {
+        match self {
+            Season::Spring => 0.6,
+            Season::Summer => 1.5,
+            Season::Autumn => 0.5,
+            Season::Winter => 0.1,
+        }
+    }
+
     /// Base grass color tint for terrain rendering, varying by season.
     pub fn grass_color(self) -> [f32; 3] {
         match self {
@@ -1,13 +1,17 @@
+use std::cmp::Reverse;
+
 use bevy::prelude::*;
+use bitcode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::citizen::{Citizen, CitizenDetails, Position, WorkLocation};
 use crate::config::CELL_SIZE;
 use crate::crime::CrimeGrid;
+use crate::economy::CityBudget;
 use crate::grid::WorldGrid;
 use crate::homelessness::{HomelessShelter, HomelessnessStats};
 use crate::services::{ServiceBuilding, ServiceType};
-use crate::SlowTickTimer;
+use crate::{Saveable, SlowTickTimer};
 
 // ---------------------------------------------------------------------------
 // Resource
@@ -30,8 +34,64 @@ pub struct WelfareStats {
     pub welfare_office_count: u32,
     /// Number of homeless shelters in the city.
     pub shelter_count: u32,
+    /// Caseworkers employed across all welfare offices.
+    pub caseworkers: u32,
+    /// Applications waiting for a caseworker.
+    pub queue_length: u32,
+    /// Applications approved on the last slow tick.
+    pub processed_last_tick: u32,
+    /// Average wait of queued applications (slow ticks).
+    pub avg_wait_ticks: f32,
+    /// Monthly staffing cost above (or below) baseline staffing.
+    pub staffing_cost: f64,
+}
+
+/// Staffing budget for welfare offices. `level` scales caseworkers per
+/// office: 1.0 is the baseline covered by office maintenance.
+#[derive(Resource, Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct WelfareStaffing {
+    pub level: f32,
+}
+
+impl Default for WelfareStaffing {
+    fn default() -> Self {
+        Self { level: 1.0 }
+    }
+}
+
+impl WelfareStaffing {
+    /// Staffing level clamped to the supported range.
+    pub fn level(&self) -> f32 {
+        self.level.clamp(MIN_STAFFING_LEVEL, MAX_STAFFING_LEVEL)
+    }
+}
+
+impl Saveable for WelfareStaffing {
+    const SAVE_KEY: &'static str = "welfare_staffing";
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(bitcode::encode(self))
+    }
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// An unemployed citizen whose benefits application is waiting for a
+/// caseworker.
+#[derive(Component, Debug, Clone, Default)]
+pub struct WelfareApplication {
+    /// Slow ticks spent in the queue.
+    pub waited: u32,
 }
 
+/// An unemployed citizen whose benefits have been approved.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WelfareBenefits;
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -46,6 +106,32 @@ const WELFARE_CRIME_REDUCTION: u8 = 8;
 /// Applied as a multiplier to the citizen's salary (simulating job training benefit).
 const JOB_TRAINING_SALARY_BONUS: f32 = 50.0;
 
+/// Caseworkers per welfare office at baseline staffing.
+pub const CASEWORKERS_PER_OFFICE: f32 = 5.0;
+
+/// Applications one caseworker processes per slow tick.
+pub const CASES_PER_CASEWORKER: f32 = 2.0;
+
+/// Monthly wage per caseworker, charged for staffing above baseline.
+pub const CASEWORKER_MONTHLY_WAGE: f64 = 4.0;
+
+/// Staffing slider range.
+pub const MIN_STAFFING_LEVEL: f32 = 0.25;
+pub const MAX_STAFFING_LEVEL: f32 = 2.0;
+
+/// Slow ticks an applicant can wait before the benefit gap starts to hurt.
+pub const WAIT_GRACE_TICKS: u32 = 5;
+
+/// Happiness lost per slow tick while waiting past the grace period.
+const WAITING_HAPPINESS_PENALTY: f32 = 2.0;
+
+/// Savings drained per slow tick while waiting past the grace period. Once
+/// savings go negative the citizen is at risk of homelessness.
+const BENEFIT_GAP_SAVINGS_LOSS: f32 = 40.0;
+
+/// Slow ticks per 30-day month (1440 ticks/day, slow tick every 100).
+const SLOW_TICKS_PER_MONTH: f64 = 30.0 * 1440.0 / 100.0;
+
 // ---------------------------------------------------------------------------
 // System: update_welfare
 // ---------------------------------------------------------------------------
@@ -54,21 +140,43 @@ const JOB_TRAINING_SALARY_BONUS: f32 = 50.0;
 ///
 /// Runs on SlowTickTimer (every 100 ticks):
 /// - Counts homeless shelters and their total capacity
-/// - Welfare offices provide job training bonus (increase salary for unemployed citizens in radius)
+/// - Unemployed citizens in a welfare office radius apply for benefits;
+///   applications queue and are approved oldest-first, limited by caseworker
+///   capacity (scaled by `WelfareStaffing`)
+/// - Approved recipients get a job training bonus (increased salary)
+/// - Applicants waiting past `WAIT_GRACE_TICKS` lose happiness and savings,
+///   which can push them into homelessness
 /// - Welfare offices reduce crime in radius (social safety net effect)
 /// - Tracks shelter occupancy vs capacity
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn update_welfare(
+    mut commands: Commands,
     slow_timer: Res<SlowTickTimer>,
     services: Query<&ServiceBuilding>,
     shelters: Query<&HomelessShelter>,
     homeless_stats: Res<HomelessnessStats>,
+    staffing: Res<WelfareStaffing>,
+    mut budget: ResMut<CityBudget>,
     mut welfare_stats: ResMut<WelfareStats>,
     mut crime_grid: ResMut<CrimeGrid>,
     mut unemployed_citizens: Query<
-        (&Position, &mut CitizenDetails),
+        (
+            Entity,
+            &Position,
+            &mut CitizenDetails,
+            Option<&mut WelfareApplication>,
+            Has<WelfareBenefits>,
+        ),
         (With<Citizen>, Without<WorkLocation>),
     >,
+    closed_cases: Query<
+        Entity,
+        (
+            With<Citizen>,
+            With<WorkLocation>,
+            Or<(With<WelfareApplication>, With<WelfareBenefits>)>,
+        ),
+    >,
     grid: Res<WorldGrid>,
 ) {
     if !slow_timer.should_run() {
@@ -138,36 +246,91 @@ pub fn update_welfare(
         }
     }
 
-    // 2. Job training bonus: boost salary for unemployed citizens near welfare offices
-    //    This makes them more employable in the job_seeking system.
+    // 2. Caseload: employed citizens no longer need benefits.
+    for entity in &closed_cases {
+        commands
+            .entity(entity)
+            .remove::<(WelfareApplication, WelfareBenefits)>();
+    }
+
+    let level = staffing.level();
+    let caseworkers = (welfare_office_count as f32 * CASEWORKERS_PER_OFFICE * level).round() as u32;
+    let capacity = (caseworkers as f32 * CASES_PER_CASEWORKER).round() as usize;
+
+    // 3. Job training bonus for approved recipients; queue everyone else
+    //    near an office. Recipients keep benefits while any office exists.
     let mut welfare_recipients = 0u32;
+    let mut queue: Vec<(Entity, u32)> = Vec::new();
 
-    if !welfare_offices.is_empty() {
-        for (pos, mut details) in &mut unemployed_citizens {
-            // Check if citizen is within any welfare office radius
-            for &(gx, gy, radius) in &welfare_offices {
-                let (office_wx, office_wy) = WorldGrid::grid_to_world(gx, gy);
-                let dx = pos.x - office_wx;
-                let dy = pos.y - office_wy;
-                let dist_sq = dx * dx + dy * dy;
-
-                if dist_sq <= radius * radius {
-                    // Apply job training benefit: increase effective salary
-                    // This makes the citizen more competitive in the job market
-                    details.salary = (details.salary + JOB_TRAINING_SALARY_BONUS).min(10000.0);
-                    welfare_recipients += 1;
-                    break; // Only count once per citizen
-                }
+    for (entity, pos, mut details, application, has_benefits) in &mut unemployed_citizens {
+        if has_benefits {
+            if welfare_offices.is_empty() {
+                commands.entity(entity).remove::<WelfareBenefits>();
+                continue;
+            }
+            // Apply job training benefit: increase effective salary
+            // This makes the citizen more competitive in the job market
+            details.salary = (details.salary + JOB_TRAINING_SALARY_BONUS).min(10000.0);
+            welfare_recipients += 1;
+            continue;
+        }
+
+        if let Some(mut application) = application {
+            application.waited += 1;
+            if application.waited > WAIT_GRACE_TICKS {
+                // Benefit gap: no income support while the case is pending.
+                details.happiness = (details.happiness - WAITING_HAPPINESS_PENALTY).max(0.0);
+                details.savings -= BENEFIT_GAP_SAVINGS_LOSS;
             }
+            queue.push((entity, application.waited));
+            continue;
         }
+
+        // New applicants must be within a welfare office radius.
+        let in_range = welfare_offices.iter().any(|&(gx, gy, radius)| {
+            let (office_wx, office_wy) = WorldGrid::grid_to_world(gx, gy);
+            let dx = pos.x - office_wx;
+            let dy = pos.y - office_wy;
+            dx * dx + dy * dy <= radius * radius
+        });
+        if in_range {
+            commands
+                .entity(entity)
+                .insert(WelfareApplication::default());
+            queue.push((entity, 0));
+        }
+    }
+
+    // 4. Caseworkers approve the longest-waiting applications first.
+    queue.sort_by_key(|&(_, waited)| Reverse(waited));
+    let processed = capacity.min(queue.len());
+    for &(entity, _) in &queue[..processed] {
+        commands
+            .entity(entity)
+            .remove::<WelfareApplication>()
+            .insert(WelfareBenefits);
     }
+    welfare_recipients += processed as u32;
+    let waiting = &queue[processed..];
+    let avg_wait_ticks = if waiting.is_empty() {
+        0.0
+    } else {
+        waiting.iter().map(|&(_, w)| w as f32).sum::<f32>() / waiting.len() as f32
+    };
+
+    // 5. Staffing above baseline costs extra; understaffing saves money.
+    let staffing_cost = welfare_office_count as f64
+        * CASEWORKERS_PER_OFFICE as f64
+        * (level as f64 - 1.0)
+        * CASEWORKER_MONTHLY_WAGE;
+    budget.treasury -= staffing_cost / SLOW_TICKS_PER_MONTH;
 
     // --- Calculate monthly cost ---
     let shelter_maintenance: f64 = effective_shelter_count as f64
         * ServiceBuilding::monthly_maintenance(ServiceType::HomelessShelter);
     let welfare_maintenance: f64 = welfare_office_count as f64
         * ServiceBuilding::monthly_maintenance(ServiceType::WelfareOffice);
-    let monthly_cost = shelter_maintenance + welfare_maintenance;
+    let monthly_cost = shelter_maintenance + welfare_maintenance + staffing_cost;
 
     // --- Update stats resource ---
     welfare_stats.total_sheltered = homeless_stats.sheltered;
@@ -177,6 +340,11 @@ pub fn update_welfare(
     welfare_stats.shelter_occupancy = total_occupancy;
     welfare_stats.welfare_office_count = welfare_office_count;
     welfare_stats.shelter_count = effective_shelter_count;
+    welfare_stats.caseworkers = caseworkers;
+    welfare_stats.queue_length = waiting.len() as u32;
+    welfare_stats.processed_last_tick = processed as u32;
+    welfare_stats.avg_wait_ticks = avg_wait_ticks;
+    welfare_stats.staffing_cost = staffing_cost;
 }
 
 // ---------------------------------------------------------------------------
@@ -233,6 +401,21 @@ mod tests {
         assert_eq!(SHELTER_CAPACITY_PER_BUILDING, 50);
     }
 
+    #[test]
+    fn staffing_level_is_clamped() {
+        assert_eq!(WelfareStaffing::default().level(), 1.0);
+        assert_eq!(WelfareStaffing { level: 5.0 }.level(), MAX_STAFFING_LEVEL);
+        assert_eq!(WelfareStaffing { level: 0.0 }.level(), MIN_STAFFING_LEVEL);
+    }
+
+    #[test]
+    fn staffing_saveable_roundtrip() {
+        let staffing = WelfareStaffing { level: 1.5 };
+        let bytes = staffing.save_to_bytes().unwrap();
+        let restored = WelfareStaffing::load_from_bytes(&bytes);
+        assert_eq!(restored.level, 1.5);
+    }
+
     #[test]
     fn crime_reduction_constant() {
         assert!(WELFARE_CRIME_REDUCTION > 0);
@@ -244,7 +427,16 @@ pub struct WelfarePlugin;
 
 impl Plugin for WelfarePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<WelfareStats>().add_systems(
+        app.init_resource::<WelfareStats>()
+            .init_resource::<WelfareStaffing>();
+
+        // Register for save/load via the SaveableRegistry.
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<WelfareStaffing>();
+
+        app.add_systems(
             FixedUpdate,
             update_welfare
                 .after(crate::homelessness::recover_from_homelessness)
@@ -0,0 +1,23 @@
+//! Urban wildlife and ecosystem layer.
+//!
+//! Every slow tick each cell gets a habitat value from its cover (parks,
+//! trees, rivers and lakes, open grass; nothing on roads and buildings),
+//! less what air pollution, noise and the light from nearby buildings and
+//! streets take away. Connected cells of good habitat form green corridors;
+//! corridors below a viable size support no wildlife and larger ones
+//! support disproportionately more, so fragmenting a corridor with a road
+//! costs more than the cells it paves. The wildlife population grows or
+//! shrinks toward what the corridors support.
+//!
+//! Nature reserves (nature reserve park districts) add habitat and shield
+//! it from part of the disturbance. Biodiversity adds to the city's natural
+//! beauty for tourism, and wildlife around a park district draws extra
+//! visitors. The per-cell biodiversity grid backs the biodiversity overlay.
+
+pub mod systems;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub use systems::{update_wildlife, wildlife_park_visits, WildlifePlugin};
+pub use types::*;
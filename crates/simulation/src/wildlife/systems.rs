@@ -0,0 +1,237 @@
+//! Habitat mapping, corridor detection, wildlife growth and the wildlife
+//! draw on park districts.
+
+use bevy::prelude::*;
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::flood_simulation::{SurfaceKind, SurfaceMap};
+use crate::grid::{CellType, WorldGrid};
+use crate::noise::NoisePollutionGrid;
+use crate::park_districts::ParkDistrictState;
+use crate::pollution::PollutionGrid;
+use crate::river_flow::RiverFlowState;
+use crate::services::{ServiceBuilding, ServiceType};
+use crate::trees::TreeGrid;
+use crate::SlowTickTimer;
+
+use super::types::*;
+
+/// Mark every cell within `radius` of (`cx`, `cy`) in `mask`.
+fn mark_radius(mask: &mut [bool], cx: usize, cy: usize, radius: i32) {
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy > radius * radius {
+                continue;
+            }
+            let (nx, ny) = (cx as i32 + dx, cy as i32 + dy);
+            if nx >= 0 && ny >= 0 && (nx as usize) < GRID_WIDTH && (ny as usize) < GRID_HEIGHT {
+                mask[ny as usize * GRID_WIDTH + nx as usize] = true;
+            }
+        }
+    }
+}
+
+/// Number of lit cells (buildings and roads) within `LIGHT_RADIUS` of each
+/// cell.
+fn light_counts(grid: &WorldGrid) -> Vec<u32> {
+    let lit: Vec<bool> = grid
+        .cells
+        .iter()
+        .map(|c| c.building_id.is_some() || c.cell_type == CellType::Road)
+        .collect();
+    let mut counts = vec![0u32; GRID_WIDTH * GRID_HEIGHT];
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            if !lit[y * GRID_WIDTH + x] {
+                continue;
+            }
+            let x0 = x.saturating_sub(LIGHT_RADIUS as usize);
+            let x1 = (x + LIGHT_RADIUS as usize).min(GRID_WIDTH - 1);
+            let y0 = y.saturating_sub(LIGHT_RADIUS as usize);
+            let y1 = (y + LIGHT_RADIUS as usize).min(GRID_HEIGHT - 1);
+            for ny in y0..=y1 {
+                for nx in x0..=x1 {
+                    counts[ny * GRID_WIDTH + nx] += 1;
+                }
+            }
+        }
+    }
+    counts
+}
+
+/// Label the 4-connected corridors of cells with at least `MIN_HABITAT`.
+/// Returns the corridor of each cell and each corridor's (cells, total
+/// habitat).
+fn find_patches(habitat: &[u8]) -> (Vec<Option<u32>>, Vec<(u32, u32)>) {
+    let mut labels = vec![None; habitat.len()];
+    let mut patches = Vec::new();
+    let mut stack = Vec::new();
+    for start in 0..habitat.len() {
+        if habitat[start] < MIN_HABITAT || labels[start].is_some() {
+            continue;
+        }
+        let id = patches.len() as u32;
+        let (mut cells, mut total) = (0u32, 0u32);
+        labels[start] = Some(id);
+        stack.push(start);
+        while let Some(idx) = stack.pop() {
+            cells += 1;
+            total += habitat[idx] as u32;
+            let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+            let neighbors = [
+                (x > 0).then(|| idx - 1),
+                (x + 1 < GRID_WIDTH).then(|| idx + 1),
+                (y > 0).then(|| idx - GRID_WIDTH),
+                (y + 1 < GRID_HEIGHT).then(|| idx + GRID_WIDTH),
+            ];
+            for n in neighbors.into_iter().flatten() {
+                if habitat[n] >= MIN_HABITAT && labels[n].is_none() {
+                    labels[n] = Some(id);
+                    stack.push(n);
+                }
+            }
+        }
+        patches.push((cells, total));
+    }
+    (labels, patches)
+}
+
+/// Every slow tick, map habitat from trees, parks and water less pollution,
+/// noise and light, find the green corridors, and grow or shrink the
+/// wildlife population toward what the corridors can support. River channels
+/// lose habitat as their flow drops.
+#[allow(clippy::too_many_arguments)]
+pub fn update_wildlife(
+    slow_timer: Res<SlowTickTimer>,
+    grid: Res<WorldGrid>,
+    trees: Res<TreeGrid>,
+    services: Query<&ServiceBuilding>,
+    pollution: Res<PollutionGrid>,
+    noise: Res<NoisePollutionGrid>,
+    parks: Res<ParkDistrictState>,
+    river: Res<RiverFlowState>,
+    mut surface: Local<SurfaceMap>,
+    mut biodiversity: ResMut<BiodiversityGrid>,
+    mut wildlife: ResMut<WildlifeState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    if grid.is_changed() || surface.kinds.is_empty() {
+        *surface = SurfaceMap::build(&grid);
+    }
+    let channel_habitat = (WATER_HABITAT as f32 * river.channel_habitat()) as u8;
+
+    let n = GRID_WIDTH * GRID_HEIGHT;
+    let mut parkland = vec![false; n];
+    for service in &services {
+        let radius = match service.service_type {
+            ServiceType::SmallPark => SMALL_PARK_HABITAT_RADIUS,
+            ServiceType::LargePark => LARGE_PARK_HABITAT_RADIUS,
+            _ => continue,
+        };
+        mark_radius(&mut parkland, service.grid_x, service.grid_y, radius);
+    }
+    let mut reserve = vec![false; n];
+    for district in parks.nature_reserves() {
+        mark_radius(
+            &mut reserve,
+            district.center_x,
+            district.center_y,
+            district.radius_cells(),
+        );
+    }
+    let lights = light_counts(&grid);
+
+    let habitat: Vec<u8> = (0..n)
+        .map(|idx| {
+            let (x, y) = (idx % GRID_WIDTH, idx / GRID_WIDTH);
+            let cell = grid.get(x, y);
+            let base = if parkland[idx] {
+                PARK_HABITAT
+            } else if surface.kinds[idx] == SurfaceKind::Channel {
+                channel_habitat
+            } else if cell.cell_type == CellType::Water {
+                WATER_HABITAT
+            } else if cell.cell_type == CellType::Road || cell.building_id.is_some() {
+                0
+            } else if trees.has_tree(x, y) {
+                TREE_HABITAT
+            } else {
+                OPEN_GRASS_HABITAT
+            };
+            cell_habitat(
+                base,
+                pollution.get(x, y),
+                noise.get(x, y),
+                lights[idx],
+                reserve[idx],
+            )
+        })
+        .collect();
+
+    let (labels, patches) = find_patches(&habitat);
+    for (idx, level) in biodiversity.levels.iter_mut().enumerate() {
+        *level = labels[idx].map_or(0, |id| {
+            (habitat[idx] as f32 * patch_connectivity(patches[id as usize].0)) as u8
+        });
+    }
+
+    wildlife.habitat_cells = patches.iter().map(|&(cells, _)| cells).sum();
+    wildlife.viable_patches = patches
+        .iter()
+        .filter(|&&(cells, _)| cells >= MIN_VIABLE_PATCH)
+        .count() as u32;
+    wildlife.fragments = patches.len() as u32 - wildlife.viable_patches;
+    wildlife.largest_patch = patches.iter().map(|&(cells, _)| cells).max().unwrap_or(0);
+    wildlife.carrying_capacity = patches
+        .iter()
+        .map(|&(cells, total)| patch_capacity(cells, total))
+        .sum();
+    wildlife.grow();
+}
+
+/// Every slow tick, wildlife around a park district draws extra visitors.
+pub fn wildlife_park_visits(
+    slow_timer: Res<SlowTickTimer>,
+    biodiversity: Res<BiodiversityGrid>,
+    mut parks: ResMut<ParkDistrictState>,
+) {
+    if !slow_timer.should_run() {
+        return;
+    }
+    for district in &mut parks.districts {
+        let x = district.center_x.min(GRID_WIDTH - 1);
+        let y = district.center_y.min(GRID_HEIGHT - 1);
+        let visitors = wildlife_park_visitors(biodiversity.get(x, y));
+        if visitors > 0 {
+            district.total_visitors = district.total_visitors.saturating_add(visitors);
+            district.recalculate_level();
+        }
+    }
+}
+
+pub struct WildlifePlugin;
+
+impl Plugin for WildlifePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BiodiversityGrid>()
+            .init_resource::<WildlifeState>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_wildlife
+                        .after(crate::wind_pollution::update_pollution_gaussian_plume)
+                        .after(crate::noise::update_noise_pollution),
+                    wildlife_park_visits.after(crate::park_districts::update_park_districts),
+                )
+                    .chain()
+                    .in_set(crate::SimulationSet::Simulation),
+            );
+
+        app.init_resource::<crate::SaveableRegistry>();
+        app.world_mut()
+            .resource_mut::<crate::SaveableRegistry>()
+            .register::<WildlifeState>();
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use crate::Saveable;
+
+#[test]
+fn test_developed_cells_are_not_habitat() {
+    assert_eq!(cell_habitat(0, 0, 0, 0, true), 0);
+}
+
+#[test]
+fn test_disturbance_lowers_habitat() {
+    let clean = cell_habitat(TREE_HABITAT, 0, 0, 0, false);
+    assert_eq!(clean, TREE_HABITAT);
+    assert!(cell_habitat(TREE_HABITAT, 100, 0, 0, false) < clean);
+    assert!(cell_habitat(TREE_HABITAT, 0, 50, 0, false) < clean);
+    assert!(cell_habitat(TREE_HABITAT, 0, 0, 5, false) < clean);
+    assert_eq!(cell_habitat(TREE_HABITAT, 255, 100, 25, false), 0);
+}
+
+#[test]
+fn test_reserve_adds_habitat_and_shields_disturbance() {
+    let open = cell_habitat(TREE_HABITAT, 80, 20, 4, false);
+    let reserve = cell_habitat(TREE_HABITAT, 80, 20, 4, true);
+    let loss = TREE_HABITAT as f32 - open as f32;
+    assert!(reserve as f32 >= TREE_HABITAT as f32 + RESERVE_HABITAT_BONUS as f32 - loss);
+    assert_eq!(
+        cell_habitat(OPEN_GRASS_HABITAT, 0, 0, 0, true),
+        OPEN_GRASS_HABITAT + RESERVE_HABITAT_BONUS
+    );
+}
+
+#[test]
+fn test_small_fragments_support_nothing() {
+    assert_eq!(patch_connectivity(MIN_VIABLE_PATCH - 1), 0.0);
+    assert!(patch_connectivity(MIN_VIABLE_PATCH) > 0.0);
+    assert!(patch_connectivity(500) > patch_connectivity(50));
+    assert!(patch_connectivity(10_000) < 1.0);
+}
+
+#[test]
+fn test_fragmentation_lowers_capacity() {
+    let whole = patch_capacity(100, 100 * 130);
+    let halves = 2.0 * patch_capacity(50, 50 * 130);
+    assert!(
+        whole > halves,
+        "one corridor {whole} vs two halves {halves}"
+    );
+}
+
+#[test]
+fn test_population_approaches_capacity() {
+    let mut wildlife = WildlifeState {
+        carrying_capacity: 100.0,
+        ..Default::default()
+    };
+    wildlife.grow();
+    assert!(wildlife.population > 0.0 && wildlife.population < 100.0);
+    for _ in 0..200 {
+        wildlife.grow();
+    }
+    assert!((wildlife.population - 100.0).abs() < 0.1);
+
+    wildlife.carrying_capacity = 0.0;
+    wildlife.grow();
+    assert!(
+        wildlife.population < 100.0,
+        "lost habitat shrinks the population"
+    );
+}
+
+#[test]
+fn test_biodiversity_and_tourism_scale_with_population() {
+    let mut wildlife = WildlifeState::default();
+    assert_eq!(wildlife.biodiversity(), 0.0);
+    assert_eq!(wildlife.tourism_points(), 0.0);
+    wildlife.population = BIODIVERSITY_HALF_POPULATION;
+    assert!((wildlife.biodiversity() - 50.0).abs() < 1e-3);
+    assert!((wildlife.tourism_points() - WILDLIFE_TOURISM_POINTS / 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_park_visitors_scale_with_biodiversity() {
+    assert_eq!(wildlife_park_visitors(0), 0);
+    assert_eq!(wildlife_park_visitors(255), WILDLIFE_PARK_VISITORS as u32);
+}
+
+#[test]
+fn test_saveable_roundtrip() {
+    assert!(WildlifeState::default().save_to_bytes().is_none());
+    let wildlife = WildlifeState {
+        population: 42.5,
+        carrying_capacity: 80.0,
+        habitat_cells: 120,
+        viable_patches: 2,
+        fragments: 3,
+        largest_patch: 90,
+    };
+    let bytes = wildlife.save_to_bytes().expect("non-default state saves");
+    assert_eq!(WildlifeState::load_from_bytes(&bytes), wildlife);
+}
@@ -0,0 +1,199 @@
+//! Habitat quality, the biodiversity grid and the city's wildlife.
+
+use bevy::prelude::*;
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::Saveable;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Habitat value of open, undeveloped grass.
+pub const OPEN_GRASS_HABITAT: u8 = 50;
+
+/// Habitat value of a cell with a tree.
+pub const TREE_HABITAT: u8 = 130;
+
+/// Habitat value of river and lake cells.
+pub const WATER_HABITAT: u8 = 110;
+
+/// Habitat value of cells in and around a park.
+pub const PARK_HABITAT: u8 = 150;
+
+/// Cells around a small park that count as parkland.
+pub const SMALL_PARK_HABITAT_RADIUS: i32 = 2;
+
+/// Cells around a large park that count as parkland.
+pub const LARGE_PARK_HABITAT_RADIUS: i32 = 4;
+
+/// Extra habitat value inside a nature reserve.
+pub const RESERVE_HABITAT_BONUS: u8 = 60;
+
+/// Habitat lost per unit of air pollution.
+pub const POLLUTION_HABITAT_LOSS: f32 = 0.5;
+
+/// Habitat lost per unit of noise.
+pub const NOISE_HABITAT_LOSS: f32 = 0.8;
+
+/// Distance in cells over which buildings and streetlights light up a cell.
+pub const LIGHT_RADIUS: i32 = 2;
+
+/// Habitat lost per lit cell (building or road) within `LIGHT_RADIUS`.
+pub const LIGHT_HABITAT_LOSS: f32 = 6.0;
+
+/// Share of pollution, noise and light losses a nature reserve keeps out.
+pub const RESERVE_PROTECTION: f32 = 0.5;
+
+/// Habitat value a cell needs to be part of a green corridor.
+pub const MIN_HABITAT: u8 = 60;
+
+/// Smallest corridor that can sustain a wildlife population.
+pub const MIN_VIABLE_PATCH: u32 = 9;
+
+/// Corridor size at which wildlife reaches half its potential density;
+/// many small corridors support far less than one large one.
+pub const PATCH_HALF_SIZE: f32 = 50.0;
+
+/// Animals a cell of perfect habitat supports in a large corridor.
+pub const ANIMALS_PER_CELL: f32 = 2.0;
+
+/// Share of the gap to the carrying capacity closed each slow tick.
+pub const WILDLIFE_GROWTH_RATE: f32 = 0.1;
+
+/// Wildlife population at which biodiversity reaches 50.
+pub const BIODIVERSITY_HALF_POPULATION: f32 = 500.0;
+
+/// Raw natural beauty points for tourism at full biodiversity.
+pub const WILDLIFE_TOURISM_POINTS: f32 = 30.0;
+
+/// Extra park visitors per slow tick at a park district with perfect habitat.
+pub const WILDLIFE_PARK_VISITORS: f32 = 10.0;
+
+/// Cost of designating a nature reserve.
+pub const NATURE_RESERVE_COST: f64 = 3_000.0;
+
+// ---------------------------------------------------------------------------
+// Habitat model
+// ---------------------------------------------------------------------------
+
+/// Habitat value of a cell: its `base` cover less what pollution, noise and
+/// nearby lights take away. A nature reserve adds habitat and keeps part of
+/// the disturbance out.
+pub fn cell_habitat(base: u8, pollution: u8, noise: u8, lit_cells: u32, reserve: bool) -> u8 {
+    if base == 0 {
+        return 0;
+    }
+    let mut loss = pollution as f32 * POLLUTION_HABITAT_LOSS
+        + noise as f32 * NOISE_HABITAT_LOSS
+        + lit_cells as f32 * LIGHT_HABITAT_LOSS;
+    let mut value = base as f32;
+    if reserve {
+        loss *= 1.0 - RESERVE_PROTECTION;
+        value += RESERVE_HABITAT_BONUS as f32;
+    }
+    (value - loss).clamp(0.0, 255.0) as u8
+}
+
+/// How well a corridor of `cells` supports wildlife, 0-1. Corridors below
+/// the viable size support none.
+pub fn patch_connectivity(cells: u32) -> f32 {
+    if cells < MIN_VIABLE_PATCH {
+        return 0.0;
+    }
+    cells as f32 / (cells as f32 + PATCH_HALF_SIZE)
+}
+
+/// Animals a corridor supports given its size and total habitat value.
+pub fn patch_capacity(cells: u32, total_habitat: u32) -> f32 {
+    total_habitat as f32 / 255.0 * ANIMALS_PER_CELL * patch_connectivity(cells)
+}
+
+// ---------------------------------------------------------------------------
+// Biodiversity grid
+// ---------------------------------------------------------------------------
+
+/// Per-cell biodiversity: habitat value scaled by how well connected the
+/// corridor it belongs to is. Isolated fragments show as zero.
+#[derive(Resource)]
+pub struct BiodiversityGrid {
+    pub levels: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for BiodiversityGrid {
+    fn default() -> Self {
+        Self {
+            levels: vec![0; GRID_WIDTH * GRID_HEIGHT],
+            width: GRID_WIDTH,
+            height: GRID_HEIGHT,
+        }
+    }
+}
+
+impl BiodiversityGrid {
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.levels[y * self.width + x]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Wildlife state
+// ---------------------------------------------------------------------------
+
+/// City-wide wildlife population and the corridors that support it.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct WildlifeState {
+    pub population: f32,
+    /// Animals the city's corridors can support.
+    pub carrying_capacity: f32,
+    /// Cells good enough to be part of a corridor.
+    pub habitat_cells: u32,
+    /// Corridors large enough to sustain wildlife.
+    pub viable_patches: u32,
+    /// Corridors too small to sustain wildlife.
+    pub fragments: u32,
+    pub largest_patch: u32,
+}
+
+impl WildlifeState {
+    /// Move the population toward the carrying capacity.
+    pub fn grow(&mut self) {
+        self.population += (self.carrying_capacity - self.population) * WILDLIFE_GROWTH_RATE;
+        self.population = self.population.max(0.0);
+    }
+
+    /// Biodiversity index, 0-100.
+    pub fn biodiversity(&self) -> f32 {
+        100.0 * self.population / (self.population + BIODIVERSITY_HALF_POPULATION)
+    }
+
+    /// Raw natural beauty points wildlife adds to tourism.
+    pub fn tourism_points(&self) -> f32 {
+        self.biodiversity() / 100.0 * WILDLIFE_TOURISM_POINTS
+    }
+}
+
+/// Extra visitors a park district draws each slow tick from the wildlife
+/// living around it.
+pub fn wildlife_park_visitors(biodiversity: u8) -> u32 {
+    (biodiversity as f32 / 255.0 * WILDLIFE_PARK_VISITORS).round() as u32
+}
+
+impl Saveable for WildlifeState {
+    const SAVE_KEY: &'static str = "wildlife";
+
+    fn save_to_bytes(&self) -> Option<Vec<u8>> {
+        if *self == Self::default() {
+            return None;
+        }
+        Some(bitcode::encode(self))
+    }
+
+    fn load_from_bytes(bytes: &[u8]) -> Self {
+        crate::decode_or_warn(Self::SAVE_KEY, bytes)
+    }
+}
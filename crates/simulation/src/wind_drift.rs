@@ -29,6 +29,23 @@ pub fn apply_wind_drift(pollution: &mut PollutionGrid, wind: &WindState) {
         return;
     }
 
+    let levels: Vec<f32> = pollution.levels.iter().map(|&v| v as f32).collect();
+    let result = drift_field(&levels, pollution.width, pollution.height, wind);
+
+    // Write back, clamping to u8 range
+    for (level, &val) in pollution.levels.iter_mut().zip(result.iter()) {
+        *level = val.clamp(0.0, 255.0) as u8;
+    }
+}
+
+/// Shifts a floating-point field laid out like the pollution grid in the
+/// wind direction, with the same bilinear "pull" as [`apply_wind_drift`].
+/// Calm wind returns the field unchanged.
+pub fn drift_field(levels: &[f32], w: usize, h: usize, wind: &WindState) -> Vec<f32> {
+    if wind.speed < CALM_THRESHOLD {
+        return levels.to_vec();
+    }
+
     let (dx, dy) = wind.direction_vector();
     let drift = wind.speed * DRIFT_RATE;
 
@@ -36,14 +53,10 @@ pub fn apply_wind_drift(pollution: &mut PollutionGrid, wind: &WindState) {
     let shift_x = dx * drift;
     let shift_y = dy * drift;
 
-    let w = pollution.width;
-    let h = pollution.height;
-    let total = w * h;
-
-    // Temporary buffer to build the new shifted pollution grid.
+    // Temporary buffer to build the new shifted field.
     // For each destination cell (x, y), we sample the source at (x - shift_x, y - shift_y)
     // using bilinear interpolation.
-    let mut result: Vec<f32> = vec![0.0; total];
+    let mut result: Vec<f32> = vec![0.0; w * h];
 
     for y in 0..h {
         for x in 0..w {
@@ -68,28 +81,25 @@ pub fn apply_wind_drift(pollution: &mut PollutionGrid, wind: &WindState) {
             let w11 = fx * fy;
 
             // Sample source cells (out-of-bounds = 0, boundary drain)
-            let s00 = sample_grid(&pollution.levels, w, h, x0, y0);
-            let s10 = sample_grid(&pollution.levels, w, h, x1, y0);
-            let s01 = sample_grid(&pollution.levels, w, h, x0, y1);
-            let s11 = sample_grid(&pollution.levels, w, h, x1, y1);
+            let s00 = sample_grid(levels, w, h, x0, y0);
+            let s10 = sample_grid(levels, w, h, x1, y0);
+            let s01 = sample_grid(levels, w, h, x0, y1);
+            let s11 = sample_grid(levels, w, h, x1, y1);
 
             let val = w00 * s00 + w10 * s10 + w01 * s01 + w11 * s11;
             result[y * w + x] = val;
         }
     }
 
-    // Write back, clamping to u8 range
-    for (level, &val) in pollution.levels.iter_mut().zip(result.iter()).take(total) {
-        *level = val.clamp(0.0, 255.0) as u8;
-    }
+    result
 }
 
-/// Samples the pollution grid at integer coordinates, returning 0.0 for
-/// out-of-bounds positions (boundary drain).
+/// Samples a field at integer coordinates, returning 0.0 for out-of-bounds
+/// positions (boundary drain).
 #[inline]
-fn sample_grid(levels: &[u8], width: usize, height: usize, x: i32, y: i32) -> f32 {
+fn sample_grid(levels: &[f32], width: usize, height: usize, x: i32, y: i32) -> f32 {
     if x >= 0 && (x as usize) < width && y >= 0 && (y as usize) < height {
-        levels[y as usize * width + x as usize] as f32
+        levels[y as usize * width + x as usize]
     } else {
         0.0
     }
@@ -98,12 +108,13 @@ fn sample_grid(levels: &[u8], width: usize, height: usize, x: i32, y: i32) -> f3
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chunked_grid::ChunkedGrid;
     use crate::wind::WindState;
 
     /// Helper to create a small test pollution grid.
     fn make_grid(width: usize, height: usize) -> PollutionGrid {
         PollutionGrid {
-            levels: vec![0; width * height],
+            levels: ChunkedGrid::new(width, height, 0),
             width,
             height,
         }
@@ -282,7 +293,7 @@ mod tests {
 
     #[test]
     fn test_sample_grid_out_of_bounds() {
-        let levels = vec![100u8; 16];
+        let levels = vec![100.0f32; 16];
         assert_eq!(sample_grid(&levels, 4, 4, -1, 0), 0.0);
         assert_eq!(sample_grid(&levels, 4, 4, 0, -1), 0.0);
         assert_eq!(sample_grid(&levels, 4, 4, 4, 0), 0.0);
@@ -291,7 +302,7 @@ mod tests {
 
     #[test]
     fn test_sample_grid_in_bounds() {
-        let levels = vec![42u8; 16];
+        let levels = vec![42.0f32; 16];
         assert_eq!(sample_grid(&levels, 4, 4, 0, 0), 42.0);
         assert_eq!(sample_grid(&levels, 4, 4, 3, 3), 42.0);
     }
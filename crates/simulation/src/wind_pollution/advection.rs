@@ -0,0 +1,45 @@
+//! Advection of the lingering air mass and rain washout.
+//!
+//! Pollution no longer vanishes between updates: a share of each update's
+//! concentration stays in the air, drifts downwind with the wind and mixes
+//! with the next update's fresh emissions. Rain washes pollutants out of
+//! the air.
+
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::wind::WindState;
+use crate::wind_drift::drift_field;
+
+/// Share of the air mass that lingers into the next update.
+pub const AIR_RETENTION: f32 = 0.35;
+
+/// Share of airborne pollution washed out per inch/hour of rain.
+pub const RAIN_WASHOUT_PER_INCH: f32 = 0.6;
+
+/// Most of the airborne pollution rain can wash out in one update.
+pub const MAX_RAIN_WASHOUT: f32 = 0.6;
+
+/// Share of airborne pollution washed out by rain of the given intensity
+/// (inches per hour).
+pub fn rain_washout(precipitation_intensity: f32) -> f32 {
+    (precipitation_intensity.max(0.0) * RAIN_WASHOUT_PER_INCH).min(MAX_RAIN_WASHOUT)
+}
+
+/// Carry the lingering share of the last update's pollution downwind.
+pub fn carried_air<'a>(levels: impl IntoIterator<Item = &'a u8>, wind: &WindState) -> Vec<f32> {
+    let levels: Vec<f32> = levels.into_iter().map(|&v| v as f32).collect();
+    let mut carried = drift_field(&levels, GRID_WIDTH, GRID_HEIGHT, wind);
+    for v in &mut carried {
+        *v *= AIR_RETENTION;
+    }
+    carried
+}
+
+/// Wash the given share of pollution out of `levels`.
+pub fn wash_out(levels: &mut [f32], washout: f32) {
+    if washout <= 0.0 {
+        return;
+    }
+    for v in levels {
+        *v *= 1.0 - washout;
+    }
+}
@@ -4,13 +4,22 @@
 //! model. Pollution from each source spreads downwind in a cone pattern, with
 //! concentration following a simplified Gaussian distribution in the crosswind
 //! direction. Wind direction from [`WindState`] determines dispersion direction.
+//!
+//! Part of each update's air lingers into the next one, drifting downwind, so
+//! plumes from industry and busy roads stretch further than a single update's
+//! reach and stagnant air builds up when the wind drops. Rain washes
+//! pollutants out of the air.
 
+mod advection;
 mod config;
 mod dispersion;
 mod system;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_advection;
 
+pub use advection::{rain_washout, AIR_RETENTION, MAX_RAIN_WASHOUT, RAIN_WASHOUT_PER_INCH};
 pub use config::WindPollutionConfig;
 pub use system::update_pollution_gaussian_plume;
 
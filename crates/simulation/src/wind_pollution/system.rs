@@ -8,13 +8,16 @@ use crate::building_emissions::{
 use crate::buildings::Building;
 use crate::coal_power::{PowerPlant, PowerPlantType};
 use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::dirty_chunks::{DirtyChunkCursor, DirtyChunks};
 use crate::grid::{CellType, WorldGrid};
 use crate::pollution::PollutionGrid;
 use crate::services::ServiceBuilding;
 use crate::traffic::TrafficGrid;
+use crate::weather::Weather;
 use crate::wind::WindState;
 use crate::SlowTickTimer;
 
+use super::advection::{carried_air, rain_washout, wash_out};
 use super::config::WindPollutionConfig;
 use super::dispersion::{apply_isotropic_source, apply_plume_source, PollutionSource};
 
@@ -52,11 +55,53 @@ const PARK_RADIUS: i32 = 6;
 // Source collection
 // =============================================================================
 
+/// Road cells per `WorldGrid` chunk. Only chunks whose layout changed are
+/// rescanned, instead of walking the whole grid every slow tick.
+#[derive(Default)]
+pub struct RoadCellCache {
+    cursor: DirtyChunkCursor,
+    per_chunk: Vec<Vec<(usize, usize)>>,
+    /// Every road cell in row-major order, so sources are applied in the
+    /// same order as a full grid scan.
+    cells: Vec<(usize, usize)>,
+}
+
+impl RoadCellCache {
+    /// Bring the cache up to date with `grid`.
+    pub fn refresh(&mut self, dirty: &DirtyChunks, grid: &WorldGrid) {
+        let region = dirty.take(&mut self.cursor);
+        let chunk_count = grid.cells.chunk_count();
+        let rescan_all = region.is_full()
+            || region.chunk_count() != chunk_count
+            || self.per_chunk.len() != chunk_count;
+        if !rescan_all && region.is_empty() {
+            return;
+        }
+        self.per_chunk.resize(chunk_count, Vec::new());
+        for ci in 0..chunk_count {
+            if rescan_all || region.contains_chunk(ci) {
+                self.per_chunk[ci] = grid
+                    .cells
+                    .chunk_cells(ci)
+                    .filter(|(_, _, cell)| cell.cell_type == CellType::Road)
+                    .map(|(x, y, _)| (x, y))
+                    .collect();
+            }
+        }
+        self.cells = self.per_chunk.iter().flatten().copied().collect();
+        self.cells.sort_unstable_by_key(|&(x, y)| (y, x));
+    }
+
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+}
+
 /// Collects all pollution sources from the world, using per-building-type
 /// emission profiles from `building_emissions`.
 #[allow(clippy::too_many_arguments)]
 fn collect_sources(
-    grid: &WorldGrid,
+    road_cells: &[(usize, usize)],
     buildings: &Query<&Building>,
     power_plants: &Query<&PowerPlant>,
     services: &Query<&ServiceBuilding>,
@@ -67,18 +112,14 @@ fn collect_sources(
     let mut sources = Vec::new();
 
     // Roads: traffic-scaled emissions (POLL-002)
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            if grid.get(x, y).cell_type == CellType::Road {
-                let congestion = traffic.congestion_level(x, y);
-                let q = road_emission_q(congestion) * scrubber_mult;
-                sources.push(PollutionSource {
-                    x,
-                    y,
-                    emission_q: q,
-                });
-            }
-        }
+    for &(x, y) in road_cells {
+        let congestion = traffic.congestion_level(x, y);
+        let q = road_emission_q(congestion) * scrubber_mult;
+        sources.push(PollutionSource {
+            x,
+            y,
+            emission_q: q,
+        });
     }
 
     // Zoned buildings: per-type emission profiles (POLL-002)
@@ -137,11 +178,12 @@ fn collect_sources(
 /// Wind-aware Gaussian plume pollution dispersion system.
 ///
 /// Replaces the old isotropic diffusion. Each tick:
-/// 1. Clear the pollution grid
+/// 1. Carry the lingering air mass downwind
 /// 2. Collect all pollution sources (buildings, services, power plants, roads)
 /// 3. For each source, apply Gaussian plume dispersion in the wind direction
-/// 4. Apply park reduction
+/// 4. Wash pollution out with rain
 /// 5. Clamp values to u8 range
+/// 6. Apply park reduction
 #[allow(clippy::too_many_arguments)]
 pub fn update_pollution_gaussian_plume(
     slow_timer: Res<SlowTickTimer>,
@@ -154,13 +196,16 @@ pub fn update_pollution_gaussian_plume(
     wind: Res<WindState>,
     config: Res<WindPollutionConfig>,
     traffic: Res<TrafficGrid>,
+    weather: Res<Weather>,
+    dirty_chunks: Res<DirtyChunks>,
+    mut road_cells: Local<RoadCellCache>,
 ) {
     if !slow_timer.should_run() {
         return;
     }
 
-    // Clear grid
-    pollution.levels.fill(0);
+    // Part of the last update's pollution lingers and drifts downwind
+    let mut float_levels = carried_air(&pollution.levels, &wind);
 
     // Compute multipliers
     let scrubber_mult = if config.scrubbers_enabled {
@@ -170,8 +215,9 @@ pub fn update_pollution_gaussian_plume(
     };
 
     // Collect sources (POLL-002: per-building-type profiles)
+    road_cells.refresh(&dirty_chunks, &grid);
     let sources = collect_sources(
-        &grid,
+        road_cells.cells(),
         &buildings,
         &power_plants,
         &services,
@@ -180,10 +226,6 @@ pub fn update_pollution_gaussian_plume(
         scrubber_mult,
     );
 
-    // Floating-point accumulator for precision
-    let total_cells = GRID_WIDTH * GRID_HEIGHT;
-    let mut float_levels = vec![0.0f32; total_cells];
-
     let (wind_dx, wind_dy) = wind.direction_vector();
     let is_calm = wind.speed < CALM_WIND_THRESHOLD;
 
@@ -196,17 +238,26 @@ pub fn update_pollution_gaussian_plume(
         }
     }
 
-    // Write to pollution grid, clamping to u8
-    for (level, &val) in pollution.levels.iter_mut().zip(float_levels.iter()) {
-        *level = val.clamp(0.0, 255.0) as u8;
-    }
+    // Rain washes pollutants out of the air
+    wash_out(&mut float_levels, rain_washout(weather.precipitation_intensity));
+
+    // Clamp to u8
+    let mut levels: Vec<u8> = float_levels
+        .iter()
+        .map(|&val| val.clamp(0.0, 255.0) as u8)
+        .collect();
 
     // Parks reduce pollution
-    apply_park_reduction(&mut pollution, &services);
+    apply_park_reduction(&mut levels, &services);
+
+    // Only chunks whose pollution moved get a new revision, which is what
+    // land value watches to skip settled chunks.
+    pollution.levels.assign_row_major(&levels);
 }
 
-/// Applies park pollution reduction around park service buildings.
-fn apply_park_reduction(pollution: &mut PollutionGrid, services: &Query<&ServiceBuilding>) {
+/// Applies park pollution reduction around park service buildings to
+/// row-major `levels`.
+fn apply_park_reduction(levels: &mut [u8], services: &Query<&ServiceBuilding>) {
     for service in services {
         if ServiceBuilding::is_park(service.service_type) {
             let radius = PARK_RADIUS;
@@ -222,8 +273,8 @@ fn apply_park_reduction(pollution: &mut PollutionGrid, services: &Query<&Service
                     {
                         let dist = dx.abs() + dy.abs();
                         let effect = reduction.saturating_sub(dist as u8);
-                        let cur = pollution.get(nx as usize, ny as usize);
-                        pollution.set(nx as usize, ny as usize, cur.saturating_sub(effect));
+                        let idx = ny as usize * GRID_WIDTH + nx as usize;
+                        levels[idx] = levels[idx].saturating_sub(effect);
                     }
                 }
             }
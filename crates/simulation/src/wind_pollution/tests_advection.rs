@@ -0,0 +1,53 @@
+//! Unit tests for pollution carried downwind and washed out by rain.
+
+use super::advection::{carried_air, rain_washout, wash_out, AIR_RETENTION, MAX_RAIN_WASHOUT};
+use crate::config::{GRID_HEIGHT, GRID_WIDTH};
+use crate::wind::WindState;
+
+#[test]
+fn test_rain_washout_scales_and_caps() {
+    assert_eq!(rain_washout(0.0), 0.0);
+    assert!(rain_washout(0.1) > 0.0);
+    assert!(rain_washout(0.5) > rain_washout(0.1));
+    assert_eq!(rain_washout(10.0), MAX_RAIN_WASHOUT);
+}
+
+#[test]
+fn test_wash_out_removes_share() {
+    let mut levels = vec![100.0, 50.0, 0.0];
+    wash_out(&mut levels, 0.5);
+    assert_eq!(levels, vec![50.0, 25.0, 0.0]);
+    wash_out(&mut levels, 0.0);
+    assert_eq!(levels, vec![50.0, 25.0, 0.0]);
+}
+
+#[test]
+fn test_carried_air_lingers_in_calm() {
+    let mut levels = vec![0u8; GRID_WIDTH * GRID_HEIGHT];
+    levels[100 * GRID_WIDTH + 100] = 100;
+    let calm = WindState {
+        speed: 0.0,
+        ..Default::default()
+    };
+    let carried = carried_air(&levels, &calm);
+    let expected = 100.0 * AIR_RETENTION;
+    assert!((carried[100 * GRID_WIDTH + 100] - expected).abs() < 1e-3);
+}
+
+#[test]
+fn test_carried_air_drifts_downwind() {
+    let mut levels = vec![0u8; GRID_WIDTH * GRID_HEIGHT];
+    levels[100 * GRID_WIDTH + 100] = 200;
+    let east = WindState {
+        direction: 0.0,
+        speed: 1.0,
+        ..Default::default()
+    };
+    let carried = carried_air(&levels, &east);
+    let downwind = carried[100 * GRID_WIDTH + 101] + carried[100 * GRID_WIDTH + 102];
+    let upwind = carried[100 * GRID_WIDTH + 99] + carried[100 * GRID_WIDTH + 98];
+    assert!(downwind > 0.0);
+    assert_eq!(upwind, 0.0);
+    let total: f32 = carried.iter().sum();
+    assert!(total <= 200.0 * AIR_RETENTION + 1e-3);
+}
@@ -19,6 +19,7 @@ pub fn update_zone_demand(
     mixed_use_buildings: Query<&MixedUseBuilding>,
     mut demand: ResMut<ZoneDemand>,
     game_params: Res<GameParams>,
+    settings: Res<crate::game_settings::GameSettings>,
 ) {
     if !slow_tick.should_run() {
         return;
@@ -36,6 +37,15 @@ pub fn update_zone_demand(
     let zdp = &game_params.zone_demand;
     let (r_target, c_target, i_target, o_target) = compute_market_demand_with_params(&zs, zdp);
 
+    // Difficulty scales how strongly demand responds to the market.
+    let elasticity = settings.difficulty.demand_elasticity;
+    let (r_target, c_target, i_target, o_target) = (
+        r_target * elasticity,
+        c_target * elasticity,
+        i_target * elasticity,
+        o_target * elasticity,
+    );
+
     // Apply damping: smoothly interpolate toward target to avoid oscillation.
     let damping = zdp.damping;
     demand.residential += (r_target - demand.residential) * damping;
@@ -0,0 +1,222 @@
+//! UI side of the keyboard and screen-reader accessibility mode.
+//!
+//! While `AccessibilitySettings::enabled` is on:
+//! - the focused widget gets a thick, high-contrast outline, and keys pressed
+//!   while a widget has focus go to egui only, so Tab / Shift+Tab, the arrow
+//!   keys, Space and Enter can drive every panel without also moving the
+//!   camera or triggering shortcuts (Escape hands the keyboard back);
+//! - Tab is reserved for moving focus instead of cycling overlays;
+//! - color-coded bars are followed by a text rating (see [`rating_text`]);
+//! - the "Accessible Log" window (Shift+F10) shows critical notifications as
+//!   selectable plain text.
+//!
+//! Leaf drawing helpers that have no access to resources call [`enabled`],
+//! which reads a flag this module publishes into egui's memory every frame.
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use simulation::accessibility::{AccessibilitySettings, AccessibleLog};
+use simulation::keybindings::KeyBindings;
+
+use crate::theme;
+
+/// Outline drawn around the focused widget in accessibility mode.
+const FOCUS_STROKE: egui::Stroke = egui::Stroke {
+    width: 3.0,
+    color: theme::WARNING,
+};
+
+/// Whether the accessible log window is open.
+#[derive(Resource, Default)]
+pub struct AccessibleLogVisible(pub bool);
+
+fn flag_id() -> egui::Id {
+    egui::Id::new("accessibility_mode_enabled")
+}
+
+/// Whether accessibility mode is on, for drawing code without access to
+/// `AccessibilitySettings`.
+pub fn enabled(ctx: &egui::Context) -> bool {
+    ctx.data(|d| d.get_temp::<bool>(flag_id())).unwrap_or(false)
+}
+
+/// Words for a 0..1 value where higher is better, matching the
+/// green / yellow / red thresholds the panels color with.
+pub fn rating_text(value: f32) -> &'static str {
+    if value >= 0.7 {
+        "good"
+    } else if value >= 0.4 {
+        "fair"
+    } else {
+        "poor"
+    }
+}
+
+/// Words for a 0..1 utilization where higher is worse.
+pub fn load_text(value: f32) -> &'static str {
+    if value > 0.8 {
+        "high load"
+    } else if value > 0.5 {
+        "moderate load"
+    } else {
+        "low load"
+    }
+}
+
+/// Signed text for an RCI demand value, where 0.5 is balanced
+/// (e.g. 0.7 -> "+40%", 0.4 -> "-20%").
+pub fn demand_text(value: f32) -> String {
+    let signed = ((value.clamp(0.0, 1.0) - 0.5) * 200.0).round() as i32;
+    if signed > 0 {
+        format!("+{signed}%")
+    } else {
+        format!("{signed}%")
+    }
+}
+
+/// System: publish the mode for [`enabled`] and swap the focus outline.
+fn apply_accessibility_style(mut contexts: EguiContexts, settings: Res<AccessibilitySettings>) {
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    ctx.data_mut(|d| d.insert_temp(flag_id(), settings.enabled));
+
+    let (active, selection) = if settings.enabled {
+        (FOCUS_STROKE, FOCUS_STROKE)
+    } else {
+        // The theme's own strokes, see `theme::setup_megacity_theme`.
+        (
+            egui::Stroke::new(1.5, theme::PRIMARY),
+            egui::Stroke::new(1.0, theme::PRIMARY),
+        )
+    };
+    let style = ctx.style();
+    if style.visuals.widgets.active.bg_stroke != active
+        || style.visuals.selection.stroke != selection
+    {
+        ctx.style_mut(|style| {
+            style.visuals.widgets.active.bg_stroke = active;
+            style.visuals.selection.stroke = selection;
+        });
+    }
+}
+
+/// System: keep keys meant for UI navigation away from the game's bindings.
+fn reserve_navigation_keys(
+    mut contexts: EguiContexts,
+    settings: Res<AccessibilitySettings>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    if ctx.memory(|m| m.focused().is_some()) {
+        keyboard.reset_all();
+    } else {
+        keyboard.reset(KeyCode::Tab);
+    }
+}
+
+/// System: toggle the accessible log with the configured key.
+fn accessible_log_keybind(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut visible: ResMut<AccessibleLogVisible>,
+    mut contexts: EguiContexts,
+) {
+    if contexts.ctx_mut().wants_keyboard_input() {
+        return;
+    }
+    if bindings.toggle_accessible_log.just_pressed(&keyboard) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// System: render the accessible log as read-only, selectable text.
+fn accessible_log_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<AccessibleLogVisible>,
+    log: Res<AccessibleLog>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Accessible Log")
+        .open(&mut open)
+        .default_width(420.0)
+        .default_height(260.0)
+        .show(contexts.ctx_mut(), |ui| {
+            if log.lines.is_empty() {
+                ui.label("No critical notifications yet.");
+                return;
+            }
+            let text = log.text();
+            egui::ScrollArea::vertical()
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut text.as_str())
+                            .desired_width(f32::INFINITY)
+                            .font(egui::TextStyle::Monospace),
+                    );
+                });
+        });
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct AccessibilityUiPlugin;
+
+impl Plugin for AccessibilityUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AccessibleLogVisible>()
+            .add_systems(PreUpdate, reserve_navigation_keys.after(InputSystem))
+            .add_systems(
+                Update,
+                (
+                    apply_accessibility_style,
+                    accessible_log_keybind,
+                    accessible_log_ui,
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demand_text_is_signed_around_balance() {
+        assert_eq!(demand_text(0.7), "+40%");
+        assert_eq!(demand_text(0.4), "-20%");
+        assert_eq!(demand_text(0.5), "0%");
+        assert_eq!(demand_text(2.0), "+100%");
+    }
+
+    #[test]
+    fn test_rating_and_load_text() {
+        assert_eq!(rating_text(0.9), "good");
+        assert_eq!(rating_text(0.5), "fair");
+        assert_eq!(rating_text(0.1), "poor");
+        assert_eq!(load_text(0.9), "high load");
+        assert_eq!(load_text(0.6), "moderate load");
+        assert_eq!(load_text(0.2), "low load");
+    }
+
+    #[test]
+    fn test_enabled_reads_published_flag() {
+        let ctx = egui::Context::default();
+        assert!(!enabled(&ctx));
+        ctx.data_mut(|d| d.insert_temp(flag_id(), true));
+        assert!(enabled(&ctx));
+    }
+}
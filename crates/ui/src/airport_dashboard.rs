@@ -0,0 +1,220 @@
+//! Airport dashboard.
+//!
+//! Shows the day's flight board and, for every airport, its runways, gates
+//! and cargo terminals against the flights they carry, and lets the player
+//! pay to expand them. Opens from the Transport toolbar category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::airport::{
+    AirportExpansion, AirportExpansionRequest, AirportOperations, AirportStats, FlightBoard,
+    FlightKind, FlightStatus,
+};
+use simulation::economy::CityBudget;
+use simulation::time_of_day::GameClock;
+
+const COLOR_GOOD: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const COLOR_WARN: egui::Color32 = egui::Color32::from_rgb(230, 160, 60);
+const COLOR_BAD: egui::Color32 = egui::Color32::from_rgb(220, 60, 60);
+const COLOR_DONE: egui::Color32 = egui::Color32::from_rgb(150, 150, 150);
+
+/// Flights shown on the board, starting from the most recent ones.
+const BOARD_ROWS: usize = 40;
+
+/// Whether the airport dashboard is visible.
+#[derive(Resource, Default)]
+pub struct AirportDashboardVisible(pub bool);
+
+fn status_color(status: FlightStatus) -> egui::Color32 {
+    match status {
+        FlightStatus::Scheduled | FlightStatus::Boarding => COLOR_GOOD,
+        FlightStatus::Delayed => COLOR_WARN,
+        FlightStatus::Cancelled => COLOR_BAD,
+        FlightStatus::Departed | FlightStatus::Landed => COLOR_DONE,
+    }
+}
+
+fn format_minute(minute: u16) -> String {
+    format!("{:02}:{:02}", minute / 60, minute % 60)
+}
+
+/// Renders the airport dashboard window.
+#[allow(clippy::too_many_arguments)]
+pub fn airport_dashboard_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<AirportDashboardVisible>,
+    operations: Res<AirportOperations>,
+    stats: Res<AirportStats>,
+    board: Res<FlightBoard>,
+    clock: Res<GameClock>,
+    budget: Res<CityBudget>,
+    mut expansions: EventWriter<AirportExpansionRequest>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Airports")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(contexts.ctx_mut(), |ui| {
+            if operations.airports.is_empty() {
+                ui.label("The city has no airports.");
+                return;
+            }
+
+            egui::Grid::new("airport_summary")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Passenger flights");
+                    ui.label(format!(
+                        "{} / {} a month",
+                        stats.passenger_flights_per_month, stats.passenger_capacity
+                    ));
+                    ui.end_row();
+                    ui.label("Cargo flights");
+                    ui.label(format!(
+                        "{} a month ({} freighters)",
+                        stats.cargo_flights_per_month, stats.freighter_flights_per_month
+                    ));
+                    ui.end_row();
+                    ui.label("Noise contours");
+                    let [loud, louder, loudest] = stats.noise_contour_cells;
+                    ui.label(format!(
+                        "{loud} / {louder} / {loudest} cells above 55 / 65 / 75 dB"
+                    ));
+                    ui.end_row();
+                });
+
+            ui.separator();
+            ui.heading("Facilities");
+            for airport in &operations.airports {
+                let (x, y) = airport.cell;
+                egui::CollapsingHeader::new(format!("{} ({x}, {y})", airport.tier.name()))
+                    .id_salt(("airport", x, y))
+                    .default_open(operations.airports.len() == 1)
+                    .show(ui, |ui| {
+                        egui::Grid::new(("airport_facilities", x, y))
+                            .num_columns(3)
+                            .show(ui, |ui| {
+                                for kind in AirportExpansion::ALL {
+                                    let max = kind.max(airport.tier);
+                                    ui.label(kind.name());
+                                    ui.label(format!("{} / {max}", airport.count(kind)));
+                                    if max > 0 {
+                                        let cost = kind.cost();
+                                        let enabled =
+                                            airport.can_expand(kind) && budget.treasury >= cost;
+                                        let button = ui.add_enabled(
+                                            enabled,
+                                            egui::Button::new(format!("Add (${cost:.0})")),
+                                        );
+                                        if button.clicked() {
+                                            expansions.send(AirportExpansionRequest {
+                                                airport: airport.cell,
+                                                kind,
+                                            });
+                                        }
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                        ui.small(format!(
+                            "{} / {} passenger flights (runways {}, gates {}), \
+                             {} / {} freighters a month",
+                            airport.passenger_flights,
+                            airport.passenger_capacity(),
+                            airport.runway_capacity(),
+                            airport.gate_capacity(),
+                            airport.freighter_flights,
+                            airport.freighter_capacity(),
+                        ));
+                    });
+            }
+
+            ui.separator();
+            ui.heading(format!("Flight board, day {}", board.day));
+            if board.flights.is_empty() {
+                ui.label("No flights today.");
+            } else {
+                let minute = (clock.hour * 60.0) as u16;
+                let first = board
+                    .flights
+                    .iter()
+                    .position(|f| f.time() + 30 >= minute)
+                    .unwrap_or(board.flights.len());
+                egui::ScrollArea::vertical()
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("flight_board")
+                            .num_columns(6)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.strong("Time");
+                                ui.strong("Flight");
+                                ui.strong("From / To");
+                                ui.strong("Rwy");
+                                ui.strong("Gate");
+                                ui.strong("Status");
+                                ui.end_row();
+                                for flight in board.flights.iter().skip(first).take(BOARD_ROWS) {
+                                    let prefix = if flight.cargo { "CG" } else { "MC" };
+                                    let direction = match flight.kind {
+                                        FlightKind::Arrival => "from",
+                                        FlightKind::Departure => "to",
+                                    };
+                                    let status = flight.status(minute);
+                                    ui.label(format_minute(flight.time()));
+                                    ui.label(format!("{prefix}{}", flight.number));
+                                    ui.label(format!("{direction} {}", flight.city));
+                                    if flight.cancelled {
+                                        ui.label("-");
+                                        ui.label("-");
+                                    } else {
+                                        ui.label(format!("{}", flight.runway));
+                                        let stand = if flight.cargo { "C" } else { "" };
+                                        ui.label(format!("{stand}{}", flight.stand));
+                                    }
+                                    let text = if status == FlightStatus::Delayed {
+                                        format!("Delayed {} min", flight.delay)
+                                    } else {
+                                        status.label().to_string()
+                                    };
+                                    ui.colored_label(status_color(status), text);
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                ui.small(format!(
+                    "{} flights today, {} delayed, {} cancelled",
+                    board.flights.len(),
+                    board.delayed(),
+                    board.cancelled()
+                ));
+            }
+
+            ui.separator();
+            ui.small(format!(
+                "Spent on airport expansions so far: ${:.0}",
+                operations.expansion_spent
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct AirportDashboardPlugin;
+
+impl Plugin for AirportDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AirportDashboardVisible>().add_systems(
+            Update,
+            airport_dashboard_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
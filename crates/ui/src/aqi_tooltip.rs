@@ -5,6 +5,7 @@
 //! - AQI numeric value
 //! - Tier name and color
 //! - Health advisory text
+//! - The city-wide AQI where residents live, and any smog alert
 //!
 //! This is a standalone system that renders its own tooltip panel,
 //! positioned below the main cell tooltip.
@@ -16,6 +17,7 @@ use bevy_egui::{egui, EguiContexts};
 use rendering::aqi_colors;
 use rendering::input::CursorGridPos;
 use rendering::overlay::{OverlayMode, OverlayState};
+use simulation::air_quality::AirQuality;
 use simulation::config::{GRID_HEIGHT, GRID_WIDTH};
 use simulation::pollution::PollutionGrid;
 
@@ -42,6 +44,7 @@ fn aqi_tooltip_ui(
     cursor: Res<CursorGridPos>,
     overlay: Res<OverlayState>,
     pollution: Res<PollutionGrid>,
+    air_quality: Res<AirQuality>,
     hover: Res<CellHoverState>,
 ) {
     // Only show when pollution overlay is active
@@ -147,6 +150,34 @@ fn aqi_tooltip_ui(
                             .color(egui::Color32::from_rgb(180, 180, 180))
                             .italics(),
                     );
+
+                    ui.separator();
+
+                    // City-wide air quality where residents live
+                    let city_aqi = aqi_colors::concentration_to_aqi(air_quality.city_level);
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new("City AQI:")
+                                .size(11.0)
+                                .color(egui::Color32::LIGHT_GRAY),
+                        );
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{city_aqi} ({})",
+                                aqi_colors::aqi_to_tier(city_aqi).label()
+                            ))
+                            .size(11.0)
+                            .color(egui::Color32::WHITE),
+                        );
+                    });
+                    if air_quality.smog_alert {
+                        ui.label(
+                            egui::RichText::new("Smog alert in effect")
+                                .size(11.0)
+                                .strong()
+                                .color(egui::Color32::from_rgb(230, 80, 60)),
+                        );
+                    }
                 });
         });
 }
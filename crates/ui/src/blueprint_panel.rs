@@ -0,0 +1,156 @@
+//! Blueprint Panel
+//!
+//! Shown while the blueprint tool is active. Saves the last Shift+dragged box
+//! selection as a named blueprint, lists the library to pick a blueprint to
+//! stamp or delete, and shows the cost of the stamp under the cursor and
+//! whether it fits there.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use rendering::blueprint_tool::{BlueprintGhost, BlueprintToolState};
+use rendering::box_selection::BoxSelectionState;
+use rendering::input::ActiveTool;
+use simulation::app_state::AppState;
+use simulation::blueprints::{BlueprintLibrary, CaptureBlueprint};
+use simulation::economy::CityBudget;
+use simulation::keybindings::KeyBindings;
+
+use crate::theme;
+
+/// Display the blueprint capture and library window.
+#[allow(clippy::too_many_arguments)]
+pub fn blueprint_panel_ui(
+    mut contexts: EguiContexts,
+    tool: Res<ActiveTool>,
+    box_state: Res<BoxSelectionState>,
+    ghost: Res<BlueprintGhost>,
+    budget: Res<CityBudget>,
+    bindings: Res<KeyBindings>,
+    mut library: ResMut<BlueprintLibrary>,
+    mut tool_state: ResMut<BlueprintToolState>,
+    mut capture_events: EventWriter<CaptureBlueprint>,
+    mut name: Local<String>,
+) {
+    if *tool != ActiveTool::Blueprint {
+        return;
+    }
+
+    egui::Window::new("Blueprints")
+        .id(egui::Id::new("blueprint_panel"))
+        .default_pos(egui::pos2(10.0, 420.0))
+        .default_width(260.0)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            // --- Capture ---
+            ui.label(egui::RichText::new("Save area").strong());
+            match box_state.last_area {
+                Some((x, y, w, h)) => {
+                    ui.label(format!("Selection: {}x{} cells at ({}, {})", w, h, x, y));
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut *name)
+                                .hint_text("Blueprint name")
+                                .desired_width(150.0),
+                        );
+                        let default_name = format!("Blueprint {}", library.count() + 1);
+                        if ui.button("Save").clicked() {
+                            let name = std::mem::take(&mut *name);
+                            capture_events.send(CaptureBlueprint {
+                                origin_x: x,
+                                origin_y: y,
+                                width: w,
+                                height: h,
+                                name: if name.trim().is_empty() {
+                                    default_name
+                                } else {
+                                    name.trim().to_string()
+                                },
+                            });
+                        }
+                    });
+                }
+                None => {
+                    ui.colored_label(
+                        theme::TEXT_MUTED,
+                        "Shift+drag on the map to select an area to save.",
+                    );
+                }
+            }
+
+            ui.separator();
+
+            // --- Library ---
+            ui.label(egui::RichText::new("Library").strong());
+            if library.is_empty() {
+                ui.colored_label(theme::TEXT_MUTED, "No blueprints saved yet.");
+            }
+            let mut delete = None;
+            for (i, bp) in library.blueprints.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    let selected = tool_state.selected == Some(i);
+                    let label = format!("{} ({}x{})", bp.name, bp.width, bp.height);
+                    if ui.selectable_label(selected, label).clicked() {
+                        tool_state.selected = if selected { None } else { Some(i) };
+                    }
+                    if ui.small_button("Delete").clicked() {
+                        delete = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = delete {
+                library.remove(i);
+                tool_state.selected = match tool_state.selected {
+                    Some(s) if s == i => None,
+                    Some(s) if s > i => Some(s - 1),
+                    other => other,
+                };
+            }
+
+            // --- Stamp preview ---
+            if tool_state.selected.is_none() {
+                return;
+            }
+            ui.separator();
+            ui.label(format!(
+                "Rotation: {}° ({} to rotate)",
+                tool_state.rotation.degrees(),
+                bindings.rotate_blueprint.display_label()
+            ));
+            let Some(plan) = &ghost.plan else {
+                ui.colored_label(theme::TEXT_MUTED, "Move the cursor over the map.");
+                return;
+            };
+            let affordable = budget.treasury >= plan.cost;
+            ui.colored_label(
+                if affordable {
+                    theme::SUCCESS
+                } else {
+                    theme::ERROR
+                },
+                format!("Cost: ${:.0}", plan.cost),
+            );
+            match plan.first_conflict() {
+                Some(conflict) => {
+                    ui.colored_label(theme::ERROR, conflict.message());
+                }
+                None if !affordable => {
+                    ui.colored_label(theme::ERROR, "Not enough money!");
+                }
+                None => {
+                    ui.label("Click to place, right-click to put away.");
+                }
+            }
+        });
+}
+
+pub struct BlueprintPanelPlugin;
+
+impl Plugin for BlueprintPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            blueprint_panel_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
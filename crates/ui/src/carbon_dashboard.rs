@@ -0,0 +1,180 @@
+//! Carbon emissions dashboard.
+//!
+//! Breaks the city's CO2 down by source for the month so far and the last
+//! closed month, charts net emissions against the neutrality trajectory,
+//! and lets the player choose how emissions are priced and how soon the
+//! city should reach net zero. Opens from the Environment toolbar category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::carbon_ledger::{
+    CarbonLedger, CarbonPricing, Emissions, MAX_CARBON_TAX, PERMIT_BASE_PRICE,
+};
+
+use crate::graphs::drawing::{draw_multi_line_chart, legend_item};
+
+const COLOR_NET: egui::Color32 = egui::Color32::from_rgb(230, 120, 60);
+const COLOR_TARGET: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const COLOR_OFF_TRACK: egui::Color32 = egui::Color32::from_rgb(220, 60, 60);
+
+/// Whether the carbon dashboard is visible.
+#[derive(Resource, Default)]
+pub struct CarbonDashboardVisible(pub bool);
+
+fn emissions_grid(ui: &mut egui::Ui, id: &str, emissions: &Emissions) {
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        for (label, tons) in [
+            ("Power plants", emissions.power),
+            ("Traffic", emissions.traffic),
+            ("Industry", emissions.industry),
+            ("Heating", emissions.heating),
+        ] {
+            ui.label(label);
+            ui.label(format!("{tons:.0} t"));
+            ui.end_row();
+        }
+        ui.label("Absorbed by trees");
+        ui.label(format!("-{:.0} t", emissions.sequestered));
+        ui.end_row();
+        ui.strong("Net");
+        ui.strong(format!("{:.0} t", emissions.net()));
+        ui.end_row();
+    });
+}
+
+/// Renders the carbon dashboard window.
+pub fn carbon_dashboard_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<CarbonDashboardVisible>,
+    mut ledger: ResMut<CarbonLedger>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Carbon Emissions")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("This month so far");
+            emissions_grid(ui, "carbon_current", &ledger.current);
+
+            if let Some(last) = ledger.last_month() {
+                ui.separator();
+                ui.heading("Last month");
+                emissions_grid(ui, "carbon_last", &last.emissions);
+            }
+
+            ui.separator();
+            ui.heading("Path to neutrality");
+            match (ledger.last_month(), ledger.neutrality_month()) {
+                (Some(last), Some(neutral)) => {
+                    if let Some(target) = last.target {
+                        let (text, color) = if last.on_track() {
+                            ("On track", COLOR_TARGET)
+                        } else {
+                            ("Off track", COLOR_OFF_TRACK)
+                        };
+                        ui.horizontal(|ui| {
+                            ui.colored_label(color, text);
+                            ui.label(format!("(target {target:.0} t last month)"));
+                        });
+                    }
+                    let months_left = neutral.saturating_sub(ledger.months_closed);
+                    ui.label(format!(
+                        "Net zero due in {:.1} years",
+                        months_left as f32 / 12.0
+                    ));
+                }
+                _ => {
+                    ui.label("The trajectory starts after the first month with emissions.");
+                }
+            }
+
+            // Chart only the months since the baseline, when both lines exist.
+            let (net, target): (Vec<f32>, Vec<f32>) = ledger
+                .history
+                .iter()
+                .filter_map(|m| Some((m.emissions.net() as f32, m.target? as f32)))
+                .unzip();
+            draw_multi_line_chart(
+                ui,
+                &[
+                    (&net, COLOR_NET, "Net emissions"),
+                    (&target, COLOR_TARGET, "Target"),
+                ],
+                400.0,
+                100.0,
+            );
+            ui.horizontal(|ui| {
+                legend_item(ui, COLOR_NET, "Net emissions");
+                legend_item(ui, COLOR_TARGET, "Target");
+            });
+
+            let mut years = ledger.neutrality_years;
+            ui.add(egui::Slider::new(&mut years, 10..=50).text("Years to net zero"));
+            if years != ledger.neutrality_years {
+                ledger.neutrality_years = years;
+            }
+
+            ui.separator();
+            ui.heading("Carbon pricing");
+            let mut pricing = ledger.pricing;
+            ui.horizontal(|ui| {
+                for option in CarbonPricing::ALL {
+                    ui.radio_value(&mut pricing, option, option.name())
+                        .on_hover_text(option.description());
+                }
+            });
+            if pricing != ledger.pricing {
+                ledger.pricing = pricing;
+            }
+            match pricing {
+                CarbonPricing::None => {}
+                CarbonPricing::Tax => {
+                    let mut rate = ledger.tax_rate;
+                    ui.add(
+                        egui::Slider::new(&mut rate, 0.01..=MAX_CARBON_TAX)
+                            .text("$ per ton")
+                            .fixed_decimals(2),
+                    );
+                    if rate != ledger.tax_rate {
+                        ledger.tax_rate = rate;
+                    }
+                }
+                CarbonPricing::CapAndTrade => {
+                    ui.label(format!(
+                        "Permits cost ${PERMIT_BASE_PRICE:.2}/t when emissions meet the cap"
+                    ));
+                }
+            }
+            if let Some(last) = ledger.last_month() {
+                ui.label(format!(
+                    "Last month: ${:.2}/t, ${:.0} raised",
+                    last.price, last.revenue
+                ));
+            }
+            ui.small(format!(
+                "Raised so far: ${:.0}. A carbon price lowers industrial demand.",
+                ledger.total_revenue
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct CarbonDashboardPlugin;
+
+impl Plugin for CarbonDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CarbonDashboardVisible>().add_systems(
+            Update,
+            carbon_dashboard_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -138,8 +138,8 @@ pub fn cell_info_panel_ui(
     let idx = gy * GRID_WIDTH + gx;
 
     let elevation = cell.elevation;
-    let lv = land_value.values.get(idx).copied().unwrap_or(0);
-    let poll_level = pollution.levels.get(idx).copied().unwrap_or(0);
+    let lv = land_value.get(gx, gy);
+    let poll_level = pollution.get(gx, gy);
     let noise_level = noise.levels.get(idx).copied().unwrap_or(0);
 
     // Calculate the world-space position of this cell for service distance checks
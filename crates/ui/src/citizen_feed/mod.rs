@@ -0,0 +1,44 @@
+//! Citizen social feed ("City Chatter").
+//!
+//! A ticker of short posts written by actual citizens about what is going
+//! on around them:
+//! - Reactions to new `EventJournal` entries, by citizens living near the
+//!   event when it has a location
+//! - Neighbours of a newly opened park
+//! - Earners noticing a tax hike or cut
+//! - Every few game hours, someone's commute, job hunt or unmet need
+//!
+//! The wording follows the author's happiness. Clicking an author jumps the
+//! camera to them and opens their citizen info panel.
+
+mod posts;
+mod systems;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use posts::{commute_minutes, compose_post, FeedTopic};
+pub use systems::{citizen_feed_ui, collect_feed_posts, reset_citizen_feed};
+pub use types::{CitizenFeed, FeedPost, Mood};
+
+use bevy::prelude::*;
+use simulation::app_state::AppState;
+use simulation::SaveLoadState;
+
+pub struct CitizenFeedPlugin;
+
+impl Plugin for CitizenFeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CitizenFeed>()
+            .add_systems(OnEnter(SaveLoadState::Loading), reset_citizen_feed)
+            .add_systems(OnEnter(SaveLoadState::NewGame), reset_citizen_feed)
+            .add_systems(
+                Update,
+                (collect_feed_posts, citizen_feed_ui)
+                    .chain()
+                    .run_if(in_state(SaveLoadState::Idle))
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
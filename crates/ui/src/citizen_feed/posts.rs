@@ -0,0 +1,193 @@
+//! What citizens post about and how they word it.
+
+use simulation::config::CELL_SIZE;
+use simulation::events::CityEventType;
+use simulation::mode_choice::TransportMode;
+
+use super::types::Mood;
+
+/// Average door-to-door speed of a car trip through town, in metres per
+/// minute, including junctions and parking.
+const DRIVE_METRES_PER_MINUTE: f32 = 250.0;
+
+/// Minutes spent leaving home and getting from the stop or car park to work.
+const COMMUTE_OVERHEAD_MINUTES: f32 = 4.0;
+
+/// Something a citizen posts about.
+#[derive(Debug, Clone)]
+pub enum FeedTopic {
+    Commute {
+        minutes: u32,
+        mode: TransportMode,
+    },
+    NewPark {
+        park: String,
+    },
+    TaxChange {
+        from_pct: f32,
+        to_pct: f32,
+    },
+    JobHunt,
+    /// A need below its comfortable level: "hunger", "energy", ...
+    Need(&'static str),
+    CityEvent(CityEventType),
+}
+
+/// Estimated one-way commute over `cells` grid cells (Manhattan distance).
+pub fn commute_minutes(cells: f32, mode: TransportMode) -> u32 {
+    let metres = cells * CELL_SIZE;
+    let minutes = metres / (DRIVE_METRES_PER_MINUTE * mode.speed_multiplier());
+    (minutes + COMMUTE_OVERHEAD_MINUTES).round() as u32
+}
+
+fn mode_phrase(mode: TransportMode) -> &'static str {
+    match mode {
+        TransportMode::Walk => "on foot",
+        TransportMode::Bike => "by bike",
+        TransportMode::Drive => "by car",
+        TransportMode::Transit => "on the bus",
+    }
+}
+
+/// One of `options`, chosen by `seed`.
+fn pick<'a>(options: &[&'a str], seed: u64) -> &'a str {
+    options[(seed % options.len() as u64) as usize]
+}
+
+/// Text of a post about `topic` by an author in the given mood.
+pub fn compose_post(topic: &FeedTopic, mood: Mood, seed: u64) -> String {
+    match topic {
+        FeedTopic::Commute { minutes, mode } => {
+            let how = mode_phrase(*mode);
+            match (mood, *minutes) {
+                (_, m) if m >= 40 => format!(
+                    "{m} minutes to get to work {how}. {}",
+                    pick(
+                        &[
+                            "Every. Single. Day.",
+                            "There has to be a better way.",
+                            "Fix the roads already!",
+                        ],
+                        seed,
+                    )
+                ),
+                (Mood::Upset, m) => format!("{m} minutes {how} and I'm still late. Great."),
+                (_, m) if m <= 12 => format!(
+                    "Only {m} minutes to work {how}. {}",
+                    pick(
+                        &["Love this city!", "Can't complain.", "Short and sweet."],
+                        seed
+                    )
+                ),
+                (_, m) => format!("{m} minute commute {how} today. Could be worse."),
+            }
+        }
+        FeedTopic::NewPark { park } => match mood {
+            Mood::Upset => {
+                format!("A new {park} down the street. Nice, I guess. Now fix the rest.")
+            }
+            _ => format!(
+                "A new {park} just opened near my place! {}",
+                pick(
+                    &[
+                        "Picnic this weekend?",
+                        "See you there.",
+                        "Finally some green."
+                    ],
+                    seed
+                )
+            ),
+        },
+        FeedTopic::TaxChange { from_pct, to_pct } if to_pct > from_pct => match mood {
+            Mood::Happy => format!("Taxes up to {to_pct:.0}%. Hope it pays for something nice."),
+            _ => format!(
+                "Taxes up from {from_pct:.0}% to {to_pct:.0}%? {}",
+                pick(
+                    &[
+                        "My paycheck is already stretched thin.",
+                        "Where does all that money even go?",
+                        "Thinking about moving out of town.",
+                    ],
+                    seed,
+                )
+            ),
+        },
+        FeedTopic::TaxChange { to_pct, .. } => {
+            format!("Taxes down to {to_pct:.0}%! Dinner's on me tonight.")
+        }
+        FeedTopic::JobHunt => pick(
+            &[
+                "Still looking for a job. Anyone hiring?",
+                "Another day, another rejection letter.",
+                "Sent out ten applications today. Fingers crossed.",
+            ],
+            seed,
+        )
+        .to_string(),
+        FeedTopic::Need(need) => match *need {
+            "hunger" => "Nowhere decent to buy groceries around here. Starving.",
+            "energy" => "So tired. Couldn't sleep a wink last night.",
+            "social" => "Haven't seen my friends in ages. Anyone around?",
+            "fun" => "Nothing to do in this town. So bored.",
+            _ => "My apartment is falling apart and the landlord doesn't care.",
+        }
+        .to_string(),
+        FeedTopic::CityEvent(event) => compose_event_post(event, mood, seed),
+    }
+}
+
+fn compose_event_post(event: &CityEventType, mood: Mood, seed: u64) -> String {
+    match event {
+        CityEventType::MilestoneReached(milestone) => {
+            format!("{}! Proud to live here.", milestone.trim_end_matches('!'))
+        }
+        CityEventType::BuildingFire(x, y) => {
+            format!("Smoke rising over by ({x}, {y}). Hope everyone got out.")
+        }
+        CityEventType::DisasterStrike(what) => {
+            format!(
+                "Did everyone see that {}? Stay safe out there.",
+                what.to_lowercase()
+            )
+        }
+        CityEventType::NewPolicy(policy) => match mood {
+            Mood::Upset => format!("{policy}. Nobody asked for this."),
+            _ => format!("{policy}. About time!"),
+        },
+        CityEventType::BudgetCrisis => "The city is broke? Where do our taxes go?".to_string(),
+        CityEventType::PopulationBoom => match mood {
+            Mood::Upset => "So many new people. Rents are going through the roof.".to_string(),
+            _ => "Love all the new faces around town!".to_string(),
+        },
+        CityEventType::Epidemic => {
+            "Half my office is out sick. Wash your hands, people.".to_string()
+        }
+        CityEventType::Festival => pick(
+            &[
+                "Festival downtown tonight! Who's going?",
+                "The festival is amazing, best night in years.",
+            ],
+            seed,
+        )
+        .to_string(),
+        CityEventType::EconomicBoom => {
+            "Business is booming. Maybe I'll finally get that raise.".to_string()
+        }
+        CityEventType::ResourceDepleted(what) => {
+            format!("{what}. What happens to all those jobs now?")
+        }
+        CityEventType::DisasterReport(report) => format!(
+            "Still cleaning up after the {}. {} buildings gone.",
+            report.disaster.to_lowercase(),
+            report.buildings_destroyed
+        ),
+    }
+}
+
+/// Grid cell an event happened at, when it has one.
+pub fn event_location(event: &CityEventType) -> Option<(usize, usize)> {
+    match event {
+        CityEventType::BuildingFire(x, y) => Some((*x, *y)),
+        _ => None,
+    }
+}
@@ -0,0 +1,290 @@
+//! Systems that turn city events and citizens' lives into posts, and the
+//! feed window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use rendering::camera::OrbitCamera;
+use rendering::input::ActiveTool;
+use simulation::citizen::{Citizen, CitizenDetails, HomeLocation, Needs, Position, WorkLocation};
+use simulation::config::CELL_SIZE;
+use simulation::economy::CityBudget;
+use simulation::events::EventJournal;
+use simulation::mode_choice::{manhattan_distance, ChosenTransportMode, TransportMode};
+use simulation::services::ServiceBuilding;
+use simulation::time_of_day::GameClock;
+
+use super::posts::{commute_minutes, compose_post, event_location, FeedTopic};
+use super::types::{CitizenFeed, FeedPost, Mood, AMBIENT_POST_INTERVAL_HOURS};
+use crate::citizen_info::{citizen_name, SelectedCitizen};
+use crate::theme;
+
+/// Journal events posted about per frame; the rest of a burst is skipped.
+const MAX_EVENT_POSTS: usize = 3;
+
+/// More parks than this appearing at once is a city being loaded, not built.
+const MAX_PARK_POSTS: usize = 3;
+
+/// Citizens living this many cells from an event are the ones posting about it.
+const NEIGHBOURHOOD_CELLS: usize = 12;
+
+/// Needs below this are worth complaining about.
+const NEED_COMPLAINT_LEVEL: f32 = 25.0;
+
+type CitizenItem<'a> = (
+    Entity,
+    &'a CitizenDetails,
+    &'a HomeLocation,
+    Option<&'a WorkLocation>,
+    Option<&'a Needs>,
+    Option<&'a ChosenTransportMode>,
+);
+
+fn add_post(
+    feed: &mut CitizenFeed,
+    clock: &GameClock,
+    author: Entity,
+    details: &CitizenDetails,
+    topic: &FeedTopic,
+) {
+    let mood = Mood::from_happiness(details.happiness);
+    let seed = feed.next_seed();
+    feed.push(FeedPost {
+        author,
+        author_name: citizen_name(author, details.gender),
+        mood,
+        text: compose_post(topic, mood, seed),
+        day: clock.day,
+        hour: clock.hour,
+    });
+}
+
+/// One of `candidates`, chosen by the feed's generator.
+fn choose(feed: &mut CitizenFeed, candidates: &[Entity]) -> Option<Entity> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let i = feed.next_seed() % candidates.len() as u64;
+    Some(candidates[i as usize])
+}
+
+/// What an everyday post by this citizen is about, if anything.
+fn everyday_topic((_, details, home, work, needs, mode): CitizenItem<'_>) -> Option<FeedTopic> {
+    if let Some((need, level)) = needs.map(Needs::most_critical) {
+        if level < NEED_COMPLAINT_LEVEL {
+            return Some(FeedTopic::Need(need));
+        }
+    }
+    match work {
+        Some(work) => {
+            let cells = manhattan_distance((home.grid_x, home.grid_y), (work.grid_x, work.grid_y));
+            let mode = mode.map_or(TransportMode::Drive, |m| m.0);
+            Some(FeedTopic::Commute {
+                minutes: commute_minutes(cells, mode),
+                mode,
+            })
+        }
+        None if (18..65).contains(&details.age) => Some(FeedTopic::JobHunt),
+        None => None,
+    }
+}
+
+/// Posts reactions to new journal events, newly opened parks and tax
+/// changes, and every few game hours a post about some citizen's day.
+#[allow(clippy::type_complexity)]
+pub fn collect_feed_posts(
+    clock: Res<GameClock>,
+    budget: Res<CityBudget>,
+    journal: Res<EventJournal>,
+    new_services: Query<&ServiceBuilding, Added<ServiceBuilding>>,
+    citizens: Query<
+        (
+            Entity,
+            &CitizenDetails,
+            &HomeLocation,
+            Option<&WorkLocation>,
+            Option<&Needs>,
+            Option<&ChosenTransportMode>,
+        ),
+        With<Citizen>,
+    >,
+    mut feed: ResMut<CitizenFeed>,
+) {
+    let now_hour = clock.day as f32 * 24.0 + clock.hour;
+    let latest_event = journal.events.last().map(|e| (e.day, e.hour));
+
+    // Catch up silently with a freshly loaded or generated city.
+    if !feed.started {
+        feed.started = true;
+        feed.last_event = latest_event.unwrap_or((0, 0.0));
+        feed.tax_rate = budget.tax_rate;
+        feed.last_ambient_hour = now_hour;
+        return;
+    }
+    let feed = &mut *feed;
+
+    // --- Journal events ---
+    let last_event = feed.last_event;
+    let new_events: Vec<_> = journal
+        .events
+        .iter()
+        .filter(|e| (e.day, e.hour) > last_event)
+        .collect();
+    for event in new_events.iter().rev().take(MAX_EVENT_POSTS) {
+        let candidates: Vec<Entity> = match event_location(&event.event_type) {
+            Some((x, y)) => citizens
+                .iter()
+                .filter(|(_, _, home, ..)| {
+                    home.grid_x.abs_diff(x) + home.grid_y.abs_diff(y) <= NEIGHBOURHOOD_CELLS
+                })
+                .map(|(entity, ..)| entity)
+                .collect(),
+            None => Vec::new(),
+        };
+        let author = match choose(feed, &candidates) {
+            Some(author) => Some(author),
+            None => {
+                let everyone: Vec<Entity> = citizens.iter().map(|(entity, ..)| entity).collect();
+                choose(feed, &everyone)
+            }
+        };
+        if let Some((entity, details, ..)) = author.and_then(|a| citizens.get(a).ok()) {
+            let topic = FeedTopic::CityEvent(event.event_type.clone());
+            add_post(feed, &clock, entity, details, &topic);
+        }
+    }
+    if let Some(latest) = latest_event {
+        feed.last_event = latest;
+    }
+
+    // --- New parks ---
+    let parks: Vec<&ServiceBuilding> = new_services
+        .iter()
+        .filter(|s| ServiceBuilding::is_park(s.service_type))
+        .collect();
+    if parks.len() <= MAX_PARK_POSTS {
+        for park in parks {
+            let neighbours: Vec<Entity> = citizens
+                .iter()
+                .filter(|(_, _, home, ..)| {
+                    let dx = home.grid_x.abs_diff(park.grid_x) as f32 * CELL_SIZE;
+                    let dy = home.grid_y.abs_diff(park.grid_y) as f32 * CELL_SIZE;
+                    dx * dx + dy * dy <= park.radius * park.radius
+                })
+                .map(|(entity, ..)| entity)
+                .collect();
+            let author = choose(feed, &neighbours);
+            if let Some((entity, details, ..)) = author.and_then(|a| citizens.get(a).ok()) {
+                let topic = FeedTopic::NewPark {
+                    park: park.service_type.name().to_lowercase(),
+                };
+                add_post(feed, &clock, entity, details, &topic);
+            }
+        }
+    }
+
+    if now_hour - feed.last_ambient_hour < AMBIENT_POST_INTERVAL_HOURS {
+        return;
+    }
+    feed.last_ambient_hour = now_hour;
+
+    // --- Tax changes, checked at this slower pace so a dragged slider posts once ---
+    if (budget.tax_rate - feed.tax_rate).abs() >= 0.005 {
+        let earners: Vec<Entity> = citizens
+            .iter()
+            .filter(|(_, details, ..)| details.salary > 0.0)
+            .map(|(entity, ..)| entity)
+            .collect();
+        if let Some((entity, details, ..)) =
+            choose(feed, &earners).and_then(|a| citizens.get(a).ok())
+        {
+            let topic = FeedTopic::TaxChange {
+                from_pct: feed.tax_rate * 100.0,
+                to_pct: budget.tax_rate * 100.0,
+            };
+            add_post(feed, &clock, entity, details, &topic);
+        }
+        feed.tax_rate = budget.tax_rate;
+    }
+
+    // --- Everyday life ---
+    let count = citizens.iter().count();
+    if count == 0 {
+        return;
+    }
+    let i = (feed.next_seed() % count as u64) as usize;
+    if let Some(citizen) = citizens.iter().nth(i) {
+        if let Some(topic) = everyday_topic(citizen) {
+            add_post(feed, &clock, citizen.0, citizen.1, &topic);
+        }
+    }
+}
+
+fn mood_color(mood: Mood) -> egui::Color32 {
+    match mood {
+        Mood::Happy => theme::SUCCESS,
+        Mood::Content => theme::TEXT,
+        Mood::Upset => theme::ERROR,
+    }
+}
+
+/// Feed window. Clicking an author jumps the camera to them and opens
+/// their citizen info panel.
+pub fn citizen_feed_ui(
+    mut contexts: EguiContexts,
+    feed: Res<CitizenFeed>,
+    citizens: Query<&Position, With<Citizen>>,
+    mut selected: ResMut<SelectedCitizen>,
+    mut tool: ResMut<ActiveTool>,
+    mut orbit: ResMut<OrbitCamera>,
+) {
+    let mut jump_to: Option<Entity> = None;
+
+    egui::Window::new("City Chatter")
+        .default_open(false)
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            if feed.posts.is_empty() {
+                ui.label("Nobody is talking yet...");
+                return;
+            }
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    for post in &feed.posts {
+                        ui.horizontal(|ui| {
+                            let name = egui::RichText::new(&post.author_name)
+                                .strong()
+                                .color(mood_color(post.mood));
+                            if ui
+                                .link(name)
+                                .on_hover_text("Click to jump to this citizen")
+                                .clicked()
+                            {
+                                jump_to = Some(post.author);
+                            }
+                            ui.weak(format!("Day {} {:02}:00", post.day, post.hour as u32));
+                        });
+                        ui.label(&post.text);
+                        ui.separator();
+                    }
+                });
+        });
+
+    let Some(author) = jump_to else {
+        return;
+    };
+    let Ok(pos) = citizens.get(author) else {
+        return;
+    };
+    orbit.focus.x = pos.x;
+    orbit.focus.z = pos.y;
+    orbit.distance = orbit.distance.min(400.0);
+    *tool = ActiveTool::Inspect;
+    selected.0 = Some(author);
+}
+
+/// Clears the feed when another city is loaded or generated.
+pub fn reset_citizen_feed(mut feed: ResMut<CitizenFeed>) {
+    *feed = CitizenFeed::default();
+}
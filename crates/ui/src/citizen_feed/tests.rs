@@ -0,0 +1,84 @@
+//! Tests for the citizen social feed.
+
+use bevy::prelude::*;
+use simulation::events::CityEventType;
+use simulation::mode_choice::TransportMode;
+
+use super::posts::{commute_minutes, compose_post, FeedTopic};
+use super::types::{CitizenFeed, FeedPost, Mood, MAX_POSTS};
+
+#[test]
+fn test_mood_from_happiness() {
+    assert_eq!(Mood::from_happiness(85.0), Mood::Happy);
+    assert_eq!(Mood::from_happiness(50.0), Mood::Content);
+    assert_eq!(Mood::from_happiness(10.0), Mood::Upset);
+}
+
+#[test]
+fn test_walking_commute_takes_longer_than_driving() {
+    let walk = commute_minutes(40.0, TransportMode::Walk);
+    let drive = commute_minutes(40.0, TransportMode::Drive);
+    assert!(walk > drive, "walk {walk} min vs drive {drive} min");
+    // Even next door there is getting out of the house.
+    assert!(commute_minutes(0.0, TransportMode::Drive) > 0);
+}
+
+#[test]
+fn test_tax_posts_follow_direction() {
+    let hike = FeedTopic::TaxChange {
+        from_pct: 10.0,
+        to_pct: 15.0,
+    };
+    let cut = FeedTopic::TaxChange {
+        from_pct: 15.0,
+        to_pct: 10.0,
+    };
+    assert!(compose_post(&hike, Mood::Upset, 0).contains("up from 10% to 15%"));
+    assert!(compose_post(&cut, Mood::Upset, 0).contains("down to 10%"));
+}
+
+#[test]
+fn test_commute_post_mentions_time_and_mode() {
+    let topic = FeedTopic::Commute {
+        minutes: 45,
+        mode: TransportMode::Transit,
+    };
+    let text = compose_post(&topic, Mood::Content, 7);
+    assert!(text.starts_with("45 minutes to get to work on the bus."));
+}
+
+#[test]
+fn test_milestone_post_has_single_exclamation() {
+    let topic = FeedTopic::CityEvent(CityEventType::MilestoneReached(
+        "Reached Town (1,000 population)!".to_string(),
+    ));
+    assert_eq!(
+        compose_post(&topic, Mood::Happy, 0),
+        "Reached Town (1,000 population)! Proud to live here."
+    );
+}
+
+#[test]
+fn test_feed_keeps_newest_posts() {
+    let mut feed = CitizenFeed::default();
+    for day in 0..(MAX_POSTS as u32 + 5) {
+        feed.push(FeedPost {
+            author: Entity::from_raw(day),
+            author_name: "Mary Smith".to_string(),
+            mood: Mood::Content,
+            text: String::new(),
+            day,
+            hour: 8.0,
+        });
+    }
+    assert_eq!(feed.posts.len(), MAX_POSTS);
+    assert_eq!(feed.posts[0].day, MAX_POSTS as u32 + 4);
+}
+
+#[test]
+fn test_feed_seed_varies() {
+    let mut feed = CitizenFeed::default();
+    let a = feed.next_seed();
+    let b = feed.next_seed();
+    assert_ne!(a, b);
+}
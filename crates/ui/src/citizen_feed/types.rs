@@ -0,0 +1,89 @@
+//! Feed posts and the feed resource.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+/// Posts kept in the feed; older ones scroll off.
+pub const MAX_POSTS: usize = 60;
+
+/// Game hours between posts about citizens' everyday lives.
+pub const AMBIENT_POST_INTERVAL_HOURS: f32 = 3.0;
+
+/// How the author feels, from their happiness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mood {
+    Happy,
+    Content,
+    Upset,
+}
+
+impl Mood {
+    pub fn from_happiness(happiness: f32) -> Self {
+        if happiness >= 70.0 {
+            Mood::Happy
+        } else if happiness >= 40.0 {
+            Mood::Content
+        } else {
+            Mood::Upset
+        }
+    }
+}
+
+/// One post by a citizen.
+#[derive(Debug, Clone)]
+pub struct FeedPost {
+    pub author: Entity,
+    pub author_name: String,
+    pub mood: Mood,
+    pub text: String,
+    pub day: u32,
+    pub hour: f32,
+}
+
+/// The city's social feed: what citizens are posting about.
+#[derive(Resource)]
+pub struct CitizenFeed {
+    /// Newest post first.
+    pub posts: VecDeque<FeedPost>,
+    /// Set once the feed has caught up with the city it watches.
+    pub(crate) started: bool,
+    /// Day and hour of the newest journal event already posted about.
+    pub(crate) last_event: (u32, f32),
+    /// Tax rate the citizens last saw.
+    pub(crate) tax_rate: f32,
+    /// Game hour (counted from day 0) of the last everyday post.
+    pub(crate) last_ambient_hour: f32,
+    /// State of the generator picking authors and wording.
+    pub(crate) seed: u64,
+}
+
+impl Default for CitizenFeed {
+    fn default() -> Self {
+        Self {
+            posts: VecDeque::with_capacity(MAX_POSTS),
+            started: false,
+            last_event: (0, 0.0),
+            tax_rate: 0.0,
+            last_ambient_hour: 0.0,
+            seed: 0x5EED_C17E,
+        }
+    }
+}
+
+impl CitizenFeed {
+    /// Add a post on top, dropping the oldest past `MAX_POSTS`.
+    pub fn push(&mut self, post: FeedPost) {
+        self.posts.push_front(post);
+        self.posts.truncate(MAX_POSTS);
+    }
+
+    /// Next pseudo-random number (splitmix64).
+    pub(crate) fn next_seed(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
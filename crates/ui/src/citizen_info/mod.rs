@@ -16,6 +16,7 @@ mod systems;
 #[cfg(test)]
 mod tests;
 
+pub use names::citizen_name;
 pub use plugin::CitizenInfoPlugin;
 pub use resources::{FollowCitizen, SelectedCitizen};
 pub use systems::{camera_follow_citizen, citizen_info_panel_ui, detect_citizen_selection};
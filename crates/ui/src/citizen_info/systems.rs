@@ -11,6 +11,7 @@ use simulation::citizen::{
     WorkLocation,
 };
 use simulation::config::CELL_SIZE;
+use simulation::watchlist::{CitizenKey, WatchTarget, Watchlist};
 
 use super::display::{education_label, gender_label, happiness_color, needs_bar, state_label};
 use super::names::citizen_name;
@@ -86,6 +87,7 @@ pub fn citizen_info_panel_ui(
         With<Citizen>,
     >,
     mut orbit: ResMut<OrbitCamera>,
+    mut watchlist: ResMut<Watchlist>,
 ) {
     let Some(entity) = selected.0 else {
         return;
@@ -263,20 +265,24 @@ pub fn citizen_info_panel_ui(
                 }
             }
 
-            // Follow button
+            // Follow and pin buttons
             ui.separator();
-            let is_following = follow.0 == Some(entity);
-            let btn_text = if is_following {
-                "Stop Following"
-            } else {
-                "Follow"
-            };
-            if ui.button(btn_text).clicked() {
-                if is_following {
-                    follow.0 = None;
+            ui.horizontal(|ui| {
+                let is_following = follow.0 == Some(entity);
+                let btn_text = if is_following {
+                    "Stop Following"
                 } else {
-                    follow.0 = Some(entity);
+                    "Follow"
+                };
+                if ui.button(btn_text).clicked() {
+                    if is_following {
+                        follow.0 = None;
+                    } else {
+                        follow.0 = Some(entity);
+                    }
                 }
-            }
+                let target = WatchTarget::Citizen(CitizenKey::new(details, home));
+                crate::watchlist::pin_button(ui, &mut watchlist, target);
+            });
         });
 }
@@ -0,0 +1,132 @@
+//! Beach and coastal defence dashboard.
+//!
+//! Shows how much sand is left on the city's beaches, what they add to
+//! tourism and the state of every breakwater, and lets the player pay for
+//! beach nourishment. Opens from the Environment toolbar category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::coastal_erosion::{
+    BeachNourishmentRequest, CoastState, BEACH_TOURISM_POINTS, BEACH_WARNING_HEALTH,
+};
+use simulation::economy::CityBudget;
+
+const COLOR_GOOD: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const COLOR_WORN: egui::Color32 = egui::Color32::from_rgb(230, 160, 60);
+const COLOR_BAD: egui::Color32 = egui::Color32::from_rgb(220, 60, 60);
+
+/// Whether the coast dashboard is visible.
+#[derive(Resource, Default)]
+pub struct CoastDashboardVisible(pub bool);
+
+fn condition_color(condition: f32) -> egui::Color32 {
+    if condition < BEACH_WARNING_HEALTH {
+        COLOR_BAD
+    } else if condition < 0.7 {
+        COLOR_WORN
+    } else {
+        COLOR_GOOD
+    }
+}
+
+/// Renders the coast dashboard window.
+pub fn coast_dashboard_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<CoastDashboardVisible>,
+    coast: Res<CoastState>,
+    budget: Res<CityBudget>,
+    mut nourish: EventWriter<BeachNourishmentRequest>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Coast")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Beaches");
+            if coast.beaches.is_empty() {
+                ui.label("The city has no beaches.");
+            } else {
+                let health = coast.health();
+                egui::Grid::new("coast_beaches")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Beach cells");
+                        ui.label(format!("{}", coast.beaches.len()));
+                        ui.end_row();
+                        ui.label("Sand left");
+                        ui.colored_label(
+                            condition_color(health),
+                            format!("{:.0}%", health * 100.0),
+                        );
+                        ui.end_row();
+                        ui.label("Tourism draw");
+                        ui.label(format!(
+                            "{:.1} / {BEACH_TOURISM_POINTS:.0}",
+                            coast.tourism_points()
+                        ));
+                        ui.end_row();
+                    });
+
+                let cost = coast.nourishment_cost();
+                ui.add_space(4.0);
+                let affordable = cost > 0.0 && budget.treasury >= cost;
+                let button = ui.add_enabled(
+                    affordable,
+                    egui::Button::new(format!("Nourish beaches (${cost:.0})")),
+                );
+                if button.clicked() {
+                    nourish.send(BeachNourishmentRequest);
+                }
+                ui.small(format!(
+                    "{} nourishment project(s) so far. Fresh sand does not slow the sea.",
+                    coast.nourishments
+                ));
+            }
+
+            ui.separator();
+            ui.heading("Breakwaters");
+            if coast.breakwaters.is_empty() {
+                ui.label("None built. Place breakwaters offshore to shelter beaches.");
+            } else {
+                egui::Grid::new("coast_breakwaters")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for breakwater in &coast.breakwaters {
+                            ui.label(format!("({}, {})", breakwater.x, breakwater.y));
+                            ui.colored_label(
+                                condition_color(breakwater.condition),
+                                format!("{:.0}% condition", breakwater.condition * 100.0),
+                            );
+                            ui.end_row();
+                        }
+                    });
+            }
+
+            ui.separator();
+            ui.small(format!(
+                "Spent on the coast so far: ${:.0}",
+                coast.total_spent
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct CoastDashboardPlugin;
+
+impl Plugin for CoastDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoastDashboardVisible>().add_systems(
+            Update,
+            coast_dashboard_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
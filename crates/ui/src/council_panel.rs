@@ -0,0 +1,117 @@
+//! City council window.
+//!
+//! Shows the seat split between voting blocs, motions awaiting the next
+//! session and recent vote results, and lets the player put a stadium
+//! permit to a vote. Opened from the Policies window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::city_council::{CityCouncil, Faction, Motion, COUNCIL_SEATS};
+
+/// Whether the council window is visible.
+#[derive(Resource, Default)]
+pub struct CouncilPanelVisible(pub bool);
+
+fn faction_color(faction: Faction) -> egui::Color32 {
+    match faction {
+        Faction::Progressive => egui::Color32::from_rgb(80, 170, 90),
+        Faction::Business => egui::Color32::from_rgb(70, 120, 210),
+        Faction::Populist => egui::Color32::from_rgb(210, 120, 50),
+    }
+}
+
+/// Renders the city council window.
+pub fn council_panel_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<CouncilPanelVisible>,
+    mut council: ResMut<CityCouncil>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("City Council")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            // One square per seat, colored by bloc.
+            ui.horizontal(|ui| {
+                for faction in Faction::ALL {
+                    for _ in 0..council.seats_of(faction) {
+                        let (rect, _) =
+                            ui.allocate_exact_size(egui::vec2(16.0, 16.0), egui::Sense::hover());
+                        ui.painter().rect_filled(rect, 3.0, faction_color(faction));
+                    }
+                }
+            });
+            for faction in Faction::ALL {
+                ui.colored_label(
+                    faction_color(faction),
+                    format!(
+                        "{}: {} of {} seats",
+                        faction.name(),
+                        council.seats_of(faction),
+                        COUNCIL_SEATS
+                    ),
+                );
+            }
+
+            ui.separator();
+            ui.label(format!("Tax cap: {:.0}%", council.tax_cap * 100.0));
+            ui.label(format!("Stadium permits: {}", council.stadium_permits));
+            let pending = council.is_pending(Motion::BuildStadium);
+            if ui
+                .add_enabled(!pending, egui::Button::new("Propose a stadium"))
+                .clicked()
+            {
+                council.propose(Motion::BuildStadium);
+            }
+
+            ui.separator();
+            ui.heading("Next session");
+            if council.pending.is_empty() {
+                ui.label("No motions on the agenda.");
+            }
+            for motion in &council.pending {
+                ui.label(format!("- {}", motion.describe()));
+            }
+
+            ui.separator();
+            ui.heading("Recent votes");
+            if council.history.is_empty() {
+                ui.label("The council has not voted yet.");
+            }
+            egui::Grid::new("council_votes")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for record in council.history.iter().rev() {
+                        ui.label(format!("Day {}", record.day));
+                        ui.label(record.motion.describe());
+                        let (text, color) = if record.passed {
+                            ("Passed", egui::Color32::from_rgb(50, 200, 50))
+                        } else {
+                            ("Failed", egui::Color32::from_rgb(220, 50, 50))
+                        };
+                        ui.colored_label(color, format!("{text} {}-{}", record.yes, record.no));
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct CouncilPanelPlugin;
+
+impl Plugin for CouncilPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CouncilPanelVisible>()
+            .add_systems(Update, council_panel_ui.run_if(in_state(AppState::Playing)));
+    }
+}
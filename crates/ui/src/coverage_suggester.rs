@@ -0,0 +1,134 @@
+//! Coverage gap suggester window.
+//!
+//! Pick a service type and a number of sites, run the gap analysis, then
+//! preview each suggested site's coverage on the map or build it directly.
+//! Opened from the Service Coverage panel.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::coverage_gaps::{
+    BuildSuggestion, CoverageGapAnalysis, CoverageGapRequest, DEFAULT_SUGGESTION_COUNT,
+    MAX_SUGGESTION_COUNT, SUGGESTABLE_SERVICES,
+};
+use simulation::services::{ServiceBuilding, ServiceType};
+
+/// Whether the suggester window is visible.
+#[derive(Resource, Default)]
+pub struct CoverageSuggesterVisible(pub bool);
+
+/// Choices in the suggester form, kept between openings.
+#[derive(Resource)]
+pub struct CoverageSuggesterForm {
+    pub service_type: ServiceType,
+    pub count: usize,
+}
+
+impl Default for CoverageSuggesterForm {
+    fn default() -> Self {
+        Self {
+            service_type: SUGGESTABLE_SERVICES[0],
+            count: DEFAULT_SUGGESTION_COUNT,
+        }
+    }
+}
+
+/// Renders the coverage suggester window.
+pub fn coverage_suggester_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<CoverageSuggesterVisible>,
+    mut form: ResMut<CoverageSuggesterForm>,
+    mut analysis: ResMut<CoverageGapAnalysis>,
+    mut requests: EventWriter<CoverageGapRequest>,
+    mut builds: EventWriter<BuildSuggestion>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Placement Suggestions")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Service:");
+                egui::ComboBox::from_id_salt("coverage_suggester_service")
+                    .selected_text(form.service_type.name())
+                    .show_ui(ui, |ui| {
+                        for service_type in SUGGESTABLE_SERVICES {
+                            ui.selectable_value(
+                                &mut form.service_type,
+                                service_type,
+                                service_type.name(),
+                            );
+                        }
+                    });
+            });
+            ui.add(egui::Slider::new(&mut form.count, 1..=MAX_SUGGESTION_COUNT).text("Sites"));
+            if ui.button("Analyze").clicked() {
+                requests.send(CoverageGapRequest {
+                    service_type: form.service_type,
+                    count: form.count,
+                });
+            }
+
+            let Some(service_type) = analysis.service_type else {
+                return;
+            };
+            ui.separator();
+            ui.heading(service_type.name());
+            ui.label(format!(
+                "{} of {} residents uncovered",
+                analysis.uncovered_demand, analysis.total_demand
+            ));
+            if analysis.suggestions.is_empty() {
+                ui.label("No free sites would improve coverage.");
+                return;
+            }
+            ui.label(format!(
+                "Suggested sites would cover {} more residents (${:.0} each)",
+                analysis.suggested_coverage(),
+                ServiceBuilding::cost(service_type)
+            ));
+
+            let mut preview = analysis.preview;
+            egui::Grid::new("coverage_suggestions")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (i, site) in analysis.suggestions.iter().enumerate() {
+                        ui.label(format!("({}, {})", site.grid_x, site.grid_y));
+                        ui.label(format!("+{} residents", site.newly_covered));
+                        let previewing = preview == Some(i);
+                        if ui.selectable_label(previewing, "Preview").clicked() {
+                            preview = if previewing { None } else { Some(i) };
+                        }
+                        if ui.button("Build").clicked() {
+                            builds.send(BuildSuggestion { index: i });
+                        }
+                        ui.end_row();
+                    }
+                });
+            analysis.preview = preview;
+        });
+
+    if !open {
+        visible.0 = false;
+        analysis.preview = None;
+    }
+}
+
+pub struct CoverageSuggesterPlugin;
+
+impl Plugin for CoverageSuggesterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoverageSuggesterVisible>()
+            .init_resource::<CoverageSuggesterForm>()
+            .add_systems(
+                Update,
+                coverage_suggester_ui.run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,192 @@
+//! Disaster report window.
+//!
+//! Lists the documented damage of recent disasters with what insurance paid
+//! and the status of any federal aid request, and lets the player choose the
+//! city's insurance coverage. Also shows how recovery is going: debris left
+//! to clear, displaced residents, and whether reconstruction is subsidized.
+//! Opens by itself when a claim is settled, and from the Policies window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::disaster_insurance::{
+    AidRequest, AidStatus, CoverageLevel, DisasterInsurance, AID_DECLARATION_THRESHOLD,
+};
+use simulation::disaster_recovery::{
+    RecoveryState, DEBRIS_CLEARING_COST, RECONSTRUCTION_SUBSIDY, SUBSIDY_SPEEDUP,
+};
+use simulation::emergency_management::EmergencyManagementState;
+
+/// Whether the disaster report window is visible.
+#[derive(Resource, Default)]
+pub struct DisasterReportVisible(pub bool);
+
+fn aid_label(status: AidStatus) -> (String, egui::Color32) {
+    match status {
+        AidStatus::NotRequested => ("-".to_string(), egui::Color32::GRAY),
+        AidStatus::Pending { decision_day } => (
+            format!("Pending (day {decision_day})"),
+            egui::Color32::from_rgb(230, 180, 60),
+        ),
+        AidStatus::Approved { amount } => (
+            format!("${amount:.0}"),
+            egui::Color32::from_rgb(50, 200, 50),
+        ),
+        AidStatus::Denied => ("Denied".to_string(), egui::Color32::from_rgb(220, 50, 50)),
+    }
+}
+
+/// Open the report when a new claim is settled.
+pub fn open_on_settlement(
+    insurance: Res<DisasterInsurance>,
+    mut visible: ResMut<DisasterReportVisible>,
+    mut settled: Local<usize>,
+) {
+    if !insurance.is_changed() {
+        return;
+    }
+    let count = insurance.claims.iter().filter(|c| c.settled).count();
+    if count > *settled {
+        visible.0 = true;
+    }
+    *settled = count;
+}
+
+/// Renders the disaster report window.
+pub fn disaster_report_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<DisasterReportVisible>,
+    mut insurance: ResMut<DisasterInsurance>,
+    mut recovery: ResMut<RecoveryState>,
+    emergency: Res<EmergencyManagementState>,
+    mut requests: EventWriter<AidRequest>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Disaster Report")
+        .open(&mut open)
+        .default_width(460.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Insurance policy");
+            let mut coverage = insurance.coverage;
+            ui.horizontal(|ui| {
+                for level in CoverageLevel::ALL {
+                    ui.radio_value(&mut coverage, level, level.name());
+                }
+            });
+            if coverage != insurance.coverage {
+                insurance.coverage = coverage;
+            }
+            if coverage != CoverageLevel::None {
+                ui.label(format!(
+                    "Pays {:.0}% of damage above a ${:.0} deductible",
+                    coverage.coverage() * 100.0,
+                    coverage.deductible()
+                ));
+            }
+            ui.label(format!("Premium: ${:.0}/mo", insurance.monthly_premium));
+            ui.small(format!(
+                "Paid so far: ${:.0} in premiums, ${:.0} received from insurers, \
+                 ${:.0} in federal aid",
+                insurance.total_premiums, insurance.total_payouts, insurance.total_aid
+            ));
+
+            ui.separator();
+            ui.heading("Recovery");
+            if recovery.debris.is_empty() {
+                ui.label("No debris left to clear.");
+            } else {
+                ui.label(format!(
+                    "Debris on {} cells (${DEBRIS_CLEARING_COST:.0} per cell to clear)",
+                    recovery.debris.len()
+                ));
+            }
+            if recovery.displaced > 0 {
+                ui.label(format!(
+                    "Displaced residents: {} ({} without a shelter bed)",
+                    recovery.displaced,
+                    recovery.unsheltered(emergency.shelter_capacity)
+                ));
+            }
+            let mut subsidies = recovery.subsidies;
+            ui.checkbox(
+                &mut subsidies,
+                format!("Subsidize reconstruction (${RECONSTRUCTION_SUBSIDY:.0} per building)"),
+            )
+            .on_hover_text(format!(
+                "Buildings on cleared disaster sites go up {:.0}% faster",
+                SUBSIDY_SPEEDUP * 100.0
+            ));
+            if subsidies != recovery.subsidies {
+                recovery.subsidies = subsidies;
+            }
+            ui.small(format!(
+                "Cleared so far: {} cells, {} rebuilt, ${:.0} in subsidies",
+                recovery.total_cleared, recovery.total_rebuilt, recovery.total_subsidies
+            ));
+
+            ui.separator();
+            ui.heading("Damage claims");
+            if insurance.claims.is_empty() {
+                ui.label("No disaster damage has been documented.");
+                return;
+            }
+            ui.small(format!(
+                "Federal aid requires at least ${AID_DECLARATION_THRESHOLD:.0} of documented damage"
+            ));
+            egui::Grid::new("disaster_claims")
+                .num_columns(6)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Disaster");
+                    ui.strong("Day");
+                    ui.strong("Destroyed / damaged");
+                    ui.strong("Loss");
+                    ui.strong("Insurance");
+                    ui.strong("Federal aid");
+                    ui.end_row();
+
+                    for claim in insurance.claims.iter().rev() {
+                        ui.label(claim.kind.name());
+                        ui.label(claim.first_day.to_string());
+                        ui.label(format!("{} / {}", claim.destroyed, claim.damaged));
+                        ui.label(format!("${:.0}", claim.loss));
+                        if claim.settled {
+                            ui.label(format!("${:.0}", claim.insurance_payout));
+                        } else {
+                            ui.label("Assessing...");
+                        }
+                        if claim.can_request_aid() {
+                            if ui.small_button("Request aid").clicked() {
+                                requests.send(AidRequest { claim_id: claim.id });
+                            }
+                        } else {
+                            let (text, color) = aid_label(claim.aid);
+                            ui.colored_label(color, text);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct DisasterReportPanelPlugin;
+
+impl Plugin for DisasterReportPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisasterReportVisible>().add_systems(
+            Update,
+            (open_on_settlement, disaster_report_ui)
+                .chain()
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
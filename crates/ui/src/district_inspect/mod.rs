@@ -8,6 +8,7 @@
 //! - Service coverage: counts of fire, police, health, education, parks, and
 //!   transport services whose radius overlaps cells in the district
 //! - District boundary highlighting via an optional overlay flag
+//! - An architecture style picker that themes the district's buildings
 //!
 //! The panel appears as an egui window anchored to the left side of the screen.
 
@@ -3,14 +3,26 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use simulation::citizen_aggregates::{CitizenAggregates, DemographicAggregate, Histogram};
+use simulation::district_styles::{ArchitectureStyle, DistrictStyles};
+use simulation::watchlist::{WatchTarget, Watchlist};
+
 use super::helpers::{happiness_color, happiness_label};
-use super::resources::DistrictInspectCache;
+use super::resources::{DistrictInspectCache, SelectedDistrict};
 
 /// System that renders the District Inspection Panel using egui.
-pub fn district_inspect_ui(mut contexts: EguiContexts, cache: Res<DistrictInspectCache>) {
+pub fn district_inspect_ui(
+    mut contexts: EguiContexts,
+    cache: Res<DistrictInspectCache>,
+    selected: Res<SelectedDistrict>,
+    aggregates: Res<CitizenAggregates>,
+    mut styles: ResMut<DistrictStyles>,
+    mut watchlist: ResMut<Watchlist>,
+) {
     if !cache.valid {
         return;
     }
+    let demographics = selected.0.and_then(|di| aggregates.district(di));
 
     egui::Window::new("District Info")
         .default_width(260.0)
@@ -59,6 +71,11 @@ pub fn district_inspect_ui(mut contexts: EguiContexts, cache: Res<DistrictInspec
                     ui.end_row();
                 });
 
+            if let Some(di) = selected.0 {
+                crate::watchlist::pin_button(ui, &mut watchlist, WatchTarget::district(di));
+                draw_style_picker(ui, di, &mut styles);
+            }
+
             ui.separator();
             ui.heading("Jobs");
             egui::Grid::new("district_jobs")
@@ -91,6 +108,82 @@ pub fn district_inspect_ui(mut contexts: EguiContexts, cache: Res<DistrictInspec
                     service_row(ui, "Parks", cache.park_services);
                     service_row(ui, "Transport", cache.transport_services);
                 });
+
+            if let Some(demographics) = demographics.filter(|d| d.population > 0) {
+                ui.separator();
+                draw_demographics(ui, demographics);
+            }
+        });
+}
+
+/// Architecture style picker; changing it rebuilds the district's buildings
+/// with the new style's models.
+fn draw_style_picker(ui: &mut egui::Ui, district_idx: usize, styles: &mut DistrictStyles) {
+    let current = styles.get(district_idx);
+    let mut chosen = current;
+    ui.horizontal(|ui| {
+        ui.label("Architecture:");
+        egui::ComboBox::from_id_salt("district_architecture")
+            .selected_text(current.name())
+            .show_ui(ui, |ui| {
+                for style in ArchitectureStyle::ALL {
+                    ui.selectable_value(&mut chosen, style, style.name())
+                        .on_hover_text(style.description());
+                }
+            });
+    });
+    // Only write on change so the resource is not marked changed every frame
+    if chosen != current {
+        styles.set(district_idx, chosen);
+    }
+}
+
+/// Resident histograms from the slow-tick citizen aggregates.
+fn draw_demographics(ui: &mut egui::Ui, agg: &DemographicAggregate) {
+    ui.heading("Residents");
+    ui.label(format!(
+        "{} residents, {:.0}% employed",
+        agg.population,
+        agg.employment_rate() * 100.0
+    ));
+    ui.collapsing(format!("Age (avg {:.0})", agg.age.mean()), |ui| {
+        histogram_bars(ui, &agg.age, "");
+    });
+    ui.collapsing(format!("Income (avg ${:.0})", agg.income.mean()), |ui| {
+        histogram_bars(ui, &agg.income, "$");
+    });
+    ui.collapsing(
+        format!("Happiness (avg {:.0})", agg.happiness.mean()),
+        |ui| histogram_bars(ui, &agg.happiness, ""),
+    );
+    ui.collapsing(
+        format!("Commute (avg {:.0} min)", agg.commute.mean()),
+        |ui| histogram_bars(ui, &agg.commute, ""),
+    );
+}
+
+/// One horizontal bar per histogram bin, scaled to the largest bin.
+fn histogram_bars(ui: &mut egui::Ui, histogram: &Histogram, prefix: &str) {
+    let max = histogram.counts.iter().copied().max().unwrap_or(0).max(1) as f32;
+    egui::Grid::new("district_histogram")
+        .num_columns(3)
+        .spacing([8.0, 2.0])
+        .show(ui, |ui| {
+            for (i, &count) in histogram.counts.iter().enumerate() {
+                ui.label(format!("{}{}", prefix, histogram.bin_label(i)));
+                let (rect, _) =
+                    ui.allocate_exact_size(egui::vec2(120.0, 10.0), egui::Sense::hover());
+                let filled = egui::Rect::from_min_size(
+                    rect.min,
+                    egui::vec2(rect.width() * count as f32 / max, rect.height()),
+                );
+                ui.painter()
+                    .rect_filled(rect, 2.0, egui::Color32::from_gray(40));
+                ui.painter()
+                    .rect_filled(filled, 2.0, egui::Color32::from_rgb(90, 160, 220));
+                ui.label(format!("{}", count));
+                ui.end_row();
+            }
         });
 }
 
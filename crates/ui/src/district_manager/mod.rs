@@ -0,0 +1,88 @@
+//! District Manager Panel.
+//!
+//! A window for managing all player-defined districts at once, opened with
+//! the district manager key (Shift+D by default) or from the Districts
+//! section of the info panel:
+//! - A comparison table of every painted district, sortable by clicking a
+//!   column header
+//! - Rename and recolor the selected district
+//! - Trend charts of the selected district's population, happiness, crime,
+//!   jobs and land value, optionally against every other district
+//! - Per-district tax overrides, policies, service budget, density cap and
+//!   setback, with their monthly cost
+
+mod panel;
+mod table;
+
+#[cfg(test)]
+mod tests;
+
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use simulation::app_state::AppState;
+use simulation::keybindings::KeyBindings;
+
+use crate::district_inspect::SelectedDistrict;
+
+pub use panel::district_manager_ui;
+pub use table::{build_rows, sort_rows, DistrictRow, DistrictSortColumn, TrendMetric};
+
+/// Open state, selection, sorting and chart settings of the district manager.
+#[derive(Resource, Default)]
+pub struct DistrictManagerState {
+    pub open: bool,
+    /// Index into `DistrictMap.districts`.
+    pub selected: Option<usize>,
+    pub sort_column: DistrictSortColumn,
+    pub sort_descending: bool,
+    pub trend_metric: TrendMetric,
+    /// Plot every district in the trend chart, not just the selected one.
+    pub compare_all: bool,
+}
+
+impl DistrictManagerState {
+    /// Sort by `column`, flipping the direction if it is already the sort column.
+    pub fn click_column(&mut self, column: DistrictSortColumn) {
+        if self.sort_column == column {
+            self.sort_descending = !self.sort_descending;
+        } else {
+            self.sort_column = column;
+            // Names read best A-Z, numbers largest first.
+            self.sort_descending = column != DistrictSortColumn::Name;
+        }
+    }
+}
+
+/// Toggle the district manager with the configured key (Shift+D by default).
+/// Opening it selects the district picked in the district info panel.
+fn district_manager_keybind(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    inspected: Res<SelectedDistrict>,
+    mut state: ResMut<DistrictManagerState>,
+    mut contexts: EguiContexts,
+) {
+    if contexts.ctx_mut().wants_keyboard_input() {
+        return;
+    }
+    if bindings.toggle_district_manager.just_pressed(&keyboard) {
+        state.open = !state.open;
+        if state.open && inspected.0.is_some() {
+            state.selected = inspected.0;
+        }
+    }
+}
+
+pub struct DistrictManagerPlugin;
+
+impl Plugin for DistrictManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DistrictManagerState>().add_systems(
+            Update,
+            (district_manager_keybind, district_manager_ui)
+                .chain()
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
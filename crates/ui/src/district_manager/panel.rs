@@ -0,0 +1,372 @@
+//! Egui rendering for the district manager panel.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use simulation::budget::ExtendedBudget;
+use simulation::district_history::{DistrictHistory, RECORD_INTERVAL_DAYS};
+use simulation::district_policies::{
+    DistrictPolicyOverrides, DistrictPolicyState, MAX_DENSITY_LEVEL, MAX_SERVICE_BUDGET_MULTIPLIER,
+    MAX_SETBACK_CELLS, MIN_SERVICE_BUDGET_MULTIPLIER,
+};
+use simulation::districts::DistrictMap;
+
+use super::table::{build_rows, sort_rows, DistrictSortColumn, TrendMetric};
+use super::DistrictManagerState;
+use crate::district_inspect::happiness_color;
+use crate::graphs::drawing::{draw_multi_line_chart, legend_item};
+use crate::info_panel::format_pop;
+use crate::theme;
+
+const CHART_WIDTH: f32 = 480.0;
+const CHART_HEIGHT: f32 = 110.0;
+
+fn district_color32(color: [f32; 4]) -> egui::Color32 {
+    egui::Color32::from_rgb(
+        (color[0] * 255.0) as u8,
+        (color[1] * 255.0) as u8,
+        (color[2] * 255.0) as u8,
+    )
+}
+
+/// System that renders the District Manager window.
+pub fn district_manager_ui(
+    mut contexts: EguiContexts,
+    mut state: ResMut<DistrictManagerState>,
+    mut district_map: ResMut<DistrictMap>,
+    mut policies: ResMut<DistrictPolicyState>,
+    history: Res<DistrictHistory>,
+    ext_budget: Res<ExtendedBudget>,
+) {
+    if !state.open {
+        return;
+    }
+
+    let mut rows = build_rows(&district_map, &history, &policies);
+    sort_rows(&mut rows, state.sort_column, !state.sort_descending);
+    if state
+        .selected
+        .is_some_and(|i| !rows.iter().any(|r| r.index == i))
+    {
+        state.selected = None;
+    }
+
+    let mut open = true;
+    egui::Window::new("District Manager")
+        .id(egui::Id::new("district_manager"))
+        .open(&mut open)
+        .default_pos(egui::pos2(300.0, 80.0))
+        .default_width(520.0)
+        .show(contexts.ctx_mut(), |ui| {
+            if rows.is_empty() {
+                ui.colored_label(theme::TEXT_MUTED, "No districts painted yet.");
+                ui.colored_label(theme::TEXT_MUTED, "Use the Districts toolbar to paint.");
+                return;
+            }
+
+            // --- Comparison table ---
+            egui::Grid::new("district_manager_table")
+                .num_columns(DistrictSortColumn::ALL.len())
+                .striped(true)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    for column in DistrictSortColumn::ALL {
+                        let active = state.sort_column == column;
+                        let arrow = match (active, state.sort_descending) {
+                            (false, _) => "",
+                            (true, false) => " ^",
+                            (true, true) => " v",
+                        };
+                        let header = egui::RichText::new(format!("{}{}", column.label(), arrow));
+                        if ui.selectable_label(active, header.strong()).clicked() {
+                            state.click_column(column);
+                        }
+                    }
+                    ui.end_row();
+
+                    for row in &rows {
+                        let selected = state.selected == Some(row.index);
+                        let name =
+                            egui::RichText::new(&row.name).color(district_color32(row.color));
+                        if ui.selectable_label(selected, name).clicked() {
+                            state.selected = Some(row.index);
+                        }
+                        ui.label(format_pop(row.population));
+                        if row.population > 0 {
+                            ui.colored_label(
+                                happiness_color(row.happiness),
+                                format!("{:.0}%", row.happiness),
+                            );
+                        } else {
+                            ui.label("-");
+                        }
+                        ui.label(format!("{:.0}", row.crime));
+                        ui.label(format_pop(row.jobs));
+                        ui.label(format!("{:.0}", row.land_value));
+                        ui.label(format!("{}", row.active_policies));
+                        ui.label(format!("${:.0}", row.monthly_cost));
+                        ui.end_row();
+                    }
+                });
+
+            let Some(di) = state.selected else {
+                ui.separator();
+                ui.colored_label(theme::TEXT_MUTED, "Select a district to manage it.");
+                return;
+            };
+
+            // --- Name and color ---
+            ui.separator();
+            ui.horizontal(|ui| {
+                let district = &district_map.districts[di];
+                let mut name = district.name.clone();
+                let mut rgb = [district.color[0], district.color[1], district.color[2]];
+                if ui.color_edit_button_rgb(&mut rgb).changed() {
+                    district_map.districts[di].color[..3].copy_from_slice(&rgb);
+                }
+                let response = ui.add(egui::TextEdit::singleline(&mut name).desired_width(200.0));
+                if response.changed() && !name.trim().is_empty() {
+                    district_map.districts[di].name = name;
+                }
+                ui.label(format!("{} cells", district_map.districts[di].cells.len()));
+            });
+
+            // --- Trends ---
+            ui.separator();
+            draw_trends(ui, &mut state, di, &district_map, &history);
+
+            // --- Policies and budget ---
+            ui.separator();
+            draw_policies(ui, di, &mut policies, &ext_budget);
+        });
+
+    if !open {
+        state.open = false;
+    }
+}
+
+fn draw_trends(
+    ui: &mut egui::Ui,
+    state: &mut DistrictManagerState,
+    di: usize,
+    district_map: &DistrictMap,
+    history: &DistrictHistory,
+) {
+    ui.horizontal(|ui| {
+        ui.strong("Trends");
+        egui::ComboBox::from_id_salt("district_manager_trend")
+            .selected_text(state.trend_metric.label())
+            .show_ui(ui, |ui| {
+                for metric in TrendMetric::ALL {
+                    ui.selectable_value(&mut state.trend_metric, metric, metric.label());
+                }
+            });
+        ui.checkbox(&mut state.compare_all, "Compare all districts");
+    });
+
+    if history.series(di).len() < 2 {
+        ui.colored_label(
+            theme::TEXT_MUTED,
+            format!(
+                "Trends appear after a few snapshots (one every {} days).",
+                RECORD_INTERVAL_DAYS
+            ),
+        );
+        return;
+    }
+
+    let metric = state.trend_metric;
+    let mut lines: Vec<(usize, Vec<f32>)> = vec![(di, metric.series(history, di))];
+    if state.compare_all {
+        for (i, d) in district_map.districts.iter().enumerate() {
+            if i != di && !d.cells.is_empty() {
+                lines.push((i, metric.series(history, i)));
+            }
+        }
+    }
+    let series: Vec<(&[f32], egui::Color32, &str)> = lines
+        .iter()
+        .map(|(i, data)| {
+            let d = &district_map.districts[*i];
+            (data.as_slice(), district_color32(d.color), d.name.as_str())
+        })
+        .collect();
+    draw_multi_line_chart(ui, &series, CHART_WIDTH, CHART_HEIGHT);
+    ui.horizontal_wrapped(|ui| {
+        for (_, color, name) in &series {
+            legend_item(ui, *color, name);
+        }
+    });
+}
+
+/// A tax override checkbox and slider. Returns the new override on change.
+fn tax_override_row(
+    ui: &mut egui::Ui,
+    label: &str,
+    current: Option<f32>,
+    city_wide: f32,
+) -> Option<Option<f32>> {
+    let mut overridden = current.is_some();
+    let mut pct = current.unwrap_or(city_wide) * 100.0;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut overridden, label)
+            .on_hover_text(format!("City-wide rate: {:.0}%", city_wide * 100.0))
+            .changed();
+        ui.add_enabled_ui(overridden, |ui| {
+            changed |= ui
+                .add(egui::Slider::new(&mut pct, 0.0..=30.0).suffix("%"))
+                .changed();
+        });
+    });
+    changed.then(|| overridden.then_some(pct / 100.0))
+}
+
+/// Policy controls for one district. Takes the `ResMut` so the state is only
+/// marked changed when a control is actually used.
+fn draw_policies(
+    ui: &mut egui::Ui,
+    di: usize,
+    policies: &mut ResMut<DistrictPolicyState>,
+    ext_budget: &ExtendedBudget,
+) {
+    let current = policies.get(di).cloned().unwrap_or_default();
+    let city = &ext_budget.zone_taxes;
+
+    ui.horizontal(|ui| {
+        ui.strong("Policies & budget");
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui
+                .add_enabled(
+                    !current.is_default(),
+                    egui::Button::new("Reset to city-wide"),
+                )
+                .clicked()
+            {
+                policies.clear_overrides(di);
+            }
+        });
+    });
+
+    ui.label("Tax overrides");
+    if let Some(rate) =
+        tax_override_row(ui, "Residential", current.residential_tax, city.residential)
+    {
+        match rate {
+            Some(r) => policies.set_residential_tax(di, r),
+            None => policies.get_or_create_mut(di).residential_tax = None,
+        }
+    }
+    if let Some(rate) = tax_override_row(ui, "Commercial", current.commercial_tax, city.commercial)
+    {
+        match rate {
+            Some(r) => policies.set_commercial_tax(di, r),
+            None => policies.get_or_create_mut(di).commercial_tax = None,
+        }
+    }
+    if let Some(rate) = tax_override_row(ui, "Industrial", current.industrial_tax, city.industrial)
+    {
+        match rate {
+            Some(r) => policies.set_industrial_tax(di, r),
+            None => policies.get_or_create_mut(di).industrial_tax = None,
+        }
+    }
+    if let Some(rate) = tax_override_row(ui, "Office", current.office_tax, city.office) {
+        match rate {
+            Some(r) => policies.set_office_tax(di, r),
+            None => policies.get_or_create_mut(di).office_tax = None,
+        }
+    }
+
+    ui.add_space(4.0);
+    ui.label("Policies");
+    let toggles: [(&str, bool, fn(&mut DistrictPolicyState, usize)); 5] = [
+        (
+            "High-rise ban",
+            current.high_rise_ban,
+            DistrictPolicyState::toggle_high_rise_ban,
+        ),
+        (
+            "Heavy industry ban",
+            current.heavy_industry_ban,
+            DistrictPolicyState::toggle_heavy_industry_ban,
+        ),
+        (
+            "Small business incentive",
+            current.small_business_incentive,
+            DistrictPolicyState::toggle_small_business_incentive,
+        ),
+        (
+            "Noise ordinance",
+            current.noise_ordinance,
+            DistrictPolicyState::toggle_noise_ordinance,
+        ),
+        (
+            "Green space mandate",
+            current.green_space_mandate,
+            DistrictPolicyState::toggle_green_space_mandate,
+        ),
+    ];
+    for (label, mut enabled, toggle) in toggles {
+        if ui.checkbox(&mut enabled, label).changed() {
+            toggle(policies, di);
+        }
+    }
+
+    ui.add_space(4.0);
+    let mut budget_pct = current.service_budget_multiplier.unwrap_or(1.0) * 100.0;
+    if ui
+        .add(
+            egui::Slider::new(
+                &mut budget_pct,
+                MIN_SERVICE_BUDGET_MULTIPLIER * 100.0..=MAX_SERVICE_BUDGET_MULTIPLIER * 100.0,
+            )
+            .suffix("%")
+            .text("Service budget"),
+        )
+        .changed()
+    {
+        policies.set_service_budget_multiplier(di, budget_pct / 100.0);
+    }
+
+    ui.horizontal(|ui| {
+        ui.label("Density cap");
+        let cap_label = |cap: Option<u8>| match cap {
+            Some(level) => format!("Level {}", level),
+            None => "No cap".to_string(),
+        };
+        let mut cap = current.max_level;
+        egui::ComboBox::from_id_salt("district_manager_density_cap")
+            .selected_text(cap_label(cap))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut cap, None, cap_label(None));
+                for level in 1..=MAX_DENSITY_LEVEL {
+                    ui.selectable_value(&mut cap, Some(level), cap_label(Some(level)));
+                }
+            });
+        if cap != current.max_level {
+            policies.set_max_level(di, cap);
+        }
+    });
+
+    let mut setback = current.setback_cells;
+    if ui
+        .add(egui::Slider::new(&mut setback, 0..=MAX_SETBACK_CELLS).text("Edge setback (cells)"))
+        .changed()
+    {
+        policies.set_setback_cells(di, setback);
+    }
+
+    // Drop entries that were edited back to the city-wide defaults.
+    if policies
+        .get(di)
+        .is_some_and(DistrictPolicyOverrides::is_default)
+    {
+        policies.clear_overrides(di);
+    }
+
+    ui.add_space(4.0);
+    let cost = policies.get(di).map_or(0.0, |o| o.monthly_cost());
+    ui.label(format!("Monthly policy cost: ${:.0}", cost));
+}
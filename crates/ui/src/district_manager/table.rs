@@ -0,0 +1,167 @@
+//! Comparison table rows, sorting, and trend metrics (testable without ECS).
+
+use std::cmp::Ordering;
+
+use simulation::district_history::{DistrictHistory, DistrictSnapshot};
+use simulation::district_policies::DistrictPolicyState;
+use simulation::districts::DistrictMap;
+
+/// One district's row in the comparison table.
+#[derive(Debug, Clone, Default)]
+pub struct DistrictRow {
+    pub index: usize,
+    pub name: String,
+    pub color: [f32; 4],
+    pub cells: usize,
+    pub population: u32,
+    pub happiness: f32,
+    pub crime: f32,
+    /// Jobs and land value come from the latest history snapshot.
+    pub jobs: u32,
+    pub land_value: f32,
+    pub active_policies: u32,
+    pub monthly_cost: f64,
+}
+
+/// Column the comparison table is sorted by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistrictSortColumn {
+    #[default]
+    Name,
+    Population,
+    Happiness,
+    Crime,
+    Jobs,
+    LandValue,
+    Policies,
+    Cost,
+}
+
+impl DistrictSortColumn {
+    pub const ALL: [DistrictSortColumn; 8] = [
+        DistrictSortColumn::Name,
+        DistrictSortColumn::Population,
+        DistrictSortColumn::Happiness,
+        DistrictSortColumn::Crime,
+        DistrictSortColumn::Jobs,
+        DistrictSortColumn::LandValue,
+        DistrictSortColumn::Policies,
+        DistrictSortColumn::Cost,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DistrictSortColumn::Name => "District",
+            DistrictSortColumn::Population => "Pop",
+            DistrictSortColumn::Happiness => "Happy",
+            DistrictSortColumn::Crime => "Crime",
+            DistrictSortColumn::Jobs => "Jobs",
+            DistrictSortColumn::LandValue => "Land",
+            DistrictSortColumn::Policies => "Policies",
+            DistrictSortColumn::Cost => "Cost/mo",
+        }
+    }
+
+    fn compare(self, a: &DistrictRow, b: &DistrictRow) -> Ordering {
+        match self {
+            DistrictSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            DistrictSortColumn::Population => a.population.cmp(&b.population),
+            DistrictSortColumn::Happiness => a.happiness.total_cmp(&b.happiness),
+            DistrictSortColumn::Crime => a.crime.total_cmp(&b.crime),
+            DistrictSortColumn::Jobs => a.jobs.cmp(&b.jobs),
+            DistrictSortColumn::LandValue => a.land_value.total_cmp(&b.land_value),
+            DistrictSortColumn::Policies => a.active_policies.cmp(&b.active_policies),
+            DistrictSortColumn::Cost => a.monthly_cost.total_cmp(&b.monthly_cost),
+        }
+    }
+}
+
+/// Build a row for every painted district.
+pub fn build_rows(
+    district_map: &DistrictMap,
+    history: &DistrictHistory,
+    policies: &DistrictPolicyState,
+) -> Vec<DistrictRow> {
+    district_map
+        .districts
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| !d.cells.is_empty())
+        .map(|(i, d)| {
+            let latest = history.latest(i);
+            let overrides = policies.get(i);
+            DistrictRow {
+                index: i,
+                name: d.name.clone(),
+                color: d.color,
+                cells: d.cells.len(),
+                population: d.stats.population,
+                happiness: d.stats.avg_happiness,
+                crime: d.stats.crime,
+                jobs: latest.map_or(0, |s| s.jobs),
+                land_value: latest.map_or(0.0, |s| s.land_value),
+                active_policies: overrides.map_or(0, |o| o.active_policy_count()),
+                monthly_cost: overrides.map_or(0.0, |o| o.monthly_cost()),
+            }
+        })
+        .collect()
+}
+
+/// Sort rows by a column. Ties keep district order.
+pub fn sort_rows(rows: &mut [DistrictRow], column: DistrictSortColumn, ascending: bool) {
+    rows.sort_by(|a, b| {
+        let ord = column.compare(a, b);
+        let ord = if ascending { ord } else { ord.reverse() };
+        ord.then(a.index.cmp(&b.index))
+    });
+}
+
+/// Statistic plotted in the trend chart.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrendMetric {
+    #[default]
+    Population,
+    Happiness,
+    Crime,
+    Jobs,
+    LandValue,
+}
+
+impl TrendMetric {
+    pub const ALL: [TrendMetric; 5] = [
+        TrendMetric::Population,
+        TrendMetric::Happiness,
+        TrendMetric::Crime,
+        TrendMetric::Jobs,
+        TrendMetric::LandValue,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TrendMetric::Population => "Population",
+            TrendMetric::Happiness => "Happiness",
+            TrendMetric::Crime => "Crime",
+            TrendMetric::Jobs => "Jobs",
+            TrendMetric::LandValue => "Land value",
+        }
+    }
+
+    pub fn value(self, snapshot: &DistrictSnapshot) -> f32 {
+        match self {
+            TrendMetric::Population => snapshot.population as f32,
+            TrendMetric::Happiness => snapshot.happiness,
+            TrendMetric::Crime => snapshot.crime,
+            TrendMetric::Jobs => snapshot.jobs as f32,
+            TrendMetric::LandValue => snapshot.land_value,
+        }
+    }
+
+    /// The metric's values over a district's history, oldest first.
+    pub fn series(self, history: &DistrictHistory, district_idx: usize) -> Vec<f32> {
+        history
+            .series(district_idx)
+            .iter()
+            .map(|s| self.value(s))
+            .collect()
+    }
+}
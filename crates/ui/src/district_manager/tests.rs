@@ -0,0 +1,110 @@
+//! Tests for the district manager's comparison table and sorting.
+
+use simulation::district_history::{DistrictHistory, DistrictSnapshot};
+use simulation::district_policies::DistrictPolicyState;
+use simulation::districts::DistrictMap;
+
+use super::*;
+
+fn row(index: usize, name: &str, population: u32, happiness: f32) -> DistrictRow {
+    DistrictRow {
+        index,
+        name: name.to_string(),
+        population,
+        happiness,
+        ..Default::default()
+    }
+}
+
+fn indices(rows: &[DistrictRow]) -> Vec<usize> {
+    rows.iter().map(|r| r.index).collect()
+}
+
+#[test]
+fn test_sort_by_name_ignores_case() {
+    let mut rows = vec![row(0, "harbor", 0, 0.0), row(1, "Airport", 0, 0.0)];
+    sort_rows(&mut rows, DistrictSortColumn::Name, true);
+    assert_eq!(indices(&rows), vec![1, 0]);
+}
+
+#[test]
+fn test_sort_numeric_both_directions() {
+    let mut rows = vec![
+        row(0, "A", 300, 40.0),
+        row(1, "B", 100, 90.0),
+        row(2, "C", 200, 60.0),
+    ];
+    sort_rows(&mut rows, DistrictSortColumn::Population, false);
+    assert_eq!(indices(&rows), vec![0, 2, 1]);
+    sort_rows(&mut rows, DistrictSortColumn::Happiness, true);
+    assert_eq!(indices(&rows), vec![0, 2, 1]);
+    sort_rows(&mut rows, DistrictSortColumn::Happiness, false);
+    assert_eq!(indices(&rows), vec![1, 2, 0]);
+}
+
+#[test]
+fn test_sort_ties_keep_district_order() {
+    let mut rows = vec![
+        row(2, "C", 50, 0.0),
+        row(0, "A", 50, 0.0),
+        row(1, "B", 50, 0.0),
+    ];
+    sort_rows(&mut rows, DistrictSortColumn::Population, false);
+    assert_eq!(indices(&rows), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_click_column_toggles_direction() {
+    let mut state = DistrictManagerState::default();
+    state.click_column(DistrictSortColumn::Population);
+    assert_eq!(state.sort_column, DistrictSortColumn::Population);
+    assert!(state.sort_descending);
+    state.click_column(DistrictSortColumn::Population);
+    assert!(!state.sort_descending);
+    state.click_column(DistrictSortColumn::Name);
+    assert!(!state.sort_descending);
+}
+
+#[test]
+fn test_build_rows_skips_unpainted_districts() {
+    let mut map = DistrictMap::default();
+    map.assign_cell_to_district(3, 3, 1);
+    map.districts[1].stats.population = 120;
+
+    let mut history = DistrictHistory::default();
+    let mut snapshots = vec![DistrictSnapshot::default(); map.districts.len()];
+    snapshots[1].jobs = 45;
+    snapshots[1].land_value = 80.0;
+    history.record(snapshots);
+
+    let mut policies = DistrictPolicyState::default();
+    policies.toggle_noise_ordinance(1);
+
+    let rows = build_rows(&map, &history, &policies);
+    assert_eq!(rows.len(), 1);
+    let r = &rows[0];
+    assert_eq!(r.index, 1);
+    assert_eq!(r.cells, 1);
+    assert_eq!(r.population, 120);
+    assert_eq!(r.jobs, 45);
+    assert_eq!(r.land_value, 80.0);
+    assert_eq!(r.active_policies, 1);
+    assert!(r.monthly_cost > 0.0);
+}
+
+#[test]
+fn test_trend_metric_series() {
+    let mut history = DistrictHistory::default();
+    for day in 1..=3 {
+        history.record(vec![DistrictSnapshot {
+            day,
+            crime: day as f32 * 10.0,
+            ..Default::default()
+        }]);
+    }
+    assert_eq!(
+        TrendMetric::Crime.series(&history, 0),
+        vec![10.0, 20.0, 30.0]
+    );
+    assert!(TrendMetric::Jobs.series(&history, 4).is_empty());
+}
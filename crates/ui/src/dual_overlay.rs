@@ -4,6 +4,7 @@
 //! - Select a secondary overlay to display alongside the primary
 //! - Toggle between Blend and Split display modes
 //! - Adjust the blend factor (in Blend mode)
+//! - Snapshot the primary overlay and show the change since the snapshot
 
 use bevy::prelude::*;
 use simulation::app_state::AppState;
@@ -11,6 +12,8 @@ use bevy_egui::{egui, EguiContexts};
 use rendering::overlay::{
     DualOverlayMode, DualOverlayState, OverlayMode, OverlayState, OVERLAY_CHOICES,
 };
+use rendering::overlay_snapshot::{can_snapshot, OverlaySnapshots};
+use simulation::time_of_day::GameClock;
 
 pub struct DualOverlayPlugin;
 
@@ -25,6 +28,8 @@ fn dual_overlay_ui(
     mut contexts: EguiContexts,
     overlay: Res<OverlayState>,
     mut dual: ResMut<DualOverlayState>,
+    mut snapshots: ResMut<OverlaySnapshots>,
+    clock: Res<GameClock>,
 ) {
     // Only show the dual overlay toggle button when a primary overlay is active
     if overlay.mode == OverlayMode::None {
@@ -45,6 +50,8 @@ fn dual_overlay_ui(
         .show(contexts.ctx_mut(), |ui| {
             let label = if dual.secondary != OverlayMode::None {
                 format!("Compare: {} [x]", dual.secondary.label())
+            } else if snapshots.diff_for(overlay.mode).is_some() {
+                "Compare: Before/after".to_string()
             } else {
                 "Compare...".to_string()
             };
@@ -225,10 +232,93 @@ fn dual_overlay_ui(
                             dual.panel_open = false;
                         }
                     }
+
+                    if can_snapshot(overlay.mode) {
+                        ui.add_space(4.0);
+                        ui.separator();
+                        draw_snapshot_section(ui, overlay.mode, &mut snapshots, clock.day);
+                    }
                 });
         });
 }
 
+/// Before/after controls: snapshot the primary overlay, then toggle showing
+/// the change since the snapshot.
+fn draw_snapshot_section(
+    ui: &mut egui::Ui,
+    primary: OverlayMode,
+    snapshots: &mut ResMut<OverlaySnapshots>,
+    day: u32,
+) {
+    ui.label(
+        egui::RichText::new("Before / After")
+            .strong()
+            .size(12.0)
+            .color(egui::Color32::from_rgb(180, 220, 255)),
+    );
+
+    let snapshot_day = snapshots.get(primary).map(|s| s.day);
+    match snapshot_day {
+        Some(taken) => {
+            let age = day.saturating_sub(taken);
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} snapshot from day {} ({} days ago)",
+                    primary.label(),
+                    taken,
+                    age
+                ))
+                .size(10.0)
+                .color(egui::Color32::from_rgb(160, 160, 160)),
+            );
+            // Only write on change so the heatmap is not repacked every frame.
+            let mut show = snapshots.show_diff;
+            if ui
+                .checkbox(&mut show, egui::RichText::new("Show change").size(11.0))
+                .on_hover_text("Orange: higher than in the snapshot. Blue: lower.")
+                .changed()
+            {
+                snapshots.show_diff = show;
+            }
+        }
+        None => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Snapshot {} now, then make changes to compare.",
+                    primary.label()
+                ))
+                .size(10.0)
+                .color(egui::Color32::from_rgb(160, 160, 160)),
+            );
+        }
+    }
+
+    ui.horizontal(|ui| {
+        let capture_label = if snapshot_day.is_some() {
+            "Retake Snapshot"
+        } else {
+            "Take Snapshot"
+        };
+        if ui
+            .button(egui::RichText::new(capture_label).size(11.0))
+            .clicked()
+        {
+            snapshots.pending_capture = Some(primary);
+        }
+        if snapshot_day.is_some()
+            && ui
+                .button(
+                    egui::RichText::new("Discard")
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(220, 100, 100)),
+                )
+                .clicked()
+        {
+            snapshots.remove(primary);
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,171 @@
+//! In-game encyclopedia window.
+//!
+//! Toggled via Shift+F1 (or the user's configured `toggle_encyclopedia`
+//! binding). Lists every service, utility, road, zone, policy and game system
+//! with the numbers the simulation actually uses; the entries themselves are
+//! generated in `simulation::encyclopedia`. Entries can be searched by name or
+//! description and filtered by category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use simulation::app_state::AppState;
+use simulation::encyclopedia::{
+    all_entries, filter_entries, EncyclopediaCategory, EncyclopediaEntry,
+};
+use simulation::keybindings::KeyBindings;
+
+use crate::theme;
+
+/// Open state, search and selection of the encyclopedia window.
+#[derive(Resource)]
+pub struct EncyclopediaState {
+    pub open: bool,
+    pub query: String,
+    /// Only show entries of this category; `None` shows all.
+    pub category: Option<EncyclopediaCategory>,
+    /// Title of the entry shown in the detail pane.
+    pub selected: Option<&'static str>,
+    /// Generated once: every number comes from simulation constants.
+    entries: Vec<EncyclopediaEntry>,
+}
+
+impl Default for EncyclopediaState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            query: String::new(),
+            category: None,
+            selected: None,
+            entries: all_entries(),
+        }
+    }
+}
+
+/// System: toggle the encyclopedia when the configured key is pressed.
+fn encyclopedia_keybind(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut state: ResMut<EncyclopediaState>,
+    mut contexts: EguiContexts,
+) {
+    if contexts.ctx_mut().wants_keyboard_input() {
+        return;
+    }
+    if bindings.toggle_encyclopedia.just_pressed(&keyboard) {
+        state.open = !state.open;
+    }
+}
+
+/// System: render the encyclopedia window.
+fn encyclopedia_ui(mut contexts: EguiContexts, mut state: ResMut<EncyclopediaState>) {
+    if !state.open {
+        return;
+    }
+
+    let state = &mut *state;
+    let mut open = true;
+    egui::Window::new("Encyclopedia")
+        .id(egui::Id::new("encyclopedia"))
+        .open(&mut open)
+        .default_pos(egui::pos2(260.0, 90.0))
+        .default_size(egui::vec2(620.0, 440.0))
+        .show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Search:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.query)
+                        .hint_text("building, policy or system")
+                        .desired_width(220.0),
+                );
+                if !state.query.is_empty() && ui.small_button("x").clicked() {
+                    state.query.clear();
+                }
+            });
+            ui.horizontal_wrapped(|ui| {
+                ui.selectable_value(&mut state.category, None, "All");
+                for category in EncyclopediaCategory::ALL {
+                    ui.selectable_value(&mut state.category, Some(category), category.label());
+                }
+            });
+            ui.separator();
+
+            let hits = filter_entries(&state.entries, &state.query, state.category);
+            if hits.is_empty() {
+                ui.colored_label(theme::TEXT_MUTED, "No entries match the search.");
+                return;
+            }
+            if !state
+                .selected
+                .is_some_and(|t| hits.iter().any(|e| e.title == t))
+            {
+                state.selected = Some(hits[0].title);
+            }
+
+            ui.horizontal_top(|ui| {
+                egui::ScrollArea::vertical()
+                    .id_salt("encyclopedia_list")
+                    .max_width(200.0)
+                    .show(ui, |ui| {
+                        ui.set_min_width(200.0);
+                        let mut last_category = None;
+                        for entry in &hits {
+                            if last_category != Some(entry.category) {
+                                last_category = Some(entry.category);
+                                ui.colored_label(theme::TEXT_MUTED, entry.category.label());
+                            }
+                            let selected = state.selected == Some(entry.title);
+                            if ui.selectable_label(selected, entry.title).clicked() {
+                                state.selected = Some(entry.title);
+                            }
+                        }
+                    });
+                ui.separator();
+                if let Some(entry) = hits.iter().find(|e| Some(e.title) == state.selected) {
+                    draw_entry(ui, entry);
+                }
+            });
+        });
+
+    if !open {
+        state.open = false;
+    }
+}
+
+fn draw_entry(ui: &mut egui::Ui, entry: &EncyclopediaEntry) {
+    ui.vertical(|ui| {
+        ui.label(
+            egui::RichText::new(entry.title)
+                .size(theme::FONT_HEADING)
+                .color(theme::TEXT_HEADING),
+        );
+        ui.colored_label(theme::TEXT_MUTED, entry.category.label());
+        ui.add_space(theme::ITEM_SPACING);
+        ui.label(entry.summary);
+        ui.add_space(theme::SECTION_SPACING);
+        egui::Grid::new("encyclopedia_facts")
+            .num_columns(2)
+            .striped(true)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                for (label, value) in &entry.facts {
+                    ui.label(*label);
+                    ui.strong(value);
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+pub struct EncyclopediaPlugin;
+
+impl Plugin for EncyclopediaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EncyclopediaState>().add_systems(
+            Update,
+            (encyclopedia_keybind, encyclopedia_ui)
+                .chain()
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Random events editor.
+//!
+//! Lets players switch event categories off for the current city and lets
+//! modders edit the event catalog (chances, prerequisites, effects) and write
+//! it back to `event_catalog.json`. Accessible from the Settings panel.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::event_catalog::{
+    DisabledEventCategories, EventCatalog, EventCategory, EventDefinition, EventEffect,
+    EventPrerequisites, JournalKind,
+};
+
+/// Whether the event editor window is visible.
+#[derive(Resource, Default)]
+pub struct EventEditorVisible(pub bool);
+
+/// Editor selection and the result of the last file operation.
+#[derive(Resource, Default)]
+pub struct EventEditorState {
+    selected: usize,
+    status: Option<String>,
+}
+
+/// Renders the event editor window.
+pub fn event_editor_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<EventEditorVisible>,
+    mut state: ResMut<EventEditorState>,
+    mut catalog: ResMut<EventCatalog>,
+    mut disabled: ResMut<DisabledEventCategories>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Random Events")
+        .open(&mut open)
+        .resizable(true)
+        .default_width(460.0)
+        .max_height(640.0)
+        .vscroll(true)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Enabled categories (this city)");
+            ui.separator();
+            ui.horizontal_wrapped(|ui| {
+                for category in EventCategory::ALL {
+                    let mut enabled = disabled.is_enabled(category);
+                    if ui.checkbox(&mut enabled, category.name()).changed() {
+                        disabled.set_enabled(category, enabled);
+                    }
+                }
+            });
+
+            ui.add_space(12.0);
+            ui.heading("Event catalog");
+            ui.separator();
+
+            ui.horizontal_wrapped(|ui| {
+                for (i, def) in catalog.events.iter().enumerate() {
+                    if ui
+                        .selectable_label(state.selected == i, &def.name)
+                        .clicked()
+                    {
+                        state.selected = i;
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Add event").clicked() {
+                    let id = format!("custom_event_{}", catalog.events.len() + 1);
+                    catalog.events.push(EventDefinition {
+                        name: "New Event".to_string(),
+                        id,
+                        category: EventCategory::Disaster,
+                        chance: 0.01,
+                        prerequisites: EventPrerequisites::default(),
+                        effects: Vec::new(),
+                        journal: JournalKind::Disaster,
+                        description: String::new(),
+                    });
+                    state.selected = catalog.events.len() - 1;
+                }
+                if !catalog.events.is_empty() && ui.button("Remove event").clicked() {
+                    let index = state.selected.min(catalog.events.len() - 1);
+                    catalog.events.remove(index);
+                    state.selected = index.saturating_sub(1);
+                }
+            });
+
+            let selected = state.selected;
+            if let Some(def) = catalog.events.get_mut(selected) {
+                ui.add_space(8.0);
+                draw_definition(ui, selected, def);
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+            if let Err(e) = catalog.validate() {
+                ui.colored_label(egui::Color32::from_rgb(255, 180, 50), e);
+            }
+            draw_file_actions(ui, &mut state, &mut catalog);
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+fn draw_definition(ui: &mut egui::Ui, index: usize, def: &mut EventDefinition) {
+    egui::Grid::new("event_definition")
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Id:");
+            ui.text_edit_singleline(&mut def.id);
+            ui.end_row();
+
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut def.name);
+            ui.end_row();
+
+            ui.label("Category:");
+            egui::ComboBox::from_id_salt(("event_category", index))
+                .selected_text(def.category.name())
+                .show_ui(ui, |ui| {
+                    for category in EventCategory::ALL {
+                        ui.selectable_value(&mut def.category, category, category.name());
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Chance / tick:");
+            ui.add(
+                egui::Slider::new(&mut def.chance, 0.0..=1.0)
+                    .logarithmic(true)
+                    .max_decimals(4),
+            );
+            ui.end_row();
+
+            ui.label("Journal type:");
+            egui::ComboBox::from_id_salt(("event_journal", index))
+                .selected_text(format!("{:?}", def.journal))
+                .show_ui(ui, |ui| {
+                    for kind in JournalKind::ALL {
+                        ui.selectable_value(&mut def.journal, kind, format!("{kind:?}"));
+                    }
+                });
+            ui.end_row();
+
+            let prereq = &mut def.prerequisites;
+            ui.label("Min population:");
+            ui.add(egui::DragValue::new(&mut prereq.min_population).speed(100));
+            ui.end_row();
+
+            ui.label("Max population:");
+            optional_value(ui, &mut prereq.max_population, 100_000, 100.0);
+            ui.end_row();
+
+            ui.label("Min treasury:");
+            optional_value(ui, &mut prereq.min_treasury, 0.0, 100.0);
+            ui.end_row();
+
+            ui.label("Earliest day:");
+            ui.add(egui::DragValue::new(&mut prereq.min_day));
+            ui.end_row();
+        });
+
+    ui.label("Description ({export_rate} is replaced in-game):");
+    ui.text_edit_multiline(&mut def.description);
+
+    ui.add_space(4.0);
+    ui.label("Effects:");
+    let mut remove = None;
+    for (i, effect) in def.effects.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(effect.label());
+            match effect {
+                EventEffect::Happiness { amount } | EventEffect::Health { amount } => {
+                    ui.add(egui::DragValue::new(amount).range(-100.0..=100.0));
+                }
+                EventEffect::Treasury { amount } => {
+                    ui.add(egui::DragValue::new(amount).speed(50.0).prefix("$"));
+                }
+                EventEffect::Festival { ticks }
+                | EventEffect::EconomicBoom { ticks }
+                | EventEffect::Epidemic { ticks } => {
+                    ui.add(egui::DragValue::new(ticks).suffix(" ticks"));
+                }
+            }
+            if ui.small_button("x").clicked() {
+                remove = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove {
+        def.effects.remove(i);
+    }
+    egui::ComboBox::from_id_salt(("event_add_effect", index))
+        .selected_text("Add effect...")
+        .show_ui(ui, |ui| {
+            for template in EventEffect::templates() {
+                if ui.selectable_label(false, template.label()).clicked() {
+                    def.effects.push(template);
+                }
+            }
+        });
+}
+
+/// Checkbox plus value editor for an optional numeric field.
+fn optional_value<T: egui::emath::Numeric>(
+    ui: &mut egui::Ui,
+    value: &mut Option<T>,
+    default: T,
+    speed: f64,
+) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        if ui.checkbox(&mut enabled, "").changed() {
+            *value = enabled.then_some(default);
+        }
+        if let Some(v) = value.as_mut() {
+            ui.add(egui::DragValue::new(v).speed(speed));
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn draw_file_actions(ui: &mut egui::Ui, state: &mut EventEditorState, catalog: &mut EventCatalog) {
+    let path = std::path::Path::new(simulation::event_catalog::CATALOG_PATH);
+    ui.horizontal(|ui| {
+        if ui.button("Save to file").clicked() {
+            state.status = Some(match catalog.save(path) {
+                Ok(()) => format!("Saved {}", path.display()),
+                Err(e) => format!("Save failed: {e}"),
+            });
+        }
+        if ui.button("Reload from file").clicked() {
+            state.status = Some(match EventCatalog::load(path) {
+                Ok(loaded) => {
+                    *catalog = loaded;
+                    state.selected = 0;
+                    format!("Loaded {}", path.display())
+                }
+                Err(e) => format!("Load failed: {e}"),
+            });
+        }
+        if ui.button("Restore defaults").clicked() {
+            *catalog = EventCatalog::builtin();
+            state.selected = 0;
+            state.status = None;
+        }
+    });
+    if let Some(status) = &state.status {
+        ui.small(status);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn draw_file_actions(ui: &mut egui::Ui, state: &mut EventEditorState, catalog: &mut EventCatalog) {
+    if ui.button("Restore defaults").clicked() {
+        *catalog = EventCatalog::builtin();
+        state.selected = 0;
+    }
+}
+
+pub struct EventEditorPlugin;
+
+impl Plugin for EventEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventEditorVisible>()
+            .init_resource::<EventEditorState>()
+            .add_systems(Update, event_editor_ui.run_if(in_state(AppState::Playing)));
+    }
+}
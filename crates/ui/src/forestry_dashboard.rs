@@ -0,0 +1,113 @@
+//! Urban forestry dashboard.
+//!
+//! Shows how many street trees the city has, how many have grown to full
+//! size, their health, upkeep and water use, and lets the player pick the
+//! species the tree tool plants and how well pruning is funded. Opens from
+//! the Environment toolbar category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::urban_forestry::{TreeSpecies, UrbanForestry};
+
+const COLOR_GOOD: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const COLOR_WORN: egui::Color32 = egui::Color32::from_rgb(230, 160, 60);
+const COLOR_BAD: egui::Color32 = egui::Color32::from_rgb(220, 60, 60);
+
+/// Whether the forestry dashboard is visible.
+#[derive(Resource, Default)]
+pub struct ForestryDashboardVisible(pub bool);
+
+fn health_color(health: f32) -> egui::Color32 {
+    if health < 0.4 {
+        COLOR_BAD
+    } else if health < 0.8 {
+        COLOR_WORN
+    } else {
+        COLOR_GOOD
+    }
+}
+
+/// Renders the forestry dashboard window.
+pub fn forestry_dashboard_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<ForestryDashboardVisible>,
+    mut forestry: ResMut<UrbanForestry>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Urban Forestry")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Street trees");
+            let health = forestry.average_health();
+            egui::Grid::new("forestry_trees")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Trees");
+                    ui.label(format!("{}", forestry.tree_count));
+                    ui.end_row();
+                    ui.label("Fully grown");
+                    ui.label(format!("{}", forestry.mature_count));
+                    ui.end_row();
+                    ui.label("Health");
+                    ui.colored_label(health_color(health), format!("{:.0}%", health * 100.0));
+                    ui.end_row();
+                    ui.label("Water use");
+                    ui.label(format!("{:.3} MGD", forestry.water_use_mgd));
+                    ui.end_row();
+                    ui.label("Lost to neglect");
+                    ui.label(format!("{}", forestry.trees_lost));
+                    ui.end_row();
+                });
+
+            ui.separator();
+            ui.heading("Planting");
+            let mut species = forestry.planting_species;
+            for option in TreeSpecies::ALL {
+                ui.radio_value(&mut species, option, option.name())
+                    .on_hover_text(format!(
+                        "{}. Full size in {:.0} years.",
+                        option.description(),
+                        option.years_to_mature()
+                    ));
+            }
+            if species != forestry.planting_species {
+                forestry.planting_species = species;
+            }
+
+            ui.separator();
+            ui.heading("Pruning budget");
+            let mut funding = forestry.funding * 100.0;
+            ui.add(egui::Slider::new(&mut funding, 0.0..=100.0).text("% funded"));
+            if funding / 100.0 != forestry.funding {
+                forestry.funding = funding / 100.0;
+            }
+            ui.label(format!(
+                "${:.0}/day of ${:.0}/day needed",
+                forestry.daily_cost,
+                forestry.full_upkeep()
+            ));
+            ui.small("Unpruned trees decline, large trees fastest, and die if neglected.");
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct ForestryDashboardPlugin;
+
+impl Plugin for ForestryDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ForestryDashboardVisible>().add_systems(
+            Update,
+            forestry_dashboard_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! CSV export of chart series. Every chart in the statistics window can
+//! write the series it draws to `megacity_<chart>.csv`, one column per
+//! series.
+
+use bevy_egui::egui;
+
+/// One named column of chart data.
+pub(crate) struct ChartSeries {
+    pub name: &'static str,
+    pub values: Vec<f64>,
+}
+
+impl ChartSeries {
+    pub(crate) fn new<T: Into<f64>>(
+        name: &'static str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            name,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// CSV text of the series: a header row of names, then one row per index.
+/// Shorter series leave their cells empty.
+pub(crate) fn series_csv(series: &[ChartSeries]) -> String {
+    let header: Vec<String> = series.iter().map(|s| csv_field(s.name)).collect();
+    let mut csv = header.join(",");
+    csv.push('\n');
+    let rows = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    for row in 0..rows {
+        let cells: Vec<String> = series
+            .iter()
+            .map(|s| s.values.get(row).map(|v| v.to_string()).unwrap_or_default())
+            .collect();
+        csv.push_str(&cells.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// File a chart is exported to, relative to the working directory.
+pub(crate) fn export_path(chart: &str) -> String {
+    let slug: String = chart
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("megacity_{slug}.csv")
+}
+
+/// "Export CSV" button for a chart. The outcome is left in `status`.
+pub(crate) fn export_button(
+    ui: &mut egui::Ui,
+    status: &mut Option<String>,
+    chart: &str,
+    series: impl FnOnce() -> Vec<ChartSeries>,
+) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if ui.small_button("Export CSV").clicked() {
+        let path = export_path(chart);
+        *status = Some(match std::fs::write(&path, series_csv(&series())) {
+            Ok(()) => format!("Exported {path}"),
+            Err(e) => format!("Export failed: {e}"),
+        });
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (ui, status, chart, series);
+}
@@ -0,0 +1,150 @@
+//! Population pyramid and income distribution of the latest snapshot.
+
+use bevy_egui::egui;
+
+use super::csv_export::{export_button, ChartSeries};
+use super::drawing::legend_item;
+use super::history::{AGE_BAND_YEARS, INCOME_BRACKETS};
+use super::HistoryData;
+
+const MALE_COLOR: egui::Color32 = egui::Color32::from_rgb(100, 150, 255);
+const FEMALE_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 130, 170);
+
+/// Label of an age band, the last one open-ended.
+fn age_band_label(band: usize, bands: usize) -> String {
+    let from = band * AGE_BAND_YEARS as usize;
+    if band + 1 == bands {
+        format!("{from}+")
+    } else {
+        format!("{}-{}", from, from + AGE_BAND_YEARS as usize - 1)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Population pyramid
+// -----------------------------------------------------------------------
+
+pub(crate) fn draw_population_pyramid(
+    ui: &mut egui::Ui,
+    history: &HistoryData,
+    export: &mut Option<String>,
+) {
+    let pyramid = &history.age_pyramid;
+    let largest = pyramid.iter().map(|&(m, f)| m.max(f)).max().unwrap_or(0);
+    if largest == 0 {
+        ui.label("No data yet...");
+        return;
+    }
+
+    ui.heading("Population Pyramid");
+    let row_height = 16.0;
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(380.0, row_height * pyramid.len() as f32),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_gray(30));
+
+    // Men extend left of the label column, women right, oldest on top.
+    let label_width = 44.0;
+    let center = rect.center().x;
+    let half = (rect.width() - label_width) / 2.0;
+    for (band, &(men, women)) in pyramid.iter().enumerate() {
+        let top = rect.max.y - (band + 1) as f32 * row_height;
+        let bottom = top + row_height - 2.0;
+        let men_w = men as f32 / largest as f32 * half;
+        let women_w = women as f32 / largest as f32 * half;
+        let left = center - label_width / 2.0;
+        let right = center + label_width / 2.0;
+        painter.rect_filled(
+            egui::Rect::from_min_max(egui::pos2(left - men_w, top), egui::pos2(left, bottom)),
+            1.0,
+            MALE_COLOR,
+        );
+        painter.rect_filled(
+            egui::Rect::from_min_max(egui::pos2(right, top), egui::pos2(right + women_w, bottom)),
+            1.0,
+            FEMALE_COLOR,
+        );
+        painter.text(
+            egui::pos2(center, (top + bottom) / 2.0),
+            egui::Align2::CENTER_CENTER,
+            age_band_label(band, pyramid.len()),
+            egui::FontId::proportional(9.0),
+            egui::Color32::LIGHT_GRAY,
+        );
+    }
+
+    let men: u32 = pyramid.iter().map(|&(m, _)| m).sum();
+    let women: u32 = pyramid.iter().map(|&(_, f)| f).sum();
+    ui.horizontal(|ui| {
+        legend_item(ui, MALE_COLOR, &format!("Men: {men}"));
+        legend_item(ui, FEMALE_COLOR, &format!("Women: {women}"));
+    });
+    export_button(ui, export, "Population Pyramid", || {
+        vec![
+            ChartSeries::new(
+                "age_from",
+                (0..pyramid.len()).map(|band| (band * AGE_BAND_YEARS as usize) as u32),
+            ),
+            ChartSeries::new("men", pyramid.iter().map(|&(m, _)| m)),
+            ChartSeries::new("women", pyramid.iter().map(|&(_, f)| f)),
+        ]
+    });
+}
+
+// -----------------------------------------------------------------------
+// Income distribution histogram
+// -----------------------------------------------------------------------
+
+pub(crate) fn draw_income_distribution(
+    ui: &mut egui::Ui,
+    history: &HistoryData,
+    export: &mut Option<String>,
+) {
+    let brackets = &history.income_distribution;
+    let earners: u32 = brackets.iter().sum();
+    if earners == 0 {
+        ui.label("No data yet...");
+        return;
+    }
+
+    ui.heading("Income Distribution");
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(380.0, 140.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_gray(30));
+
+    let largest = brackets.iter().copied().max().unwrap_or(1).max(1);
+    let bar_width = rect.width() / brackets.len() as f32;
+    for (i, &count) in brackets.iter().enumerate() {
+        let height = count as f32 / largest as f32 * (rect.height() - 16.0);
+        let x = rect.min.x + i as f32 * bar_width;
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(x + 2.0, rect.max.y - 14.0 - height),
+                egui::pos2(x + bar_width - 2.0, rect.max.y - 14.0),
+            ),
+            1.0,
+            egui::Color32::from_rgb(100, 200, 100),
+        );
+        let label = match INCOME_BRACKETS.get(i + 1) {
+            Some(next) => format!("<${:.1}k", next / 1000.0),
+            None => format!("${:.0}k+", INCOME_BRACKETS[i] / 1000.0),
+        };
+        painter.text(
+            egui::pos2(x + bar_width / 2.0, rect.max.y - 6.0),
+            egui::Align2::CENTER_CENTER,
+            label,
+            egui::FontId::proportional(9.0),
+            egui::Color32::GRAY,
+        );
+    }
+
+    ui.label(format!("{earners} earners"));
+    export_button(ui, export, "Income Distribution", || {
+        vec![
+            ChartSeries::new("salary_from", INCOME_BRACKETS),
+            ChartSeries::new("earners", brackets.iter().copied()),
+        ]
+    });
+}
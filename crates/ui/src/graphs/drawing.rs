@@ -1,6 +1,8 @@
 //! Shared drawing helpers for charts: sparklines, multi-line charts,
 //! stacked areas, congestion colours, and legend items.
 
+use std::collections::VecDeque;
+
 use bevy_egui::egui;
 
 /// Return the last `max` elements of a slice.
@@ -12,6 +14,14 @@ pub(crate) fn tail_slice<T>(data: &[T], max: usize) -> &[T] {
     }
 }
 
+/// Copy the last `max` elements of a deque.
+pub(crate) fn tail_deque<T: Copy>(data: &VecDeque<T>, max: usize) -> Vec<T> {
+    data.iter()
+        .skip(data.len().saturating_sub(max))
+        .copied()
+        .collect()
+}
+
 pub(crate) fn draw_sparkline(ui: &mut egui::Ui, data: &[f32], color: egui::Color32) {
     let (rect, _) = ui.allocate_exact_size(egui::vec2(380.0, 40.0), egui::Sense::hover());
     let painter = ui.painter_at(rect);
@@ -0,0 +1,182 @@
+//! Statistics recorded for the charts window every 10 game days: city
+//! totals, budget, commute mode share and the energy mix over time, plus
+//! the latest age pyramid and income distribution of the citizens.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use simulation::citizen::{Citizen, CitizenDetails, Gender};
+use simulation::coal_power::{PowerPlant, PowerPlantType};
+use simulation::mode_choice::ModeShareStats;
+use simulation::stats::CityStats;
+use simulation::time_of_day::{GameClock, DAYS_PER_YEAR};
+
+/// Game days between snapshots.
+pub(crate) const RECORD_INTERVAL_DAYS: u32 = 10;
+
+/// Snapshots kept: ten years of history.
+pub(crate) const MAX_HISTORY: usize = (10 * DAYS_PER_YEAR / RECORD_INTERVAL_DAYS) as usize;
+
+/// Width of one age band of the population pyramid, in years.
+pub(crate) const AGE_BAND_YEARS: u8 = 10;
+
+/// Bands of the pyramid; the last one holds everyone older.
+pub(crate) const AGE_BANDS: usize = 9;
+
+/// Lower bound of each monthly salary bracket of the income distribution.
+pub(crate) const INCOME_BRACKETS: [f32; 6] = [0.0, 1000.0, 2000.0, 3500.0, 5000.0, 8000.0];
+
+/// Generation sources of the energy mix with their chart colours.
+pub(crate) const ENERGY_SOURCES: [(PowerPlantType, &str, [u8; 3]); 10] = [
+    (PowerPlantType::Coal, "Coal", [90, 90, 90]),
+    (PowerPlantType::Oil, "Oil", [140, 100, 60]),
+    (PowerPlantType::NaturalGas, "Gas", [255, 160, 60]),
+    (PowerPlantType::Nuclear, "Nuclear", [200, 120, 255]),
+    (PowerPlantType::HydroDam, "Hydro", [60, 140, 255]),
+    (PowerPlantType::Geothermal, "Geothermal", [220, 80, 60]),
+    (PowerPlantType::Biomass, "Biomass", [120, 170, 60]),
+    (PowerPlantType::WasteToEnergy, "Waste", [170, 150, 110]),
+    (PowerPlantType::WindTurbine, "Wind", [150, 220, 240]),
+    (PowerPlantType::Solar, "Solar", [255, 220, 60]),
+];
+
+/// Men and women in each age band, youngest first.
+pub(crate) type AgePyramid = [(u32, u32); AGE_BANDS];
+
+#[derive(Resource)]
+pub struct HistoryData {
+    /// Game day of each snapshot.
+    pub days: VecDeque<u32>,
+    pub population: VecDeque<f32>,
+    pub happiness: VecDeque<f32>,
+    pub treasury: VecDeque<f32>,
+    pub monthly_income: VecDeque<f32>,
+    pub monthly_expenses: VecDeque<f32>,
+    /// Walk, bike, drive and transit share of trips, in percent.
+    pub mode_share: VecDeque<[f32; 4]>,
+    /// Output of each `ENERGY_SOURCES` entry, in MW.
+    pub energy_mix: VecDeque<[f32; ENERGY_SOURCES.len()]>,
+    /// Latest age pyramid.
+    pub age_pyramid: AgePyramid,
+    /// Latest number of earners in each `INCOME_BRACKETS` bracket.
+    pub income_distribution: [u32; INCOME_BRACKETS.len()],
+    pub last_record_day: u32,
+}
+
+impl Default for HistoryData {
+    fn default() -> Self {
+        Self {
+            days: VecDeque::with_capacity(MAX_HISTORY),
+            population: VecDeque::with_capacity(MAX_HISTORY),
+            happiness: VecDeque::with_capacity(MAX_HISTORY),
+            treasury: VecDeque::with_capacity(MAX_HISTORY),
+            monthly_income: VecDeque::with_capacity(MAX_HISTORY),
+            monthly_expenses: VecDeque::with_capacity(MAX_HISTORY),
+            mode_share: VecDeque::with_capacity(MAX_HISTORY),
+            energy_mix: VecDeque::with_capacity(MAX_HISTORY),
+            age_pyramid: [(0, 0); AGE_BANDS],
+            income_distribution: [0; INCOME_BRACKETS.len()],
+            last_record_day: 0,
+        }
+    }
+}
+
+impl HistoryData {
+    /// Drop the oldest snapshot once more than `MAX_HISTORY` are stored.
+    fn trim(&mut self) {
+        if self.days.len() > MAX_HISTORY {
+            self.days.pop_front();
+            self.population.pop_front();
+            self.happiness.pop_front();
+            self.treasury.pop_front();
+            self.monthly_income.pop_front();
+            self.monthly_expenses.pop_front();
+            self.mode_share.pop_front();
+            self.energy_mix.pop_front();
+        }
+    }
+}
+
+/// Count citizens by age band and gender.
+pub(crate) fn age_pyramid(citizens: impl Iterator<Item = (u8, Gender)>) -> AgePyramid {
+    let mut pyramid = [(0, 0); AGE_BANDS];
+    for (age, gender) in citizens {
+        let band = ((age / AGE_BAND_YEARS) as usize).min(AGE_BANDS - 1);
+        match gender {
+            Gender::Male => pyramid[band].0 += 1,
+            Gender::Female => pyramid[band].1 += 1,
+        }
+    }
+    pyramid
+}
+
+/// Count earners by salary bracket. Citizens without a salary are left out.
+pub(crate) fn income_distribution(
+    salaries: impl Iterator<Item = f32>,
+) -> [u32; INCOME_BRACKETS.len()] {
+    let mut brackets = [0; INCOME_BRACKETS.len()];
+    for salary in salaries.filter(|&s| s > 0.0) {
+        let bracket = INCOME_BRACKETS
+            .iter()
+            .rposition(|&low| salary >= low)
+            .unwrap_or(0);
+        brackets[bracket] += 1;
+    }
+    brackets
+}
+
+/// Output of each energy source, in `ENERGY_SOURCES` order.
+pub(crate) fn energy_mix<'a>(
+    plants: impl Iterator<Item = &'a PowerPlant>,
+) -> [f32; ENERGY_SOURCES.len()] {
+    let mut mix = [0.0; ENERGY_SOURCES.len()];
+    for plant in plants {
+        if let Some(i) = ENERGY_SOURCES
+            .iter()
+            .position(|(t, _, _)| *t == plant.plant_type)
+        {
+            mix[i] += plant.current_output_mw;
+        }
+    }
+    mix
+}
+
+pub fn record_history(
+    clock: Res<GameClock>,
+    stats: Res<CityStats>,
+    budget: Res<simulation::economy::CityBudget>,
+    mode_share: Res<ModeShareStats>,
+    citizens: Query<&CitizenDetails, With<Citizen>>,
+    plants: Query<&PowerPlant>,
+    mut history: ResMut<HistoryData>,
+) {
+    // Record every 10 game days
+    if clock.day <= history.last_record_day + RECORD_INTERVAL_DAYS {
+        return;
+    }
+    history.last_record_day = clock.day;
+
+    history.days.push_back(clock.day);
+    history.population.push_back(stats.population as f32);
+    history.happiness.push_back(stats.average_happiness);
+    history.treasury.push_back(budget.treasury as f32);
+    history
+        .monthly_income
+        .push_back(budget.monthly_income as f32);
+    history
+        .monthly_expenses
+        .push_back(budget.monthly_expenses as f32);
+    history.mode_share.push_back([
+        mode_share.walk_pct,
+        mode_share.bike_pct,
+        mode_share.drive_pct,
+        mode_share.transit_pct,
+    ]);
+    history.energy_mix.push_back(energy_mix(plants.iter()));
+    history.age_pyramid = age_pyramid(citizens.iter().map(|d| (d.age, d.gender)));
+    history.income_distribution = income_distribution(citizens.iter().map(|d| d.salary));
+
+    // Trim old data (O(1) front removal with VecDeque)
+    history.trim();
+}
@@ -1,81 +1,45 @@
-//! Charts panel (UX-046): Enhanced graphs with population lines, budget area,
-//! traffic bars, service radar, and happiness breakdown.
-
-mod drawing;
+//! Statistics window (UX-046): population lines and pyramid, income
+//! distribution, budget and treasury history, commute mode share, energy
+//! mix, traffic bars, service radar and happiness breakdown. Every chart can
+//! be exported as CSV.
+
+mod csv_export;
+mod demographics;
+pub(crate) mod drawing;
+mod history;
+mod mode_energy;
 mod population_budget;
+mod timelapse;
 mod traffic_services_happiness;
 
-use std::collections::VecDeque;
+#[cfg(test)]
+mod tests;
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use simulation::chart_data::ChartHistory;
-use simulation::stats::CityStats;
-use simulation::time_of_day::GameClock;
+use simulation::timelapse::{TimelapseHistory, TimelapsePlayer};
+
+pub use history::{record_history, HistoryData};
 
+use demographics::{draw_income_distribution, draw_population_pyramid};
+use mode_energy::{draw_energy_mix, draw_mode_share};
 use population_budget::{draw_budget_chart, draw_population_chart};
+use timelapse::{draw_timelapse, TimelapseView};
 use traffic_services_happiness::{
     draw_happiness_breakdown, draw_service_radar, draw_traffic_chart,
 };
 
-const MAX_HISTORY: usize = 200;
-
-// -----------------------------------------------------------------------
-// Legacy history (kept for backward compat with existing record_history)
-// -----------------------------------------------------------------------
-
-#[derive(Resource)]
-pub struct HistoryData {
-    pub population: VecDeque<f32>,
-    pub happiness: VecDeque<f32>,
-    pub treasury: VecDeque<f32>,
-    pub last_record_day: u32,
-}
-
-impl Default for HistoryData {
-    fn default() -> Self {
-        Self {
-            population: VecDeque::with_capacity(MAX_HISTORY),
-            happiness: VecDeque::with_capacity(MAX_HISTORY),
-            treasury: VecDeque::with_capacity(MAX_HISTORY),
-            last_record_day: 0,
-        }
-    }
-}
-
-pub fn record_history(
-    clock: Res<GameClock>,
-    stats: Res<CityStats>,
-    budget: Res<simulation::economy::CityBudget>,
-    mut history: ResMut<HistoryData>,
-) {
-    // Record every 10 game days
-    if clock.day <= history.last_record_day + 10 {
-        return;
-    }
-    history.last_record_day = clock.day;
-
-    history.population.push_back(stats.population as f32);
-    history.happiness.push_back(stats.average_happiness);
-    history.treasury.push_back(budget.treasury as f32);
-
-    // Trim old data (O(1) front removal with VecDeque)
-    if history.population.len() > MAX_HISTORY {
-        history.population.pop_front();
-        history.happiness.pop_front();
-        history.treasury.pop_front();
-    }
-}
-
 // -----------------------------------------------------------------------
 // Time range selector
 // -----------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TimeRange {
-    Month, // ~3 snapshots (30 days / 10 days per snapshot)
-    Year,  // ~36 snapshots (360 days)
+    Month,    // ~3 snapshots (30 days / 10 days per snapshot)
+    Year,     // ~36 snapshots (360 days)
+    TenYears, // ~360 snapshots
     AllTime,
 }
 
@@ -84,6 +48,7 @@ impl TimeRange {
         match self {
             TimeRange::Month => "1 Month",
             TimeRange::Year => "1 Year",
+            TimeRange::TenYears => "10 Years",
             TimeRange::AllTime => "All Time",
         }
     }
@@ -92,6 +57,7 @@ impl TimeRange {
         match self {
             TimeRange::Month => 3,
             TimeRange::Year => 36,
+            TimeRange::TenYears => 360,
             TimeRange::AllTime => usize::MAX,
         }
     }
@@ -104,29 +70,44 @@ impl TimeRange {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ChartTab {
     Population,
+    Pyramid,
+    Income,
     Budget,
+    ModeShare,
+    Energy,
     Traffic,
     Services,
     Happiness,
+    Timelapse,
 }
 
 impl ChartTab {
     fn label(self) -> &'static str {
         match self {
             ChartTab::Population => "Population",
+            ChartTab::Pyramid => "Ages",
+            ChartTab::Income => "Incomes",
             ChartTab::Budget => "Budget",
+            ChartTab::ModeShare => "Mode Share",
+            ChartTab::Energy => "Energy",
             ChartTab::Traffic => "Traffic",
             ChartTab::Services => "Services",
             ChartTab::Happiness => "Happiness",
+            ChartTab::Timelapse => "Timelapse",
         }
     }
 
-    const ALL: [ChartTab; 5] = [
+    const ALL: [ChartTab; 10] = [
         ChartTab::Population,
+        ChartTab::Pyramid,
+        ChartTab::Income,
         ChartTab::Budget,
+        ChartTab::ModeShare,
+        ChartTab::Energy,
         ChartTab::Traffic,
         ChartTab::Services,
         ChartTab::Happiness,
+        ChartTab::Timelapse,
     ];
 }
 
@@ -139,6 +120,9 @@ impl ChartTab {
 pub struct ChartsState {
     tab: ChartTab,
     range: TimeRange,
+    timelapse: TimelapseView,
+    /// Outcome of the last CSV export.
+    export_status: Option<String>,
 }
 
 impl Default for ChartsState {
@@ -146,6 +130,8 @@ impl Default for ChartsState {
         Self {
             tab: ChartTab::Population,
             range: TimeRange::AllTime,
+            timelapse: TimelapseView::default(),
+            export_status: None,
         }
     }
 }
@@ -156,18 +142,20 @@ pub fn graphs_ui(
     chart_history: Res<ChartHistory>,
     visible: Res<crate::info_panel::ChartsVisible>,
     mut state: ResMut<ChartsState>,
+    timelapse_history: Res<TimelapseHistory>,
+    mut timelapse_player: ResMut<TimelapsePlayer>,
 ) {
     if !visible.0 {
         return;
     }
 
-    egui::Window::new("Charts")
-        .default_size([420.0, 380.0])
+    egui::Window::new("Statistics")
+        .default_size([420.0, 420.0])
         .show(contexts.ctx_mut(), |ui| {
             ui.small("Press [C] to toggle");
 
             // Tab bar
-            ui.horizontal(|ui| {
+            ui.horizontal_wrapped(|ui| {
                 for tab in ChartTab::ALL {
                     if ui.selectable_label(state.tab == tab, tab.label()).clicked() {
                         state.tab = tab;
@@ -178,7 +166,12 @@ pub fn graphs_ui(
             // Time range selector
             ui.horizontal(|ui| {
                 ui.label("Range:");
-                for range in [TimeRange::Month, TimeRange::Year, TimeRange::AllTime] {
+                for range in [
+                    TimeRange::Month,
+                    TimeRange::Year,
+                    TimeRange::TenYears,
+                    TimeRange::AllTime,
+                ] {
                     if ui
                         .selectable_label(state.range == range, range.label())
                         .clicked()
@@ -190,14 +183,30 @@ pub fn graphs_ui(
 
             ui.separator();
 
+            let state = &mut *state;
+            let range = state.range;
+            let export = &mut state.export_status;
             match state.tab {
                 ChartTab::Population => {
-                    draw_population_chart(ui, &chart_history, &history, state.range)
+                    draw_population_chart(ui, &chart_history, &history, range, export)
                 }
-                ChartTab::Budget => draw_budget_chart(ui, &chart_history, state.range),
-                ChartTab::Traffic => draw_traffic_chart(ui, &chart_history),
-                ChartTab::Services => draw_service_radar(ui, &chart_history),
-                ChartTab::Happiness => draw_happiness_breakdown(ui, &chart_history),
+                ChartTab::Pyramid => draw_population_pyramid(ui, &history, export),
+                ChartTab::Income => draw_income_distribution(ui, &history, export),
+                ChartTab::Budget => draw_budget_chart(ui, &chart_history, &history, range, export),
+                ChartTab::ModeShare => draw_mode_share(ui, &history, range, export),
+                ChartTab::Energy => draw_energy_mix(ui, &history, range, export),
+                ChartTab::Traffic => draw_traffic_chart(ui, &chart_history, export),
+                ChartTab::Services => draw_service_radar(ui, &chart_history, export),
+                ChartTab::Happiness => draw_happiness_breakdown(ui, &chart_history, export),
+                ChartTab::Timelapse => draw_timelapse(
+                    ui,
+                    &timelapse_history,
+                    &mut timelapse_player,
+                    &mut state.timelapse,
+                ),
+            }
+            if let Some(status) = &state.export_status {
+                ui.small(status);
             }
         });
 }
@@ -0,0 +1,110 @@
+//! Commute mode share and energy mix stacked-area charts.
+
+use bevy_egui::egui;
+
+use super::csv_export::{export_button, ChartSeries};
+use super::drawing::{draw_stacked_area, legend_item, tail_deque};
+use super::history::ENERGY_SOURCES;
+use super::{HistoryData, TimeRange};
+
+const MODES: [(&str, egui::Color32); 4] = [
+    ("Walk", egui::Color32::from_rgb(100, 200, 100)),
+    ("Bike", egui::Color32::from_rgb(100, 220, 220)),
+    ("Drive", egui::Color32::from_rgb(255, 150, 100)),
+    ("Transit", egui::Color32::from_rgb(100, 150, 255)),
+];
+
+// -----------------------------------------------------------------------
+// Mode share
+// -----------------------------------------------------------------------
+
+pub(crate) fn draw_mode_share(
+    ui: &mut egui::Ui,
+    history: &HistoryData,
+    range: TimeRange,
+    export: &mut Option<String>,
+) {
+    let data = tail_deque(&history.mode_share, range.max_points());
+    if data.is_empty() {
+        ui.label("No data yet...");
+        return;
+    }
+
+    ui.heading("Mode Share");
+    let layers: Vec<(&str, egui::Color32, Vec<f64>)> = MODES
+        .iter()
+        .enumerate()
+        .map(|(i, &(name, color))| (name, color, data.iter().map(|s| s[i] as f64).collect()))
+        .collect();
+    draw_stacked_area(ui, &layers, 380.0, 120.0);
+
+    if let Some(last) = data.last() {
+        ui.horizontal_wrapped(|ui| {
+            for (i, (name, color)) in MODES.iter().enumerate() {
+                legend_item(ui, *color, &format!("{name}: {:.0}%", last[i]));
+            }
+        });
+    }
+    let days = tail_deque(&history.days, range.max_points());
+    export_button(ui, export, "Mode Share", || {
+        let mut series = vec![ChartSeries::new("day", days.iter().copied())];
+        series.extend(
+            layers
+                .iter()
+                .map(|(name, _, values)| ChartSeries::new(*name, values.iter().copied())),
+        );
+        series
+    });
+}
+
+// -----------------------------------------------------------------------
+// Energy mix
+// -----------------------------------------------------------------------
+
+pub(crate) fn draw_energy_mix(
+    ui: &mut egui::Ui,
+    history: &HistoryData,
+    range: TimeRange,
+    export: &mut Option<String>,
+) {
+    let data = tail_deque(&history.energy_mix, range.max_points());
+    let Some(last) = data.last() else {
+        ui.label("No data yet...");
+        return;
+    };
+
+    ui.heading("Energy Mix");
+    let layers: Vec<(&str, egui::Color32, Vec<f64>)> = ENERGY_SOURCES
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, name, [r, g, b]))| {
+            let values = data.iter().map(|mix| mix[i] as f64).collect();
+            (name, egui::Color32::from_rgb(r, g, b), values)
+        })
+        .collect();
+    draw_stacked_area(ui, &layers, 380.0, 120.0);
+
+    let total: f32 = last.iter().sum();
+    ui.horizontal_wrapped(|ui| {
+        for (i, (name, color, _)) in layers.iter().enumerate() {
+            if last[i] > 0.0 {
+                let share = last[i] / total.max(f32::EPSILON) * 100.0;
+                legend_item(
+                    ui,
+                    *color,
+                    &format!("{name}: {:.0} MW ({share:.0}%)", last[i]),
+                );
+            }
+        }
+    });
+    let days = tail_deque(&history.days, range.max_points());
+    export_button(ui, export, "Energy Mix", || {
+        let mut series = vec![ChartSeries::new("day", days.iter().copied())];
+        series.extend(
+            layers
+                .iter()
+                .map(|(name, _, values)| ChartSeries::new(*name, values.iter().copied())),
+        );
+        series
+    });
+}
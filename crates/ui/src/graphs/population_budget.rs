@@ -1,11 +1,12 @@
-//! Population line chart and budget stacked-area chart.
+//! Population line chart, budget stacked-area chart and treasury history.
 
 use bevy_egui::egui;
 
 use simulation::chart_data::ChartHistory;
 
+use super::csv_export::{export_button, ChartSeries};
 use super::drawing::{
-    draw_multi_line_chart, draw_sparkline, draw_stacked_area, legend_item, tail_slice,
+    draw_multi_line_chart, draw_sparkline, draw_stacked_area, legend_item, tail_deque, tail_slice,
 };
 use super::{HistoryData, TimeRange};
 
@@ -18,6 +19,7 @@ pub(crate) fn draw_population_chart(
     chart: &ChartHistory,
     legacy: &HistoryData,
     range: TimeRange,
+    export: &mut Option<String>,
 ) {
     if chart.population.is_empty() && legacy.population.is_empty() {
         ui.label("No data yet...");
@@ -69,6 +71,14 @@ pub(crate) fn draw_population_chart(
                 );
             }
         });
+        export_button(ui, export, "Population", || {
+            vec![
+                ChartSeries::new("total", data.iter().map(|s| s.total)),
+                ChartSeries::new("residential_workers", res.iter().copied()),
+                ChartSeries::new("commercial_workers", com.iter().copied()),
+                ChartSeries::new("industrial_workers", ind.iter().copied()),
+            ]
+        });
     } else {
         // Legacy fallback
         ui.heading("Population (legacy)");
@@ -78,6 +88,9 @@ pub(crate) fn draw_population_chart(
         if let Some(&last) = data.last() {
             ui.label(format!("  Latest: {:.0}", last));
         }
+        export_button(ui, export, "Population", || {
+            vec![ChartSeries::new("population", data.iter().copied())]
+        });
     }
 }
 
@@ -85,13 +98,22 @@ pub(crate) fn draw_population_chart(
 // Budget stacked area chart
 // -----------------------------------------------------------------------
 
-pub(crate) fn draw_budget_chart(ui: &mut egui::Ui, chart: &ChartHistory, range: TimeRange) {
+pub(crate) fn draw_budget_chart(
+    ui: &mut egui::Ui,
+    chart: &ChartHistory,
+    history: &HistoryData,
+    range: TimeRange,
+    export: &mut Option<String>,
+) {
     if chart.budget.is_empty() {
         ui.label("No budget data yet...");
         return;
     }
 
     let max_pts = range.max_points();
+    draw_treasury_chart(ui, history, max_pts, export);
+    ui.add_space(8.0);
+
     let data = tail_slice(&chart.budget, max_pts);
 
     // Income stacked area
@@ -155,6 +177,12 @@ pub(crate) fn draw_budget_chart(ui: &mut egui::Ui, chart: &ChartHistory, range:
             );
         });
     }
+    export_button(ui, export, "Income", || {
+        income_layers
+            .iter()
+            .map(|(name, _, values)| ChartSeries::new(*name, values.iter().copied()))
+            .collect()
+    });
 
     ui.add_space(8.0);
 
@@ -209,4 +237,72 @@ pub(crate) fn draw_budget_chart(ui: &mut egui::Ui, chart: &ChartHistory, range:
             );
         });
     }
+    export_button(ui, export, "Expenses", || {
+        expense_layers
+            .iter()
+            .map(|(name, _, values)| ChartSeries::new(*name, values.iter().copied()))
+            .collect()
+    });
+}
+
+// -----------------------------------------------------------------------
+// Treasury, income and expenses over time
+// -----------------------------------------------------------------------
+
+fn draw_treasury_chart(
+    ui: &mut egui::Ui,
+    history: &HistoryData,
+    max_pts: usize,
+    export: &mut Option<String>,
+) {
+    let days = tail_deque(&history.days, max_pts);
+    let treasury = tail_deque(&history.treasury, max_pts);
+    let income = tail_deque(&history.monthly_income, max_pts);
+    let expenses = tail_deque(&history.monthly_expenses, max_pts);
+    if treasury.is_empty() {
+        return;
+    }
+
+    ui.heading("Treasury");
+    draw_sparkline(ui, &treasury, egui::Color32::from_rgb(255, 215, 0));
+    draw_multi_line_chart(
+        ui,
+        &[
+            (&income, egui::Color32::from_rgb(100, 200, 100), "Income"),
+            (
+                &expenses,
+                egui::Color32::from_rgb(255, 100, 100),
+                "Expenses",
+            ),
+        ],
+        380.0,
+        80.0,
+    );
+    ui.horizontal_wrapped(|ui| {
+        if let (Some(t), Some(i), Some(e)) = (treasury.last(), income.last(), expenses.last()) {
+            legend_item(
+                ui,
+                egui::Color32::from_rgb(255, 215, 0),
+                &format!("Treasury: ${t:.0}"),
+            );
+            legend_item(
+                ui,
+                egui::Color32::from_rgb(100, 200, 100),
+                &format!("Income: ${i:.0}/mo"),
+            );
+            legend_item(
+                ui,
+                egui::Color32::from_rgb(255, 100, 100),
+                &format!("Expenses: ${e:.0}/mo"),
+            );
+        }
+    });
+    export_button(ui, export, "Treasury", || {
+        vec![
+            ChartSeries::new("day", days.iter().copied()),
+            ChartSeries::new("treasury", treasury.iter().copied()),
+            ChartSeries::new("monthly_income", income.iter().copied()),
+            ChartSeries::new("monthly_expenses", expenses.iter().copied()),
+        ]
+    });
 }
@@ -0,0 +1,57 @@
+//! Unit tests for the statistics window data and CSV export.
+
+use simulation::citizen::Gender;
+
+use super::csv_export::{export_path, series_csv, ChartSeries};
+use super::drawing::tail_deque;
+use super::history::{age_pyramid, income_distribution, AGE_BANDS, MAX_HISTORY};
+use super::TimeRange;
+
+#[test]
+fn test_history_covers_ten_years() {
+    assert!(MAX_HISTORY >= TimeRange::TenYears.max_points());
+}
+
+#[test]
+fn test_age_pyramid_bands() {
+    let pyramid = age_pyramid(
+        [
+            (4, Gender::Male),
+            (9, Gender::Female),
+            (35, Gender::Female),
+            (120, Gender::Male),
+        ]
+        .into_iter(),
+    );
+    assert_eq!(pyramid[0], (1, 1));
+    assert_eq!(pyramid[3], (0, 1));
+    // Everyone past the last band lands in it.
+    assert_eq!(pyramid[AGE_BANDS - 1], (1, 0));
+}
+
+#[test]
+fn test_income_distribution_skips_unpaid() {
+    let brackets = income_distribution([0.0, 500.0, 1500.0, 2200.0, 9000.0].into_iter());
+    assert_eq!(brackets, [1, 1, 1, 0, 0, 1]);
+}
+
+#[test]
+fn test_series_csv_pads_short_columns() {
+    let csv = series_csv(&[
+        ChartSeries::new("day", [10_u32, 20, 30]),
+        ChartSeries::new("walk, %", [1.5_f32, 2.0]),
+    ]);
+    assert_eq!(csv, "day,\"walk, %\"\n10,1.5\n20,2\n30,\n");
+}
+
+#[test]
+fn test_export_path_slug() {
+    assert_eq!(export_path("Mode Share"), "megacity_mode_share.csv");
+}
+
+#[test]
+fn test_tail_deque_keeps_latest() {
+    let data: std::collections::VecDeque<u32> = (0..10).collect();
+    assert_eq!(tail_deque(&data, 3), vec![7, 8, 9]);
+    assert_eq!(tail_deque(&data, 50).len(), 10);
+}
@@ -0,0 +1,152 @@
+//! Time-lapse player tab: replays recorded city snapshots and exports them
+//! as an animated GIF or a PNG sequence.
+
+use bevy_egui::egui;
+
+use simulation::timelapse::{TimelapseHistory, TimelapsePlayer, FRAME_HEIGHT, FRAME_WIDTH};
+
+/// Output path for GIF export (relative to the working directory).
+#[cfg(not(target_arch = "wasm32"))]
+const EXPORT_PATH: &str = "megacity_timelapse.gif";
+
+/// Output directory for PNG sequence export (relative to the working
+/// directory).
+#[cfg(not(target_arch = "wasm32"))]
+const PNG_EXPORT_DIR: &str = "megacity_timelapse";
+
+/// Pixel enlargement used by both exports.
+#[cfg(not(target_arch = "wasm32"))]
+const EXPORT_SCALE: usize = 2;
+
+/// Write every frame to `dir` as `frame_0001.png`, `frame_0002.png`, ...
+/// replacing frames left over from an earlier export. Returns the number of
+/// files written.
+#[cfg(not(target_arch = "wasm32"))]
+fn export_png_sequence(
+    frames: &[simulation::timelapse::TimelapseFrame],
+    dir: &std::path::Path,
+) -> Result<usize, String> {
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    for entry in std::fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with("frame_") && name.ends_with(".png") {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+
+    let size = bevy::math::UVec2::new(
+        (FRAME_WIDTH * EXPORT_SCALE) as u32,
+        (FRAME_HEIGHT * EXPORT_SCALE) as u32,
+    );
+    for (i, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("frame_{:04}.png", i + 1));
+        let chunks = [
+            ("Title", format!("Day {}", frame.day)),
+            ("Comment", format!("Population {}", frame.population)),
+        ];
+        rendering::photo_mode::write_png(
+            &path.to_string_lossy(),
+            size,
+            &frame.decode_rgb_scaled(EXPORT_SCALE),
+            &chunks,
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(frames.len())
+}
+
+/// Cached texture for the frame currently displayed.
+#[derive(Default)]
+pub(crate) struct TimelapseView {
+    texture: Option<(usize, u32, egui::TextureHandle)>,
+    status: Option<String>,
+}
+
+pub(crate) fn draw_timelapse(
+    ui: &mut egui::Ui,
+    history: &TimelapseHistory,
+    player: &mut TimelapsePlayer,
+    view: &mut TimelapseView,
+) {
+    let count = history.frames.len();
+    let Some(index) = player.current_frame(count) else {
+        ui.label("No snapshots recorded yet...");
+        return;
+    };
+    let frame = &history.frames[index];
+
+    // Rebuild the texture only when the displayed frame changes.
+    let stale = !matches!(&view.texture, Some((i, d, _)) if *i == index && *d == frame.day);
+    if stale {
+        let rgb = frame.decode_rgb();
+        let image = egui::ColorImage::from_rgb([FRAME_WIDTH, FRAME_HEIGHT], &rgb);
+        let handle = ui
+            .ctx()
+            .load_texture("timelapse_frame", image, egui::TextureOptions::NEAREST);
+        view.texture = Some((index, frame.day, handle));
+    }
+    if let Some((_, _, texture)) = &view.texture {
+        ui.image((texture.id(), egui::vec2(256.0, 256.0)));
+    }
+
+    ui.label(format!(
+        "Day {} — population {} ({}/{})",
+        frame.day,
+        frame.population,
+        index + 1,
+        count
+    ));
+
+    ui.horizontal(|ui| {
+        let label = if player.playing { "Pause" } else { "Play" };
+        if ui.button(label).clicked() {
+            if !player.playing && index + 1 == count {
+                player.seek(0);
+            }
+            player.playing = !player.playing;
+        }
+        let mut selected = index;
+        if ui
+            .add(egui::Slider::new(&mut selected, 0..=count - 1).show_value(false))
+            .changed()
+        {
+            player.seek(selected);
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Speed:");
+        ui.add(egui::Slider::new(&mut player.fps, 1.0..=30.0).suffix(" fps"));
+        ui.checkbox(&mut player.looping, "Loop");
+    });
+
+    #[cfg(not(target_arch = "wasm32"))]
+    ui.horizontal(|ui| {
+        if ui.button("Export GIF").clicked() {
+            let path = std::path::Path::new(EXPORT_PATH);
+            let delay_cs = (100.0 / player.fps.max(1.0)) as u16;
+            let frames = &history.frames;
+            view.status = Some(
+                match simulation::timelapse::gif::export_gif(frames, path, EXPORT_SCALE, delay_cs) {
+                    Ok(bytes) => format!("Exported {} ({} KB)", EXPORT_PATH, bytes / 1024),
+                    Err(e) => format!("Export failed: {e}"),
+                },
+            );
+        }
+        if ui.button("Export PNG frames").clicked() {
+            let dir = std::path::Path::new(PNG_EXPORT_DIR);
+            view.status = Some(match export_png_sequence(&history.frames, dir) {
+                Ok(n) => format!("Exported {n} frames to {PNG_EXPORT_DIR}/"),
+                Err(e) => format!("Export failed: {e}"),
+            });
+        }
+    });
+    if let Some(status) = &view.status {
+        ui.small(status);
+    }
+    ui.small(format!(
+        "Capturing every {} days — {} frames stored",
+        history.interval_days, count
+    ));
+}
@@ -7,13 +7,18 @@ use bevy_egui::egui;
 
 use simulation::chart_data::ChartHistory;
 
+use super::csv_export::{export_button, ChartSeries};
 use super::drawing::congestion_color;
 
 // -----------------------------------------------------------------------
 // Traffic congestion by hour (24-bar chart)
 // -----------------------------------------------------------------------
 
-pub(crate) fn draw_traffic_chart(ui: &mut egui::Ui, chart: &ChartHistory) {
+pub(crate) fn draw_traffic_chart(
+    ui: &mut egui::Ui,
+    chart: &ChartHistory,
+    export: &mut Option<String>,
+) {
     ui.heading("Congestion by Hour");
 
     let (rect, _) = ui.allocate_exact_size(egui::vec2(380.0, 140.0), egui::Sense::hover());
@@ -70,13 +75,23 @@ pub(crate) fn draw_traffic_chart(ui: &mut egui::Ui, chart: &ChartHistory) {
         peak_hour,
         peak_val * 100.0
     ));
+    export_button(ui, export, "Congestion by Hour", || {
+        vec![
+            ChartSeries::new("hour", 0..24_u32),
+            ChartSeries::new("congestion", chart.traffic_hourly.congestion),
+        ]
+    });
 }
 
 // -----------------------------------------------------------------------
 // Service coverage radar/spider chart
 // -----------------------------------------------------------------------
 
-pub(crate) fn draw_service_radar(ui: &mut egui::Ui, chart: &ChartHistory) {
+pub(crate) fn draw_service_radar(
+    ui: &mut egui::Ui,
+    chart: &ChartHistory,
+    export: &mut Option<String>,
+) {
     ui.heading("Service Coverage");
 
     let cov = &chart.service_coverage;
@@ -187,13 +202,23 @@ pub(crate) fn draw_service_radar(ui: &mut egui::Ui, chart: &ChartHistory) {
             ui.label(format!("{}: {:.0}%", label, val * 100.0));
         }
     });
+    export_button(ui, export, "Service Coverage", || {
+        categories
+            .iter()
+            .map(|&(label, val)| ChartSeries::new(label, [val]))
+            .collect()
+    });
 }
 
 // -----------------------------------------------------------------------
 // Happiness breakdown stacked horizontal bar
 // -----------------------------------------------------------------------
 
-pub(crate) fn draw_happiness_breakdown(ui: &mut egui::Ui, chart: &ChartHistory) {
+pub(crate) fn draw_happiness_breakdown(
+    ui: &mut egui::Ui,
+    chart: &ChartHistory,
+    export: &mut Option<String>,
+) {
     ui.heading("Happiness Breakdown");
 
     let hap = &chart.happiness;
@@ -296,4 +321,10 @@ pub(crate) fn draw_happiness_breakdown(ui: &mut egui::Ui, chart: &ChartHistory)
     // Net happiness
     let net: f32 = factors.iter().map(|(_, v, _)| v).sum();
     ui.label(format!("Net happiness contribution: {:.1}", net));
+    export_button(ui, export, "Happiness Breakdown", || {
+        factors
+            .iter()
+            .map(|&(name, val, _)| ChartSeries::new(name, [val]))
+            .collect()
+    });
 }
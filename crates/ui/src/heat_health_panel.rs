@@ -0,0 +1,130 @@
+//! Heat-health action plan window.
+//!
+//! Choose which measures a heat alert activates and follow the current
+//! alert: forecast lead time, cooling center coverage and the share of
+//! heat deaths being prevented. Opened from the Policies window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::heat_health::{
+    HeatHealthPlan, COOLING_SITE_DAILY_COST, HYDRATION_STATION_DAILY_COST, MAX_HYDRATION_STATIONS,
+    OUTREACH_DAILY_COST,
+};
+use simulation::heat_wave::HeatWaveState;
+use simulation::weather::WeatherForecast;
+
+/// Whether the heat-health window is visible.
+#[derive(Resource, Default)]
+pub struct HeatHealthPanelVisible(pub bool);
+
+/// Renders the heat-health action plan window.
+pub fn heat_health_panel_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<HeatHealthPanelVisible>,
+    mut plan: ResMut<HeatHealthPlan>,
+    forecast: Res<WeatherForecast>,
+    heat_wave: Res<HeatWaveState>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Heat-Health Plan")
+        .open(&mut open)
+        .default_width(300.0)
+        .show(contexts.ctx_mut(), |ui| {
+            match forecast.heat_wave_in_days {
+                Some(days) => ui.colored_label(
+                    egui::Color32::from_rgb(230, 140, 40),
+                    format!("Forecast: heat wave in {days} days"),
+                ),
+                None => ui.label("Forecast: no heat wave expected"),
+            };
+
+            ui.separator();
+            ui.heading("Measures");
+            ui.checkbox(
+                &mut plan.cooling_centers,
+                format!(
+                    "Cooling centers (${:.0}/day per site)",
+                    COOLING_SITE_DAILY_COST
+                ),
+            );
+            ui.small("  Libraries, community centers and senior centers");
+            ui.checkbox(
+                &mut plan.outreach,
+                format!(
+                    "Vulnerable-resident outreach (${:.0}/day)",
+                    OUTREACH_DAILY_COST
+                ),
+            );
+            ui.add(
+                egui::Slider::new(&mut plan.hydration_stations, 0..=MAX_HYDRATION_STATIONS).text(
+                    format!(
+                        "Hydration stations (${:.0}/day each)",
+                        HYDRATION_STATION_DAILY_COST
+                    ),
+                ),
+            );
+
+            ui.separator();
+            if !plan.alert_active {
+                ui.label("No heat alert in force.");
+            } else {
+                ui.colored_label(egui::Color32::from_rgb(220, 80, 50), "Heat alert in force");
+                egui::Grid::new("heat_health_alert")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Warning given:");
+                        ui.label(format!("{} days", plan.lead_days));
+                        ui.end_row();
+                        ui.label("Readiness:");
+                        ui.label(format!("{:.0}%", plan.readiness * 100.0));
+                        ui.end_row();
+                        ui.label("Cooling sites:");
+                        ui.label(format!(
+                            "{} ({:.0}% of residents in reach)",
+                            plan.cooling_sites,
+                            plan.cooling_coverage * 100.0
+                        ));
+                        ui.end_row();
+                        ui.label("Deaths prevented:");
+                        ui.label(format!("{:.0}%", plan.mortality_reduction * 100.0));
+                        ui.end_row();
+                        ui.label("Excess deaths:");
+                        ui.label(format!(
+                            "{:.2} per 100k (from {:.2})",
+                            plan.net_excess_mortality_per_100k, heat_wave.excess_mortality_per_100k
+                        ));
+                        ui.end_row();
+                        ui.label("Daily cost:");
+                        ui.label(format!("${:.0}", plan.daily_cost()));
+                        ui.end_row();
+                    });
+            }
+
+            ui.separator();
+            ui.small(format!(
+                "Lives saved so far: {:.0}  |  Spent: ${:.0}",
+                plan.lives_saved, plan.total_cost
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct HeatHealthPanelPlugin;
+
+impl Plugin for HeatHealthPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeatHealthPanelVisible>().add_systems(
+            Update,
+            heat_health_panel_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -2,14 +2,16 @@
 //!
 //! Toggled via F1 (or the user's configured `toggle_help` binding).
 //! Displays a read-only reference of all current keybindings in a centered
-//! egui window, grouped by category. Can be dismissed with the Close button,
-//! Escape, or pressing F1 again.
+//! egui window, grouped by category, below the list of tutorials the player
+//! can start. Can be dismissed with the Close button, Escape, or pressing F1
+//! again.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use simulation::app_state::AppState;
 use simulation::keybindings::{BindableAction, KeyBindings};
+use simulation::tutorial::{TutorialLibrary, TutorialState};
 
 /// Whether the help overlay is currently visible.
 #[derive(Resource, Default)]
@@ -38,6 +40,8 @@ fn help_overlay_ui(
     mut contexts: EguiContexts,
     mut open: ResMut<HelpOverlayOpen>,
     bindings: Res<KeyBindings>,
+    mut tutorial: ResMut<TutorialState>,
+    library: Res<TutorialLibrary>,
 ) {
     if !open.0 {
         return;
@@ -62,36 +66,51 @@ fn help_overlay_ui(
             );
             ui.add_space(8.0);
 
-            let mut current_category = "";
+            ui.heading("Tutorials");
+            ui.separator();
+            for definition in &library.tutorials {
+                ui.horizontal(|ui| {
+                    ui.colored_label(egui::Color32::from_gray(220), &definition.name)
+                        .on_hover_text(&definition.description);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("Start").clicked() {
+                            tutorial.start(definition.clone());
+                            should_close = true;
+                        }
+                    });
+                });
+            }
+            ui.add_space(6.0);
 
-            for &action in BindableAction::ALL {
-                let category = action.category();
-                if category != current_category {
-                    if !current_category.is_empty() {
-                        ui.add_space(6.0);
-                    }
-                    ui.heading(category);
-                    ui.separator();
-                    current_category = category;
+            for (i, category) in BindableAction::categories().into_iter().enumerate() {
+                if i > 0 {
+                    ui.add_space(6.0);
                 }
+                ui.heading(category);
+                ui.separator();
 
-                let binding = bindings.get(action);
-                ui.horizontal(|ui| {
-                    ui.colored_label(
-                        egui::Color32::from_gray(220),
-                        action.label(),
-                    );
-                    ui.with_layout(
-                        egui::Layout::right_to_left(egui::Align::Center),
-                        |ui| {
-                            ui.colored_label(
-                                egui::Color32::from_rgb(130, 200, 255),
-                                egui::RichText::new(binding.display_label())
-                                    .monospace(),
-                            );
-                        },
-                    );
-                });
+                for &action in BindableAction::ALL {
+                    if action.category() != category {
+                        continue;
+                    }
+                    let binding = bindings.get(action);
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            egui::Color32::from_gray(220),
+                            action.label(),
+                        );
+                        ui.with_layout(
+                            egui::Layout::right_to_left(egui::Align::Center),
+                            |ui| {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(130, 200, 255),
+                                    egui::RichText::new(binding.display_label())
+                                        .monospace(),
+                                );
+                            },
+                        );
+                    });
+                }
             }
 
             ui.add_space(12.0);
@@ -3,6 +3,8 @@ use bevy_egui::{egui, EguiContexts};
 
 use simulation::economy::CityBudget;
 
+use super::budget_breakdown::{draw_budget_breakdown, trend_indicator};
+use super::budget_sankey::{draw_budget_sankey, snapshot_budget_flows, BudgetFlowSnapshot};
 use super::types::BudgetPanelVisible;
 
 // ---------------------------------------------------------------------------
@@ -27,6 +29,9 @@ pub struct PrevIncome {
     pub industrial_tax: f64,
     pub office_tax: f64,
     pub trade_income: f64,
+    pub insurance_payouts: f64,
+    pub disaster_aid: f64,
+    pub carbon_revenue: f64,
 }
 
 #[derive(Default, Clone)]
@@ -36,6 +41,7 @@ pub struct PrevExpenses {
     pub policy_costs: f64,
     pub loan_payments: f64,
     pub fuel_costs: f64,
+    pub insurance_premiums: f64,
 }
 
 /// System that snapshots budget values every 30 days for trend comparison.
@@ -59,6 +65,9 @@ pub fn snapshot_budget_trends(
         industrial_tax: inc.industrial_tax,
         office_tax: inc.office_tax,
         trade_income: inc.trade_income,
+        insurance_payouts: inc.insurance_payouts,
+        disaster_aid: inc.disaster_aid,
+        carbon_revenue: inc.carbon_revenue,
     };
     trends.prev_expenses = PrevExpenses {
         road_maintenance: exp.road_maintenance,
@@ -66,42 +75,54 @@ pub fn snapshot_budget_trends(
         policy_costs: exp.policy_costs,
         loan_payments: exp.loan_payments,
         fuel_costs: exp.fuel_costs,
+        insurance_premiums: exp.insurance_premiums,
     };
     trends.prev_total_income = inc.residential_tax
         + inc.commercial_tax
         + inc.industrial_tax
         + inc.office_tax
-        + inc.trade_income;
-    trends.prev_total_expenses =
-        exp.road_maintenance + exp.service_costs + exp.policy_costs + exp.loan_payments + exp.fuel_costs;
+        + inc.trade_income
+        + inc.insurance_payouts
+        + inc.disaster_aid
+        + inc.carbon_revenue;
+    trends.prev_total_expenses = exp.road_maintenance
+        + exp.service_costs
+        + exp.policy_costs
+        + exp.loan_payments
+        + exp.fuel_costs
+        + exp.insurance_premiums;
 }
 
 // ---------------------------------------------------------------------------
 // Colors
 // ---------------------------------------------------------------------------
 
-const COLOR_INCOME_GREEN: egui::Color32 = egui::Color32::from_rgb(80, 200, 80);
-const COLOR_EXPENSE_RED: egui::Color32 = egui::Color32::from_rgb(220, 80, 80);
-const COLOR_BAR_BG: egui::Color32 = egui::Color32::from_rgb(40, 40, 50);
+pub(super) const COLOR_INCOME_GREEN: egui::Color32 = egui::Color32::from_rgb(80, 200, 80);
+pub(super) const COLOR_EXPENSE_RED: egui::Color32 = egui::Color32::from_rgb(220, 80, 80);
+pub(super) const COLOR_BAR_BG: egui::Color32 = egui::Color32::from_rgb(40, 40, 50);
 const COLOR_NET_POSITIVE: egui::Color32 = egui::Color32::from_rgb(80, 220, 80);
 const COLOR_NET_NEGATIVE: egui::Color32 = egui::Color32::from_rgb(255, 60, 60);
 
 /// Per-category income colors (shades of green/teal).
-const INCOME_COLORS: [egui::Color32; 5] = [
+pub(crate) const INCOME_COLORS: [egui::Color32; 8] = [
     egui::Color32::from_rgb(76, 175, 80),   // Residential - green
     egui::Color32::from_rgb(38, 166, 154),  // Commercial - teal
     egui::Color32::from_rgb(129, 199, 132), // Industrial - light green
     egui::Color32::from_rgb(0, 150, 136),   // Office - dark teal
     egui::Color32::from_rgb(174, 213, 129), // Tourism - lime
+    egui::Color32::from_rgb(77, 182, 172),  // Insurance payouts - light teal
+    egui::Color32::from_rgb(102, 187, 106), // Disaster aid - mid green
+    egui::Color32::from_rgb(156, 204, 101), // Carbon revenue - olive
 ];
 
 /// Per-category expense colors (shades of red/orange).
-const EXPENSE_COLORS: [egui::Color32; 5] = [
-    egui::Color32::from_rgb(239, 83, 80),  // Road maintenance - red
-    egui::Color32::from_rgb(255, 152, 0),  // Service costs - orange
-    egui::Color32::from_rgb(255, 112, 67), // Policy costs - deep orange
-    egui::Color32::from_rgb(244, 67, 54),  // Loan payments - bright red
-    egui::Color32::from_rgb(255, 183, 77), // Fuel costs - amber
+pub(crate) const EXPENSE_COLORS: [egui::Color32; 6] = [
+    egui::Color32::from_rgb(239, 83, 80),   // Road maintenance - red
+    egui::Color32::from_rgb(255, 152, 0),   // Service costs - orange
+    egui::Color32::from_rgb(255, 112, 67),  // Policy costs - deep orange
+    egui::Color32::from_rgb(244, 67, 54),   // Loan payments - bright red
+    egui::Color32::from_rgb(255, 183, 77),  // Fuel costs - amber
+    egui::Color32::from_rgb(229, 115, 115), // Insurance premiums - rose
 ];
 
 // ---------------------------------------------------------------------------
@@ -109,13 +130,16 @@ const EXPENSE_COLORS: [egui::Color32; 5] = [
 // ---------------------------------------------------------------------------
 
 /// Displays a comprehensive budget breakdown window with income and expense
-/// categories, percentages, colored bars, and trend indicators.
+/// categories, percentages, colored bars, and trend indicators, or the same
+/// money as a flow diagram.
 pub fn budget_panel_ui(
     mut contexts: EguiContexts,
     budget: Res<CityBudget>,
     ext_budget: Res<simulation::budget::ExtendedBudget>,
     mut visible: ResMut<BudgetPanelVisible>,
     trends: Res<BudgetTrends>,
+    flows: Res<BudgetFlowSnapshot>,
+    mut show_flows: Local<bool>,
 ) {
     if !visible.0 {
         return;
@@ -128,12 +152,16 @@ pub fn budget_panel_ui(
         + income.commercial_tax
         + income.industrial_tax
         + income.office_tax
-        + income.trade_income;
+        + income.trade_income
+        + income.insurance_payouts
+        + income.disaster_aid
+        + income.carbon_revenue;
     let total_expenses = expenses.road_maintenance
         + expenses.service_costs
         + expenses.policy_costs
         + expenses.loan_payments
-        + expenses.fuel_costs;
+        + expenses.fuel_costs
+        + expenses.insurance_premiums;
     let net = total_income - total_expenses;
 
     let mut open = true;
@@ -163,110 +191,19 @@ pub fn budget_panel_ui(
                     ui.colored_label(net_color, format!("Net: {sign}${:.0}/mo", net));
                 });
             });
-            ui.separator();
-
-            // ---- Stacked overview bar ----
-            draw_stacked_bar(ui, total_income, total_expenses);
-            ui.add_space(4.0);
-
-            // ---- Income section ----
-            ui.heading("Income");
-            ui.add_space(2.0);
-
-            let income_items: [(&str, f64, f64, egui::Color32); 5] = [
-                (
-                    "Residential Tax",
-                    income.residential_tax,
-                    trends.prev_income.residential_tax,
-                    INCOME_COLORS[0],
-                ),
-                (
-                    "Commercial Tax",
-                    income.commercial_tax,
-                    trends.prev_income.commercial_tax,
-                    INCOME_COLORS[1],
-                ),
-                (
-                    "Industrial Tax",
-                    income.industrial_tax,
-                    trends.prev_income.industrial_tax,
-                    INCOME_COLORS[2],
-                ),
-                (
-                    "Office Tax",
-                    income.office_tax,
-                    trends.prev_income.office_tax,
-                    INCOME_COLORS[3],
-                ),
-                (
-                    "Trade / Tourism",
-                    income.trade_income,
-                    trends.prev_income.trade_income,
-                    INCOME_COLORS[4],
-                ),
-            ];
-
-            for (label, amount, prev, color) in &income_items {
-                budget_line_with_bar(ui, label, *amount, total_income, *prev, *color);
-            }
-
-            ui.add_space(2.0);
             ui.horizontal(|ui| {
-                ui.strong("Total Income:");
-                ui.colored_label(COLOR_INCOME_GREEN, format!("${:.0}/mo", total_income));
-                trend_indicator(ui, total_income, trends.prev_total_income);
+                ui.selectable_value(&mut *show_flows, false, "Breakdown");
+                ui.selectable_value(&mut *show_flows, true, "Flow");
             });
-            ui.add_space(6.0);
-
-            // ---- Expenses section ----
             ui.separator();
-            ui.heading("Expenses");
-            ui.add_space(2.0);
 
-            let expense_items: [(&str, f64, f64, egui::Color32); 5] = [
-                (
-                    "Road Maintenance",
-                    expenses.road_maintenance,
-                    trends.prev_expenses.road_maintenance,
-                    EXPENSE_COLORS[0],
-                ),
-                (
-                    "Service Costs",
-                    expenses.service_costs,
-                    trends.prev_expenses.service_costs,
-                    EXPENSE_COLORS[1],
-                ),
-                (
-                    "Policy Costs",
-                    expenses.policy_costs,
-                    trends.prev_expenses.policy_costs,
-                    EXPENSE_COLORS[2],
-                ),
-                (
-                    "Loan Payments",
-                    expenses.loan_payments,
-                    trends.prev_expenses.loan_payments,
-                    EXPENSE_COLORS[3],
-                ),
-                (
-                    "Power Fuel",
-                    expenses.fuel_costs,
-                    trends.prev_expenses.fuel_costs,
-                    EXPENSE_COLORS[0],
-                ),
-            ];
-
-            for (label, amount, prev, color) in &expense_items {
-                budget_line_with_bar(ui, label, *amount, total_expenses, *prev, *color);
+            // ---- Flow diagram ----
+            if *show_flows {
+                draw_budget_sankey(ui, &flows.flows);
+                return;
             }
 
-            ui.add_space(2.0);
-            ui.horizontal(|ui| {
-                ui.strong("Total Expenses:");
-                ui.colored_label(COLOR_EXPENSE_RED, format!("${:.0}/mo", total_expenses));
-                trend_indicator(ui, total_expenses, trends.prev_total_expenses);
-            });
-            ui.add_space(6.0);
+            draw_budget_breakdown(ui, income, expenses, &trends, total_income, total_expenses);
 
             // ---- Net income ----
             ui.separator();
@@ -313,164 +250,23 @@ pub fn budget_panel_ui(
     }
 }
 
-// ---------------------------------------------------------------------------
-// Drawing helpers
-// ---------------------------------------------------------------------------
-
-/// Draws a stacked horizontal bar showing income (green) vs expenses (red)
-/// proportionally.
-fn draw_stacked_bar(ui: &mut egui::Ui, total_income: f64, total_expenses: f64) {
-    let bar_height = 16.0;
-    let available_width = ui.available_width().min(360.0);
-    let (rect, _response) = ui.allocate_exact_size(
-        egui::vec2(available_width, bar_height),
-        egui::Sense::hover(),
-    );
-    let painter = ui.painter_at(rect);
-
-    // Background
-    painter.rect_filled(rect, 3.0, COLOR_BAR_BG);
-
-    let grand_total = total_income + total_expenses;
-    if grand_total > 0.0 {
-        let income_frac = (total_income / grand_total) as f32;
-
-        // Income portion (left, green)
-        let income_width = available_width * income_frac;
-        if income_width > 0.5 {
-            let income_rect =
-                egui::Rect::from_min_size(rect.min, egui::vec2(income_width, bar_height));
-            painter.rect_filled(income_rect, 3.0, COLOR_INCOME_GREEN);
-        }
-
-        // Expense portion (right, red)
-        let expense_width = available_width * (1.0 - income_frac);
-        if expense_width > 0.5 {
-            let expense_rect = egui::Rect::from_min_max(
-                egui::pos2(rect.min.x + income_width, rect.min.y),
-                rect.max,
-            );
-            painter.rect_filled(expense_rect, 3.0, COLOR_EXPENSE_RED);
-        }
-
-        // Labels on the bar
-        if income_frac > 0.15 {
-            let income_pct = income_frac * 100.0;
-            let income_rect =
-                egui::Rect::from_min_size(rect.min, egui::vec2(income_width, bar_height));
-            painter.text(
-                income_rect.center(),
-                egui::Align2::CENTER_CENTER,
-                format!("{income_pct:.0}%"),
-                egui::FontId::proportional(11.0),
-                egui::Color32::WHITE,
-            );
-        }
-        let expense_frac = 1.0 - income_frac;
-        if expense_frac > 0.15 {
-            let expense_pct = expense_frac * 100.0;
-            let expense_rect = egui::Rect::from_min_max(
-                egui::pos2(rect.min.x + income_width, rect.min.y),
-                rect.max,
-            );
-            painter.text(
-                expense_rect.center(),
-                egui::Align2::CENTER_CENTER,
-                format!("{expense_pct:.0}%"),
-                egui::FontId::proportional(11.0),
-                egui::Color32::WHITE,
-            );
-        }
-    }
-
-    // Legend below the bar
-    ui.horizontal(|ui| {
-        ui.colored_label(COLOR_INCOME_GREEN, "Income");
-        ui.label("|");
-        ui.colored_label(COLOR_EXPENSE_RED, "Expenses");
-    });
-}
-
-/// Renders a single budget line item with label, amount, percentage, colored bar, and trend.
-fn budget_line_with_bar(
-    ui: &mut egui::Ui,
-    label: &str,
-    amount: f64,
-    total: f64,
-    prev_amount: f64,
-    bar_color: egui::Color32,
-) {
-    let pct = if total > 0.0 {
-        (amount / total * 100.0) as f32
-    } else {
-        0.0
-    };
-
-    ui.horizontal(|ui| {
-        // Fixed-width label
-        ui.allocate_ui_with_layout(
-            egui::vec2(130.0, 18.0),
-            egui::Layout::left_to_right(egui::Align::Center),
-            |ui| {
-                ui.label(format!("  {label}"));
-            },
-        );
-
-        // Colored progress bar
-        let bar_width = 80.0;
-        let bar_height = 12.0;
-        let (bar_rect, _) =
-            ui.allocate_exact_size(egui::vec2(bar_width, bar_height), egui::Sense::hover());
-        let painter = ui.painter_at(bar_rect);
-        painter.rect_filled(bar_rect, 2.0, COLOR_BAR_BG);
-        if pct > 0.0 {
-            let fill_width = bar_width * (pct / 100.0).min(1.0);
-            let fill_rect =
-                egui::Rect::from_min_size(bar_rect.min, egui::vec2(fill_width, bar_height));
-            painter.rect_filled(fill_rect, 2.0, bar_color);
-        }
-
-        // Amount and percentage
-        ui.label(format!("${:.0}", amount));
-        ui.colored_label(
-            egui::Color32::from_rgb(160, 160, 180),
-            format!("({pct:.0}%)"),
-        );
-
-        // Trend indicator
-        trend_indicator(ui, amount, prev_amount);
-    });
-}
-
-/// Draws a small trend arrow: green up-arrow if value increased, red down-arrow
-/// if decreased, grey dash if unchanged.
-fn trend_indicator(ui: &mut egui::Ui, current: f64, previous: f64) {
-    let diff = current - previous;
-    let threshold = 0.5; // Ignore tiny fluctuations
-    if diff > threshold {
-        ui.colored_label(egui::Color32::from_rgb(100, 220, 100), "\u{25B2}"); // up triangle
-    } else if diff < -threshold {
-        ui.colored_label(egui::Color32::from_rgb(220, 100, 100), "\u{25BC}"); // down triangle
-    } else {
-        ui.colored_label(egui::Color32::from_rgb(120, 120, 120), "\u{2014}"); // em dash
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Plugin
 // ---------------------------------------------------------------------------
 
-/// Plugin that registers the budget trend tracking system.
+/// Plugin that registers the budget trend and flow tracking systems.
 /// The budget panel UI itself is registered in `UiPlugin`.
 pub struct BudgetBreakdownPlugin;
 
 impl Plugin for BudgetBreakdownPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<BudgetTrends>().add_systems(
-            FixedUpdate,
-            snapshot_budget_trends
-                .after(simulation::economy::collect_taxes)
-                .in_set(simulation::SimulationSet::PostSim),
-        );
+        app.init_resource::<BudgetTrends>()
+            .init_resource::<BudgetFlowSnapshot>()
+            .add_systems(
+                FixedUpdate,
+                (snapshot_budget_trends, snapshot_budget_flows)
+                    .after(simulation::economy::collect_taxes)
+                    .in_set(simulation::SimulationSet::PostSim),
+            );
     }
 }
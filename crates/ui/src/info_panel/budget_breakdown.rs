@@ -0,0 +1,287 @@
+//! The "Breakdown" view of the budget panel: an income vs expenses bar and
+//! every income and expense category with its share and trend.
+
+use bevy_egui::egui;
+
+use simulation::budget::{ExpenseBreakdown, IncomeBreakdown};
+
+use super::budget::{
+    BudgetTrends, COLOR_BAR_BG, COLOR_EXPENSE_RED, COLOR_INCOME_GREEN, EXPENSE_COLORS,
+    INCOME_COLORS,
+};
+
+/// Draws the stacked overview bar and the income and expense line items.
+pub(super) fn draw_budget_breakdown(
+    ui: &mut egui::Ui,
+    income: &IncomeBreakdown,
+    expenses: &ExpenseBreakdown,
+    trends: &BudgetTrends,
+    total_income: f64,
+    total_expenses: f64,
+) {
+    // ---- Stacked overview bar ----
+    draw_stacked_bar(ui, total_income, total_expenses);
+    ui.add_space(4.0);
+
+    // ---- Income section ----
+    ui.heading("Income");
+    ui.add_space(2.0);
+
+    let income_items: [(&str, f64, f64, egui::Color32); 8] = [
+        (
+            "Residential Tax",
+            income.residential_tax,
+            trends.prev_income.residential_tax,
+            INCOME_COLORS[0],
+        ),
+        (
+            "Commercial Tax",
+            income.commercial_tax,
+            trends.prev_income.commercial_tax,
+            INCOME_COLORS[1],
+        ),
+        (
+            "Industrial Tax",
+            income.industrial_tax,
+            trends.prev_income.industrial_tax,
+            INCOME_COLORS[2],
+        ),
+        (
+            "Office Tax",
+            income.office_tax,
+            trends.prev_income.office_tax,
+            INCOME_COLORS[3],
+        ),
+        (
+            "Trade / Tourism",
+            income.trade_income,
+            trends.prev_income.trade_income,
+            INCOME_COLORS[4],
+        ),
+        (
+            "Insurance Payouts",
+            income.insurance_payouts,
+            trends.prev_income.insurance_payouts,
+            INCOME_COLORS[5],
+        ),
+        (
+            "Disaster Aid",
+            income.disaster_aid,
+            trends.prev_income.disaster_aid,
+            INCOME_COLORS[6],
+        ),
+        (
+            "Carbon Revenue",
+            income.carbon_revenue,
+            trends.prev_income.carbon_revenue,
+            INCOME_COLORS[7],
+        ),
+    ];
+
+    for (label, amount, prev, color) in &income_items {
+        budget_line_with_bar(ui, label, *amount, total_income, *prev, *color);
+    }
+
+    ui.add_space(2.0);
+    ui.horizontal(|ui| {
+        ui.strong("Total Income:");
+        ui.colored_label(COLOR_INCOME_GREEN, format!("${:.0}/mo", total_income));
+        trend_indicator(ui, total_income, trends.prev_total_income);
+    });
+    ui.add_space(6.0);
+
+    // ---- Expenses section ----
+    ui.separator();
+    ui.heading("Expenses");
+    ui.add_space(2.0);
+
+    let expense_items: [(&str, f64, f64, egui::Color32); 6] = [
+        (
+            "Road Maintenance",
+            expenses.road_maintenance,
+            trends.prev_expenses.road_maintenance,
+            EXPENSE_COLORS[0],
+        ),
+        (
+            "Service Costs",
+            expenses.service_costs,
+            trends.prev_expenses.service_costs,
+            EXPENSE_COLORS[1],
+        ),
+        (
+            "Policy Costs",
+            expenses.policy_costs,
+            trends.prev_expenses.policy_costs,
+            EXPENSE_COLORS[2],
+        ),
+        (
+            "Loan Payments",
+            expenses.loan_payments,
+            trends.prev_expenses.loan_payments,
+            EXPENSE_COLORS[3],
+        ),
+        (
+            "Power Fuel",
+            expenses.fuel_costs,
+            trends.prev_expenses.fuel_costs,
+            EXPENSE_COLORS[0],
+        ),
+        (
+            "Disaster Insurance",
+            expenses.insurance_premiums,
+            trends.prev_expenses.insurance_premiums,
+            EXPENSE_COLORS[5],
+        ),
+    ];
+
+    for (label, amount, prev, color) in &expense_items {
+        budget_line_with_bar(ui, label, *amount, total_expenses, *prev, *color);
+    }
+
+    ui.add_space(2.0);
+    ui.horizontal(|ui| {
+        ui.strong("Total Expenses:");
+        ui.colored_label(COLOR_EXPENSE_RED, format!("${:.0}/mo", total_expenses));
+        trend_indicator(ui, total_expenses, trends.prev_total_expenses);
+    });
+    ui.add_space(6.0);
+}
+
+/// Draws a stacked horizontal bar showing income (green) vs expenses (red)
+/// proportionally.
+fn draw_stacked_bar(ui: &mut egui::Ui, total_income: f64, total_expenses: f64) {
+    let bar_height = 16.0;
+    let available_width = ui.available_width().min(360.0);
+    let (rect, _response) = ui.allocate_exact_size(
+        egui::vec2(available_width, bar_height),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+
+    // Background
+    painter.rect_filled(rect, 3.0, COLOR_BAR_BG);
+
+    let grand_total = total_income + total_expenses;
+    if grand_total > 0.0 {
+        let income_frac = (total_income / grand_total) as f32;
+
+        // Income portion (left, green)
+        let income_width = available_width * income_frac;
+        if income_width > 0.5 {
+            let income_rect =
+                egui::Rect::from_min_size(rect.min, egui::vec2(income_width, bar_height));
+            painter.rect_filled(income_rect, 3.0, COLOR_INCOME_GREEN);
+        }
+
+        // Expense portion (right, red)
+        let expense_width = available_width * (1.0 - income_frac);
+        if expense_width > 0.5 {
+            let expense_rect = egui::Rect::from_min_max(
+                egui::pos2(rect.min.x + income_width, rect.min.y),
+                rect.max,
+            );
+            painter.rect_filled(expense_rect, 3.0, COLOR_EXPENSE_RED);
+        }
+
+        // Labels on the bar
+        if income_frac > 0.15 {
+            let income_pct = income_frac * 100.0;
+            let income_rect =
+                egui::Rect::from_min_size(rect.min, egui::vec2(income_width, bar_height));
+            painter.text(
+                income_rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("{income_pct:.0}%"),
+                egui::FontId::proportional(11.0),
+                egui::Color32::WHITE,
+            );
+        }
+        let expense_frac = 1.0 - income_frac;
+        if expense_frac > 0.15 {
+            let expense_pct = expense_frac * 100.0;
+            let expense_rect = egui::Rect::from_min_max(
+                egui::pos2(rect.min.x + income_width, rect.min.y),
+                rect.max,
+            );
+            painter.text(
+                expense_rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("{expense_pct:.0}%"),
+                egui::FontId::proportional(11.0),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
+    // Legend below the bar
+    ui.horizontal(|ui| {
+        ui.colored_label(COLOR_INCOME_GREEN, "Income");
+        ui.label("|");
+        ui.colored_label(COLOR_EXPENSE_RED, "Expenses");
+    });
+}
+
+/// Renders a single budget line item with label, amount, percentage, colored bar, and trend.
+fn budget_line_with_bar(
+    ui: &mut egui::Ui,
+    label: &str,
+    amount: f64,
+    total: f64,
+    prev_amount: f64,
+    bar_color: egui::Color32,
+) {
+    let pct = if total > 0.0 {
+        (amount / total * 100.0) as f32
+    } else {
+        0.0
+    };
+
+    ui.horizontal(|ui| {
+        // Fixed-width label
+        ui.allocate_ui_with_layout(
+            egui::vec2(130.0, 18.0),
+            egui::Layout::left_to_right(egui::Align::Center),
+            |ui| {
+                ui.label(format!("  {label}"));
+            },
+        );
+
+        // Colored progress bar
+        let bar_width = 80.0;
+        let bar_height = 12.0;
+        let (bar_rect, _) =
+            ui.allocate_exact_size(egui::vec2(bar_width, bar_height), egui::Sense::hover());
+        let painter = ui.painter_at(bar_rect);
+        painter.rect_filled(bar_rect, 2.0, COLOR_BAR_BG);
+        if pct > 0.0 {
+            let fill_width = bar_width * (pct / 100.0).min(1.0);
+            let fill_rect =
+                egui::Rect::from_min_size(bar_rect.min, egui::vec2(fill_width, bar_height));
+            painter.rect_filled(fill_rect, 2.0, bar_color);
+        }
+
+        // Amount and percentage
+        ui.label(format!("${:.0}", amount));
+        ui.colored_label(
+            egui::Color32::from_rgb(160, 160, 180),
+            format!("({pct:.0}%)"),
+        );
+
+        // Trend indicator
+        trend_indicator(ui, amount, prev_amount);
+    });
+}
+
+/// Draws a small trend arrow: green up-arrow if value increased, red down-arrow
+/// if decreased, grey dash if unchanged.
+pub(super) fn trend_indicator(ui: &mut egui::Ui, current: f64, previous: f64) {
+    let diff = current - previous;
+    let threshold = 0.5; // Ignore tiny fluctuations
+    if diff > threshold {
+        ui.colored_label(egui::Color32::from_rgb(100, 220, 100), "\u{25B2}"); // up triangle
+    } else if diff < -threshold {
+        ui.colored_label(egui::Color32::from_rgb(220, 100, 100), "\u{25BC}"); // down triangle
+    } else {
+        ui.colored_label(egui::Color32::from_rgb(120, 120, 120), "\u{2014}"); // em dash
+    }
+}
@@ -0,0 +1,436 @@
+//! Budget flow (Sankey) diagram.
+//!
+//! Revenue sources on the left flow into the city budget in the middle,
+//! which flows out to departments and other expenses on the right. The
+//! flows are snapshotted once a month right after tax collection; a month
+//! that spends more than it earns shows the shortfall as a red "Deficit"
+//! source drawn from the treasury.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use simulation::budget::{ExpenseBreakdown, ExtendedBudget, IncomeBreakdown};
+use simulation::loans::LoanBook;
+use simulation::services::{ServiceBuilding, ServiceType};
+use simulation::time_of_day::GameClock;
+
+use super::budget::{EXPENSE_COLORS, INCOME_COLORS};
+
+const COLOR_DEFICIT: egui::Color32 = egui::Color32::from_rgb(230, 50, 50);
+const COLOR_SURPLUS: egui::Color32 = egui::Color32::from_rgb(80, 220, 80);
+const COLOR_BUDGET_NODE: egui::Color32 = egui::Color32::from_rgb(120, 130, 160);
+
+const DIAGRAM_HEIGHT: f32 = 240.0;
+const NODE_WIDTH: f32 = 10.0;
+const NODE_GAP: f32 = 3.0;
+const LABEL_WIDTH: f32 = 96.0;
+/// Bands thinner than this get no label; hover them instead.
+const MIN_LABEL_HEIGHT: f32 = 9.0;
+/// Vertical slices per ribbon.
+const RIBBON_STEPS: usize = 16;
+
+/// Departments the service maintenance bill is split into.
+pub const DEPARTMENTS: [(&str, egui::Color32); 8] = [
+    ("Fire", egui::Color32::from_rgb(239, 83, 80)),
+    ("Police", egui::Color32::from_rgb(92, 107, 192)),
+    ("Health", egui::Color32::from_rgb(236, 64, 122)),
+    ("Education", egui::Color32::from_rgb(255, 167, 38)),
+    ("Sanitation", egui::Color32::from_rgb(141, 110, 99)),
+    ("Transport", egui::Color32::from_rgb(41, 182, 246)),
+    ("Parks", egui::Color32::from_rgb(102, 187, 106)),
+    ("Other Services", egui::Color32::from_rgb(158, 158, 158)),
+];
+
+/// Index into `DEPARTMENTS` of the department running a service.
+pub fn department_of(service_type: ServiceType) -> usize {
+    if ServiceBuilding::is_fire(service_type) {
+        0
+    } else if ServiceBuilding::is_police(service_type) {
+        1
+    } else if ServiceBuilding::is_health(service_type) {
+        2
+    } else if ServiceBuilding::is_education(service_type) {
+        3
+    } else if ServiceBuilding::is_garbage(service_type) {
+        4
+    } else if ServiceBuilding::is_transport(service_type) {
+        5
+    } else if ServiceBuilding::is_park(service_type) {
+        6
+    } else {
+        7
+    }
+}
+
+/// One node of the diagram with the money flowing through it per month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowNode {
+    pub label: &'static str,
+    pub amount: f64,
+    pub color: egui::Color32,
+}
+
+/// Monthly money flows into and out of the city budget.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BudgetFlows {
+    /// Revenue sources, plus "Deficit" when spending exceeds revenue.
+    pub sources: Vec<FlowNode>,
+    /// Departments and other expenses, plus "Surplus" when revenue is left.
+    pub sinks: Vec<FlowNode>,
+    /// Spending not covered by revenue this month.
+    pub deficit: f64,
+}
+
+impl BudgetFlows {
+    /// Money through the budget node; sources and sinks both add up to it.
+    pub fn total(&self) -> f64 {
+        self.sources.iter().map(|n| n.amount).sum()
+    }
+}
+
+/// Work out the monthly flows. `departments` holds the service maintenance
+/// of each `DEPARTMENTS` entry; `loan_book_payments` the monthly payments of
+/// the council's loan book on top of the budget's own loans.
+pub fn budget_flows(
+    income: &IncomeBreakdown,
+    expenses: &ExpenseBreakdown,
+    departments: &[f64; DEPARTMENTS.len()],
+    loan_book_payments: f64,
+) -> BudgetFlows {
+    let node = |label, amount, color| FlowNode {
+        label,
+        amount,
+        color,
+    };
+    let mut sources: Vec<FlowNode> = [
+        node("Residential Tax", income.residential_tax, INCOME_COLORS[0]),
+        node("Commercial Tax", income.commercial_tax, INCOME_COLORS[1]),
+        node("Industrial Tax", income.industrial_tax, INCOME_COLORS[2]),
+        node("Office Tax", income.office_tax, INCOME_COLORS[3]),
+        node("Trade / Tourism", income.trade_income, INCOME_COLORS[4]),
+        node(
+            "Insurance Payouts",
+            income.insurance_payouts,
+            INCOME_COLORS[5],
+        ),
+        node("Disaster Aid", income.disaster_aid, INCOME_COLORS[6]),
+        node("Carbon Revenue", income.carbon_revenue, INCOME_COLORS[7]),
+    ]
+    .into_iter()
+    .filter(|n| n.amount > 0.0)
+    .collect();
+
+    let mut sinks: Vec<FlowNode> = DEPARTMENTS
+        .iter()
+        .zip(departments)
+        .map(|(&(label, color), &amount)| node(label, amount, color))
+        .chain([
+            node("Roads", expenses.road_maintenance, EXPENSE_COLORS[0]),
+            node("Policies", expenses.policy_costs, EXPENSE_COLORS[2]),
+            node(
+                "Loan Repayments",
+                expenses.loan_payments + loan_book_payments,
+                EXPENSE_COLORS[3],
+            ),
+            node("Power Fuel", expenses.fuel_costs, EXPENSE_COLORS[4]),
+            node("Insurance", expenses.insurance_premiums, EXPENSE_COLORS[5]),
+        ])
+        .filter(|n| n.amount > 0.0)
+        .collect();
+
+    let revenue: f64 = sources.iter().map(|n| n.amount).sum();
+    let spending: f64 = sinks.iter().map(|n| n.amount).sum();
+    let deficit = (spending - revenue).max(0.0);
+    if deficit > 0.0 {
+        sources.push(node("Deficit", deficit, COLOR_DEFICIT));
+    } else if revenue > spending {
+        sinks.push(node("Surplus", revenue - spending, COLOR_SURPLUS));
+    }
+
+    BudgetFlows {
+        sources,
+        sinks,
+        deficit,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Monthly snapshot
+// ---------------------------------------------------------------------------
+
+/// Budget flows as of the last tax collection.
+#[derive(Resource, Default)]
+pub struct BudgetFlowSnapshot {
+    pub flows: BudgetFlows,
+    /// The simulation day when we last snapshotted.
+    pub last_snapshot_day: u32,
+}
+
+/// System that snapshots the budget flows every 30 days, after taxes are
+/// collected, so the diagram changes once a month.
+pub fn snapshot_budget_flows(
+    clock: Res<GameClock>,
+    ext_budget: Res<ExtendedBudget>,
+    loan_book: Res<LoanBook>,
+    services: Query<&ServiceBuilding>,
+    mut snapshot: ResMut<BudgetFlowSnapshot>,
+) {
+    if clock.day <= snapshot.last_snapshot_day + 30 {
+        return;
+    }
+    snapshot.last_snapshot_day = clock.day;
+
+    // Same formula as the service maintenance in `collect_taxes`.
+    let mut departments = [0.0; DEPARTMENTS.len()];
+    for service in &services {
+        let level = ext_budget.service_budgets.for_service(service.service_type);
+        departments[department_of(service.service_type)] +=
+            ServiceBuilding::monthly_maintenance(service.service_type) * level as f64;
+    }
+
+    snapshot.flows = budget_flows(
+        &ext_budget.income_breakdown,
+        &ext_budget.expense_breakdown,
+        &departments,
+        loan_book.total_monthly_payments(),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Drawing
+// ---------------------------------------------------------------------------
+
+/// Stack `nodes` in a column of `height`, returning each node's y-range.
+fn stack(nodes: &[FlowNode], top: f32, height: f32, scale: f32) -> Vec<(f32, f32)> {
+    let gaps = NODE_GAP * nodes.len().saturating_sub(1) as f32;
+    let used: f32 = nodes.iter().map(|n| n.amount as f32 * scale).sum::<f32>() + gaps;
+    let mut y = top + (height - used).max(0.0) / 2.0;
+    nodes
+        .iter()
+        .map(|n| {
+            let span = (y, y + (n.amount as f32 * scale).max(1.0));
+            y = span.1 + NODE_GAP;
+            span
+        })
+        .collect()
+}
+
+/// A band from `(x0, a)` to `(x1, b)`, each a vertical (top, bottom) span,
+/// eased so it leaves and enters horizontally.
+fn draw_ribbon(
+    painter: &egui::Painter,
+    (x0, a): (f32, (f32, f32)),
+    (x1, b): (f32, (f32, f32)),
+    color: egui::Color32,
+) {
+    let at = |i: usize| {
+        let t = i as f32 / RIBBON_STEPS as f32;
+        let ease = t * t * (3.0 - 2.0 * t);
+        let x = x0 + (x1 - x0) * t;
+        let top = a.0 + (b.0 - a.0) * ease;
+        let bottom = a.1 + (b.1 - a.1) * ease;
+        (x, top, bottom)
+    };
+    let fill = color.gamma_multiply(0.55);
+    for i in 0..RIBBON_STEPS {
+        let (xa, ta, ba) = at(i);
+        let (xb, tb, bb) = at(i + 1);
+        painter.add(egui::Shape::convex_polygon(
+            vec![
+                egui::pos2(xa, ta),
+                egui::pos2(xb, tb),
+                egui::pos2(xb, bb),
+                egui::pos2(xa, ba),
+            ],
+            fill,
+            egui::Stroke::NONE,
+        ));
+    }
+}
+
+fn node_text(node: &FlowNode) -> String {
+    format!("{} ${:.0}", node.label, node.amount)
+}
+
+/// Draw the flow diagram into the current UI.
+pub fn draw_budget_sankey(ui: &mut egui::Ui, flows: &BudgetFlows) {
+    let total = flows.total();
+    if total <= 0.0 {
+        ui.label("No budget data yet. Flows appear after the first tax collection.");
+        return;
+    }
+
+    let width = ui.available_width().min(380.0);
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(width, DIAGRAM_HEIGHT), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    let gaps = NODE_GAP * (flows.sources.len().max(flows.sinks.len()) as f32 - 1.0).max(0.0);
+    let scale = (DIAGRAM_HEIGHT - gaps) / total as f32;
+    let left_x = rect.min.x + LABEL_WIDTH;
+    let right_x = rect.max.x - LABEL_WIDTH - NODE_WIDTH;
+    let mid_x = (left_x + right_x) / 2.0;
+
+    let sources = stack(&flows.sources, rect.min.y, DIAGRAM_HEIGHT, scale);
+    let sinks = stack(&flows.sinks, rect.min.y, DIAGRAM_HEIGHT, scale);
+    let budget_height = total as f32 * scale;
+    let budget_top = rect.min.y + (DIAGRAM_HEIGHT - budget_height) / 2.0;
+
+    // Ribbons: each source fills its share of the budget node, which then
+    // empties into the sinks in the same top-to-bottom order.
+    let mut y = budget_top;
+    for (node, &span) in flows.sources.iter().zip(&sources) {
+        let h = node.amount as f32 * scale;
+        draw_ribbon(
+            &painter,
+            (left_x + NODE_WIDTH, span),
+            (mid_x, (y, y + h)),
+            node.color,
+        );
+        y += h;
+    }
+    let mut y = budget_top;
+    for (node, &span) in flows.sinks.iter().zip(&sinks) {
+        let h = node.amount as f32 * scale;
+        draw_ribbon(
+            &painter,
+            (mid_x + NODE_WIDTH, (y, y + h)),
+            (right_x, span),
+            node.color,
+        );
+        y += h;
+    }
+
+    // Nodes and labels
+    let font = egui::FontId::proportional(9.0);
+    let mut hovered: Option<&FlowNode> = None;
+    let hover_pos = response.hover_pos();
+    for (nodes, spans, x, align, label_x) in [
+        (
+            &flows.sources,
+            &sources,
+            left_x,
+            egui::Align2::RIGHT_CENTER,
+            left_x - 3.0,
+        ),
+        (
+            &flows.sinks,
+            &sinks,
+            right_x,
+            egui::Align2::LEFT_CENTER,
+            right_x + NODE_WIDTH + 3.0,
+        ),
+    ] {
+        for (node, &(top, bottom)) in nodes.iter().zip(spans) {
+            let node_rect =
+                egui::Rect::from_min_max(egui::pos2(x, top), egui::pos2(x + NODE_WIDTH, bottom));
+            painter.rect_filled(node_rect, 1.0, node.color);
+            if bottom - top >= MIN_LABEL_HEIGHT {
+                let color = if node.label == "Deficit" {
+                    COLOR_DEFICIT
+                } else {
+                    egui::Color32::LIGHT_GRAY
+                };
+                painter.text(
+                    egui::pos2(label_x, (top + bottom) / 2.0),
+                    align,
+                    node_text(node),
+                    font.clone(),
+                    color,
+                );
+            }
+            let band = egui::Rect::from_x_y_ranges(rect.x_range(), top..=bottom);
+            if hover_pos.is_some_and(|p| band.contains(p) && (p.x - x).abs() < LABEL_WIDTH) {
+                hovered = Some(node);
+            }
+        }
+    }
+
+    let budget_rect = egui::Rect::from_min_size(
+        egui::pos2(mid_x, budget_top),
+        egui::vec2(NODE_WIDTH, budget_height),
+    );
+    painter.rect_filled(budget_rect, 1.0, COLOR_BUDGET_NODE);
+    if flows.deficit > 0.0 {
+        painter.rect_stroke(budget_rect.expand(1.5), 1.0, (2.0, COLOR_DEFICIT));
+    }
+
+    if let Some(node) = hovered {
+        response.on_hover_text(format!(
+            "{}: {:.0}% of the budget",
+            node_text(node),
+            node.amount / total * 100.0
+        ));
+    }
+
+    if flows.deficit > 0.0 {
+        ui.colored_label(
+            COLOR_DEFICIT,
+            format!("Deficit: ${:.0}/mo drawn from the treasury", flows.deficit),
+        );
+    } else {
+        ui.label(format!("${total:.0}/mo through the budget"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surplus_balances_flows() {
+        let income = IncomeBreakdown {
+            residential_tax: 800.0,
+            commercial_tax: 200.0,
+            ..Default::default()
+        };
+        let expenses = ExpenseBreakdown {
+            road_maintenance: 300.0,
+            ..Default::default()
+        };
+        let mut departments = [0.0; DEPARTMENTS.len()];
+        departments[0] = 400.0;
+        let flows = budget_flows(&income, &expenses, &departments, 0.0);
+
+        assert_eq!(flows.deficit, 0.0);
+        assert_eq!(flows.sources.len(), 2);
+        let surplus = flows.sinks.last().unwrap();
+        assert_eq!((surplus.label, surplus.amount), ("Surplus", 300.0));
+        let out: f64 = flows.sinks.iter().map(|n| n.amount).sum();
+        assert_eq!(out, flows.total());
+    }
+
+    #[test]
+    fn test_deficit_is_a_source() {
+        let income = IncomeBreakdown {
+            residential_tax: 100.0,
+            ..Default::default()
+        };
+        let expenses = ExpenseBreakdown {
+            loan_payments: 50.0,
+            ..Default::default()
+        };
+        let departments = [0.0; DEPARTMENTS.len()];
+        let flows = budget_flows(&income, &expenses, &departments, 150.0);
+
+        assert_eq!(flows.deficit, 100.0);
+        let deficit = flows.sources.last().unwrap();
+        assert_eq!((deficit.label, deficit.color), ("Deficit", COLOR_DEFICIT));
+        assert!(flows.sinks.iter().all(|n| n.label != "Surplus"));
+        let loans = flows.sinks.iter().find(|n| n.label == "Loan Repayments");
+        assert_eq!(loans.map(|n| n.amount), Some(200.0));
+    }
+
+    #[test]
+    fn test_departments() {
+        assert_eq!(DEPARTMENTS[department_of(ServiceType::FireHQ)].0, "Fire");
+        assert_eq!(
+            DEPARTMENTS[department_of(ServiceType::SmallPark)].0,
+            "Parks"
+        );
+        assert_eq!(
+            DEPARTMENTS[department_of(ServiceType::University)].0,
+            "Education"
+        );
+    }
+}
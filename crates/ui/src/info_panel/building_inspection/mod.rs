@@ -1,5 +1,6 @@
 mod helpers;
 mod residential;
+mod structural;
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
@@ -7,31 +8,41 @@ use bevy_egui::{egui, EguiContexts};
 use simulation::buildings::Building;
 use simulation::citizen::Citizen;
 use simulation::config::CELL_SIZE;
-use simulation::config::GRID_WIDTH;
 use simulation::economy::CityBudget;
 use simulation::grid::WorldGrid;
 use simulation::land_value::LandValueGrid;
 use simulation::pollution::PollutionGrid;
+use simulation::service_capacity::ServiceCapacity;
+use simulation::service_upgrade::{check_upgrade, next_tier, ServiceUpgradeRequest};
 use simulation::services::ServiceBuilding;
+use simulation::structural_integrity::{StructuralActionRequest, StructuralState};
 use simulation::utilities::UtilitySource;
+use simulation::watchlist::{WatchTarget, Watchlist};
 
 use rendering::input::SelectedBuilding;
 
-use helpers::{power_water_labels, zone_type_name};
+pub use helpers::zone_type_name;
+
+use helpers::power_water_labels;
 use residential::{render_residential_section, render_workers_section, CitizenQuery};
+use structural::render_structural_section;
 
 #[allow(clippy::too_many_arguments)]
 pub fn building_inspection_ui(
     mut contexts: EguiContexts,
     selected: Res<SelectedBuilding>,
     buildings: Query<&Building>,
-    service_buildings: Query<&ServiceBuilding>,
+    service_buildings: Query<(&ServiceBuilding, Option<&ServiceCapacity>)>,
     utility_sources: Query<&UtilitySource>,
     citizens: Query<CitizenQuery, With<Citizen>>,
     grid: Res<WorldGrid>,
     pollution: Res<PollutionGrid>,
     land_value: Res<LandValueGrid>,
     budget: Res<CityBudget>,
+    mut upgrade_requests: EventWriter<ServiceUpgradeRequest>,
+    structural: Res<StructuralState>,
+    mut structural_requests: EventWriter<StructuralActionRequest>,
+    mut watchlist: ResMut<Watchlist>,
 ) {
     let Some(entity) = selected.0 else {
         return;
@@ -48,13 +59,25 @@ pub fn building_inspection_ui(
             &pollution,
             &land_value,
             &budget,
+            &structural,
+            &mut structural_requests,
+            &mut watchlist,
         );
         return;
     }
 
     // Service building inspection
-    if let Ok(service) = service_buildings.get(entity) {
-        render_service_building(&mut contexts, service, &grid, &land_value);
+    if let Ok((service, capacity)) = service_buildings.get(entity) {
+        render_service_building(
+            &mut contexts,
+            entity,
+            service,
+            capacity,
+            &grid,
+            &land_value,
+            budget.treasury,
+            &mut upgrade_requests,
+        );
         return;
     }
 
@@ -74,11 +97,13 @@ fn render_zone_building(
     pollution: &PollutionGrid,
     land_value: &LandValueGrid,
     budget: &CityBudget,
+    structural: &StructuralState,
+    structural_requests: &mut EventWriter<StructuralActionRequest>,
+    watchlist: &mut ResMut<Watchlist>,
 ) {
     let cell = grid.get(building.grid_x, building.grid_y);
-    let idx = building.grid_y * GRID_WIDTH + building.grid_x;
-    let poll_level = pollution.levels.get(idx).copied().unwrap_or(0);
-    let lv = land_value.values.get(idx).copied().unwrap_or(0);
+    let poll_level = pollution.get(building.grid_x, building.grid_y);
+    let lv = land_value.get(building.grid_x, building.grid_y);
     let occupancy_pct = if building.capacity > 0 {
         (building.occupants as f32 / building.capacity as f32 * 100.0).min(100.0)
     } else {
@@ -90,6 +115,11 @@ fn render_zone_building(
         .anchor(egui::Align2::LEFT_TOP, egui::vec2(8.0, 40.0))
         .show(contexts.ctx_mut(), |ui| {
             ui.heading(zone_type_name(building.zone_type));
+            crate::watchlist::pin_button(
+                ui,
+                watchlist,
+                WatchTarget::building(building.grid_x, building.grid_y),
+            );
             ui.separator();
 
             render_building_overview(ui, building, occupancy_pct, lv, poll_level);
@@ -100,6 +130,15 @@ fn render_zone_building(
                 power_water_labels(ui, cell.has_power, cell.has_water);
             });
 
+            ui.separator();
+            render_structural_section(
+                ui,
+                building,
+                structural,
+                budget.treasury,
+                structural_requests,
+            );
+
             if building.zone_type.is_residential() {
                 render_residential_section(ui, entity, citizens, budget);
             } else {
@@ -164,15 +203,19 @@ fn render_building_overview(
         });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_service_building(
     contexts: &mut EguiContexts,
+    entity: Entity,
     service: &ServiceBuilding,
+    capacity: Option<&ServiceCapacity>,
     grid: &WorldGrid,
     land_value: &LandValueGrid,
+    treasury: f64,
+    upgrade_requests: &mut EventWriter<ServiceUpgradeRequest>,
 ) {
     let cell = grid.get(service.grid_x, service.grid_y);
-    let idx = service.grid_y * GRID_WIDTH + service.grid_x;
-    let lv = land_value.values.get(idx).copied().unwrap_or(0);
+    let lv = land_value.get(service.grid_x, service.grid_y);
     let monthly_cost =
         simulation::services::ServiceBuilding::monthly_maintenance(service.service_type);
 
@@ -202,12 +245,42 @@ fn render_service_building(
                     ui.label("Land value:");
                     ui.label(format!("{}/255", lv));
                     ui.end_row();
+                    if let Some(capacity) = capacity {
+                        ui.label("Utilization:");
+                        ui.label(format!(
+                            "{}/{} ({:.0}%)",
+                            capacity.current_usage,
+                            capacity.capacity,
+                            capacity.utilization() * 100.0
+                        ));
+                        ui.end_row();
+                    }
                 });
 
             ui.separator();
             ui.horizontal(|ui| {
                 power_water_labels(ui, cell.has_power, cell.has_water);
             });
+
+            if let Some(next) = next_tier(service.service_type) {
+                ui.separator();
+                let utilization = capacity.map_or(0.0, |c| c.utilization());
+                match check_upgrade(entity, service, utilization, treasury, grid) {
+                    Ok(option) => {
+                        let label = format!("Upgrade to {} (${:.0})", next.name(), option.cost);
+                        if ui.button(label).clicked() {
+                            upgrade_requests.send(ServiceUpgradeRequest { entity });
+                        }
+                    }
+                    Err(blocker) => {
+                        ui.add_enabled(
+                            false,
+                            egui::Button::new(format!("Upgrade to {}", next.name())),
+                        );
+                        ui.small(blocker.message());
+                    }
+                }
+            }
         });
 }
 
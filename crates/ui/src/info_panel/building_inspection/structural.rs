@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use simulation::buildings::Building;
+use simulation::structural_integrity::{
+    renovation_cost, StructuralAction, StructuralActionRequest, StructuralState, StructuralStatus,
+};
+
+/// Renders the building's structural condition, with renovate and demolish
+/// buttons once inspectors have found it unsafe.
+pub fn render_structural_section(
+    ui: &mut egui::Ui,
+    building: &Building,
+    structural: &StructuralState,
+    treasury: f64,
+    requests: &mut EventWriter<StructuralActionRequest>,
+) {
+    let (x, y) = (building.grid_x, building.grid_y);
+    let record = structural.record(x, y);
+    let status = structural.status(x, y);
+    let color = match status {
+        StructuralStatus::Sound => egui::Color32::from_rgb(50, 200, 50),
+        StructuralStatus::Unsafe => egui::Color32::from_rgb(220, 180, 50),
+        StructuralStatus::Condemned => egui::Color32::from_rgb(220, 50, 50),
+    };
+
+    ui.horizontal(|ui| {
+        ui.label("Condition:");
+        ui.colored_label(
+            color,
+            format!("{:.0}% ({})", record.condition, status.label()),
+        );
+    });
+    if let Some(day) = record.condemned_day {
+        ui.small(format!(
+            "Condemned on day {day}; residents and workers have left."
+        ));
+    }
+    if status == StructuralStatus::Sound {
+        return;
+    }
+
+    let cost = renovation_cost(building.level);
+    ui.horizontal(|ui| {
+        let renovate = ui.add_enabled(
+            treasury >= cost,
+            egui::Button::new(format!("Renovate (${cost:.0})")),
+        );
+        if renovate.clicked() {
+            requests.send(StructuralActionRequest {
+                grid_x: x,
+                grid_y: y,
+                action: StructuralAction::Renovate,
+            });
+        }
+        if ui.button("Demolish").clicked() {
+            requests.send(StructuralActionRequest {
+                grid_x: x,
+                grid_y: y,
+                action: StructuralAction::Demolish,
+            });
+        }
+    });
+}
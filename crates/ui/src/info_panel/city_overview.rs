@@ -11,6 +11,8 @@ use simulation::zones::ZoneDemand;
 
 use super::types::{coverage_bar, demand_bar, format_pop, InfoPanelExtras};
 
+use crate::accessibility;
+
 /// Render city stats, buildings, RCIO demand, and employment sections.
 pub fn draw_city_stats(
     ui: &mut egui::Ui,
@@ -60,6 +62,15 @@ pub fn draw_city_stats(
                         ws.welfare_office_count, ws.total_welfare_recipients
                     ),
                 );
+                if ws.queue_length > 0 {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(220, 160, 60),
+                        format!(
+                            "Caseload: {} waiting (avg {:.1} ticks)",
+                            ws.queue_length, ws.avg_wait_ticks
+                        ),
+                    );
+                }
             }
         }
     }
@@ -178,9 +189,13 @@ pub fn draw_attractiveness(
             egui::Color32::from_rgb(220, 50, 50)
         };
 
+        let accessible = accessibility::enabled(ui.ctx());
         ui.horizontal(|ui| {
             ui.label("Score:");
             ui.colored_label(score_color, format!("{:.0}/100", score));
+            if accessible {
+                ui.label(accessibility::rating_text(score / 100.0));
+            }
         });
 
         // Score bar
@@ -207,6 +222,13 @@ pub fn draw_attractiveness(
                 egui::Color32::from_rgb(220, 50, 50)
             }
         };
+        let factor_text = |v: f32| -> String {
+            if accessible {
+                format!("{:.0}% ({})", v * 100.0, accessibility::rating_text(v))
+            } else {
+                format!("{:.0}%", v * 100.0)
+            }
+        };
 
         egui::Grid::new("attractiveness_breakdown")
             .num_columns(2)
@@ -214,35 +236,35 @@ pub fn draw_attractiveness(
                 ui.label("Employment:");
                 ui.colored_label(
                     factor_color(attractiveness.employment_factor),
-                    format!("{:.0}%", attractiveness.employment_factor * 100.0),
+                    factor_text(attractiveness.employment_factor),
                 );
                 ui.end_row();
 
                 ui.label("Happiness:");
                 ui.colored_label(
                     factor_color(attractiveness.happiness_factor),
-                    format!("{:.0}%", attractiveness.happiness_factor * 100.0),
+                    factor_text(attractiveness.happiness_factor),
                 );
                 ui.end_row();
 
                 ui.label("Services:");
                 ui.colored_label(
                     factor_color(attractiveness.services_factor),
-                    format!("{:.0}%", attractiveness.services_factor * 100.0),
+                    factor_text(attractiveness.services_factor),
                 );
                 ui.end_row();
 
                 ui.label("Housing:");
                 ui.colored_label(
                     factor_color(attractiveness.housing_factor),
-                    format!("{:.0}%", attractiveness.housing_factor * 100.0),
+                    factor_text(attractiveness.housing_factor),
                 );
                 ui.end_row();
 
                 ui.label("Tax:");
                 ui.colored_label(
                     factor_color(attractiveness.tax_factor),
-                    format!("{:.0}%", attractiveness.tax_factor * 100.0),
+                    factor_text(attractiveness.tax_factor),
                 );
                 ui.end_row();
             });
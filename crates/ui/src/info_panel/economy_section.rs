@@ -14,7 +14,7 @@ use super::minimap::{build_minimap_pixels, MINIMAP_SIZE};
 use super::types::{InfoPanelExtras, MinimapCache};
 
 /// Render the Economy: Production Chains collapsing section.
-pub fn draw_production_chains(ui: &mut egui::Ui, extras: &InfoPanelExtras) {
+pub fn draw_production_chains(ui: &mut egui::Ui, extras: &mut InfoPanelExtras) {
     let city_goods = &extras.city_goods;
 
     ui.separator();
@@ -56,6 +56,14 @@ pub fn draw_production_chains(ui: &mut egui::Ui, extras: &InfoPanelExtras) {
             ui.label("Trade balance:");
             ui.colored_label(tb_color, format!("${:.1}/tick", tb));
         });
+
+        if ui
+            .button("Flow Diagram")
+            .on_hover_text("Show how goods move between industries, commerce and trade")
+            .clicked()
+        {
+            extras.production_flow.0 = !extras.production_flow.0;
+        }
     });
 }
 
@@ -323,6 +331,11 @@ pub fn draw_minimap(
         OverlayMode::GroundwaterLevel => "GW Level overlay [Tab]",
         OverlayMode::GroundwaterQuality => "GW Quality overlay [Tab]",
         OverlayMode::Wind => "Wind overlay [Tab]",
+        OverlayMode::Biodiversity => "Biodiversity overlay [Tab]",
+        OverlayMode::Erosion => "Erosion overlay [Tab]",
+        OverlayMode::CommuteTime => "Commute Time overlay [Tab]",
+        OverlayMode::TransitRidership => "Ridership overlay [Tab]",
+        OverlayMode::JobAccess => "Job Access overlay [Tab]",
     };
     ui.small(overlay_text);
 
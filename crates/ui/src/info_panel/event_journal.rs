@@ -19,6 +19,7 @@ fn event_type_color(event_type: &simulation::events::CityEventType) -> egui::Col
         CityEventType::Festival => egui::Color32::from_rgb(255, 215, 0),
         CityEventType::EconomicBoom => egui::Color32::from_rgb(50, 220, 50),
         CityEventType::ResourceDepleted(_) => egui::Color32::from_rgb(200, 150, 50),
+        CityEventType::DisasterReport(_) => egui::Color32::from_rgb(230, 120, 90),
     }
 }
 
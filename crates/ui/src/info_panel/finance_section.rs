@@ -3,17 +3,25 @@
 
 use bevy_egui::egui;
 
+use simulation::city_council::{loan_requires_vote, Motion, MAX_TAX_CAP};
 use simulation::economy::CityBudget;
 use simulation::loans::{LoanBook, LoanTier};
+use simulation::receivership::{AUSTERITY_BUDGET_CAP, IMPOSED_TAX_RATE};
 
+use super::receivership_section::draw_receivership;
 use super::types::InfoPanelExtras;
 
+use crate::accessibility;
+
 /// Render the budget overview with per-zone tax sliders and budget-details button.
 ///
 /// The zone tax sliders modify `ExtendedBudget.zone_taxes` directly, which is
 /// what `collect_taxes()` actually reads. When any slider changes we also update
 /// `CityBudget.tax_rate` to the average of all zone rates so that happiness,
 /// immigration, and advisor systems (which read the single rate) stay correct.
+///
+/// Sliders stop at the council-approved tax cap; raising the cap is proposed
+/// to the city council from here.
 pub fn draw_budget(
     ui: &mut egui::Ui,
     budget: &mut CityBudget,
@@ -27,6 +35,13 @@ pub fn draw_budget(
     ui.label(format!("Expenses: ${:.0}/month", budget.monthly_expenses));
 
     let mut changed = false;
+    let cap_pct = extras.council.tax_cap * 100.0;
+    // The receiver sets a floor under every zone tax.
+    let floor_pct = if extras.receivership.controls_finances() {
+        IMPOSED_TAX_RATE * 100.0
+    } else {
+        0.0
+    };
 
     ui.collapsing("Tax Rates", |ui| {
         let zt = &mut ext_budget.zone_taxes;
@@ -35,7 +50,7 @@ pub fn draw_budget(
         ui.horizontal(|ui| {
             ui.label("Residential:");
             if ui
-                .add(egui::Slider::new(&mut res_pct, 0.0..=25.0).suffix("%"))
+                .add(egui::Slider::new(&mut res_pct, floor_pct..=cap_pct.max(res_pct)).suffix("%"))
                 .changed()
             {
                 zt.residential = res_pct / 100.0;
@@ -47,7 +62,7 @@ pub fn draw_budget(
         ui.horizontal(|ui| {
             ui.label("Commercial:");
             if ui
-                .add(egui::Slider::new(&mut com_pct, 0.0..=25.0).suffix("%"))
+                .add(egui::Slider::new(&mut com_pct, floor_pct..=cap_pct.max(com_pct)).suffix("%"))
                 .changed()
             {
                 zt.commercial = com_pct / 100.0;
@@ -59,7 +74,7 @@ pub fn draw_budget(
         ui.horizontal(|ui| {
             ui.label("Industrial:");
             if ui
-                .add(egui::Slider::new(&mut ind_pct, 0.0..=25.0).suffix("%"))
+                .add(egui::Slider::new(&mut ind_pct, floor_pct..=cap_pct.max(ind_pct)).suffix("%"))
                 .changed()
             {
                 zt.industrial = ind_pct / 100.0;
@@ -71,7 +86,7 @@ pub fn draw_budget(
         ui.horizontal(|ui| {
             ui.label("Office:");
             if ui
-                .add(egui::Slider::new(&mut off_pct, 0.0..=25.0).suffix("%"))
+                .add(egui::Slider::new(&mut off_pct, floor_pct..=cap_pct.max(off_pct)).suffix("%"))
                 .changed()
             {
                 zt.office = off_pct / 100.0;
@@ -79,17 +94,36 @@ pub fn draw_budget(
             }
         });
 
+        if floor_pct > 0.0 {
+            ui.small(format!("Receiver minimum: {floor_pct:.0}%"));
+        }
+
         // Show effective average rate for reference
         let avg = (zt.residential + zt.commercial + zt.industrial + zt.office) / 4.0;
         ui.label(format!("Avg rate: {:.1}%", avg * 100.0));
+
+        ui.horizontal(|ui| {
+            ui.label(format!("Council cap: {:.0}%", cap_pct));
+            let council = &mut extras.council;
+            if council.tax_cap < MAX_TAX_CAP {
+                let pending = council.is_pending(Motion::RaiseTaxCap);
+                let label = if pending {
+                    "Vote pending"
+                } else {
+                    "Propose raise"
+                };
+                if ui.add_enabled(!pending, egui::Button::new(label)).clicked() {
+                    council.propose(Motion::RaiseTaxCap);
+                }
+            }
+        });
     });
 
     // Sync the summary tax_rate field so happiness/immigration/advisors stay
     // consistent with what the player chose in the per-zone sliders.
     if changed {
         let zt = &ext_budget.zone_taxes;
-        budget.tax_rate =
-            (zt.residential + zt.commercial + zt.industrial + zt.office) / 4.0;
+        budget.tax_rate = (zt.residential + zt.commercial + zt.industrial + zt.office) / 4.0;
     }
 
     if ui.button("Budget Details...").clicked() {
@@ -105,12 +139,12 @@ pub fn draw_road_maintenance(ui: &mut egui::Ui, extras: &mut InfoPanelExtras) {
     ui.collapsing("Road Maintenance", |ui| {
         let avg = road_maint_stats.avg_condition;
         let avg_pct = avg / 255.0;
-        let cond_color = if avg > 150.0 {
-            egui::Color32::from_rgb(50, 200, 50)
+        let (cond_color, cond_text) = if avg > 150.0 {
+            (egui::Color32::from_rgb(50, 200, 50), "good")
         } else if avg > 80.0 {
-            egui::Color32::from_rgb(220, 180, 50)
+            (egui::Color32::from_rgb(220, 180, 50), "fair")
         } else {
-            egui::Color32::from_rgb(220, 50, 50)
+            (egui::Color32::from_rgb(220, 50, 50), "poor")
         };
         ui.horizontal(|ui| {
             ui.label("Avg condition:");
@@ -123,6 +157,9 @@ pub fn draw_road_maintenance(ui: &mut egui::Ui, extras: &mut InfoPanelExtras) {
             );
             painter.rect_filled(fill_rect, 2.0, cond_color);
             ui.colored_label(cond_color, format!("{:.0}", avg));
+            if accessibility::enabled(ui.ctx()) {
+                ui.label(cond_text);
+            }
         });
 
         let poor_color = if road_maint_stats.poor_roads_count > 100 {
@@ -192,7 +229,7 @@ pub fn draw_finance(
     ui: &mut egui::Ui,
     budget: &mut CityBudget,
     loan_book: &mut LoanBook,
-    extras: &InfoPanelExtras,
+    extras: &mut InfoPanelExtras,
 ) {
     let resource_balance = &extras.resource_balance;
 
@@ -282,24 +319,41 @@ pub fn draw_finance(
 
         ui.add_space(4.0);
 
+        draw_receivership(ui, &extras.receivership);
+
         // Take Loan buttons
         let at_max = loan_book.active_loans.len() >= loan_book.max_loans;
+        let frozen = extras.receivership.controls_finances();
+        let rate_multiplier = extras.game_settings.difficulty.loan_rate_multiplier;
         ui.label("Take a Loan:");
         for tier in LoanTier::ALL {
             let label = format!(
                 "{}: ${:.0} @ {:.0}% / {}mo",
                 tier.name(),
                 tier.amount(),
-                tier.interest_rate() * 100.0,
+                tier.interest_rate() * rate_multiplier * 100.0,
                 tier.term_months(),
             );
+            let motion = Motion::TakeLoan(tier);
+            let needs_vote = loan_requires_vote(tier);
+            let pending = extras.council.is_pending(motion);
             let button = egui::Button::new(&label);
-            let response = ui.add_enabled(!at_max, button);
+            let response = ui.add_enabled(!at_max && !pending && !frozen, button);
             if response.clicked() {
-                loan_book.take_loan(tier, &mut budget.treasury);
+                if needs_vote {
+                    extras.council.propose(motion);
+                } else {
+                    loan_book.take_loan_with_rate(tier, &mut budget.treasury, rate_multiplier);
+                }
             }
-            if at_max {
+            if frozen {
+                response.on_hover_text("Borrowing is frozen during receivership");
+            } else if at_max {
                 response.on_hover_text("Maximum loans reached");
+            } else if pending {
+                response.on_hover_text("Awaiting city council vote");
+            } else if needs_vote {
+                response.on_hover_text("Requires a city council vote");
             }
         }
     });
@@ -309,9 +363,20 @@ pub fn draw_finance(
 pub fn draw_service_budgets(
     ui: &mut egui::Ui,
     ext_budget: &mut simulation::budget::ExtendedBudget,
+    welfare_staffing: &mut simulation::welfare::WelfareStaffing,
+    in_receivership: bool,
 ) {
     ui.separator();
     ui.heading("Service Budgets");
+    // The receiver caps every service budget.
+    let max_pct = if in_receivership {
+        AUSTERITY_BUDGET_CAP * 100.0
+    } else {
+        150.0
+    };
+    if in_receivership {
+        ui.small(format!("Capped at {max_pct:.0}% by the receiver"));
+    }
     {
         let sb = &mut ext_budget.service_budgets;
         let mut fire_pct = sb.fire * 100.0;
@@ -324,7 +389,7 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Fire:");
             if ui
-                .add(egui::Slider::new(&mut fire_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut fire_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.fire = fire_pct / 100.0;
@@ -333,7 +398,7 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Police:");
             if ui
-                .add(egui::Slider::new(&mut police_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut police_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.police = police_pct / 100.0;
@@ -342,7 +407,7 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Health:");
             if ui
-                .add(egui::Slider::new(&mut health_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut health_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.healthcare = health_pct / 100.0;
@@ -351,7 +416,7 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Education:");
             if ui
-                .add(egui::Slider::new(&mut edu_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut edu_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.education = edu_pct / 100.0;
@@ -360,7 +425,7 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Sanitation:");
             if ui
-                .add(egui::Slider::new(&mut sanit_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut sanit_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.sanitation = sanit_pct / 100.0;
@@ -369,11 +434,30 @@ pub fn draw_service_budgets(
         ui.horizontal(|ui| {
             ui.label("Transport:");
             if ui
-                .add(egui::Slider::new(&mut trans_pct, 0.0..=150.0).suffix("%"))
+                .add(egui::Slider::new(&mut trans_pct, 0.0..=max_pct).suffix("%"))
                 .changed()
             {
                 sb.transport = trans_pct / 100.0;
             }
         });
     }
+    {
+        let mut staffing_pct = welfare_staffing.level() * 100.0;
+        ui.horizontal(|ui| {
+            ui.label("Welfare staff:");
+            if ui
+                .add(
+                    egui::Slider::new(
+                        &mut staffing_pct,
+                        simulation::welfare::MIN_STAFFING_LEVEL * 100.0
+                            ..=simulation::welfare::MAX_STAFFING_LEVEL * 100.0,
+                    )
+                    .suffix("%"),
+                )
+                .changed()
+            {
+                welfare_staffing.level = staffing_pct / 100.0;
+            }
+        });
+    }
 }
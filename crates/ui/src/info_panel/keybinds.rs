@@ -66,6 +66,7 @@ pub fn quick_save_load_keybinds(
         notifications.send(simulation::notifications::NotificationEvent {
             text: "Quick saved".to_string(),
             priority: simulation::notifications::NotificationPriority::Info,
+            category: simulation::notifications::NotificationCategory::General,
             location: None,
         });
     }
@@ -77,6 +78,7 @@ pub fn quick_save_load_keybinds(
                 notifications.send(simulation::notifications::NotificationEvent {
                     text: "No quicksave found".to_string(),
                     priority: simulation::notifications::NotificationPriority::Warning,
+                    category: simulation::notifications::NotificationCategory::General,
                     location: None,
                 });
                 return;
@@ -52,10 +52,15 @@ pub fn info_panel_ui(
             finance_section::draw_road_maintenance(ui, &mut extras);
 
             // Finance (loans, credit rating, trade balance)
-            finance_section::draw_finance(ui, &mut budget, &mut loan_book, &extras);
+            finance_section::draw_finance(ui, &mut budget, &mut loan_book, &mut extras);
 
             // Service budget sliders
-            finance_section::draw_service_budgets(ui, &mut ext_budget);
+            finance_section::draw_service_budgets(
+                ui,
+                &mut ext_budget,
+                &mut extras.welfare_staffing,
+                extras.receivership.controls_finances(),
+            );
 
             // Service coverage bars
             services_section::draw_service_coverage(ui, &coverage, &extras);
@@ -64,7 +69,7 @@ pub fn info_panel_ui(
             services_section::draw_groundwater(ui, &extras);
 
             // Districts
-            services_section::draw_districts(ui, &extras.district_map);
+            services_section::draw_districts(ui, &mut extras);
 
             // Outside Connections
             services_section::draw_outside_connections(ui, &extras);
@@ -73,7 +78,7 @@ pub fn info_panel_ui(
             services_section::draw_aviation(ui, &extras);
 
             // Economy: Production Chains
-            economy_section::draw_production_chains(ui, &extras);
+            economy_section::draw_production_chains(ui, &mut extras);
 
             // Market Prices
             economy_section::draw_market_prices(ui, &extras);
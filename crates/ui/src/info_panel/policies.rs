@@ -1,14 +1,27 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use simulation::city_council::{policy_requires_vote, CityCouncil, Motion};
 use simulation::policies::{Policies, Policy};
+use simulation::seismic::SeismicState;
 
 use super::PoliciesVisible;
+use crate::council_panel::CouncilPanelVisible;
+use crate::disaster_report_panel::DisasterReportVisible;
+use crate::heat_health_panel::HeatHealthPanelVisible;
+use crate::justice_panel::JusticePanelVisible;
 
+#[allow(clippy::too_many_arguments)]
 pub fn policies_ui(
     mut contexts: EguiContexts,
     mut policies: ResMut<Policies>,
     visible: Res<PoliciesVisible>,
+    mut council: ResMut<CityCouncil>,
+    mut council_visible: ResMut<CouncilPanelVisible>,
+    mut heat_health_visible: ResMut<HeatHealthPanelVisible>,
+    mut disaster_report_visible: ResMut<DisasterReportVisible>,
+    mut justice_visible: ResMut<JusticePanelVisible>,
+    seismic: Res<SeismicState>,
 ) {
     if !visible.0 {
         return;
@@ -23,6 +36,20 @@ pub fn policies_ui(
                 "Monthly cost: ${:.0}",
                 policies.total_monthly_cost()
             ));
+            ui.horizontal(|ui| {
+                if ui.button("City Council...").clicked() {
+                    council_visible.0 = !council_visible.0;
+                }
+                if ui.button("Heat-Health Plan...").clicked() {
+                    heat_health_visible.0 = !heat_health_visible.0;
+                }
+                if ui.button("Disaster Insurance...").clicked() {
+                    disaster_report_visible.0 = !disaster_report_visible.0;
+                }
+            });
+            if ui.button("Justice Policy...").clicked() {
+                justice_visible.0 = !justice_visible.0;
+            }
             ui.separator();
 
             for &policy in Policy::all() {
@@ -32,14 +59,46 @@ pub fn policies_ui(
                 } else {
                     String::new()
                 };
-                if ui
+                if !active && policy_requires_vote(policy) {
+                    let motion = Motion::EnactPolicy(policy);
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}{}", policy.name(), cost_str));
+                        if council.is_pending(motion) {
+                            ui.small("(vote pending)");
+                        } else if ui.small_button("Propose to council").clicked() {
+                            council.propose(motion);
+                        }
+                    });
+                } else if ui
                     .checkbox(&mut active, format!("{}{}", policy.name(), cost_str))
                     .changed()
                 {
                     policies.toggle(policy);
                 }
                 ui.label(format!("  {}", policy.description()));
+                if policy == Policy::SeismicRetrofit {
+                    draw_retrofit_status(ui, &seismic);
+                }
                 ui.add_space(4.0);
             }
         });
 }
+
+/// Progress of the retrofit program and how the last earthquake went.
+fn draw_retrofit_status(ui: &mut egui::Ui, seismic: &SeismicState) {
+    if !seismic.retrofitted.is_empty() || !seismic.projects.is_empty() {
+        ui.small(format!(
+            "  {} retrofitted, {} in progress (${:.0}/mo), ${:.0} spent",
+            seismic.retrofitted.len(),
+            seismic.projects.len(),
+            seismic.monthly_installments(),
+            seismic.total_spent
+        ));
+    }
+    if let Some(quake) = &seismic.last_quake {
+        ui.small(format!(
+            "  Last earthquake: M{:.1}, {} collapsed, {} damaged, {} retrofits held",
+            quake.magnitude, quake.collapsed, quake.damaged, quake.retrofits_held
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+//! Info panel section: receivership status and the recovery plan, shown in
+//! the Finance section after bankruptcy.
+
+use bevy_egui::egui;
+
+use simulation::receivership::{
+    FiscalPhase, Receivership, RecoveryMilestone, RECEIVERSHIP_SOLVENT_DAYS,
+};
+
+/// Receivership status and recovery plan; nothing is shown while solvent.
+pub(super) fn draw_receivership(ui: &mut egui::Ui, receivership: &Receivership) {
+    match receivership.phase {
+        FiscalPhase::Solvent => {}
+        FiscalPhase::Receivership => {
+            ui.add_space(4.0);
+            ui.colored_label(
+                egui::Color32::from_rgb(220, 50, 50),
+                format!("In receivership since day {}", receivership.entered_day),
+            );
+            ui.label(format!(
+                "Solvent days: {} / {}",
+                receivership.solvent_days, RECEIVERSHIP_SOLVENT_DAYS
+            ));
+            if receivership.assets_sold > 0 {
+                ui.label(format!(
+                    "Assets sold: {} (${:.0})",
+                    receivership.assets_sold, receivership.sale_proceeds
+                ));
+            }
+        }
+        FiscalPhase::Recovery => {
+            ui.add_space(4.0);
+            ui.colored_label(egui::Color32::from_rgb(220, 200, 50), "Recovery plan:");
+            for milestone in RecoveryMilestone::ALL {
+                let mark = if receivership.milestone_met(milestone) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                ui.label(format!("{mark} {}", milestone.describe()));
+            }
+        }
+    }
+}
@@ -4,10 +4,11 @@
 use bevy_egui::egui;
 
 use simulation::coverage_metrics::CoverageMetrics;
-use simulation::districts::DistrictMap;
 
 use super::types::{coverage_bar, format_pop, InfoPanelExtras};
 
+use crate::accessibility;
+
 /// Render service coverage bars from cached values.
 pub fn draw_service_coverage(
     ui: &mut egui::Ui,
@@ -118,7 +119,8 @@ pub fn draw_groundwater(ui: &mut egui::Ui, extras: &InfoPanelExtras) {
 }
 
 /// Render the Districts section.
-pub fn draw_districts(ui: &mut egui::Ui, district_map: &DistrictMap) {
+pub fn draw_districts(ui: &mut egui::Ui, extras: &mut InfoPanelExtras) {
+    let district_map = &extras.district_map;
     ui.separator();
     ui.heading("Districts");
     let has_any_district = district_map.districts.iter().any(|d| !d.cells.is_empty());
@@ -164,6 +166,23 @@ pub fn draw_districts(ui: &mut egui::Ui, district_map: &DistrictMap) {
         ui.small("No districts painted yet.");
         ui.small("Use the Districts toolbar to paint.");
     }
+    if has_any_district
+        && ui
+            .button("Manage districts")
+            .on_hover_text("Rename, compare and set policies per district")
+            .clicked()
+    {
+        extras.district_manager.open = true;
+    }
+    if ui
+        .button("Auto-generate districts")
+        .on_hover_text("Replace all districts with detected neighborhoods")
+        .clicked()
+    {
+        extras
+            .auto_district
+            .send(simulation::auto_district::AutoDistrictRequest::default());
+    }
 }
 
 /// Render the Outside Connections collapsing section.
@@ -214,6 +233,9 @@ pub fn draw_outside_connections(ui: &mut egui::Ui, extras: &InfoPanelExtras) {
                     );
                     painter.rect_filled(fill_rect, 2.0, util_color);
                     ui.label(format!("{:.0}%", stat.avg_utilization * 100.0));
+                    if accessibility::enabled(ui.ctx()) {
+                        ui.label(accessibility::load_text(stat.avg_utilization));
+                    }
                 });
 
                 ui.horizontal(|ui| {
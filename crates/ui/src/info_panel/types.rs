@@ -21,9 +21,11 @@ use simulation::postal::PostalStats;
 use simulation::production::CityGoods;
 use simulation::specialization::{CitySpecializations, SpecializationBonuses};
 use simulation::weather::Weather;
-use simulation::welfare::WelfareStats;
+use simulation::welfare::{WelfareStaffing, WelfareStats};
 use simulation::wind::WindState;
 
+use crate::accessibility;
+
 // ---------------------------------------------------------------------------
 // Shared types & resources
 // ---------------------------------------------------------------------------
@@ -84,12 +86,19 @@ pub struct InfoPanelExtras<'w> {
     pub achievement_tracker: Res<'w, AchievementTracker>,
     pub achievement_notifications: ResMut<'w, AchievementNotification>,
     pub welfare_stats: Res<'w, WelfareStats>,
+    pub welfare_staffing: ResMut<'w, WelfareStaffing>,
+    pub council: ResMut<'w, simulation::city_council::CityCouncil>,
+    pub receivership: Res<'w, simulation::receivership::Receivership>,
+    pub game_settings: Res<'w, simulation::game_settings::GameSettings>,
     pub airport_stats: Res<'w, AirportStats>,
     pub postal_stats: Res<'w, PostalStats>,
     pub heating_stats: Res<'w, HeatingStats>,
     pub weather: Res<'w, Weather>,
     pub groundwater_stats: Res<'w, GroundwaterStats>,
     pub budget_visible: ResMut<'w, BudgetPanelVisible>,
+    pub auto_district: EventWriter<'w, simulation::auto_district::AutoDistrictRequest>,
+    pub district_manager: ResMut<'w, crate::district_manager::DistrictManagerState>,
+    pub production_flow: ResMut<'w, crate::production_flow::ProductionFlowVisible>,
 }
 
 // ---------------------------------------------------------------------------
@@ -133,5 +142,8 @@ pub fn coverage_bar(ui: &mut egui::Ui, label: &str, value: f32, color: egui::Col
         );
         painter.rect_filled(fill_rect, 2.0, color);
         ui.label(format!("{:.0}%", value * 100.0));
+        if accessibility::enabled(ui.ctx()) {
+            ui.label(accessibility::rating_text(value));
+        }
     });
 }
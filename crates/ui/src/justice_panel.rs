@@ -0,0 +1,101 @@
+//! Justice policy window.
+//!
+//! Set the city's sentencing policy and whether inmates are enrolled in
+//! education programs, and follow prison occupancy and recidivism. Opened
+//! from the Policies window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::prison_system::{PrisonState, SentencingPolicy, EDUCATION_COST_PER_INMATE};
+
+/// Whether the justice policy window is visible.
+#[derive(Resource, Default)]
+pub struct JusticePanelVisible(pub bool);
+
+fn policy_description(policy: SentencingPolicy) -> &'static str {
+    match policy {
+        SentencingPolicy::Rehabilitative => "Shorter sentences, fewer repeat offenders",
+        SentencingPolicy::Standard => "Base sentence for each crime",
+        SentencingPolicy::Punitive => "Longer sentences, fuller prisons, more repeat offenders",
+    }
+}
+
+/// Renders the justice policy window.
+pub fn justice_panel_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<JusticePanelVisible>,
+    mut prisons: ResMut<PrisonState>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Justice Policy")
+        .open(&mut open)
+        .default_width(300.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Sentencing");
+            for policy in SentencingPolicy::ALL {
+                if ui.radio(prisons.policy == policy, policy.name()).clicked() {
+                    prisons.policy = policy;
+                }
+                ui.small(format!("  {}", policy_description(policy)));
+            }
+            ui.small("Applies to sentences handed down from now on.");
+
+            ui.separator();
+            ui.checkbox(
+                &mut prisons.education_program,
+                format!(
+                    "Prison education (${:.1}/inmate per tick)",
+                    EDUCATION_COST_PER_INMATE
+                ),
+            );
+            ui.small("  Educated inmates are less likely to reoffend");
+
+            ui.separator();
+            if prisons.prisons.is_empty() {
+                ui.label("The city has no prisons.");
+            }
+            egui::Grid::new("justice_prisons")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Inmates:");
+                    ui.label(format!(
+                        "{} / {} ({:.0}%)",
+                        prisons.population(),
+                        prisons.capacity(),
+                        prisons.occupancy() * 100.0
+                    ));
+                    ui.end_row();
+                    ui.label("Released:");
+                    ui.label(format!(
+                        "{} ({} early)",
+                        prisons.total_released, prisons.total_early_released
+                    ));
+                    ui.end_row();
+                    ui.label("Reoffended:");
+                    ui.label(format!("{:.0}%", prisons.recidivism_rate() * 100.0));
+                    ui.end_row();
+                    ui.label("Education cost:");
+                    ui.label(format!("${:.0} last tick", prisons.education_cost));
+                    ui.end_row();
+                });
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct JusticePanelPlugin;
+
+impl Plugin for JusticePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<JusticePanelVisible>()
+            .add_systems(Update, justice_panel_ui.run_if(in_state(AppState::Playing)));
+    }
+}
@@ -1,8 +1,9 @@
 //! Keybindings settings panel (UX-035).
 //!
 //! Provides an egui window for viewing current keybindings, click-to-rebind,
-//! conflict detection with warnings, and a "Reset to Defaults" button.
-//! Accessible from the Settings panel.
+//! conflict detection with warnings, a per-action reset button and a
+//! "Reset to Defaults" button. Accessible from the Settings panel. Changes
+//! are written to the keybindings file as soon as they are made.
 
 use bevy::prelude::*;
 use simulation::app_state::AppState;
@@ -59,62 +60,75 @@ pub fn keybindings_panel_ui(
                 ui.add_space(4.0);
             }
 
-            let mut current_category = "";
+            for (i, category) in BindableAction::categories().into_iter().enumerate() {
+                if i > 0 {
+                    ui.add_space(8.0);
+                }
+                ui.heading(category);
+                ui.separator();
 
-            for &action in BindableAction::ALL {
-                let category = action.category();
-                if category != current_category {
-                    if !current_category.is_empty() {
-                        ui.add_space(8.0);
+                for &action in BindableAction::ALL {
+                    if action.category() != category {
+                        continue;
                     }
-                    ui.heading(category);
-                    ui.separator();
-                    current_category = category;
-                }
 
-                let binding = bindings.get(action);
-                let is_awaiting = rebind_state.awaiting == Some(action);
-                let has_conflict = conflict_actions.contains(&action);
-
-                ui.horizontal(|ui| {
-                    let label_color = if has_conflict {
-                        egui::Color32::from_rgb(255, 180, 50)
-                    } else {
-                        egui::Color32::from_gray(220)
-                    };
-                    ui.colored_label(label_color, action.label());
-
-                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        let button_text = if is_awaiting {
-                            "[ ... ]".to_string()
-                        } else {
-                            binding.display_label()
-                        };
+                    let binding = bindings.get(action);
+                    let is_awaiting = rebind_state.awaiting == Some(action);
+                    let has_conflict = conflict_actions.contains(&action);
+                    let is_default = bindings.is_default(action);
 
-                        let button_color = if is_awaiting {
-                            egui::Color32::from_rgb(100, 200, 255)
-                        } else if has_conflict {
+                    ui.horizontal(|ui| {
+                        let label_color = if has_conflict {
                             egui::Color32::from_rgb(255, 180, 50)
                         } else {
-                            egui::Color32::from_gray(180)
+                            egui::Color32::from_gray(220)
                         };
+                        ui.colored_label(label_color, action.label());
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let default_label = KeyBindings::default().get(action).display_label();
+                            if ui
+                                .add_enabled(!is_default, egui::Button::new("Reset").small())
+                                .on_hover_text(format!("Default: {default_label}"))
+                                .clicked()
+                            {
+                                bindings.reset(action);
+                                if is_awaiting {
+                                    rebind_state.awaiting = None;
+                                }
+                            }
 
-                        let btn = egui::Button::new(
-                            egui::RichText::new(&button_text)
-                                .color(button_color)
-                                .monospace(),
-                        )
-                        .min_size(egui::vec2(100.0, 0.0));
+                            let button_text = if is_awaiting {
+                                "[ ... ]".to_string()
+                            } else {
+                                binding.display_label()
+                            };
 
-                        if ui.add(btn).clicked() {
-                            if is_awaiting {
-                                rebind_state.awaiting = None;
+                            let button_color = if is_awaiting {
+                                egui::Color32::from_rgb(100, 200, 255)
+                            } else if has_conflict {
+                                egui::Color32::from_rgb(255, 180, 50)
                             } else {
-                                rebind_state.awaiting = Some(action);
+                                egui::Color32::from_gray(180)
+                            };
+
+                            let btn = egui::Button::new(
+                                egui::RichText::new(&button_text)
+                                    .color(button_color)
+                                    .monospace(),
+                            )
+                            .min_size(egui::vec2(100.0, 0.0));
+
+                            if ui.add(btn).clicked() {
+                                if is_awaiting {
+                                    rebind_state.awaiting = None;
+                                } else {
+                                    rebind_state.awaiting = Some(action);
+                                }
                             }
-                        }
+                        });
                     });
-                });
+                }
             }
 
             ui.add_space(16.0);
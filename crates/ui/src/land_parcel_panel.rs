@@ -0,0 +1,153 @@
+//! Parcel ownership window.
+//!
+//! Shown alongside the Cell Info panel or the Building Inspector for a zoned
+//! building. Lets the player buy the parcel (by eminent domain if something
+//! stands on it or a developer owns it), lease city land out, or auction it.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use rendering::input::SelectedBuilding;
+use simulation::buildings::Building;
+use simulation::economy::CityBudget;
+use simulation::grid::WorldGrid;
+use simulation::land_ownership::{
+    auction_price, lease_rent, market_price, seizure_price, LandAction, LandActionKind,
+    LandOwnership, ParcelOwner,
+};
+use simulation::land_value::LandValueGrid;
+
+use crate::cell_info_panel::SelectedCell;
+
+/// Renders the parcel window for the selected cell or zoned building.
+#[allow(clippy::too_many_arguments)]
+pub fn land_parcel_panel_ui(
+    mut contexts: EguiContexts,
+    selected_cell: Res<SelectedCell>,
+    selected_building: Res<SelectedBuilding>,
+    buildings: Query<&Building>,
+    grid: Res<WorldGrid>,
+    land: Res<LandOwnership>,
+    land_value: Res<LandValueGrid>,
+    budget: Res<CityBudget>,
+    mut actions: EventWriter<LandAction>,
+) {
+    let building = selected_building.0.and_then(|e| buildings.get(e).ok());
+    let (x, y) = match (building, selected_building.0, selected_cell.0) {
+        (Some(b), _, _) => (b.grid_x, b.grid_y),
+        // Service and utility buildings are not for sale.
+        (None, Some(_), _) => return,
+        (None, None, Some(cell)) => cell,
+        (None, None, None) => return,
+    };
+    if !grid.in_bounds(x, y) {
+        return;
+    }
+
+    let owner = land.owner(x, y);
+    let lv = land_value.get(x, y);
+    let built = grid.get(x, y).building_id.is_some();
+    // Built or developer-owned parcels can only be taken by eminent domain.
+    let seize = built || owner == ParcelOwner::Developer;
+
+    egui::Window::new("Parcel")
+        .default_width(260.0)
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8.0, -40.0))
+        .show(contexts.ctx_mut(), |ui| {
+            egui::Grid::new("parcel_grid")
+                .num_columns(2)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Owner:");
+                    ui.label(owner.name());
+                    ui.end_row();
+
+                    ui.label("Market value:");
+                    ui.label(format!("${:.0}", market_price(lv)));
+                    ui.end_row();
+                });
+            ui.separator();
+
+            let mut send = |kind| {
+                actions.send(LandAction {
+                    grid_x: x,
+                    grid_y: y,
+                    kind,
+                });
+            };
+            match owner {
+                ParcelOwner::Private | ParcelOwner::Developer if seize => {
+                    let price = seizure_price(lv, building.map_or(0, |b| b.level));
+                    let label = format!("Seize by eminent domain (${price:.0})");
+                    if ui
+                        .add_enabled(budget.treasury >= price, egui::Button::new(label))
+                        .on_hover_text("Demolishes any building and angers residents")
+                        .clicked()
+                    {
+                        send(LandActionKind::Buy);
+                    }
+                }
+                ParcelOwner::Private | ParcelOwner::Developer => {
+                    let price = market_price(lv);
+                    if ui
+                        .add_enabled(
+                            budget.treasury >= price,
+                            egui::Button::new(format!("Buy (${price:.0})")),
+                        )
+                        .clicked()
+                    {
+                        send(LandActionKind::Buy);
+                    }
+                }
+                ParcelOwner::City | ParcelOwner::Leased => {
+                    if owner == ParcelOwner::City {
+                        if ui
+                            .button(format!("Lease out (${:.0}/month)", lease_rent(lv)))
+                            .clicked()
+                        {
+                            send(LandActionKind::Lease);
+                        }
+                    } else if ui
+                        .add_enabled(!built, egui::Button::new("End lease"))
+                        .clicked()
+                    {
+                        send(LandActionKind::EndLease);
+                    }
+                    if ui
+                        .button(format!("Auction (~${:.0})", auction_price(lv)))
+                        .on_hover_text("The city loses control of zoning here")
+                        .clicked()
+                    {
+                        send(LandActionKind::Auction);
+                    }
+                }
+            }
+
+            ui.separator();
+            ui.small(format!(
+                "City parcels: {}  |  Last lease income: ${:.0}",
+                land.city_parcels(),
+                land.last_lease_income
+            ));
+            if land.grievance > 0.0 {
+                ui.colored_label(
+                    egui::Color32::from_rgb(220, 120, 50),
+                    format!("Seizure backlash: -{:.1} happiness", land.grievance),
+                );
+            }
+        });
+}
+
+pub struct LandParcelPanelPlugin;
+
+impl Plugin for LandParcelPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            land_parcel_panel_ui
+                .after(crate::cell_info_panel::update_selected_cell)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -1,12 +1,16 @@
 //! Loading and transition screen overlays (PLAY-011).
 //!
 //! Displays a full-screen semi-transparent overlay with a contextual message
-//! during save, load, and new-game operations. An animated dots effect
-//! provides visual feedback so the player knows the application has not frozen.
+//! during load and new-game operations. An animated dots effect provides
+//! visual feedback so the player knows the application has not frozen.
+//!
+//! Saving doesn't block play (the file is written in the background), so it
+//! only shows a small spinner in the corner until the write finishes.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use save::BackgroundSave;
 use simulation::SaveLoadState;
 
 use crate::theme;
@@ -42,7 +46,7 @@ pub struct LoadingScreenPlugin;
 impl Plugin for LoadingScreenPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LoadingAnimation>();
-        app.add_systems(Update, loading_screen_ui);
+        app.add_systems(Update, (loading_screen_ui, saving_indicator_ui));
     }
 }
 
@@ -50,10 +54,11 @@ impl Plugin for LoadingScreenPlugin {
 // Systems
 // =============================================================================
 
-/// Renders a loading overlay when a save/load/new-game operation is active.
+/// Renders a loading overlay when a load/new-game operation is active.
 ///
-/// The system checks the current `SaveLoadState` and, if it is anything other
-/// than `Idle`, draws a full-screen dark overlay with a centered message.
+/// The system checks the current `SaveLoadState` and, if it is loading or
+/// generating a world, draws a full-screen dark overlay with a centered
+/// message.
 fn loading_screen_ui(
     mut contexts: EguiContexts,
     state: Res<State<SaveLoadState>>,
@@ -61,13 +66,12 @@ fn loading_screen_ui(
     mut animation: ResMut<LoadingAnimation>,
 ) {
     let label = match state.get() {
-        SaveLoadState::Idle => {
+        SaveLoadState::Idle | SaveLoadState::Saving => {
             // Reset animation so it starts fresh next time.
             animation.dots = 1;
             animation.timer.reset();
             return;
         }
-        SaveLoadState::Saving => "Saving",
         SaveLoadState::Loading => "Loading",
         SaveLoadState::NewGame => "Generating World",
     };
@@ -120,3 +124,29 @@ fn loading_screen_ui(
             });
         });
 }
+
+/// Shows a non-blocking "Saving" spinner in the bottom-right corner while a
+/// save is being collected or written in the background.
+fn saving_indicator_ui(
+    mut contexts: EguiContexts,
+    state: Res<State<SaveLoadState>>,
+    background: Res<BackgroundSave>,
+) {
+    if *state.get() != SaveLoadState::Saving && !background.is_saving() {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+    egui::Area::new(egui::Id::new("saving_indicator"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -12.0))
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Spinner::new().size(theme::FONT_BODY));
+                    ui.label(egui::RichText::new("Saving").color(theme::TEXT));
+                });
+            });
+        });
+}
@@ -4,18 +4,24 @@
 //! buttons for New Game, Continue (most recent save), Load Game, Settings,
 //! and Quit (hidden on WASM).
 //!
-//! The load screen is extracted to `main_menu_load.rs` for modularity.
+//! The load screen is extracted to `main_menu_load.rs` and the heightmap and
+//! street map fields to `main_menu_map_import.rs` for modularity.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use save::{LoadGameEvent, NewGameEvent, PendingSavePath};
 use simulation::app_state::AppState;
+use simulation::game_settings::{Difficulty, GameSettings};
 use simulation::new_game_config::{random_seed, NewGameConfig};
 use simulation::save_slots::SaveSlotManager;
+use simulation::scenario::{PendingScenario, ScenarioLibrary};
 use simulation::PreLoadAppState;
 
 use crate::main_menu_load::{discover_save_files, SaveFileEntry};
+use crate::main_menu_map_import::{
+    load_map_imports, render_map_import_fields, MapImportInputs, PendingMapImports,
+};
 use crate::settings_menu::SettingsMenuOpen;
 
 // ---------------------------------------------------------------------------
@@ -27,10 +33,7 @@ pub struct MainMenuPlugin;
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MainMenuState>();
-        app.add_systems(
-            Update,
-            main_menu_ui.run_if(in_state(AppState::MainMenu)),
-        );
+        app.add_systems(Update, main_menu_ui.run_if(in_state(AppState::MainMenu)));
         app.add_systems(OnEnter(AppState::MainMenu), refresh_save_list);
     }
 }
@@ -56,6 +59,9 @@ struct MainMenuState {
     city_name_input: String,
     seed_input: String,
     seed_value: u64,
+    map_import: MapImportInputs,
+    /// Index into the scenario library, or `None` for a sandbox city.
+    scenario: Option<usize>,
     confirm_delete: Option<u32>,
 }
 
@@ -71,6 +77,8 @@ fn refresh_save_list(mut state: ResMut<MainMenuState>) {
     let seed = random_seed();
     state.seed_value = seed;
     state.seed_input = seed.to_string();
+    state.map_import.reset();
+    state.scenario = None;
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -87,6 +95,10 @@ fn main_menu_ui(
     slot_manager: Res<SaveSlotManager>,
     mut delete_events: EventWriter<simulation::save_slots::DeleteSlotEvent>,
     mut pre_load: ResMut<PreLoadAppState>,
+    scenarios: Res<ScenarioLibrary>,
+    mut pending_scenario: ResMut<PendingScenario>,
+    mut game_settings: ResMut<GameSettings>,
+    mut pending_map: PendingMapImports,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -121,6 +133,10 @@ fn main_menu_ui(
                 &mut next_app_state,
                 &mut new_game_events,
                 &mut new_game_config,
+                &scenarios,
+                &mut pending_scenario,
+                &mut game_settings,
+                &mut pending_map,
             );
         }
         MenuScreen::Main => {
@@ -257,12 +273,17 @@ fn render_main_buttons(
         });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_new_game_dialog(
     ctx: &egui::Context,
     state: &mut ResMut<MainMenuState>,
     next_app_state: &mut ResMut<NextState<AppState>>,
     new_game_events: &mut EventWriter<NewGameEvent>,
     new_game_config: &mut ResMut<NewGameConfig>,
+    scenarios: &ScenarioLibrary,
+    pending_scenario: &mut PendingScenario,
+    game_settings: &mut GameSettings,
+    pending_map: &mut PendingMapImports,
 ) {
     egui::CentralPanel::default()
         .frame(egui::Frame::NONE.fill(egui::Color32::from_rgba_premultiplied(20, 22, 30, 240)))
@@ -334,6 +355,91 @@ fn render_new_game_dialog(
                         state.seed_input = new_seed.to_string();
                     }
                 });
+                ui.add_space(20.0);
+
+                render_map_import_fields(ui, &mut state.map_import, field_width);
+                ui.add_space(20.0);
+
+                ui.label(
+                    egui::RichText::new("Scenario")
+                        .size(16.0)
+                        .color(egui::Color32::from_rgb(180, 190, 210)),
+                );
+                ui.add_space(4.0);
+                let selected = state.scenario.and_then(|i| scenarios.scenarios.get(i));
+                egui::ComboBox::from_id_salt("new_game_scenario")
+                    .width(field_width)
+                    .selected_text(selected.map_or("Sandbox", |s| s.name.as_str()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut state.scenario, None, "Sandbox");
+                        for (i, scenario) in scenarios.scenarios.iter().enumerate() {
+                            ui.selectable_value(
+                                &mut state.scenario,
+                                Some(i),
+                                scenario.name.as_str(),
+                            );
+                        }
+                    });
+                if let Some(scenario) = state.scenario.and_then(|i| scenarios.scenarios.get(i)) {
+                    ui.add_space(4.0);
+                    ui.add_sized(
+                        egui::vec2(field_width, 0.0),
+                        egui::Label::new(
+                            egui::RichText::new(&scenario.description)
+                                .size(13.0)
+                                .color(egui::Color32::from_rgb(150, 160, 180)),
+                        )
+                        .wrap(),
+                    );
+                }
+                if !scenarios.errors.is_empty() {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} scenario file(s) could not be loaded",
+                            scenarios.errors.len()
+                        ))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(220, 160, 40)),
+                    )
+                    .on_hover_text(scenarios.errors.join("\n"));
+                }
+                ui.add_space(20.0);
+
+                ui.label(
+                    egui::RichText::new("Difficulty")
+                        .size(16.0)
+                        .color(egui::Color32::from_rgb(180, 190, 210)),
+                );
+                ui.add_space(4.0);
+                let mut preset = game_settings.preset;
+                egui::ComboBox::from_id_salt("new_game_difficulty")
+                    .width(field_width)
+                    .selected_text(preset.name())
+                    .show_ui(ui, |ui| {
+                        for option in Difficulty::PRESETS {
+                            ui.selectable_value(&mut preset, option, option.name());
+                        }
+                    });
+                if preset != game_settings.preset {
+                    game_settings.apply_preset(preset);
+                }
+                let difficulty = &game_settings.difficulty;
+                ui.label(
+                    egui::RichText::new(format!(
+                        "${:.0} start · demand x{:.2} · disasters x{:.1} · loan rates x{:.1}",
+                        difficulty.starting_treasury,
+                        difficulty.demand_elasticity,
+                        difficulty.disaster_frequency,
+                        difficulty.loan_rate_multiplier,
+                    ))
+                    .size(13.0)
+                    .color(egui::Color32::from_rgb(150, 160, 180)),
+                );
+                ui.add_space(4.0);
+                let sandbox = &mut game_settings.sandbox;
+                ui.checkbox(&mut sandbox.unlimited_money, "Unlimited money");
+                ui.checkbox(&mut sandbox.no_disasters, "No disasters");
+                ui.checkbox(&mut sandbox.instant_construction, "Instant construction");
                 ui.add_space(32.0);
 
                 ui.horizontal(|ui| {
@@ -353,8 +459,15 @@ fn render_new_game_dialog(
                     if !name_valid {
                         start_btn.on_disabled_hover_text("City name cannot be empty");
                     } else if start_btn.clicked() {
+                        if !load_map_imports(&mut state.map_import, pending_map) {
+                            return;
+                        }
                         new_game_config.city_name = state.city_name_input.trim().to_string();
                         new_game_config.seed = state.seed_value;
+                        pending_scenario.0 = state
+                            .scenario
+                            .and_then(|i| scenarios.scenarios.get(i))
+                            .cloned();
                         new_game_events.send(NewGameEvent);
                         next_app_state.set(AppState::Playing);
                     }
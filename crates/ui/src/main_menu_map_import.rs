@@ -0,0 +1,145 @@
+//! New game dialog map import fields — heightmap and street map.
+//!
+//! Extracted from `main_menu.rs` to stay within the 500-line limit. The
+//! dialog renders [`render_map_import_fields`] and calls
+//! [`load_map_imports`] when Start is clicked.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use simulation::heightmap_import::{read_heightmap_file, PendingHeightmap};
+use simulation::osm_import::{read_osm_file, OsmImportOptions, PendingOsmImport};
+
+/// Map files chosen in the new game dialog.
+#[derive(Default)]
+pub struct MapImportInputs {
+    /// Path to a heightmap PNG; empty for procedural terrain.
+    pub heightmap_path: String,
+    /// Why the chosen heightmap was rejected.
+    pub heightmap_error: Option<String>,
+    /// Path to an OpenStreetMap extract; empty for an empty map.
+    pub streets_path: String,
+    /// Why the chosen extract was rejected.
+    pub streets_error: Option<String>,
+    /// Zone along imported streets from OSM land use.
+    pub zone_from_landuse: bool,
+}
+
+impl MapImportInputs {
+    /// Clears both paths for a fresh dialog.
+    pub fn reset(&mut self) {
+        *self = Self {
+            zone_from_landuse: true,
+            ..Self::default()
+        };
+    }
+}
+
+/// The imports the new game picks up once it starts.
+#[derive(SystemParam)]
+pub struct PendingMapImports<'w> {
+    pub heightmap: ResMut<'w, PendingHeightmap>,
+    pub streets: ResMut<'w, PendingOsmImport>,
+}
+
+fn error_label(ui: &mut egui::Ui, error: &Option<String>) {
+    if let Some(error) = error {
+        ui.label(
+            egui::RichText::new(error)
+                .size(13.0)
+                .color(egui::Color32::from_rgb(220, 80, 60)),
+        );
+    }
+}
+
+fn path_field(ui: &mut egui::Ui, title: &str, path: &mut String, hint: &str, width: f32) -> bool {
+    ui.label(
+        egui::RichText::new(title)
+            .size(16.0)
+            .color(egui::Color32::from_rgb(180, 190, 210)),
+    );
+    ui.add_space(4.0);
+    ui.add(
+        egui::TextEdit::singleline(path)
+            .desired_width(width)
+            .hint_text(hint)
+            .font(egui::TextStyle::Body),
+    )
+    .changed()
+}
+
+fn help_label(ui: &mut egui::Ui, text: &str) {
+    ui.label(
+        egui::RichText::new(text)
+            .size(13.0)
+            .color(egui::Color32::from_rgb(150, 160, 180)),
+    );
+}
+
+/// Renders the heightmap and street map fields of the new game dialog.
+pub fn render_map_import_fields(ui: &mut egui::Ui, inputs: &mut MapImportInputs, width: f32) {
+    if path_field(
+        ui,
+        "Heightmap (optional)",
+        &mut inputs.heightmap_path,
+        "path/to/heightmap.png",
+        width,
+    ) {
+        inputs.heightmap_error = None;
+    }
+    help_label(ui, "16-bit grayscale PNG; replaces the seed's terrain");
+    error_label(ui, &inputs.heightmap_error);
+    ui.add_space(20.0);
+
+    if path_field(
+        ui,
+        "Street map (optional)",
+        &mut inputs.streets_path,
+        "path/to/extract.osm.pbf",
+        width,
+    ) {
+        inputs.streets_error = None;
+    }
+    help_label(
+        ui,
+        "OpenStreetMap XML or PBF extract, laid out at real scale",
+    );
+    if !inputs.streets_path.trim().is_empty() {
+        ui.checkbox(&mut inputs.zone_from_landuse, "Zone from land use");
+    }
+    error_label(ui, &inputs.streets_error);
+}
+
+/// Reads the chosen map files into `pending`. Returns `false`, with the
+/// reason on the field, when a file can't be read.
+pub fn load_map_imports(inputs: &mut MapImportInputs, pending: &mut PendingMapImports) -> bool {
+    let path = inputs.heightmap_path.trim();
+    pending.heightmap.0 = None;
+    if !path.is_empty() {
+        match read_heightmap_file(std::path::Path::new(path)) {
+            Ok(heightmap) => pending.heightmap.0 = Some(heightmap),
+            Err(e) => {
+                inputs.heightmap_error = Some(e.to_string());
+                return false;
+            }
+        }
+    }
+    let path = inputs.streets_path.trim();
+    pending.streets.0 = None;
+    if !path.is_empty() {
+        match read_osm_file(std::path::Path::new(path)) {
+            Ok(data) => {
+                let options = OsmImportOptions {
+                    zone_from_landuse: inputs.zone_from_landuse,
+                };
+                pending.streets.0 = Some((data, options));
+            }
+            Err(e) => {
+                inputs.streets_error = Some(e.to_string());
+                return false;
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,137 @@
+//! Map tiles dashboard.
+//!
+//! Shows the map as a grid of tiles: the ones the city owns, the ones it
+//! can buy next with their price, and how many more the current milestone
+//! allows. Clicking a bordering tile buys it. Opens from the Terrain toolbar
+//! category.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::economy::CityBudget;
+use simulation::land_value::LandValueGrid;
+use simulation::map_tiles::{
+    max_owned_tiles, purchase_blocker, tile_land_value, tile_price, MapTiles, TilePurchase,
+    TILES_PER_SIDE,
+};
+use simulation::milestones::{MilestoneProgress, MilestoneTier};
+
+const COLOR_OWNED: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const COLOR_FOR_SALE: egui::Color32 = egui::Color32::from_rgb(230, 200, 80);
+const COLOR_LOCKED: egui::Color32 = egui::Color32::from_rgb(90, 90, 90);
+
+/// Side of one tile button, in points.
+const TILE_BUTTON_SIZE: f32 = 44.0;
+
+/// Whether the map tiles dashboard is visible.
+#[derive(Resource, Default)]
+pub struct MapTilesDashboardVisible(pub bool);
+
+/// Renders the map tiles dashboard window.
+pub fn map_tiles_dashboard_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<MapTilesDashboardVisible>,
+    tiles: Res<MapTiles>,
+    land_value: Res<LandValueGrid>,
+    progress: Res<MilestoneProgress>,
+    budget: Res<CityBudget>,
+    mut purchases: EventWriter<TilePurchase>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let tier = progress.current_tier;
+    let mut open = true;
+    egui::Window::new("Map Tiles")
+        .open(&mut open)
+        .default_width(TILE_BUTTON_SIZE * TILES_PER_SIDE as f32 + 40.0)
+        .show(contexts.ctx_mut(), |ui| {
+            if !tiles.is_restricted() {
+                ui.label("The whole map is open to build on.");
+                return;
+            }
+
+            let owned = tiles.owned_count();
+            let allowed = max_owned_tiles(tier);
+            ui.label(format!("Owned tiles: {owned} of {allowed} allowed"));
+            if owned >= allowed {
+                let next = MilestoneTier::ALL
+                    .iter()
+                    .find(|t| max_owned_tiles(**t) > owned);
+                if let Some(next) = next {
+                    ui.small(format!("Reach {} to buy more tiles.", next.name()));
+                }
+            }
+            ui.separator();
+
+            egui::Grid::new("map_tiles_grid")
+                .spacing(egui::vec2(2.0, 2.0))
+                .show(ui, |ui| {
+                    for ty in 0..TILES_PER_SIDE {
+                        for tx in 0..TILES_PER_SIDE {
+                            let size = egui::vec2(TILE_BUTTON_SIZE, TILE_BUTTON_SIZE);
+                            if tiles.is_owned(tx, ty) {
+                                let button = egui::Button::new("").fill(COLOR_OWNED);
+                                ui.add_enabled(false, button.min_size(size));
+                                continue;
+                            }
+                            let price = tile_price(tile_land_value(&tiles, &land_value, tx, ty));
+                            let blocker = purchase_blocker(&tiles, tx, ty, tier);
+                            let (fill, text) = match &blocker {
+                                None => (COLOR_FOR_SALE, format!("${:.0}k", price / 1000.0)),
+                                Some(_) => (COLOR_LOCKED, String::new()),
+                            };
+                            let button = egui::Button::new(
+                                egui::RichText::new(text)
+                                    .small()
+                                    .color(egui::Color32::BLACK),
+                            )
+                            .fill(fill)
+                            .min_size(size);
+                            let affordable = budget.treasury >= price;
+                            let response = ui.add_enabled(blocker.is_none(), button);
+                            let response = match &blocker {
+                                Some(reason) => response.on_disabled_hover_text(reason),
+                                None if affordable => response.on_hover_text(format!(
+                                    "Buy tile ({tx}, {ty}) for ${price:.0}"
+                                )),
+                                None => response.on_hover_text(format!(
+                                    "Needs ${price:.0}, treasury has ${:.0}",
+                                    budget.treasury
+                                )),
+                            };
+                            if response.clicked() {
+                                purchases.send(TilePurchase {
+                                    tile_x: tx,
+                                    tile_y: ty,
+                                });
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.small(format!(
+                "Tiles bought: {}, for ${:.0}. Prices rise with land value.",
+                tiles.purchased, tiles.total_spent
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct MapTilesDashboardPlugin;
+
+impl Plugin for MapTilesDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapTilesDashboardVisible>().add_systems(
+            Update,
+            map_tiles_dashboard_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
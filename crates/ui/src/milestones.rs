@@ -3,6 +3,8 @@ use bevy_egui::{egui, EguiContexts};
 
 use simulation::stats::CityStats;
 
+use crate::unlock_tree_panel::UnlockTreeVisible;
+
 #[derive(Resource, Default)]
 pub struct Milestones {
     pub reached: Vec<MilestoneEntry>,
@@ -70,6 +72,7 @@ pub fn milestones_ui(
     mut contexts: EguiContexts,
     milestones: Res<Milestones>,
     stats: Res<CityStats>,
+    mut tree_visible: ResMut<UnlockTreeVisible>,
 ) {
     egui::Window::new("Milestones")
         .default_open(false)
@@ -78,6 +81,9 @@ pub fn milestones_ui(
                 "City Status: {}",
                 current_city_name(stats.population)
             ));
+            if ui.button("Unlock Tree...").clicked() {
+                tree_visible.0 = !tree_visible.0;
+            }
             ui.separator();
 
             for &(pop, name) in MILESTONE_POPS {
@@ -10,7 +10,7 @@
 //! - Camera viewport shown as white rectangle
 //! - Click on minimap moves camera focus with smooth transition
 //! - Updated every 3 seconds (not real-time)
-//! - Toggle visibility with M key
+//! - Toggle visibility with M key (rebindable)
 
 use bevy::prelude::*;
 use simulation::app_state::AppState;
@@ -19,6 +19,7 @@ use bevy_egui::{egui, EguiContexts};
 use rendering::camera::OrbitCamera;
 use simulation::config::{GRID_HEIGHT, GRID_WIDTH, WORLD_HEIGHT, WORLD_WIDTH};
 use simulation::grid::{CellType, WorldGrid, ZoneType};
+use simulation::keybindings::KeyBindings;
 
 // =============================================================================
 // Constants
@@ -115,17 +116,17 @@ struct CameraTransition {
 // Systems
 // =============================================================================
 
-/// Toggle minimap visibility with M key.
+/// Toggle minimap visibility with the configured key (M by default).
 fn minimap_toggle_keybind(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     mut state: ResMut<MinimapState>,
     mut contexts: EguiContexts,
 ) {
     if contexts.ctx_mut().wants_keyboard_input() {
         return;
     }
-    let shift_held = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-    if keyboard.just_pressed(KeyCode::KeyM) && !shift_held {
+    if bindings.toggle_minimap.just_pressed(&keyboard) {
         state.visible = !state.visible;
     }
 }
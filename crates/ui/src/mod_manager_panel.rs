@@ -0,0 +1,136 @@
+//! Mod manager window.
+//!
+//! Lists the installed mod packages with their versions and dependencies,
+//! lets the player switch them on or off (from the next start), and shows
+//! the load order, dependency and save-key problems, and any mods the loaded
+//! save was made with that aren't running. Accessible from the Settings
+//! panel.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::mods::{ModManager, MODS_DIR};
+
+/// Whether the mod manager window is visible.
+#[derive(Resource, Default)]
+pub struct ModManagerVisible(pub bool);
+
+const WARNING: egui::Color32 = egui::Color32::from_rgb(255, 180, 50);
+const MUTED: egui::Color32 = egui::Color32::from_gray(160);
+
+/// Renders the mod manager window.
+pub fn mod_manager_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<ModManagerVisible>,
+    mut mods: ResMut<ModManager>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Mods")
+        .open(&mut open)
+        .resizable(true)
+        .default_width(420.0)
+        .max_height(600.0)
+        .vscroll(true)
+        .show(contexts.ctx_mut(), |ui| {
+            if !mods.save_mismatches.is_empty() {
+                ui.heading("This city");
+                ui.separator();
+                for mismatch in &mods.save_mismatches {
+                    ui.colored_label(WARNING, mismatch.to_string());
+                }
+                ui.add_space(12.0);
+            }
+
+            ui.heading("Installed");
+            ui.separator();
+            if mods.installed.is_empty() {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "No mods installed. Put each mod in its own folder under {MODS_DIR}/ \
+                         with a mod.json manifest."
+                    ))
+                    .small()
+                    .color(MUTED),
+                );
+            }
+            let mut toggled = None;
+            for installed in &mods.installed {
+                let manifest = &installed.manifest;
+                ui.horizontal(|ui| {
+                    let mut enabled = mods.is_enabled(&manifest.id);
+                    if ui.checkbox(&mut enabled, &manifest.name).changed() {
+                        toggled = Some((manifest.id.clone(), enabled));
+                    }
+                    ui.label(egui::RichText::new(manifest.version.to_string()).color(MUTED));
+                    if mods.is_enabled(&manifest.id) && !mods.is_loaded(&manifest.id) {
+                        ui.colored_label(WARNING, "not loaded");
+                    }
+                });
+                ui.indent(&manifest.id, |ui| {
+                    if !manifest.description.is_empty() {
+                        ui.label(egui::RichText::new(&manifest.description).small());
+                    }
+                    if !manifest.dependencies.is_empty() {
+                        let deps: Vec<String> = manifest
+                            .dependencies
+                            .iter()
+                            .map(|d| format!("{} {}+", d.id, d.version))
+                            .collect();
+                        ui.label(
+                            egui::RichText::new(format!("Requires {}", deps.join(", ")))
+                                .small()
+                                .color(MUTED),
+                        );
+                    }
+                });
+            }
+            if let Some((id, enabled)) = toggled {
+                mods.set_enabled(&id, enabled);
+            }
+            if mods.restart_required() {
+                ui.add_space(4.0);
+                ui.colored_label(WARNING, "Restart the game to apply mod changes.");
+            }
+
+            if !mods.load_order.is_empty() {
+                ui.add_space(12.0);
+                ui.heading("Load order");
+                ui.separator();
+                for (i, installed) in mods.loaded().enumerate() {
+                    ui.label(format!(
+                        "{}. {} {}",
+                        i + 1,
+                        installed.manifest.name,
+                        installed.manifest.version
+                    ));
+                }
+            }
+
+            if !mods.issues.is_empty() {
+                ui.add_space(12.0);
+                ui.heading("Problems");
+                ui.separator();
+                for issue in &mods.issues {
+                    ui.colored_label(WARNING, issue.to_string());
+                }
+            }
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct ModManagerPanelPlugin;
+
+impl Plugin for ModManagerPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModManagerVisible>()
+            .add_systems(Update, mod_manager_ui.run_if(in_state(AppState::Playing)));
+    }
+}
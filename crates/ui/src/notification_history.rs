@@ -0,0 +1,203 @@
+//! Notification history drawer.
+//!
+//! Lists every past notification, filterable by category and priority, and
+//! holds the popup filter controls from `NotificationFilters`: which
+//! categories pop up, the least urgent priority that does, and
+//! do-not-disturb during disasters. Toggled from the notification ticker's
+//! History button; clicking an entry with a location jumps the camera there.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::notification_ticker::{priority_color, priority_prefix, NotificationJournalVisible};
+use crate::theme;
+use rendering::camera::OrbitCamera;
+use simulation::accessibility::AccessibilitySettings;
+use simulation::notification_filters::NotificationFilters;
+use simulation::notifications::{
+    JournalEntry, NotificationCategory, NotificationLog, NotificationPriority,
+};
+
+/// What the history drawer lists. `None` shows everything.
+#[derive(Default)]
+pub struct HistoryView {
+    pub category: Option<NotificationCategory>,
+    /// Least urgent priority listed.
+    pub min_priority: Option<NotificationPriority>,
+}
+
+impl HistoryView {
+    fn matches(&self, entry: &JournalEntry) -> bool {
+        self.category.is_none_or(|c| entry.category == c)
+            && self.min_priority.is_none_or(|p| entry.priority <= p)
+    }
+}
+
+/// Draws the popup filter controls.
+fn popup_filter_controls(ui: &mut egui::Ui, filters: &mut ResMut<NotificationFilters>) {
+    ui.label("Pop up:");
+    ui.horizontal_wrapped(|ui| {
+        for category in NotificationCategory::ALL {
+            let mut shown = filters.shows_category(category);
+            if ui.checkbox(&mut shown, category.label()).changed() {
+                filters.set_category(category, shown);
+            }
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label("Least urgent:");
+        let mut min_priority = filters.min_priority;
+        egui::ComboBox::from_id_salt("popup_min_priority")
+            .selected_text(min_priority.label())
+            .show_ui(ui, |ui| {
+                for priority in NotificationPriority::ALL {
+                    ui.selectable_value(&mut min_priority, priority, priority.label());
+                }
+            });
+        if min_priority != filters.min_priority {
+            filters.min_priority = min_priority;
+        }
+    });
+    let mut dnd = filters.do_not_disturb;
+    if ui
+        .checkbox(&mut dnd, "Do not disturb during disasters")
+        .on_hover_text("Only emergencies and disaster updates pop up while a disaster is under way")
+        .changed()
+    {
+        filters.do_not_disturb = dnd;
+    }
+    ui.label(
+        egui::RichText::new("Emergencies always pop up.")
+            .small()
+            .color(theme::TEXT_MUTED),
+    );
+}
+
+/// Draws the category and priority selectors for the history list.
+fn history_view_controls(ui: &mut egui::Ui, view: &mut HistoryView) {
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt("history_category")
+            .selected_text(view.category.map_or("All categories", |c| c.label()))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut view.category, None, "All categories");
+                for category in NotificationCategory::ALL {
+                    ui.selectable_value(&mut view.category, Some(category), category.label());
+                }
+            });
+        egui::ComboBox::from_id_salt("history_priority")
+            .selected_text(view.min_priority.map_or("All priorities", |p| p.label()))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut view.min_priority, None, "All priorities");
+                for priority in NotificationPriority::ALL {
+                    ui.selectable_value(&mut view.min_priority, Some(priority), priority.label());
+                }
+            });
+    });
+}
+
+/// Renders the notification history drawer.
+#[allow(clippy::too_many_arguments)]
+pub fn notification_journal_ui(
+    mut contexts: EguiContexts,
+    log: Res<NotificationLog>,
+    mut orbit: ResMut<OrbitCamera>,
+    visible: Res<NotificationJournalVisible>,
+    accessibility: Res<AccessibilitySettings>,
+    mut filters: ResMut<NotificationFilters>,
+    mut view: Local<HistoryView>,
+) {
+    if !visible.0 {
+        return;
+    }
+    let mut jump_target: Option<(f32, f32)> = None;
+
+    egui::Window::new("Notification History")
+        .default_width(400.0)
+        .default_height(350.0)
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8.0, -8.0))
+        .resizable(true)
+        .collapsible(true)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.collapsing("Popup Filters", |ui| {
+                popup_filter_controls(ui, &mut filters);
+            });
+            ui.separator();
+            history_view_controls(ui, &mut view);
+            let shown = log.journal.iter().filter(|e| view.matches(e)).count();
+            ui.label(format!("{} of {} entries", shown, log.journal.len()));
+            ui.separator();
+            if log.journal.is_empty() {
+                ui.label("No notifications recorded yet.");
+                return;
+            }
+            egui::ScrollArea::vertical()
+                .max_height(400.0)
+                .show(ui, |ui| {
+                    for entry in log.journal.iter().rev().filter(|e| view.matches(e)) {
+                        let color = priority_color(entry.priority);
+                        let prefix = priority_prefix(entry.priority, accessibility.enabled);
+                        let h = entry.hour as u32;
+                        let m = ((entry.hour.fract()) * 60.0) as u32;
+                        let time_str = format!("Day {} {:02}:{:02}", entry.day, h, m);
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                egui::RichText::new(&time_str)
+                                    .small()
+                                    .color(theme::TEXT_MUTED),
+                            );
+                            let label_text = format!("{} {}", prefix, entry.text);
+                            let response = ui.add(
+                                egui::Label::new(egui::RichText::new(&label_text).color(color))
+                                    .sense(egui::Sense::click()),
+                            );
+                            if response.clicked() {
+                                if let Some(loc) = entry.location {
+                                    jump_target = Some(loc);
+                                }
+                            }
+                            if entry.location.is_some() {
+                                response.on_hover_text("Click to jump to location");
+                            }
+                        });
+                        ui.add_space(1.0);
+                    }
+                });
+        });
+
+    if let Some((wx, wz)) = jump_target {
+        orbit.focus.x = wx;
+        orbit.focus.z = wz;
+        orbit.distance = orbit.distance.min(400.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_view_filters_by_category_and_priority() {
+        let entry = JournalEntry {
+            text: "Treasury low".to_string(),
+            priority: NotificationPriority::Warning,
+            category: NotificationCategory::Finance,
+            location: None,
+            day: 1,
+            hour: 8.0,
+        };
+        assert!(HistoryView::default().matches(&entry));
+
+        let mut view = HistoryView {
+            category: Some(NotificationCategory::Disaster),
+            min_priority: None,
+        };
+        assert!(!view.matches(&entry));
+        view.category = Some(NotificationCategory::Finance);
+        assert!(view.matches(&entry));
+
+        view.min_priority = Some(NotificationPriority::Emergency);
+        assert!(!view.matches(&entry));
+        view.min_priority = Some(NotificationPriority::Attention);
+        assert!(view.matches(&entry));
+    }
+}
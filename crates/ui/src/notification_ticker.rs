@@ -6,8 +6,12 @@
 //! clicking. Emergency notifications stay longer. Clicking a notification
 //! with a location also jumps the camera there.
 //!
-//! Also provides a notification journal window (toggled via button) showing
-//! the full history of past notifications.
+//! Notifications rejected by the popup filters in `NotificationFilters` are
+//! dismissed straight away but stay in the history. A button toggles the
+//! history drawer (`notification_history.rs`).
+//!
+//! In accessibility mode priorities are spelled out ("[WARNING]") instead of
+//! shown as icons, and toasts stay up twice as long.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
@@ -15,12 +19,17 @@ use simulation::app_state::AppState;
 
 use crate::theme;
 use rendering::camera::OrbitCamera;
+use simulation::accessibility::AccessibilitySettings;
+use simulation::disasters::ActiveDisaster;
+use simulation::notification_filters::NotificationFilters;
 use simulation::notifications::{NotificationLog, NotificationPriority};
 
 const MAX_VISIBLE_TOASTS: usize = 5;
 const TOAST_DURATION_DEFAULT: f32 = 5.0;
 const TOAST_DURATION_CRITICAL: f32 = 15.0;
 const TOAST_DURATION_WARNING: f32 = 8.0;
+/// Toast durations are multiplied by this in accessibility mode.
+const ACCESSIBLE_TOAST_DURATION_FACTOR: f32 = 2.0;
 const TOAST_WIDTH: f32 = 360.0;
 const TOAST_RIGHT_MARGIN: f32 = 16.0;
 const TOAST_TOP_MARGIN: f32 = 48.0;
@@ -65,7 +74,7 @@ impl ToastTimers {
     }
 }
 
-fn priority_color(priority: NotificationPriority) -> egui::Color32 {
+pub(crate) fn priority_color(priority: NotificationPriority) -> egui::Color32 {
     match priority {
         NotificationPriority::Emergency => theme::ERROR,
         NotificationPriority::Warning | NotificationPriority::Attention => theme::WARNING,
@@ -94,26 +103,58 @@ fn priority_icon(priority: NotificationPriority) -> &'static str {
     }
 }
 
-fn toast_duration(priority: NotificationPriority) -> f32 {
-    match priority {
+/// Icon, or the priority in words when `accessible`.
+pub(crate) fn priority_prefix(priority: NotificationPriority, accessible: bool) -> String {
+    if accessible {
+        format!("[{}]", priority.label())
+    } else {
+        priority_icon(priority).to_string()
+    }
+}
+
+fn toast_duration(priority: NotificationPriority, accessible: bool) -> f32 {
+    let duration = match priority {
         NotificationPriority::Emergency => TOAST_DURATION_CRITICAL,
         NotificationPriority::Warning => TOAST_DURATION_WARNING,
         _ => TOAST_DURATION_DEFAULT,
+    };
+    if accessible {
+        duration * ACCESSIBLE_TOAST_DURATION_FACTOR
+    } else {
+        duration
     }
 }
 
 /// Ticks toast timers and dismisses expired toasts from the notification log.
+/// Notifications the popup filters reject are dismissed immediately.
 pub fn tick_toast_timers(
     mut timers: ResMut<ToastTimers>,
     mut log: ResMut<NotificationLog>,
     time: Res<Time>,
+    accessibility: Res<AccessibilitySettings>,
+    filters: Res<NotificationFilters>,
+    disaster: Res<ActiveDisaster>,
 ) {
     let dt = time.delta_secs();
     if dt <= 0.0 {
         return;
     }
+    let disaster_active = disaster.current.is_some();
+    let filtered: Vec<u64> = log
+        .active
+        .iter()
+        .filter(|n| !n.dismissed)
+        .filter(|n| !filters.allows_popup(n.priority, n.category, disaster_active))
+        .map(|n| n.id)
+        .collect();
+    for id in filtered {
+        log.dismiss(id);
+    }
     for notif in &log.active {
-        timers.ensure_timer(notif.id, toast_duration(notif.priority));
+        timers.ensure_timer(
+            notif.id,
+            toast_duration(notif.priority, accessibility.enabled),
+        );
     }
     let active_ids: Vec<u64> = log.active.iter().map(|n| n.id).collect();
     timers.timers.retain(|(id, _)| active_ids.contains(id));
@@ -131,9 +172,12 @@ pub fn notification_toast_ui(
     mut orbit: ResMut<OrbitCamera>,
     mut journal_visible: ResMut<NotificationJournalVisible>,
     mut timers: ResMut<ToastTimers>,
+    accessibility: Res<AccessibilitySettings>,
+    filters: Res<NotificationFilters>,
+    disaster: Res<ActiveDisaster>,
 ) {
-    let active_count = log.active.len();
-    if active_count == 0 && !journal_visible.0 {
+    let active_count = log.active.iter().filter(|n| !n.dismissed).count();
+    if active_count == 0 && !journal_visible.0 && log.journal.is_empty() {
         return;
     }
 
@@ -142,7 +186,9 @@ pub fn notification_toast_ui(
     let ctx = contexts.ctx_mut();
     let screen_width = ctx.screen_rect().width();
 
-    let mut sorted_indices: Vec<usize> = (0..log.active.len()).collect();
+    let mut sorted_indices: Vec<usize> = (0..log.active.len())
+        .filter(|&i| !log.active[i].dismissed)
+        .collect();
     sorted_indices.sort_by(|&a, &b| {
         let na = &log.active[a];
         let nb = &log.active[b];
@@ -157,8 +203,8 @@ pub fn notification_toast_ui(
         let toast_id = notif.id;
         let color = priority_color(notif.priority);
         let bg = priority_bg(notif.priority);
-        let icon = priority_icon(notif.priority);
-        let label_text = format!("{} {}", icon, notif.text);
+        let prefix = priority_prefix(notif.priority, accessibility.enabled);
+        let label_text = format!("{} {}", prefix, notif.text);
         let priority = notif.priority;
         let location = notif.location;
         let day = notif.day;
@@ -231,31 +277,36 @@ pub fn notification_toast_ui(
             });
     }
 
-    // Journal toggle button
-    if active_count > 0 || journal_visible.0 {
-        egui::Area::new(egui::Id::new("toast_journal_toggle"))
-            .fixed_pos(egui::pos2(8.0, TOAST_TOP_MARGIN))
-            .order(egui::Order::Middle)
-            .show(ctx, |ui| {
-                ui.horizontal(|ui| {
-                    let journal_btn = if journal_visible.0 {
-                        "Journal [v]"
-                    } else {
-                        "Journal [>]"
-                    };
-                    if ui.small_button(journal_btn).clicked() {
-                        journal_visible.0 = !journal_visible.0;
-                    }
-                    if active_count > 0 {
-                        ui.label(
-                            egui::RichText::new(format!("({} active)", active_count))
-                                .small()
-                                .color(theme::TEXT_MUTED),
-                        );
-                    }
-                });
+    // History toggle button
+    egui::Area::new(egui::Id::new("toast_journal_toggle"))
+        .fixed_pos(egui::pos2(8.0, TOAST_TOP_MARGIN))
+        .order(egui::Order::Middle)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let journal_btn = if journal_visible.0 {
+                    "History [v]"
+                } else {
+                    "History [>]"
+                };
+                if ui.small_button(journal_btn).clicked() {
+                    journal_visible.0 = !journal_visible.0;
+                }
+                if active_count > 0 {
+                    ui.label(
+                        egui::RichText::new(format!("({} active)", active_count))
+                            .small()
+                            .color(theme::TEXT_MUTED),
+                    );
+                }
+                if filters.do_not_disturb && disaster.current.is_some() {
+                    ui.label(
+                        egui::RichText::new("Do not disturb")
+                            .small()
+                            .color(theme::WARNING),
+                    );
+                }
             });
-    }
+        });
 
     if let Some((wx, wz)) = jump_target {
         orbit.focus.x = wx;
@@ -268,74 +319,6 @@ pub fn notification_toast_ui(
     }
 }
 
-/// Renders the notification journal window showing full history.
-pub fn notification_journal_ui(
-    mut contexts: EguiContexts,
-    log: Res<NotificationLog>,
-    mut orbit: ResMut<OrbitCamera>,
-    visible: Res<NotificationJournalVisible>,
-) {
-    if !visible.0 {
-        return;
-    }
-    let mut jump_target: Option<(f32, f32)> = None;
-
-    egui::Window::new("Notification Journal")
-        .default_width(400.0)
-        .default_height(350.0)
-        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8.0, -8.0))
-        .resizable(true)
-        .collapsible(true)
-        .show(contexts.ctx_mut(), |ui| {
-            ui.label(format!("{} entries", log.journal.len()));
-            ui.separator();
-            if log.journal.is_empty() {
-                ui.label("No notifications recorded yet.");
-                return;
-            }
-            egui::ScrollArea::vertical()
-                .max_height(400.0)
-                .show(ui, |ui| {
-                    for entry in log.journal.iter().rev() {
-                        let color = priority_color(entry.priority);
-                        let icon = priority_icon(entry.priority);
-                        let h = entry.hour as u32;
-                        let m = ((entry.hour.fract()) * 60.0) as u32;
-                        let time_str = format!("Day {} {:02}:{:02}", entry.day, h, m);
-                        ui.horizontal(|ui| {
-                            ui.label(
-                                egui::RichText::new(&time_str)
-                                    .small()
-                                    .color(theme::TEXT_MUTED),
-                            );
-                            let label_text = format!("{} {}", icon, entry.text);
-                            let response = ui.add(
-                                egui::Label::new(
-                                    egui::RichText::new(&label_text).color(color),
-                                )
-                                .sense(egui::Sense::click()),
-                            );
-                            if response.clicked() {
-                                if let Some(loc) = entry.location {
-                                    jump_target = Some(loc);
-                                }
-                            }
-                            if entry.location.is_some() {
-                                response.on_hover_text("Click to jump to location");
-                            }
-                        });
-                        ui.add_space(1.0);
-                    }
-                });
-        });
-
-    if let Some((wx, wz)) = jump_target {
-        orbit.focus.x = wx;
-        orbit.focus.z = wz;
-        orbit.distance = orbit.distance.min(400.0);
-    }
-}
-
 pub struct NotificationTickerPlugin;
 
 impl Plugin for NotificationTickerPlugin {
@@ -347,7 +330,7 @@ impl Plugin for NotificationTickerPlugin {
                 (
                     tick_toast_timers,
                     notification_toast_ui,
-                    notification_journal_ui,
+                    crate::notification_history::notification_journal_ui,
                 )
                     .chain()
                     .run_if(in_state(AppState::Playing)),
@@ -429,12 +412,25 @@ mod tests {
     #[test]
     fn test_toast_duration_critical_longer() {
         assert!(
-            toast_duration(NotificationPriority::Emergency)
-                > toast_duration(NotificationPriority::Info)
+            toast_duration(NotificationPriority::Emergency, false)
+                > toast_duration(NotificationPriority::Info, false)
+        );
+        assert!(
+            toast_duration(NotificationPriority::Warning, false)
+                > toast_duration(NotificationPriority::Info, false)
+        );
+    }
+
+    #[test]
+    fn test_accessible_toasts_spell_out_priority_and_last_longer() {
+        assert_eq!(priority_prefix(NotificationPriority::Warning, false), "[W]");
+        assert_eq!(
+            priority_prefix(NotificationPriority::Warning, true),
+            "[WARNING]"
         );
         assert!(
-            toast_duration(NotificationPriority::Warning)
-                > toast_duration(NotificationPriority::Info)
+            toast_duration(NotificationPriority::Info, true)
+                > toast_duration(NotificationPriority::Info, false)
         );
     }
 
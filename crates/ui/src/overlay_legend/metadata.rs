@@ -3,7 +3,9 @@
 //! Maps each `OverlayMode` to its legend representation (continuous ramp,
 //! binary swatches, tiered bands, or directional label).
 
-use rendering::color_ramps::{CIVIDIS, GROUNDWATER_LEVEL, GROUNDWATER_QUALITY, INFERNO, VIRIDIS};
+use rendering::color_ramps::{
+    BEACH_SAND, CIVIDIS, GROUNDWATER_LEVEL, GROUNDWATER_QUALITY, INFERNO, VIRIDIS,
+};
 use rendering::colorblind_palette;
 use rendering::overlay::OverlayMode;
 
@@ -162,5 +164,45 @@ pub(crate) fn legend_for_mode(
                 description: "Arrows show wind direction and speed",
             },
         )),
+        OverlayMode::Biodiversity => Some((
+            "Biodiversity",
+            LegendKind::Continuous {
+                ramp: &VIRIDIS,
+                min_label: "Barren",
+                max_label: "Thriving",
+            },
+        )),
+        OverlayMode::Erosion => Some((
+            "Beach Erosion",
+            LegendKind::Continuous {
+                ramp: &BEACH_SAND,
+                min_label: "Eroded",
+                max_label: "Full sand",
+            },
+        )),
+        OverlayMode::CommuteTime => Some((
+            "Commute Time",
+            LegendKind::Continuous {
+                ramp: &INFERNO,
+                min_label: "Short",
+                max_label: "60+ min",
+            },
+        )),
+        OverlayMode::TransitRidership => Some((
+            "Transit Ridership",
+            LegendKind::Continuous {
+                ramp: &VIRIDIS,
+                min_label: "Quiet",
+                max_label: "Busiest stop",
+            },
+        )),
+        OverlayMode::JobAccess => Some((
+            "Jobs Within 30 min",
+            LegendKind::Continuous {
+                ramp: &CIVIDIS,
+                min_label: "Few",
+                max_label: "All jobs",
+            },
+        )),
     }
 }
@@ -3,8 +3,9 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
-use rendering::color_ramps::ColorRamp;
+use rendering::color_ramps::{ColorRamp, CHANGE};
 use rendering::overlay::{OverlayMode, OverlayState};
+use rendering::overlay_snapshot::OverlaySnapshots;
 use simulation::colorblind::ColorblindSettings;
 
 use super::metadata::legend_for_mode;
@@ -20,6 +21,7 @@ pub(crate) fn overlay_legend_ui(
     mut contexts: EguiContexts,
     overlay: Res<OverlayState>,
     cb_settings: Res<ColorblindSettings>,
+    snapshots: Res<OverlaySnapshots>,
     mut cache: ResMut<LegendTextureCache>,
 ) {
     let cb_mode = cb_settings.mode;
@@ -38,6 +40,20 @@ pub(crate) fn overlay_legend_ui(
     let ctx = contexts.ctx_mut();
     let screen = ctx.screen_rect();
 
+    // Showing the change since a snapshot replaces the overlay's own ramp.
+    let diff = snapshots.diff_for(mode).is_some();
+    if cache.cached_diff != diff {
+        cache.cached_diff = diff;
+        cache.texture = None;
+    }
+    if diff {
+        let name = format!("{} change", name);
+        render_continuous_legend(
+            ctx, &mut cache, mode, cb_mode, &CHANGE, &name, "Lower", "Higher", &screen,
+        );
+        return;
+    }
+
     match kind {
         LegendKind::Continuous {
             ramp,
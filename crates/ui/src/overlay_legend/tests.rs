@@ -27,6 +27,11 @@ fn legend_returns_some_for_all_active_overlays() {
         OverlayMode::GroundwaterLevel,
         OverlayMode::GroundwaterQuality,
         OverlayMode::Wind,
+        OverlayMode::Biodiversity,
+        OverlayMode::Erosion,
+        OverlayMode::CommuteTime,
+        OverlayMode::TransitRidership,
+        OverlayMode::JobAccess,
     ];
     for mode in modes {
         let result = legend_for_mode(mode, ColorblindMode::Normal);
@@ -29,6 +29,8 @@ pub struct LegendTextureCache {
     pub(crate) cached_mode: Option<OverlayMode>,
     /// Whether colorblind mode was active when the texture was generated.
     pub(crate) cached_cb_mode: Option<simulation::colorblind::ColorblindMode>,
+    /// Whether the texture shows the before/after change ramp.
+    pub(crate) cached_diff: bool,
     /// The egui texture handle for the gradient.
     pub(crate) texture: Option<egui::TextureHandle>,
 }
@@ -9,6 +9,7 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use rendering::photo_mode::PhotoMode;
 use simulation::app_state::AppState;
 use simulation::time_of_day::GameClock;
 
@@ -56,6 +57,7 @@ fn toggle_pause(
     mut confirm: ResMut<MainMenuConfirm>,
     mut settings_menu: ResMut<SettingsMenuOpen>,
     mut pending_confirm: ResMut<PendingConfirmAction>,
+    mut photo: ResMut<PhotoMode>,
 ) {
     // Don't intercept ESC if egui is consuming keyboard input (e.g. text fields).
     if contexts.ctx_mut().wants_keyboard_input() {
@@ -78,6 +80,12 @@ fn toggle_pause(
         return;
     }
 
+    // In photo mode, ESC leaves photo mode, which restores the clock itself.
+    if photo.active {
+        photo.active = false;
+        return;
+    }
+
     match app_state.get() {
         AppState::Playing => {
             game_clock.paused = true;
@@ -0,0 +1,104 @@
+//! Photo mode controls.
+//!
+//! Shown while photo mode is active: colour filter, exposure, depth of
+//! field, capture resolution, hiding the UI, taking the photo and leaving
+//! photo mode. The free camera itself is flown from `rendering::photo_mode`.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use rendering::photo_mode::{PhotoFilter, PhotoMode, MAX_RESOLUTION_SCALE};
+use simulation::app_state::AppState;
+use simulation::keybindings::KeyBindings;
+
+use crate::theme;
+
+pub struct PhotoModePanelPlugin;
+
+impl Plugin for PhotoModePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            photo_mode_panel_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+/// Renders the photo mode controls window.
+fn photo_mode_panel_ui(
+    mut contexts: EguiContexts,
+    mut photo: ResMut<PhotoMode>,
+    bindings: Res<KeyBindings>,
+) {
+    if !photo.active {
+        return;
+    }
+
+    // Edit a copy and write back only on change, so the camera effects are
+    // not re-applied every frame.
+    let mut edit = photo.clone();
+    egui::Window::new("Photo Mode")
+        .resizable(false)
+        .default_width(260.0)
+        .anchor(egui::Align2::RIGHT_TOP, [-12.0, 12.0])
+        .show(contexts.ctx_mut(), |ui| {
+            ui.spacing_mut().item_spacing.y = 6.0;
+
+            ui.label("Filter:");
+            ui.horizontal_wrapped(|ui| {
+                for filter in PhotoFilter::ALL {
+                    ui.selectable_value(&mut edit.filter, filter, filter.name());
+                }
+            });
+            ui.add(egui::Slider::new(&mut edit.exposure, -3.0..=3.0).text("exposure (EV)"));
+
+            ui.separator();
+            ui.checkbox(&mut edit.depth_of_field, "Depth of field");
+            ui.add_enabled_ui(edit.depth_of_field, |ui| {
+                ui.add(
+                    egui::Slider::new(&mut edit.focal_distance, 10.0..=4000.0)
+                        .logarithmic(true)
+                        .text("focus distance"),
+                );
+                ui.add(
+                    egui::Slider::new(&mut edit.aperture_f_stops, 0.5..=16.0)
+                        .logarithmic(true)
+                        .text("aperture (f-stop)"),
+                );
+            });
+
+            ui.separator();
+            ui.add(
+                egui::Slider::new(&mut edit.resolution_scale, 1..=MAX_RESOLUTION_SCALE)
+                    .text("x window resolution"),
+            );
+            ui.checkbox(&mut edit.hide_ui, "Hide UI (H)");
+
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                let take = format!("Take Photo ({})", bindings.screenshot.display_label());
+                if ui.button(take).clicked() {
+                    edit.capture_requested = true;
+                }
+                let exit = format!("Exit ({})", bindings.toggle_photo_mode.display_label());
+                if ui.button(exit).clicked() {
+                    edit.active = false;
+                }
+            });
+
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(
+                    "Move with the camera pan keys, roll with the rotate keys, \
+                     look around with right-drag and climb with the scroll wheel. \
+                     Hold Shift to move faster.",
+                )
+                .size(theme::FONT_SMALL)
+                .color(theme::TEXT_MUTED),
+            );
+        });
+
+    if edit != *photo {
+        *photo = edit;
+    }
+}
@@ -21,22 +21,19 @@ use simulation::SaveLoadState;
 pub(crate) fn register_ui_systems(app: &mut App) {
     let idle = in_state(SaveLoadState::Idle);
     let playing = in_state(AppState::Playing);
-    let replay_viewer_mode = app
-        .world()
-        .contains_resource::<simulation::replay::ReplayViewerMode>();
 
     // Core egui
     app.add_plugins(EguiPlugin);
 
-    // Replay viewer mode gets a minimal watch-only UI surface.
-    if replay_viewer_mode {
-        app.add_plugins(theme::ThemePlugin);
-        app.add_plugins(replay_viewer::ReplayViewerUiPlugin);
-        return;
-    }
-
     // UI feature plugins
     app.add_plugins(theme::ThemePlugin);
+    // Replay viewer controls; shown only while in replay viewer mode, which
+    // can end at runtime when the player takes over the replay.
+    app.add_plugins(replay_viewer::ReplayViewerUiPlugin);
+    app.add_plugins(ui_scale::UiScaleUiPlugin);
+    app.add_plugins(accessibility::AccessibilityUiPlugin);
+    app.add_plugins(watchlist::WatchlistUiPlugin);
+    app.add_plugins(production_flow::ProductionFlowPlugin);
     app.add_plugins(cell_info_panel::CellInfoPanelPlugin);
     app.add_plugins(cell_tooltip::CellTooltipPlugin);
     app.add_plugins(citizen_info::CitizenInfoPlugin);
@@ -54,6 +51,24 @@ pub(crate) fn register_ui_systems(app: &mut App) {
     app.add_plugins(aqi_tooltip::AqiTooltipPlugin);
     app.add_plugins(auto_grid_ui::AutoGridUiPlugin);
     app.add_plugins(keybindings_panel::KeybindingsPanelPlugin);
+    app.add_plugins(event_editor::EventEditorPlugin);
+    app.add_plugins(mod_manager_panel::ModManagerPanelPlugin);
+    app.add_plugins(council_panel::CouncilPanelPlugin);
+    app.add_plugins(coverage_suggester::CoverageSuggesterPlugin);
+    app.add_plugins(land_parcel_panel::LandParcelPanelPlugin);
+    app.add_plugins(regional_water_panel::RegionalWaterPanelPlugin);
+    app.add_plugins(heat_health_panel::HeatHealthPanelPlugin);
+    app.add_plugins(justice_panel::JusticePanelPlugin);
+    app.add_plugins(unrest_panel::UnrestPanelPlugin);
+    app.add_plugins(disaster_report_panel::DisasterReportPanelPlugin);
+    app.add_plugins(carbon_dashboard::CarbonDashboardPlugin);
+    app.add_plugins(coast_dashboard::CoastDashboardPlugin);
+    app.add_plugins(forestry_dashboard::ForestryDashboardPlugin);
+    app.add_plugins(map_tiles_dashboard::MapTilesDashboardPlugin);
+    app.add_plugins(airport_dashboard::AirportDashboardPlugin);
+    app.add_plugins(scenario_panel::ScenarioPanelPlugin);
+    app.add_plugins(scenario_hud::ScenarioHudPlugin);
+    app.add_plugins(unlock_tree_panel::UnlockTreePanelPlugin);
     app.add_plugins(search::SearchPlugin);
     app.add_plugins(overlay_legend::OverlayLegendPlugin);
     app.add_plugins(dual_overlay::DualOverlayPlugin);
@@ -74,6 +89,11 @@ pub(crate) fn register_ui_systems(app: &mut App) {
     app.add_plugins(crash_recovery_ui::CrashRecoveryUiPlugin);
     app.add_plugins(dashboard_toggles::DashboardTogglesPlugin);
     app.add_plugins(confirm_dialog::ConfirmDialogPlugin);
+    app.add_plugins(photo_mode_panel::PhotoModePanelPlugin);
+    app.add_plugins(citizen_feed::CitizenFeedPlugin);
+    app.add_plugins(blueprint_panel::BlueprintPanelPlugin);
+    app.add_plugins(district_manager::DistrictManagerPlugin);
+    app.add_plugins(encyclopedia::EncyclopediaPlugin);
     // UI resources
     app.init_resource::<day_night_panel::DayNightPanelVisible>();
     app.init_resource::<milestones::Milestones>();
@@ -116,6 +136,7 @@ pub(crate) fn register_ui_systems(app: &mut App) {
             info_panel::groundwater_tooltip_ui,
             water_dashboard::water_dashboard_ui,
             tutorial::tutorial_ui,
+            tutorial::tutorial_signals,
             day_night_panel::day_night_panel_ui,
             road_cost_display::road_cost_display_ui,
         )
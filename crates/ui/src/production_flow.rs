@@ -0,0 +1,343 @@
+//! Production chain flow diagram.
+//!
+//! Draws the goods flows recorded by `simulation::production::GoodsFlows`
+//! as a node graph: imports on the left, then extraction, processing and
+//! manufacturing industries, and commerce and exports on the right. Edge
+//! thickness follows throughput and edge color the goods type. Industries
+//! whose buildings are short of inputs are outlined in red and explained
+//! below the graph, so a starved factory can be traced back to the input it
+//! is missing. Opens from the Production Chains section of the info panel.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::production::{FlowNode, GoodsFlows, GoodsType, IndustryFlow, IndustryType};
+
+use crate::theme;
+
+const COLUMNS: usize = 5;
+const DIAGRAM_WIDTH: f32 = 680.0;
+const NODE_WIDTH: f32 = 112.0;
+const NODE_HEIGHT: f32 = 38.0;
+const NODE_GAP: f32 = 14.0;
+const MAX_EDGE_WIDTH: f32 = 8.0;
+
+/// Whether the production flow window is visible.
+#[derive(Resource, Default)]
+pub struct ProductionFlowVisible(pub bool);
+
+/// Edge color for a goods type.
+pub fn goods_color(goods: GoodsType) -> egui::Color32 {
+    match goods {
+        GoodsType::RawFood => egui::Color32::from_rgb(139, 195, 74),
+        GoodsType::ProcessedFood => egui::Color32::from_rgb(255, 183, 77),
+        GoodsType::Lumber => egui::Color32::from_rgb(161, 136, 127),
+        GoodsType::Steel => egui::Color32::from_rgb(144, 164, 174),
+        GoodsType::Fuel => egui::Color32::from_rgb(255, 112, 67),
+        GoodsType::Electronics => egui::Color32::from_rgb(79, 195, 247),
+        GoodsType::ConsumerGoods => egui::Color32::from_rgb(186, 104, 200),
+    }
+}
+
+/// Amount flowing through a node per production cycle.
+fn node_throughput(flows: &GoodsFlows, node: FlowNode) -> f32 {
+    match node {
+        FlowNode::Imports => flows.imports.values().sum(),
+        FlowNode::Industry(industry) => flows
+            .industries
+            .get(&industry)
+            .map_or(0.0, IndustryFlow::throughput),
+        FlowNode::Commerce => flows.commerce.values().sum(),
+        FlowNode::Exports => flows.exports.values().sum(),
+    }
+}
+
+/// Why an industry is a bottleneck, in one sentence.
+pub fn bottleneck_text(industry: IndustryType, flows: &GoodsFlows) -> String {
+    let Some(flow) = flows.industries.get(&industry) else {
+        return String::new();
+    };
+    let starved = format!(
+        "{}: {} of {} buildings starved",
+        industry.name(),
+        flow.starved,
+        flow.buildings
+    );
+    match flow.scarcest_input() {
+        Some(goods) => {
+            let wanted = flow.wanted.get(&goods).copied().unwrap_or(0.0);
+            let got = flow.consumed.get(&goods).copied().unwrap_or(0.0);
+            let local = flows
+                .industries
+                .values()
+                .any(|f| f.produced.get(&goods).is_some_and(|&a| a > 0.0));
+            format!(
+                "{starved}, getting {:.0}% of the {} they need{}",
+                got / wanted * 100.0,
+                goods.name(),
+                if local { "" } else { " (no local producer)" }
+            )
+        }
+        None if industry.is_extraction() => format!("{starved}, deposits running low"),
+        None => starved,
+    }
+}
+
+/// Tooltip for a node: what goes in and out.
+fn node_tooltip(ui: &mut egui::Ui, flows: &GoodsFlows, node: FlowNode) {
+    ui.strong(node.name());
+    let list = |ui: &mut egui::Ui, title: &str, goods: Vec<(GoodsType, String)>| {
+        if goods.is_empty() {
+            return;
+        }
+        ui.label(title);
+        for (g, text) in goods {
+            ui.colored_label(goods_color(g), format!("  {} {text}", g.name()));
+        }
+    };
+    let amounts = |map: &std::collections::HashMap<GoodsType, f32>| {
+        GoodsType::all()
+            .iter()
+            .filter_map(|g| map.get(g).map(|a| (*g, format!("{a:.1}"))))
+            .collect::<Vec<_>>()
+    };
+    match node {
+        FlowNode::Imports => list(ui, "Imported:", amounts(&flows.imports)),
+        FlowNode::Commerce => list(ui, "Bought:", amounts(&flows.commerce)),
+        FlowNode::Exports => list(ui, "Exported:", amounts(&flows.exports)),
+        FlowNode::Industry(industry) => {
+            let Some(flow) = flows.industries.get(&industry) else {
+                return;
+            };
+            ui.label(format!(
+                "{} buildings, {:.0}% of inputs supplied",
+                flow.buildings,
+                flow.satisfaction() * 100.0
+            ));
+            let inputs = GoodsType::all()
+                .iter()
+                .filter_map(|g| {
+                    let wanted = flow.wanted.get(g)?;
+                    let got = flow.consumed.get(g).copied().unwrap_or(0.0);
+                    Some((*g, format!("{got:.1} of {wanted:.1}")))
+                })
+                .collect();
+            list(ui, "Inputs:", inputs);
+            list(ui, "Outputs:", amounts(&flow.produced));
+        }
+    }
+}
+
+/// Draw the flow graph into the current UI.
+pub fn draw_flow_diagram(ui: &mut egui::Ui, flows: &GoodsFlows) {
+    let nodes = flows.nodes();
+    if !nodes.iter().any(|n| matches!(n, FlowNode::Industry(_))) {
+        ui.label("No industry yet. Zone industrial land to start production chains.");
+        return;
+    }
+
+    let mut columns: [Vec<FlowNode>; COLUMNS] = Default::default();
+    for &node in &nodes {
+        columns[node.column()].push(node);
+    }
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(1) as f32;
+    let height = rows * NODE_HEIGHT + (rows + 1.0) * NODE_GAP;
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(DIAGRAM_WIDTH, height), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    let column_width = DIAGRAM_WIDTH / COLUMNS as f32;
+    let mut node_rects = Vec::with_capacity(nodes.len());
+    for (col, column) in columns.iter().enumerate() {
+        let used = column.len() as f32 * (NODE_HEIGHT + NODE_GAP) - NODE_GAP;
+        let top = rect.min.y + (height - used) / 2.0;
+        let x = rect.min.x + col as f32 * column_width + (column_width - NODE_WIDTH) / 2.0;
+        for (row, &node) in column.iter().enumerate() {
+            let y = top + row as f32 * (NODE_HEIGHT + NODE_GAP);
+            let node_rect =
+                egui::Rect::from_min_size(egui::pos2(x, y), egui::vec2(NODE_WIDTH, NODE_HEIGHT));
+            node_rects.push((node, node_rect));
+        }
+    }
+    let rect_of = |node: FlowNode| node_rects.iter().find(|(n, _)| *n == node).map(|(_, r)| *r);
+
+    // Edges first so nodes are drawn over their ends.
+    let edges = flows.edges();
+    let max_amount = edges.iter().map(|e| e.amount).fold(0.0, f32::max);
+    for edge in &edges {
+        let (Some(from), Some(to)) = (rect_of(edge.from), rect_of(edge.to)) else {
+            continue;
+        };
+        let start = from.right_center();
+        let end = to.left_center();
+        let bend = ((end.x - start.x) / 2.0).max(24.0);
+        let width = 1.0 + MAX_EDGE_WIDTH * (edge.amount / max_amount).sqrt();
+        let color = goods_color(edge.goods).gamma_multiply(0.75);
+        painter.add(egui::epaint::CubicBezierShape::from_points_stroke(
+            [
+                start,
+                start + egui::vec2(bend, 0.0),
+                end - egui::vec2(bend, 0.0),
+                end,
+            ],
+            false,
+            egui::Color32::TRANSPARENT,
+            egui::Stroke::new(width, color),
+        ));
+    }
+
+    let font = egui::FontId::proportional(theme::FONT_SMALL);
+    for &(node, node_rect) in &node_rects {
+        let bottleneck = match node {
+            FlowNode::Industry(industry) => flows
+                .industries
+                .get(&industry)
+                .is_some_and(IndustryFlow::is_bottleneck),
+            _ => false,
+        };
+        painter.rect_filled(node_rect, 4.0, theme::BG_SURFACE);
+        let stroke = if bottleneck {
+            egui::Stroke::new(2.0, theme::ERROR)
+        } else {
+            egui::Stroke::new(1.0, theme::BORDER)
+        };
+        painter.rect_stroke(node_rect, 4.0, stroke, egui::StrokeKind::Inside);
+        painter.text(
+            node_rect.center_top() + egui::vec2(0.0, 4.0),
+            egui::Align2::CENTER_TOP,
+            node.name(),
+            font.clone(),
+            theme::TEXT,
+        );
+        let detail = match node {
+            FlowNode::Industry(industry) if bottleneck => {
+                let flow = &flows.industries[&industry];
+                format!("starved {}/{}", flow.starved, flow.buildings)
+            }
+            _ => format!("{:.1}", node_throughput(flows, node)),
+        };
+        painter.text(
+            node_rect.center_bottom() - egui::vec2(0.0, 4.0),
+            egui::Align2::CENTER_BOTTOM,
+            detail,
+            font.clone(),
+            if bottleneck {
+                theme::ERROR
+            } else {
+                theme::TEXT_MUTED
+            },
+        );
+        ui.interact(
+            node_rect,
+            ui.id().with(("production_flow_node", node)),
+            egui::Sense::hover(),
+        )
+        .on_hover_ui(|ui| node_tooltip(ui, flows, node));
+    }
+}
+
+/// Renders the production flow window.
+pub fn production_flow_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<ProductionFlowVisible>,
+    flows: Res<GoodsFlows>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Production Flow")
+        .open(&mut open)
+        .default_width(DIAGRAM_WIDTH + 16.0)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.colored_label(
+                theme::TEXT_MUTED,
+                "Goods per production cycle. Hover a node for details.",
+            );
+            draw_flow_diagram(ui, &flows);
+
+            ui.separator();
+            ui.horizontal_wrapped(|ui| {
+                for &goods in GoodsType::all() {
+                    let text = if flows.is_short(goods) {
+                        format!("{} (short)", goods.name())
+                    } else {
+                        goods.name().to_string()
+                    };
+                    ui.colored_label(goods_color(goods), text);
+                }
+            });
+
+            let bottlenecks = flows.bottlenecks();
+            ui.separator();
+            if bottlenecks.is_empty() {
+                ui.colored_label(
+                    theme::SUCCESS,
+                    "No bottlenecks: every industry is supplied.",
+                );
+            } else {
+                ui.strong("Bottlenecks");
+                for industry in bottlenecks {
+                    ui.colored_label(theme::ERROR, bottleneck_text(industry, &flows));
+                }
+            }
+        });
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct ProductionFlowPlugin;
+
+impl Plugin for ProductionFlowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProductionFlowVisible>().add_systems(
+            Update,
+            production_flow_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goods_colors_are_distinct() {
+        let all = GoodsType::all();
+        for (i, a) in all.iter().enumerate() {
+            for b in &all[i + 1..] {
+                assert_ne!(goods_color(*a), goods_color(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bottleneck_text_names_missing_input() {
+        let mut flows = GoodsFlows::default();
+        flows.record_building(IndustryType::TechAssembly, Some(0.25));
+        flows.record_input(IndustryType::TechAssembly, GoodsType::Steel, 4.0, 1.0);
+        flows.record_input(IndustryType::TechAssembly, GoodsType::Fuel, 1.0, 1.0);
+        assert_eq!(
+            bottleneck_text(IndustryType::TechAssembly, &flows),
+            "Tech Assembly: 1 of 1 buildings starved, getting 25% of the Steel they need \
+             (no local producer)"
+        );
+
+        flows.record_building(IndustryType::Mining, Some(1.0));
+        flows.record_output(IndustryType::Mining, GoodsType::Steel, 1.0);
+        assert!(!bottleneck_text(IndustryType::TechAssembly, &flows).contains("no local"));
+    }
+
+    #[test]
+    fn test_bottleneck_text_for_depleted_extraction() {
+        let mut flows = GoodsFlows::default();
+        flows.record_building(IndustryType::Mining, Some(0.2));
+        assert_eq!(
+            bottleneck_text(IndustryType::Mining, &flows),
+            "Mining: 1 of 1 buildings starved, deposits running low"
+        );
+    }
+}
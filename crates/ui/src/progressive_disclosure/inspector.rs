@@ -81,9 +81,9 @@ pub fn progressive_building_inspection_ui(
     if let Ok(building) = buildings.get(entity) {
         let cell = grid.get(building.grid_x, building.grid_y);
         let idx = building.grid_y * GRID_WIDTH + building.grid_x;
-        let poll_level = pollution.levels.get(idx).copied().unwrap_or(0);
+        let poll_level = pollution.get(building.grid_x, building.grid_y);
         let noise_level = noise.levels.get(idx).copied().unwrap_or(0);
-        let lv = land_value.values.get(idx).copied().unwrap_or(0);
+        let lv = land_value.get(building.grid_x, building.grid_y);
         let occupancy_pct = if building.capacity > 0 {
             (building.occupants as f32 / building.capacity as f32 * 100.0).min(100.0)
         } else {
@@ -188,8 +188,7 @@ pub fn progressive_building_inspection_ui(
     // === Service building inspection (simpler, no tabs needed) ===
     if let Ok(service) = service_buildings.get(entity) {
         let cell = grid.get(service.grid_x, service.grid_y);
-        let idx = service.grid_y * GRID_WIDTH + service.grid_x;
-        let lv = land_value.values.get(idx).copied().unwrap_or(0);
+        let lv = land_value.get(service.grid_x, service.grid_y);
         let monthly_cost =
             simulation::services::ServiceBuilding::monthly_maintenance(service.service_type);
 
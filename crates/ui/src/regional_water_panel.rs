@@ -0,0 +1,209 @@
+//! Regional water window.
+//!
+//! Lists the neighbouring regions and the city's pipelines to them, lets the
+//! player lay pipelines and sign or cancel water agreements, and shows how
+//! much each agreement is delivering under the current drought. Opened from
+//! the Water Supply Dashboard.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::outside_connections::OutsideConnections;
+use simulation::water_rights::{
+    agreement_price, has_right_of_way, region, CurtailmentClause, MapEdge, TradeDirection,
+    WaterRights, WaterRightsAction, MIN_AGREEMENT_MGD, PIPELINE_COST, REGIONS,
+};
+
+/// Whether the regional water window is visible.
+#[derive(Resource, Default)]
+pub struct RegionalWaterPanelVisible(pub bool);
+
+/// Choices in the new-agreement form, kept between openings.
+#[derive(Resource)]
+pub struct AgreementForm {
+    pub region: MapEdge,
+    pub direction: TradeDirection,
+    pub volume_mgd: f32,
+    pub clause: CurtailmentClause,
+}
+
+impl Default for AgreementForm {
+    fn default() -> Self {
+        Self {
+            region: MapEdge::North,
+            direction: TradeDirection::Buy,
+            volume_mgd: 1.0,
+            clause: CurtailmentClause::Standard,
+        }
+    }
+}
+
+fn direction_name(direction: TradeDirection) -> &'static str {
+    match direction {
+        TradeDirection::Buy => "Buy",
+        TradeDirection::Sell => "Sell",
+    }
+}
+
+/// Renders the regional water window.
+pub fn regional_water_panel_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<RegionalWaterPanelVisible>,
+    mut form: ResMut<AgreementForm>,
+    rights: Res<WaterRights>,
+    connections: Res<OutsideConnections>,
+    mut actions: EventWriter<WaterRightsAction>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("Regional Water")
+        .open(&mut open)
+        .default_width(400.0)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.heading("Neighbours");
+            egui::Grid::new("regional_water_regions")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for neighbor in &REGIONS {
+                        let edge = neighbor.edge;
+                        ui.label(format!("{} ({})", neighbor.name, edge.name()));
+                        ui.label(format!(
+                            "Sells {:.1} / buys {:.1} MGD",
+                            rights.available_with(edge, TradeDirection::Buy),
+                            rights.available_with(edge, TradeDirection::Sell)
+                        ));
+                        ui.label(format!("${:.0}/MGD", neighbor.price_per_mgd));
+                        if rights.has_pipeline(edge) {
+                            ui.label("Pipeline");
+                        } else if has_right_of_way(&connections.connections, edge) {
+                            if ui
+                                .button(format!("Build pipeline (${PIPELINE_COST:.0})"))
+                                .clicked()
+                            {
+                                actions.send(WaterRightsAction::BuildPipeline(edge));
+                            }
+                        } else {
+                            ui.label("No right-of-way").on_hover_text(
+                                "Connect a highway or railway to this edge of the map",
+                            );
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.heading("New agreement");
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("regional_water_region")
+                    .selected_text(region(form.region).name)
+                    .show_ui(ui, |ui| {
+                        for neighbor in &REGIONS {
+                            ui.selectable_value(&mut form.region, neighbor.edge, neighbor.name);
+                        }
+                    });
+                for direction in [TradeDirection::Buy, TradeDirection::Sell] {
+                    ui.radio_value(&mut form.direction, direction, direction_name(direction));
+                }
+                egui::ComboBox::from_id_salt("regional_water_clause")
+                    .selected_text(form.clause.name())
+                    .show_ui(ui, |ui| {
+                        for clause in CurtailmentClause::ALL {
+                            ui.selectable_value(&mut form.clause, clause, clause.name());
+                        }
+                    });
+            });
+            let available = rights.available_with(form.region, form.direction);
+            let reachable = rights.is_reachable(form.region);
+            let max = available.max(MIN_AGREEMENT_MGD);
+            form.volume_mgd = form.volume_mgd.clamp(MIN_AGREEMENT_MGD, max);
+            ui.add(egui::Slider::new(&mut form.volume_mgd, MIN_AGREEMENT_MGD..=max).text("MGD"));
+            let price = agreement_price(region(form.region), form.direction, form.clause);
+            ui.label(format!(
+                "${price:.0} per MGD a month (${:.0} a month)",
+                price * form.volume_mgd as f64
+            ));
+            let can_sign = reachable && available >= MIN_AGREEMENT_MGD;
+            let sign = ui
+                .add_enabled(can_sign, egui::Button::new("Sign"))
+                .on_disabled_hover_text(if reachable {
+                    "This region has no more water to trade"
+                } else {
+                    "No pipeline route reaches this region"
+                });
+            if sign.clicked() {
+                actions.send(WaterRightsAction::Sign {
+                    region: form.region,
+                    direction: form.direction,
+                    volume_mgd: form.volume_mgd,
+                    clause: form.clause,
+                });
+            }
+
+            ui.separator();
+            ui.heading("Agreements");
+            if rights.agreements.is_empty() {
+                ui.label("No water agreements signed.");
+            }
+            egui::Grid::new("regional_water_agreements")
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (i, agreement) in rights.agreements.iter().enumerate() {
+                        ui.label(format!(
+                            "{} {}",
+                            direction_name(agreement.direction),
+                            region(agreement.region).name
+                        ));
+                        let delivered = format!(
+                            "{:.1} of {:.1} MGD",
+                            agreement.delivered_mgd, agreement.volume_mgd
+                        );
+                        if agreement.curtailed {
+                            ui.colored_label(egui::Color32::from_rgb(220, 160, 40), delivered)
+                                .on_hover_text("Curtailed by the drought clause");
+                        } else {
+                            ui.label(delivered);
+                        }
+                        ui.label(agreement.clause.name());
+                        ui.label(format!("${:+.0}/mo", agreement.monthly_net()));
+                        if ui.small_button("Cancel").clicked() {
+                            actions.send(WaterRightsAction::Cancel(i));
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.label(format!(
+                "Importing {:.1} MGD, exporting {:.1} MGD",
+                rights.imported_mgd, rights.exported_mgd
+            ));
+            ui.label(format!("Net: ${:+.0} a month", rights.monthly_net()));
+            ui.label(format!(
+                "Paid ${:.0}, earned ${:.0} to date",
+                rights.total_paid, rights.total_earned
+            ));
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct RegionalWaterPanelPlugin;
+
+impl Plugin for RegionalWaterPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RegionalWaterPanelVisible>()
+            .init_resource::<AgreementForm>()
+            .add_systems(
+                Update,
+                regional_water_panel_ui.run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -1,10 +1,16 @@
-//! Minimal watch-only UI for replay viewer mode.
+//! Playback controls for replay viewer mode: play/pause and speed, a
+//! timeline to scrub to any tick, and a button to take over the replay and
+//! continue as normal play from the current tick. Embedded state checksums
+//! are checked as playback passes them.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use simulation::app_state::AppState;
-use simulation::replay::ReplayViewerInfo;
+use simulation::replay::{
+    ReplayCheckpoints, ReplaySeek, ReplayTakeOverEvent, ReplayVerification, ReplayViewerInfo,
+    ReplayViewerMode,
+};
 use simulation::time_of_day::GameClock;
 use simulation::TickCounter;
 
@@ -14,16 +20,24 @@ impl Plugin for ReplayViewerUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            replay_viewer_ui.run_if(in_state(AppState::Playing)),
+            replay_viewer_ui
+                .run_if(in_state(AppState::Playing))
+                .run_if(resource_exists::<ReplayViewerMode>),
         );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn replay_viewer_ui(
     mut contexts: EguiContexts,
     mut clock: ResMut<GameClock>,
     tick: Res<TickCounter>,
     info: Option<Res<ReplayViewerInfo>>,
+    mut seek: ResMut<ReplaySeek>,
+    checkpoints: Res<ReplayCheckpoints>,
+    verification: Res<ReplayVerification>,
+    mut take_over: EventWriter<ReplayTakeOverEvent>,
+    mut scrubbing: Local<Option<u64>>,
 ) {
     let ctx = contexts.ctx_mut();
     egui::TopBottomPanel::top("replay_viewer_controls").show(ctx, |ui| {
@@ -64,19 +78,57 @@ fn replay_viewer_ui(
             });
 
             if let Some(info) = info {
-                let denom = info.end_tick.max(1);
-                let progress = (tick.0 as f64 / denom as f64).clamp(0.0, 1.0) as f32;
-                ui.add(
-                    egui::ProgressBar::new(progress)
-                        .desired_width(180.0)
-                        .show_percentage(),
+                // Dragging the timeline scrubs; the seek starts on release.
+                let mut scrub = scrubbing.or(seek.target()).unwrap_or(tick.0);
+                let timeline = ui.add(
+                    egui::Slider::new(&mut scrub, info.start_tick..=info.end_tick.max(1))
+                        .show_value(false)
+                        .text("Timeline"),
                 );
+                if timeline.dragged() {
+                    ui.label(format!("Go to tick {scrub}"));
+                    *scrubbing = Some(scrub);
+                } else if timeline.drag_stopped() || timeline.changed() {
+                    *scrubbing = None;
+                    seek.request(scrub);
+                }
                 ui.label(format!("End tick: {}", info.end_tick));
                 ui.label(format!("Entries: {}", info.entry_count));
                 ui.label(format!("Replay: {}", info.source));
             }
+
+            ui.separator();
+            if let Some(target) = seek.target() {
+                ui.label(format!("Seeking to tick {target}..."));
+            } else if ui
+                .button("Take Over")
+                .on_hover_text("Stop the replay here and keep playing from this tick")
+                .clicked()
+            {
+                take_over.send(ReplayTakeOverEvent);
+            }
         });
 
+        if let Some(error) = &seek.last_error {
+            ui.colored_label(egui::Color32::from_rgb(220, 120, 80), error);
+        }
+        ui.small(format!(
+            "Checkpoints: {} (every {} ticks); seeking back re-simulates from the nearest one.",
+            checkpoints.checkpoints().len(),
+            checkpoints.interval()
+        ));
+        if let Some(tick) = verification.first_mismatch {
+            ui.colored_label(
+                egui::Color32::from_rgb(220, 120, 80),
+                format!(
+                    "Diverged from the recording by tick {tick} ({} of {} checksums differ)",
+                    verification.mismatches, verification.checked
+                ),
+            );
+        } else if verification.checked > 0 {
+            ui.small(format!("Checksums verified: {}", verification.checked));
+        }
+
         ui.small("Camera controls: WASD/Arrow pan, drag to pan/orbit, wheel zoom.");
     });
 }
@@ -6,7 +6,8 @@
 
 use simulation::save_slots::SaveSlotInfo;
 
-/// Format slot details for display (population, day, play time, timestamp).
+/// Format slot details for display (population, treasury, day, play time,
+/// timestamp).
 pub fn format_slot_details(slot: &SaveSlotInfo) -> String {
     let play_hours = (slot.play_time_seconds / 3600.0) as u32;
     let play_mins = ((slot.play_time_seconds % 3600.0) / 60.0) as u32;
@@ -14,8 +15,9 @@ pub fn format_slot_details(slot: &SaveSlotInfo) -> String {
     let time_str = format_timestamp(slot.timestamp);
 
     format!(
-        "Pop: {} | Day {} | {}h{}m played | {}",
+        "Pop: {} | {} | Day {} | {}h{}m played | {}",
         format_population(slot.population),
+        format_treasury(slot.treasury),
         slot.day,
         play_hours,
         play_mins,
@@ -34,6 +36,15 @@ pub fn format_population(pop: u32) -> String {
     }
 }
 
+/// Format a treasury balance compactly, e.g. `$12.5K` or `-$800`.
+pub fn format_treasury(treasury: f64) -> String {
+    let sign = if treasury < 0.0 { "-" } else { "" };
+    format!(
+        "{sign}${}",
+        format_population(treasury.abs().round() as u32)
+    )
+}
+
 /// Format a Unix timestamp into a human-readable date/time string.
 pub fn format_timestamp(timestamp: u64) -> String {
     if timestamp == 0 {
@@ -92,6 +103,12 @@ mod tests {
         assert_eq!(format_population(1_000_000), "1.0M");
     }
 
+    #[test]
+    fn test_format_treasury() {
+        assert_eq!(format_treasury(12_500.0), "$12.5K");
+        assert_eq!(format_treasury(-800.4), "-$800");
+    }
+
     #[test]
     fn test_format_timestamp_zero() {
         assert_eq!(format_timestamp(0), "Unknown");
@@ -0,0 +1,68 @@
+//! Thumbnails in the save slot browser.
+//!
+//! Slot thumbnails are stored as run-length-encoded timelapse frames; this
+//! module keeps an egui texture per slot, rebuilt only when the slot's frame
+//! changes.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use simulation::save_slot_thumbnails::SaveSlotThumbnails;
+use simulation::timelapse::{TimelapseFrame, FRAME_HEIGHT, FRAME_WIDTH};
+
+/// Side of a thumbnail in a slot row, in points.
+pub const THUMBNAIL_SIZE: f32 = 48.0;
+
+/// Textures of the slot thumbnails shown so far, with the frame each was
+/// built from.
+#[derive(Resource, Default)]
+pub struct SaveSlotThumbnailCache {
+    textures: HashMap<u32, (TimelapseFrame, egui::TextureHandle)>,
+}
+
+impl SaveSlotThumbnailCache {
+    /// Texture of slot `slot_index`'s thumbnail, `None` for slots saved
+    /// before thumbnails existed.
+    pub fn texture(
+        &mut self,
+        ctx: &egui::Context,
+        thumbnails: &SaveSlotThumbnails,
+        slot_index: u32,
+    ) -> Option<egui::TextureId> {
+        let Some(frame) = thumbnails.get(slot_index) else {
+            self.textures.remove(&slot_index);
+            return None;
+        };
+        let stale = !matches!(self.textures.get(&slot_index), Some((f, _)) if f == frame);
+        if stale {
+            let image =
+                egui::ColorImage::from_rgb([FRAME_WIDTH, FRAME_HEIGHT], &frame.decode_rgb());
+            let handle = ctx.load_texture(
+                format!("save_slot_thumbnail_{slot_index}"),
+                image,
+                egui::TextureOptions::NEAREST,
+            );
+            self.textures.insert(slot_index, (frame.clone(), handle));
+        }
+        self.textures
+            .get(&slot_index)
+            .map(|(_, handle)| handle.id())
+    }
+}
+
+/// Draw a slot thumbnail, or an empty placeholder of the same size.
+pub fn show_thumbnail(ui: &mut egui::Ui, texture: Option<egui::TextureId>) {
+    let size = egui::vec2(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    match texture {
+        Some(id) => {
+            ui.image((id, size));
+        }
+        None => {
+            let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+            ui.painter()
+                .rect_filled(rect, egui::CornerRadius::same(2), crate::theme::BG_DARK);
+        }
+    }
+}
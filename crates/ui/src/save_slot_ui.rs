@@ -2,7 +2,7 @@
 //!
 //! Provides egui windows for:
 //! - **Save dialog**: Choose an existing slot to overwrite or create a new save
-//! - **Load dialog**: Browse available saves with metadata and load one
+//! - **Load dialog**: Browse available saves with metadata and thumbnails and load one
 //!
 //! Integrates with the backend `SaveSlotManager` from simulation and the
 //! `SaveGameEvent`/`LoadGameEvent` from the save crate.
@@ -12,13 +12,15 @@ use bevy_egui::{egui, EguiContexts};
 
 use save::{LoadGameEvent, PendingSavePath, SaveGameEvent};
 use simulation::app_state::AppState;
-use simulation::notifications::{NotificationEvent, NotificationPriority};
+use simulation::notifications::{NotificationCategory, NotificationEvent, NotificationPriority};
 use simulation::PreLoadAppState;
+use simulation::save_slot_thumbnails::SaveSlotThumbnails;
 use simulation::save_slots::{
     DeleteSlotEvent, SaveSlotInfo, SaveSlotManager, SaveToSlotEvent, MAX_SAVE_SLOTS,
 };
 
 pub use crate::save_slot_format::{format_population, format_slot_details, format_timestamp};
+use crate::save_slot_thumbnail::{show_thumbnail, SaveSlotThumbnailCache};
 
 // =============================================================================
 // Resources
@@ -55,7 +57,7 @@ pub struct SaveSlotUiPlugin;
 
 impl Plugin for SaveSlotUiPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<SaveSlotUiState>();
+        app.init_resource::<SaveSlotUiState>().init_resource::<SaveSlotThumbnailCache>();
         app.add_systems(
             Update,
             (save_slot_dialog_system, load_slot_dialog_system)
@@ -135,6 +137,8 @@ fn load_slot_dialog_system(
     mut next_app_state: ResMut<NextState<AppState>>,
     mut pre_load: ResMut<PreLoadAppState>,
     current_app_state: Res<State<AppState>>,
+    thumbnails: Res<SaveSlotThumbnails>,
+    mut thumbnail_cache: ResMut<SaveSlotThumbnailCache>,
 ) {
     if !ui_state.load_dialog_open {
         return;
@@ -175,8 +179,10 @@ fn load_slot_dialog_system(
                         .max_height(360.0)
                         .show(ui, |ui| {
                             for slot in &slots {
+                                let thumbnail =
+                                    thumbnail_cache.texture(ui.ctx(), &thumbnails, slot.slot_index);
                                 render_load_slot_row(
-                                    ui, slot, &mut ui_state,
+                                    ui, slot, thumbnail, &mut ui_state,
                                     &mut load_game_events, &mut pending_path,
                                     &mut delete_events, &mut next_app_state,
                                     &mut should_close, &mut pre_load,
@@ -247,6 +253,7 @@ fn render_new_save_section(
                 notifications.send(NotificationEvent {
                     text: format!("Saved to slot {}: {}", idx + 1, name),
                     priority: NotificationPriority::Info,
+                    category: NotificationCategory::General,
                     location: None,
                 });
                 *should_close = true;
@@ -365,6 +372,7 @@ fn render_save_slot_row(
                             notifications.send(NotificationEvent {
                                 text: format!("Overwrote slot {}: {}", slot.slot_index + 1, name),
                                 priority: NotificationPriority::Info,
+                                category: NotificationCategory::General,
                                 location: None,
                             });
                             *should_close = true;
@@ -385,6 +393,7 @@ fn render_save_slot_row(
 fn render_load_slot_row(
     ui: &mut egui::Ui,
     slot: &SaveSlotInfo,
+    thumbnail: Option<egui::TextureId>,
     ui_state: &mut ResMut<SaveSlotUiState>,
     load_game_events: &mut EventWriter<LoadGameEvent>,
     pending_path: &mut ResMut<PendingSavePath>,
@@ -403,8 +412,9 @@ fn render_load_slot_row(
         .show(ui, |ui| {
             ui.set_min_height(SLOT_ROW_HEIGHT);
             ui.horizontal(|ui| {
+                show_thumbnail(ui, thumbnail);
                 ui.vertical(|ui| {
-                    ui.set_min_width(DIALOG_WIDTH - 200.0);
+                    ui.set_min_width(DIALOG_WIDTH - 260.0);
                     ui.label(
                         egui::RichText::new(&slot.display_name)
                             .size(crate::theme::FONT_BODY)
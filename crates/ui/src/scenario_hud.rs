@@ -0,0 +1,143 @@
+//! Scenario objective tracker HUD.
+//!
+//! A compact, collapsible widget shown while a scenario is being played. It
+//! lists the objectives still pending with a progress bar, the days left
+//! before each deadline and the reward for completing it. Completions and
+//! failures are announced as toasts by `simulation::scenario::update_scenario`;
+//! the full objectives window opens from the Details button.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::scenario::{ObjectiveStatus, ScenarioOutcome, ScenarioState};
+use simulation::time_of_day::GameClock;
+
+use crate::scenario_panel::ScenarioPanelVisible;
+use crate::theme;
+
+/// Deadlines this close are drawn as warnings.
+const DEADLINE_WARNING_DAYS: u32 = 30;
+/// Deadlines this close are drawn as urgent.
+const DEADLINE_URGENT_DAYS: u32 = 7;
+
+const HUD_WIDTH: f32 = 260.0;
+
+/// Color for a deadline `days` away.
+fn deadline_color(days: u32) -> egui::Color32 {
+    if days <= DEADLINE_URGENT_DAYS {
+        theme::ERROR
+    } else if days <= DEADLINE_WARNING_DAYS {
+        theme::WARNING
+    } else {
+        theme::TEXT_MUTED
+    }
+}
+
+/// Renders the objective tracker.
+pub fn scenario_hud_ui(
+    mut contexts: EguiContexts,
+    mut details: ResMut<ScenarioPanelVisible>,
+    state: Res<ScenarioState>,
+    clock: Res<GameClock>,
+) {
+    let Some(definition) = state.definition.as_ref() else {
+        return;
+    };
+
+    let statuses = || {
+        definition.objectives.iter().enumerate().map(|(i, o)| {
+            let status = state
+                .objectives
+                .get(i)
+                .copied()
+                .unwrap_or(ObjectiveStatus::Pending);
+            (i, o, status)
+        })
+    };
+    let complete = statuses()
+        .filter(|(_, _, s)| matches!(s, ObjectiveStatus::Complete { .. }))
+        .count();
+
+    egui::Window::new("Objectives")
+        .id(egui::Id::new("scenario_hud"))
+        .collapsible(true)
+        .resizable(false)
+        .default_width(HUD_WIDTH)
+        .anchor(egui::Align2::RIGHT_TOP, [-10.0, 60.0])
+        .show(contexts.ctx_mut(), |ui| {
+            ui.set_max_width(HUD_WIDTH);
+            ui.horizontal(|ui| {
+                ui.strong(&definition.name);
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("Details").clicked() {
+                        details.0 = !details.0;
+                    }
+                });
+            });
+            match &state.outcome {
+                ScenarioOutcome::InProgress => {
+                    ui.colored_label(
+                        theme::TEXT_MUTED,
+                        format!(
+                            "Day {} - {complete} of {} complete",
+                            state.days_elapsed(clock.day) + 1,
+                            definition.objectives.len()
+                        ),
+                    );
+                }
+                ScenarioOutcome::Won { .. } => {
+                    ui.colored_label(theme::SUCCESS, "Scenario won");
+                }
+                ScenarioOutcome::Lost { reason, .. } => {
+                    ui.colored_label(theme::ERROR, format!("Scenario lost: {reason}"));
+                }
+            }
+
+            for (i, objective, status) in statuses() {
+                if status != ObjectiveStatus::Pending {
+                    continue;
+                }
+                ui.separator();
+                if objective.required {
+                    ui.label(&objective.description);
+                } else {
+                    ui.label(format!("{} (optional)", objective.description));
+                }
+                ui.add(
+                    egui::ProgressBar::new(objective.condition.progress(&state.metrics))
+                        .desired_width(HUD_WIDTH)
+                        .text(objective.condition.status(&state.metrics)),
+                );
+                ui.horizontal(|ui| {
+                    if let Some(days) = state.days_left(i, clock.day) {
+                        ui.colored_label(deadline_color(days), format!("{days} days left"));
+                    }
+                    if let Some(reward) = &objective.reward {
+                        ui.colored_label(theme::SUCCESS, format!("Reward: {}", reward.describe()));
+                    }
+                });
+            }
+        });
+}
+
+pub struct ScenarioHudPlugin;
+
+impl Plugin for ScenarioHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, scenario_hud_ui.run_if(in_state(AppState::Playing)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadline_color_escalates() {
+        assert_eq!(deadline_color(100), theme::TEXT_MUTED);
+        assert_eq!(deadline_color(DEADLINE_WARNING_DAYS), theme::WARNING);
+        assert_eq!(deadline_color(DEADLINE_URGENT_DAYS), theme::ERROR);
+        assert_eq!(deadline_color(0), theme::ERROR);
+    }
+}
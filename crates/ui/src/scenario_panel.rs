@@ -0,0 +1,123 @@
+//! Scenario objectives window.
+//!
+//! Opened from the objective tracker HUD while playing a scenario: lists each
+//! objective with its progress, deadline, reward and status, the conditions
+//! that lose the scenario, and the outcome once it is decided.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::scenario::{ObjectiveStatus, ScenarioOutcome, ScenarioState};
+use simulation::time_of_day::GameClock;
+
+/// Whether the objectives window is visible. Only drawn when a scenario is
+/// being played.
+#[derive(Resource, Default)]
+pub struct ScenarioPanelVisible(pub bool);
+
+const COMPLETE: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const FAILED: egui::Color32 = egui::Color32::from_rgb(220, 80, 80);
+
+/// Renders the scenario objectives window.
+pub fn scenario_panel_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<ScenarioPanelVisible>,
+    state: Res<ScenarioState>,
+    clock: Res<GameClock>,
+) {
+    if !visible.0 {
+        return;
+    }
+    let Some(definition) = state.definition.as_ref() else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new(format!("Scenario: {}", definition.name))
+        .open(&mut open)
+        .default_width(340.0)
+        .default_pos(egui::pos2(300.0, 80.0))
+        .show(contexts.ctx_mut(), |ui| {
+            match &state.outcome {
+                ScenarioOutcome::InProgress => {
+                    ui.label(format!("Day {}", state.days_elapsed(clock.day) + 1));
+                }
+                ScenarioOutcome::Won { day } => {
+                    ui.colored_label(
+                        COMPLETE,
+                        format!("Won on day {}", day.saturating_sub(state.start_day) + 1),
+                    );
+                }
+                ScenarioOutcome::Lost { day, reason } => {
+                    ui.colored_label(
+                        FAILED,
+                        format!(
+                            "Lost on day {}: {reason}",
+                            day.saturating_sub(state.start_day) + 1
+                        ),
+                    );
+                }
+            }
+
+            ui.separator();
+            ui.heading("Objectives");
+            for (i, objective) in definition.objectives.iter().enumerate() {
+                let status = state
+                    .objectives
+                    .get(i)
+                    .copied()
+                    .unwrap_or(ObjectiveStatus::Pending);
+                let title = if objective.required {
+                    objective.description.clone()
+                } else {
+                    format!("{} (optional)", objective.description)
+                };
+                match status {
+                    ObjectiveStatus::Complete { .. } => {
+                        ui.colored_label(COMPLETE, format!("✔ {title}"));
+                    }
+                    ObjectiveStatus::Failed { .. } => {
+                        ui.colored_label(FAILED, format!("✘ {title}"));
+                    }
+                    ObjectiveStatus::Pending => {
+                        ui.label(title);
+                        ui.add(
+                            egui::ProgressBar::new(objective.condition.progress(&state.metrics))
+                                .text(objective.condition.status(&state.metrics)),
+                        );
+                        if let Some(days) = state.days_left(i, clock.day) {
+                            ui.small(format!("{days} days left"));
+                        }
+                    }
+                }
+                if let Some(reward) = &objective.reward {
+                    ui.small(format!("Reward: {}", reward.describe()));
+                }
+                ui.add_space(4.0);
+            }
+
+            if !definition.lose_conditions.is_empty() {
+                ui.separator();
+                ui.label("Lose if:");
+                for condition in &definition.lose_conditions {
+                    ui.label(format!("• {}", condition.describe()));
+                }
+            }
+        });
+
+    if !open {
+        visible.0 = false;
+    }
+}
+
+pub struct ScenarioPanelPlugin;
+
+impl Plugin for ScenarioPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScenarioPanelVisible>().add_systems(
+            Update,
+            scenario_panel_ui.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
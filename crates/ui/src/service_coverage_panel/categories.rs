@@ -124,6 +124,7 @@ impl ServiceCategory {
                 ServiceType::FireHouse,
                 ServiceType::FireStation,
                 ServiceType::FireHQ,
+                ServiceType::HazmatResponse,
             ],
             Self::Parks => &[
                 ServiceType::SmallPark,
@@ -137,6 +138,8 @@ impl ServiceCategory {
                 ServiceType::Museum,
                 ServiceType::Cathedral,
                 ServiceType::TVStation,
+                ServiceType::Hotel,
+                ServiceType::ConventionCenter,
             ],
             Self::Telecom => &[ServiceType::CellTower, ServiceType::DataCenter],
             Self::Transport => &[
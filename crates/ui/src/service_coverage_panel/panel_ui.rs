@@ -4,13 +4,16 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use rendering::overlay::{OverlayMode, OverlayState};
+use simulation::app_state::AppState;
 use simulation::config::{GRID_HEIGHT, GRID_WIDTH};
 use simulation::grid::{WorldGrid, ZoneType};
 use simulation::happiness::ServiceCoverageGrid;
+use simulation::keybindings::KeyBindings;
 use simulation::services::ServiceBuilding;
-use simulation::app_state::AppState;
 use simulation::SaveLoadState;
 
+use crate::coverage_suggester::CoverageSuggesterVisible;
+
 use super::categories::{OtherServiceGroup, ServiceCategory};
 use super::stats::{
     compute_category_stats, compute_service_type_stats, coverage_color, coverage_label,
@@ -40,16 +43,17 @@ pub struct ExpandedCategories {
 // Keybind system
 // =============================================================================
 
-/// Toggles service coverage panel with K key.
+/// Toggles service coverage panel with the configured key (K by default).
 pub fn service_coverage_keybind(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     mut visible: ResMut<ServiceCoveragePanelVisible>,
     mut contexts: EguiContexts,
 ) {
     if contexts.ctx_mut().wants_keyboard_input() {
         return;
     }
-    if keyboard.just_pressed(KeyCode::KeyK) {
+    if bindings.toggle_service_coverage.just_pressed(&keyboard) {
         visible.0 = !visible.0;
     }
 }
@@ -68,6 +72,7 @@ pub fn service_coverage_panel_ui(
     services: Query<&ServiceBuilding>,
     mut overlay: ResMut<OverlayState>,
     mut expanded: ResMut<ExpandedCategories>,
+    mut suggester: ResMut<CoverageSuggesterVisible>,
 ) {
     if !visible.0 {
         return;
@@ -80,6 +85,9 @@ pub fn service_coverage_panel_ui(
         .default_width(400.0)
         .show(contexts.ctx_mut(), |ui| {
             ui.small("Per-service coverage and utilization (K to toggle)");
+            if ui.button("Suggest placements...").clicked() {
+                suggester.0 = true;
+            }
             ui.separator();
 
             // Compute demand cells count (same for all categories)
@@ -2,11 +2,13 @@
 //!
 //! A full-screen settings menu accessible from both the main menu and
 //! the pause menu. Provides audio volume sliders (master, music, SFX, UI)
-//! and a mute toggle, with placeholder sections for graphics and controls.
+//! and a mute toggle, sun shadow quality under graphics, and a placeholder
+//! section for controls.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
+use rendering::sun_shadows::{ShadowQuality, ShadowSettings};
 use simulation::audio_settings::AudioSettings;
 
 use crate::theme;
@@ -66,6 +68,7 @@ fn settings_menu_ui(
     mut menu: ResMut<SettingsMenuOpen>,
     mut tab_state: ResMut<SettingsTabState>,
     mut audio: ResMut<AudioSettings>,
+    mut shadows: ResMut<ShadowSettings>,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -115,7 +118,7 @@ fn settings_menu_ui(
                 // Tab content
                 match tab_state.tab {
                     SettingsTab::Audio => render_audio_tab(ui, &mut audio),
-                    SettingsTab::Graphics => render_graphics_tab(ui),
+                    SettingsTab::Graphics => render_graphics_tab(ui, &mut shadows),
                     SettingsTab::Controls => render_controls_tab(ui),
                 }
 
@@ -272,24 +275,36 @@ fn render_audio_tab(ui: &mut egui::Ui, audio: &mut ResMut<AudioSettings>) {
     }
 }
 
-/// Renders the graphics settings tab (placeholder).
-fn render_graphics_tab(ui: &mut egui::Ui) {
-    ui.add_space(20.0);
+/// Renders the graphics settings tab with the sun shadow quality picker.
+fn render_graphics_tab(ui: &mut egui::Ui, shadows: &mut ResMut<ShadowSettings>) {
     ui.label(
-        egui::RichText::new("Graphics settings coming soon")
-            .size(theme::FONT_BODY)
-            .color(theme::TEXT_MUTED)
-            .italics(),
+        egui::RichText::new("Shadows")
+            .size(theme::FONT_SUBHEADING)
+            .color(theme::TEXT_HEADING),
     );
     ui.add_space(8.0);
+
+    let mut quality = shadows.quality;
+    ui.horizontal(|ui| {
+        for option in ShadowQuality::ALL {
+            ui.selectable_value(&mut quality, option, option.name());
+        }
+    });
+    // Only write on change so the cascades are not rebuilt every frame.
+    if quality != shadows.quality {
+        shadows.quality = quality;
+    }
+
+    ui.add_space(4.0);
     ui.label(
         egui::RichText::new(
-            "Future options: resolution, quality presets, LOD distance, shadows, anti-aliasing.",
+            "Shadows follow the sun through the day and seasons. \
+             They switch off at night and in satellite view.",
         )
         .size(theme::FONT_SMALL)
         .color(theme::TEXT_MUTED),
     );
-    ui.add_space(20.0);
+    ui.add_space(8.0);
 }
 
 /// Renders the controls settings tab (placeholder).
@@ -1,15 +1,21 @@
 //! Settings panel UI (UX-039 Colorblind Accessibility).
 //!
-//! Provides an egui window with colorblind mode selection and other
-//! accessibility settings. Toggled via the F10 key.
+//! Provides an egui window with colorblind mode selection, UI scale and font
+//! size, the keyboard / screen-reader accessibility mode, and other settings.
+//! Toggled via the F10 key.
 
 use bevy::prelude::*;
 use simulation::app_state::AppState;
 use bevy_egui::{egui, EguiContexts};
 
+use simulation::accessibility::AccessibilitySettings;
 use simulation::colorblind::{ColorblindMode, ColorblindSettings};
+use simulation::ui_scale::{FontSizePreset, UiScaleSettings, MAX_UI_SCALE, MIN_UI_SCALE};
 
+use crate::accessibility::AccessibleLogVisible;
+use crate::event_editor::EventEditorVisible;
 use crate::keybindings_panel::KeybindingsPanelVisible;
+use crate::mod_manager_panel::ModManagerVisible;
 
 // =============================================================================
 // Resources
@@ -39,11 +45,18 @@ pub fn settings_panel_keybind(
 }
 
 /// Renders the settings panel window.
+#[allow(clippy::too_many_arguments)]
 pub fn settings_panel_ui(
     mut contexts: EguiContexts,
     mut visible: ResMut<SettingsPanelVisible>,
     mut cb_settings: ResMut<ColorblindSettings>,
     mut kb_visible: ResMut<KeybindingsPanelVisible>,
+    mut events_visible: ResMut<EventEditorVisible>,
+    mut mods_visible: ResMut<ModManagerVisible>,
+    mut ui_scale: ResMut<UiScaleSettings>,
+    mut scale_draft: Local<Option<f32>>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    mut log_visible: ResMut<AccessibleLogVisible>,
 ) {
     if !visible.0 {
         return;
@@ -93,6 +106,81 @@ pub fn settings_panel_ui(
                     .color(egui::Color32::from_gray(160)),
             );
 
+            ui.add_space(8.0);
+
+            let mut enabled = accessibility.enabled;
+            if ui
+                .checkbox(&mut enabled, "Keyboard and screen-reader mode")
+                .changed()
+            {
+                accessibility.enabled = enabled;
+            }
+            ui.label(
+                egui::RichText::new(
+                    "Tab moves between controls, Space/Enter activates them and Escape \
+                     returns the keyboard to the game. Colored bars gain text ratings.",
+                )
+                .small()
+                .color(egui::Color32::from_gray(160)),
+            );
+            ui.add_enabled_ui(accessibility.enabled, |ui| {
+                let mut log_to_file = accessibility.log_to_file;
+                if ui
+                    .checkbox(&mut log_to_file, "Also write the log to accessibility_log.txt")
+                    .changed()
+                {
+                    accessibility.log_to_file = log_to_file;
+                }
+            });
+            if ui.button("Open Accessible Log...").clicked() {
+                log_visible.0 = true;
+            }
+
+            ui.add_space(16.0);
+
+            // --- Interface scale section ---
+            ui.heading("Interface");
+            ui.separator();
+
+            // The slider value is only applied when released, so the window
+            // does not rescale under the cursor mid-drag.
+            let mut scale = scale_draft.unwrap_or(ui_scale.scale);
+            ui.horizontal(|ui| {
+                ui.label("UI Scale:");
+                let response = ui.add(
+                    egui::Slider::new(&mut scale, MIN_UI_SCALE..=MAX_UI_SCALE)
+                        .step_by(0.05)
+                        .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+                );
+                if response.dragged() {
+                    *scale_draft = Some(scale);
+                } else if response.changed() || scale_draft.is_some() {
+                    if scale != ui_scale.scale {
+                        ui_scale.set_scale(scale);
+                    }
+                    *scale_draft = None;
+                }
+            });
+
+            let mut auto = ui_scale.auto;
+            if ui
+                .checkbox(&mut auto, "Detect scale from display on startup")
+                .changed()
+            {
+                ui_scale.auto = auto;
+            }
+
+            ui.label("Font Size:");
+            ui.horizontal(|ui| {
+                let current = ui_scale.font_size;
+                for preset in FontSizePreset::ALL {
+                    let selected = current == preset;
+                    if ui.selectable_label(selected, preset.label()).clicked() && !selected {
+                        ui_scale.font_size = preset;
+                    }
+                }
+            });
+
             ui.add_space(16.0);
 
             // --- Controls section ---
@@ -105,6 +193,19 @@ pub fn settings_panel_ui(
 
             ui.add_space(16.0);
 
+            // --- Gameplay section ---
+            ui.heading("Gameplay");
+            ui.separator();
+
+            if ui.button("Random Events...").clicked() {
+                events_visible.0 = true;
+            }
+            if ui.button("Mods...").clicked() {
+                mods_visible.0 = true;
+            }
+
+            ui.add_space(16.0);
+
             // --- About / Version section ---
             ui.heading("About");
             ui.separator();
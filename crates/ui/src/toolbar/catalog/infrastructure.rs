@@ -76,6 +76,14 @@ pub(super) fn infrastructure_categories() -> Vec<ToolCategory> {
                     overlay: None,
                     dashboard: None,
                 },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceStreetLamp),
+                    icon: "SL",
+                    name: "Street Lamp",
+                    cost: Some(simulation::street_lighting::STREET_LAMP_COST),
+                    overlay: None,
+                    dashboard: None,
+                },
             ],
         },
         ToolCategory {
@@ -254,6 +262,14 @@ pub(super) fn infrastructure_categories() -> Vec<ToolCategory> {
                     overlay: None,
                     dashboard: None,
                 },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceHazmatResponse),
+                    icon: "Hz",
+                    name: "Hazmat Response",
+                    cost: Some(2500.0),
+                    overlay: None,
+                    dashboard: None,
+                },
                 ToolItem {
                     tool: Some(ActiveTool::PlacePoliceKiosk),
                     icon: "Pk",
@@ -0,0 +1,453 @@
+//! Tool categories for viewing and shaping the map: Views, Environment,
+//! Terrain, Districts, and Tools.
+
+use rendering::input::ActiveTool;
+use rendering::overlay::OverlayMode;
+use simulation::natural_resources::ResourceType;
+
+use super::{DashboardKind, ToolCategory, ToolItem};
+
+/// Returns tool categories for overlays, terrain, districts, and misc tools.
+pub(super) fn map_categories() -> Vec<ToolCategory> {
+    vec![
+        ToolCategory {
+            icon: "V",
+            name: "Views",
+            items: vec![
+                ToolItem {
+                    tool: None,
+                    icon: "Pw",
+                    name: "Power",
+                    cost: None,
+                    overlay: Some(OverlayMode::Power),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Wa",
+                    name: "Water",
+                    cost: None,
+                    overlay: Some(OverlayMode::Water),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Tr",
+                    name: "Traffic",
+                    cost: None,
+                    overlay: Some(OverlayMode::Traffic),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Po",
+                    name: "Pollution",
+                    cost: None,
+                    overlay: Some(OverlayMode::Pollution),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "LV",
+                    name: "Land Value",
+                    cost: None,
+                    overlay: Some(OverlayMode::LandValue),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Ed",
+                    name: "Education",
+                    cost: None,
+                    overlay: Some(OverlayMode::Education),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Gb",
+                    name: "Garbage",
+                    cost: None,
+                    overlay: Some(OverlayMode::Garbage),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "No",
+                    name: "Noise",
+                    cost: None,
+                    overlay: Some(OverlayMode::Noise),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "WP",
+                    name: "Water Pollution",
+                    cost: None,
+                    overlay: Some(OverlayMode::WaterPollution),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "GL",
+                    name: "GW Level",
+                    cost: None,
+                    overlay: Some(OverlayMode::GroundwaterLevel),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "GQ",
+                    name: "GW Quality",
+                    cost: None,
+                    overlay: Some(OverlayMode::GroundwaterQuality),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Bd",
+                    name: "Biodiversity",
+                    cost: None,
+                    overlay: Some(OverlayMode::Biodiversity),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Er",
+                    name: "Erosion",
+                    cost: None,
+                    overlay: Some(OverlayMode::Erosion),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Ct",
+                    name: "Commute Time",
+                    cost: None,
+                    overlay: Some(OverlayMode::CommuteTime),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Rd",
+                    name: "Ridership",
+                    cost: None,
+                    overlay: Some(OverlayMode::TransitRidership),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "Jb",
+                    name: "Job Access",
+                    cost: None,
+                    overlay: Some(OverlayMode::JobAccess),
+                    dashboard: None,
+                },
+                // --- Dashboards ---
+                ToolItem {
+                    tool: None,
+                    icon: "ED",
+                    name: "Energy Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Energy),
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "WD",
+                    name: "Water Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Water),
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "GD",
+                    name: "Waste Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Waste),
+                },
+            ],
+        },
+        ToolCategory {
+            icon: "Ev",
+            name: "Environment",
+            items: vec![
+                ToolItem {
+                    tool: Some(ActiveTool::TreePlant),
+                    icon: "Tp",
+                    name: "Plant Tree",
+                    cost: Some(50.0),
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::TreeRemove),
+                    icon: "Tr",
+                    name: "Remove Tree",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "UF",
+                    name: "Forestry Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Forestry),
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceSeawall),
+                    icon: "Sw",
+                    name: "Seawall",
+                    cost: Some(simulation::flood_protection::SEAWALL_COST),
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DesignateNatureReserve),
+                    icon: "NR",
+                    name: "Nature Reserve",
+                    cost: Some(simulation::wildlife::NATURE_RESERVE_COST),
+                    overlay: Some(OverlayMode::Biodiversity),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "CD",
+                    name: "Carbon Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Carbon),
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceBreakwater),
+                    icon: "Bw",
+                    name: "Breakwater",
+                    cost: Some(simulation::coastal_erosion::BREAKWATER_COST),
+                    overlay: Some(OverlayMode::Erosion),
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "CO",
+                    name: "Coast Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Coast),
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceRooftopFarm),
+                    icon: "RF",
+                    name: "Rooftop Farm",
+                    cost: Some(simulation::agriculture::ROOFTOP_FARM_COST),
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceVerticalFarm),
+                    icon: "VF",
+                    name: "Vertical Farm",
+                    cost: Some(simulation::agriculture::VERTICAL_FARM_COST),
+                    overlay: None,
+                    dashboard: None,
+                },
+            ],
+        },
+        ToolCategory {
+            icon: "Te",
+            name: "Terrain",
+            items: vec![
+                ToolItem {
+                    tool: Some(ActiveTool::TerrainRaise),
+                    icon: "/\\",
+                    name: "Raise",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::TerrainLower),
+                    icon: "\\/",
+                    name: "Lower",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::TerrainLevel),
+                    icon: "--",
+                    name: "Flatten",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::TerrainWater),
+                    icon: "~~",
+                    name: "Water",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PaintResource(ResourceType::Ore)),
+                    icon: "Or",
+                    name: "Ore",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PaintResource(ResourceType::Oil)),
+                    icon: "Oi",
+                    name: "Oil",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PaintResource(ResourceType::FertileLand)),
+                    icon: "Fl",
+                    name: "Fertile Land",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PaintResource(ResourceType::Forest)),
+                    icon: "Fo",
+                    name: "Forest",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::EraseResource),
+                    icon: "xR",
+                    name: "Erase Deposits",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: None,
+                    icon: "MT",
+                    name: "Map Tiles",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::MapTiles),
+                },
+            ],
+        },
+        ToolCategory {
+            icon: "D",
+            name: "Districts",
+            items: vec![
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(0)),
+                    icon: "D0",
+                    name: "Downtown",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(1)),
+                    icon: "D1",
+                    name: "Suburbs",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(2)),
+                    icon: "D2",
+                    name: "Industrial",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(3)),
+                    icon: "D3",
+                    name: "Waterfront",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(4)),
+                    icon: "D4",
+                    name: "Historic",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(5)),
+                    icon: "D5",
+                    name: "University",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(6)),
+                    icon: "D6",
+                    name: "Arts",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictPaint(7)),
+                    icon: "D7",
+                    name: "Tech Park",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::DistrictErase),
+                    icon: "DE",
+                    name: "Erase District",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+            ],
+        },
+        ToolCategory {
+            icon: "T",
+            name: "Tools",
+            items: vec![
+                ToolItem {
+                    tool: Some(ActiveTool::Bulldoze),
+                    icon: "Bd",
+                    name: "Bulldoze",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::Inspect),
+                    icon: "?",
+                    name: "Inspect",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::Blueprint),
+                    icon: "Bp",
+                    name: "Blueprint",
+                    cost: None,
+                    overlay: None,
+                    dashboard: None,
+                },
+            ],
+        },
+    ]
+}
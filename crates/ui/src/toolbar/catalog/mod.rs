@@ -9,6 +9,7 @@ use rendering::input::ActiveTool;
 use rendering::overlay::OverlayMode;
 
 mod infrastructure;
+mod map_tools;
 mod services;
 pub(super) mod unlock_filter;
 
@@ -29,6 +30,7 @@ impl Default for ToolCatalog {
     fn default() -> Self {
         let mut cats = infrastructure::infrastructure_categories();
         cats.extend(services::services_categories());
+        cats.extend(map_tools::map_categories());
         Self { categories: cats }
     }
 }
@@ -43,6 +45,11 @@ pub enum DashboardKind {
     Energy,
     Water,
     Waste,
+    Carbon,
+    Coast,
+    Forestry,
+    MapTiles,
+    Airport,
 }
 
 // ---------------------------------------------------------------------------
@@ -105,6 +112,7 @@ fn tool_description(item: &ToolItem) -> Option<&'static str> {
         ActiveTool::PlaceFireHouse => "Small fire response station",
         ActiveTool::PlaceFireStation => "Standard fire protection and response",
         ActiveTool::PlaceFireHQ => "Regional fire department headquarters",
+        ActiveTool::PlaceHazmatResponse => "Cleanup crews that contain chemical spills",
         ActiveTool::PlacePoliceKiosk => "Small police outpost for local patrol",
         ActiveTool::PlacePoliceStation => "Standard law enforcement station",
         ActiveTool::PlacePoliceHQ => "Regional police headquarters",
@@ -130,6 +138,8 @@ fn tool_description(item: &ToolItem) -> Option<&'static str> {
         ActiveTool::PlaceMuseum => "Cultural institution attracting tourists",
         ActiveTool::PlaceCathedral => "Historic religious landmark",
         ActiveTool::PlaceTVStation => "Broadcasting station for city media",
+        ActiveTool::PlaceHotel => "Rooms for overnight tourists, priced by occupancy",
+        ActiveTool::PlaceConventionCenter => "Hosts conventions that fill hotels",
         // Sanitation
         ActiveTool::PlaceLandfill => "Basic waste disposal site",
         ActiveTool::PlaceRecyclingCenter => "Sorts and recycles waste materials",
@@ -150,13 +160,28 @@ fn tool_description(item: &ToolItem) -> Option<&'static str> {
         ActiveTool::PlaceCellTower => "Mobile network coverage tower",
         ActiveTool::PlaceDataCenter => "Internet and data processing facility",
         // Environment
-        ActiveTool::TreePlant => "Plant a tree to improve air quality",
+        ActiveTool::TreePlant => "Plant a sapling of the chosen species",
         ActiveTool::TreeRemove => "Remove an existing tree",
+        ActiveTool::PlaceSeawall => "Coastal wall that holds the shoreline against rising seas",
+        ActiveTool::DesignateNatureReserve => {
+            "Protect the surrounding habitat as a nature reserve for wildlife"
+        }
+        ActiveTool::PlaceBreakwater => "Offshore barrier that shelters nearby beaches from erosion",
+        ActiveTool::PlaceRooftopFarm => {
+            "Farm on a dense building's roof: grows food and cools the block"
+        }
+        ActiveTool::PlaceVerticalFarm => {
+            "Indoor farm in a dense building: high yield, heavy power and water use"
+        }
         // Terrain
-        ActiveTool::TerrainRaise => "Raise terrain elevation",
-        ActiveTool::TerrainLower => "Lower terrain elevation",
-        ActiveTool::TerrainLevel => "Flatten terrain to uniform height",
-        ActiveTool::TerrainWater => "Create water body on terrain",
+        ActiveTool::TerrainRaise => "Raise terrain with fill from the soil stockpile or imports",
+        ActiveTool::TerrainLower => "Lower terrain; cut soil goes to the stockpile",
+        ActiveTool::TerrainLevel => "Flatten terrain to uniform height, paying for soil moved",
+        ActiveTool::TerrainWater => "Carve canals and lakes; joined to water they flow",
+        ActiveTool::PaintResource(_) => {
+            "Sandbox: paint a finite deposit that extraction industry works until empty"
+        }
+        ActiveTool::EraseResource => "Sandbox: remove natural resource deposits",
         // Tools
         ActiveTool::Bulldoze => "Demolish buildings and roads",
         ActiveTool::Inspect => "View detailed cell information",
@@ -164,6 +189,12 @@ fn tool_description(item: &ToolItem) -> Option<&'static str> {
         ActiveTool::DistrictPaint(_) => "Paint cells to assign to this district",
         ActiveTool::DistrictErase => "Remove district assignment from cells",
         ActiveTool::AutoGrid => "Auto-generate a grid of roads in a rectangular area",
+        ActiveTool::Blueprint => {
+            "Save a Shift+dragged area as a blueprint and stamp copies of it elsewhere"
+        }
+        ActiveTool::PlaceStreetLamp => {
+            "Light a dark street to deter night crime; click a lamp to remove it"
+        }
     })
 }
 
@@ -173,6 +204,11 @@ fn dashboard_description(kind: DashboardKind) -> &'static str {
         DashboardKind::Energy => "Open power grid overview dashboard (F3)",
         DashboardKind::Water => "Open water supply dashboard (F4)",
         DashboardKind::Waste => "Open waste management dashboard (F6)",
+        DashboardKind::Carbon => "Open carbon emissions dashboard",
+        DashboardKind::Coast => "Open beach and coastal defence dashboard",
+        DashboardKind::Forestry => "Open urban forestry dashboard",
+        DashboardKind::MapTiles => "Open map tiles dashboard to buy land",
+        DashboardKind::Airport => "Open flight board and airport expansion dashboard",
     }
 }
 
@@ -261,6 +297,11 @@ fn overlay_description(mode: OverlayMode) -> &'static str {
         OverlayMode::GroundwaterLevel => "Shows underground water table depth",
         OverlayMode::GroundwaterQuality => "Shows groundwater purity levels",
         OverlayMode::Wind => "Shows wind speed and direction",
+        OverlayMode::Biodiversity => "Shows wildlife habitat and green corridors",
+        OverlayMode::Erosion => "Shows how much sand is left on the beaches",
+        OverlayMode::CommuteTime => "Shows the average commute of the people living in each home",
+        OverlayMode::TransitRidership => "Shows how busy each transit stop and station is",
+        OverlayMode::JobAccess => "Shows how many jobs are within 30 minutes without a car",
         OverlayMode::None => "",
     }
 }
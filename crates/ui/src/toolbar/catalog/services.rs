@@ -1,12 +1,11 @@
-//! Tool categories for city services and overlays: Landmarks, Sanitation,
-//! Transport, Telecom, Views, Environment, Terrain, Districts, and Tools.
+//! Tool categories for city services: Landmarks, Sanitation, Transport, and
+//! Telecom.
 
 use rendering::input::ActiveTool;
-use rendering::overlay::OverlayMode;
 
 use super::{DashboardKind, ToolCategory, ToolItem};
 
-/// Returns tool categories for services, overlays, and misc tools.
+/// Returns tool categories for city services.
 pub(super) fn services_categories() -> Vec<ToolCategory> {
     vec![
         ToolCategory {
@@ -45,6 +44,22 @@ pub(super) fn services_categories() -> Vec<ToolCategory> {
                     overlay: None,
                     dashboard: None,
                 },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceHotel),
+                    icon: "Ht",
+                    name: "Hotel",
+                    cost: Some(3500.0),
+                    overlay: None,
+                    dashboard: None,
+                },
+                ToolItem {
+                    tool: Some(ActiveTool::PlaceConventionCenter),
+                    icon: "CC",
+                    name: "Convention Center",
+                    cost: Some(6000.0),
+                    overlay: None,
+                    dashboard: None,
+                },
             ],
         },
         ToolCategory {
@@ -169,6 +184,14 @@ pub(super) fn services_categories() -> Vec<ToolCategory> {
                     overlay: None,
                     dashboard: None,
                 },
+                ToolItem {
+                    tool: None,
+                    icon: "AD",
+                    name: "Airport Dashboard",
+                    cost: None,
+                    overlay: None,
+                    dashboard: Some(DashboardKind::Airport),
+                },
             ],
         },
         ToolCategory {
@@ -193,284 +216,5 @@ pub(super) fn services_categories() -> Vec<ToolCategory> {
                 },
             ],
         },
-        ToolCategory {
-            icon: "V",
-            name: "Views",
-            items: vec![
-                ToolItem {
-                    tool: None,
-                    icon: "Pw",
-                    name: "Power",
-                    cost: None,
-                    overlay: Some(OverlayMode::Power),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "Wa",
-                    name: "Water",
-                    cost: None,
-                    overlay: Some(OverlayMode::Water),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "Tr",
-                    name: "Traffic",
-                    cost: None,
-                    overlay: Some(OverlayMode::Traffic),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "Po",
-                    name: "Pollution",
-                    cost: None,
-                    overlay: Some(OverlayMode::Pollution),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "LV",
-                    name: "Land Value",
-                    cost: None,
-                    overlay: Some(OverlayMode::LandValue),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "Ed",
-                    name: "Education",
-                    cost: None,
-                    overlay: Some(OverlayMode::Education),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "Gb",
-                    name: "Garbage",
-                    cost: None,
-                    overlay: Some(OverlayMode::Garbage),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "No",
-                    name: "Noise",
-                    cost: None,
-                    overlay: Some(OverlayMode::Noise),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "WP",
-                    name: "Water Pollution",
-                    cost: None,
-                    overlay: Some(OverlayMode::WaterPollution),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "GL",
-                    name: "GW Level",
-                    cost: None,
-                    overlay: Some(OverlayMode::GroundwaterLevel),
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "GQ",
-                    name: "GW Quality",
-                    cost: None,
-                    overlay: Some(OverlayMode::GroundwaterQuality),
-                    dashboard: None,
-                },
-                // --- Dashboards ---
-                ToolItem {
-                    tool: None,
-                    icon: "ED",
-                    name: "Energy Dashboard",
-                    cost: None,
-                    overlay: None,
-                    dashboard: Some(DashboardKind::Energy),
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "WD",
-                    name: "Water Dashboard",
-                    cost: None,
-                    overlay: None,
-                    dashboard: Some(DashboardKind::Water),
-                },
-                ToolItem {
-                    tool: None,
-                    icon: "GD",
-                    name: "Waste Dashboard",
-                    cost: None,
-                    overlay: None,
-                    dashboard: Some(DashboardKind::Waste),
-                },
-            ],
-        },
-        ToolCategory {
-            icon: "Ev",
-            name: "Environment",
-            items: vec![
-                ToolItem {
-                    tool: Some(ActiveTool::TreePlant),
-                    icon: "Tp",
-                    name: "Plant Tree",
-                    cost: Some(50.0),
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::TreeRemove),
-                    icon: "Tr",
-                    name: "Remove Tree",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-            ],
-        },
-        ToolCategory {
-            icon: "Te",
-            name: "Terrain",
-            items: vec![
-                ToolItem {
-                    tool: Some(ActiveTool::TerrainRaise),
-                    icon: "/\\",
-                    name: "Raise",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::TerrainLower),
-                    icon: "\\/",
-                    name: "Lower",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::TerrainLevel),
-                    icon: "--",
-                    name: "Flatten",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::TerrainWater),
-                    icon: "~~",
-                    name: "Water",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-            ],
-        },
-        ToolCategory {
-            icon: "D",
-            name: "Districts",
-            items: vec![
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(0)),
-                    icon: "D0",
-                    name: "Downtown",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(1)),
-                    icon: "D1",
-                    name: "Suburbs",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(2)),
-                    icon: "D2",
-                    name: "Industrial",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(3)),
-                    icon: "D3",
-                    name: "Waterfront",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(4)),
-                    icon: "D4",
-                    name: "Historic",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(5)),
-                    icon: "D5",
-                    name: "University",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(6)),
-                    icon: "D6",
-                    name: "Arts",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictPaint(7)),
-                    icon: "D7",
-                    name: "Tech Park",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::DistrictErase),
-                    icon: "DE",
-                    name: "Erase District",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-            ],
-        },
-        ToolCategory {
-            icon: "T",
-            name: "Tools",
-            items: vec![
-                ToolItem {
-                    tool: Some(ActiveTool::Bulldoze),
-                    icon: "Bd",
-                    name: "Bulldoze",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-                ToolItem {
-                    tool: Some(ActiveTool::Inspect),
-                    icon: "?",
-                    name: "Inspect",
-                    cost: None,
-                    overlay: None,
-                    dashboard: None,
-                },
-            ],
-        },
     ]
 }
@@ -2,6 +2,7 @@
 //! available based on the current `UnlockState`.
 
 use rendering::input::ActiveTool;
+use simulation::scenario::ScenarioState;
 use simulation::unlocks::{UnlockNode, UnlockState};
 
 /// Returns `true` if the given tool is available to the player.
@@ -21,10 +22,10 @@ pub fn is_tool_unlocked(tool: &ActiveTool, unlocks: &UnlockState) -> bool {
     // Check zone tools that require unlocks
     match tool {
         ActiveTool::ZoneResidentialHigh => {
-            unlocks.is_unlocked(UnlockNode::HighDensityResidential)
+            unlocks.has_access(UnlockNode::HighDensityResidential)
         }
         ActiveTool::ZoneCommercialHigh => {
-            unlocks.is_unlocked(UnlockNode::HighDensityCommercial)
+            unlocks.has_access(UnlockNode::HighDensityCommercial)
         }
         ActiveTool::ZoneOffice => unlocks.is_unlocked(UnlockNode::OfficeZoning),
         // All other tools (roads, basic zones, terrain, districts, views,
@@ -58,6 +59,14 @@ pub fn unlock_progress(
     if is_tool_unlocked(tool, unlocks) {
         return None;
     }
+    if let Some(choice) = tool_utility_type(tool).and_then(|ut| unlocks.banned_by(ut)) {
+        return Some(UnlockProgress {
+            requirement: format!("Not allowed under {}", choice.name()),
+            progress_text: String::new(),
+            fraction: 0.0,
+            nearly_unlocked: false,
+        });
+    }
     let node = required_unlock_node(tool)?;
     let required_pop = node.required_population();
     let tier = tier_name_for_population(required_pop);
@@ -96,6 +105,24 @@ pub fn unlock_progress(
     })
 }
 
+/// Lock info for a tool the current scenario takes away, shown in place of
+/// unlock progress. Returns `None` if the scenario allows the tool.
+pub fn scenario_lock(tool: &ActiveTool, scenario: &ScenarioState) -> Option<UnlockProgress> {
+    let locked = if let Some(ut) = tool_utility_type(tool) {
+        scenario.locks_utility(ut)
+    } else if let Some(st) = tool.service_type() {
+        scenario.locks_service(st)
+    } else {
+        tool.zone_type().is_some_and(|zone| scenario.locks_zone(zone))
+    };
+    locked.then(|| UnlockProgress {
+        requirement: "Not available in this scenario".to_string(),
+        progress_text: String::new(),
+        fraction: 0.0,
+        nearly_unlocked: false,
+    })
+}
+
 /// Maps an `ActiveTool` to the `UnlockNode` that gates it.
 fn required_unlock_node(tool: &ActiveTool) -> Option<UnlockNode> {
     // Utility tools
@@ -138,7 +165,9 @@ fn service_to_unlock_node(
         ServiceType::FireStation | ServiceType::FireHouse => {
             Some(UnlockNode::FireService)
         }
-        ServiceType::FireHQ => Some(UnlockNode::AdvancedEmergency),
+        ServiceType::FireHQ | ServiceType::HazmatResponse => {
+            Some(UnlockNode::AdvancedEmergency)
+        }
         ServiceType::PoliceStation | ServiceType::PoliceKiosk => {
             Some(UnlockNode::PoliceService)
         }
@@ -160,7 +189,7 @@ fn service_to_unlock_node(
         ServiceType::LargePark | ServiceType::SportsField => {
             Some(UnlockNode::AdvancedParks)
         }
-        ServiceType::Plaza | ServiceType::Stadium => {
+        ServiceType::Plaza | ServiceType::Stadium | ServiceType::Hotel => {
             Some(UnlockNode::Entertainment)
         }
         ServiceType::Landfill | ServiceType::TransferStation => {
@@ -175,7 +204,8 @@ fn service_to_unlock_node(
         ServiceType::CityHall
         | ServiceType::Museum
         | ServiceType::Cathedral
-        | ServiceType::TVStation => Some(UnlockNode::Landmarks),
+        | ServiceType::TVStation
+        | ServiceType::ConventionCenter => Some(UnlockNode::Landmarks),
         ServiceType::BusDepot | ServiceType::TrainStation => {
             Some(UnlockNode::PublicTransport)
         }
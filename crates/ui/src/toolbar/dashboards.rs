@@ -0,0 +1,59 @@
+//! Visibility of the dashboards the toolbar opens.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::airport_dashboard::AirportDashboardVisible;
+use crate::carbon_dashboard::CarbonDashboardVisible;
+use crate::coast_dashboard::CoastDashboardVisible;
+use crate::energy_dashboard::EnergyDashboardVisible;
+use crate::forestry_dashboard::ForestryDashboardVisible;
+use crate::map_tiles_dashboard::MapTilesDashboardVisible;
+use crate::waste_dashboard::WasteDashboardVisible;
+use crate::water_dashboard::WaterDashboardVisible;
+
+use super::catalog::DashboardKind;
+
+/// The visibility resource of every dashboard listed in the tool catalog.
+#[derive(SystemParam)]
+pub(crate) struct DashboardVisibility<'w> {
+    energy: ResMut<'w, EnergyDashboardVisible>,
+    water: ResMut<'w, WaterDashboardVisible>,
+    waste: ResMut<'w, WasteDashboardVisible>,
+    carbon: ResMut<'w, CarbonDashboardVisible>,
+    coast: ResMut<'w, CoastDashboardVisible>,
+    forestry: ResMut<'w, ForestryDashboardVisible>,
+    map_tiles: ResMut<'w, MapTilesDashboardVisible>,
+    airport: ResMut<'w, AirportDashboardVisible>,
+}
+
+impl DashboardVisibility<'_> {
+    /// Check if a dashboard is currently visible.
+    pub(crate) fn is_visible(&self, kind: DashboardKind) -> bool {
+        match kind {
+            DashboardKind::Energy => self.energy.0,
+            DashboardKind::Water => self.water.0,
+            DashboardKind::Waste => self.waste.0,
+            DashboardKind::Carbon => self.carbon.0,
+            DashboardKind::Coast => self.coast.0,
+            DashboardKind::Forestry => self.forestry.0,
+            DashboardKind::MapTiles => self.map_tiles.0,
+            DashboardKind::Airport => self.airport.0,
+        }
+    }
+
+    /// Toggle a dashboard's visibility resource by kind.
+    pub(crate) fn toggle(&mut self, kind: DashboardKind) {
+        let visible = match kind {
+            DashboardKind::Energy => &mut self.energy.0,
+            DashboardKind::Water => &mut self.water.0,
+            DashboardKind::Waste => &mut self.waste.0,
+            DashboardKind::Carbon => &mut self.carbon.0,
+            DashboardKind::Coast => &mut self.coast.0,
+            DashboardKind::Forestry => &mut self.forestry.0,
+            DashboardKind::MapTiles => &mut self.map_tiles.0,
+            DashboardKind::Airport => &mut self.airport.0,
+        };
+        *visible = !*visible;
+    }
+}
@@ -1,11 +1,13 @@
 //! Toolbar UI module — split into sub-modules for maintainability.
 //!
 //! - `catalog`: tool/category definitions, tooltips, and the `ToolCatalog` resource
+//! - `dashboards`: visibility of the dashboards opened from the toolbar
 //! - `speed`: simulation speed keyboard shortcuts
 //! - `ui_system`: the main `toolbar_ui` egui system
 //! - `widgets`: reusable UI helpers (demand bars, speed buttons, formatting)
 
 mod catalog;
+mod dashboards;
 mod speed;
 mod ui_system;
 mod widgets;
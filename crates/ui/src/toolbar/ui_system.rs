@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
-use simulation::bankruptcy_warning::{BankruptcyLevel, BankruptcyState};
+use simulation::bankruptcy_warning::BankruptcyState;
 use simulation::budget::ExtendedBudget;
 use simulation::economy::CityBudget;
+use simulation::scenario::ScenarioState;
 use simulation::stats::CityStats;
 use simulation::time_of_day::GameClock;
+use simulation::tutorial_hints::TutorialUiHint;
 use simulation::unlocks::UnlockState;
 use simulation::weather::Weather;
 use simulation::zones::ZoneDemand;
@@ -13,55 +15,16 @@ use simulation::zones::ZoneDemand;
 use rendering::input::{ActiveTool, GridSnap, StatusMessage};
 use rendering::overlay::{DualOverlayMode, DualOverlayState, OverlayMode, OverlayState};
 
+use crate::accessibility;
 use crate::confirm_dialog::{ConfirmAction, PendingConfirmAction};
 use crate::save_slot_ui::SaveSlotUiState;
 
 use super::catalog::unlock_filter;
-use super::catalog::{show_tool_tooltip, DashboardKind, OpenCategory, ToolCatalog};
-use super::widgets::{format_pop, milestone_name, rci_demand_bars, speed_button};
-
-use crate::energy_dashboard::EnergyDashboardVisible;
-use crate::waste_dashboard::WasteDashboardVisible;
-use crate::water_dashboard::WaterDashboardVisible;
-
-/// Return the color for the treasury label based on the current bankruptcy level.
-fn treasury_color(level: BankruptcyLevel) -> egui::Color32 {
-    match level {
-        BankruptcyLevel::Bankrupt | BankruptcyLevel::Critical => {
-            egui::Color32::from_rgb(220, 60, 60)
-        }
-        BankruptcyLevel::Warning => egui::Color32::from_rgb(230, 200, 50),
-        BankruptcyLevel::Normal => egui::Color32::from_rgb(200, 200, 200),
-    }
-}
-
-/// Toggle a dashboard's visibility resource by kind.
-fn toggle_dashboard(
-    kind: DashboardKind,
-    energy: &mut ResMut<EnergyDashboardVisible>,
-    water: &mut ResMut<WaterDashboardVisible>,
-    waste: &mut ResMut<WasteDashboardVisible>,
-) {
-    match kind {
-        DashboardKind::Energy => energy.0 = !energy.0,
-        DashboardKind::Water => water.0 = !water.0,
-        DashboardKind::Waste => waste.0 = !waste.0,
-    }
-}
-
-/// Check if a dashboard is currently visible.
-fn is_dashboard_visible(
-    kind: DashboardKind,
-    energy: &EnergyDashboardVisible,
-    water: &WaterDashboardVisible,
-    waste: &WasteDashboardVisible,
-) -> bool {
-    match kind {
-        DashboardKind::Energy => energy.0,
-        DashboardKind::Water => water.0,
-        DashboardKind::Waste => waste.0,
-    }
-}
+use super::catalog::{show_tool_tooltip, OpenCategory, ToolCatalog};
+use super::dashboards::DashboardVisibility;
+use super::widgets::{
+    format_pop, milestone_name, rci_demand_bars, speed_button, treasury_color, treasury_status,
+};
 
 // ---------------------------------------------------------------------------
 // Main toolbar system
@@ -82,15 +45,17 @@ pub fn toolbar_ui(
     mut open_cat: ResMut<OpenCategory>,
     weather_snap: (Res<Weather>, Res<GridSnap>),
     extended_budget: Res<ExtendedBudget>,
-    catalog_unlocks_bankruptcy: (Res<ToolCatalog>, Res<UnlockState>, Res<BankruptcyState>),
-    mut dashboard_vis: (
-        ResMut<EnergyDashboardVisible>,
-        ResMut<WaterDashboardVisible>,
-        ResMut<WasteDashboardVisible>,
+    catalog_unlocks_bankruptcy: (
+        Res<ToolCatalog>,
+        Res<UnlockState>,
+        Res<BankruptcyState>,
+        Res<ScenarioState>,
     ),
+    mut dashboard_vis: DashboardVisibility,
+    tutorial_hint: Res<TutorialUiHint>,
 ) {
     let (mut overlay, dual_overlay) = overlay_params;
-    let (catalog, unlocks, bankruptcy) = catalog_unlocks_bankruptcy;
+    let (catalog, unlocks, bankruptcy, scenario) = catalog_unlocks_bankruptcy;
     let (weather, grid_snap) = weather_snap;
     let categories = &catalog.categories;
     let current_pop = stats.population;
@@ -132,6 +97,11 @@ pub fn toolbar_ui(
                 ui.label(
                     egui::RichText::new(format!("${:.0}", budget.treasury)).color(money_color),
                 );
+                if accessibility::enabled(ui.ctx()) {
+                    if let Some(status) = treasury_status(bankruptcy.level) {
+                        ui.colored_label(money_color, format!("({status})"));
+                    }
+                }
 
                 // Net income indicator
                 {
@@ -161,6 +131,9 @@ pub fn toolbar_ui(
                             ui.label(format!("Industrial Tax: ${:.0}", ib.industrial_tax));
                             ui.label(format!("Office Tax: ${:.0}", ib.office_tax));
                             ui.label(format!("Tourism: ${:.0}", ib.trade_income));
+                            ui.label(format!("Insurance Payouts: ${:.0}", ib.insurance_payouts));
+                            ui.label(format!("Disaster Aid: ${:.0}", ib.disaster_aid));
+                            ui.label(format!("Carbon Revenue: ${:.0}", ib.carbon_revenue));
                         });
                         ui.separator();
                         ui.label(
@@ -173,6 +146,7 @@ pub fn toolbar_ui(
                             ui.label(format!("Policy Costs: ${:.0}", eb.policy_costs));
                             ui.label(format!("Loan Payments: ${:.0}", eb.loan_payments));
                             ui.label(format!("Power Fuel: ${:.0}", eb.fuel_costs));
+                            ui.label(format!("Disaster Insurance: ${:.0}", eb.insurance_premiums));
                         });
                         ui.separator();
                         ui.label(
@@ -323,6 +297,21 @@ pub fn toolbar_ui(
                 for (idx, cat) in categories.iter().enumerate() {
                     let is_open = open_cat.0 == Some(idx);
                     let btn = ui.selectable_label(is_open, egui::RichText::new(cat.name).strong());
+                    if !is_open && tutorial_hint.toolbar_category() == Some(cat.name) {
+                        // Pulsing outline around the category the tutorial points at.
+                        let pulse = ((ui.input(|i| i.time) * 3.0).sin() * 0.5 + 0.5) as f32;
+                        let alpha = (140.0 + pulse * 115.0) as u8;
+                        ui.painter().rect_stroke(
+                            btn.rect.expand(2.0),
+                            4.0,
+                            egui::Stroke::new(
+                                2.0,
+                                egui::Color32::from_rgba_unmultiplied(255, 200, 60, alpha),
+                            ),
+                            egui::StrokeKind::Outside,
+                        );
+                        ui.ctx().request_repaint();
+                    }
                     if btn.clicked() {
                         if is_open {
                             open_cat.0 = None;
@@ -362,7 +351,9 @@ pub fn toolbar_ui(
                                 for item in cat.items.iter() {
                                     // Check unlock state for this item
                                     let progress = item.tool.as_ref().and_then(|t| {
-                                        unlock_filter::unlock_progress(t, &unlocks, current_pop)
+                                        unlock_filter::scenario_lock(t, &scenario).or_else(|| {
+                                            unlock_filter::unlock_progress(t, &unlocks, current_pop)
+                                        })
                                     });
                                     let is_locked = progress.is_some();
 
@@ -377,12 +368,7 @@ pub fn toolbar_ui(
                                         None => match item.overlay {
                                             Some(ov) => overlay.mode == ov,
                                             None => match item.dashboard {
-                                                Some(dk) => is_dashboard_visible(
-                                                    dk,
-                                                    &dashboard_vis.0,
-                                                    &dashboard_vis.1,
-                                                    &dashboard_vis.2,
-                                                ),
+                                                Some(dk) => dashboard_vis.is_visible(dk),
                                                 None => false,
                                             },
                                         },
@@ -435,12 +421,7 @@ pub fn toolbar_ui(
                                                     ov
                                                 };
                                             } else if let Some(dk) = item.dashboard {
-                                                toggle_dashboard(
-                                                    dk,
-                                                    &mut dashboard_vis.0,
-                                                    &mut dashboard_vis.1,
-                                                    &mut dashboard_vis.2,
-                                                );
+                                                dashboard_vis.toggle(dk);
                                             }
                                         }
                                     }
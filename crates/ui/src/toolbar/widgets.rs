@@ -1,7 +1,10 @@
 use bevy_egui::egui;
 
+use simulation::bankruptcy_warning::BankruptcyLevel;
 use simulation::zones::ZoneDemand;
 
+use crate::accessibility::{self, demand_text};
+
 // ---------------------------------------------------------------------------
 // Population formatting
 // ---------------------------------------------------------------------------
@@ -38,6 +41,31 @@ pub(crate) fn milestone_name(pop: u32) -> &'static str {
     "Settlement"
 }
 
+// ---------------------------------------------------------------------------
+// Treasury status
+// ---------------------------------------------------------------------------
+
+/// Return the color for the treasury label based on the current bankruptcy level.
+pub(crate) fn treasury_color(level: BankruptcyLevel) -> egui::Color32 {
+    match level {
+        BankruptcyLevel::Bankrupt | BankruptcyLevel::Critical => {
+            egui::Color32::from_rgb(220, 60, 60)
+        }
+        BankruptcyLevel::Warning => egui::Color32::from_rgb(230, 200, 50),
+        BankruptcyLevel::Normal => egui::Color32::from_rgb(200, 200, 200),
+    }
+}
+
+/// Text equivalent of `treasury_color` for accessibility mode.
+pub(crate) fn treasury_status(level: BankruptcyLevel) -> Option<&'static str> {
+    match level {
+        BankruptcyLevel::Bankrupt => Some("bankrupt"),
+        BankruptcyLevel::Critical => Some("critical funds"),
+        BankruptcyLevel::Warning => Some("low funds"),
+        BankruptcyLevel::Normal => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // RCI Demand Bars
 // ---------------------------------------------------------------------------
@@ -140,6 +168,14 @@ pub(crate) fn rci_demand_bars(ui: &mut egui::Ui, demand: &ZoneDemand) {
             demand.industrial,
             egui::Color32::from_rgb(220, 200, 60),
         );
+        if accessibility::enabled(ui.ctx()) {
+            ui.label(format!(
+                "R {} C {} I {}",
+                demand_text(demand.residential),
+                demand_text(demand.commercial),
+                demand_text(demand.industrial),
+            ));
+        }
     });
 }
 
@@ -195,6 +231,16 @@ pub(crate) fn speed_button(
             painter.rect_filled(rect.shrink(1.0), 4.0, egui::Color32::from_white_alpha(10));
         }
 
+        // Keyboard focus outline (custom-painted, so egui does not draw one)
+        if response.has_focus() {
+            painter.rect_stroke(
+                rect,
+                4.0,
+                ui.visuals().widgets.active.bg_stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+
         // Draw the colored dot
         let dot_center = egui::pos2(rect.left() + dot_radius + 4.0, rect.center().y);
         if active {
@@ -223,5 +269,8 @@ pub(crate) fn speed_button(
         );
     }
 
+    response.widget_info(|| {
+        egui::WidgetInfo::selected(egui::WidgetType::SelectableLabel, true, active, label)
+    });
     response
 }
@@ -2,15 +2,22 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use simulation::time_of_day::GameClock;
-use simulation::tutorial::{TutorialState, TutorialStep};
+use simulation::tutorial::{
+    TutorialSignal, TutorialState, TutorialStepDef, SIGNAL_ADVISOR_OPENED, SIGNAL_BUDGET_OPENED,
+    SIGNAL_CHARTS_OPENED, SIGNAL_JOURNAL_OPENED, SIGNAL_POLICIES_OPENED,
+};
 use simulation::tutorial_hints::TutorialUiHint;
 
+use crate::info_panel::{
+    AdvisorVisible, BudgetPanelVisible, ChartsVisible, JournalVisible, PoliciesVisible,
+};
+
 /// Renders the tutorial overlay window when the tutorial is active.
 ///
 /// Features:
 /// - Progress indicator with step counter and progress bar
 /// - Styled step title, description, and hint text
-/// - Pulsing toolbar highlight indicator (e.g. ">>> Roads <<<")
+/// - Pulsing highlight indicator (e.g. ">>> Roads <<<")
 /// - Back / Next / Skip buttons
 /// - Rounded corner frame styling with polished colors
 #[allow(clippy::too_many_arguments)]
@@ -25,9 +32,10 @@ pub fn tutorial_ui(
         return;
     }
 
-    let step = tutorial.current_step;
-    let step_index = step.index();
-    let total = TutorialStep::total_steps();
+    let step = tutorial.step().clone();
+    let step_index = tutorial.current_step;
+    let total = tutorial.total_steps();
+    let is_last = tutorial.is_last_step();
     let elapsed = time.elapsed_secs_f64();
 
     let ctx = contexts.ctx_mut();
@@ -47,43 +55,43 @@ pub fn tutorial_ui(
         .frame(frame)
         .title_bar(false)
         .show(ctx, |ui| {
-            render_progress(ui, step, step_index, total);
-            render_title(ui, step);
-            render_description(ui, step);
-            render_hint(ui, step);
+            if !is_last {
+                render_progress(ui, step_index, total);
+            }
+            render_title(ui, &step);
+            render_description(ui, &step);
+            render_hint(ui, &step);
             render_highlight_indicator(ui, &hint, elapsed);
             ui.add_space(12.0);
-            render_buttons(ui, &mut tutorial, &mut clock, step);
+            render_buttons(ui, &mut tutorial, &mut clock, step_index, is_last);
         });
 }
 
 /// Draw the progress bar and step counter.
-fn render_progress(ui: &mut egui::Ui, step: TutorialStep, step_index: usize, total: usize) {
-    if step != TutorialStep::Completed {
-        ui.horizontal(|ui| {
-            ui.label(
-                egui::RichText::new(format!("Step {}/{}", step_index, total))
-                    .small()
-                    .color(egui::Color32::from_rgb(160, 160, 180)),
-            );
-
-            let progress = step_index as f32 / total as f32;
-            ui.add(
-                egui::ProgressBar::new(progress)
-                    .desired_width(200.0)
-                    .show_percentage(),
-            );
-        });
-        ui.add_space(4.0);
-        ui.separator();
-        ui.add_space(4.0);
-    }
+fn render_progress(ui: &mut egui::Ui, step_index: usize, total: usize) {
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(format!("Step {}/{}", step_index, total))
+                .small()
+                .color(egui::Color32::from_rgb(160, 160, 180)),
+        );
+
+        let progress = step_index as f32 / total as f32;
+        ui.add(
+            egui::ProgressBar::new(progress)
+                .desired_width(200.0)
+                .show_percentage(),
+        );
+    });
+    ui.add_space(4.0);
+    ui.separator();
+    ui.add_space(4.0);
 }
 
 /// Draw the step title.
-fn render_title(ui: &mut egui::Ui, step: TutorialStep) {
+fn render_title(ui: &mut egui::Ui, step: &TutorialStepDef) {
     ui.heading(
-        egui::RichText::new(step.title())
+        egui::RichText::new(&step.title)
             .strong()
             .size(18.0)
             .color(egui::Color32::from_rgb(100, 200, 255)),
@@ -92,9 +100,9 @@ fn render_title(ui: &mut egui::Ui, step: TutorialStep) {
 }
 
 /// Draw the step description text.
-fn render_description(ui: &mut egui::Ui, step: TutorialStep) {
+fn render_description(ui: &mut egui::Ui, step: &TutorialStepDef) {
     ui.label(
-        egui::RichText::new(step.description())
+        egui::RichText::new(&step.description)
             .size(14.0)
             .color(egui::Color32::from_rgb(220, 220, 230)),
     );
@@ -102,18 +110,21 @@ fn render_description(ui: &mut egui::Ui, step: TutorialStep) {
 }
 
 /// Draw the step hint text.
-fn render_hint(ui: &mut egui::Ui, step: TutorialStep) {
+fn render_hint(ui: &mut egui::Ui, step: &TutorialStepDef) {
+    if step.hint.is_empty() {
+        return;
+    }
     ui.label(
-        egui::RichText::new(step.hint())
+        egui::RichText::new(&step.hint)
             .italics()
             .size(13.0)
             .color(egui::Color32::from_rgb(180, 210, 140)),
     );
 }
 
-/// Draw a pulsing indicator pointing to the relevant toolbar category.
+/// Draw a pulsing indicator naming what the step points at.
 fn render_highlight_indicator(ui: &mut egui::Ui, hint: &TutorialUiHint, elapsed: f64) {
-    if let Some(target) = hint.highlight_target {
+    if let Some(target) = &hint.highlight {
         ui.add_space(8.0);
 
         // Pulsing alpha based on elapsed time
@@ -122,7 +133,12 @@ fn render_highlight_indicator(ui: &mut egui::Ui, hint: &TutorialUiHint, elapsed:
         let color = egui::Color32::from_rgba_unmultiplied(255, 200, 60, alpha);
 
         ui.horizontal(|ui| {
-            ui.label(egui::RichText::new(format!(">>> {} <<<", target)).strong().size(15.0).color(color));
+            ui.label(
+                egui::RichText::new(format!(">>> {} <<<", target.label()))
+                    .strong()
+                    .size(15.0)
+                    .color(color),
+            );
         });
     }
 }
@@ -132,20 +148,18 @@ fn render_buttons(
     ui: &mut egui::Ui,
     tutorial: &mut ResMut<TutorialState>,
     clock: &mut ResMut<GameClock>,
-    step: TutorialStep,
+    step_index: usize,
+    is_last: bool,
 ) {
     ui.horizontal(|ui| {
-        // Back button (disabled on Welcome step)
-        let can_go_back = step != TutorialStep::Welcome
-            && step != TutorialStep::Completed
-            && !tutorial.completed;
+        // Back button (disabled on the first and closing steps)
+        let can_go_back = step_index > 0 && !is_last && !tutorial.completed;
 
         if ui
             .add_enabled(
                 can_go_back,
                 egui::Button::new(
-                    egui::RichText::new("Back")
-                        .color(egui::Color32::from_rgb(180, 180, 200)),
+                    egui::RichText::new("Back").color(egui::Color32::from_rgb(180, 180, 200)),
                 ),
             )
             .clicked()
@@ -155,11 +169,7 @@ fn render_buttons(
 
         // Next / Close button for manual steps
         if tutorial.is_manual_step() {
-            let button_text = if step == TutorialStep::Completed {
-                "Close"
-            } else {
-                "Next"
-            };
+            let button_text = if is_last { "Close" } else { "Next" };
 
             if ui
                 .button(
@@ -169,7 +179,7 @@ fn render_buttons(
                 )
                 .clicked()
             {
-                if step == TutorialStep::Completed {
+                if is_last {
                     tutorial.active = false;
                 } else {
                     if tutorial.paused_by_tutorial {
@@ -188,8 +198,8 @@ fn render_buttons(
         }
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            // Skip button (always available except when completed)
-            if step != TutorialStep::Completed
+            // Skip button (always available except on the closing step)
+            if !is_last
                 && ui
                     .button(
                         egui::RichText::new("Skip Tutorial")
@@ -205,3 +215,34 @@ fn render_buttons(
         });
     });
 }
+
+/// Tells the tutorial when the player opens a window a step may be waiting
+/// for. Only sends while a tutorial is running.
+#[allow(clippy::too_many_arguments)]
+pub fn tutorial_signals(
+    tutorial: Res<TutorialState>,
+    budget: Res<BudgetPanelVisible>,
+    policies: Res<PoliciesVisible>,
+    charts: Res<ChartsVisible>,
+    advisor: Res<AdvisorVisible>,
+    journal: Res<JournalVisible>,
+    mut was_open: Local<[bool; 5]>,
+    mut signals: EventWriter<TutorialSignal>,
+) {
+    let open = [budget.0, policies.0, charts.0, advisor.0, journal.0];
+    let names = [
+        SIGNAL_BUDGET_OPENED,
+        SIGNAL_POLICIES_OPENED,
+        SIGNAL_CHARTS_OPENED,
+        SIGNAL_ADVISOR_OPENED,
+        SIGNAL_JOURNAL_OPENED,
+    ];
+    if tutorial.active {
+        for ((&now, &before), name) in open.iter().zip(was_open.iter()).zip(names) {
+            if now && !before {
+                signals.send(TutorialSignal(name.to_string()));
+            }
+        }
+    }
+    *was_open = open;
+}
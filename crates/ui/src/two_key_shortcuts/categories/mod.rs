@@ -1,18 +1,17 @@
 //! Shortcut category and item type definitions.
 
-use bevy::prelude::*;
 use rendering::input::ActiveTool;
 use rendering::overlay::OverlayMode;
+use simulation::keybindings::BindableAction;
 
 mod placement;
 mod views_and_tools;
 
-/// A shortcut category: a trigger key and the list of sub-tools.
+/// A shortcut category: the bindable action that opens it and the list of
+/// sub-tools. The trigger key is looked up in `KeyBindings`.
 pub(crate) struct ShortcutCategory {
-    pub key: KeyCode,
+    pub action: BindableAction,
     pub label: &'static str,
-    /// Short string shown in the popup header, e.g. "R" for roads.
-    pub key_hint: &'static str,
     pub items: Vec<ShortcutItem>,
 }
 
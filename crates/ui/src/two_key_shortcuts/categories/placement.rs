@@ -1,17 +1,16 @@
 //! Placement-oriented shortcut categories: roads, zones, utilities, emergency,
 //! education, parks, landmarks, sanitation, transport, and telecom.
 
-use bevy::prelude::*;
 use rendering::input::ActiveTool;
+use simulation::keybindings::BindableAction;
 
 use super::{ShortcutCategory, ShortcutItem};
 
 pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
     vec![
         ShortcutCategory {
-            key: KeyCode::KeyR,
+            action: BindableAction::MenuRoads,
             label: "Roads",
-            key_hint: "R",
             items: vec![
                 ShortcutItem {
                     name: "Local Road",
@@ -46,9 +45,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyZ,
+            action: BindableAction::MenuZones,
             label: "Zones",
-            key_hint: "Z",
             items: vec![
                 ShortcutItem {
                     name: "Res Low",
@@ -93,9 +91,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyU,
+            action: BindableAction::MenuUtilities,
             label: "Utilities",
-            key_hint: "U",
             items: vec![
                 ShortcutItem {
                     name: "Power Plant",
@@ -145,9 +142,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyE,
+            action: BindableAction::MenuEmergency,
             label: "Emergency",
-            key_hint: "E",
             items: vec![
                 ShortcutItem {
                     name: "Fire House",
@@ -202,9 +198,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyH,
+            action: BindableAction::MenuEducation,
             label: "Education",
-            key_hint: "H",
             items: vec![
                 ShortcutItem {
                     name: "Kindergarten",
@@ -234,9 +229,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyK,
+            action: BindableAction::MenuParks,
             label: "Parks",
-            key_hint: "K",
             items: vec![
                 ShortcutItem {
                     name: "Small Park",
@@ -271,9 +265,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyL,
+            action: BindableAction::MenuLandmarks,
             label: "Landmarks",
-            key_hint: "L",
             items: vec![
                 ShortcutItem {
                     name: "City Hall",
@@ -298,9 +291,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyG,
+            action: BindableAction::MenuSanitation,
             label: "Sanitation",
-            key_hint: "G",
             items: vec![
                 ShortcutItem {
                     name: "Landfill",
@@ -335,9 +327,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyX,
+            action: BindableAction::MenuTransport,
             label: "Transport",
-            key_hint: "X",
             items: vec![
                 ShortcutItem {
                     name: "Bus Depot",
@@ -382,9 +373,8 @@ pub(super) fn placement_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyN,
+            action: BindableAction::MenuTelecom,
             label: "Telecom",
-            key_hint: "N",
             items: vec![
                 ShortcutItem {
                     name: "Cell Tower",
@@ -1,18 +1,17 @@
 //! Non-placement shortcut categories: overlay views, environment, terrain,
 //! districts, and general tools.
 
-use bevy::prelude::*;
 use rendering::input::ActiveTool;
 use rendering::overlay::OverlayMode;
+use simulation::keybindings::BindableAction;
 
 use super::{ShortcutCategory, ShortcutItem};
 
 pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
     vec![
         ShortcutCategory {
-            key: KeyCode::KeyV,
+            action: BindableAction::MenuViews,
             label: "Views",
-            key_hint: "V",
             items: vec![
                 ShortcutItem {
                     name: "Power",
@@ -67,9 +66,8 @@ pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyF,
+            action: BindableAction::MenuEnvironment,
             label: "Environment",
-            key_hint: "F",
             items: vec![
                 ShortcutItem {
                     name: "Plant Tree",
@@ -84,9 +82,8 @@ pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyY,
+            action: BindableAction::MenuTerrain,
             label: "Terrain",
-            key_hint: "Y",
             items: vec![
                 ShortcutItem {
                     name: "Raise",
@@ -111,9 +108,8 @@ pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyO,
+            action: BindableAction::MenuDistricts,
             label: "Districts",
-            key_hint: "O",
             items: vec![
                 ShortcutItem {
                     name: "Downtown",
@@ -163,9 +159,8 @@ pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
             ],
         },
         ShortcutCategory {
-            key: KeyCode::KeyT,
+            action: BindableAction::MenuTools,
             label: "Tools",
-            key_hint: "T",
             items: vec![
                 ShortcutItem {
                     name: "Bulldoze",
@@ -177,6 +172,11 @@ pub(super) fn views_and_tools_categories() -> Vec<ShortcutCategory> {
                     tool: Some(ActiveTool::Inspect),
                     overlay: None,
                 },
+                ShortcutItem {
+                    name: "Blueprint",
+                    tool: Some(ActiveTool::Blueprint),
+                    overlay: None,
+                },
             ],
         },
     ]
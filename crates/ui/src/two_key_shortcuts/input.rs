@@ -8,6 +8,7 @@ use bevy_egui::EguiContexts;
 
 use rendering::input::ActiveTool;
 use rendering::overlay::{OverlayMode, OverlayState};
+use simulation::keybindings::KeyBindings;
 
 use crate::toolbar::OpenCategory;
 
@@ -59,6 +60,7 @@ fn digit_key_to_index(key: KeyCode) -> Option<usize> {
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn two_key_input_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     time: Res<Time>,
     mut state: ResMut<TwoKeyShortcutState>,
     mut tool: ResMut<ActiveTool>,
@@ -76,7 +78,7 @@ pub(crate) fn two_key_input_system(
     // --- If a category is already pending, handle sub-key / timeout / escape ---
     if let Some(cat_idx) = state.pending_category {
         // Escape cancels
-        if keyboard.just_pressed(KeyCode::Escape) {
+        if bindings.escape.just_pressed(&keyboard) {
             state.pending_category = None;
             state.timer = 0.0;
             return;
@@ -124,7 +126,7 @@ pub(crate) fn two_key_input_system(
 
         // Pressing a different category key switches category
         for (idx, cat) in categories.iter().enumerate() {
-            if keyboard.just_pressed(cat.key) && idx != cat_idx {
+            if bindings.get(cat.action).just_pressed(&keyboard) && idx != cat_idx {
                 state.pending_category = Some(idx);
                 state.timer = TIMEOUT_SECS;
                 // Also open the toolbar category popup for consistency
@@ -144,14 +146,10 @@ pub(crate) fn two_key_input_system(
     }
 
     // --- No pending category: check for category key presses ---
-    // Skip if modifier keys are held — those are separate bindings (e.g. Shift+G for curve draw).
-    let shift_held = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-    let ctrl_held = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
-    if shift_held || ctrl_held {
-        return;
-    }
+    // Bindings match modifiers exactly, so Shift+G (curve draw) does not open
+    // the G menu.
     for (idx, cat) in categories.iter().enumerate() {
-        if keyboard.just_pressed(cat.key) {
+        if bindings.get(cat.action).just_pressed(&keyboard) {
             state.pending_category = Some(idx);
             state.timer = TIMEOUT_SECS;
             // Also open the matching toolbar category popup for consistency
@@ -1,7 +1,8 @@
 //! Two-key tool shortcuts (UX-014).
 //!
-//! Press a category key (R for roads, Z for zones, etc.) to open a numbered
-//! popup listing the sub-tools for that category.  Then press a digit key
+//! Press a category key (R for roads, Z for zones, etc., rebindable under
+//! "Build Menus" in the keybindings panel) to open a numbered popup listing
+//! the sub-tools for that category.  Then press a digit key
 //! (1-9, 0) to select the corresponding sub-tool.  The popup auto-closes
 //! after a 2-second timeout or when Escape is pressed.
 
@@ -5,12 +5,17 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use simulation::keybindings::KeyBindings;
 
 use super::categories::build_shortcut_categories;
 use super::input::{TwoKeyShortcutState, TIMEOUT_SECS};
 
 /// Draws the numbered sub-tool popup when a category key is pending.
-pub(crate) fn two_key_popup_ui(state: Res<TwoKeyShortcutState>, mut contexts: EguiContexts) {
+pub(crate) fn two_key_popup_ui(
+    state: Res<TwoKeyShortcutState>,
+    bindings: Res<KeyBindings>,
+    mut contexts: EguiContexts,
+) {
     let cat_idx = match state.pending_category {
         Some(idx) => idx,
         None => return,
@@ -21,6 +26,7 @@ pub(crate) fn two_key_popup_ui(state: Res<TwoKeyShortcutState>, mut contexts: Eg
         return;
     }
     let cat = &categories[cat_idx];
+    let key_hint = bindings.get(cat.action).display_label();
 
     let screen = contexts.ctx_mut().screen_rect();
 
@@ -41,7 +47,7 @@ pub(crate) fn two_key_popup_ui(state: Res<TwoKeyShortcutState>, mut contexts: Eg
                     // Header with category name and key hint
                     ui.horizontal(|ui| {
                         ui.heading(
-                            egui::RichText::new(format!("[{}] {}", cat.key_hint, cat.label))
+                            egui::RichText::new(format!("[{key_hint}] {}", cat.label))
                                 .strong()
                                 .color(egui::Color32::from_rgb(140, 200, 255)),
                         );
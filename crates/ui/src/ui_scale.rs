@@ -0,0 +1,89 @@
+//! Applies `UiScaleSettings` to egui.
+//!
+//! The scale becomes the primary window's egui scale factor, so every panel
+//! grows and shrinks together on top of the operating system's DPI scaling.
+//! The font-size preset rescales egui's text styles from their defaults;
+//! text drawn with an explicit size only follows the global scale.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::{egui, EguiContextSettings, EguiContexts};
+
+use simulation::ui_scale::{detect_scale, UiScaleSettings};
+
+/// Text styles for a font-size factor, scaled from egui's defaults.
+fn scaled_text_styles(factor: f32) -> std::collections::BTreeMap<egui::TextStyle, egui::FontId> {
+    egui::Style::default()
+        .text_styles
+        .into_iter()
+        .map(|(style, font)| (style, egui::FontId::new(font.size * factor, font.family)))
+        .collect()
+}
+
+/// System: once the primary window exists, pick the scale from the display
+/// if auto-detection is on.
+fn detect_ui_scale(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut settings: ResMut<UiScaleSettings>,
+    mut done: Local<bool>,
+) {
+    if *done {
+        return;
+    }
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    *done = true;
+    if !settings.auto {
+        return;
+    }
+    let scale = detect_scale(window.scale_factor(), window.physical_width());
+    if settings.scale != scale {
+        settings.scale = scale;
+    }
+}
+
+/// System: keep egui's scale factor and text styles in line with the settings.
+fn apply_ui_scale(
+    mut contexts: EguiContexts,
+    settings: Res<UiScaleSettings>,
+    mut egui_settings: Query<&mut EguiContextSettings, With<PrimaryWindow>>,
+) {
+    for mut egui_settings in &mut egui_settings {
+        if egui_settings.scale_factor != settings.scale {
+            egui_settings.scale_factor = settings.scale;
+        }
+    }
+
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    let text_styles = scaled_text_styles(settings.font_size.factor());
+    if ctx.style().text_styles != text_styles {
+        ctx.style_mut(|style| style.text_styles = text_styles);
+    }
+}
+
+pub struct UiScaleUiPlugin;
+
+impl Plugin for UiScaleUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (detect_ui_scale, apply_ui_scale).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_text_styles_multiply_defaults() {
+        let defaults = egui::Style::default().text_styles;
+        let large = scaled_text_styles(1.2);
+        assert_eq!(large.len(), defaults.len());
+        for (style, font) in &defaults {
+            assert!((large[style].size - font.size * 1.2).abs() < 1e-4);
+        }
+        assert_eq!(scaled_text_styles(1.0), defaults);
+    }
+}
@@ -0,0 +1,158 @@
+//! Unlock tree viewer.
+//!
+//! Shows the trunk of the tree tier by tier, as unlocked by population
+//! milestones, and the branch points where development points are spent.
+//! Taking one option at a branch closes the other for the playthrough, so
+//! the choice asks for confirmation. Opened from the Milestones window.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::milestones::{MilestoneProgress, MilestoneTier};
+use simulation::stats::CityStats;
+use simulation::unlocks::{UnlockBranch, UnlockNode, UnlockState};
+
+/// Whether the unlock tree window is visible.
+#[derive(Resource, Default)]
+pub struct UnlockTreeVisible(pub bool);
+
+/// Branch option awaiting confirmation.
+#[derive(Resource, Default)]
+pub struct PendingBranchChoice(pub Option<UnlockNode>);
+
+const UNLOCKED: egui::Color32 = egui::Color32::from_rgb(80, 200, 120);
+const LOCKED: egui::Color32 = egui::Color32::from_rgb(140, 140, 150);
+const CLOSED: egui::Color32 = egui::Color32::from_rgb(200, 90, 90);
+
+/// Why a branch option can't be taken yet, for the disabled button's hover.
+fn blocked_reason(node: UnlockNode, unlocks: &UnlockState, population: u32) -> String {
+    let branch = node.branch().expect("branch option");
+    if !unlocks.is_unlocked(branch.prerequisite()) {
+        format!("Requires {}", branch.prerequisite().name())
+    } else if population < node.required_population() {
+        format!("Requires {} population", node.required_population())
+    } else {
+        format!("Requires {} development points", node.cost())
+    }
+}
+
+/// Renders the unlock tree window.
+pub fn unlock_tree_ui(
+    mut contexts: EguiContexts,
+    mut visible: ResMut<UnlockTreeVisible>,
+    mut pending: ResMut<PendingBranchChoice>,
+    mut unlocks: ResMut<UnlockState>,
+    progress: Res<MilestoneProgress>,
+    stats: Res<CityStats>,
+) {
+    if !visible.0 {
+        return;
+    }
+    let population = stats.population;
+
+    let mut open = true;
+    egui::Window::new("Unlock Tree")
+        .open(&mut open)
+        .default_width(460.0)
+        .vscroll(true)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.label(format!(
+                "Development points: {} available ({} spent)",
+                unlocks.available_points(),
+                unlocks.spent_points
+            ));
+
+            ui.separator();
+            ui.heading("Branch points");
+            for branch in UnlockBranch::ALL {
+                ui.label(egui::RichText::new(branch.name()).strong());
+                ui.small(format!("After {}", branch.prerequisite().name()));
+                for node in branch.options() {
+                    ui.horizontal(|ui| {
+                        if unlocks.is_unlocked(node) {
+                            ui.colored_label(UNLOCKED, format!("✔ {}", node.name()));
+                        } else if unlocks.is_foreclosed(node) {
+                            ui.colored_label(CLOSED, format!("✘ {}", node.name()))
+                                .on_hover_text("Closed by the other choice");
+                        } else {
+                            ui.label(node.name());
+                            let can = unlocks.can_purchase(node, population);
+                            if ui
+                                .add_enabled(
+                                    can,
+                                    egui::Button::new(format!("Choose ({} DP)", node.cost())),
+                                )
+                                .on_disabled_hover_text(blocked_reason(node, &unlocks, population))
+                                .clicked()
+                            {
+                                pending.0 = Some(node);
+                            }
+                        }
+                    });
+                    ui.small(format!("  {}", node.description()));
+                }
+                ui.add_space(6.0);
+            }
+
+            if let Some(node) = pending.0 {
+                ui.separator();
+                let other = node
+                    .branch()
+                    .and_then(|b| b.options().into_iter().find(|&o| o != node));
+                ui.label(format!(
+                    "Choose {}? {} will be closed for the rest of this city.",
+                    node.name(),
+                    other.map_or("The alternative", |o| o.name())
+                ));
+                ui.horizontal(|ui| {
+                    if ui.button("Confirm").clicked() {
+                        if unlocks.can_purchase(node, population) {
+                            unlocks.purchase(node);
+                        }
+                        pending.0 = None;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        pending.0 = None;
+                    }
+                });
+            }
+
+            ui.separator();
+            ui.heading("Milestones");
+            for &tier in MilestoneTier::ALL {
+                let reached = progress.has_reached(tier);
+                let title = format!("{} ({} pop)", tier.name(), tier.required_population());
+                if reached {
+                    ui.colored_label(UNLOCKED, title);
+                } else {
+                    ui.colored_label(LOCKED, title);
+                }
+                ui.horizontal_wrapped(|ui| {
+                    for &node in tier.unlocks() {
+                        let text = format!("  {}", node.name());
+                        if unlocks.has_access(node) {
+                            ui.colored_label(UNLOCKED, text);
+                        } else {
+                            ui.colored_label(LOCKED, text);
+                        }
+                    }
+                });
+            }
+        });
+
+    if !open {
+        visible.0 = false;
+        pending.0 = None;
+    }
+}
+
+pub struct UnlockTreePanelPlugin;
+
+impl Plugin for UnlockTreePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnlockTreeVisible>()
+            .init_resource::<PendingBranchChoice>()
+            .add_systems(Update, unlock_tree_ui.run_if(in_state(AppState::Playing)));
+    }
+}
@@ -0,0 +1,94 @@
+//! Civil unrest window.
+//!
+//! Pops up while any district is rioting. For each riot it shows how long it
+//! has been going on, the buildings burned and the police overtime billed so
+//! far, and offers the two ways to end it: concessions or a crackdown.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use simulation::app_state::AppState;
+
+use simulation::economy::CityBudget;
+use simulation::time_of_day::GameClock;
+use simulation::unrest::{
+    district_center, Resolution, UnrestResolutionRequest, UnrestState, CRACKDOWN_COST,
+};
+
+/// Renders the unrest window while riots are active.
+pub fn unrest_panel_ui(
+    mut contexts: EguiContexts,
+    state: Res<UnrestState>,
+    budget: Res<CityBudget>,
+    clock: Res<GameClock>,
+    mut requests: EventWriter<UnrestResolutionRequest>,
+) {
+    if state.riots.is_empty() {
+        return;
+    }
+
+    egui::Window::new("Civil Unrest")
+        .default_width(320.0)
+        .show(contexts.ctx_mut(), |ui| {
+            for riot in &state.riots {
+                let (x, y) = district_center(riot.district);
+                ui.colored_label(
+                    egui::Color32::from_rgb(220, 60, 40),
+                    format!("Riot in the district around ({x}, {y})"),
+                );
+                ui.label(format!(
+                    "Day {} of the riot, {} buildings burned",
+                    clock.day.saturating_sub(riot.started_day) + 1,
+                    riot.buildings_burned
+                ));
+                ui.label(format!(
+                    "Police overtime so far: ${:.0}",
+                    riot.overtime_cost
+                ));
+
+                ui.horizontal(|ui| {
+                    let concessions = riot.concession_cost();
+                    if ui
+                        .add_enabled(
+                            budget.treasury >= concessions,
+                            egui::Button::new(format!("Concessions (${concessions:.0})")),
+                        )
+                        .on_hover_text("Ends the riot; residents stay happier for months")
+                        .clicked()
+                    {
+                        requests.send(UnrestResolutionRequest {
+                            district: riot.district,
+                            resolution: Resolution::Concessions,
+                        });
+                    }
+                    if ui
+                        .add_enabled(
+                            budget.treasury >= CRACKDOWN_COST,
+                            egui::Button::new(format!("Crackdown (${CRACKDOWN_COST:.0})")),
+                        )
+                        .on_hover_text(
+                            "Ends the riot; residents resent it and unrest returns sooner",
+                        )
+                        .clicked()
+                    {
+                        requests.send(UnrestResolutionRequest {
+                            district: riot.district,
+                            resolution: Resolution::Crackdown,
+                        });
+                    }
+                });
+                ui.separator();
+            }
+            ui.small(format!(
+                "Riots so far: {}, buildings burned: {}",
+                state.total_riots, state.total_burned
+            ));
+        });
+}
+
+pub struct UnrestPanelPlugin;
+
+impl Plugin for UnrestPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, unrest_panel_ui.run_if(in_state(AppState::Playing)));
+    }
+}
@@ -0,0 +1,208 @@
+//! Watchlist panel for pinned citizens, buildings and districts.
+//!
+//! Shown whenever something is pinned. Each row names the target with one or
+//! two live stats from `simulation::watchlist::WatchlistLive`; clicking the
+//! name moves the camera to it and "x" unpins it. Targets are pinned from
+//! the citizen, building and district inspection panels via [`pin_button`].
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use rendering::camera::OrbitCamera;
+use simulation::app_state::AppState;
+use simulation::watchlist::{WatchRow, WatchStats, WatchTarget, Watchlist, WatchlistLive};
+
+use crate::citizen_info::{citizen_name, FollowCitizen};
+use crate::info_panel::zone_type_name;
+use crate::theme;
+
+const PANEL_WIDTH: f32 = 220.0;
+
+/// Name shown for a row.
+pub fn row_label(row: &WatchRow) -> String {
+    match (&row.stats, row.entity) {
+        (WatchStats::Citizen { gender, .. }, Some(entity)) => citizen_name(entity, *gender),
+        (WatchStats::Building { zone, level, .. }, _) => {
+            format!("{} L{level}", zone_type_name(*zone))
+        }
+        (WatchStats::District { name, .. }, _) => name.clone(),
+        _ => match row.target {
+            WatchTarget::Citizen(key) => format!("Citizen from ({}, {})", key.home_x, key.home_y),
+            WatchTarget::Building { x, y } => format!("Building at ({x}, {y})"),
+            WatchTarget::District { index } => format!("District #{}", index + 1),
+        },
+    }
+}
+
+/// The live stats for a row, in one line.
+pub fn row_stats(row: &WatchRow) -> String {
+    match &row.stats {
+        WatchStats::Missing => "No longer in the city".to_string(),
+        WatchStats::Citizen {
+            happiness, health, ..
+        } => format!("Happy {happiness:.0}%  Health {health:.0}%"),
+        WatchStats::Building {
+            occupants,
+            capacity,
+            condition,
+            ..
+        } => format!("{occupants}/{capacity} occupied  Condition {condition:.0}%"),
+        WatchStats::District {
+            population,
+            happiness,
+            ..
+        } => format!("Pop {population}  Happy {happiness:.0}%"),
+    }
+}
+
+/// "Pin" / "Unpin" toggle for the inspection panels. Takes the `ResMut` so
+/// the watchlist is only marked changed when a button is clicked.
+pub fn pin_button(ui: &mut egui::Ui, watchlist: &mut ResMut<Watchlist>, target: WatchTarget) {
+    if watchlist.contains(&target) {
+        if ui.button("Unpin from Watchlist").clicked() {
+            watchlist.unpin(&target);
+        }
+    } else {
+        let full = watchlist.is_full();
+        let response = ui.add_enabled(!full, egui::Button::new("Pin to Watchlist"));
+        let response = if full {
+            response.on_disabled_hover_text("The watchlist is full")
+        } else {
+            response
+        };
+        if response.clicked() {
+            watchlist.pin(target);
+        }
+    }
+}
+
+/// Renders the watchlist panel.
+pub fn watchlist_ui(
+    mut contexts: EguiContexts,
+    mut watchlist: ResMut<Watchlist>,
+    live: Res<WatchlistLive>,
+    mut orbit: ResMut<OrbitCamera>,
+    mut follow: ResMut<FollowCitizen>,
+) {
+    if live.rows.is_empty() {
+        return;
+    }
+
+    let mut unpin = None;
+    egui::Window::new("Watchlist")
+        .id(egui::Id::new("watchlist_panel"))
+        .collapsible(true)
+        .resizable(false)
+        .default_width(PANEL_WIDTH)
+        .anchor(egui::Align2::RIGHT_CENTER, [-8.0, 0.0])
+        .show(contexts.ctx_mut(), |ui| {
+            ui.set_max_width(PANEL_WIDTH);
+            for (i, row) in live.rows.iter().enumerate() {
+                if i > 0 {
+                    ui.separator();
+                }
+                ui.horizontal(|ui| {
+                    let label = row_label(row);
+                    match row.focus {
+                        Some((wx, wz)) => {
+                            if ui.link(label).on_hover_text("Show on map").clicked() {
+                                follow.0 = None;
+                                orbit.focus.x = wx;
+                                orbit.focus.z = wz;
+                            }
+                        }
+                        None => {
+                            ui.label(label);
+                        }
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("x").on_hover_text("Unpin").clicked() {
+                            unpin = Some(row.target);
+                        }
+                    });
+                });
+                let color = if row.stats == WatchStats::Missing {
+                    theme::WARNING
+                } else {
+                    theme::TEXT_MUTED
+                };
+                ui.colored_label(color, row_stats(row));
+            }
+        });
+
+    if let Some(target) = unpin {
+        watchlist.unpin(&target);
+    }
+}
+
+pub struct WatchlistUiPlugin;
+
+impl Plugin for WatchlistUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, watchlist_ui.run_if(in_state(AppState::Playing)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use simulation::citizen::Gender;
+    use simulation::grid::ZoneType;
+    use simulation::watchlist::CitizenKey;
+
+    fn row(target: WatchTarget, stats: WatchStats) -> WatchRow {
+        WatchRow {
+            target,
+            entity: None,
+            focus: None,
+            stats,
+        }
+    }
+
+    #[test]
+    fn test_building_row_text() {
+        let r = row(
+            WatchTarget::building(4, 5),
+            WatchStats::Building {
+                zone: ZoneType::Industrial,
+                level: 2,
+                occupants: 12,
+                capacity: 20,
+                condition: 87.4,
+            },
+        );
+        assert_eq!(row_label(&r), "Industrial L2");
+        assert_eq!(row_stats(&r), "12/20 occupied  Condition 87%");
+    }
+
+    #[test]
+    fn test_citizen_row_uses_generated_name() {
+        let entity = Entity::from_raw(42);
+        let r = WatchRow {
+            entity: Some(entity),
+            ..row(
+                WatchTarget::Citizen(CitizenKey {
+                    home_x: 1,
+                    home_y: 1,
+                    age: 40,
+                    female: false,
+                    education: 1,
+                }),
+                WatchStats::Citizen {
+                    gender: Gender::Male,
+                    happiness: 70.0,
+                    health: 90.0,
+                },
+            )
+        };
+        assert_eq!(row_label(&r), citizen_name(entity, Gender::Male));
+        assert_eq!(row_stats(&r), "Happy 70%  Health 90%");
+    }
+
+    #[test]
+    fn test_missing_row_describes_target() {
+        let r = row(WatchTarget::district(2), WatchStats::Missing);
+        assert_eq!(row_label(&r), "District #3");
+        assert_eq!(row_stats(&r), "No longer in the city");
+    }
+}
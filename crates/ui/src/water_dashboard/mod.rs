@@ -5,6 +5,7 @@
 //! - Source breakdown: wells, surface intake, reservoir, desalination contributions
 //! - Groundwater level indicator with depletion warning
 //! - Reservoir level: % full, days of storage
+//! - Yarkon river flow: dam storage and release, extraction, downstream flow
 //! - Service coverage: % of buildings with water service
 //! - Water quality: treatment level and output quality
 //! - Sewage treatment: % of wastewater treated, treatment level
@@ -4,6 +4,7 @@ use bevy_egui::egui;
 
 use simulation::groundwater::GroundwaterStats;
 use simulation::reservoir::ReservoirState;
+use simulation::river_flow::{RiverFlowState, ENVIRONMENTAL_FLOW_MGD, MAX_RELEASE_MGD};
 use simulation::wastewater::WastewaterState;
 use simulation::water_demand::WaterSupply;
 use simulation::water_treatment::WaterTreatmentState;
@@ -188,6 +189,73 @@ pub fn render_reservoir(ui: &mut egui::Ui, reservoir_state: &ReservoirState) {
     }
 }
 
+/// Renders the river flow panel. Returns the new dam release target when
+/// the player moves the slider.
+pub fn render_river(ui: &mut egui::Ui, river: &RiverFlowState) -> Option<f32> {
+    ui.heading("Yarkon River");
+    egui::Grid::new("river_flow_grid")
+        .num_columns(2)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Inflow to Dam:");
+            ui.label(format!("{:.1} MGD", river.inflow_mgd));
+            ui.end_row();
+
+            ui.label("Dam Storage:");
+            ui.label(format!(
+                "{:.0}% ({:.0} days at target)",
+                river.dam_fill() * 100.0,
+                river.dam_days_left().min(9_999.0)
+            ));
+            ui.end_row();
+
+            ui.label("Released:");
+            ui.label(format!("{:.1} MGD", river.release_mgd));
+            ui.end_row();
+
+            ui.label("Extracted:");
+            ui.label(format!(
+                "{:.1} of {:.1} MGD",
+                river.extraction_mgd, river.extraction_demand_mgd
+            ));
+            ui.end_row();
+
+            ui.label("Downstream Flow:");
+            let color = if river.is_dry() {
+                egui::Color32::from_rgb(255, 60, 60)
+            } else if river.health() < 1.0 {
+                egui::Color32::from_rgb(220, 200, 50)
+            } else {
+                egui::Color32::from_rgb(50, 180, 220)
+            };
+            ui.colored_label(color, format!("{:.1} MGD", river.downstream_mgd));
+            ui.end_row();
+        });
+
+    if river.shortfall_mgd() > 0.0 {
+        ui.colored_label(
+            egui::Color32::from_rgb(255, 60, 60),
+            format!(
+                "Intakes short by {:.1} MGD: the river cannot meet the draw",
+                river.shortfall_mgd()
+            ),
+        );
+    }
+    if river.is_dry() {
+        ui.colored_label(
+            egui::Color32::from_rgb(255, 60, 60),
+            format!("Downstream reach dry for {} days", river.dry_days),
+        );
+    }
+
+    let mut target = river.release_target_mgd;
+    ui.add(egui::Slider::new(&mut target, 0.0..=MAX_RELEASE_MGD).text("Dam release (MGD)"));
+    ui.small(format!(
+        "The reach below the intakes needs {ENVIRONMENTAL_FLOW_MGD:.0} MGD to stay healthy"
+    ));
+    (target != river.release_target_mgd).then_some(target)
+}
+
 /// Renders the service coverage panel.
 pub fn render_service_coverage(ui: &mut egui::Ui, water_supply: &WaterSupply) {
     ui.heading("Service Coverage");
@@ -5,11 +5,14 @@ use bevy_egui::{egui, EguiContexts};
 
 use simulation::groundwater::GroundwaterStats;
 use simulation::reservoir::ReservoirState;
+use simulation::river_flow::RiverFlowState;
 use simulation::wastewater::WastewaterState;
 use simulation::water_demand::WaterSupply;
 use simulation::water_sources::{WaterSource, WaterSourceType};
 use simulation::water_treatment::WaterTreatmentState;
 
+use crate::regional_water_panel::RegionalWaterPanelVisible;
+
 use super::panels;
 use super::types::{SourceAggregation, WaterDashboardVisible, MGD_TO_GPD};
 
@@ -55,8 +58,8 @@ fn aggregate_sources(sources: &Query<&WaterSource>) -> SourceAggregation {
 /// Displays the water supply dashboard window.
 ///
 /// Shows demand/supply balance, source breakdown, groundwater status,
-/// reservoir levels, service coverage, water quality, sewage treatment,
-/// and monthly water budget information.
+/// reservoir levels, river flow, service coverage, water quality, sewage
+/// treatment, and monthly water budget information.
 #[allow(clippy::too_many_arguments)]
 pub fn water_dashboard_ui(
     mut contexts: EguiContexts,
@@ -67,6 +70,8 @@ pub fn water_dashboard_ui(
     treatment_state: Res<WaterTreatmentState>,
     wastewater_state: Res<WastewaterState>,
     sources: Query<&WaterSource>,
+    mut regional_visible: ResMut<RegionalWaterPanelVisible>,
+    mut river: ResMut<RiverFlowState>,
 ) {
     if !visible.0 {
         return;
@@ -84,6 +89,9 @@ pub fn water_dashboard_ui(
             ui.separator();
 
             panels::render_supply_demand(ui, total_demand_mgd, total_supply_mgd);
+            if ui.button("Regional Water...").clicked() {
+                regional_visible.0 = true;
+            }
 
             ui.add_space(4.0);
             ui.separator();
@@ -103,6 +111,13 @@ pub fn water_dashboard_ui(
             ui.add_space(4.0);
             ui.separator();
 
+            if let Some(target) = panels::render_river(ui, &river) {
+                river.release_target_mgd = target;
+            }
+
+            ui.add_space(4.0);
+            ui.separator();
+
             panels::render_service_coverage(ui, &water_supply);
 
             ui.add_space(4.0);
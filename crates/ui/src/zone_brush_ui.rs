@@ -7,6 +7,8 @@ use rendering::zone_brush_preview::{
     brush_cells, is_cell_valid_for_zone, ZoneBrushSize, ZONE_COST_PER_CELL,
 };
 use simulation::grid::WorldGrid;
+use simulation::heightmap_import::BuildabilityGrid;
+use simulation::map_tiles::MapTiles;
 use simulation::urban_growth_boundary::UrbanGrowthBoundary;
 
 /// Display zone brush info and total cost near the cursor via egui.
@@ -17,6 +19,8 @@ pub fn zone_brush_cost_ui(
     cursor: Res<CursorGridPos>,
     grid: Res<WorldGrid>,
     ugb: Res<UrbanGrowthBoundary>,
+    buildable: Res<BuildabilityGrid>,
+    tiles: Res<MapTiles>,
 ) {
     let Some(zone) = tool.zone_type() else {
         return;
@@ -28,7 +32,11 @@ pub fn zone_brush_cost_ui(
     let cells = brush_cells(cursor.grid_x, cursor.grid_y, brush.half_extent, &grid);
     let valid_count = cells
         .iter()
-        .filter(|(gx, gy)| is_cell_valid_for_zone(&grid, *gx, *gy, zone, &ugb))
+        .filter(|(gx, gy)| {
+            is_cell_valid_for_zone(&grid, *gx, *gy, zone, &ugb)
+                && buildable.is_buildable(*gx, *gy)
+                && tiles.contains(*gx, *gy)
+        })
         .count();
     let total_cost = valid_count as f64 * ZONE_COST_PER_CELL;
 